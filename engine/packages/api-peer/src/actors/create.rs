@@ -4,6 +4,8 @@ use rivet_api_builder::ApiCtx;
 use rivet_api_types::actors::create::{CreateQuery, CreateRequest, CreateResponse};
 
 const MAX_ACTOR_KEY_SIZE: usize = 1024;
+const MAX_AFFINITY_ACTORS: usize = 32;
+const MAX_REQUIRED_CAPABILITIES: usize = 16;
 
 #[tracing::instrument(skip_all)]
 pub async fn create(
@@ -12,18 +14,7 @@ pub async fn create(
 	query: CreateQuery,
 	body: CreateRequest,
 ) -> Result<CreateResponse> {
-	if let Some(key) = &body.key {
-		if key.is_empty() {
-			return Err(pegboard::errors::Actor::EmptyKey.build());
-		}
-		if key.len() > MAX_ACTOR_KEY_SIZE {
-			return Err(pegboard::errors::Actor::KeyTooLarge {
-				max_size: MAX_ACTOR_KEY_SIZE,
-				key_preview: util::safe_slice(key, 0, MAX_ACTOR_KEY_SIZE).to_string(),
-			}
-			.build());
-		}
-	}
+	validate(&body)?;
 
 	let namespace = ctx
 		.op(namespace::ops::resolve_for_name_global::Input {
@@ -43,6 +34,9 @@ pub async fn create(
 			runner_name_selector: body.runner_name_selector,
 			input: body.input.clone(),
 			crash_policy: body.crash_policy,
+			affinity: body.affinity.filter(|affinity| !affinity.is_empty()),
+			required_capabilities: body.required_capabilities,
+			tags: body.tags,
 			// NOTE: This can forward if the user attempts to create an actor with a target dc and this dc
 			// ends up forwarding to another.
 			forward_request: true,
@@ -53,3 +47,39 @@ pub async fn create(
 
 	Ok(CreateResponse { actor: res.actor })
 }
+
+/// Checks the limits on a create request that do not need any reads.
+pub(crate) fn validate(body: &CreateRequest) -> Result<()> {
+	if let Some(key) = &body.key {
+		if key.is_empty() {
+			return Err(pegboard::errors::Actor::EmptyKey.build());
+		}
+		if key.len() > MAX_ACTOR_KEY_SIZE {
+			return Err(pegboard::errors::Actor::KeyTooLarge {
+				max_size: MAX_ACTOR_KEY_SIZE,
+				key_preview: util::safe_slice(key, 0, MAX_ACTOR_KEY_SIZE).to_string(),
+			}
+			.build());
+		}
+	}
+
+	if let Some(affinity) = &body.affinity {
+		if affinity.colocate_with.len() + affinity.avoid.len() > MAX_AFFINITY_ACTORS {
+			return Err(pegboard::errors::Actor::AffinityTooLarge {
+				max_actors: MAX_AFFINITY_ACTORS,
+			}
+			.build());
+		}
+	}
+
+	if body.required_capabilities.len() > MAX_REQUIRED_CAPABILITIES {
+		return Err(pegboard::errors::Actor::TooManyRequiredCapabilities {
+			max_capabilities: MAX_REQUIRED_CAPABILITIES,
+		}
+		.build());
+	}
+
+	pegboard::utils::validate_tags(body.tags.iter()).map_err(|err| err.build())?;
+
+	Ok(())
+}
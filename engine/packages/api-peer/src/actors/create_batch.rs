@@ -0,0 +1,110 @@
+use anyhow::Result;
+use gas::prelude::*;
+use rivet_api_builder::{ApiCtx, RawErrorResponse};
+use rivet_api_types::actors::create_batch::{
+	CreateBatchError, CreateBatchQuery, CreateBatchRequest, CreateBatchResponse, CreateBatchResult,
+};
+use rivet_error::RivetError;
+
+#[tracing::instrument(skip_all)]
+pub async fn create_batch(
+	ctx: ApiCtx,
+	_path: (),
+	query: CreateBatchQuery,
+	body: CreateBatchRequest,
+) -> Result<CreateBatchResponse> {
+	pegboard::utils::validate_create_batch_size(ctx.config(), body.actors.len())?;
+
+	let namespace = ctx
+		.op(namespace::ops::resolve_for_name_global::Input {
+			name: query.namespace.clone(),
+		})
+		.await?
+		.ok_or_else(|| namespace::errors::Namespace::NotFound.build())?;
+
+	// Actors that fail validation are reported without being sent to pegboard
+	let mut results = Vec::with_capacity(body.actors.len());
+	let mut actors = Vec::new();
+	for actor in body.actors {
+		match super::create::validate(&actor) {
+			Ok(()) => {
+				results.push(None);
+				actors.push(pegboard::ops::actor::create_batch::BatchActor {
+					actor_id: Id::new_v1(ctx.config().dc_label()),
+					name: actor.name,
+					key: actor.key,
+					runner_name_selector: actor.runner_name_selector,
+					crash_policy: actor.crash_policy,
+					affinity: actor.affinity.filter(|affinity| !affinity.is_empty()),
+					required_capabilities: actor.required_capabilities,
+					tags: actor.tags,
+					input: actor.input,
+				});
+			}
+			Err(err) => results.push(Some(failed(batch_error(&err)))),
+		}
+	}
+
+	let mut created = if actors.is_empty() {
+		Vec::new()
+	} else {
+		ctx.op(pegboard::ops::actor::create_batch::Input {
+			namespace_id: namespace.namespace_id,
+			actors,
+		})
+		.await?
+		.results
+	}
+	.into_iter();
+
+	let results = results
+		.into_iter()
+		.map(|res| {
+			res.unwrap_or_else(|| match created.next() {
+				Some(Ok(actor)) => CreateBatchResult {
+					actor: Some(actor),
+					error: None,
+				},
+				Some(Err(err)) => failed(batch_error(&err)),
+				None => failed(batch_error(&anyhow::anyhow!("missing batch create result"))),
+			})
+		})
+		.collect();
+
+	Ok(CreateBatchResponse { results })
+}
+
+/// Converts an error to the same group, code, and message the API would respond with for a single
+/// create.
+pub fn batch_error(err: &anyhow::Error) -> CreateBatchError {
+	if let Some(raw_err) = err
+		.chain()
+		.find_map(|x| x.downcast_ref::<RawErrorResponse>())
+	{
+		CreateBatchError {
+			group: raw_err.1.group.to_string(),
+			code: raw_err.1.code.to_string(),
+			message: raw_err.1.message.clone(),
+		}
+	} else {
+		let rivet_err = RivetError::extract(err);
+		if rivet_err.group() == rivet_error::INTERNAL_ERROR.group
+			&& rivet_err.code() == rivet_error::INTERNAL_ERROR.code
+		{
+			tracing::warn!(?err, "internal error in actor batch");
+		}
+
+		CreateBatchError {
+			group: rivet_err.group().to_string(),
+			code: rivet_err.code().to_string(),
+			message: rivet_err.message().to_string(),
+		}
+	}
+}
+
+pub fn failed(error: CreateBatchError) -> CreateBatchResult {
+	CreateBatchResult {
+		actor: None,
+		error: Some(error),
+	}
+}
@@ -0,0 +1,87 @@
+use anyhow::Result;
+use gas::prelude::*;
+use pegboard::ops::actor::destroy_batch::Selector;
+use rivet_api_builder::{ApiBadRequest, ApiCtx};
+use rivet_api_types::actors::destroy_batch::{
+	DestroyBatchError, DestroyBatchQuery, DestroyBatchRequest, DestroyBatchResponse,
+	DestroyBatchResult,
+};
+
+use super::create_batch::batch_error;
+
+#[tracing::instrument(skip_all)]
+pub async fn destroy_batch(
+	ctx: ApiCtx,
+	_path: (),
+	query: DestroyBatchQuery,
+	body: DestroyBatchRequest,
+) -> Result<DestroyBatchResponse> {
+	let selector = selector(body)?;
+
+	let namespace = ctx
+		.op(namespace::ops::resolve_for_name_global::Input {
+			name: query.namespace.clone(),
+		})
+		.await?
+		.ok_or_else(|| namespace::errors::Namespace::NotFound.build())?;
+
+	let res = ctx
+		.op(pegboard::ops::actor::destroy_batch::Input {
+			namespace_id: namespace.namespace_id,
+			selector,
+		})
+		.await?;
+
+	let results = res
+		.results
+		.into_iter()
+		.map(|(actor_id, res)| DestroyBatchResult {
+			actor_id,
+			error: res.err().map(|err| destroy_batch_error(&err)),
+		})
+		.collect();
+
+	Ok(DestroyBatchResponse {
+		results,
+		has_more: res.has_more,
+	})
+}
+
+/// Validates that the request sets either actor IDs or a filter.
+pub fn selector(body: DestroyBatchRequest) -> Result<Selector> {
+	match (body.actor_ids.is_empty(), body.name) {
+		(false, Some(_)) => Err(ApiBadRequest {
+			reason: "cannot provide both `actor_ids` and `name`".to_string(),
+		}
+		.build()),
+		(false, None) => {
+			if body.key_prefix.is_some() {
+				return Err(ApiBadRequest {
+					reason: "`name` is required when `key_prefix` is provided".to_string(),
+				}
+				.build());
+			}
+
+			Ok(Selector::ActorIds(body.actor_ids))
+		}
+		(true, Some(name)) => Ok(Selector::Filter {
+			name,
+			key_prefix: body.key_prefix,
+		}),
+		(true, None) => Err(ApiBadRequest {
+			reason: "either `actor_ids` or `name` is required".to_string(),
+		}
+		.build()),
+	}
+}
+
+/// Converts an error to a destroy result error, see [`batch_error`].
+pub fn destroy_batch_error(err: &anyhow::Error) -> DestroyBatchError {
+	let err = batch_error(err);
+
+	DestroyBatchError {
+		group: err.group,
+		code: err.code,
+		message: err.message,
+	}
+}
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use anyhow::Result;
 use gas::prelude::*;
 use rivet_api_builder::ApiCtx;
@@ -60,6 +62,9 @@ pub async fn get_or_create(
 					runner_name_selector: body.runner_name_selector,
 					input: body.input.clone(),
 					crash_policy: body.crash_policy,
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: HashMap::new(),
 					// NOTE: This can forward if the user attempts to create an actor with a target dc and this dc
 					// ends up forwarding to another.
 					forward_request: true,
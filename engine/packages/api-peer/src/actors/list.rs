@@ -27,6 +27,21 @@ pub async fn list(ctx: ApiCtx, _path: (), query: ListQuery) -> Result<ListRespon
 	]
 	.concat();
 	let include_destroyed = query.include_destroyed.unwrap_or(false);
+	let limit = query.limit.unwrap_or(100);
+	let cursor = query.cursor.as_deref().map(ListCursor::parse).transpose()?;
+	let tags = query.tags()?;
+	// Cursors without an actor ID skip every actor created at their timestamp
+	let created_before = match cursor {
+		Some(ListCursor {
+			create_ts,
+			actor_id: None,
+		}) => Some(
+			query
+				.created_before
+				.map_or(create_ts, |created_before| created_before.min(create_ts)),
+		),
+		_ => query.created_before,
+	};
 
 	// TODO: Update api-peer to require including the reservation ID in the query if querying with
 	// key in order to assert the request was sent to the correct datacenter
@@ -49,36 +64,67 @@ pub async fn list(ctx: ApiCtx, _path: (), query: ListQuery) -> Result<ListRespon
 			})
 			.await?;
 
-		// Filter actors by namespace
+		// Filter actors by namespace and the query's filters
 		let mut actors: Vec<rivet_types::actors::Actor> = get_res
 			.actors
 			.into_iter()
 			.filter(|actor| actor.namespace_id == namespace.namespace_id)
+			.filter(|actor| query.state.is_none_or(|state| actor.state() == state))
+			.filter(|actor| {
+				query
+					.created_before
+					.is_none_or(|created_before| actor.create_ts < created_before)
+			})
+			.filter(|actor| {
+				query
+					.created_after
+					.is_none_or(|created_after| actor.create_ts > created_after)
+			})
+			.filter(|actor| cursor.is_none_or(|cursor| cursor.precedes(actor)))
 			.collect();
 
 		// Sort by create ts desc
-		actors.sort_by_cached_key(|x| std::cmp::Reverse(x.create_ts));
-
-		// Apply cursor (cursor is the create_ts of the last actor returned in the previous page;
-		// we want strictly older actors since results are sorted desc by create_ts)
-		if let Some(cursor) = query.cursor.as_deref() {
-			let cursor_ts: i64 = cursor.parse()?;
-			actors.retain(|actor| actor.create_ts < cursor_ts);
-		}
+		actors.sort_by_cached_key(|x| std::cmp::Reverse((x.create_ts, x.actor_id)));
 
 		// Apply limit
-		actors.truncate(query.limit.unwrap_or(100));
-
-		let cursor = actors.last().map(|x| x.create_ts.to_string());
+		actors.truncate(limit);
 
 		Ok(ListResponse {
+			pagination: Pagination {
+				cursor: next_cursor(&actors, limit),
+			},
 			actors,
-			pagination: Pagination { cursor },
+		})
+	} else if !tags.is_empty() {
+		let namespace = ctx
+			.op(namespace::ops::resolve_for_name_global::Input {
+				name: query.namespace.clone(),
+			})
+			.await?
+			.ok_or_else(|| namespace::errors::Namespace::NotFound.build())?;
+
+		let list_res = ctx
+			.op(pegboard::ops::actor::list_for_tags::Input {
+				namespace_id: namespace.namespace_id,
+				tags,
+				created_before,
+				created_after: query.created_after,
+				cursor: cursor.and_then(|cursor| Some((cursor.create_ts, cursor.actor_id?))),
+				limit,
+				fetch_error: true,
+			})
+			.await?;
+
+		Ok(ListResponse {
+			pagination: Pagination {
+				cursor: next_cursor(&list_res.actors, limit),
+			},
+			actors: list_res.actors,
 		})
 	} else {
 		// Original list logic for name/key
 		let Some(name) = query.name else {
-			bail!("name is required when not using actor_ids")
+			bail!("name is required when not using actor_ids or tags")
 		};
 
 		let namespace = ctx
@@ -94,21 +140,32 @@ pub async fn list(ctx: ApiCtx, _path: (), query: ListQuery) -> Result<ListRespon
 				name,
 				key,
 				include_destroyed,
-				created_before: query
-					.cursor
-					.as_deref()
-					.map(|c| c.parse::<i64>())
-					.transpose()?,
-				limit: query.limit.unwrap_or(100),
+				state: query.state,
+				created_before,
+				created_after: query.created_after,
+				cursor: cursor.and_then(|cursor| Some((cursor.create_ts, cursor.actor_id?))),
+				limit,
 				fetch_error: true,
 			})
 			.await?;
 
-		let cursor = list_res.actors.last().map(|x| x.create_ts.to_string());
-
 		Ok(ListResponse {
+			pagination: Pagination {
+				cursor: next_cursor(&list_res.actors, limit),
+			},
 			actors: list_res.actors,
-			pagination: Pagination { cursor },
 		})
 	}
 }
+
+/// Cursor for the page after `actors`. Only set if the page is full, otherwise there are no more
+/// actors to list.
+pub fn next_cursor(actors: &[rivet_types::actors::Actor], limit: usize) -> Option<String> {
+	if actors.len() < limit {
+		return None;
+	}
+
+	actors
+		.last()
+		.map(|actor| ListCursor::new(actor).to_string())
+}
@@ -0,0 +1,55 @@
+use anyhow::*;
+use gas::prelude::*;
+use rivet_api_builder::ApiCtx;
+use rivet_api_types::actors::logs::*;
+
+#[utoipa::path(
+	get,
+	operation_id = "actors_logs",
+	path = "/actors/{actor_id}/logs",
+	params(
+		("actor_id" = Id, Path),
+		LogsQuery,
+	),
+	responses(
+		(status = 200, body = LogsResponse),
+	),
+)]
+#[tracing::instrument(skip_all)]
+pub async fn logs(ctx: ApiCtx, path: LogsPath, query: LogsQuery) -> Result<LogsResponse> {
+	// Get the actor first to verify it exists
+	let actors_res = ctx
+		.op(pegboard::ops::actor::get::Input {
+			actor_ids: vec![path.actor_id],
+			fetch_error: false,
+		})
+		.await?;
+
+	let actor = actors_res
+		.actors
+		.into_iter()
+		.next()
+		.ok_or_else(|| pegboard::errors::Actor::NotFound.build())?;
+
+	// Verify the actor belongs to the specified namespace
+	let namespace = ctx
+		.op(namespace::ops::resolve_for_name_global::Input {
+			name: query.namespace,
+		})
+		.await?
+		.ok_or_else(|| namespace::errors::Namespace::NotFound.build())?;
+
+	if actor.namespace_id != namespace.namespace_id {
+		return Err(pegboard::errors::Actor::NotFound.build());
+	}
+
+	let res = ctx
+		.op(pegboard::ops::actor::log::list::Input {
+			actor_id: actor.actor_id,
+			after: query.after,
+			limit: query.limit.unwrap_or(100),
+		})
+		.await?;
+
+	Ok(LogsResponse { logs: res.logs })
+}
@@ -740,3 +740,34 @@ pub async fn set_epoxy_kv(
 
 	Ok(SetEpoxyKvResponse { result: result_str })
 }
+
+// MARK: Serverless
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SetServerlessAutoscalerPausedRequest {
+	pub namespace_id: Id,
+	pub paused: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SetServerlessAutoscalerPausedResponse {}
+
+/// Pauses or resumes the serverless autoscaler for a namespace in this datacenter.
+///
+/// While paused, serverless runner pools keep their current connection count regardless of
+/// desired slots. Useful for freezing scaling during an incident without deleting runner configs.
+pub async fn set_serverless_autoscaler_paused(
+	ctx: ApiCtx,
+	_path: (),
+	_query: (),
+	body: SetServerlessAutoscalerPausedRequest,
+) -> Result<SetServerlessAutoscalerPausedResponse> {
+	ctx.op(pegboard::ops::runner_config::set_autoscaler_paused::Input {
+		namespace_id: body.namespace_id,
+		paused: body.paused,
+	})
+	.await?;
+
+	Ok(SetServerlessAutoscalerPausedResponse {})
+}
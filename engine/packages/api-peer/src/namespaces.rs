@@ -79,6 +79,17 @@ pub async fn list(ctx: ApiCtx, _path: (), query: ListQuery) -> Result<ListRespon
 pub struct CreateRequest {
 	pub name: String,
 	pub display_name: String,
+	/// Crash policy used for actors created in this namespace without an explicit crash policy.
+	#[serde(default)]
+	pub default_crash_policy: Option<rivet_types::actors::CrashPolicy>,
+	/// CORS policy the gateway applies to requests for actors in this namespace. All origins,
+	/// methods and headers are allowed if not set.
+	#[serde(default)]
+	pub cors_policy: Option<rivet_types::namespaces::CorsPolicy>,
+	/// Rate limit the gateway applies to requests for each actor in this namespace, tracked per
+	/// client IP. Requests are not rate limited per actor if not set.
+	#[serde(default)]
+	pub actor_rate_limit: Option<rivet_types::namespaces::ActorRateLimit>,
 }
 
 #[derive(Serialize, Deserialize, ToSchema)]
@@ -95,6 +106,16 @@ pub async fn create(
 	_query: (),
 	body: CreateRequest,
 ) -> Result<CreateResponse> {
+	if let Some(cors_policy) = &body.cors_policy {
+		namespace::utils::validate_cors_policy(cors_policy)
+			.map_err(|reason| namespace::errors::Namespace::FailedToCreate { reason }.build())?;
+	}
+
+	if let Some(actor_rate_limit) = &body.actor_rate_limit {
+		namespace::utils::validate_actor_rate_limit(actor_rate_limit)
+			.map_err(|reason| namespace::errors::Namespace::FailedToCreate { reason }.build())?;
+	}
+
 	let namespace_id = Id::new_v1(ctx.config().dc_label());
 
 	let mut create_sub = ctx
@@ -111,6 +132,9 @@ pub async fn create(
 		namespace_id,
 		name: body.name.clone(),
 		display_name: body.display_name.clone(),
+		default_crash_policy: body.default_crash_policy,
+		cors_policy: body.cors_policy.clone(),
+		actor_rate_limit: body.actor_rate_limit,
 	})
 	.tag("namespace_id", namespace_id)
 	.dispatch()
@@ -135,3 +159,70 @@ pub async fn create(
 
 	Ok(CreateResponse { namespace })
 }
+
+#[derive(Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct UpdatePath {
+	pub namespace: String,
+}
+
+#[derive(Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+#[schema(as = NamespacesUpdateRequest)]
+pub struct UpdateRequest {
+	/// Crash policy used for actors created in this namespace without an explicit crash policy.
+	/// Cleared if not set.
+	#[serde(default)]
+	pub default_crash_policy: Option<rivet_types::actors::CrashPolicy>,
+	/// CORS policy the gateway applies to requests for actors in this namespace. All origins,
+	/// methods and headers are allowed if not set.
+	#[serde(default)]
+	pub cors_policy: Option<rivet_types::namespaces::CorsPolicy>,
+	/// Rate limit the gateway applies to requests for each actor in this namespace, tracked per
+	/// client IP. Requests are not rate limited per actor if not set.
+	#[serde(default)]
+	pub actor_rate_limit: Option<rivet_types::namespaces::ActorRateLimit>,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+#[schema(as = NamespacesUpdateResponse)]
+pub struct UpdateResponse {
+	pub namespace: rivet_types::namespaces::Namespace,
+}
+
+#[tracing::instrument(skip_all)]
+pub async fn update(
+	ctx: ApiCtx,
+	path: UpdatePath,
+	_query: (),
+	body: UpdateRequest,
+) -> Result<UpdateResponse> {
+	let namespace = ctx
+		.op(namespace::ops::resolve_for_name_global::Input {
+			name: path.namespace.clone(),
+		})
+		.await?
+		.ok_or_else(|| namespace::errors::Namespace::NotFound.build())?;
+
+	let namespace = ctx
+		.op(namespace::ops::update::Input {
+			namespace_id: namespace.namespace_id,
+			default_crash_policy: body.default_crash_policy,
+			cors_policy: body.cors_policy,
+			actor_rate_limit: body.actor_rate_limit,
+		})
+		.await?;
+
+	// Other datacenters cache namespaces fetched from the leader
+	rivet_api_util::cache_purge_global(&ctx, "namespace.get_global", vec![namespace.namespace_id])
+		.await?;
+	rivet_api_util::cache_purge_global(
+		&ctx,
+		"namespace.resolve_for_name_global",
+		vec![namespace.name.clone()],
+	)
+	.await?;
+
+	Ok(UpdateResponse { namespace })
+}
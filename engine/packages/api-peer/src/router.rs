@@ -13,6 +13,7 @@ pub async fn router(
 			// MARK: Namespaces
 			.route("/namespaces", get(namespaces::list))
 			.route("/namespaces", post(namespaces::create))
+			.route("/namespaces/{namespace}", put(namespaces::update))
 			// MARK: Runner configs
 			.route("/runner-configs", get(runner_configs::list))
 			.route("/runner-configs/{runner_name}", put(runner_configs::upsert))
@@ -26,10 +27,16 @@ pub async fn router(
 			.route("/actors", put(actors::get_or_create::get_or_create))
 			.route("/actors/{actor_id}", delete(actors::delete::delete))
 			.route("/actors/names", get(actors::list_names::list_names))
+			.route("/actors/batch", post(actors::create_batch::create_batch))
+			.route(
+				"/actors/destroy-batch",
+				post(actors::destroy_batch::destroy_batch),
+			)
 			.route(
 				"/actors/{actor_id}/kv/keys/{key}",
 				get(actors::kv_get::kv_get),
 			)
+			.route("/actors/{actor_id}/logs", get(actors::logs::logs))
 			.route("/actors/{actor_id}/sleep", post(actors::sleep::sleep))
 			.route(
 				"/actors/{actor_id}/reschedule",
@@ -100,6 +107,10 @@ pub async fn router(
 			.route("/epoxy/replica/kv/{key}", put(internal::set_epoxy_kv))
 			.route("/debug/tracing/config", put(internal::set_tracing_config))
 			.route("/debug/profile/config", put(internal::set_profiling_config))
+			.route(
+				"/serverless/autoscaler/paused",
+				put(internal::set_serverless_autoscaler_paused),
+			)
 	})
 	.await
 }
@@ -112,6 +112,9 @@ pub async fn list(ctx: ApiCtx, _path: ListPath, query: ListQuery) -> Result<List
 					name,
 					RunnerConfigResponse {
 						config,
+						runner_pool_error_category: runner_pool_error
+							.as_ref()
+							.map(|error| error.category()),
 						runner_pool_error,
 						protocol_version,
 					},
@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use axum::response::{IntoResponse, Response};
+use rivet_api_builder::{
+	ApiError,
+	extract::{Extension, Json, Query},
+};
+use rivet_api_peer::actors::create_batch::{batch_error, failed};
+use rivet_api_types::actors::{create::CreateRequest, create_batch::*};
+use rivet_api_util::request_remote_datacenter;
+
+use crate::ctx::ApiCtx;
+
+/// Creates multiple actors in one request.
+///
+/// Actors are grouped by target datacenter and each group is created with a single request to that
+/// datacenter. A failure to create one actor does not fail the rest of the batch; each result has
+/// either the created actor or the error for that actor.
+///
+/// ## Datacenter Round Trips
+///
+/// 2 round trips:
+/// - namespace::ops::resolve_for_name_global
+/// - [pegboard::workflows::actor] Create actor workflows (includes Epoxy key allocation)
+///
+/// Each target datacenter other than the current one adds a POST /actors/batch round trip. All
+/// datacenters are requested in parallel.
+#[utoipa::path(
+	post,
+	operation_id = "actors_create_batch",
+	path = "/actors/batch",
+	params(CreateBatchQuery),
+	request_body(content = CreateBatchRequest, content_type = "application/json"),
+	responses(
+		(status = 200, body = CreateBatchResponse),
+	),
+)]
+pub async fn create_batch(
+	Extension(ctx): Extension<ApiCtx>,
+	Query(query): Query<CreateBatchQuery>,
+	Json(body): Json<CreateBatchRequest>,
+) -> Response {
+	match create_batch_inner(ctx, query, body).await {
+		Ok(response) => Json(response).into_response(),
+		Err(err) => ApiError::from(err).into_response(),
+	}
+}
+
+#[tracing::instrument(skip_all)]
+async fn create_batch_inner(
+	ctx: ApiCtx,
+	query: CreateBatchQuery,
+	body: CreateBatchRequest,
+) -> Result<CreateBatchResponse> {
+	ctx.skip_auth();
+
+	pegboard::utils::validate_create_batch_size(ctx.config(), body.actors.len())?;
+
+	let namespace = ctx
+		.op(namespace::ops::resolve_for_name_global::Input {
+			name: query.namespace.clone(),
+		})
+		.await?
+		.ok_or_else(|| namespace::errors::Namespace::NotFound.build())?;
+
+	let mut results = (0..body.actors.len()).map(|_| None).collect::<Vec<_>>();
+
+	// Group actors by target datacenter, only looking up each runner and datacenter pair once
+	let mut target_dcs = HashMap::<(String, Option<String>), Result<u16, CreateBatchError>>::new();
+	let mut actors_by_dc = HashMap::<u16, Vec<(usize, CreateRequest)>>::new();
+	for (idx, actor) in body.actors.into_iter().enumerate() {
+		let cache_key = (actor.runner_name_selector.clone(), actor.datacenter.clone());
+		let target_dc_label = if let Some(target_dc_label) = target_dcs.get(&cache_key) {
+			target_dc_label.clone()
+		} else {
+			let target_dc_label = super::utils::find_dc_for_actor_creation(
+				&ctx,
+				namespace.namespace_id,
+				&query.namespace,
+				&actor.runner_name_selector,
+				actor.datacenter.as_deref(),
+			)
+			.await
+			.map_err(|err| batch_error(&err));
+			target_dcs.insert(cache_key, target_dc_label.clone());
+			target_dc_label
+		};
+
+		match target_dc_label {
+			Ok(target_dc_label) => actors_by_dc
+				.entry(target_dc_label)
+				.or_default()
+				.push((idx, actor)),
+			Err(err) => results[idx] = Some(failed(err)),
+		}
+	}
+
+	let create_futures = actors_by_dc.into_iter().map(|(dc_label, dc_actors)| {
+		let ctx = ctx.clone();
+		let (idxs, actors) = dc_actors.into_iter().unzip::<_, _, Vec<_>, Vec<_>>();
+		let query = CreateBatchQuery {
+			namespace: query.namespace.clone(),
+		};
+
+		async move {
+			let body = CreateBatchRequest { actors };
+			let res = if dc_label == ctx.config().dc_label() {
+				rivet_api_peer::actors::create_batch::create_batch(ctx.into(), (), query, body)
+					.await
+			} else {
+				request_remote_datacenter::<CreateBatchResponse>(
+					ctx.config(),
+					dc_label,
+					"/actors/batch",
+					axum::http::Method::POST,
+					Some(&query),
+					Some(&body),
+				)
+				.await
+			};
+
+			(idxs, res)
+		}
+	});
+
+	for (idxs, res) in futures_util::future::join_all(create_futures).await {
+		match res {
+			Ok(res) => {
+				for (idx, result) in idxs.into_iter().zip(res.results) {
+					results[idx] = Some(result);
+				}
+			}
+			Err(err) => {
+				// The whole datacenter request failed, report it for every actor sent there
+				tracing::error!(?err, "failed to create actor batch in datacenter");
+				let err = batch_error(&err);
+				for idx in idxs {
+					results[idx] = Some(failed(err.clone()));
+				}
+			}
+		}
+	}
+
+	let results = results
+		.into_iter()
+		.map(|res| {
+			res.unwrap_or_else(|| {
+				failed(batch_error(&anyhow::anyhow!("missing batch create result")))
+			})
+		})
+		.collect();
+
+	Ok(CreateBatchResponse { results })
+}
@@ -0,0 +1,219 @@
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use axum::response::{IntoResponse, Response};
+use futures_util::StreamExt;
+use pegboard::ops::actor::destroy_batch::Selector;
+use rivet_api_builder::{
+	ApiError,
+	extract::{Extension, Json, Query},
+};
+use rivet_api_peer::actors::destroy_batch::destroy_batch_error;
+use rivet_api_types::actors::destroy_batch::*;
+use rivet_api_util::request_remote_datacenter;
+use rivet_util::Id;
+
+use crate::ctx::ApiCtx;
+
+/// Destroys multiple actors in one request, either by ID or every active actor matching a name and
+/// optional key prefix.
+///
+/// Each actor is destroyed the same as `DELETE /actors/{actor_id}`. A failure to destroy one actor
+/// does not fail the rest of the batch. A filter destroys a limited number of actors per
+/// datacenter, send the request again while `has_more` is true to destroy the rest.
+///
+/// ## Datacenter Round Trips
+///
+/// **If destroying by actor IDs**
+///
+/// 2 round trips:
+/// - POST /actors/destroy-batch (one per datacenter the actors live in)
+/// - [api-peer] namespace::ops::resolve_for_name_global
+///
+/// **If destroying by filter**
+///
+/// 2 round trips:
+/// - POST /actors/destroy-batch (fanout)
+/// - [api-peer] namespace::ops::resolve_for_name_global
+#[utoipa::path(
+	post,
+	operation_id = "actors_destroy_batch",
+	path = "/actors/destroy-batch",
+	params(DestroyBatchQuery),
+	request_body(content = DestroyBatchRequest, content_type = "application/json"),
+	responses(
+		(status = 200, body = DestroyBatchResponse),
+	),
+	security(("bearer_auth" = [])),
+)]
+pub async fn destroy_batch(
+	Extension(ctx): Extension<ApiCtx>,
+	Query(query): Query<DestroyBatchQuery>,
+	Json(body): Json<DestroyBatchRequest>,
+) -> Response {
+	match destroy_batch_inner(ctx, query, body).await {
+		Ok(response) => Json(response).into_response(),
+		Err(err) => ApiError::from(err).into_response(),
+	}
+}
+
+#[tracing::instrument(skip_all)]
+async fn destroy_batch_inner(
+	ctx: ApiCtx,
+	query: DestroyBatchQuery,
+	body: DestroyBatchRequest,
+) -> Result<DestroyBatchResponse> {
+	ctx.auth().await?;
+
+	match rivet_api_peer::actors::destroy_batch::selector(body.clone())? {
+		Selector::ActorIds(actor_ids) => destroy_by_ids(&ctx, query, actor_ids).await,
+		Selector::Filter { .. } => destroy_by_filter(&ctx, query, body).await,
+	}
+}
+
+/// Sends each actor ID to the datacenter it lives in.
+async fn destroy_by_ids(
+	ctx: &ApiCtx,
+	query: DestroyBatchQuery,
+	actor_ids: Vec<Id>,
+) -> Result<DestroyBatchResponse> {
+	pegboard::utils::validate_destroy_batch_size(ctx.config(), actor_ids.len())?;
+
+	let mut idxs_by_dc = HashMap::<u16, Vec<usize>>::new();
+	for (idx, actor_id) in actor_ids.iter().enumerate() {
+		idxs_by_dc.entry(actor_id.label()).or_default().push(idx);
+	}
+
+	let destroy_futures = idxs_by_dc.into_iter().map(|(dc_label, idxs)| {
+		let ctx = ctx.clone();
+		let query = query.clone();
+		let body = DestroyBatchRequest {
+			actor_ids: idxs.iter().map(|idx| actor_ids[*idx]).collect(),
+			..Default::default()
+		};
+
+		async move {
+			let res = request_dc(ctx, dc_label, query, body).await;
+			(idxs, res)
+		}
+	});
+
+	let mut errors = actor_ids.iter().map(|_| None).collect::<Vec<_>>();
+	for (idxs, res) in futures_util::future::join_all(destroy_futures).await {
+		match res {
+			Ok(res) => {
+				for (idx, result) in idxs.into_iter().zip(res.results) {
+					errors[idx] = Some(result.error);
+				}
+			}
+			Err(err) => {
+				// The whole datacenter request failed, report it for every actor sent there
+				tracing::error!(?err, "failed to destroy actor batch in datacenter");
+				let err = destroy_batch_error(&err);
+				for idx in idxs {
+					errors[idx] = Some(Some(err.clone()));
+				}
+			}
+		}
+	}
+
+	let results = actor_ids
+		.into_iter()
+		.zip(errors)
+		.map(|(actor_id, error)| DestroyBatchResult {
+			actor_id,
+			error: error.unwrap_or_else(|| {
+				Some(destroy_batch_error(&anyhow::anyhow!(
+					"missing batch destroy result"
+				)))
+			}),
+		})
+		.collect();
+
+	Ok(DestroyBatchResponse {
+		results,
+		has_more: false,
+	})
+}
+
+/// Sends the filter to every datacenter.
+async fn destroy_by_filter(
+	ctx: &ApiCtx,
+	query: DestroyBatchQuery,
+	body: DestroyBatchRequest,
+) -> Result<DestroyBatchResponse> {
+	let dcs = ctx
+		.config()
+		.topology()
+		.datacenters
+		.iter()
+		.map(|dc| dc.datacenter_label)
+		.collect::<Vec<_>>();
+
+	let responses = futures_util::stream::iter(dcs)
+		.map(|dc_label| {
+			let ctx = ctx.clone();
+			let query = query.clone();
+			let body = body.clone();
+
+			async move { (dc_label, request_dc(ctx, dc_label, query, body).await) }
+		})
+		.buffer_unordered(16)
+		.collect::<Vec<_>>()
+		.await;
+
+	let response_count = responses.len();
+	let mut errors = Vec::new();
+	let mut res = DestroyBatchResponse {
+		results: Vec::new(),
+		has_more: false,
+	};
+	for (dc_label, dc_res) in responses {
+		match dc_res {
+			Ok(dc_res) => {
+				res.results.extend(dc_res.results);
+				res.has_more |= dc_res.has_more;
+			}
+			Err(err) => {
+				// Matching actors may remain in this datacenter
+				tracing::error!(
+					?dc_label,
+					?err,
+					"failed to destroy actor batch in datacenter"
+				);
+				res.has_more = true;
+				errors.push(err);
+			}
+		}
+	}
+
+	// Error only if all requests failed
+	if response_count == errors.len()
+		&& let Some(err) = errors.into_iter().next()
+	{
+		return Err(err).context("all datacenter requests failed");
+	}
+
+	Ok(res)
+}
+
+async fn request_dc(
+	ctx: ApiCtx,
+	dc_label: u16,
+	query: DestroyBatchQuery,
+	body: DestroyBatchRequest,
+) -> Result<DestroyBatchResponse> {
+	if dc_label == ctx.config().dc_label() {
+		rivet_api_peer::actors::destroy_batch::destroy_batch(ctx.into(), (), query, body).await
+	} else {
+		request_remote_datacenter::<DestroyBatchResponse>(
+			ctx.config(),
+			dc_label,
+			"/actors/destroy-batch",
+			axum::http::Method::POST,
+			Some(&query),
+			Some(&body),
+		)
+		.await
+	}
+}
@@ -55,7 +55,7 @@ pub async fn get_or_create(
 }
 
 #[tracing::instrument(skip_all)]
-async fn get_or_create_inner(
+pub(crate) async fn get_or_create_inner(
 	ctx: ApiCtx,
 	query: GetOrCreateQuery,
 	body: GetOrCreateRequest,
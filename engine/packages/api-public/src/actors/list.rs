@@ -79,6 +79,20 @@ async fn list_inner(ctx: ApiCtx, query: ListQuery) -> Result<ListResponse> {
 		.build());
 	}
 
+	// Validate tags
+	let tags = query.tags().map_err(|err| {
+		errors::Validation::InvalidInput {
+			message: err.to_string(),
+		}
+		.build()
+	})?;
+	if !tags.is_empty() && (!actor_ids.is_empty() || query.name.is_some() || query.key.is_some()) {
+		return Err(errors::Validation::InvalidInput {
+			message: "Cannot provide tag with actor_id, name, or key.".to_string(),
+		}
+		.build());
+	}
+
 	// Validate key
 	if query.key.is_some() && query.name.is_none() {
 		return Err(errors::Validation::InvalidInput {
@@ -104,27 +118,10 @@ async fn list_inner(ctx: ApiCtx, query: ListQuery) -> Result<ListResponse> {
 		.await?
 		.ok_or_else(|| namespace::errors::Namespace::NotFound.build())?;
 
-		let limit = query.limit.unwrap_or(100);
-
 		// Fetch actors
-		let mut actors = fetch_actors_by_ids(
-			&ctx,
-			actor_ids,
-			query.namespace.clone(),
-			query.include_destroyed,
-			Some(limit),
-			query.cursor.clone(),
-		)
-		.await?;
-
-		actors.truncate(limit);
+		let pages = fetch_actors_by_ids(&ctx, actor_ids, &query).await?;
 
-		let cursor = actors.last().map(|x| x.create_ts.to_string());
-
-		Ok(ListResponse {
-			actors,
-			pagination: Pagination { cursor },
-		})
+		Ok(merge_pages(pages, query.limit.unwrap_or(100)))
 	} else if let (Some(key), Some(name)) = (&query.key, &query.name)
 		&& !include_destroyed
 	{
@@ -148,11 +145,20 @@ async fn list_inner(ctx: ApiCtx, query: ListQuery) -> Result<ListResponse> {
 			.await?;
 		match res {
 			pegboard::ops::actor::get_for_key::Output::Found { actor } => {
-				let cursor = Some(actor.create_ts.to_string());
-
+				let cursor = query.cursor.as_deref().map(ListCursor::parse).transpose()?;
+				let matches = query.state.is_none_or(|state| actor.state() == state)
+					&& query
+						.created_before
+						.is_none_or(|created_before| actor.create_ts < created_before)
+					&& query
+						.created_after
+						.is_none_or(|created_after| actor.create_ts > created_after)
+					&& cursor.is_none_or(|cursor| cursor.precedes(&actor));
+
+				// There is only one active actor per key, so there is never a next page
 				Ok(ListResponse {
-					actors: vec![actor],
-					pagination: Pagination { cursor },
+					actors: if matches { vec![actor] } else { Vec::new() },
+					pagination: Pagination { cursor: None },
 				})
 			}
 			pegboard::ops::actor::get_for_key::Output::NotFound => Ok(ListResponse {
@@ -174,10 +180,10 @@ async fn list_inner(ctx: ApiCtx, query: ListQuery) -> Result<ListResponse> {
 		}
 	} else {
 		// Fanout path: used when include_destroyed is true or when no key is provided
-		// Require name for fanout operations
-		if query.name.is_none() {
+		// Require name or tags for fanout operations
+		if query.name.is_none() && tags.is_empty() {
 			return Err(errors::Validation::InvalidInput {
-				message: "Name is required when not using actor_ids.".to_string(),
+				message: "Name is required when not using actor_ids or tags.".to_string(),
 			}
 			.build());
 		}
@@ -185,26 +191,50 @@ async fn list_inner(ctx: ApiCtx, query: ListQuery) -> Result<ListResponse> {
 		let limit = query.limit.unwrap_or(100);
 
 		// Fanout to all datacenters
-		let mut actors =
-			fanout_to_datacenters::<ListResponse, _, _, _, _, Vec<rivet_types::actors::Actor>>(
-				&ctx,
-				"/actors",
-				query,
-				|ctx, query| async move { rivet_api_peer::actors::list::list(ctx, (), query).await },
-				|_, res, agg| agg.extend(res.actors),
-			)
-			.await?;
+		let pages = fanout_to_datacenters::<ListResponse, _, _, _, _, Vec<ListResponse>>(
+			&ctx,
+			"/actors",
+			query,
+			|ctx, query| async move { rivet_api_peer::actors::list::list(ctx, (), query).await },
+			|_, res, agg| agg.push(res),
+		)
+		.await?;
 
-		// Sort by create ts desc
-		actors.sort_by_cached_key(|x| std::cmp::Reverse(x.create_ts));
+		Ok(merge_pages(pages, limit))
+	}
+}
 
+/// Merges pages from multiple datacenters into a single page, newest first.
+///
+/// The merged cursor points at the last actor returned, so the next request resumes every
+/// datacenter from the same position. Actors that were cut from this page are listed again on the
+/// next one.
+fn merge_pages(pages: Vec<ListResponse>, limit: usize) -> ListResponse {
+	let mut has_more = pages.iter().any(|page| page.pagination.cursor.is_some());
+
+	let mut actors = pages
+		.into_iter()
+		.flat_map(|page| page.actors)
+		.collect::<Vec<_>>();
+
+	// Sort by create ts desc, actor IDs break ties to match the order of the cursor
+	actors.sort_by_cached_key(|x| std::cmp::Reverse((x.create_ts, x.actor_id)));
+
+	if actors.len() > limit {
+		has_more = true;
 		actors.truncate(limit);
+	}
 
-		let cursor = actors.last().map(|x| x.create_ts.to_string());
+	let cursor = if has_more {
+		actors
+			.last()
+			.map(|actor| ListCursor::new(actor).to_string())
+	} else {
+		None
+	};
 
-		Ok(ListResponse {
-			actors,
-			pagination: Pagination { cursor },
-		})
+	ListResponse {
+		actors,
+		pagination: Pagination { cursor },
 	}
 }
@@ -0,0 +1,167 @@
+use std::{collections::VecDeque, time::Duration};
+
+use anyhow::Result;
+use axum::response::{
+	IntoResponse, Response,
+	sse::{Event, KeepAlive, Sse},
+};
+use futures_util::{Stream, stream};
+use rivet_api_builder::{
+	ApiError,
+	extract::{Extension, Json, Path, Query},
+};
+use rivet_api_types::actors::logs::*;
+use rivet_api_util::request_remote_datacenter;
+use rivet_types::actors::ActorLog;
+use rivet_util::Id;
+
+use crate::{actors::utils::fetch_actor_by_id, ctx::ApiCtx};
+
+/// Returns log lines forwarded by the runner hosting the actor, oldest first.
+///
+/// With `follow`, responds with a `text/event-stream` instead where each `log` event holds one line
+/// as JSON. The event id is the line index, pass it as `after` to resume. The stream ends once the
+/// actor is destroyed and its remaining lines are sent.
+#[utoipa::path(
+	get,
+	operation_id = "actors_logs",
+	path = "/actors/{actor_id}/logs",
+	params(
+		("actor_id" = Id, Path),
+		LogsQuery,
+	),
+	responses(
+		(status = 200, body = LogsResponse),
+	),
+	security(("bearer_auth" = [])),
+)]
+#[tracing::instrument(skip_all)]
+pub async fn logs(
+	Extension(ctx): Extension<ApiCtx>,
+	Path(path): Path<LogsPath>,
+	Query(query): Query<LogsQuery>,
+) -> Response {
+	match logs_inner(ctx, path, query).await {
+		Ok(response) => response,
+		Err(err) => ApiError::from(err).into_response(),
+	}
+}
+
+#[tracing::instrument(skip_all)]
+async fn logs_inner(ctx: ApiCtx, path: LogsPath, query: LogsQuery) -> Result<Response> {
+	ctx.auth().await?;
+
+	let follow = query.follow.unwrap_or_default();
+	let query = LogsQuery {
+		follow: None,
+		..query
+	};
+
+	// Fetch before responding so errors (i.e. actor not found) are returned as a normal response
+	let res = fetch_logs(&ctx, path.actor_id, query.clone()).await?;
+
+	if follow {
+		Ok(Sse::new(follow_logs(ctx, path.actor_id, query, res.logs))
+			.keep_alive(KeepAlive::default())
+			.into_response())
+	} else {
+		Ok(Json(res).into_response())
+	}
+}
+
+async fn fetch_logs(ctx: &ApiCtx, actor_id: Id, query: LogsQuery) -> Result<LogsResponse> {
+	if actor_id.label() == ctx.config().dc_label() {
+		rivet_api_peer::actors::logs::logs(ctx.clone().into(), LogsPath { actor_id }, query).await
+	} else {
+		request_remote_datacenter::<LogsResponse>(
+			ctx.config(),
+			actor_id.label(),
+			&format!("/actors/{actor_id}/logs"),
+			axum::http::Method::GET,
+			Some(&query),
+			Option::<&()>::None,
+		)
+		.await
+	}
+}
+
+struct FollowState {
+	ctx: ApiCtx,
+	actor_id: Id,
+	query: LogsQuery,
+	pending: VecDeque<ActorLog>,
+	/// Last poll returned a full page, poll again without waiting.
+	more: bool,
+	/// Actor was destroyed before the last poll, no new lines will be stored.
+	destroyed: bool,
+}
+
+/// Polls the actor's logs and yields new lines as they are stored.
+fn follow_logs(
+	ctx: ApiCtx,
+	actor_id: Id,
+	query: LogsQuery,
+	logs: Vec<ActorLog>,
+) -> impl Stream<Item = Result<Event, axum::Error>> {
+	let limit = query.limit.unwrap_or(100);
+	let poll_interval =
+		Duration::from_millis(ctx.config().pegboard().actor_log_follow_poll_interval());
+
+	let state = FollowState {
+		ctx,
+		actor_id,
+		query,
+		more: !logs.is_empty() && logs.len() >= limit,
+		destroyed: false,
+		pending: logs.into(),
+	};
+
+	stream::unfold(state, move |mut state| async move {
+		loop {
+			if let Some(log) = state.pending.pop_front() {
+				state.query.after = Some(log.idx);
+
+				let event = Event::default()
+					.event("log")
+					.id(log.idx.to_string())
+					.json_data(&log);
+
+				return Some((event, state));
+			}
+
+			if !state.more {
+				if state.destroyed {
+					return None;
+				}
+
+				tokio::time::sleep(poll_interval).await;
+
+				// Checked before polling so lines stored before the destroy are still sent
+				match fetch_actor_by_id(
+					&state.ctx.clone().into(),
+					state.actor_id,
+					state.query.namespace.clone(),
+				)
+				.await
+				{
+					Ok(actor) => state.destroyed = actor.destroy_ts.is_some(),
+					Err(err) => {
+						tracing::warn!(?err, actor_id=?state.actor_id, "failed to get actor, ending stream");
+						return None;
+					}
+				}
+			}
+
+			match fetch_logs(&state.ctx, state.actor_id, state.query.clone()).await {
+				Ok(res) => {
+					state.more = !res.logs.is_empty() && res.logs.len() >= limit;
+					state.pending.extend(res.logs);
+				}
+				Err(err) => {
+					tracing::warn!(?err, actor_id=?state.actor_id, "failed to poll actor logs, ending stream");
+					return None;
+				}
+			}
+		}
+	})
+}
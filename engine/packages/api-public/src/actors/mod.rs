@@ -1,9 +1,13 @@
 pub mod create;
+pub mod create_batch;
 pub mod delete;
+pub mod destroy_batch;
 pub mod get_or_create;
 pub mod kv_get;
 pub mod list;
 pub mod list_names;
+pub mod logs;
+pub mod request;
 pub mod reschedule;
 pub mod sleep;
 pub mod utils;
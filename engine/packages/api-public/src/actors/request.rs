@@ -0,0 +1,134 @@
+use anyhow::{Context, Result};
+use axum::{
+	body::{Body, Bytes},
+	http::{HeaderMap, HeaderName, HeaderValue, header},
+	response::{IntoResponse, Response},
+};
+use rivet_api_builder::{
+	ApiError,
+	extract::{Extension, Path, Query},
+};
+use rivet_api_types::actors::{get_or_create::*, request::*};
+
+use crate::ctx::ApiCtx;
+
+const X_RIVET_ACTOR: HeaderName = HeaderName::from_static("x-rivet-actor");
+const X_RIVET_TOKEN: HeaderName = HeaderName::from_static("x-rivet-token");
+/// Not forwarded to the actor. The bearer token is passed to the gateway as `x-rivet-token` instead.
+const SKIPPED_REQUEST_HEADERS: [HeaderName; 5] = [
+	header::HOST,
+	header::AUTHORIZATION,
+	header::CONTENT_LENGTH,
+	header::CONNECTION,
+	header::TRANSFER_ENCODING,
+];
+
+/// Sends a request to the actor with the given name and key, creating it first if it does not exist.
+///
+/// The request body and headers are forwarded to the actor through the gateway and the actor's
+/// response is returned as is. The ID of the actor that handled the request is returned in the
+/// `x-rivet-actor` header.
+#[utoipa::path(
+	post,
+	operation_id = "actors_request",
+	path = "/actors/by-key/{name}/{key}/request",
+	params(
+		("name" = String, Path),
+		("key" = String, Path),
+		RequestQuery,
+	),
+	request_body(content = String, content_type = "application/octet-stream"),
+	responses(
+		(
+			status = 200,
+			description = "Response returned by the actor.",
+			headers(("x-rivet-actor" = String, description = "ID of the actor that handled the request.")),
+		),
+	),
+)]
+#[tracing::instrument(skip_all)]
+pub async fn request(
+	Extension(ctx): Extension<ApiCtx>,
+	Path(path): Path<RequestPath>,
+	Query(query): Query<RequestQuery>,
+	headers: HeaderMap,
+	body: Bytes,
+) -> Response {
+	match request_inner(ctx, path, query, headers, body).await {
+		Ok(response) => response,
+		Err(err) => ApiError::from(err).into_response(),
+	}
+}
+
+#[tracing::instrument(skip_all)]
+async fn request_inner(
+	ctx: ApiCtx,
+	path: RequestPath,
+	query: RequestQuery,
+	headers: HeaderMap,
+	body: Bytes,
+) -> Result<Response> {
+	// Same as get or create. Access to the actor itself is checked by the gateway.
+	let res = super::get_or_create::get_or_create_inner(
+		ctx.clone(),
+		GetOrCreateQuery {
+			namespace: query.namespace,
+		},
+		GetOrCreateRequest {
+			datacenter: query.datacenter,
+			name: path.name,
+			key: path.key,
+			input: None,
+			runner_name_selector: query.runner_name_selector,
+			crash_policy: query.crash_policy,
+		},
+	)
+	.await?;
+	let actor_id = res.actor.actor_id;
+
+	// Send through this datacenter's gateway, which forwards to the actor's datacenter if needed
+	let actor_path = query.path.unwrap_or_default();
+	let actor_path = actor_path.strip_prefix('/').unwrap_or(&actor_path);
+	let url = ctx
+		.config()
+		.topology()
+		.current_dc()?
+		.proxy_url()
+		.join(&format!("gateway/{actor_id}/{actor_path}"))
+		.context("invalid actor request path")?;
+
+	let client = rivet_pools::reqwest::client().await?;
+	let mut request = client.post(url).body(body);
+
+	for (name, value) in &headers {
+		if !SKIPPED_REQUEST_HEADERS.contains(name) {
+			request = request.header(name, value);
+		}
+	}
+
+	if let Some(token) = ctx.token() {
+		request = request.header(X_RIVET_TOKEN, token);
+	}
+
+	let actor_res = request
+		.send()
+		.await
+		.context("failed to send request to actor gateway")?;
+
+	let mut response = Response::builder().status(actor_res.status());
+	let response_headers = response
+		.headers_mut()
+		.context("response builder in error state")?;
+	*response_headers = actor_res.headers().clone();
+	response_headers.remove(header::CONNECTION);
+	response_headers.remove(header::TRANSFER_ENCODING);
+	response_headers.insert(X_RIVET_ACTOR, HeaderValue::from_str(&actor_id.to_string())?);
+
+	// api-public responses are buffered by guard
+	let body = actor_res
+		.bytes()
+		.await
+		.context("failed to read actor response body")?;
+
+	Ok(response.body(Body::from(body))?)
+}
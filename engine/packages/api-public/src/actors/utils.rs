@@ -49,15 +49,14 @@ pub async fn fetch_actor_by_id(ctx: &ApiCtx, actor_id: Id, namespace: String) ->
 
 /// Helper function to fetch multiple actors by their IDs, automatically routing to the correct datacenters
 /// based on each actor ID's label. This function batches requests by datacenter for efficiency.
+///
+/// Returns one page per datacenter, filtered and paginated by the rest of `query`.
 #[tracing::instrument(skip_all)]
 pub async fn fetch_actors_by_ids(
 	ctx: &ApiCtx,
 	actor_ids: Vec<Id>,
-	namespace: String,
-	include_destroyed: Option<bool>,
-	limit: Option<usize>,
-	cursor: Option<String>,
-) -> Result<Vec<Actor>> {
+	query: &rivet_api_types::actors::list::ListQuery,
+) -> Result<Vec<rivet_api_types::actors::list::ListResponse>> {
 	if actor_ids.is_empty() {
 		return Ok(Vec::new());
 	}
@@ -74,31 +73,23 @@ pub async fn fetch_actors_by_ids(
 	// Fetch actors in batch from each datacenter
 	let fetch_futures = actors_by_dc.into_iter().map(|(dc_label, dc_actor_ids)| {
 		let ctx = ctx.clone();
-		let namespace = namespace.clone();
-		let include_destroyed = include_destroyed;
-		let limit = limit;
-		let cursor = cursor.clone();
 
-		async move {
-			// Prepare peer query with actor_ids
-			let peer_query = rivet_api_types::actors::list::ListQuery {
-				namespace: namespace.clone(),
-				name: None,
-				key: None,
-				actor_ids: None,
-				actor_id: dc_actor_ids,
-				include_destroyed,
-				limit,
-				cursor,
-			};
+		// Prepare peer query with actor_ids
+		let peer_query = rivet_api_types::actors::list::ListQuery {
+			name: None,
+			key: None,
+			actor_ids: None,
+			actor_id: dc_actor_ids,
+			..query.clone()
+		};
 
+		async move {
 			if dc_label == ctx.config().dc_label() {
 				// Local datacenter - use peer API directly
-				let res = rivet_api_peer::actors::list::list(ctx.into(), (), peer_query).await?;
-				Ok::<Vec<Actor>, anyhow::Error>(res.actors)
+				rivet_api_peer::actors::list::list(ctx.into(), (), peer_query).await
 			} else {
 				// Remote datacenter - make HTTP request
-				let res = request_remote_datacenter::<rivet_api_types::actors::list::ListResponse>(
+				request_remote_datacenter::<rivet_api_types::actors::list::ListResponse>(
 					ctx.config(),
 					dc_label,
 					"/actors",
@@ -106,8 +97,7 @@ pub async fn fetch_actors_by_ids(
 					Some(&peer_query),
 					Option::<&()>::None,
 				)
-				.await?;
-				Ok(res.actors)
+				.await
 			}
 		}
 	});
@@ -116,18 +106,15 @@ pub async fn fetch_actors_by_ids(
 	let results = futures_util::future::join_all(fetch_futures).await;
 
 	// Aggregate results
-	let mut actors = Vec::new();
+	let mut pages = Vec::new();
 	for res in results {
 		match res {
-			Ok(dc_actors) => actors.extend(dc_actors),
+			Ok(page) => pages.push(page),
 			Err(err) => tracing::error!(?err, "failed to fetch actors from datacenter"),
 		}
 	}
 
-	// Sort by create ts desc
-	actors.sort_by_cached_key(|x| std::cmp::Reverse(x.create_ts));
-
-	Ok(actors)
+	Ok(pages)
 }
 
 /// Determine the datacenter label to create the actor in.
@@ -2,7 +2,7 @@ use anyhow::Result;
 use axum::response::{IntoResponse, Response};
 use rivet_api_builder::{
 	ApiError,
-	extract::{Extension, Json, Query},
+	extract::{Extension, Json, Path, Query},
 };
 use rivet_api_peer::namespaces::*;
 use rivet_api_types::namespaces::list::*;
@@ -87,3 +87,52 @@ async fn create_inner(ctx: ApiCtx, body: CreateRequest) -> Result<CreateResponse
 		.await
 	}
 }
+
+#[utoipa::path(
+	put,
+	operation_id = "namespaces_update",
+	path = "/namespaces/{namespace}",
+	params(
+		("namespace" = String, Path),
+	),
+	request_body(content = UpdateRequest, content_type = "application/json"),
+	responses(
+		(status = 200, body = UpdateResponse),
+	),
+	security(("bearer_auth" = [])),
+)]
+#[tracing::instrument(skip_all)]
+pub async fn update(
+	Extension(ctx): Extension<ApiCtx>,
+	Path(path): Path<UpdatePath>,
+	Json(body): Json<UpdateRequest>,
+) -> Response {
+	match update_inner(ctx, path, body).await {
+		Ok(response) => Json(response).into_response(),
+		Err(err) => ApiError::from(err).into_response(),
+	}
+}
+
+#[tracing::instrument(skip_all)]
+async fn update_inner(
+	ctx: ApiCtx,
+	path: UpdatePath,
+	body: UpdateRequest,
+) -> Result<UpdateResponse> {
+	ctx.auth().await?;
+
+	if ctx.config().is_leader() {
+		rivet_api_peer::namespaces::update(ctx.into(), path, (), body).await
+	} else {
+		let leader_dc = ctx.config().leader_dc()?;
+		request_remote_datacenter::<UpdateResponse>(
+			ctx.config(),
+			leader_dc.datacenter_label,
+			&format!("/namespaces/{}", urlencoding::encode(&path.namespace)),
+			axum::http::Method::PUT,
+			Option::<&()>::None,
+			Some(&body),
+		)
+		.await
+	}
+}
@@ -17,10 +17,14 @@ use crate::{
 	paths(
 		actors::list::list,
 		actors::create::create,
+		actors::create_batch::create_batch,
 		actors::delete::delete,
+		actors::destroy_batch::destroy_batch,
 		actors::list_names::list_names,
 		actors::get_or_create::get_or_create,
 		actors::kv_get::kv_get,
+		actors::logs::logs,
+		actors::request::request,
 		actors::sleep::sleep,
 		actors::reschedule::reschedule,
 		runners::list,
@@ -28,6 +32,7 @@ use crate::{
 		envoys::list,
 		namespaces::list,
 		namespaces::create,
+		namespaces::update,
 		runner_configs::list::list,
 		runner_configs::upsert::upsert,
 		runner_configs::delete::delete,
@@ -64,6 +69,10 @@ pub async fn router(
 			// MARK: Namespaces
 			.route("/namespaces", axum::routing::get(namespaces::list))
 			.route("/namespaces", axum::routing::post(namespaces::create))
+			.route(
+				"/namespaces/{namespace}",
+				axum::routing::put(namespaces::update),
+			)
 			.route("/runner-configs", axum::routing::get(runner_configs::list))
 			.route(
 				"/runner-configs/serverless-health-check",
@@ -96,10 +105,26 @@ pub async fn router(
 				"/actors/names",
 				axum::routing::get(actors::list_names::list_names),
 			)
+			.route(
+				"/actors/batch",
+				axum::routing::post(actors::create_batch::create_batch),
+			)
+			.route(
+				"/actors/destroy-batch",
+				axum::routing::post(actors::destroy_batch::destroy_batch),
+			)
 			.route(
 				"/actors/{actor_id}/kv/keys/{key}",
 				axum::routing::get(actors::kv_get::kv_get),
 			)
+			.route(
+				"/actors/{actor_id}/logs",
+				axum::routing::get(actors::logs::logs),
+			)
+			.route(
+				"/actors/by-key/{name}/{key}/request",
+				axum::routing::post(actors::request::request),
+			)
 			.route(
 				"/actors/{actor_id}/sleep",
 				axum::routing::post(actors::sleep::sleep),
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::time::Instant;
 
 use anyhow::Result;
 use axum::response::{IntoResponse, Response};
@@ -36,9 +37,13 @@ pub struct ServerlessHealthCheckRequest {
 pub enum ServerlessHealthCheckResponse {
 	Success {
 		version: String,
+		/// Round trip time of the probe request in milliseconds.
+		latency_ms: u64,
 	},
 	Failure {
 		error: ServerlessMetadataErrorEnvelope,
+		/// Time until the probe request failed in milliseconds.
+		latency_ms: u64,
 	},
 }
 
@@ -76,12 +81,18 @@ async fn serverless_health_check_inner(
 
 	let ServerlessHealthCheckRequest { url, headers } = body;
 
-	match fetch_serverless_metadata(&ctx, url, headers).await {
+	let start = Instant::now();
+	let res = fetch_serverless_metadata(&ctx, url, headers).await;
+	let latency_ms = start.elapsed().as_millis() as u64;
+
+	match res {
 		Ok(metadata) => Ok(ServerlessHealthCheckResponse::Success {
 			version: metadata.version,
+			latency_ms,
 		}),
 		Err(error) => Ok(ServerlessHealthCheckResponse::Failure {
 			error: error.into(),
+			latency_ms,
 		}),
 	}
 }
@@ -44,7 +44,7 @@ pub async fn fetch_serverless_metadata(
 ) -> std::result::Result<ServerlessMetadata, ServerlessMetadataError> {
 	ctx.op(pegboard::ops::serverless_metadata::fetch::Input { url, headers })
 		.await
-		.map_err(|_| ServerlessMetadataError::RequestFailed {})?
+		.map_err(|_| ServerlessMetadataError::RequestFailed { reason: None })?
 		.map(ServerlessMetadata::from)
 }
 
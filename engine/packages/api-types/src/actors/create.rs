@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
 
@@ -19,7 +21,18 @@ pub struct CreateRequest {
 	/// Arbitrary base64 encoded binary data.
 	pub input: Option<String>,
 	pub runner_name_selector: String,
-	pub crash_policy: rivet_types::actors::CrashPolicy,
+	/// Defaults to the namespace's default crash policy, or `destroy` if the namespace has none.
+	#[serde(default)]
+	pub crash_policy: Option<rivet_types::actors::CrashPolicy>,
+	/// Soft placement rules relative to other actors in this datacenter.
+	#[serde(default)]
+	pub affinity: Option<rivet_types::actors::ActorAffinity>,
+	/// Capabilities a runner must advertise for this actor to be allocated to it.
+	#[serde(default)]
+	pub required_capabilities: Vec<String>,
+	/// Free-form tags used to group and filter actors. See the `tag` query of the list endpoint.
+	#[serde(default)]
+	pub tags: HashMap<String, String>,
 }
 
 #[derive(Serialize, Deserialize, ToSchema)]
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use super::create::CreateRequest;
+
+#[derive(Debug, Serialize, Deserialize, IntoParams)]
+#[serde(deny_unknown_fields)]
+#[into_params(parameter_in = Query)]
+pub struct CreateBatchQuery {
+	pub namespace: String,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+#[schema(as = ActorsCreateBatchRequest)]
+pub struct CreateBatchRequest {
+	pub actors: Vec<CreateRequest>,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+#[schema(as = ActorsCreateBatchResponse)]
+pub struct CreateBatchResponse {
+	/// One result per requested actor, in the same order as the request.
+	pub results: Vec<CreateBatchResult>,
+}
+
+/// Exactly one of `actor` or `error` is set.
+#[derive(Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+#[schema(as = ActorsCreateBatchResult)]
+pub struct CreateBatchResult {
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub actor: Option<rivet_types::actors::Actor>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub error: Option<CreateBatchError>,
+}
+
+#[derive(Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+#[schema(as = ActorsCreateBatchError)]
+pub struct CreateBatchError {
+	pub group: String,
+	pub code: String,
+	pub message: String,
+}
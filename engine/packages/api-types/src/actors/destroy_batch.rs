@@ -0,0 +1,57 @@
+use rivet_util::Id;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Debug, Clone, Serialize, Deserialize, IntoParams)]
+#[serde(deny_unknown_fields)]
+#[into_params(parameter_in = Query)]
+pub struct DestroyBatchQuery {
+	pub namespace: String,
+}
+
+/// Either `actor_ids` or `name` must be set.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+#[schema(as = ActorsDestroyBatchRequest)]
+pub struct DestroyBatchRequest {
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	pub actor_ids: Vec<Id>,
+	/// Destroy every active actor with this name.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub name: Option<String>,
+	/// Only destroy actors with `name` whose key starts with this prefix. Actors without a key are
+	/// skipped.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub key_prefix: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+#[schema(as = ActorsDestroyBatchResponse)]
+pub struct DestroyBatchResponse {
+	/// One result per actor this request tried to destroy. When destroying by `actor_ids`, in the
+	/// same order as the request.
+	pub results: Vec<DestroyBatchResult>,
+	/// More actors match `name` and `key_prefix` than this request destroyed. Send the same request
+	/// again until this is false.
+	pub has_more: bool,
+}
+
+/// The actor was destroyed unless `error` is set.
+#[derive(Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+#[schema(as = ActorsDestroyBatchResult)]
+pub struct DestroyBatchResult {
+	pub actor_id: Id,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub error: Option<DestroyBatchError>,
+}
+
+#[derive(Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+#[schema(as = ActorsDestroyBatchError)]
+pub struct DestroyBatchError {
+	pub group: String,
+	pub code: String,
+	pub message: String,
+}
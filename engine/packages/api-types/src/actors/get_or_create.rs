@@ -18,7 +18,9 @@ pub struct GetOrCreateRequest {
 	pub key: String,
 	pub input: Option<String>,
 	pub runner_name_selector: String,
-	pub crash_policy: rivet_types::actors::CrashPolicy,
+	/// Defaults to the namespace's default crash policy, or `destroy` if the namespace has none.
+	#[serde(default)]
+	pub crash_policy: Option<rivet_types::actors::CrashPolicy>,
 }
 
 #[derive(Serialize, Deserialize, ToSchema)]
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use gas::prelude::*;
 use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
@@ -16,11 +18,86 @@ pub struct ListQuery {
 	pub actor_ids: Option<String>,
 	#[serde(default)]
 	pub actor_id: Vec<Id>,
+	/// Only list active actors with all of these tags, formatted as `key=value`. Cannot be combined
+	/// with `name`, `key`, or `actor_id`.
+	#[serde(default)]
+	pub tag: Vec<String>,
 	pub include_destroyed: Option<bool>,
+	/// Only list actors in this state. Takes precedence over `include_destroyed`.
+	pub state: Option<rivet_types::actors::ActorState>,
+	/// Only list actors created strictly before this timestamp.
+	pub created_before: Option<i64>,
+	/// Only list actors created strictly after this timestamp.
+	pub created_after: Option<i64>,
 	pub limit: Option<usize>,
 	pub cursor: Option<String>,
 }
 
+impl ListQuery {
+	/// Parses the `tag` filters into a map of tag keys to values.
+	pub fn tags(&self) -> anyhow::Result<HashMap<String, String>> {
+		self.tag
+			.iter()
+			.map(|tag| {
+				let (k, v) = tag.split_once('=').ok_or_else(|| {
+					anyhow::anyhow!("tag must be formatted as `key=value`: {tag}")
+				})?;
+
+				Ok((k.to_string(), v.to_string()))
+			})
+			.collect()
+	}
+}
+
+/// Position of the last actor of a page. Actors are listed newest first, ties on `create_ts` are
+/// broken by actor ID so pages stay stable when merged across datacenters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListCursor {
+	pub create_ts: i64,
+	/// Not set for cursors that only hold a timestamp, which skip every actor created at
+	/// `create_ts`.
+	pub actor_id: Option<Id>,
+}
+
+impl ListCursor {
+	pub fn new(actor: &rivet_types::actors::Actor) -> Self {
+		ListCursor {
+			create_ts: actor.create_ts,
+			actor_id: Some(actor.actor_id),
+		}
+	}
+
+	/// Parses a cursor formatted as `{create_ts}` or `{create_ts}:{actor_id}`.
+	pub fn parse(cursor: &str) -> anyhow::Result<Self> {
+		let (create_ts, actor_id) = match cursor.split_once(':') {
+			Some((create_ts, actor_id)) => (create_ts, Some(actor_id.parse()?)),
+			None => (cursor, None),
+		};
+
+		Ok(ListCursor {
+			create_ts: create_ts.parse()?,
+			actor_id,
+		})
+	}
+
+	/// Returns true if the actor is listed after this cursor, newest first.
+	pub fn precedes(&self, actor: &rivet_types::actors::Actor) -> bool {
+		match self.actor_id {
+			Some(actor_id) => (actor.create_ts, actor.actor_id) < (self.create_ts, actor_id),
+			None => actor.create_ts < self.create_ts,
+		}
+	}
+}
+
+impl std::fmt::Display for ListCursor {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self.actor_id {
+			Some(actor_id) => write!(f, "{}:{}", self.create_ts, actor_id),
+			None => write!(f, "{}", self.create_ts),
+		}
+	}
+}
+
 #[derive(Serialize, Deserialize, ToSchema)]
 #[serde(deny_unknown_fields)]
 #[schema(as = ActorsListResponse)]
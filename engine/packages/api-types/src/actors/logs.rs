@@ -0,0 +1,28 @@
+use gas::prelude::*;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Debug, Clone, Serialize, Deserialize, IntoParams)]
+#[serde(deny_unknown_fields)]
+#[into_params(parameter_in = Query)]
+pub struct LogsQuery {
+	pub namespace: String,
+	/// Only return lines with an index greater than this.
+	pub after: Option<u64>,
+	pub limit: Option<usize>,
+	/// Stream new lines as server-sent events instead of returning once.
+	pub follow: Option<bool>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LogsPath {
+	pub actor_id: Id,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+#[schema(as = ActorsLogsResponse)]
+#[serde(deny_unknown_fields)]
+pub struct LogsResponse {
+	pub logs: Vec<rivet_types::actors::ActorLog>,
+}
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+use utoipa::IntoParams;
+
+#[derive(Debug, Serialize, Deserialize, IntoParams)]
+#[serde(deny_unknown_fields)]
+#[into_params(parameter_in = Query)]
+pub struct RequestQuery {
+	pub namespace: String,
+	/// Runner the actor is created on if it does not exist yet.
+	pub runner_name_selector: String,
+	/// Datacenter the actor is created in if it does not exist yet.
+	pub datacenter: Option<String>,
+	/// Defaults to the namespace's default crash policy, or `destroy` if the namespace has none.
+	#[serde(default)]
+	pub crash_policy: Option<rivet_types::actors::CrashPolicy>,
+	/// Path and query string of the request sent to the actor. Defaults to `/`.
+	pub path: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RequestPath {
+	pub name: String,
+	pub key: String,
+}
@@ -50,6 +50,14 @@ pub enum RunnerConfigKind {
 		actor_eviction_period: Option<u32>,
 		/// Actors per second.
 		actor_eviction_rate: Option<f32>,
+		/// HTTP method used for the `/start` request. Defaults to `get`. Envoy pools always POST
+		/// the start command.
+		method: Option<rivet_types::runner_configs::ServerlessRequestMethod>,
+		/// Body sent with the `/start` request. `{{endpoint}}`, `{{namespace_name}}`,
+		/// `{{runner_name}}` and `{{total_slots}}` are replaced before sending. Not supported by
+		/// envoy pools, which send the start command as the body.
+		body: Option<String>,
+		auth: Option<rivet_types::runner_configs::ServerlessAuth>,
 	},
 }
 
@@ -93,6 +101,9 @@ impl Into<rivet_types::runner_configs::RunnerConfig> for RunnerConfig {
 				actor_eviction_delay,
 				actor_eviction_period,
 				actor_eviction_rate,
+				method,
+				body,
+				auth,
 			} => rivet_types::runner_configs::RunnerConfigKind::Serverless {
 				url,
 				headers: headers.unwrap_or_default(),
@@ -112,6 +123,9 @@ impl Into<rivet_types::runner_configs::RunnerConfig> for RunnerConfig {
 				actor_eviction_delay: actor_eviction_delay.unwrap_or(0),
 				actor_eviction_period: actor_eviction_period.unwrap_or(0),
 				actor_eviction_rate: actor_eviction_rate.unwrap_or(1.0),
+				method: method.unwrap_or_default(),
+				body,
+				auth,
 			},
 		};
 		rivet_types::runner_configs::RunnerConfig { kind, metadata }
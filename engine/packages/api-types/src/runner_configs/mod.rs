@@ -11,4 +11,8 @@ pub struct RunnerConfigResponse {
 	#[serde(default, skip_serializing_if = "Option::is_none")]
 	#[schema(value_type = Option<Object>, additional_properties = true)]
 	pub runner_pool_error: Option<rivet_types::actor::RunnerPoolError>,
+	/// Groups `runner_pool_error` by cause (e.g. `auth`, `timeout`).
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	#[schema(value_type = Option<String>)]
+	pub runner_pool_error_category: Option<rivet_types::actor::RunnerPoolErrorCategory>,
 }
@@ -11,6 +11,9 @@ pub mod errors;
 
 pub use axum::http::{HeaderMap, Method};
 
+/// Appended to error bodies that were cut off at the configured limit.
+pub const TRUNCATED_BODY_SUFFIX: &str = "... (truncated)";
+
 /// Sends a request to a remote datacenter with proper error context.
 async fn send_request(
 	request: reqwest::RequestBuilder,
@@ -55,7 +58,7 @@ pub async fn request_remote_datacenter_raw(
 	}
 
 	let res = send_request(request, dc_label, &url_string).await?;
-	reqwest_to_axum_response(res).await
+	reqwest_to_axum_response(res, ctx.config().api_peer().max_error_body_size()).await
 }
 
 /// Generic function to make requests to a specific datacenter
@@ -178,30 +181,68 @@ where
 	Ok(aggregated)
 }
 
+/// Converts a response from a remote datacenter into an axum response. At most
+/// `max_error_body_size` bytes of an error body are read, longer error bodies are truncated.
 #[tracing::instrument(skip_all)]
-pub async fn reqwest_to_axum_response(reqwest_response: reqwest::Response) -> Result<Response> {
+pub async fn reqwest_to_axum_response(
+	reqwest_response: reqwest::Response,
+	max_error_body_size: usize,
+) -> Result<Response> {
 	let status = reqwest_response.status();
-	let headers = reqwest_response.headers().clone();
+	let mut headers = reqwest_response.headers().clone();
 	let ray_id = headers
 		.get(X_RIVET_RAY_ID)
 		.and_then(|v| v.to_str().ok())
 		.map(|x| x.to_string());
-	let body_bytes = reqwest_response.bytes().await?;
 
-	if !status.is_success() {
-		let body_text = String::from_utf8_lossy(&body_bytes);
-		tracing::error!(?status, ?ray_id, %body_text, "remote dc returned error");
-	}
+	let body = if status.is_success() {
+		Body::from(reqwest_response.bytes().await?)
+	} else {
+		let (mut body_bytes, truncated) =
+			read_body_capped(reqwest_response, max_error_body_size).await?;
+		if truncated {
+			body_bytes.extend_from_slice(TRUNCATED_BODY_SUFFIX.as_bytes());
+			headers.remove(axum::http::header::CONTENT_LENGTH);
+		}
+
+		tracing::error!(
+			?status,
+			?ray_id,
+			body_text = %String::from_utf8_lossy(&body_bytes),
+			truncated,
+			"remote dc returned error"
+		);
+
+		Body::from(body_bytes)
+	};
 
-	let mut response = Response::builder()
-		.status(status)
-		.body(Body::from(body_bytes))?;
+	let mut response = Response::builder().status(status).body(body)?;
 
 	*response.headers_mut() = headers;
 
 	Ok(response)
 }
 
+/// Reads at most `limit` bytes of the response body without buffering the rest. Returns whether the
+/// body was cut off.
+async fn read_body_capped(
+	mut reqwest_response: reqwest::Response,
+	limit: usize,
+) -> Result<(Vec<u8>, bool)> {
+	let mut body_bytes = Vec::new();
+	while let Some(chunk) = reqwest_response.chunk().await? {
+		let remaining = limit - body_bytes.len();
+		if chunk.len() > remaining {
+			body_bytes.extend_from_slice(&chunk[..remaining]);
+			return Ok((body_bytes, true));
+		}
+
+		body_bytes.extend_from_slice(&chunk);
+	}
+
+	Ok((body_bytes, false))
+}
+
 #[tracing::instrument(skip_all)]
 pub async fn parse_response<T: DeserializeOwned>(reqwest_response: reqwest::Response) -> Result<T> {
 	let status = reqwest_response.status();
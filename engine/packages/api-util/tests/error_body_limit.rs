@@ -0,0 +1,62 @@
+use axum::{Router, http::StatusCode, routing::get};
+use rivet_api_util::{TRUNCATED_BODY_SUFFIX, reqwest_to_axum_response};
+
+const HUGE_BODY_SIZE: usize = 16 * 1024 * 1024;
+const MAX_ERROR_BODY_SIZE: usize = 1024;
+
+async fn start_server() -> String {
+	let app = Router::new()
+		.route(
+			"/huge-error",
+			get(|| async { (StatusCode::BAD_GATEWAY, "x".repeat(HUGE_BODY_SIZE)) }),
+		)
+		.route(
+			"/small-error",
+			get(|| async { (StatusCode::BAD_REQUEST, "bad request") }),
+		);
+
+	let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+	let addr = listener.local_addr().unwrap();
+	tokio::spawn(async move {
+		axum::serve(listener, app).await.unwrap();
+	});
+
+	format!("http://{addr}")
+}
+
+async fn proxy(url: String) -> (StatusCode, Vec<u8>) {
+	let res = reqwest::get(url).await.unwrap();
+	let response = reqwest_to_axum_response(res, MAX_ERROR_BODY_SIZE)
+		.await
+		.unwrap();
+	let status = response.status();
+	let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+		.await
+		.unwrap();
+
+	(status, body.to_vec())
+}
+
+#[tokio::test]
+async fn huge_error_body_is_truncated() {
+	let base_url = start_server().await;
+
+	let (status, body) = proxy(format!("{base_url}/huge-error")).await;
+
+	assert_eq!(status, StatusCode::BAD_GATEWAY);
+	assert_eq!(
+		body.len(),
+		MAX_ERROR_BODY_SIZE + TRUNCATED_BODY_SUFFIX.len()
+	);
+	assert!(body.ends_with(TRUNCATED_BODY_SUFFIX.as_bytes()));
+}
+
+#[tokio::test]
+async fn small_error_body_is_forwarded() {
+	let base_url = start_server().await;
+
+	let (status, body) = proxy(format!("{base_url}/small-error")).await;
+
+	assert_eq!(status, StatusCode::BAD_REQUEST);
+	assert_eq!(body, b"bad request");
+}
@@ -76,6 +76,19 @@ pub async fn run(ctx: &StandaloneCtx) -> Result<()> {
 			.await?;
 	}
 
+	// Actor state index backfill
+	if !is_complete(
+		ctx,
+		pegboard::workflows::actor_state_idx_backfill::BACKFILL_NAME,
+	)
+	.await?
+	{
+		ctx.workflow(pegboard::workflows::actor_state_idx_backfill::Input {})
+			.unique()
+			.dispatch()
+			.await?;
+	}
+
 	Ok(())
 }
 
@@ -1,6 +1,7 @@
 use gas::prelude::*;
 
 mod backfill;
+mod migrations;
 
 pub async fn start(config: rivet_config::Config, pools: rivet_pools::Pools) -> Result<()> {
 	let cache = rivet_cache::CacheInner::from_env(&config, pools.clone())?;
@@ -22,6 +23,7 @@ pub async fn start(config: rivet_config::Config, pools: rivet_pools::Pools) -> R
 		},
 		create_default_namespace(&ctx),
 		backfill::run(&ctx),
+		migrations::run(&ctx),
 		setup_pegboard_metrics_aggregator(&ctx),
 		setup_gas_pruner(&ctx),
 		setup_datacenter_ping(&ctx),
@@ -89,6 +91,9 @@ async fn create_default_namespace(ctx: &StandaloneCtx) -> Result<()> {
 				namespace_id,
 				name: "default".to_string(),
 				display_name: "Default".to_string(),
+				default_crash_policy: None,
+				cors_policy: None,
+				actor_rate_limit: None,
 			})
 			.tag("namespace_id", namespace_id)
 			.dispatch()
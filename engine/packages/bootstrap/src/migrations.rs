@@ -0,0 +1,17 @@
+use gas::prelude::*;
+use universaldb::migration::{KeyMigration, MigrationRunner};
+
+/// Key layout migrations. Each one is run to completion in order, completed migrations are skipped.
+fn migrations() -> Vec<Box<dyn KeyMigration>> {
+	Vec::new()
+}
+
+pub async fn run(ctx: &StandaloneCtx) -> Result<()> {
+	let runner = MigrationRunner::new((*ctx.udb()?).clone());
+
+	for migration in migrations() {
+		runner.run(migration.as_ref()).await?;
+	}
+
+	Ok(())
+}
@@ -8,6 +8,9 @@ use std::net::IpAddr;
 pub struct ApiPeer {
 	pub host: Option<IpAddr>,
 	pub port: Option<u16>,
+	/// Max bytes of an error response body from a peer datacenter that are read and forwarded.
+	/// Longer bodies are truncated.
+	pub max_error_body_size: Option<usize>,
 }
 
 impl ApiPeer {
@@ -18,4 +21,8 @@ impl ApiPeer {
 	pub fn port(&self) -> u16 {
 		self.port.unwrap_or(crate::defaults::ports::API_PEER)
 	}
+
+	pub fn max_error_body_size(&self) -> usize {
+		self.max_error_body_size.unwrap_or(64 * 1024) // 64 KiB
+	}
 }
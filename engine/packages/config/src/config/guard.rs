@@ -40,6 +40,8 @@ pub struct Guard {
 	pub route_pegboard_wake_signal_timeout_ms: Option<u64>,
 	/// Timeout for resolving pegboard actor query routes in milliseconds.
 	pub route_pegboard_resolve_query_timeout_ms: Option<u64>,
+	/// Timeout for reading the shared response cache in milliseconds. Treated as a cache miss.
+	pub route_response_cache_timeout_ms: Option<u64>,
 	/// Timeout for waiting for an actor to become ready in milliseconds.
 	pub actor_ready_timeout_ms: Option<u64>,
 	/// Timeout sent with actor force-wake requests in milliseconds.
@@ -48,14 +50,36 @@ pub struct Guard {
 	pub https: Option<Https>,
 	/// Max HTTP request body size in bytes (first line of defense).
 	pub http_max_request_body_size: Option<usize>,
+	/// Names of actors whose concurrent identical GET requests are coalesced into a single request
+	/// to the actor, with the buffered response fanned out to every waiting client. Requests are
+	/// matched by route, path, credential headers, and `Accept` headers, so only list actors that
+	/// return the same response to every client with the same credentials. Responses that set
+	/// cookies, send `Vary`, or are marked `Cache-Control: private` or `no-store` are never shared.
+	pub request_coalescing_actor_names: Option<Vec<String>>,
+	/// Max size in bytes of a response shared between coalesced requests, 1 MiB by default. Larger
+	/// and streamed responses are not shared.
+	pub request_coalescing_max_response_size: Option<usize>,
+	/// Max times an idempotent HTTP request (GET, HEAD, or any request with an `Idempotency-Key`
+	/// header) is replayed to the actor's new runner after the actor stops before responding.
+	/// When set, other requests that already reached the actor fail instead of being retried.
+	/// Unset by default, which retries every request like any other transient error.
+	pub request_replay_max_attempts: Option<u32>,
 	/// Max WebSocket message size in bytes.
 	pub websocket_max_message_size: Option<usize>,
 	/// Max WebSocket frame size in bytes.
 	pub websocket_max_frame_size: Option<usize>,
+	/// Max size in bytes of WebSocket messages sent from actors to clients. The client WebSocket is
+	/// closed with 1009 (message too big) if exceeded. Not limited if not set.
+	pub websocket_max_actor_message_size: Option<usize>,
 
 	/// Enables W3C trace context propagation (extract from incoming requests, inject into
 	/// upstream requests/websockets).
 	pub trace_propagation: Option<bool>,
+	/// Emits one access log entry per HTTP request and WebSocket upgrade. Disabled if not set.
+	pub access_log: Option<AccessLog>,
+	/// Caches GET responses from actors that set a cacheable `Cache-Control` header. Disabled if
+	/// not set.
+	pub response_cache: Option<ResponseCache>,
 }
 
 impl Guard {
@@ -126,6 +150,10 @@ impl Guard {
 		)
 	}
 
+	pub fn route_response_cache_timeout(&self) -> std::time::Duration {
+		std::time::Duration::from_millis(self.route_response_cache_timeout_ms.unwrap_or(1_000))
+	}
+
 	pub fn actor_ready_timeout(&self) -> std::time::Duration {
 		// Keep this high because serverless cold starts can take 10 to 20 seconds.
 		// If this grows again, verify route_timeout_ms and route_dispatch_timeout_ms leave enough outer budget.
@@ -141,6 +169,21 @@ impl Guard {
 		self.http_max_request_body_size.unwrap_or(20 * 1024 * 1024) // 20 MiB
 	}
 
+	pub fn request_coalescing_for_actor(&self, actor_name: &str) -> bool {
+		self.request_coalescing_actor_names
+			.as_ref()
+			.is_some_and(|names| names.iter().any(|name| name == actor_name))
+	}
+
+	pub fn request_coalescing_max_response_size(&self) -> usize {
+		self.request_coalescing_max_response_size
+			.unwrap_or(1024 * 1024)
+	}
+
+	pub fn request_replay_max_attempts(&self) -> Option<u32> {
+		self.request_replay_max_attempts
+	}
+
 	pub fn websocket_max_message_size(&self) -> usize {
 		self.websocket_max_message_size
 			.unwrap_or(DEFAULT_WEBSOCKET_MAX_MESSAGE_SIZE)
@@ -151,9 +194,61 @@ impl Guard {
 			.unwrap_or(DEFAULT_WEBSOCKET_MAX_FRAME_SIZE)
 	}
 
+	pub fn websocket_max_actor_message_size(&self) -> Option<usize> {
+		self.websocket_max_actor_message_size
+	}
+
 	pub fn trace_propagation(&self) -> bool {
 		self.trace_propagation.unwrap_or(false)
 	}
+
+	pub fn access_log(&self) -> Option<&AccessLog> {
+		self.access_log.as_ref()
+	}
+
+	pub fn response_cache(&self) -> Option<&ResponseCache> {
+		self.response_cache.as_ref()
+	}
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AccessLog {
+	/// File to append entries to as JSON lines. Entries are emitted as info-level tracing events
+	/// with the `guard_access_log` target if not set.
+	pub path: Option<PathBuf>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ResponseCache {
+	/// Max total size of response bodies cached in memory on each guard node, in bytes.
+	pub max_capacity_bytes: Option<u64>,
+	/// Responses with a larger body than this are not cached, in bytes.
+	pub max_body_size: Option<usize>,
+	/// Upper bound on how long a response is cached regardless of its `max-age`, in milliseconds.
+	pub max_ttl_ms: Option<u64>,
+	/// Also stores responses in UniversalDB so guard nodes share cached responses. Responses too
+	/// large for a single UniversalDB value are only cached in memory.
+	pub shared: Option<bool>,
+}
+
+impl ResponseCache {
+	pub fn max_capacity_bytes(&self) -> u64 {
+		self.max_capacity_bytes.unwrap_or(64 * 1024 * 1024)
+	}
+
+	pub fn max_body_size(&self) -> usize {
+		self.max_body_size.unwrap_or(1024 * 1024)
+	}
+
+	pub fn max_ttl(&self) -> std::time::Duration {
+		std::time::Duration::from_millis(self.max_ttl_ms.unwrap_or(60 * 60 * 1000))
+	}
+
+	pub fn shared(&self) -> bool {
+		self.shared.unwrap_or(false)
+	}
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
@@ -162,6 +257,15 @@ impl Guard {
 pub struct Https {
 	pub port: u16, // Port for HTTPS traffic
 	pub tls: Tls,  // TLS configuration
+	/// Enable & configure HTTP/3 (QUIC). Uses the same TLS configuration as HTTPS.
+	pub http3: Option<Http3>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Http3 {
+	/// UDP port for HTTP/3 traffic. Usually the same number as the HTTPS port.
+	pub port: u16,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
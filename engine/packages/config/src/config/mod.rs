@@ -205,6 +205,7 @@ impl Root {
 				#[allow(deprecated)]
 				memory_optimization: None,
 				disable_memory_optimization: false,
+				listen_connections: pubsub::Postgres::default_listen_connections(),
 				ssl: pg.ssl.clone(),
 			}));
 		}
@@ -261,6 +262,26 @@ impl Root {
 			);
 		}
 
+		// Validate service_shutdown_duration leaves worker and guard time to stop before services are
+		// aborted, and fires before the process is forcibly exited
+		let service = self.runtime.service_shutdown_duration();
+		if service < max_graceful || service > force {
+			bail!(
+				"service_shutdown_duration ({service:?}) must be greater than or equal to both \
+				worker_shutdown_duration ({worker:?}) and guard_shutdown_duration ({guard:?}), and \
+				less than or equal to force_shutdown_duration ({force:?})"
+			);
+		}
+
+		// Validate gasoline_debug_parallelization is accepted by the bulk workflow operations
+		let parallelization = self.runtime.gasoline_debug_parallelization();
+		if parallelization == 0 || parallelization > MAX_GASOLINE_DEBUG_PARALLELIZATION {
+			bail!(
+				"gasoline_debug_parallelization ({parallelization}) must be between 1 and \
+				{MAX_GASOLINE_DEBUG_PARALLELIZATION}"
+			);
+		}
+
 		Ok(())
 	}
 
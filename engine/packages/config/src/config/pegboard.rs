@@ -1,7 +1,14 @@
+use std::collections::HashMap;
+
 use anyhow::{Result, bail};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::secret::Secret;
+
+/// Lower bound for `metrics_aggregator_interval`, in milliseconds.
+pub const MIN_METRICS_AGGREGATOR_INTERVAL: u64 = 1_000;
+
 #[derive(Debug, Serialize, Deserialize, Clone, Default, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct Pegboard {
@@ -34,6 +41,26 @@ pub struct Pegboard {
 	///
 	/// This controls the maximum backoff duration when rescheduling actors.
 	pub reschedule_backoff_max_exponent: Option<usize>,
+	/// Maximum number of actor reschedules per second for a single runner pool.
+	///
+	/// Reschedules past this rate are deferred so that losing a runner with many actors does not
+	/// reallocate all of them at once.
+	pub actor_reschedule_rate: Option<u32>,
+	/// How long audit records of destroyed actors are kept.
+	///
+	/// Unit is in milliseconds.
+	pub actor_destroy_audit_ttl: Option<i64>,
+	/// How long webhook notifications that could not be delivered are kept.
+	///
+	/// Unit is in milliseconds. Default: 7 days.
+	pub webhook_dead_letter_ttl: Option<i64>,
+	/// How often the metrics aggregator scans the database to export pegboard gauges.
+	///
+	/// Longer intervals reduce the cost of the scan on large deployments at the expense of staler
+	/// metrics. Must be at least 1 second.
+	///
+	/// Unit is in milliseconds.
+	pub metrics_aggregator_interval: Option<u64>,
 	/// How long after last ping before considering a runner ineligible for allocation.
 	///
 	/// Unit is in milliseconds.
@@ -45,10 +72,20 @@ pub struct Pegboard {
 	///
 	/// Unit is in milliseconds.
 	pub runner_lost_threshold: Option<i64>,
+	/// How long a runner that passed the runner lost threshold is kept disconnected before its actors are
+	/// rescheduled. No new actors are allocated to it during this window. If it reconnects with the same key
+	/// before the window ends, it resumes its existing actor allocations.
+	///
+	/// Unit is in milliseconds.
+	pub runner_reconnect_grace_period: Option<i64>,
 	/// How long after last ping before considering a hibernating request disconnected.
 	///
 	/// Unit is in milliseconds.
 	pub hibernating_request_eligible_threshold: Option<i64>,
+	/// Overrides `hibernating_request_eligible_threshold` for actors with the given name.
+	///
+	/// Unit is in milliseconds.
+	pub hibernating_request_eligible_threshold_overrides: Option<HashMap<String, i64>>,
 	/// Time to delay a serverless runner from attempting a new outbound connection after a connection failure.
 	///
 	/// Unit is in milliseconds.
@@ -62,6 +99,14 @@ pub struct Pegboard {
 	///
 	/// This controls the maximum backoff duration when serverlessly connecting to runners.
 	pub serverless_backoff_max_exponent: Option<usize>,
+	/// Consecutive connection errors of a serverless runner pool after which its circuit breaker opens. While
+	/// open, no requests are sent to the serverless endpoint until the cooldown passes.
+	pub serverless_circuit_breaker_threshold: Option<u32>,
+	/// How long the serverless circuit breaker stays open before letting requests through again. If the next
+	/// request fails, the circuit opens again.
+	///
+	/// Unit is in milliseconds.
+	pub serverless_circuit_breaker_cooldown: Option<i64>,
 
 	/// Global pool desired max.
 	pub pool_desired_max_override: Option<u32>,
@@ -99,12 +144,23 @@ pub struct Pegboard {
 	pub runner_event_demuxer_gc_interval_ms: Option<u64>,
 	/// Max time since last seen before actor is considered stale, in milliseconds.
 	pub runner_event_demuxer_max_last_seen_ms: Option<u64>,
+	/// Tokens runners must present to connect to a namespace, keyed by namespace name and then by token
+	/// name. Any token of the namespace is accepted, which allows adding a new token before removing the
+	/// old one when rotating.
+	///
+	/// Tokens are only checked when a runner connects, so removing a token does not disconnect runners
+	/// that are already connected with it. Namespaces without tokens only require the admin token (if
+	/// auth is enabled).
+	pub runner_tokens: Option<HashMap<String, HashMap<String, Secret<String>>>>,
 
 	// === Gateway Settings ===
 	/// WebSocket open/handshake timeout in milliseconds.
 	pub gateway_websocket_open_timeout_ms: Option<u64>,
 	/// Timeout for response to start in milliseconds.
 	pub gateway_response_start_timeout_ms: Option<u64>,
+	/// Max time between chunks of a streamed response body in milliseconds. The response is
+	/// aborted once exceeded.
+	pub gateway_response_body_idle_timeout_ms: Option<u64>,
 	/// Ping interval for gateway updates in milliseconds.
 	pub gateway_update_ping_interval_ms: Option<u64>,
 	/// GC interval for in-flight requests in milliseconds.
@@ -117,6 +173,24 @@ pub struct Pegboard {
 	pub gateway_hws_max_pending_size: Option<u64>,
 	/// Max HTTP request body size in bytes for requests to actors.
 	pub gateway_http_max_request_body_size: Option<usize>,
+	/// Max concurrent WebSockets to a single actor through a gateway. New WebSockets to the actor are closed
+	/// once the limit is reached. Unlimited if not set.
+	pub gateway_actor_max_websockets: Option<usize>,
+	/// Max concurrent HTTP requests and WebSockets to a single runner through a gateway. New requests
+	/// to the runner fail with 503 once the limit is reached. Unlimited if not set.
+	pub gateway_runner_max_in_flight_requests: Option<usize>,
+	/// Max unacknowledged hibernatable WebSocket messages buffered for a single runner by a gateway.
+	/// New requests to the runner fail with 503 while the limit is reached. Unlimited if not set.
+	pub gateway_runner_max_pending_websocket_messages: Option<usize>,
+	/// Request bodies larger than this or of unknown size are streamed from the client to the runner in
+	/// chunks of this size instead of being buffered and sent in a single tunnel message. Streamed requests
+	/// are not retried. Bodies are always buffered if not set.
+	///
+	/// Unit is in bytes.
+	pub gateway_request_body_chunk_size: Option<usize>,
+	/// Offer lz4 compression of WebSocket message payloads sent through the tunnel. Only used when the
+	/// envoy accepts it. Enabled by default.
+	pub gateway_websocket_compression: Option<bool>,
 
 	// === Envoy Settings ===
 	/// How long to wait before considering an envoy lost and evicting all of its actors.
@@ -155,6 +229,16 @@ pub struct Pegboard {
 	///
 	/// Unit is in milliseconds.
 	pub serverless_drain_grace_period: Option<u64>,
+	/// How long to wait for a serverless runner to send its init message after the SSE stream is opened.
+	///
+	/// If the runner does not initialize within this time, the connection is closed and retried.
+	///
+	/// Unit is in milliseconds.
+	pub serverless_init_timeout: Option<u64>,
+	/// Names of engine environment variables that serverless runner configs may send as a bearer
+	/// token with `auth: { bearer_env: { env } }`. Any other name is rejected. Empty by default, so
+	/// runner configs can't read the engine's environment unless explicitly allowed.
+	pub serverless_auth_env_allowlist: Option<Vec<String>>,
 
 	// === KV Preload Settings ===
 	/// Maximum total size of all preloaded KV data sent with the actor start command.
@@ -162,6 +246,57 @@ pub struct Pegboard {
 	///
 	/// Unit is in bytes. Default: 1,048,576 (1 MiB).
 	pub preload_max_total_bytes: Option<u64>,
+
+	/// Maximum number of items accepted by a single batch operation (batch actor get, KV put).
+	/// Larger batches are rejected before any work is done.
+	///
+	/// Default: 10,000.
+	pub max_batch_size: Option<usize>,
+
+	/// Maximum number of actors created by a single batch create request. Each actor dispatches its
+	/// own workflow, so this is much lower than `max_batch_size`.
+	///
+	/// Default: 100.
+	pub max_create_batch_size: Option<usize>,
+
+	/// Number of actor workflows a batch create request dispatches and waits on at once.
+	///
+	/// Default: 16.
+	pub create_batch_concurrency: Option<usize>,
+
+	/// Maximum number of actors destroyed by a single batch destroy request. A filtered destroy stops
+	/// after this many actors and reports that more remain.
+	///
+	/// Default: 100.
+	pub max_destroy_batch_size: Option<usize>,
+
+	/// Number of actors a batch destroy request signals and waits on at once.
+	///
+	/// Default: 16.
+	pub destroy_batch_concurrency: Option<usize>,
+
+	// === Actor Log Settings ===
+	/// Number of most recent log lines retained per actor. Older lines are dropped as new lines are
+	/// forwarded by the runner. Setting to 0 disables log capture.
+	///
+	/// Default: 1,000.
+	pub actor_log_max_lines: Option<u64>,
+
+	/// Log lines longer than this are truncated before being stored.
+	///
+	/// Unit is in bytes. Default: 4,096.
+	pub actor_log_max_line_size: Option<usize>,
+
+	/// How often a following `GET /actors/{actor_id}/logs` request polls for new lines.
+	///
+	/// Unit is in milliseconds. Default: 1,000.
+	pub actor_log_follow_poll_interval: Option<u64>,
+
+	/// Max log lines stored per second for a single actor. Lines over this rate are dropped. Allows
+	/// bursts of up to one second's worth of lines.
+	///
+	/// Default: 200.
+	pub actor_log_max_lines_per_second: Option<u32>,
 }
 
 impl Pegboard {
@@ -191,10 +326,55 @@ impl Pegboard {
 			}
 		}
 
+		if self.actor_reschedule_rate == Some(0) {
+			bail!("pegboard.actor_reschedule_rate must be greater than 0");
+		}
+
+		if self
+			.metrics_aggregator_interval
+			.is_some_and(|interval| interval < MIN_METRICS_AGGREGATOR_INTERVAL)
+		{
+			bail!(
+				"pegboard.metrics_aggregator_interval must be at least {MIN_METRICS_AGGREGATOR_INTERVAL}ms"
+			);
+		}
+
 		if self.envoy_expire_scheduler_max_concurrent_expires == Some(0) {
 			bail!("pegboard.envoy_expire_scheduler_max_concurrent_expires must be greater than 0");
 		}
 
+		if self.gateway_request_body_chunk_size == Some(0) {
+			bail!("pegboard.gateway_request_body_chunk_size must be greater than 0");
+		}
+
+		if self.max_batch_size == Some(0) {
+			bail!("pegboard.max_batch_size must be greater than 0");
+		}
+
+		if self.max_create_batch_size == Some(0) {
+			bail!("pegboard.max_create_batch_size must be greater than 0");
+		}
+
+		if self.create_batch_concurrency == Some(0) {
+			bail!("pegboard.create_batch_concurrency must be greater than 0");
+		}
+
+		if self.max_destroy_batch_size == Some(0) {
+			bail!("pegboard.max_destroy_batch_size must be greater than 0");
+		}
+
+		if self.destroy_batch_concurrency == Some(0) {
+			bail!("pegboard.destroy_batch_concurrency must be greater than 0");
+		}
+
+		if self.actor_log_follow_poll_interval == Some(0) {
+			bail!("pegboard.actor_log_follow_poll_interval must be greater than 0");
+		}
+
+		if self.actor_log_max_lines_per_second == Some(0) {
+			bail!("pegboard.actor_log_max_lines_per_second must be greater than 0");
+		}
+
 		Ok(())
 	}
 
@@ -228,6 +408,24 @@ impl Pegboard {
 		self.reschedule_backoff_max_exponent.unwrap_or(8)
 	}
 
+	pub fn actor_reschedule_rate(&self) -> u32 {
+		self.actor_reschedule_rate.unwrap_or(50)
+	}
+
+	pub fn actor_destroy_audit_ttl(&self) -> i64 {
+		self.actor_destroy_audit_ttl
+			.unwrap_or(7 * 24 * 60 * 60 * 1000)
+	}
+
+	pub fn webhook_dead_letter_ttl(&self) -> i64 {
+		self.webhook_dead_letter_ttl
+			.unwrap_or(7 * 24 * 60 * 60 * 1000)
+	}
+
+	pub fn metrics_aggregator_interval(&self) -> u64 {
+		self.metrics_aggregator_interval.unwrap_or(15_000)
+	}
+
 	pub fn runner_eligible_threshold(&self) -> i64 {
 		self.runner_eligible_threshold.unwrap_or(10_000)
 	}
@@ -236,11 +434,32 @@ impl Pegboard {
 		self.runner_lost_threshold.unwrap_or(15_000)
 	}
 
+	pub fn runner_reconnect_grace_period(&self) -> i64 {
+		self.runner_reconnect_grace_period.unwrap_or(0)
+	}
+
 	pub fn hibernating_request_eligible_threshold(&self) -> i64 {
 		self.hibernating_request_eligible_threshold
 			.unwrap_or(90_000)
 	}
 
+	pub fn hibernating_request_eligible_threshold_for_actor(&self, actor_name: &str) -> i64 {
+		self.hibernating_request_eligible_threshold_overrides
+			.as_ref()
+			.and_then(|overrides| overrides.get(actor_name))
+			.copied()
+			.unwrap_or_else(|| self.hibernating_request_eligible_threshold())
+	}
+
+	/// Longest threshold of any actor, including overrides.
+	pub fn max_hibernating_request_eligible_threshold(&self) -> i64 {
+		self.hibernating_request_eligible_threshold_overrides
+			.iter()
+			.flat_map(|overrides| overrides.values())
+			.copied()
+			.fold(self.hibernating_request_eligible_threshold(), i64::max)
+	}
+
 	pub fn serverless_base_retry_timeout(&self) -> usize {
 		self.serverless_base_retry_timeout.unwrap_or(2_000)
 	}
@@ -254,6 +473,14 @@ impl Pegboard {
 		self.serverless_backoff_max_exponent.unwrap_or(8)
 	}
 
+	pub fn serverless_circuit_breaker_threshold(&self) -> u32 {
+		self.serverless_circuit_breaker_threshold.unwrap_or(10)
+	}
+
+	pub fn serverless_circuit_breaker_cooldown(&self) -> i64 {
+		self.serverless_circuit_breaker_cooldown.unwrap_or(30_000)
+	}
+
 	pub fn runner_pool_error_consecutive_successes_to_clear(&self) -> u32 {
 		self.runner_pool_consecutive_successes_to_clear_error
 			.unwrap_or(3)
@@ -280,6 +507,10 @@ impl Pegboard {
 			.unwrap_or(5 * 60 * 1000)
 	}
 
+	pub fn gateway_response_body_idle_timeout_ms(&self) -> u64 {
+		self.gateway_response_body_idle_timeout_ms.unwrap_or(60_000)
+	}
+
 	pub fn gateway_update_ping_interval_ms(&self) -> u64 {
 		self.gateway_update_ping_interval_ms.unwrap_or(3_000)
 	}
@@ -301,6 +532,26 @@ impl Pegboard {
 			.unwrap_or(128 * 1024 * 1024) // 128 MiB
 	}
 
+	pub fn gateway_actor_max_websockets(&self) -> Option<usize> {
+		self.gateway_actor_max_websockets
+	}
+
+	pub fn gateway_runner_max_in_flight_requests(&self) -> Option<usize> {
+		self.gateway_runner_max_in_flight_requests
+	}
+
+	pub fn gateway_runner_max_pending_websocket_messages(&self) -> Option<usize> {
+		self.gateway_runner_max_pending_websocket_messages
+	}
+
+	pub fn gateway_request_body_chunk_size(&self) -> Option<usize> {
+		self.gateway_request_body_chunk_size
+	}
+
+	pub fn gateway_websocket_compression(&self) -> bool {
+		self.gateway_websocket_compression.unwrap_or(true)
+	}
+
 	pub fn runner_max_response_payload_body_size(&self) -> usize {
 		self.runner_max_response_payload_body_size
 			.unwrap_or(20 * 1024 * 1024) // 20 MiB
@@ -322,6 +573,18 @@ impl Pegboard {
 		self.runner_event_demuxer_max_last_seen_ms.unwrap_or(30_000)
 	}
 
+	/// Tokens accepted for runners connecting to the given namespace. `None` if the namespace has no
+	/// runner tokens configured.
+	pub fn runner_tokens_for_namespace(
+		&self,
+		namespace: &str,
+	) -> Option<&HashMap<String, Secret<String>>> {
+		self.runner_tokens
+			.as_ref()
+			.and_then(|tokens| tokens.get(namespace))
+			.filter(|tokens| !tokens.is_empty())
+	}
+
 	pub fn envoy_lost_threshold(&self) -> i64 {
 		self.envoy_lost_threshold.unwrap_or(15_000)
 	}
@@ -369,9 +632,55 @@ impl Pegboard {
 		self.serverless_drain_grace_period.unwrap_or(10_000)
 	}
 
+	pub fn serverless_init_timeout(&self) -> u64 {
+		self.serverless_init_timeout.unwrap_or(30_000)
+	}
+
+	pub fn serverless_auth_env_allowlist(&self) -> &[String] {
+		self.serverless_auth_env_allowlist
+			.as_deref()
+			.unwrap_or_default()
+	}
+
 	pub fn preload_max_total_bytes(&self) -> u64 {
 		self.preload_max_total_bytes.unwrap_or(1_048_576)
 	}
+
+	pub fn max_batch_size(&self) -> usize {
+		self.max_batch_size.unwrap_or(10_000)
+	}
+
+	pub fn max_create_batch_size(&self) -> usize {
+		self.max_create_batch_size.unwrap_or(100)
+	}
+
+	pub fn create_batch_concurrency(&self) -> usize {
+		self.create_batch_concurrency.unwrap_or(16)
+	}
+
+	pub fn max_destroy_batch_size(&self) -> usize {
+		self.max_destroy_batch_size.unwrap_or(100)
+	}
+
+	pub fn destroy_batch_concurrency(&self) -> usize {
+		self.destroy_batch_concurrency.unwrap_or(16)
+	}
+
+	pub fn actor_log_max_lines(&self) -> u64 {
+		self.actor_log_max_lines.unwrap_or(1_000)
+	}
+
+	pub fn actor_log_max_line_size(&self) -> usize {
+		self.actor_log_max_line_size.unwrap_or(4_096)
+	}
+
+	pub fn actor_log_follow_poll_interval(&self) -> u64 {
+		self.actor_log_follow_poll_interval.unwrap_or(1_000)
+	}
+
+	pub fn actor_log_max_lines_per_second(&self) -> u32 {
+		self.actor_log_max_lines_per_second.unwrap_or(200)
+	}
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, JsonSchema)]
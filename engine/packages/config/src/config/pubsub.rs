@@ -37,6 +37,12 @@ pub struct Postgres {
 	/// engine pod. Opt-in diagnostic; default false.
 	#[serde(default)]
 	pub disable_memory_optimization: bool,
+	/// Number of dedicated connections used to LISTEN for notifications. Channels are spread
+	/// across connections with a consistent hash.
+	///
+	/// Increase on busy clusters where a single connection becomes a bottleneck.
+	#[serde(default = "Postgres::default_listen_connections")]
+	pub listen_connections: usize,
 	/// SSL configuration options
 	#[serde(default)]
 	pub ssl: Option<PostgresSsl>,
@@ -49,11 +55,18 @@ impl Default for Postgres {
 			#[allow(deprecated)]
 			memory_optimization: None,
 			disable_memory_optimization: false,
+			listen_connections: Self::default_listen_connections(),
 			ssl: None,
 		}
 	}
 }
 
+impl Postgres {
+	pub(crate) fn default_listen_connections() -> usize {
+		1
+	}
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct Nats {
@@ -3,6 +3,9 @@ use std::time::Duration;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+/// Highest parallelization accepted by the bulk workflow revive, silence and prune operations.
+pub const MAX_GASOLINE_DEBUG_PARALLELIZATION: u16 = 1023;
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct Runtime {
@@ -34,6 +37,12 @@ pub struct Runtime {
 	/// Must be greater than or equal to both worker_shutdown_duration and guard_shutdown_duration.
 	/// Defaults to 10 minutes.
 	force_shutdown_duration: Option<u32>,
+	/// Time (in seconds) to wait for services that require graceful shutdown to stop after receiving
+	/// SIGTERM. Services still running after this are aborted. Must be greater than or equal to both
+	/// worker_shutdown_duration and guard_shutdown_duration, and less than or equal to
+	/// force_shutdown_duration. Defaults to the longer of worker_shutdown_duration and
+	/// guard_shutdown_duration.
+	service_shutdown_duration: Option<u32>,
 	/// Whether or not to allow running the engine when the previous version that was run is higher than
 	/// the current version.
 	allow_version_rollback: Option<bool>,
@@ -42,6 +51,20 @@ pub struct Runtime {
 	gasoline_prune_eligibility_duration: Option<u64>,
 	/// Time (in seconds) to periodically check for workflows to prune. Defaults to 12 hours.
 	gasoline_prune_interval_duration: Option<u64>,
+	/// Whether or not workflows can be put into step mode, where they pause before every activity and signal
+	/// listen until continued by an operator. Only meant for debugging. Defaults to false.
+	gasoline_step_mode: Option<bool>,
+	/// Parallelization used by the manual workflow revive, silence and prune commands when none is given.
+	/// Must be between 1 and 1023. Defaults to 1.
+	gasoline_debug_parallelization: Option<u16>,
+	/// Time (in seconds) during which dispatching a workflow with the same name and idempotency key returns
+	/// the existing workflow instead of creating a new one. The key is cleared once the window has passed and
+	/// the workflow completes, the key is reused, or the workflow is pruned. Defaults to 24 hours.
+	gasoline_idempotency_window: Option<u64>,
+	/// Whether or not large workflow states are compressed before being stored. Compressed states are
+	/// always read back, so only enable once every engine node runs a version that can read them.
+	/// Defaults to false.
+	gasoline_compress_state: Option<bool>,
 }
 
 impl Runtime {
@@ -67,6 +90,17 @@ impl Runtime {
 		Duration::from_secs(self.force_shutdown_duration.unwrap_or(10 * 60) as u64)
 	}
 
+	/// Returns the service shutdown duration, defaulting to the longer of the worker and guard shutdown
+	/// durations.
+	pub fn service_shutdown_duration(&self) -> Duration {
+		self.service_shutdown_duration
+			.map(|x| Duration::from_secs(x as u64))
+			.unwrap_or_else(|| {
+				self.worker_shutdown_duration()
+					.max(self.guard_shutdown_duration())
+			})
+	}
+
 	pub fn allow_version_rollback(&self) -> bool {
 		self.allow_version_rollback.unwrap_or_default()
 	}
@@ -89,4 +123,20 @@ impl Runtime {
 				.unwrap_or(60 * 60 * 12),
 		)
 	}
+
+	pub fn gasoline_step_mode(&self) -> bool {
+		self.gasoline_step_mode.unwrap_or_default()
+	}
+
+	pub fn gasoline_debug_parallelization(&self) -> u16 {
+		self.gasoline_debug_parallelization.unwrap_or(1)
+	}
+
+	pub fn gasoline_idempotency_window(&self) -> Duration {
+		Duration::from_secs(self.gasoline_idempotency_window.unwrap_or(60 * 60 * 24))
+	}
+
+	pub fn gasoline_compress_state(&self) -> bool {
+		self.gasoline_compress_state.unwrap_or_default()
+	}
 }
@@ -0,0 +1,86 @@
+use std::time::Duration;
+
+use rivet_config::config::Root;
+
+fn root(runtime: serde_json::Value) -> Root {
+	serde_json::from_value(serde_json::json!({ "runtime": runtime })).expect("invalid config")
+}
+
+#[test]
+fn service_shutdown_duration_defaults_to_longest_graceful_shutdown() {
+	let mut config = root(serde_json::json!({
+		"worker_shutdown_duration": 30,
+		"guard_shutdown_duration": 60,
+		"force_shutdown_duration": 120,
+	}));
+	config.validate_and_set_defaults().unwrap();
+
+	assert_eq!(
+		config.runtime.service_shutdown_duration(),
+		Duration::from_secs(60)
+	);
+
+	// Defaults are valid on their own
+	Root::default().validate_and_set_defaults().unwrap();
+}
+
+#[test]
+fn service_shutdown_duration_must_cover_worker_and_guard() {
+	let mut config = root(serde_json::json!({
+		"worker_shutdown_duration": 30,
+		"guard_shutdown_duration": 60,
+		"force_shutdown_duration": 120,
+		"service_shutdown_duration": 45,
+	}));
+	let err = config.validate_and_set_defaults().unwrap_err();
+	assert!(
+		err.to_string().contains("service_shutdown_duration"),
+		"unexpected error: {err}"
+	);
+
+	let mut config = root(serde_json::json!({
+		"worker_shutdown_duration": 90,
+		"guard_shutdown_duration": 60,
+		"force_shutdown_duration": 120,
+		"service_shutdown_duration": 60,
+	}));
+	assert!(config.validate_and_set_defaults().is_err());
+}
+
+#[test]
+fn service_shutdown_duration_must_not_exceed_force_shutdown() {
+	let mut config = root(serde_json::json!({
+		"worker_shutdown_duration": 30,
+		"guard_shutdown_duration": 60,
+		"force_shutdown_duration": 120,
+		"service_shutdown_duration": 180,
+	}));
+	assert!(config.validate_and_set_defaults().is_err());
+
+	let mut config = root(serde_json::json!({
+		"worker_shutdown_duration": 30,
+		"guard_shutdown_duration": 60,
+		"force_shutdown_duration": 120,
+		"service_shutdown_duration": 120,
+	}));
+	config.validate_and_set_defaults().unwrap();
+}
+
+#[test]
+fn gasoline_debug_parallelization_must_be_in_range() {
+	for parallelization in [0, 1024] {
+		let mut config = root(serde_json::json!({
+			"gasoline_debug_parallelization": parallelization,
+		}));
+		let err = config.validate_and_set_defaults().unwrap_err();
+		assert!(
+			err.to_string().contains("gasoline_debug_parallelization"),
+			"unexpected error: {err}"
+		);
+	}
+
+	let mut config = root(serde_json::json!({
+		"gasoline_debug_parallelization": 1023,
+	}));
+	config.validate_and_set_defaults().unwrap();
+}
@@ -10,7 +10,7 @@ use anyhow::Result;
 use async_trait::async_trait;
 use depot::error::SqliteStorageError;
 use depot_client::{
-	database::{NativeDatabaseHandle, open_database_from_transport},
+	database::{NativeDatabaseHandle, NativeDatabaseOptions, open_database_from_transport},
 	vfs::{SqliteTransport, SqliteVfsMetrics},
 };
 use rivet_envoy_protocol as protocol;
@@ -38,6 +38,7 @@ pub async fn open_database_from_embedded_depot(
 		actor_id,
 		generation,
 		rt_handle,
+		NativeDatabaseOptions::default(),
 		metrics,
 	)
 	.await
@@ -7,6 +7,24 @@ pub fn is_head_fence_mismatch(group: &str, code: &str) -> bool {
 	group == HEAD_FENCE_MISMATCH_GROUP && code == HEAD_FENCE_MISMATCH_CODE
 }
 
+/// Builds a parameterized insert of one row into `table`, with one `?` per column.
+pub fn insert_sql(table: &str, columns: &[String]) -> String {
+	format!(
+		"INSERT INTO {} ({}) VALUES ({})",
+		quote_identifier(table),
+		columns
+			.iter()
+			.map(|column| quote_identifier(column))
+			.collect::<Vec<_>>()
+			.join(", "),
+		vec!["?"; columns.len()].join(", "),
+	)
+}
+
+fn quote_identifier(identifier: &str) -> String {
+	format!("\"{}\"", identifier.replace('"', "\"\""))
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum BindParam {
 	Null,
@@ -16,6 +16,34 @@ use crate::{
 	worker::{SqliteWorkerFatalError, SqliteWorkerHandle},
 };
 
+/// Per-database options applied when opening a `NativeDatabaseHandle`.
+#[derive(Clone, Debug)]
+pub struct NativeDatabaseOptions {
+	/// Enforce foreign key constraints. Can be disabled for schemas that rely on SQLite ignoring them.
+	pub foreign_keys: bool,
+	/// Open the database read-only so SQLite rejects every write, e.g. when serving a snapshot.
+	pub readonly: bool,
+}
+
+impl Default for NativeDatabaseOptions {
+	fn default() -> Self {
+		Self {
+			foreign_keys: true,
+			readonly: false,
+		}
+	}
+}
+
+impl NativeDatabaseOptions {
+	fn vfs_config(&self) -> VfsConfig {
+		VfsConfig {
+			foreign_keys: self.foreign_keys,
+			readonly: self.readonly,
+			..VfsConfig::default()
+		}
+	}
+}
+
 #[derive(Clone)]
 pub struct NativeDatabaseHandle {
 	vfs: NativeVfsHandle,
@@ -55,11 +83,12 @@ pub async fn open_database_from_transport(
 	actor_id: String,
 	generation: u64,
 	rt_handle: Handle,
+	options: NativeDatabaseOptions,
 	metrics: Option<Arc<dyn SqliteVfsMetrics>>,
 ) -> Result<NativeDatabaseHandle> {
 	let open_timer = SqliteOpenTimer::new(&metrics);
 	let vfs_name = vfs_name_for_actor_database(&actor_id, generation);
-	let config = VfsConfig::default();
+	let config = options.vfs_config();
 	let transport: SqliteTransportHandle = Arc::new(GenerationFencedTransport {
 		inner: transport,
 		generation,
@@ -242,6 +271,16 @@ impl NativeDatabaseHandle {
 		self.map_worker_result(self.worker.execute(sql, params).await)
 	}
 
+	pub async fn insert_many(
+		&self,
+		table: String,
+		columns: Vec<String>,
+		rows: Vec<Vec<BindParam>>,
+	) -> Result<ExecResult> {
+		self.check_fatal_error()?;
+		self.map_worker_result(self.worker.insert_many(table, columns, rows).await)
+	}
+
 	pub async fn close(&self) -> Result<()> {
 		match self.worker.close().await {
 			Ok(()) => Ok(()),
@@ -1,18 +1,76 @@
 use std::ffi::{CStr, CString};
-use std::os::raw::c_char;
+use std::fmt;
+use std::os::raw::{c_char, c_int};
 use std::ptr;
 
 use anyhow::{Result, anyhow};
 pub use depot_client_types::{BindParam, ColumnValue, ExecResult, ExecuteResult, QueryResult};
 use libsqlite3_sys::{
-	SQLITE_BLOB, SQLITE_DONE, SQLITE_FLOAT, SQLITE_INTEGER, SQLITE_NULL, SQLITE_OK, SQLITE_ROW,
-	SQLITE_TEXT, SQLITE_TRANSIENT, sqlite3, sqlite3_bind_blob, sqlite3_bind_double,
-	sqlite3_bind_int64, sqlite3_bind_null, sqlite3_bind_text, sqlite3_changes, sqlite3_column_blob,
+	SQLITE_BLOB, SQLITE_BUSY, SQLITE_CONSTRAINT, SQLITE_CORRUPT, SQLITE_DONE, SQLITE_FLOAT,
+	SQLITE_INTEGER, SQLITE_LOCKED, SQLITE_NOTADB, SQLITE_NULL, SQLITE_OK, SQLITE_ROW, SQLITE_TEXT,
+	SQLITE_TRANSIENT, sqlite3, sqlite3_bind_blob, sqlite3_bind_double, sqlite3_bind_int64,
+	sqlite3_bind_null, sqlite3_bind_text, sqlite3_changes, sqlite3_column_blob,
 	sqlite3_column_bytes, sqlite3_column_count, sqlite3_column_double, sqlite3_column_int64,
 	sqlite3_column_name, sqlite3_column_text, sqlite3_column_type, sqlite3_errmsg,
-	sqlite3_finalize, sqlite3_last_insert_rowid, sqlite3_prepare_v2, sqlite3_step,
+	sqlite3_extended_errcode, sqlite3_finalize, sqlite3_last_insert_rowid, sqlite3_prepare_v2,
+	sqlite3_reset, sqlite3_step,
 };
 
+/// Broad class of a SQLite failure, derived from its primary result code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SqliteErrorCategory {
+	Constraint,
+	Busy,
+	Locked,
+	Corrupt,
+	Other,
+}
+
+/// SQLite failure carrying the extended result code reported by the connection.
+#[derive(Debug, Clone)]
+pub struct SqliteQueryError {
+	context: String,
+	message: String,
+	extended_code: c_int,
+}
+
+impl SqliteQueryError {
+	pub fn new(context: impl Into<String>, message: impl Into<String>, extended_code: i32) -> Self {
+		Self {
+			context: context.into(),
+			message: message.into(),
+			extended_code,
+		}
+	}
+
+	pub fn message(&self) -> &str {
+		&self.message
+	}
+
+	pub fn extended_code(&self) -> i32 {
+		self.extended_code
+	}
+
+	pub fn category(&self) -> SqliteErrorCategory {
+		// The primary result code is the low byte of the extended code
+		match self.extended_code & 0xff {
+			SQLITE_CONSTRAINT => SqliteErrorCategory::Constraint,
+			SQLITE_BUSY => SqliteErrorCategory::Busy,
+			SQLITE_LOCKED => SqliteErrorCategory::Locked,
+			SQLITE_CORRUPT | SQLITE_NOTADB => SqliteErrorCategory::Corrupt,
+			_ => SqliteErrorCategory::Other,
+		}
+	}
+}
+
+impl fmt::Display for SqliteQueryError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}: {}", self.context, self.message)
+	}
+}
+
+impl std::error::Error for SqliteQueryError {}
+
 pub fn execute_statement(
 	db: *mut sqlite3,
 	sql: &str,
@@ -242,6 +300,84 @@ pub fn exec_statements(db: *mut sqlite3, sql: &str) -> Result<QueryResult> {
 	Ok(final_result)
 }
 
+/// Inserts `rows` into `table` with a single prepared statement inside a savepoint. Either every row
+/// is inserted or none are.
+pub fn insert_many(
+	db: *mut sqlite3,
+	table: &str,
+	columns: &[String],
+	rows: &[Vec<BindParam>],
+) -> Result<ExecResult> {
+	if columns.is_empty() {
+		return Err(anyhow!("sqlite insert_many requires at least one column"));
+	}
+	if let Some(row) = rows.iter().find(|row| row.len() != columns.len()) {
+		return Err(anyhow!(
+			"sqlite insert_many row has {} values but {} columns were given",
+			row.len(),
+			columns.len()
+		));
+	}
+	if rows.is_empty() {
+		return Ok(ExecResult { changes: 0 });
+	}
+
+	let c_sql = CString::new(depot_client_types::insert_sql(table, columns))
+		.map_err(|err| anyhow!(err.to_string()))?;
+
+	// A savepoint nests inside a caller's transaction and behaves like BEGIN outside of one
+	exec_statements(db, "SAVEPOINT insert_many;")?;
+
+	let mut stmt = ptr::null_mut();
+	let rc = unsafe { sqlite3_prepare_v2(db, c_sql.as_ptr(), -1, &mut stmt, ptr::null_mut()) };
+	let result = if rc != SQLITE_OK {
+		Err(sqlite_error(
+			db,
+			"failed to prepare sqlite insert statement",
+		))
+	} else {
+		let result = (|| {
+			let mut changes = 0;
+			for row in rows {
+				bind_params(db, stmt, row)?;
+				if unsafe { sqlite3_step(stmt) } != SQLITE_DONE {
+					return Err(sqlite_error(
+						db,
+						"failed to execute sqlite insert statement",
+					));
+				}
+				changes += unsafe { sqlite3_changes(db) as i64 };
+				unsafe {
+					sqlite3_reset(stmt);
+				}
+			}
+
+			Ok(ExecResult { changes })
+		})();
+
+		unsafe {
+			sqlite3_finalize(stmt);
+		}
+
+		result
+	};
+
+	match result {
+		Ok(result) => {
+			exec_statements(db, "RELEASE insert_many;")?;
+			Ok(result)
+		}
+		Err(err) => {
+			if let Err(rollback_err) =
+				exec_statements(db, "ROLLBACK TO insert_many; RELEASE insert_many;")
+			{
+				tracing::warn!(?rollback_err, "failed to roll back sqlite insert_many");
+			}
+			Err(err)
+		}
+	}
+}
+
 fn bind_params(
 	db: *mut sqlite3,
 	stmt: *mut libsqlite3_sys::sqlite3_stmt,
@@ -337,16 +473,19 @@ fn has_non_whitespace_tail(tail: *const c_char) -> bool {
 }
 
 fn sqlite_error(db: *mut sqlite3, context: &str) -> anyhow::Error {
-	let message = unsafe {
+	let (message, extended_code) = unsafe {
 		if db.is_null() {
-			"unknown sqlite error".to_string()
+			("unknown sqlite error".to_string(), 0)
 		} else {
-			CStr::from_ptr(sqlite3_errmsg(db))
-				.to_string_lossy()
-				.into_owned()
+			(
+				CStr::from_ptr(sqlite3_errmsg(db))
+					.to_string_lossy()
+					.into_owned(),
+				sqlite3_extended_errcode(db),
+			)
 		}
 	};
-	anyhow!("{context}: {message}")
+	SqliteQueryError::new(context, message, extended_code).into()
 }
 
 #[cfg(test)]
@@ -358,7 +497,11 @@ mod tests {
 
 	impl MemoryDb {
 		fn open() -> Self {
-			let name = CString::new(":memory:").unwrap();
+			Self::open_named(":memory:")
+		}
+
+		fn open_named(name: &str) -> Self {
+			let name = CString::new(name).unwrap();
 			let mut db = ptr::null_mut();
 			let rc = unsafe { sqlite3_open(name.as_ptr(), &mut db) };
 			assert_eq!(rc, SQLITE_OK);
@@ -537,4 +680,147 @@ mod tests {
 			"unexpected error: {err:#}"
 		);
 	}
+
+	#[test]
+	fn sqlite_errors_are_categorized() {
+		let db = MemoryDb::open();
+		exec_statements(
+			db.as_ptr(),
+			"CREATE TABLE unique_items(id INTEGER PRIMARY KEY, label TEXT UNIQUE); \
+			INSERT INTO unique_items(label) VALUES ('alpha');",
+		)
+		.unwrap();
+
+		let err = execute_statement(
+			db.as_ptr(),
+			"INSERT INTO unique_items(label) VALUES ('alpha');",
+			None,
+		)
+		.expect_err("duplicate insert should fail");
+		let err = err
+			.downcast_ref::<SqliteQueryError>()
+			.expect("expected a sqlite query error");
+		assert_eq!(err.category(), SqliteErrorCategory::Constraint);
+		assert_eq!(
+			err.extended_code(),
+			libsqlite3_sys::SQLITE_CONSTRAINT_UNIQUE
+		);
+
+		let err = execute_single_statement(db.as_ptr(), "SELECT FROM", None)
+			.expect_err("malformed execute should fail");
+		let err = err
+			.downcast_ref::<SqliteQueryError>()
+			.expect("expected a sqlite query error");
+		assert_eq!(err.category(), SqliteErrorCategory::Other);
+	}
+
+	#[test]
+	fn sqlite_busy_errors_are_categorized() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("busy.db");
+		let path = path.to_str().unwrap();
+
+		let holder = MemoryDb::open_named(path);
+		exec_statements(holder.as_ptr(), "CREATE TABLE items(id INTEGER);").unwrap();
+		exec_statements(holder.as_ptr(), "BEGIN EXCLUSIVE;").unwrap();
+
+		let other = MemoryDb::open_named(path);
+		let err = execute_statement(other.as_ptr(), "INSERT INTO items VALUES (1);", None)
+			.expect_err("write during exclusive transaction should fail");
+		let err = err
+			.downcast_ref::<SqliteQueryError>()
+			.expect("expected a sqlite query error");
+		assert_eq!(err.category(), SqliteErrorCategory::Busy);
+	}
+
+	#[test]
+	fn insert_many_inserts_all_rows() {
+		const ROWS: i64 = 100;
+
+		let db = MemoryDb::open();
+		exec_statements(
+			db.as_ptr(),
+			"CREATE TABLE bulk_items(id INTEGER PRIMARY KEY, label TEXT, score REAL);",
+		)
+		.unwrap();
+
+		let columns = vec!["id".to_owned(), "label".to_owned(), "score".to_owned()];
+		let rows = (0..ROWS)
+			.map(|i| {
+				vec![
+					BindParam::Integer(i),
+					BindParam::Text(format!("row-{i}")),
+					BindParam::Float(i as f64 + 0.5),
+				]
+			})
+			.collect::<Vec<_>>();
+		let result = insert_many(db.as_ptr(), "bulk_items", &columns, &rows).unwrap();
+
+		assert_eq!(result.changes, ROWS);
+		let count = query_statement(db.as_ptr(), "SELECT COUNT(*) FROM bulk_items;", None).unwrap();
+		assert_eq!(count.rows, vec![vec![ColumnValue::Integer(ROWS)]]);
+		let row = query_statement(
+			db.as_ptr(),
+			"SELECT label, score FROM bulk_items WHERE id = 42;",
+			None,
+		)
+		.unwrap();
+		assert_eq!(
+			row.rows,
+			vec![vec![
+				ColumnValue::Text("row-42".to_owned()),
+				ColumnValue::Float(42.5),
+			]]
+		);
+	}
+
+	#[test]
+	fn insert_many_rolls_back_on_failure() {
+		let db = MemoryDb::open();
+		exec_statements(
+			db.as_ptr(),
+			"CREATE TABLE unique_items(id INTEGER PRIMARY KEY, label TEXT UNIQUE);",
+		)
+		.unwrap();
+
+		let columns = vec!["label".to_owned()];
+		let err = insert_many(
+			db.as_ptr(),
+			"unique_items",
+			&columns,
+			&[
+				vec![BindParam::Text("alpha".to_owned())],
+				vec![BindParam::Text("beta".to_owned())],
+				vec![BindParam::Text("alpha".to_owned())],
+			],
+		)
+		.expect_err("duplicate label should fail");
+		assert_eq!(
+			err.downcast_ref::<SqliteQueryError>()
+				.map(SqliteQueryError::category),
+			Some(SqliteErrorCategory::Constraint)
+		);
+
+		let count =
+			query_statement(db.as_ptr(), "SELECT COUNT(*) FROM unique_items;", None).unwrap();
+		assert_eq!(count.rows, vec![vec![ColumnValue::Integer(0)]]);
+
+		// The savepoint is released, so the connection is back in autocommit mode
+		assert_ne!(
+			unsafe { libsqlite3_sys::sqlite3_get_autocommit(db.as_ptr()) },
+			0
+		);
+
+		let err = insert_many(
+			db.as_ptr(),
+			"unique_items",
+			&columns,
+			&[vec![BindParam::Text("alpha".to_owned()), BindParam::Null]],
+		)
+		.expect_err("mismatched row should fail");
+		assert!(
+			err.to_string().contains("1 columns were given"),
+			"unexpected error: {err:#}"
+		);
+	}
 }
@@ -152,6 +152,10 @@ pub struct VfsConfig {
 	pub recent_page_hints: bool,
 	pub adaptive_read_ahead: bool,
 	pub retain_read_cache: bool,
+	/// Enforce foreign key constraints. SQLite ignores them unless enabled per connection.
+	pub foreign_keys: bool,
+	/// Open connections with `SQLITE_OPEN_READONLY` so SQLite rejects every write.
+	pub readonly: bool,
 	#[cfg(test)]
 	pub assert_batch_atomic: bool,
 	#[cfg(test)]
@@ -208,12 +212,23 @@ impl VfsConfig {
 			recent_page_hints: flags.recent_page_hints,
 			adaptive_read_ahead: flags.adaptive_read_ahead,
 			retain_read_cache: flags.vfs_page_cache_mode.caches_any_pages(),
+			foreign_keys: true,
+			readonly: false,
 			#[cfg(test)]
 			assert_batch_atomic: true,
 			#[cfg(test)]
 			advertise_batch_atomic: true,
 		}
 	}
+
+	/// Flags passed to `sqlite3_open_v2` for connections using this config.
+	pub fn open_flags(&self) -> c_int {
+		if self.readonly {
+			SQLITE_OPEN_READONLY
+		} else {
+			SQLITE_OPEN_READWRITE | SQLITE_OPEN_CREATE
+		}
+	}
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -3137,6 +3152,10 @@ impl SqliteVfs {
 		self.ctx.sqlite_vfs_metrics()
 	}
 
+	pub(crate) fn open_flags(&self) -> c_int {
+		self.ctx.config.open_flags()
+	}
+
 	#[cfg(test)]
 	pub(crate) fn register_with_transport(
 		name: &str,
@@ -3369,12 +3388,8 @@ pub fn open_database(
 	vfs: SqliteVfs,
 	file_name: &str,
 ) -> std::result::Result<NativeDatabase, String> {
-	open_connection(
-		Arc::new(vfs),
-		file_name,
-		SQLITE_OPEN_READWRITE | SQLITE_OPEN_CREATE,
-	)
-	.and_then(|connection| {
+	let flags = vfs.open_flags();
+	open_connection(Arc::new(vfs), file_name, flags).and_then(|connection| {
 		configure_connection_for_database(connection.as_ptr(), &connection._vfs, file_name)?;
 		verify_batch_atomic_writes(connection.as_ptr(), &connection._vfs, file_name)?;
 		Ok(connection)
@@ -3427,6 +3442,11 @@ pub fn configure_connection_for_database(
 		"PRAGMA auto_vacuum = NONE;",
 		"PRAGMA locking_mode = EXCLUSIVE;",
 		cache_size_pragma.as_str(),
+		if vfs.ctx.config.foreign_keys {
+			"PRAGMA foreign_keys = ON;"
+		} else {
+			"PRAGMA foreign_keys = OFF;"
+		},
 	];
 	for pragma in &pragmas {
 		if let Err(err) = sqlite_exec(db, pragma) {
@@ -3453,7 +3473,8 @@ pub fn verify_batch_atomic_writes(
 	let assert_batch_atomic = vfs.ctx.config.assert_batch_atomic;
 	#[cfg(not(test))]
 	let assert_batch_atomic = true;
-	if assert_batch_atomic {
+	// The probe writes a table, which a read-only connection cannot do
+	if assert_batch_atomic && !vfs.ctx.config.readonly {
 		if let Err(err) = assert_batch_atomic_probe(db, &vfs) {
 			tracing::error!(
 				file_name,
@@ -11,12 +11,15 @@ use std::{
 
 use anyhow::{Context, Result, anyhow};
 use crossbeam_channel::{Receiver, Sender, TrySendError};
-use libsqlite3_sys::{SQLITE_OPEN_CREATE, SQLITE_OPEN_READWRITE, sqlite3_get_autocommit};
+use libsqlite3_sys::sqlite3_get_autocommit;
 use parking_lot::Mutex;
 use tokio::sync::{Notify, oneshot};
 
 use crate::{
-	query::{BindParam, ExecuteResult, QueryResult, exec_statements, execute_single_statement},
+	query::{
+		BindParam, ExecResult, ExecuteResult, QueryResult, exec_statements,
+		execute_single_statement, insert_many,
+	},
 	vfs::{
 		NativeConnection, NativeVfsHandle, SqliteRoundTripCounts, SqliteVfsMetrics,
 		configure_connection_for_database, open_connection, verify_batch_atomic_writes,
@@ -62,6 +65,12 @@ enum SqliteCommand {
 		sql: String,
 		reply: oneshot::Sender<Result<QueryResult>>,
 	},
+	InsertMany {
+		table: String,
+		columns: Vec<String>,
+		rows: Vec<Vec<BindParam>>,
+		reply: oneshot::Sender<Result<ExecResult>>,
+	},
 	#[cfg(test)]
 	Pause {
 		entered: oneshot::Sender<()>,
@@ -171,6 +180,22 @@ impl SqliteWorkerHandle {
 		result.await.map_err(|_| sqlite_worker_dead_error())?
 	}
 
+	pub async fn insert_many(
+		&self,
+		table: String,
+		columns: Vec<String>,
+		rows: Vec<Vec<BindParam>>,
+	) -> Result<ExecResult> {
+		let (reply, result) = oneshot::channel();
+		self.enqueue(SqliteCommand::InsertMany {
+			table,
+			columns,
+			rows,
+			reply,
+		})?;
+		result.await.map_err(|_| sqlite_worker_dead_error())?
+	}
+
 	pub async fn close(&self) -> Result<()> {
 		let start = Instant::now();
 		if self.inner.mark_closing() {
@@ -425,12 +450,8 @@ fn worker_main(mut ctx: WorkerContext) {
 }
 
 fn open_worker_connection(ctx: &WorkerContext) -> Result<NativeConnection> {
-	let connection = open_connection(
-		ctx.vfs.clone(),
-		&ctx.file_name,
-		SQLITE_OPEN_READWRITE | SQLITE_OPEN_CREATE,
-	)
-	.map_err(anyhow::Error::msg)?;
+	let connection = open_connection(ctx.vfs.clone(), &ctx.file_name, ctx.vfs.open_flags())
+		.map_err(anyhow::Error::msg)?;
 	configure_connection_for_database(connection.as_ptr(), &ctx.vfs, &ctx.file_name)
 		.map_err(anyhow::Error::msg)?;
 	verify_batch_atomic_writes(connection.as_ptr(), &ctx.vfs, &ctx.file_name)
@@ -481,6 +502,29 @@ fn run_command(
 			finalize_transaction_if_complete(db, metrics, file_name, transaction);
 			let _ = reply.send(result);
 		}
+		SqliteCommand::InsertMany {
+			table,
+			columns,
+			rows,
+			reply,
+		} => {
+			if reply.is_closed() {
+				return;
+			}
+			begin_transaction_if_needed(db, transaction);
+			let in_tx = command_in_tx(db);
+			let result = insert_many(db.as_ptr(), &table, &columns, &rows);
+			record_command_metrics(
+				metrics,
+				"insert_many",
+				in_tx,
+				"insert",
+				&result,
+				start.elapsed(),
+			);
+			finalize_transaction_if_complete(db, metrics, file_name, transaction);
+			let _ = reply.send(result);
+		}
 		#[cfg(test)]
 		SqliteCommand::Pause { entered, resume } => {
 			let _ = entered.send(());
@@ -662,6 +706,9 @@ fn fail_command(command: SqliteCommand) {
 		SqliteCommand::Exec { reply, .. } => {
 			let _ = reply.send(Err(sqlite_closing_error()));
 		}
+		SqliteCommand::InsertMany { reply, .. } => {
+			let _ = reply.send(Err(sqlite_closing_error()));
+		}
 		#[cfg(test)]
 		SqliteCommand::Pause { resume, .. } => {
 			drop(resume);
@@ -3735,6 +3735,102 @@ fn open_database_supports_explicit_status_insert_after_pragma_migration() {
 	.expect("explicit status insert should succeed");
 }
 
+const FOREIGN_KEY_SCHEMA: &str = "CREATE TABLE parents (id INTEGER PRIMARY KEY); \
+	CREATE TABLE children (id INTEGER PRIMARY KEY, parent_id INTEGER NOT NULL REFERENCES parents(id));";
+
+#[test]
+fn open_database_enforces_foreign_keys_by_default() {
+	let runtime = direct_runtime();
+	let harness = DirectEngineHarness::new();
+	let db = harness.open_db(&runtime);
+
+	assert_eq!(sqlite_query_i64(db.as_ptr(), "PRAGMA foreign_keys;"), Ok(1));
+	sqlite_exec(db.as_ptr(), FOREIGN_KEY_SCHEMA).expect("create tables should succeed");
+	sqlite_step_statement(db.as_ptr(), "INSERT INTO parents (id) VALUES (1);")
+		.expect("parent insert should succeed");
+	sqlite_step_statement(
+		db.as_ptr(),
+		"INSERT INTO children (id, parent_id) VALUES (1, 1);",
+	)
+	.expect("child with existing parent should insert");
+
+	let err = sqlite_step_statement(
+		db.as_ptr(),
+		"INSERT INTO children (id, parent_id) VALUES (2, 2);",
+	)
+	.expect_err("child with missing parent should be rejected");
+	assert!(
+		err.contains("FOREIGN KEY constraint failed"),
+		"unexpected error: {err}"
+	);
+}
+
+#[test]
+fn open_database_can_opt_out_of_foreign_keys() {
+	let runtime = direct_runtime();
+	let harness = DirectEngineHarness::new();
+	let engine = runtime.block_on(harness.open_engine());
+	let db = harness.open_db_on_engine(
+		&runtime,
+		engine,
+		&harness.actor_id,
+		VfsConfig {
+			foreign_keys: false,
+			..VfsConfig::default()
+		},
+	);
+
+	assert_eq!(sqlite_query_i64(db.as_ptr(), "PRAGMA foreign_keys;"), Ok(0));
+	sqlite_exec(db.as_ptr(), FOREIGN_KEY_SCHEMA).expect("create tables should succeed");
+	sqlite_step_statement(
+		db.as_ptr(),
+		"INSERT INTO children (id, parent_id) VALUES (1, 2);",
+	)
+	.expect("foreign keys should not be enforced");
+}
+
+#[test]
+fn open_database_readonly_rejects_writes() {
+	let runtime = direct_runtime();
+	let harness = DirectEngineHarness::new();
+	let engine = runtime.block_on(harness.open_engine());
+	let actor_id = harness.actor_id.clone();
+
+	let db = harness.open_db_on_engine(&runtime, engine.clone(), &actor_id, VfsConfig::default());
+	sqlite_exec(
+		db.as_ptr(),
+		"CREATE TABLE items (id INTEGER PRIMARY KEY, label TEXT NOT NULL); \
+		INSERT INTO items (id, label) VALUES (1, 'alpha');",
+	)
+	.expect("seed should succeed");
+	drop(db);
+
+	let readonly = harness.open_db_on_engine(
+		&runtime,
+		engine,
+		&actor_id,
+		VfsConfig {
+			readonly: true,
+			..VfsConfig::default()
+		},
+	);
+	assert_eq!(
+		sqlite_query_text(readonly.as_ptr(), "SELECT label FROM items WHERE id = 1;"),
+		Ok("alpha".to_string())
+	);
+
+	let err = sqlite_step_statement(
+		readonly.as_ptr(),
+		"INSERT INTO items (id, label) VALUES (2, 'beta');",
+	)
+	.expect_err("insert on a read-only database should fail");
+	assert!(err.contains("readonly database"), "unexpected error: {err}");
+	assert_eq!(
+		sqlite_query_i64(readonly.as_ptr(), "SELECT COUNT(*) FROM items;"),
+		Ok(1)
+	);
+}
+
 #[test]
 fn open_database_supports_hot_row_update_churn() {
 	let runtime = direct_runtime();
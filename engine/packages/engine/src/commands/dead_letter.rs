@@ -0,0 +1,94 @@
+use anyhow::{Result, ensure};
+use clap::Parser;
+use gas::prelude::*;
+
+#[derive(Parser)]
+pub enum SubCommand {
+	/// Lists webhook notifications of a namespace that could not be delivered, newest first.
+	List {
+		#[clap(long)]
+		namespace_id: Id,
+		#[clap(short = 'l', long, default_value_t = 100)]
+		limit: usize,
+	},
+	/// Redelivers dead-lettered webhook notifications to the url they were originally sent to.
+	Replay {
+		#[clap(long)]
+		namespace_id: Id,
+		/// Notifications to replay.
+		notification_ids: Vec<Id>,
+		/// Replays every dead letter of the namespace instead of the given notifications.
+		#[clap(short = 'a', long)]
+		all: bool,
+		/// How many of the newest dead letters to scan.
+		#[clap(short = 'l', long, default_value_t = 1000)]
+		limit: usize,
+	},
+}
+
+impl SubCommand {
+	pub async fn execute(self, config: rivet_config::Config) -> Result<()> {
+		let pools = rivet_pools::Pools::new(config.clone()).await?;
+		let cache = rivet_cache::CacheInner::from_env(&config, pools.clone())?;
+		let ctx = StandaloneCtx::new(
+			db::DatabaseKv::new(config.clone(), pools.clone()).await?,
+			config.clone(),
+			pools,
+			cache,
+			"dead_letter_cli",
+			Id::new_v1(config.dc_label()),
+			Id::new_v1(config.dc_label()),
+		)?;
+
+		match self {
+			Self::List {
+				namespace_id,
+				limit,
+			} => {
+				let dead_letters = ctx
+					.op(pegboard::ops::webhook::list_dead_letters::Input {
+						namespace_id,
+						limit,
+					})
+					.await?;
+
+				println!("{}", serde_json::to_string_pretty(&dead_letters)?);
+
+				Ok(())
+			}
+			Self::Replay {
+				namespace_id,
+				notification_ids,
+				all,
+				limit,
+			} => {
+				if all {
+					ensure!(
+						notification_ids.is_empty(),
+						"--all cannot be combined with notification ids"
+					);
+				} else {
+					ensure!(
+						!notification_ids.is_empty(),
+						"provide notification ids to replay or --all"
+					);
+				}
+
+				let replayed = ctx
+					.op(pegboard::ops::webhook::replay_dead_letters::Input {
+						namespace_id,
+						notification_ids,
+						limit,
+					})
+					.await?;
+
+				for notification_id in &replayed {
+					println!("replayed {notification_id}");
+				}
+				println!("replayed {} dead letters", replayed.len());
+
+				Ok(())
+			}
+		}
+	}
+}
@@ -1,5 +1,6 @@
 pub mod config;
 pub mod db;
+pub mod dead_letter;
 pub mod depot;
 pub mod epoxy;
 pub mod profile;
@@ -1,16 +1,17 @@
-use std::sync::Arc;
+use std::{path::PathBuf, sync::Arc};
 
-use anyhow::{Result, ensure};
+use anyhow::{Result, bail, ensure};
 use clap::{Parser, ValueEnum};
 use gas::db::{
 	self, Database,
-	debug::{DatabaseDebug, WorkflowState as DebugWorkflowState},
+	debug::{DatabaseDebug, WorkflowExport, WorkflowState as DebugWorkflowState},
 };
 use rivet_util::Id;
 
 use crate::util::{self, wf::KvPair};
 
 mod signal;
+mod step;
 
 #[derive(Parser)]
 pub enum SubCommand {
@@ -30,6 +31,17 @@ pub enum SubCommand {
 	},
 	/// Silences a workflow from showing up as dead or running again.
 	Silence { workflow_ids: Vec<Id> },
+	/// Silences dead workflows that match the name and before filter.
+	SilenceDead {
+		#[clap(short = 'n', long)]
+		name: Vec<String>,
+		#[clap(short = 'b', long)]
+		before: chrono::DateTime<chrono::Utc>,
+		#[clap(short = 'd', long)]
+		dry_run: bool,
+		#[clap(short = 'p', long)]
+		parallelization: Option<u16>,
+	},
 	/// Sets the wake immediate property of a workflow to true.
 	Wake { workflow_ids: Vec<Id> },
 	/// Wakes dead workflows that match the name and error queries.
@@ -72,10 +84,46 @@ pub enum SubCommand {
 		#[clap(short = 't', action = clap::ArgAction::Count, long)]
 		print_ts: u8,
 	},
+	/// Scans the history of a workflow and reports corruption (missing event data, out of order indexes,
+	/// orphaned chunks) without failing on the first problem.
+	CheckHistory {
+		#[clap(index = 1)]
+		workflow_id: Id,
+	},
+	/// Prints the sub workflows dispatched by a workflow.
+	Children {
+		#[clap(index = 1)]
+		workflow_id: Id,
+		/// Prints paragraphs instead of a table.
+		#[clap(long, short = 'p')]
+		pretty: bool,
+	},
+	/// Prints the workflow that dispatched the given sub workflow.
+	Parent {
+		#[clap(index = 1)]
+		workflow_id: Id,
+	},
+	/// Prints a JSON export of a workflow's data, history, pending signals and wake conditions.
+	Export {
+		#[clap(index = 1)]
+		workflow_id: Id,
+	},
+	/// Replays a workflow export (see `export`) against the current workflow code without side effects and
+	/// reports whether the recorded history is still compatible.
+	Replay {
+		/// Path to the JSON file written by `export`.
+		#[clap(index = 1)]
+		path: PathBuf,
+	},
 	Signal {
 		#[clap(subcommand)]
 		command: signal::SubCommand,
 	},
+	/// Steps through a workflow's execution one activity or signal listen at a time.
+	Step {
+		#[clap(subcommand)]
+		command: step::SubCommand,
+	},
 	/// Prints the current workflow registry
 	Registry {},
 }
@@ -83,7 +131,8 @@ pub enum SubCommand {
 impl SubCommand {
 	pub async fn execute(self, config: rivet_config::Config) -> Result<()> {
 		let pools = rivet_pools::Pools::new(config.clone()).await?;
-		let db = db::DatabaseKv::new(config.clone(), pools).await? as Arc<dyn DatabaseDebug>;
+		let db =
+			db::DatabaseKv::new(config.clone(), pools.clone()).await? as Arc<dyn DatabaseDebug>;
 
 		match self {
 			Self::Get { workflow_ids } => {
@@ -109,6 +158,34 @@ impl SubCommand {
 				util::wf::print_workflows(workflows, pretty).await
 			}
 			Self::Silence { workflow_ids } => db.silence_workflows(workflow_ids).await,
+			Self::SilenceDead {
+				name,
+				before,
+				dry_run,
+				parallelization,
+			} => {
+				ensure!(!name.is_empty(), "must provide at least one name");
+
+				let parallelization =
+					parallelization.unwrap_or(config.runtime.gasoline_debug_parallelization());
+
+				let total = db
+					.silence_workflows_by_filter(
+						&name.iter().map(|x| x.as_str()).collect::<Vec<_>>(),
+						before.timestamp_millis(),
+						dry_run,
+						parallelization,
+					)
+					.await?;
+
+				if dry_run {
+					rivet_term::status::success("Workflows Matched", total);
+				} else {
+					rivet_term::status::success("Workflows Silenced", total);
+				}
+
+				Ok(())
+			}
 			Self::Wake { workflow_ids } => db.wake_workflows(workflow_ids).await,
 			Self::Revive {
 				name,
@@ -118,12 +195,15 @@ impl SubCommand {
 			} => {
 				ensure!(!name.is_empty(), "must provide at least one name");
 
+				let parallelization =
+					parallelization.unwrap_or(config.runtime.gasoline_debug_parallelization());
+
 				let total = db
 					.revive_workflows(
 						&name.iter().map(|x| x.as_str()).collect::<Vec<_>>(),
 						&error.iter().map(|x| x.as_str()).collect::<Vec<_>>(),
 						dry_run,
-						parallelization.unwrap_or(1),
+						parallelization,
 					)
 					.await?;
 
@@ -141,12 +221,15 @@ impl SubCommand {
 				dry_run,
 				parallelization,
 			} => {
+				let parallelization =
+					parallelization.unwrap_or(config.runtime.gasoline_debug_parallelization());
+
 				let total = db
 					.prune_complete_workflow_history(
 						&name.iter().map(|x| x.as_str()).collect::<Vec<_>>(),
 						before.timestamp_millis(),
 						dry_run,
-						parallelization.unwrap_or(1),
+						parallelization,
 					)
 					.await?;
 
@@ -170,7 +253,62 @@ impl SubCommand {
 					.await?;
 				util::wf::print_history(history, exclude_json, print_location, print_ts).await
 			}
-			Self::Signal { command } => command.execute(db).await,
+			Self::CheckHistory { workflow_id } => {
+				let issues = db.check_workflow_history(workflow_id).await?;
+				util::wf::print_history_issues(issues);
+
+				Ok(())
+			}
+			Self::Children {
+				workflow_id,
+				pretty,
+			} => {
+				let child_workflow_ids = db.get_workflow_children(workflow_id).await?;
+				let workflows = DatabaseDebug::get_workflows(&*db, child_workflow_ids).await?;
+				util::wf::print_workflows(workflows, pretty).await
+			}
+			Self::Parent { workflow_id } => {
+				let Some(parent_workflow_id) = db.get_workflow_parent(workflow_id).await? else {
+					bail!("workflow {workflow_id} has no parent");
+				};
+
+				let workflows =
+					DatabaseDebug::get_workflows(&*db, vec![parent_workflow_id]).await?;
+				util::wf::print_workflows(workflows, true).await
+			}
+			Self::Export { workflow_id } => {
+				let Some(export) = db.export_workflow(workflow_id).await? else {
+					bail!("workflow {workflow_id} not found");
+				};
+
+				println!("{}", serde_json::to_string_pretty(&export)?);
+
+				Ok(())
+			}
+			Self::Replay { path } => {
+				let export =
+					serde_json::from_slice::<WorkflowExport>(&tokio::fs::read(&path).await?)?;
+
+				let cache = rivet_cache::CacheInner::from_env(&config, pools.clone())?;
+				let db =
+					db::DatabaseKv::new(config.clone(), pools.clone()).await? as db::DatabaseHandle;
+
+				let res = gas::sandbox::replay_workflow(
+					rivet_workflow_worker::registry()?.handle(),
+					db,
+					config,
+					pools,
+					cache,
+					&export,
+				)
+				.await?;
+
+				println!("{}", serde_json::to_string_pretty(&res)?);
+
+				Ok(())
+			}
+			Self::Signal { command } => command.execute(&config, db).await,
+			Self::Step { command } => command.execute(db).await,
 			Self::Registry {} => {
 				let reg = rivet_workflow_worker::registry()?;
 				let mut names = reg.names();
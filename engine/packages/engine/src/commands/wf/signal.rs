@@ -43,7 +43,11 @@ pub enum SubCommand {
 }
 
 impl SubCommand {
-	pub async fn execute(self, db: Arc<dyn DatabaseDebug>) -> Result<()> {
+	pub async fn execute(
+		self,
+		config: &rivet_config::Config,
+		db: Arc<dyn DatabaseDebug>,
+	) -> Result<()> {
 		match self {
 			Self::Get { signal_ids } => {
 				let signals = db.get_signals(signal_ids).await?;
@@ -77,12 +81,15 @@ impl SubCommand {
 				parallelization,
 				max_per_txn,
 			} => {
+				let parallelization =
+					parallelization.unwrap_or(config.runtime.gasoline_debug_parallelization());
+
 				let total = db
 					.prune_acked_signals(
 						&name.iter().map(|x| x.as_str()).collect::<Vec<_>>(),
 						before.timestamp_millis(),
 						dry_run,
-						parallelization.unwrap_or(1),
+						parallelization,
 						max_per_txn,
 					)
 					.await?;
@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use anyhow::*;
+use clap::Parser;
+use gas::db::debug::DatabaseDebug;
+use rivet_util::Id;
+
+#[derive(Parser)]
+pub enum SubCommand {
+	/// Pauses the workflow before every activity and signal listen. Requires `gasoline_step_mode` in the
+	/// runtime config.
+	Enable { workflow_id: Id },
+	/// Disables step mode, releasing the workflow if it is paused.
+	Disable { workflow_id: Id },
+	/// Continues a paused workflow until its next pause point.
+	Continue { workflow_id: Id },
+	/// Prints the current pause point of a workflow in step mode.
+	Get { workflow_id: Id },
+}
+
+impl SubCommand {
+	pub async fn execute(self, db: Arc<dyn DatabaseDebug>) -> Result<()> {
+		match self {
+			Self::Enable { workflow_id } => db.set_workflow_step_mode(workflow_id, true).await,
+			Self::Disable { workflow_id } => db.set_workflow_step_mode(workflow_id, false).await,
+			Self::Continue { workflow_id } => {
+				if db.continue_workflow_step(workflow_id).await? {
+					rivet_term::status::success("Continued", workflow_id);
+				} else {
+					rivet_term::status::warn("Workflow not paused", workflow_id);
+				}
+
+				Ok(())
+			}
+			Self::Get { workflow_id } => {
+				let Some(step_state) = db.get_workflow_step(workflow_id).await? else {
+					bail!("step mode not enabled for workflow {workflow_id}");
+				};
+
+				println!("{}", serde_json::to_string_pretty(&step_state)?);
+
+				Ok(())
+			}
+		}
+	}
+}
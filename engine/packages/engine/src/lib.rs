@@ -44,6 +44,12 @@ pub enum SubCommand {
 		#[clap(subcommand)]
 		command: epoxy::SubCommand,
 	},
+	/// Inspects and replays undelivered webhook notifications
+	#[clap(alias = "dl")]
+	DeadLetter {
+		#[clap(subcommand)]
+		command: dead_letter::SubCommand,
+	},
 	/// Depot SQLite diagnostic commands
 	Depot {
 		#[clap(subcommand)]
@@ -63,6 +69,7 @@ impl SubCommand {
 			SubCommand::Tracing { command } => command.execute(config).await,
 			SubCommand::Profile { command } => command.execute(config).await,
 			SubCommand::Epoxy { command } => command.execute(config).await,
+			SubCommand::DeadLetter { command } => command.execute(config).await,
 			SubCommand::Depot { command } => command.execute(config).await,
 			SubCommand::Udb(opts) => opts.execute(config).await,
 		}
@@ -1,3 +1,5 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
 use anyhow::*;
 use rivet_service_manager::{RunConfigData, Service, ServiceKind};
 
@@ -8,13 +10,21 @@ pub fn config(_rivet_config: rivet_config::Config) -> Result<RunConfigData> {
 			ServiceKind::ApiPeer,
 			|config, pools| Box::pin(rivet_api_peer::start(config, pools)),
 			false,
-		),
+		)
+		.with_ready_check(|config, _pools| async move {
+			port_ready(config.api_peer().host(), config.api_peer().port()).await
+		}),
 		Service::new(
 			"guard",
 			ServiceKind::ApiPublic,
 			|config, pools| Box::pin(rivet_guard::start(config, pools)),
 			true,
-		),
+		)
+		// Guard proxies API requests to api-peer
+		.with_depends_on(["api_peer"])
+		.with_ready_check(|config, _pools| async move {
+			port_ready(config.guard().host(), config.guard().port()).await
+		}),
 		Service::new(
 			"workflow_worker",
 			ServiceKind::Standalone,
@@ -26,7 +36,9 @@ pub fn config(_rivet_config: rivet_config::Config) -> Result<RunConfigData> {
 			ServiceKind::Standalone,
 			|config, pools| Box::pin(pegboard_outbound::start(config, pools)),
 			true,
-		),
+		)
+		// Serverless runners started by outbound connect back through guard
+		.with_depends_on(["guard"]),
 		Service::new(
 			"bootstrap",
 			ServiceKind::Oneshot,
@@ -62,3 +74,19 @@ pub fn config(_rivet_config: rivet_config::Config) -> Result<RunConfigData> {
 
 	Ok(RunConfigData { services })
 }
+
+/// Ready once the service accepts connections on its port.
+async fn port_ready(host: IpAddr, port: u16) -> Result<()> {
+	// Services listening on all interfaces are reachable on loopback
+	let host = match host {
+		IpAddr::V4(host) if host.is_unspecified() => IpAddr::V4(Ipv4Addr::LOCALHOST),
+		IpAddr::V6(host) if host.is_unspecified() => IpAddr::V6(Ipv6Addr::LOCALHOST),
+		host => host,
+	};
+
+	tokio::net::TcpStream::connect((host, port))
+		.await
+		.with_context(|| format!("port {port} not open"))?;
+
+	Ok(())
+}
@@ -1,6 +1,6 @@
 use anyhow::*;
 use chrono::{TimeZone, Utc};
-use gas::db::debug::{Event, EventData, HistoryData, WorkflowState};
+use gas::db::debug::{Event, EventData, HistoryData, HistoryIssue, WorkflowState};
 use gas::history::event::SleepState;
 use rivet_term::console::{Style, style};
 
@@ -113,6 +113,25 @@ pub async fn print_workflows(
 	Ok(())
 }
 
+pub fn print_history_issues(issues: Vec<HistoryIssue>) {
+	if issues.is_empty() {
+		rivet_term::status::success("No history issues found", "");
+		return;
+	}
+
+	rivet_term::status::warn("History issues", issues.len());
+
+	for issue in issues {
+		print!("  {} ", style(issue.location).dim());
+
+		if issue.forgotten {
+			print!("{}", style("forgotten ").red().dim())
+		}
+
+		println!("{}", issue.kind);
+	}
+}
+
 pub async fn print_history(
 	history: Option<HistoryData>,
 	exclude_json: bool,
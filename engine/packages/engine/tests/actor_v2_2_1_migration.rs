@@ -36,7 +36,10 @@ async fn actor_v2_2_1_baseline_migrates_to_current_layout() -> Result<()> {
 			name: ACTOR_NAME.to_string(),
 			key: None,
 			include_destroyed: true,
+			state: None,
 			created_before: None,
+			created_after: None,
+			cursor: None,
 			limit: 1,
 			fetch_error: false,
 		})
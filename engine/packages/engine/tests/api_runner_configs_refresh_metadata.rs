@@ -97,6 +97,9 @@ async fn setup_test_namespace(leader_dc: &ctx::TestDatacenter) -> (String, rivet
 		rivet_api_peer::namespaces::CreateRequest {
 			name: namespace_name,
 			display_name: "Test Namespace".to_string(),
+			default_crash_policy: None,
+			cors_policy: None,
+			actor_rate_limit: None,
 		},
 	)
 	.await
@@ -223,7 +226,10 @@ fn refresh_metadata_invalidates_protocol_cache_before_v2_dispatch() {
 					key: Some(format!("key-{}", rand::random::<u64>())),
 					input: None,
 					runner_name_selector: runner_name.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
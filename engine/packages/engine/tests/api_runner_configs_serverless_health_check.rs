@@ -75,6 +75,9 @@ async fn setup_test_namespace(leader_dc: &ctx::TestDatacenter) -> (String, rivet
 		rivet_api_peer::namespaces::CreateRequest {
 			name: namespace_name,
 			display_name: "Test Namespace".to_string(),
+			default_crash_policy: None,
+			cors_policy: None,
+			actor_rate_limit: None,
 		},
 	)
 	.await
@@ -107,7 +110,7 @@ fn serverless_health_check_returns_invalid_response_json_for_malformed_body() {
 		handle.abort();
 
 		match response {
-			api::public::ServerlessHealthCheckResponse::Failure { error } => {
+			api::public::ServerlessHealthCheckResponse::Failure { error, .. } => {
 				assert_eq!(
 					error
 						.metadata
@@ -161,7 +164,7 @@ fn serverless_health_check_surfaces_invalid_envoy_protocol_version_in_metadata()
 		handle.abort();
 
 		match response {
-			api::public::ServerlessHealthCheckResponse::Failure { error } => {
+			api::public::ServerlessHealthCheckResponse::Failure { error, .. } => {
 				assert_eq!(
 					error
 						.metadata
@@ -189,3 +192,99 @@ fn serverless_health_check_surfaces_invalid_envoy_protocol_version_in_metadata()
 		}
 	});
 }
+
+#[test]
+fn serverless_health_check_categorizes_unauthorized_as_auth() {
+	run(ctx::TestOpts::new(1), |ctx| async move {
+		let (namespace, _) = setup_test_namespace(ctx.leader_dc()).await;
+		let (port, handle) =
+			spawn_mock_metadata_server(StatusCode::UNAUTHORIZED, r#"{"error":"unauthorized"}"#)
+				.await;
+
+		let response = api::public::runner_configs_serverless_health_check(
+			ctx.leader_dc().guard_port(),
+			api::public::ServerlessHealthCheckQuery {
+				namespace: namespace.clone(),
+			},
+			api::public::ServerlessHealthCheckRequest {
+				url: format!("http://127.0.0.1:{port}"),
+				headers: Default::default(),
+			},
+		)
+		.await
+		.expect("health check request failed");
+
+		handle.abort();
+
+		match response {
+			api::public::ServerlessHealthCheckResponse::Failure { error, .. } => {
+				assert_eq!(
+					error
+						.metadata
+						.get("category")
+						.and_then(serde_json::Value::as_str),
+					Some("auth"),
+				);
+				assert_eq!(
+					error
+						.metadata
+						.get("status_code")
+						.and_then(serde_json::Value::as_u64),
+					Some(401),
+				);
+			}
+			other => panic!("expected failure response, got {other:?}"),
+		}
+	});
+}
+
+#[test]
+fn serverless_health_check_reports_connection_failure_for_unreachable_url() {
+	run(ctx::TestOpts::new(1), |ctx| async move {
+		let (namespace, _) = setup_test_namespace(ctx.leader_dc()).await;
+		// Nothing is listening on this port
+		let port = portpicker::pick_unused_port().expect("failed to pick port");
+
+		let response = api::public::runner_configs_serverless_health_check(
+			ctx.leader_dc().guard_port(),
+			api::public::ServerlessHealthCheckQuery {
+				namespace: namespace.clone(),
+			},
+			api::public::ServerlessHealthCheckRequest {
+				url: format!("http://127.0.0.1:{port}"),
+				headers: Default::default(),
+			},
+		)
+		.await
+		.expect("health check request failed");
+
+		match response {
+			api::public::ServerlessHealthCheckResponse::Failure { error, latency_ms } => {
+				assert_eq!(
+					error
+						.metadata
+						.get("kind")
+						.and_then(serde_json::Value::as_str),
+					Some("request_failed"),
+				);
+				assert_eq!(
+					error
+						.metadata
+						.get("category")
+						.and_then(serde_json::Value::as_str),
+					Some("connection"),
+				);
+				let details = error.details.expect("failure should include details");
+				assert!(
+					details.to_ascii_lowercase().contains("connect"),
+					"details should describe the connection failure, got {details:?}",
+				);
+				assert!(
+					latency_ms < 10_000,
+					"probe should fail fast, took {latency_ms}ms"
+				);
+			}
+			other => panic!("expected failure response, got {other:?}"),
+		}
+	});
+}
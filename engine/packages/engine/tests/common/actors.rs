@@ -50,7 +50,11 @@ pub async fn try_get_actor(
 			namespace: namespace.to_string(),
 			name: None,
 			key: None,
+			tag: Vec::new(),
 			include_destroyed: Some(true),
+			state: None,
+			created_before: None,
+			created_after: None,
 			limit: None,
 			cursor: None,
 		},
@@ -120,7 +124,10 @@ pub async fn bulk_create_actors(
 				key: Some(generate_unique_key()),
 				input: None,
 				runner_name_selector: TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+				affinity: None,
+				required_capabilities: Vec::new(),
+				tags: Default::default(),
 			},
 		)
 		.await
@@ -362,3 +362,25 @@ pub async fn set_tracing_config(
 	let response = req.send().await?;
 	parse_response(response).await
 }
+
+pub async fn build_set_serverless_autoscaler_paused_request(
+	port: u16,
+	request: rivet_api_peer::internal::SetServerlessAutoscalerPausedRequest,
+) -> Result<reqwest::RequestBuilder> {
+	let client = rivet_pools::reqwest::client().await?;
+	Ok(client
+		.put(format!(
+			"{}/serverless/autoscaler/paused",
+			get_endpoint(port)
+		))
+		.json(&request))
+}
+
+pub async fn set_serverless_autoscaler_paused(
+	port: u16,
+	request: rivet_api_peer::internal::SetServerlessAutoscalerPausedRequest,
+) -> Result<rivet_api_peer::internal::SetServerlessAutoscalerPausedResponse> {
+	let req = build_set_serverless_autoscaler_paused_request(port, request).await?;
+	let response = req.send().await?;
+	parse_response(response).await
+}
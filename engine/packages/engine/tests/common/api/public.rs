@@ -85,6 +85,31 @@ pub async fn namespaces_create(
 	parse_response(response).await
 }
 
+pub async fn build_namespaces_update_request(
+	port: u16,
+	path: rivet_api_peer::namespaces::UpdatePath,
+	request: rivet_api_peer::namespaces::UpdateRequest,
+) -> Result<reqwest::RequestBuilder> {
+	let client = rivet_pools::reqwest::client().await?;
+	Ok(client
+		.put(format!(
+			"{}/namespaces/{}",
+			get_endpoint(port),
+			path.namespace
+		))
+		.json(&request))
+}
+
+pub async fn namespaces_update(
+	port: u16,
+	path: rivet_api_peer::namespaces::UpdatePath,
+	request: rivet_api_peer::namespaces::UpdateRequest,
+) -> Result<rivet_api_peer::namespaces::UpdateResponse> {
+	let req = build_namespaces_update_request(port, path, request).await?;
+	let response = req.send().await?;
+	parse_response(response).await
+}
+
 // MARK: Runner Configs
 
 pub async fn build_runner_configs_list_request(
@@ -123,8 +148,14 @@ pub struct ServerlessHealthCheckRequest {
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ServerlessHealthCheckResponse {
-	Success { version: String },
-	Failure { error: ServerlessMetadataError },
+	Success {
+		version: String,
+		latency_ms: u64,
+	},
+	Failure {
+		error: ServerlessMetadataError,
+		latency_ms: u64,
+	},
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -301,6 +332,56 @@ pub async fn actors_create(
 	parse_response(response).await
 }
 
+pub async fn build_actors_create_batch_request(
+	port: u16,
+	query: actors::create_batch::CreateBatchQuery,
+	request: actors::create_batch::CreateBatchRequest,
+) -> Result<reqwest::RequestBuilder> {
+	let client = rivet_pools::reqwest::client().await?;
+	Ok(client
+		.post(format!(
+			"{}/actors/batch?{}",
+			get_endpoint(port),
+			serde_html_form::to_string(&query)?
+		))
+		.json(&request))
+}
+
+pub async fn actors_create_batch(
+	port: u16,
+	query: actors::create_batch::CreateBatchQuery,
+	request: actors::create_batch::CreateBatchRequest,
+) -> Result<actors::create_batch::CreateBatchResponse> {
+	let req = build_actors_create_batch_request(port, query, request).await?;
+	let response = req.send().await?;
+	parse_response(response).await
+}
+
+pub async fn build_actors_destroy_batch_request(
+	port: u16,
+	query: actors::destroy_batch::DestroyBatchQuery,
+	request: actors::destroy_batch::DestroyBatchRequest,
+) -> Result<reqwest::RequestBuilder> {
+	let client = rivet_pools::reqwest::client().await?;
+	Ok(client
+		.post(format!(
+			"{}/actors/destroy-batch?{}",
+			get_endpoint(port),
+			serde_html_form::to_string(&query)?
+		))
+		.json(&request))
+}
+
+pub async fn actors_destroy_batch(
+	port: u16,
+	query: actors::destroy_batch::DestroyBatchQuery,
+	request: actors::destroy_batch::DestroyBatchRequest,
+) -> Result<actors::destroy_batch::DestroyBatchResponse> {
+	let req = build_actors_destroy_batch_request(port, query, request).await?;
+	let response = req.send().await?;
+	parse_response(response).await
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct GetOrCreateQuery {
 	pub namespace: String,
@@ -313,7 +394,7 @@ pub struct GetOrCreateRequest {
 	pub key: String,
 	pub input: Option<String>,
 	pub runner_name_selector: String,
-	pub crash_policy: rivet_types::actors::CrashPolicy,
+	pub crash_policy: Option<rivet_types::actors::CrashPolicy>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -392,6 +473,66 @@ pub async fn actors_list_names(
 	parse_response(response).await
 }
 
+pub async fn build_actors_request_request(
+	port: u16,
+	path: actors::request::RequestPath,
+	query: actors::request::RequestQuery,
+	body: Vec<u8>,
+) -> Result<reqwest::RequestBuilder> {
+	let client = rivet_pools::reqwest::client().await?;
+	Ok(client
+		.post(format!(
+			"{}/actors/by-key/{}/{}/request?{}",
+			get_endpoint(port),
+			urlencoding::encode(&path.name),
+			urlencoding::encode(&path.key),
+			serde_html_form::to_string(&query)?
+		))
+		.body(body))
+}
+
+/// Returns the raw response since the body is whatever the actor responded with.
+pub async fn actors_request(
+	port: u16,
+	path: actors::request::RequestPath,
+	query: actors::request::RequestQuery,
+	body: Vec<u8>,
+) -> Result<reqwest::Response> {
+	let request = build_actors_request_request(port, path, query, body).await?;
+	let response = request.send().await?;
+	if !response.status().is_success() {
+		let status = response.status();
+		let text = response.text().await?;
+		bail!("request failed with status {}: {}", status, text);
+	}
+
+	Ok(response)
+}
+
+pub async fn build_actors_logs_request(
+	port: u16,
+	path: actors::logs::LogsPath,
+	query: actors::logs::LogsQuery,
+) -> Result<reqwest::RequestBuilder> {
+	let client = rivet_pools::reqwest::client().await?;
+	Ok(client.get(format!(
+		"{}/actors/{}/logs?{}",
+		get_endpoint(port),
+		path.actor_id,
+		serde_html_form::to_string(&query)?
+	)))
+}
+
+pub async fn actors_logs(
+	port: u16,
+	path: actors::logs::LogsPath,
+	query: actors::logs::LogsQuery,
+) -> Result<actors::logs::LogsResponse> {
+	let request = build_actors_logs_request(port, path, query).await?;
+	let response = request.send().await?;
+	parse_response(response).await
+}
+
 // MARK: Runners
 
 pub async fn build_runners_list_request(
@@ -1,7 +1,7 @@
 use anyhow::*;
 use gas::prelude::*;
 use rivet_service_manager::{Service, ServiceKind};
-use std::time::Duration;
+use std::{collections::HashMap, time::Duration};
 
 pub struct TestOpts {
 	pub datacenters: usize,
@@ -9,6 +9,16 @@ pub struct TestOpts {
 	pub pegboard_outbound: bool,
 	pub auth_admin_token: Option<String>,
 	pub network_faults: bool,
+	pub metrics_aggregator_interval: Option<u64>,
+	pub request_coalescing_actor_names: Option<Vec<String>>,
+	pub request_replay_max_attempts: Option<u32>,
+	pub max_batch_size: Option<usize>,
+	pub gateway_request_body_chunk_size: Option<usize>,
+	pub gateway_response_body_idle_timeout_ms: Option<u64>,
+	pub gateway_actor_max_websockets: Option<usize>,
+	pub runner_reconnect_grace_period: Option<i64>,
+	pub runner_tokens: Option<HashMap<String, HashMap<String, String>>>,
+	pub serverless_circuit_breaker: Option<(u32, i64)>,
 }
 
 impl TestOpts {
@@ -19,6 +29,16 @@ impl TestOpts {
 			pegboard_outbound: false,
 			auth_admin_token: None,
 			network_faults: false,
+			metrics_aggregator_interval: None,
+			request_coalescing_actor_names: None,
+			request_replay_max_attempts: None,
+			max_batch_size: None,
+			gateway_request_body_chunk_size: None,
+			gateway_response_body_idle_timeout_ms: None,
+			gateway_actor_max_websockets: None,
+			runner_reconnect_grace_period: None,
+			runner_tokens: None,
+			serverless_circuit_breaker: None,
 		}
 	}
 
@@ -41,6 +61,68 @@ impl TestOpts {
 		self.network_faults = true;
 		self
 	}
+
+	pub fn with_metrics_aggregator_interval(mut self, interval_ms: u64) -> Self {
+		self.metrics_aggregator_interval = Some(interval_ms);
+		self
+	}
+
+	pub fn with_request_coalescing(mut self, actor_name: impl Into<String>) -> Self {
+		self.request_coalescing_actor_names
+			.get_or_insert_default()
+			.push(actor_name.into());
+		self
+	}
+
+	pub fn with_request_replay(mut self, max_attempts: u32) -> Self {
+		self.request_replay_max_attempts = Some(max_attempts);
+		self
+	}
+
+	pub fn with_max_batch_size(mut self, max_batch_size: usize) -> Self {
+		self.max_batch_size = Some(max_batch_size);
+		self
+	}
+
+	pub fn with_gateway_request_body_chunk_size(mut self, chunk_size: usize) -> Self {
+		self.gateway_request_body_chunk_size = Some(chunk_size);
+		self
+	}
+
+	pub fn with_gateway_response_body_idle_timeout_ms(mut self, timeout_ms: u64) -> Self {
+		self.gateway_response_body_idle_timeout_ms = Some(timeout_ms);
+		self
+	}
+
+	pub fn with_gateway_actor_max_websockets(mut self, max_websockets: usize) -> Self {
+		self.gateway_actor_max_websockets = Some(max_websockets);
+		self
+	}
+
+	pub fn with_runner_reconnect_grace_period(mut self, grace_period_ms: i64) -> Self {
+		self.runner_reconnect_grace_period = Some(grace_period_ms);
+		self
+	}
+
+	/// Adds a token runners must present to connect to the given namespace.
+	pub fn with_runner_token(
+		mut self,
+		namespace: impl Into<String>,
+		name: impl Into<String>,
+		token: impl Into<String>,
+	) -> Self {
+		self.runner_tokens
+			.get_or_insert_default()
+			.entry(namespace.into())
+			.or_default()
+			.insert(name.into(), token.into());
+		self
+	}
+
+	pub fn with_serverless_circuit_breaker(mut self, threshold: u32, cooldown_ms: i64) -> Self {
+		self.serverless_circuit_breaker = Some((threshold, cooldown_ms));
+		self
+	}
 }
 
 impl Default for TestOpts {
@@ -51,6 +133,16 @@ impl Default for TestOpts {
 			pegboard_outbound: false,
 			auth_admin_token: None,
 			network_faults: false,
+			metrics_aggregator_interval: None,
+			request_coalescing_actor_names: None,
+			request_replay_max_attempts: None,
+			max_batch_size: None,
+			gateway_request_body_chunk_size: None,
+			gateway_response_body_idle_timeout_ms: None,
+			gateway_actor_max_websockets: None,
+			runner_reconnect_grace_period: None,
+			runner_tokens: None,
+			serverless_circuit_breaker: None,
 		}
 	}
 }
@@ -102,6 +194,16 @@ impl TestCtx {
 				test_deps,
 				opts.pegboard_outbound,
 				opts.auth_admin_token.clone(),
+				opts.metrics_aggregator_interval,
+				opts.request_coalescing_actor_names.clone(),
+				opts.request_replay_max_attempts,
+				opts.max_batch_size,
+				opts.gateway_request_body_chunk_size,
+				opts.gateway_response_body_idle_timeout_ms,
+				opts.gateway_actor_max_websockets,
+				opts.runner_reconnect_grace_period,
+				opts.runner_tokens.clone(),
+				opts.serverless_circuit_breaker,
 			)
 		});
 		let mut dcs: Vec<TestDatacenter> =
@@ -125,12 +227,94 @@ impl TestCtx {
 		test_deps: rivet_test_deps::TestDeps,
 		include_pegboard_outbound: bool,
 		auth_admin_token: Option<String>,
+		metrics_aggregator_interval: Option<u64>,
+		request_coalescing_actor_names: Option<Vec<String>>,
+		request_replay_max_attempts: Option<u32>,
+		max_batch_size: Option<usize>,
+		gateway_request_body_chunk_size: Option<usize>,
+		gateway_response_body_idle_timeout_ms: Option<u64>,
+		gateway_actor_max_websockets: Option<usize>,
+		runner_reconnect_grace_period: Option<i64>,
+		runner_tokens: Option<HashMap<String, HashMap<String, String>>>,
+		serverless_circuit_breaker: Option<(u32, i64)>,
 	) -> Result<TestDatacenter> {
-		let config = if let Some(admin_token) = auth_admin_token {
+		let config = if auth_admin_token.is_some()
+			|| metrics_aggregator_interval.is_some()
+			|| request_coalescing_actor_names.is_some()
+			|| request_replay_max_attempts.is_some()
+			|| max_batch_size.is_some()
+			|| gateway_request_body_chunk_size.is_some()
+			|| gateway_response_body_idle_timeout_ms.is_some()
+			|| gateway_actor_max_websockets.is_some()
+			|| runner_reconnect_grace_period.is_some()
+			|| runner_tokens.is_some()
+			|| serverless_circuit_breaker.is_some()
+		{
 			let mut root = (**test_deps.config()).clone();
-			root.auth = Some(rivet_config::config::auth::Auth {
-				admin_token: rivet_config::secret::Secret::new(admin_token),
-			});
+			if let Some(admin_token) = auth_admin_token {
+				root.auth = Some(rivet_config::config::auth::Auth {
+					admin_token: rivet_config::secret::Secret::new(admin_token),
+				});
+			}
+			if let Some(interval) = metrics_aggregator_interval {
+				root.pegboard
+					.get_or_insert_default()
+					.metrics_aggregator_interval = Some(interval);
+			}
+			if let Some(actor_names) = request_coalescing_actor_names {
+				root.guard
+					.get_or_insert_default()
+					.request_coalescing_actor_names = Some(actor_names);
+			}
+			if let Some(max_attempts) = request_replay_max_attempts {
+				root.guard
+					.get_or_insert_default()
+					.request_replay_max_attempts = Some(max_attempts);
+			}
+			if let Some(max_batch_size) = max_batch_size {
+				root.pegboard.get_or_insert_default().max_batch_size = Some(max_batch_size);
+			}
+			if let Some(chunk_size) = gateway_request_body_chunk_size {
+				root.pegboard
+					.get_or_insert_default()
+					.gateway_request_body_chunk_size = Some(chunk_size);
+			}
+			if let Some(timeout_ms) = gateway_response_body_idle_timeout_ms {
+				root.pegboard
+					.get_or_insert_default()
+					.gateway_response_body_idle_timeout_ms = Some(timeout_ms);
+			}
+			if let Some(max_websockets) = gateway_actor_max_websockets {
+				root.pegboard
+					.get_or_insert_default()
+					.gateway_actor_max_websockets = Some(max_websockets);
+			}
+			if let Some(grace_period_ms) = runner_reconnect_grace_period {
+				root.pegboard
+					.get_or_insert_default()
+					.runner_reconnect_grace_period = Some(grace_period_ms);
+			}
+			if let Some(runner_tokens) = runner_tokens {
+				root.pegboard.get_or_insert_default().runner_tokens = Some(
+					runner_tokens
+						.into_iter()
+						.map(|(namespace, tokens)| {
+							let tokens = tokens
+								.into_iter()
+								.map(|(name, token)| {
+									(name, rivet_config::secret::Secret::new(token))
+								})
+								.collect();
+							(namespace, tokens)
+						})
+						.collect(),
+				);
+			}
+			if let Some((threshold, cooldown_ms)) = serverless_circuit_breaker {
+				let pegboard = root.pegboard.get_or_insert_default();
+				pegboard.serverless_circuit_breaker_threshold = Some(threshold);
+				pegboard.serverless_circuit_breaker_cooldown = Some(cooldown_ms);
+			}
 			rivet_config::Config::from_root(root)
 		} else {
 			test_deps.config().clone()
@@ -4,7 +4,8 @@
 //! defeats tests that need to observe one peer's behavior when it has no signal that the other
 //! peer hung up. `FreezeProxy` is a single-purpose forwarder that supports a true black-hole
 //! mode: while frozen, bytes are read from each peer and discarded, and an EOF from either peer
-//! is held instead of being forwarded.
+//! is held instead of being forwarded. It can also sever every open connection, like a network
+//! failure, while still accepting new ones.
 
 use std::net::SocketAddr;
 use std::sync::Arc;
@@ -15,10 +16,12 @@ use tokio::io::AsyncReadExt;
 use tokio::io::AsyncWriteExt;
 use tokio::net::tcp::{OwnedReadHalf, OwnedWriteHalf};
 use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::broadcast;
 
 pub struct FreezeProxy {
 	listen_addr: SocketAddr,
 	frozen: Arc<AtomicBool>,
+	sever_tx: broadcast::Sender<()>,
 }
 
 impl FreezeProxy {
@@ -30,9 +33,11 @@ impl FreezeProxy {
 			.local_addr()
 			.context("failed to read FreezeProxy listen addr")?;
 		let frozen = Arc::new(AtomicBool::new(false));
+		let (sever_tx, _) = broadcast::channel(1);
 
 		tokio::spawn({
 			let frozen = frozen.clone();
+			let sever_tx = sever_tx.clone();
 			async move {
 				loop {
 					let (client, _) = match listener.accept().await {
@@ -44,6 +49,7 @@ impl FreezeProxy {
 					};
 					let _ = client.set_nodelay(true);
 					let frozen = frozen.clone();
+					let sever_tx = sever_tx.clone();
 					tokio::spawn(async move {
 						let server = match TcpStream::connect(upstream).await {
 							Ok(stream) => stream,
@@ -55,8 +61,13 @@ impl FreezeProxy {
 						let _ = server.set_nodelay(true);
 						let (client_r, client_w) = client.into_split();
 						let (server_r, server_w) = server.into_split();
-						tokio::spawn(forward(client_r, server_w, frozen.clone()));
-						tokio::spawn(forward(server_r, client_w, frozen));
+						tokio::spawn(forward(
+							client_r,
+							server_w,
+							frozen.clone(),
+							sever_tx.subscribe(),
+						));
+						tokio::spawn(forward(server_r, client_w, frozen, sever_tx.subscribe()));
 					});
 				}
 			}
@@ -65,6 +76,7 @@ impl FreezeProxy {
 		Ok(Self {
 			listen_addr,
 			frozen,
+			sever_tx,
 		})
 	}
 
@@ -78,12 +90,29 @@ impl FreezeProxy {
 	pub fn freeze(&self) {
 		self.frozen.store(true, Ordering::SeqCst);
 	}
+
+	/// Closes every open connection without a WebSocket close frame. New connections are still
+	/// accepted.
+	pub fn sever(&self) {
+		let _ = self.sever_tx.send(());
+	}
 }
 
-async fn forward(mut src: OwnedReadHalf, mut dst: OwnedWriteHalf, frozen: Arc<AtomicBool>) {
+async fn forward(
+	mut src: OwnedReadHalf,
+	mut dst: OwnedWriteHalf,
+	frozen: Arc<AtomicBool>,
+	mut sever_rx: broadcast::Receiver<()>,
+) {
 	let mut buf = vec![0u8; 8192];
 	loop {
-		match src.read(&mut buf).await {
+		let res = tokio::select! {
+			res = src.read(&mut buf) => res,
+			// Dropping both halves closes the socket
+			_ = sever_rx.recv() => return,
+		};
+
+		match res {
 			Ok(0) => {
 				if frozen.load(Ordering::SeqCst) {
 					// Hold the destination open: the peer's own send/recv buffer keeps it
@@ -0,0 +1,74 @@
+use anyhow::Result;
+use pegboard::actor_kv::Recipient;
+use rivet_envoy_protocol as ep;
+
+use super::TestDatacenter;
+
+/// Reads an actor's KV directly from the database, bypassing the runner.
+pub struct ActorKv {
+	db: universaldb::Database,
+	recipient: Recipient,
+}
+
+impl ActorKv {
+	pub fn new(
+		dc: &TestDatacenter,
+		namespace_id: rivet_util::Id,
+		actor_id: rivet_util::Id,
+		name: &str,
+	) -> Self {
+		Self {
+			db: (*dc.workflow_ctx.udb().expect("failed to get udb")).clone(),
+			recipient: Recipient {
+				actor_id,
+				namespace_id,
+				name: name.to_string(),
+			},
+		}
+	}
+
+	pub async fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
+		let (_, values, _) =
+			pegboard::actor_kv::get(&self.db, &self.recipient, vec![key.to_vec()]).await?;
+
+		Ok(values.into_iter().next())
+	}
+
+	/// Returns every key-value pair stored for the actor, in key order.
+	pub async fn list(&self) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+		let (keys, values, _) = pegboard::actor_kv::list(
+			&self.db,
+			&self.recipient,
+			ep::KvListQuery::KvListAllQuery,
+			false,
+			None,
+		)
+		.await?;
+
+		Ok(keys.into_iter().zip(values).collect())
+	}
+
+	pub async fn assert_kv_contains(&self, key: &[u8], value: &[u8]) {
+		let actual = self.get(key).await.expect("failed to read actor kv");
+		assert_eq!(
+			actual.as_deref(),
+			Some(value),
+			"actor kv key {:?} should hold {:?}, found {:?}",
+			String::from_utf8_lossy(key),
+			String::from_utf8_lossy(value),
+			actual.as_deref().map(String::from_utf8_lossy),
+		);
+	}
+
+	pub async fn assert_kv_empty(&self) {
+		let entries = self.list().await.expect("failed to list actor kv");
+		assert!(
+			entries.is_empty(),
+			"actor kv should be empty, found keys {:?}",
+			entries
+				.iter()
+				.map(|(key, _)| String::from_utf8_lossy(key))
+				.collect::<Vec<_>>()
+		);
+	}
+}
@@ -4,12 +4,14 @@ pub mod actors;
 pub mod api;
 pub mod ctx;
 pub mod freeze_proxy;
+pub mod kv;
 pub mod test_envoy;
 pub mod test_helpers;
 pub mod test_runner;
 
 pub use actors::*;
 pub use ctx::*;
+pub use kv::*;
 pub use rivet_api_types as api_types;
 pub const TEST_RUNNER_NAME: &'static str = "test-runner";
 pub use test_helpers::*;
@@ -352,6 +352,9 @@ impl TestEnvoyCallbacks {
 			key: config.key,
 			create_ts: config.create_ts,
 			input: config.input,
+			seed: rand::random(),
+			max_concurrent_requests: None,
+			request_limit_policy: Default::default(),
 			event_tx,
 			kv_request_tx: _kv_tx,
 		}
@@ -38,6 +38,9 @@ pub async fn setup_test_namespace(leader_dc: &TestDatacenter) -> (String, rivet_
 			rivet_api_peer::namespaces::CreateRequest {
 				name: namespace_name.clone(),
 				display_name: "Test Namespace".to_string(),
+				default_crash_policy: None,
+				cors_policy: None,
+				actor_rate_limit: None,
 			},
 		)
 		.await
@@ -407,7 +410,10 @@ pub async fn create_actor(
 			key: None,
 			input: None,
 			runner_name_selector: runner_name.to_string(),
-			crash_policy,
+			crash_policy: Some(crash_policy),
+			affinity: None,
+			required_capabilities: Vec::new(),
+			tags: Default::default(),
 		},
 	)
 	.await
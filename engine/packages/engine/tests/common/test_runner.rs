@@ -6,6 +6,7 @@
 use anyhow::{Context, Result, bail};
 use async_trait::async_trait;
 use futures_util::{SinkExt, StreamExt};
+use rand::{Rng, SeedableRng, rngs::StdRng};
 use rivet_runner_protocol::{self as rp, PROTOCOL_MK2_VERSION, mk2, versioned};
 use std::{
 	collections::HashMap,
@@ -13,11 +14,11 @@ use std::{
 	pin::Pin,
 	sync::{
 		Arc, Mutex,
-		atomic::{AtomicBool, AtomicU32, Ordering},
+		atomic::{AtomicBool, AtomicU32, AtomicU64, AtomicUsize, Ordering},
 	},
-	time::Duration,
+	time::{Duration, Instant},
 };
-use tokio::sync::{broadcast, mpsc, oneshot};
+use tokio::sync::{Semaphore, broadcast, mpsc, oneshot, watch};
 use tokio_tungstenite::{connect_async, tungstenite::Message};
 use vbare::OwnedVersionedData;
 
@@ -26,7 +27,12 @@ pub use rivet_runner_protocol::PROTOCOL_MK2_VERSION as PROTOCOL_VERSION;
 
 type WsStream =
 	tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
-type ActorFactory = Arc<dyn Fn(ActorConfig) -> Box<dyn TestActor> + Send + Sync>;
+pub type ActorFactory = Arc<dyn Fn(ActorConfig) -> Box<dyn TestActor> + Send + Sync>;
+
+/// Delay between chunks of a streamed response.
+const STREAM_CHUNK_INTERVAL: Duration = Duration::from_millis(500);
+/// How long `/stream-stall` waits before finishing its response.
+const STREAM_STALL_DURATION: Duration = Duration::from_secs(5);
 
 pub type TestRunner = Runner;
 pub type RunnerBuilderLegacy = RunnerBuilder;
@@ -39,8 +45,16 @@ pub struct ActorConfig {
 	pub key: Option<String>,
 	pub create_ts: i64,
 	pub input: Option<Vec<u8>>,
+	/// Seed for this actor's randomness, drawn from the runner's RNG.
+	pub seed: u64,
+	/// Max requests the runner lets this actor handle at once. Unlimited if `None`.
+	pub max_concurrent_requests: Option<usize>,
+	/// What happens to requests over `max_concurrent_requests`.
+	pub request_limit_policy: RequestLimitPolicy,
+	/// Reads and writes this actor's KV through the runner connection.
+	pub kv: KvClient,
 	pub(crate) event_tx: mpsc::UnboundedSender<ActorEvent>,
-	pub(crate) kv_request_tx: mpsc::UnboundedSender<KvRequest>,
+	log_tx: mpsc::UnboundedSender<mk2::ToServerActorLog>,
 }
 
 impl ActorConfig {
@@ -48,21 +62,36 @@ impl ActorConfig {
 		config: &mk2::ActorConfig,
 		actor_id: String,
 		generation: u32,
+		seed: u64,
+		request_limit: Option<(usize, RequestLimitPolicy)>,
 		event_tx: mpsc::UnboundedSender<ActorEvent>,
 		kv_request_tx: mpsc::UnboundedSender<KvRequest>,
+		log_tx: mpsc::UnboundedSender<mk2::ToServerActorLog>,
 	) -> Self {
 		Self {
-			actor_id,
 			generation,
 			name: config.name.clone(),
 			key: config.key.clone(),
 			create_ts: config.create_ts,
 			input: config.input.clone(),
+			seed,
+			max_concurrent_requests: request_limit.map(|(max, _)| max),
+			request_limit_policy: request_limit.map(|(_, policy)| policy).unwrap_or_default(),
+			kv: KvClient {
+				actor_id: actor_id.clone(),
+				kv_request_tx,
+			},
+			actor_id,
 			event_tx,
-			kv_request_tx,
+			log_tx,
 		}
 	}
 
+	/// RNG for behaviors that need randomness. Reproducible when the runner is built with a seed.
+	pub fn rng(&self) -> StdRng {
+		StdRng::seed_from_u64(self.seed)
+	}
+
 	pub fn send_sleep_intent(&self) {
 		self.send_event(mk2::Event::EventActorIntent(mk2::EventActorIntent {
 			intent: mk2::ActorIntent::ActorIntentSleep,
@@ -87,6 +116,15 @@ impl ActorConfig {
 		}));
 	}
 
+	/// Forwards log lines to the engine as if the actor had written them.
+	pub fn send_log(&self, lines: Vec<mk2::ActorLogLine>) {
+		let _ = self.log_tx.send(mk2::ToServerActorLog {
+			actor_id: self.actor_id.clone(),
+			generation: self.generation,
+			lines,
+		});
+	}
+
 	fn send_event(&self, event: mk2::Event) {
 		let _ = self.event_tx.send(ActorEvent {
 			actor_id: self.actor_id.clone(),
@@ -177,6 +215,101 @@ impl ActorConfig {
 	}
 
 	async fn send_kv(&self, data: mk2::KvRequestData) -> Result<mk2::KvResponseData> {
+		Ok(self.kv.request(data).await?)
+	}
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum KvError {
+	#[error("KV {op} failed: {message}")]
+	Engine { op: &'static str, message: String },
+	#[error("unexpected response type for KV {op}")]
+	UnexpectedResponse { op: &'static str },
+	#[error("runner stopped before the KV request completed")]
+	RunnerStopped,
+}
+
+/// Handle for an actor's KV. Requests go over the runner's connection, so they exercise the same
+/// path as a real actor.
+#[derive(Clone)]
+pub struct KvClient {
+	actor_id: String,
+	kv_request_tx: mpsc::UnboundedSender<KvRequest>,
+}
+
+impl KvClient {
+	pub async fn get(&self, key: impl Into<Vec<u8>>) -> Result<Option<Vec<u8>>, KvError> {
+		let key = key.into();
+		match self
+			.request(mk2::KvRequestData::KvGetRequest(mk2::KvGetRequest {
+				keys: vec![key.clone()],
+			}))
+			.await?
+		{
+			mk2::KvResponseData::KvGetResponse(res) => Ok(res
+				.keys
+				.into_iter()
+				.zip(res.values)
+				.find_map(|(k, v)| (k == key).then_some(v))),
+			res => Err(response_error("get", res)),
+		}
+	}
+
+	pub async fn put(
+		&self,
+		key: impl Into<Vec<u8>>,
+		value: impl Into<Vec<u8>>,
+	) -> Result<(), KvError> {
+		match self
+			.request(mk2::KvRequestData::KvPutRequest(mk2::KvPutRequest {
+				keys: vec![key.into()],
+				values: vec![value.into()],
+			}))
+			.await?
+		{
+			mk2::KvResponseData::KvPutResponse => Ok(()),
+			res => Err(response_error("put", res)),
+		}
+	}
+
+	/// Returns the matching key-value pairs in key order.
+	pub async fn list(&self, query: mk2::KvListQuery) -> Result<Vec<(Vec<u8>, Vec<u8>)>, KvError> {
+		match self
+			.request(mk2::KvRequestData::KvListRequest(mk2::KvListRequest {
+				query,
+				reverse: None,
+				limit: None,
+			}))
+			.await?
+		{
+			mk2::KvResponseData::KvListResponse(res) => {
+				Ok(res.keys.into_iter().zip(res.values).collect())
+			}
+			res => Err(response_error("list", res)),
+		}
+	}
+
+	pub async fn delete(&self, key: impl Into<Vec<u8>>) -> Result<(), KvError> {
+		match self
+			.request(mk2::KvRequestData::KvDeleteRequest(mk2::KvDeleteRequest {
+				keys: vec![key.into()],
+			}))
+			.await?
+		{
+			mk2::KvResponseData::KvDeleteResponse => Ok(()),
+			res => Err(response_error("delete", res)),
+		}
+	}
+
+	/// Deletes every key the actor has stored.
+	pub async fn delete_all(&self) -> Result<(), KvError> {
+		match self.request(mk2::KvRequestData::KvDropRequest).await? {
+			mk2::KvResponseData::KvDropResponse => Ok(()),
+			res => Err(response_error("delete all", res)),
+		}
+	}
+
+	async fn request(&self, data: mk2::KvRequestData) -> Result<mk2::KvResponseData, KvError> {
 		let (response_tx, response_rx) = oneshot::channel();
 		self.kv_request_tx
 			.send(KvRequest {
@@ -184,8 +317,18 @@ impl ActorConfig {
 				data,
 				response_tx,
 			})
-			.context("failed to send KV request")?;
-		response_rx.await.context("KV response channel closed")
+			.map_err(|_| KvError::RunnerStopped)?;
+		response_rx.await.map_err(|_| KvError::RunnerStopped)
+	}
+}
+
+fn response_error(op: &'static str, res: mk2::KvResponseData) -> KvError {
+	match res {
+		mk2::KvResponseData::KvErrorResponse(err) => KvError::Engine {
+			op,
+			message: err.message,
+		},
+		_ => KvError::UnexpectedResponse { op },
 	}
 }
 
@@ -204,11 +347,83 @@ pub enum ActorStopResult {
 	Crash { code: i32, message: String },
 }
 
+/// What the runner does when the engine starts an actor whose name has no registered behavior.
+#[derive(Clone)]
+pub enum UnknownActorPolicy {
+	/// Start the actor with this factory.
+	Fallback(ActorFactory),
+	/// Report the actor as stopped with an error so the engine fails it.
+	Reject,
+}
+
+impl Default for UnknownActorPolicy {
+	fn default() -> Self {
+		UnknownActorPolicy::Fallback(Arc::new(|_| Box::new(EchoActor::new())))
+	}
+}
+
+/// What the runner does with a request that arrives while its actor is at
+/// `max_concurrent_requests`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RequestLimitPolicy {
+	/// Hold the request until another one finishes.
+	#[default]
+	Queue,
+	/// Respond with 429 without calling the actor.
+	Reject,
+}
+
+#[derive(Debug, Clone)]
+pub enum ActorRequestAction {
+	Respond,
+	/// Drop the runner connection, reconnect with the same key, then respond on the new
+	/// connection.
+	ReconnectThenRespond,
+	/// Wait before responding.
+	DelayThenRespond(Duration),
+}
+
+/// How the runner answers a WebSocket the gateway opens to an actor.
+#[derive(Debug, Clone)]
+pub enum WebSocketOpenAction {
+	/// Accept the connection. The gateway keeps hibernatable connections open while the actor is
+	/// away, and the runner acks every message received on them.
+	Accept { can_hibernate: bool },
+	/// Refuse the connection with a close frame.
+	Close { code: u16, reason: String },
+}
+
 #[async_trait]
 pub trait Actor: Send + Sync {
 	async fn on_start(&mut self, config: ActorConfig) -> Result<ActorStartResult>;
 	async fn on_stop(&mut self) -> Result<ActorStopResult>;
 
+	/// Called when an HTTP request for this actor arrives, before the runner responds.
+	fn on_request(&mut self, _path: &str) -> ActorRequestAction {
+		ActorRequestAction::Respond
+	}
+
+	/// Called when the gateway opens a WebSocket to this actor. `ws` can be kept to send on the
+	/// connection later. Refused by default.
+	fn on_websocket_open(&mut self, _ws: WebSocketSender, _path: &str) -> WebSocketOpenAction {
+		WebSocketOpenAction::Close {
+			code: 1011,
+			reason: "websockets not supported".to_string(),
+		}
+	}
+
+	/// Called for each message the client sends on an accepted WebSocket.
+	fn on_websocket_message(&mut self, _ws: &WebSocketSender, _data: &[u8], _binary: bool) {}
+
+	/// Called when the client or gateway closes an accepted WebSocket.
+	fn on_websocket_close(
+		&mut self,
+		_ws: &WebSocketSender,
+		_code: Option<u16>,
+		_reason: Option<&str>,
+	) {
+	}
+
 	fn name(&self) -> &str {
 		"TestActor"
 	}
@@ -228,10 +443,334 @@ pub struct KvRequest {
 	pub response_tx: oneshot::Sender<mk2::KvResponseData>,
 }
 
+/// `RequestCompleted` is sent after an HTTP response is written to the connection.
+/// `RequestLimited` is sent when a request arrives while its actor is at `max_concurrent_requests`.
+/// `UnknownActor` is sent when the engine starts an actor with no registered behavior.
+/// `Draining` is sent when a drain starts, with the number of actors it waits on.
+/// `DrainRejected` is sent when the engine starts an actor while the runner is draining.
+/// `Drained` is sent once a shutdown or drain closes the connection, with the number of requests
+/// and actors still running when the drain deadline passed.
 #[derive(Debug, Clone)]
 pub enum ActorLifecycleEvent {
-	Started { actor_id: String, generation: u32 },
-	Stopped { actor_id: String, generation: u32 },
+	Started {
+		actor_id: String,
+		generation: u32,
+	},
+	Stopped {
+		actor_id: String,
+		generation: u32,
+	},
+	RequestCompleted {
+		actor_id: String,
+	},
+	RequestLimited {
+		actor_id: String,
+		rejected: bool,
+	},
+	UnknownActor {
+		actor_id: String,
+		name: String,
+		rejected: bool,
+	},
+	Draining {
+		actors: usize,
+	},
+	DrainRejected {
+		actor_id: String,
+	},
+	Drained {
+		abandoned: usize,
+		remaining_actors: usize,
+	},
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunnerStatus {
+	/// Opening the first connection, or waiting for the engine to send `ToClientInit`.
+	Connecting,
+	/// Initialized by the engine and processing commands.
+	Connected,
+	/// The connection was lost and is being reopened with the same runner key.
+	Reconnecting,
+	/// The message loop exited after a shutdown, crash, or close from the engine.
+	Stopped,
+}
+
+/// How the runner reconnects after losing its connection. Each attempt waits
+/// `initial_backoff * 2^attempt`, capped at `max_backoff` and randomized by up to `jitter` (a
+/// fraction of the delay) in either direction.
+#[derive(Debug, Clone)]
+pub struct ReconnectPolicy {
+	pub initial_backoff: Duration,
+	pub max_backoff: Duration,
+	pub jitter: f64,
+	/// Attempts before the runner gives up and stops. Retries forever if `None`.
+	pub max_attempts: Option<u32>,
+}
+
+impl Default for ReconnectPolicy {
+	fn default() -> Self {
+		Self {
+			initial_backoff: Duration::from_millis(500),
+			max_backoff: Duration::from_secs(10),
+			jitter: 0.2,
+			max_attempts: Some(10),
+		}
+	}
+}
+
+impl ReconnectPolicy {
+	fn backoff(&self, attempt: u32) -> Duration {
+		let backoff = self
+			.initial_backoff
+			.saturating_mul(2u32.saturating_pow(attempt))
+			.min(self.max_backoff);
+		// Not drawn from the runner's RNG so reconnects do not change the seeds actors get
+		let jitter = 1.0 + self.jitter * (rand::random::<f64>() * 2.0 - 1.0);
+
+		backoff.mul_f64(jitter.max(0.0))
+	}
+}
+
+/// Hook for observing runner activity, e.g. to export it as Prometheus metrics. Every method
+/// defaults to a no-op.
+pub trait RunnerMetrics: Send + Sync {
+	/// An HTTP request finished and its response was sent.
+	fn request_handled(&self, _actor_id: &str, _status: u16, _duration: Duration) {}
+	/// The number of actors running on this runner changed.
+	fn set_active_actors(&self, _count: usize) {}
+	fn frame_sent(&self) {}
+	fn frame_received(&self) {}
+}
+
+/// `RunnerMetrics` that keeps running totals in memory.
+#[derive(Default)]
+pub struct CounterMetrics {
+	requests_handled: AtomicU64,
+	request_duration_ms: AtomicU64,
+	active_actors: AtomicUsize,
+	frames_sent: AtomicU64,
+	frames_received: AtomicU64,
+}
+
+impl CounterMetrics {
+	pub fn requests_handled(&self) -> u64 {
+		self.requests_handled.load(Ordering::SeqCst)
+	}
+
+	/// Sum of the duration of every handled request.
+	pub fn request_duration(&self) -> Duration {
+		Duration::from_millis(self.request_duration_ms.load(Ordering::SeqCst))
+	}
+
+	pub fn active_actors(&self) -> usize {
+		self.active_actors.load(Ordering::SeqCst)
+	}
+
+	pub fn frames_sent(&self) -> u64 {
+		self.frames_sent.load(Ordering::SeqCst)
+	}
+
+	pub fn frames_received(&self) -> u64 {
+		self.frames_received.load(Ordering::SeqCst)
+	}
+}
+
+impl RunnerMetrics for CounterMetrics {
+	fn request_handled(&self, _actor_id: &str, _status: u16, duration: Duration) {
+		self.requests_handled.fetch_add(1, Ordering::SeqCst);
+		self.request_duration_ms
+			.fetch_add(duration.as_millis() as u64, Ordering::SeqCst);
+	}
+
+	fn set_active_actors(&self, count: usize) {
+		self.active_actors.store(count, Ordering::SeqCst);
+	}
+
+	fn frame_sent(&self) {
+		self.frames_sent.fetch_add(1, Ordering::SeqCst);
+	}
+
+	fn frame_received(&self) {
+		self.frames_received.fetch_add(1, Ordering::SeqCst);
+	}
+}
+
+type StreamingRequest = (mk2::ToClientRequestStart, Vec<u8>);
+
+/// Response to a tunnel request, sent by the message loop. Streamed responses are split across
+/// several messages, the last of which is marked `finished`. WebSocket messages are never
+/// `finished` and do not count as in-flight requests.
+struct TunnelResponse {
+	actor_id: String,
+	status: u16,
+	received_at: Instant,
+	message: mk2::ToServerTunnelMessage,
+	finished: bool,
+}
+
+/// Sends on a WebSocket the gateway opened to an actor. Messages sent before the runner accepts
+/// the connection are held until it does, and messages sent after it closes are dropped.
+#[derive(Clone)]
+pub struct WebSocketSender {
+	actor_id: String,
+	gateway_id: mk2::GatewayId,
+	request_id: mk2::RequestId,
+	state: Arc<Mutex<WebSocketSenderState>>,
+	tunnel_response_tx: mpsc::UnboundedSender<TunnelResponse>,
+}
+
+struct WebSocketSenderState {
+	next_message_index: mk2::MessageIndex,
+	/// Messages sent before the connection was accepted. `None` once it is.
+	pending: Option<Vec<mk2::ToServerTunnelMessageKind>>,
+	closed: bool,
+}
+
+impl WebSocketSender {
+	fn new(
+		actor_id: String,
+		gateway_id: mk2::GatewayId,
+		request_id: mk2::RequestId,
+		tunnel_response_tx: mpsc::UnboundedSender<TunnelResponse>,
+	) -> Self {
+		Self {
+			actor_id,
+			gateway_id,
+			request_id,
+			state: Arc::new(Mutex::new(WebSocketSenderState {
+				next_message_index: 0,
+				pending: Some(Vec::new()),
+				closed: false,
+			})),
+			tunnel_response_tx,
+		}
+	}
+
+	pub fn actor_id(&self) -> &str {
+		&self.actor_id
+	}
+
+	pub fn send_text(&self, text: impl Into<String>) {
+		self.send(mk2::ToServerTunnelMessageKind::ToServerWebSocketMessage(
+			mk2::ToServerWebSocketMessage {
+				data: text.into().into_bytes(),
+				binary: false,
+			},
+		));
+	}
+
+	pub fn send_binary(&self, data: impl Into<Vec<u8>>) {
+		self.send(mk2::ToServerTunnelMessageKind::ToServerWebSocketMessage(
+			mk2::ToServerWebSocketMessage {
+				data: data.into(),
+				binary: true,
+			},
+		));
+	}
+
+	pub fn close(&self, code: Option<u16>, reason: Option<String>) {
+		self.send(mk2::ToServerTunnelMessageKind::ToServerWebSocketClose(
+			mk2::ToServerWebSocketClose {
+				code,
+				reason,
+				hibernate: false,
+			},
+		));
+		self.state.lock().expect("websocket state lock").closed = true;
+	}
+
+	/// Asks the gateway to keep the client connected while the actor is away. Messages keep being
+	/// delivered once the gateway reconnects the WebSocket. Only honored on hibernatable
+	/// connections.
+	pub fn hibernate(&self) {
+		self.send(mk2::ToServerTunnelMessageKind::ToServerWebSocketClose(
+			mk2::ToServerWebSocketClose {
+				code: None,
+				reason: None,
+				hibernate: true,
+			},
+		));
+	}
+
+	/// Whether the connection was closed by the actor, client or gateway.
+	pub fn is_closed(&self) -> bool {
+		self.state.lock().expect("websocket state lock").closed
+	}
+
+	fn send(&self, message_kind: mk2::ToServerTunnelMessageKind) {
+		let mut state = self.state.lock().expect("websocket state lock");
+		if state.closed {
+			return;
+		}
+		match &mut state.pending {
+			Some(pending) => pending.push(message_kind),
+			None => self.send_now(&mut state, message_kind),
+		}
+	}
+
+	/// Sends the open response followed by the messages held until now.
+	fn accept(&self, can_hibernate: bool) {
+		let mut state = self.state.lock().expect("websocket state lock");
+		let pending = state.pending.take().unwrap_or_default();
+		self.send_now(
+			&mut state,
+			mk2::ToServerTunnelMessageKind::ToServerWebSocketOpen(mk2::ToServerWebSocketOpen {
+				can_hibernate,
+			}),
+		);
+		for message_kind in pending {
+			self.send_now(&mut state, message_kind);
+		}
+	}
+
+	/// Refuses the connection, dropping the messages held until now.
+	fn reject(&self, code: u16, reason: String) {
+		let mut state = self.state.lock().expect("websocket state lock");
+		state.pending = None;
+		state.closed = true;
+		self.send_now(
+			&mut state,
+			mk2::ToServerTunnelMessageKind::ToServerWebSocketClose(mk2::ToServerWebSocketClose {
+				code: Some(code),
+				reason: Some(reason),
+				hibernate: false,
+			}),
+		);
+	}
+
+	fn mark_closed(&self) {
+		self.state.lock().expect("websocket state lock").closed = true;
+	}
+
+	fn send_now(
+		&self,
+		state: &mut WebSocketSenderState,
+		message_kind: mk2::ToServerTunnelMessageKind,
+	) {
+		let message_index = state.next_message_index;
+		state.next_message_index = message_index.wrapping_add(1);
+		let _ = self.tunnel_response_tx.send(TunnelResponse {
+			actor_id: self.actor_id.clone(),
+			status: 101,
+			received_at: Instant::now(),
+			message: mk2::ToServerTunnelMessage {
+				message_id: mk2::MessageId {
+					gateway_id: self.gateway_id,
+					request_id: self.request_id,
+					message_index,
+				},
+				message_kind,
+			},
+			finished: false,
+		});
+	}
+}
+
+/// A WebSocket accepted by one of the runner's actors.
+struct OpenWebSocket {
+	sender: WebSocketSender,
+	can_hibernate: bool,
 }
 
 #[derive(Clone)]
@@ -243,6 +782,9 @@ pub struct RunnerConfig {
 	runner_key: String,
 	version: u32,
 	total_slots: u32,
+	capabilities: Vec<String>,
+	drain_timeout: Duration,
+	reconnect_policy: Option<ReconnectPolicy>,
 }
 
 impl RunnerConfig {
@@ -260,6 +802,9 @@ pub struct RunnerConfigBuilder {
 	runner_key: Option<String>,
 	version: Option<u32>,
 	total_slots: Option<u32>,
+	capabilities: Vec<String>,
+	drain_timeout: Option<Duration>,
+	reconnect_policy: Option<ReconnectPolicy>,
 }
 
 impl RunnerConfigBuilder {
@@ -298,6 +843,24 @@ impl RunnerConfigBuilder {
 		self
 	}
 
+	pub fn capabilities(mut self, capabilities: Vec<String>) -> Self {
+		self.capabilities = capabilities;
+		self
+	}
+
+	/// How long a shutdown waits for in-flight requests to finish before closing the connection.
+	pub fn drain_timeout(mut self, drain_timeout: Duration) -> Self {
+		self.drain_timeout = Some(drain_timeout);
+		self
+	}
+
+	/// Reconnects with the same runner key when the connection drops unexpectedly. Without a
+	/// policy the runner stops instead.
+	pub fn reconnect_policy(mut self, reconnect_policy: ReconnectPolicy) -> Self {
+		self.reconnect_policy = Some(reconnect_policy);
+		self
+	}
+
 	pub fn build(self) -> Result<RunnerConfig> {
 		Ok(RunnerConfig {
 			endpoint: self.endpoint.context("endpoint is required")?,
@@ -311,6 +874,9 @@ impl RunnerConfigBuilder {
 				.unwrap_or_else(|| format!("key-{:012x}", rand::random::<u64>())),
 			version: self.version.unwrap_or(1),
 			total_slots: self.total_slots.unwrap_or(100),
+			capabilities: self.capabilities,
+			drain_timeout: self.drain_timeout.unwrap_or(Duration::from_secs(10)),
+			reconnect_policy: self.reconnect_policy,
 		})
 	}
 }
@@ -318,6 +884,12 @@ impl RunnerConfigBuilder {
 pub struct RunnerBuilder {
 	config: RunnerConfig,
 	actor_factories: HashMap<String, ActorFactory>,
+	/// Actor names in the order they are announced and started.
+	actor_names: Vec<String>,
+	seed: Option<u64>,
+	request_limits: HashMap<String, (usize, RequestLimitPolicy)>,
+	metrics: Option<Arc<dyn RunnerMetrics>>,
+	unknown_actor_policy: UnknownActorPolicy,
 }
 
 impl RunnerBuilder {
@@ -325,40 +897,117 @@ impl RunnerBuilder {
 		Self {
 			config,
 			actor_factories: HashMap::new(),
+			actor_names: Vec::new(),
+			seed: None,
+			request_limits: HashMap::new(),
+			metrics: None,
+			unknown_actor_policy: UnknownActorPolicy::default(),
 		}
 	}
 
+	/// Seeds all randomness in the runner and its actor behaviors so a run can be replayed.
+	pub fn with_seed(mut self, seed: u64) -> Self {
+		self.seed = Some(seed);
+		self
+	}
+
 	pub fn with_actor_behavior<F>(mut self, actor_name: &str, factory: F) -> Self
 	where
 		F: Fn(ActorConfig) -> Box<dyn TestActor> + Send + Sync + 'static,
 	{
-		self.actor_factories
-			.insert(actor_name.to_string(), Arc::new(factory));
+		if self
+			.actor_factories
+			.insert(actor_name.to_string(), Arc::new(factory))
+			.is_none()
+		{
+			self.actor_names.push(actor_name.to_string());
+		}
+		self
+	}
+
+	pub fn with_metrics(mut self, metrics: Arc<dyn RunnerMetrics>) -> Self {
+		self.metrics = Some(metrics);
+		self
+	}
+
+	/// Defaults to starting unknown actors as `EchoActor`.
+	pub fn with_unknown_actor_policy(mut self, policy: UnknownActorPolicy) -> Self {
+		self.unknown_actor_policy = policy;
+		self
+	}
+
+	/// Caps how many requests each actor with this name handles at once. See
+	/// `ActorConfig::max_concurrent_requests`.
+	pub fn with_max_concurrent_requests(
+		mut self,
+		actor_name: &str,
+		max_concurrent_requests: usize,
+		policy: RequestLimitPolicy,
+	) -> Self {
+		self.request_limits
+			.insert(actor_name.to_string(), (max_concurrent_requests, policy));
+		self
+	}
+
+	/// Moves the given actor names to the front of the announce and start order. Names not
+	/// listed keep their registration order after them.
+	pub fn with_start_order(mut self, mut actor_names: Vec<String>) -> Self {
+		let rest = self
+			.actor_names
+			.into_iter()
+			.filter(|name| !actor_names.contains(name))
+			.collect::<Vec<_>>();
+		actor_names.extend(rest);
+		self.actor_names = actor_names;
 		self
 	}
 
 	pub fn build(self) -> Result<Runner> {
 		let (event_tx, event_rx) = mpsc::unbounded_channel();
 		let (kv_request_tx, kv_request_rx) = mpsc::unbounded_channel();
+		let (log_tx, log_rx) = mpsc::unbounded_channel();
 		let (lifecycle_tx, _) = broadcast::channel(100);
 		let (control_tx, control_rx) = mpsc::unbounded_channel();
+		let (tunnel_response_tx, tunnel_response_rx) = mpsc::unbounded_channel();
+
+		let rng = match self.seed {
+			Some(seed) => StdRng::seed_from_u64(seed),
+			None => StdRng::from_entropy(),
+		};
 
 		Ok(Runner {
 			config: self.config,
 			actor_factories: self.actor_factories,
+			actor_names: Arc::new(self.actor_names),
+			request_limits: Arc::new(self.request_limits),
+			metrics: self.metrics,
+			unknown_actor_policy: self.unknown_actor_policy,
+			rng: Arc::new(Mutex::new(rng)),
+			frames: Arc::new(FrameLog::default()),
 			runner_id: Arc::new(tokio::sync::Mutex::new(None)),
 			ready: Arc::new(AtomicBool::new(false)),
+			connected: Arc::new(AtomicBool::new(false)),
+			draining: Arc::new(AtomicBool::new(false)),
+			status: Arc::new(watch::channel(RunnerStatus::Connecting).0),
 			actors: Arc::new(tokio::sync::Mutex::new(HashMap::new())),
 			event_indices: Arc::new(tokio::sync::Mutex::new(HashMap::new())),
+			unacked_events: Arc::new(tokio::sync::Mutex::new(Vec::new())),
 			pending_kv: Arc::new(tokio::sync::Mutex::new(HashMap::new())),
 			next_kv_request_id: Arc::new(tokio::sync::Mutex::new(1)),
 			event_tx,
 			event_rx: Arc::new(tokio::sync::Mutex::new(Some(event_rx))),
 			kv_request_tx,
 			kv_request_rx: Arc::new(tokio::sync::Mutex::new(Some(kv_request_rx))),
+			log_tx,
+			log_rx: Arc::new(tokio::sync::Mutex::new(Some(log_rx))),
 			lifecycle_tx,
 			control_tx,
 			control_rx: Arc::new(tokio::sync::Mutex::new(Some(control_rx))),
+			in_flight_requests: Arc::new(AtomicUsize::new(0)),
+			tunnel_response_tx,
+			tunnel_response_rx: Arc::new(tokio::sync::Mutex::new(Some(tunnel_response_rx))),
+			streaming_requests: Arc::new(tokio::sync::Mutex::new(HashMap::new())),
+			websockets: Arc::new(tokio::sync::Mutex::new(HashMap::new())),
 		})
 	}
 }
@@ -366,30 +1015,64 @@ impl RunnerBuilder {
 struct ActorState {
 	generation: u32,
 	actor: Box<dyn TestActor>,
+	/// Permits for `ActorConfig::max_concurrent_requests`.
+	request_slots: Option<(Arc<Semaphore>, RequestLimitPolicy)>,
+}
+
+/// Protocol frames exchanged over the runner websocket, kept for assertions.
+#[derive(Default)]
+struct FrameLog {
+	sent: Mutex<Vec<mk2::ToServer>>,
+	received: Mutex<Vec<mk2::ToClient>>,
 }
 
 #[derive(Clone, Copy)]
 enum Control {
 	Shutdown,
+	Drain(Duration),
 	Crash,
+	Disconnect,
 }
 
 pub struct Runner {
 	config: RunnerConfig,
 	actor_factories: HashMap<String, ActorFactory>,
+	actor_names: Arc<Vec<String>>,
+	request_limits: Arc<HashMap<String, (usize, RequestLimitPolicy)>>,
+	metrics: Option<Arc<dyn RunnerMetrics>>,
+	unknown_actor_policy: UnknownActorPolicy,
+	rng: Arc<Mutex<StdRng>>,
+	frames: Arc<FrameLog>,
 	runner_id: Arc<tokio::sync::Mutex<Option<String>>>,
 	ready: Arc<AtomicBool>,
+	connected: Arc<AtomicBool>,
+	/// Set by `drain`. New actors are rejected while set.
+	draining: Arc<AtomicBool>,
+	status: Arc<watch::Sender<RunnerStatus>>,
 	actors: Arc<tokio::sync::Mutex<HashMap<String, ActorState>>>,
 	event_indices: Arc<tokio::sync::Mutex<HashMap<(String, u32), i64>>>,
+	/// Events sent to the engine that it has not acked yet, replayed after a reconnect.
+	unacked_events: Arc<tokio::sync::Mutex<Vec<mk2::EventWrapper>>>,
 	pending_kv: Arc<tokio::sync::Mutex<HashMap<u32, oneshot::Sender<mk2::KvResponseData>>>>,
 	next_kv_request_id: Arc<tokio::sync::Mutex<u32>>,
 	event_tx: mpsc::UnboundedSender<ActorEvent>,
 	event_rx: Arc<tokio::sync::Mutex<Option<mpsc::UnboundedReceiver<ActorEvent>>>>,
 	kv_request_tx: mpsc::UnboundedSender<KvRequest>,
 	kv_request_rx: Arc<tokio::sync::Mutex<Option<mpsc::UnboundedReceiver<KvRequest>>>>,
+	log_tx: mpsc::UnboundedSender<mk2::ToServerActorLog>,
+	log_rx: Arc<tokio::sync::Mutex<Option<mpsc::UnboundedReceiver<mk2::ToServerActorLog>>>>,
 	lifecycle_tx: broadcast::Sender<ActorLifecycleEvent>,
 	control_tx: mpsc::UnboundedSender<Control>,
 	control_rx: Arc<tokio::sync::Mutex<Option<mpsc::UnboundedReceiver<Control>>>>,
+	in_flight_requests: Arc<AtomicUsize>,
+	tunnel_response_tx: mpsc::UnboundedSender<TunnelResponse>,
+	tunnel_response_rx: Arc<tokio::sync::Mutex<Option<mpsc::UnboundedReceiver<TunnelResponse>>>>,
+	/// Requests with a streamed body that are still receiving chunks, keyed by gateway and request
+	/// id.
+	streaming_requests:
+		Arc<tokio::sync::Mutex<HashMap<(mk2::GatewayId, mk2::RequestId), StreamingRequest>>>,
+	/// WebSockets accepted by actors, keyed by gateway and request id.
+	websockets: Arc<tokio::sync::Mutex<HashMap<(mk2::GatewayId, mk2::RequestId), OpenWebSocket>>>,
 }
 
 impl Runner {
@@ -406,13 +1089,53 @@ impl Runner {
 			.await
 			.take()
 			.context("runner already started")?;
+		let mut log_rx = self
+			.log_rx
+			.lock()
+			.await
+			.take()
+			.context("runner already started")?;
 		let mut control_rx = self
 			.control_rx
 			.lock()
 			.await
 			.take()
 			.context("runner already started")?;
+		let mut tunnel_response_rx = self
+			.tunnel_response_rx
+			.lock()
+			.await
+			.take()
+			.context("runner already started")?;
+
+		let mut ws_stream = self.connect().await?;
+		self.connected.store(true, Ordering::SeqCst);
+
+		let runner = self.clone_for_task();
+		tokio::spawn(async move {
+			let connected = runner.connected.clone();
+			let status = runner.status.clone();
+			if let Err(err) = runner
+				.run_message_loop(
+					&mut ws_stream,
+					&mut event_rx,
+					&mut kv_request_rx,
+					&mut log_rx,
+					&mut control_rx,
+					&mut tunnel_response_rx,
+				)
+				.await
+			{
+				tracing::error!(?err, "runner message loop failed");
+			}
+			connected.store(false, Ordering::SeqCst);
+			status.send_replace(RunnerStatus::Stopped);
+		});
+
+		Ok(())
+	}
 
+	async fn connect(&self) -> Result<WsStream> {
 		let ws_url = self.build_ws_url();
 		let token_protocol = format!("rivet_token.{}", self.config.token);
 
@@ -434,20 +1157,46 @@ impl Runner {
 			.await
 			.context("failed to send runner init")?;
 
-		let runner = self.clone_for_task();
-		tokio::spawn(async move {
-			if let Err(err) = runner
-				.run_message_loop(
-					&mut ws_stream,
-					&mut event_rx,
-					&mut kv_request_rx,
-					&mut control_rx,
-				)
-				.await
-			{
-				tracing::error!(?err, "runner message loop failed");
+		Ok(ws_stream)
+	}
+
+	/// Closes the current connection and opens a new one with the same runner key, retrying with
+	/// backoff. Running actors are kept and events the engine has not acked are sent again.
+	async fn reconnect(&self, ws_stream: &mut WsStream, policy: &ReconnectPolicy) -> Result<()> {
+		let _ = ws_stream.close(None).await;
+		self.connected.store(false, Ordering::SeqCst);
+		self.status.send_replace(RunnerStatus::Reconnecting);
+
+		let mut attempt = 0;
+		*ws_stream = loop {
+			tokio::time::sleep(policy.backoff(attempt)).await;
+
+			match self.connect().await {
+				Ok(ws_stream) => break ws_stream,
+				Err(err) => {
+					attempt += 1;
+					if policy.max_attempts.is_some_and(|max| attempt >= max) {
+						return Err(err.context(format!(
+							"runner failed to reconnect after {attempt} attempts"
+						)));
+					}
+					tracing::warn!(?err, attempt, "runner reconnect failed");
+				}
 			}
-		});
+		};
+		self.connected.store(true, Ordering::SeqCst);
+
+		// The engine ignores events at or below the last index it processed, so replaying is safe
+		let unacked_events = self.unacked_events.lock().await.clone();
+		if !unacked_events.is_empty() {
+			tracing::debug!(count = unacked_events.len(), "replaying unacked events");
+			ws_stream
+				.send(Message::Binary(
+					self.encode_to_server(mk2::ToServer::ToServerEvents(unacked_events))?
+						.into(),
+				))
+				.await?;
+		}
 
 		Ok(())
 	}
@@ -456,19 +1205,36 @@ impl Runner {
 		Self {
 			config: self.config.clone(),
 			actor_factories: self.actor_factories.clone(),
+			actor_names: self.actor_names.clone(),
+			request_limits: self.request_limits.clone(),
+			metrics: self.metrics.clone(),
+			unknown_actor_policy: self.unknown_actor_policy.clone(),
+			rng: self.rng.clone(),
+			frames: self.frames.clone(),
 			runner_id: self.runner_id.clone(),
 			ready: self.ready.clone(),
+			connected: self.connected.clone(),
+			draining: self.draining.clone(),
+			status: self.status.clone(),
 			actors: self.actors.clone(),
 			event_indices: self.event_indices.clone(),
+			unacked_events: self.unacked_events.clone(),
 			pending_kv: self.pending_kv.clone(),
 			next_kv_request_id: self.next_kv_request_id.clone(),
 			event_tx: self.event_tx.clone(),
 			event_rx: self.event_rx.clone(),
 			kv_request_tx: self.kv_request_tx.clone(),
 			kv_request_rx: self.kv_request_rx.clone(),
+			log_tx: self.log_tx.clone(),
+			log_rx: self.log_rx.clone(),
 			lifecycle_tx: self.lifecycle_tx.clone(),
 			control_tx: self.control_tx.clone(),
 			control_rx: self.control_rx.clone(),
+			in_flight_requests: self.in_flight_requests.clone(),
+			tunnel_response_tx: self.tunnel_response_tx.clone(),
+			tunnel_response_rx: self.tunnel_response_rx.clone(),
+			streaming_requests: self.streaming_requests.clone(),
+			websockets: self.websockets.clone(),
 		}
 	}
 
@@ -477,32 +1243,111 @@ impl Runner {
 		ws_stream: &mut WsStream,
 		event_rx: &mut mpsc::UnboundedReceiver<ActorEvent>,
 		kv_request_rx: &mut mpsc::UnboundedReceiver<KvRequest>,
+		log_rx: &mut mpsc::UnboundedReceiver<mk2::ToServerActorLog>,
 		control_rx: &mut mpsc::UnboundedReceiver<Control>,
+		tunnel_response_rx: &mut mpsc::UnboundedReceiver<TunnelResponse>,
 	) -> Result<()> {
+		// Set once a shutdown or drain starts. The connection stays open until every in-flight
+		// request has responded or the deadline passes.
+		let mut drain_deadline = None;
+		// Set by a drain, which also waits for every actor to stop and report it to the engine.
+		let mut wait_for_actors = false;
+
 		loop {
+			if drain_deadline.is_some()
+				&& self.in_flight_requests.load(Ordering::SeqCst) == 0
+				&& (!wait_for_actors
+					|| (self.actors.lock().await.is_empty() && event_rx.is_empty()))
+			{
+				self.finish_drain(ws_stream, 0, 0).await;
+				break;
+			}
+
 			tokio::select! {
 				Some(control) = control_rx.recv() => {
 					match control {
 						Control::Shutdown => {
-							let _ = ws_stream.send(Message::Binary(self.encode_to_server(mk2::ToServer::ToServerStopping)?.into())).await;
-							let _ = ws_stream.close(None).await;
+							if drain_deadline.is_none() {
+								let _ = ws_stream.send(Message::Binary(self.encode_to_server(mk2::ToServer::ToServerStopping)?.into())).await;
+								drain_deadline = Some(tokio::time::Instant::now() + self.config.drain_timeout);
+							}
+						}
+						Control::Drain(timeout) => {
+							if drain_deadline.is_none() {
+								self.draining.store(true, Ordering::SeqCst);
+								let _ = ws_stream.send(Message::Binary(self.encode_to_server(mk2::ToServer::ToServerStopping)?.into())).await;
+								drain_deadline = Some(tokio::time::Instant::now() + timeout);
+								wait_for_actors = true;
+								let _ = self.lifecycle_tx.send(ActorLifecycleEvent::Draining {
+									actors: self.actors.lock().await.len(),
+								});
+							}
 						}
 						Control::Crash => {
 							let _ = ws_stream.close(None).await;
+							break;
 						}
+						Control::Disconnect => {
+							let policy = self.config.reconnect_policy.clone().unwrap_or_default();
+							self.reconnect(ws_stream, &policy).await?;
+						}
+					}
+				}
+				_ = async {
+					match drain_deadline {
+						Some(deadline) => tokio::time::sleep_until(deadline).await,
+						None => std::future::pending().await,
 					}
+				} => {
+					let abandoned = self.in_flight_requests.load(Ordering::SeqCst);
+					let remaining_actors = if wait_for_actors {
+						self.actors.lock().await.len()
+					} else {
+						0
+					};
+					tracing::warn!(abandoned, remaining_actors, "runner drain deadline passed with work in flight");
+					self.finish_drain(ws_stream, abandoned, remaining_actors).await;
 					break;
 				}
+				Some(res) = tunnel_response_rx.recv() => {
+					let sent = ws_stream
+						.send(Message::Binary(self.encode_to_server(mk2::ToServer::ToServerTunnelMessage(res.message))?.into()))
+						.await;
+					if !res.finished {
+						sent?;
+						continue;
+					}
+					self.in_flight_requests.fetch_sub(1, Ordering::SeqCst);
+					sent?;
+					if let Some(metrics) = &self.metrics {
+						metrics.request_handled(&res.actor_id, res.status, res.received_at.elapsed());
+					}
+					let _ = self.lifecycle_tx.send(ActorLifecycleEvent::RequestCompleted {
+						actor_id: res.actor_id,
+					});
+				}
 				Some(event) = event_rx.recv() => {
 					self.send_actor_event(ws_stream, event).await?;
 				}
 				Some(req) = kv_request_rx.recv() => {
 					self.send_kv_request(ws_stream, req).await?;
 				}
+				Some(log) = log_rx.recv() => {
+					ws_stream
+						.send(Message::Binary(self.encode_to_server(mk2::ToServer::ToServerActorLog(log))?.into()))
+						.await?;
+				}
 				msg = ws_stream.next() => {
 					match msg {
 						Some(Ok(Message::Binary(buf))) => self.handle_message(ws_stream, &buf).await?,
-						Some(Ok(Message::Close(_))) | None => break,
+						Some(Ok(Message::Close(_))) => break,
+						// Lost without a close frame, e.g. a network failure
+						None | Some(Err(_)) if drain_deadline.is_none() && self.config.reconnect_policy.is_some() => {
+							tracing::warn!("runner connection lost, reconnecting");
+							let policy = self.config.reconnect_policy.clone().unwrap_or_default();
+							self.reconnect(ws_stream, &policy).await?;
+						}
+						None => break,
 						Some(Err(err)) => return Err(err.into()),
 						_ => {}
 					}
@@ -512,17 +1357,47 @@ impl Runner {
 		Ok(())
 	}
 
+	async fn finish_drain(
+		&self,
+		ws_stream: &mut WsStream,
+		abandoned: usize,
+		remaining_actors: usize,
+	) {
+		let _ = ws_stream.close(None).await;
+		let _ = self.lifecycle_tx.send(ActorLifecycleEvent::Drained {
+			abandoned,
+			remaining_actors,
+		});
+	}
+
 	async fn handle_message(&self, ws_stream: &mut WsStream, buf: &[u8]) -> Result<()> {
 		let msg = versioned::ToClientMk2::deserialize(buf, PROTOCOL_MK2_VERSION)?;
+		self.frames
+			.received
+			.lock()
+			.expect("frame log lock")
+			.push(msg.clone());
+		if let Some(metrics) = &self.metrics {
+			metrics.frame_received();
+		}
 		match msg {
 			mk2::ToClient::ToClientInit(init) => {
 				*self.runner_id.lock().await = Some(init.runner_id);
 				self.ready.store(true, Ordering::SeqCst);
+				self.status.send_replace(RunnerStatus::Connected);
 			}
 			mk2::ToClient::ToClientCommands(commands) => {
 				self.handle_commands(ws_stream, commands).await?;
 			}
-			mk2::ToClient::ToClientAckEvents(_) => {}
+			mk2::ToClient::ToClientAckEvents(ack) => {
+				self.unacked_events.lock().await.retain(|event| {
+					!ack.last_event_checkpoints.iter().any(|checkpoint| {
+						checkpoint.actor_id == event.checkpoint.actor_id
+							&& checkpoint.generation == event.checkpoint.generation
+							&& checkpoint.index >= event.checkpoint.index
+					})
+				});
+			}
 			mk2::ToClient::ToClientKvResponse(response) => {
 				if let Some(tx) = self.pending_kv.lock().await.remove(&response.request_id) {
 					let _ = tx.send(response.data);
@@ -548,8 +1423,24 @@ impl Runner {
 	async fn handle_commands(
 		&self,
 		ws_stream: &mut WsStream,
-		commands: Vec<mk2::CommandWrapper>,
+		mut commands: Vec<mk2::CommandWrapper>,
 	) -> Result<()> {
+		// Start consecutive actors in a batch in the configured order. Stops stay where the
+		// engine put them so a stop is never reordered around a start.
+		let is_start = |command: &mk2::CommandWrapper| {
+			matches!(command.inner, mk2::Command::CommandStartActor(_))
+		};
+		for run in commands.chunk_by_mut(|a, b| is_start(a) && is_start(b)) {
+			run.sort_by_key(|command| match &command.inner {
+				mk2::Command::CommandStartActor(start) => self
+					.actor_names
+					.iter()
+					.position(|name| *name == start.config.name)
+					.unwrap_or(self.actor_names.len()),
+				mk2::Command::CommandStopActor => 0,
+			});
+		}
+
 		let mut checkpoints = Vec::new();
 		for command in commands {
 			let checkpoint = command.checkpoint.clone();
@@ -583,18 +1474,64 @@ impl Runner {
 		checkpoint: mk2::ActorCheckpoint,
 		start: mk2::CommandStartActor,
 	) -> Result<()> {
-		let factory = self
-			.actor_factories
-			.get(&start.config.name)
-			.cloned()
-			.unwrap_or_else(|| Arc::new(|_| Box::new(EchoActor::new())));
+		if self.draining.load(Ordering::SeqCst) {
+			tracing::debug!(actor_id = ?checkpoint.actor_id, "rejecting actor while draining");
+			let _ = self.lifecycle_tx.send(ActorLifecycleEvent::DrainRejected {
+				actor_id: checkpoint.actor_id.clone(),
+			});
+			self.send_state_stopped(
+				checkpoint.actor_id,
+				checkpoint.generation,
+				1,
+				Some("runner is draining".to_string()),
+			);
+			return Ok(());
+		}
+
+		let factory = match self.actor_factories.get(&start.config.name) {
+			Some(factory) => factory.clone(),
+			None => {
+				let rejected = matches!(self.unknown_actor_policy, UnknownActorPolicy::Reject);
+				let _ = self.lifecycle_tx.send(ActorLifecycleEvent::UnknownActor {
+					actor_id: checkpoint.actor_id.clone(),
+					name: start.config.name.clone(),
+					rejected,
+				});
+
+				match &self.unknown_actor_policy {
+					UnknownActorPolicy::Fallback(factory) => factory.clone(),
+					UnknownActorPolicy::Reject => {
+						tracing::warn!(
+							actor_id = ?checkpoint.actor_id,
+							name = ?start.config.name,
+							"rejecting actor with no registered behavior"
+						);
+						self.send_state_stopped(
+							checkpoint.actor_id,
+							checkpoint.generation,
+							1,
+							Some(format!(
+								"no behavior registered for actor name '{}'",
+								start.config.name
+							)),
+						);
+						return Ok(());
+					}
+				}
+			}
+		};
 		let (actor_event_tx, actor_event_rx) = mpsc::unbounded_channel();
+		let seed = self.rng.lock().expect("rng lock").r#gen::<u64>();
+		let request_limit = self.request_limits.get(&start.config.name).copied();
 		let config = ActorConfig::new(
 			&start.config,
 			checkpoint.actor_id.clone(),
 			checkpoint.generation,
+			seed,
+			request_limit,
 			actor_event_tx,
 			self.kv_request_tx.clone(),
+			self.log_tx.clone(),
 		);
 		let runner = self.clone_for_task();
 
@@ -608,6 +1545,7 @@ impl Runner {
 							checkpoint.actor_id,
 							checkpoint.generation,
 							actor,
+							request_limit,
 							start_result,
 							actor_event_rx,
 						)
@@ -627,6 +1565,7 @@ impl Runner {
 		actor_id: String,
 		generation: u32,
 		actor: Box<dyn TestActor>,
+		request_limit: Option<(usize, RequestLimitPolicy)>,
 		start_result: ActorStartResult,
 		mut actor_event_rx: mpsc::UnboundedReceiver<ActorEvent>,
 	) {
@@ -634,10 +1573,18 @@ impl Runner {
 			actor_id: actor_id.clone(),
 			generation,
 		});
-		self.actors
-			.lock()
-			.await
-			.insert(actor_id.clone(), ActorState { generation, actor });
+		let mut actors = self.actors.lock().await;
+		actors.insert(
+			actor_id.clone(),
+			ActorState {
+				generation,
+				actor,
+				request_slots: request_limit
+					.map(|(max, policy)| (Arc::new(Semaphore::new(max)), policy)),
+			},
+		);
+		self.record_active_actors(actors.len());
+		drop(actors);
 
 		match start_result {
 			ActorStartResult::Running => {
@@ -662,11 +1609,19 @@ impl Runner {
 			ActorStartResult::Crash { code, message } => {
 				drain_actor_events_to(self.event_tx.clone(), &mut actor_event_rx);
 				self.send_state_stopped(actor_id.clone(), generation, code, Some(message));
-				self.actors.lock().await.remove(&actor_id);
+				let mut actors = self.actors.lock().await;
+				actors.remove(&actor_id);
+				self.record_active_actors(actors.len());
 			}
 		}
 	}
 
+	fn record_active_actors(&self, count: usize) {
+		if let Some(metrics) = &self.metrics {
+			metrics.set_active_actors(count);
+		}
+	}
+
 	fn forward_actor_events(&self, actor_event_rx: mpsc::UnboundedReceiver<ActorEvent>) {
 		forward_actor_events_to(self.event_tx.clone(), actor_event_rx);
 	}
@@ -676,6 +1631,7 @@ impl Runner {
 		let Some(mut actor_state) = actors.remove(&checkpoint.actor_id) else {
 			return Ok(());
 		};
+		self.record_active_actors(actors.len());
 		let stop_result = actor_state.actor.on_stop().await?;
 		drop(actors);
 
@@ -755,6 +1711,7 @@ impl Runner {
 			inner: actor_event.event,
 		};
 		drop(indices);
+		self.unacked_events.lock().await.push(event.clone());
 
 		ws_stream
 			.send(Message::Binary(
@@ -793,57 +1750,321 @@ impl Runner {
 		ws_stream: &mut WsStream,
 		message: mk2::ToClientTunnelMessage,
 	) -> Result<()> {
-		let response = match message.message_kind {
-			mk2::ToClientTunnelMessageKind::ToClientRequestStart(req) => {
-				let (status, body) = if req.path == "/ping" && self.has_actor(&req.actor_id).await {
-					(
-						200,
-						serde_json::to_vec(&serde_json::json!({
-							"actorId": req.actor_id,
-							"status": "ok",
-							"timestamp": rivet_util::timestamp::now(),
-						}))?,
-					)
-				} else {
-					(404, b"not found".to_vec())
+		let message_id = message.message_id;
+		let stream_key = (message_id.gateway_id, message_id.request_id);
+		let (req, body) = match message.message_kind {
+			// Streamed bodies arrive as chunks after the start. The request is handled once the
+			// last chunk arrives.
+			mk2::ToClientTunnelMessageKind::ToClientRequestStart(req) if req.stream => {
+				self.streaming_requests
+					.lock()
+					.await
+					.insert(stream_key, (req, Vec::new()));
+				return Ok(());
+			}
+			mk2::ToClientTunnelMessageKind::ToClientRequestStart(mut req) => {
+				let body = req.body.take().unwrap_or_default();
+				(req, body)
+			}
+			mk2::ToClientTunnelMessageKind::ToClientRequestChunk(chunk) => {
+				let mut streaming_requests = self.streaming_requests.lock().await;
+				let Some((_, body)) = streaming_requests.get_mut(&stream_key) else {
+					return Ok(());
 				};
-				Some(mk2::ToServerTunnelMessageKind::ToServerResponseStart(
-					mk2::ToServerResponseStart {
-						status,
-						headers: HashMap::new().into(),
-						body: Some(body),
-						stream: false,
-					},
-				))
+				body.extend(chunk.body);
+				if !chunk.finish {
+					return Ok(());
+				}
+				streaming_requests
+					.remove(&stream_key)
+					.expect("streaming request should exist")
+			}
+			mk2::ToClientTunnelMessageKind::ToClientRequestAbort => {
+				self.streaming_requests.lock().await.remove(&stream_key);
+				return Ok(());
+			}
+			mk2::ToClientTunnelMessageKind::ToClientWebSocketOpen(open) => {
+				self.handle_websocket_open(message_id, open).await;
+				return Ok(());
+			}
+			mk2::ToClientTunnelMessageKind::ToClientWebSocketMessage(msg) => {
+				self.handle_websocket_message(message_id, msg).await;
+				return Ok(());
+			}
+			mk2::ToClientTunnelMessageKind::ToClientWebSocketClose(close) => {
+				self.handle_websocket_close(message_id, close).await;
+				return Ok(());
 			}
-			_ => None,
 		};
 
-		if let Some(message_kind) = response {
-			ws_stream
-				.send(Message::Binary(
-					self.encode_to_server(mk2::ToServer::ToServerTunnelMessage(
-						mk2::ToServerTunnelMessage {
-							message_id: message.message_id,
-							message_kind,
-						},
-					))?
-					.into(),
-				))
-				.await?;
+		let (action, request_slots) = match self.actors.lock().await.get_mut(&req.actor_id) {
+			Some(state) => (
+				Some(state.actor.on_request(&req.path)),
+				state.request_slots.clone(),
+			),
+			None => (None, None),
+		};
+		let mut delay = None;
+		match action {
+			Some(ActorRequestAction::ReconnectThenRespond) => {
+				tracing::info!(actor_id = ?req.actor_id, "reconnecting runner mid request");
+				let policy = self.config.reconnect_policy.clone().unwrap_or_default();
+				self.reconnect(ws_stream, &policy).await?;
+			}
+			Some(ActorRequestAction::DelayThenRespond(duration)) => delay = Some(duration),
+			_ => {}
 		}
+
+		// Respond from a separate task so slow requests do not block the connection and a
+		// shutdown can wait for them to finish
+		self.in_flight_requests.fetch_add(1, Ordering::SeqCst);
+		let tunnel_response_tx = self.tunnel_response_tx.clone();
+		let lifecycle_tx = self.lifecycle_tx.clone();
+		let actor_found = action.is_some();
+		let received_at = Instant::now();
+		tokio::spawn(async move {
+			// Held until the response is handed to the message loop
+			let (permit, rejected) = match request_slots {
+				Some((slots, policy)) => match slots.clone().try_acquire_owned() {
+					Ok(permit) => (Some(permit), false),
+					Err(_) => {
+						let _ = lifecycle_tx.send(ActorLifecycleEvent::RequestLimited {
+							actor_id: req.actor_id.clone(),
+							rejected: policy == RequestLimitPolicy::Reject,
+						});
+						match policy {
+							RequestLimitPolicy::Queue => (slots.acquire_owned().await.ok(), false),
+							RequestLimitPolicy::Reject => (None, true),
+						}
+					}
+				},
+				None => (None, false),
+			};
+
+			if let (Some(delay), false) = (delay, rejected) {
+				tokio::time::sleep(delay).await;
+			}
+
+			let (status, body) = if rejected {
+				(429, b"too many requests".to_vec())
+			} else if req.path == "/ping" && actor_found {
+				(
+					200,
+					serde_json::json!({
+						"actorId": req.actor_id,
+						"status": "ok",
+						"timestamp": rivet_util::timestamp::now(),
+					})
+					.to_string()
+					.into_bytes(),
+				)
+			} else if req.path == "/body-size" && actor_found {
+				(
+					200,
+					serde_json::json!({ "bodySize": body.len() })
+						.to_string()
+						.into_bytes(),
+				)
+			} else if matches!(req.path.as_str(), "/stream" | "/stream-stall") && actor_found {
+				// Body is sent in chunks below
+				(200, Vec::new())
+			} else {
+				(404, b"not found".to_vec())
+			};
+
+			// `/stream` sends its body in chunks over time. `/stream-stall` sends one chunk and then
+			// goes quiet for longer than a test idle timeout.
+			let stream_chunks = match req.path.as_str() {
+				"/stream" if status == 200 => Some((3u16, true)),
+				"/stream-stall" if status == 200 => Some((1u16, false)),
+				_ => None,
+			};
+
+			let send = |message_index: u16, message_kind, finished| {
+				let _ = tunnel_response_tx.send(TunnelResponse {
+					actor_id: req.actor_id.clone(),
+					status,
+					received_at,
+					message: mk2::ToServerTunnelMessage {
+						message_id: mk2::MessageId {
+							message_index: message_id.message_index.wrapping_add(message_index),
+							..message_id
+						},
+						message_kind,
+					},
+					finished,
+				});
+			};
+
+			match stream_chunks {
+				Some((chunk_count, finish)) => {
+					send(
+						0,
+						mk2::ToServerTunnelMessageKind::ToServerResponseStart(
+							mk2::ToServerResponseStart {
+								status,
+								headers: HashMap::new().into(),
+								body: None,
+								stream: true,
+							},
+						),
+						false,
+					);
+
+					for i in 0..chunk_count {
+						tokio::time::sleep(STREAM_CHUNK_INTERVAL).await;
+						let last = finish && i + 1 == chunk_count;
+						send(
+							i + 1,
+							mk2::ToServerTunnelMessageKind::ToServerResponseChunk(
+								mk2::ToServerResponseChunk {
+									body: format!("chunk-{i}\n").into_bytes(),
+									finish: last,
+								},
+							),
+							last,
+						);
+					}
+
+					if !finish {
+						tokio::time::sleep(STREAM_STALL_DURATION).await;
+						send(
+							chunk_count + 1,
+							mk2::ToServerTunnelMessageKind::ToServerResponseChunk(
+								mk2::ToServerResponseChunk {
+									body: Vec::new(),
+									finish: true,
+								},
+							),
+							true,
+						);
+					}
+				}
+				None => send(
+					0,
+					mk2::ToServerTunnelMessageKind::ToServerResponseStart(
+						mk2::ToServerResponseStart {
+							status,
+							headers: HashMap::new().into(),
+							body: Some(body),
+							stream: false,
+						},
+					),
+					true,
+				),
+			}
+			drop(permit);
+		});
+
 		Ok(())
 	}
 
+	async fn handle_websocket_open(
+		&self,
+		message_id: mk2::MessageId,
+		open: mk2::ToClientWebSocketOpen,
+	) {
+		let sender = WebSocketSender::new(
+			open.actor_id.clone(),
+			message_id.gateway_id,
+			message_id.request_id,
+			self.tunnel_response_tx.clone(),
+		);
+		let action = match self.actors.lock().await.get_mut(&open.actor_id) {
+			Some(state) => state.actor.on_websocket_open(sender.clone(), &open.path),
+			None => WebSocketOpenAction::Close {
+				code: 1011,
+				reason: "actor not found".to_string(),
+			},
+		};
+
+		match action {
+			WebSocketOpenAction::Accept { can_hibernate } => {
+				sender.accept(can_hibernate);
+				self.websockets.lock().await.insert(
+					(message_id.gateway_id, message_id.request_id),
+					OpenWebSocket {
+						sender,
+						can_hibernate,
+					},
+				);
+			}
+			WebSocketOpenAction::Close { code, reason } => sender.reject(code, reason),
+		}
+	}
+
+	async fn handle_websocket_message(
+		&self,
+		message_id: mk2::MessageId,
+		msg: mk2::ToClientWebSocketMessage,
+	) {
+		let key = (message_id.gateway_id, message_id.request_id);
+		let mut websockets = self.websockets.lock().await;
+		let Some(ws) = websockets.get(&key) else {
+			return;
+		};
+		if ws.sender.is_closed() {
+			websockets.remove(&key);
+			return;
+		}
+		let (sender, can_hibernate) = (ws.sender.clone(), ws.can_hibernate);
+		drop(websockets);
+
+		// The gateway holds messages on hibernatable connections until they are acked, so it can
+		// replay them after the actor wakes
+		if can_hibernate {
+			sender.send(mk2::ToServerTunnelMessageKind::ToServerWebSocketMessageAck(
+				mk2::ToServerWebSocketMessageAck {
+					index: message_id.message_index,
+				},
+			));
+		}
+
+		if let Some(state) = self.actors.lock().await.get_mut(sender.actor_id()) {
+			state
+				.actor
+				.on_websocket_message(&sender, &msg.data, msg.binary);
+		}
+	}
+
+	async fn handle_websocket_close(
+		&self,
+		message_id: mk2::MessageId,
+		close: mk2::ToClientWebSocketClose,
+	) {
+		let Some(ws) = self
+			.websockets
+			.lock()
+			.await
+			.remove(&(message_id.gateway_id, message_id.request_id))
+		else {
+			return;
+		};
+		ws.sender.mark_closed();
+
+		if let Some(state) = self.actors.lock().await.get_mut(ws.sender.actor_id()) {
+			state
+				.actor
+				.on_websocket_close(&ws.sender, close.code, close.reason.as_deref());
+		}
+	}
+
 	fn build_ws_url(&self) -> String {
 		let endpoint = self.config.endpoint.replace("http://", "ws://");
-		format!(
+		let mut url = format!(
 			"{}/runners/connect?protocol_version={}&namespace={}&runner_key={}",
 			endpoint.trim_end_matches('/'),
 			PROTOCOL_MK2_VERSION,
 			urlencoding::encode(&self.config.namespace),
 			urlencoding::encode(&self.config.runner_key),
-		)
+		);
+		if !self.config.capabilities.is_empty() {
+			url.push_str(&format!(
+				"&capabilities={}",
+				urlencoding::encode(&self.config.capabilities.join(","))
+			));
+		}
+		url
 	}
 
 	fn build_init(&self) -> mk2::ToServer {
@@ -856,6 +2077,7 @@ impl Runner {
 				serde_json::json!({
 					"runner_key": self.config.runner_key,
 					"total_slots": self.config.total_slots,
+					"actor_names": *self.actor_names,
 				})
 				.to_string(),
 			),
@@ -863,6 +2085,14 @@ impl Runner {
 	}
 
 	fn encode_to_server(&self, msg: mk2::ToServer) -> Result<Vec<u8>> {
+		self.frames
+			.sent
+			.lock()
+			.expect("frame log lock")
+			.push(msg.clone());
+		if let Some(metrics) = &self.metrics {
+			metrics.frame_sent();
+		}
 		versioned::ToServerMk2::wrap_latest(msg)
 			.serialize(PROTOCOL_MK2_VERSION)
 			.map_err(Into::into)
@@ -879,6 +2109,40 @@ impl Runner {
 			.expect("runner id should be set when ready")
 	}
 
+	pub fn status(&self) -> RunnerStatus {
+		*self.status.borrow()
+	}
+
+	/// Watches the connection status, e.g. to observe reconnects.
+	pub fn subscribe_status(&self) -> watch::Receiver<RunnerStatus> {
+		self.status.subscribe()
+	}
+
+	/// Waits until the engine has initialized the runner. Fails if the runner stops or the timeout
+	/// passes first.
+	pub async fn wait_connected(&self, timeout: Duration) -> Result<()> {
+		let mut status_rx = self.status.subscribe();
+		let status = tokio::time::timeout(
+			timeout,
+			status_rx.wait_for(|status| {
+				matches!(status, RunnerStatus::Connected | RunnerStatus::Stopped)
+			}),
+		)
+		.await
+		.context("timed out waiting for runner to connect")??;
+
+		if *status == RunnerStatus::Stopped {
+			bail!("runner stopped before connecting");
+		}
+
+		Ok(())
+	}
+
+	/// Whether the runner's websocket is still open.
+	pub fn is_connected(&self) -> bool {
+		self.connected.load(Ordering::SeqCst)
+	}
+
 	pub async fn has_actor(&self, actor_id: &str) -> bool {
 		self.actors.lock().await.contains_key(actor_id)
 	}
@@ -891,18 +2155,122 @@ impl Runner {
 		&self.config.runner_name
 	}
 
+	/// Whether a frame matching `predicate` has been sent to the engine.
+	pub fn has_sent<F>(&self, predicate: F) -> bool
+	where
+		F: Fn(&mk2::ToServer) -> bool,
+	{
+		self.frames
+			.sent
+			.lock()
+			.expect("frame log lock")
+			.iter()
+			.any(predicate)
+	}
+
+	/// Number of frames matching `predicate` sent to the engine.
+	pub fn count_sent<F>(&self, predicate: F) -> usize
+	where
+		F: Fn(&mk2::ToServer) -> bool,
+	{
+		self.frames
+			.sent
+			.lock()
+			.expect("frame log lock")
+			.iter()
+			.filter(|frame| predicate(frame))
+			.count()
+	}
+
+	/// Panics unless a frame matching `predicate` has been sent to the engine.
+	pub fn assert_sent<F>(&self, predicate: F)
+	where
+		F: Fn(&mk2::ToServer) -> bool,
+	{
+		assert!(
+			self.has_sent(predicate),
+			"no matching frame among {} sent frames",
+			self.frames.sent.lock().expect("frame log lock").len()
+		);
+	}
+
+	/// Number of frames matching `predicate` received from the engine.
+	pub fn count_received<F>(&self, predicate: F) -> usize
+	where
+		F: Fn(&mk2::ToClient) -> bool,
+	{
+		self.frames
+			.received
+			.lock()
+			.expect("frame log lock")
+			.iter()
+			.filter(|frame| predicate(frame))
+			.count()
+	}
+
+	/// Waits up to `timeout` for a frame matching `predicate` to be received from the engine.
+	/// Frames received before the call count.
+	pub async fn assert_received<F>(&self, predicate: F, timeout: Duration)
+	where
+		F: Fn(&mk2::ToClient) -> bool,
+	{
+		let res = tokio::time::timeout(timeout, async {
+			loop {
+				if self
+					.frames
+					.received
+					.lock()
+					.expect("frame log lock")
+					.iter()
+					.any(&predicate)
+				{
+					break;
+				}
+				tokio::time::sleep(Duration::from_millis(25)).await;
+			}
+		})
+		.await;
+		if res.is_err() {
+			panic!(
+				"no matching frame among {} received frames after {timeout:?}",
+				self.frames.received.lock().expect("frame log lock").len()
+			);
+		}
+	}
+
 	pub fn subscribe_lifecycle_events(&self) -> broadcast::Receiver<ActorLifecycleEvent> {
 		self.lifecycle_tx.subscribe()
 	}
 
+	/// Sends `ToServerStopping` and closes the connection once in-flight requests have responded
+	/// or the drain timeout passes.
 	pub async fn shutdown(&self) {
 		let _ = self.control_tx.send(Control::Shutdown);
 		self.actors.lock().await.clear();
+		self.record_active_actors(0);
+	}
+
+	/// Sends `ToServerStopping`, rejects new actors, and waits for the engine to stop every running
+	/// actor and for in-flight requests to respond. Closes the connection once drained or after
+	/// `timeout`, and returns when the runner has stopped.
+	pub async fn drain(&self, timeout: Duration) {
+		let mut status_rx = self.status.subscribe();
+		let _ = self.control_tx.send(Control::Drain(timeout));
+		let _ = status_rx
+			.wait_for(|status| *status == RunnerStatus::Stopped)
+			.await;
+	}
+
+	/// Drops the connection without sending `ToServerStopping`, like a network failure. The runner
+	/// reconnects with the same key after a short delay and keeps its actors.
+	pub fn disconnect(&self) {
+		let _ = self.control_tx.send(Control::Disconnect);
 	}
 
 	pub async fn crash(&self) {
 		let _ = self.control_tx.send(Control::Crash);
 		self.actors.lock().await.clear();
+		self.record_active_actors(0);
 	}
 }
 
@@ -949,7 +2317,17 @@ pub struct TestRunnerBuilder {
 	runner_key: String,
 	version: u32,
 	total_slots: u32,
-	actor_factories: HashMap<String, ActorFactory>,
+	capabilities: Vec<String>,
+	actor_factories: Vec<(String, ActorFactory)>,
+	start_order: Vec<String>,
+	seed: Option<u64>,
+	drain_timeout: Option<Duration>,
+	reconnect_policy: Option<ReconnectPolicy>,
+	endpoint: Option<String>,
+	token: Option<String>,
+	request_limits: Vec<(String, usize, RequestLimitPolicy)>,
+	metrics: Option<Arc<dyn RunnerMetrics>>,
+	unknown_actor_policy: Option<UnknownActorPolicy>,
 }
 
 impl TestRunnerBuilder {
@@ -960,7 +2338,17 @@ impl TestRunnerBuilder {
 			runner_key: format!("key-{:012x}", rand::random::<u64>()),
 			version: 1,
 			total_slots: 100,
-			actor_factories: HashMap::new(),
+			capabilities: Vec::new(),
+			actor_factories: Vec::new(),
+			start_order: Vec::new(),
+			seed: None,
+			drain_timeout: None,
+			reconnect_policy: None,
+			endpoint: None,
+			token: None,
+			request_limits: Vec::new(),
+			metrics: None,
+			unknown_actor_policy: None,
 		}
 	}
 
@@ -984,30 +2372,128 @@ impl TestRunnerBuilder {
 		self
 	}
 
+	pub fn with_capabilities(mut self, capabilities: &[&str]) -> Self {
+		self.capabilities = capabilities.iter().map(|c| c.to_string()).collect();
+		self
+	}
+
 	pub fn with_actor_behavior<F>(mut self, actor_name: &str, factory: F) -> Self
 	where
 		F: Fn(ActorConfig) -> Box<dyn TestActor> + Send + Sync + 'static,
 	{
-		self.actor_factories
-			.insert(actor_name.to_string(), Arc::new(factory));
+		let factory: ActorFactory = Arc::new(factory);
+		match self
+			.actor_factories
+			.iter_mut()
+			.find(|(name, _)| name == actor_name)
+		{
+			Some((_, existing)) => *existing = factory,
+			None => self.actor_factories.push((actor_name.to_string(), factory)),
+		}
+		self
+	}
+
+	/// Order actors are announced to the engine and started in when a batch of start commands
+	/// arrives. Unlisted actors follow in registration order.
+	pub fn start_order(mut self, actor_names: Vec<&str>) -> Self {
+		self.start_order = actor_names.into_iter().map(ToString::to_string).collect();
+		self
+	}
+
+	/// Seeds the runner's randomness. See `RunnerBuilder::with_seed`.
+	pub fn with_seed(mut self, seed: u64) -> Self {
+		self.seed = Some(seed);
+		self
+	}
+
+	pub fn with_metrics(mut self, metrics: Arc<dyn RunnerMetrics>) -> Self {
+		self.metrics = Some(metrics);
+		self
+	}
+
+	/// See `RunnerBuilder::with_unknown_actor_policy`.
+	pub fn with_unknown_actor_policy(mut self, policy: UnknownActorPolicy) -> Self {
+		self.unknown_actor_policy = Some(policy);
+		self
+	}
+
+	/// See `RunnerBuilder::with_max_concurrent_requests`.
+	pub fn with_max_concurrent_requests(
+		mut self,
+		actor_name: &str,
+		max_concurrent_requests: usize,
+		policy: RequestLimitPolicy,
+	) -> Self {
+		self.request_limits
+			.push((actor_name.to_string(), max_concurrent_requests, policy));
+		self
+	}
+
+	/// See `RunnerConfigBuilder::drain_timeout`.
+	pub fn with_drain_timeout(mut self, drain_timeout: Duration) -> Self {
+		self.drain_timeout = Some(drain_timeout);
+		self
+	}
+
+	/// See `RunnerConfigBuilder::reconnect_policy`.
+	pub fn with_reconnect_policy(mut self, reconnect_policy: ReconnectPolicy) -> Self {
+		self.reconnect_policy = Some(reconnect_policy);
+		self
+	}
+
+	/// Connects through the given endpoint instead of the datacenter's guard, e.g. a proxy that
+	/// injects network faults.
+	pub fn with_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+		self.endpoint = Some(endpoint.into());
+		self
+	}
+
+	/// Token sent when connecting. Defaults to `dev`.
+	pub fn with_token(mut self, token: impl Into<String>) -> Self {
+		self.token = Some(token.into());
 		self
 	}
 
 	pub async fn build(self, dc: &super::TestDatacenter) -> Result<Runner> {
-		let config = RunnerConfig::builder()
-			.endpoint(format!("http://127.0.0.1:{}", dc.guard_port()))
-			.token("dev")
+		let mut config = RunnerConfig::builder()
+			.endpoint(
+				self.endpoint
+					.unwrap_or_else(|| format!("http://127.0.0.1:{}", dc.guard_port())),
+			)
+			.token(self.token.unwrap_or_else(|| "dev".to_string()))
 			.namespace(&self.namespace)
 			.runner_name(&self.runner_name)
 			.runner_key(&self.runner_key)
 			.version(self.version)
 			.total_slots(self.total_slots)
-			.build()?;
+			.capabilities(self.capabilities);
+		if let Some(drain_timeout) = self.drain_timeout {
+			config = config.drain_timeout(drain_timeout);
+		}
+		if let Some(reconnect_policy) = self.reconnect_policy {
+			config = config.reconnect_policy(reconnect_policy);
+		}
+		let config = config.build()?;
 
 		let mut builder = RunnerBuilder::new(config);
+		if let Some(seed) = self.seed {
+			builder = builder.with_seed(seed);
+		}
 		for (name, factory) in self.actor_factories {
 			builder = builder.with_actor_behavior(&name, move |config| factory(config));
 		}
+		if let Some(metrics) = self.metrics {
+			builder = builder.with_metrics(metrics);
+		}
+		if let Some(policy) = self.unknown_actor_policy {
+			builder = builder.with_unknown_actor_policy(policy);
+		}
+		for (name, max_concurrent_requests, policy) in self.request_limits {
+			builder = builder.with_max_concurrent_requests(&name, max_concurrent_requests, policy);
+		}
+		if !self.start_order.is_empty() {
+			builder = builder.with_start_order(self.start_order);
+		}
 		builder.build()
 	}
 }
@@ -1284,6 +2770,336 @@ impl TestActor for StopImmediatelyActor {
 	}
 }
 
+/// Actor that makes its runner reconnect while handling its first request, so the response is sent
+/// on a new connection.
+pub struct ReconnectMidRequestActor {
+	reconnected: bool,
+}
+
+impl ReconnectMidRequestActor {
+	pub fn new() -> Self {
+		Self { reconnected: false }
+	}
+}
+
+#[async_trait]
+impl TestActor for ReconnectMidRequestActor {
+	async fn on_start(&mut self, _config: ActorConfig) -> Result<ActorStartResult> {
+		Ok(ActorStartResult::Running)
+	}
+
+	async fn on_stop(&mut self) -> Result<ActorStopResult> {
+		Ok(ActorStopResult::Success)
+	}
+
+	fn on_request(&mut self, _path: &str) -> ActorRequestAction {
+		if std::mem::replace(&mut self.reconnected, true) {
+			ActorRequestAction::Respond
+		} else {
+			ActorRequestAction::ReconnectThenRespond
+		}
+	}
+
+	fn name(&self) -> &str {
+		"ReconnectMidRequestActor"
+	}
+}
+
+/// Actor that counts the requests it receives and responds to each after a delay.
+pub struct SlowRequestActor {
+	delay: Duration,
+	request_count: Arc<AtomicU32>,
+}
+
+impl SlowRequestActor {
+	pub fn new(delay: Duration, request_count: Arc<AtomicU32>) -> Self {
+		Self {
+			delay,
+			request_count,
+		}
+	}
+}
+
+#[async_trait]
+impl TestActor for SlowRequestActor {
+	async fn on_start(&mut self, _config: ActorConfig) -> Result<ActorStartResult> {
+		Ok(ActorStartResult::Running)
+	}
+
+	async fn on_stop(&mut self) -> Result<ActorStopResult> {
+		Ok(ActorStopResult::Success)
+	}
+
+	fn on_request(&mut self, _path: &str) -> ActorRequestAction {
+		self.request_count.fetch_add(1, Ordering::SeqCst);
+		ActorRequestAction::DelayThenRespond(self.delay)
+	}
+
+	fn name(&self) -> &str {
+		"SlowRequestActor"
+	}
+}
+
+/// Actor that goes to sleep instead of responding to the first request it receives, across all of
+/// its generations. Later requests are answered normally.
+pub struct SleepOnFirstRequestActor {
+	request_count: Arc<AtomicU32>,
+	config: Option<ActorConfig>,
+}
+
+impl SleepOnFirstRequestActor {
+	pub fn new(request_count: Arc<AtomicU32>) -> Self {
+		Self {
+			request_count,
+			config: None,
+		}
+	}
+}
+
+#[async_trait]
+impl TestActor for SleepOnFirstRequestActor {
+	async fn on_start(&mut self, config: ActorConfig) -> Result<ActorStartResult> {
+		self.config = Some(config);
+		Ok(ActorStartResult::Running)
+	}
+
+	async fn on_stop(&mut self) -> Result<ActorStopResult> {
+		Ok(ActorStopResult::Success)
+	}
+
+	fn on_request(&mut self, _path: &str) -> ActorRequestAction {
+		if self.request_count.fetch_add(1, Ordering::SeqCst) > 0 {
+			return ActorRequestAction::Respond;
+		}
+
+		if let Some(config) = &self.config {
+			config.send_sleep_intent();
+		}
+		// Outlives the actor, so the gateway sees it stop before any response
+		ActorRequestAction::DelayThenRespond(Duration::from_secs(30))
+	}
+
+	fn name(&self) -> &str {
+		"SleepOnFirstRequestActor"
+	}
+}
+
+/// Actor that accepts WebSockets and sends every message back unchanged.
+pub struct WebSocketEchoActor;
+
+impl WebSocketEchoActor {
+	pub fn new() -> Self {
+		Self
+	}
+}
+
+impl Default for WebSocketEchoActor {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[async_trait]
+impl TestActor for WebSocketEchoActor {
+	async fn on_start(&mut self, _config: ActorConfig) -> Result<ActorStartResult> {
+		Ok(ActorStartResult::Running)
+	}
+
+	async fn on_stop(&mut self) -> Result<ActorStopResult> {
+		Ok(ActorStopResult::Success)
+	}
+
+	fn on_websocket_open(&mut self, _ws: WebSocketSender, _path: &str) -> WebSocketOpenAction {
+		WebSocketOpenAction::Accept {
+			can_hibernate: false,
+		}
+	}
+
+	fn on_websocket_message(&mut self, ws: &WebSocketSender, data: &[u8], binary: bool) {
+		echo(ws, data, binary);
+	}
+
+	fn name(&self) -> &str {
+		"WebSocketEchoActor"
+	}
+}
+
+/// Actor that sends `ping <n>` on each WebSocket at a fixed interval until it closes.
+pub struct WebSocketPingActor {
+	interval: Duration,
+}
+
+impl WebSocketPingActor {
+	pub fn new(interval: Duration) -> Self {
+		Self { interval }
+	}
+}
+
+#[async_trait]
+impl TestActor for WebSocketPingActor {
+	async fn on_start(&mut self, _config: ActorConfig) -> Result<ActorStartResult> {
+		Ok(ActorStartResult::Running)
+	}
+
+	async fn on_stop(&mut self) -> Result<ActorStopResult> {
+		Ok(ActorStopResult::Success)
+	}
+
+	fn on_websocket_open(&mut self, ws: WebSocketSender, _path: &str) -> WebSocketOpenAction {
+		let interval = self.interval;
+		tokio::spawn(async move {
+			let mut n = 0u64;
+			loop {
+				tokio::time::sleep(interval).await;
+				if ws.is_closed() {
+					break;
+				}
+				ws.send_text(format!("ping {n}"));
+				n += 1;
+			}
+		});
+
+		WebSocketOpenAction::Accept {
+			can_hibernate: false,
+		}
+	}
+
+	fn name(&self) -> &str {
+		"WebSocketPingActor"
+	}
+}
+
+/// Actor that echoes WebSocket messages and closes the connection with `code` once it has received
+/// `count` messages on it.
+pub struct WebSocketCloseAfterActor {
+	count: usize,
+	code: u16,
+	received: HashMap<(mk2::GatewayId, mk2::RequestId), usize>,
+}
+
+impl WebSocketCloseAfterActor {
+	pub fn new(count: usize, code: u16) -> Self {
+		Self {
+			count,
+			code,
+			received: HashMap::new(),
+		}
+	}
+}
+
+#[async_trait]
+impl TestActor for WebSocketCloseAfterActor {
+	async fn on_start(&mut self, _config: ActorConfig) -> Result<ActorStartResult> {
+		Ok(ActorStartResult::Running)
+	}
+
+	async fn on_stop(&mut self) -> Result<ActorStopResult> {
+		Ok(ActorStopResult::Success)
+	}
+
+	fn on_websocket_open(&mut self, _ws: WebSocketSender, _path: &str) -> WebSocketOpenAction {
+		WebSocketOpenAction::Accept {
+			can_hibernate: false,
+		}
+	}
+
+	fn on_websocket_message(&mut self, ws: &WebSocketSender, data: &[u8], binary: bool) {
+		echo(ws, data, binary);
+
+		let received = self
+			.received
+			.entry((ws.gateway_id, ws.request_id))
+			.or_default();
+		*received += 1;
+		if *received >= self.count {
+			ws.close(
+				Some(self.code),
+				Some(format!("closing after {} messages", self.count)),
+			);
+		}
+	}
+
+	fn on_websocket_close(
+		&mut self,
+		ws: &WebSocketSender,
+		_code: Option<u16>,
+		_reason: Option<&str>,
+	) {
+		self.received.remove(&(ws.gateway_id, ws.request_id));
+	}
+
+	fn name(&self) -> &str {
+		"WebSocketCloseAfterActor"
+	}
+}
+
+/// Actor that accepts hibernatable WebSockets and echoes their messages. A `hibernate` text message
+/// makes it ask the gateway to hibernate the connection instead. A `hibernate-close` text message
+/// hibernates the connection, then closes it with [`WebSocketHibernateActor::CLOSE_CODE`] while it
+/// is hibernating.
+pub struct WebSocketHibernateActor;
+
+impl WebSocketHibernateActor {
+	pub const CLOSE_CODE: u16 = 4002;
+	pub const CLOSE_REASON: &'static str = "closed while hibernating";
+
+	pub fn new() -> Self {
+		Self
+	}
+}
+
+impl Default for WebSocketHibernateActor {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[async_trait]
+impl TestActor for WebSocketHibernateActor {
+	async fn on_start(&mut self, _config: ActorConfig) -> Result<ActorStartResult> {
+		Ok(ActorStartResult::Running)
+	}
+
+	async fn on_stop(&mut self) -> Result<ActorStopResult> {
+		Ok(ActorStopResult::Success)
+	}
+
+	fn on_websocket_open(&mut self, _ws: WebSocketSender, _path: &str) -> WebSocketOpenAction {
+		WebSocketOpenAction::Accept {
+			can_hibernate: true,
+		}
+	}
+
+	fn on_websocket_message(&mut self, ws: &WebSocketSender, data: &[u8], binary: bool) {
+		if !binary && data == b"hibernate" {
+			ws.hibernate();
+		} else if !binary && data == b"hibernate-close" {
+			ws.hibernate();
+
+			// Give the gateway time to start hibernating before closing
+			let ws = ws.clone();
+			tokio::spawn(async move {
+				tokio::time::sleep(Duration::from_millis(250)).await;
+				ws.close(Some(Self::CLOSE_CODE), Some(Self::CLOSE_REASON.to_string()));
+			});
+		} else {
+			echo(ws, data, binary);
+		}
+	}
+
+	fn name(&self) -> &str {
+		"WebSocketHibernateActor"
+	}
+}
+
+fn echo(ws: &WebSocketSender, data: &[u8], binary: bool) {
+	if binary {
+		ws.send_binary(data);
+	} else {
+		ws.send_text(String::from_utf8_lossy(data));
+	}
+}
+
 pub struct CustomActor {
 	on_start_fn: Box<
 		dyn Fn(ActorConfig) -> Pin<Box<dyn Future<Output = Result<ActorStartResult>> + Send>>
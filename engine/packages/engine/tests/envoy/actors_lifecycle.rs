@@ -106,7 +106,10 @@ fn envoy_create_actor_with_input() {
 					key: None,
 					input: Some(input_data.clone()),
 					runner_name_selector: envoy.pool_name().to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -436,7 +439,7 @@ fn public_get_or_create_with_unavailable_datacenter_returns_typed_error() {
 					key: "public-explicit-wrong-dc-key".to_string(),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
 				},
 			)
 			.await
@@ -471,7 +474,10 @@ fn public_create_with_unavailable_datacenter_returns_typed_error() {
 					key: Some("public-create-explicit-wrong-dc-key".to_string()),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -1274,3 +1280,56 @@ fn envoy_normal_pool_does_not_apply_legacy_runner_slot_capacity() {
 		}
 	});
 }
+
+#[test]
+fn envoy_actor_pending_reason_without_envoy() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let guard_port = ctx.leader_dc().guard_port();
+		let (namespace, _) = common::setup_test_namespace(ctx.leader_dc()).await;
+
+		// Connect once so the pool is upgraded to actor2, then leave it without envoys
+		let envoy = common::setup_envoy(ctx.leader_dc(), &namespace, |builder| {
+			builder.with_actor_behavior("test-actor", |_| {
+				Box::new(common::test_envoy::EchoActor::new())
+			})
+		})
+		.await;
+		envoy.shutdown().await;
+
+		let res = common::create_actor(
+			guard_port,
+			&namespace,
+			"test-actor",
+			envoy.pool_name(),
+			rivet_types::actors::CrashPolicy::Destroy,
+		)
+		.await;
+		let actor_id = res.actor.actor_id.to_string();
+
+		let start = std::time::Instant::now();
+		let pending_reason = loop {
+			let actor = common::try_get_actor(guard_port, &actor_id, &namespace)
+				.await
+				.expect("failed to get actor")
+				.expect("actor should exist");
+			if let Some(pending_reason) = actor.pending_reason {
+				break pending_reason;
+			}
+
+			if start.elapsed() > std::time::Duration::from_secs(10) {
+				panic!("actor2 actor never reported a pending reason");
+			}
+			tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+		};
+
+		// Depending on timing the envoy is either still marked as stopping or already removed
+		assert!(
+			matches!(
+				pending_reason,
+				rivet_types::actors::PendingReason::AllRunnersDraining
+					| rivet_types::actors::PendingReason::NoRunnerMatchesSelector
+			),
+			"unexpected pending reason: {pending_reason:?}"
+		);
+	});
+}
@@ -0,0 +1,79 @@
+use futures_util::{SinkExt, StreamExt};
+use tokio_tungstenite::{
+	connect_async,
+	tungstenite::{Message, client::IntoClientRequest},
+};
+
+use super::super::common;
+
+#[test]
+fn envoy_actor_websocket_round_trips_large_compressible_message() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let (namespace, _, envoy) = common::setup_test_namespace_with_envoy(ctx.leader_dc()).await;
+		assert!(
+			ctx.leader_dc()
+				.config
+				.pegboard()
+				.gateway_websocket_compression(),
+			"tunnel compression should be enabled by default"
+		);
+
+		let res = common::create_actor(
+			ctx.leader_dc().guard_port(),
+			&namespace,
+			"test-actor",
+			envoy.pool_name(),
+			rivet_types::actors::CrashPolicy::Destroy,
+		)
+		.await;
+		let actor_id = res.actor.actor_id.to_string();
+
+		common::wait_with_poll(
+			std::time::Duration::from_secs(10),
+			std::time::Duration::from_millis(100),
+			|| async {
+				common::try_get_actor(ctx.leader_dc().guard_port(), &actor_id, &namespace)
+					.await
+					.ok()
+					.flatten()
+					.and_then(|actor| actor.connectable_ts)
+			},
+		)
+		.await
+		.expect("actor never became connectable");
+
+		let mut request = format!("ws://127.0.0.1:{}/ws", ctx.leader_dc().guard_port())
+			.into_client_request()
+			.expect("failed to create websocket request");
+		request.headers_mut().insert(
+			"Sec-WebSocket-Protocol",
+			format!(
+				"rivet, rivet_target.actor, rivet_actor.{}",
+				urlencoding::encode(&actor_id)
+			)
+			.parse()
+			.unwrap(),
+		);
+		let (mut ws, _) = connect_async(request)
+			.await
+			.expect("failed to connect to websocket");
+
+		// Large enough to be compressed in both directions through the tunnel
+		let payload = r#"{"key":"value","items":[1,2,3]}"#.repeat(1024);
+		assert!(payload.len() > rivet_envoy_protocol::compression::MIN_COMPRESS_SIZE);
+
+		ws.send(Message::Text(payload.clone().into()))
+			.await
+			.expect("failed to send websocket message");
+
+		let msg = tokio::time::timeout(std::time::Duration::from_secs(5), ws.next())
+			.await
+			.expect("timed out waiting for websocket response")
+			.expect("websocket stream ended unexpectedly")
+			.expect("websocket error");
+		let Message::Text(text) = msg else {
+			panic!("unexpected websocket message: {msg:?}");
+		};
+		assert_eq!(text.as_str(), format!("Echo: {payload}"));
+	});
+}
@@ -0,0 +1,135 @@
+use futures_util::{SinkExt, StreamExt};
+use tokio::net::TcpStream;
+use tokio_tungstenite::{
+	MaybeTlsStream, WebSocketStream, connect_async,
+	tungstenite::{Message, client::IntoClientRequest, protocol::frame::coding::CloseCode},
+};
+
+use super::super::common;
+
+type ActorWebSocket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+async fn connect_actor_websocket(dc: &common::TestDatacenter, actor_id: &str) -> ActorWebSocket {
+	let mut request = format!("ws://127.0.0.1:{}/ws", dc.guard_port())
+		.into_client_request()
+		.expect("failed to create websocket request");
+	request.headers_mut().insert(
+		"Sec-WebSocket-Protocol",
+		format!(
+			"rivet, rivet_target.actor, rivet_actor.{}",
+			urlencoding::encode(actor_id)
+		)
+		.parse()
+		.unwrap(),
+	);
+
+	let (ws, _) = connect_async(request)
+		.await
+		.expect("failed to connect to websocket");
+
+	ws
+}
+
+/// Sends a message and waits for the actor to echo it. Returns the close frame instead if the gateway closed
+/// the WebSocket.
+async fn try_echo(ws: &mut ActorWebSocket) -> Result<(), Option<(CloseCode, String)>> {
+	ws.send(Message::Text("ping".into()))
+		.await
+		.map_err(|_| None)?;
+
+	let msg = tokio::time::timeout(std::time::Duration::from_secs(5), ws.next())
+		.await
+		.expect("timed out waiting for websocket response")
+		.expect("websocket stream ended unexpectedly")
+		.map_err(|_| None)?;
+
+	match msg {
+		Message::Text(text) => {
+			assert_eq!(text.as_str(), "Echo: ping");
+			Ok(())
+		}
+		Message::Close(Some(frame)) => Err(Some((frame.code, frame.reason.to_string()))),
+		other => panic!("unexpected websocket message: {other:?}"),
+	}
+}
+
+#[test]
+fn envoy_actor_websocket_limit_rejects_new_connections() {
+	common::run(
+		common::TestOpts::new(1).with_gateway_actor_max_websockets(4),
+		|ctx| async move {
+			let (namespace, _, envoy) =
+				common::setup_test_namespace_with_envoy(ctx.leader_dc()).await;
+			let max_websockets = ctx
+				.leader_dc()
+				.config
+				.pegboard()
+				.gateway_actor_max_websockets()
+				.expect("test should configure an actor websocket limit");
+
+			let res = common::create_actor(
+				ctx.leader_dc().guard_port(),
+				&namespace,
+				"test-actor",
+				envoy.pool_name(),
+				rivet_types::actors::CrashPolicy::Destroy,
+			)
+			.await;
+			let actor_id = res.actor.actor_id.to_string();
+
+			common::wait_with_poll(
+				std::time::Duration::from_secs(10),
+				std::time::Duration::from_millis(100),
+				|| async {
+					common::try_get_actor(ctx.leader_dc().guard_port(), &actor_id, &namespace)
+						.await
+						.ok()
+						.flatten()
+						.and_then(|actor| actor.connectable_ts)
+				},
+			)
+			.await
+			.expect("actor never became connectable");
+
+			// Open WebSockets up to the limit
+			let mut open = Vec::new();
+			for _ in 0..max_websockets {
+				let mut ws = connect_actor_websocket(ctx.leader_dc(), &actor_id).await;
+				try_echo(&mut ws)
+					.await
+					.expect("websocket under the limit should be accepted");
+				open.push(ws);
+			}
+
+			// The next WebSocket is closed by the gateway
+			let mut rejected = connect_actor_websocket(ctx.leader_dc(), &actor_id).await;
+			let (code, reason) = try_echo(&mut rejected)
+				.await
+				.expect_err("websocket over the limit should be rejected")
+				.expect("rejected websocket should receive a close frame");
+			assert_eq!(code, CloseCode::Again);
+			assert!(
+				reason.starts_with("guard.websocket_actor_limit_reached"),
+				"unexpected close reason {reason:?}"
+			);
+
+			// Closing a WebSocket frees up a slot
+			let mut closed = open.pop().unwrap();
+			closed.close(None).await.expect("failed to close websocket");
+
+			let accepted = common::wait_with_poll(
+				std::time::Duration::from_secs(10),
+				std::time::Duration::from_millis(200),
+				|| async {
+					let mut ws = connect_actor_websocket(ctx.leader_dc(), &actor_id).await;
+					try_echo(&mut ws).await.ok().map(|_| ws)
+				},
+			)
+			.await;
+			assert!(
+				accepted.is_some(),
+				"websocket should be accepted after another one closes"
+			);
+		},
+	);
+}
@@ -20,7 +20,10 @@ fn create_actor_valid_namespace() {
 					key: None,
 					input: None,
 					runner_name_selector: runner.pool_name().to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -60,7 +63,10 @@ fn create_actor_with_key() {
 					key: Some(key.clone()),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -98,7 +104,10 @@ fn create_actor_with_input() {
 					key: None,
 					input: Some(input_data.clone()),
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -129,7 +138,10 @@ fn create_actor_sleep_crash_policy() {
 					key: None,
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -167,7 +179,10 @@ fn create_actor_specific_datacenter() {
 					key: None,
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -201,7 +216,10 @@ fn create_actor_non_existent_namespace() {
 					key: None,
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await;
@@ -233,7 +251,10 @@ fn create_actor_invalid_datacenter() {
 					key: None,
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await;
@@ -266,7 +287,10 @@ fn create_actor_remote_datacenter_verify() {
 					key: None,
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -310,7 +334,10 @@ fn create_actor_input_large() {
 					key: None,
 					input: Some(input_data),
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -345,7 +372,10 @@ fn create_actor_input_exceeds_max_size() {
 					key: None,
 					input: Some(input_data),
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await;
@@ -378,7 +408,10 @@ fn create_actor_empty_key() {
 					key: Some("".to_string()),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await;
@@ -410,7 +443,10 @@ fn create_actor_key_at_max_size() {
 					key: Some(key.clone()),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -450,7 +486,10 @@ fn create_actor_key_exceeds_max_size() {
 					key: Some(key),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await;
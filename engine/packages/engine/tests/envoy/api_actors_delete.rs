@@ -21,7 +21,10 @@ fn delete_existing_actor_with_namespace() {
 					key: None,
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -71,7 +74,10 @@ fn delete_existing_actor_without_namespace() {
 					key: None,
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -121,7 +127,10 @@ fn delete_actor_current_datacenter() {
 					key: None,
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -170,7 +179,10 @@ fn delete_actor_remote_datacenter() {
 					key: None,
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -248,7 +260,10 @@ fn delete_actor_wrong_namespace() {
 					key: None,
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -301,7 +316,10 @@ fn delete_with_non_existent_namespace() {
 					key: None,
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -350,7 +368,10 @@ fn delete_remote_actor_verify_propagation() {
 					key: None,
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -407,7 +428,10 @@ fn delete_already_destroyed_actor() {
 					key: None,
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -469,7 +493,10 @@ fn delete_actor_twice_rapidly() {
 					key: None,
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
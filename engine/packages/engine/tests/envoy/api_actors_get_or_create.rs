@@ -25,7 +25,7 @@ fn get_or_create_creates_new_actor() {
 					key: actor_key.to_string(),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
 				},
 			)
 			.await
@@ -63,7 +63,7 @@ fn get_or_create_returns_existing_actor() {
 					key: actor_key.to_string(),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
 				},
 			)
 			.await
@@ -84,7 +84,7 @@ fn get_or_create_returns_existing_actor() {
 					key: actor_key.to_string(),
 					input: Some("different-input".to_string()), // Different input should be ignored
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
 				},
 			)
 			.await
@@ -126,7 +126,7 @@ fn get_or_create_same_name_different_keys() {
 					key: "key1".to_string(),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
 				},
 			)
 			.await
@@ -144,7 +144,7 @@ fn get_or_create_same_name_different_keys() {
 					key: "key2".to_string(),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
 				},
 			)
 			.await
@@ -187,7 +187,7 @@ fn get_or_create_idempotent() {
 						key: actor_key.to_string(),
 						input: None,
 						runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-						crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+						crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
 					},
 				)
 				.await
@@ -238,7 +238,7 @@ fn get_or_create_race_condition_handling() {
 						key: actor_key.to_string(),
 						input: None,
 						runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-						crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+						crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
 					},
 				)
 				.await
@@ -256,7 +256,7 @@ fn get_or_create_race_condition_handling() {
 						key: actor_key.to_string(),
 						input: None,
 						runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-						crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+						crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
 					},
 				)
 				.await
@@ -315,7 +315,7 @@ fn get_or_create_returns_winner_on_race() {
 							key: actor_key.to_string(),
 							input: None,
 							runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-							crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+							crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
 						},
 					)
 					.await
@@ -347,7 +347,7 @@ fn get_or_create_returns_winner_on_race() {
 								key: actor_key.to_string(),
 								input: None,
 								runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-								crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+								crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
 							},
 						)
 						.await
@@ -421,7 +421,7 @@ fn get_or_create_race_condition_across_datacenters() {
 						key: actor_key.to_string(),
 						input: None,
 						runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-						crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+						crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
 					},
 				)
 				.await
@@ -439,7 +439,7 @@ fn get_or_create_race_condition_across_datacenters() {
 						key: actor_key.to_string(),
 						input: None,
 						runner_name_selector: DC2_RUNNER_NAME.to_string(),
-						crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+						crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
 					},
 				)
 				.await
@@ -490,7 +490,7 @@ fn get_or_create_in_current_datacenter() {
 					key: "current-dc-key".to_string(),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
 				},
 			)
 			.await
@@ -533,7 +533,7 @@ fn get_or_create_in_remote_datacenter() {
 					key: "remote-dc-key".to_string(),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
 				},
 			)
 			.await
@@ -568,7 +568,7 @@ fn get_or_create_with_non_existent_namespace() {
 					key: "test-key".to_string(),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
 				},
 			)
 			.await;
@@ -597,7 +597,7 @@ fn get_or_create_with_invalid_datacenter() {
 					key: "test-key".to_string(),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
 				},
 			)
 			.await;
@@ -626,7 +626,7 @@ fn get_or_create_empty_key() {
 					key: "".to_string(),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
 				},
 			)
 			.await;
@@ -660,7 +660,7 @@ fn get_or_create_key_exceeds_max_size() {
 					key,
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
 				},
 			)
 			.await;
@@ -698,7 +698,7 @@ fn get_or_create_with_destroyed_actor() {
 					key: actor_key.to_string(),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
 				},
 			)
 			.await
@@ -732,7 +732,7 @@ fn get_or_create_with_destroyed_actor() {
 					key: actor_key.to_string(),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
 				},
 			)
 			.await
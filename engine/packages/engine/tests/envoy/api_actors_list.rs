@@ -28,7 +28,10 @@ fn list_actors_by_namespace_and_name() {
 						key: Some(format!("key-{}", i)),
 						input: None,
 						runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-						crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+						crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+						affinity: None,
+						required_capabilities: Vec::new(),
+						tags: Default::default(),
 					},
 				)
 				.await
@@ -45,7 +48,11 @@ fn list_actors_by_namespace_and_name() {
 					key: None,
 					actor_ids: None,
 					actor_id: vec![],
+					tag: Vec::new(),
 					include_destroyed: None,
+					state: None,
+					created_before: None,
+					created_after: None,
 					limit: None,
 					cursor: None,
 				},
@@ -96,7 +103,10 @@ fn list_with_pagination() {
 						key: Some(format!("key-{}", i)),
 						input: None,
 						runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-						crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+						crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+						affinity: None,
+						required_capabilities: Vec::new(),
+						tags: Default::default(),
 					},
 				)
 				.await
@@ -113,7 +123,11 @@ fn list_with_pagination() {
 					key: None,
 					actor_ids: None,
 					actor_id: vec![],
+					tag: Vec::new(),
 					include_destroyed: None,
+					state: None,
+					created_before: None,
+					created_after: None,
 					limit: Some(2),
 					cursor: None,
 				},
@@ -136,7 +150,11 @@ fn list_with_pagination() {
 					key: None,
 					actor_ids: None,
 					actor_id: vec![],
+					tag: Vec::new(),
 					include_destroyed: None,
+					state: None,
+					created_before: None,
+					created_after: None,
 					limit: None,
 					cursor: None,
 				},
@@ -239,7 +257,11 @@ fn list_returns_empty_array_when_no_actors() {
 					key: None,
 					actor_ids: None,
 					actor_id: vec![],
+					tag: Vec::new(),
 					include_destroyed: None,
+					state: None,
+					created_before: None,
+					created_after: None,
 					limit: None,
 					cursor: None,
 				},
@@ -278,7 +300,10 @@ fn list_actors_by_namespace_name_and_key() {
 					key: Some(key1.clone()),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -296,7 +321,10 @@ fn list_actors_by_namespace_name_and_key() {
 					key: Some(key2.clone()),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -311,7 +339,11 @@ fn list_actors_by_namespace_name_and_key() {
 					key: Some("key1".to_string()),
 					actor_ids: None,
 					actor_id: vec![],
+					tag: Vec::new(),
 					include_destroyed: None,
+					state: None,
+					created_before: None,
+					created_after: None,
 					limit: None,
 					cursor: None,
 				},
@@ -347,7 +379,10 @@ fn list_with_include_destroyed_false() {
 					key: Some("destroyed-key".to_string()),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -378,7 +413,10 @@ fn list_with_include_destroyed_false() {
 					key: Some("active-key".to_string()),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -394,7 +432,11 @@ fn list_with_include_destroyed_false() {
 					key: None,
 					actor_ids: None,
 					actor_id: vec![],
+					tag: Vec::new(),
 					include_destroyed: Some(false),
+					state: None,
+					created_before: None,
+					created_after: None,
 					limit: None,
 					cursor: None,
 				},
@@ -430,7 +472,10 @@ fn list_with_include_destroyed_true() {
 					key: Some("destroyed-key".to_string()),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -461,7 +506,10 @@ fn list_with_include_destroyed_true() {
 					key: Some("active-key".to_string()),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -477,7 +525,11 @@ fn list_with_include_destroyed_true() {
 					key: None,
 					actor_ids: None,
 					actor_id: vec![],
+					tag: Vec::new(),
 					include_destroyed: Some(true),
+					state: None,
+					created_before: None,
+					created_after: None,
 					limit: None,
 					cursor: None,
 				},
@@ -540,7 +592,11 @@ fn list_specific_actors_by_ids() {
 					key: None,
 					actor_id: selected_ids.clone(),
 					actor_ids: None,
+					tag: Vec::new(),
 					include_destroyed: None,
+					state: None,
+					created_before: None,
+					created_after: None,
 					limit: None,
 					cursor: None,
 				},
@@ -597,7 +653,10 @@ fn list_actors_from_multiple_datacenters() {
 					key: Some("dc1-key".to_string()),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -615,7 +674,10 @@ fn list_actors_from_multiple_datacenters() {
 					key: Some("dc2-key".to_string()),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -631,7 +693,11 @@ fn list_actors_from_multiple_datacenters() {
 					key: None,
 					actor_id: vec![actor_id_dc1, actor_id_dc2],
 					actor_ids: None,
+					tag: Vec::new(),
 					include_destroyed: None,
+					state: None,
+					created_before: None,
+					created_after: None,
 					limit: None,
 					cursor: None,
 				},
@@ -664,7 +730,11 @@ fn list_with_non_existent_namespace() {
 					key: None,
 					actor_ids: None,
 					actor_id: vec![],
+					tag: Vec::new(),
 					include_destroyed: None,
+					state: None,
+					created_before: None,
+					created_after: None,
 					limit: None,
 					cursor: None,
 				},
@@ -694,7 +764,11 @@ fn list_with_key_but_no_name() {
 					key: Some("key1".to_string()),
 					actor_ids: None,
 					actor_id: vec![],
+					tag: Vec::new(),
 					include_destroyed: None,
+					state: None,
+					created_before: None,
+					created_after: None,
 					limit: None,
 					cursor: None,
 				},
@@ -728,7 +802,11 @@ fn list_with_more_than_32_actor_ids() {
 					key: None,
 					actor_id: actor_ids,
 					actor_ids: None,
+					tag: Vec::new(),
 					include_destroyed: None,
+					state: None,
+					created_before: None,
+					created_after: None,
 					limit: None,
 					cursor: None,
 				},
@@ -758,7 +836,11 @@ fn list_without_name_when_not_using_actor_ids() {
 					key: None,
 					actor_ids: None,
 					actor_id: vec![],
+					tag: Vec::new(),
 					include_destroyed: None,
+					state: None,
+					created_before: None,
+					created_after: None,
 					limit: None,
 					cursor: None,
 				},
@@ -800,7 +882,10 @@ fn verify_sorting_by_create_ts_descending() {
 						key: Some(format!("key-{}", i)),
 						input: None,
 						runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-						crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+						crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+						affinity: None,
+						required_capabilities: Vec::new(),
+						tags: Default::default(),
 					},
 				)
 				.await
@@ -817,7 +902,11 @@ fn verify_sorting_by_create_ts_descending() {
 					key: None,
 					actor_ids: None,
 					actor_id: vec![],
+					tag: Vec::new(),
 					include_destroyed: None,
+					state: None,
+					created_before: None,
+					created_after: None,
 					limit: None,
 					cursor: None,
 				},
@@ -869,7 +958,10 @@ fn list_aggregates_results_from_all_datacenters() {
 					key: Some("dc1-key".to_string()),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -887,7 +979,10 @@ fn list_aggregates_results_from_all_datacenters() {
 					key: Some("dc2-key".to_string()),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -903,7 +998,11 @@ fn list_aggregates_results_from_all_datacenters() {
 					key: None,
 					actor_ids: None,
 					actor_id: vec![],
+					tag: Vec::new(),
 					include_destroyed: None,
+					state: None,
+					created_before: None,
+					created_after: None,
 					limit: None,
 					cursor: None,
 				},
@@ -953,7 +1052,11 @@ fn list_with_exactly_32_actor_ids() {
 					key: None,
 					actor_id: actor_ids,
 					actor_ids: None,
+					tag: Vec::new(),
 					include_destroyed: None,
+					state: None,
+					created_before: None,
+					created_after: None,
 					limit: None,
 					cursor: None,
 				},
@@ -994,7 +1097,10 @@ fn list_by_key_with_include_destroyed_true() {
 					key: Some(key.to_string()),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -1025,7 +1131,10 @@ fn list_by_key_with_include_destroyed_true() {
 					key: Some(key.to_string()),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -1042,7 +1151,11 @@ fn list_by_key_with_include_destroyed_true() {
 					key: Some(key.to_string()),
 					actor_ids: None,
 					actor_id: vec![],
+					tag: Vec::new(),
 					include_destroyed: Some(true),
+					state: None,
+					created_before: None,
+					created_after: None,
 					limit: None,
 					cursor: None,
 				},
@@ -1094,7 +1207,11 @@ fn list_default_limit_100() {
 					key: None,
 					actor_ids: None,
 					actor_id: vec![],
+					tag: Vec::new(),
 					include_destroyed: None,
+					state: None,
+					created_before: None,
+					created_after: None,
 					limit: None, // No limit specified - should default to 100
 					cursor: None,
 				},
@@ -1140,7 +1257,10 @@ fn list_with_invalid_actor_id_format_in_comma_list() {
 					key: Some("test-key".to_string()),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -1163,7 +1283,11 @@ fn list_with_invalid_actor_id_format_in_comma_list() {
 					key: None,
 					actor_id: vec![],
 					actor_ids: Some(mixed_ids.join(",")),
+					tag: Vec::new(),
 					include_destroyed: None,
+					state: None,
+					created_before: None,
+					created_after: None,
 					limit: None,
 					cursor: None,
 				},
@@ -1208,7 +1332,10 @@ fn list_with_cursor_pagination() {
 						key: Some(format!("cursor-key-{}", i)),
 						input: None,
 						runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-						crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+						crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+						affinity: None,
+						required_capabilities: Vec::new(),
+						tags: Default::default(),
 					},
 				)
 				.await
@@ -1225,7 +1352,11 @@ fn list_with_cursor_pagination() {
 					key: None,
 					actor_ids: None,
 					actor_id: vec![],
+					tag: Vec::new(),
 					include_destroyed: None,
+					state: None,
+					created_before: None,
+					created_after: None,
 					limit: Some(2),
 					cursor: None,
 				},
@@ -1248,7 +1379,11 @@ fn list_with_cursor_pagination() {
 					key: None,
 					actor_ids: None,
 					actor_id: vec![],
+					tag: Vec::new(),
 					include_destroyed: None,
+					state: None,
+					created_before: None,
+					created_after: None,
 					limit: Some(2),
 					cursor: page1.pagination.cursor.clone(),
 				},
@@ -1271,7 +1406,11 @@ fn list_with_cursor_pagination() {
 					key: None,
 					actor_ids: None,
 					actor_id: vec![],
+					tag: Vec::new(),
 					include_destroyed: None,
+					state: None,
+					created_before: None,
+					created_after: None,
 					limit: Some(2),
 					cursor: page2.pagination.cursor.clone(),
 				},
@@ -1355,7 +1494,10 @@ fn list_cursor_filters_by_timestamp() {
 						key: Some(format!("ts-key-{}", i)),
 						input: None,
 						runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-						crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+						crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+						affinity: None,
+						required_capabilities: Vec::new(),
+						tags: Default::default(),
 					},
 				)
 				.await
@@ -1371,7 +1513,11 @@ fn list_cursor_filters_by_timestamp() {
 					key: None,
 					actor_ids: None,
 					actor_id: vec![],
+					tag: Vec::new(),
 					include_destroyed: None,
+					state: None,
+					created_before: None,
+					created_after: None,
 					limit: None,
 					cursor: None,
 				},
@@ -1393,7 +1539,11 @@ fn list_cursor_filters_by_timestamp() {
 					key: None,
 					actor_ids: None,
 					actor_id: vec![],
+					tag: Vec::new(),
 					include_destroyed: None,
+					state: None,
+					created_before: None,
+					created_after: None,
 					limit: None,
 					cursor: Some(cursor.clone()),
 				},
@@ -1444,7 +1594,10 @@ fn list_cursor_with_exact_timestamp_boundary() {
 						key: Some(format!("boundary-key-{}", i)),
 						input: None,
 						runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-						crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+						crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+						affinity: None,
+						required_capabilities: Vec::new(),
+						tags: Default::default(),
 					},
 				)
 				.await
@@ -1460,7 +1613,11 @@ fn list_cursor_with_exact_timestamp_boundary() {
 					key: None,
 					actor_ids: None,
 					actor_id: vec![],
+					tag: Vec::new(),
 					include_destroyed: None,
+					state: None,
+					created_before: None,
+					created_after: None,
 					limit: Some(1),
 					cursor: None,
 				},
@@ -1480,7 +1637,11 @@ fn list_cursor_with_exact_timestamp_boundary() {
 					key: None,
 					actor_ids: None,
 					actor_id: vec![],
+					tag: Vec::new(),
 					include_destroyed: None,
+					state: None,
+					created_before: None,
+					created_after: None,
 					limit: None,
 					cursor: page1.pagination.cursor.clone(),
 				},
@@ -1525,7 +1686,10 @@ fn list_cursor_empty_results_when_no_more_actors() {
 						key: Some(format!("empty-key-{}", i)),
 						input: None,
 						runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-						crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+						crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+						affinity: None,
+						required_capabilities: Vec::new(),
+						tags: Default::default(),
 					},
 				)
 				.await
@@ -1541,7 +1705,11 @@ fn list_cursor_empty_results_when_no_more_actors() {
 					key: None,
 					actor_ids: None,
 					actor_id: vec![],
+					tag: Vec::new(),
 					include_destroyed: None,
+					state: None,
+					created_before: None,
+					created_after: None,
 					limit: Some(10),
 					cursor: None,
 				},
@@ -1561,7 +1729,11 @@ fn list_cursor_empty_results_when_no_more_actors() {
 						key: None,
 						actor_ids: None,
 						actor_id: vec![],
+						tag: Vec::new(),
 						include_destroyed: None,
+						state: None,
+						created_before: None,
+						created_after: None,
 						limit: Some(10),
 						cursor: Some(cursor),
 					},
@@ -1602,7 +1774,11 @@ fn list_invalid_cursor_format() {
 					key: None,
 					actor_ids: None,
 					actor_id: vec![],
+					tag: Vec::new(),
 					include_destroyed: None,
+					state: None,
+					created_before: None,
+					created_after: None,
 					limit: None,
 					cursor: Some("not-a-number".to_string()),
 				},
@@ -1649,7 +1825,10 @@ fn list_cursor_across_datacenters() {
 						key: Some(format!("dc1-cursor-key-{}", i)),
 						input: None,
 						runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-						crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+						crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+						affinity: None,
+						required_capabilities: Vec::new(),
+						tags: Default::default(),
 					},
 				)
 				.await
@@ -1668,7 +1847,10 @@ fn list_cursor_across_datacenters() {
 						key: Some(format!("dc2-cursor-key-{}", i)),
 						input: None,
 						runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-						crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+						crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+						affinity: None,
+						required_capabilities: Vec::new(),
+						tags: Default::default(),
 					},
 				)
 				.await
@@ -1684,7 +1866,11 @@ fn list_cursor_across_datacenters() {
 					key: None,
 					actor_ids: None,
 					actor_id: vec![],
+					tag: Vec::new(),
 					include_destroyed: None,
+					state: None,
+					created_before: None,
+					created_after: None,
 					limit: Some(3),
 					cursor: None,
 				},
@@ -1707,7 +1893,11 @@ fn list_cursor_across_datacenters() {
 						key: None,
 						actor_ids: None,
 						actor_id: vec![],
+						tag: Vec::new(),
 						include_destroyed: None,
+						state: None,
+						created_before: None,
+						created_after: None,
 						limit: Some(3),
 						cursor: Some(cursor),
 					},
@@ -1761,7 +1951,11 @@ fn list_actor_ids_with_cursor_pagination() {
 					key: None,
 					actor_id: actor_ids.clone(),
 					actor_ids: None,
+					tag: Vec::new(),
 					include_destroyed: None,
+					state: None,
+					created_before: None,
+					created_after: None,
 					limit: Some(2),
 					cursor: None,
 				},
@@ -1788,7 +1982,11 @@ fn list_actor_ids_with_cursor_pagination() {
 					key: None,
 					actor_id: actor_ids.clone(),
 					actor_ids: None,
+					tag: Vec::new(),
 					include_destroyed: None,
+					state: None,
+					created_before: None,
+					created_after: None,
 					limit: Some(2),
 					cursor: page1.pagination.cursor.clone(),
 				},
@@ -1815,7 +2013,11 @@ fn list_actor_ids_with_cursor_pagination() {
 					key: None,
 					actor_id: actor_ids.clone(),
 					actor_ids: None,
+					tag: Vec::new(),
 					include_destroyed: None,
+					state: None,
+					created_before: None,
+					created_after: None,
 					limit: Some(2),
 					cursor: page2.pagination.cursor.clone(),
 				},
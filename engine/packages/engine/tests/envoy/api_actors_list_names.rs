@@ -27,7 +27,10 @@ fn list_all_actor_names_in_namespace() {
 						key: Some(common::generate_unique_key()),
 						input: None,
 						runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-						crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+						crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+						affinity: None,
+						required_capabilities: Vec::new(),
+						tags: Default::default(),
 					},
 				)
 				.await
@@ -47,7 +50,10 @@ fn list_all_actor_names_in_namespace() {
 						key: Some(format!("key-{}", i)),
 						input: None,
 						runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-						crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+						crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+						affinity: None,
+						required_capabilities: Vec::new(),
+						tags: Default::default(),
 					},
 				)
 				.await
@@ -106,7 +112,10 @@ fn list_names_with_pagination() {
 						key: Some(common::generate_unique_key()),
 						input: None,
 						runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-						crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+						crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+						affinity: None,
+						required_capabilities: Vec::new(),
+						tags: Default::default(),
 					},
 				)
 				.await
@@ -245,7 +254,10 @@ fn list_names_fanout_to_all_datacenters() {
 					key: Some(common::generate_unique_key()),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -262,7 +274,10 @@ fn list_names_fanout_to_all_datacenters() {
 					key: Some(common::generate_unique_key()),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -327,7 +342,10 @@ fn list_names_deduplication_across_datacenters() {
 					key: Some("dc1-key".to_string()),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -344,7 +362,10 @@ fn list_names_deduplication_across_datacenters() {
 					key: Some("dc2-key".to_string()),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -402,7 +423,10 @@ fn list_names_alphabetical_sorting() {
 						key: Some(common::generate_unique_key()),
 						input: None,
 						runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-						crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+						crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+						affinity: None,
+						required_capabilities: Vec::new(),
+						tags: Default::default(),
 					},
 				)
 				.await
@@ -461,7 +485,10 @@ fn list_names_default_limit_100() {
 						key: Some(common::generate_unique_key()),
 						input: None,
 						runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-						crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+						crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+						affinity: None,
+						required_capabilities: Vec::new(),
+						tags: Default::default(),
 					},
 				)
 				.await
@@ -520,7 +547,10 @@ fn list_names_with_metadata() {
 					key: Some(common::generate_unique_key()),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -617,7 +647,10 @@ fn list_names_pagination_no_duplicates_comprehensive() {
 						key: Some(common::generate_unique_key()),
 						input: None,
 						runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-						crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+						crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+						affinity: None,
+						required_capabilities: Vec::new(),
+						tags: Default::default(),
 					},
 				)
 				.await
@@ -709,7 +742,10 @@ fn list_names_pagination_boundary_cases() {
 						key: Some(common::generate_unique_key()),
 						input: None,
 						runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-						crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+						crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+						affinity: None,
+						required_capabilities: Vec::new(),
+						tags: Default::default(),
 					},
 				)
 				.await
@@ -30,6 +30,9 @@ fn envoy_connect_rejects_bad_token() {
 				rivet_api_peer::namespaces::CreateRequest {
 					name: namespace.clone(),
 					display_name: "Test Namespace".to_string(),
+					default_crash_policy: None,
+					cors_policy: None,
+					actor_rate_limit: None,
 				},
 			)
 			.await
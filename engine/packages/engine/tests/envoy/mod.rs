@@ -5,6 +5,8 @@ pub mod actors_kv_drop;
 pub mod actors_kv_list;
 pub mod actors_kv_misc;
 pub mod actors_lifecycle;
+pub mod actors_websocket_compression;
+pub mod actors_websocket_limit;
 pub mod api_actors_create;
 pub mod api_actors_delete;
 pub mod api_actors_get_or_create;
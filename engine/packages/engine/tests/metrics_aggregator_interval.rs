@@ -0,0 +1,67 @@
+mod common;
+
+use std::time::{Duration, Instant};
+
+const INTERVAL: Duration = Duration::from_secs(60);
+
+fn running_actors(namespace_id: rivet_util::Id) -> i64 {
+	pegboard::metrics::ACTOR_STATE
+		.with_label_values(&[&namespace_id.to_string(), "running"])
+		.get()
+}
+
+#[test]
+fn metrics_aggregator_runs_at_configured_interval() {
+	common::run(
+		common::TestOpts::new(1)
+			.with_timeout(240)
+			.with_metrics_aggregator_interval(INTERVAL.as_millis() as u64),
+		|ctx| async move {
+			let (namespace, namespace_id, runner) =
+				common::setup_test_namespace_with_runner(ctx.leader_dc()).await;
+
+			let res = common::create_actor(
+				ctx.leader_dc().guard_port(),
+				&namespace,
+				"test-actor",
+				runner.name(),
+				rivet_types::actors::CrashPolicy::Destroy,
+			)
+			.await;
+
+			// First tick that sees the running actor
+			common::wait_with_poll(INTERVAL * 2, Duration::from_millis(100), || async {
+				(running_actors(namespace_id) == 1).then_some(())
+			})
+			.await
+			.expect("aggregator never reported the running actor");
+			let first_tick = Instant::now();
+
+			common::api::public::actors_delete(
+				ctx.leader_dc().guard_port(),
+				common::api_types::actors::delete::DeletePath {
+					actor_id: res.actor.actor_id,
+				},
+				common::api_types::actors::delete::DeleteQuery {
+					namespace: namespace.clone(),
+				},
+			)
+			.await
+			.expect("failed to delete actor");
+
+			// The gauge only changes on the next tick
+			common::wait_with_poll(INTERVAL * 2, Duration::from_millis(100), || async {
+				(running_actors(namespace_id) == 0).then_some(())
+			})
+			.await
+			.expect("aggregator never reported the destroyed actor");
+			let elapsed = first_tick.elapsed();
+
+			assert!(
+				elapsed >= INTERVAL - Duration::from_secs(5)
+					&& elapsed <= INTERVAL + Duration::from_secs(10),
+				"aggregator should tick every {INTERVAL:?}, next tick came after {elapsed:?}"
+			);
+		},
+	);
+}
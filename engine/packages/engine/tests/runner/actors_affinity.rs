@@ -0,0 +1,102 @@
+use super::super::common;
+
+async fn create_actor_with_affinity(
+	guard_port: u16,
+	namespace: &str,
+	runner_name: &str,
+	affinity: Option<rivet_types::actors::ActorAffinity>,
+) -> rivet_util::Id {
+	let res = common::api::public::actors_create(
+		guard_port,
+		common::api_types::actors::create::CreateQuery {
+			namespace: namespace.to_string(),
+		},
+		common::api_types::actors::create::CreateRequest {
+			datacenter: None,
+			name: "test-actor".to_string(),
+			key: None,
+			input: None,
+			runner_name_selector: runner_name.to_string(),
+			crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+			affinity,
+			required_capabilities: Vec::new(),
+			tags: Default::default(),
+		},
+	)
+	.await
+	.expect("failed to create actor");
+
+	res.actor.actor_id
+}
+
+/// Waits for the actor to be allocated to one of the runners and returns the index of that runner.
+async fn wait_for_runner(
+	runners: &[&common::test_runner::TestRunner],
+	actor_id: rivet_util::Id,
+) -> usize {
+	let actor_id = actor_id.to_string();
+	let start = std::time::Instant::now();
+	loop {
+		for (i, runner) in runners.iter().enumerate() {
+			if runner.has_actor(&actor_id).await {
+				return i;
+			}
+		}
+
+		if start.elapsed() > std::time::Duration::from_secs(10) {
+			panic!("actor {actor_id} was never allocated to a runner");
+		}
+		tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+	}
+}
+
+#[test]
+fn actor_anti_affinity_spreads_across_runners() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let (namespace, _) = common::setup_test_namespace(ctx.leader_dc()).await;
+
+		// Two runners in the same pool, each with enough capacity for every actor in this test
+		let runner_a = common::setup_runner(ctx.leader_dc(), &namespace, |builder| {
+			builder
+				.with_total_slots(4)
+				.with_actor_behavior("test-actor", |_| {
+					Box::new(common::test_runner::EchoActor::new())
+				})
+		})
+		.await;
+		let runner_b = common::setup_runner(ctx.leader_dc(), &namespace, |builder| {
+			builder
+				.with_total_slots(4)
+				.with_actor_behavior("test-actor", |_| {
+					Box::new(common::test_runner::EchoActor::new())
+				})
+		})
+		.await;
+		let runners = [&runner_a, &runner_b];
+		let guard_port = ctx.leader_dc().guard_port();
+
+		let first_actor_id =
+			create_actor_with_affinity(guard_port, &namespace, runner_a.name(), None).await;
+		let first_runner = wait_for_runner(&runners, first_actor_id).await;
+
+		// Every anti-affine actor must land on the other runner since it has capacity
+		for _ in 0..3 {
+			let actor_id = create_actor_with_affinity(
+				guard_port,
+				&namespace,
+				runner_a.name(),
+				Some(rivet_types::actors::ActorAffinity {
+					colocate_with: Vec::new(),
+					avoid: vec![first_actor_id],
+				}),
+			)
+			.await;
+
+			assert_ne!(
+				wait_for_runner(&runners, actor_id).await,
+				first_runner,
+				"anti-affine actor should not share a runner with {first_actor_id}"
+			);
+		}
+	});
+}
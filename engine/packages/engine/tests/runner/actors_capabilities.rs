@@ -0,0 +1,141 @@
+use super::super::common;
+
+async fn create_actor_with_capabilities(
+	guard_port: u16,
+	namespace: &str,
+	runner_name: &str,
+	required_capabilities: Vec<String>,
+) -> rivet_util::Id {
+	let res = common::api::public::actors_create(
+		guard_port,
+		common::api_types::actors::create::CreateQuery {
+			namespace: namespace.to_string(),
+		},
+		common::api_types::actors::create::CreateRequest {
+			datacenter: None,
+			name: "test-actor".to_string(),
+			key: None,
+			input: None,
+			runner_name_selector: runner_name.to_string(),
+			crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+			affinity: None,
+			required_capabilities,
+			tags: Default::default(),
+		},
+	)
+	.await
+	.expect("failed to create actor");
+
+	res.actor.actor_id
+}
+
+/// Waits for the actor to be allocated to one of the runners and returns the index of that runner.
+async fn wait_for_runner(
+	runners: &[&common::test_runner::TestRunner],
+	actor_id: rivet_util::Id,
+) -> usize {
+	let actor_id = actor_id.to_string();
+	let start = std::time::Instant::now();
+	loop {
+		for (i, runner) in runners.iter().enumerate() {
+			if runner.has_actor(&actor_id).await {
+				return i;
+			}
+		}
+
+		if start.elapsed() > std::time::Duration::from_secs(10) {
+			panic!("actor {actor_id} was never allocated to a runner");
+		}
+		tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+	}
+}
+
+#[test]
+fn actor_required_capabilities_only_allocate_to_capable_runner() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let (namespace, _) = common::setup_test_namespace(ctx.leader_dc()).await;
+
+		// Two runners in the same pool, only one of which advertises a GPU
+		let gpu_runner = common::setup_runner(ctx.leader_dc(), &namespace, |builder| {
+			builder
+				.with_total_slots(8)
+				.with_capabilities(&["gpu", "cuda-12"])
+				.with_actor_behavior("test-actor", |_| {
+					Box::new(common::test_runner::EchoActor::new())
+				})
+		})
+		.await;
+		let plain_runner = common::setup_runner(ctx.leader_dc(), &namespace, |builder| {
+			builder
+				.with_total_slots(8)
+				.with_actor_behavior("test-actor", |_| {
+					Box::new(common::test_runner::EchoActor::new())
+				})
+		})
+		.await;
+		let runners = [&gpu_runner, &plain_runner];
+		let guard_port = ctx.leader_dc().guard_port();
+
+		for _ in 0..4 {
+			let actor_id = create_actor_with_capabilities(
+				guard_port,
+				&namespace,
+				gpu_runner.name(),
+				vec!["gpu".to_string()],
+			)
+			.await;
+
+			assert_eq!(
+				wait_for_runner(&runners, actor_id).await,
+				0,
+				"actor requiring a gpu should only be allocated to the gpu runner"
+			);
+		}
+	});
+}
+
+#[test]
+fn actor_required_capabilities_stay_pending_without_capable_runner() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let (namespace, _) = common::setup_test_namespace(ctx.leader_dc()).await;
+
+		let plain_runner = common::setup_runner(ctx.leader_dc(), &namespace, |builder| {
+			builder.with_actor_behavior("test-actor", |_| {
+				Box::new(common::test_runner::EchoActor::new())
+			})
+		})
+		.await;
+
+		let actor_id = create_actor_with_capabilities(
+			ctx.leader_dc().guard_port(),
+			&namespace,
+			plain_runner.name(),
+			vec!["gpu".to_string()],
+		)
+		.await;
+
+		// TODO: Hook into engine instead of sleep
+		tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+
+		assert!(
+			!plain_runner.has_actor(&actor_id.to_string()).await,
+			"actor requiring a gpu should not be allocated to a runner without one"
+		);
+
+		// Once a capable runner connects, the pending actor is allocated to it
+		let gpu_runner = common::setup_runner(ctx.leader_dc(), &namespace, |builder| {
+			builder
+				.with_capabilities(&["gpu"])
+				.with_actor_behavior("test-actor", |_| {
+					Box::new(common::test_runner::EchoActor::new())
+				})
+		})
+		.await;
+
+		assert_eq!(
+			wait_for_runner(&[&gpu_runner, &plain_runner], actor_id).await,
+			0,
+			"pending actor should be allocated to the gpu runner"
+		);
+	});
+}
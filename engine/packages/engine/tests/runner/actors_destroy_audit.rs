@@ -0,0 +1,185 @@
+use std::sync::{Arc, Mutex};
+
+use pegboard::workflows::actor::DestroyReason;
+
+use super::super::common;
+
+async fn wait_for_destroy_audit(
+	ctx: &common::TestCtx,
+	actor_id: rivet_util::Id,
+) -> pegboard::keys::destroy_audit::DestroyAudit {
+	common::wait_with_poll(
+		std::time::Duration::from_secs(10),
+		std::time::Duration::from_millis(50),
+		|| async {
+			ctx.leader_dc()
+				.workflow_ctx
+				.op(pegboard::ops::actor::get_destroy_audit::Input { actor_id })
+				.await
+				.expect("failed to get destroy audit")
+		},
+	)
+	.await
+	.expect("timed out waiting for destroy audit")
+}
+
+#[test]
+fn destroy_audit_records_requested_destroy() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let (namespace, _, runner) =
+			common::setup_test_namespace_with_runner(ctx.leader_dc()).await;
+
+		let res = common::create_actor(
+			ctx.leader_dc().guard_port(),
+			&namespace,
+			"test-actor",
+			runner.name(),
+			rivet_types::actors::CrashPolicy::Destroy,
+		)
+		.await;
+		let actor_id = res.actor.actor_id;
+
+		common::api::public::actors_delete(
+			ctx.leader_dc().guard_port(),
+			common::api_types::actors::delete::DeletePath { actor_id },
+			common::api_types::actors::delete::DeleteQuery {
+				namespace: namespace.clone(),
+			},
+		)
+		.await
+		.expect("failed to delete actor");
+
+		let audit = wait_for_destroy_audit(&ctx, actor_id).await;
+		assert_eq!(audit.reason, DestroyReason::Requested);
+		assert!(audit.destroy_ts >= res.actor.create_ts);
+
+		let actor = common::assert_actor_exists(
+			ctx.leader_dc().guard_port(),
+			&actor_id.to_string(),
+			&namespace,
+		)
+		.await;
+		assert_eq!(actor.destroy_ts, Some(audit.destroy_ts));
+	});
+}
+
+#[test]
+fn destroy_audit_records_crash() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let (namespace, _) = common::setup_test_namespace(ctx.leader_dc()).await;
+
+		let (crash_tx, crash_rx) = tokio::sync::oneshot::channel();
+		let crash_tx = Arc::new(Mutex::new(Some(crash_tx)));
+
+		let runner = common::setup_runner(ctx.leader_dc(), &namespace, |builder| {
+			builder.with_actor_behavior("crash-actor", move |_| {
+				Box::new(common::test_runner::CrashOnStartActor::new_with_notify(
+					1,
+					crash_tx.clone(),
+				))
+			})
+		})
+		.await;
+
+		let res = common::create_actor(
+			ctx.leader_dc().guard_port(),
+			&namespace,
+			"crash-actor",
+			runner.name(),
+			rivet_types::actors::CrashPolicy::Destroy,
+		)
+		.await;
+
+		crash_rx
+			.await
+			.expect("actor should have sent crash notification");
+
+		let audit = wait_for_destroy_audit(&ctx, res.actor.actor_id).await;
+		assert_eq!(audit.reason, DestroyReason::Crashed);
+		assert!(
+			audit.start_ts.is_none(),
+			"actor crashed before it started running"
+		);
+	});
+}
+
+#[test]
+fn destroy_audit_records_validation_failure() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let (namespace, namespace_id, runner) =
+			common::setup_test_namespace_with_runner(ctx.leader_dc()).await;
+
+		// Create the actor holding the key
+		common::api::public::actors_create(
+			ctx.leader_dc().guard_port(),
+			common::api_types::actors::create::CreateQuery {
+				namespace: namespace.clone(),
+			},
+			common::api_types::actors::create::CreateRequest {
+				datacenter: None,
+				name: "test-actor".to_string(),
+				key: Some("duplicate-key".to_string()),
+				input: None,
+				runner_name_selector: runner.name().to_string(),
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+				affinity: None,
+				required_capabilities: Vec::new(),
+				tags: Default::default(),
+			},
+		)
+		.await
+		.expect("failed to create actor");
+
+		// Creating another actor with the same key fails after its workflow started
+		let actor_id = rivet_util::Id::new_v1(ctx.leader_dc().config.dc_label());
+		ctx.leader_dc()
+			.workflow_ctx
+			.op(pegboard::ops::actor::create::Input {
+				actor_id,
+				namespace_id,
+				name: "test-actor".to_string(),
+				key: Some("duplicate-key".to_string()),
+				runner_name_selector: runner.name().to_string(),
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+				affinity: None,
+				required_capabilities: Vec::new(),
+				tags: std::collections::HashMap::new(),
+				input: None,
+				forward_request: false,
+				datacenter_name: None,
+			})
+			.await
+			.expect_err("duplicate key should fail");
+
+		let audit = wait_for_destroy_audit(&ctx, actor_id).await;
+		assert_eq!(audit.reason, DestroyReason::ValidationFailed);
+		assert_eq!(audit.generation, 0);
+	});
+}
+
+#[test]
+fn destroy_audit_missing_for_live_actor() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let (namespace, _, runner) =
+			common::setup_test_namespace_with_runner(ctx.leader_dc()).await;
+
+		let res = common::create_actor(
+			ctx.leader_dc().guard_port(),
+			&namespace,
+			"test-actor",
+			runner.name(),
+			rivet_types::actors::CrashPolicy::Destroy,
+		)
+		.await;
+
+		let audit = ctx
+			.leader_dc()
+			.workflow_ctx
+			.op(pegboard::ops::actor::get_destroy_audit::Input {
+				actor_id: res.actor.actor_id,
+			})
+			.await
+			.expect("failed to get destroy audit");
+		assert!(audit.is_none());
+	});
+}
@@ -0,0 +1,102 @@
+use super::super::common;
+
+#[test]
+fn get_batch_returns_actors_in_order_with_missing_as_none() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let (namespace, _, runner) =
+			common::setup_test_namespace_with_runner(ctx.leader_dc()).await;
+
+		let mut actor_ids = Vec::new();
+		for _ in 0..48 {
+			let res = common::create_actor(
+				ctx.leader_dc().guard_port(),
+				&namespace,
+				"test-actor",
+				runner.name(),
+				rivet_types::actors::CrashPolicy::Destroy,
+			)
+			.await;
+			actor_ids.push(Some(res.actor.actor_id));
+		}
+
+		// Mix in actors that do not exist
+		let dc_label = ctx.leader_dc().config.dc_label();
+		actor_ids.insert(0, None);
+		actor_ids.insert(25, None);
+
+		let res = ctx
+			.leader_dc()
+			.workflow_ctx
+			.op(pegboard::ops::actor::get_batch::Input {
+				actor_ids: actor_ids
+					.iter()
+					.map(|actor_id| actor_id.unwrap_or_else(|| rivet_util::Id::new_v1(dc_label)))
+					.collect(),
+				fetch_error: false,
+			})
+			.await
+			.expect("failed to batch get actors");
+
+		assert_eq!(res.actors.len(), 50);
+		for (expected, actor) in actor_ids.iter().zip(res.actors.iter()) {
+			assert_eq!(
+				actor.as_ref().map(|actor| actor.actor_id),
+				*expected,
+				"batch get should return existing actors in order and None for missing ones"
+			);
+		}
+	});
+}
+
+#[test]
+fn get_batch_empty() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let res = ctx
+			.leader_dc()
+			.workflow_ctx
+			.op(pegboard::ops::actor::get_batch::Input {
+				actor_ids: Vec::new(),
+				fetch_error: false,
+			})
+			.await
+			.expect("failed to batch get actors");
+
+		assert!(res.actors.is_empty());
+	});
+}
+
+#[test]
+fn get_batch_rejects_oversized_batch() {
+	common::run(
+		common::TestOpts::new(1).with_max_batch_size(4),
+		|ctx| async move {
+			let dc_label = ctx.leader_dc().config.dc_label();
+			let actor_ids = (0..5)
+				.map(|_| rivet_util::Id::new_v1(dc_label))
+				.collect::<Vec<_>>();
+
+			let err = ctx
+				.leader_dc()
+				.workflow_ctx
+				.op(pegboard::ops::actor::get_batch::Input {
+					actor_ids: actor_ids.clone(),
+					fetch_error: false,
+				})
+				.await
+				.expect_err("batch over the limit should be rejected");
+			assert!(err.to_string().contains("batch_too_large"), "{err:?}");
+
+			// A batch at the limit is still accepted
+			let res = ctx
+				.leader_dc()
+				.workflow_ctx
+				.op(pegboard::ops::actor::get_batch::Input {
+					actor_ids: actor_ids[..4].to_vec(),
+					fetch_error: false,
+				})
+				.await
+				.expect("failed to batch get actors");
+			assert_eq!(res.actors.len(), 4);
+		},
+	);
+}
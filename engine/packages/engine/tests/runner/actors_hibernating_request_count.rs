@@ -0,0 +1,76 @@
+use super::super::common;
+
+#[test]
+fn hibernating_request_count() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let (namespace, _) = common::setup_test_namespace(ctx.leader_dc()).await;
+
+		let runner = common::setup_runner(ctx.leader_dc(), &namespace, |builder| {
+			builder.with_actor_behavior("test-actor", |_| {
+				Box::new(common::test_runner::EchoActor::new())
+			})
+		})
+		.await;
+
+		let res = common::create_actor(
+			ctx.leader_dc().guard_port(),
+			&namespace,
+			"test-actor",
+			runner.name(),
+			rivet_types::actors::CrashPolicy::Destroy,
+		)
+		.await;
+		let actor_id = res.actor.actor_id;
+		let workflow_ctx = &ctx.leader_dc().workflow_ctx;
+
+		let count = workflow_ctx
+			.op(pegboard::ops::actor::hibernating_request::count::Input { actor_id })
+			.await
+			.expect("failed to count hibernating requests");
+		assert_eq!(count, 0, "new actor should have no hibernating requests");
+
+		// Hibernate three connections from the same gateway
+		let gateway_id = [1, 2, 3, 4];
+		for i in 0..3u8 {
+			workflow_ctx
+				.op(pegboard::ops::actor::hibernating_request::upsert::Input {
+					actor_id,
+					gateway_id,
+					request_id: [0, 0, 0, i],
+				})
+				.await
+				.expect("failed to upsert hibernating request");
+		}
+
+		// Upserting an existing request again should not count it twice
+		workflow_ctx
+			.op(pegboard::ops::actor::hibernating_request::upsert::Input {
+				actor_id,
+				gateway_id,
+				request_id: [0, 0, 0, 0],
+			})
+			.await
+			.expect("failed to upsert hibernating request");
+
+		let count = workflow_ctx
+			.op(pegboard::ops::actor::hibernating_request::count::Input { actor_id })
+			.await
+			.expect("failed to count hibernating requests");
+		assert_eq!(count, 3, "should count each hibernating request once");
+
+		workflow_ctx
+			.op(pegboard::ops::actor::hibernating_request::delete::Input {
+				actor_id,
+				gateway_id,
+				request_id: [0, 0, 0, 1],
+			})
+			.await
+			.expect("failed to delete hibernating request");
+
+		let count = workflow_ctx
+			.op(pegboard::ops::actor::hibernating_request::count::Input { actor_id })
+			.await
+			.expect("failed to count hibernating requests");
+		assert_eq!(count, 2, "deleted request should no longer be counted");
+	});
+}
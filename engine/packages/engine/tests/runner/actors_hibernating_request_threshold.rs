@@ -0,0 +1,91 @@
+use super::super::common;
+
+// Matches the override in the test datacenter config
+const SHORT_HIBERNATION_ACTOR: &str = "short-hibernation-actor";
+
+#[test]
+fn hibernating_request_threshold_override() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let (namespace, _) = common::setup_test_namespace(ctx.leader_dc()).await;
+		let pb = ctx.leader_dc().config.pegboard();
+
+		let runner = common::setup_runner(ctx.leader_dc(), &namespace, |builder| {
+			builder
+				.with_actor_behavior("test-actor", |_| {
+					Box::new(common::test_runner::EchoActor::new())
+				})
+				.with_actor_behavior(SHORT_HIBERNATION_ACTOR, |_| {
+					Box::new(common::test_runner::EchoActor::new())
+				})
+		})
+		.await;
+
+		let default_actor_id = common::create_actor(
+			ctx.leader_dc().guard_port(),
+			&namespace,
+			"test-actor",
+			runner.name(),
+			rivet_types::actors::CrashPolicy::Destroy,
+		)
+		.await
+		.actor
+		.actor_id;
+		let short_actor_id = common::create_actor(
+			ctx.leader_dc().guard_port(),
+			&namespace,
+			SHORT_HIBERNATION_ACTOR,
+			runner.name(),
+			rivet_types::actors::CrashPolicy::Destroy,
+		)
+		.await
+		.actor
+		.actor_id;
+
+		let short_threshold =
+			pb.hibernating_request_eligible_threshold_for_actor(SHORT_HIBERNATION_ACTOR);
+		assert!(
+			short_threshold < pb.hibernating_request_eligible_threshold_for_actor("test-actor"),
+			"override should be shorter than the default threshold"
+		);
+
+		let workflow_ctx = &ctx.leader_dc().workflow_ctx;
+		for actor_id in [default_actor_id, short_actor_id] {
+			workflow_ctx
+				.op(pegboard::ops::actor::hibernating_request::upsert::Input {
+					actor_id,
+					gateway_id: [1, 2, 3, 4],
+					request_id: [0, 0, 0, 1],
+				})
+				.await
+				.expect("failed to upsert hibernating request");
+		}
+
+		// Let the last ping age past the overridden threshold
+		tokio::time::sleep(std::time::Duration::from_millis(
+			(short_threshold * 2).try_into().unwrap(),
+		))
+		.await;
+
+		let short_count = workflow_ctx
+			.op(pegboard::ops::actor::hibernating_request::count::Input {
+				actor_id: short_actor_id,
+			})
+			.await
+			.expect("failed to count hibernating requests");
+		assert_eq!(
+			short_count, 0,
+			"request should no longer be eligible after the overridden threshold"
+		);
+
+		let default_count = workflow_ctx
+			.op(pegboard::ops::actor::hibernating_request::count::Input {
+				actor_id: default_actor_id,
+			})
+			.await
+			.expect("failed to count hibernating requests");
+		assert_eq!(
+			default_count, 1,
+			"request should still be eligible under the default threshold"
+		);
+	});
+}
@@ -0,0 +1,70 @@
+use super::super::common;
+
+async fn reservation_dc_for_key(
+	dc: &common::TestDatacenter,
+	namespace_id: rivet_util::Id,
+	key: &str,
+) -> Option<u16> {
+	dc.workflow_ctx
+		.op(pegboard::ops::actor::get_reservation_dc_for_key::Input {
+			namespace_id,
+			name: "test-actor".to_string(),
+			key: key.to_string(),
+			pool_name: None,
+		})
+		.await
+		.expect("failed to get reservation dc for key")
+		.datacenter_label
+}
+
+#[test]
+fn key_reservation_dc_reported_from_every_dc() {
+	common::run(common::TestOpts::new(2), |ctx| async move {
+		let (namespace, namespace_id, _runner) =
+			common::setup_test_namespace_with_runner(ctx.leader_dc()).await;
+
+		// Reserve the key in DC2
+		let key = common::generate_unique_key();
+		common::api::public::actors_create(
+			ctx.leader_dc().guard_port(),
+			common::api_types::actors::create::CreateQuery {
+				namespace: namespace.clone(),
+			},
+			common::api_types::actors::create::CreateRequest {
+				datacenter: Some("dc-2".to_string()),
+				name: "test-actor".to_string(),
+				key: Some(key.clone()),
+				input: None,
+				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+				affinity: None,
+				required_capabilities: Vec::new(),
+				tags: Default::default(),
+			},
+		)
+		.await
+		.expect("failed to create actor");
+
+		assert_eq!(
+			reservation_dc_for_key(ctx.leader_dc(), namespace_id, &key).await,
+			Some(2),
+			"leader dc should report dc-2 as the reservation holder"
+		);
+		assert_eq!(
+			reservation_dc_for_key(ctx.get_dc(2), namespace_id, &key).await,
+			Some(2),
+			"dc-2 should report itself as the reservation holder"
+		);
+
+		assert_eq!(
+			reservation_dc_for_key(
+				ctx.leader_dc(),
+				namespace_id,
+				&common::generate_unique_key()
+			)
+			.await,
+			None,
+			"unreserved key should not report a dc"
+		);
+	});
+}
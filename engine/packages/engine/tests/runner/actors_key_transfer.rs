@@ -0,0 +1,179 @@
+use super::super::common;
+
+async fn create_actor(guard_port: u16, namespace: &str, key: Option<String>) -> rivet_util::Id {
+	let res = common::api::public::actors_create(
+		guard_port,
+		common::api_types::actors::create::CreateQuery {
+			namespace: namespace.to_string(),
+		},
+		common::api_types::actors::create::CreateRequest {
+			datacenter: None,
+			name: "test-actor".to_string(),
+			key,
+			input: None,
+			runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
+			crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+			affinity: None,
+			required_capabilities: Vec::new(),
+			tags: Default::default(),
+		},
+	)
+	.await
+	.expect("failed to create actor");
+
+	res.actor.actor_id
+}
+
+async fn actor_for_key(
+	dc: &common::TestDatacenter,
+	namespace_id: rivet_util::Id,
+	key: &str,
+) -> Option<rivet_util::Id> {
+	let res = dc
+		.workflow_ctx
+		.op(pegboard::ops::actor::get_for_key::Input {
+			namespace_id,
+			name: "test-actor".to_string(),
+			key: key.to_string(),
+			pool_name: None,
+			fetch_error: false,
+		})
+		.await
+		.expect("failed to get actor for key");
+
+	match res {
+		pegboard::ops::actor::get_for_key::Output::Found { actor } => Some(actor.actor_id),
+		_ => None,
+	}
+}
+
+async fn reservation_for_key(
+	dc: &common::TestDatacenter,
+	namespace_id: rivet_util::Id,
+	key: &str,
+) -> Option<rivet_util::Id> {
+	dc.workflow_ctx
+		.op(pegboard::ops::actor::get_reservation_for_key::Input {
+			namespace_id,
+			name: "test-actor".to_string(),
+			key: key.to_string(),
+			pool_name: None,
+		})
+		.await
+		.expect("failed to get reservation for key")
+		.reservation_id
+}
+
+async fn api_key(guard_port: u16, namespace: &str, actor_id: rivet_util::Id) -> Option<String> {
+	common::try_get_actor(guard_port, &actor_id.to_string(), namespace)
+		.await
+		.expect("failed to get actor")
+		.expect("actor should exist")
+		.key
+}
+
+#[test]
+fn actor_key_transfer() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let dc = ctx.leader_dc();
+		let (namespace, namespace_id, _runner) = common::setup_test_namespace_with_runner(dc).await;
+
+		let key = common::generate_unique_key();
+		let blue_actor_id = create_actor(dc.guard_port(), &namespace, Some(key.clone())).await;
+		let green_actor_id = create_actor(dc.guard_port(), &namespace, None).await;
+
+		assert_eq!(
+			actor_for_key(dc, namespace_id, &key).await,
+			Some(blue_actor_id)
+		);
+		let reservation_id = reservation_for_key(dc, namespace_id, &key).await;
+		assert!(reservation_id.is_some(), "key should be reserved");
+
+		dc.workflow_ctx
+			.op(pegboard::ops::actor::transfer_key::Input {
+				namespace_id,
+				name: "test-actor".to_string(),
+				key: key.clone(),
+				from_actor_id: blue_actor_id,
+				to_actor_id: green_actor_id,
+			})
+			.await
+			.expect("failed to transfer key");
+
+		// The reservation is untouched and the key resolves straight to the new actor
+		assert_eq!(
+			reservation_for_key(dc, namespace_id, &key).await,
+			reservation_id
+		);
+		assert_eq!(
+			actor_for_key(dc, namespace_id, &key).await,
+			Some(green_actor_id)
+		);
+
+		// The API reports the key on the new actor only
+		assert_eq!(
+			api_key(dc.guard_port(), &namespace, blue_actor_id).await,
+			None
+		);
+		assert_eq!(
+			api_key(dc.guard_port(), &namespace, green_actor_id).await,
+			Some(key.clone())
+		);
+
+		// The old actor no longer holds the key
+		let err = dc
+			.workflow_ctx
+			.op(pegboard::ops::actor::transfer_key::Input {
+				namespace_id,
+				name: "test-actor".to_string(),
+				key: key.clone(),
+				from_actor_id: blue_actor_id,
+				to_actor_id: green_actor_id,
+			})
+			.await
+			.expect_err("old actor should not be able to transfer the key again");
+		assert!(err.to_string().contains("key_not_held"), "{err:?}");
+
+		// Destroying the old actor does not release the key from the new one
+		common::api::public::actors_delete(
+			dc.guard_port(),
+			common::api_types::actors::delete::DeletePath {
+				actor_id: blue_actor_id,
+			},
+			common::api_types::actors::delete::DeleteQuery {
+				namespace: namespace.clone(),
+			},
+		)
+		.await
+		.expect("failed to delete actor");
+
+		// Poll for actor to be destroyed or timeout after 5s
+		let start = std::time::Instant::now();
+		loop {
+			let actor =
+				common::try_get_actor(dc.guard_port(), &blue_actor_id.to_string(), &namespace)
+					.await
+					.expect("failed to get actor")
+					.expect("actor should still exist in database");
+
+			if actor.destroy_ts.is_some() {
+				break;
+			}
+
+			if start.elapsed() > std::time::Duration::from_secs(5) {
+				panic!("actor deletion timed out after 5 seconds");
+			}
+
+			tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+		}
+
+		assert_eq!(
+			actor_for_key(dc, namespace_id, &key).await,
+			Some(green_actor_id)
+		);
+		assert_eq!(
+			api_key(dc.guard_port(), &namespace, green_actor_id).await,
+			Some(key.clone())
+		);
+	});
+}
@@ -0,0 +1,85 @@
+use std::sync::{Arc, Mutex};
+
+use super::super::common;
+
+#[test]
+fn actor_kv_helper_reads_written_key() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let (namespace, namespace_id) = common::setup_test_namespace(ctx.leader_dc()).await;
+
+		let (written_tx, written_rx) = tokio::sync::oneshot::channel();
+		let written_tx = Arc::new(Mutex::new(Some(written_tx)));
+
+		let runner = common::setup_runner(ctx.leader_dc(), &namespace, |builder| {
+			builder.with_actor_behavior("kv-writer", move |_| {
+				let written_tx = written_tx.clone();
+				Box::new(
+					common::test_runner::CustomActorBuilder::new()
+						.on_start(move |config| {
+							let written_tx = written_tx.clone();
+							Box::pin(async move {
+								config
+									.send_kv_put(
+										vec![b"greeting".to_vec()],
+										vec![b"hello".to_vec()],
+									)
+									.await?;
+								if let Some(tx) = written_tx.lock().expect("written lock").take() {
+									let _ = tx.send(());
+								}
+								Ok(common::test_runner::ActorStartResult::Running)
+							})
+						})
+						.build(),
+				)
+			})
+		})
+		.await;
+
+		let res = common::create_actor(
+			ctx.leader_dc().guard_port(),
+			&namespace,
+			"kv-writer",
+			runner.name(),
+			rivet_types::actors::CrashPolicy::Destroy,
+		)
+		.await;
+
+		let kv = common::ActorKv::new(
+			ctx.leader_dc(),
+			namespace_id,
+			res.actor.actor_id,
+			"kv-writer",
+		);
+
+		written_rx.await.expect("actor should write its key");
+
+		kv.assert_kv_contains(b"greeting", b"hello").await;
+	});
+}
+
+#[test]
+fn actor_kv_helper_reports_empty_kv() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let (namespace, namespace_id, runner) =
+			common::setup_test_namespace_with_runner(ctx.leader_dc()).await;
+
+		let res = common::create_actor(
+			ctx.leader_dc().guard_port(),
+			&namespace,
+			"test-actor",
+			runner.name(),
+			rivet_types::actors::CrashPolicy::Destroy,
+		)
+		.await;
+
+		common::ActorKv::new(
+			ctx.leader_dc(),
+			namespace_id,
+			res.actor.actor_id,
+			"test-actor",
+		)
+		.assert_kv_empty()
+		.await;
+	});
+}
@@ -0,0 +1,96 @@
+use std::sync::{Arc, Mutex};
+
+use anyhow::{Result, ensure};
+use common::test_runner::{KvClient, KvError};
+use rivet_runner_protocol::mk2 as rp;
+
+use super::super::common;
+
+/// Runs every `KvClient` operation against the engine.
+async fn exercise_kv(kv: &KvClient) -> Result<()> {
+	kv.put("a", "1").await?;
+	kv.put("b", "2").await?;
+	ensure!(
+		kv.get("a").await? == Some(b"1".to_vec()),
+		"get returned the wrong value"
+	);
+	ensure!(
+		kv.get("missing").await?.is_none(),
+		"missing key should not be found"
+	);
+
+	let entries = kv.list(rp::KvListQuery::KvListAllQuery).await?;
+	ensure!(
+		entries
+			== vec![
+				(b"a".to_vec(), b"1".to_vec()),
+				(b"b".to_vec(), b"2".to_vec())
+			],
+		"list returned {entries:?}"
+	);
+
+	kv.delete("a").await?;
+	ensure!(
+		kv.get("a").await?.is_none(),
+		"deleted key should not be found"
+	);
+
+	// Keys are limited to 2 KiB
+	let res = kv.put(vec![0u8; 4096], "too long").await;
+	ensure!(
+		matches!(res, Err(KvError::Engine { op: "put", .. })),
+		"oversized key should be rejected by the engine, got {res:?}"
+	);
+
+	kv.delete_all().await?;
+	let entries = kv.list(rp::KvListQuery::KvListAllQuery).await?;
+	ensure!(entries.is_empty(), "delete all left {entries:?}");
+
+	Ok(())
+}
+
+#[test]
+fn actor_kv_client_round_trip() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let (namespace, _) = common::setup_test_namespace(ctx.leader_dc()).await;
+
+		let (result_tx, result_rx) = tokio::sync::oneshot::channel();
+		let result_tx = Arc::new(Mutex::new(Some(result_tx)));
+
+		let runner = common::setup_runner(ctx.leader_dc(), &namespace, |builder| {
+			builder.with_actor_behavior("kv-client", move |_| {
+				let result_tx = result_tx.clone();
+				Box::new(
+					common::test_runner::CustomActorBuilder::new()
+						.on_start(move |config| {
+							let result_tx = result_tx.clone();
+							Box::pin(async move {
+								let res =
+									exercise_kv(&config.kv).await.map_err(|err| err.to_string());
+								if let Some(tx) = result_tx.lock().expect("result lock").take() {
+									let _ = tx.send(res);
+								}
+								Ok(common::test_runner::ActorStartResult::Running)
+							})
+						})
+						.build(),
+				)
+			})
+		})
+		.await;
+
+		common::create_actor(
+			ctx.leader_dc().guard_port(),
+			&namespace,
+			"kv-client",
+			runner.name(),
+			rivet_types::actors::CrashPolicy::Destroy,
+		)
+		.await;
+
+		result_rx
+			.await
+			.expect("actor should report its KV result")
+			.expect("KV operations failed");
+	});
+}
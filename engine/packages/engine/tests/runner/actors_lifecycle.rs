@@ -86,7 +86,10 @@ fn create_actor_with_input() {
 				key: None,
 				input: Some(input_data.clone()),
 				runner_name_selector: runner.name().to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+				affinity: None,
+				required_capabilities: Vec::new(),
+				tags: Default::default(),
 			},
 		)
 		.await
@@ -792,6 +795,77 @@ fn actor_sleep_intent() {
 	});
 }
 
+#[test]
+fn sleeping_actor_wakes_on_http_request() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let (namespace, _) = common::setup_test_namespace(ctx.leader_dc()).await;
+
+		// Sleeps on its first start only so the wake from the request sticks
+		let starts = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+		let actor_starts = starts.clone();
+		let runner = common::setup_runner(ctx.leader_dc(), &namespace, |builder| {
+			builder.with_actor_behavior("sleep-once-actor", move |_| {
+				let starts = actor_starts.clone();
+				Box::new(
+					common::test_runner::CustomActorBuilder::new()
+						.on_start(move |config| {
+							let starts = starts.clone();
+							Box::pin(async move {
+								if starts.fetch_add(1, std::sync::atomic::Ordering::SeqCst) == 0 {
+									config.send_sleep_intent();
+								}
+								Ok(common::test_runner::ActorStartResult::Running)
+							})
+						})
+						.build(),
+				)
+			})
+		})
+		.await;
+
+		let res = common::create_actor(
+			ctx.leader_dc().guard_port(),
+			&namespace,
+			"sleep-once-actor",
+			runner.name(),
+			rivet_types::actors::CrashPolicy::Destroy,
+		)
+		.await;
+		let actor_id_str = res.actor.actor_id.to_string();
+
+		common::wait_with_poll(
+			std::time::Duration::from_secs(10),
+			std::time::Duration::from_millis(50),
+			|| async {
+				common::try_get_actor(ctx.leader_dc().guard_port(), &actor_id_str, &namespace)
+					.await
+					.expect("failed to get actor")
+					.filter(|actor| actor.sleep_ts.is_some())
+			},
+		)
+		.await
+		.expect("actor should enter sleep state");
+
+		// The gateway wakes the actor and holds the request until it is ready
+		let response = common::ping_actor_via_guard(ctx.leader_dc(), &actor_id_str).await;
+		assert_eq!(response["status"], "ok");
+		assert_eq!(
+			starts.load(std::sync::atomic::Ordering::SeqCst),
+			2,
+			"actor should have been started again by the request"
+		);
+
+		let actor = common::try_get_actor(ctx.leader_dc().guard_port(), &actor_id_str, &namespace)
+			.await
+			.expect("failed to get actor")
+			.expect("actor should exist");
+		assert!(
+			actor.connectable_ts.is_some(),
+			"actor should be connectable after waking"
+		);
+	});
+}
+
 // MARK: Pending Allocation Queue
 #[test]
 // Broken legacy Pegboard Runner test: full engine sweep can observe the actor
@@ -0,0 +1,94 @@
+use rivet_runner_protocol::mk2 as rp;
+use rivet_types::actors::{ActorLog, ActorLogStream};
+
+use super::super::common;
+
+fn line(stream: rp::ActorLogStream, message: &str) -> rp::ActorLogLine {
+	rp::ActorLogLine {
+		ts: rivet_util::timestamp::now(),
+		stream,
+		message: message.to_string(),
+	}
+}
+
+async fn list_logs(
+	ctx: &common::TestCtx,
+	namespace: &str,
+	actor_id: rivet_util::Id,
+	after: Option<u64>,
+) -> Vec<ActorLog> {
+	common::api::public::actors_logs(
+		ctx.leader_dc().guard_port(),
+		common::api_types::actors::logs::LogsPath { actor_id },
+		common::api_types::actors::logs::LogsQuery {
+			namespace: namespace.to_string(),
+			after,
+			limit: None,
+			follow: None,
+		},
+	)
+	.await
+	.expect("failed to list actor logs")
+	.logs
+}
+
+#[test]
+fn actor_logs_forwarded_by_runner_are_listed() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let (namespace, _) = common::setup_test_namespace(ctx.leader_dc()).await;
+
+		let runner = common::setup_runner(ctx.leader_dc(), &namespace, |builder| {
+			builder.with_actor_behavior("logger", |_| {
+				Box::new(
+					common::test_runner::CustomActorBuilder::new()
+						.on_start(|config| {
+							Box::pin(async move {
+								config.send_log(vec![
+									line(rp::ActorLogStream::Stdout, "starting"),
+									line(rp::ActorLogStream::Stderr, "warning"),
+									line(rp::ActorLogStream::Stdout, "ready"),
+								]);
+								Ok(common::test_runner::ActorStartResult::Running)
+							})
+						})
+						.build(),
+				)
+			})
+		})
+		.await;
+
+		let res = common::create_actor(
+			ctx.leader_dc().guard_port(),
+			&namespace,
+			"logger",
+			runner.name(),
+			rivet_types::actors::CrashPolicy::Destroy,
+		)
+		.await;
+		let actor_id = res.actor.actor_id;
+
+		let logs = common::wait_with_poll(
+			std::time::Duration::from_secs(10),
+			std::time::Duration::from_millis(50),
+			|| async {
+				let logs = list_logs(&ctx, &namespace, actor_id, None).await;
+				(logs.len() == 3).then_some(logs)
+			},
+		)
+		.await
+		.expect("timed out waiting for actor logs");
+
+		let messages = logs
+			.iter()
+			.map(|log| log.message.as_str())
+			.collect::<Vec<_>>();
+		assert_eq!(messages, vec!["starting", "warning", "ready"]);
+		assert!(matches!(logs[1].stream, ActorLogStream::Stderr));
+		assert!(logs.windows(2).all(|w| w[0].idx < w[1].idx));
+
+		// Resuming from a cursor only returns later lines
+		let rest = list_logs(&ctx, &namespace, actor_id, Some(logs[0].idx)).await;
+		assert_eq!(rest.len(), 2);
+		assert_eq!(rest[0].message, "warning");
+	});
+}
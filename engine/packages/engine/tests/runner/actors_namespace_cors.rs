@@ -0,0 +1,218 @@
+use std::time::Duration;
+
+use super::super::common;
+
+const ALLOWED_ORIGIN: &str = "https://allowed.example";
+const DISALLOWED_ORIGIN: &str = "https://disallowed.example";
+
+/// CORS policy that only allows `ALLOWED_ORIGIN`.
+fn restrictive_cors_policy() -> rivet_types::namespaces::CorsPolicy {
+	rivet_types::namespaces::CorsPolicy {
+		allowed_origins: Some(vec![ALLOWED_ORIGIN.to_string()]),
+		allowed_methods: Some(vec!["GET".to_string(), "POST".to_string()]),
+		allowed_headers: Some(vec!["content-type".to_string()]),
+		max_age: Some(600),
+	}
+}
+
+/// Creates a namespace with a CORS policy that only allows `ALLOWED_ORIGIN` and an actor in it.
+/// Returns the runner and actor id.
+async fn setup_actor_with_cors_policy(dc: &common::TestDatacenter) -> (common::TestRunner, String) {
+	let res = common::api::public::namespaces_create(
+		dc.guard_port(),
+		rivet_api_peer::namespaces::CreateRequest {
+			name: format!("test-{}", rand::random::<u16>()),
+			display_name: "Test Namespace".to_string(),
+			default_crash_policy: None,
+			cors_policy: Some(restrictive_cors_policy()),
+			actor_rate_limit: None,
+		},
+	)
+	.await
+	.expect("failed to create namespace");
+
+	setup_actor(dc, &res.namespace.name).await
+}
+
+/// Creates an actor in the namespace. Returns the runner and actor id.
+async fn setup_actor(dc: &common::TestDatacenter, namespace: &str) -> (common::TestRunner, String) {
+	let runner = common::setup_runner(dc, namespace, |builder| {
+		builder.with_actor_behavior("test-actor", |_config| {
+			Box::new(common::test_runner::EchoActor::new())
+		})
+	})
+	.await;
+
+	let res = common::create_actor(
+		dc.guard_port(),
+		namespace,
+		"test-actor",
+		runner.name(),
+		rivet_types::actors::CrashPolicy::Destroy,
+	)
+	.await;
+	let actor_id = res.actor.actor_id.to_string();
+
+	common::wait_with_poll(
+		Duration::from_secs(10),
+		Duration::from_millis(50),
+		|| async { runner.has_actor(&actor_id).await.then_some(()) },
+	)
+	.await
+	.expect("actor was never allocated to the runner");
+
+	(runner, actor_id)
+}
+
+async fn preflight(dc: &common::TestDatacenter, actor_id: &str, origin: &str) -> reqwest::Response {
+	reqwest::Client::new()
+		.request(
+			reqwest::Method::OPTIONS,
+			format!(
+				"http://127.0.0.1:{}/gateway/{actor_id}/ping",
+				dc.guard_port()
+			),
+		)
+		.header("Origin", origin)
+		.header("Access-Control-Request-Method", "POST")
+		.header("Access-Control-Request-Headers", "x-custom")
+		.send()
+		.await
+		.expect("failed to send preflight")
+}
+
+async fn ping(dc: &common::TestDatacenter, actor_id: &str, origin: &str) -> reqwest::Response {
+	let response = reqwest::Client::new()
+		.get(format!("http://127.0.0.1:{}/ping", dc.guard_port()))
+		.header("X-Rivet-Target", "actor")
+		.header("X-Rivet-Actor", actor_id)
+		.header("Origin", origin)
+		.send()
+		.await
+		.expect("failed to send request");
+	assert!(
+		response.status().is_success(),
+		"request failed: {}",
+		response.status()
+	);
+
+	response
+}
+
+fn header<'a>(response: &'a reqwest::Response, name: &str) -> Option<&'a str> {
+	response
+		.headers()
+		.get(name)
+		.map(|v| v.to_str().expect("invalid header value"))
+}
+
+#[test]
+fn namespace_cors_policy_applies_to_preflight() {
+	common::run(
+		common::TestOpts::new(1).with_timeout(30),
+		|ctx| async move {
+			let (_runner, actor_id) = setup_actor_with_cors_policy(ctx.leader_dc()).await;
+
+			let response = preflight(ctx.leader_dc(), &actor_id, ALLOWED_ORIGIN).await;
+			assert_eq!(response.status(), reqwest::StatusCode::NO_CONTENT);
+			assert_eq!(
+				header(&response, "access-control-allow-origin"),
+				Some(ALLOWED_ORIGIN)
+			);
+			assert_eq!(
+				header(&response, "access-control-allow-methods"),
+				Some("GET, POST")
+			);
+			assert_eq!(
+				header(&response, "access-control-allow-headers"),
+				Some("content-type")
+			);
+			assert_eq!(header(&response, "access-control-max-age"), Some("600"));
+
+			let response = preflight(ctx.leader_dc(), &actor_id, DISALLOWED_ORIGIN).await;
+			assert_eq!(response.status(), reqwest::StatusCode::NO_CONTENT);
+			assert_eq!(header(&response, "access-control-allow-origin"), None);
+		},
+	);
+}
+
+#[test]
+fn namespace_cors_policy_applies_to_actor_response() {
+	common::run(
+		common::TestOpts::new(1).with_timeout(30),
+		|ctx| async move {
+			let (_runner, actor_id) = setup_actor_with_cors_policy(ctx.leader_dc()).await;
+
+			let response = ping(ctx.leader_dc(), &actor_id, ALLOWED_ORIGIN).await;
+			assert_eq!(
+				header(&response, "access-control-allow-origin"),
+				Some(ALLOWED_ORIGIN)
+			);
+
+			// The request still reaches the actor, the browser blocks the response
+			let response = ping(ctx.leader_dc(), &actor_id, DISALLOWED_ORIGIN).await;
+			assert_eq!(header(&response, "access-control-allow-origin"), None);
+		},
+	);
+}
+
+#[test]
+fn namespace_cors_policy_can_be_updated() {
+	common::run(
+		common::TestOpts::new(1).with_timeout(30),
+		|ctx| async move {
+			let (namespace, _) = common::setup_test_namespace(ctx.leader_dc()).await;
+			let (_runner, actor_id) = setup_actor(ctx.leader_dc(), &namespace).await;
+
+			// All origins are allowed without a policy
+			let response = ping(ctx.leader_dc(), &actor_id, DISALLOWED_ORIGIN).await;
+			assert!(header(&response, "access-control-allow-origin").is_some());
+
+			common::api::public::namespaces_update(
+				ctx.leader_dc().guard_port(),
+				rivet_api_peer::namespaces::UpdatePath {
+					namespace: namespace.clone(),
+				},
+				rivet_api_peer::namespaces::UpdateRequest {
+					default_crash_policy: None,
+					cors_policy: Some(restrictive_cors_policy()),
+					actor_rate_limit: None,
+				},
+			)
+			.await
+			.expect("failed to update namespace");
+
+			let response = ping(ctx.leader_dc(), &actor_id, ALLOWED_ORIGIN).await;
+			assert_eq!(
+				header(&response, "access-control-allow-origin"),
+				Some(ALLOWED_ORIGIN)
+			);
+
+			let response = ping(ctx.leader_dc(), &actor_id, DISALLOWED_ORIGIN).await;
+			assert_eq!(header(&response, "access-control-allow-origin"), None);
+		},
+	);
+}
+
+#[test]
+fn namespace_cors_policy_update_rejects_invalid_policy() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let (namespace, _) = common::setup_test_namespace(ctx.leader_dc()).await;
+
+		let res = common::api::public::namespaces_update(
+			ctx.leader_dc().guard_port(),
+			rivet_api_peer::namespaces::UpdatePath { namespace },
+			rivet_api_peer::namespaces::UpdateRequest {
+				default_crash_policy: None,
+				cors_policy: Some(rivet_types::namespaces::CorsPolicy {
+					allowed_methods: Some(vec!["GET POST".to_string()]),
+					..Default::default()
+				}),
+				actor_rate_limit: None,
+			},
+		)
+		.await;
+
+		assert!(res.is_err(), "invalid cors policy should be rejected");
+	});
+}
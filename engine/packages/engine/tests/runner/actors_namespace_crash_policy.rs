@@ -0,0 +1,242 @@
+use std::sync::{Arc, Mutex};
+
+use super::super::common;
+
+async fn setup_namespace_with_default_crash_policy(
+	ctx: &common::TestCtx,
+	default_crash_policy: rivet_types::actors::CrashPolicy,
+) -> String {
+	let res = common::api::public::namespaces_create(
+		ctx.leader_dc().guard_port(),
+		rivet_api_peer::namespaces::CreateRequest {
+			name: format!("test-{}", rand::random::<u16>()),
+			display_name: "Test Namespace".to_string(),
+			default_crash_policy: Some(default_crash_policy),
+			cors_policy: None,
+			actor_rate_limit: None,
+		},
+	)
+	.await
+	.expect("failed to create namespace");
+
+	assert_eq!(
+		res.namespace.default_crash_policy,
+		Some(default_crash_policy)
+	);
+
+	res.namespace.name
+}
+
+async fn update_namespace_default_crash_policy(
+	ctx: &common::TestCtx,
+	namespace: &str,
+	default_crash_policy: Option<rivet_types::actors::CrashPolicy>,
+) {
+	let res = common::api::public::namespaces_update(
+		ctx.leader_dc().guard_port(),
+		rivet_api_peer::namespaces::UpdatePath {
+			namespace: namespace.to_string(),
+		},
+		rivet_api_peer::namespaces::UpdateRequest {
+			default_crash_policy,
+			cors_policy: None,
+			actor_rate_limit: None,
+		},
+	)
+	.await
+	.expect("failed to update namespace");
+
+	assert_eq!(res.namespace.default_crash_policy, default_crash_policy);
+}
+
+async fn create_actor_with_crash_policy(
+	ctx: &common::TestCtx,
+	namespace: &str,
+	name: &str,
+	runner_name: &str,
+	crash_policy: Option<rivet_types::actors::CrashPolicy>,
+) -> rivet_types::actors::Actor {
+	common::api::public::actors_create(
+		ctx.leader_dc().guard_port(),
+		common::api_types::actors::create::CreateQuery {
+			namespace: namespace.to_string(),
+		},
+		common::api_types::actors::create::CreateRequest {
+			datacenter: None,
+			name: name.to_string(),
+			key: None,
+			input: None,
+			runner_name_selector: runner_name.to_string(),
+			crash_policy,
+			affinity: None,
+			required_capabilities: Vec::new(),
+			tags: Default::default(),
+		},
+	)
+	.await
+	.expect("failed to create actor")
+	.actor
+}
+
+#[test]
+fn namespace_default_crash_policy_applies_when_unspecified() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let namespace = setup_namespace_with_default_crash_policy(
+			&ctx,
+			rivet_types::actors::CrashPolicy::Sleep,
+		)
+		.await;
+
+		// Create channel to be notified when actor crashes
+		let (crash_tx, crash_rx) = tokio::sync::oneshot::channel();
+		let crash_tx = Arc::new(Mutex::new(Some(crash_tx)));
+
+		let runner = common::setup_runner(ctx.leader_dc(), &namespace, |builder| {
+			builder.with_actor_behavior("crash-actor", move |_| {
+				Box::new(common::test_runner::CrashOnStartActor::new_with_notify(
+					1,
+					crash_tx.clone(),
+				))
+			})
+		})
+		.await;
+
+		let actor =
+			create_actor_with_crash_policy(&ctx, &namespace, "crash-actor", runner.name(), None)
+				.await;
+		assert_eq!(actor.crash_policy, rivet_types::actors::CrashPolicy::Sleep);
+
+		let actor_id_str = actor.actor_id.to_string();
+
+		crash_rx
+			.await
+			.expect("actor should have sent crash notification");
+
+		// Poll for sleep_ts to be set (system needs to process the crash)
+		let actor = loop {
+			let actor =
+				common::try_get_actor(ctx.leader_dc().guard_port(), &actor_id_str, &namespace)
+					.await
+					.expect("failed to get actor")
+					.expect("actor should exist");
+
+			if actor.sleep_ts.is_some() {
+				break actor;
+			}
+
+			tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+		};
+
+		assert!(
+			actor.destroy_ts.is_none(),
+			"actor should sleep instead of being destroyed after crash"
+		);
+	});
+}
+
+#[test]
+fn actor_crash_policy_overrides_namespace_default() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let namespace = setup_namespace_with_default_crash_policy(
+			&ctx,
+			rivet_types::actors::CrashPolicy::Sleep,
+		)
+		.await;
+
+		let runner = common::setup_runner(ctx.leader_dc(), &namespace, |builder| {
+			builder.with_actor_behavior("test-actor", |_| {
+				Box::new(common::test_runner::EchoActor::new())
+			})
+		})
+		.await;
+
+		let actor = create_actor_with_crash_policy(
+			&ctx,
+			&namespace,
+			"test-actor",
+			runner.name(),
+			Some(rivet_types::actors::CrashPolicy::Restart),
+		)
+		.await;
+		assert_eq!(
+			actor.crash_policy,
+			rivet_types::actors::CrashPolicy::Restart
+		);
+	});
+}
+
+#[test]
+fn crash_policy_defaults_to_destroy_without_namespace_default() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let (namespace, _, runner) =
+			common::setup_test_namespace_with_runner(ctx.leader_dc()).await;
+
+		let actor =
+			create_actor_with_crash_policy(&ctx, &namespace, "test-actor", runner.name(), None)
+				.await;
+		assert_eq!(
+			actor.crash_policy,
+			rivet_types::actors::CrashPolicy::Destroy
+		);
+	});
+}
+
+#[test]
+fn namespace_default_crash_policy_can_be_updated() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let (namespace, _, runner) =
+			common::setup_test_namespace_with_runner(ctx.leader_dc()).await;
+
+		// Creating an actor first caches the namespace, so this also checks the update purges it
+		let actor =
+			create_actor_with_crash_policy(&ctx, &namespace, "test-actor", runner.name(), None)
+				.await;
+		assert_eq!(
+			actor.crash_policy,
+			rivet_types::actors::CrashPolicy::Destroy
+		);
+
+		update_namespace_default_crash_policy(
+			&ctx,
+			&namespace,
+			Some(rivet_types::actors::CrashPolicy::Sleep),
+		)
+		.await;
+
+		let actor =
+			create_actor_with_crash_policy(&ctx, &namespace, "test-actor", runner.name(), None)
+				.await;
+		assert_eq!(actor.crash_policy, rivet_types::actors::CrashPolicy::Sleep);
+
+		// Clearing the default falls back to destroy again
+		update_namespace_default_crash_policy(&ctx, &namespace, None).await;
+
+		let actor =
+			create_actor_with_crash_policy(&ctx, &namespace, "test-actor", runner.name(), None)
+				.await;
+		assert_eq!(
+			actor.crash_policy,
+			rivet_types::actors::CrashPolicy::Destroy
+		);
+	});
+}
+
+#[test]
+fn namespace_update_unknown_namespace_fails() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let res = common::api::public::namespaces_update(
+			ctx.leader_dc().guard_port(),
+			rivet_api_peer::namespaces::UpdatePath {
+				namespace: format!("missing-{}", rand::random::<u16>()),
+			},
+			rivet_api_peer::namespaces::UpdateRequest {
+				default_crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+				cors_policy: None,
+				actor_rate_limit: None,
+			},
+		)
+		.await;
+
+		assert!(res.is_err(), "updating a missing namespace should fail");
+	});
+}
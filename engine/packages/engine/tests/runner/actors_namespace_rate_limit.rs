@@ -0,0 +1,197 @@
+use std::time::Duration;
+
+use super::super::common;
+
+/// Creates a namespace that allows 2 requests per actor per second with no extra burst and an
+/// actor in it. Returns the runner and actor id.
+async fn setup_actor_with_rate_limit(dc: &common::TestDatacenter) -> (common::TestRunner, String) {
+	let res = common::api::public::namespaces_create(
+		dc.guard_port(),
+		rivet_api_peer::namespaces::CreateRequest {
+			name: format!("test-{}", rand::random::<u16>()),
+			display_name: "Test Namespace".to_string(),
+			default_crash_policy: None,
+			cors_policy: None,
+			actor_rate_limit: Some(rivet_types::namespaces::ActorRateLimit {
+				requests_per_second: 2,
+				burst: None,
+			}),
+		},
+	)
+	.await
+	.expect("failed to create namespace");
+
+	setup_actor(dc, &res.namespace.name).await
+}
+
+/// Creates an actor in the namespace. Returns the runner and actor id.
+async fn setup_actor(dc: &common::TestDatacenter, namespace: &str) -> (common::TestRunner, String) {
+	let runner = common::setup_runner(dc, namespace, |builder| {
+		builder.with_actor_behavior("test-actor", |_config| {
+			Box::new(common::test_runner::EchoActor::new())
+		})
+	})
+	.await;
+
+	let res = common::create_actor(
+		dc.guard_port(),
+		namespace,
+		"test-actor",
+		runner.name(),
+		rivet_types::actors::CrashPolicy::Destroy,
+	)
+	.await;
+	let actor_id = res.actor.actor_id.to_string();
+
+	common::wait_with_poll(
+		Duration::from_secs(10),
+		Duration::from_millis(50),
+		|| async { runner.has_actor(&actor_id).await.then_some(()) },
+	)
+	.await
+	.expect("actor was never allocated to the runner");
+
+	(runner, actor_id)
+}
+
+async fn update_actor_rate_limit(
+	dc: &common::TestDatacenter,
+	namespace: &str,
+	actor_rate_limit: Option<rivet_types::namespaces::ActorRateLimit>,
+) {
+	let res = common::api::public::namespaces_update(
+		dc.guard_port(),
+		rivet_api_peer::namespaces::UpdatePath {
+			namespace: namespace.to_string(),
+		},
+		rivet_api_peer::namespaces::UpdateRequest {
+			default_crash_policy: None,
+			cors_policy: None,
+			actor_rate_limit,
+		},
+	)
+	.await
+	.expect("failed to update namespace");
+
+	assert_eq!(res.namespace.actor_rate_limit, actor_rate_limit);
+}
+
+async fn ping(dc: &common::TestDatacenter, actor_id: &str) -> reqwest::Response {
+	reqwest::Client::new()
+		.get(format!("http://127.0.0.1:{}/ping", dc.guard_port()))
+		.header("X-Rivet-Target", "actor")
+		.header("X-Rivet-Actor", actor_id)
+		.send()
+		.await
+		.expect("failed to send request")
+}
+
+#[test]
+fn namespace_actor_rate_limit_throttles_requests() {
+	common::run(
+		common::TestOpts::new(1).with_timeout(30),
+		|ctx| async move {
+			let (_runner, actor_id) = setup_actor_with_rate_limit(ctx.leader_dc()).await;
+
+			for _ in 0..2 {
+				let response = ping(ctx.leader_dc(), &actor_id).await;
+				assert!(
+					response.status().is_success(),
+					"request failed: {}",
+					response.status()
+				);
+			}
+
+			let response = ping(ctx.leader_dc(), &actor_id).await;
+			assert_eq!(response.status(), reqwest::StatusCode::TOO_MANY_REQUESTS);
+			assert!(
+				response.headers().contains_key("retry-after"),
+				"throttled response should include retry-after"
+			);
+
+			// Tokens refill over time
+			tokio::time::sleep(Duration::from_secs(1)).await;
+			let response = ping(ctx.leader_dc(), &actor_id).await;
+			assert!(
+				response.status().is_success(),
+				"request failed after refill: {}",
+				response.status()
+			);
+		},
+	);
+}
+
+#[test]
+fn namespace_actor_rate_limit_can_be_updated() {
+	common::run(
+		common::TestOpts::new(1).with_timeout(30),
+		|ctx| async move {
+			let (namespace, _) = common::setup_test_namespace(ctx.leader_dc()).await;
+			let (_runner, actor_id) = setup_actor(ctx.leader_dc(), &namespace).await;
+
+			for _ in 0..3 {
+				let response = ping(ctx.leader_dc(), &actor_id).await;
+				assert!(
+					response.status().is_success(),
+					"request failed without a rate limit: {}",
+					response.status()
+				);
+			}
+
+			update_actor_rate_limit(
+				ctx.leader_dc(),
+				&namespace,
+				Some(rivet_types::namespaces::ActorRateLimit {
+					requests_per_second: 2,
+					burst: None,
+				}),
+			)
+			.await;
+
+			for _ in 0..2 {
+				let response = ping(ctx.leader_dc(), &actor_id).await;
+				assert!(
+					response.status().is_success(),
+					"request failed: {}",
+					response.status()
+				);
+			}
+
+			let response = ping(ctx.leader_dc(), &actor_id).await;
+			assert_eq!(response.status(), reqwest::StatusCode::TOO_MANY_REQUESTS);
+
+			// Clearing the rate limit stops throttling
+			update_actor_rate_limit(ctx.leader_dc(), &namespace, None).await;
+
+			let response = ping(ctx.leader_dc(), &actor_id).await;
+			assert!(
+				response.status().is_success(),
+				"request failed after clearing the rate limit: {}",
+				response.status()
+			);
+		},
+	);
+}
+
+#[test]
+fn namespace_actor_rate_limit_update_rejects_invalid_limit() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let (namespace, _) = common::setup_test_namespace(ctx.leader_dc()).await;
+
+		let res = common::api::public::namespaces_update(
+			ctx.leader_dc().guard_port(),
+			rivet_api_peer::namespaces::UpdatePath { namespace },
+			rivet_api_peer::namespaces::UpdateRequest {
+				default_crash_policy: None,
+				cors_policy: None,
+				actor_rate_limit: Some(rivet_types::namespaces::ActorRateLimit {
+					requests_per_second: 0,
+					burst: None,
+				}),
+			},
+		)
+		.await;
+
+		assert!(res.is_err(), "invalid actor rate limit should be rejected");
+	});
+}
@@ -0,0 +1,309 @@
+use super::super::common;
+
+use futures_util::{StreamExt, TryStreamExt};
+use serde_json::json;
+use std::net::SocketAddr;
+use std::time::Duration;
+use universaldb::prelude::*;
+
+/// Max signals the runner pool drains per tick (`SIGNAL_BATCH_SIZE * MAX_SIGNAL_BATCHES` in
+/// `pegboard::workflows::runner_pool`).
+const POOL_DRAIN_LIMIT: i64 = 256 * 8;
+
+fn unrouted_selector_count(namespace_id: rivet_util::Id, runner_name: &str) -> u64 {
+	pegboard::metrics::ACTOR_UNROUTED_SELECTOR_TOTAL
+		.with_label_values(&[&namespace_id.to_string(), runner_name])
+		.get()
+}
+
+fn serverless_desired_runners(namespace_id: rivet_util::Id, runner_name: &str) -> i64 {
+	pegboard::metrics::SERVERLESS_DESIRED_RUNNERS
+		.with_label_values(&[&namespace_id.to_string(), runner_name])
+		.get()
+}
+
+fn serverless_pool_signal_backlog(namespace_id: rivet_util::Id, runner_name: &str) -> i64 {
+	pegboard::metrics::SERVERLESS_POOL_SIGNAL_BACKLOG
+		.with_label_values(&[&namespace_id.to_string(), runner_name])
+		.get()
+}
+
+fn serverless_pool_bumps_coalesced(namespace_id: rivet_util::Id, runner_name: &str) -> u64 {
+	pegboard::metrics::SERVERLESS_POOL_BUMPS_COALESCED_TOTAL
+		.with_label_values(&[&namespace_id.to_string(), runner_name])
+		.get()
+}
+
+async fn is_pool_backlogged(
+	ctx: &common::TestCtx,
+	namespace_id: rivet_util::Id,
+	runner_name: &str,
+) -> bool {
+	ctx.leader_dc()
+		.workflow_ctx
+		.udb()
+		.expect("failed to get udb")
+		.txn("test_read_pool_signal_backlog", |tx| async move {
+			let tx = tx.with_subspace(pegboard::keys::subspace());
+
+			tx.exists(
+				&pegboard::keys::ns::ServerlessPoolSignalBacklogKey::new(
+					namespace_id,
+					runner_name.to_string(),
+				),
+				Serializable,
+			)
+			.await
+		})
+		.await
+		.expect("failed to read pool signal backlog")
+}
+
+/// Starts a mock serverless endpoint that keeps every SSE stream open without ever connecting a
+/// runner, so actors stay pending.
+async fn start_mock_serverless() -> (SocketAddr, tokio::task::JoinHandle<()>) {
+	use axum::{
+		Router,
+		body::Body,
+		http::{Response, StatusCode, header},
+		routing::get,
+	};
+
+	let router = Router::new().route(
+		"/start",
+		get(|| async {
+			Response::builder()
+				.status(StatusCode::OK)
+				.header(header::CONTENT_TYPE, "text/event-stream")
+				.header(header::CACHE_CONTROL, "no-cache")
+				.body(Body::from_stream(futures_util::stream::pending::<
+					Result<&'static [u8], std::io::Error>,
+				>()))
+				.unwrap()
+		}),
+	);
+
+	let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+	let addr = listener.local_addr().unwrap();
+
+	let handle = tokio::spawn(async move {
+		axum::serve(listener, router).await.unwrap();
+	});
+
+	(addr, handle)
+}
+
+async fn upsert_serverless_config(
+	ctx: &common::TestCtx,
+	namespace: &str,
+	runner_name: &str,
+	mock_addr: SocketAddr,
+) {
+	let guard_port = ctx.leader_dc().guard_port();
+	let response = reqwest::Client::new()
+		.put(format!(
+			"http://127.0.0.1:{guard_port}/runner-configs/{runner_name}?namespace={namespace}"
+		))
+		.json(&json!({
+			"datacenters": {
+				"dc-1": {
+					"serverless": {
+						"url": format!("http://{mock_addr}"),
+						"max_runners": 4,
+						"slots_per_runner": 1,
+						"request_lifespan": 300,
+					}
+				}
+			}
+		}))
+		.send()
+		.await
+		.unwrap();
+	assert!(
+		response.status().is_success(),
+		"failed to create runner config: {}",
+		response.text().await.unwrap()
+	);
+}
+
+async fn create_actor(
+	ctx: &common::TestCtx,
+	namespace_id: rivet_util::Id,
+	runner_name: &str,
+) -> anyhow::Result<()> {
+	ctx.leader_dc()
+		.workflow_ctx
+		.op(pegboard::ops::actor::create::Input {
+			actor_id: rivet_util::Id::new_v1(ctx.leader_dc().config.dc_label()),
+			namespace_id,
+			name: "test-actor".to_string(),
+			key: None,
+			runner_name_selector: runner_name.to_string(),
+			crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+			affinity: None,
+			required_capabilities: Vec::new(),
+			tags: Default::default(),
+			input: None,
+			forward_request: false,
+			datacenter_name: None,
+		})
+		.await?;
+
+	Ok(())
+}
+
+#[test]
+fn unrouted_selector_metric_counts_allocation_without_runner_config() {
+	common::run(
+		common::TestOpts::new(1).with_timeout(30),
+		|ctx| async move {
+			let (_namespace, namespace_id) = common::setup_test_namespace(ctx.leader_dc()).await;
+			let runner_name = format!("missing-pool-{}", rand::random::<u16>());
+			assert_eq!(unrouted_selector_count(namespace_id, &runner_name), 0);
+
+			create_actor(&ctx, namespace_id, &runner_name)
+				.await
+				.expect("failed to create actor");
+
+			common::wait_with_poll(
+				Duration::from_secs(10),
+				Duration::from_millis(50),
+				|| async {
+					(unrouted_selector_count(namespace_id, &runner_name) > 0).then_some(())
+				},
+			)
+			.await
+			.expect("allocation without a runner config was never flagged");
+
+			assert_eq!(unrouted_selector_count(namespace_id, &runner_name), 1);
+		},
+	);
+}
+
+#[test]
+fn serverless_desired_runners_metric_tracks_pending_actors() {
+	common::run(
+		common::TestOpts::new(1).with_timeout(45),
+		|ctx| async move {
+			let (namespace, namespace_id) = common::setup_test_namespace(ctx.leader_dc()).await;
+			let (mock_addr, _mock_handle) = start_mock_serverless().await;
+
+			let runner_name = format!("serverless-metrics-{}", rand::random::<u16>());
+			upsert_serverless_config(&ctx, &namespace, &runner_name, mock_addr).await;
+
+			// One slot per runner, so each pending actor needs its own runner
+			for _ in 0..2 {
+				create_actor(&ctx, namespace_id, &runner_name)
+					.await
+					.expect("failed to create actor");
+			}
+
+			common::wait_with_poll(
+				Duration::from_secs(20),
+				Duration::from_millis(100),
+				|| async {
+					(serverless_desired_runners(namespace_id, &runner_name) == 2).then_some(())
+				},
+			)
+			.await
+			.unwrap_or_else(|| {
+				panic!(
+					"serverless desired runners never reached 2, got {}",
+					serverless_desired_runners(namespace_id, &runner_name)
+				)
+			});
+
+			// Other runner names in the namespace are scaled independently
+			assert_eq!(serverless_desired_runners(namespace_id, "other-pool"), 0);
+		},
+	);
+}
+
+#[test]
+fn serverless_pool_coalesces_flooded_bumps_into_backlog() {
+	common::run(
+		common::TestOpts::new(1).with_timeout(60),
+		|ctx| async move {
+			let (namespace, namespace_id) = common::setup_test_namespace(ctx.leader_dc()).await;
+			let (mock_addr, _mock_handle) = start_mock_serverless().await;
+
+			let runner_name = format!("serverless-backlog-{}", rand::random::<u16>());
+			upsert_serverless_config(&ctx, &namespace, &runner_name, mock_addr).await;
+
+			let workflow_ctx = ctx.leader_dc().workflow_ctx.clone();
+			let pool_wf_id = common::wait_with_poll(
+				Duration::from_secs(10),
+				Duration::from_millis(50),
+				|| async {
+					workflow_ctx
+						.find_workflow::<pegboard::workflows::runner_pool::Workflow>(json!({
+							"namespace_id": namespace_id,
+							"runner_name": runner_name,
+						}))
+						.await
+						.expect("failed to find runner pool workflow")
+				},
+			)
+			.await
+			.expect("runner pool workflow was never created");
+
+			// Flood the pool with several ticks worth of bumps
+			let flood_count = (POOL_DRAIN_LIMIT * 4) as usize;
+			let flood = tokio::spawn(async move {
+				futures_util::stream::iter(0..flood_count)
+					.map(|_| {
+						workflow_ctx
+							.signal(pegboard::workflows::runner_pool::Bump::default())
+							.to_workflow_id(pool_wf_id)
+							.send()
+					})
+					.buffer_unordered(256)
+					.try_collect::<Vec<_>>()
+					.await
+			});
+
+			// While the pool is behind, publishers see the backlog key and the depth metric shows a
+			// full drain
+			common::wait_with_poll(
+				Duration::from_secs(30),
+				Duration::from_millis(10),
+				|| async {
+					(is_pool_backlogged(&ctx, namespace_id, &runner_name).await
+						&& serverless_pool_signal_backlog(namespace_id, &runner_name)
+							== POOL_DRAIN_LIMIT)
+						.then_some(())
+				},
+			)
+			.await
+			.unwrap_or_else(|| {
+				panic!(
+					"runner pool never reported a backlog, last drained {}",
+					serverless_pool_signal_backlog(namespace_id, &runner_name)
+				)
+			});
+
+			flood.await.unwrap().expect("failed to send bump signals");
+
+			// Once caught up, the backlog key is cleared
+			common::wait_with_poll(
+				Duration::from_secs(20),
+				Duration::from_millis(50),
+				|| async {
+					(!is_pool_backlogged(&ctx, namespace_id, &runner_name).await).then_some(())
+				},
+			)
+			.await
+			.expect("runner pool backlog was never cleared");
+
+			assert!(serverless_pool_signal_backlog(namespace_id, &runner_name) < POOL_DRAIN_LIMIT);
+
+			// Every tick handles its drained bumps as one, so at least a full drain's worth of bumps
+			// were coalesced
+			let coalesced = serverless_pool_bumps_coalesced(namespace_id, &runner_name);
+			assert!(
+				coalesced >= (POOL_DRAIN_LIMIT - 1) as u64,
+				"expected bumps to be coalesced, got {coalesced}"
+			);
+		},
+	);
+}
@@ -0,0 +1,86 @@
+use std::time::Duration;
+
+use rivet_runner_protocol::mk2 as rp;
+
+use super::super::common;
+
+const CHUNK_SIZE: usize = 64 * 1024;
+
+async fn send_body(
+	dc: &common::TestDatacenter,
+	actor_id: &str,
+	body: Vec<u8>,
+) -> serde_json::Value {
+	let response = reqwest::Client::new()
+		.post(format!("http://127.0.0.1:{}/body-size", dc.guard_port()))
+		.header("X-Rivet-Target", "actor")
+		.header("X-Rivet-Actor", actor_id)
+		.body(body)
+		.send()
+		.await
+		.expect("failed to send request");
+	assert!(
+		response.status().is_success(),
+		"request failed: {}",
+		response.status()
+	);
+
+	response.json().await.expect("failed to parse response")
+}
+
+fn count_body_chunks(runner: &common::TestRunner) -> usize {
+	runner.count_received(|frame| {
+		matches!(
+			frame,
+			rp::ToClient::ToClientTunnelMessage(rp::ToClientTunnelMessage {
+				message_kind: rp::ToClientTunnelMessageKind::ToClientRequestChunk(_),
+				..
+			})
+		)
+	})
+}
+
+#[test]
+fn large_request_body_is_streamed_in_chunks() {
+	common::run(
+		common::TestOpts::new(1)
+			.with_timeout(30)
+			.with_gateway_request_body_chunk_size(CHUNK_SIZE),
+		|ctx| async move {
+			let (namespace, _, runner) =
+				common::setup_test_namespace_with_runner(ctx.leader_dc()).await;
+
+			let res = common::create_actor(
+				ctx.leader_dc().guard_port(),
+				&namespace,
+				"test-actor",
+				runner.name(),
+				rivet_types::actors::CrashPolicy::Destroy,
+			)
+			.await;
+			let actor_id = res.actor.actor_id.to_string();
+
+			common::wait_with_poll(
+				Duration::from_secs(10),
+				Duration::from_millis(50),
+				|| async { runner.has_actor(&actor_id).await.then_some(()) },
+			)
+			.await
+			.expect("actor was never allocated to the runner");
+
+			// Bodies under the chunk size are still sent in the request start
+			let response = send_body(ctx.leader_dc(), &actor_id, vec![1; 1024]).await;
+			assert_eq!(response["bodySize"], 1024);
+			assert_eq!(count_body_chunks(&runner), 0);
+
+			let body_size = 4 * 1024 * 1024 + 1;
+			let response = send_body(ctx.leader_dc(), &actor_id, vec![7; body_size]).await;
+			assert_eq!(response["bodySize"], body_size);
+			assert_eq!(
+				count_body_chunks(&runner),
+				body_size.div_ceil(CHUNK_SIZE),
+				"body should arrive as chunks of the configured size"
+			);
+		},
+	);
+}
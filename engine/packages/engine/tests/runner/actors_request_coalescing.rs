@@ -0,0 +1,212 @@
+use std::sync::{
+	Arc,
+	atomic::{AtomicU32, Ordering},
+};
+
+use super::super::common;
+
+#[test]
+fn concurrent_identical_gets_share_one_actor_request() {
+	common::run(
+		common::TestOpts::new(1)
+			.with_timeout(30)
+			.with_request_coalescing("slow-actor"),
+		|ctx| async move {
+			let (namespace, _) = common::setup_test_namespace(ctx.leader_dc()).await;
+
+			let request_count = Arc::new(AtomicU32::new(0));
+			let request_count_clone = request_count.clone();
+
+			let runner = common::setup_runner(ctx.leader_dc(), &namespace, |builder| {
+				builder.with_actor_behavior("slow-actor", move |_| {
+					Box::new(common::test_runner::SlowRequestActor::new(
+						std::time::Duration::from_secs(1),
+						request_count_clone.clone(),
+					))
+				})
+			})
+			.await;
+			runner.wait_ready().await;
+
+			let res = common::create_actor(
+				ctx.leader_dc().guard_port(),
+				&namespace,
+				"slow-actor",
+				runner.name(),
+				rivet_types::actors::CrashPolicy::Destroy,
+			)
+			.await;
+			let actor_id = res.actor.actor_id.to_string();
+
+			common::wait_with_poll(
+				std::time::Duration::from_secs(10),
+				std::time::Duration::from_millis(50),
+				|| async { runner.has_actor(&actor_id).await.then_some(()) },
+			)
+			.await
+			.expect("actor was never allocated to the runner");
+
+			// The actor holds the first request for a second, so every other request arrives
+			// while it is still in flight
+			let responses = futures_util::future::join_all(
+				(0..100).map(|_| common::ping_actor_via_guard(ctx.leader_dc(), &actor_id)),
+			)
+			.await;
+
+			assert_eq!(
+				request_count.load(Ordering::SeqCst),
+				1,
+				"actor should see a single request"
+			);
+			assert_eq!(responses.len(), 100);
+			for response in &responses {
+				assert_eq!(response["actorId"], actor_id);
+				assert_eq!(
+					response, &responses[0],
+					"every client should receive the shared response"
+				);
+			}
+		},
+	);
+}
+
+#[test]
+fn gets_with_different_credentials_are_not_coalesced() {
+	common::run(
+		common::TestOpts::new(1)
+			.with_timeout(30)
+			.with_request_coalescing("slow-actor"),
+		|ctx| async move {
+			let (namespace, _) = common::setup_test_namespace(ctx.leader_dc()).await;
+
+			let request_count = Arc::new(AtomicU32::new(0));
+			let request_count_clone = request_count.clone();
+
+			let runner = common::setup_runner(ctx.leader_dc(), &namespace, |builder| {
+				builder.with_actor_behavior("slow-actor", move |_| {
+					Box::new(common::test_runner::SlowRequestActor::new(
+						std::time::Duration::from_secs(1),
+						request_count_clone.clone(),
+					))
+				})
+			})
+			.await;
+			runner.wait_ready().await;
+
+			let res = common::create_actor(
+				ctx.leader_dc().guard_port(),
+				&namespace,
+				"slow-actor",
+				runner.name(),
+				rivet_types::actors::CrashPolicy::Destroy,
+			)
+			.await;
+			let actor_id = res.actor.actor_id.to_string();
+
+			common::wait_with_poll(
+				std::time::Duration::from_secs(10),
+				std::time::Duration::from_millis(50),
+				|| async { runner.has_actor(&actor_id).await.then_some(()) },
+			)
+			.await
+			.expect("actor was never allocated to the runner");
+
+			let guard_port = ctx.leader_dc().guard_port();
+			let client = reqwest::Client::new();
+			let responses =
+				futures_util::future::join_all(["session=a", "session=b"].map(|cookie| {
+					client
+						.get(format!("http://127.0.0.1:{guard_port}/ping"))
+						.header("X-Rivet-Target", "actor")
+						.header("X-Rivet-Actor", &actor_id)
+						.header(reqwest::header::COOKIE, cookie)
+						.send()
+				}))
+				.await;
+
+			for response in responses {
+				assert!(
+					response
+						.expect("failed to send request")
+						.status()
+						.is_success()
+				);
+			}
+			assert_eq!(
+				request_count.load(Ordering::SeqCst),
+				2,
+				"requests from different clients should not share a response"
+			);
+		},
+	);
+}
+
+#[test]
+fn gets_with_different_accept_encoding_are_not_coalesced() {
+	common::run(
+		common::TestOpts::new(1)
+			.with_timeout(30)
+			.with_request_coalescing("slow-actor"),
+		|ctx| async move {
+			let (namespace, _) = common::setup_test_namespace(ctx.leader_dc()).await;
+
+			let request_count = Arc::new(AtomicU32::new(0));
+			let request_count_clone = request_count.clone();
+
+			let runner = common::setup_runner(ctx.leader_dc(), &namespace, |builder| {
+				builder.with_actor_behavior("slow-actor", move |_| {
+					Box::new(common::test_runner::SlowRequestActor::new(
+						std::time::Duration::from_secs(1),
+						request_count_clone.clone(),
+					))
+				})
+			})
+			.await;
+			runner.wait_ready().await;
+
+			let res = common::create_actor(
+				ctx.leader_dc().guard_port(),
+				&namespace,
+				"slow-actor",
+				runner.name(),
+				rivet_types::actors::CrashPolicy::Destroy,
+			)
+			.await;
+			let actor_id = res.actor.actor_id.to_string();
+
+			common::wait_with_poll(
+				std::time::Duration::from_secs(10),
+				std::time::Duration::from_millis(50),
+				|| async { runner.has_actor(&actor_id).await.then_some(()) },
+			)
+			.await
+			.expect("actor was never allocated to the runner");
+
+			let guard_port = ctx.leader_dc().guard_port();
+			let client = reqwest::Client::new();
+			let responses = futures_util::future::join_all(["gzip", "br"].map(|encoding| {
+				client
+					.get(format!("http://127.0.0.1:{guard_port}/ping"))
+					.header("X-Rivet-Target", "actor")
+					.header("X-Rivet-Actor", &actor_id)
+					.header(reqwest::header::ACCEPT_ENCODING, encoding)
+					.send()
+			}))
+			.await;
+
+			for response in responses {
+				assert!(
+					response
+						.expect("failed to send request")
+						.status()
+						.is_success()
+				);
+			}
+			assert_eq!(
+				request_count.load(Ordering::SeqCst),
+				2,
+				"requests for different encodings should not share a response"
+			);
+		},
+	);
+}
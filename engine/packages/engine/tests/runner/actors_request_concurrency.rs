@@ -0,0 +1,142 @@
+use std::{
+	sync::{
+		Arc,
+		atomic::{AtomicU32, Ordering},
+	},
+	time::{Duration, Instant},
+};
+
+use super::super::common;
+use common::test_runner::{ActorLifecycleEvent, RequestLimitPolicy};
+
+/// Starts a slow actor limited to 2 concurrent requests and returns its runner and id.
+async fn setup_limited_actor(
+	dc: &common::TestDatacenter,
+	policy: RequestLimitPolicy,
+) -> (common::TestRunner, String) {
+	let (namespace, _) = common::setup_test_namespace(dc).await;
+
+	let runner = common::setup_runner(dc, &namespace, |builder| {
+		builder
+			.with_actor_behavior("slow-actor", |_| {
+				Box::new(common::test_runner::SlowRequestActor::new(
+					Duration::from_secs(1),
+					Arc::new(AtomicU32::new(0)),
+				))
+			})
+			.with_max_concurrent_requests("slow-actor", 2, policy)
+	})
+	.await;
+
+	let res = common::create_actor(
+		dc.guard_port(),
+		&namespace,
+		"slow-actor",
+		runner.name(),
+		rivet_types::actors::CrashPolicy::Destroy,
+	)
+	.await;
+	let actor_id = res.actor.actor_id.to_string();
+
+	common::wait_with_poll(
+		Duration::from_secs(10),
+		Duration::from_millis(50),
+		|| async { runner.has_actor(&actor_id).await.then_some(()) },
+	)
+	.await
+	.expect("actor was never allocated to the runner");
+
+	(runner, actor_id)
+}
+
+fn limited_requests(
+	lifecycle_rx: &mut tokio::sync::broadcast::Receiver<ActorLifecycleEvent>,
+	actor_id: &str,
+) -> Vec<bool> {
+	let mut limited = Vec::new();
+	while let Ok(event) = lifecycle_rx.try_recv() {
+		if let ActorLifecycleEvent::RequestLimited {
+			actor_id: id,
+			rejected,
+		} = event && id == actor_id
+		{
+			limited.push(rejected);
+		}
+	}
+	limited
+}
+
+#[test]
+fn requests_over_limit_are_queued() {
+	common::run(
+		common::TestOpts::new(1).with_timeout(30),
+		|ctx| async move {
+			let (runner, actor_id) =
+				setup_limited_actor(ctx.leader_dc(), RequestLimitPolicy::Queue).await;
+			let mut lifecycle_rx = runner.subscribe_lifecycle_events();
+
+			let start = Instant::now();
+			let responses = futures_util::future::join_all(
+				(0..5).map(|_| common::ping_actor_via_guard(ctx.leader_dc(), &actor_id)),
+			)
+			.await;
+			let elapsed = start.elapsed();
+
+			for response in &responses {
+				assert_eq!(response["actorId"], actor_id);
+			}
+
+			// 5 requests at 2 at a time take three rounds of the actor's 1s delay
+			assert!(
+				elapsed >= Duration::from_millis(2900),
+				"requests should not all run concurrently, took {elapsed:?}"
+			);
+			assert_eq!(
+				limited_requests(&mut lifecycle_rx, &actor_id),
+				vec![false; 3],
+				"requests over the limit should be queued"
+			);
+		},
+	);
+}
+
+#[test]
+fn requests_over_limit_are_rejected() {
+	common::run(
+		common::TestOpts::new(1).with_timeout(30),
+		|ctx| async move {
+			let (runner, actor_id) =
+				setup_limited_actor(ctx.leader_dc(), RequestLimitPolicy::Reject).await;
+			let mut lifecycle_rx = runner.subscribe_lifecycle_events();
+
+			let client = reqwest::Client::new();
+			let guard_port = ctx.leader_dc().guard_port();
+			let statuses = futures_util::future::join_all((0..5).map(|_| async {
+				client
+					.get(format!("http://127.0.0.1:{guard_port}/ping"))
+					.header("X-Rivet-Target", "actor")
+					.header("X-Rivet-Actor", &actor_id)
+					.send()
+					.await
+					.expect("failed to send request")
+					.status()
+			}))
+			.await;
+
+			let ok = statuses
+				.iter()
+				.filter(|status| **status == reqwest::StatusCode::OK)
+				.count();
+			let rejected = statuses
+				.iter()
+				.filter(|status| **status == reqwest::StatusCode::TOO_MANY_REQUESTS)
+				.count();
+			assert_eq!(ok, 2, "only 2 requests should run, got {statuses:?}");
+			assert_eq!(rejected, 3, "the rest should be rejected, got {statuses:?}");
+			assert_eq!(
+				limited_requests(&mut lifecycle_rx, &actor_id),
+				vec![true; 3]
+			);
+		},
+	);
+}
@@ -0,0 +1,125 @@
+use std::{
+	sync::{
+		Arc,
+		atomic::{AtomicU32, Ordering},
+	},
+	time::Duration,
+};
+
+use super::super::common;
+
+/// Starts an actor that sleeps instead of answering its first request. Returns the runner, actor id
+/// and the actor's request count.
+async fn setup_sleep_on_first_request_actor(
+	dc: &common::TestDatacenter,
+) -> (common::TestRunner, String, Arc<AtomicU32>) {
+	let (namespace, _) = common::setup_test_namespace(dc).await;
+
+	let request_count = Arc::new(AtomicU32::new(0));
+	let request_count_clone = request_count.clone();
+	let runner = common::setup_runner(dc, &namespace, |builder| {
+		builder.with_actor_behavior("sleepy-actor", move |_| {
+			Box::new(common::test_runner::SleepOnFirstRequestActor::new(
+				request_count_clone.clone(),
+			))
+		})
+	})
+	.await;
+
+	let res = common::create_actor(
+		dc.guard_port(),
+		&namespace,
+		"sleepy-actor",
+		runner.name(),
+		rivet_types::actors::CrashPolicy::Destroy,
+	)
+	.await;
+	let actor_id = res.actor.actor_id.to_string();
+
+	common::wait_with_poll(
+		Duration::from_secs(10),
+		Duration::from_millis(50),
+		|| async { runner.has_actor(&actor_id).await.then_some(()) },
+	)
+	.await
+	.expect("actor was never allocated to the runner");
+
+	(runner, actor_id, request_count)
+}
+
+fn actor_request(
+	dc: &common::TestDatacenter,
+	method: reqwest::Method,
+	actor_id: &str,
+) -> reqwest::RequestBuilder {
+	reqwest::Client::new()
+		.request(method, format!("http://127.0.0.1:{}/ping", dc.guard_port()))
+		.header("X-Rivet-Target", "actor")
+		.header("X-Rivet-Actor", actor_id)
+}
+
+#[test]
+fn idempotent_request_is_replayed_after_actor_stops() {
+	common::run(
+		common::TestOpts::new(1)
+			.with_timeout(30)
+			.with_request_replay(2),
+		|ctx| async move {
+			let (_runner, actor_id, request_count) =
+				setup_sleep_on_first_request_actor(ctx.leader_dc()).await;
+
+			let response = actor_request(ctx.leader_dc(), reqwest::Method::GET, &actor_id)
+				.send()
+				.await
+				.expect("failed to send request");
+			assert!(
+				response.status().is_success(),
+				"replayed request failed: {}",
+				response.status()
+			);
+			assert_eq!(
+				request_count.load(Ordering::SeqCst),
+				2,
+				"request should reach the woken actor a second time"
+			);
+		},
+	);
+}
+
+#[test]
+fn non_idempotent_request_is_not_replayed_after_actor_stops() {
+	common::run(
+		common::TestOpts::new(1)
+			.with_timeout(30)
+			.with_request_replay(2),
+		|ctx| async move {
+			let (_runner, actor_id, request_count) =
+				setup_sleep_on_first_request_actor(ctx.leader_dc()).await;
+
+			let response = actor_request(ctx.leader_dc(), reqwest::Method::POST, &actor_id)
+				.send()
+				.await
+				.expect("failed to send request");
+			assert!(
+				!response.status().is_success(),
+				"request should fail instead of being replayed"
+			);
+			assert_eq!(request_count.load(Ordering::SeqCst), 1);
+
+			// Requests with an idempotency key opt in to replay
+			let (_runner, actor_id, request_count) =
+				setup_sleep_on_first_request_actor(ctx.leader_dc()).await;
+			let response = actor_request(ctx.leader_dc(), reqwest::Method::POST, &actor_id)
+				.header("Idempotency-Key", "test-key")
+				.send()
+				.await
+				.expect("failed to send request");
+			assert!(
+				response.status().is_success(),
+				"replayed request failed: {}",
+				response.status()
+			);
+			assert_eq!(request_count.load(Ordering::SeqCst), 2);
+		},
+	);
+}
@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+
+use super::super::common;
+
+const ACTOR_COUNT: usize = 100;
+
+#[test]
+fn runner_lost_reschedules_are_paced() {
+	common::run(
+		common::TestOpts::new(1).with_timeout(90),
+		|ctx| async move {
+			let (namespace, _) = common::setup_test_namespace(ctx.leader_dc()).await;
+			let pb = ctx.leader_dc().config.pegboard();
+
+			let runner_a = common::setup_runner(ctx.leader_dc(), &namespace, |builder| {
+				builder
+					.with_total_slots(ACTOR_COUNT as u32)
+					.with_actor_behavior("test-actor", |_| {
+						Box::new(common::test_runner::EchoActor::new())
+					})
+			})
+			.await;
+
+			let mut actor_ids = Vec::with_capacity(ACTOR_COUNT);
+			for _ in 0..ACTOR_COUNT {
+				let res = common::create_actor(
+					ctx.leader_dc().guard_port(),
+					&namespace,
+					"test-actor",
+					runner_a.name(),
+					rivet_types::actors::CrashPolicy::Restart,
+				)
+				.await;
+				actor_ids.push(res.actor.actor_id.to_string());
+			}
+
+			common::wait_with_poll(
+				std::time::Duration::from_secs(30),
+				std::time::Duration::from_millis(100),
+				|| async { (runner_a.get_actor_ids().await.len() == ACTOR_COUNT).then_some(()) },
+			)
+			.await
+			.expect("actors were never allocated to the first runner");
+
+			// Second runner in the same pool to take over the actors
+			let runner_b = common::test_runner::TestRunnerBuilder::new(&namespace)
+				.with_runner_name(runner_a.name())
+				.with_runner_key(&format!("key-{:012x}", rand::random::<u64>()))
+				.with_version(1)
+				.with_total_slots(ACTOR_COUNT as u32)
+				.with_actor_behavior("test-actor", |_| {
+					Box::new(common::test_runner::EchoActor::new())
+				})
+				.build(ctx.leader_dc())
+				.await
+				.expect("failed to build test runner");
+			runner_b.start().await.expect("failed to start runner");
+			runner_b.wait_ready().await;
+
+			// Lose the first runner, rescheduling all of its actors at once
+			runner_a.crash().await;
+
+			// Record when each actor lands on the second runner
+			let mut allocated_at = HashMap::new();
+			let deadline = std::time::Instant::now()
+				+ std::time::Duration::from_millis(
+					(pb.runner_lost_threshold() + pb.runner_reconnect_grace_period())
+						.try_into()
+						.unwrap(),
+				) + std::time::Duration::from_secs(30);
+			while allocated_at.len() < ACTOR_COUNT {
+				assert!(
+					std::time::Instant::now() < deadline,
+					"only {} of {ACTOR_COUNT} actors were rescheduled",
+					allocated_at.len()
+				);
+
+				let now = std::time::Instant::now();
+				for actor_id in runner_b.get_actor_ids().await {
+					allocated_at.entry(actor_id).or_insert(now);
+				}
+
+				tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+			}
+
+			assert!(
+				actor_ids.iter().all(|id| allocated_at.contains_key(id)),
+				"all actors should be rescheduled to the second runner"
+			);
+
+			let first = allocated_at.values().min().unwrap();
+			let last = allocated_at.values().max().unwrap();
+			let spread = last.duration_since(*first);
+
+			// Reschedules are spaced by the configured rate. Allow some slack for timer resolution.
+			let expected = std::time::Duration::from_millis(
+				(ACTOR_COUNT as u64 - 1) * 1000 / u64::from(pb.actor_reschedule_rate()),
+			);
+			assert!(
+				spread >= expected * 3 / 4,
+				"reschedules should be paced over at least {expected:?}, took {spread:?}"
+			);
+		},
+	);
+}
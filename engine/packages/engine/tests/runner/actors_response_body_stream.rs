@@ -0,0 +1,112 @@
+use std::time::{Duration, Instant};
+
+use super::super::common;
+
+/// Creates an actor on a fresh runner and returns the runner and actor id.
+async fn setup_actor(dc: &common::TestDatacenter) -> (common::TestRunner, String) {
+	let (namespace, _, runner) = common::setup_test_namespace_with_runner(dc).await;
+
+	let res = common::create_actor(
+		dc.guard_port(),
+		&namespace,
+		"test-actor",
+		runner.name(),
+		rivet_types::actors::CrashPolicy::Destroy,
+	)
+	.await;
+	let actor_id = res.actor.actor_id.to_string();
+
+	common::wait_with_poll(
+		Duration::from_secs(10),
+		Duration::from_millis(50),
+		|| async { runner.has_actor(&actor_id).await.then_some(()) },
+	)
+	.await
+	.expect("actor was never allocated to the runner");
+
+	(runner, actor_id)
+}
+
+async fn get_actor_path(
+	dc: &common::TestDatacenter,
+	actor_id: &str,
+	path: &str,
+) -> reqwest::Response {
+	let response = reqwest::Client::new()
+		.get(format!("http://127.0.0.1:{}{path}", dc.guard_port()))
+		.header("X-Rivet-Target", "actor")
+		.header("X-Rivet-Actor", actor_id)
+		.send()
+		.await
+		.expect("failed to send request");
+	assert!(
+		response.status().is_success(),
+		"request failed: {}",
+		response.status()
+	);
+
+	response
+}
+
+#[test]
+fn streamed_response_is_forwarded_as_chunks_arrive() {
+	common::run(
+		common::TestOpts::new(1).with_timeout(30),
+		|ctx| async move {
+			let (_runner, actor_id) = setup_actor(ctx.leader_dc()).await;
+
+			let mut response = get_actor_path(ctx.leader_dc(), &actor_id, "/stream").await;
+
+			let mut body = Vec::new();
+			let mut first_chunk_at = None;
+			while let Some(chunk) = response.chunk().await.expect("failed to read body") {
+				first_chunk_at.get_or_insert_with(Instant::now);
+				body.extend_from_slice(&chunk);
+			}
+
+			assert_eq!(body, b"chunk-0\nchunk-1\nchunk-2\n");
+
+			// The runner sends a chunk every 500ms, so a buffered body would arrive all at once
+			let first_chunk_at = first_chunk_at.expect("no body chunks received");
+			assert!(
+				first_chunk_at.elapsed() >= Duration::from_millis(800),
+				"body was not streamed, last chunk arrived {:?} after the first",
+				first_chunk_at.elapsed()
+			);
+		},
+	);
+}
+
+#[test]
+fn stalled_response_stream_is_aborted_after_idle_timeout() {
+	common::run(
+		common::TestOpts::new(1)
+			.with_timeout(30)
+			.with_gateway_response_body_idle_timeout_ms(1_000),
+		|ctx| async move {
+			let (_runner, actor_id) = setup_actor(ctx.leader_dc()).await;
+
+			let mut response = get_actor_path(ctx.leader_dc(), &actor_id, "/stream-stall").await;
+
+			let chunk = response
+				.chunk()
+				.await
+				.expect("failed to read first chunk")
+				.expect("body ended before first chunk");
+			assert_eq!(&chunk[..], b"chunk-0\n");
+
+			// The runner stalls for 5s, well past the idle timeout
+			let start = Instant::now();
+			let res = response.chunk().await;
+			assert!(
+				res.is_err(),
+				"stalled body should fail instead of ending cleanly, got {res:?}"
+			);
+			assert!(
+				start.elapsed() < Duration::from_secs(4),
+				"body was not aborted by the idle timeout, took {:?}",
+				start.elapsed()
+			);
+		},
+	);
+}
@@ -209,7 +209,11 @@ async fn get_actor(
 			namespace: namespace.to_string(),
 			name: None,
 			key: None,
+			tag: Vec::new(),
 			include_destroyed: Some(true),
+			state: None,
+			created_before: None,
+			created_after: None,
 			limit: None,
 			cursor: None,
 		},
@@ -254,6 +258,43 @@ fn no_runners_available_error() {
 	});
 }
 
+#[test]
+fn pending_reason_no_runner_matches_selector() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let guard_port = ctx.leader_dc().guard_port();
+		let (namespace, _) = common::setup_test_namespace(ctx.leader_dc()).await;
+
+		// Configure a normal pool but never connect a runner to it
+		let runner_name = format!("no-runners-{}", rand::random::<u16>());
+		common::upsert_normal_runner_config(ctx.leader_dc(), &namespace, &runner_name).await;
+
+		let actor_id = create_actor_with_policy(guard_port, &namespace, &runner_name, "destroy")
+			.await
+			.expect("actor creation should succeed");
+
+		let start = std::time::Instant::now();
+		let pending_reason = loop {
+			let actor = get_actor(guard_port, &namespace, &actor_id)
+				.await
+				.expect("actor should exist");
+			if let Some(pending_reason) = actor.pending_reason {
+				break pending_reason;
+			}
+
+			if start.elapsed() > Duration::from_secs(10) {
+				panic!("actor never reported a pending reason");
+			}
+			tokio::time::sleep(Duration::from_millis(200)).await;
+		};
+
+		assert_eq!(
+			pending_reason,
+			rivet_types::actors::PendingReason::NoRunnerMatchesSelector
+		);
+		assert_eq!(pending_reason.to_string(), "no runner matches selector");
+	});
+}
+
 #[test]
 fn serverless_http_404_error() {
 	common::run(
@@ -306,6 +347,11 @@ fn serverless_http_404_error() {
 				}
 				other => panic!("expected ServerlessHttpError, got: {:?}", other),
 			}
+
+			assert_eq!(
+				get_runner_config_pool_error_category(guard_port, &namespace, &runner_name).await,
+				Some(rivet_types::actor::RunnerPoolErrorCategory::ClientError),
+			);
 		},
 	);
 }
@@ -451,6 +497,11 @@ fn serverless_stream_ended_then_http_error() {
 				}
 				other => panic!("expected ServerlessHttpError, got: {:?}", other),
 			}
+
+			assert_eq!(
+				get_runner_config_pool_error_category(guard_port, &namespace, &runner_name).await,
+				Some(rivet_types::actor::RunnerPoolErrorCategory::ServerError),
+			);
 		},
 	);
 }
@@ -461,6 +512,34 @@ async fn get_runner_config_pool_error(
 	namespace: &str,
 	runner_name: &str,
 ) -> Option<rivet_types::actor::RunnerPoolError> {
+	let dc_config = get_runner_config_dc(guard_port, namespace, runner_name).await?;
+
+	// Parse pool_error if present
+	// Handle both string format (for errors without fields) and object format (for errors with fields)
+	dc_config
+		.get("runner_pool_error")
+		.map(|pool_error| serde_json::from_value(pool_error.clone()).unwrap())
+}
+
+/// Fetches runner config from the API and returns the pool error's category if present.
+async fn get_runner_config_pool_error_category(
+	guard_port: u16,
+	namespace: &str,
+	runner_name: &str,
+) -> Option<rivet_types::actor::RunnerPoolErrorCategory> {
+	let dc_config = get_runner_config_dc(guard_port, namespace, runner_name).await?;
+
+	dc_config
+		.get("runner_pool_error_category")
+		.map(|category| serde_json::from_value(category.clone()).unwrap())
+}
+
+/// Fetches the dc-1 runner config from the API.
+async fn get_runner_config_dc(
+	guard_port: u16,
+	namespace: &str,
+	runner_name: &str,
+) -> Option<serde_json::Map<String, serde_json::Value>> {
 	let client = reqwest::Client::new();
 	let response = client
 		.get(format!(
@@ -483,13 +562,7 @@ async fn get_runner_config_pool_error(
 	let config = runner_configs.get(runner_name)?;
 
 	// The public API returns datacenters nested under each runner config
-	let dc_config = config["datacenters"]["dc-1"].as_object()?;
-
-	// Parse pool_error if present
-	// Handle both string format (for errors without fields) and object format (for errors with fields)
-	dc_config.get("runner_pool_error").map(|_| {
-		serde_json::from_value(config["datacenters"]["dc-1"]["runner_pool_error"].clone()).unwrap()
-	})
+	config["datacenters"]["dc-1"].as_object().cloned()
 }
 
 /// Tests that both the runner configs API and actor API return pool errors for serverless configs.
@@ -1024,3 +1097,101 @@ async fn start_mock_serverless_invalid_payload() -> (SocketAddr, tokio::task::Jo
 
 	(addr, handle)
 }
+
+/// Tests that a serverless connection which opens the SSE stream but never sends init is closed
+/// and retried once the init timeout elapses.
+#[test]
+fn serverless_init_timeout_error() {
+	common::run(
+		common::TestOpts::new(1).with_timeout(45),
+		|ctx| async move {
+			let guard_port = ctx.leader_dc().guard_port();
+			let (namespace, _) = common::setup_test_namespace(ctx.leader_dc()).await;
+
+			// Start mock that accepts the SSE stream but never sends anything
+			let (mock_addr, _mock_handle, connection_count) =
+				start_mock_serverless_never_init().await;
+			let serverless_url = format!("http://{}", mock_addr);
+
+			let runner_name = format!("serverless-inittimeout-{}", rand::random::<u16>());
+			create_serverless_runner_config(guard_port, &namespace, &runner_name, &serverless_url)
+				.await;
+
+			// Create actor to trigger serverless connection attempt
+			let _actor_id = create_actor(guard_port, &namespace, &runner_name)
+				.await
+				.expect("actor creation should succeed");
+
+			// Test config sets the init timeout to 5s, wait for the first connection to be reaped
+			// and retried
+			let start = std::time::Instant::now();
+			while connection_count.load(Ordering::SeqCst) < 2 {
+				if start.elapsed() > Duration::from_secs(20) {
+					panic!(
+						"expected connection to be retried after init timeout, got {} connections",
+						connection_count.load(Ordering::SeqCst)
+					);
+				}
+				tokio::time::sleep(Duration::from_millis(200)).await;
+			}
+
+			let pool_error = get_runner_config_pool_error(guard_port, &namespace, &runner_name)
+				.await
+				.expect("pool should have error after init timeout");
+
+			assert_eq!(
+				pool_error.category(),
+				rivet_types::actor::RunnerPoolErrorCategory::Init
+			);
+			match pool_error {
+				rivet_types::actor::RunnerPoolError::ServerlessInitTimeout { timeout_ms } => {
+					assert_eq!(timeout_ms, 5_000);
+				}
+				other => panic!("expected ServerlessInitTimeout, got: {:?}", other),
+			}
+		},
+	);
+}
+
+/// Starts a mock serverless that returns an SSE stream which stays open but never sends an init
+/// message. Returns the address, handle, and a counter of how many connections have been made.
+async fn start_mock_serverless_never_init()
+-> (SocketAddr, tokio::task::JoinHandle<()>, Arc<AtomicU32>) {
+	use axum::{
+		Router,
+		body::Body,
+		http::{Response, StatusCode, header},
+		routing::get,
+	};
+
+	let connection_count = Arc::new(AtomicU32::new(0));
+	let connection_count_clone = connection_count.clone();
+
+	let router = Router::new().route(
+		"/start",
+		get(move || {
+			let connection_count = connection_count_clone.clone();
+			async move {
+				connection_count.fetch_add(1, Ordering::SeqCst);
+
+				Response::builder()
+					.status(StatusCode::OK)
+					.header(header::CONTENT_TYPE, "text/event-stream")
+					.header(header::CACHE_CONTROL, "no-cache")
+					.body(Body::from_stream(futures_util::stream::pending::<
+						Result<&'static [u8], std::io::Error>,
+					>()))
+					.unwrap()
+			}
+		}),
+	);
+
+	let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+	let addr = listener.local_addr().unwrap();
+
+	let handle = tokio::spawn(async move {
+		axum::serve(listener, router).await.unwrap();
+	});
+
+	(addr, handle, connection_count)
+}
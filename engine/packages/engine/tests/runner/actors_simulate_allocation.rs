@@ -0,0 +1,83 @@
+use super::super::common;
+
+#[test]
+fn simulate_allocation_reports_remaining_capacity() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let (namespace, namespace_id, runner) =
+			common::setup_test_namespace_with_runner(ctx.leader_dc()).await;
+		let runner_id: rivet_util::Id = runner
+			.wait_ready()
+			.await
+			.parse()
+			.expect("invalid runner id");
+
+		// Fill the runner's 20 slots up to 15
+		for _ in 0..15 {
+			common::create_actor(
+				ctx.leader_dc().guard_port(),
+				&namespace,
+				"test-actor",
+				runner.name(),
+				rivet_types::actors::CrashPolicy::Destroy,
+			)
+			.await;
+		}
+
+		common::wait_with_poll(
+			std::time::Duration::from_secs(10),
+			std::time::Duration::from_millis(50),
+			|| async { (runner.get_actor_ids().await.len() == 15).then_some(()) },
+		)
+		.await
+		.expect("actors were never allocated");
+
+		let simulate = || async {
+			ctx.leader_dc()
+				.workflow_ctx
+				.op(pegboard::ops::actor::simulate_allocation::Input {
+					namespace_id,
+					runner_name_selector: runner.name().to_string(),
+					count: 50,
+					required_capabilities: Vec::new(),
+				})
+				.await
+				.expect("failed to simulate allocation")
+		};
+
+		let res = simulate().await;
+		assert_eq!(res.allocated, 5);
+		assert_eq!(res.pending, 45);
+		assert_eq!(res.runners.len(), 1);
+		assert_eq!(res.runners[0].runner_id, runner_id);
+		assert_eq!(res.runners[0].actors, 5);
+		assert_eq!(res.runners[0].remaining_slots, 0);
+
+		// Simulating does not consume any capacity
+		let res = simulate().await;
+		assert_eq!(res.allocated, 5);
+		assert_eq!(runner.get_actor_ids().await.len(), 15);
+	});
+}
+
+#[test]
+fn simulate_allocation_without_runners() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let (_, namespace_id) = common::setup_test_namespace(ctx.leader_dc()).await;
+
+		let res = ctx
+			.leader_dc()
+			.workflow_ctx
+			.op(pegboard::ops::actor::simulate_allocation::Input {
+				namespace_id,
+				runner_name_selector: "missing-runner".to_string(),
+				count: 10,
+				required_capabilities: Vec::new(),
+			})
+			.await
+			.expect("failed to simulate allocation");
+
+		assert_eq!(res.allocated, 0);
+		assert_eq!(res.pending, 10);
+		assert!(res.runners.is_empty());
+	});
+}
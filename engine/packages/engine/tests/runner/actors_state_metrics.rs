@@ -0,0 +1,102 @@
+use super::super::common;
+
+fn actor_state_gauge(namespace_id: rivet_util::Id, state: &str) -> i64 {
+	pegboard::metrics::ACTOR_STATE
+		.with_label_values(&[&namespace_id.to_string(), state])
+		.get()
+}
+
+#[test]
+fn actor_state_metrics_aggregate_per_namespace() {
+	common::run(
+		common::TestOpts::new(1).with_timeout(90),
+		|ctx| async move {
+			let (namespace_a, namespace_id_a, runner_a) =
+				common::setup_test_namespace_with_runner(ctx.leader_dc()).await;
+			let (namespace_b, namespace_id_b) = common::setup_test_namespace(ctx.leader_dc()).await;
+			let runner_b = common::setup_runner(ctx.leader_dc(), &namespace_b, |builder| {
+				builder
+					.with_actor_behavior("test-actor", |_| {
+						Box::new(common::test_runner::EchoActor::new())
+					})
+					.with_actor_behavior("sleep-actor", |_| {
+						Box::new(common::test_runner::SleepImmediatelyActor::new())
+					})
+			})
+			.await;
+
+			// Two running actors in the first namespace
+			for _ in 0..2 {
+				common::create_actor(
+					ctx.leader_dc().guard_port(),
+					&namespace_a,
+					"test-actor",
+					runner_a.name(),
+					rivet_types::actors::CrashPolicy::Destroy,
+				)
+				.await;
+			}
+
+			// One running and one sleeping actor in the second namespace
+			common::create_actor(
+				ctx.leader_dc().guard_port(),
+				&namespace_b,
+				"test-actor",
+				runner_b.name(),
+				rivet_types::actors::CrashPolicy::Destroy,
+			)
+			.await;
+			let sleeping = common::create_actor(
+				ctx.leader_dc().guard_port(),
+				&namespace_b,
+				"sleep-actor",
+				runner_b.name(),
+				rivet_types::actors::CrashPolicy::Destroy,
+			)
+			.await;
+			let sleeping_id = sleeping.actor.actor_id.to_string();
+
+			common::wait_with_poll(
+				std::time::Duration::from_secs(10),
+				std::time::Duration::from_millis(50),
+				|| async {
+					let actor = common::try_get_actor(
+						ctx.leader_dc().guard_port(),
+						&sleeping_id,
+						&namespace_b,
+					)
+					.await
+					.expect("failed to get actor")
+					.expect("actor should exist");
+					actor.sleep_ts.is_some().then_some(())
+				},
+			)
+			.await
+			.expect("actor never went to sleep");
+
+			// The aggregator runs on an interval, wait for a tick that saw every actor
+			common::wait_with_poll(
+				std::time::Duration::from_secs(45),
+				std::time::Duration::from_millis(250),
+				|| async {
+					(actor_state_gauge(namespace_id_a, "running") == 2
+						&& actor_state_gauge(namespace_id_b, "running") == 1
+						&& actor_state_gauge(namespace_id_b, "sleeping") == 1)
+						.then_some(())
+				},
+			)
+			.await
+			.unwrap_or_else(|| {
+				panic!(
+					"actor state gauges never matched, got a running={} b running={} b sleeping={}",
+					actor_state_gauge(namespace_id_a, "running"),
+					actor_state_gauge(namespace_id_b, "running"),
+					actor_state_gauge(namespace_id_b, "sleeping"),
+				)
+			});
+
+			assert_eq!(actor_state_gauge(namespace_id_a, "sleeping"), 0);
+			assert_eq!(actor_state_gauge(namespace_id_a, "stopping"), 0);
+		},
+	);
+}
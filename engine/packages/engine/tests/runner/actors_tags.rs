@@ -0,0 +1,313 @@
+use std::collections::HashMap;
+
+use super::super::common;
+
+async fn create_actor_with_tags(
+	ctx: &common::TestCtx,
+	namespace_id: rivet_util::Id,
+	runner_name: &str,
+	tags: &[(&str, &str)],
+) -> anyhow::Result<rivet_util::Id> {
+	let actor_id = rivet_util::Id::new_v1(ctx.leader_dc().config.dc_label());
+	ctx.leader_dc()
+		.workflow_ctx
+		.op(pegboard::ops::actor::create::Input {
+			actor_id,
+			namespace_id,
+			name: "test-actor".to_string(),
+			key: None,
+			runner_name_selector: runner_name.to_string(),
+			crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+			affinity: None,
+			required_capabilities: Vec::new(),
+			tags: tags
+				.iter()
+				.map(|(k, v)| (k.to_string(), v.to_string()))
+				.collect(),
+			input: None,
+			forward_request: false,
+			datacenter_name: None,
+		})
+		.await?;
+
+	Ok(actor_id)
+}
+
+async fn list_actor_ids_for_tags(
+	ctx: &common::TestCtx,
+	namespace_id: rivet_util::Id,
+	tags: &[(&str, &str)],
+) -> Vec<rivet_util::Id> {
+	let mut actor_ids = ctx
+		.leader_dc()
+		.workflow_ctx
+		.op(pegboard::ops::actor::list_for_tags::Input {
+			namespace_id,
+			tags: tags
+				.iter()
+				.map(|(k, v)| (k.to_string(), v.to_string()))
+				.collect::<HashMap<_, _>>(),
+			created_before: None,
+			created_after: None,
+			cursor: None,
+			limit: 100,
+			fetch_error: false,
+		})
+		.await
+		.expect("failed to list actors for tags")
+		.actors
+		.into_iter()
+		.map(|actor| actor.actor_id)
+		.collect::<Vec<_>>();
+	actor_ids.sort();
+
+	actor_ids
+}
+
+#[test]
+fn list_actors_for_tags_returns_matching_subset() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let (_, namespace_id, runner) =
+			common::setup_test_namespace_with_runner(ctx.leader_dc()).await;
+
+		let premium_us = create_actor_with_tags(
+			&ctx,
+			namespace_id,
+			runner.name(),
+			&[("tier", "premium"), ("region", "us")],
+		)
+		.await
+		.expect("failed to create actor");
+		let premium_eu = create_actor_with_tags(
+			&ctx,
+			namespace_id,
+			runner.name(),
+			&[("tier", "premium"), ("region", "eu")],
+		)
+		.await
+		.expect("failed to create actor");
+		let free_us = create_actor_with_tags(
+			&ctx,
+			namespace_id,
+			runner.name(),
+			&[("tier", "free"), ("region", "us")],
+		)
+		.await
+		.expect("failed to create actor");
+		create_actor_with_tags(&ctx, namespace_id, runner.name(), &[])
+			.await
+			.expect("failed to create actor");
+
+		let mut expected = vec![premium_us, premium_eu];
+		expected.sort();
+		assert_eq!(
+			list_actor_ids_for_tags(&ctx, namespace_id, &[("tier", "premium")]).await,
+			expected
+		);
+
+		let mut expected = vec![premium_us, free_us];
+		expected.sort();
+		assert_eq!(
+			list_actor_ids_for_tags(&ctx, namespace_id, &[("region", "us")]).await,
+			expected
+		);
+
+		assert_eq!(
+			list_actor_ids_for_tags(&ctx, namespace_id, &[("tier", "premium"), ("region", "us")])
+				.await,
+			vec![premium_us]
+		);
+
+		assert!(
+			list_actor_ids_for_tags(&ctx, namespace_id, &[("tier", "enterprise")])
+				.await
+				.is_empty()
+		);
+	});
+}
+
+#[test]
+fn list_actors_for_tags_excludes_destroyed_actors() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let (namespace, namespace_id, runner) =
+			common::setup_test_namespace_with_runner(ctx.leader_dc()).await;
+
+		let actor_id =
+			create_actor_with_tags(&ctx, namespace_id, runner.name(), &[("tier", "premium")])
+				.await
+				.expect("failed to create actor");
+		assert_eq!(
+			list_actor_ids_for_tags(&ctx, namespace_id, &[("tier", "premium")]).await,
+			vec![actor_id]
+		);
+
+		common::api::public::actors_delete(
+			ctx.leader_dc().guard_port(),
+			common::api_types::actors::delete::DeletePath { actor_id },
+			common::api_types::actors::delete::DeleteQuery {
+				namespace: namespace.clone(),
+			},
+		)
+		.await
+		.expect("failed to delete actor");
+
+		common::wait_with_poll(
+			std::time::Duration::from_secs(10),
+			std::time::Duration::from_millis(50),
+			|| async {
+				list_actor_ids_for_tags(&ctx, namespace_id, &[("tier", "premium")])
+					.await
+					.is_empty()
+					.then_some(())
+			},
+		)
+		.await
+		.expect("destroyed actor should be removed from the tag index");
+	});
+}
+
+async fn api_create_actor_with_tags(
+	ctx: &common::TestCtx,
+	namespace: &str,
+	datacenter: Option<&str>,
+	tags: &[(&str, &str)],
+) -> rivet_util::Id {
+	common::api::public::actors_create(
+		ctx.leader_dc().guard_port(),
+		common::api_types::actors::create::CreateQuery {
+			namespace: namespace.to_string(),
+		},
+		common::api_types::actors::create::CreateRequest {
+			datacenter: datacenter.map(|dc| dc.to_string()),
+			name: "test-actor".to_string(),
+			key: None,
+			input: None,
+			runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
+			crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+			affinity: None,
+			required_capabilities: Vec::new(),
+			tags: tags
+				.iter()
+				.map(|(k, v)| (k.to_string(), v.to_string()))
+				.collect(),
+		},
+	)
+	.await
+	.expect("failed to create actor")
+	.actor
+	.actor_id
+}
+
+async fn api_list_actors_for_tags(
+	ctx: &common::TestCtx,
+	namespace: &str,
+	tags: &[(&str, &str)],
+	limit: Option<usize>,
+	cursor: Option<String>,
+) -> common::api_types::actors::list::ListResponse {
+	common::api::public::actors_list(
+		ctx.leader_dc().guard_port(),
+		common::api_types::actors::list::ListQuery {
+			namespace: namespace.to_string(),
+			tag: tags.iter().map(|(k, v)| format!("{k}={v}")).collect(),
+			limit,
+			cursor,
+			..Default::default()
+		},
+	)
+	.await
+	.expect("failed to list actors")
+}
+
+#[test]
+fn api_list_actors_by_tag_includes_remote_dc_actors() {
+	common::run(common::TestOpts::new(2), |ctx| async move {
+		let (namespace, _, _runner) =
+			common::setup_test_namespace_with_runner(ctx.leader_dc()).await;
+
+		let local =
+			api_create_actor_with_tags(&ctx, &namespace, None, &[("tier", "premium")]).await;
+		let remote =
+			api_create_actor_with_tags(&ctx, &namespace, Some("dc-2"), &[("tier", "premium")])
+				.await;
+		api_create_actor_with_tags(&ctx, &namespace, Some("dc-2"), &[("tier", "free")]).await;
+		assert_eq!(remote.label(), 2, "actor should be created in dc-2");
+
+		let res =
+			api_list_actors_for_tags(&ctx, &namespace, &[("tier", "premium")], None, None).await;
+		let mut actor_ids = res
+			.actors
+			.iter()
+			.map(|actor| actor.actor_id)
+			.collect::<Vec<_>>();
+		actor_ids.sort();
+		let mut expected = vec![local, remote];
+		expected.sort();
+		assert_eq!(actor_ids, expected);
+		assert!(res.pagination.cursor.is_none());
+	});
+}
+
+#[test]
+fn api_list_actors_by_tag_paginates() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let (namespace, _, _runner) =
+			common::setup_test_namespace_with_runner(ctx.leader_dc()).await;
+
+		let mut expected = Vec::new();
+		for _ in 0..3 {
+			expected.push(
+				api_create_actor_with_tags(&ctx, &namespace, None, &[("tier", "premium")]).await,
+			);
+		}
+		expected.sort();
+
+		let first =
+			api_list_actors_for_tags(&ctx, &namespace, &[("tier", "premium")], Some(2), None).await;
+		assert_eq!(first.actors.len(), 2);
+		let cursor = first
+			.pagination
+			.cursor
+			.clone()
+			.expect("full page should have a cursor");
+
+		let second = api_list_actors_for_tags(
+			&ctx,
+			&namespace,
+			&[("tier", "premium")],
+			Some(2),
+			Some(cursor),
+		)
+		.await;
+		assert_eq!(second.actors.len(), 1);
+
+		let mut actor_ids = first
+			.actors
+			.iter()
+			.chain(&second.actors)
+			.map(|actor| actor.actor_id)
+			.collect::<Vec<_>>();
+		actor_ids.sort();
+		assert_eq!(actor_ids, expected);
+	});
+}
+
+#[test]
+fn create_actor_with_too_many_tags_fails() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let (_, namespace_id, runner) =
+			common::setup_test_namespace_with_runner(ctx.leader_dc()).await;
+
+		let tags = (0..33)
+			.map(|i| (format!("tag-{i}"), "value".to_string()))
+			.collect::<Vec<_>>();
+		let tags = tags
+			.iter()
+			.map(|(k, v)| (k.as_str(), v.as_str()))
+			.collect::<Vec<_>>();
+
+		create_actor_with_tags(&ctx, namespace_id, runner.name(), &tags)
+			.await
+			.expect_err("actor with too many tags should fail to create");
+	});
+}
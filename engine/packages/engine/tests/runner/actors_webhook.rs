@@ -0,0 +1,331 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU16, Ordering};
+use std::time::Duration;
+
+use axum::{Router, body::Bytes, extract::State, http::HeaderMap, http::StatusCode, routing::post};
+use pegboard::workflows::webhook::{
+	NOTIFICATION_ID_HEADER, SIGNATURE_HEADER, TIMESTAMP_HEADER, WebhookEvent, WebhookPayload,
+};
+use tokio::sync::mpsc;
+
+use super::super::common;
+
+const WEBHOOK_SECRET: &str = "test-webhook-secret";
+
+struct MockWebhookState {
+	status: AtomicU16,
+	tx: mpsc::UnboundedSender<(HeaderMap, Bytes)>,
+}
+
+impl MockWebhookState {
+	fn set_status(&self, status: StatusCode) {
+		self.status.store(status.as_u16(), Ordering::SeqCst);
+	}
+}
+
+async fn webhook_handler(
+	State(state): State<Arc<MockWebhookState>>,
+	headers: HeaderMap,
+	body: Bytes,
+) -> StatusCode {
+	let _ = state.tx.send((headers, body));
+	StatusCode::from_u16(state.status.load(Ordering::SeqCst)).expect("invalid status")
+}
+
+/// Starts a webhook endpoint responding with the given status. Returns its url and received requests.
+async fn start_mock_webhook(
+	status: StatusCode,
+) -> (String, mpsc::UnboundedReceiver<(HeaderMap, Bytes)>) {
+	let (url, rx, _) = start_mock_webhook_with_state(status).await;
+	(url, rx)
+}
+
+/// Same as `start_mock_webhook`, also returning the state to change the response status later.
+async fn start_mock_webhook_with_state(
+	status: StatusCode,
+) -> (
+	String,
+	mpsc::UnboundedReceiver<(HeaderMap, Bytes)>,
+	Arc<MockWebhookState>,
+) {
+	let (tx, rx) = mpsc::unbounded_channel();
+	let state = Arc::new(MockWebhookState {
+		status: AtomicU16::new(status.as_u16()),
+		tx,
+	});
+	let app = Router::new()
+		.route("/webhook", post(webhook_handler))
+		.with_state(state.clone());
+
+	let port = portpicker::pick_unused_port().expect("failed to pick port");
+	let listener = tokio::net::TcpListener::bind(format!("127.0.0.1:{port}"))
+		.await
+		.expect("failed to bind mock webhook endpoint");
+	tokio::spawn(async move {
+		axum::serve(listener, app).await.expect("server error");
+	});
+
+	(format!("http://127.0.0.1:{port}/webhook"), rx, state)
+}
+
+async fn set_webhook(ctx: &common::TestCtx, namespace_id: rivet_util::Id, url: String) {
+	ctx.leader_dc()
+		.workflow_ctx
+		.op(pegboard::ops::webhook::set::Input {
+			namespace_id,
+			config: Some(pegboard::keys::webhook::WebhookConfig {
+				url,
+				secret: WEBHOOK_SECRET.to_string(),
+			}),
+		})
+		.await
+		.expect("failed to set webhook");
+}
+
+/// Waits for the next notification of the given actor, verifying its signature.
+async fn recv_notification(
+	rx: &mut mpsc::UnboundedReceiver<(HeaderMap, Bytes)>,
+	actor_id: rivet_util::Id,
+) -> WebhookPayload {
+	loop {
+		let (headers, body) = tokio::time::timeout(Duration::from_secs(10), rx.recv())
+			.await
+			.expect("timed out waiting for webhook notification")
+			.expect("webhook channel closed");
+
+		let header = |name: &str| {
+			headers
+				.get(name)
+				.unwrap_or_else(|| panic!("missing {name} header"))
+				.to_str()
+				.expect("invalid header")
+				.to_string()
+		};
+
+		let body = std::str::from_utf8(&body).expect("body should be utf-8");
+		let ts = header(TIMESTAMP_HEADER)
+			.parse::<i64>()
+			.expect("invalid timestamp header");
+		let expected_signature = pegboard::workflows::webhook::sign(WEBHOOK_SECRET, ts, body)
+			.expect("failed to sign body");
+		assert_eq!(
+			header(SIGNATURE_HEADER),
+			expected_signature,
+			"webhook signature should match body"
+		);
+
+		let payload = serde_json::from_str::<WebhookPayload>(body).expect("invalid payload");
+		assert_eq!(
+			header(NOTIFICATION_ID_HEADER),
+			payload.notification_id.to_string()
+		);
+
+		if payload.actor_id == actor_id {
+			return payload;
+		}
+	}
+}
+
+#[test]
+fn webhook_receives_signed_lifecycle_notifications() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let (namespace, namespace_id, runner) =
+			common::setup_test_namespace_with_runner(ctx.leader_dc()).await;
+
+		let (url, mut rx) = start_mock_webhook(StatusCode::OK).await;
+		set_webhook(&ctx, namespace_id, url).await;
+
+		let res = common::create_actor(
+			ctx.leader_dc().guard_port(),
+			&namespace,
+			"test-actor",
+			runner.name(),
+			rivet_types::actors::CrashPolicy::Destroy,
+		)
+		.await;
+		let actor_id = res.actor.actor_id;
+
+		let ready = recv_notification(&mut rx, actor_id).await;
+		assert_eq!(ready.event, WebhookEvent::Ready);
+		assert_eq!(ready.namespace_id, namespace_id);
+		assert_eq!(ready.actor_name, "test-actor");
+
+		common::api::public::actors_delete(
+			ctx.leader_dc().guard_port(),
+			common::api_types::actors::delete::DeletePath { actor_id },
+			common::api_types::actors::delete::DeleteQuery {
+				namespace: namespace.clone(),
+			},
+		)
+		.await
+		.expect("failed to delete actor");
+
+		let stopped = recv_notification(&mut rx, actor_id).await;
+		assert_eq!(stopped.event, WebhookEvent::Stopped);
+		assert_ne!(stopped.notification_id, ready.notification_id);
+		assert!(stopped.ts >= ready.ts);
+	});
+}
+
+#[test]
+fn removed_webhook_is_not_notified() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let (namespace, namespace_id, runner) =
+			common::setup_test_namespace_with_runner(ctx.leader_dc()).await;
+
+		let (url, mut rx) = start_mock_webhook(StatusCode::OK).await;
+		set_webhook(&ctx, namespace_id, url).await;
+
+		// Remove the webhook again
+		ctx.leader_dc()
+			.workflow_ctx
+			.op(pegboard::ops::webhook::set::Input {
+				namespace_id,
+				config: None,
+			})
+			.await
+			.expect("failed to remove webhook");
+
+		common::create_actor(
+			ctx.leader_dc().guard_port(),
+			&namespace,
+			"test-actor",
+			runner.name(),
+			rivet_types::actors::CrashPolicy::Destroy,
+		)
+		.await;
+
+		assert!(
+			tokio::time::timeout(Duration::from_secs(2), rx.recv())
+				.await
+				.is_err(),
+			"removed webhook should not be notified"
+		);
+	});
+}
+
+#[test]
+fn webhook_moves_undeliverable_notifications_to_dead_letters() {
+	common::run(
+		common::TestOpts::new(1).with_timeout(60),
+		|ctx| async move {
+			let (namespace, namespace_id, runner) =
+				common::setup_test_namespace_with_runner(ctx.leader_dc()).await;
+
+			let (url, _rx) = start_mock_webhook(StatusCode::INTERNAL_SERVER_ERROR).await;
+			set_webhook(&ctx, namespace_id, url.clone()).await;
+
+			common::create_actor(
+				ctx.leader_dc().guard_port(),
+				&namespace,
+				"test-actor",
+				runner.name(),
+				rivet_types::actors::CrashPolicy::Destroy,
+			)
+			.await;
+
+			let dead_letter = common::wait_with_poll(
+				Duration::from_secs(30),
+				Duration::from_millis(250),
+				|| async {
+					ctx.leader_dc()
+						.workflow_ctx
+						.op(pegboard::ops::webhook::list_dead_letters::Input {
+							namespace_id,
+							limit: 10,
+						})
+						.await
+						.expect("failed to list dead letters")
+						.into_iter()
+						.next()
+				},
+			)
+			.await
+			.expect("timed out waiting for dead letter");
+
+			assert_eq!(dead_letter.url, url);
+			assert_eq!(dead_letter.attempts, 5);
+			assert!(
+				dead_letter.error.contains("500"),
+				"unexpected error: {}",
+				dead_letter.error
+			);
+
+			let payload = serde_json::from_str::<WebhookPayload>(&dead_letter.body)
+				.expect("dead letter should hold the payload");
+			assert_eq!(payload.event, WebhookEvent::Ready);
+		},
+	);
+}
+
+#[test]
+fn replayed_dead_letter_is_redelivered() {
+	common::run(
+		common::TestOpts::new(1).with_timeout(60),
+		|ctx| async move {
+			let (namespace, namespace_id, runner) =
+				common::setup_test_namespace_with_runner(ctx.leader_dc()).await;
+
+			let (url, mut rx, state) =
+				start_mock_webhook_with_state(StatusCode::INTERNAL_SERVER_ERROR).await;
+			set_webhook(&ctx, namespace_id, url.clone()).await;
+
+			let res = common::create_actor(
+				ctx.leader_dc().guard_port(),
+				&namespace,
+				"test-actor",
+				runner.name(),
+				rivet_types::actors::CrashPolicy::Destroy,
+			)
+			.await;
+			let actor_id = res.actor.actor_id;
+
+			let list_dead_letters = || async {
+				ctx.leader_dc()
+					.workflow_ctx
+					.op(pegboard::ops::webhook::list_dead_letters::Input {
+						namespace_id,
+						limit: 10,
+					})
+					.await
+					.expect("failed to list dead letters")
+			};
+
+			let dead_letter = common::wait_with_poll(
+				Duration::from_secs(30),
+				Duration::from_millis(250),
+				|| async { list_dead_letters().await.into_iter().next() },
+			)
+			.await
+			.expect("timed out waiting for dead letter");
+
+			// Let the endpoint recover and drop the failed attempts
+			state.set_status(StatusCode::OK);
+			while rx.try_recv().is_ok() {}
+
+			let replayed = ctx
+				.leader_dc()
+				.workflow_ctx
+				.op(pegboard::ops::webhook::replay_dead_letters::Input {
+					namespace_id,
+					notification_ids: vec![dead_letter.notification_id],
+					limit: 10,
+				})
+				.await
+				.expect("failed to replay dead letters");
+			assert_eq!(replayed, vec![dead_letter.notification_id]);
+
+			let payload = recv_notification(&mut rx, actor_id).await;
+			assert_eq!(payload.notification_id, dead_letter.notification_id);
+			assert_eq!(payload.event, WebhookEvent::Ready);
+
+			assert!(
+				list_dead_letters()
+					.await
+					.iter()
+					.all(|x| x.notification_id != dead_letter.notification_id),
+				"replayed dead letter should be removed"
+			);
+		},
+	);
+}
@@ -0,0 +1,339 @@
+use std::time::Duration;
+
+use futures_util::{SinkExt, StreamExt};
+use tokio::net::TcpStream;
+use tokio_tungstenite::{
+	MaybeTlsStream, WebSocketStream, connect_async,
+	tungstenite::{Message, client::IntoClientRequest, protocol::frame::coding::CloseCode},
+};
+
+use super::super::common;
+use common::test_runner::{
+	WebSocketCloseAfterActor, WebSocketEchoActor, WebSocketHibernateActor, WebSocketPingActor,
+	protocol_types::mk2,
+};
+
+type ActorWebSocket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+const CLOSE_AFTER_MESSAGES: usize = 2;
+const CLOSE_CODE: u16 = 4001;
+
+/// Starts a runner with every WebSocket behavior and creates an actor with the given name.
+/// Returns the runner and actor id.
+async fn setup_websocket_actor(
+	dc: &common::TestDatacenter,
+	actor_name: &str,
+) -> (common::TestRunner, String) {
+	let (namespace, _) = common::setup_test_namespace(dc).await;
+
+	let runner = common::setup_runner(dc, &namespace, |builder| {
+		builder
+			.with_actor_behavior("ws-echo", |_| Box::new(WebSocketEchoActor::new()))
+			.with_actor_behavior("ws-ping", |_| {
+				Box::new(WebSocketPingActor::new(Duration::from_millis(100)))
+			})
+			.with_actor_behavior("ws-close-after", |_| {
+				Box::new(WebSocketCloseAfterActor::new(
+					CLOSE_AFTER_MESSAGES,
+					CLOSE_CODE,
+				))
+			})
+			.with_actor_behavior("ws-hibernate", |_| Box::new(WebSocketHibernateActor::new()))
+	})
+	.await;
+
+	let res = common::create_actor(
+		dc.guard_port(),
+		&namespace,
+		actor_name,
+		runner.name(),
+		rivet_types::actors::CrashPolicy::Destroy,
+	)
+	.await;
+	let actor_id = res.actor.actor_id.to_string();
+
+	common::wait_with_poll(
+		Duration::from_secs(10),
+		Duration::from_millis(50),
+		|| async { runner.has_actor(&actor_id).await.then_some(()) },
+	)
+	.await
+	.expect("actor was never allocated to the runner");
+
+	(runner, actor_id)
+}
+
+async fn connect_actor_websocket(dc: &common::TestDatacenter, actor_id: &str) -> ActorWebSocket {
+	let mut request = format!("ws://127.0.0.1:{}/ws", dc.guard_port())
+		.into_client_request()
+		.expect("failed to create websocket request");
+	request.headers_mut().insert(
+		"Sec-WebSocket-Protocol",
+		format!(
+			"rivet, rivet_target.actor, rivet_actor.{}",
+			urlencoding::encode(actor_id)
+		)
+		.parse()
+		.unwrap(),
+	);
+
+	let (ws, _) = connect_async(request)
+		.await
+		.expect("failed to connect to websocket");
+
+	ws
+}
+
+async fn next_message(ws: &mut ActorWebSocket) -> Message {
+	tokio::time::timeout(Duration::from_secs(5), ws.next())
+		.await
+		.expect("timed out waiting for websocket message")
+		.expect("websocket stream ended unexpectedly")
+		.expect("websocket error")
+}
+
+/// Number of times the actor asked the gateway to hibernate a WebSocket.
+fn count_hibernations(runner: &common::TestRunner) -> usize {
+	runner.count_sent(|frame| {
+		matches!(
+			frame,
+			mk2::ToServer::ToServerTunnelMessage(mk2::ToServerTunnelMessage {
+				message_kind: mk2::ToServerTunnelMessageKind::ToServerWebSocketClose(
+					mk2::ToServerWebSocketClose {
+						hibernate: true,
+						..
+					}
+				),
+				..
+			})
+		)
+	})
+}
+
+async fn wait_for_hibernations(runner: &common::TestRunner, count: usize) {
+	common::wait_with_poll(
+		Duration::from_secs(5),
+		Duration::from_millis(50),
+		|| async { (count_hibernations(runner) >= count).then_some(()) },
+	)
+	.await
+	.expect("actor never asked the gateway to hibernate the websocket");
+}
+
+/// Sends a final message and asserts its echo is the next message, so any message delivered more
+/// than once before it fails the test.
+async fn assert_no_duplicates(ws: &mut ActorWebSocket) {
+	ws.send(Message::Text("end".into()))
+		.await
+		.expect("failed to send end");
+	assert_eq!(next_message(ws).await, Message::Text("end".into()));
+}
+
+#[test]
+fn websocket_echo_actor_echoes_messages() {
+	common::run(
+		common::TestOpts::new(1).with_timeout(30),
+		|ctx| async move {
+			let (_runner, actor_id) = setup_websocket_actor(ctx.leader_dc(), "ws-echo").await;
+			let mut ws = connect_actor_websocket(ctx.leader_dc(), &actor_id).await;
+
+			ws.send(Message::Text("hello".into()))
+				.await
+				.expect("failed to send text");
+			assert_eq!(next_message(&mut ws).await, Message::Text("hello".into()));
+
+			ws.send(Message::Binary(vec![1u8, 2, 3].into()))
+				.await
+				.expect("failed to send binary");
+			assert_eq!(
+				next_message(&mut ws).await,
+				Message::Binary(vec![1u8, 2, 3].into())
+			);
+		},
+	);
+}
+
+#[test]
+fn websocket_ping_actor_sends_pings() {
+	common::run(
+		common::TestOpts::new(1).with_timeout(30),
+		|ctx| async move {
+			let (_runner, actor_id) = setup_websocket_actor(ctx.leader_dc(), "ws-ping").await;
+			let mut ws = connect_actor_websocket(ctx.leader_dc(), &actor_id).await;
+
+			for n in 0..3 {
+				assert_eq!(
+					next_message(&mut ws).await,
+					Message::Text(format!("ping {n}").into())
+				);
+			}
+		},
+	);
+}
+
+#[test]
+fn websocket_close_after_actor_closes_with_code() {
+	common::run(
+		common::TestOpts::new(1).with_timeout(30),
+		|ctx| async move {
+			let (_runner, actor_id) =
+				setup_websocket_actor(ctx.leader_dc(), "ws-close-after").await;
+			let mut ws = connect_actor_websocket(ctx.leader_dc(), &actor_id).await;
+
+			for i in 0..CLOSE_AFTER_MESSAGES {
+				ws.send(Message::Text(format!("msg {i}").into()))
+					.await
+					.expect("failed to send message");
+				assert_eq!(
+					next_message(&mut ws).await,
+					Message::Text(format!("msg {i}").into())
+				);
+			}
+
+			match next_message(&mut ws).await {
+				Message::Close(Some(frame)) => {
+					assert_eq!(frame.code, CloseCode::from(CLOSE_CODE));
+					assert_eq!(
+						frame.reason.as_str(),
+						format!("closing after {CLOSE_AFTER_MESSAGES} messages")
+					);
+				}
+				other => panic!("expected close frame, got {other:?}"),
+			}
+		},
+	);
+}
+
+#[test]
+fn websocket_hibernate_actor_keeps_client_connected() {
+	common::run(
+		common::TestOpts::new(1).with_timeout(30),
+		|ctx| async move {
+			let (runner, actor_id) = setup_websocket_actor(ctx.leader_dc(), "ws-hibernate").await;
+			let mut ws = connect_actor_websocket(ctx.leader_dc(), &actor_id).await;
+
+			ws.send(Message::Text("hibernate".into()))
+				.await
+				.expect("failed to send hibernate");
+			wait_for_hibernations(&runner, 1).await;
+
+			// The gateway reconnects the hibernated WebSocket to the actor on the next message
+			ws.send(Message::Text("after hibernate".into()))
+				.await
+				.expect("failed to send message");
+			assert_eq!(
+				next_message(&mut ws).await,
+				Message::Text("after hibernate".into())
+			);
+		},
+	);
+}
+
+#[test]
+fn websocket_hibernate_actor_close_code_passthrough() {
+	common::run(
+		common::TestOpts::new(1).with_timeout(30),
+		|ctx| async move {
+			let (_runner, actor_id) = setup_websocket_actor(ctx.leader_dc(), "ws-hibernate").await;
+			let mut ws = connect_actor_websocket(ctx.leader_dc(), &actor_id).await;
+
+			ws.send(Message::Text("hibernate-close".into()))
+				.await
+				.expect("failed to send hibernate-close");
+
+			// The close the actor sends while the WebSocket is hibernating reaches the client as is
+			match next_message(&mut ws).await {
+				Message::Close(Some(frame)) => {
+					assert_eq!(
+						frame.code,
+						CloseCode::from(WebSocketHibernateActor::CLOSE_CODE)
+					);
+					assert_eq!(frame.reason.as_str(), WebSocketHibernateActor::CLOSE_REASON);
+				}
+				other => panic!("expected close frame, got {other:?}"),
+			}
+		},
+	);
+}
+
+#[test]
+fn websocket_hibernate_message_during_hibernation_start_is_delivered_once() {
+	common::run(
+		common::TestOpts::new(1).with_timeout(30),
+		|ctx| async move {
+			let (runner, actor_id) = setup_websocket_actor(ctx.leader_dc(), "ws-hibernate").await;
+			let mut ws = connect_actor_websocket(ctx.leader_dc(), &actor_id).await;
+
+			// Sent back to back so the message reaches the gateway while it starts hibernating
+			ws.send(Message::Text("hibernate".into()))
+				.await
+				.expect("failed to send hibernate");
+			ws.send(Message::Text("a".into()))
+				.await
+				.expect("failed to send message");
+
+			assert_eq!(next_message(&mut ws).await, Message::Text("a".into()));
+			assert_no_duplicates(&mut ws).await;
+			assert!(count_hibernations(&runner) >= 1);
+		},
+	);
+}
+
+#[test]
+fn websocket_hibernate_message_during_keepalive_is_delivered_once() {
+	common::run(
+		common::TestOpts::new(1).with_timeout(30),
+		|ctx| async move {
+			let (runner, actor_id) = setup_websocket_actor(ctx.leader_dc(), "ws-hibernate").await;
+			let mut ws = connect_actor_websocket(ctx.leader_dc(), &actor_id).await;
+
+			ws.send(Message::Text("hibernate".into()))
+				.await
+				.expect("failed to send hibernate");
+			wait_for_hibernations(&runner, 1).await;
+
+			// Let the gateway settle into the hibernation keepalive loop
+			tokio::time::sleep(Duration::from_millis(500)).await;
+
+			ws.send(Message::Text("b".into()))
+				.await
+				.expect("failed to send message");
+			assert_eq!(next_message(&mut ws).await, Message::Text("b".into()));
+			assert_no_duplicates(&mut ws).await;
+		},
+	);
+}
+
+#[test]
+fn websocket_hibernate_rewake_with_concurrent_messages_delivers_each_once() {
+	common::run(
+		common::TestOpts::new(1).with_timeout(30),
+		|ctx| async move {
+			let (runner, actor_id) = setup_websocket_actor(ctx.leader_dc(), "ws-hibernate").await;
+			let mut ws = connect_actor_websocket(ctx.leader_dc(), &actor_id).await;
+
+			for round in 0..3 {
+				ws.send(Message::Text("hibernate".into()))
+					.await
+					.expect("failed to send hibernate");
+				wait_for_hibernations(&runner, round + 1).await;
+
+				// The first message rewakes the WebSocket, the rest arrive while the actor becomes
+				// ready again
+				for i in 0..5 {
+					ws.send(Message::Text(format!("{round}:{i}").into()))
+						.await
+						.expect("failed to send message");
+				}
+				for i in 0..5 {
+					assert_eq!(
+						next_message(&mut ws).await,
+						Message::Text(format!("{round}:{i}").into())
+					);
+				}
+			}
+
+			assert_no_duplicates(&mut ws).await;
+		},
+	);
+}
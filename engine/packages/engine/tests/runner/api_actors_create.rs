@@ -18,7 +18,10 @@ fn create_actor_valid_namespace() {
 				key: None,
 				input: None,
 				runner_name_selector: runner.name().to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+				affinity: None,
+				required_capabilities: Vec::new(),
+				tags: Default::default(),
 			},
 		)
 		.await
@@ -55,7 +58,10 @@ fn create_actor_with_key() {
 				key: Some(key.clone()),
 				input: None,
 				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+				affinity: None,
+				required_capabilities: Vec::new(),
+				tags: Default::default(),
 			},
 		)
 		.await
@@ -89,7 +95,10 @@ fn create_actor_with_input() {
 				key: None,
 				input: Some(input_data.clone()),
 				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+				affinity: None,
+				required_capabilities: Vec::new(),
+				tags: Default::default(),
 			},
 		)
 		.await
@@ -120,7 +129,10 @@ fn create_durable_actor() {
 				key: None,
 				input: None,
 				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Restart,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Restart),
+				affinity: None,
+				required_capabilities: Vec::new(),
+				tags: Default::default(),
 			},
 		)
 		.await
@@ -159,7 +171,10 @@ fn create_actor_specific_datacenter() {
 				key: None,
 				input: None,
 				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+				affinity: None,
+				required_capabilities: Vec::new(),
+				tags: Default::default(),
 			},
 		)
 		.await
@@ -189,7 +204,10 @@ fn create_actor_non_existent_namespace() {
 				key: None,
 				input: None,
 				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+				affinity: None,
+				required_capabilities: Vec::new(),
+				tags: Default::default(),
 			},
 		)
 		.await;
@@ -218,7 +236,10 @@ fn create_actor_invalid_datacenter() {
 				key: None,
 				input: None,
 				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+				affinity: None,
+				required_capabilities: Vec::new(),
+				tags: Default::default(),
 			},
 		)
 		.await;
@@ -251,7 +272,10 @@ fn create_actor_remote_datacenter_verify() {
 				key: None,
 				input: None,
 				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+				affinity: None,
+				required_capabilities: Vec::new(),
+				tags: Default::default(),
 			},
 		)
 		.await
@@ -291,7 +315,10 @@ fn create_actor_input_large() {
 				key: None,
 				input: Some(input_data),
 				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+				affinity: None,
+				required_capabilities: Vec::new(),
+				tags: Default::default(),
 			},
 		)
 		.await
@@ -323,7 +350,10 @@ fn create_actor_input_exceeds_max_size() {
 				key: None,
 				input: Some(input_data),
 				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+				affinity: None,
+				required_capabilities: Vec::new(),
+				tags: Default::default(),
 			},
 		)
 		.await;
@@ -353,7 +383,10 @@ fn create_actor_empty_key() {
 				key: Some("".to_string()),
 				input: None,
 				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+				affinity: None,
+				required_capabilities: Vec::new(),
+				tags: Default::default(),
 			},
 		)
 		.await;
@@ -382,7 +415,10 @@ fn create_actor_key_at_max_size() {
 				key: Some(key.clone()),
 				input: None,
 				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+				affinity: None,
+				required_capabilities: Vec::new(),
+				tags: Default::default(),
 			},
 		)
 		.await
@@ -418,7 +454,10 @@ fn create_actor_key_exceeds_max_size() {
 				key: Some(key),
 				input: None,
 				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+				affinity: None,
+				required_capabilities: Vec::new(),
+				tags: Default::default(),
 			},
 		)
 		.await;
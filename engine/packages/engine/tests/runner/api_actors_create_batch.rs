@@ -0,0 +1,153 @@
+use super::super::common;
+
+fn actor_request(
+	runner_name: &str,
+	key: Option<String>,
+) -> common::api_types::actors::create::CreateRequest {
+	common::api_types::actors::create::CreateRequest {
+		datacenter: None,
+		name: "test-actor".to_string(),
+		key,
+		input: None,
+		runner_name_selector: runner_name.to_string(),
+		crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+		affinity: None,
+		required_capabilities: Vec::new(),
+		tags: Default::default(),
+	}
+}
+
+#[test]
+fn create_batch_returns_results_in_order() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let (namespace, _, runner) =
+			common::setup_test_namespace_with_runner(ctx.leader_dc()).await;
+
+		let keys = (0..10)
+			.map(|_| common::generate_unique_key())
+			.collect::<Vec<_>>();
+		let mut actors = keys
+			.iter()
+			.map(|key| actor_request(runner.name(), Some(key.clone())))
+			.collect::<Vec<_>>();
+		// Invalid actors fail without affecting the rest of the batch
+		actors.insert(3, actor_request(runner.name(), Some(String::new())));
+
+		let res = common::api::public::actors_create_batch(
+			ctx.leader_dc().guard_port(),
+			common::api_types::actors::create_batch::CreateBatchQuery {
+				namespace: namespace.clone(),
+			},
+			common::api_types::actors::create_batch::CreateBatchRequest { actors },
+		)
+		.await
+		.expect("failed to create actor batch");
+		assert_eq!(res.results.len(), 11);
+
+		let error = res.results[3]
+			.error
+			.as_ref()
+			.expect("empty key should fail");
+		assert_eq!(error.group, "actor");
+		assert_eq!(error.code, "empty_key");
+		assert!(res.results[3].actor.is_none());
+
+		let created = res
+			.results
+			.iter()
+			.enumerate()
+			.filter(|(idx, _)| *idx != 3)
+			.map(|(_, result)| {
+				assert!(result.error.is_none(), "unexpected error in batch result");
+				result.actor.as_ref().expect("actor should be created")
+			})
+			.collect::<Vec<_>>();
+		for (key, actor) in keys.iter().zip(created) {
+			assert_eq!(actor.key.as_ref(), Some(key));
+			common::assert_actor_exists(
+				ctx.leader_dc().guard_port(),
+				&actor.actor_id.to_string(),
+				&namespace,
+			)
+			.await;
+		}
+	});
+}
+
+#[test]
+fn create_batch_duplicate_key_creates_one_actor() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let (namespace, _, runner) =
+			common::setup_test_namespace_with_runner(ctx.leader_dc()).await;
+
+		let key = common::generate_unique_key();
+		let res = common::api::public::actors_create_batch(
+			ctx.leader_dc().guard_port(),
+			common::api_types::actors::create_batch::CreateBatchQuery {
+				namespace: namespace.clone(),
+			},
+			common::api_types::actors::create_batch::CreateBatchRequest {
+				actors: vec![
+					actor_request(runner.name(), Some(key.clone())),
+					actor_request(runner.name(), Some(key.clone())),
+				],
+			},
+		)
+		.await
+		.expect("failed to create actor batch");
+
+		let created = res
+			.results
+			.iter()
+			.filter(|result| result.actor.is_some())
+			.count();
+		assert_eq!(created, 1, "only one actor should be created for a key");
+
+		let error = res
+			.results
+			.iter()
+			.find_map(|result| result.error.as_ref())
+			.expect("duplicate key should fail");
+		assert_eq!(error.code, "duplicate_key");
+	});
+}
+
+#[test]
+fn create_batch_too_large() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let (namespace, _, runner) =
+			common::setup_test_namespace_with_runner(ctx.leader_dc()).await;
+
+		let max_size = ctx.leader_dc().config.pegboard().max_create_batch_size();
+		let res = common::api::public::actors_create_batch(
+			ctx.leader_dc().guard_port(),
+			common::api_types::actors::create_batch::CreateBatchQuery {
+				namespace: namespace.clone(),
+			},
+			common::api_types::actors::create_batch::CreateBatchRequest {
+				actors: (0..=max_size)
+					.map(|_| actor_request(runner.name(), None))
+					.collect(),
+			},
+		)
+		.await;
+
+		let err = res.expect_err("oversized batch should fail");
+		assert!(
+			err.to_string().contains("batch_too_large"),
+			"unexpected error: {err}"
+		);
+
+		let actors = common::api::public::actors_list(
+			ctx.leader_dc().guard_port(),
+			common::api_types::actors::list::ListQuery {
+				namespace,
+				name: Some("test-actor".to_string()),
+				..Default::default()
+			},
+		)
+		.await
+		.expect("failed to list actors");
+		assert!(actors.actors.is_empty(), "no actors should be created");
+	});
+}
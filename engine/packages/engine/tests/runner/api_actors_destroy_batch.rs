@@ -0,0 +1,178 @@
+use super::super::common;
+
+async fn create_actors(
+	port: u16,
+	namespace: &str,
+	runner_name: &str,
+	keys: &[String],
+) -> Vec<rivet_types::actors::Actor> {
+	let actors = keys
+		.iter()
+		.map(|key| common::api_types::actors::create::CreateRequest {
+			datacenter: None,
+			name: "test-actor".to_string(),
+			key: Some(key.clone()),
+			input: None,
+			runner_name_selector: runner_name.to_string(),
+			crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+			affinity: None,
+			required_capabilities: Vec::new(),
+			tags: Default::default(),
+		})
+		.collect();
+
+	common::api::public::actors_create_batch(
+		port,
+		common::api_types::actors::create_batch::CreateBatchQuery {
+			namespace: namespace.to_string(),
+		},
+		common::api_types::actors::create_batch::CreateBatchRequest { actors },
+	)
+	.await
+	.expect("failed to create actor batch")
+	.results
+	.into_iter()
+	.map(|result| result.actor.expect("actor should be created"))
+	.collect()
+}
+
+#[test]
+fn destroy_batch_by_actor_ids() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let (namespace, _, runner) =
+			common::setup_test_namespace_with_runner(ctx.leader_dc()).await;
+
+		let keys = (0..3)
+			.map(|_| common::generate_unique_key())
+			.collect::<Vec<_>>();
+		let actors = create_actors(
+			ctx.leader_dc().guard_port(),
+			&namespace,
+			runner.name(),
+			&keys,
+		)
+		.await;
+
+		let missing_actor_id = rivet_util::Id::new_v1(ctx.leader_dc().config.dc_label());
+		let res = common::api::public::actors_destroy_batch(
+			ctx.leader_dc().guard_port(),
+			common::api_types::actors::destroy_batch::DestroyBatchQuery {
+				namespace: namespace.clone(),
+			},
+			common::api_types::actors::destroy_batch::DestroyBatchRequest {
+				actor_ids: vec![actors[0].actor_id, missing_actor_id, actors[1].actor_id],
+				..Default::default()
+			},
+		)
+		.await
+		.expect("failed to destroy actor batch");
+		assert!(!res.has_more);
+		assert_eq!(res.results.len(), 3);
+
+		assert_eq!(res.results[0].actor_id, actors[0].actor_id);
+		assert!(res.results[0].error.is_none());
+		assert_eq!(res.results[1].actor_id, missing_actor_id);
+		let error = res.results[1]
+			.error
+			.as_ref()
+			.expect("missing actor should fail");
+		assert_eq!(error.code, "not_found");
+		assert_eq!(res.results[2].actor_id, actors[1].actor_id);
+		assert!(res.results[2].error.is_none());
+
+		for actor in &actors[..2] {
+			common::assert_actor_is_destroyed(
+				ctx.leader_dc().guard_port(),
+				&actor.actor_id.to_string(),
+				&namespace,
+			)
+			.await;
+		}
+		common::assert_actor_is_alive(
+			ctx.leader_dc().guard_port(),
+			&actors[2].actor_id.to_string(),
+			&namespace,
+		)
+		.await;
+	});
+}
+
+#[test]
+fn destroy_batch_by_key_prefix() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let (namespace, _, runner) =
+			common::setup_test_namespace_with_runner(ctx.leader_dc()).await;
+
+		let prefix = format!("{}-", common::generate_unique_key());
+		let keys = (0..3)
+			.map(|i| format!("{prefix}{i}"))
+			.chain([common::generate_unique_key()])
+			.collect::<Vec<_>>();
+		let actors = create_actors(
+			ctx.leader_dc().guard_port(),
+			&namespace,
+			runner.name(),
+			&keys,
+		)
+		.await;
+
+		let res = common::api::public::actors_destroy_batch(
+			ctx.leader_dc().guard_port(),
+			common::api_types::actors::destroy_batch::DestroyBatchQuery {
+				namespace: namespace.clone(),
+			},
+			common::api_types::actors::destroy_batch::DestroyBatchRequest {
+				name: Some("test-actor".to_string()),
+				key_prefix: Some(prefix),
+				..Default::default()
+			},
+		)
+		.await
+		.expect("failed to destroy actor batch");
+		assert!(!res.has_more);
+		assert_eq!(res.results.len(), 3);
+		assert!(res.results.iter().all(|result| result.error.is_none()));
+
+		for actor in &actors[..3] {
+			assert!(
+				res.results
+					.iter()
+					.any(|result| result.actor_id == actor.actor_id),
+				"actor with matching key should be destroyed"
+			);
+			common::assert_actor_is_destroyed(
+				ctx.leader_dc().guard_port(),
+				&actor.actor_id.to_string(),
+				&namespace,
+			)
+			.await;
+		}
+		common::assert_actor_is_alive(
+			ctx.leader_dc().guard_port(),
+			&actors[3].actor_id.to_string(),
+			&namespace,
+		)
+		.await;
+	});
+}
+
+#[test]
+fn destroy_batch_requires_actor_ids_or_name() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let (namespace, _, _runner) =
+			common::setup_test_namespace_with_runner(ctx.leader_dc()).await;
+
+		let res = common::api::public::actors_destroy_batch(
+			ctx.leader_dc().guard_port(),
+			common::api_types::actors::destroy_batch::DestroyBatchQuery { namespace },
+			common::api_types::actors::destroy_batch::DestroyBatchRequest::default(),
+		)
+		.await;
+
+		let err = res.expect_err("empty request should fail");
+		assert!(
+			err.to_string().contains("bad_request"),
+			"unexpected error: {err}"
+		);
+	});
+}
@@ -23,7 +23,7 @@ fn get_or_create_creates_new_actor() {
 				key: actor_key.to_string(),
 				input: None,
 				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
 			},
 		)
 		.await
@@ -59,7 +59,7 @@ fn get_or_create_returns_existing_actor() {
 				key: actor_key.to_string(),
 				input: None,
 				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
 			},
 		)
 		.await
@@ -80,7 +80,7 @@ fn get_or_create_returns_existing_actor() {
 				key: actor_key.to_string(),
 				input: Some("different-input".to_string()), // Different input should be ignored
 				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
 			},
 		)
 		.await
@@ -120,7 +120,7 @@ fn get_or_create_same_name_different_keys() {
 				key: "key1".to_string(),
 				input: None,
 				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
 			},
 		)
 		.await
@@ -138,7 +138,7 @@ fn get_or_create_same_name_different_keys() {
 				key: "key2".to_string(),
 				input: None,
 				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
 			},
 		)
 		.await
@@ -181,7 +181,7 @@ fn get_or_create_idempotent() {
 					key: actor_key.to_string(),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
 				},
 			)
 			.await
@@ -232,7 +232,7 @@ fn get_or_create_race_condition_handling() {
 					key: actor_key.to_string(),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
 				},
 			)
 			.await
@@ -250,7 +250,7 @@ fn get_or_create_race_condition_handling() {
 					key: actor_key.to_string(),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
 				},
 			)
 			.await
@@ -307,7 +307,7 @@ fn get_or_create_returns_winner_on_race() {
 						key: actor_key.to_string(),
 						input: None,
 						runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-						crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+						crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
 					},
 				)
 				.await
@@ -339,7 +339,7 @@ fn get_or_create_returns_winner_on_race() {
 							key: actor_key.to_string(),
 							input: None,
 							runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-							crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+							crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
 						},
 					)
 					.await
@@ -413,7 +413,7 @@ fn get_or_create_race_condition_across_datacenters() {
 					key: actor_key.to_string(),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
 				},
 			)
 			.await
@@ -431,7 +431,7 @@ fn get_or_create_race_condition_across_datacenters() {
 					key: actor_key.to_string(),
 					input: None,
 					runner_name_selector: DC2_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
 				},
 			)
 			.await
@@ -481,7 +481,7 @@ fn get_or_create_in_current_datacenter() {
 				key: "current-dc-key".to_string(),
 				input: None,
 				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
 			},
 		)
 		.await
@@ -516,7 +516,7 @@ fn get_or_create_in_remote_datacenter() {
 				key: "remote-dc-key".to_string(),
 				input: None,
 				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
 			},
 		)
 		.await
@@ -548,7 +548,7 @@ fn get_or_create_with_non_existent_namespace() {
 				key: "test-key".to_string(),
 				input: None,
 				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
 			},
 		)
 		.await;
@@ -577,7 +577,7 @@ fn get_or_create_with_invalid_datacenter() {
 				key: "test-key".to_string(),
 				input: None,
 				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
 			},
 		)
 		.await;
@@ -612,7 +612,7 @@ fn get_or_create_with_destroyed_actor() {
 				key: actor_key.to_string(),
 				input: None,
 				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
 			},
 		)
 		.await
@@ -646,7 +646,7 @@ fn get_or_create_with_destroyed_actor() {
 				key: actor_key.to_string(),
 				input: None,
 				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
 			},
 		)
 		.await
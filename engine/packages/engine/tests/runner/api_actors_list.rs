@@ -29,7 +29,10 @@ fn list_actors_by_namespace_and_name() {
 					key: Some(format!("key-{}", i)),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -46,7 +49,11 @@ fn list_actors_by_namespace_and_name() {
 				key: None,
 				actor_ids: None,
 				actor_id: vec![],
+				tag: Vec::new(),
 				include_destroyed: None,
+				state: None,
+				created_before: None,
+				created_after: None,
 				limit: None,
 				cursor: None,
 			},
@@ -94,7 +101,10 @@ fn list_with_pagination() {
 					key: Some(format!("key-{}", i)),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -111,7 +121,11 @@ fn list_with_pagination() {
 				key: None,
 				actor_ids: None,
 				actor_id: vec![],
+				tag: Vec::new(),
 				include_destroyed: None,
+				state: None,
+				created_before: None,
+				created_after: None,
 				limit: Some(2),
 				cursor: None,
 			},
@@ -134,7 +148,11 @@ fn list_with_pagination() {
 				key: None,
 				actor_ids: None,
 				actor_id: vec![],
+				tag: Vec::new(),
 				include_destroyed: None,
+				state: None,
+				created_before: None,
+				created_after: None,
 				limit: None,
 				cursor: None,
 			},
@@ -232,7 +250,11 @@ fn list_returns_empty_array_when_no_actors() {
 				key: None,
 				actor_ids: None,
 				actor_id: vec![],
+				tag: Vec::new(),
 				include_destroyed: None,
+				state: None,
+				created_before: None,
+				created_after: None,
 				limit: None,
 				cursor: None,
 			},
@@ -271,7 +293,10 @@ fn list_actors_by_namespace_name_and_key() {
 				key: Some(key1.clone()),
 				input: None,
 				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+				affinity: None,
+				required_capabilities: Vec::new(),
+				tags: Default::default(),
 			},
 		)
 		.await
@@ -289,7 +314,10 @@ fn list_actors_by_namespace_name_and_key() {
 				key: Some(key2.clone()),
 				input: None,
 				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+				affinity: None,
+				required_capabilities: Vec::new(),
+				tags: Default::default(),
 			},
 		)
 		.await
@@ -304,7 +332,11 @@ fn list_actors_by_namespace_name_and_key() {
 				key: Some("key1".to_string()),
 				actor_ids: None,
 				actor_id: vec![],
+				tag: Vec::new(),
 				include_destroyed: None,
+				state: None,
+				created_before: None,
+				created_after: None,
 				limit: None,
 				cursor: None,
 			},
@@ -337,7 +369,10 @@ fn list_with_include_destroyed_false() {
 				key: Some("destroyed-key".to_string()),
 				input: None,
 				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+				affinity: None,
+				required_capabilities: Vec::new(),
+				tags: Default::default(),
 			},
 		)
 		.await
@@ -368,7 +403,10 @@ fn list_with_include_destroyed_false() {
 				key: Some("active-key".to_string()),
 				input: None,
 				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+				affinity: None,
+				required_capabilities: Vec::new(),
+				tags: Default::default(),
 			},
 		)
 		.await
@@ -384,7 +422,11 @@ fn list_with_include_destroyed_false() {
 				key: None,
 				actor_ids: None,
 				actor_id: vec![],
+				tag: Vec::new(),
 				include_destroyed: Some(false),
+				state: None,
+				created_before: None,
+				created_after: None,
 				limit: None,
 				cursor: None,
 			},
@@ -417,7 +459,10 @@ fn list_with_include_destroyed_true() {
 				key: Some("destroyed-key".to_string()),
 				input: None,
 				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+				affinity: None,
+				required_capabilities: Vec::new(),
+				tags: Default::default(),
 			},
 		)
 		.await
@@ -448,7 +493,10 @@ fn list_with_include_destroyed_true() {
 				key: Some("active-key".to_string()),
 				input: None,
 				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+				affinity: None,
+				required_capabilities: Vec::new(),
+				tags: Default::default(),
 			},
 		)
 		.await
@@ -464,7 +512,11 @@ fn list_with_include_destroyed_true() {
 				key: None,
 				actor_ids: None,
 				actor_id: vec![],
+				tag: Vec::new(),
 				include_destroyed: Some(true),
+				state: None,
+				created_before: None,
+				created_after: None,
 				limit: None,
 				cursor: None,
 			},
@@ -521,7 +573,11 @@ fn list_specific_actors_by_ids() {
 				key: None,
 				actor_id: selected_ids.clone(),
 				actor_ids: None,
+				tag: Vec::new(),
 				include_destroyed: None,
+				state: None,
+				created_before: None,
+				created_after: None,
 				limit: None,
 				cursor: None,
 			},
@@ -572,7 +628,10 @@ fn list_actors_from_multiple_datacenters() {
 				key: Some("dc1-key".to_string()),
 				input: None,
 				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+				affinity: None,
+				required_capabilities: Vec::new(),
+				tags: Default::default(),
 			},
 		)
 		.await
@@ -590,7 +649,10 @@ fn list_actors_from_multiple_datacenters() {
 				key: Some("dc2-key".to_string()),
 				input: None,
 				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+				affinity: None,
+				required_capabilities: Vec::new(),
+				tags: Default::default(),
 			},
 		)
 		.await
@@ -606,7 +668,11 @@ fn list_actors_from_multiple_datacenters() {
 				key: None,
 				actor_id: vec![actor_id_dc1, actor_id_dc2],
 				actor_ids: None,
+				tag: Vec::new(),
 				include_destroyed: None,
+				state: None,
+				created_before: None,
+				created_after: None,
 				limit: None,
 				cursor: None,
 			},
@@ -636,7 +702,11 @@ fn list_with_non_existent_namespace() {
 				key: None,
 				actor_ids: None,
 				actor_id: vec![],
+				tag: Vec::new(),
 				include_destroyed: None,
+				state: None,
+				created_before: None,
+				created_after: None,
 				limit: None,
 				cursor: None,
 			},
@@ -663,7 +733,11 @@ fn list_with_key_but_no_name() {
 				key: Some("key1".to_string()),
 				actor_ids: None,
 				actor_id: vec![],
+				tag: Vec::new(),
 				include_destroyed: None,
+				state: None,
+				created_before: None,
+				created_after: None,
 				limit: None,
 				cursor: None,
 			},
@@ -694,7 +768,11 @@ fn list_with_more_than_32_actor_ids() {
 				key: None,
 				actor_id: actor_ids,
 				actor_ids: None,
+				tag: Vec::new(),
 				include_destroyed: None,
+				state: None,
+				created_before: None,
+				created_after: None,
 				limit: None,
 				cursor: None,
 			},
@@ -721,7 +799,11 @@ fn list_without_name_when_not_using_actor_ids() {
 				key: None,
 				actor_ids: None,
 				actor_id: vec![],
+				tag: Vec::new(),
 				include_destroyed: None,
+				state: None,
+				created_before: None,
+				created_after: None,
 				limit: None,
 				cursor: None,
 			},
@@ -760,7 +842,10 @@ fn verify_sorting_by_create_ts_descending() {
 					key: Some(format!("key-{}", i)),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -777,7 +862,11 @@ fn verify_sorting_by_create_ts_descending() {
 				key: None,
 				actor_ids: None,
 				actor_id: vec![],
+				tag: Vec::new(),
 				include_destroyed: None,
+				state: None,
+				created_before: None,
+				created_after: None,
 				limit: None,
 				cursor: None,
 			},
@@ -821,7 +910,10 @@ fn list_aggregates_results_from_all_datacenters() {
 				key: Some("dc1-key".to_string()),
 				input: None,
 				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+				affinity: None,
+				required_capabilities: Vec::new(),
+				tags: Default::default(),
 			},
 		)
 		.await
@@ -839,7 +931,10 @@ fn list_aggregates_results_from_all_datacenters() {
 				key: Some("dc2-key".to_string()),
 				input: None,
 				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+				affinity: None,
+				required_capabilities: Vec::new(),
+				tags: Default::default(),
 			},
 		)
 		.await
@@ -855,7 +950,11 @@ fn list_aggregates_results_from_all_datacenters() {
 				key: None,
 				actor_ids: None,
 				actor_id: vec![],
+				tag: Vec::new(),
 				include_destroyed: None,
+				state: None,
+				created_before: None,
+				created_after: None,
 				limit: None,
 				cursor: None,
 			},
@@ -902,7 +1001,11 @@ fn list_with_exactly_32_actor_ids() {
 				key: None,
 				actor_id: actor_ids,
 				actor_ids: None,
+				tag: Vec::new(),
 				include_destroyed: None,
+				state: None,
+				created_before: None,
+				created_after: None,
 				limit: None,
 				cursor: None,
 			},
@@ -940,7 +1043,10 @@ fn list_by_key_with_include_destroyed_true() {
 				key: Some(key.to_string()),
 				input: None,
 				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+				affinity: None,
+				required_capabilities: Vec::new(),
+				tags: Default::default(),
 			},
 		)
 		.await
@@ -971,7 +1077,10 @@ fn list_by_key_with_include_destroyed_true() {
 				key: Some(key.to_string()),
 				input: None,
 				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+				affinity: None,
+				required_capabilities: Vec::new(),
+				tags: Default::default(),
 			},
 		)
 		.await
@@ -988,7 +1097,11 @@ fn list_by_key_with_include_destroyed_true() {
 				key: Some(key.to_string()),
 				actor_ids: None,
 				actor_id: vec![],
+				tag: Vec::new(),
 				include_destroyed: Some(true),
+				state: None,
+				created_before: None,
+				created_after: None,
 				limit: None,
 				cursor: None,
 			},
@@ -1039,7 +1152,11 @@ fn list_default_limit_100() {
 				key: None,
 				actor_ids: None,
 				actor_id: vec![],
+				tag: Vec::new(),
 				include_destroyed: None,
+				state: None,
+				created_before: None,
+				created_after: None,
 				limit: None, // No limit specified - should default to 100
 				cursor: None,
 			},
@@ -1083,7 +1200,10 @@ fn list_with_invalid_actor_id_format_in_comma_list() {
 				key: Some("test-key".to_string()),
 				input: None,
 				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+				affinity: None,
+				required_capabilities: Vec::new(),
+				tags: Default::default(),
 			},
 		)
 		.await
@@ -1106,7 +1226,11 @@ fn list_with_invalid_actor_id_format_in_comma_list() {
 				key: None,
 				actor_id: vec![],
 				actor_ids: Some(mixed_ids.join(",")),
+				tag: Vec::new(),
 				include_destroyed: None,
+				state: None,
+				created_before: None,
+				created_after: None,
 				limit: None,
 				cursor: None,
 			},
@@ -1148,7 +1272,10 @@ fn list_with_cursor_pagination() {
 					key: Some(format!("cursor-key-{}", i)),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -1165,7 +1292,11 @@ fn list_with_cursor_pagination() {
 				key: None,
 				actor_ids: None,
 				actor_id: vec![],
+				tag: Vec::new(),
 				include_destroyed: None,
+				state: None,
+				created_before: None,
+				created_after: None,
 				limit: Some(2),
 				cursor: None,
 			},
@@ -1188,7 +1319,11 @@ fn list_with_cursor_pagination() {
 				key: None,
 				actor_ids: None,
 				actor_id: vec![],
+				tag: Vec::new(),
 				include_destroyed: None,
+				state: None,
+				created_before: None,
+				created_after: None,
 				limit: Some(2),
 				cursor: page1.pagination.cursor.clone(),
 			},
@@ -1211,7 +1346,11 @@ fn list_with_cursor_pagination() {
 				key: None,
 				actor_ids: None,
 				actor_id: vec![],
+				tag: Vec::new(),
 				include_destroyed: None,
+				state: None,
+				created_before: None,
+				created_after: None,
 				limit: Some(2),
 				cursor: page2.pagination.cursor.clone(),
 			},
@@ -1295,7 +1434,10 @@ fn list_cursor_filters_by_timestamp() {
 					key: Some(format!("ts-key-{}", i)),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -1311,7 +1453,11 @@ fn list_cursor_filters_by_timestamp() {
 				key: None,
 				actor_ids: None,
 				actor_id: vec![],
+				tag: Vec::new(),
 				include_destroyed: None,
+				state: None,
+				created_before: None,
+				created_after: None,
 				limit: None,
 				cursor: None,
 			},
@@ -1333,7 +1479,11 @@ fn list_cursor_filters_by_timestamp() {
 				key: None,
 				actor_ids: None,
 				actor_id: vec![],
+				tag: Vec::new(),
 				include_destroyed: None,
+				state: None,
+				created_before: None,
+				created_after: None,
 				limit: None,
 				cursor: Some(cursor.clone()),
 			},
@@ -1381,7 +1531,10 @@ fn list_cursor_with_exact_timestamp_boundary() {
 					key: Some(format!("boundary-key-{}", i)),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -1397,7 +1550,11 @@ fn list_cursor_with_exact_timestamp_boundary() {
 				key: None,
 				actor_ids: None,
 				actor_id: vec![],
+				tag: Vec::new(),
 				include_destroyed: None,
+				state: None,
+				created_before: None,
+				created_after: None,
 				limit: Some(1),
 				cursor: None,
 			},
@@ -1417,7 +1574,11 @@ fn list_cursor_with_exact_timestamp_boundary() {
 				key: None,
 				actor_ids: None,
 				actor_id: vec![],
+				tag: Vec::new(),
 				include_destroyed: None,
+				state: None,
+				created_before: None,
+				created_after: None,
 				limit: None,
 				cursor: page1.pagination.cursor.clone(),
 			},
@@ -1459,7 +1620,10 @@ fn list_cursor_empty_results_when_no_more_actors() {
 					key: Some(format!("empty-key-{}", i)),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -1475,7 +1639,11 @@ fn list_cursor_empty_results_when_no_more_actors() {
 				key: None,
 				actor_ids: None,
 				actor_id: vec![],
+				tag: Vec::new(),
 				include_destroyed: None,
+				state: None,
+				created_before: None,
+				created_after: None,
 				limit: Some(10),
 				cursor: None,
 			},
@@ -1495,7 +1663,11 @@ fn list_cursor_empty_results_when_no_more_actors() {
 					key: None,
 					actor_ids: None,
 					actor_id: vec![],
+					tag: Vec::new(),
 					include_destroyed: None,
+					state: None,
+					created_before: None,
+					created_after: None,
 					limit: Some(10),
 					cursor: Some(cursor),
 				},
@@ -1533,7 +1705,11 @@ fn list_invalid_cursor_format() {
 				key: None,
 				actor_ids: None,
 				actor_id: vec![],
+				tag: Vec::new(),
 				include_destroyed: None,
+				state: None,
+				created_before: None,
+				created_after: None,
 				limit: None,
 				cursor: Some("not-a-number".to_string()),
 			},
@@ -1572,7 +1748,10 @@ fn list_cursor_across_datacenters() {
 					key: Some(format!("dc1-cursor-key-{}", i)),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -1591,7 +1770,10 @@ fn list_cursor_across_datacenters() {
 					key: Some(format!("dc2-cursor-key-{}", i)),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -1607,7 +1789,11 @@ fn list_cursor_across_datacenters() {
 				key: None,
 				actor_ids: None,
 				actor_id: vec![],
+				tag: Vec::new(),
 				include_destroyed: None,
+				state: None,
+				created_before: None,
+				created_after: None,
 				limit: Some(3),
 				cursor: None,
 			},
@@ -1630,7 +1816,11 @@ fn list_cursor_across_datacenters() {
 					key: None,
 					actor_ids: None,
 					actor_id: vec![],
+					tag: Vec::new(),
 					include_destroyed: None,
+					state: None,
+					created_before: None,
+					created_after: None,
 					limit: Some(3),
 					cursor: Some(cursor),
 				},
@@ -1682,7 +1872,11 @@ fn list_actor_ids_with_cursor_pagination() {
 				key: None,
 				actor_id: actor_ids.clone(),
 				actor_ids: None,
+				tag: Vec::new(),
 				include_destroyed: None,
+				state: None,
+				created_before: None,
+				created_after: None,
 				limit: Some(2),
 				cursor: None,
 			},
@@ -1709,7 +1903,11 @@ fn list_actor_ids_with_cursor_pagination() {
 				key: None,
 				actor_id: actor_ids.clone(),
 				actor_ids: None,
+				tag: Vec::new(),
 				include_destroyed: None,
+				state: None,
+				created_before: None,
+				created_after: None,
 				limit: Some(2),
 				cursor: page1.pagination.cursor.clone(),
 			},
@@ -1736,7 +1934,11 @@ fn list_actor_ids_with_cursor_pagination() {
 				key: None,
 				actor_id: actor_ids.clone(),
 				actor_ids: None,
+				tag: Vec::new(),
 				include_destroyed: None,
+				state: None,
+				created_before: None,
+				created_after: None,
 				limit: Some(2),
 				cursor: page2.pagination.cursor.clone(),
 			},
@@ -1799,3 +2001,130 @@ fn list_actor_ids_with_cursor_pagination() {
 		}
 	});
 }
+
+// MARK: State and time filters
+
+#[test]
+fn list_filters_by_state() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let (namespace, _, _runner) =
+			common::setup_test_namespace_with_runner(ctx.leader_dc()).await;
+
+		let name = "state-filter-test";
+
+		let actor_ids =
+			common::bulk_create_actors(ctx.leader_dc().guard_port(), &namespace, name, 2).await;
+		let destroyed_actor_id = actor_ids[0];
+
+		common::api::public::actors_delete(
+			ctx.leader_dc().guard_port(),
+			common::api_types::actors::delete::DeletePath {
+				actor_id: destroyed_actor_id,
+			},
+			common::api_types::actors::delete::DeleteQuery {
+				namespace: namespace.clone(),
+			},
+		)
+		.await
+		.expect("failed to delete actor");
+
+		// Destroyed actors are listed by state without `include_destroyed`
+		let response = common::api::public::actors_list(
+			ctx.leader_dc().guard_port(),
+			common::api_types::actors::list::ListQuery {
+				namespace: namespace.clone(),
+				name: Some(name.to_string()),
+				state: Some(rivet_types::actors::ActorState::Destroyed),
+				..Default::default()
+			},
+		)
+		.await
+		.expect("failed to list actors");
+
+		let returned_ids = response
+			.actors
+			.iter()
+			.map(|a| a.actor_id)
+			.collect::<Vec<_>>();
+		assert_eq!(returned_ids, vec![destroyed_actor_id]);
+		assert_eq!(
+			response.actors[0].state(),
+			rivet_types::actors::ActorState::Destroyed
+		);
+	});
+}
+
+#[test]
+fn list_filters_by_create_ts() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let (namespace, _, _runner) =
+			common::setup_test_namespace_with_runner(ctx.leader_dc()).await;
+
+		let name = "create-ts-filter-test";
+
+		common::bulk_create_actors(ctx.leader_dc().guard_port(), &namespace, name, 3).await;
+
+		let all_actors = common::api::public::actors_list(
+			ctx.leader_dc().guard_port(),
+			common::api_types::actors::list::ListQuery {
+				namespace: namespace.clone(),
+				name: Some(name.to_string()),
+				..Default::default()
+			},
+		)
+		.await
+		.expect("failed to list actors");
+		assert_eq!(all_actors.actors.len(), 3, "Should have 3 actors");
+
+		// Sorted newest first
+		let middle = &all_actors.actors[1];
+
+		let before = common::api::public::actors_list(
+			ctx.leader_dc().guard_port(),
+			common::api_types::actors::list::ListQuery {
+				namespace: namespace.clone(),
+				name: Some(name.to_string()),
+				created_before: Some(middle.create_ts),
+				..Default::default()
+			},
+		)
+		.await
+		.expect("failed to list actors created before");
+		assert!(
+			before
+				.actors
+				.iter()
+				.all(|actor| actor.create_ts < middle.create_ts)
+		);
+		assert!(
+			before
+				.actors
+				.iter()
+				.any(|actor| actor.actor_id == all_actors.actors[2].actor_id)
+		);
+
+		let after = common::api::public::actors_list(
+			ctx.leader_dc().guard_port(),
+			common::api_types::actors::list::ListQuery {
+				namespace: namespace.clone(),
+				name: Some(name.to_string()),
+				created_after: Some(middle.create_ts),
+				..Default::default()
+			},
+		)
+		.await
+		.expect("failed to list actors created after");
+		assert!(
+			after
+				.actors
+				.iter()
+				.all(|actor| actor.create_ts > middle.create_ts)
+		);
+		assert!(
+			after
+				.actors
+				.iter()
+				.any(|actor| actor.actor_id == all_actors.actors[0].actor_id)
+		);
+	});
+}
@@ -24,7 +24,10 @@ fn list_all_actor_names_in_namespace() {
 					key: Some(common::generate_unique_key()),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -44,7 +47,10 @@ fn list_all_actor_names_in_namespace() {
 					key: Some(format!("key-{}", i)),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -100,7 +106,10 @@ fn list_names_with_pagination() {
 					key: Some(common::generate_unique_key()),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -228,7 +237,10 @@ fn list_names_fanout_to_all_datacenters() {
 				key: Some(common::generate_unique_key()),
 				input: None,
 				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+				affinity: None,
+				required_capabilities: Vec::new(),
+				tags: Default::default(),
 			},
 		)
 		.await
@@ -245,7 +257,10 @@ fn list_names_fanout_to_all_datacenters() {
 				key: Some(common::generate_unique_key()),
 				input: None,
 				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+				affinity: None,
+				required_capabilities: Vec::new(),
+				tags: Default::default(),
 			},
 		)
 		.await
@@ -299,7 +314,10 @@ fn list_names_deduplication_across_datacenters() {
 				key: Some("dc1-key".to_string()),
 				input: None,
 				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+				affinity: None,
+				required_capabilities: Vec::new(),
+				tags: Default::default(),
 			},
 		)
 		.await
@@ -316,7 +334,10 @@ fn list_names_deduplication_across_datacenters() {
 				key: Some("dc2-key".to_string()),
 				input: None,
 				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+				affinity: None,
+				required_capabilities: Vec::new(),
+				tags: Default::default(),
 			},
 		)
 		.await
@@ -370,7 +391,10 @@ fn list_names_alphabetical_sorting() {
 					key: Some(common::generate_unique_key()),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -426,7 +450,10 @@ fn list_names_default_limit_100() {
 					key: Some(common::generate_unique_key()),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -480,7 +507,10 @@ fn list_names_with_metadata() {
 				key: Some(common::generate_unique_key()),
 				input: None,
 				runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-				crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+				crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+				affinity: None,
+				required_capabilities: Vec::new(),
+				tags: Default::default(),
 			},
 		)
 		.await
@@ -569,7 +599,10 @@ fn list_names_pagination_no_duplicates_comprehensive() {
 					key: Some(common::generate_unique_key()),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -660,7 +693,10 @@ fn list_names_pagination_boundary_cases() {
 					key: Some(common::generate_unique_key()),
 					input: None,
 					runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Destroy,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
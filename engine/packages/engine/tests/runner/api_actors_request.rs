@@ -0,0 +1,56 @@
+use super::super::common;
+
+async fn ping_by_key(
+	ctx: &common::TestCtx,
+	namespace: &str,
+	key: &str,
+) -> (String, serde_json::Value) {
+	let response = common::api::public::actors_request(
+		ctx.leader_dc().guard_port(),
+		common::api_types::actors::request::RequestPath {
+			name: "test-actor".to_string(),
+			key: key.to_string(),
+		},
+		common::api_types::actors::request::RequestQuery {
+			namespace: namespace.to_string(),
+			runner_name_selector: common::TEST_RUNNER_NAME.to_string(),
+			datacenter: None,
+			crash_policy: Some(rivet_types::actors::CrashPolicy::Destroy),
+			path: Some("/ping".to_string()),
+		},
+		Vec::new(),
+	)
+	.await
+	.expect("failed to send request by key");
+
+	let actor_id = response
+		.headers()
+		.get("x-rivet-actor")
+		.expect("response should include the actor id")
+		.to_str()
+		.expect("invalid x-rivet-actor header")
+		.to_string();
+	let body = response.json().await.expect("failed to parse response");
+
+	(actor_id, body)
+}
+
+#[test]
+fn request_by_key_creates_actor_and_proxies() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let (namespace, _, _runner) =
+			common::setup_test_namespace_with_runner(ctx.leader_dc()).await;
+
+		let (actor_id, body) = ping_by_key(&ctx, &namespace, "request-key-1").await;
+		assert_eq!(body["status"], "ok");
+		assert_eq!(body["actorId"], actor_id.as_str());
+
+		// Same key reaches the same actor
+		let (actor_id_2, _) = ping_by_key(&ctx, &namespace, "request-key-1").await;
+		assert_eq!(actor_id, actor_id_2);
+
+		// Different key creates a different actor
+		let (actor_id_3, _) = ping_by_key(&ctx, &namespace, "request-key-2").await;
+		assert_ne!(actor_id, actor_id_3);
+	});
+}
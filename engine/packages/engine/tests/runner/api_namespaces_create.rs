@@ -12,6 +12,9 @@ fn create_namespace_success() {
 			rivet_api_peer::namespaces::CreateRequest {
 				name: "test-namespace".to_string(),
 				display_name: "Test Namespace".to_string(),
+				default_crash_policy: None,
+				cors_policy: None,
+				actor_rate_limit: None,
 			},
 		)
 		.await
@@ -33,6 +36,9 @@ fn create_namespace_validates_returned_data() {
 			rivet_api_peer::namespaces::CreateRequest {
 				name: "validate-test".to_string(),
 				display_name: "Validation Test".to_string(),
+				default_crash_policy: None,
+				cors_policy: None,
+				actor_rate_limit: None,
 			},
 		)
 		.await
@@ -58,6 +64,9 @@ fn create_namespace_generates_unique_ids() {
 				rivet_api_peer::namespaces::CreateRequest {
 					name: format!("unique-test-{}", i),
 					display_name: format!("Unique Test {}", i),
+					default_crash_policy: None,
+					cors_policy: None,
+					actor_rate_limit: None,
 				},
 			)
 			.await
@@ -86,6 +95,9 @@ fn create_namespace_with_long_display_name() {
 			rivet_api_peer::namespaces::CreateRequest {
 				name: "long-display".to_string(),
 				display_name: long_display_name.clone(),
+				default_crash_policy: None,
+				cors_policy: None,
+				actor_rate_limit: None,
 			},
 		)
 		.await
@@ -105,6 +117,9 @@ fn create_namespace_persists_data() {
 			rivet_api_peer::namespaces::CreateRequest {
 				name: namespace_name.to_string(),
 				display_name: "Persist Test".to_string(),
+				default_crash_policy: None,
+				cors_policy: None,
+				actor_rate_limit: None,
 			},
 		)
 		.await
@@ -155,6 +170,9 @@ fn create_namespace_with_valid_dns_name() {
 				rivet_api_peer::namespaces::CreateRequest {
 					name: name.to_string(),
 					display_name: format!("Valid DNS: {}", name),
+					default_crash_policy: None,
+					cors_policy: None,
+					actor_rate_limit: None,
 				},
 			)
 			.await
@@ -176,6 +194,9 @@ fn create_namespace_duplicate_name_fails() {
 			rivet_api_peer::namespaces::CreateRequest {
 				name: namespace_name.to_string(),
 				display_name: "First".to_string(),
+				default_crash_policy: None,
+				cors_policy: None,
+				actor_rate_limit: None,
 			},
 		)
 		.await
@@ -187,6 +208,9 @@ fn create_namespace_duplicate_name_fails() {
 			rivet_api_peer::namespaces::CreateRequest {
 				name: namespace_name.to_string(),
 				display_name: "Second".to_string(),
+				default_crash_policy: None,
+				cors_policy: None,
+				actor_rate_limit: None,
 			},
 		)
 		.await;
@@ -206,6 +230,9 @@ fn create_namespace_invalid_uppercase() {
 			rivet_api_peer::namespaces::CreateRequest {
 				name: "UpperCase".to_string(),
 				display_name: "Invalid Uppercase".to_string(),
+				default_crash_policy: None,
+				cors_policy: None,
+				actor_rate_limit: None,
 			},
 		)
 		.await;
@@ -234,6 +261,9 @@ fn create_namespace_invalid_special_chars() {
 				rivet_api_peer::namespaces::CreateRequest {
 					name: name.to_string(),
 					display_name: "Invalid Special Chars".to_string(),
+					default_crash_policy: None,
+					cors_policy: None,
+					actor_rate_limit: None,
 				},
 			)
 			.await;
@@ -258,6 +288,9 @@ fn create_namespace_invalid_starts_with_hyphen() {
 			rivet_api_peer::namespaces::CreateRequest {
 				name: "-starts-with-hyphen".to_string(),
 				display_name: "Invalid Start".to_string(),
+				default_crash_policy: None,
+				cors_policy: None,
+				actor_rate_limit: None,
 			},
 		)
 		.await;
@@ -277,6 +310,9 @@ fn create_namespace_invalid_ends_with_hyphen() {
 			rivet_api_peer::namespaces::CreateRequest {
 				name: "ends-with-hyphen-".to_string(),
 				display_name: "Invalid End".to_string(),
+				default_crash_policy: None,
+				cors_policy: None,
+				actor_rate_limit: None,
 			},
 		)
 		.await;
@@ -298,6 +334,9 @@ fn create_namespace_empty_display_name_fails() {
 			rivet_api_peer::namespaces::CreateRequest {
 				name: "empty-display".to_string(),
 				display_name: "".to_string(),
+				default_crash_policy: None,
+				cors_policy: None,
+				actor_rate_limit: None,
 			},
 		)
 		.await;
@@ -309,6 +348,56 @@ fn create_namespace_empty_display_name_fails() {
 	});
 }
 
+#[test]
+fn create_namespace_invalid_cors_policy_fails() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let result = common::api::public::namespaces_create(
+			ctx.leader_dc().guard_port(),
+			rivet_api_peer::namespaces::CreateRequest {
+				name: "invalid-cors".to_string(),
+				display_name: "Invalid CORS".to_string(),
+				default_crash_policy: None,
+				cors_policy: Some(rivet_types::namespaces::CorsPolicy {
+					allowed_origins: Some(vec!["https://a.example, https://b.example".to_string()]),
+					..Default::default()
+				}),
+				actor_rate_limit: None,
+			},
+		)
+		.await;
+
+		assert!(
+			result.is_err(),
+			"should fail to create namespace with an invalid cors origin"
+		);
+	});
+}
+
+#[test]
+fn create_namespace_invalid_actor_rate_limit_fails() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let result = common::api::public::namespaces_create(
+			ctx.leader_dc().guard_port(),
+			rivet_api_peer::namespaces::CreateRequest {
+				name: "invalid-rate-limit".to_string(),
+				display_name: "Invalid Rate Limit".to_string(),
+				default_crash_policy: None,
+				cors_policy: None,
+				actor_rate_limit: Some(rivet_types::namespaces::ActorRateLimit {
+					requests_per_second: 0,
+					burst: None,
+				}),
+			},
+		)
+		.await;
+
+		assert!(
+			result.is_err(),
+			"should fail to create namespace with a zero actor rate limit"
+		);
+	});
+}
+
 #[test]
 // Broken legacy Pegboard Runner test: full engine sweep timed out in
 // `create_namespace_with_unicode_display_name`.
@@ -322,6 +411,9 @@ fn create_namespace_with_unicode_display_name() {
 			rivet_api_peer::namespaces::CreateRequest {
 				name: "unicode-display".to_string(),
 				display_name: unicode_display.to_string(),
+				default_crash_policy: None,
+				cors_policy: None,
+				actor_rate_limit: None,
 			},
 		)
 		.await
@@ -344,6 +436,9 @@ fn create_namespace_from_leader() {
 			rivet_api_peer::namespaces::CreateRequest {
 				name: "leader-test".to_string(),
 				display_name: "Leader Test".to_string(),
+				default_crash_policy: None,
+				cors_policy: None,
+				actor_rate_limit: None,
 			},
 		)
 		.await
@@ -369,6 +464,9 @@ fn create_namespace_from_follower_routes_to_leader() {
 			rivet_api_peer::namespaces::CreateRequest {
 				name: "follower-test".to_string(),
 				display_name: "Follower Test".to_string(),
+				default_crash_policy: None,
+				cors_policy: None,
+				actor_rate_limit: None,
 			},
 		)
 		.await
@@ -396,6 +494,9 @@ fn create_namespace_empty_name_fails() {
 			rivet_api_peer::namespaces::CreateRequest {
 				name: "".to_string(),
 				display_name: "Empty Name".to_string(),
+				default_crash_policy: None,
+				cors_policy: None,
+				actor_rate_limit: None,
 			},
 		)
 		.await;
@@ -416,6 +517,9 @@ fn create_namespace_min_length_name() {
 			rivet_api_peer::namespaces::CreateRequest {
 				name: "a".to_string(),
 				display_name: "Single Char".to_string(),
+				default_crash_policy: None,
+				cors_policy: None,
+				actor_rate_limit: None,
 			},
 		)
 		.await
@@ -436,6 +540,9 @@ fn create_namespace_max_length_name() {
 			rivet_api_peer::namespaces::CreateRequest {
 				name: max_name.clone(),
 				display_name: "Max Length".to_string(),
+				default_crash_policy: None,
+				cors_policy: None,
+				actor_rate_limit: None,
 			},
 		)
 		.await
@@ -41,6 +41,9 @@ fn list_namespaces_returns_all() {
 				rivet_api_peer::namespaces::CreateRequest {
 					name: format!("test-ns-{}", i),
 					display_name: format!("Test Namespace {}", i),
+					default_crash_policy: None,
+					cors_policy: None,
+					actor_rate_limit: None,
 				},
 			)
 			.await
@@ -88,6 +91,9 @@ fn list_namespaces_validates_returned_data() {
 			rivet_api_peer::namespaces::CreateRequest {
 				name: "validation-test".to_string(),
 				display_name: "Validation Test".to_string(),
+				default_crash_policy: None,
+				cors_policy: None,
+				actor_rate_limit: None,
 			},
 		)
 		.await
@@ -128,6 +134,9 @@ fn list_namespaces_ordered_by_create_ts() {
 				rivet_api_peer::namespaces::CreateRequest {
 					name: format!("ordered-{}", i),
 					display_name: format!("Ordered {}", i),
+					default_crash_policy: None,
+					cors_policy: None,
+					actor_rate_limit: None,
 				},
 			)
 			.await
@@ -169,6 +178,9 @@ fn list_namespaces_includes_default() {
 			rivet_api_peer::namespaces::CreateRequest {
 				name: "test-default".to_string(),
 				display_name: "Test Default".to_string(),
+				default_crash_policy: None,
+				cors_policy: None,
+				actor_rate_limit: None,
 			},
 		)
 		.await
@@ -206,6 +218,9 @@ fn list_namespaces_filter_by_name_exists() {
 			rivet_api_peer::namespaces::CreateRequest {
 				name: namespace_name.to_string(),
 				display_name: "Filter Test".to_string(),
+				default_crash_policy: None,
+				cors_policy: None,
+				actor_rate_limit: None,
 			},
 		)
 		.await
@@ -273,6 +288,9 @@ fn list_namespaces_filter_by_name_ignores_other_params() {
 			rivet_api_peer::namespaces::CreateRequest {
 				name: namespace_name.to_string(),
 				display_name: "Filter Ignores Test".to_string(),
+				default_crash_policy: None,
+				cors_policy: None,
+				actor_rate_limit: None,
 			},
 		)
 		.await
@@ -311,6 +329,9 @@ fn list_namespaces_filter_by_single_id() {
 			rivet_api_peer::namespaces::CreateRequest {
 				name: "filter-single-id".to_string(),
 				display_name: "Filter Single ID".to_string(),
+				default_crash_policy: None,
+				cors_policy: None,
+				actor_rate_limit: None,
 			},
 		)
 		.await
@@ -348,6 +369,9 @@ fn list_namespaces_filter_by_multiple_ids() {
 				rivet_api_peer::namespaces::CreateRequest {
 					name: format!("filter-multi-{}", i),
 					display_name: format!("Filter Multi {}", i),
+					default_crash_policy: None,
+					cors_policy: None,
+					actor_rate_limit: None,
 				},
 			)
 			.await
@@ -397,6 +421,9 @@ fn list_namespaces_filter_by_ids_with_invalid_id() {
 			rivet_api_peer::namespaces::CreateRequest {
 				name: "filter-invalid-id".to_string(),
 				display_name: "Filter Invalid ID".to_string(),
+				default_crash_policy: None,
+				cors_policy: None,
+				actor_rate_limit: None,
 			},
 		)
 		.await
@@ -464,6 +491,9 @@ fn list_namespaces_default_limit() {
 				rivet_api_peer::namespaces::CreateRequest {
 					name: format!("default-limit-{}", i),
 					display_name: format!("Default Limit {}", i),
+					default_crash_policy: None,
+					cors_policy: None,
+					actor_rate_limit: None,
 				},
 			)
 			.await
@@ -502,6 +532,9 @@ fn list_namespaces_with_limit() {
 				rivet_api_peer::namespaces::CreateRequest {
 					name: format!("limit-test-{}", i),
 					display_name: format!("Limit Test {}", i),
+					default_crash_policy: None,
+					cors_policy: None,
+					actor_rate_limit: None,
 				},
 			)
 			.await
@@ -543,6 +576,9 @@ fn list_namespaces_cursor_pagination() {
 				rivet_api_peer::namespaces::CreateRequest {
 					name: format!("cursor-test-{}", i),
 					display_name: format!("Cursor Test {}", i),
+					default_crash_policy: None,
+					cors_policy: None,
+					actor_rate_limit: None,
 				},
 			)
 			.await
@@ -602,6 +638,9 @@ fn list_namespaces_cursor_no_more_results() {
 				rivet_api_peer::namespaces::CreateRequest {
 					name: format!("no-more-{}", i),
 					display_name: format!("No More {}", i),
+					default_crash_policy: None,
+					cors_policy: None,
+					actor_rate_limit: None,
 				},
 			)
 			.await
@@ -643,6 +682,9 @@ fn list_namespaces_from_leader() {
 			rivet_api_peer::namespaces::CreateRequest {
 				name: "leader-list-test".to_string(),
 				display_name: "Leader List Test".to_string(),
+				default_crash_policy: None,
+				cors_policy: None,
+				actor_rate_limit: None,
 			},
 		)
 		.await
@@ -680,6 +722,9 @@ fn list_namespaces_from_follower_routes_to_leader() {
 			rivet_api_peer::namespaces::CreateRequest {
 				name: "follower-list-test".to_string(),
 				display_name: "Follower List Test".to_string(),
+				default_crash_policy: None,
+				cors_policy: None,
+				actor_rate_limit: None,
 			},
 		)
 		.await
@@ -749,6 +794,9 @@ fn list_namespaces_large_limit() {
 				rivet_api_peer::namespaces::CreateRequest {
 					name: format!("large-limit-{}", i),
 					display_name: format!("Large Limit {}", i),
+					default_crash_policy: None,
+					cors_policy: None,
+					actor_rate_limit: None,
 				},
 			)
 			.await
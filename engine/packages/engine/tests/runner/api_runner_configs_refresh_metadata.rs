@@ -172,7 +172,10 @@ fn refresh_metadata_invalidates_protocol_cache_before_v2_dispatch() {
 					key: Some(format!("key-{}", rand::random::<u64>())),
 					input: None,
 					runner_name_selector: runner_name.to_string(),
-					crash_policy: rivet_types::actors::CrashPolicy::Sleep,
+					crash_policy: Some(rivet_types::actors::CrashPolicy::Sleep),
+					affinity: None,
+					required_capabilities: Vec::new(),
+					tags: Default::default(),
 				},
 			)
 			.await
@@ -135,6 +135,9 @@ fn list_runners_empty_response_no_cursor() {
 			rivet_api_peer::namespaces::CreateRequest {
 				name: namespace_name.clone(),
 				display_name: "No Runners NS".to_string(),
+				default_crash_policy: None,
+				cors_policy: None,
+				actor_rate_limit: None,
 			},
 		)
 		.await
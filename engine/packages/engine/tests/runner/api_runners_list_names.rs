@@ -218,6 +218,9 @@ fn list_runner_names_returns_empty_for_empty_namespace() {
 			rivet_api_peer::namespaces::CreateRequest {
 				name: namespace_name.clone(),
 				display_name: "Empty Runners NS".to_string(),
+				default_crash_policy: None,
+				cors_policy: None,
+				actor_rate_limit: None,
 			},
 		)
 		.await
@@ -305,6 +308,9 @@ fn list_runner_names_empty_response_no_cursor() {
 			rivet_api_peer::namespaces::CreateRequest {
 				name: namespace_name.clone(),
 				display_name: "No Cursor NS".to_string(),
+				default_crash_policy: None,
+				cors_policy: None,
+				actor_rate_limit: None,
 			},
 		)
 		.await
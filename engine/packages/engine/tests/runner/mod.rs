@@ -1,16 +1,45 @@
+pub mod actors_affinity;
 pub mod actors_alarm;
+pub mod actors_capabilities;
+pub mod actors_destroy_audit;
+pub mod actors_get_batch;
+pub mod actors_hibernating_request_count;
+pub mod actors_hibernating_request_threshold;
+pub mod actors_key_reservation_dc;
+pub mod actors_key_transfer;
+pub mod actors_kv_assert;
+pub mod actors_kv_client;
 pub mod actors_kv_crud;
 pub mod actors_kv_delete_range;
 pub mod actors_kv_drop;
 pub mod actors_kv_list;
 pub mod actors_kv_misc;
 pub mod actors_lifecycle;
+pub mod actors_logs;
+pub mod actors_namespace_cors;
+pub mod actors_namespace_crash_policy;
+pub mod actors_namespace_rate_limit;
+pub mod actors_pool_metrics;
+pub mod actors_request_body_stream;
+pub mod actors_request_coalescing;
+pub mod actors_request_concurrency;
+pub mod actors_request_replay;
+pub mod actors_reschedule_pacing;
+pub mod actors_response_body_stream;
 pub mod actors_scheduling_errors;
+pub mod actors_simulate_allocation;
+pub mod actors_state_metrics;
+pub mod actors_tags;
+pub mod actors_webhook;
+pub mod actors_websocket;
 pub mod api_actors_create;
+pub mod api_actors_create_batch;
 pub mod api_actors_delete;
+pub mod api_actors_destroy_batch;
 pub mod api_actors_get_or_create;
 pub mod api_actors_list;
 pub mod api_actors_list_names;
+pub mod api_actors_request;
 pub mod api_namespaces_create;
 pub mod api_namespaces_list;
 pub mod api_runner_configs_list;
@@ -18,4 +47,20 @@ pub mod api_runner_configs_refresh_metadata;
 pub mod api_runner_configs_upsert;
 pub mod api_runners_list;
 pub mod api_runners_list_names;
+pub mod runner_auth;
+pub mod runner_connection_info;
 pub mod runner_drain_on_version;
+pub mod runner_duplicate_key;
+pub mod runner_graceful_shutdown;
+pub mod runner_metrics;
+pub mod runner_protocol_frames;
+pub mod runner_reconnect_backoff;
+pub mod runner_reconnect_grace_period;
+pub mod runner_reconnect_mid_request;
+pub mod runner_seed;
+pub mod runner_start_order;
+pub mod runner_status;
+pub mod runner_unknown_actor;
+pub mod serverless_autoscaler_pause;
+pub mod serverless_circuit_breaker;
+pub mod serverless_start_request;
@@ -0,0 +1,171 @@
+use futures_util::StreamExt;
+use tokio_tungstenite::{
+	connect_async,
+	tungstenite::{Message, client::IntoClientRequest, protocol::frame::coding::CloseCode},
+};
+
+use super::super::common;
+
+async fn create_namespace(dc: &common::TestDatacenter, namespace: &str) {
+	common::api::peer::namespaces_create(
+		dc.api_peer_port(),
+		rivet_api_peer::namespaces::CreateRequest {
+			name: namespace.to_string(),
+			display_name: "Test Namespace".to_string(),
+			default_crash_policy: None,
+			cors_policy: None,
+			actor_rate_limit: None,
+		},
+	)
+	.await
+	.expect("failed to create namespace");
+}
+
+/// Connects to the runner endpoint with the given WebSocket protocols and asserts that the engine
+/// closes the connection as unauthorized.
+async fn assert_unauthorized(dc: &common::TestDatacenter, namespace: &str, protocols: &str) {
+	let mut request = format!(
+		"ws://127.0.0.1:{}/runners/connect?protocol_version={}&namespace={}&runner_key=unauthorized",
+		dc.guard_port(),
+		common::test_runner::PROTOCOL_VERSION,
+		namespace,
+	)
+	.into_client_request()
+	.expect("failed to create runner connect request");
+	request
+		.headers_mut()
+		.insert("Sec-WebSocket-Protocol", protocols.parse().unwrap());
+
+	let (mut ws, _) = connect_async(request)
+		.await
+		.expect("failed to connect to runner endpoint");
+	let msg = tokio::time::timeout(std::time::Duration::from_secs(5), ws.next())
+		.await
+		.expect("timed out waiting for runner rejection")
+		.expect("runner websocket should close after rejection")
+		.expect("runner websocket close should not error");
+	match msg {
+		Message::Close(Some(frame)) => {
+			assert_eq!(frame.code, CloseCode::Policy);
+			assert!(
+				frame.reason.starts_with("ws.unauthorized"),
+				"close reason should be unauthorized, got {:?}",
+				frame.reason
+			);
+		}
+		other => panic!("expected runner rejection close frame, got {other:?}"),
+	}
+}
+
+/// Starts a runner with the given token. The runner config is upserted through the peer API since the
+/// public API requires the admin token when auth is enabled.
+async fn start_runner(
+	dc: &common::TestDatacenter,
+	namespace: &str,
+	token: &str,
+) -> common::test_runner::TestRunner {
+	let runner = common::test_runner::TestRunnerBuilder::new(namespace)
+		.with_runner_key(&format!("key-{:012x}", rand::random::<u64>()))
+		.with_version(1)
+		.with_total_slots(20)
+		.with_token(token)
+		.build(dc)
+		.await
+		.expect("failed to build test runner");
+
+	common::api::peer::runner_configs_upsert(
+		dc.api_peer_port(),
+		rivet_api_peer::runner_configs::UpsertPath {
+			runner_name: runner.name().to_string(),
+		},
+		rivet_api_peer::runner_configs::UpsertQuery {
+			namespace: namespace.to_string(),
+		},
+		rivet_api_peer::runner_configs::UpsertRequest(
+			rivet_api_types::namespaces::runner_configs::RunnerConfig {
+				kind: rivet_api_types::namespaces::runner_configs::RunnerConfigKind::Normal {},
+				metadata: None,
+				drain_on_version_upgrade: true,
+			},
+		),
+	)
+	.await
+	.expect("failed to upsert runner config");
+
+	runner.start().await.expect("failed to start runner");
+
+	runner
+}
+
+#[test]
+fn runner_connects_with_any_namespace_token() {
+	let namespace = format!("test-{}", rand::random::<u16>());
+
+	common::run(
+		common::TestOpts::new(1)
+			.with_timeout(30)
+			.with_runner_token(&namespace, "old", "old-token")
+			.with_runner_token(&namespace, "new", "new-token"),
+		|ctx| async move {
+			let dc = ctx.leader_dc();
+			create_namespace(dc, &namespace).await;
+
+			// Both tokens are accepted while rotating
+			let old_runner =
+				common::setup_runner(dc, &namespace, |builder| builder.with_token("old-token"))
+					.await;
+			let new_runner =
+				common::setup_runner(dc, &namespace, |builder| builder.with_token("new-token"))
+					.await;
+			assert_ne!(old_runner.wait_ready().await, new_runner.wait_ready().await);
+
+			// Namespaces without runner tokens are not affected
+			let (other_namespace, _) = common::setup_test_namespace(dc).await;
+			common::setup_runner(dc, &other_namespace, |builder| builder).await;
+		},
+	);
+}
+
+#[test]
+fn runner_connect_rejects_invalid_token() {
+	let namespace = format!("test-{}", rand::random::<u16>());
+
+	common::run(
+		common::TestOpts::new(1).with_timeout(30).with_runner_token(
+			&namespace,
+			"default",
+			"good-token",
+		),
+		|ctx| async move {
+			let dc = ctx.leader_dc();
+			create_namespace(dc, &namespace).await;
+
+			assert_unauthorized(dc, &namespace, "rivet, rivet_token.bad-token").await;
+			assert_unauthorized(dc, &namespace, "rivet").await;
+		},
+	);
+}
+
+#[test]
+fn runner_connects_with_admin_token_alongside_runner_tokens() {
+	let namespace = format!("test-{}", rand::random::<u16>());
+
+	common::run(
+		common::TestOpts::new(1)
+			.with_timeout(30)
+			.with_auth_admin_token("admin-token")
+			.with_runner_token(&namespace, "default", "good-token"),
+		|ctx| async move {
+			let dc = ctx.leader_dc();
+			create_namespace(dc, &namespace).await;
+
+			// The admin token keeps working once runner tokens are configured
+			let admin_runner = start_runner(dc, &namespace, "admin-token").await;
+			let runner = start_runner(dc, &namespace, "good-token").await;
+			assert_ne!(admin_runner.wait_ready().await, runner.wait_ready().await);
+
+			assert_unauthorized(dc, &namespace, "rivet, rivet_token.bad-token").await;
+			assert_unauthorized(dc, &namespace, "rivet").await;
+		},
+	);
+}
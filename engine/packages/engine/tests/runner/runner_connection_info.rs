@@ -0,0 +1,50 @@
+use super::super::common;
+
+#[test]
+fn runner_connection_info() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let start_ts = rivet_util::timestamp::now();
+		let (_namespace, _, runner) =
+			common::setup_test_namespace_with_runner(ctx.leader_dc()).await;
+		let runner_id: rivet_util::Id = runner
+			.wait_ready()
+			.await
+			.parse()
+			.expect("invalid runner id");
+
+		let connection = ctx
+			.leader_dc()
+			.workflow_ctx
+			.op(pegboard::ops::runner::get_connection_info::Input { runner_id })
+			.await
+			.expect("failed to get runner connection info")
+			.connection
+			.expect("connection info should exist for a connected runner");
+
+		assert_eq!(connection.runner_id, runner_id);
+		assert_eq!(
+			connection.protocol_version,
+			Some(common::test_runner::PROTOCOL_VERSION)
+		);
+
+		let connected_ts = connection
+			.connected_ts
+			.expect("connected runner should have a connect timestamp");
+		assert!(
+			connected_ts >= start_ts && connected_ts <= rivet_util::timestamp::now(),
+			"connect timestamp {connected_ts} should be during the test"
+		);
+		assert!(connection.stop_ts.is_none());
+
+		// Unknown runners have no connection info
+		let missing = ctx
+			.leader_dc()
+			.workflow_ctx
+			.op(pegboard::ops::runner::get_connection_info::Input {
+				runner_id: rivet_util::Id::new_v1(ctx.leader_dc().config.dc_label()),
+			})
+			.await
+			.expect("failed to get runner connection info");
+		assert!(missing.connection.is_none());
+	});
+}
@@ -0,0 +1,63 @@
+use super::super::common;
+
+#[test]
+fn runner_duplicate_key_connect_race_keeps_one() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let (namespace, _) = common::setup_test_namespace(ctx.leader_dc()).await;
+		let runner_key = format!("key-{:012x}", rand::random::<u64>());
+
+		let runner_a = common::test_runner::TestRunnerBuilder::new(&namespace)
+			.with_runner_key(&runner_key)
+			.build(ctx.leader_dc())
+			.await
+			.expect("failed to build test runner");
+		let runner_b = common::test_runner::TestRunnerBuilder::new(&namespace)
+			.with_runner_key(&runner_key)
+			.build(ctx.leader_dc())
+			.await
+			.expect("failed to build test runner");
+
+		common::upsert_normal_runner_config(ctx.leader_dc(), &namespace, runner_a.name()).await;
+
+		// Connect both runners with the same key at the same time
+		let (res_a, res_b) = tokio::join!(runner_a.start(), runner_b.start());
+		res_a.expect("failed to start runner a");
+		res_b.expect("failed to start runner b");
+
+		// Wait for the eviction race to settle
+		let start = std::time::Instant::now();
+		loop {
+			if runner_a.is_connected() != runner_b.is_connected() {
+				break;
+			}
+
+			assert!(
+				runner_a.is_connected(),
+				"both runners with the same key were evicted"
+			);
+
+			if start.elapsed() > std::time::Duration::from_secs(10) {
+				panic!("both runners with the same key stayed connected");
+			}
+
+			tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+		}
+
+		// The survivor must not be evicted later on
+		tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+
+		let connected = [runner_a.is_connected(), runner_b.is_connected()];
+		assert_eq!(
+			connected.iter().filter(|c| **c).count(),
+			1,
+			"exactly one runner with the same key should stay connected, got {connected:?}"
+		);
+
+		let survivor = if runner_a.is_connected() {
+			&runner_a
+		} else {
+			&runner_b
+		};
+		survivor.wait_ready().await;
+	});
+}
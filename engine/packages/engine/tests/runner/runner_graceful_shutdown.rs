@@ -0,0 +1,175 @@
+use std::{
+	sync::{
+		Arc,
+		atomic::{AtomicU32, Ordering},
+	},
+	time::Duration,
+};
+
+use super::super::common;
+use common::test_runner::ActorLifecycleEvent;
+
+#[test]
+fn runner_shutdown_finishes_in_flight_request() {
+	common::run(
+		common::TestOpts::new(1).with_timeout(30),
+		|ctx| async move {
+			let (namespace, _) = common::setup_test_namespace(ctx.leader_dc()).await;
+
+			let request_count = Arc::new(AtomicU32::new(0));
+			let request_count_clone = request_count.clone();
+
+			let runner = common::setup_runner(ctx.leader_dc(), &namespace, |builder| {
+				builder
+					.with_drain_timeout(Duration::from_secs(10))
+					.with_actor_behavior("slow-actor", move |_| {
+						Box::new(common::test_runner::SlowRequestActor::new(
+							Duration::from_secs(2),
+							request_count_clone.clone(),
+						))
+					})
+			})
+			.await;
+			let mut lifecycle_rx = runner.subscribe_lifecycle_events();
+
+			let res = common::create_actor(
+				ctx.leader_dc().guard_port(),
+				&namespace,
+				"slow-actor",
+				runner.name(),
+				rivet_types::actors::CrashPolicy::Destroy,
+			)
+			.await;
+			let actor_id = res.actor.actor_id.to_string();
+
+			common::wait_with_poll(
+				Duration::from_secs(10),
+				Duration::from_millis(50),
+				|| async { runner.has_actor(&actor_id).await.then_some(()) },
+			)
+			.await
+			.expect("actor was never allocated to the runner");
+
+			let (response, _) = tokio::join!(
+				common::ping_actor_via_guard(ctx.leader_dc(), &actor_id),
+				async {
+					common::wait_with_poll(
+						Duration::from_secs(10),
+						Duration::from_millis(20),
+						|| async { (request_count.load(Ordering::SeqCst) == 1).then_some(()) },
+					)
+					.await
+					.expect("request never reached the actor");
+
+					// Stop the runner while the actor is still holding the request
+					runner.shutdown().await;
+				},
+			);
+			assert_eq!(response["actorId"], actor_id);
+
+			let mut completed = false;
+			let abandoned = loop {
+				match tokio::time::timeout(Duration::from_secs(10), lifecycle_rx.recv())
+					.await
+					.expect("timed out waiting for runner to drain")
+					.expect("lifecycle channel closed")
+				{
+					ActorLifecycleEvent::RequestCompleted { actor_id: id } if id == actor_id => {
+						completed = true;
+					}
+					ActorLifecycleEvent::Drained { abandoned, .. } => break abandoned,
+					_ => {}
+				}
+			};
+			assert!(
+				completed,
+				"request should complete before the runner drains"
+			);
+			assert_eq!(abandoned, 0, "no requests should be abandoned");
+
+			common::wait_with_poll(
+				Duration::from_secs(5),
+				Duration::from_millis(50),
+				|| async { (!runner.is_connected()).then_some(()) },
+			)
+			.await
+			.expect("runner should disconnect after draining");
+		},
+	);
+}
+
+#[test]
+fn runner_drain_waits_for_actors_to_stop() {
+	common::run(
+		common::TestOpts::new(1).with_timeout(30),
+		|ctx| async move {
+			let (namespace, _, runner) =
+				common::setup_test_namespace_with_runner(ctx.leader_dc()).await;
+
+			let mut actor_ids = Vec::new();
+			for _ in 0..2 {
+				let res = common::create_actor(
+					ctx.leader_dc().guard_port(),
+					&namespace,
+					"test-actor",
+					runner.name(),
+					rivet_types::actors::CrashPolicy::Destroy,
+				)
+				.await;
+				actor_ids.push(res.actor.actor_id.to_string());
+			}
+
+			common::wait_with_poll(
+				Duration::from_secs(10),
+				Duration::from_millis(50),
+				|| async { (runner.get_actor_ids().await.len() == 2).then_some(()) },
+			)
+			.await
+			.expect("actors were never allocated to the runner");
+
+			let mut lifecycle_rx = runner.subscribe_lifecycle_events();
+			tokio::time::timeout(
+				Duration::from_secs(15),
+				runner.drain(Duration::from_secs(10)),
+			)
+			.await
+			.expect("timed out waiting for runner to drain");
+
+			// The drain returns once the runner has stopped, so every event is already buffered
+			let mut events = Vec::new();
+			while let Ok(event) = lifecycle_rx.try_recv() {
+				events.push(event);
+			}
+
+			assert!(
+				matches!(
+					events.first(),
+					Some(ActorLifecycleEvent::Draining { actors: 2 })
+				),
+				"drain should start with both actors running: {events:?}"
+			);
+			for actor_id in &actor_ids {
+				assert!(
+					events.iter().any(|event| matches!(
+						event,
+						ActorLifecycleEvent::Stopped { actor_id: id, .. } if id == actor_id
+					)),
+					"actor {actor_id} should stop before the runner drains: {events:?}"
+				);
+			}
+			assert!(
+				matches!(
+					events.last(),
+					Some(ActorLifecycleEvent::Drained {
+						abandoned: 0,
+						remaining_actors: 0,
+					})
+				),
+				"drain should finish with no remaining work: {events:?}"
+			);
+
+			assert_eq!(runner.status(), common::test_runner::RunnerStatus::Stopped);
+			assert!(!runner.is_connected());
+		},
+	);
+}
@@ -0,0 +1,82 @@
+use std::{sync::Arc, time::Duration};
+
+use super::super::common;
+use common::test_runner::CounterMetrics;
+
+#[test]
+fn runner_metrics_track_requests_and_actors() {
+	common::run(
+		common::TestOpts::new(1).with_timeout(30),
+		|ctx| async move {
+			let (namespace, _) = common::setup_test_namespace(ctx.leader_dc()).await;
+
+			let metrics = Arc::new(CounterMetrics::default());
+			let runner = common::setup_runner(ctx.leader_dc(), &namespace, |builder| {
+				builder.with_metrics(metrics.clone())
+			})
+			.await;
+
+			let mut actor_ids = Vec::new();
+			for _ in 0..2 {
+				let res = common::create_actor(
+					ctx.leader_dc().guard_port(),
+					&namespace,
+					"test-actor",
+					runner.name(),
+					rivet_types::actors::CrashPolicy::Destroy,
+				)
+				.await;
+				actor_ids.push(res.actor.actor_id);
+			}
+
+			common::wait_with_poll(
+				Duration::from_secs(10),
+				Duration::from_millis(50),
+				|| async { (metrics.active_actors() == 2).then_some(()) },
+			)
+			.await
+			.expect("metrics should report both actors as active");
+
+			for actor_id in &actor_ids {
+				for _ in 0..3 {
+					common::ping_actor_via_guard(ctx.leader_dc(), &actor_id.to_string()).await;
+				}
+			}
+
+			common::wait_with_poll(
+				Duration::from_secs(5),
+				Duration::from_millis(20),
+				|| async { (metrics.requests_handled() == 6).then_some(()) },
+			)
+			.await
+			.unwrap_or_else(|| {
+				panic!(
+					"metrics should count every request, got {}",
+					metrics.requests_handled()
+				)
+			});
+			assert!(metrics.frames_sent() > 0);
+			assert!(metrics.frames_received() > 0);
+
+			common::api::public::actors_delete(
+				ctx.leader_dc().guard_port(),
+				common::api_types::actors::delete::DeletePath {
+					actor_id: actor_ids[0],
+				},
+				common::api_types::actors::delete::DeleteQuery {
+					namespace: namespace.clone(),
+				},
+			)
+			.await
+			.expect("failed to delete actor");
+
+			common::wait_with_poll(
+				Duration::from_secs(10),
+				Duration::from_millis(50),
+				|| async { (metrics.active_actors() == 1).then_some(()) },
+			)
+			.await
+			.expect("metrics should drop the destroyed actor");
+		},
+	);
+}
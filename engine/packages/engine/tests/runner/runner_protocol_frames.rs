@@ -0,0 +1,96 @@
+use super::super::common;
+use common::test_runner::protocol_types::mk2;
+
+#[test]
+fn runner_records_stop_actor_frames() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let (namespace, _, runner) =
+			common::setup_test_namespace_with_runner(ctx.leader_dc()).await;
+
+		let res = common::create_actor(
+			ctx.leader_dc().guard_port(),
+			&namespace,
+			"test-actor",
+			runner.name(),
+			rivet_types::actors::CrashPolicy::Destroy,
+		)
+		.await;
+		let actor_id = res.actor.actor_id.to_string();
+
+		runner
+			.assert_received(
+				|frame| {
+					has_command(frame, &actor_id, |command| {
+						matches!(command, mk2::Command::CommandStartActor(_))
+					})
+				},
+				std::time::Duration::from_secs(10),
+			)
+			.await;
+
+		common::api::public::actors_delete(
+			ctx.leader_dc().guard_port(),
+			common::api_types::actors::delete::DeletePath {
+				actor_id: res.actor.actor_id,
+			},
+			common::api_types::actors::delete::DeleteQuery {
+				namespace: namespace.clone(),
+			},
+		)
+		.await
+		.expect("failed to delete actor");
+
+		runner
+			.assert_received(
+				|frame| {
+					has_command(frame, &actor_id, |command| {
+						matches!(command, mk2::Command::CommandStopActor)
+					})
+				},
+				std::time::Duration::from_secs(10),
+			)
+			.await;
+
+		// Stopping the actor reports its new state after acking the command
+		let stopped = |frame: &mk2::ToServer| match frame {
+			mk2::ToServer::ToServerEvents(events) => events.iter().any(|event| {
+				event.checkpoint.actor_id == actor_id
+					&& matches!(
+						event.inner,
+						mk2::Event::EventActorStateUpdate(mk2::EventActorStateUpdate {
+							state: mk2::ActorState::ActorStateStopped(_),
+						})
+					)
+			}),
+			_ => false,
+		};
+		common::wait_with_poll(
+			std::time::Duration::from_secs(5),
+			std::time::Duration::from_millis(25),
+			|| async { runner.has_sent(&stopped).then_some(()) },
+		)
+		.await
+		.expect("runner should report the actor stopped");
+
+		runner.assert_sent(|frame| match frame {
+			mk2::ToServer::ToServerAckCommands(ack) => ack
+				.last_command_checkpoints
+				.iter()
+				.any(|checkpoint| checkpoint.actor_id == actor_id),
+			_ => false,
+		});
+	});
+}
+
+fn has_command(
+	frame: &mk2::ToClient,
+	actor_id: &str,
+	predicate: impl Fn(&mk2::Command) -> bool,
+) -> bool {
+	match frame {
+		mk2::ToClient::ToClientCommands(commands) => commands
+			.iter()
+			.any(|command| command.checkpoint.actor_id == actor_id && predicate(&command.inner)),
+		_ => false,
+	}
+}
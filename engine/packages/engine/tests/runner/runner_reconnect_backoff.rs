@@ -0,0 +1,93 @@
+use std::time::Duration;
+
+use super::super::common;
+use common::test_runner::{ReconnectPolicy, RunnerStatus};
+
+#[test]
+fn runner_reconnects_after_connection_is_lost() {
+	common::run(
+		common::TestOpts::new(1).with_timeout(45),
+		|ctx| async move {
+			let dc = ctx.leader_dc();
+			let (namespace, _) = common::setup_test_namespace(dc).await;
+
+			let proxy = common::freeze_proxy::FreezeProxy::start(std::net::SocketAddr::from((
+				[127, 0, 0, 1],
+				dc.guard_port(),
+			)))
+			.await
+			.expect("failed to start freeze proxy");
+
+			let runner = common::setup_runner(dc, &namespace, |builder| {
+				builder
+					.with_endpoint(proxy.endpoint())
+					.with_reconnect_policy(ReconnectPolicy {
+						initial_backoff: Duration::from_millis(100),
+						max_backoff: Duration::from_secs(1),
+						jitter: 0.2,
+						max_attempts: Some(5),
+					})
+					.with_actor_behavior("test-actor", |_| {
+						Box::new(common::test_runner::EchoActor::new())
+					})
+			})
+			.await;
+			let runner_id = runner.wait_ready().await;
+
+			let res = common::create_actor(
+				dc.guard_port(),
+				&namespace,
+				"test-actor",
+				runner.name(),
+				rivet_types::actors::CrashPolicy::Destroy,
+			)
+			.await;
+			let actor_id = res.actor.actor_id.to_string();
+
+			common::wait_with_poll(
+				Duration::from_secs(10),
+				Duration::from_millis(50),
+				|| async { runner.has_actor(&actor_id).await.then_some(()) },
+			)
+			.await
+			.expect("actor was never allocated to the runner");
+
+			// Drop the connection without a close frame, like a network failure
+			let mut status_rx = runner.subscribe_status();
+			proxy.sever();
+
+			tokio::time::timeout(
+				Duration::from_secs(5),
+				status_rx.wait_for(|status| *status == RunnerStatus::Reconnecting),
+			)
+			.await
+			.expect("runner should report reconnecting after losing its connection")
+			.expect("status channel closed");
+			tokio::time::timeout(
+				Duration::from_secs(10),
+				status_rx.wait_for(|status| *status == RunnerStatus::Connected),
+			)
+			.await
+			.expect("runner should reconnect")
+			.expect("status channel closed");
+
+			assert_eq!(
+				runner.count_sent(|frame| matches!(
+					frame,
+					common::test_runner::protocol_types::mk2::ToServer::ToServerInit(_)
+				)),
+				2,
+				"runner should have reconnected once"
+			);
+			assert_eq!(
+				runner.wait_ready().await,
+				runner_id,
+				"reconnected runner should resume the existing runner"
+			);
+
+			let response = common::ping_actor_via_guard(dc, &actor_id).await;
+			assert_eq!(response["actorId"], actor_id);
+			assert_eq!(response["status"], "ok");
+		},
+	);
+}
@@ -0,0 +1,113 @@
+use super::super::common;
+
+#[test]
+fn runner_reconnect_within_grace_period_keeps_actors() {
+	common::run(
+		common::TestOpts::new(1)
+			.with_timeout(45)
+			.with_runner_reconnect_grace_period(5_000),
+		|ctx| async move {
+			let (namespace, _) = common::setup_test_namespace(ctx.leader_dc()).await;
+			let runner_key = format!("key-{:012x}", rand::random::<u64>());
+			let pb = ctx.leader_dc().config.pegboard();
+
+			let runner_a = common::setup_runner(ctx.leader_dc(), &namespace, |builder| {
+				builder
+					.with_runner_key(&runner_key)
+					.with_actor_behavior("test-actor", |_| {
+						Box::new(common::test_runner::EchoActor::new())
+					})
+			})
+			.await;
+			let runner_id: rivet_util::Id = runner_a
+				.wait_ready()
+				.await
+				.parse()
+				.expect("invalid runner id");
+
+			let res = common::create_actor(
+				ctx.leader_dc().guard_port(),
+				&namespace,
+				"test-actor",
+				runner_a.name(),
+				rivet_types::actors::CrashPolicy::Restart,
+			)
+			.await;
+			let actor_id = res.actor.actor_id;
+
+			common::wait_with_poll(
+				std::time::Duration::from_secs(10),
+				std::time::Duration::from_millis(50),
+				|| async {
+					runner_a
+						.has_actor(&actor_id.to_string())
+						.await
+						.then_some(())
+				},
+			)
+			.await
+			.expect("actor was never allocated to the runner");
+
+			// Drop the connection and stay away past the runner lost threshold, but within the grace period
+			runner_a.crash().await;
+			tokio::time::sleep(std::time::Duration::from_millis(
+				(pb.runner_lost_threshold() + pb.runner_reconnect_grace_period() / 2)
+					.try_into()
+					.unwrap(),
+			))
+			.await;
+
+			// Reconnect with the same key
+			let runner_b = common::test_runner::TestRunnerBuilder::new(&namespace)
+				.with_runner_name(runner_a.name())
+				.with_runner_key(&runner_key)
+				.with_version(1)
+				.with_total_slots(20)
+				.build(ctx.leader_dc())
+				.await
+				.expect("failed to build test runner");
+			runner_b.start().await.expect("failed to start runner");
+
+			let reconnected_runner_id: rivet_util::Id = runner_b
+				.wait_ready()
+				.await
+				.parse()
+				.expect("invalid runner id");
+			assert_eq!(
+				reconnected_runner_id, runner_id,
+				"reconnecting runner should resume the existing runner"
+			);
+
+			// Wait until the original connection would have expired without the grace period
+			tokio::time::sleep(std::time::Duration::from_millis(
+				pb.runner_reconnect_grace_period().try_into().unwrap(),
+			))
+			.await;
+
+			let actor_runner = ctx
+				.leader_dc()
+				.workflow_ctx
+				.op(pegboard::ops::actor::get_for_runner::Input { actor_id })
+				.await
+				.expect("failed to get actor runner")
+				.expect("actor should still be allocated");
+			assert_eq!(
+				actor_runner.runner_id, runner_id,
+				"actor should stay allocated to the resumed runner"
+			);
+
+			let actor = common::try_get_actor(
+				ctx.leader_dc().guard_port(),
+				&actor_id.to_string(),
+				&namespace,
+			)
+			.await
+			.expect("failed to get actor")
+			.expect("actor should exist");
+			assert!(
+				actor.pending_allocation_ts.is_none() && actor.reschedule_ts.is_none(),
+				"actor should not be rescheduled, got {actor:?}"
+			);
+		},
+	);
+}
@@ -0,0 +1,55 @@
+use super::super::common;
+
+#[test]
+fn request_survives_runner_reconnect_mid_request() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let (namespace, _) = common::setup_test_namespace(ctx.leader_dc()).await;
+
+		let runner = common::setup_runner(ctx.leader_dc(), &namespace, |builder| {
+			builder.with_actor_behavior("reconnect-actor", |_| {
+				Box::new(common::test_runner::ReconnectMidRequestActor::new())
+			})
+		})
+		.await;
+		let runner_id = runner.wait_ready().await;
+
+		let res = common::create_actor(
+			ctx.leader_dc().guard_port(),
+			&namespace,
+			"reconnect-actor",
+			runner.name(),
+			rivet_types::actors::CrashPolicy::Destroy,
+		)
+		.await;
+		let actor_id = res.actor.actor_id.to_string();
+
+		common::wait_with_poll(
+			std::time::Duration::from_secs(10),
+			std::time::Duration::from_millis(50),
+			|| async { runner.has_actor(&actor_id).await.then_some(()) },
+		)
+		.await
+		.expect("actor was never allocated to the runner");
+
+		// The runner drops its connection before responding, so the response comes back on a new
+		// connection
+		let response = common::ping_actor_via_guard(ctx.leader_dc(), &actor_id).await;
+		assert_eq!(response["actorId"], actor_id);
+		assert_eq!(response["status"], "ok");
+
+		assert_eq!(
+			runner.count_sent(|frame| matches!(
+				frame,
+				common::test_runner::protocol_types::mk2::ToServer::ToServerInit(_)
+			)),
+			2,
+			"runner should have reconnected once"
+		);
+		assert!(runner.is_connected());
+		assert_eq!(
+			runner.wait_ready().await,
+			runner_id,
+			"reconnected runner should resume the existing runner"
+		);
+	});
+}
@@ -0,0 +1,80 @@
+use std::sync::{Arc, Mutex};
+
+use rand::Rng;
+
+use super::super::common;
+
+const ACTOR_COUNT: usize = 5;
+
+/// Starts actors one at a time on a runner with the given seed. Each actor draws a start jitter
+/// from its RNG, and the draws are returned in start order.
+async fn run_seeded_scenario(ctx: &common::TestCtx, seed: u64) -> Vec<u64> {
+	let (namespace, _) = common::setup_test_namespace(ctx.leader_dc()).await;
+
+	let draws = Arc::new(Mutex::new(Vec::new()));
+	let runner = common::setup_runner(ctx.leader_dc(), &namespace, |builder| {
+		let draws = draws.clone();
+		builder
+			.with_seed(seed)
+			.with_actor_behavior("jitter-actor", move |_| {
+				let draws = draws.clone();
+				Box::new(
+					common::test_runner::CustomActorBuilder::new()
+						.on_start(move |config| {
+							let draws = draws.clone();
+							Box::pin(async move {
+								let jitter_ms = config.rng().gen_range(0..50);
+								draws.lock().expect("draws lock").push(jitter_ms);
+								tokio::time::sleep(std::time::Duration::from_millis(jitter_ms))
+									.await;
+								Ok(common::test_runner::ActorStartResult::Running)
+							})
+						})
+						.build(),
+				)
+			})
+	})
+	.await;
+
+	for i in 0..ACTOR_COUNT {
+		let res = common::create_actor(
+			ctx.leader_dc().guard_port(),
+			&namespace,
+			"jitter-actor",
+			runner.name(),
+			rivet_types::actors::CrashPolicy::Destroy,
+		)
+		.await;
+		let actor_id = res.actor.actor_id.to_string();
+
+		// Wait for each actor so they start in the same order every run
+		common::wait_with_poll(
+			std::time::Duration::from_secs(10),
+			std::time::Duration::from_millis(50),
+			|| async { runner.has_actor(&actor_id).await.then_some(()) },
+		)
+		.await
+		.unwrap_or_else(|| panic!("actor {i} never started"));
+	}
+
+	runner.shutdown().await;
+
+	let draws = draws.lock().expect("draws lock").clone();
+	assert_eq!(draws.len(), ACTOR_COUNT);
+	draws
+}
+
+#[test]
+fn runner_seed_makes_runs_reproducible() {
+	common::run(
+		common::TestOpts::new(1).with_timeout(60),
+		|ctx| async move {
+			let first = run_seeded_scenario(&ctx, 42).await;
+			let second = run_seeded_scenario(&ctx, 42).await;
+			assert_eq!(first, second, "same seed should replay the same draws");
+
+			let other = run_seeded_scenario(&ctx, 7).await;
+			assert_ne!(first, other, "different seeds should diverge");
+		},
+	);
+}
@@ -0,0 +1,44 @@
+use super::super::common;
+
+#[test]
+fn runner_announces_actors_in_start_order() {
+	common::run(common::TestOpts::new(1), |ctx| async move {
+		let (namespace, _) = common::setup_test_namespace(ctx.leader_dc()).await;
+
+		let runner = common::setup_runner(ctx.leader_dc(), &namespace, |builder| {
+			let mut builder = builder;
+			for name in ["alpha", "beta", "gamma"] {
+				builder = builder
+					.with_actor_behavior(name, |_| Box::new(common::test_runner::EchoActor::new()));
+			}
+			builder.start_order(vec!["gamma", "alpha"])
+		})
+		.await;
+
+		let response = common::api::public::runners_list(
+			ctx.leader_dc().guard_port(),
+			rivet_api_types::runners::list::ListQuery {
+				namespace: namespace.clone(),
+				name: Some(runner.name().to_string()),
+				runner_ids: None,
+				runner_id: vec![],
+				include_stopped: None,
+				limit: None,
+				cursor: None,
+			},
+		)
+		.await
+		.expect("failed to list runners");
+		assert_eq!(response.runners.len(), 1);
+
+		// Listed actors come first, the rest follow in registration order
+		let metadata = response.runners[0]
+			.metadata
+			.as_ref()
+			.expect("runner should have metadata");
+		assert_eq!(
+			metadata.get("actor_names"),
+			Some(&serde_json::json!(["gamma", "alpha", "beta"]))
+		);
+	});
+}
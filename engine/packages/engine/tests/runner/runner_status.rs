@@ -0,0 +1,50 @@
+use std::time::Duration;
+
+use super::super::common;
+use common::test_runner::RunnerStatus;
+
+#[test]
+fn runner_status_tracks_connection() {
+	common::run(
+		common::TestOpts::new(1).with_timeout(30),
+		|ctx| async move {
+			let (namespace, _) = common::setup_test_namespace(ctx.leader_dc()).await;
+
+			let runner = common::setup_runner(ctx.leader_dc(), &namespace, |builder| builder).await;
+			runner
+				.wait_connected(Duration::from_secs(5))
+				.await
+				.expect("runner should connect");
+			assert_eq!(runner.status(), RunnerStatus::Connected);
+
+			// Drop the socket out from under the runner
+			runner.disconnect();
+			common::wait_with_poll(
+				Duration::from_secs(5),
+				Duration::from_millis(10),
+				|| async { (runner.status() == RunnerStatus::Reconnecting).then_some(()) },
+			)
+			.await
+			.expect("runner should report reconnecting after losing its socket");
+
+			runner
+				.wait_connected(Duration::from_secs(10))
+				.await
+				.expect("runner should reconnect");
+			assert_eq!(runner.status(), RunnerStatus::Connected);
+
+			runner.shutdown().await;
+			common::wait_with_poll(
+				Duration::from_secs(5),
+				Duration::from_millis(50),
+				|| async { (runner.status() == RunnerStatus::Stopped).then_some(()) },
+			)
+			.await
+			.expect("runner should report stopped after shutdown");
+			runner
+				.wait_connected(Duration::from_secs(1))
+				.await
+				.expect_err("stopped runner should not report connected");
+		},
+	);
+}
@@ -0,0 +1,124 @@
+use std::{
+	sync::{
+		Arc,
+		atomic::{AtomicBool, Ordering},
+	},
+	time::Duration,
+};
+
+use super::super::common;
+use common::test_runner::{ActorLifecycleEvent, UnknownActorPolicy};
+
+async fn wait_for_unknown_actor_event(
+	lifecycle_rx: &mut tokio::sync::broadcast::Receiver<ActorLifecycleEvent>,
+	actor_id: &str,
+) -> (String, bool) {
+	loop {
+		match tokio::time::timeout(Duration::from_secs(10), lifecycle_rx.recv())
+			.await
+			.expect("timed out waiting for unknown actor event")
+			.expect("lifecycle channel closed")
+		{
+			ActorLifecycleEvent::UnknownActor {
+				actor_id: id,
+				name,
+				rejected,
+			} if id == actor_id => return (name, rejected),
+			_ => {}
+		}
+	}
+}
+
+#[test]
+fn unknown_actor_is_rejected() {
+	common::run(
+		common::TestOpts::new(1).with_timeout(30),
+		|ctx| async move {
+			let (namespace, _) = common::setup_test_namespace(ctx.leader_dc()).await;
+
+			let runner = common::setup_runner(ctx.leader_dc(), &namespace, |builder| {
+				builder.with_unknown_actor_policy(UnknownActorPolicy::Reject)
+			})
+			.await;
+			let mut lifecycle_rx = runner.subscribe_lifecycle_events();
+
+			let res = common::create_actor(
+				ctx.leader_dc().guard_port(),
+				&namespace,
+				"unregistered-actor",
+				runner.name(),
+				rivet_types::actors::CrashPolicy::Destroy,
+			)
+			.await;
+			let actor_id = res.actor.actor_id.to_string();
+
+			let (name, rejected) = wait_for_unknown_actor_event(&mut lifecycle_rx, &actor_id).await;
+			assert_eq!(name, "unregistered-actor");
+			assert!(rejected, "unknown actor should be rejected");
+			assert!(!runner.has_actor(&actor_id).await);
+
+			// The rejection reaches the engine as a failed stop, so the actor is destroyed
+			common::wait_with_poll(
+				Duration::from_secs(10),
+				Duration::from_millis(100),
+				|| async {
+					common::try_get_actor(ctx.leader_dc().guard_port(), &actor_id, &namespace)
+						.await
+						.ok()
+						.flatten()
+						.and_then(|actor| actor.destroy_ts)
+				},
+			)
+			.await
+			.expect("rejected actor should be destroyed");
+		},
+	);
+}
+
+#[test]
+fn unknown_actor_uses_fallback() {
+	common::run(
+		common::TestOpts::new(1).with_timeout(30),
+		|ctx| async move {
+			let (namespace, _) = common::setup_test_namespace(ctx.leader_dc()).await;
+
+			let fallback_used = Arc::new(AtomicBool::new(false));
+			let fallback_used_clone = fallback_used.clone();
+			let runner = common::setup_runner(ctx.leader_dc(), &namespace, |builder| {
+				builder.with_unknown_actor_policy(UnknownActorPolicy::Fallback(Arc::new(
+					move |_| {
+						fallback_used_clone.store(true, Ordering::SeqCst);
+						Box::new(common::test_runner::EchoActor::new())
+					},
+				)))
+			})
+			.await;
+			let mut lifecycle_rx = runner.subscribe_lifecycle_events();
+
+			let res = common::create_actor(
+				ctx.leader_dc().guard_port(),
+				&namespace,
+				"unregistered-actor",
+				runner.name(),
+				rivet_types::actors::CrashPolicy::Destroy,
+			)
+			.await;
+			let actor_id = res.actor.actor_id.to_string();
+
+			let (_, rejected) = wait_for_unknown_actor_event(&mut lifecycle_rx, &actor_id).await;
+			assert!(!rejected, "unknown actor should fall back");
+
+			common::wait_with_poll(
+				Duration::from_secs(10),
+				Duration::from_millis(50),
+				|| async { runner.has_actor(&actor_id).await.then_some(()) },
+			)
+			.await
+			.expect("fallback actor should start");
+			assert!(fallback_used.load(Ordering::SeqCst));
+
+			let response = common::ping_actor_via_guard(ctx.leader_dc(), &actor_id).await;
+			assert_eq!(response["actorId"], actor_id);
+		},
+	);
+}
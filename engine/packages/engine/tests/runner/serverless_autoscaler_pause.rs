@@ -0,0 +1,165 @@
+use super::super::common;
+
+use serde_json::json;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
+
+/// Starts a mock serverless endpoint that keeps every SSE stream open and counts connections.
+async fn start_mock_serverless() -> (SocketAddr, tokio::task::JoinHandle<()>, Arc<AtomicU32>) {
+	use axum::{
+		Router,
+		body::Body,
+		http::{Response, StatusCode, header},
+		routing::get,
+	};
+
+	let connection_count = Arc::new(AtomicU32::new(0));
+	let connection_count_clone = connection_count.clone();
+
+	let router = Router::new().route(
+		"/start",
+		get(move || {
+			let connection_count = connection_count_clone.clone();
+			async move {
+				connection_count.fetch_add(1, Ordering::SeqCst);
+
+				Response::builder()
+					.status(StatusCode::OK)
+					.header(header::CONTENT_TYPE, "text/event-stream")
+					.header(header::CACHE_CONTROL, "no-cache")
+					.body(Body::from_stream(futures_util::stream::pending::<
+						Result<&'static [u8], std::io::Error>,
+					>()))
+					.unwrap()
+			}
+		}),
+	);
+
+	let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+	let addr = listener.local_addr().unwrap();
+
+	let handle = tokio::spawn(async move {
+		axum::serve(listener, router).await.unwrap();
+	});
+
+	(addr, handle, connection_count)
+}
+
+async fn create_serverless_runner_config(
+	guard_port: u16,
+	namespace: &str,
+	runner_name: &str,
+	serverless_url: &str,
+) {
+	let client = reqwest::Client::new();
+	let response = client
+		.put(format!(
+			"http://127.0.0.1:{}/runner-configs/{}?namespace={}",
+			guard_port, runner_name, namespace
+		))
+		.json(&json!({
+			"datacenters": {
+				"dc-1": {
+					"serverless": {
+						"url": serverless_url,
+						"max_runners": 4,
+						"slots_per_runner": 1,
+						"request_lifespan": 300,
+					}
+				}
+			}
+		}))
+		.send()
+		.await
+		.unwrap();
+
+	if !response.status().is_success() {
+		let text = response.text().await.unwrap();
+		panic!("failed to create runner config: {}", text);
+	}
+}
+
+async fn create_actor(guard_port: u16, namespace: &str, runner_name: &str, key: &str) {
+	let client = reqwest::Client::new();
+	let response = client
+		.post(format!(
+			"http://127.0.0.1:{}/actors?namespace={}",
+			guard_port, namespace
+		))
+		.json(&json!({
+			"name": "test",
+			"key": key,
+			"crash_policy": "sleep",
+			"runner_name_selector": runner_name,
+		}))
+		.send()
+		.await
+		.unwrap();
+
+	if !response.status().is_success() {
+		let text = response.text().await.unwrap();
+		panic!("failed to create actor: {}", text);
+	}
+}
+
+/// Tests that pausing the serverless autoscaler for a namespace holds the connection count while
+/// desired slots change, and that scaling resumes once unpaused.
+#[test]
+fn serverless_autoscaler_pause_holds_connection_count() {
+	common::run(
+		common::TestOpts::new(1).with_timeout(45),
+		|ctx| async move {
+			let guard_port = ctx.leader_dc().guard_port();
+			let api_peer_port = ctx.leader_dc().api_peer_port();
+			let (namespace, namespace_id) = common::setup_test_namespace(ctx.leader_dc()).await;
+
+			let (mock_addr, _mock_handle, connection_count) = start_mock_serverless().await;
+			let serverless_url = format!("http://{}", mock_addr);
+
+			common::api::peer::set_serverless_autoscaler_paused(
+				api_peer_port,
+				rivet_api_peer::internal::SetServerlessAutoscalerPausedRequest {
+					namespace_id,
+					paused: true,
+				},
+			)
+			.await
+			.expect("failed to pause autoscaler");
+
+			let runner_name = format!("serverless-paused-{}", rand::random::<u16>());
+			create_serverless_runner_config(guard_port, &namespace, &runner_name, &serverless_url)
+				.await;
+
+			// Increase desired slots while paused
+			create_actor(guard_port, &namespace, &runner_name, "a").await;
+			create_actor(guard_port, &namespace, &runner_name, "b").await;
+
+			tokio::time::sleep(Duration::from_secs(3)).await;
+			assert_eq!(
+				connection_count.load(Ordering::SeqCst),
+				0,
+				"no connections should be opened while the autoscaler is paused"
+			);
+
+			common::api::peer::set_serverless_autoscaler_paused(
+				api_peer_port,
+				rivet_api_peer::internal::SetServerlessAutoscalerPausedRequest {
+					namespace_id,
+					paused: false,
+				},
+			)
+			.await
+			.expect("failed to resume autoscaler");
+
+			let start = std::time::Instant::now();
+			while connection_count.load(Ordering::SeqCst) == 0 {
+				if start.elapsed() > Duration::from_secs(10) {
+					panic!("expected connections to open after the autoscaler was resumed");
+				}
+				tokio::time::sleep(Duration::from_millis(200)).await;
+			}
+		},
+	);
+}
@@ -0,0 +1,138 @@
+use super::super::common;
+
+use serde_json::json;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
+
+/// Starts a mock serverless endpoint that fails every request and counts them.
+async fn start_failing_serverless() -> (SocketAddr, tokio::task::JoinHandle<()>, Arc<AtomicU32>) {
+	use axum::{Router, http::StatusCode, routing::get};
+
+	let request_count = Arc::new(AtomicU32::new(0));
+	let request_count_clone = request_count.clone();
+
+	let router = Router::new().route(
+		"/start",
+		get(move || {
+			let request_count = request_count_clone.clone();
+			async move {
+				request_count.fetch_add(1, Ordering::SeqCst);
+
+				(StatusCode::INTERNAL_SERVER_ERROR, "misconfigured")
+			}
+		}),
+	);
+
+	let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+	let addr = listener.local_addr().unwrap();
+
+	let handle = tokio::spawn(async move {
+		axum::serve(listener, router).await.unwrap();
+	});
+
+	(addr, handle, request_count)
+}
+
+async fn create_serverless_runner_config(
+	guard_port: u16,
+	namespace: &str,
+	runner_name: &str,
+	serverless_url: &str,
+) {
+	let client = reqwest::Client::new();
+	let response = client
+		.put(format!(
+			"http://127.0.0.1:{}/runner-configs/{}?namespace={}",
+			guard_port, runner_name, namespace
+		))
+		.json(&json!({
+			"datacenters": {
+				"dc-1": {
+					"serverless": {
+						"url": serverless_url,
+						"max_runners": 4,
+						"slots_per_runner": 1,
+						"request_lifespan": 300,
+					}
+				}
+			}
+		}))
+		.send()
+		.await
+		.unwrap();
+
+	if !response.status().is_success() {
+		let text = response.text().await.unwrap();
+		panic!("failed to create runner config: {}", text);
+	}
+}
+
+async fn create_actor(guard_port: u16, namespace: &str, runner_name: &str, key: &str) {
+	let client = reqwest::Client::new();
+	let response = client
+		.post(format!(
+			"http://127.0.0.1:{}/actors?namespace={}",
+			guard_port, namespace
+		))
+		.json(&json!({
+			"name": "test",
+			"key": key,
+			"crash_policy": "sleep",
+			"runner_name_selector": runner_name,
+		}))
+		.send()
+		.await
+		.unwrap();
+
+	if !response.status().is_success() {
+		let text = response.text().await.unwrap();
+		panic!("failed to create actor: {}", text);
+	}
+}
+
+/// Tests that the circuit breaker stops outbound requests to a serverless endpoint that keeps failing.
+#[test]
+fn serverless_circuit_breaker_stops_requests_to_failing_endpoint() {
+	common::run(
+		common::TestOpts::new(1)
+			.with_timeout(45)
+			.with_serverless_circuit_breaker(2, 60_000),
+		|ctx| async move {
+			let guard_port = ctx.leader_dc().guard_port();
+			let (namespace, _) = common::setup_test_namespace(ctx.leader_dc()).await;
+
+			let (mock_addr, _mock_handle, request_count) = start_failing_serverless().await;
+			let serverless_url = format!("http://{}", mock_addr);
+
+			let runner_name = format!("serverless-failing-{}", rand::random::<u16>());
+			create_serverless_runner_config(guard_port, &namespace, &runner_name, &serverless_url)
+				.await;
+
+			// Each actor opens a connection to the endpoint
+			create_actor(guard_port, &namespace, &runner_name, "a").await;
+			create_actor(guard_port, &namespace, &runner_name, "b").await;
+
+			common::wait_with_poll(
+				Duration::from_secs(10),
+				Duration::from_millis(100),
+				|| async { (request_count.load(Ordering::SeqCst) >= 2).then_some(()) },
+			)
+			.await
+			.expect("connections should have sent requests to the endpoint");
+
+			// Give the error tracker time to open the circuit
+			tokio::time::sleep(Duration::from_secs(1)).await;
+			let count_after_open = request_count.load(Ordering::SeqCst);
+
+			// Without the circuit breaker, connections would retry with backoff during this time
+			tokio::time::sleep(Duration::from_secs(8)).await;
+			assert_eq!(
+				request_count.load(Ordering::SeqCst),
+				count_after_open,
+				"no requests should be sent while the circuit breaker is open"
+			);
+		},
+	);
+}
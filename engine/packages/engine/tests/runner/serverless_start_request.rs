@@ -0,0 +1,238 @@
+use super::super::common;
+
+use serde_json::json;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+struct StartRequest {
+	method: String,
+	authorization: Option<String>,
+	content_type: Option<String>,
+	body: String,
+}
+
+/// Starts a mock serverless endpoint that records every `/start` request it receives.
+async fn start_recording_serverless() -> (
+	SocketAddr,
+	tokio::task::JoinHandle<()>,
+	Arc<Mutex<Vec<StartRequest>>>,
+) {
+	use axum::{
+		Router,
+		http::{HeaderMap, Method, StatusCode, header},
+		routing::any,
+	};
+
+	let requests = Arc::new(Mutex::new(Vec::new()));
+	let requests_clone = requests.clone();
+
+	let router = Router::new().route(
+		"/start",
+		any(move |method: Method, headers: HeaderMap, body: String| {
+			let requests = requests_clone.clone();
+			async move {
+				let get_header = |name: header::HeaderName| {
+					headers
+						.get(name)
+						.and_then(|v| v.to_str().ok())
+						.map(ToString::to_string)
+				};
+				requests.lock().unwrap().push(StartRequest {
+					method: method.to_string(),
+					authorization: get_header(header::AUTHORIZATION),
+					content_type: get_header(header::CONTENT_TYPE),
+					body,
+				});
+
+				(StatusCode::INTERNAL_SERVER_ERROR, "not a runner")
+			}
+		}),
+	);
+
+	let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+	let addr = listener.local_addr().unwrap();
+
+	let handle = tokio::spawn(async move {
+		axum::serve(listener, router).await.unwrap();
+	});
+
+	(addr, handle, requests)
+}
+
+async fn upsert_serverless_runner_config(
+	guard_port: u16,
+	namespace: &str,
+	runner_name: &str,
+	serverless: serde_json::Value,
+) -> reqwest::Response {
+	reqwest::Client::new()
+		.put(format!(
+			"http://127.0.0.1:{}/runner-configs/{}?namespace={}",
+			guard_port, runner_name, namespace
+		))
+		.json(&json!({
+			"datacenters": {
+				"dc-1": {
+					"serverless": serverless,
+				}
+			}
+		}))
+		.send()
+		.await
+		.unwrap()
+}
+
+async fn create_actor(guard_port: u16, namespace: &str, runner_name: &str) {
+	let client = reqwest::Client::new();
+	let response = client
+		.post(format!(
+			"http://127.0.0.1:{}/actors?namespace={}",
+			guard_port, namespace
+		))
+		.json(&json!({
+			"name": "test",
+			"key": "start-request",
+			"crash_policy": "sleep",
+			"runner_name_selector": runner_name,
+		}))
+		.send()
+		.await
+		.unwrap();
+
+	if !response.status().is_success() {
+		let text = response.text().await.unwrap();
+		panic!("failed to create actor: {}", text);
+	}
+}
+
+/// Tests that the `/start` request uses the configured method, body template and auth.
+#[test]
+fn serverless_start_request_uses_configured_method_body_and_auth() {
+	common::run(
+		common::TestOpts::new(1).with_timeout(30),
+		|ctx| async move {
+			let guard_port = ctx.leader_dc().guard_port();
+			let (namespace, _) = common::setup_test_namespace(ctx.leader_dc()).await;
+
+			let (mock_addr, _mock_handle, requests) = start_recording_serverless().await;
+
+			let runner_name = format!("serverless-post-{}", rand::random::<u16>());
+			let response = upsert_serverless_runner_config(
+				guard_port,
+				&namespace,
+				&runner_name,
+				json!({
+					"url": format!("http://{}", mock_addr),
+					"max_runners": 1,
+					"slots_per_runner": 1,
+					"request_lifespan": 300,
+					"method": "post",
+					"body": r#"{"runner":"{{runner_name}}","namespace":"{{namespace_name}}"}"#,
+					"auth": { "bearer": { "token": "secret-token" } },
+				}),
+			)
+			.await;
+			assert!(
+				response.status().is_success(),
+				"failed to create runner config: {}",
+				response.text().await.unwrap()
+			);
+
+			create_actor(guard_port, &namespace, &runner_name).await;
+
+			let request = common::wait_with_poll(
+				Duration::from_secs(10),
+				Duration::from_millis(100),
+				|| async { requests.lock().unwrap().first().cloned() },
+			)
+			.await
+			.expect("serverless endpoint never received a start request");
+
+			assert_eq!(request.method, "POST");
+			assert_eq!(
+				request.authorization.as_deref(),
+				Some("Bearer secret-token")
+			);
+			assert_eq!(request.content_type.as_deref(), Some("application/json"));
+			assert_eq!(
+				serde_json::from_str::<serde_json::Value>(&request.body).unwrap(),
+				json!({ "runner": runner_name, "namespace": namespace }),
+			);
+		},
+	);
+}
+
+#[test]
+fn serverless_start_request_rejects_body_with_get() {
+	common::run(
+		common::TestOpts::new(1).with_timeout(30),
+		|ctx| async move {
+			let guard_port = ctx.leader_dc().guard_port();
+			let (namespace, _) = common::setup_test_namespace(ctx.leader_dc()).await;
+
+			let response = upsert_serverless_runner_config(
+				guard_port,
+				&namespace,
+				"serverless-get-body",
+				json!({
+					"url": "http://127.0.0.1:1",
+					"request_lifespan": 300,
+					"body": "{}",
+				}),
+			)
+			.await;
+			assert_eq!(response.status(), reqwest::StatusCode::BAD_REQUEST);
+		},
+	);
+}
+
+#[test]
+fn serverless_start_request_rejects_env_not_in_allowlist() {
+	common::run(
+		common::TestOpts::new(1).with_timeout(30),
+		|ctx| async move {
+			let guard_port = ctx.leader_dc().guard_port();
+			let (namespace, _) = common::setup_test_namespace(ctx.leader_dc()).await;
+
+			let response = upsert_serverless_runner_config(
+				guard_port,
+				&namespace,
+				"serverless-bearer-env",
+				json!({
+					"url": "http://127.0.0.1:1",
+					"request_lifespan": 300,
+					"auth": { "bearer_env": { "env": "RIVET__AUTH__ADMIN_TOKEN" } },
+				}),
+			)
+			.await;
+			assert_eq!(response.status(), reqwest::StatusCode::BAD_REQUEST);
+		},
+	);
+}
+
+#[test]
+fn serverless_start_request_rejects_auth_with_authorization_header() {
+	common::run(
+		common::TestOpts::new(1).with_timeout(30),
+		|ctx| async move {
+			let guard_port = ctx.leader_dc().guard_port();
+			let (namespace, _) = common::setup_test_namespace(ctx.leader_dc()).await;
+
+			let response = upsert_serverless_runner_config(
+				guard_port,
+				&namespace,
+				"serverless-duplicate-auth",
+				json!({
+					"url": "http://127.0.0.1:1",
+					"request_lifespan": 300,
+					"headers": { "Authorization": "Bearer other-token" },
+					"auth": { "bearer": { "token": "secret-token" } },
+				}),
+			)
+			.await;
+			assert_eq!(response.status(), reqwest::StatusCode::BAD_REQUEST);
+		},
+	);
+}
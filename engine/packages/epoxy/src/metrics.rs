@@ -70,6 +70,14 @@ lazy_static::lazy_static! {
 		*REGISTRY
 	).unwrap();
 
+	// MARK: Coordinator
+	pub static ref COORDINATOR_SINGLE_NODE_TOTAL: IntCounterVec = register_int_counter_vec_with_registry!(
+		"epoxy_coordinator_single_node_total",
+		"Total number of coordinator steps applied locally because the cluster has a single replica.",
+		&["step"],
+		*REGISTRY
+	).unwrap();
+
 	// MARK: Cluster state
 	pub static ref REPLICAS_TOTAL: IntGaugeVec = register_int_gauge_vec_with_registry!(
 		"epoxy_replicas_total",
@@ -101,6 +109,12 @@ pub fn record_request_result(request_type: &str, result: &str) {
 		.inc();
 }
 
+pub fn record_coordinator_single_node(step: &str) {
+	COORDINATOR_SINGLE_NODE_TOTAL
+		.with_label_values(&[step])
+		.inc();
+}
+
 pub fn record_replicas(config: &protocol::ClusterConfig) {
 	REPLICAS_TOTAL.reset();
 	for replica in &config.replicas {
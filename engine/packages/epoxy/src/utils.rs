@@ -23,6 +23,13 @@ impl fmt::Display for QuorumType {
 	}
 }
 
+/// Returns true if the given replica is the only member of the cluster. There is nothing to
+/// coordinate with in this case, so the coordinator applies config changes locally. Any new replica
+/// makes this false and the coordinator falls back to the regular reconfiguration flow.
+pub fn is_single_node(config: &protocol::ClusterConfig, replica_id: ReplicaId) -> bool {
+	matches!(config.replicas.as_slice(), [replica] if replica.replica_id == replica_id)
+}
+
 /// Use this replica list for any action that requires a quorum.
 pub fn get_quorum_members(config: &protocol::ClusterConfig) -> Vec<ReplicaId> {
 	config
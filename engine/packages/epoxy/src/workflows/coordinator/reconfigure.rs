@@ -4,7 +4,7 @@ use gas::prelude::*;
 use rivet_api_builder::ApiCtx;
 use serde::{Deserialize, Serialize};
 
-use crate::types;
+use crate::{metrics, types, utils};
 
 use super::State;
 
@@ -234,6 +234,15 @@ pub async fn send_begin_learning_to_all(
 	let config: protocol::ClusterConfig = state.config.clone().into();
 	let replicas = state.config.replicas.clone();
 
+	// A single replica has no changelog to catch up on and is already active in the initial config,
+	// so skip the learning round trip through the replica workflow
+	if utils::is_single_node(&config, ctx.config().epoxy_replica_id()) {
+		super::replica_status_change::store_local_config(ctx, config).await?;
+		metrics::record_coordinator_single_node("begin_learning");
+
+		return Ok(());
+	}
+
 	let begin_learning_futures = replicas.iter().map(|replica| {
 		let replica_id = replica.replica_id;
 		let config = config.clone();
@@ -5,7 +5,7 @@ use rivet_api_builder::ApiCtx;
 use serde::{Deserialize, Serialize};
 
 use super::State;
-use crate::types;
+use crate::{metrics, types, utils};
 
 #[tracing::instrument(skip_all)]
 pub async fn replica_status_change(
@@ -163,6 +163,15 @@ pub async fn notify_all_replicas(
 		"notifying all replicas of config change"
 	);
 
+	if utils::is_single_node(&config, ctx.config().epoxy_replica_id()) {
+		store_local_config(ctx, config).await?;
+		metrics::record_coordinator_single_node("notify_replicas");
+
+		return Ok(NotifyAllReplicasOutput {
+			config: state.config.clone(),
+		});
+	}
+
 	// Send update config to all replicas
 	let update_futures = config.replicas.iter().map(|replica| {
 		let replica_id = replica.replica_id;
@@ -192,3 +201,23 @@ pub async fn notify_all_replicas(
 		config: state.config.clone(),
 	})
 }
+
+/// Writes the cluster config directly to the local replica's UDB subspace. Used instead of sending
+/// messages to replicas when the coordinator is the only replica.
+pub(super) async fn store_local_config(
+	ctx: &ActivityCtx,
+	config: protocol::ClusterConfig,
+) -> Result<()> {
+	let replica_id = ctx.config().epoxy_replica_id();
+	let update_req = protocol::UpdateConfigRequest { config };
+
+	ctx.udb()?
+		.txn("epoxy_coordinator_store_local_config", |tx| {
+			let update_req = update_req.clone();
+			async move { crate::replica::update_config::update_config(&*tx, replica_id, update_req) }
+		})
+		.custom_instrument(tracing::info_span!("store_local_config_tx"))
+		.await?;
+
+	Ok(())
+}
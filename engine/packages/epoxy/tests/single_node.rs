@@ -0,0 +1,51 @@
+mod common;
+
+use common::{
+	TestCtx,
+	utils::{get_local, set_if_absent},
+};
+use epoxy::{metrics, ops::propose::ProposalResult};
+
+static TEST_LOCK: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
+#[tokio::test(flavor = "multi_thread")]
+async fn single_node_coordinator_skips_cross_replica_coordination() {
+	let _guard = TEST_LOCK.lock().await;
+
+	let update_config_requests_before = metrics::REQUEST_TOTAL
+		.with_label_values(&["update_config", "ok"])
+		.get();
+
+	let mut test_ctx = TestCtx::new_with(&[1]).await.unwrap();
+	let replica_id = test_ctx.leader_id;
+	let ctx = test_ctx.get_ctx(replica_id);
+
+	// Both coordinator steps that normally fan out to replicas were applied locally
+	assert!(
+		metrics::COORDINATOR_SINGLE_NODE_TOTAL
+			.with_label_values(&["begin_learning"])
+			.get() >= 1
+	);
+	assert!(
+		metrics::COORDINATOR_SINGLE_NODE_TOTAL
+			.with_label_values(&["notify_replicas"])
+			.get() >= 1
+	);
+	assert_eq!(
+		metrics::REQUEST_TOTAL
+			.with_label_values(&["update_config", "ok"])
+			.get() - update_config_requests_before,
+		0,
+	);
+
+	// The locally stored config is enough for proposals to commit
+	let key = b"single-node-key";
+	let result = set_if_absent(ctx, key, b"value").await.unwrap();
+	assert!(matches!(result, ProposalResult::Committed));
+	assert_eq!(
+		get_local(ctx, replica_id, key).await.unwrap(),
+		Some(b"value".to_vec()),
+	);
+
+	test_ctx.shutdown().await.unwrap();
+}
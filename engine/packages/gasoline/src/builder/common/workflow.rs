@@ -21,6 +21,7 @@ pub struct WorkflowBuilder<T, I: WorkflowInput> {
 	repr: T,
 	tags: serde_json::Map<String, serde_json::Value>,
 	unique: bool,
+	idempotency_key: Option<String>,
 	error: Option<BuilderError>,
 	_marker: PhantomData<I>,
 }
@@ -45,6 +46,7 @@ where
 			repr,
 			tags: serde_json::Map::new(),
 			unique: false,
+			idempotency_key: None,
 			error: from_workflow.then_some(BuilderError::CannotDispatchFromOpInWorkflow),
 			_marker: PhantomData,
 		}
@@ -103,6 +105,19 @@ where
 		self
 	}
 
+	/// Returns the existing workflow instead of dispatching a new one if a workflow with the same name was
+	/// already dispatched with this key within the idempotency window (see `gasoline_idempotency_window`).
+	/// Useful for making dispatches from retried requests safe.
+	pub fn idempotency_key(mut self, idempotency_key: impl Display) -> Self {
+		if self.error.is_some() {
+			return self;
+		}
+
+		self.idempotency_key = Some(idempotency_key.to_string());
+
+		self
+	}
+
 	#[tracing::instrument(skip_all, fields(workflow_name=I::Workflow::NAME, workflow_id, unique=self.unique))]
 	pub async fn dispatch(self) -> Result<Id> {
 		if let Some(err) = self.error {
@@ -137,6 +152,7 @@ where
 				tags,
 				&input_val,
 				self.unique,
+				self.idempotency_key.as_deref(),
 			)
 			.await?;
 
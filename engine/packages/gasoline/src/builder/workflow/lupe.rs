@@ -62,6 +62,7 @@ impl<'a, S: Serialize + DeserializeOwned> LoopBuilder<'a, S> {
 		ctx.check_stop()?;
 
 		let history_res = ctx.cursor().compare_loop(ctx.version())?;
+		ctx.check_sandbox(&history_res)?;
 		let loop_location = ctx.cursor().current_location_for(&history_res);
 
 		// Loop existed before
@@ -178,6 +179,13 @@ impl<'a, S: Serialize + DeserializeOwned> LoopBuilder<'a, S> {
 						async {
 							// Insert event if iteration is not a replay
 							if !loop_branch.cursor().compare_loop_branch(iteration)? {
+								if ctx.is_sandbox() {
+									return Err(WorkflowError::SandboxReplayEnded(
+										iteration_branch_root.clone(),
+									)
+									.into());
+								}
+
 								db2.commit_workflow_branch_event(
 									ctx.workflow_id(),
 									&iteration_branch_root,
@@ -216,8 +224,10 @@ impl<'a, S: Serialize + DeserializeOwned> LoopBuilder<'a, S> {
 							iteration += 1;
 
 							// Commit workflow state to db
-							if iteration % commit_interval.unwrap_or(DEFAULT_LOOP_COMMIT_INTERVAL)
-								== 0
+							if !ctx.is_sandbox()
+								&& iteration
+									% commit_interval.unwrap_or(DEFAULT_LOOP_COMMIT_INTERVAL)
+									== 0
 							{
 								let state_val = serde_json::value::to_raw_value(&state)
 									.map_err(WorkflowError::SerializeLoopOutput)?;
@@ -258,18 +268,20 @@ impl<'a, S: Serialize + DeserializeOwned> LoopBuilder<'a, S> {
 
 							// Commit loop output and final state to db. Note that we don't defer this because
 							// there will be no more loop iterations afterwards.
-							ctx.db()
-								.upsert_workflow_loop_event(
-									ctx.workflow_id(),
-									&ctx.name(),
-									&loop_location,
-									ctx.version(),
-									iteration,
-									&state_val,
-									Some(&output_val),
-									ctx.loop_location(),
-								)
-								.await?;
+							if !ctx.is_sandbox() {
+								ctx.db()
+									.upsert_workflow_loop_event(
+										ctx.workflow_id(),
+										&ctx.name(),
+										&loop_location,
+										ctx.version(),
+										iteration,
+										&state_val,
+										Some(&output_val),
+										ctx.loop_location(),
+									)
+									.await?;
+							}
 
 							Ok(Some(res))
 						}
@@ -63,6 +63,7 @@ impl<'a, M: Message> MessageBuilder<'a, M> {
 		self.ctx.compare_version("message", self.version)?;
 
 		let history_res = self.ctx.cursor().compare_msg(self.version, M::NAME)?;
+		self.ctx.check_sandbox(&history_res)?;
 		let location = self.ctx.cursor().current_location_for(&history_res);
 
 		// Message sent before
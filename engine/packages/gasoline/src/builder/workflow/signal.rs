@@ -130,6 +130,7 @@ impl<'a, T: Signal + Serialize> SignalBuilder<'a, T> {
 			.ctx
 			.cursor()
 			.compare_signal_send(self.version, T::NAME)?;
+		self.ctx.check_sandbox(&history_res)?;
 		let location = self.ctx.cursor().current_location_for(&history_res);
 
 		// Signal sent before
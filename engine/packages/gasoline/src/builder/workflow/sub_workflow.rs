@@ -134,6 +134,7 @@ where
 		let history_res = ctx
 			.cursor()
 			.compare_sub_workflow(version, I::Workflow::NAME)?;
+		ctx.check_sandbox(&history_res)?;
 		let location = ctx.cursor().current_location_for(&history_res);
 
 		// Sub workflow dispatched before
@@ -9,10 +9,14 @@ use tracing::Instrument;
 use crate::{
 	builder::{WorkflowRepr, common as builder},
 	ctx::{MessageCtx, common, message::SubscriptionHandle},
-	db::{Database, DatabaseHandle, WorkflowData, debug::DatabaseDebug},
+	db::{
+		Database, DatabaseHandle, WorkflowData,
+		debug::{DatabaseDebug, WorkflowExport},
+	},
 	message::Message,
 	operation::{Operation, OperationInput},
 	prelude::*,
+	sandbox::{self, ReplayResult},
 	signal::Signal,
 	utils::{tags::AsTags, topic::AsTopic},
 	workflow::{Workflow, WorkflowInput},
@@ -181,6 +185,25 @@ impl TestCtx {
 			.await
 	}
 
+	/// Replays an exported workflow in a sandbox against the workflows in the given registry.
+	#[tracing::instrument(skip_all)]
+	pub async fn replay_workflow(
+		&self,
+		reg: Registry,
+		export: &WorkflowExport,
+	) -> Result<ReplayResult> {
+		sandbox::replay_workflow(
+			reg.handle(),
+			self.db.clone(),
+			self.config.clone(),
+			self.pools.clone(),
+			self.cache.clone(),
+			export,
+		)
+		.in_current_span()
+		.await
+	}
+
 	/// Creates a signal builder.
 	pub fn signal<T: Signal + Serialize>(&self, body: T) -> builder::signal::SignalBuilder<T> {
 		builder::signal::SignalBuilder::new(
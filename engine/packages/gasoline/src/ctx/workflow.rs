@@ -23,7 +23,7 @@ use crate::{
 	history::{
 		History,
 		cursor::{CheckVersionHistoryResult, Cursor, HistoryResult, RemovedHistoryResult},
-		event::SleepState,
+		event::{EventType, SleepState},
 		location::Location,
 		removed::Removed,
 	},
@@ -77,6 +77,9 @@ pub struct WorkflowCtx {
 
 	/// Whether or not this ctx is used as part of a .join
 	parallelized: bool,
+	/// Whether or not this ctx is replaying history in a sandbox. Sandboxed workflows never run or record
+	/// new events.
+	sandbox: bool,
 }
 
 impl WorkflowCtx {
@@ -119,9 +122,27 @@ impl WorkflowCtx {
 			stop,
 
 			parallelized: false,
+			sandbox: false,
 		})
 	}
 
+	/// Creates a workflow ctx that replays the given history without side effects. See
+	/// `crate::sandbox`.
+	pub(crate) fn new_sandbox(
+		registry: RegistryHandle,
+		db: DatabaseHandle,
+		config: rivet_config::Config,
+		pools: rivet_pools::Pools,
+		cache: rivet_cache::Cache,
+		data: PulledWorkflowData,
+		stop: watch::Receiver<()>,
+	) -> Result<Self> {
+		let mut ctx = WorkflowCtx::new(registry, db, config, pools, cache, data, stop)?;
+		ctx.sandbox = true;
+
+		Ok(ctx)
+	}
+
 	/// Creates a workflow ctx reference with a given version.
 	pub fn v(&mut self, version: usize) -> VersionedWorkflowCtx<'_> {
 		VersionedWorkflowCtx::new(self, version)
@@ -145,6 +166,29 @@ impl WorkflowCtx {
 		}
 	}
 
+	/// Errors if the given history result would run or record a new event while replaying in a sandbox.
+	pub(crate) fn check_sandbox<T>(&self, history_res: &HistoryResult<T>) -> WorkflowResult<()> {
+		if self.sandbox && !matches!(history_res, HistoryResult::Event(_)) {
+			return Err(WorkflowError::SandboxReplayEnded(
+				self.cursor.current_location_for(history_res),
+			));
+		}
+
+		Ok(())
+	}
+
+	/// Runs the workflow against its recorded history only. Unlike `run`, nothing is committed to the
+	/// database.
+	#[tracing::instrument(name="sandbox_workflow", skip_all, fields(workflow_id=%self.workflow_id, workflow_name=%self.name))]
+	pub(crate) async fn run_sandbox(mut self) -> WorkflowResult<Box<serde_json::value::RawValue>> {
+		let workflow = self.registry.get_workflow(&self.name)?;
+
+		let output = (workflow.run)(&mut self).await?;
+		self.cursor().check_clear()?;
+
+		Ok(output)
+	}
+
 	#[tracing::instrument(name="workflow", skip_all, fields(workflow_id=%self.workflow_id, workflow_name=%self.name, ray_id=%self.ray_id))]
 	pub(crate) async fn run(mut self, parent_span_ctx: SpanContext) -> WorkflowResult<()> {
 		tracing::Span::current().add_link(parent_span_ctx);
@@ -430,6 +474,7 @@ impl WorkflowCtx {
 		version: usize,
 	) -> WorkflowResult<Self> {
 		let history_res = self.cursor.compare_branch(version)?;
+		self.check_sandbox(&history_res)?;
 		let location = self.cursor.current_location_for(&history_res);
 
 		// Validate history is consistent
@@ -480,6 +525,7 @@ impl WorkflowCtx {
 			stop: self.stop.clone(),
 
 			parallelized: self.parallelized,
+			sandbox: self.sandbox,
 		}
 	}
 
@@ -506,6 +552,58 @@ impl WorkflowCtx {
 		let _ = self.stop.clone().changed().await;
 		Err(WorkflowError::WorkflowEvicted)
 	}
+
+	/// If step mode is enabled for this workflow, blocks before a new activity or signal listen until an
+	/// operator continues the workflow.
+	async fn wait_for_step(
+		&self,
+		location: &Location,
+		event_type: EventType,
+		name: Option<&str>,
+	) -> WorkflowResult<()> {
+		if !self.config.runtime.gasoline_step_mode() {
+			return Ok(());
+		}
+
+		if !self
+			.db
+			.pause_workflow_step(self.workflow_id, location, event_type, name)
+			.await?
+		{
+			return Ok(());
+		}
+
+		tracing::info!(%location, %event_type, ?name, "paused workflow in step mode");
+
+		let mut bump_sub = self
+			.db
+			.bump_sub(BumpSubSubject::WorkflowStep {
+				workflow_id: self.workflow_id,
+			})
+			.await?;
+		let mut interval = tokio::time::interval(self.db.signal_poll_interval());
+		interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+		// Skip first tick, we wait after the db call instead of before
+		interval.tick().await;
+
+		while self
+			.db
+			.is_workflow_step_paused(self.workflow_id, location)
+			.await?
+		{
+			// Poll and wait for a wake at the same time
+			tokio::select! {
+				_ = bump_sub.next() => {},
+				_ = interval.tick() => {},
+				res = self.wait_stop() => res?,
+			}
+		}
+
+		tracing::info!(%location, "continued workflow in step mode");
+
+		Ok(())
+	}
 }
 
 impl WorkflowCtx {
@@ -536,6 +634,7 @@ impl WorkflowCtx {
 		let history_res = self
 			.cursor
 			.compare_activity(self.version, I::Activity::NAME)?;
+		self.check_sandbox(&history_res)?;
 		let location = self.cursor.current_location_for(&history_res);
 
 		// Activity was ran before
@@ -546,6 +645,10 @@ impl WorkflowCtx {
 			if let Some(output) = activity.parse_output()? {
 				output
 			}
+			// Sandboxed replays can't retry failed activities
+			else if self.sandbox {
+				return Err(WorkflowError::SandboxReplayEnded(location).into());
+			}
 			// Activity failed, retry
 			else {
 				let error_count = activity.error_count;
@@ -561,6 +664,9 @@ impl WorkflowCtx {
 						.await;
 				}
 
+				self.wait_for_step(&location, EventType::Activity, Some(I::Activity::NAME))
+					.await?;
+
 				match self
 					.run_activity::<I::Activity>(&input, &location, activity.create_ts)
 					.await
@@ -607,6 +713,9 @@ impl WorkflowCtx {
 		}
 		// This is a new activity
 		else {
+			self.wait_for_step(&location, EventType::Activity, Some(I::Activity::NAME))
+				.await?;
+
 			self.run_activity::<I::Activity>(&input, &location, rivet_util::timestamp::now())
 				.await?
 		};
@@ -683,6 +792,7 @@ impl WorkflowCtx {
 		self.check_stop()?;
 
 		let history_res = self.cursor.compare_signals(self.version)?;
+		self.check_sandbox(&history_res)?;
 		let location = self.cursor.current_location_for(&history_res);
 
 		// Signals received before
@@ -701,6 +811,9 @@ impl WorkflowCtx {
 		}
 		// Listen for new signals
 		else {
+			self.wait_for_step(&location, EventType::Signals, None)
+				.await?;
+
 			tracing::debug!("listening for signals");
 
 			let mut bump_sub = self
@@ -801,6 +914,7 @@ impl WorkflowCtx {
 		self.check_stop()?;
 
 		let history_res = self.cursor.compare_sleep(self.version)?;
+		self.check_sandbox(&history_res)?;
 		let location = self.cursor.current_location_for(&history_res);
 
 		// Slept before
@@ -829,8 +943,9 @@ impl WorkflowCtx {
 		let duration = deadline_ts.saturating_sub(rivet_util::timestamp::now());
 		tracing::Span::current().record("duration", &duration);
 
-		// No-op
-		if duration <= 0 {
+		// No-op. Sandboxed replays don't wait out recorded sleeps, the next event in history determines
+		// whether the sleep finished.
+		if duration <= 0 || self.sandbox {
 			if !replay && duration < -25 {
 				tracing::warn!(%duration, "tried to sleep for a negative duration");
 			}
@@ -906,6 +1021,7 @@ impl WorkflowCtx {
 		self.check_stop()?;
 
 		let history_res = self.cursor.compare_sleep(self.version)?;
+		self.check_sandbox(&history_res)?;
 		let history_res2 = history_res.equivalent();
 		let sleep_location = self.cursor.current_location_for(&history_res);
 
@@ -917,6 +1033,9 @@ impl WorkflowCtx {
 		}
 		// Sleep
 		else {
+			self.wait_for_step(&sleep_location, EventType::Sleep, None)
+				.await?;
+
 			let deadline_ts = TsToMillis::to_millis(time)?;
 
 			self.db
@@ -969,6 +1088,16 @@ impl WorkflowCtx {
 
 		// Location of the signals event (comes after the sleep event)
 		let signals_location = self.cursor.current_location_for(&history_res2);
+
+		// Sandboxed replays can't pull signals. An uninterrupted sleep timed out, otherwise recorded
+		// history ends here.
+		if self.sandbox {
+			if matches!(state, SleepState::Normal) {
+				return Err(WorkflowError::SandboxReplayEnded(signals_location).into());
+			}
+
+			return Ok(Vec::new());
+		}
 		let duration = deadline_ts.saturating_sub(rivet_util::timestamp::now());
 		tracing::Span::current().record("duration", &duration);
 
@@ -1131,6 +1260,12 @@ impl WorkflowCtx {
 
 		match self.cursor.compare_removed::<T>() {
 			RemovedHistoryResult::New => {
+				if self.sandbox {
+					return Err(
+						WorkflowError::SandboxReplayEnded(self.cursor.current_location()).into(),
+					);
+				}
+
 				tracing::debug!("inserting removed step");
 
 				self.db
@@ -1187,6 +1322,10 @@ impl WorkflowCtx {
 		};
 
 		if let Some(event_version) = event_version {
+			if self.sandbox {
+				return Err(WorkflowError::SandboxReplayEnded(event_location).into());
+			}
+
 			tracing::debug!("inserting version check");
 
 			self.db
@@ -1216,6 +1355,10 @@ impl WorkflowCtx {
 		self.loop_location.as_ref()
 	}
 
+	pub(crate) fn is_sandbox(&self) -> bool {
+		self.sandbox
+	}
+
 	pub(crate) fn set_loop_location(&mut self, loop_location: Location) {
 		self.loop_location = Some(loop_location);
 	}
@@ -1,12 +1,19 @@
 use anyhow::Result;
 use rivet_util::Id;
+use serde::{Deserialize, Serialize};
 
 use super::Database;
-use crate::history::{
-	event::{RemovedEvent, SleepEvent, VersionCheckEvent},
-	location::Location,
+use crate::{
+	error::{WorkflowError, WorkflowResult},
+	history::{
+		event::{EventType, RemovedEvent, SleepEvent, VersionCheckEvent},
+		location::Location,
+	},
 };
 
+/// Highest parallelization accepted by the bulk revive, silence and prune operations.
+pub const MAX_PARALLELIZATION: u16 = rivet_config::config::MAX_GASOLINE_DEBUG_PARALLELIZATION;
+
 #[async_trait::async_trait]
 pub trait DatabaseDebug: Database {
 	async fn get_workflows(&self, workflow_ids: Vec<Id>) -> Result<Vec<WorkflowData>>;
@@ -28,6 +35,32 @@ pub trait DatabaseDebug: Database {
 		include_forgotten: bool,
 	) -> Result<Option<HistoryData>>;
 
+	/// Scans the workflow's entire history (including forgotten events) and reports any inconsistencies
+	/// instead of failing on the first one like `get_workflow_history`.
+	async fn check_workflow_history(&self, workflow_id: Id) -> Result<Vec<HistoryIssue>>;
+
+	/// Reads the workflow's data, full history (including forgotten events), pending signals and wake
+	/// conditions in a single transaction so the result is a consistent snapshot.
+	async fn export_workflow(&self, workflow_id: Id) -> Result<Option<WorkflowExport>>;
+
+	/// Returns the ids of all sub workflows dispatched by the given workflow.
+	async fn get_workflow_children(&self, workflow_id: Id) -> Result<Vec<Id>>;
+
+	/// Returns the id of the workflow that dispatched the given workflow as a sub workflow, or `None` if it
+	/// was dispatched outside of a workflow.
+	async fn get_workflow_parent(&self, workflow_id: Id) -> Result<Option<Id>>;
+
+	/// Enables or disables step mode for the workflow. Requires `gasoline_step_mode` to be enabled in the
+	/// runtime config to have any effect. Disabling step mode releases the workflow if it is paused.
+	async fn set_workflow_step_mode(&self, workflow_id: Id, enabled: bool) -> Result<()>;
+
+	/// Returns the step mode state of the workflow, or `None` if step mode is not enabled for it.
+	async fn get_workflow_step(&self, workflow_id: Id) -> Result<Option<WorkflowStepState>>;
+
+	/// Lets a workflow in step mode continue past its current pause point. Returns false if the workflow
+	/// was not paused.
+	async fn continue_workflow_step(&self, workflow_id: Id) -> Result<bool>;
+
 	async fn get_signals(&self, signal_ids: Vec<Id>) -> Result<Vec<SignalData>>;
 
 	async fn find_signals(
@@ -48,6 +81,15 @@ pub trait DatabaseDebug: Database {
 		parallelization: u16,
 	) -> Result<usize>;
 
+	/// Silences dead workflows with one of the given names that were created before the given timestamp.
+	async fn silence_workflows_by_filter(
+		&self,
+		names: &[&str],
+		before_ts: i64,
+		dry_run: bool,
+		parallelization: u16,
+	) -> Result<usize>;
+
 	/// Used by pruner workflow for automatic pruning.
 	async fn prune_workflows(
 		&self,
@@ -76,7 +118,16 @@ pub trait DatabaseDebug: Database {
 	) -> Result<usize>;
 }
 
-#[derive(Debug)]
+/// Validates the parallelization passed to the bulk revive, silence and prune operations.
+pub fn validate_parallelization(parallelization: u16) -> WorkflowResult<()> {
+	if parallelization == 0 || parallelization > MAX_PARALLELIZATION {
+		return Err(WorkflowError::InvalidParallelization(parallelization));
+	}
+
+	Ok(())
+}
+
+#[derive(Debug, Serialize, Deserialize)]
 pub struct WorkflowData {
 	pub workflow_id: Id,
 	pub workflow_name: String,
@@ -90,7 +141,7 @@ pub struct WorkflowData {
 	pub state: WorkflowState,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum WorkflowState {
 	Complete,
 	Running,
@@ -99,13 +150,93 @@ pub enum WorkflowState {
 	Silenced,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct HistoryData {
 	pub wf: WorkflowData,
 	pub events: Vec<Event>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HistoryIssue {
+	pub location: Location,
+	pub forgotten: bool,
+	pub kind: HistoryIssueKind,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum HistoryIssueKind {
+	/// A key required by the event's type does not exist.
+	MissingEventData { field: String },
+	/// An indexed key (used by signals events) was found before all of the indexes preceding it.
+	OutOfOrderIndex {
+		field: String,
+		index: usize,
+		expected: usize,
+	},
+	/// Data chunks exist for an event without an event type or for an index without a name.
+	OrphanedChunk { field: String },
+	/// A value or the combined chunks of an event could not be deserialized.
+	InvalidEventData { error: String },
+	/// A key in the history subspace could not be parsed.
+	InvalidKey { error: String },
+}
+
+impl std::fmt::Display for HistoryIssueKind {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			HistoryIssueKind::MissingEventData { field } => write!(f, "missing event data {field}"),
+			HistoryIssueKind::OutOfOrderIndex {
+				field,
+				index,
+				expected,
+			} => write!(
+				f,
+				"out of order {field} index {index}, expected index {expected}"
+			),
+			HistoryIssueKind::OrphanedChunk { field } => write!(f, "orphaned {field} chunk"),
+			HistoryIssueKind::InvalidEventData { error } => {
+				write!(f, "invalid event data: {error}")
+			}
+			HistoryIssueKind::InvalidKey { error } => write!(f, "invalid key: {error}"),
+		}
+	}
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WorkflowExport {
+	pub workflow: WorkflowData,
+	pub events: Vec<Event>,
+	pub pending_signals: Vec<SignalData>,
+	pub wake_conditions: WakeConditions,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WakeConditions {
+	pub signal_names: Vec<String>,
+	pub deadline_ts: Option<i64>,
+	pub sub_workflow_id: Option<Id>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct WorkflowStepState {
+	/// The last pause point the workflow reached. `None` until the workflow reaches an activity or signal
+	/// listen after step mode was enabled.
+	pub pause_point: Option<StepPausePoint>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StepPausePoint {
+	pub location: Location,
+	pub event_type: EventType,
+	/// Activity name, not set for signal listens.
+	pub name: Option<String>,
+	pub paused_ts: i64,
+	/// Set once the workflow was continued past this pause point.
+	pub continued: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Event {
 	pub location: Location,
 	pub version: usize,
@@ -114,7 +245,7 @@ pub struct Event {
 	pub data: EventData,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub enum EventData {
 	Activity(ActivityEvent),
 	Signal(SignalEvent),
@@ -163,7 +294,7 @@ impl std::fmt::Display for EventData {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ActivityEvent {
 	pub name: String,
 	pub input: serde_json::Value,
@@ -171,14 +302,14 @@ pub struct ActivityEvent {
 	pub errors: Vec<ActivityError>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct SignalEvent {
 	pub signal_id: Id,
 	pub name: String,
 	pub body: serde_json::Value,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct SignalSendEvent {
 	pub signal_id: Id,
 	pub name: String,
@@ -187,14 +318,14 @@ pub struct SignalSendEvent {
 	pub body: serde_json::Value,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct MessageSendEvent {
 	pub name: String,
 	pub tags: serde_json::Value,
 	pub body: serde_json::Value,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct SubWorkflowEvent {
 	pub sub_workflow_id: Id,
 	pub name: String,
@@ -202,7 +333,7 @@ pub struct SubWorkflowEvent {
 	pub input: serde_json::Value,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct LoopEvent {
 	pub state: serde_json::Value,
 	/// If the loop completes, this will be some.
@@ -210,21 +341,21 @@ pub struct LoopEvent {
 	pub iteration: usize,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct SignalsEvent {
 	pub signal_ids: Vec<Id>,
 	pub names: Vec<String>,
 	pub bodies: Vec<serde_json::Value>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ActivityError {
 	pub error: String,
 	pub count: usize,
 	pub latest_ts: i64,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct SignalData {
 	pub signal_id: Id,
 	pub signal_name: String,
@@ -236,7 +367,7 @@ pub struct SignalData {
 	pub state: SignalState,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum SignalState {
 	Acked,
 	Pending,
@@ -5,7 +5,7 @@ use std::{
 	time::{Duration, Instant},
 };
 
-use anyhow::{Context, Result, ensure};
+use anyhow::{Context, Result, bail, ensure};
 use futures_util::{StreamExt, TryStreamExt, stream::FuturesUnordered};
 use rivet_util::Id;
 use serde::Serialize;
@@ -24,9 +24,11 @@ use crate::{
 	db::{
 		BumpSubSubject,
 		debug::{
-			ActivityError, ActivityEvent, DatabaseDebug, Event, EventData, HistoryData, LoopEvent,
-			MessageSendEvent, SignalData, SignalEvent, SignalSendEvent, SignalState, SignalsEvent,
-			SubWorkflowEvent, WorkflowData, WorkflowState,
+			ActivityError, ActivityEvent, DatabaseDebug, Event, EventData, HistoryData,
+			HistoryIssue, HistoryIssueKind, LoopEvent, MessageSendEvent, SignalData, SignalEvent,
+			SignalSendEvent, SignalState, SignalsEvent, SubWorkflowEvent, WakeConditions,
+			WorkflowData, WorkflowExport, WorkflowState, WorkflowStepState,
+			validate_parallelization,
 		},
 	},
 	error::{WorkflowError, WorkflowResult},
@@ -39,6 +41,31 @@ use crate::{
 
 const EARLY_TXN_TIMEOUT: Duration = Duration::from_secs(3);
 
+/// Extra condition a dead workflow has to meet in `dead_workflows_by_filter_inner`.
+#[derive(Clone, Copy)]
+enum DeadWorkflowFilter<'a> {
+	/// Error contains one of the given strings. Matches any workflow if empty.
+	ErrorLike(&'a [&'a str]),
+	/// Created before the given timestamp.
+	CreatedBefore(i64),
+}
+
+impl DeadWorkflowFilter<'_> {
+	/// Whether a workflow matches before any of its keys are read.
+	fn default_matches(&self) -> bool {
+		match self {
+			DeadWorkflowFilter::ErrorLike(error_like) => error_like.is_empty(),
+			DeadWorkflowFilter::CreatedBefore(_) => false,
+		}
+	}
+}
+
+#[derive(Clone, Copy)]
+enum DeadWorkflowAction {
+	Wake,
+	Silence,
+}
+
 impl DatabaseKv {
 	#[tracing::instrument(skip_all)]
 	async fn get_workflows_inner(
@@ -270,6 +297,297 @@ impl DatabaseKv {
 
 		Ok(res)
 	}
+
+	#[tracing::instrument(skip_all)]
+	async fn get_workflow_history_events_inner(
+		&self,
+		workflow_id: Id,
+		include_forgotten: bool,
+		tx: &universaldb::RetryableTransaction,
+	) -> Result<Vec<Event>> {
+		let history_subspace = self
+			.subspace
+			.subspace(&keys::history::HistorySubspaceKey::new(
+				workflow_id,
+				if include_forgotten {
+					keys::history::HistorySubspaceVariant::All
+				} else {
+					keys::history::HistorySubspaceVariant::Active
+				},
+			));
+
+		let mut events_by_location: HashMap<Location, Vec<Event>> = HashMap::new();
+		let mut current_event = WorkflowHistoryEventBuilder::new(Location::empty(), false);
+
+		let mut stream = tx.get_ranges_keyvalues(
+			RangeOption {
+				mode: StreamingMode::WantAll,
+				..(&history_subspace).into()
+			},
+			Serializable,
+		);
+
+		loop {
+			let Some(entry) = stream.try_next().await? else {
+				break;
+			};
+
+			// Parse only the wf id and location of the current key
+			let partial_key = self
+				.subspace
+				.unpack::<keys::history::PartialEventKey>(entry.key())?;
+
+			if current_event.location != partial_key.location {
+				if current_event.location.is_empty() {
+					current_event = WorkflowHistoryEventBuilder::new(
+						partial_key.location,
+						partial_key.forgotten,
+					);
+				} else {
+					// Insert current event builder to into wf events and
+					// reset state
+					let previous_event = std::mem::replace(
+						&mut current_event,
+						WorkflowHistoryEventBuilder::new(
+							partial_key.location,
+							partial_key.forgotten,
+						),
+					);
+					events_by_location
+						.entry(previous_event.location.root())
+						.or_default()
+						.push(Event::try_from(previous_event)?);
+				}
+			}
+
+			if let Err(kind) = self.parse_history_entry(&mut current_event, entry) {
+				bail!("corrupt history at {}: {kind}", current_event.location);
+			}
+		}
+		// Insert final event
+		if !current_event.location.is_empty() {
+			events_by_location
+				.entry(current_event.location.root())
+				.or_default()
+				.push(Event::try_from(current_event)?);
+		}
+
+		let mut flat_events = events_by_location
+			.into_iter()
+			.flat_map(|(_, v)| v)
+			.collect::<Vec<_>>();
+		flat_events.sort_by(|a, b| a.location.cmp(&b.location));
+
+		Ok(flat_events)
+	}
+
+	/// Same as `get_workflow_history_events_inner` but collects every inconsistency instead of failing.
+	#[tracing::instrument(skip_all)]
+	async fn check_workflow_history_inner(
+		&self,
+		workflow_id: Id,
+		tx: &universaldb::RetryableTransaction,
+	) -> Result<Vec<HistoryIssue>> {
+		let history_subspace = self
+			.subspace
+			.subspace(&keys::history::HistorySubspaceKey::new(
+				workflow_id,
+				keys::history::HistorySubspaceVariant::All,
+			));
+
+		let mut issues = Vec::new();
+		let mut current_event = WorkflowHistoryEventBuilder::new(Location::empty(), false);
+
+		let mut stream = tx.get_ranges_keyvalues(
+			RangeOption {
+				mode: StreamingMode::WantAll,
+				..(&history_subspace).into()
+			},
+			Snapshot,
+		);
+
+		loop {
+			let Some(entry) = stream.try_next().await? else {
+				break;
+			};
+
+			// Parse only the wf id and location of the current key
+			let partial_key = match self
+				.subspace
+				.unpack::<keys::history::PartialEventKey>(entry.key())
+			{
+				Ok(partial_key) => partial_key,
+				Err(err) => {
+					issues.push(current_event.issue(HistoryIssueKind::InvalidKey {
+						error: err.to_string(),
+					}));
+					continue;
+				}
+			};
+
+			if current_event.location != partial_key.location
+				|| current_event.forgotten != partial_key.forgotten
+			{
+				let previous_event = std::mem::replace(
+					&mut current_event,
+					WorkflowHistoryEventBuilder::new(partial_key.location, partial_key.forgotten),
+				);
+
+				if !previous_event.location.is_empty() {
+					check_history_event(previous_event, &mut issues);
+				}
+			}
+
+			if let Err(kind) = self.parse_history_entry(&mut current_event, entry) {
+				issues.push(current_event.issue(kind));
+			}
+		}
+		// Check final event
+		if !current_event.location.is_empty() {
+			check_history_event(current_event, &mut issues);
+		}
+
+		Ok(issues)
+	}
+
+	/// Parses a history key of the current event into the event builder. Keys we don't need (like tags)
+	/// are ignored.
+	fn parse_history_entry(
+		&self,
+		current_event: &mut WorkflowHistoryEventBuilder,
+		entry: Value,
+	) -> std::result::Result<(), HistoryIssueKind> {
+		if let Ok(key) = self
+			.subspace
+			.unpack::<keys::history::EventTypeKey>(entry.key())
+		{
+			current_event.event_type = Some(read_history_value(&key, &entry, "event_type")?);
+		} else if let Ok(key) = self
+			.subspace
+			.unpack::<keys::history::VersionKey>(entry.key())
+		{
+			current_event.version = Some(read_history_value(&key, &entry, "version")?);
+		} else if let Ok(key) = self
+			.subspace
+			.unpack::<keys::history::CreateTsKey>(entry.key())
+		{
+			current_event.create_ts = Some(read_history_value(&key, &entry, "create_ts")?);
+		} else if let Ok(key) = self.subspace.unpack::<keys::history::NameKey>(entry.key()) {
+			current_event.name = Some(read_history_value(&key, &entry, "name")?);
+		} else if let Ok(key) = self
+			.subspace
+			.unpack::<keys::history::SignalIdKey>(entry.key())
+		{
+			current_event.signal_id = Some(read_history_value(&key, &entry, "signal_id")?);
+		} else if let Ok(key) = self
+			.subspace
+			.unpack::<keys::history::SubWorkflowIdKey>(entry.key())
+		{
+			current_event.sub_workflow_id =
+				Some(read_history_value(&key, &entry, "sub_workflow_id")?);
+		} else if let Ok(_key) = self
+			.subspace
+			.unpack::<keys::history::InputChunkKey>(entry.key())
+		{
+			current_event.input_chunks.push(entry);
+		} else if let Ok(_key) = self
+			.subspace
+			.unpack::<keys::history::OutputChunkKey>(entry.key())
+		{
+			current_event.output_chunks.push(entry);
+		} else if let Ok(key) = self.subspace.unpack::<keys::history::ErrorKey>(entry.key()) {
+			if let Some(err) = current_event
+				.errors
+				.iter_mut()
+				.find(|err| err.error == key.error)
+			{
+				err.count += 1;
+				err.latest_ts = err.latest_ts.max(key.ts);
+			} else {
+				current_event.errors.push(ActivityError {
+					error: key.error,
+					count: 1,
+					latest_ts: key.ts,
+				});
+			}
+		} else if let Ok(key) = self
+			.subspace
+			.unpack::<keys::history::IterationKey>(entry.key())
+		{
+			current_event.iteration = Some(read_history_value(&key, &entry, "iteration")?);
+		} else if let Ok(key) = self
+			.subspace
+			.unpack::<keys::history::DeadlineTsKey>(entry.key())
+		{
+			current_event.deadline_ts = Some(read_history_value(&key, &entry, "deadline_ts")?);
+		} else if let Ok(key) = self
+			.subspace
+			.unpack::<keys::history::SleepStateKey>(entry.key())
+		{
+			current_event.sleep_state = Some(read_history_value(&key, &entry, "sleep_state")?);
+		} else if let Ok(key) = self
+			.subspace
+			.unpack::<keys::history::InnerEventTypeKey>(entry.key())
+		{
+			current_event.inner_event_type =
+				Some(read_history_value(&key, &entry, "inner_event_type")?);
+		} else if let Ok(key) = self
+			.subspace
+			.unpack::<keys::history::InnerVersionKey>(entry.key())
+		{
+			current_event.inner_version = Some(read_history_value(&key, &entry, "inner_version")?);
+		} else if let Ok(key) = self
+			.subspace
+			.unpack::<keys::history::IndexedSignalIdKey>(entry.key())
+		{
+			let expected = current_event.indexed_signal_ids.len();
+			if key.index != expected {
+				return Err(HistoryIssueKind::OutOfOrderIndex {
+					field: "signal_id".to_string(),
+					index: key.index,
+					expected,
+				});
+			}
+
+			let signal_id = read_history_value(&key, &entry, "signal_id")?;
+			current_event.indexed_signal_ids.push(signal_id);
+		} else if let Ok(key) = self
+			.subspace
+			.unpack::<keys::history::IndexedNameKey>(entry.key())
+		{
+			let expected = current_event.indexed_names.len();
+			if key.index != expected {
+				return Err(HistoryIssueKind::OutOfOrderIndex {
+					field: "name".to_string(),
+					index: key.index,
+					expected,
+				});
+			}
+
+			let name = read_history_value(&key, &entry, "name")?;
+			current_event.indexed_names.push(name);
+		} else if let Ok(key) = self
+			.subspace
+			.unpack::<keys::history::IndexedInputChunkKey>(entry.key())
+		{
+			let len = current_event.indexed_input_chunks.len();
+
+			// Chunks of the same index are contiguous
+			if key.index + 1 == len {
+				current_event.indexed_input_chunks[key.index].push(entry);
+			} else if key.index == len {
+				current_event.indexed_input_chunks.push(vec![entry]);
+			} else {
+				return Err(HistoryIssueKind::OutOfOrderIndex {
+					field: "input".to_string(),
+					index: key.index,
+					expected: len,
+				});
+			}
+		}
+
+		Ok(())
+	}
 }
 
 // NOTE: Most of the reads here are Snapshot because we don't want this to conflict with the actual wf engine.
@@ -748,253 +1066,325 @@ impl DatabaseDebug for DatabaseKv {
 	) -> Result<Option<HistoryData>> {
 		self.pools
 			.udb()?
-			.txn("gas_debug_get_workflow_history", |tx| {
-				async move {
-					let history_subspace =
-						self.subspace
-							.subspace(&keys::history::HistorySubspaceKey::new(
-								workflow_id,
-								if include_forgotten {
-									keys::history::HistorySubspaceVariant::All
-								} else {
-									keys::history::HistorySubspaceVariant::Active
-								},
-							));
+			.txn("gas_debug_get_workflow_history", |tx| async move {
+				let (wf, events) = tokio::try_join!(
+					async {
+						self.get_workflows_inner(vec![workflow_id], &tx)
+							.await
+							.map(|wfs| wfs.into_iter().next())
+					},
+					self.get_workflow_history_events_inner(workflow_id, include_forgotten, &tx),
+				)?;
 
-					let (wf, events) = tokio::try_join!(
-						async {
-							self.get_workflows(vec![workflow_id])
-								.await
-								.map(|wfs| wfs.into_iter().next())
+				let Some(wf) = wf else {
+					return Ok(None);
+				};
+
+				Ok(Some(HistoryData { wf, events }))
+			})
+			.instrument(tracing::info_span!("pull_workflow_history_tx"))
+			.await
+			.map_err(Into::into)
+	}
+
+	#[tracing::instrument(skip_all)]
+	async fn check_workflow_history(&self, workflow_id: Id) -> Result<Vec<HistoryIssue>> {
+		self.pools
+			.udb()?
+			.txn("gas_debug_check_workflow_history", |tx| async move {
+				self.check_workflow_history_inner(workflow_id, &tx).await
+			})
+			.instrument(tracing::info_span!("check_workflow_history_tx"))
+			.await
+			.map_err(Into::into)
+	}
+
+	#[tracing::instrument(skip_all)]
+	async fn export_workflow(&self, workflow_id: Id) -> Result<Option<WorkflowExport>> {
+		self.pools
+			.udb()?
+			.txn("gas_debug_export_workflow", |tx| async move {
+				let pending_signals_subspace = self.subspace.subspace(
+					&keys::workflow::PendingSignalKey::subspace_without_name(workflow_id),
+				);
+				let wake_signals_subspace = self
+					.subspace
+					.subspace(&keys::workflow::WakeSignalKey::subspace(workflow_id));
+				let wake_deadline_key = keys::workflow::WakeDeadlineKey::new(workflow_id);
+				let wake_sub_workflow_key = keys::workflow::WakeSubWorkflowKey::new(workflow_id);
+
+				let (
+					wf,
+					events,
+					pending_signal_ids,
+					wake_signal_names,
+					wake_deadline_entry,
+					wake_sub_workflow_entry,
+				) = tokio::try_join!(
+					async {
+						self.get_workflows_inner(vec![workflow_id], &tx)
+							.await
+							.map(|wfs| wfs.into_iter().next())
+					},
+					self.get_workflow_history_events_inner(workflow_id, true, &tx),
+					tx.get_ranges_keyvalues(
+						RangeOption {
+							mode: StreamingMode::WantAll,
+							..(&pending_signals_subspace).into()
 						},
-						async {
-							let mut events_by_location: HashMap<Location, Vec<Event>> =
-								HashMap::new();
-							let mut current_event =
-								WorkflowHistoryEventBuilder::new(Location::empty(), false);
+						Snapshot,
+					)
+					.map(|res| {
+						let key = self
+							.subspace
+							.unpack::<keys::workflow::PendingSignalKey>(res?.key())?;
 
-							let mut stream = tx.get_ranges_keyvalues(
-								RangeOption {
-									mode: StreamingMode::WantAll,
-									..(&history_subspace).into()
-								},
-								Serializable,
-							);
+						Ok(key.signal_id)
+					})
+					.try_collect::<Vec<_>>(),
+					tx.get_ranges_keyvalues(
+						RangeOption {
+							mode: StreamingMode::WantAll,
+							..(&wake_signals_subspace).into()
+						},
+						Snapshot,
+					)
+					.map(|res| {
+						let key = self
+							.subspace
+							.unpack::<keys::workflow::WakeSignalKey>(res?.key())?;
 
-							loop {
-								let Some(entry) = stream.try_next().await? else {
-									break;
-								};
+						Ok(key.signal_name)
+					})
+					.try_collect::<Vec<_>>(),
+					tx.get(&self.subspace.pack(&wake_deadline_key), Snapshot),
+					tx.get(&self.subspace.pack(&wake_sub_workflow_key), Snapshot),
+				)?;
+
+				let Some(workflow) = wf else {
+					return Ok(None);
+				};
+
+				let pending_signals = self.get_signals_inner(pending_signal_ids, &tx).await?;
+
+				let deadline_ts = wake_deadline_entry
+					.map(|entry| wake_deadline_key.deserialize(&entry))
+					.transpose()?;
+				let sub_workflow_id = wake_sub_workflow_entry
+					.map(|entry| wake_sub_workflow_key.deserialize(&entry))
+					.transpose()?;
+
+				Ok(Some(WorkflowExport {
+					workflow,
+					events,
+					pending_signals,
+					wake_conditions: WakeConditions {
+						signal_names: wake_signal_names,
+						deadline_ts,
+						sub_workflow_id,
+					},
+				}))
+			})
+			.instrument(tracing::info_span!("export_workflow_tx"))
+			.await
+			.map_err(Into::into)
+	}
 
-								// Parse only the wf id and location of the current key
-								let partial_key = self
-									.subspace
-									.unpack::<keys::history::PartialEventKey>(entry.key())?;
-
-								if current_event.location != partial_key.location {
-									if current_event.location.is_empty() {
-										current_event = WorkflowHistoryEventBuilder::new(
-											partial_key.location,
-											partial_key.forgotten,
-										);
-									} else {
-										// Insert current event builder to into wf events and
-										// reset state
-										let previous_event = std::mem::replace(
-											&mut current_event,
-											WorkflowHistoryEventBuilder::new(
-												partial_key.location,
-												partial_key.forgotten,
-											),
-										);
-										events_by_location
-											.entry(previous_event.location.root())
-											.or_default()
-											.push(Event::try_from(previous_event)?);
-									}
-								}
+	#[tracing::instrument(skip_all)]
+	async fn get_workflow_children(&self, workflow_id: Id) -> Result<Vec<Id>> {
+		self.pools
+			.udb()?
+			.txn("gas_debug_get_workflow_children", |tx| async move {
+				let tx = tx.with_subspace(self.subspace.clone());
 
-								// Parse current key as any event key
-								if let Ok(key) = self
-									.subspace
-									.unpack::<keys::history::EventTypeKey>(entry.key())
-								{
-									let event_type = key.deserialize(entry.value())?;
+				let child_workflow_subspace = self
+					.subspace
+					.subspace(&keys::workflow::ChildWorkflowKey::subspace(workflow_id));
 
-									current_event.event_type = Some(event_type);
-								} else if let Ok(key) = self
-									.subspace
-									.unpack::<keys::history::VersionKey>(entry.key())
-								{
-									let version = key.deserialize(entry.value())?;
+				let (mut child_workflow_ids, wake_sub_workflow_id) = tokio::try_join!(
+					tx.get_ranges_keyvalues(
+						RangeOption {
+							mode: StreamingMode::WantAll,
+							..(&child_workflow_subspace).into()
+						},
+						Snapshot,
+					)
+					.map(|res| {
+						tx.unpack::<keys::workflow::ChildWorkflowKey>(res?.key())
+							.map(|key| key.child_workflow_id)
+					})
+					.try_collect::<Vec<_>>(),
+					// Sub workflows dispatched before the child index existed can only be found through
+					// the wake index, which is only set while the workflow is waiting on the sub workflow
+					tx.read_opt(
+						&keys::workflow::WakeSubWorkflowKey::new(workflow_id),
+						Snapshot
+					),
+				)?;
+
+				if let Some(wake_sub_workflow_id) = wake_sub_workflow_id {
+					if !child_workflow_ids.contains(&wake_sub_workflow_id) {
+						child_workflow_ids.push(wake_sub_workflow_id);
+					}
+				}
 
-									current_event.version = Some(version);
-								} else if let Ok(key) = self
-									.subspace
-									.unpack::<keys::history::CreateTsKey>(entry.key())
-								{
-									let create_ts = key.deserialize(entry.value())?;
+				Ok(child_workflow_ids)
+			})
+			.instrument(tracing::info_span!("get_workflow_children_tx"))
+			.await
+			.map_err(Into::into)
+	}
 
-									current_event.create_ts = Some(create_ts);
-								} else if let Ok(key) =
-									self.subspace.unpack::<keys::history::NameKey>(entry.key())
-								{
-									let name = key.deserialize(entry.value())?;
+	#[tracing::instrument(skip_all)]
+	async fn get_workflow_parent(&self, workflow_id: Id) -> Result<Option<Id>> {
+		self.pools
+			.udb()?
+			.txn("gas_debug_get_workflow_parent", |tx| async move {
+				let tx = tx.with_subspace(self.subspace.clone());
 
-									current_event.name = Some(name);
-								} else if let Ok(key) = self
-									.subspace
-									.unpack::<keys::history::SignalIdKey>(entry.key())
-								{
-									let signal_id = key.deserialize(entry.value())?;
+				if let Some(parent_workflow_id) = tx
+					.read_opt(
+						&keys::workflow::ParentWorkflowIdKey::new(workflow_id),
+						Snapshot,
+					)
+					.await?
+				{
+					return Ok(Some(parent_workflow_id));
+				}
 
-									current_event.signal_id = Some(signal_id);
-								} else if let Ok(key) = self
-									.subspace
-									.unpack::<keys::history::SubWorkflowIdKey>(entry.key())
-								{
-									let sub_workflow_id = key.deserialize(entry.value())?;
+				// Fall back to the sub workflow wake index for workflows dispatched before the parent
+				// index existed
+				let sub_workflow_wake_subspace = self
+					.subspace
+					.subspace(&keys::wake::SubWorkflowWakeKey::subspace(workflow_id));
 
-									current_event.sub_workflow_id = Some(sub_workflow_id);
-								} else if let Ok(_key) = self
-									.subspace
-									.unpack::<keys::history::InputChunkKey>(entry.key())
-								{
-									current_event.input_chunks.push(entry);
-								} else if let Ok(_key) = self
-									.subspace
-									.unpack::<keys::history::OutputChunkKey>(entry.key())
-								{
-									current_event.output_chunks.push(entry);
-								} else if let Ok(key) =
-									self.subspace.unpack::<keys::history::ErrorKey>(entry.key())
-								{
-									if let Some(err) = current_event
-										.errors
-										.iter_mut()
-										.find(|err| err.error == key.error)
-									{
-										err.count += 1;
-										err.latest_ts = err.latest_ts.max(key.ts);
-									} else {
-										current_event.errors.push(ActivityError {
-											error: key.error,
-											count: 1,
-											latest_ts: key.ts,
-										});
-									}
-								} else if let Ok(key) = self
-									.subspace
-									.unpack::<keys::history::IterationKey>(entry.key())
-								{
-									let iteration = key.deserialize(entry.value())?;
+				let mut stream = tx.get_ranges_keyvalues(
+					RangeOption {
+						mode: StreamingMode::Exact,
+						limit: Some(1),
+						..(&sub_workflow_wake_subspace).into()
+					},
+					Snapshot,
+				);
 
-									current_event.iteration = Some(iteration);
-								} else if let Ok(key) = self
-									.subspace
-									.unpack::<keys::history::DeadlineTsKey>(entry.key())
-								{
-									let deadline_ts = key.deserialize(entry.value())?;
+				let Some(entry) = stream.try_next().await? else {
+					return Ok(None);
+				};
 
-									current_event.deadline_ts = Some(deadline_ts);
-								} else if let Ok(key) = self
-									.subspace
-									.unpack::<keys::history::SleepStateKey>(entry.key())
-								{
-									let sleep_state = key.deserialize(entry.value())?;
+				let sub_workflow_wake_key =
+					tx.unpack::<keys::wake::SubWorkflowWakeKey>(entry.key())?;
 
-									current_event.sleep_state = Some(sleep_state);
-								} else if let Ok(key) =
-									self.subspace
-										.unpack::<keys::history::InnerEventTypeKey>(entry.key())
-								{
-									let inner_event_type = key.deserialize(entry.value())?;
+				Ok(Some(sub_workflow_wake_key.workflow_id))
+			})
+			.instrument(tracing::info_span!("get_workflow_parent_tx"))
+			.await
+			.map_err(Into::into)
+	}
 
-									current_event.inner_event_type = Some(inner_event_type);
-								} else if let Ok(key) = self
-									.subspace
-									.unpack::<keys::history::InnerVersionKey>(entry.key())
-								{
-									let inner_version = key.deserialize(entry.value())?;
+	#[tracing::instrument(skip_all)]
+	async fn set_workflow_step_mode(&self, workflow_id: Id, enabled: bool) -> Result<()> {
+		self.pools
+			.udb()?
+			.txn("gas_debug_set_workflow_step_mode", |tx| async move {
+				let tx = tx.with_subspace(self.subspace.clone());
 
-									current_event.inner_version = Some(inner_version);
-								} else if let Ok(key) =
-									self.subspace
-										.unpack::<keys::history::IndexedSignalIdKey>(entry.key())
-								{
-									ensure!(
-										current_event.indexed_signal_ids.len() == key.index,
-										"corrupt history, indexed signal doesn't exist yet or is out of order"
-									);
+				let step_key = keys::workflow::StepKey::new(workflow_id);
 
-									let signal_id = key.deserialize(entry.value())?;
-									current_event
-										.indexed_signal_ids
-										.insert(key.index, signal_id);
-								} else if let Ok(key) = self
-									.subspace
-									.unpack::<keys::history::IndexedNameKey>(entry.key())
-								{
-									ensure!(
-										current_event.indexed_names.len() == key.index,
-										"corrupt history, indexed name doesn't exist yet or is out of order"
-									);
+				if enabled {
+					ensure!(
+						tx.exists(&keys::workflow::NameKey::new(workflow_id), Serializable)
+							.await?,
+						"workflow not found"
+					);
 
-									let name = key.deserialize(entry.value())?;
-									current_event.indexed_names.insert(key.index, name);
-								} else if let Ok(key) =
-									self.subspace
-										.unpack::<keys::history::IndexedInputChunkKey>(entry.key())
-								{
-									ensure!(
-										current_event.indexed_input_chunks.len() == key.index,
-										"corrupt history, indexed chunk doesn't exist yet or is out of order"
-									);
+					// The step key is cleared when the workflow completes, don't write it again afterwards
+					let output_subspace = self
+						.subspace
+						.subspace(&keys::workflow::OutputKey::new(workflow_id));
+					let has_output = tx
+						.get_ranges_keyvalues(
+							RangeOption {
+								mode: StreamingMode::Exact,
+								limit: Some(1),
+								..(&output_subspace).into()
+							},
+							Serializable,
+						)
+						.try_next()
+						.await?
+						.is_some();
+					ensure!(!has_output, "workflow already completed");
 
-									if let Some(input_chunks) =
-										current_event.indexed_input_chunks.get_mut(key.index)
-									{
-										input_chunks.push(entry);
-									} else {
-										current_event
-											.indexed_input_chunks
-											.insert(key.index, vec![entry]);
-									}
-								}
+					if !tx.exists(&step_key, Serializable).await? {
+						tx.write(&step_key, WorkflowStepState::default())?;
+					}
+				} else {
+					tx.delete(&step_key);
+				}
 
-								// We ignore keys we don't need (like tags)
-							}
-							// Insert final event
-							if !current_event.location.is_empty() {
-								events_by_location
-									.entry(current_event.location.root())
-									.or_default()
-									.push(Event::try_from(current_event)?);
-							}
+				Ok(())
+			})
+			.instrument(tracing::info_span!("set_workflow_step_mode_tx"))
+			.await?;
 
-							Ok(events_by_location)
-						}
-					)?;
+		// Release the workflow if it is paused
+		if !enabled {
+			self.bump(BumpSubSubject::WorkflowStep { workflow_id });
+		}
 
-					let Some(wf) = wf else {
-						return Ok(None);
-					};
+		Ok(())
+	}
 
-					let mut flat_events =
-						events.into_iter().flat_map(|(_, v)| v).collect::<Vec<_>>();
-					flat_events.sort_by(|a, b| a.location.cmp(&b.location));
+	#[tracing::instrument(skip_all)]
+	async fn get_workflow_step(&self, workflow_id: Id) -> Result<Option<WorkflowStepState>> {
+		self.pools
+			.udb()?
+			.txn("gas_debug_get_workflow_step", |tx| async move {
+				let tx = tx.with_subspace(self.subspace.clone());
 
-					Ok(Some(HistoryData {
-						wf,
-						events: flat_events,
-					}))
-				}
+				tx.read_opt(&keys::workflow::StepKey::new(workflow_id), Snapshot)
+					.await
 			})
-			.instrument(tracing::info_span!("pull_workflow_history_tx"))
+			.instrument(tracing::info_span!("get_workflow_step_tx"))
 			.await
 			.map_err(Into::into)
 	}
 
+	#[tracing::instrument(skip_all)]
+	async fn continue_workflow_step(&self, workflow_id: Id) -> Result<bool> {
+		let continued = self
+			.pools
+			.udb()?
+			.txn("gas_debug_continue_workflow_step", |tx| async move {
+				let tx = tx.with_subspace(self.subspace.clone());
+
+				let step_key = keys::workflow::StepKey::new(workflow_id);
+
+				let Some(mut step_state) = tx.read_opt(&step_key, Serializable).await? else {
+					return Ok(false);
+				};
+				let Some(pause_point) = &mut step_state.pause_point else {
+					return Ok(false);
+				};
+				if pause_point.continued {
+					return Ok(false);
+				}
+
+				pause_point.continued = true;
+				tx.write(&step_key, step_state)?;
+
+				Ok(true)
+			})
+			.instrument(tracing::info_span!("continue_workflow_step_tx"))
+			.await?;
+
+		if continued {
+			self.bump(BumpSubSubject::WorkflowStep { workflow_id });
+		}
+
+		Ok(continued)
+	}
+
 	#[tracing::instrument(skip_all)]
 	async fn get_signals(&self, signal_ids: Vec<Id>) -> Result<Vec<SignalData>> {
 		self.pools
@@ -1225,8 +1615,7 @@ impl DatabaseDebug for DatabaseKv {
 		dry_run: bool,
 		parallelization: u16,
 	) -> Result<usize> {
-		ensure!(parallelization > 0);
-		ensure!(parallelization < 1024);
+		validate_parallelization(parallelization)?;
 
 		let chunk_size = u128::MAX / parallelization as u128;
 		let mut futs = FuturesUnordered::new();
@@ -1252,6 +1641,40 @@ impl DatabaseDebug for DatabaseKv {
 		Ok(total)
 	}
 
+	#[tracing::instrument(skip_all)]
+	async fn silence_workflows_by_filter(
+		&self,
+		names: &[&str],
+		before_ts: i64,
+		dry_run: bool,
+		parallelization: u16,
+	) -> Result<usize> {
+		validate_parallelization(parallelization)?;
+
+		let chunk_size = u128::MAX / parallelization as u128;
+		let mut futs = FuturesUnordered::new();
+
+		for i in 0..parallelization as u128 {
+			let start = i * chunk_size;
+			futs.push(self.silence_workflows_by_filter_inner(
+				names,
+				before_ts,
+				dry_run,
+				start,
+				start + chunk_size,
+			));
+		}
+
+		let mut total = 0;
+		while let Some(res) = futs.next().await {
+			total += res?;
+		}
+
+		tracing::info!(?total, "workflows silenced");
+
+		Ok(total)
+	}
+
 	#[tracing::instrument(skip_all)]
 	async fn prune_workflows(
 		&self,
@@ -1335,6 +1758,30 @@ impl DatabaseDebug for DatabaseKv {
 						// Remove prune idx entry
 						tx.delete(&prune_key);
 
+						// Clear the idempotency key the workflow was dispatched with. Only cleared if it
+						// still points at this workflow, a newer dispatch may have taken it over.
+						let workflow_idempotency_key =
+							keys::workflow::WorkflowIdempotencyKey::new(prune_key.workflow_id);
+						if let Some(idempotency_key) =
+							tx.read_opt(&workflow_idempotency_key, Serializable).await?
+						{
+							let workflow_name = tx
+								.read(
+									&keys::workflow::NameKey::new(prune_key.workflow_id),
+									Serializable,
+								)
+								.await?;
+							let idempotency_key =
+								keys::workflow::IdempotencyKey::new(workflow_name, idempotency_key);
+
+							if tx.read_opt(&idempotency_key, Serializable).await?
+								== Some(prune_key.workflow_id)
+							{
+								tx.delete(&idempotency_key);
+							}
+							tx.delete(&workflow_idempotency_key);
+						}
+
 						match prune_key.variant {
 							PruneVariant::All => {
 								let data_subspace =
@@ -1409,8 +1856,7 @@ impl DatabaseDebug for DatabaseKv {
 		dry_run: bool,
 		parallelization: u16,
 	) -> Result<usize> {
-		ensure!(parallelization > 0);
-		ensure!(parallelization < 1024);
+		validate_parallelization(parallelization)?;
 
 		let chunk_size = u128::MAX / parallelization as u128;
 		let mut futs = FuturesUnordered::new();
@@ -1445,8 +1891,7 @@ impl DatabaseDebug for DatabaseKv {
 		parallelization: u16,
 		max_per_txn: Option<usize>,
 	) -> Result<usize> {
-		ensure!(parallelization > 0);
-		ensure!(parallelization < 1024);
+		validate_parallelization(parallelization)?;
 
 		let chunk_size = u128::MAX / parallelization as u128;
 		let mut futs = FuturesUnordered::new();
@@ -1482,18 +1927,64 @@ impl DatabaseKv {
 		dry_run: bool,
 		start: u128,
 		end: u128,
+	) -> Result<usize> {
+		self.dead_workflows_by_filter_inner(
+			names,
+			DeadWorkflowFilter::ErrorLike(error_like),
+			DeadWorkflowAction::Wake,
+			dry_run,
+			start,
+			end,
+		)
+		.await
+	}
+
+	pub async fn silence_workflows_by_filter_inner(
+		&self,
+		names: &[&str],
+		before_ts: i64,
+		dry_run: bool,
+		start: u128,
+		end: u128,
+	) -> Result<usize> {
+		self.dead_workflows_by_filter_inner(
+			names,
+			DeadWorkflowFilter::CreatedBefore(before_ts),
+			DeadWorkflowAction::Silence,
+			dry_run,
+			start,
+			end,
+		)
+		.await
+	}
+
+	/// Scans the workflows in the given id range in batches and wakes or silences the dead ones with
+	/// one of the given names that match the filter.
+	async fn dead_workflows_by_filter_inner(
+		&self,
+		names: &[&str],
+		filter: DeadWorkflowFilter<'_>,
+		action: DeadWorkflowAction,
+		dry_run: bool,
+		start: u128,
+		end: u128,
 	) -> Result<usize> {
 		let mut total = 0;
-		let mut current_workflow_id = Some(Id::v1(Uuid::from_u128(start), self.config.dc_label()));
+		// The next batch starts after this workflow
+		let mut cursor = Id::v1(Uuid::from_u128(start), self.config.dc_label());
 		let end_workflow_id = Id::v1(Uuid::from_u128(end), self.config.dc_label());
+		let tag = match action {
+			DeadWorkflowAction::Wake => "revive_workflows",
+			DeadWorkflowAction::Silence => "silence_workflows",
+		};
 
 		loop {
-			let (new_current_workflow_id, workflow_ids) = self.pools
+			let (new_cursor, workflow_ids) = self.pools
 				.udb()?
-				.txn("gas_debug_revive_workflows", |tx| {
+				.txn("gas_debug_find_dead_workflows", |tx| {
 					async move {
 						for name in names {
-							tx.tag(&format!("revive_workflows:{name}"))?;
+							tx.tag(&format!("{tag}:{name}"))?;
 						}
 
 						let mut workflow_ids = Vec::new();
@@ -1501,20 +1992,12 @@ impl DatabaseKv {
 						let key_end = keys::workflow::DataSubspaceKey::new_with_workflow_id(end_workflow_id);
 						let end = self.subspace.subspace(&key_end).range().1;
 
-						let start = if let Some(current_workflow_id) = current_workflow_id {
-							let key_start = keys::workflow::DataSubspaceKey::new_with_workflow_id(current_workflow_id);
-							let mut bytes = self.subspace.subspace(&key_start).range().0;
-
-							if let Some(b) = bytes.last_mut() {
-								*b = 255;
-							}
-
-							bytes
-						} else {
-							let entire_subspace_key = keys::workflow::DataSubspaceKey::new();
+						let key_start = keys::workflow::DataSubspaceKey::new_with_workflow_id(cursor);
+						let mut start = self.subspace.subspace(&key_start).range().0;
 
-							self.subspace.subspace(&entire_subspace_key).range().0
-						};
+						if let Some(b) = start.last_mut() {
+							*b = 255;
+						}
 
 						let mut stream = tx.get_ranges_keyvalues(
 							RangeOption {
@@ -1525,10 +2008,12 @@ impl DatabaseKv {
 						);
 
 						let mut workflows_processed = 0;
+						// Last workflow whose keys were all read
+						let mut last_complete_workflow_id = None;
 						let mut current_workflow_id = None;
 						let mut name_matches = false;
 						let mut state_matches = true;
-						let mut error_matches = error_like.is_empty();
+						let mut filter_matches = filter.default_matches();
 
 						let fut = async {
 							while let Some(entry) = stream.try_next().await? {
@@ -1537,16 +2022,17 @@ impl DatabaseKv {
 								if let Some(curr) = current_workflow_id {
 									if workflow_id != curr {
 										// Save if matches query
-										if name_matches && state_matches && error_matches {
+										if name_matches && state_matches && filter_matches {
 											workflow_ids.push(curr);
 										}
 
 										workflows_processed += 1;
+										last_complete_workflow_id = Some(curr);
 
 										// Reset state
 										name_matches = false;
 										state_matches = true;
-										error_matches = error_like.is_empty();
+										filter_matches = filter.default_matches();
 									}
 								}
 
@@ -1578,55 +2064,81 @@ impl DatabaseKv {
 									.unpack::<keys::workflow::SilenceTsKey>(entry.key())
 								{
 									state_matches = false;
-								} else if let Ok(error_key) = self
-									.subspace
-									.unpack::<keys::workflow::ErrorKey>(entry.key())
-								{
-									let error = error_key.deserialize(entry.value())?.to_lowercase();
-
-									error_matches = error_like.is_empty() || error_like.iter().any(|err| error.contains(err));
+								} else {
+									match filter {
+										DeadWorkflowFilter::ErrorLike(error_like) => {
+											if let Ok(error_key) = self
+												.subspace
+												.unpack::<keys::workflow::ErrorKey>(entry.key())
+											{
+												let error = error_key.deserialize(entry.value())?.to_lowercase();
+
+												filter_matches = error_like.is_empty() || error_like.iter().any(|err| error.contains(err));
+											}
+										}
+										DeadWorkflowFilter::CreatedBefore(before_ts) => {
+											if let Ok(create_ts_key) = self
+												.subspace
+												.unpack::<keys::workflow::CreateTsKey>(entry.key())
+											{
+												let create_ts = create_ts_key.deserialize(entry.value())?;
+
+												filter_matches = create_ts < before_ts;
+											}
+										}
+									}
 								}
 							}
 
-							if let (Some(workflow_id), true) = (
-								current_workflow_id,
-								name_matches && state_matches && error_matches,
-							) {
-								workflow_ids.push(workflow_id);
-								current_workflow_id = None;
-							}
+							// Reached the end of the range so the last workflow is complete
+							if let Some(workflow_id) = current_workflow_id {
+								if name_matches && state_matches && filter_matches {
+									workflow_ids.push(workflow_id);
+								}
 
-							if current_workflow_id.is_some() {
 								workflows_processed += 1;
 							}
 
 							anyhow::Ok(())
 						};
 
-						match tokio::time::timeout(EARLY_TXN_TIMEOUT, fut).await {
-							Ok(res) => res?,
-							Err(_) => tracing::debug!("timed out reading workflows"),
-						}
+						let new_cursor = match tokio::time::timeout(EARLY_TXN_TIMEOUT, fut).await {
+							Ok(res) => {
+								res?;
+
+								None
+							}
+							Err(_) => {
+								tracing::debug!("timed out reading workflows");
+
+								// Re-read the partially read workflow in the next batch. Skip it if it's the
+								// only one read so the scan still makes progress.
+								last_complete_workflow_id.or(current_workflow_id).or(Some(cursor))
+							}
+						};
 
 						tracing::info!(?workflows_processed, matching_workflows=?workflow_ids.len(), "batch processed workflows");
 
-						Ok((current_workflow_id, workflow_ids))
+						Ok((new_cursor, workflow_ids))
 					}
 				})
 				.instrument(tracing::info_span!("find_dead_workflows_tx"))
 				.await?;
 
-			current_workflow_id = new_current_workflow_id;
 			total += workflow_ids.len();
 
 			if !dry_run {
-				self.wake_workflows(workflow_ids).await?;
+				match action {
+					DeadWorkflowAction::Wake => self.wake_workflows(workflow_ids).await?,
+					DeadWorkflowAction::Silence => self.silence_workflows(workflow_ids).await?,
+				}
 			}
 
-			if current_workflow_id.is_none() {
+			let Some(new_cursor) = new_cursor else {
 				tracing::info!("reached end of workflows");
 				break;
-			}
+			};
+			cursor = new_cursor;
 		}
 
 		Ok(total)
@@ -2055,6 +2567,67 @@ impl WorkflowHistoryEventBuilder {
 			indexed_input_chunks: Vec::new(),
 		}
 	}
+
+	fn issue(&self, kind: HistoryIssueKind) -> HistoryIssue {
+		HistoryIssue {
+			location: self.location.clone(),
+			forgotten: self.forgotten,
+			kind,
+		}
+	}
+}
+
+/// Deserializes the value of a history key, returning an issue instead of an error.
+fn read_history_value<K: FormalKey>(
+	key: &K,
+	entry: &Value,
+	field: &str,
+) -> std::result::Result<K::Value, HistoryIssueKind> {
+	key.deserialize(entry.value())
+		.map_err(|err| HistoryIssueKind::InvalidEventData {
+			error: format!("failed to deserialize {field}: {err}"),
+		})
+}
+
+/// Reports orphaned chunks and any data the event's type requires that is missing or invalid.
+fn check_history_event(event: WorkflowHistoryEventBuilder, issues: &mut Vec<HistoryIssue>) {
+	if event.event_type.is_none() {
+		if !event.input_chunks.is_empty() {
+			issues.push(event.issue(HistoryIssueKind::OrphanedChunk {
+				field: "input".to_string(),
+			}));
+		}
+		if !event.output_chunks.is_empty() {
+			issues.push(event.issue(HistoryIssueKind::OrphanedChunk {
+				field: "output".to_string(),
+			}));
+		}
+	}
+
+	// Every indexed input must have a matching indexed name
+	for index in event.indexed_names.len()..event.indexed_input_chunks.len() {
+		issues.push(event.issue(HistoryIssueKind::OrphanedChunk {
+			field: format!("input[{index}]"),
+		}));
+	}
+
+	let location = event.location.clone();
+	let forgotten = event.forgotten;
+
+	if let Err(err) = Event::try_from(event) {
+		issues.push(HistoryIssue {
+			location,
+			forgotten,
+			kind: match err {
+				WorkflowError::MissingEventData(field) => HistoryIssueKind::MissingEventData {
+					field: field.to_string(),
+				},
+				err => HistoryIssueKind::InvalidEventData {
+					error: format!("{:#}", anyhow::Error::from(err)),
+				},
+			},
+		});
+	}
 }
 
 impl TryFrom<WorkflowHistoryEventBuilder> for Event {
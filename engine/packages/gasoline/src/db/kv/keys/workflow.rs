@@ -4,7 +4,7 @@ use anyhow::*;
 use rivet_util::Id;
 use universaldb::prelude::*;
 
-use crate::workflow::PruneVariant;
+use crate::{db::debug::WorkflowStepState, workflow::PruneVariant};
 
 #[derive(Debug)]
 pub struct LeaseKey {
@@ -322,6 +322,11 @@ impl<'de> TupleUnpack<'de> for OutputChunkKey {
 	}
 }
 
+/// States larger than this are compressed before being written.
+const STATE_COMPRESSION_THRESHOLD: usize = 4 * 1024;
+/// Prefix of compressed states. Never the first byte of JSON.
+const COMPRESSED_STATE_MARKER: u8 = 0;
+
 pub struct StateKey {
 	workflow_id: Id,
 }
@@ -331,10 +336,25 @@ impl StateKey {
 		StateKey { workflow_id }
 	}
 
-	pub fn split_ref(&self, value: &serde_json::value::RawValue) -> Result<Vec<Vec<u8>>> {
-		Ok(value
-			.get()
-			.as_bytes()
+	/// Splits the state into chunks, compressing it first if `compress` is set and it is large.
+	pub fn split_ref(
+		&self,
+		value: &serde_json::value::RawValue,
+		compress: bool,
+	) -> Result<Vec<Vec<u8>>> {
+		let raw = value.get().as_bytes();
+
+		let compressed;
+		let buf = if compress && raw.len() > STATE_COMPRESSION_THRESHOLD {
+			compressed = std::iter::once(COMPRESSED_STATE_MARKER)
+				.chain(lz4_flex::compress_prepend_size(raw))
+				.collect::<Vec<_>>();
+			&compressed
+		} else {
+			raw
+		};
+
+		Ok(buf
 			.chunks(universaldb::utils::CHUNK_SIZE)
 			.map(|x| x.to_vec())
 			.collect())
@@ -353,18 +373,26 @@ impl FormalChunkedKey for StateKey {
 	}
 
 	fn combine(&self, chunks: Vec<Value>) -> Result<Self::Value> {
-		serde_json::value::RawValue::from_string(String::from_utf8(
-			chunks
-				.iter()
-				.map(|x| x.value().iter().map(|x| *x))
-				.flatten()
-				.collect(),
-		)?)
-		.context("failed to combine `StateKey`")
+		let buf = chunks
+			.iter()
+			.map(|x| x.value().iter().map(|x| *x))
+			.flatten()
+			.collect::<Vec<_>>();
+
+		// States written before compression was added are never prefixed with the marker
+		let buf = if let Some((&COMPRESSED_STATE_MARKER, compressed)) = buf.split_first() {
+			lz4_flex::decompress_size_prepended(compressed)
+				.context("failed to decompress `StateKey`")?
+		} else {
+			buf
+		};
+
+		serde_json::value::RawValue::from_string(String::from_utf8(buf)?)
+			.context("failed to combine `StateKey`")
 	}
 
 	fn split(&self, value: Self::Value) -> Result<Vec<Vec<u8>>> {
-		self.split_ref(value.as_ref())
+		self.split_ref(value.as_ref(), false)
 	}
 }
 
@@ -806,6 +834,10 @@ impl PendingSignalKey {
 	pub fn subspace(workflow_id: Id, signal_name: String) -> PendingSignalSubspaceKey {
 		PendingSignalSubspaceKey::new(workflow_id, signal_name)
 	}
+
+	pub fn subspace_without_name(workflow_id: Id) -> PendingSignalSubspaceKey {
+		PendingSignalSubspaceKey::new_without_name(workflow_id)
+	}
 }
 
 impl FormalKey for PendingSignalKey {
@@ -856,14 +888,21 @@ impl<'de> TupleUnpack<'de> for PendingSignalKey {
 
 pub struct PendingSignalSubspaceKey {
 	workflow_id: Id,
-	signal_name: String,
+	signal_name: Option<String>,
 }
 
 impl PendingSignalSubspaceKey {
 	pub fn new(workflow_id: Id, signal_name: String) -> Self {
 		PendingSignalSubspaceKey {
 			workflow_id,
-			signal_name,
+			signal_name: Some(signal_name),
+		}
+	}
+
+	pub fn new_without_name(workflow_id: Id) -> Self {
+		PendingSignalSubspaceKey {
+			workflow_id,
+			signal_name: None,
 		}
 	}
 }
@@ -874,14 +913,16 @@ impl TuplePack for PendingSignalSubspaceKey {
 		w: &mut W,
 		tuple_depth: TupleDepth,
 	) -> std::io::Result<VersionstampOffset> {
-		let t = (
-			WORKFLOW,
-			SIGNAL,
-			self.workflow_id,
-			PENDING,
-			&self.signal_name,
-		);
-		t.pack(w, tuple_depth)
+		let mut offset = VersionstampOffset::None { size: 0 };
+
+		let t = (WORKFLOW, SIGNAL, self.workflow_id, PENDING);
+		offset += t.pack(w, tuple_depth)?;
+
+		if let Some(signal_name) = &self.signal_name {
+			offset += signal_name.pack(w, tuple_depth)?;
+		}
+
+		Ok(offset)
 	}
 }
 
@@ -1010,6 +1051,113 @@ impl TuplePack for ByNameAndTagSubspaceKey {
 	}
 }
 
+#[derive(Debug)]
+pub struct IdempotencyKey {
+	workflow_name: String,
+	idempotency_key: String,
+}
+
+impl IdempotencyKey {
+	pub fn new(workflow_name: String, idempotency_key: String) -> Self {
+		IdempotencyKey {
+			workflow_name,
+			idempotency_key,
+		}
+	}
+}
+
+impl FormalKey for IdempotencyKey {
+	/// Id of the workflow dispatched with this idempotency key.
+	type Value = Id;
+
+	fn deserialize(&self, raw: &[u8]) -> Result<Self::Value> {
+		Ok(Id::from_slice(raw)?)
+	}
+
+	fn serialize(&self, value: Self::Value) -> Result<Vec<u8>> {
+		Ok(value.as_bytes().to_vec())
+	}
+}
+
+impl TuplePack for IdempotencyKey {
+	fn pack<W: std::io::Write>(
+		&self,
+		w: &mut W,
+		tuple_depth: TupleDepth,
+	) -> std::io::Result<VersionstampOffset> {
+		let t = (
+			WORKFLOW,
+			IDEMPOTENCY_KEY,
+			&self.workflow_name,
+			&self.idempotency_key,
+		);
+		t.pack(w, tuple_depth)
+	}
+}
+
+impl<'de> TupleUnpack<'de> for IdempotencyKey {
+	fn unpack(input: &[u8], tuple_depth: TupleDepth) -> PackResult<(&[u8], Self)> {
+		let (input, (_, _, workflow_name, idempotency_key)) =
+			<(usize, usize, String, String)>::unpack(input, tuple_depth)?;
+		let v = IdempotencyKey {
+			workflow_name,
+			idempotency_key,
+		};
+
+		Ok((input, v))
+	}
+}
+
+/// Idempotency key the workflow was dispatched with. Used to clear its `IdempotencyKey` once the
+/// workflow is pruned.
+#[derive(Debug)]
+pub struct WorkflowIdempotencyKey {
+	workflow_id: Id,
+}
+
+impl WorkflowIdempotencyKey {
+	pub fn new(workflow_id: Id) -> Self {
+		WorkflowIdempotencyKey { workflow_id }
+	}
+}
+
+impl FormalKey for WorkflowIdempotencyKey {
+	type Value = String;
+
+	fn deserialize(&self, raw: &[u8]) -> Result<Self::Value> {
+		String::from_utf8(raw.to_vec()).map_err(Into::into)
+	}
+
+	fn serialize(&self, value: Self::Value) -> Result<Vec<u8>> {
+		Ok(value.into_bytes())
+	}
+}
+
+impl TuplePack for WorkflowIdempotencyKey {
+	fn pack<W: std::io::Write>(
+		&self,
+		w: &mut W,
+		tuple_depth: TupleDepth,
+	) -> std::io::Result<VersionstampOffset> {
+		let t = (WORKFLOW, DATA, self.workflow_id, IDEMPOTENCY_KEY);
+		t.pack(w, tuple_depth)
+	}
+}
+
+impl<'de> TupleUnpack<'de> for WorkflowIdempotencyKey {
+	fn unpack(input: &[u8], tuple_depth: TupleDepth) -> PackResult<(&[u8], Self)> {
+		let (input, (_, _, workflow_id, data)) =
+			<(usize, usize, Id, usize)>::unpack(input, tuple_depth)?;
+		if data != IDEMPOTENCY_KEY {
+			return Err(PackError::Message("expected IDEMPOTENCY_KEY data".into()));
+		}
+
+		let v = WorkflowIdempotencyKey { workflow_id };
+
+		Ok((input, v))
+	}
+}
+
 #[derive(Debug)]
 pub struct HasWakeConditionKey {
 	pub workflow_id: Id,
@@ -1192,6 +1340,191 @@ impl<'de> TupleUnpack<'de> for SilenceTsKey {
 	}
 }
 
+#[derive(Debug)]
+pub struct StepKey {
+	workflow_id: Id,
+}
+
+impl StepKey {
+	pub fn new(workflow_id: Id) -> Self {
+		StepKey { workflow_id }
+	}
+}
+
+impl FormalKey for StepKey {
+	type Value = WorkflowStepState;
+
+	fn deserialize(&self, raw: &[u8]) -> Result<Self::Value> {
+		serde_json::from_slice(raw).map_err(Into::into)
+	}
+
+	fn serialize(&self, value: Self::Value) -> Result<Vec<u8>> {
+		serde_json::to_vec(&value).map_err(Into::into)
+	}
+}
+
+impl TuplePack for StepKey {
+	fn pack<W: std::io::Write>(
+		&self,
+		w: &mut W,
+		tuple_depth: TupleDepth,
+	) -> std::io::Result<VersionstampOffset> {
+		let t = (WORKFLOW, DATA, self.workflow_id, STEP);
+		t.pack(w, tuple_depth)
+	}
+}
+
+impl<'de> TupleUnpack<'de> for StepKey {
+	fn unpack(input: &[u8], tuple_depth: TupleDepth) -> PackResult<(&[u8], Self)> {
+		let (input, (_, _, workflow_id, data)) =
+			<(usize, usize, Id, usize)>::unpack(input, tuple_depth)?;
+		if data != STEP {
+			return Err(PackError::Message("expected STEP data".into()));
+		}
+
+		let v = StepKey { workflow_id };
+
+		Ok((input, v))
+	}
+}
+
+#[derive(Debug)]
+pub struct ParentWorkflowIdKey {
+	workflow_id: Id,
+}
+
+impl ParentWorkflowIdKey {
+	pub fn new(workflow_id: Id) -> Self {
+		ParentWorkflowIdKey { workflow_id }
+	}
+}
+
+impl FormalKey for ParentWorkflowIdKey {
+	/// Id of the workflow that dispatched this workflow as a sub workflow.
+	type Value = Id;
+
+	fn deserialize(&self, raw: &[u8]) -> Result<Self::Value> {
+		Ok(Id::from_slice(raw)?)
+	}
+
+	fn serialize(&self, value: Self::Value) -> Result<Vec<u8>> {
+		Ok(value.as_bytes().to_vec())
+	}
+}
+
+impl TuplePack for ParentWorkflowIdKey {
+	fn pack<W: std::io::Write>(
+		&self,
+		w: &mut W,
+		tuple_depth: TupleDepth,
+	) -> std::io::Result<VersionstampOffset> {
+		let t = (WORKFLOW, DATA, self.workflow_id, PARENT_WORKFLOW_ID);
+		t.pack(w, tuple_depth)
+	}
+}
+
+impl<'de> TupleUnpack<'de> for ParentWorkflowIdKey {
+	fn unpack(input: &[u8], tuple_depth: TupleDepth) -> PackResult<(&[u8], Self)> {
+		let (input, (_, _, workflow_id, data)) =
+			<(usize, usize, Id, usize)>::unpack(input, tuple_depth)?;
+		if data != PARENT_WORKFLOW_ID {
+			return Err(PackError::Message(
+				"expected PARENT_WORKFLOW_ID data".into(),
+			));
+		}
+
+		let v = ParentWorkflowIdKey { workflow_id };
+
+		Ok((input, v))
+	}
+}
+
+#[derive(Debug)]
+pub struct ChildWorkflowKey {
+	workflow_id: Id,
+	pub child_workflow_id: Id,
+}
+
+impl ChildWorkflowKey {
+	pub fn new(workflow_id: Id, child_workflow_id: Id) -> Self {
+		ChildWorkflowKey {
+			workflow_id,
+			child_workflow_id,
+		}
+	}
+
+	pub fn subspace(workflow_id: Id) -> ChildWorkflowSubspaceKey {
+		ChildWorkflowSubspaceKey::new(workflow_id)
+	}
+}
+
+impl FormalKey for ChildWorkflowKey {
+	type Value = ();
+
+	fn deserialize(&self, _raw: &[u8]) -> Result<Self::Value> {
+		Ok(())
+	}
+
+	fn serialize(&self, _value: Self::Value) -> Result<Vec<u8>> {
+		Ok(Vec::new())
+	}
+}
+
+impl TuplePack for ChildWorkflowKey {
+	fn pack<W: std::io::Write>(
+		&self,
+		w: &mut W,
+		tuple_depth: TupleDepth,
+	) -> std::io::Result<VersionstampOffset> {
+		let t = (
+			WORKFLOW,
+			DATA,
+			self.workflow_id,
+			CHILD_WORKFLOW,
+			self.child_workflow_id,
+		);
+		t.pack(w, tuple_depth)
+	}
+}
+
+impl<'de> TupleUnpack<'de> for ChildWorkflowKey {
+	fn unpack(input: &[u8], tuple_depth: TupleDepth) -> PackResult<(&[u8], Self)> {
+		let (input, (_, _, workflow_id, data, child_workflow_id)) =
+			<(usize, usize, Id, usize, Id)>::unpack(input, tuple_depth)?;
+		if data != CHILD_WORKFLOW {
+			return Err(PackError::Message("expected CHILD_WORKFLOW data".into()));
+		}
+
+		let v = ChildWorkflowKey {
+			workflow_id,
+			child_workflow_id,
+		};
+
+		Ok((input, v))
+	}
+}
+
+pub struct ChildWorkflowSubspaceKey {
+	workflow_id: Id,
+}
+
+impl ChildWorkflowSubspaceKey {
+	pub fn new(workflow_id: Id) -> Self {
+		ChildWorkflowSubspaceKey { workflow_id }
+	}
+}
+
+impl TuplePack for ChildWorkflowSubspaceKey {
+	fn pack<W: std::io::Write>(
+		&self,
+		w: &mut W,
+		tuple_depth: TupleDepth,
+	) -> std::io::Result<VersionstampOffset> {
+		let t = (WORKFLOW, DATA, self.workflow_id, CHILD_WORKFLOW);
+		t.pack(w, tuple_depth)
+	}
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub enum Metric {
 	/// Count (signal name)
@@ -1519,3 +1852,69 @@ impl TuplePack for PruneIdxSubspaceKey {
 		Ok(offset)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use rivet_util::Id;
+	use universaldb::prelude::*;
+
+	use super::{COMPRESSED_STATE_MARKER, STATE_COMPRESSION_THRESHOLD, StateKey};
+
+	fn roundtrip(state_key: &StateKey, raw: &str, compress: bool) -> (usize, String) {
+		let value = serde_json::value::RawValue::from_string(raw.to_string()).unwrap();
+		let chunks = state_key.split_ref(&value, compress).unwrap();
+		let stored = chunks.iter().map(|x| x.len()).sum();
+
+		let combined = state_key
+			.combine(
+				chunks
+					.into_iter()
+					.map(|chunk| Value::new(Vec::new(), chunk))
+					.collect(),
+			)
+			.unwrap();
+
+		(stored, combined.get().to_string())
+	}
+
+	#[test]
+	fn small_state_is_not_compressed() {
+		let state_key = StateKey::new(Id::nil());
+		let raw = r#"{"value":1}"#;
+
+		let (stored, combined) = roundtrip(&state_key, raw, true);
+		assert_eq!(stored, raw.len());
+		assert_eq!(combined, raw);
+	}
+
+	#[test]
+	fn large_state_is_compressed() {
+		let state_key = StateKey::new(Id::nil());
+		let raw = format!(
+			r#"{{"items":[{}]}}"#,
+			vec![r#"{"name":"actor","generation":0}"#; 1000].join(",")
+		);
+		assert!(raw.len() > STATE_COMPRESSION_THRESHOLD);
+
+		let value = serde_json::value::RawValue::from_string(raw.clone()).unwrap();
+		let chunks = state_key.split_ref(&value, true).unwrap();
+		assert_eq!(chunks[0][0], COMPRESSED_STATE_MARKER);
+
+		let (stored, combined) = roundtrip(&state_key, &raw, true);
+		assert!(stored < raw.len() / 10);
+		assert_eq!(combined, raw);
+	}
+
+	#[test]
+	fn large_state_is_not_compressed_when_disabled() {
+		let state_key = StateKey::new(Id::nil());
+		let raw = format!(
+			r#"{{"items":[{}]}}"#,
+			vec![r#"{"name":"actor","generation":0}"#; 1000].join(",")
+		);
+
+		let (stored, combined) = roundtrip(&state_key, &raw, false);
+		assert_eq!(stored, raw.len());
+		assert_eq!(combined, raw);
+	}
+}
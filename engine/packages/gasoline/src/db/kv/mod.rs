@@ -18,7 +18,9 @@ use tracing::Instrument;
 use universaldb::prelude::*;
 use universaldb::utils::end_of_key_range;
 
-use super::{BumpSubSubject, Database, PulledWorkflowData, SignalData, WorkflowData};
+use super::{
+	BumpSubSubject, Database, PulledWorkflowData, SignalData, WorkflowData, debug::StepPausePoint,
+};
 use crate::{
 	error::{WorkflowError, WorkflowResult},
 	history::{
@@ -103,6 +105,35 @@ impl DatabaseKv {
 		Ok(())
 	}
 
+	/// Writes the parent/child indexes used to navigate workflow trees. Unlike the sub workflow wake
+	/// indexes, these are never cleared while the workflows exist.
+	fn write_sub_workflow_relationship(
+		&self,
+		workflow_id: Id,
+		sub_workflow_id: Id,
+		write_parent: bool,
+		tx: &universaldb::Transaction,
+	) -> Result<()> {
+		let child_workflow_key =
+			keys::workflow::ChildWorkflowKey::new(workflow_id, sub_workflow_id);
+
+		tx.set(
+			&self.subspace.pack(&child_workflow_key),
+			&child_workflow_key.serialize(())?,
+		);
+
+		if write_parent {
+			let parent_workflow_id_key = keys::workflow::ParentWorkflowIdKey::new(sub_workflow_id);
+
+			tx.set(
+				&self.subspace.pack(&parent_workflow_id_key),
+				&parent_workflow_id_key.serialize(workflow_id)?,
+			);
+		}
+
+		Ok(())
+	}
+
 	fn write_sub_workflow_wake_idx(
 		&self,
 		workflow_id: Id,
@@ -244,6 +275,7 @@ impl DatabaseKv {
 		tags: Option<&serde_json::Value>,
 		input: &serde_json::value::RawValue,
 		unique: bool,
+		idempotency_key: Option<&str>,
 		tx: &universaldb::Transaction,
 	) -> Result<Id> {
 		let tx = tx.with_subspace(self.subspace.clone());
@@ -260,6 +292,49 @@ impl DatabaseKv {
 			}
 		}
 
+		if let Some(idempotency_key_str) = idempotency_key {
+			let idempotency_key = keys::workflow::IdempotencyKey::new(
+				workflow_name.to_string(),
+				idempotency_key_str.to_string(),
+			);
+
+			if let Some(existing_workflow_id) = tx.read_opt(&idempotency_key, Serializable).await? {
+				let window_start = rivet_util::timestamp::now()
+					- self
+						.config
+						.runtime
+						.gasoline_idempotency_window()
+						.as_millis() as i64;
+
+				// Pruned workflows no longer have a create ts, in which case the key is reused
+				if let Some(create_ts) = tx
+					.read_opt(
+						&keys::workflow::CreateTsKey::new(existing_workflow_id),
+						Serializable,
+					)
+					.await?
+				{
+					if create_ts >= window_start {
+						tracing::debug!(
+							?existing_workflow_id,
+							"found existing workflow with idempotency key"
+						);
+						return Ok(existing_workflow_id);
+					}
+				}
+
+				// Window passed, release the key from the existing workflow before taking it over
+				self.clear_idempotency_key_inner(existing_workflow_id, workflow_name, &tx)
+					.await?;
+			}
+
+			tx.write(&idempotency_key, workflow_id)?;
+			tx.write(
+				&keys::workflow::WorkflowIdempotencyKey::new(workflow_id),
+				idempotency_key_str.to_string(),
+			)?;
+		}
+
 		tx.write(
 			&keys::workflow::CreateTsKey::new(workflow_id),
 			rivet_util::timestamp::now(),
@@ -354,6 +429,30 @@ impl DatabaseKv {
 		Ok(workflow_id)
 	}
 
+	/// Clears the idempotency key the workflow was dispatched with, if any. The key is only cleared if it
+	/// still points at this workflow, a newer dispatch may have taken it over.
+	async fn clear_idempotency_key_inner(
+		&self,
+		workflow_id: Id,
+		workflow_name: &str,
+		tx: &universaldb::Transaction,
+	) -> Result<()> {
+		let workflow_idempotency_key = keys::workflow::WorkflowIdempotencyKey::new(workflow_id);
+		let Some(idempotency_key) = tx.read_opt(&workflow_idempotency_key, Serializable).await?
+		else {
+			return Ok(());
+		};
+
+		let idempotency_key =
+			keys::workflow::IdempotencyKey::new(workflow_name.to_string(), idempotency_key);
+		if tx.read_opt(&idempotency_key, Serializable).await? == Some(workflow_id) {
+			tx.delete(&idempotency_key);
+		}
+		tx.delete(&workflow_idempotency_key);
+
+		Ok(())
+	}
+
 	async fn find_workflow_inner(
 		&self,
 		workflow_name: &str,
@@ -882,7 +981,7 @@ impl Database for DatabaseKv {
 		Ok(())
 	}
 
-	#[tracing::instrument(skip_all, fields(%workflow_id, %workflow_name, unique))]
+	#[tracing::instrument(skip_all, fields(%workflow_id, %workflow_name, unique, ?idempotency_key))]
 	async fn dispatch_workflow(
 		&self,
 		ray_id: Id,
@@ -891,6 +990,7 @@ impl Database for DatabaseKv {
 		tags: Option<&serde_json::Value>,
 		input: &serde_json::value::RawValue,
 		unique: bool,
+		idempotency_key: Option<&str>,
 	) -> WorkflowResult<Id> {
 		let workflow_id = self
 			.pools
@@ -906,6 +1006,7 @@ impl Database for DatabaseKv {
 					tags,
 					input,
 					unique,
+					idempotency_key,
 					&tx,
 				)
 				.await
@@ -2002,6 +2103,9 @@ impl Database for DatabaseKv {
 					tx.delete(&keys::workflow::LeaseKey::new(workflow_id));
 					tx.delete(&keys::workflow::WorkerIdKey::new(workflow_id));
 
+					// Clear step mode state
+					tx.delete(&keys::workflow::StepKey::new(workflow_id));
+
 					// Clear pending signals metric for observability
 					let metrics_subspace = self
 						.subspace
@@ -2052,6 +2156,22 @@ impl Database for DatabaseKv {
 						PruneVariant::None => {}
 					}
 
+					// Clear the idempotency key if its window has already passed, otherwise it is cleared when
+					// the key is reused or the workflow is pruned
+					let window_start = rivet_util::timestamp::now()
+						- self
+							.config
+							.runtime
+							.gasoline_idempotency_window()
+							.as_millis() as i64;
+					let create_ts = tx
+						.read_opt(&keys::workflow::CreateTsKey::new(workflow_id), Serializable)
+						.await?;
+					if create_ts.is_some_and(|create_ts| create_ts < window_start) {
+						self.clear_idempotency_key_inner(workflow_id, workflow_name, &tx)
+							.await?;
+					}
+
 					tx.write(
 						&keys::workflow::CompleteTsKey::new(workflow_id),
 						rivet_util::timestamp::now(),
@@ -2418,6 +2538,7 @@ impl Database for DatabaseKv {
 										signal_id: key.signal_id,
 										signal_name: key.signal_name,
 										create_ts: key.ts,
+										ack_ts: now,
 										body,
 									})
 								}
@@ -2470,7 +2591,16 @@ impl Database for DatabaseKv {
 
 			// If the error indicates the payload is too large, shrink the limit procedurally until it succeeds.
 			match res {
-				Ok(signals) => return Ok(signals),
+				Ok(signals) => {
+					// Recorded after the txn commits so that retries are not counted
+					for signal in &signals {
+						metrics::SIGNAL_ACK_LATENCY_SECONDS
+							.with_label_values(&[signal.signal_name.as_str()])
+							.observe((signal.ack_ts - signal.create_ts) as f64 / 1000.0);
+					}
+
+					return Ok(signals);
+				}
 				Err(err) => {
 					if universaldb::utils::error_is_transaction_too_large(&err) {
 						limit = (limit / 2).max(1);
@@ -2715,7 +2845,7 @@ impl Database for DatabaseKv {
 			.txn("gas_dispatch_sub_workflow", |tx| async move {
 				tx.tag(&format!("dispatch_workflow:{sub_workflow_name}"))?;
 
-				let sub_workflow_id = self
+				let dispatched_workflow_id = self
 					.dispatch_workflow_inner(
 						ray_id,
 						sub_workflow_id,
@@ -2723,10 +2853,21 @@ impl Database for DatabaseKv {
 						tags,
 						input,
 						unique,
+						None,
 						&tx,
 					)
 					.await?;
 
+				self.write_sub_workflow_relationship(
+					from_workflow_id,
+					dispatched_workflow_id,
+					// Existing unique workflows keep their original parent
+					dispatched_workflow_id == sub_workflow_id,
+					&tx,
+				)?;
+
+				let sub_workflow_id = dispatched_workflow_id;
+
 				// Insert history event
 				keys::history::insert::sub_workflow_event(
 					&self.subspace,
@@ -2773,7 +2914,11 @@ impl Database for DatabaseKv {
 					tx.clear_subspace_range(&state_subspace);
 
 					// Write new state
-					for (i, chunk) in state_key.split_ref(&state)?.into_iter().enumerate() {
+					for (i, chunk) in state_key
+						.split_ref(&state, self.config.runtime.gasoline_compress_state())?
+						.into_iter()
+						.enumerate()
+					{
 						let chunk_key = state_key.chunk(i);
 
 						tx.set(&self.subspace.pack(&chunk_key), &chunk);
@@ -3183,6 +3328,81 @@ impl Database for DatabaseKv {
 
 		Ok(())
 	}
+
+	#[tracing::instrument(skip_all)]
+	async fn pause_workflow_step(
+		&self,
+		workflow_id: Id,
+		location: &Location,
+		event_type: EventType,
+		name: Option<&str>,
+	) -> WorkflowResult<bool> {
+		self.pools
+			.udb()
+			.map_err(WorkflowError::PoolsGeneric)?
+			.txn("gas_pause_workflow_step", |tx| async move {
+				let tx = tx.with_subspace(self.subspace.clone());
+
+				let step_key = keys::workflow::StepKey::new(workflow_id);
+
+				// Step mode not enabled
+				let Some(mut step_state) = tx.read_opt(&step_key, Serializable).await? else {
+					return Ok(false);
+				};
+
+				// Already continued past this point (workflow was rerun after sleeping or being evicted)
+				if let Some(pause_point) = &step_state.pause_point {
+					if &pause_point.location == location && pause_point.continued {
+						return Ok(false);
+					}
+				}
+
+				step_state.pause_point = Some(StepPausePoint {
+					location: location.clone(),
+					event_type,
+					name: name.map(ToString::to_string),
+					paused_ts: rivet_util::timestamp::now(),
+					continued: false,
+				});
+
+				tx.write(&step_key, step_state)?;
+
+				Ok(true)
+			})
+			.custom_instrument(tracing::info_span!("pause_workflow_step_tx"))
+			.await
+			.context("failed to pause workflow step")
+			.map_err(WorkflowError::Udb)
+	}
+
+	#[tracing::instrument(skip_all)]
+	async fn is_workflow_step_paused(
+		&self,
+		workflow_id: Id,
+		location: &Location,
+	) -> WorkflowResult<bool> {
+		self.pools
+			.udb()
+			.map_err(WorkflowError::PoolsGeneric)?
+			.txn("gas_is_workflow_step_paused", |tx| async move {
+				let tx = tx.with_subspace(self.subspace.clone());
+
+				let step_state = tx
+					.read_opt(&keys::workflow::StepKey::new(workflow_id), Serializable)
+					.await?;
+
+				// Disabling step mode releases the workflow
+				Ok(step_state
+					.and_then(|step_state| step_state.pause_point)
+					.is_some_and(|pause_point| {
+						&pause_point.location == location && !pause_point.continued
+					}))
+			})
+			.custom_instrument(tracing::info_span!("is_workflow_step_paused_tx"))
+			.await
+			.context("failed to read workflow step state")
+			.map_err(WorkflowError::Udb)
+	}
 }
 
 impl Drop for DatabaseKv {
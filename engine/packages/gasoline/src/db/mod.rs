@@ -85,7 +85,8 @@ pub trait Database: Send {
 	// MARK: Workflows/signals
 
 	/// Writes a new workflow to the database. If unique is set, this should return the existing workflow ID
-	/// (if one exists) instead of the given workflow ID.
+	/// (if one exists) instead of the given workflow ID. The same applies if a workflow with the same name
+	/// was dispatched with the given idempotency key within the configured idempotency window.
 	async fn dispatch_workflow(
 		&self,
 		ray_id: Id,
@@ -94,6 +95,7 @@ pub trait Database: Send {
 		tags: Option<&serde_json::Value>,
 		input: &serde_json::value::RawValue,
 		unique: bool,
+		idempotency_key: Option<&str>,
 	) -> WorkflowResult<Id>;
 
 	/// Retrieves workflows with the given IDs.
@@ -296,6 +298,23 @@ pub trait Database: Send {
 		inner_version: usize,
 		loop_location: Option<&Location>,
 	) -> WorkflowResult<()>;
+
+	/// Records that the workflow reached a pause point if step mode is enabled for it. Returns true if the
+	/// workflow has to wait until it is continued.
+	async fn pause_workflow_step(
+		&self,
+		workflow_id: Id,
+		location: &Location,
+		event_type: EventType,
+		name: Option<&str>,
+	) -> WorkflowResult<bool>;
+
+	/// Returns true if the workflow is still paused at the given location.
+	async fn is_workflow_step_paused(
+		&self,
+		workflow_id: Id,
+		location: &Location,
+	) -> WorkflowResult<bool>;
 }
 
 #[derive(Debug)]
@@ -352,12 +371,15 @@ pub struct SignalData {
 	pub signal_name: String,
 	pub body: Box<serde_json::value::RawValue>,
 	pub create_ts: i64,
+	/// When the signal was acknowledged (pulled by the workflow).
+	pub ack_ts: i64,
 }
 
 pub enum BumpSubSubject {
 	Worker,
 	WorkflowComplete { workflow_id: Id },
 	SignalPublish { to_workflow_id: Id },
+	WorkflowStep { workflow_id: Id },
 }
 
 impl std::fmt::Display for BumpSubSubject {
@@ -370,6 +392,9 @@ impl std::fmt::Display for BumpSubSubject {
 			BumpSubSubject::SignalPublish { to_workflow_id } => {
 				write!(f, "gasoline.signal.for-workflow.{to_workflow_id}")
 			}
+			BumpSubSubject::WorkflowStep { workflow_id } => {
+				write!(f, "gasoline.workflow.step.{workflow_id}")
+			}
 		}
 	}
 }
@@ -3,7 +3,7 @@ use std::time::{SystemTime, UNIX_EPOCH};
 use rivet_util::Id;
 use tokio::time::Instant;
 
-use crate::ctx::common::RETRY_TIMEOUT_MS;
+use crate::{ctx::common::RETRY_TIMEOUT_MS, history::location::Location};
 
 pub type WorkflowResult<T> = Result<T, WorkflowError>;
 
@@ -37,6 +37,9 @@ pub enum WorkflowError {
 	#[error("latent history found: {0}")]
 	LatentHistoryFound(String),
 
+	#[error("sandbox replay reached the end of recorded history at {0}")]
+	SandboxReplayEnded(Location),
+
 	#[error("serialize workflow input: {0}")]
 	SerializeWorkflowInput(#[source] serde_json::Error),
 
@@ -179,6 +182,12 @@ pub enum WorkflowError {
 
 	#[error("flush channel closed")]
 	FlushChannelClosed,
+
+	#[error(
+		"invalid parallelization {0}, must be between 1 and {max}",
+		max = crate::db::debug::MAX_PARALLELIZATION
+	)]
+	InvalidParallelization(u16),
 }
 
 impl WorkflowError {
@@ -1,7 +1,7 @@
 use std::ops::Deref;
 
 use rivet_util::Id;
-use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
 use strum::FromRepr;
 
 use super::location::Coordinate;
@@ -85,7 +85,7 @@ impl std::fmt::Display for EventData {
 	}
 }
 
-#[derive(Hash, Debug, Clone, Copy, PartialEq, Eq, FromRepr)]
+#[derive(Hash, Debug, Clone, Copy, PartialEq, Eq, FromRepr, Serialize, Deserialize)]
 pub enum EventType {
 	Activity = 0,
 	/// Deprecated.
@@ -178,13 +178,13 @@ impl LoopEvent {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct SleepEvent {
 	pub deadline_ts: i64,
 	pub state: SleepState,
 }
 
-#[derive(Debug, Clone, Hash, Copy, PartialEq, Eq, FromRepr)]
+#[derive(Debug, Clone, Hash, Copy, PartialEq, Eq, FromRepr, Serialize, Deserialize)]
 pub enum SleepState {
 	Normal = 0,
 	Uninterrupted = 1,
@@ -201,13 +201,13 @@ impl std::fmt::Display for SleepState {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct RemovedEvent {
 	pub event_type: EventType,
 	pub name: Option<String>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct VersionCheckEvent {
 	pub inner_version: usize,
 }
@@ -12,6 +12,7 @@ pub mod operation;
 pub mod prelude;
 pub mod pubsub_subjects;
 pub mod registry;
+pub mod sandbox;
 pub mod signal;
 mod stub;
 pub mod utils;
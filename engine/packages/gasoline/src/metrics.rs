@@ -166,6 +166,13 @@ lazy_static::lazy_static! {
 		BUCKETS.to_vec(),
 		*REGISTRY
 	).unwrap();
+	pub static ref SIGNAL_ACK_LATENCY_SECONDS: HistogramVec = register_histogram_vec_with_registry!(
+		"gasoline_signal_ack_latency_seconds",
+		"Time between the signal's creation and its acknowledgment by the receiving workflow.",
+		&["signal_name"],
+		BUCKETS.to_vec(),
+		*REGISTRY
+	).unwrap();
 	pub static ref SIGNAL_PUBLISHED: IntCounterVec = register_int_counter_vec_with_registry!(
 		"gasoline_signal_published",
 		"Total published signals.",
@@ -0,0 +1,179 @@
+//! Replays exported workflows against the current workflow code without side effects. Activities return
+//! their recorded outputs and nothing is committed to the database, so this can be used to catch breaking
+//! workflow changes before they are deployed.
+
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use rivet_util::Id;
+use serde::Serialize;
+use tokio::sync::watch;
+
+use crate::{
+	ctx::WorkflowCtx,
+	db::{
+		DatabaseHandle, PulledWorkflowData,
+		debug::{self, WorkflowExport},
+	},
+	error::WorkflowError,
+	history::{
+		event::{
+			ActivityEvent, Event, EventData, LoopEvent, MessageSendEvent, RemovedEvent,
+			SignalSendEvent, SignalsEvent, SleepEvent, SubWorkflowEvent, VersionCheckEvent,
+		},
+		location::Location,
+	},
+	registry::RegistryHandle,
+};
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case", tag = "result")]
+pub enum ReplayResult {
+	/// The workflow ran to completion using only recorded history.
+	Complete,
+	/// All recorded history was replayed successfully. The workflow would continue with a new event at
+	/// this location.
+	Compatible { next_location: Location },
+	/// The current workflow code does not match the recorded history.
+	Diverged { error: String },
+	/// The replay failed for a reason unrelated to history compatibility.
+	Failed { error: String },
+}
+
+/// Re-runs the recorded history of an exported workflow against the workflow code in the given registry.
+/// The database is only read from (e.g. to wait on sub workflow outputs), never written to.
+pub async fn replay_workflow(
+	registry: RegistryHandle,
+	db: DatabaseHandle,
+	config: rivet_config::Config,
+	pools: rivet_pools::Pools,
+	cache: rivet_cache::Cache,
+	export: &WorkflowExport,
+) -> Result<ReplayResult> {
+	let data = PulledWorkflowData {
+		workflow_id: export.workflow.workflow_id,
+		workflow_name: export.workflow.workflow_name.clone(),
+		create_ts: export.workflow.create_ts,
+		ray_id: Id::new_v1(config.dc_label()),
+		input: serde_json::value::to_raw_value(&export.workflow.input)?,
+		state: serde_json::value::to_raw_value(&export.workflow.data)?,
+		// Activity backoff is not applied in sandboxes
+		wake_deadline_ts: None,
+		events: history_from_export(&export.events)?,
+	};
+
+	// Held until the replay finishes, dropping the sender evicts the workflow
+	let (_stop_tx, stop_rx) = watch::channel(());
+
+	let ctx = WorkflowCtx::new_sandbox(registry, db, config, pools, cache, data, stop_rx)?;
+
+	let res = match ctx.run_sandbox().await {
+		Ok(_) => ReplayResult::Complete,
+		Err(WorkflowError::SandboxReplayEnded(next_location)) => {
+			ReplayResult::Compatible { next_location }
+		}
+		Err(
+			err @ (WorkflowError::HistoryDiverged(_)
+			| WorkflowError::LatentHistoryFound(_)
+			| WorkflowError::DeserializeWorkflowInput(_)
+			| WorkflowError::DeserializeActivityOutput(_)
+			| WorkflowError::DeserializeLoopState(_)
+			| WorkflowError::DeserializeLoopOutput(_)
+			| WorkflowError::DeserializeSignalBody(_)),
+		) => ReplayResult::Diverged {
+			error: err.to_string(),
+		},
+		Err(err) => ReplayResult::Failed {
+			error: err.to_string(),
+		},
+	};
+
+	Ok(res)
+}
+
+/// Converts exported history into the format used by `WorkflowCtx`. Forgotten events are skipped, same as
+/// when a workflow is pulled by a worker.
+fn history_from_export(events: &[debug::Event]) -> Result<HashMap<Location, Vec<Event>>> {
+	let mut events_by_location: HashMap<Location, Vec<Event>> = HashMap::new();
+
+	for event in events.iter().filter(|event| !event.forgotten) {
+		let data = match &event.data {
+			debug::EventData::Activity(activity) => EventData::Activity(ActivityEvent {
+				name: activity.name.clone(),
+				create_ts: event.create_ts,
+				output: activity
+					.output
+					.as_ref()
+					.map(serde_json::value::to_raw_value)
+					.transpose()?,
+				error_count: activity.errors.iter().map(|err| err.count).sum(),
+			}),
+			// Deprecated, manually convert to newer type
+			debug::EventData::Signal(signal) => EventData::Signals(SignalsEvent {
+				names: vec![signal.name.clone()],
+				bodies: vec![serde_json::value::to_raw_value(&signal.body)?],
+			}),
+			debug::EventData::SignalSend(signal_send) => EventData::SignalSend(SignalSendEvent {
+				signal_id: signal_send.signal_id,
+				name: signal_send.name.clone(),
+			}),
+			debug::EventData::MessageSend(message_send) => {
+				EventData::MessageSend(MessageSendEvent {
+					name: message_send.name.clone(),
+				})
+			}
+			debug::EventData::SubWorkflow(sub_workflow) => {
+				EventData::SubWorkflow(SubWorkflowEvent {
+					sub_workflow_id: sub_workflow.sub_workflow_id,
+					name: sub_workflow.name.clone(),
+				})
+			}
+			debug::EventData::Loop(loop_event) => EventData::Loop(LoopEvent {
+				state: serde_json::value::to_raw_value(&loop_event.state)?,
+				output: loop_event
+					.output
+					.as_ref()
+					.map(serde_json::value::to_raw_value)
+					.transpose()?,
+				iteration: loop_event.iteration,
+			}),
+			debug::EventData::Sleep(sleep) => EventData::Sleep(SleepEvent {
+				deadline_ts: sleep.deadline_ts,
+				state: sleep.state,
+			}),
+			debug::EventData::Removed(removed) => EventData::Removed(RemovedEvent {
+				event_type: removed.event_type,
+				name: removed.name.clone(),
+			}),
+			debug::EventData::VersionCheck(version_check) => {
+				EventData::VersionCheck(VersionCheckEvent {
+					inner_version: version_check.inner_version,
+				})
+			}
+			debug::EventData::Branch => EventData::Branch,
+			debug::EventData::Signals(signals) => EventData::Signals(SignalsEvent {
+				names: signals.names.clone(),
+				bodies: signals
+					.bodies
+					.iter()
+					.map(serde_json::value::to_raw_value)
+					.collect::<serde_json::Result<Vec<_>>>()?,
+			}),
+		};
+
+		events_by_location
+			.entry(event.location.root())
+			.or_default()
+			.push(Event {
+				coordinate: event
+					.location
+					.tail()
+					.cloned()
+					.context("exported event has empty location")?,
+				version: event.version,
+				data,
+			});
+	}
+
+	Ok(events_by_location)
+}
@@ -56,6 +56,7 @@ async fn workflow_created_bump_fires_for_workflow_tag() -> Result<()> {
 		Some(&tags),
 		input.as_ref(),
 		false,
+		None,
 	)
 	.await?;
 
@@ -6,11 +6,15 @@ use gasoline as gas;
 mod workflows;
 use workflows::activity_test::*;
 use workflows::basic::*;
+use workflows::dead_test::*;
 use workflows::eviction_test::*;
 use workflows::listen_timeout::*;
 use workflows::loop_test::*;
+use workflows::replay_test::*;
 use workflows::signal_test::*;
 use workflows::sleep_test::*;
+use workflows::state_test::*;
+use workflows::step_test::*;
 use workflows::sub_test::*;
 
 #[tokio::test]
@@ -41,6 +45,86 @@ async fn test_workflow_basic() {
 	assert_eq!(res, "test_value");
 }
 
+#[tokio::test]
+async fn test_workflow_idempotency_key() {
+	let mut reg = Registry::new();
+	reg.register_workflow::<BasicWorkflow>().unwrap();
+
+	let window = Duration::from_secs(3);
+	let mut test_deps = rivet_test_deps::TestDeps::new().await.unwrap();
+	let mut root = (**test_deps.config()).clone();
+	root.runtime = serde_json::from_value(serde_json::json!({
+		"gasoline_idempotency_window": window.as_secs(),
+	}))
+	.unwrap();
+	test_deps.config = rivet_config::Config::from_root(root);
+
+	let test_ctx = TestCtx::new_with_deps(reg, test_deps).await.unwrap();
+
+	let idempotency_key = format!("idempotency-{}", Id::new_v1(test_ctx.config().dc_label()));
+
+	let mut workflow_ids = Vec::new();
+	for _ in 0..2 {
+		workflow_ids.push(
+			test_ctx
+				.workflow(BasicWorkflowInput {
+					value: "idempotent".to_string(),
+				})
+				.idempotency_key(&idempotency_key)
+				.dispatch()
+				.await
+				.unwrap(),
+		);
+	}
+	assert_eq!(workflow_ids[0], workflow_ids[1]);
+
+	// A different key creates a new workflow
+	let other_workflow_id = test_ctx
+		.workflow(BasicWorkflowInput {
+			value: "idempotent".to_string(),
+		})
+		.idempotency_key(format!("{idempotency_key}-other"))
+		.dispatch()
+		.await
+		.unwrap();
+	assert_ne!(other_workflow_id, workflow_ids[0]);
+
+	let res = tokio::time::timeout(
+		Duration::from_secs(5),
+		test_ctx
+			.workflow::<BasicWorkflowInput>(workflow_ids[0])
+			.output(),
+	)
+	.await
+	.unwrap()
+	.unwrap();
+	assert_eq!(res, "idempotent");
+
+	// Completing the workflow does not release the key while still within the window
+	let retried_workflow_id = test_ctx
+		.workflow(BasicWorkflowInput {
+			value: "idempotent".to_string(),
+		})
+		.idempotency_key(&idempotency_key)
+		.dispatch()
+		.await
+		.unwrap();
+	assert_eq!(retried_workflow_id, workflow_ids[0]);
+
+	// Once the window has passed the key creates a new workflow
+	tokio::time::sleep(window).await;
+
+	let new_workflow_id = test_ctx
+		.workflow(BasicWorkflowInput {
+			value: "idempotent".to_string(),
+		})
+		.idempotency_key(&idempotency_key)
+		.dispatch()
+		.await
+		.unwrap();
+	assert_ne!(new_workflow_id, workflow_ids[0]);
+}
+
 #[tokio::test]
 async fn test_workflow_activity() {
 	let mut reg = Registry::new();
@@ -92,6 +176,58 @@ async fn test_workflow_sub_workflow() {
 	assert_eq!(res, "parent_sub");
 }
 
+#[tokio::test]
+async fn test_workflow_children_and_parent() {
+	use gas::db::debug::DatabaseDebug;
+
+	let mut reg = Registry::new();
+	reg.register_workflow::<BasicWorkflow>().unwrap();
+	reg.register_workflow::<MultiSubTestWorkflow>().unwrap();
+	let test_ctx = TestCtx::new(reg).await.unwrap();
+	let debug_db = test_ctx.debug_db();
+
+	let workflow_id = test_ctx
+		.workflow(MultiSubWorkflowInput {})
+		.dispatch()
+		.await
+		.unwrap();
+
+	let mut sub_workflow_ids = tokio::time::timeout(
+		Duration::from_secs(5),
+		test_ctx
+			.workflow::<MultiSubWorkflowInput>(workflow_id)
+			.output(),
+	)
+	.await
+	.unwrap()
+	.unwrap();
+	sub_workflow_ids.sort();
+	assert_eq!(sub_workflow_ids.len(), 2);
+
+	let mut children = debug_db.get_workflow_children(workflow_id).await.unwrap();
+	children.sort();
+	assert_eq!(children, sub_workflow_ids);
+
+	for sub_workflow_id in sub_workflow_ids {
+		assert_eq!(
+			debug_db.get_workflow_parent(sub_workflow_id).await.unwrap(),
+			Some(workflow_id)
+		);
+		assert!(
+			debug_db
+				.get_workflow_children(sub_workflow_id)
+				.await
+				.unwrap()
+				.is_empty()
+		);
+	}
+
+	assert_eq!(
+		debug_db.get_workflow_parent(workflow_id).await.unwrap(),
+		None
+	);
+}
+
 #[tokio::test]
 async fn test_workflow_sleep() {
 	let mut reg = Registry::new();
@@ -152,6 +288,49 @@ async fn test_workflow_signal() {
 	assert_eq!(res, "signal_value");
 }
 
+#[tokio::test]
+async fn test_workflow_signal_ack_latency() {
+	let mut reg = Registry::new();
+	reg.register_workflow::<SlowSignalTestWorkflow>().unwrap();
+	let test_ctx = TestCtx::new(reg).await.unwrap();
+
+	let ack_latency =
+		gas::metrics::SIGNAL_ACK_LATENCY_SECONDS.with_label_values(&["slow_test_signal"]);
+	let sample_count_before = ack_latency.get_sample_count();
+	let sample_sum_before = ack_latency.get_sample_sum();
+
+	let workflow_id = test_ctx
+		.workflow(SlowSignalTestInput { delay_ms: 500 })
+		.dispatch()
+		.await
+		.unwrap();
+
+	// Sent immediately, the workflow does not ack it until its sleep is over
+	test_ctx
+		.signal(SlowTestSignal {
+			value: "slow".to_string(),
+		})
+		.to_workflow_id(workflow_id)
+		.send()
+		.await
+		.unwrap();
+
+	let res = tokio::time::timeout(
+		Duration::from_secs(5),
+		test_ctx
+			.workflow::<SlowSignalTestInput>(workflow_id)
+			.output(),
+	)
+	.await
+	.unwrap()
+	.unwrap();
+	assert_eq!(res, "slow");
+
+	assert_eq!(ack_latency.get_sample_count() - sample_count_before, 1);
+	// Slightly less than the sleep since the workflow may start sleeping before the signal is sent
+	assert!(ack_latency.get_sample_sum() - sample_sum_before >= 0.4);
+}
+
 #[tokio::test]
 async fn test_workflow_loop() {
 	let mut reg = Registry::new();
@@ -286,3 +465,563 @@ async fn test_workflow_eviction() {
 		sub.next().await.unwrap();
 	}
 }
+
+#[tokio::test]
+async fn test_workflow_export() {
+	let mut reg = Registry::new();
+	reg.register_workflow::<EvictionTestWorkflow>().unwrap();
+	let test_ctx = TestCtx::new(reg).await.unwrap();
+
+	let test_id = Uuid::new_v4();
+	let mut sub = test_ctx
+		.subscribe::<RunningMessage>(("test", test_id))
+		.await
+		.unwrap();
+
+	let workflow_id = test_ctx
+		.workflow(EvictionTestInput { test_id })
+		.dispatch()
+		.await
+		.unwrap();
+
+	sub.next().await.unwrap();
+
+	// Not listened for by the workflow, so it stays pending
+	test_ctx
+		.signal(TestSignal {
+			value: "pending".to_string(),
+		})
+		.to_workflow_id(workflow_id)
+		.send()
+		.await
+		.unwrap();
+
+	// Wait for the workflow to go to sleep on its listen
+	let export = tokio::time::timeout(Duration::from_secs(5), async {
+		loop {
+			let export =
+				gas::db::debug::DatabaseDebug::export_workflow(test_ctx.debug_db(), workflow_id)
+					.await
+					.unwrap()
+					.expect("workflow should exist");
+
+			if !export.wake_conditions.signal_names.is_empty() && !export.pending_signals.is_empty()
+			{
+				break export;
+			}
+
+			tokio::time::sleep(Duration::from_millis(50)).await;
+		}
+	})
+	.await
+	.unwrap();
+
+	assert_eq!(export.workflow.workflow_id, workflow_id);
+	assert_eq!(export.workflow.input["test_id"], test_id.to_string());
+	assert!(export.events.iter().any(|event| matches!(
+		&event.data,
+		gas::db::debug::EventData::MessageSend(msg) if msg.name == "running_message"
+	)));
+	assert_eq!(
+		export.wake_conditions.signal_names,
+		vec!["test_signal2".to_string()]
+	);
+	assert_eq!(export.pending_signals.len(), 1);
+	assert_eq!(export.pending_signals[0].signal_name, "test_signal");
+	assert_eq!(
+		export.pending_signals[0].state,
+		gas::db::debug::SignalState::Pending
+	);
+
+	// The whole export serializes into a single blob
+	let blob = serde_json::to_value(&export).unwrap();
+	assert_eq!(blob["pending_signals"][0]["body"]["value"], "pending");
+}
+
+#[tokio::test]
+async fn test_workflow_sandbox_replay() {
+	let mut reg = Registry::new();
+	reg.register_workflow::<ReplayTestWorkflow>().unwrap();
+	let test_ctx = TestCtx::new(reg).await.unwrap();
+
+	let workflow_id = test_ctx
+		.workflow(ReplayTestInput {})
+		.dispatch()
+		.await
+		.unwrap();
+
+	// Wait for the workflow to run both activities and go to sleep on its listen
+	let export = tokio::time::timeout(Duration::from_secs(5), async {
+		loop {
+			let export =
+				gas::db::debug::DatabaseDebug::export_workflow(test_ctx.debug_db(), workflow_id)
+					.await
+					.unwrap()
+					.expect("workflow should exist");
+
+			if !export.wake_conditions.signal_names.is_empty() {
+				break export;
+			}
+
+			tokio::time::sleep(Duration::from_millis(50)).await;
+		}
+	})
+	.await
+	.unwrap();
+
+	// Replaying against the same code consumes all recorded history
+	let mut reg = Registry::new();
+	reg.register_workflow::<ReplayTestWorkflow>().unwrap();
+	let res = test_ctx.replay_workflow(reg, &export).await.unwrap();
+	let gas::sandbox::ReplayResult::Compatible { next_location } = res else {
+		panic!("expected compatible replay, got {res:?}");
+	};
+	assert_eq!(next_location.to_string(), "{3}");
+
+	// Replaying against the changed code detects the replaced activity
+	let mut reg = Registry::new();
+	reg.register_workflow::<v2::ReplayTestWorkflowV2>().unwrap();
+	let res = test_ctx.replay_workflow(reg, &export).await.unwrap();
+	let gas::sandbox::ReplayResult::Diverged { error } = res else {
+		panic!("expected diverged replay, got {res:?}");
+	};
+	assert!(
+		error.contains("\"second_activity\" at {2}"),
+		"unexpected divergence: {error}"
+	);
+
+	// The sandbox did not write anything to the workflow's history
+	let history =
+		gas::db::debug::DatabaseDebug::get_workflow_history(test_ctx.debug_db(), workflow_id, true)
+			.await
+			.unwrap()
+			.unwrap();
+	assert_eq!(history.events.len(), export.events.len());
+}
+
+#[tokio::test]
+async fn test_workflow_check_history() {
+	use futures_util::TryStreamExt;
+	use gas::db::debug::DatabaseDebug;
+	use universaldb::prelude::*;
+
+	let mut reg = Registry::new();
+	reg.register_workflow::<ReplayTestWorkflow>().unwrap();
+	let test_ctx = TestCtx::new(reg).await.unwrap();
+	let debug_db = test_ctx.debug_db();
+
+	let workflow_id = test_ctx
+		.workflow(ReplayTestInput {})
+		.dispatch()
+		.await
+		.unwrap();
+
+	// Wait for the workflow to run both activities and go to sleep on its listen
+	tokio::time::timeout(Duration::from_secs(5), async {
+		loop {
+			let export = debug_db
+				.export_workflow(workflow_id)
+				.await
+				.unwrap()
+				.expect("workflow should exist");
+
+			if !export.wake_conditions.signal_names.is_empty() {
+				break;
+			}
+
+			tokio::time::sleep(Duration::from_millis(50)).await;
+		}
+	})
+	.await
+	.unwrap();
+
+	assert!(
+		debug_db
+			.check_workflow_history(workflow_id)
+			.await
+			.unwrap()
+			.is_empty()
+	);
+
+	// Corrupt the history by removing the name of the first activity and the event type of the second
+	test_ctx
+		.pools()
+		.udb()
+		.unwrap()
+		.txn("test_corrupt_history", |tx| async move {
+			let history_subspace = universaldb::Subspace::new(&(RIVET, GASOLINE, KV)).subspace(&(
+				WORKFLOW,
+				DATA,
+				workflow_id,
+				HISTORY,
+				ACTIVE,
+			));
+			let keys = tx
+				.get_ranges_keyvalues(
+					RangeOption {
+						mode: StreamingMode::WantAll,
+						..(&history_subspace).into()
+					},
+					Serializable,
+				)
+				.map_ok(|entry| entry.key().to_vec())
+				.try_collect::<Vec<_>>()
+				.await?;
+
+			let name_suffix = universaldb::tuple::pack(&(DATA, NAME));
+			let event_type_suffix = universaldb::tuple::pack(&(DATA, EVENT_TYPE));
+
+			let name_key = keys
+				.iter()
+				.find(|key| key.ends_with(&name_suffix))
+				.context("missing name key")?;
+			let event_type_key = keys
+				.iter()
+				.filter(|key| key.ends_with(&event_type_suffix))
+				.nth(1)
+				.context("missing event type key")?;
+
+			tx.clear(name_key);
+			tx.clear(event_type_key);
+
+			Ok(())
+		})
+		.await
+		.unwrap();
+
+	// A regular history read fails on the first problem
+	assert!(
+		debug_db
+			.get_workflow_history(workflow_id, false)
+			.await
+			.is_err()
+	);
+
+	let issues = debug_db
+		.check_workflow_history(workflow_id)
+		.await
+		.unwrap()
+		.into_iter()
+		.map(|issue| format!("{} {}", issue.location, issue.kind))
+		.collect::<Vec<_>>();
+	for expected in [
+		"{1} missing event data name",
+		"{2} missing event data event_type",
+		"{2} orphaned input chunk",
+	] {
+		assert!(
+			issues.iter().any(|issue| issue == expected),
+			"{expected:?} not reported: {issues:?}"
+		);
+	}
+}
+
+#[tokio::test]
+async fn test_workflow_silence_by_filter() {
+	use gas::db::debug::{DatabaseDebug, WorkflowState};
+
+	let mut reg = Registry::new();
+	reg.register_workflow::<DeadTestWorkflow>().unwrap();
+	reg.register_workflow::<OtherDeadTestWorkflow>().unwrap();
+	let test_ctx = TestCtx::new(reg).await.unwrap();
+	let debug_db = test_ctx.debug_db();
+
+	let mut dead_workflow_ids = Vec::new();
+	for _ in 0..2 {
+		dead_workflow_ids.push(
+			test_ctx
+				.workflow(DeadTestInput { fail: true })
+				.dispatch()
+				.await
+				.unwrap(),
+		);
+	}
+	let sleeping_workflow_id = test_ctx
+		.workflow(DeadTestInput { fail: false })
+		.dispatch()
+		.await
+		.unwrap();
+	let other_workflow_id = test_ctx
+		.workflow(OtherDeadTestInput {})
+		.dispatch()
+		.await
+		.unwrap();
+
+	let all_workflow_ids = [
+		dead_workflow_ids[0],
+		dead_workflow_ids[1],
+		sleeping_workflow_id,
+		other_workflow_id,
+	];
+	let get_states = || async {
+		debug_db
+			.get_workflows(all_workflow_ids.to_vec())
+			.await
+			.unwrap()
+			.into_iter()
+			.map(|wf| (wf.workflow_id, wf.state))
+			.collect::<std::collections::HashMap<_, _>>()
+	};
+
+	// Wait for all workflows to run once
+	tokio::time::timeout(Duration::from_secs(5), async {
+		loop {
+			let states = get_states().await;
+			if states
+				.values()
+				.all(|state| matches!(state, WorkflowState::Dead | WorkflowState::Sleeping))
+			{
+				break;
+			}
+
+			tokio::time::sleep(Duration::from_millis(50)).await;
+		}
+	})
+	.await
+	.unwrap();
+
+	let before_ts = rivet_util::timestamp::now() + 1;
+
+	// Workflows created after the timestamp are not matched
+	assert_eq!(
+		debug_db
+			.silence_workflows_by_filter(&["dead_test_workflow"], 0, true, 1)
+			.await
+			.unwrap(),
+		0
+	);
+
+	// Dry run only counts
+	assert_eq!(
+		debug_db
+			.silence_workflows_by_filter(&["dead_test_workflow"], before_ts, true, 4)
+			.await
+			.unwrap(),
+		2
+	);
+	assert_eq!(
+		get_states().await[&dead_workflow_ids[0]],
+		WorkflowState::Dead
+	);
+
+	assert_eq!(
+		debug_db
+			.silence_workflows_by_filter(&["dead_test_workflow"], before_ts, false, 4)
+			.await
+			.unwrap(),
+		2
+	);
+
+	let states = get_states().await;
+	for workflow_id in &dead_workflow_ids {
+		assert_eq!(states[workflow_id], WorkflowState::Silenced);
+	}
+	assert_eq!(states[&sleeping_workflow_id], WorkflowState::Sleeping);
+	assert_eq!(states[&other_workflow_id], WorkflowState::Dead);
+}
+
+/// Waits until the workflow reports a pause point at the given location that has not been continued yet.
+async fn wait_for_pause_point(
+	test_ctx: &TestCtx,
+	workflow_id: Id,
+	location: &str,
+) -> gas::db::debug::StepPausePoint {
+	tokio::time::timeout(Duration::from_secs(5), async {
+		loop {
+			let step_state =
+				gas::db::debug::DatabaseDebug::get_workflow_step(test_ctx.debug_db(), workflow_id)
+					.await
+					.unwrap()
+					.expect("step mode should be enabled");
+
+			if let Some(pause_point) = step_state.pause_point {
+				if pause_point.location.to_string() == location && !pause_point.continued {
+					break pause_point;
+				}
+			}
+
+			tokio::time::sleep(Duration::from_millis(50)).await;
+		}
+	})
+	.await
+	.unwrap()
+}
+
+#[tokio::test]
+async fn test_workflow_step_mode() {
+	let mut reg = Registry::new();
+	reg.register_workflow::<StepTestWorkflow>().unwrap();
+
+	// Step mode is off by default
+	let mut test_deps = rivet_test_deps::TestDeps::new().await.unwrap();
+	let mut root = (**test_deps.config()).clone();
+	root.runtime =
+		serde_json::from_value(serde_json::json!({ "gasoline_step_mode": true })).unwrap();
+	test_deps.config = rivet_config::Config::from_root(root);
+
+	let test_ctx = TestCtx::new_with_deps(reg, test_deps).await.unwrap();
+	let debug_db = test_ctx.debug_db();
+
+	let workflow_id = test_ctx
+		.workflow(StepTestInput {})
+		.dispatch()
+		.await
+		.unwrap();
+
+	// Give workflow time to start listening
+	tokio::time::sleep(Duration::from_millis(100)).await;
+
+	gas::db::debug::DatabaseDebug::set_workflow_step_mode(debug_db, workflow_id, true)
+		.await
+		.unwrap();
+
+	test_ctx
+		.signal(StepTestSignal {
+			value: "step_value".to_string(),
+		})
+		.to_workflow_id(workflow_id)
+		.send()
+		.await
+		.unwrap();
+
+	// Paused before receiving the signal
+	let pause_point = wait_for_pause_point(&test_ctx, workflow_id, "{1}").await;
+	assert_eq!(
+		pause_point.event_type,
+		gas::history::event::EventType::Signals
+	);
+	assert!(
+		gas::db::debug::DatabaseDebug::continue_workflow_step(debug_db, workflow_id)
+			.await
+			.unwrap()
+	);
+
+	// Paused before the activity
+	let pause_point = wait_for_pause_point(&test_ctx, workflow_id, "{2}").await;
+	assert_eq!(
+		pause_point.event_type,
+		gas::history::event::EventType::Activity
+	);
+	assert_eq!(pause_point.name.as_deref(), Some("step_activity"));
+
+	// The workflow stays halted until continued
+	tokio::time::sleep(Duration::from_millis(500)).await;
+	let history = gas::db::debug::DatabaseDebug::get_workflow_history(debug_db, workflow_id, false)
+		.await
+		.unwrap()
+		.unwrap();
+	assert!(
+		!history
+			.events
+			.iter()
+			.any(|event| matches!(event.data, gas::db::debug::EventData::Activity(_)))
+	);
+	assert!(history.wf.output.is_none());
+
+	assert!(
+		gas::db::debug::DatabaseDebug::continue_workflow_step(debug_db, workflow_id)
+			.await
+			.unwrap()
+	);
+
+	let res = tokio::time::timeout(
+		Duration::from_secs(5),
+		test_ctx.workflow::<StepTestInput>(workflow_id).output(),
+	)
+	.await
+	.unwrap()
+	.unwrap();
+	assert_eq!(res, "step_value");
+
+	// Step state is cleared on completion
+	assert!(
+		gas::db::debug::DatabaseDebug::get_workflow_step(debug_db, workflow_id)
+			.await
+			.unwrap()
+			.is_none()
+	);
+
+	// Nothing left to continue
+	assert!(
+		!gas::db::debug::DatabaseDebug::continue_workflow_step(debug_db, workflow_id)
+			.await
+			.unwrap()
+	);
+}
+
+#[tokio::test]
+async fn test_workflow_invalid_parallelization() {
+	use gas::db::debug::DatabaseDebug;
+
+	let mut reg = Registry::new();
+	reg.register_workflow::<DeadTestWorkflow>().unwrap();
+	let test_ctx = TestCtx::new(reg).await.unwrap();
+	let debug_db = test_ctx.debug_db();
+
+	let err = debug_db
+		.revive_workflows(&["dead_test"], &[], true, 0)
+		.await
+		.unwrap_err();
+	let err = err
+		.downcast_ref::<WorkflowError>()
+		.expect("expected a workflow error");
+	assert!(matches!(err, WorkflowError::InvalidParallelization(0)));
+	assert_eq!(
+		err.to_string(),
+		"invalid parallelization 0, must be between 1 and 1023"
+	);
+
+	let err = debug_db
+		.prune_acked_signals(&["dead_test"], 0, true, 1024, None)
+		.await
+		.unwrap_err();
+	assert!(matches!(
+		err.downcast_ref::<WorkflowError>(),
+		Some(WorkflowError::InvalidParallelization(1024))
+	));
+}
+
+#[tokio::test]
+async fn test_workflow_large_state() {
+	use gas::db::debug::DatabaseDebug;
+
+	let mut reg = Registry::new();
+	reg.register_workflow::<LargeStateTestWorkflow>().unwrap();
+
+	let mut test_deps = rivet_test_deps::TestDeps::new().await.unwrap();
+	let mut root = (**test_deps.config()).clone();
+	root.runtime =
+		serde_json::from_value(serde_json::json!({ "gasoline_compress_state": true })).unwrap();
+	test_deps.config = rivet_config::Config::from_root(root);
+
+	let test_ctx = TestCtx::new_with_deps(reg, test_deps).await.unwrap();
+
+	// Large enough to be compressed when stored
+	let entries = 10_000;
+	let workflow_id = test_ctx
+		.workflow(LargeStateTestInput { entries })
+		.dispatch()
+		.await
+		.unwrap();
+
+	let res = tokio::time::timeout(
+		Duration::from_secs(5),
+		test_ctx
+			.workflow::<LargeStateTestInput>(workflow_id)
+			.output(),
+	)
+	.await
+	.unwrap()
+	.unwrap();
+	assert_eq!(res, entries);
+
+	// State reads back the same as it was written
+	let workflows = test_ctx
+		.debug_db()
+		.get_workflows(vec![workflow_id])
+		.await
+		.unwrap();
+	let state: LargeTestState = serde_json::from_value(workflows[0].data.clone()).unwrap();
+	assert_eq!(state.entries.len(), entries);
+	assert_eq!(state.entries[12], "entry-2");
+}
@@ -0,0 +1,36 @@
+use gas::prelude::*;
+use gasoline as gas;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[allow(dead_code)]
+pub struct DeadTestInput {
+	pub fail: bool,
+}
+
+#[workflow(DeadTestWorkflow)]
+pub async fn dead_test_workflow(ctx: &mut WorkflowCtx, input: &DeadTestInput) -> Result<()> {
+	if input.fail {
+		bail!("dead test workflow failed");
+	}
+
+	ctx.listen::<DeadTestSignal>().await?;
+
+	Ok(())
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[allow(dead_code)]
+pub struct OtherDeadTestInput {}
+
+#[workflow(OtherDeadTestWorkflow)]
+pub async fn other_dead_test_workflow(
+	_ctx: &mut WorkflowCtx,
+	_input: &OtherDeadTestInput,
+) -> Result<()> {
+	bail!("other dead test workflow failed");
+}
+
+#[signal("dead_test_signal")]
+#[derive(Debug)]
+#[allow(dead_code)]
+pub struct DeadTestSignal {}
@@ -1,11 +1,14 @@
 pub mod activity_test;
 pub mod basic;
+pub mod dead_test;
 pub mod eviction_test;
 pub mod listen_timeout;
 pub mod loop_test;
 pub mod properties_test;
+pub mod replay_test;
 pub mod signal_test;
 pub mod sleep_test;
 pub mod state_test;
+pub mod step_test;
 pub mod sub_test;
 pub mod tags_test;
@@ -0,0 +1,73 @@
+use gas::prelude::*;
+use gasoline as gas;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[allow(dead_code)]
+pub struct ReplayTestInput {}
+
+#[workflow(ReplayTestWorkflow)]
+pub async fn replay_test_workflow(ctx: &mut WorkflowCtx, _input: &ReplayTestInput) -> Result<()> {
+	ctx.activity(FirstActivityInput {}).await?;
+	ctx.activity(SecondActivityInput {}).await?;
+
+	ctx.listen::<ReplayTestSignal>().await?;
+
+	Ok(())
+}
+
+#[derive(Debug, Serialize, Deserialize, Hash)]
+#[allow(dead_code)]
+pub struct FirstActivityInput {}
+
+#[activity(FirstActivity)]
+pub async fn first_activity(_ctx: &ActivityCtx, _input: &FirstActivityInput) -> Result<()> {
+	Ok(())
+}
+
+#[derive(Debug, Serialize, Deserialize, Hash)]
+#[allow(dead_code)]
+pub struct SecondActivityInput {}
+
+#[activity(SecondActivity)]
+pub async fn second_activity(_ctx: &ActivityCtx, _input: &SecondActivityInput) -> Result<()> {
+	Ok(())
+}
+
+#[signal("replay_test_signal")]
+#[derive(Debug)]
+#[allow(dead_code)]
+pub struct ReplayTestSignal {}
+
+/// Incompatible version of `ReplayTestWorkflow` (same workflow name) that replaces the second activity.
+pub mod v2 {
+	use gas::prelude::*;
+	use gasoline as gas;
+
+	use super::{FirstActivityInput, ReplayTestSignal};
+
+	#[derive(Debug, Serialize, Deserialize)]
+	#[allow(dead_code)]
+	pub struct ReplayTestInputV2 {}
+
+	#[workflow(ReplayTestWorkflowV2)]
+	pub async fn replay_test_workflow(
+		ctx: &mut WorkflowCtx,
+		_input: &ReplayTestInputV2,
+	) -> Result<()> {
+		ctx.activity(FirstActivityInput {}).await?;
+		ctx.activity(ThirdActivityInput {}).await?;
+
+		ctx.listen::<ReplayTestSignal>().await?;
+
+		Ok(())
+	}
+
+	#[derive(Debug, Serialize, Deserialize, Hash)]
+	#[allow(dead_code)]
+	pub struct ThirdActivityInput {}
+
+	#[activity(ThirdActivity)]
+	pub async fn third_activity(_ctx: &ActivityCtx, _input: &ThirdActivityInput) -> Result<()> {
+		Ok(())
+	}
+}
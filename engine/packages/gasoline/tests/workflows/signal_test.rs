@@ -22,3 +22,27 @@ pub async fn signal_test_workflow(
 pub struct TestSignal {
 	pub value: String,
 }
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SlowSignalTestInput {
+	pub delay_ms: u64,
+}
+
+/// Does not listen for its signal until after sleeping.
+#[workflow(SlowSignalTestWorkflow)]
+pub async fn slow_signal_test_workflow(
+	ctx: &mut WorkflowCtx,
+	input: &SlowSignalTestInput,
+) -> Result<String> {
+	ctx.sleep(input.delay_ms).await?;
+
+	let signal = ctx.listen::<SlowTestSignal>().await?;
+
+	Ok(signal.value)
+}
+
+#[signal("slow_test_signal")]
+#[derive(Debug)]
+pub struct SlowTestSignal {
+	pub value: String,
+}
@@ -67,3 +67,66 @@ pub async fn get_state_activity(ctx: &ActivityCtx, _input: &GetStateActivityInpu
 pub struct TestState {
 	pub value: i32,
 }
+
+#[derive(Debug, Serialize, Deserialize)]
+#[allow(dead_code)]
+pub struct LargeStateTestInput {
+	pub entries: usize,
+}
+
+#[workflow(LargeStateTestWorkflow)]
+pub async fn large_state_test_workflow(
+	ctx: &mut WorkflowCtx,
+	input: &LargeStateTestInput,
+) -> Result<usize> {
+	ctx.activity(SetLargeStateActivityInput {
+		entries: input.entries,
+	})
+	.await?;
+
+	let result = ctx.activity(GetLargeStateActivityInput {}).await?;
+
+	Ok(result)
+}
+
+#[derive(Debug, Serialize, Deserialize, Hash)]
+#[allow(dead_code)]
+pub struct SetLargeStateActivityInput {
+	pub entries: usize,
+}
+
+#[activity(SetLargeStateActivity)]
+pub async fn set_large_state_activity(
+	ctx: &ActivityCtx,
+	input: &SetLargeStateActivityInput,
+) -> Result<()> {
+	let mut state = ctx.state::<Option<LargeTestState>>()?;
+
+	*state = Some(LargeTestState {
+		entries: (0..input.entries)
+			.map(|i| format!("entry-{}", i % 10))
+			.collect(),
+	});
+
+	Ok(())
+}
+
+#[derive(Debug, Serialize, Deserialize, Hash)]
+#[allow(dead_code)]
+pub struct GetLargeStateActivityInput {}
+
+#[activity(GetLargeStateActivity)]
+pub async fn get_large_state_activity(
+	ctx: &ActivityCtx,
+	_input: &GetLargeStateActivityInput,
+) -> Result<usize> {
+	let state = ctx.state::<Option<LargeTestState>>()?;
+
+	Ok(state.as_ref().map(|s| s.entries.len()).unwrap_or_default())
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+#[allow(dead_code)]
+pub struct LargeTestState {
+	pub entries: Vec<String>,
+}
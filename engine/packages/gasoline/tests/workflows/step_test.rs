@@ -0,0 +1,34 @@
+use gas::prelude::*;
+use gasoline as gas;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[allow(dead_code)]
+pub struct StepTestInput {}
+
+#[workflow(StepTestWorkflow)]
+pub async fn step_test_workflow(ctx: &mut WorkflowCtx, _input: &StepTestInput) -> Result<String> {
+	let signal = ctx.listen::<StepTestSignal>().await?;
+
+	ctx.activity(StepActivityInput {
+		value: signal.value,
+	})
+	.await
+}
+
+#[derive(Debug, Serialize, Deserialize, Hash)]
+#[allow(dead_code)]
+pub struct StepActivityInput {
+	pub value: String,
+}
+
+#[activity(StepActivity)]
+pub async fn step_activity(_ctx: &ActivityCtx, input: &StepActivityInput) -> Result<String> {
+	Ok(input.value.clone())
+}
+
+#[signal("step_test_signal")]
+#[derive(Debug)]
+#[allow(dead_code)]
+pub struct StepTestSignal {
+	pub value: String,
+}
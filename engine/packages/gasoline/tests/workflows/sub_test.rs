@@ -19,3 +19,27 @@ pub async fn sub_test_workflow(ctx: &mut WorkflowCtx, input: &SubWorkflowInput)
 
 	Ok(sub_result)
 }
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MultiSubWorkflowInput {}
+
+#[workflow(MultiSubTestWorkflow)]
+pub async fn multi_sub_test_workflow(
+	ctx: &mut WorkflowCtx,
+	_input: &MultiSubWorkflowInput,
+) -> Result<Vec<Id>> {
+	let mut sub_workflow_ids = Vec::new();
+
+	for i in 0..2 {
+		let sub_workflow_id = ctx
+			.workflow(BasicWorkflowInput {
+				value: format!("child_{i}"),
+			})
+			.dispatch()
+			.await?;
+
+		sub_workflow_ids.push(sub_workflow_id);
+	}
+
+	Ok(sub_workflow_ids)
+}
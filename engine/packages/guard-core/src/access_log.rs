@@ -0,0 +1,167 @@
+use std::net::IpAddr;
+
+use rivet_util::Id;
+use serde::Serialize;
+use tokio::{io::AsyncWriteExt, sync::mpsc};
+
+use crate::{metrics, request_context::RequestContext};
+
+/// Max entries buffered for the file writer before new entries are dropped.
+const FILE_BUFFER_SIZE: usize = 4096;
+
+/// Emits one structured entry per HTTP request and WebSocket upgrade, similar to an NGINX access
+/// log.
+pub(crate) enum AccessLog {
+	Disabled,
+	Tracing,
+	File(mpsc::Sender<Vec<u8>>),
+}
+
+#[derive(Serialize)]
+pub(crate) struct AccessLogEntry<'a> {
+	pub ts: i64,
+	pub ray_id: Id,
+	pub req_id: Id,
+	pub protocol: &'static str,
+	pub method: &'a str,
+	pub host: &'a str,
+	pub path: &'a str,
+	pub status: u16,
+	pub latency_ms: u64,
+	pub bytes_in: u64,
+	pub bytes_out: u64,
+	pub client_ip: IpAddr,
+	pub actor_id: Option<Id>,
+	pub runner_id: Option<Id>,
+	pub route_cache_hit: bool,
+}
+
+impl<'a> AccessLogEntry<'a> {
+	/// Builds an entry for a request that is about to return its response head. Byte counts come from
+	/// `Content-Length` headers, so streamed bodies and WebSocket messages are not counted.
+	pub(crate) fn new(
+		req_ctx: &'a RequestContext,
+		status: u16,
+		bytes_in: u64,
+		bytes_out: u64,
+	) -> Self {
+		AccessLogEntry {
+			ts: rivet_util::timestamp::now(),
+			ray_id: req_ctx.ray_id,
+			req_id: req_ctx.req_id,
+			protocol: if req_ctx.is_websocket {
+				"websocket"
+			} else {
+				"http"
+			},
+			method: req_ctx.method.as_str(),
+			host: &req_ctx.host,
+			path: &req_ctx.path,
+			status,
+			latency_ms: req_ctx.start_time.elapsed().as_millis() as u64,
+			bytes_in,
+			bytes_out,
+			client_ip: req_ctx.client_ip,
+			actor_id: req_ctx.actor_id,
+			runner_id: req_ctx.runner_id,
+			route_cache_hit: req_ctx.route_cache_hit,
+		}
+	}
+}
+
+impl AccessLog {
+	/// Must be called from within a Tokio runtime when writing to a file. Falls back to tracing if
+	/// the file can't be opened so access logs are never silently lost.
+	pub(crate) fn new(config: &rivet_config::Config) -> Self {
+		let Some(access_log) = config.guard().access_log() else {
+			return AccessLog::Disabled;
+		};
+
+		let Some(path) = &access_log.path else {
+			return AccessLog::Tracing;
+		};
+
+		let file = match std::fs::OpenOptions::new()
+			.create(true)
+			.append(true)
+			.open(path)
+		{
+			Ok(file) => file,
+			Err(err) => {
+				tracing::error!(
+					?err,
+					path=%path.display(),
+					"failed to open access log file, falling back to tracing"
+				);
+				return AccessLog::Tracing;
+			}
+		};
+		let (tx, rx) = mpsc::channel(FILE_BUFFER_SIZE);
+		tokio::spawn(write_file(tokio::fs::File::from_std(file), rx));
+
+		AccessLog::File(tx)
+	}
+
+	pub(crate) fn enabled(&self) -> bool {
+		!matches!(self, AccessLog::Disabled)
+	}
+
+	pub(crate) fn log(&self, entry: AccessLogEntry) {
+		match self {
+			AccessLog::Disabled => {}
+			AccessLog::Tracing => {
+				tracing::info!(
+					target: "guard_access_log",
+					ray_id=%entry.ray_id,
+					req_id=%entry.req_id,
+					protocol=%entry.protocol,
+					method=%entry.method,
+					host=%entry.host,
+					path=%entry.path,
+					status=%entry.status,
+					latency_ms=%entry.latency_ms,
+					bytes_in=%entry.bytes_in,
+					bytes_out=%entry.bytes_out,
+					client_ip=%entry.client_ip,
+					actor_id=?entry.actor_id,
+					runner_id=?entry.runner_id,
+					route_cache_hit=%entry.route_cache_hit,
+					"access"
+				);
+			}
+			AccessLog::File(tx) => {
+				let mut line = match serde_json::to_vec(&entry) {
+					Ok(line) => line,
+					Err(err) => {
+						tracing::error!(?err, "failed to serialize access log entry");
+						return;
+					}
+				};
+				line.push(b'\n');
+
+				// Never hold up requests on a slow disk
+				if tx.try_send(line).is_err() {
+					metrics::ACCESS_LOG_DROPPED_TOTAL.inc();
+				}
+			}
+		}
+	}
+}
+
+async fn write_file(mut file: tokio::fs::File, mut rx: mpsc::Receiver<Vec<u8>>) {
+	while let Some(line) = rx.recv().await {
+		let mut res = file.write_all(&line).await;
+
+		// Write everything already queued before flushing
+		while res.is_ok() {
+			match rx.try_recv() {
+				Ok(line) => res = file.write_all(&line).await,
+				Err(_) => break,
+			}
+		}
+
+		if let Err(err) = res.and(file.flush().await) {
+			tracing::error!(?err, "failed to write access log");
+		}
+	}
+}
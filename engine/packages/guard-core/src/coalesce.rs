@@ -0,0 +1,187 @@
+use std::sync::{
+	Arc,
+	atomic::{AtomicU64, Ordering},
+};
+
+use bytes::Bytes;
+use http_body_util::Full;
+use hyper::{
+	HeaderMap, Response, StatusCode, Version,
+	header::{self, HeaderName, HeaderValue},
+};
+use scc::{HashMap, hash_map::Entry};
+use tokio::sync::watch;
+
+use crate::{request_context::RequestContext, response_body::ResponseBody, utils::X_RIVET_TOKEN};
+
+type ResponseRx = watch::Receiver<Option<Arc<SharedResponse>>>;
+
+/// Headers that identify the client. Requests are only coalesced with requests carrying the same
+/// values.
+const CREDENTIAL_HEADERS: [HeaderName; 4] = [
+	header::AUTHORIZATION,
+	header::PROXY_AUTHORIZATION,
+	header::COOKIE,
+	X_RIVET_TOKEN,
+];
+
+/// Headers that select between representations of the same resource. Requests are only coalesced
+/// with requests carrying the same values, since the actor may not send `Vary`.
+const CONTENT_NEGOTIATION_HEADERS: [HeaderName; 3] = [
+	header::ACCEPT,
+	header::ACCEPT_ENCODING,
+	header::ACCEPT_LANGUAGE,
+];
+
+/// Identifies requests that can share a response.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub(crate) struct CoalesceKey {
+	cache_key: u64,
+	/// Includes the query, which can carry a gateway token.
+	path: String,
+	credentials: Vec<(HeaderName, HeaderValue)>,
+	content_negotiation: Vec<(HeaderName, HeaderValue)>,
+}
+
+impl CoalesceKey {
+	/// The route cache key does not always include the query, credentials, or content negotiation
+	/// headers, so all three are mixed in.
+	pub(crate) fn new(cache_key: u64, req_ctx: &RequestContext) -> Self {
+		CoalesceKey {
+			cache_key,
+			path: req_ctx.path().to_string(),
+			credentials: header_values(req_ctx.headers(), &CREDENTIAL_HEADERS),
+			content_negotiation: header_values(req_ctx.headers(), &CONTENT_NEGOTIATION_HEADERS),
+		}
+	}
+}
+
+fn header_values(headers: &HeaderMap, names: &[HeaderName]) -> Vec<(HeaderName, HeaderValue)> {
+	names
+		.iter()
+		.flat_map(|name| {
+			headers
+				.get_all(name)
+				.iter()
+				.map(move |value| (name.clone(), value.clone()))
+		})
+		.collect()
+}
+
+/// Buffered response that can be handed to every coalesced request.
+pub(crate) struct SharedResponse {
+	pub(crate) status: StatusCode,
+	pub(crate) version: Version,
+	pub(crate) headers: HeaderMap,
+	pub(crate) body: Bytes,
+}
+
+impl SharedResponse {
+	pub(crate) fn to_response(&self) -> Response<ResponseBody> {
+		let mut res = Response::new(ResponseBody::Full(Full::new(self.body.clone())));
+		*res.status_mut() = self.status;
+		*res.version_mut() = self.version;
+		*res.headers_mut() = self.headers.clone();
+		res
+	}
+}
+
+pub(crate) enum Coalesced {
+	/// No identical request is in flight. The caller must send the upstream request and publish
+	/// the response with [`CoalesceLeader::complete`].
+	Leader(CoalesceLeader),
+	/// An identical request is already in flight. Resolves to `None` if the leader did not produce
+	/// a shareable response, in which case the caller sends its own request.
+	Follower(ResponseRx),
+}
+
+struct InFlight {
+	/// Identifies the leader that registered this entry.
+	leader_id: u64,
+	rx: ResponseRx,
+}
+
+/// Tracks in-flight GET requests so identical concurrent requests share one upstream request.
+#[derive(Default)]
+pub(crate) struct RequestCoalescer {
+	in_flight: Arc<HashMap<CoalesceKey, InFlight>>,
+	next_leader_id: AtomicU64,
+}
+
+impl RequestCoalescer {
+	pub(crate) fn join(&self, key: CoalesceKey) -> Coalesced {
+		match self.in_flight.entry_sync(key.clone()) {
+			Entry::Occupied(entry) => Coalesced::Follower(entry.rx.clone()),
+			Entry::Vacant(entry) => {
+				let leader_id = self.next_leader_id.fetch_add(1, Ordering::Relaxed);
+				let (tx, rx) = watch::channel(None);
+				entry.insert_entry(InFlight { leader_id, rx });
+
+				Coalesced::Leader(CoalesceLeader {
+					key,
+					leader_id,
+					tx,
+					in_flight: self.in_flight.clone(),
+				})
+			}
+		}
+	}
+}
+
+/// Held by the request sending the upstream request. Dropping it without calling `complete` (on
+/// error, unshareable response, or cancellation) releases all followers to send their own
+/// requests.
+pub(crate) struct CoalesceLeader {
+	key: CoalesceKey,
+	leader_id: u64,
+	tx: watch::Sender<Option<Arc<SharedResponse>>>,
+	in_flight: Arc<HashMap<CoalesceKey, InFlight>>,
+}
+
+impl CoalesceLeader {
+	pub(crate) fn complete(self, res: Arc<SharedResponse>) {
+		// Remove the entry before publishing so requests arriving afterwards do not receive a
+		// response that was produced before they were sent
+		self.remove();
+		let _ = self.tx.send(Some(res));
+	}
+
+	/// Only removes the entry if it is still ours. After `complete` removes it, a new leader may
+	/// register the same key before this leader is dropped.
+	fn remove(&self) {
+		self.in_flight
+			.remove_if_sync(&self.key, |entry| entry.leader_id == self.leader_id);
+	}
+}
+
+impl Drop for CoalesceLeader {
+	fn drop(&mut self) {
+		self.remove();
+	}
+}
+
+/// Waits for the leader's response. Returns `None` if the leader gave up.
+pub(crate) async fn wait(mut rx: ResponseRx) -> Option<Arc<SharedResponse>> {
+	rx.wait_for(|res| res.is_some())
+		.await
+		.ok()
+		.and_then(|res| res.clone())
+}
+
+/// Returns false for responses meant for a single client or that vary on request headers not in the
+/// coalesce key.
+pub(crate) fn is_shareable(headers: &HeaderMap) -> bool {
+	if headers.contains_key(header::SET_COOKIE) || headers.contains_key(header::VARY) {
+		return false;
+	}
+
+	!headers
+		.get_all(header::CACHE_CONTROL)
+		.iter()
+		.filter_map(|value| value.to_str().ok())
+		.flat_map(|value| value.split(','))
+		.any(|directive| {
+			let directive = directive.trim();
+			directive.eq_ignore_ascii_case("private") || directive.eq_ignore_ascii_case("no-store")
+		})
+}
@@ -1,8 +1,8 @@
 use anyhow::{Result, bail};
 use async_trait::async_trait;
 use bytes::Bytes;
-use http_body_util::Full;
-use hyper::{Request, Response};
+use http_body_util::{Full, Limited};
+use hyper::{Request, Response, body::Incoming as BodyIncoming};
 use tokio_tungstenite::tungstenite::protocol::frame::CloseFrame;
 
 use crate::WebSocketHandle;
@@ -12,6 +12,9 @@ use crate::response_body::ResponseBody;
 pub enum HibernationResult {
 	Continue,
 	Close,
+	/// The downstream closed the websocket while it was hibernating. The close frame is forwarded to the
+	/// client as is.
+	ServerClose(Option<CloseFrame>),
 }
 
 /// Trait for custom request serving logic that can handle both HTTP and WebSocket requests
@@ -24,6 +27,22 @@ pub trait CustomServeTrait: Send + Sync {
 		req_ctx: &mut RequestContext,
 	) -> Result<Response<ResponseBody>>;
 
+	/// Returns true if the request body should be passed to `handle_request_streaming` as it arrives instead
+	/// of being buffered for `handle_request`.
+	fn stream_request_body(&self, _req: &Request<BodyIncoming>) -> bool {
+		false
+	}
+
+	/// Handle a regular HTTP request without buffering its body. Not retried since the body can't be
+	/// replayed.
+	async fn handle_request_streaming(
+		&self,
+		_req: Request<Limited<BodyIncoming>>,
+		_req_ctx: &mut RequestContext,
+	) -> Result<Response<ResponseBody>> {
+		bail!("service does not support streaming request bodies");
+	}
+
 	/// Handle a WebSocket connection after upgrade. Supports connection retries.
 	async fn handle_websocket(
 		&self,
@@ -35,6 +54,12 @@ pub trait CustomServeTrait: Send + Sync {
 		bail!("service does not support websockets");
 	}
 
+	/// Returns true if concurrent identical GET requests to this service may share one upstream
+	/// request and response.
+	fn request_coalescing(&self) -> bool {
+		false
+	}
+
 	// TODO: Combine into handle_websocket, remove hibernation from guard
 	/// Returns true if the websocket should close.
 	async fn handle_websocket_hibernation(
@@ -36,6 +36,19 @@ pub struct RateLimit {
 	pub ip: String,
 }
 
+#[derive(RivetError, Serialize, Deserialize)]
+#[error(
+	"guard",
+	"actor_rate_limit",
+	"Too many requests to this actor. Try again later.",
+	"Too many requests to actor {actor_id} from IP {ip}. Retry after {retry_after_secs} seconds."
+)]
+pub struct ActorRateLimit {
+	pub actor_id: String,
+	pub ip: String,
+	pub retry_after_secs: u64,
+}
+
 #[derive(RivetError, Serialize, Deserialize)]
 #[error(
 	"guard",
@@ -183,6 +196,17 @@ pub struct GatewayResponseStartTimeout {
 	pub timeout_ms: u64,
 }
 
+#[derive(RivetError, Serialize, Deserialize)]
+#[error(
+	"guard",
+	"gateway_response_body_idle_timeout",
+	"Timed out waiting for the next chunk of the actor response body.",
+	"No response body chunk received for {timeout_ms} ms."
+)]
+pub struct GatewayResponseBodyIdleTimeout {
+	pub timeout_ms: u64,
+}
+
 #[derive(RivetError, Serialize, Deserialize)]
 #[error(
 	"guard",
@@ -230,6 +254,39 @@ pub struct WebSocketOpenResponseClosed {
 	pub phase: String,
 }
 
+#[derive(RivetError, Serialize, Deserialize)]
+#[error(
+	"guard",
+	"websocket_actor_limit_reached",
+	"Actor has too many open WebSockets. Try again later.",
+	"Actor has reached the limit of {max_websockets} concurrent WebSockets. Try again later."
+)]
+pub struct WebSocketActorLimitReached {
+	pub max_websockets: usize,
+}
+
+#[derive(RivetError, Serialize, Deserialize)]
+#[error(
+	"guard",
+	"runner_in_flight_limit_reached",
+	"Runner has too many in-flight requests. Try again later.",
+	"Runner has reached the limit of {max_in_flight_requests} in-flight requests. Try again later."
+)]
+pub struct RunnerInFlightLimitReached {
+	pub max_in_flight_requests: usize,
+}
+
+#[derive(RivetError, Serialize, Deserialize)]
+#[error(
+	"guard",
+	"runner_pending_messages_limit_reached",
+	"Runner has too many pending WebSocket messages. Try again later.",
+	"Runner has reached the limit of {max_pending_websocket_messages} pending WebSocket messages. Try again later."
+)]
+pub struct RunnerPendingMessagesLimitReached {
+	pub max_pending_websocket_messages: usize,
+}
+
 #[derive(RivetError, Serialize, Deserialize)]
 #[error(
 	"guard",
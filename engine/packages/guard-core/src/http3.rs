@@ -0,0 +1,172 @@
+//! HTTP/3 (QUIC) listener.
+//!
+//! Each QUIC connection is bridged to an in-process HTTP/2 connection served by a regular
+//! [`ProxyService`](crate::ProxyService), so HTTP/3 requests go through the same routing and
+//! proxy logic as TCP traffic. WebSocket upgrades are not supported over HTTP/3.
+
+use std::{net::SocketAddr, sync::Arc};
+
+use anyhow::Result;
+use bytes::{Buf, Bytes};
+use futures::{SinkExt, channel::mpsc};
+use http_body_util::{BodyExt, StreamBody};
+use hyper::{Request, Response, body::Frame, service::service_fn};
+use hyper_util::rt::{TokioExecutor, TokioIo};
+use tracing::Instrument;
+
+use crate::proxy_service::ProxyServiceFactory;
+
+/// Buffer size of the in-memory pipe between the QUIC connection and the proxy service.
+const BRIDGE_BUFFER_SIZE: usize = 64 * 1024;
+
+type BridgeBody = StreamBody<mpsc::Receiver<Result<Frame<Bytes>, std::io::Error>>>;
+
+/// Binds a QUIC endpoint that negotiates HTTP/3 using the given TLS config.
+pub(crate) fn bind(
+	addr: SocketAddr,
+	mut tls_config: rustls::ServerConfig,
+) -> Result<quinn::Endpoint> {
+	tls_config.alpn_protocols = vec![b"h3".to_vec()];
+
+	let crypto = quinn::crypto::rustls::QuicServerConfig::try_from(tls_config)?;
+	let server_config = quinn::ServerConfig::with_crypto(Arc::new(crypto));
+
+	Ok(quinn::Endpoint::server(server_config, addr)?)
+}
+
+#[tracing::instrument(skip_all, fields(?remote_addr))]
+pub(crate) fn process_connection(
+	incoming: quinn::Incoming,
+	remote_addr: SocketAddr,
+	factory: Arc<ProxyServiceFactory>,
+	graceful: &hyper_util::server::graceful::GracefulShutdown,
+) {
+	let (client_io, server_io) = tokio::io::duplex(BRIDGE_BUFFER_SIZE);
+
+	// Serve the proxy side of the bridge like any other HTTP/2 connection
+	let proxy_service = factory.create_service(remote_addr);
+	let service = service_fn(move |req| {
+		let service_clone = proxy_service.clone();
+		async move { service_clone.process(req).await }
+	});
+	let conn = hyper::server::conn::http2::Builder::new(TokioExecutor::new())
+		.serve_connection(TokioIo::new(server_io), service);
+	let conn = graceful.watch(conn);
+
+	tokio::spawn(
+		async move {
+			if let Err(err) = conn.await {
+				tracing::debug!(?err, "HTTP/3 bridge connection error");
+			}
+		}
+		.instrument(tracing::info_span!(parent: None, "http3_bridge_task")),
+	);
+
+	tokio::spawn(
+		async move {
+			if let Err(err) = serve_connection(incoming, client_io).await {
+				tracing::debug!(?err, "HTTP/3 connection error");
+			}
+			tracing::debug!("HTTP/3 connection dropped: {}", remote_addr);
+		}
+		.instrument(tracing::info_span!(parent: None, "process_http3_connection_task")),
+	);
+}
+
+async fn serve_connection(
+	incoming: quinn::Incoming,
+	bridge_io: tokio::io::DuplexStream,
+) -> Result<()> {
+	let conn = incoming.await?;
+	let mut h3_conn =
+		h3::server::Connection::<_, Bytes>::new(h3_quinn::Connection::new(conn)).await?;
+
+	let (sender, bridge_conn) =
+		hyper::client::conn::http2::handshake(TokioExecutor::new(), TokioIo::new(bridge_io))
+			.await?;
+	tokio::spawn(async move {
+		if let Err(err) = bridge_conn.await {
+			tracing::debug!(?err, "HTTP/3 bridge client error");
+		}
+	});
+
+	while let Some(resolver) = h3_conn.accept().await? {
+		let sender = sender.clone();
+		tokio::spawn(async move {
+			if let Err(err) = serve_request(resolver, sender).await {
+				tracing::debug!(?err, "HTTP/3 request error");
+			}
+		});
+	}
+
+	Ok(())
+}
+
+async fn serve_request(
+	resolver: h3::server::RequestResolver<h3_quinn::Connection, Bytes>,
+	mut sender: hyper::client::conn::http2::SendRequest<BridgeBody>,
+) -> Result<()> {
+	let (req, stream) = resolver.resolve_request().await?;
+	let (mut send_stream, mut recv_stream) = stream.split();
+
+	// Stream the request body into the bridge as it arrives
+	let (mut body_tx, body_rx) = mpsc::channel(1);
+	tokio::spawn(async move {
+		loop {
+			match recv_stream.recv_data().await {
+				Ok(Some(mut chunk)) => {
+					let data = chunk.copy_to_bytes(chunk.remaining());
+					if body_tx.send(Ok(Frame::data(data))).await.is_err() {
+						break;
+					}
+				}
+				Ok(None) => {
+					if let Ok(Some(trailers)) = recv_stream.recv_trailers().await {
+						let _ = body_tx.send(Ok(Frame::trailers(trailers))).await;
+					}
+					break;
+				}
+				Err(err) => {
+					let _ = body_tx.send(Err(std::io::Error::other(err))).await;
+					break;
+				}
+			}
+		}
+	});
+
+	let (mut parts, _) = req.into_parts();
+	parts.version = hyper::Version::HTTP_2;
+
+	// Routing reads the host header, which HTTP/3 clients carry in `:authority` instead
+	if !parts.headers.contains_key(hyper::header::HOST)
+		&& let Some(authority) = parts.uri.authority()
+		&& let Ok(host) = authority.as_str().parse()
+	{
+		parts.headers.insert(hyper::header::HOST, host);
+	}
+
+	sender.ready().await?;
+	let res = sender
+		.send_request(Request::from_parts(parts, StreamBody::new(body_rx)))
+		.await?;
+
+	let (parts, mut body) = res.into_parts();
+	send_stream
+		.send_response(Response::from_parts(parts, ()))
+		.await?;
+
+	while let Some(frame) = body.frame().await {
+		match frame?.into_data() {
+			Ok(data) => send_stream.send_data(data).await?,
+			Err(frame) => {
+				if let Ok(trailers) = frame.into_trailers() {
+					send_stream.send_trailers(trailers).await?;
+				}
+			}
+		}
+	}
+
+	send_stream.finish().await?;
+
+	Ok(())
+}
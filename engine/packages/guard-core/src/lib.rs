@@ -1,6 +1,9 @@
+mod access_log;
 pub mod cert_resolver;
+mod coalesce;
 pub mod custom_serve;
 pub mod errors;
+mod http3;
 pub mod metrics;
 pub mod proxy_service;
 pub mod request_context;
@@ -15,7 +18,7 @@ pub mod websocket_handle;
 pub use cert_resolver::CertResolverFn;
 pub use custom_serve::CustomServeTrait;
 pub use proxy_service::{ProxyService, ProxyState};
-pub use response_body::ResponseBody;
+pub use response_body::{BodyError, ResponseBody};
 pub use route::{CacheKeyFn, RouteConfig, RouteTarget, RoutingFn, RoutingOutput};
 pub use websocket_handle::WebSocketHandle;
 
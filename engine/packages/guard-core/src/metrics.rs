@@ -13,6 +13,17 @@ lazy_static! {
 		"Number of active rate limiters",
 		*REGISTRY
 	).unwrap();
+	pub static ref ACTOR_RATE_LIMITER_COUNT: IntGauge = register_int_gauge_with_registry!(
+		"guard_actor_rate_limiter_count",
+		"Number of active actor rate limiters",
+		*REGISTRY
+	).unwrap();
+	pub static ref ACTOR_RATE_LIMIT_THROTTLED_TOTAL: IntCounterVec = register_int_counter_vec_with_registry!(
+		"guard_actor_rate_limit_throttled_total",
+		"Total number of requests rejected by actor rate limits",
+		&["protocol"],
+		*REGISTRY
+	).unwrap();
 	pub static ref IN_FLIGHT_COUNTER_COUNT: IntGauge = register_int_gauge_with_registry!(
 		"guard_in_flight_counter_count",
 		"Number of active in-flight counters",
@@ -23,6 +34,11 @@ lazy_static! {
 		"Number of active in-flight requests",
 		*REGISTRY
 	).unwrap();
+	pub static ref ACCESS_LOG_DROPPED_TOTAL: IntCounter = register_int_counter_with_registry!(
+		"guard_access_log_dropped_total",
+		"Total number of access log entries dropped because the file writer fell behind",
+		*REGISTRY
+	).unwrap();
 
 	// MARK: TCP
 	pub static ref TCP_CONNECTION_TOTAL: IntCounter = register_int_counter_with_registry!(
@@ -56,6 +72,11 @@ lazy_static! {
 		"Total number of requests to actor",
 		*REGISTRY
 	).unwrap();
+	pub static ref PROXY_REQUEST_COALESCED_TOTAL: IntCounter = register_int_counter_with_registry!(
+		"guard_proxy_request_coalesced_total",
+		"Total number of requests served by sharing the response of an identical in-flight request",
+		*REGISTRY
+	).unwrap();
 	pub static ref PROXY_REQUEST_PENDING: IntGauge = register_int_gauge_with_registry!(
 		"guard_proxy_request_pending",
 		"Number of pending requests to actor",
@@ -30,18 +30,23 @@ use tracing::Instrument;
 use url::Url;
 
 use crate::RouteTarget;
+use crate::access_log::{AccessLog, AccessLogEntry};
+use crate::coalesce::{CoalesceKey, Coalesced, RequestCoalescer, SharedResponse};
 use crate::request_context::RequestContext;
 use crate::response_body::ResponseBody;
 use crate::route::{CacheKeyFn, ResolveRouteOutput, RouteCache, RoutingFn, RoutingOutput};
-use crate::utils::{InFlightCounter, RateLimiter};
+use crate::utils::{ActorRateLimiters, InFlightCounter, RateLimiter};
 use crate::{
-	WebSocketHandle, custom_serve::HibernationResult, errors, metrics, task_group::TaskGroup, utils,
+	WebSocketHandle, coalesce, custom_serve::HibernationResult, errors, metrics,
+	task_group::TaskGroup, utils,
 };
 
 pub const X_FORWARDED_FOR: HeaderName = HeaderName::from_static("x-forwarded-for");
 pub const X_RIVET_ERROR: HeaderName = HeaderName::from_static("x-rivet-error");
 
 const PROXY_STATE_CACHE_TTL: Duration = Duration::from_secs(60 * 60); // 1 hour
+// Idle token buckets refill completely long before this, so evicting them loses no state
+const ACTOR_RATE_LIMITER_IDLE_TTL: Duration = Duration::from_secs(5 * 60);
 const WEBSOCKET_CLOSE_LINGER: Duration = Duration::from_millis(5); // Keep TCP connection open briefly after WebSocket close
 
 fn websocket_config(guard_config: &rivet_config::config::guard::Guard) -> WebSocketConfig {
@@ -64,8 +69,11 @@ pub struct ProxyState {
 	route_cache: RouteCache,
 	// We use moka::Cache instead of scc::HashMap because it automatically handles TTL and capacity
 	rate_limiters: Cache<std::net::IpAddr, Arc<Mutex<RateLimiter>>>,
+	actor_rate_limiters: ActorRateLimiters,
 	in_flight_counters: Cache<std::net::IpAddr, Arc<Mutex<InFlightCounter>>>,
 	in_flight_requests: Cache<protocol::RequestId, ()>,
+	coalescer: RequestCoalescer,
+	access_log: AccessLog,
 
 	tasks: Arc<TaskGroup>,
 }
@@ -96,6 +104,7 @@ impl ProxyState {
 			.pool_idle_timeout(Duration::from_secs(30))
 			.build(https_connector);
 		let route_cache_ttl = config.guard().route_cache_ttl();
+		let access_log = AccessLog::new(&config);
 
 		Self {
 			config,
@@ -107,11 +116,14 @@ impl ProxyState {
 				.max_capacity(10_000)
 				.time_to_live(PROXY_STATE_CACHE_TTL)
 				.build(),
+			actor_rate_limiters: ActorRateLimiters::new(ACTOR_RATE_LIMITER_IDLE_TTL),
 			in_flight_counters: Cache::builder()
 				.max_capacity(10_000)
 				.time_to_live(PROXY_STATE_CACHE_TTL)
 				.build(),
 			in_flight_requests: Cache::builder().max_capacity(10_000_000).build(),
+			coalescer: RequestCoalescer::default(),
+			access_log,
 			tasks: TaskGroup::new(),
 		}
 	}
@@ -138,6 +150,8 @@ impl ProxyState {
 			None
 		};
 
+		req_ctx.route_cache_hit = cache_res.is_some();
+
 		let res = if let Some(res) = cache_res {
 			res
 		} else {
@@ -442,6 +456,7 @@ impl ProxyService {
 			is_websocket,
 			client_ip,
 			start_time,
+			self.state.actor_rate_limiters.clone(),
 		);
 
 		// TLS information would be set here if available (for HTTPS connections)
@@ -686,6 +701,22 @@ impl ProxyService {
 			"Request completed"
 		);
 
+		if self.state.access_log.enabled() {
+			let bytes_in = req_ctx
+				.headers
+				.get(hyper::header::CONTENT_LENGTH)
+				.and_then(|h| h.to_str().ok())
+				.and_then(|s| s.parse::<u64>().ok())
+				.unwrap_or(0);
+
+			self.state.access_log.log(AccessLogEntry::new(
+				&req_ctx,
+				status,
+				bytes_in,
+				content_length as u64,
+			));
+		}
+
 		Ok(res)
 	}
 
@@ -727,8 +758,18 @@ impl ProxyService {
 		metrics::PROXY_REQUEST_PENDING.inc();
 		metrics::PROXY_REQUEST_TOTAL.inc();
 
+		// Coalescing is opted into by the service, e.g. per actor
+		let coalesce = req_ctx.method == hyper::Method::GET
+			&& match &target {
+				ResolveRouteOutput::CustomServe(handler) => handler.request_coalescing(),
+				ResolveRouteOutput::Target(_) => false,
+			};
+
 		let res = if hyper_tungstenite::is_upgrade_request(&req) {
 			self.handle_websocket_upgrade(req, req_ctx, target).await
+		} else if coalesce {
+			self.handle_coalesced_http_request(req, req_ctx, target)
+				.await
 		} else {
 			self.handle_http_request(req, req_ctx, target).await
 		};
@@ -762,6 +803,76 @@ impl ProxyService {
 		res
 	}
 
+	/// Shares a single upstream request between concurrent identical GET requests. Streamed, large,
+	/// and client-specific responses are not shared, any waiting requests send their own request
+	/// instead.
+	#[tracing::instrument(skip_all)]
+	async fn handle_coalesced_http_request(
+		&self,
+		req: Request<BodyIncoming>,
+		req_ctx: &mut RequestContext,
+		resolved_route: ResolveRouteOutput,
+	) -> Result<Response<ResponseBody>> {
+		let cache_key = (self.state.cache_key_fn)(req_ctx)?;
+		let key = CoalesceKey::new(cache_key, req_ctx);
+
+		let leader = match self.state.coalescer.join(key) {
+			Coalesced::Leader(leader) => leader,
+			Coalesced::Follower(rx) => {
+				if let Some(shared) = coalesce::wait(rx).await {
+					tracing::debug!("served coalesced response");
+					metrics::PROXY_REQUEST_COALESCED_TOTAL.inc();
+
+					return Ok(shared.to_response());
+				}
+
+				tracing::debug!("coalesced request leader failed, sending own request");
+				return self.handle_http_request(req, req_ctx, resolved_route).await;
+			}
+		};
+
+		let res = self
+			.handle_http_request(req, req_ctx, resolved_route)
+			.await?;
+
+		// Only buffer bodies with a known size under the limit. Streamed bodies (SSE, chunked
+		// responses) have no exact size and are passed through as is.
+		let max_size = self
+			.state
+			.config
+			.guard()
+			.request_coalescing_max_response_size();
+		let fits = http_body::Body::size_hint(res.body())
+			.exact()
+			.is_some_and(|size| size <= max_size as u64);
+		if !fits || !coalesce::is_shareable(res.headers()) {
+			// Dropping the leader releases any waiting requests
+			return Ok(res);
+		}
+
+		let (parts, body) = res.into_parts();
+		let body = Limited::new(body, max_size)
+			.collect()
+			.await
+			.map_err(|err| {
+				errors::InvalidResponseBody {
+					reason: err.to_string(),
+				}
+				.build()
+			})?
+			.to_bytes();
+
+		let shared = Arc::new(SharedResponse {
+			status: parts.status,
+			version: parts.version,
+			headers: parts.headers,
+			body,
+		});
+		leader.complete(shared.clone());
+
+		Ok(shared.to_response())
+	}
+
 	#[tracing::instrument(skip_all)]
 	async fn handle_http_request(
 		&self,
@@ -936,6 +1047,21 @@ impl ProxyService {
 				.build());
 			}
 			ResolveRouteOutput::CustomServe(mut handler) => {
+				if handler.stream_request_body(&req) {
+					let (req_parts, body) = req.into_parts();
+					let req = hyper::Request::from_parts(
+						req_parts,
+						Limited::new(body, self.state.config.guard().http_max_request_body_size()),
+					);
+
+					let res = handler.handle_request_streaming(req, req_ctx).await;
+
+					self.state
+						.release_in_flight(req_ctx.client_ip, req_ctx.in_flight_request_id)
+						.await;
+					return res;
+				}
+
 				// Collect request body
 				let (req_parts, body) = req.into_parts();
 				let req_body =
@@ -956,11 +1082,39 @@ impl ProxyService {
 				let mut attempts = 0;
 				let mut last_status = "none".to_owned();
 				let mut last_error_code = "none".to_owned();
+				let replay_max_attempts = self.state.config.guard().request_replay_max_attempts();
+				let mut replays = 0;
 				while attempts < req_ctx.retry.max_attempts {
 					attempts += 1;
 
 					let res = handler.handle_request(req_collected.clone(), req_ctx).await;
 					if utils::should_retry_request(&res) {
+						// With replay enabled, a request the actor may have started handling is only
+						// sent again if that is safe and the replay budget allows it
+						if let Some(replay_max_attempts) = replay_max_attempts
+							&& utils::is_delivered_request_error(&res)
+						{
+							if replays >= replay_max_attempts
+								|| !utils::is_idempotent_request(&req_collected)
+							{
+								tracing::debug!(
+									replays,
+									"not replaying request after actor stopped"
+								);
+
+								self.state
+									.release_in_flight(
+										req_ctx.client_ip,
+										req_ctx.in_flight_request_id,
+									)
+									.await;
+								return res;
+							}
+
+							replays += 1;
+							tracing::debug!(replays, "replaying request to rescheduled actor");
+						}
+
 						match &res {
 							Ok(resp) => {
 								last_status = resp.status().as_u16().to_string();
@@ -1756,13 +1910,48 @@ impl ProxyService {
 
 										after_hibernation = true;
 
-										// Despite receiving a close frame from the client during hibernation
-										// we are going to reconnect to the actor so that it knows the
-										// connection has closed
-										if let HibernationResult::Close = res {
-											tracing::debug!("starting hibernating websocket close");
+										match res {
+											HibernationResult::Continue => {}
+											// Despite receiving a close frame from the client during
+											// hibernation we are going to reconnect to the actor so that it
+											// knows the connection has closed
+											HibernationResult::Close => {
+												tracing::debug!(
+													"starting hibernating websocket close"
+												);
+
+												ws_hibernation_close = true;
+											}
+											// The actor already knows the connection has closed, forward its
+											// close frame instead of reconnecting
+											HibernationResult::ServerClose(close_frame) => {
+												tracing::debug!(
+													?close_frame,
+													"websocket closed by downstream during hibernation"
+												);
+
+												if let Err(err) = ws_handle
+													.send(utils::to_hyper_close(close_frame))
+													.await
+												{
+													tracing::debug!(
+														?err,
+														"failed to send close frame (websocket may be already closing)"
+													);
+												}
+
+												if let Err(err) = ws_handle.flush().await {
+													tracing::debug!(
+														?err,
+														"failed to flush websocket (websocket may be already closing)"
+													);
+												}
+
+												// Keep TCP connection open briefly to allow client to process close
+												tokio::time::sleep(WEBSOCKET_CLOSE_LINGER).await;
 
-											ws_hibernation_close = true;
+												break;
+											}
 										}
 									} else if attempts > req_ctx.retry.max_attempts
 										|| !utils::is_retryable_ws_error(&err)
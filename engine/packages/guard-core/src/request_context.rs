@@ -7,6 +7,8 @@ use std::{
 	time::{Duration, Instant},
 };
 
+use crate::{errors, metrics, utils::ActorRateLimiters};
+
 #[derive(Clone)]
 pub struct RequestContext {
 	pub(crate) remote_addr: SocketAddr,
@@ -31,6 +33,16 @@ pub struct RequestContext {
 
 	pub(crate) in_flight_request_id: Option<protocol::RequestId>,
 	pub(crate) cors: Option<CorsConfig>,
+
+	pub(crate) actor_rate_limiters: ActorRateLimiters,
+	/// Actor this request already took a rate limit token for. Routing reruns on retries and
+	/// should not consume another token.
+	pub(crate) actor_rate_limit_acquired: Option<Id>,
+
+	// Reported in access logs
+	pub(crate) actor_id: Option<Id>,
+	pub(crate) runner_id: Option<Id>,
+	pub(crate) route_cache_hit: bool,
 }
 
 impl RequestContext {
@@ -45,6 +57,7 @@ impl RequestContext {
 		is_websocket: bool,
 		client_ip: IpAddr,
 		start_time: Instant,
+		actor_rate_limiters: ActorRateLimiters,
 	) -> Self {
 		let hostname = host.split(':').next().unwrap_or(&host).to_string();
 
@@ -78,6 +91,13 @@ impl RequestContext {
 
 			in_flight_request_id: None,
 			cors: None,
+
+			actor_rate_limiters,
+			actor_rate_limit_acquired: None,
+
+			actor_id: None,
+			runner_id: None,
+			route_cache_hit: false,
 		}
 	}
 
@@ -125,6 +145,64 @@ impl RequestContext {
 	pub fn set_cors(&mut self, cors_config: CorsConfig) {
 		self.cors = Some(cors_config);
 	}
+
+	/// Removes any CORS config so the response is sent without CORS headers.
+	pub fn clear_cors(&mut self) {
+		self.cors = None;
+	}
+
+	/// Records the actor this request was routed to for access logs.
+	pub fn set_actor_id(&mut self, actor_id: Id) {
+		self.actor_id = Some(actor_id);
+	}
+
+	/// Records the runner this request was routed to for access logs.
+	pub fn set_runner_id(&mut self, runner_id: Id) {
+		self.runner_id = Some(runner_id);
+	}
+
+	/// Applies a token bucket rate limit to requests for an actor from this request's client IP.
+	/// Fails with `guard.actor_rate_limit` if the limit is exceeded.
+	pub async fn check_actor_rate_limit(
+		&mut self,
+		actor_id: Id,
+		config: &ActorRateLimitConfig,
+	) -> Result<()> {
+		if self.actor_rate_limit_acquired == Some(actor_id) {
+			return Ok(());
+		}
+
+		let Err(retry_after) = self
+			.actor_rate_limiters
+			.try_acquire(
+				actor_id,
+				self.client_ip,
+				config.requests_per_second,
+				config.burst,
+			)
+			.await
+		else {
+			self.actor_rate_limit_acquired = Some(actor_id);
+			return Ok(());
+		};
+
+		let protocol = if self.is_websocket {
+			"websocket"
+		} else {
+			"http"
+		};
+		metrics::ACTOR_RATE_LIMIT_THROTTLED_TOTAL
+			.with_label_values(&[protocol])
+			.inc();
+
+		Err(errors::ActorRateLimit {
+			actor_id: actor_id.to_string(),
+			ip: self.client_ip.to_string(),
+			// Round up so clients never retry before a token is available
+			retry_after_secs: retry_after.as_secs_f64().ceil().max(1.0) as u64,
+		}
+		.build())
+	}
 }
 
 #[derive(Clone, Debug)]
@@ -133,6 +211,13 @@ pub struct RateLimitConfig {
 	pub period: u64, // in seconds
 }
 
+#[derive(Clone, Debug)]
+pub struct ActorRateLimitConfig {
+	pub requests_per_second: u32,
+	/// Max requests allowed in a burst.
+	pub burst: u32,
+}
+
 #[derive(Clone, Debug)]
 pub struct MaxInFlightConfig {
 	pub amount: usize,
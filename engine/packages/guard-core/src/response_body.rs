@@ -1,6 +1,9 @@
 use bytes::Bytes;
 use http_body_util::Full;
 use hyper::body::Incoming as BodyIncoming;
+use tokio::sync::mpsc;
+
+pub type BodyError = Box<dyn std::error::Error + Send + Sync>;
 
 /// Response body type that can handle both streaming and buffered responses
 #[derive(Debug)]
@@ -9,11 +12,14 @@ pub enum ResponseBody {
 	Full(Full<Bytes>),
 	/// Streaming response body
 	Incoming(BodyIncoming),
+	/// Response body streamed through a channel. Ends once the sender is dropped. Sending an error
+	/// aborts the response.
+	Channel(mpsc::Receiver<Result<Bytes, BodyError>>),
 }
 
 impl http_body::Body for ResponseBody {
 	type Data = Bytes;
-	type Error = Box<dyn std::error::Error + Send + Sync>;
+	type Error = BodyError;
 
 	fn poll_frame(
 		self: std::pin::Pin<&mut Self>,
@@ -46,6 +52,14 @@ impl http_body::Body for ResponseBody {
 					std::task::Poll::Pending => std::task::Poll::Pending,
 				}
 			}
+			ResponseBody::Channel(rx) => match rx.poll_recv(cx) {
+				std::task::Poll::Ready(Some(Ok(data))) => {
+					std::task::Poll::Ready(Some(Ok(http_body::Frame::data(data))))
+				}
+				std::task::Poll::Ready(Some(Err(e))) => std::task::Poll::Ready(Some(Err(e))),
+				std::task::Poll::Ready(None) => std::task::Poll::Ready(None),
+				std::task::Poll::Pending => std::task::Poll::Pending,
+			},
 		}
 	}
 
@@ -53,6 +67,7 @@ impl http_body::Body for ResponseBody {
 		match self {
 			ResponseBody::Full(body) => body.is_end_stream(),
 			ResponseBody::Incoming(body) => body.is_end_stream(),
+			ResponseBody::Channel(rx) => rx.is_closed() && rx.is_empty(),
 		}
 	}
 
@@ -60,6 +75,7 @@ impl http_body::Body for ResponseBody {
 		match self {
 			ResponseBody::Full(body) => body.size_hint(),
 			ResponseBody::Incoming(body) => body.size_hint(),
+			ResponseBody::Channel(_) => http_body::SizeHint::default(),
 		}
 	}
 }
@@ -15,6 +15,7 @@ use tokio_rustls::TlsAcceptor;
 use tracing::Instrument;
 
 use crate::cert_resolver::{CertResolverFn, create_tls_config};
+use crate::http3;
 use crate::metrics;
 use crate::proxy_service::ProxyServiceFactory;
 use crate::route::{CacheKeyFn, RoutingFn};
@@ -51,9 +52,9 @@ pub async fn run_server(
 		let listener = tokio::net::TcpListener::bind(https_addr).await?;
 
 		// Configure TLS if resolver function is provided
-		let acceptor = if let Some(resolver_fn) = cert_resolver_fn {
+		let acceptor = if let Some(resolver_fn) = &cert_resolver_fn {
 			// Create a TLS server config using our certificate resolver
-			let server_config = create_tls_config(resolver_fn);
+			let server_config = create_tls_config(resolver_fn.clone());
 
 			Some(TlsAcceptor::from(Arc::new(server_config)))
 		} else {
@@ -71,6 +72,32 @@ pub async fn run_server(
 		(None, None, None, None)
 	};
 
+	// Set up HTTP/3 server (if configured)
+	let (http3_addr, http3_factory, http3_endpoint) = match config
+		.guard()
+		.https
+		.as_ref()
+		.and_then(|https| https.http3.as_ref())
+	{
+		Some(http3_config) => {
+			if let Some(resolver_fn) = &cert_resolver_fn {
+				let http3_addr: std::net::SocketAddr = ([0, 0, 0, 0], http3_config.port).into();
+				let http3_factory = Arc::new(ProxyServiceFactory::new(
+					config.clone(),
+					routing_fn.clone(),
+					cache_key_fn.clone(),
+				));
+				let endpoint = http3::bind(http3_addr, create_tls_config(resolver_fn.clone()))?;
+
+				(Some(http3_addr), Some(http3_factory), Some(endpoint))
+			} else {
+				tracing::warn!("No TLS certificate resolver provided, HTTP/3 will not be enabled");
+				(None, None, None)
+			}
+		}
+		None => (None, None, None),
+	};
+
 	let server = hyper_util::server::conn::auto::Builder::new(hyper_util::rt::TokioExecutor::new());
 	let graceful = hyper_util::server::graceful::GracefulShutdown::new();
 	let mut term_signal = TermSignal::get();
@@ -80,6 +107,9 @@ pub async fn run_server(
 	if let Some(addr) = &https_addr {
 		tracing::info!("HTTPS server listening on {}", addr);
 	}
+	if let Some(addr) = &http3_addr {
+		tracing::info!("HTTP/3 server listening on {}", addr);
+	}
 
 	// Helper function to process regular connections
 	#[tracing::instrument(skip_all, fields(?remote_addr))]
@@ -249,6 +279,22 @@ pub async fn run_server(
 
 				anyhow::Ok(())
 			}
+			incoming = async {
+				match &http3_endpoint {
+					Some(endpoint) => endpoint.accept().await,
+					None => {
+						// If HTTP/3 is not configured, this future never returns
+						std::future::pending::<Option<_>>().await
+					}
+				}
+			} => {
+				if let (Some(incoming), Some(factory)) = (incoming, &http3_factory) {
+					let remote_addr = incoming.remote_address();
+					http3::process_connection(incoming, remote_addr, factory.clone(), &graceful);
+				}
+
+				Ok(())
+			}
 			_ = term_signal.recv() => {
 				break;
 			}
@@ -262,6 +308,10 @@ pub async fn run_server(
 	let shutdown_duration = config.runtime.guard_shutdown_duration();
 	let remaining_tasks = http_factory.remaining_tasks()
 		+ https_factory
+			.as_ref()
+			.map(|f| f.remaining_tasks())
+			.unwrap_or(0)
+		+ http3_factory
 			.as_ref()
 			.map(|f| f.remaining_tasks())
 			.unwrap_or(0);
@@ -273,6 +323,7 @@ pub async fn run_server(
 	let hyper_shutdown2 = hyper_shutdown.clone();
 	let http_factory2 = http_factory.clone();
 	let https_factory2 = https_factory.clone();
+	let http3_factory2 = http3_factory.clone();
 	let mut complete_fut = async move {
 		// Wait until remaining requests finish
 		graceful.shutdown().await;
@@ -284,6 +335,16 @@ pub async fn run_server(
 		if let Some(https_factory) = https_factory2 {
 			https_factory.wait_idle().await;
 		}
+
+		if let Some(http3_factory) = http3_factory2 {
+			http3_factory.wait_idle().await;
+		}
+
+		// All bridged HTTP/3 requests have drained, close remaining QUIC connections
+		if let Some(endpoint) = http3_endpoint {
+			endpoint.close(0u32.into(), b"shutdown");
+			endpoint.wait_idle().await;
+		}
 	}
 	.boxed();
 
@@ -305,7 +366,8 @@ pub async fn run_server(
 			}
 			_ = progress_interval.tick() => {
 				let remaining_tasks = http_factory.remaining_tasks() +
-					https_factory.as_ref().map(|f| f.remaining_tasks()).unwrap_or(0);
+					https_factory.as_ref().map(|f| f.remaining_tasks()).unwrap_or(0) +
+					http3_factory.as_ref().map(|f| f.remaining_tasks()).unwrap_or(0);
 				let hyper_shutdown = hyper_shutdown.load(Ordering::Acquire);
 
 				tracing::info!(%remaining_tasks, hyper_shutdown, "guard still shutting down");
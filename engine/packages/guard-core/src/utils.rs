@@ -4,20 +4,27 @@ use http_body_util::Full;
 use hyper::Response;
 use hyper::StatusCode;
 use hyper::header::HeaderName;
+use moka::future::Cache;
 use rivet_api_builder::{ErrorResponse, RawErrorResponse};
 use rivet_error::{INTERNAL_ERROR, RivetError};
 use rivet_util::Id;
-use std::time::{Duration, Instant};
+use std::{
+	net::IpAddr,
+	sync::Arc,
+	time::{Duration, Instant},
+};
+use tokio::sync::Mutex;
 use tokio_tungstenite::tungstenite::protocol::{CloseFrame, frame::coding::CloseCode};
 use url::Url;
 
 use crate::proxy_service::{X_FORWARDED_FOR, X_RIVET_ERROR};
 use crate::response_body::ResponseBody;
-use crate::{request_context::RequestContext, route::RouteTarget};
+use crate::{metrics, request_context::RequestContext, route::RouteTarget};
 
 const X_RIVET_TARGET: HeaderName = HeaderName::from_static("x-rivet-target");
 const X_RIVET_ACTOR: HeaderName = HeaderName::from_static("x-rivet-actor");
-const X_RIVET_TOKEN: HeaderName = HeaderName::from_static("x-rivet-token");
+pub(crate) const X_RIVET_TOKEN: HeaderName = HeaderName::from_static("x-rivet-token");
+const IDEMPOTENCY_KEY: HeaderName = HeaderName::from_static("idempotency-key");
 
 // In-flight requests counter
 pub(crate) struct InFlightCounter {
@@ -81,6 +88,87 @@ impl RateLimiter {
 	}
 }
 
+/// Token bucket rate limiter. Refills continuously at `rate` tokens per second up to `burst` tokens.
+pub(crate) struct TokenBucket {
+	tokens: f64,
+	last_refill: Instant,
+	rate: f64,
+	burst: f64,
+}
+
+impl TokenBucket {
+	pub(crate) fn new(rate: u32, burst: u32) -> Self {
+		Self {
+			tokens: burst as f64,
+			last_refill: Instant::now(),
+			rate: rate as f64,
+			burst: burst as f64,
+		}
+	}
+
+	/// Updates the limit without resetting the tokens currently in the bucket.
+	pub(crate) fn set_limit(&mut self, rate: u32, burst: u32) {
+		self.rate = rate as f64;
+		self.burst = burst as f64;
+		self.tokens = self.tokens.min(self.burst);
+	}
+
+	/// Consumes a token. Returns how long until a token is available if the bucket is empty.
+	pub(crate) fn try_acquire(&mut self) -> std::result::Result<(), Duration> {
+		let now = Instant::now();
+		let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+		self.tokens = (self.tokens + elapsed * self.rate).min(self.burst);
+		self.last_refill = now;
+
+		if self.tokens >= 1.0 {
+			self.tokens -= 1.0;
+			Ok(())
+		} else if self.rate > 0.0 {
+			Err(Duration::from_secs_f64((1.0 - self.tokens) / self.rate))
+		} else {
+			Err(Duration::MAX)
+		}
+	}
+}
+
+/// Token buckets for actor rate limits, keyed by actor and client IP.
+#[derive(Clone)]
+pub(crate) struct ActorRateLimiters {
+	limiters: Cache<(Id, IpAddr), Arc<Mutex<TokenBucket>>>,
+}
+
+impl ActorRateLimiters {
+	pub(crate) fn new(ttl: Duration) -> Self {
+		Self {
+			limiters: Cache::builder()
+				.max_capacity(100_000)
+				.time_to_idle(ttl)
+				.build(),
+		}
+	}
+
+	/// Returns how long until the next request is allowed if the limit was hit.
+	pub(crate) async fn try_acquire(
+		&self,
+		actor_id: Id,
+		client_ip: IpAddr,
+		rate: u32,
+		burst: u32,
+	) -> std::result::Result<(), Duration> {
+		let limiter = self
+			.limiters
+			.get_with((actor_id, client_ip), async {
+				Arc::new(Mutex::new(TokenBucket::new(rate, burst)))
+			})
+			.await;
+		metrics::ACTOR_RATE_LIMITER_COUNT.set(self.limiters.entry_count() as i64);
+
+		let mut limiter = limiter.lock().await;
+		limiter.set_limit(rate, burst);
+		limiter.try_acquire()
+	}
+}
+
 // Calculate backoff duration for a given retry attempt
 pub(crate) fn calculate_backoff(attempt: u32, initial_interval: u64) -> Duration {
 	Duration::from_millis(initial_interval * 2u64.pow(attempt - 1))
@@ -166,55 +254,72 @@ pub(crate) fn add_proxy_headers_with_addr(
 }
 
 pub(crate) fn err_into_response(err: anyhow::Error) -> Result<Response<ResponseBody>> {
-	let (status, error_response) =
-		if let Some(rivet_err) = err.chain().find_map(|x| x.downcast_ref::<RivetError>()) {
-			let status = match (rivet_err.group(), rivet_err.code()) {
-				("api", "not_found") => StatusCode::NOT_FOUND,
-				("api", "unauthorized") => StatusCode::UNAUTHORIZED,
-				("api", "forbidden") => StatusCode::FORBIDDEN,
-				("guard", "rate_limit") => StatusCode::TOO_MANY_REQUESTS,
-				("guard", "upstream_error") => StatusCode::BAD_GATEWAY,
-				("guard", "routing_error") => StatusCode::BAD_GATEWAY,
-				("guard", "request_timeout") => StatusCode::GATEWAY_TIMEOUT,
-				("guard", "retry_attempts_exceeded") => StatusCode::BAD_GATEWAY,
-				("actor", "not_found") => StatusCode::NOT_FOUND,
-				("guard", "service_unavailable") => StatusCode::SERVICE_UNAVAILABLE,
-				("guard", "actor_stopped_while_waiting") => StatusCode::SERVICE_UNAVAILABLE,
-				("guard", "tunnel_request_aborted") => StatusCode::SERVICE_UNAVAILABLE,
-				("guard", "tunnel_message_timeout") => StatusCode::GATEWAY_TIMEOUT,
-				("guard", "tunnel_response_closed") => StatusCode::SERVICE_UNAVAILABLE,
-				("guard", "gateway_response_start_timeout") => StatusCode::GATEWAY_TIMEOUT,
-				("guard", "actor_ready_timeout") => StatusCode::SERVICE_UNAVAILABLE,
-				("guard", "no_route") => StatusCode::NOT_FOUND,
-				("guard", "invalid_request_body") => StatusCode::PAYLOAD_TOO_LARGE,
-				("guard", "invalid_response_body") => StatusCode::BAD_GATEWAY,
-				_ => StatusCode::BAD_REQUEST,
-			};
-
-			(status, ErrorResponse::from(rivet_err))
-		} else if let Some(raw_err) = err
-			.chain()
-			.find_map(|x| x.downcast_ref::<RawErrorResponse>())
-		{
-			(raw_err.0, raw_err.1.clone())
-		} else {
-			(
-				StatusCode::INTERNAL_SERVER_ERROR,
-				ErrorResponse::from(&RivetError {
-					kind: rivet_error::RivetErrorKind::Static(&rivet_error::INTERNAL_ERROR),
-					meta: None,
-					message: None,
-					actor: None,
-				}),
-			)
+	let (status, error_response) = if let Some(rivet_err) =
+		err.chain().find_map(|x| x.downcast_ref::<RivetError>())
+	{
+		let status = match (rivet_err.group(), rivet_err.code()) {
+			("api", "not_found") => StatusCode::NOT_FOUND,
+			("api", "unauthorized") => StatusCode::UNAUTHORIZED,
+			("api", "forbidden") => StatusCode::FORBIDDEN,
+			("guard", "rate_limit") => StatusCode::TOO_MANY_REQUESTS,
+			("guard", "actor_rate_limit") => StatusCode::TOO_MANY_REQUESTS,
+			("guard", "upstream_error") => StatusCode::BAD_GATEWAY,
+			("guard", "routing_error") => StatusCode::BAD_GATEWAY,
+			("guard", "request_timeout") => StatusCode::GATEWAY_TIMEOUT,
+			("guard", "retry_attempts_exceeded") => StatusCode::BAD_GATEWAY,
+			("actor", "not_found") => StatusCode::NOT_FOUND,
+			("guard", "service_unavailable") => StatusCode::SERVICE_UNAVAILABLE,
+			("guard", "runner_in_flight_limit_reached") => StatusCode::SERVICE_UNAVAILABLE,
+			("guard", "runner_pending_messages_limit_reached") => StatusCode::SERVICE_UNAVAILABLE,
+			("guard", "actor_stopped_while_waiting") => StatusCode::SERVICE_UNAVAILABLE,
+			("guard", "tunnel_request_aborted") => StatusCode::SERVICE_UNAVAILABLE,
+			("guard", "tunnel_message_timeout") => StatusCode::GATEWAY_TIMEOUT,
+			("guard", "tunnel_response_closed") => StatusCode::SERVICE_UNAVAILABLE,
+			("guard", "gateway_response_start_timeout") => StatusCode::GATEWAY_TIMEOUT,
+			("guard", "actor_ready_timeout") => StatusCode::SERVICE_UNAVAILABLE,
+			("guard", "no_route") => StatusCode::NOT_FOUND,
+			("guard", "invalid_request_body") => StatusCode::PAYLOAD_TOO_LARGE,
+			("guard", "invalid_response_body") => StatusCode::BAD_GATEWAY,
+			_ => StatusCode::BAD_REQUEST,
 		};
 
+		(status, ErrorResponse::from(rivet_err))
+	} else if let Some(raw_err) = err
+		.chain()
+		.find_map(|x| x.downcast_ref::<RawErrorResponse>())
+	{
+		(raw_err.0, raw_err.1.clone())
+	} else {
+		(
+			StatusCode::INTERNAL_SERVER_ERROR,
+			ErrorResponse::from(&RivetError {
+				kind: rivet_error::RivetErrorKind::Static(&rivet_error::INTERNAL_ERROR),
+				meta: None,
+				message: None,
+				actor: None,
+			}),
+		)
+	};
+
 	let body_json = serde_json::to_vec(&error_response)?;
 	let bytes = Bytes::from(body_json);
 
-	Response::builder()
+	let mut builder = Response::builder()
 		.status(status)
-		.header(hyper::header::CONTENT_TYPE, "application/json")
+		.header(hyper::header::CONTENT_TYPE, "application/json");
+
+	// Tell clients when they can retry after hitting an actor rate limit
+	if let Some(retry_after_secs) = err
+		.chain()
+		.find_map(|x| x.downcast_ref::<RivetError>())
+		.filter(|x| x.group() == "guard" && x.code() == "actor_rate_limit")
+		.and_then(|x| x.metadata())
+		.and_then(|x| x.get("retry_after_secs").and_then(|x| x.as_u64()))
+	{
+		builder = builder.header(hyper::header::RETRY_AFTER, retry_after_secs);
+	}
+
+	builder
 		.body(ResponseBody::Full(Full::new(bytes)))
 		.map_err(Into::into)
 }
@@ -255,6 +360,42 @@ pub(crate) fn should_retry_request_inner(status: StatusCode, headers: &hyper::He
 			.is_some_and(|(group, code)| group == "guard" && is_retryable_guard_http_error(code))
 }
 
+/// Whether a retryable failure happened after the request was handed to the actor, which may have
+/// started handling it before it stopped.
+pub(crate) fn is_delivered_request_error(res: &Result<Response<ResponseBody>>) -> bool {
+	match res {
+		Ok(resp) => resp
+			.headers()
+			.get(X_RIVET_ERROR)
+			.and_then(|value| value.to_str().ok())
+			.and_then(|value| value.split_once('.'))
+			.is_some_and(|(group, code)| group == "guard" && is_delivered_guard_http_error(code)),
+		Err(err) => err
+			.chain()
+			.find_map(|x| x.downcast_ref::<RivetError>())
+			.is_some_and(|rivet_err| {
+				rivet_err.group() == "guard" && is_delivered_guard_http_error(rivet_err.code())
+			}),
+	}
+}
+
+fn is_delivered_guard_http_error(code: &str) -> bool {
+	matches!(
+		code,
+		"actor_stopped_while_waiting"
+			| "tunnel_request_aborted"
+			| "tunnel_message_timeout"
+			| "tunnel_response_closed"
+			| "gateway_response_start_timeout"
+	)
+}
+
+/// Whether sending the request again cannot repeat its side effects.
+pub(crate) fn is_idempotent_request<B>(req: &hyper::Request<B>) -> bool {
+	matches!(*req.method(), hyper::Method::GET | hyper::Method::HEAD)
+		|| req.headers().contains_key(IDEMPOTENCY_KEY)
+}
+
 // Determine if a websocket error is retryable (e.g., transient UPS/tunnel issues)
 pub(crate) fn is_retryable_ws_error(err: &anyhow::Error) -> bool {
 	if let Some(rivet_err) = err.chain().find_map(|x| x.downcast_ref::<RivetError>()) {
@@ -281,11 +422,16 @@ pub(crate) fn err_to_close_frame(err: anyhow::Error, ray_id: Id) -> CloseFrame {
 
 	let code = match (rivet_err.group(), rivet_err.code()) {
 		("ws", "connection_closed") | ("ws", "eviction") => CloseCode::Normal,
+		("guard", "websocket_actor_limit_reached")
+		| ("guard", "runner_in_flight_limit_reached")
+		| ("guard", "runner_pending_messages_limit_reached") => CloseCode::Again,
+		("ws", "unauthorized") => CloseCode::Policy,
 		_ => CloseCode::Error,
 	};
 
 	match code {
 		CloseCode::Normal => tracing::debug!("websocket closed"),
+		CloseCode::Again | CloseCode::Policy => tracing::debug!(?err, "websocket rejected"),
 		_ => tracing::error!(?err, "websocket failed"),
 	}
 
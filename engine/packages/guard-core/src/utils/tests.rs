@@ -33,3 +33,80 @@ fn skips_non_service_unavailable_with_rivet_error_header() {
 
 	assert!(!should_retry_request_inner(StatusCode::NOT_FOUND, &headers));
 }
+
+fn error_response(error_code: &'static str) -> Result<Response<ResponseBody>> {
+	Ok(Response::builder()
+		.status(StatusCode::SERVICE_UNAVAILABLE)
+		.header(X_RIVET_ERROR, error_code)
+		.body(ResponseBody::Full(Full::new(Bytes::new())))
+		.unwrap())
+}
+
+#[test]
+fn detects_errors_after_request_reached_actor() {
+	assert!(is_delivered_request_error(&error_response(
+		"guard.actor_stopped_while_waiting"
+	)));
+	assert!(!is_delivered_request_error(&error_response(
+		"guard.actor_ready_timeout"
+	)));
+}
+
+#[test]
+fn only_safe_or_keyed_requests_are_idempotent() {
+	let get = hyper::Request::get("/").body(()).unwrap();
+	assert!(is_idempotent_request(&get));
+
+	let post = hyper::Request::post("/").body(()).unwrap();
+	assert!(!is_idempotent_request(&post));
+
+	let keyed_post = hyper::Request::post("/")
+		.header(IDEMPOTENCY_KEY, "abc")
+		.body(())
+		.unwrap();
+	assert!(is_idempotent_request(&keyed_post));
+}
+
+#[test]
+fn token_bucket_allows_burst_then_throttles() {
+	let mut bucket = TokenBucket::new(1, 3);
+
+	for _ in 0..3 {
+		assert!(bucket.try_acquire().is_ok());
+	}
+
+	let retry_after = bucket.try_acquire().unwrap_err();
+	assert!(retry_after > Duration::ZERO && retry_after <= Duration::from_secs(1));
+}
+
+#[test]
+fn actor_rate_limit_response_sets_retry_after() {
+	let err = crate::errors::ActorRateLimit {
+		actor_id: "actor".to_string(),
+		ip: "127.0.0.1".to_string(),
+		retry_after_secs: 2,
+	}
+	.build();
+
+	let res = err_into_response(err).unwrap();
+	assert_eq!(res.status(), StatusCode::TOO_MANY_REQUESTS);
+	assert_eq!(res.headers()[hyper::header::RETRY_AFTER], "2");
+}
+
+#[test]
+fn runner_limit_responses_are_unavailable_and_not_retried() {
+	let res = err_into_response(
+		crate::errors::RunnerInFlightLimitReached {
+			max_in_flight_requests: 1,
+		}
+		.build(),
+	)
+	.unwrap();
+	assert_eq!(res.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+	let err = crate::errors::RunnerPendingMessagesLimitReached {
+		max_pending_websocket_messages: 1,
+	}
+	.build();
+	assert!(!should_retry_request(&Err(err)));
+}
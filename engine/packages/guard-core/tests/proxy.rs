@@ -840,3 +840,53 @@ async fn test_header_functionality_in_routing_and_middleware() {
 		success_count, rate_limited_count
 	);
 }
+
+#[tokio::test]
+async fn test_access_log_file() {
+	init_tracing();
+
+	let test_server = TestServer::new().await;
+	let routing_fn = create_test_routing_fn(&test_server);
+
+	let log_path = std::env::temp_dir().join(format!("guard-access-log-{}.jsonl", Uuid::new_v4()));
+	let config = create_test_config(|guard| {
+		guard.access_log = Some(rivet_config::config::guard::AccessLog {
+			path: Some(log_path.clone()),
+		});
+	});
+	let (guard_addr, _shutdown) = start_guard(config, routing_fn).await;
+
+	let uri = format!("http://{}/test/access-log?foo=bar", guard_addr);
+	let response = make_request_with_body(&uri, "example.com", Method::POST, "hello".to_string())
+		.await
+		.unwrap();
+	assert_eq!(response.status(), StatusCode::OK);
+
+	// Entries are written by a background task
+	let start = Instant::now();
+	let line = loop {
+		let contents = tokio::fs::read_to_string(&log_path)
+			.await
+			.unwrap_or_default();
+		if let Some(line) = contents.lines().next() {
+			break line.to_owned();
+		}
+
+		assert!(
+			start.elapsed() < Duration::from_secs(5),
+			"access log entry was never written"
+		);
+		sleep(Duration::from_millis(20)).await;
+	};
+	let _ = tokio::fs::remove_file(&log_path).await;
+
+	let entry: serde_json::Value = serde_json::from_str(&line).unwrap();
+	assert_eq!(entry["protocol"], "http");
+	assert_eq!(entry["method"], "POST");
+	assert_eq!(entry["path"], "/test/access-log?foo=bar");
+	assert_eq!(entry["status"], 200);
+	assert_eq!(entry["bytes_in"], 5);
+	assert_eq!(entry["route_cache_hit"], false);
+	assert!(entry["actor_id"].is_null());
+	assert!(entry["ray_id"].is_string());
+}
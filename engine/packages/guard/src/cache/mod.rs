@@ -8,6 +8,7 @@ use gas::prelude::*;
 use rivet_guard_core::{CacheKeyFn, request_context::RequestContext};
 
 pub mod pegboard_gateway;
+pub mod response;
 
 use crate::routing::{SEC_WEBSOCKET_PROTOCOL, WS_PROTOCOL_TARGET, X_RIVET_TARGET, actor_path};
 
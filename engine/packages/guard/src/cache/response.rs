@@ -0,0 +1,711 @@
+use std::{
+	sync::Arc,
+	time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use async_trait::async_trait;
+use bytes::Bytes;
+use gas::prelude::*;
+use http_body_util::{BodyExt, Full};
+use hyper::{
+	HeaderMap, Request, Response, StatusCode,
+	header::{self, HeaderName, HeaderValue},
+};
+use moka::{Expiry, future::Cache};
+use rivet_cache::{CachePurgeMessage, CachePurgeSubject};
+use rivet_data::generated::pegboard_actor_response_cache_v1;
+use rivet_guard_core::{
+	CustomServeTrait, ResponseBody, WebSocketHandle, custom_serve::HibernationResult,
+	request_context::RequestContext,
+};
+use tokio_tungstenite::tungstenite::protocol::frame::CloseFrame;
+use universaldb::utils::IsolationLevel::*;
+use universalpubsub::NextOutput;
+use xxhash_rust::xxh3::xxh3_64;
+
+use crate::metrics;
+
+/// Base key for purging cached responses through the cache purge topic, e.g. with
+/// `cache.purge(RESPONSE_CACHE_BASE_KEY, vec![actor_id])`. Keys are actor IDs.
+pub const RESPONSE_CACHE_BASE_KEY: &str = "guard.response";
+
+/// Responses larger than this are only cached in memory since they don't fit in a single
+/// UniversalDB value.
+const MAX_SHARED_ENTRY_SIZE: usize = 90_000;
+
+const X_RIVET_TOKEN: HeaderName = HeaderName::from_static("x-rivet-token");
+
+/// Caches GET responses from actors that opt in with `Cache-Control`. Entries live in an in-memory
+/// LRU on each guard node and optionally in UniversalDB so they are shared between nodes.
+#[derive(Clone)]
+pub struct ResponseCache {
+	config: rivet_config::config::guard::ResponseCache,
+	entries: Cache<(Id, u64), Arc<CachedResponse>>,
+}
+
+impl ResponseCache {
+	pub fn new(config: &rivet_config::config::guard::ResponseCache) -> Self {
+		ResponseCache {
+			config: config.clone(),
+			entries: Cache::builder()
+				.max_capacity(config.max_capacity_bytes())
+				.weigher(|_, res: &Arc<CachedResponse>| {
+					res.body.len().try_into().unwrap_or(u32::MAX)
+				})
+				.expire_after(ResponseExpiry)
+				.support_invalidation_closures()
+				.build(),
+		}
+	}
+
+	#[tracing::instrument(skip_all)]
+	pub async fn start(&self, ctx: StandaloneCtx) -> Result<()> {
+		let self_clone = self.clone();
+		tokio::spawn(async move { self_clone.purge_receiver(ctx).await });
+
+		Ok(())
+	}
+
+	/// Returns a fresh cached response for the request, if any. `path` is compared with the stored
+	/// path so requests whose keys collide never receive each other's responses.
+	#[tracing::instrument(skip_all, fields(%actor_id, request_key))]
+	pub async fn get(
+		&self,
+		ctx: &StandaloneCtx,
+		actor_id: Id,
+		request_key: u64,
+		path: &str,
+	) -> Option<Arc<CachedResponse>> {
+		if let Some(res) = self.entries.get(&(actor_id, request_key)).await
+			&& res.path == path
+			&& res.is_fresh()
+		{
+			metrics::RESPONSE_CACHE_TOTAL
+				.with_label_values(&["hit"])
+				.inc();
+			return Some(res);
+		}
+
+		if self.config.shared() {
+			let timeout_duration = ctx.config().guard().route_response_cache_timeout();
+			match tokio::time::timeout(
+				timeout_duration,
+				self.get_shared(ctx, actor_id, request_key),
+			)
+			.await
+			{
+				Ok(Ok(Some(res))) if res.path == path && res.is_fresh() => {
+					let res = Arc::new(res);
+					self.entries
+						.insert((actor_id, request_key), res.clone())
+						.await;
+
+					metrics::RESPONSE_CACHE_TOTAL
+						.with_label_values(&["shared_hit"])
+						.inc();
+					return Some(res);
+				}
+				Ok(Ok(_)) => {}
+				Ok(Err(err)) => {
+					tracing::warn!(?err, "failed to read shared response cache");
+				}
+				Err(_) => {
+					tracing::warn!(
+						timeout_ms = timeout_duration.as_millis() as u64,
+						"timed out reading shared response cache"
+					);
+				}
+			}
+		}
+
+		metrics::RESPONSE_CACHE_TOTAL
+			.with_label_values(&["miss"])
+			.inc();
+
+		None
+	}
+
+	/// Wraps an actor's handler so cacheable responses are stored.
+	pub fn wrap(
+		&self,
+		ctx: &StandaloneCtx,
+		actor_id: Id,
+		request_key: u64,
+		path: String,
+		inner: Arc<dyn CustomServeTrait>,
+	) -> Arc<dyn CustomServeTrait> {
+		Arc::new(CachingServe {
+			ctx: ctx.clone(),
+			cache: self.clone(),
+			actor_id,
+			request_key,
+			path,
+			inner,
+		})
+	}
+
+	async fn get_shared(
+		&self,
+		ctx: &StandaloneCtx,
+		actor_id: Id,
+		request_key: u64,
+	) -> Result<Option<CachedResponse>> {
+		let stored = ctx
+			.udb()?
+			.txn("guard_response_cache_get", |tx| async move {
+				let tx = tx.with_subspace(pegboard::keys::subspace());
+
+				let response_cache_key =
+					pegboard::keys::actor::ResponseCacheKey::new(actor_id, request_key);
+				let Some(stored) = tx.read_opt(&response_cache_key, Serializable).await? else {
+					return Ok(None);
+				};
+
+				// Entries are never overwritten once the actor stops returning them, clear expired
+				// entries so they don't pile up
+				if stored.expires_at <= util::timestamp::now() {
+					tx.delete(&response_cache_key);
+					return Ok(None);
+				}
+
+				Ok(Some(stored))
+			})
+			.custom_instrument(tracing::info_span!("response_cache_get_tx"))
+			.await?;
+
+		stored.map(CachedResponse::from_stored).transpose()
+	}
+
+	#[tracing::instrument(skip_all, fields(%actor_id))]
+	async fn purge_local(&self, ctx: &StandaloneCtx, actor_id: Id) {
+		if let Err(err) = self
+			.entries
+			.invalidate_entries_if(move |(entry_actor_id, _), _| *entry_actor_id == actor_id)
+		{
+			tracing::error!(?err, "failed to purge cached responses");
+		}
+
+		if self.config.shared() {
+			let res = async {
+				ctx.udb()?
+					.txn("guard_response_cache_purge", |tx| async move {
+						tx.clear_subspace_range(&pegboard::keys::subspace().subspace(
+							&pegboard::keys::actor::ResponseCacheKey::subspace(actor_id),
+						));
+
+						Ok(())
+					})
+					.custom_instrument(tracing::info_span!("response_cache_purge_tx"))
+					.await
+			}
+			.await;
+
+			if let Err(err) = res {
+				tracing::error!(?err, "failed to purge shared cached responses");
+			}
+		}
+
+		metrics::RESPONSE_CACHE_PURGE_TOTAL.inc();
+	}
+
+	async fn purge_receiver(&self, ctx: StandaloneCtx) {
+		// Automatically resubscribe if unsubscribed
+		loop {
+			let sub = match ctx.ups() {
+				Ok(ups) => ups.subscribe(CachePurgeSubject).await,
+				Err(err) => Err(err),
+			};
+			let mut sub = match sub {
+				Ok(sub) => sub,
+				Err(err) => {
+					tracing::error!(
+						?err,
+						"failed to open response cache purge subscription, retrying in 2 seconds"
+					);
+					tokio::time::sleep(Duration::from_secs(2)).await;
+					continue;
+				}
+			};
+
+			while let Ok(NextOutput::Message(msg)) = sub.next().await {
+				let purge_msg = match serde_json::from_slice::<CachePurgeMessage>(&msg.payload) {
+					Ok(purge_msg) => purge_msg,
+					Err(err) => {
+						tracing::error!(?err, "failed to deserialize cache purge message");
+						continue;
+					}
+				};
+
+				if purge_msg.base_key != RESPONSE_CACHE_BASE_KEY {
+					continue;
+				}
+
+				for key in purge_msg.keys {
+					let Some(actor_id) = key
+						.strip_prefix(RESPONSE_CACHE_BASE_KEY)
+						.and_then(|key| key.strip_prefix(':'))
+						.and_then(|key| Id::parse(key).ok())
+					else {
+						tracing::warn!(?key, "invalid response cache purge key");
+						continue;
+					};
+
+					self.purge_local(&ctx, actor_id).await;
+				}
+			}
+
+			tracing::warn!("response cache purge subscription ended, resubscribing");
+		}
+	}
+}
+
+/// Builds the key of a request within an actor's cached responses. Only GET requests are cached so the
+/// key is the path alone. Uses a stable hash since keys are shared between guard nodes through
+/// UniversalDB.
+pub fn request_key(stripped_path: &str) -> u64 {
+	xxh3_64(stripped_path.as_bytes())
+}
+
+/// Returns true if a cached response may be served for this request.
+pub fn is_cacheable_request(req_ctx: &RequestContext) -> bool {
+	if req_ctx.is_websocket()
+		|| req_ctx.method() != hyper::Method::GET
+		|| req_ctx.headers().contains_key(header::RANGE)
+		|| has_caller_credentials(req_ctx.headers())
+	{
+		return false;
+	}
+
+	// Clients can ask to skip the cache
+	let directives = CacheControl::parse(req_ctx.headers());
+	!directives.no_cache && !directives.no_store
+}
+
+pub struct CachedResponse {
+	path: String,
+	status: StatusCode,
+	headers: HeaderMap,
+	body: Bytes,
+	stored_at: i64,
+	expires_at: i64,
+}
+
+impl CachedResponse {
+	fn is_fresh(&self) -> bool {
+		self.expires_at > util::timestamp::now()
+	}
+
+	fn ttl(&self) -> Duration {
+		Duration::from_millis((self.expires_at - util::timestamp::now()).max(0) as u64)
+	}
+
+	pub fn to_response(&self) -> Response<ResponseBody> {
+		let mut res = Response::new(ResponseBody::Full(Full::new(self.body.clone())));
+		*res.status_mut() = self.status;
+		*res.headers_mut() = self.headers.clone();
+
+		let age_secs = (util::timestamp::now() - self.stored_at).max(0) / 1000;
+		res.headers_mut()
+			.insert(header::AGE, HeaderValue::from(age_secs));
+
+		res
+	}
+
+	/// Approximate size of the response once stored in UniversalDB.
+	fn stored_size(&self) -> usize {
+		self.path.len()
+			+ self.body.len()
+			+ self
+				.headers
+				.iter()
+				.map(|(name, value)| name.as_str().len() + value.len())
+				.sum::<usize>()
+	}
+
+	fn to_stored(&self) -> pegboard_actor_response_cache_v1::Data {
+		pegboard_actor_response_cache_v1::Data {
+			path: self.path.clone(),
+			status: self.status.as_u16(),
+			headers: self
+				.headers
+				.iter()
+				.map(|(name, value)| pegboard_actor_response_cache_v1::Header {
+					name: name.to_string(),
+					value: value.as_bytes().to_vec(),
+				})
+				.collect(),
+			body: self.body.to_vec(),
+			stored_at: self.stored_at,
+			expires_at: self.expires_at,
+		}
+	}
+
+	fn from_stored(stored: pegboard_actor_response_cache_v1::Data) -> Result<Self> {
+		let mut headers = HeaderMap::with_capacity(stored.headers.len());
+		for header in stored.headers {
+			headers.append(
+				HeaderName::from_bytes(header.name.as_bytes())?,
+				HeaderValue::from_bytes(&header.value)?,
+			);
+		}
+
+		Ok(CachedResponse {
+			path: stored.path,
+			status: StatusCode::from_u16(stored.status)?,
+			headers,
+			body: stored.body.into(),
+			stored_at: stored.stored_at,
+			expires_at: stored.expires_at,
+		})
+	}
+}
+
+struct ResponseExpiry;
+
+impl Expiry<(Id, u64), Arc<CachedResponse>> for ResponseExpiry {
+	fn expire_after_create(
+		&self,
+		_key: &(Id, u64),
+		value: &Arc<CachedResponse>,
+		_created_at: Instant,
+	) -> Option<Duration> {
+		Some(value.ttl())
+	}
+}
+
+/// Serves a response straight from the cache without touching the actor.
+pub struct CachedServe(pub Arc<CachedResponse>);
+
+#[async_trait]
+impl CustomServeTrait for CachedServe {
+	async fn handle_request(
+		&self,
+		_req: Request<Full<Bytes>>,
+		_req_ctx: &mut RequestContext,
+	) -> Result<Response<ResponseBody>> {
+		Ok(self.0.to_response())
+	}
+}
+
+/// Forwards to the actor's gateway and stores cacheable responses.
+struct CachingServe {
+	ctx: StandaloneCtx,
+	cache: ResponseCache,
+	actor_id: Id,
+	request_key: u64,
+	path: String,
+	inner: Arc<dyn CustomServeTrait>,
+}
+
+#[async_trait]
+impl CustomServeTrait for CachingServe {
+	async fn handle_request(
+		&self,
+		req: Request<Full<Bytes>>,
+		req_ctx: &mut RequestContext,
+	) -> Result<Response<ResponseBody>> {
+		let authorized = req.headers().contains_key(header::AUTHORIZATION);
+
+		let res = self.inner.handle_request(req, req_ctx).await?;
+
+		let Some(ttl) = cacheable_ttl(&res, authorized, self.cache.config.max_ttl()) else {
+			return Ok(res);
+		};
+
+		// Streamed responses are never buffered for caching
+		let (parts, body) = res.into_parts();
+		let ResponseBody::Full(body) = body else {
+			return Ok(Response::from_parts(parts, body));
+		};
+		let body = body.collect().await?.to_bytes();
+
+		if body.len() <= self.cache.config.max_body_size() {
+			let now = util::timestamp::now();
+			let cached = CachedResponse {
+				path: self.path.clone(),
+				status: parts.status,
+				headers: parts.headers.clone(),
+				body: body.clone(),
+				stored_at: now,
+				expires_at: now + ttl.as_millis() as i64,
+			};
+
+			self.store(cached).await;
+		}
+
+		Ok(Response::from_parts(
+			parts,
+			ResponseBody::Full(Full::new(body)),
+		))
+	}
+
+	async fn handle_websocket(
+		&self,
+		req_ctx: &mut RequestContext,
+		websocket: WebSocketHandle,
+		after_hibernation: bool,
+	) -> Result<Option<CloseFrame>> {
+		self.inner
+			.handle_websocket(req_ctx, websocket, after_hibernation)
+			.await
+	}
+
+	async fn handle_websocket_hibernation(
+		&self,
+		req_ctx: &mut RequestContext,
+		websocket: WebSocketHandle,
+	) -> Result<HibernationResult> {
+		self.inner
+			.handle_websocket_hibernation(req_ctx, websocket)
+			.await
+	}
+}
+
+impl CachingServe {
+	async fn store(&self, cached: CachedResponse) {
+		let shared_value = (self.cache.config.shared()
+			&& cached.stored_size() <= MAX_SHARED_ENTRY_SIZE)
+			.then(|| cached.to_stored());
+
+		self.cache
+			.entries
+			.insert((self.actor_id, self.request_key), Arc::new(cached))
+			.await;
+		metrics::RESPONSE_CACHE_SIZE.set(self.cache.entries.weighted_size() as i64);
+
+		// Don't hold up the response on the shared tier
+		if let Some(value) = shared_value {
+			let ctx = self.ctx.clone();
+			let actor_id = self.actor_id;
+			let request_key = self.request_key;
+			tokio::spawn(async move {
+				let res = async {
+					ctx.udb()?
+						.txn("guard_response_cache_set", |tx| {
+							let value = value.clone();
+							async move {
+								let tx = tx.with_subspace(pegboard::keys::subspace());
+
+								tx.write(
+									&pegboard::keys::actor::ResponseCacheKey::new(
+										actor_id,
+										request_key,
+									),
+									value,
+								)?;
+
+								Ok(())
+							}
+						})
+						.custom_instrument(tracing::info_span!("response_cache_set_tx"))
+						.await
+				}
+				.await;
+
+				if let Err(err) = res {
+					tracing::warn!(?err, "failed to write shared response cache");
+				}
+			});
+		}
+	}
+}
+
+/// Requests with cookies or a Rivet token are never served from or stored in the cache since the
+/// response may depend on the caller and actors rarely mark those responses as private.
+fn has_caller_credentials(headers: &HeaderMap) -> bool {
+	headers.contains_key(header::COOKIE) || headers.contains_key(X_RIVET_TOKEN)
+}
+
+/// Returns how long a response may be cached by a shared cache, if at all.
+fn cacheable_ttl(
+	res: &Response<ResponseBody>,
+	authorized: bool,
+	max_ttl: Duration,
+) -> Option<Duration> {
+	if res.status() != StatusCode::OK
+		|| res.headers().contains_key(header::SET_COOKIE)
+		|| res.headers().contains_key(header::VARY)
+	{
+		return None;
+	}
+
+	let directives = CacheControl::parse(res.headers());
+	if directives.no_store || directives.no_cache || directives.private {
+		return None;
+	}
+
+	// Responses to authorized requests must explicitly allow shared caching
+	if authorized && !directives.public && directives.s_maxage.is_none() {
+		return None;
+	}
+
+	let ttl = directives.s_maxage.or(directives.max_age)?;
+	if ttl == 0 {
+		return None;
+	}
+
+	Some(Duration::from_secs(ttl).min(max_ttl))
+}
+
+#[derive(Default)]
+struct CacheControl {
+	public: bool,
+	private: bool,
+	no_cache: bool,
+	no_store: bool,
+	max_age: Option<u64>,
+	s_maxage: Option<u64>,
+}
+
+impl CacheControl {
+	fn parse(headers: &HeaderMap) -> Self {
+		let mut directives = CacheControl::default();
+
+		let values = headers
+			.get_all(header::CACHE_CONTROL)
+			.iter()
+			.filter_map(|value| value.to_str().ok())
+			.flat_map(|value| value.split(','));
+		for directive in values {
+			let (name, value) = match directive.split_once('=') {
+				Some((name, value)) => (name.trim(), Some(value.trim().trim_matches('"'))),
+				None => (directive.trim(), None),
+			};
+
+			match name.to_ascii_lowercase().as_str() {
+				"public" => directives.public = true,
+				"private" => directives.private = true,
+				"no-cache" => directives.no_cache = true,
+				"no-store" => directives.no_store = true,
+				"max-age" => directives.max_age = value.and_then(|v| v.parse().ok()),
+				"s-maxage" => directives.s_maxage = value.and_then(|v| v.parse().ok()),
+				_ => {}
+			}
+		}
+
+		// Legacy HTTP/1.0 clients
+		if headers
+			.get(header::PRAGMA)
+			.and_then(|value| value.to_str().ok())
+			.is_some_and(|value| value.eq_ignore_ascii_case("no-cache"))
+		{
+			directives.no_cache = true;
+		}
+
+		directives
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const MAX_TTL: Duration = Duration::from_secs(3600);
+
+	fn response(status: StatusCode, headers: &[(HeaderName, &str)]) -> Response<ResponseBody> {
+		let mut res = Response::new(ResponseBody::Full(Full::new(Bytes::new())));
+		*res.status_mut() = status;
+		for (name, value) in headers {
+			res.headers_mut()
+				.append(name.clone(), HeaderValue::from_str(value).unwrap());
+		}
+		res
+	}
+
+	#[test]
+	fn caches_max_age() {
+		let res = response(StatusCode::OK, &[(header::CACHE_CONTROL, "max-age=60")]);
+		assert_eq!(
+			cacheable_ttl(&res, false, MAX_TTL),
+			Some(Duration::from_secs(60))
+		);
+	}
+
+	#[test]
+	fn prefers_s_maxage_and_caps_at_max_ttl() {
+		let res = response(
+			StatusCode::OK,
+			&[(header::CACHE_CONTROL, "max-age=60, s-maxage=7200")],
+		);
+		assert_eq!(cacheable_ttl(&res, false, MAX_TTL), Some(MAX_TTL));
+	}
+
+	#[test]
+	fn skips_uncacheable_responses() {
+		for headers in [
+			&[][..],
+			&[(header::CACHE_CONTROL, "no-store, max-age=60")][..],
+			&[(header::CACHE_CONTROL, "private, max-age=60")][..],
+			&[(header::CACHE_CONTROL, "max-age=0")][..],
+			&[
+				(header::CACHE_CONTROL, "max-age=60"),
+				(header::SET_COOKIE, "a=b"),
+			][..],
+			&[
+				(header::CACHE_CONTROL, "max-age=60"),
+				(header::VARY, "Cookie"),
+			][..],
+		] {
+			let res = response(StatusCode::OK, headers);
+			assert_eq!(cacheable_ttl(&res, false, MAX_TTL), None);
+		}
+
+		let res = response(
+			StatusCode::NOT_FOUND,
+			&[(header::CACHE_CONTROL, "max-age=60")],
+		);
+		assert_eq!(cacheable_ttl(&res, false, MAX_TTL), None);
+	}
+
+	#[test]
+	fn authorized_requests_require_public() {
+		let res = response(StatusCode::OK, &[(header::CACHE_CONTROL, "max-age=60")]);
+		assert_eq!(cacheable_ttl(&res, true, MAX_TTL), None);
+
+		let res = response(
+			StatusCode::OK,
+			&[(header::CACHE_CONTROL, "public, max-age=60")],
+		);
+		assert_eq!(
+			cacheable_ttl(&res, true, MAX_TTL),
+			Some(Duration::from_secs(60))
+		);
+	}
+
+	#[test]
+	fn skips_requests_with_caller_credentials() {
+		let mut headers = HeaderMap::new();
+		assert!(!has_caller_credentials(&headers));
+
+		headers.insert(header::AUTHORIZATION, HeaderValue::from_static("Bearer a"));
+		assert!(!has_caller_credentials(&headers));
+
+		for name in [header::COOKIE, X_RIVET_TOKEN] {
+			let mut headers = HeaderMap::new();
+			headers.insert(name, HeaderValue::from_static("a"));
+			assert!(has_caller_credentials(&headers));
+		}
+	}
+
+	#[test]
+	fn stored_response_round_trips() {
+		let mut headers = HeaderMap::new();
+		headers.insert(header::CONTENT_TYPE, HeaderValue::from_static("text/plain"));
+		let res = CachedResponse {
+			path: "/a?b=c".to_string(),
+			status: StatusCode::OK,
+			headers,
+			body: Bytes::from_static(b"hello"),
+			stored_at: 1,
+			expires_at: 2,
+		};
+
+		let decoded = CachedResponse::from_stored(res.to_stored()).unwrap();
+		assert_eq!(decoded.path, "/a?b=c");
+		assert_eq!(decoded.status, StatusCode::OK);
+		assert_eq!(decoded.headers, res.headers);
+		assert_eq!(decoded.body, res.body);
+		assert_eq!(decoded.expires_at, 2);
+	}
+}
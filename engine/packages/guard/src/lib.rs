@@ -23,7 +23,7 @@ pub async fn start(config: rivet_config::Config, pools: rivet_pools::Pools) -> R
 
 	// Share shared context
 	let shared_state = shared_state::SharedState::new(&config, ctx.ups()?);
-	shared_state.start().await?;
+	shared_state.start(&ctx).await?;
 
 	// Create handlers
 	let routing_fn = routing::create_routing_function(&ctx, shared_state.clone());
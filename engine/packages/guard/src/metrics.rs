@@ -95,4 +95,25 @@ lazy_static! {
 			*REGISTRY
 		)
 		.unwrap();
+
+	// MARK: Response cache
+	pub static ref RESPONSE_CACHE_TOTAL: IntCounterVec = register_int_counter_vec_with_registry!(
+		"guard_response_cache_total",
+		"Total number of actor response cache lookups.",
+		&["result"],
+		*REGISTRY
+	)
+	.unwrap();
+	pub static ref RESPONSE_CACHE_SIZE: IntGauge = register_int_gauge_with_registry!(
+		"guard_response_cache_size",
+		"Total size of response bodies cached in memory in bytes.",
+		*REGISTRY
+	)
+	.unwrap();
+	pub static ref RESPONSE_CACHE_PURGE_TOTAL: IntCounter = register_int_counter_with_registry!(
+		"guard_response_cache_purge_total",
+		"Total number of actors whose cached responses were purged.",
+		*REGISTRY
+	)
+	.unwrap();
 }
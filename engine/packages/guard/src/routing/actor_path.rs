@@ -45,6 +45,13 @@ pub enum QueryActorQuery {
 }
 
 impl QueryActorQuery {
+	pub fn namespace(&self) -> &str {
+		match self {
+			QueryActorQuery::Get { namespace, .. }
+			| QueryActorQuery::GetOrCreate { namespace, .. } => namespace,
+		}
+	}
+
 	pub fn skip_ready_wait(&self) -> bool {
 		match self {
 			QueryActorQuery::Get {
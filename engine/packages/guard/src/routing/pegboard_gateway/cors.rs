@@ -2,24 +2,42 @@ use anyhow::Result;
 use async_trait::async_trait;
 use bytes::Bytes;
 use http_body_util::Full;
-use hyper::{Request, Response, StatusCode};
+use hyper::{HeaderMap, Request, Response, StatusCode};
 use rivet_guard_core::{
 	ResponseBody,
 	custom_serve::CustomServeTrait,
 	request_context::{CorsConfig, RequestContext},
 };
+use rivet_types::namespaces::CorsPolicy;
 
-pub fn origin_header(req_ctx: &RequestContext) -> String {
-	req_ctx
-		.headers()
-		.get("origin")
-		.and_then(|v| v.to_str().ok())
-		.unwrap_or("*")
-		.to_string()
+const DEFAULT_ALLOW_METHODS: &str = "GET, POST, PUT, DELETE, OPTIONS, PATCH";
+const DEFAULT_MAX_AGE: u32 = 86400;
+
+/// Returns the value for `access-control-allow-origin`, or `None` if the request's origin is not
+/// allowed by the namespace's policy.
+fn allow_origin(headers: &HeaderMap, cors_policy: Option<&CorsPolicy>) -> Option<String> {
+	let origin = headers.get("origin").and_then(|v| v.to_str().ok());
+
+	match cors_policy.and_then(|policy| policy.allowed_origins.as_ref()) {
+		Some(allowed_origins) => origin
+			.filter(|origin| {
+				allowed_origins
+					.iter()
+					.any(|allowed| allowed == "*" || allowed == origin)
+			})
+			.map(ToOwned::to_owned),
+		None => Some(origin.unwrap_or("*").to_string()),
+	}
 }
 
-pub fn set_non_preflight_cors(req_ctx: &mut RequestContext) {
-	let allow_origin = origin_header(req_ctx);
+/// Attaches CORS headers to the actual (non-OPTIONS) response. Called with no policy before the
+/// actor's namespace is known and again with the namespace's policy once it is.
+pub fn set_non_preflight_cors(req_ctx: &mut RequestContext, cors_policy: Option<&CorsPolicy>) {
+	let Some(allow_origin) = allow_origin(req_ctx.headers(), cors_policy) else {
+		req_ctx.clear_cors();
+		return;
+	};
+
 	req_ctx.set_cors(CorsConfig {
 		allow_origin,
 		allow_credentials: true,
@@ -30,10 +48,19 @@ pub fn set_non_preflight_cors(req_ctx: &mut RequestContext) {
 	});
 }
 
-/// Responds to CORS preflight OPTIONS requests with 204 and permissive CORS
-/// headers. Avoids actor lookup, wake, and auth because browsers cannot attach
-/// credentials to preflights. The actual request that follows is still authed.
-pub struct CorsPreflight;
+/// Responds to CORS preflight OPTIONS requests with 204 and the namespace's CORS policy, or
+/// permissive CORS headers if the namespace could not be determined from the request or has no
+/// policy. Avoids waking the actor and auth because browsers cannot attach credentials to
+/// preflights. The actual request that follows is still authed.
+pub struct CorsPreflight {
+	cors_policy: Option<CorsPolicy>,
+}
+
+impl CorsPreflight {
+	pub fn new(cors_policy: Option<CorsPolicy>) -> Self {
+		CorsPreflight { cors_policy }
+	}
+}
 
 #[async_trait]
 impl CustomServeTrait for CorsPreflight {
@@ -42,27 +69,40 @@ impl CustomServeTrait for CorsPreflight {
 		req: Request<Full<Bytes>>,
 		req_ctx: &mut RequestContext,
 	) -> Result<Response<ResponseBody>> {
-		let allow_origin = req
-			.headers()
-			.get("origin")
-			.and_then(|v| v.to_str().ok())
-			.unwrap_or("*")
-			.to_string();
-		let allow_headers = req
-			.headers()
-			.get("access-control-request-headers")
-			.and_then(|v| v.to_str().ok())
-			.unwrap_or("*")
-			.to_string();
+		let cors_policy = self.cors_policy.as_ref();
+
+		// Responding without CORS headers fails the preflight in the browser
+		if let Some(allow_origin) = allow_origin(req.headers(), cors_policy) {
+			let allow_methods = cors_policy
+				.and_then(|policy| policy.allowed_methods.as_ref())
+				.map(|methods| methods.join(", "))
+				.unwrap_or_else(|| DEFAULT_ALLOW_METHODS.to_string());
+			let allow_headers = match cors_policy.and_then(|policy| policy.allowed_headers.as_ref())
+			{
+				Some(headers) => headers.join(", "),
+				None => req
+					.headers()
+					.get("access-control-request-headers")
+					.and_then(|v| v.to_str().ok())
+					.unwrap_or("*")
+					.to_string(),
+			};
+			let max_age = cors_policy
+				.and_then(|policy| policy.max_age)
+				.unwrap_or(DEFAULT_MAX_AGE);
 
-		req_ctx.set_cors(CorsConfig {
-			allow_origin,
-			allow_credentials: true,
-			expose_headers: "*".to_string(),
-			allow_methods: Some("GET, POST, PUT, DELETE, OPTIONS, PATCH".to_string()),
-			allow_headers: Some(allow_headers),
-			max_age: Some(86400),
-		});
+			req_ctx.set_cors(CorsConfig {
+				allow_origin,
+				allow_credentials: true,
+				expose_headers: "*".to_string(),
+				allow_methods: Some(allow_methods),
+				allow_headers: Some(allow_headers),
+				max_age: Some(max_age),
+			});
+		} else {
+			tracing::debug!("cors preflight origin not allowed by namespace policy");
+			req_ctx.clear_cors();
+		}
 
 		Ok(Response::builder()
 			.status(StatusCode::NO_CONTENT)
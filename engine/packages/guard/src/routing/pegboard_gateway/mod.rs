@@ -6,13 +6,18 @@ use std::{sync::Arc, time::Duration};
 use anyhow::Result;
 use gas::{ctx::message::SubscriptionHandle, prelude::*};
 use hyper::header::HeaderName;
-use rivet_guard_core::{RouteConfig, RouteTarget, RoutingOutput, request_context::RequestContext};
+use rivet_guard_core::{
+	RouteConfig, RouteTarget, RoutingOutput,
+	request_context::{ActorRateLimitConfig, RequestContext},
+};
+use rivet_types::namespaces::{CorsPolicy, Namespace};
 
 use super::{
 	SEC_WEBSOCKET_PROTOCOL, WS_PROTOCOL_ACTOR, WS_PROTOCOL_SKIP_READY_WAIT, WS_PROTOCOL_TOKEN,
 	X_RIVET_SKIP_READY_WAIT, X_RIVET_TOKEN, actor_path::ParsedActorPath,
 };
 use crate::{
+	cache::response,
 	errors, metrics,
 	routing::{
 		Phase,
@@ -38,35 +43,26 @@ pub async fn route_request_path_based(
 	ctx: &StandaloneCtx,
 	shared_state: &SharedState,
 	req_ctx: &mut RequestContext,
-) -> Result<Option<RoutingOutput>> {
-	let res = route_request_path_based_inner(ctx, shared_state, req_ctx).await;
-
-	match &res {
-		Ok(Some(_)) | Err(_) => {
-			// Attach CORS headers to the actual (non-OPTIONS) response so both the
-			// actor response and any early error are readable by the browser.
-			set_non_preflight_cors(req_ctx);
-		}
-		_ => {}
-	}
-
-	res
-}
-
-pub async fn route_request_path_based_inner(
-	ctx: &StandaloneCtx,
-	shared_state: &SharedState,
-	req_ctx: &mut RequestContext,
 ) -> Result<Option<RoutingOutput>> {
 	if req_ctx.method() == hyper::Method::OPTIONS {
 		if is_actor_gateway_path(req_ctx.path()) {
-			return Ok(Some(RoutingOutput::CustomServe(Arc::new(CorsPreflight))));
+			let cors_policy = resolve_preflight_cors_policy(ctx, req_ctx.path()).await;
+			return Ok(Some(RoutingOutput::CustomServe(Arc::new(
+				CorsPreflight::new(cors_policy),
+			))));
 		}
 
 		return Ok(None);
 	}
 
-	let Some(actor_path) = parse_actor_path(req_ctx.path())? else {
+	let actor_path = parse_actor_path(req_ctx.path());
+	if !matches!(actor_path, Ok(None)) {
+		// Attach CORS headers to the actual (non-OPTIONS) response so both the
+		// actor response and any early error are readable by the browser. Replaced
+		// with the namespace's policy once the actor is resolved.
+		set_non_preflight_cors(req_ctx, None);
+	}
+	let Some(actor_path) = actor_path? else {
 		return Ok(None);
 	};
 
@@ -156,8 +152,12 @@ pub async fn route_request(
 		return Ok(None);
 	}
 
+	// Header-based preflights do not identify the actor since browsers do not send custom headers
+	// with them, so the namespace's CORS policy cannot be applied
 	if req_ctx.method() == hyper::Method::OPTIONS {
-		return Ok(Some(RoutingOutput::CustomServe(Arc::new(CorsPreflight))));
+		return Ok(Some(RoutingOutput::CustomServe(Arc::new(
+			CorsPreflight::new(None),
+		))));
 	}
 
 	if !req_ctx.is_websocket() && !is_actor_http_request_path(req_ctx.path()) {
@@ -165,8 +165,9 @@ pub async fn route_request(
 	}
 
 	// Attach CORS headers to the actual (non-OPTIONS) response so both the
-	// actor response and any early error are readable by the browser.
-	set_non_preflight_cors(req_ctx);
+	// actor response and any early error are readable by the browser. Replaced
+	// with the namespace's policy once the actor is resolved.
+	set_non_preflight_cors(req_ctx, None);
 
 	// Extract actor ID and token from WebSocket protocol or HTTP headers
 	let (actor_id_str, token, skip_ready_wait) = if req_ctx.is_websocket() {
@@ -251,6 +252,65 @@ pub async fn route_request(
 	.map(Some)
 }
 
+async fn get_namespace(ctx: &StandaloneCtx, namespace_id: Id) -> Result<Option<Namespace>> {
+	let namespace = ctx
+		.op(namespace::ops::get_global::Input {
+			namespace_ids: vec![namespace_id],
+		})
+		.await?
+		.into_iter()
+		.next();
+
+	Ok(namespace)
+}
+
+/// Finds the CORS policy of the namespace a path-based preflight targets. Falls back to the
+/// permissive default (`None`) if the namespace cannot be resolved, since the actual request is
+/// checked again once the actor is resolved.
+async fn resolve_preflight_cors_policy(ctx: &StandaloneCtx, path: &str) -> Option<CorsPolicy> {
+	let res = async {
+		let cors_policy = match parse_actor_path(path)? {
+			Some(ParsedActorPath::Direct(path)) => {
+				let actor_id = Id::parse(&path.actor_id).context("invalid actor id in path")?;
+
+				// Actors in other datacenters are not readable from here
+				if actor_id.label() != ctx.config().dc_label() {
+					return Ok(None);
+				}
+
+				let Some(actor) = ctx
+					.op(pegboard::ops::actor::get_for_gateway::Input { actor_id })
+					.await?
+				else {
+					return Ok(None);
+				};
+
+				get_namespace(ctx, actor.namespace_id)
+					.await?
+					.and_then(|ns| ns.cors_policy)
+			}
+			Some(ParsedActorPath::Query(path)) => ctx
+				.op(namespace::ops::resolve_for_name_global::Input {
+					name: path.query.namespace().to_string(),
+				})
+				.await?
+				.and_then(|ns| ns.cors_policy),
+			None => None,
+		};
+
+		anyhow::Ok(cors_policy)
+	}
+	.await;
+
+	match res {
+		Ok(cors_policy) => cors_policy,
+		Err(err) => {
+			tracing::debug!(?err, "failed to resolve cors policy for preflight");
+			None
+		}
+	}
+}
+
 fn is_actor_http_request_path(path: &str) -> bool {
 	let Some(stripped) = path.strip_prefix("/request") else {
 		return false;
@@ -270,6 +330,8 @@ async fn route_request_inner(
 ) -> Result<RoutingOutput> {
 	// NOTE: Token validation implemented in EE
 
+	req_ctx.set_actor_id(actor_id);
+
 	// Route to peer dc where the actor lives
 	if actor_id.label() != ctx.config().dc_label() {
 		tracing::debug!(peer_dc_label=?actor_id.label(), "re-routing actor to peer dc");
@@ -363,7 +425,44 @@ async fn route_request_inner(
 		return Err(pegboard::errors::Actor::NotFound.build());
 	}
 
-	match actor.version {
+	let namespace = get_namespace(ctx, actor.namespace_id).await?;
+
+	// Only browsers send an origin
+	if req_ctx.headers().contains_key(hyper::header::ORIGIN) {
+		let cors_policy = namespace.as_ref().and_then(|ns| ns.cors_policy.as_ref());
+		set_non_preflight_cors(req_ctx, cors_policy);
+	}
+
+	// Reject before waking the actor so throttled clients cannot keep it awake
+	if let Some(actor_rate_limit) = namespace.and_then(|ns| ns.actor_rate_limit) {
+		req_ctx
+			.check_actor_rate_limit(
+				actor_id,
+				&ActorRateLimitConfig {
+					requests_per_second: actor_rate_limit.requests_per_second,
+					burst: actor_rate_limit
+						.burst
+						.unwrap_or(actor_rate_limit.requests_per_second),
+				},
+			)
+			.await?;
+	}
+
+	// Serve cached responses without waking the actor
+	let response_cache = shared_state
+		.response_cache
+		.as_ref()
+		.filter(|_| response::is_cacheable_request(req_ctx))
+		.map(|cache| (cache, response::request_key(stripped_path)));
+	if let Some((cache, request_key)) = response_cache
+		&& let Some(cached) = cache.get(ctx, actor_id, request_key, stripped_path).await
+	{
+		return Ok(RoutingOutput::CustomServe(Arc::new(response::CachedServe(
+			cached,
+		))));
+	}
+
+	let output = match actor.version {
 		2 => {
 			drop(ready_sub);
 			drop(stopped_sub);
@@ -383,12 +482,13 @@ async fn route_request_inner(
 				fail_sub2,
 				destroy_sub2,
 			)
-			.await
+			.await?
 		}
 		1 => {
 			handle_actor_v1(
 				ctx,
 				shared_state,
+				req_ctx,
 				actor_id,
 				actor,
 				stripped_path,
@@ -403,10 +503,25 @@ async fn route_request_inner(
 				fail_sub2,
 				destroy_sub2,
 			)
-			.await
+			.await?
 		}
 		_ => bail!("unknown actor version"),
+	};
+
+	// Store cacheable responses from the actor
+	if let (Some((cache, request_key)), RoutingOutput::CustomServe(handler)) =
+		(response_cache, &output)
+	{
+		return Ok(RoutingOutput::CustomServe(cache.wrap(
+			ctx,
+			actor_id,
+			request_key,
+			stripped_path.to_string(),
+			handler.clone(),
+		)));
 	}
+
+	Ok(output)
 }
 
 async fn handle_actor_v2(
@@ -625,6 +740,7 @@ async fn handle_actor_v2(
 		pool_name,
 		envoy_key,
 		actor_id,
+		actor.name,
 		actor.key,
 		None,
 		stripped_path.to_string(),
@@ -635,6 +751,7 @@ async fn handle_actor_v2(
 async fn handle_actor_v1(
 	ctx: &StandaloneCtx,
 	shared_state: &SharedState,
+	req_ctx: &mut RequestContext,
 	actor_id: Id,
 	actor: pegboard::ops::actor::get_for_gateway::Output,
 	stripped_path: &str,
@@ -790,12 +907,16 @@ async fn handle_actor_v1(
 
 	tracing::debug!(?actor_id, ?runner_id, "actor ready");
 
+	req_ctx.set_runner_id(runner_id);
+
 	// Return pegboard-gateway instance with path
 	let gateway = pegboard_gateway::PegboardGateway::new(
 		ctx.clone(),
 		shared_state.pegboard_gateway.clone(),
+		actor.namespace_id,
 		runner_id,
 		actor_id,
+		actor.name,
 		stripped_path.to_string(),
 	);
 	Ok(RoutingOutput::CustomServe(std::sync::Arc::new(gateway)))
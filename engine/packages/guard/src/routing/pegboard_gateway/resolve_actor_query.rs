@@ -5,6 +5,8 @@
 //! resolution in `rivetkit-typescript/packages/rivetkit/src/manager/gateway.ts`
 //! (`resolveQueryActorId`).
 
+use std::collections::HashMap;
+
 use anyhow::Result;
 use base64::{Engine, engine::general_purpose::STANDARD};
 use gas::prelude::*;
@@ -157,7 +159,10 @@ async fn resolve_query_get_or_create(
 				name: name.to_string(),
 				key: Some(serialized_key.clone()),
 				runner_name_selector: pool_name.to_string(),
-				crash_policy,
+				crash_policy: Some(crash_policy),
+				affinity: None,
+				required_capabilities: Vec::new(),
+				tags: HashMap::new(),
 				input: encoded_input,
 				forward_request: true,
 				datacenter_name: None,
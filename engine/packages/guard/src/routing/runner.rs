@@ -4,8 +4,7 @@ use rivet_guard_core::{RoutingOutput, request_context::RequestContext};
 use std::sync::Arc;
 use subtle::ConstantTimeEq;
 
-use super::{SEC_WEBSOCKET_PROTOCOL, X_RIVET_TOKEN, validate_regional_host};
-pub(crate) const WS_PROTOCOL_TOKEN: &str = "rivet_token.";
+use super::{X_RIVET_TOKEN, validate_regional_host};
 
 /// Route requests to the runner service using header-based routing
 #[tracing::instrument(skip_all)]
@@ -49,49 +48,35 @@ async fn route_request_inner(
 ) -> Result<RoutingOutput> {
 	validate_regional_host(ctx, req_ctx)?;
 
-	// Check auth (if enabled)
+	// Check auth (if enabled). Runner tokens are scoped to a namespace, so when they are configured any token
+	// other than the admin token is validated by pegboard-runner once the namespace is known.
 	if let Some(auth) = &ctx.config().auth {
 		// Extract token from protocol or header
-		let token = if req_ctx.is_websocket() {
-			req_ctx
-				.headers()
-				.get(SEC_WEBSOCKET_PROTOCOL)
-				.and_then(|protocols| protocols.to_str().ok())
-				.and_then(|protocols| {
-					protocols
-						.split(',')
-						.map(|p| p.trim())
-						.find_map(|p| p.strip_prefix(WS_PROTOCOL_TOKEN))
-				})
-				.ok_or_else(|| {
-					crate::errors::MissingHeader {
-						header: "`rivet_token.*` protocol in sec-websocket-protocol".to_string(),
-					}
-					.build()
-				})?
-		} else {
-			req_ctx
-				.headers()
-				.get(X_RIVET_TOKEN)
-				.and_then(|x| x.to_str().ok())
-				.ok_or_else(|| {
-					crate::errors::MissingHeader {
-						header: X_RIVET_TOKEN.to_string(),
-					}
-					.build()
-				})?
-		};
+		let token = pegboard_runner::parse_token(req_ctx);
 
 		// Validate token
-		if token
-			.as_bytes()
-			.ct_ne(auth.admin_token.read().as_bytes())
-			.into()
-		{
+		let is_admin = token.as_ref().is_some_and(|token| {
+			token
+				.as_bytes()
+				.ct_eq(auth.admin_token.read().as_bytes())
+				.into()
+		});
+
+		if is_admin {
+			tracing::debug!("authenticated runner connection");
+		} else if ctx.config().pegboard().runner_tokens.is_none() {
+			if token.is_none() {
+				let header = if req_ctx.is_websocket() {
+					"`rivet_token.*` protocol in sec-websocket-protocol".to_string()
+				} else {
+					X_RIVET_TOKEN.to_string()
+				};
+
+				return Err(crate::errors::MissingHeader { header }.build());
+			}
+
 			return Err(rivet_api_builder::ApiForbidden.build());
 		}
-
-		tracing::debug!("authenticated runner connection");
 	}
 
 	let tunnel = pegboard_runner::PegboardRunnerWsCustomServe::new(ctx.clone());
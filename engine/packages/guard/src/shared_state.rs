@@ -1,7 +1,10 @@
 use anyhow::*;
+use gas::prelude::StandaloneCtx;
 use std::{ops::Deref, sync::Arc};
 use universalpubsub::PubSub;
 
+use crate::cache::response::ResponseCache;
+
 #[derive(Clone)]
 pub struct SharedState(Arc<SharedStateInner>);
 
@@ -13,15 +16,20 @@ impl SharedState {
 				pubsub.clone(),
 			),
 			pegboard_gateway2: pegboard_gateway2::shared_state::SharedState::new(config, pubsub),
+			response_cache: config.guard().response_cache().map(ResponseCache::new),
 		}))
 	}
 
-	pub async fn start(&self) -> Result<()> {
+	pub async fn start(&self, ctx: &StandaloneCtx) -> Result<()> {
 		tokio::try_join!(
 			self.pegboard_gateway.start(),
 			self.pegboard_gateway2.start(),
 		)?;
 
+		if let Some(response_cache) = &self.response_cache {
+			response_cache.start(ctx.clone()).await?;
+		}
+
 		Ok(())
 	}
 }
@@ -37,4 +45,5 @@ impl Deref for SharedState {
 pub struct SharedStateInner {
 	pub pegboard_gateway: pegboard_gateway::shared_state::SharedState,
 	pub pegboard_gateway2: pegboard_gateway2::shared_state::SharedState,
+	pub response_cache: Option<ResponseCache>,
 }
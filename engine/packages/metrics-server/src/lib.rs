@@ -2,4 +2,4 @@ mod providers;
 mod server;
 
 pub use providers::{OtelProviderGuard, init_otel_providers, set_sampler_ratio};
-pub use server::run_standalone;
+pub use server::{HealthReporter, run_standalone};
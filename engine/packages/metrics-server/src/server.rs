@@ -1,15 +1,31 @@
-use std::{net::SocketAddr, result::Result::Ok};
+use std::{net::SocketAddr, result::Result::Ok, sync::Arc};
 
 use anyhow::*;
 use hyper::{
-	Body, Request, Response, Server,
+	Body, Method, Request, Response, Server, StatusCode,
 	header::CONTENT_TYPE,
 	service::{make_service_fn, service_fn},
 };
 use rivet_metrics::prometheus::{Encoder, TextEncoder};
 
+/// Reports process health on the metrics server's `/health/*` routes.
+pub trait HealthReporter: Send + Sync {
+	/// Whether the process is ready to receive traffic.
+	fn ready(&self) -> bool;
+
+	/// Health of each component of the process.
+	fn report(&self) -> serde_json::Value;
+}
+
+/// Runs the metrics server.
+///
+/// Serves `/health/live` and `/health/ready` probes from `health`. Every other path serves
+/// Prometheus metrics.
 #[tracing::instrument(skip_all)]
-pub async fn run_standalone(config: rivet_config::Config) -> Result<()> {
+pub async fn run_standalone(
+	config: rivet_config::Config,
+	health: Arc<dyn HealthReporter>,
+) -> Result<()> {
 	let host = config.metrics.host();
 	let port = config.metrics.port();
 	let addr = SocketAddr::from((host, port));
@@ -24,8 +40,9 @@ pub async fn run_standalone(config: rivet_config::Config) -> Result<()> {
 		}
 	};
 
-	let server = server.serve(make_service_fn(|_| async {
-		Ok::<_, hyper::Error>(service_fn(serve_req))
+	let server = server.serve(make_service_fn(move |_| {
+		let health = health.clone();
+		async move { Ok::<_, hyper::Error>(service_fn(move |req| serve_req(req, health.clone()))) }
 	}));
 
 	tracing::info!(?host, ?port, "started metrics server");
@@ -35,7 +52,32 @@ pub async fn run_standalone(config: rivet_config::Config) -> Result<()> {
 }
 
 #[tracing::instrument(level = "debug", skip_all)]
-async fn serve_req(_req: Request<Body>) -> Result<Response<Body>, hyper::Error> {
+async fn serve_req(
+	req: Request<Body>,
+	health: Arc<dyn HealthReporter>,
+) -> Result<Response<Body>, hyper::Error> {
+	match (req.method(), req.uri().path()) {
+		(&Method::GET, "/health/live") => Ok(Response::new(Body::from("ok"))),
+		(&Method::GET, "/health/ready") => Ok(serve_ready(&*health)),
+		_ => Ok(serve_metrics()),
+	}
+}
+
+fn serve_ready(health: &dyn HealthReporter) -> Response<Body> {
+	let status = if health.ready() {
+		StatusCode::OK
+	} else {
+		StatusCode::SERVICE_UNAVAILABLE
+	};
+
+	Response::builder()
+		.status(status)
+		.header(CONTENT_TYPE, "application/json")
+		.body(Body::from(health.report().to_string()))
+		.expect("response")
+}
+
+fn serve_metrics() -> Response<Body> {
 	let encoder = TextEncoder::new();
 
 	let metric_families = rivet_metrics::REGISTRY.gather();
@@ -44,11 +86,9 @@ async fn serve_req(_req: Request<Body>) -> Result<Response<Body>, hyper::Error>
 		.encode(&metric_families, &mut buffer)
 		.expect("encode");
 
-	let response = Response::builder()
+	Response::builder()
 		.status(200)
 		.header(CONTENT_TYPE, encoder.format_type())
 		.body(Body::from(buffer))
-		.expect("response");
-
-	Ok(response)
+		.expect("response")
 }
@@ -1,6 +1,14 @@
 use anyhow::Result;
 use gas::prelude::*;
+use rivet_data::generated::{
+	namespace_actor_rate_limit_v1, namespace_cors_policy_v1, namespace_default_crash_policy_v1,
+};
+use rivet_types::{
+	actors::CrashPolicy,
+	namespaces::{ActorRateLimit, CorsPolicy},
+};
 use universaldb::prelude::*;
+use vbare::OwnedVersionedData;
 
 pub mod metric;
 
@@ -144,6 +152,185 @@ impl<'de> TupleUnpack<'de> for CreateTsKey {
 	}
 }
 
+/// Crash policy used for actors created in this namespace without an explicit crash policy. Only set
+/// if configured.
+#[derive(Debug)]
+pub struct DefaultCrashPolicyKey {
+	namespace_id: Id,
+}
+
+impl DefaultCrashPolicyKey {
+	pub fn new(namespace_id: Id) -> Self {
+		DefaultCrashPolicyKey { namespace_id }
+	}
+}
+
+impl FormalKey for DefaultCrashPolicyKey {
+	type Value = CrashPolicy;
+
+	fn deserialize(&self, raw: &[u8]) -> Result<Self::Value> {
+		let data = rivet_data::versioned::NamespaceDefaultCrashPolicyKeyData::deserialize_with_embedded_version(raw)?;
+
+		Ok(match data.crash_policy {
+			namespace_default_crash_policy_v1::CrashPolicy::Restart => CrashPolicy::Restart,
+			namespace_default_crash_policy_v1::CrashPolicy::Sleep => CrashPolicy::Sleep,
+			namespace_default_crash_policy_v1::CrashPolicy::Destroy => CrashPolicy::Destroy,
+		})
+	}
+
+	fn serialize(&self, value: Self::Value) -> Result<Vec<u8>> {
+		rivet_data::versioned::NamespaceDefaultCrashPolicyKeyData::wrap_latest(
+			namespace_default_crash_policy_v1::Data {
+				crash_policy: match value {
+					CrashPolicy::Restart => namespace_default_crash_policy_v1::CrashPolicy::Restart,
+					CrashPolicy::Sleep => namespace_default_crash_policy_v1::CrashPolicy::Sleep,
+					CrashPolicy::Destroy => namespace_default_crash_policy_v1::CrashPolicy::Destroy,
+				},
+			},
+		)
+		.serialize_with_embedded_version(rivet_data::NAMESPACE_DEFAULT_CRASH_POLICY_VERSION)
+	}
+}
+
+impl TuplePack for DefaultCrashPolicyKey {
+	fn pack<W: std::io::Write>(
+		&self,
+		w: &mut W,
+		tuple_depth: TupleDepth,
+	) -> std::io::Result<VersionstampOffset> {
+		let t = (DATA, self.namespace_id, DEFAULT_CRASH_POLICY);
+		t.pack(w, tuple_depth)
+	}
+}
+
+impl<'de> TupleUnpack<'de> for DefaultCrashPolicyKey {
+	fn unpack(input: &[u8], tuple_depth: TupleDepth) -> PackResult<(&[u8], Self)> {
+		let (input, (_, namespace_id, _)) = <(usize, Id, usize)>::unpack(input, tuple_depth)?;
+		let v = DefaultCrashPolicyKey { namespace_id };
+
+		Ok((input, v))
+	}
+}
+
+/// CORS policy applied by the gateway to requests for actors in this namespace. Only set if
+/// configured.
+#[derive(Debug)]
+pub struct CorsPolicyKey {
+	namespace_id: Id,
+}
+
+impl CorsPolicyKey {
+	pub fn new(namespace_id: Id) -> Self {
+		CorsPolicyKey { namespace_id }
+	}
+}
+
+impl FormalKey for CorsPolicyKey {
+	type Value = CorsPolicy;
+
+	fn deserialize(&self, raw: &[u8]) -> Result<Self::Value> {
+		let data =
+			rivet_data::versioned::NamespaceCorsPolicyKeyData::deserialize_with_embedded_version(
+				raw,
+			)?;
+
+		Ok(CorsPolicy {
+			allowed_origins: data.allowed_origins,
+			allowed_methods: data.allowed_methods,
+			allowed_headers: data.allowed_headers,
+			max_age: data.max_age,
+		})
+	}
+
+	fn serialize(&self, value: Self::Value) -> Result<Vec<u8>> {
+		rivet_data::versioned::NamespaceCorsPolicyKeyData::wrap_latest(
+			namespace_cors_policy_v1::Data {
+				allowed_origins: value.allowed_origins,
+				allowed_methods: value.allowed_methods,
+				allowed_headers: value.allowed_headers,
+				max_age: value.max_age,
+			},
+		)
+		.serialize_with_embedded_version(rivet_data::NAMESPACE_CORS_POLICY_VERSION)
+	}
+}
+
+impl TuplePack for CorsPolicyKey {
+	fn pack<W: std::io::Write>(
+		&self,
+		w: &mut W,
+		tuple_depth: TupleDepth,
+	) -> std::io::Result<VersionstampOffset> {
+		let t = (DATA, self.namespace_id, CORS_POLICY);
+		t.pack(w, tuple_depth)
+	}
+}
+
+impl<'de> TupleUnpack<'de> for CorsPolicyKey {
+	fn unpack(input: &[u8], tuple_depth: TupleDepth) -> PackResult<(&[u8], Self)> {
+		let (input, (_, namespace_id, _)) = <(usize, Id, usize)>::unpack(input, tuple_depth)?;
+		let v = CorsPolicyKey { namespace_id };
+
+		Ok((input, v))
+	}
+}
+
+/// Per-actor rate limit applied by the gateway to requests for actors in this namespace. Only set
+/// if configured.
+#[derive(Debug)]
+pub struct ActorRateLimitKey {
+	namespace_id: Id,
+}
+
+impl ActorRateLimitKey {
+	pub fn new(namespace_id: Id) -> Self {
+		ActorRateLimitKey { namespace_id }
+	}
+}
+
+impl FormalKey for ActorRateLimitKey {
+	type Value = ActorRateLimit;
+
+	fn deserialize(&self, raw: &[u8]) -> Result<Self::Value> {
+		let data = rivet_data::versioned::NamespaceActorRateLimitKeyData::deserialize_with_embedded_version(raw)?;
+
+		Ok(ActorRateLimit {
+			requests_per_second: data.requests_per_second,
+			burst: data.burst,
+		})
+	}
+
+	fn serialize(&self, value: Self::Value) -> Result<Vec<u8>> {
+		rivet_data::versioned::NamespaceActorRateLimitKeyData::wrap_latest(
+			namespace_actor_rate_limit_v1::Data {
+				requests_per_second: value.requests_per_second,
+				burst: value.burst,
+			},
+		)
+		.serialize_with_embedded_version(rivet_data::NAMESPACE_ACTOR_RATE_LIMIT_VERSION)
+	}
+}
+
+impl TuplePack for ActorRateLimitKey {
+	fn pack<W: std::io::Write>(
+		&self,
+		w: &mut W,
+		tuple_depth: TupleDepth,
+	) -> std::io::Result<VersionstampOffset> {
+		let t = (DATA, self.namespace_id, ACTOR_RATE_LIMIT);
+		t.pack(w, tuple_depth)
+	}
+}
+
+impl<'de> TupleUnpack<'de> for ActorRateLimitKey {
+	fn unpack(input: &[u8], tuple_depth: TupleDepth) -> PackResult<(&[u8], Self)> {
+		let (input, (_, namespace_id, _)) = <(usize, Id, usize)>::unpack(input, tuple_depth)?;
+		let v = ActorRateLimitKey { namespace_id };
+
+		Ok((input, v))
+	}
+}
+
 #[derive(Debug)]
 pub struct ByNameKey {
 	name: String,
@@ -61,11 +61,17 @@ pub(crate) async fn get_inner(
 	let name_key = keys::NameKey::new(namespace_id);
 	let display_name_key = keys::DisplayNameKey::new(namespace_id);
 	let create_ts_key = keys::CreateTsKey::new(namespace_id);
+	let default_crash_policy_key = keys::DefaultCrashPolicyKey::new(namespace_id);
+	let cors_policy_key = keys::CorsPolicyKey::new(namespace_id);
+	let actor_rate_limit_key = keys::ActorRateLimitKey::new(namespace_id);
 
-	let (name, display_name, create_ts) = tokio::try_join!(
+	let (name, display_name, create_ts, default_crash_policy, cors_policy, actor_rate_limit) = tokio::try_join!(
 		tx.read_opt(&name_key, Serializable),
 		tx.read_opt(&display_name_key, Serializable),
 		tx.read_opt(&create_ts_key, Serializable),
+		tx.read_opt(&default_crash_policy_key, Serializable),
+		tx.read_opt(&cors_policy_key, Serializable),
+		tx.read_opt(&actor_rate_limit_key, Serializable),
 	)?;
 
 	// Namespace not found
@@ -81,5 +87,8 @@ pub(crate) async fn get_inner(
 		name,
 		display_name,
 		create_ts,
+		default_crash_policy,
+		cors_policy,
+		actor_rate_limit,
 	}))
 }
@@ -3,3 +3,4 @@ pub mod get_local;
 pub mod list;
 pub mod resolve_for_name_global;
 pub mod resolve_for_name_local;
+pub mod update;
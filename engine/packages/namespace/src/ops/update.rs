@@ -0,0 +1,92 @@
+use gas::prelude::*;
+use rivet_types::{
+	actors::CrashPolicy,
+	namespaces::{ActorRateLimit, CorsPolicy, Namespace},
+};
+use universaldb::utils::IsolationLevel::*;
+
+use crate::{errors, keys, ops::get_local::get_inner, utils};
+
+#[derive(Debug)]
+pub struct Input {
+	pub namespace_id: Id,
+	/// Cleared if not set.
+	pub default_crash_policy: Option<CrashPolicy>,
+	/// Cleared if not set.
+	pub cors_policy: Option<CorsPolicy>,
+	/// Cleared if not set.
+	pub actor_rate_limit: Option<ActorRateLimit>,
+}
+
+/// Replaces the settings of an existing namespace.
+///
+/// Only purges the namespace from this datacenter's cache. Other datacenters cache namespaces too, so
+/// the caller is responsible for purging them.
+#[operation]
+pub async fn namespace_update(ctx: &OperationCtx, input: &Input) -> Result<Namespace> {
+	if !ctx.config().is_leader() {
+		return Err(errors::Namespace::NotLeader.build());
+	}
+
+	if let Some(cors_policy) = &input.cors_policy {
+		utils::validate_cors_policy(cors_policy)
+			.map_err(|reason| errors::Namespace::InvalidUpdate { reason }.build())?;
+	}
+
+	if let Some(actor_rate_limit) = &input.actor_rate_limit {
+		utils::validate_actor_rate_limit(actor_rate_limit)
+			.map_err(|reason| errors::Namespace::InvalidUpdate { reason }.build())?;
+	}
+
+	let namespace = ctx
+		.udb()?
+		.txn("namespace_update", |tx| async move {
+			let ns_tx = tx.with_subspace(keys::subspace());
+
+			if !ns_tx
+				.exists(&keys::NameKey::new(input.namespace_id), Serializable)
+				.await?
+			{
+				return Ok(None);
+			}
+
+			let default_crash_policy_key = keys::DefaultCrashPolicyKey::new(input.namespace_id);
+			if let Some(default_crash_policy) = input.default_crash_policy {
+				ns_tx.write(&default_crash_policy_key, default_crash_policy)?;
+			} else {
+				ns_tx.delete(&default_crash_policy_key);
+			}
+
+			let cors_policy_key = keys::CorsPolicyKey::new(input.namespace_id);
+			if let Some(cors_policy) = &input.cors_policy {
+				ns_tx.write(&cors_policy_key, cors_policy.clone())?;
+			} else {
+				ns_tx.delete(&cors_policy_key);
+			}
+
+			let actor_rate_limit_key = keys::ActorRateLimitKey::new(input.namespace_id);
+			if let Some(actor_rate_limit) = input.actor_rate_limit {
+				ns_tx.write(&actor_rate_limit_key, actor_rate_limit)?;
+			} else {
+				ns_tx.delete(&actor_rate_limit_key);
+			}
+
+			get_inner(input.namespace_id, &tx).await
+		})
+		.custom_instrument(tracing::info_span!("namespace_update_tx"))
+		.await?
+		.ok_or_else(|| errors::Namespace::NotFound.build())?;
+
+	ctx.cache()
+		.clone()
+		.request()
+		.purge("namespace.get_local", [input.namespace_id])
+		.await?;
+	ctx.cache()
+		.clone()
+		.request()
+		.purge("namespace.resolve_for_name_local", [namespace.name.clone()])
+		.await?;
+
+	Ok(namespace)
+}
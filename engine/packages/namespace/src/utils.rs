@@ -1,5 +1,6 @@
 use rivet_types::{
 	keys::namespace::runner_config::RunnerConfigVariant,
+	namespaces::{ActorRateLimit, CorsPolicy},
 	runner_configs::{RunnerConfig, RunnerConfigKind},
 };
 
@@ -9,3 +10,51 @@ pub fn runner_config_variant(runner_config: &RunnerConfig) -> RunnerConfigVarian
 		RunnerConfigKind::Serverless { .. } => RunnerConfigVariant::Serverless,
 	}
 }
+
+/// Checks that every value in a CORS policy can be sent in a response header. Returns the reason
+/// the policy is invalid.
+pub fn validate_cors_policy(cors_policy: &CorsPolicy) -> Result<(), String> {
+	for origin in cors_policy.allowed_origins.iter().flatten() {
+		if origin.is_empty()
+			|| !origin.chars().all(|c| c.is_ascii_graphic())
+			|| origin.contains(',')
+		{
+			return Err(format!("invalid cors origin: {origin:?}"));
+		}
+	}
+
+	let is_token = |value: &str| {
+		!value.is_empty()
+			&& value
+				.chars()
+				.all(|c| c.is_ascii_alphanumeric() || "!#$%&'*+-.^_`|~".contains(c))
+	};
+
+	for method in cors_policy.allowed_methods.iter().flatten() {
+		if !is_token(method) {
+			return Err(format!("invalid cors method: {method:?}"));
+		}
+	}
+
+	for header in cors_policy.allowed_headers.iter().flatten() {
+		if !is_token(header) {
+			return Err(format!("invalid cors header: {header:?}"));
+		}
+	}
+
+	Ok(())
+}
+
+/// Checks that an actor rate limit allows at least one request. Returns the reason the rate limit
+/// is invalid.
+pub fn validate_actor_rate_limit(actor_rate_limit: &ActorRateLimit) -> Result<(), String> {
+	if actor_rate_limit.requests_per_second == 0 {
+		return Err("actor rate limit requests_per_second must be greater than 0".to_string());
+	}
+
+	if actor_rate_limit.burst == Some(0) {
+		return Err("actor rate limit burst must be greater than 0".to_string());
+	}
+
+	Ok(())
+}
@@ -1,5 +1,9 @@
 use futures_util::FutureExt;
 use gas::prelude::*;
+use rivet_types::{
+	actors::CrashPolicy,
+	namespaces::{ActorRateLimit, CorsPolicy},
+};
 use serde::{Deserialize, Serialize};
 use universaldb::utils::IsolationLevel::*;
 
@@ -10,6 +14,12 @@ pub struct Input {
 	pub namespace_id: Id,
 	pub name: String,
 	pub display_name: String,
+	#[serde(default)]
+	pub default_crash_policy: Option<CrashPolicy>,
+	#[serde(default)]
+	pub cors_policy: Option<CorsPolicy>,
+	#[serde(default)]
+	pub actor_rate_limit: Option<ActorRateLimit>,
 }
 
 #[workflow]
@@ -36,6 +46,9 @@ pub async fn namespace(ctx: &mut WorkflowCtx, input: &Input) -> Result<()> {
 			namespace_id: input.namespace_id,
 			name: input.name.clone(),
 			display_name: input.display_name.clone(),
+			default_crash_policy: input.default_crash_policy,
+			cors_policy: input.cors_policy.clone(),
+			actor_rate_limit: input.actor_rate_limit,
 			create_ts: ctx.create_ts(),
 		})
 		.await?;
@@ -139,6 +152,12 @@ struct InsertDbInput {
 	namespace_id: Id,
 	name: String,
 	display_name: String,
+	#[serde(default)]
+	default_crash_policy: Option<CrashPolicy>,
+	#[serde(default)]
+	cors_policy: Option<CorsPolicy>,
+	#[serde(default)]
+	actor_rate_limit: Option<ActorRateLimit>,
 	create_ts: i64,
 }
 
@@ -152,6 +171,8 @@ async fn insert_db(
 			let namespace_id = input.namespace_id;
 			let name = input.name.clone();
 			let display_name = input.display_name.clone();
+			let cors_policy = input.cors_policy.clone();
+			let actor_rate_limit = input.actor_rate_limit;
 
 			async move {
 				let tx = tx.with_subspace(keys::subspace());
@@ -166,6 +187,24 @@ async fn insert_db(
 				tx.write(&keys::DisplayNameKey::new(namespace_id), display_name)?;
 				tx.write(&keys::CreateTsKey::new(namespace_id), input.create_ts)?;
 
+				if let Some(default_crash_policy) = input.default_crash_policy {
+					tx.write(
+						&keys::DefaultCrashPolicyKey::new(namespace_id),
+						default_crash_policy,
+					)?;
+				}
+
+				if let Some(cors_policy) = cors_policy {
+					tx.write(&keys::CorsPolicyKey::new(namespace_id), cors_policy)?;
+				}
+
+				if let Some(actor_rate_limit) = actor_rate_limit {
+					tx.write(
+						&keys::ActorRateLimitKey::new(namespace_id),
+						actor_rate_limit,
+					)?;
+				}
+
 				// Insert idx
 				tx.write(&name_idx_key, namespace_id)?;
 
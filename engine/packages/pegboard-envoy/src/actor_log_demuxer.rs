@@ -0,0 +1,53 @@
+use std::time::Duration;
+
+use gas::prelude::*;
+use pegboard::ops::actor::log::{append, demuxer};
+use rivet_envoy_protocol as protocol;
+
+pub type ActorLogDemuxer = demuxer::ActorLogDemuxer<protocol::ActorLogLine>;
+
+/// Creates a demuxer that only stores logs for actors that are running on this envoy.
+pub fn new(ctx: StandaloneCtx, namespace_id: Id, envoy_key: String) -> ActorLogDemuxer {
+	let pegboard_config = ctx.config().pegboard();
+	let gc_interval = Duration::from_millis(pegboard_config.envoy_event_demuxer_gc_interval());
+	let max_last_seen =
+		Duration::from_millis(pegboard_config.envoy_event_demuxer_max_last_seen_threshold());
+
+	ActorLogDemuxer::new(
+		ctx,
+		gc_interval,
+		max_last_seen,
+		convert_line,
+		move |ctx: StandaloneCtx, actor_id| {
+			let envoy_key = envoy_key.clone();
+			async move {
+				let actor = ctx
+					.op(pegboard::ops::actor::get_for_gateway::Input { actor_id })
+					.await?;
+
+				Ok(actor.is_some_and(|actor| {
+					actor.namespace_id == namespace_id
+						&& actor.envoy_key.as_deref() == Some(envoy_key.as_str())
+				}))
+			}
+		},
+	)
+}
+
+pub fn convert_log(log: protocol::ToRivetActorLog) -> demuxer::ActorLog<protocol::ActorLogLine> {
+	demuxer::ActorLog {
+		generation: log.generation,
+		lines: log.lines,
+	}
+}
+
+fn convert_line(line: protocol::ActorLogLine) -> append::Line {
+	append::Line {
+		ts: line.ts,
+		stream: match line.stream {
+			protocol::ActorLogStream::Stdout => rivet_types::actors::ActorLogStream::Stdout,
+			protocol::ActorLogStream::Stderr => rivet_types::actors::ActorLogStream::Stderr,
+		},
+		message: line.message,
+	}
+}
@@ -16,6 +16,7 @@ use universalpubsub::PublishOpts;
 mod actor_event_demuxer;
 mod actor_kv_task;
 mod actor_lifecycle;
+mod actor_log_demuxer;
 mod actor_remote_sqlite_task;
 mod actor_sqlite_page_task;
 mod conn;
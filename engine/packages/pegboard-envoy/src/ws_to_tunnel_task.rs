@@ -38,7 +38,9 @@ use vbare::OwnedVersionedData;
 use crate::{
 	LifecycleResult,
 	actor_event_demuxer::ActorEventDemuxer,
-	actor_kv_task, actor_remote_sqlite_task, actor_sqlite_page_task,
+	actor_kv_task,
+	actor_log_demuxer::{self, ActorLogDemuxer},
+	actor_remote_sqlite_task, actor_sqlite_page_task,
 	conn::{Conn, RemoteSqliteExecutors},
 	control_task, errors, metrics, sqlite_runtime, tunnel_message_task,
 };
@@ -440,11 +442,22 @@ pub async fn task(
 	ws_to_tunnel_abort_rx: watch::Receiver<()>,
 ) -> Result<LifecycleResult> {
 	let mut event_demuxer = ActorEventDemuxer::new(ctx.clone(), conn.envoy_key.clone());
+	let mut log_demuxer =
+		actor_log_demuxer::new(ctx.clone(), conn.namespace_id, conn.envoy_key.clone());
 
-	let res = task_inner(ctx, conn, ws_rx, ws_to_tunnel_abort_rx, &mut event_demuxer).await;
+	let res = task_inner(
+		ctx,
+		conn,
+		ws_rx,
+		ws_to_tunnel_abort_rx,
+		&mut event_demuxer,
+		&mut log_demuxer,
+	)
+	.await;
 
 	// Must shutdown demuxer to allow for all in-flight events to finish
 	event_demuxer.shutdown().await;
+	log_demuxer.shutdown().await;
 
 	res
 }
@@ -456,6 +469,7 @@ pub async fn task_inner(
 	ws_rx: Arc<Mutex<WebSocketReceiver>>,
 	mut ws_to_tunnel_abort_rx: watch::Receiver<()>,
 	event_demuxer: &mut ActorEventDemuxer,
+	log_demuxer: &mut ActorLogDemuxer,
 ) -> Result<LifecycleResult> {
 	let mut ws_rx = ws_rx.lock().await;
 	let mut term_signal = rivet_runtime::TermSignal::get();
@@ -497,6 +511,7 @@ pub async fn task_inner(
 								&ctx,
 								conn.clone(),
 								event_demuxer,
+								log_demuxer,
 								&mut task_manager,
 								msg,
 							)
@@ -614,6 +629,7 @@ fn message_kind_label(msg: &protocol::ToRivet) -> &'static str {
 		protocol::ToRivet::ToRivetEvents(_) => "events",
 		protocol::ToRivet::ToRivetAckCommands(_) => "ack_commands",
 		protocol::ToRivet::ToRivetStopping => "stopping",
+		protocol::ToRivet::ToRivetActorLog(_) => "actor_log",
 	}
 }
 
@@ -635,12 +651,13 @@ async fn handle_message(
 	ctx: &StandaloneCtx,
 	conn: Arc<Conn>,
 	event_demuxer: &mut ActorEventDemuxer,
+	log_demuxer: &mut ActorLogDemuxer,
 	task_manager: &mut TaskManager,
 	msg: protocol::ToRivet,
 ) -> Result<Option<LifecycleResult>> {
 	tracing::debug!(?msg, "received message from envoy");
 
-	dispatch_message(ctx, conn, event_demuxer, task_manager, msg).await
+	dispatch_message(ctx, conn, event_demuxer, log_demuxer, task_manager, msg).await
 }
 
 #[tracing::instrument(skip_all)]
@@ -648,6 +665,7 @@ async fn dispatch_message(
 	ctx: &StandaloneCtx,
 	conn: Arc<Conn>,
 	event_demuxer: &mut ActorEventDemuxer,
+	log_demuxer: &mut ActorLogDemuxer,
 	task_manager: &mut TaskManager,
 	msg: protocol::ToRivet,
 ) -> Result<Option<LifecycleResult>> {
@@ -745,6 +763,13 @@ async fn dispatch_message(
 		protocol::ToRivet::ToRivetAckCommands(ack) => {
 			task_manager.enqueue_control(control_task::Message::AckCommands(ack))?;
 		}
+		// Logs are best effort, stored off of the message loop
+		protocol::ToRivet::ToRivetActorLog(log) => match Id::parse(&log.actor_id) {
+			Ok(actor_id) => log_demuxer.ingest(actor_id, actor_log_demuxer::convert_log(log)),
+			Err(err) => {
+				tracing::warn!(?err, actor_id=%log.actor_id, "invalid actor id in actor log");
+			}
+		},
 		protocol::ToRivet::ToRivetStopping => {
 			if !conn.reported_stopping.swap(true, Ordering::SeqCst) {
 				metrics::transition_envoy_connection_state(
@@ -894,7 +919,10 @@ pub(super) async fn handle_kv_request(
 			.await?;
 		}
 		protocol::KvRequestData::KvPutRequest(body) => {
-			let res = actor_kv::put(&*ctx.udb()?, &recipient, body.keys, body.values).await;
+			let res = match pegboard::utils::validate_batch_size(ctx.config(), body.keys.len()) {
+				Ok(()) => actor_kv::put(&*ctx.udb()?, &recipient, body.keys, body.values).await,
+				Err(err) => Err(err),
+			};
 			send_actor_kv_response(
 				conn,
 				req.request_id,
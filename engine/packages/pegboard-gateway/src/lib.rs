@@ -1,19 +1,22 @@
 use anyhow::Result;
 use async_trait::async_trait;
-use bytes::Bytes;
+use bytes::{Bytes, BytesMut};
 use futures_util::TryStreamExt;
 use gas::prelude::*;
-use http_body_util::{BodyExt, Full};
-use hyper::{Request, Response, StatusCode, body::Body};
+use http_body_util::{BodyExt, Full, Limited};
+use hyper::{
+	Request, Response, StatusCode,
+	body::{Body, Incoming as BodyIncoming, SizeHint},
+};
 use rivet_error::*;
 use rivet_guard_core::{
 	ResponseBody, WebSocketHandle,
 	custom_serve::{CustomServeTrait, HibernationResult},
 	errors::{
 		ActorStoppedWhileWaiting, ActorStoppedWhileWaitingForWebSocketOpen,
-		GatewayResponseStartTimeout, TunnelMessageTimeout, TunnelRequestAborted,
-		TunnelResponseClosed, WebSocketClosedBeforeOpen, WebSocketOpenDropped,
-		WebSocketOpenResponseClosed, WebSocketOpenTimeout,
+		GatewayResponseStartTimeout, InvalidRequestBody, TunnelMessageTimeout,
+		TunnelRequestAborted, TunnelResponseClosed, WebSocketClosedBeforeOpen,
+		WebSocketOpenDropped, WebSocketOpenResponseClosed, WebSocketOpenTimeout,
 	},
 	request_context::RequestContext,
 	utils::is_ws_hibernate,
@@ -22,10 +25,13 @@ use rivet_guard_core::{
 use rivet_runner_protocol::{self as protocol, PROTOCOL_MK1_VERSION};
 use std::{
 	collections::HashMap,
-	sync::{Arc, atomic::AtomicU64},
-	time::Duration,
+	sync::{
+		Arc,
+		atomic::{AtomicU64, Ordering},
+	},
+	time::{Duration, Instant},
 };
-use tokio::sync::{Mutex, watch};
+use tokio::sync::{Mutex, mpsc, watch};
 use tokio_tungstenite::tungstenite::{
 	Message,
 	protocol::frame::{CloseFrame, coding::CloseCode},
@@ -38,6 +44,7 @@ mod keepalive_task;
 mod metrics;
 mod metrics_task;
 mod ping_task;
+mod response_stream_task;
 pub mod shared_state;
 mod tunnel_to_ws_task;
 mod ws_to_tunnel_task;
@@ -51,6 +58,8 @@ mod ws_to_tunnel_task;
 pub struct WebsocketPendingLimitReached;
 
 const UPDATE_METRICS_INTERVAL: Duration = Duration::from_secs(15);
+/// Number of response body chunks buffered between the tunnel and the client.
+const RESPONSE_STREAM_BUFFER: usize = 16;
 const PHASE_WAITING_FOR_RESPONSE_START: &str = "waiting_for_response_start";
 const PHASE_WAITING_FOR_WEBSOCKET_OPEN: &str = "waiting_for_websocket_open";
 
@@ -64,8 +73,10 @@ enum LifecycleResult {
 pub struct PegboardGateway {
 	ctx: StandaloneCtx,
 	shared_state: SharedState,
+	namespace_id: Id,
 	runner_id: Id,
 	actor_id: Id,
+	actor_name: String,
 	path: String,
 }
 
@@ -74,25 +85,48 @@ impl PegboardGateway {
 	pub fn new(
 		ctx: StandaloneCtx,
 		shared_state: SharedState,
+		namespace_id: Id,
 		runner_id: Id,
 		actor_id: Id,
+		actor_name: String,
 		path: String,
 	) -> Self {
 		Self {
 			ctx,
 			shared_state,
+			namespace_id,
 			runner_id,
 			actor_id,
+			actor_name,
 			path,
 		}
 	}
 }
 
+/// Request body as handed over by guard.
+enum RequestBody {
+	Full(Full<Bytes>),
+	/// Read from the client while it is sent to the runner in chunks of the given size.
+	Stream {
+		body: Limited<BodyIncoming>,
+		chunk_size: usize,
+	},
+}
+
+impl RequestBody {
+	fn size_hint(&self) -> SizeHint {
+		match self {
+			RequestBody::Full(body) => body.size_hint(),
+			RequestBody::Stream { body, .. } => body.size_hint(),
+		}
+	}
+}
+
 impl PegboardGateway {
 	async fn handle_request_inner(
 		&self,
 		ctx: &StandaloneCtx,
-		req: Request<Full<Bytes>>,
+		req: Request<RequestBody>,
 		req_ctx: &mut RequestContext,
 	) -> Result<Response<ResponseBody>> {
 		// Use the actor ID from the gateway instance
@@ -112,12 +146,24 @@ impl PegboardGateway {
 			.collect::<HashMap<_, _>>();
 
 		// NOTE: Size constraints have already been applied by guard
-		let body_bytes = req
-			.into_body()
-			.collect()
-			.await
-			.context("failed to read body")?
-			.to_bytes();
+		let (body_bytes, body_stream) = match req.into_body() {
+			RequestBody::Full(body) => (
+				body.collect()
+					.await
+					.context("failed to read body")?
+					.to_bytes(),
+				None,
+			),
+			RequestBody::Stream { body, chunk_size } => (Bytes::new(), Some((body, chunk_size))),
+		};
+
+		let namespace_id = self.namespace_id.to_string();
+		let metric_labels = [namespace_id.as_str(), self.actor_name.as_str()];
+		if body_stream.is_none() {
+			metrics::REQUEST_BODY_SIZE
+				.with_label_values(&metric_labels)
+				.observe(body_bytes.len() as f64);
+		}
 
 		let (mut stopped_sub, runner_protocol_version) = tokio::try_join!(
 			ctx.subscribe::<pegboard::workflows::actor::Stopped>(("actor_id", self.actor_id)),
@@ -155,16 +201,37 @@ impl PegboardGateway {
 				} else {
 					Some(body_bytes.to_vec())
 				},
-				stream: false,
+				stream: body_stream.is_some(),
 			},
 		);
+		let start = Instant::now();
 		self.shared_state.send_message(request_id, message).await?;
 
-		// Wait for response
+		// Streamed bodies are sent after the request start
+		let send_body = async {
+			if let Some((body, chunk_size)) = body_stream {
+				let body_size = self.send_request_body(request_id, body, chunk_size).await?;
+
+				metrics::REQUEST_BODY_SIZE
+					.with_label_values(&metric_labels)
+					.observe(body_size as f64);
+			}
+
+			anyhow::Ok(())
+		};
+		tokio::pin!(send_body);
+		let mut body_sent = false;
+
+		// Wait for response. The actor may respond before the whole body is sent, in which case the
+		// rest of the body is dropped.
 		tracing::debug!("gateway waiting for response from tunnel");
 		let fut = async {
 			loop {
 				tokio::select! {
+					res = &mut send_body, if !body_sent => {
+						res?;
+						body_sent = true;
+					}
 					res = msg_rx.recv() => {
 						if let Some(msg) = res {
 							match msg {
@@ -233,6 +300,12 @@ impl PegboardGateway {
 			})??;
 		tracing::debug!("response handler task ended");
 
+		metrics::REQUEST_FIRST_BYTE_DURATION
+			.with_label_values(&metric_labels)
+			.observe(start.elapsed().as_secs_f64());
+		let request_duration = metrics::REQUEST_DURATION.with_label_values(&metric_labels);
+		let response_body_size = metrics::RESPONSE_BODY_SIZE.with_label_values(&metric_labels);
+
 		// Build HTTP response
 		let mut response_builder =
 			Response::builder().status(StatusCode::from_u16(response_start.status)?);
@@ -243,12 +316,161 @@ impl PegboardGateway {
 		}
 
 		// Add body
-		let body = response_start.body.unwrap_or_default();
-		let response = response_builder.body(ResponseBody::Full(Full::new(Bytes::from(body))))?;
+		let body = if response_start.stream {
+			let (body_tx, body_rx) = mpsc::channel(RESPONSE_STREAM_BUFFER);
+			let egress_bytes = Arc::new(AtomicU64::new(0));
+
+			if let Some(body) = response_start.body.filter(|body| !body.is_empty()) {
+				egress_bytes.fetch_add(body.len() as u64, Ordering::AcqRel);
+				// Cannot fail, the channel is empty and the receiver is held below
+				let _ = body_tx.try_send(Ok(Bytes::from(body)));
+			}
+
+			let ctx = ctx.clone();
+			let shared_state = self.shared_state.clone();
+			let runner_id = self.runner_id;
+			let actor_id = self.actor_id;
+			let idle_timeout = Duration::from_millis(
+				self.ctx
+					.config()
+					.pegboard()
+					.gateway_response_body_idle_timeout_ms(),
+			);
+			tokio::spawn(async move {
+				let stream_start = Instant::now();
+
+				if let Err(err) = response_stream_task::task(
+					actor_id,
+					stopped_sub,
+					msg_rx,
+					drop_rx,
+					&body_tx,
+					egress_bytes.clone(),
+					idle_timeout,
+				)
+				.await
+				{
+					tracing::debug!(?err, "response body stream ended early");
+
+					// Fail the body so the client does not treat the truncated response as
+					// complete, then tell the actor to stop sending
+					let _ = body_tx.send(Err(err.into())).await;
+					if let Err(err) = shared_state
+						.send_message(
+							request_id,
+							protocol::mk2::ToClientTunnelMessageKind::ToClientRequestAbort,
+						)
+						.await
+					{
+						tracing::debug!(?err, "failed to abort streamed request");
+					}
+				}
+
+				let egress_bytes = egress_bytes.load(Ordering::Acquire);
+				metrics::RESPONSE_STREAM_DURATION.observe(stream_start.elapsed().as_secs_f64());
+				metrics::RESPONSE_STREAM_BYTES_TOTAL.inc_by(egress_bytes);
+				request_duration.observe(start.elapsed().as_secs_f64());
+				response_body_size.observe(egress_bytes as f64);
+
+				if let Err(err) = record_req_metrics(
+					&ctx,
+					runner_id,
+					actor_id,
+					Metric::HttpEgress(egress_bytes as usize),
+				)
+				.await
+				{
+					tracing::error!(?err, "http streamed response egress metrics failed");
+				}
+			});
+
+			ResponseBody::Channel(body_rx)
+		} else {
+			let body = response_start.body.unwrap_or_default();
+			request_duration.observe(start.elapsed().as_secs_f64());
+			response_body_size.observe(body.len() as f64);
+			ResponseBody::Full(Full::new(Bytes::from(body)))
+		};
+		let response = response_builder.body(body)?;
 
 		Ok(response)
 	}
 
+	/// Sends the request body to the runner in chunks as it is read from the client. The body is only
+	/// read further once the previous chunk has been published, so a slow tunnel slows down the upload
+	/// instead of buffering it in memory. Returns the body size.
+	async fn send_request_body(
+		&self,
+		request_id: protocol::mk2::RequestId,
+		mut body: Limited<BodyIncoming>,
+		chunk_size: usize,
+	) -> Result<usize> {
+		let mut buf = BytesMut::new();
+		let mut body_size = 0;
+
+		loop {
+			let frame = match body.frame().await {
+				Some(Ok(frame)) => frame,
+				Some(Err(err)) => {
+					// The actor already received the request start, tell it the body won't finish
+					if let Err(err) = self
+						.shared_state
+						.send_message(
+							request_id,
+							protocol::mk2::ToClientTunnelMessageKind::ToClientRequestAbort,
+						)
+						.await
+					{
+						tracing::debug!(?err, "failed to abort streamed request");
+					}
+
+					return Err(InvalidRequestBody {
+						reason: err.to_string(),
+					}
+					.build());
+				}
+				None => break,
+			};
+
+			// Trailers are not forwarded
+			let Ok(data) = frame.into_data() else {
+				continue;
+			};
+			body_size += data.len();
+			buf.extend_from_slice(&data);
+
+			// Hold back the last chunk so it can be sent with `finish`
+			while buf.len() > chunk_size {
+				let chunk = buf.split_to(chunk_size);
+				self.shared_state
+					.send_message(
+						request_id,
+						protocol::mk2::ToClientTunnelMessageKind::ToClientRequestChunk(
+							protocol::mk2::ToClientRequestChunk {
+								body: chunk.to_vec(),
+								finish: false,
+							},
+						),
+					)
+					.await?;
+			}
+		}
+
+		self.shared_state
+			.send_message(
+				request_id,
+				protocol::mk2::ToClientTunnelMessageKind::ToClientRequestChunk(
+					protocol::mk2::ToClientRequestChunk {
+						body: buf.to_vec(),
+						finish: true,
+					},
+				),
+			)
+			.await?;
+
+		Ok(body_size)
+	}
+
 	async fn handle_websocket_inner(
 		&self,
 		ctx: &StandaloneCtx,
@@ -418,6 +640,7 @@ impl PegboardGateway {
 		let (keepalive_abort_tx, keepalive_abort_rx) = watch::channel(());
 		let (metrics_abort_tx, metrics_abort_rx) = watch::channel(());
 
+		let namespace_id = self.namespace_id.to_string();
 		let tunnel_to_ws = tokio::spawn(tunnel_to_ws_task::task(
 			self.shared_state.clone(),
 			client_ws,
@@ -426,7 +649,13 @@ impl PegboardGateway {
 			msg_rx,
 			drop_rx,
 			can_hibernate,
+			self.ctx.config().guard().websocket_max_actor_message_size(),
 			egress_bytes.clone(),
+			metrics::WEBSOCKET_MESSAGES_TOTAL.with_label_values(&[
+				namespace_id.as_str(),
+				self.actor_name.as_str(),
+				"egress",
+			]),
 			tunnel_to_ws_abort_rx,
 		));
 		let ws_to_tunnel = tokio::spawn(ws_to_tunnel_task::task(
@@ -434,6 +663,11 @@ impl PegboardGateway {
 			request_id,
 			ws_rx,
 			ingress_bytes.clone(),
+			metrics::WEBSOCKET_MESSAGES_TOTAL.with_label_values(&[
+				namespace_id.as_str(),
+				self.actor_name.as_str(),
+				"ingress",
+			]),
 			ws_to_tunnel_abort_rx,
 		));
 		let update_ping_interval = Duration::from_millis(
@@ -461,6 +695,7 @@ impl PegboardGateway {
 				self.shared_state.clone(),
 				ctx.clone(),
 				self.actor_id,
+				self.actor_name.clone(),
 				self.shared_state.gateway_id(),
 				request_id,
 				keepalive_abort_rx,
@@ -614,33 +849,27 @@ impl PegboardGateway {
 
 		// Send WebSocket close message to client
 		match lifecycle_res {
-			Ok(LifecycleResult::ServerClose(close)) => {
-				if let Some(code) = close.code {
-					Ok(Some(CloseFrame {
-						code: code.into(),
-						reason: close.reason.unwrap_or_default().into(),
-					}))
-				} else {
-					Ok(None)
-				}
-			}
+			Ok(LifecycleResult::ServerClose(close)) => Ok(server_close_frame(close)),
 			Ok(_) => Ok(None),
 			Err(err) => Err(err),
 		}
 	}
 }
 
-#[async_trait]
-impl CustomServeTrait for PegboardGateway {
-	#[tracing::instrument(skip_all, fields(actor_id=?self.actor_id, runner_id=?self.runner_id))]
-	async fn handle_request(
+impl PegboardGateway {
+	async fn handle_request_with_body(
 		&self,
-		req: Request<Full<Bytes>>,
+		req: Request<RequestBody>,
 		req_ctx: &mut RequestContext,
 	) -> Result<Response<ResponseBody>> {
 		let ctx = self.ctx.with_ray(req_ctx.ray_id(), req_ctx.req_id())?;
+		let request_id = req_ctx.in_flight_request_id()?;
 		let req_body_size_hint = req.body().size_hint();
 
+		self.shared_state
+			.acquire_runner_request(self.runner_id, request_id)
+			.await?;
+
 		let (res, metrics_res) = tokio::join!(
 			self.handle_request_inner(&ctx, req, req_ctx),
 			record_req_metrics(
@@ -655,6 +884,10 @@ impl CustomServeTrait for PegboardGateway {
 			),
 		);
 
+		self.shared_state
+			.release_runner_request(self.runner_id, request_id)
+			.await;
+
 		let response_size = match &res {
 			Ok(res) => res.size_hint().upper().unwrap_or(res.size_hint().lower()),
 			Err(_) => 0,
@@ -685,6 +918,53 @@ impl CustomServeTrait for PegboardGateway {
 
 		res
 	}
+}
+
+#[async_trait]
+impl CustomServeTrait for PegboardGateway {
+	#[tracing::instrument(skip_all, fields(actor_id=?self.actor_id, runner_id=?self.runner_id))]
+	async fn handle_request(
+		&self,
+		req: Request<Full<Bytes>>,
+		req_ctx: &mut RequestContext,
+	) -> Result<Response<ResponseBody>> {
+		self.handle_request_with_body(req.map(RequestBody::Full), req_ctx)
+			.await
+	}
+
+	fn stream_request_body(&self, req: &Request<BodyIncoming>) -> bool {
+		// Bodies of unknown size are streamed since they may be arbitrarily large
+		self.ctx
+			.config()
+			.pegboard()
+			.gateway_request_body_chunk_size()
+			.is_some_and(|chunk_size| {
+				req.body()
+					.size_hint()
+					.exact()
+					.is_none_or(|size| size > chunk_size as u64)
+			})
+	}
+
+	#[tracing::instrument(skip_all, fields(actor_id=?self.actor_id, runner_id=?self.runner_id))]
+	async fn handle_request_streaming(
+		&self,
+		req: Request<Limited<BodyIncoming>>,
+		req_ctx: &mut RequestContext,
+	) -> Result<Response<ResponseBody>> {
+		let chunk_size = self
+			.ctx
+			.config()
+			.pegboard()
+			.gateway_request_body_chunk_size()
+			.context("request body streaming is not enabled")?;
+
+		self.handle_request_with_body(
+			req.map(|body| RequestBody::Stream { body, chunk_size }),
+			req_ctx,
+		)
+		.await
+	}
 
 	#[tracing::instrument(skip_all, fields(actor_id=?self.actor_id, runner_id=?self.runner_id))]
 	async fn handle_websocket(
@@ -694,11 +974,46 @@ impl CustomServeTrait for PegboardGateway {
 		after_hibernation: bool,
 	) -> Result<Option<CloseFrame>> {
 		let ctx = self.ctx.with_ray(req_ctx.ray_id(), req_ctx.req_id())?;
+		let request_id = req_ctx.in_flight_request_id()?;
+
+		if let Err(err) = self
+			.shared_state
+			.acquire_actor_websocket(self.actor_id, request_id)
+			.await
+		{
+			metrics::WEBSOCKET_ACTOR_LIMIT_REJECTED_TOTAL.inc();
+			return Err(err);
+		}
+
+		if let Err(err) = self
+			.shared_state
+			.acquire_runner_request(self.runner_id, request_id)
+			.await
+		{
+			self.shared_state
+				.release_actor_websocket(self.actor_id, request_id)
+				.await;
+
+			return Err(err);
+		}
+
 		let (res, metrics_res) = tokio::join!(
 			self.handle_websocket_inner(&ctx, req_ctx, client_ws, after_hibernation),
 			record_req_metrics(&ctx, self.runner_id, self.actor_id, Metric::WebsocketOpen),
 		);
 
+		// The actor may wake on a different runner after hibernation
+		self.shared_state
+			.release_runner_request(self.runner_id, request_id)
+			.await;
+
+		// Hibernating WebSockets are still open to the client
+		if !matches!(&res, Err(err) if is_ws_hibernate(err)) {
+			self.shared_state
+				.release_actor_websocket(self.actor_id, request_id)
+				.await;
+		}
+
 		if let Err(err) = metrics_res {
 			tracing::error!(?err, "ws open metrics failed");
 		} else {
@@ -720,6 +1035,13 @@ impl CustomServeTrait for PegboardGateway {
 		res
 	}
 
+	fn request_coalescing(&self) -> bool {
+		self.ctx
+			.config()
+			.guard()
+			.request_coalescing_for_actor(&self.actor_name)
+	}
+
 	#[tracing::instrument(skip_all, fields(actor_id=?self.actor_id))]
 	async fn handle_websocket_hibernation(
 		&self,
@@ -740,19 +1062,25 @@ impl CustomServeTrait for PegboardGateway {
 			return Ok(HibernationResult::Continue);
 		}
 
+		let msg_rx = self
+			.shared_state
+			.subscribe_hibernating_request(request_id)
+			.await?;
+
 		// Start keepalive task
 		let (keepalive_abort_tx, keepalive_abort_rx) = watch::channel(());
 		let keepalive_handle = tokio::spawn(keepalive_task::task(
 			self.shared_state.clone(),
 			ctx.clone(),
 			self.actor_id,
+			self.actor_name.clone(),
 			self.shared_state.gateway_id(),
 			request_id,
 			keepalive_abort_rx,
 		));
 
 		let (res, metrics_res) = tokio::join!(
-			self.handle_websocket_hibernation_inner(client_ws),
+			self.handle_websocket_hibernation_inner(client_ws, msg_rx),
 			record_req_metrics(
 				&ctx,
 				self.runner_id,
@@ -768,7 +1096,7 @@ impl CustomServeTrait for PegboardGateway {
 			async {
 				match &res {
 					Ok(HibernationResult::Continue) => {}
-					Ok(HibernationResult::Close) | Err(_) => {
+					Ok(HibernationResult::Close | HibernationResult::ServerClose(_)) | Err(_) => {
 						// No longer an active hibernating request, delete entry
 						ctx.op(pegboard::ops::actor::hibernating_request::delete::Input {
 							actor_id: self.actor_id,
@@ -803,6 +1131,25 @@ impl CustomServeTrait for PegboardGateway {
 			},
 		);
 
+		// The runner closed the WebSocket, stop tracking it as hibernating so the in flight request is
+		// garbage collected
+		if let Ok(HibernationResult::ServerClose(_)) = &res {
+			if let Err(err) = self
+				.shared_state
+				.toggle_hibernation(request_id, false)
+				.await
+			{
+				tracing::debug!(?err, "failed to disable hibernation after server close");
+			}
+		}
+
+		// Guard drops the client WebSocket if hibernation fails or the runner closed it
+		if matches!(res, Err(_) | Ok(HibernationResult::ServerClose(_))) || delete_res.is_err() {
+			self.shared_state
+				.release_actor_websocket(self.actor_id, request_id)
+				.await;
+		}
+
 		delete_res?;
 
 		res
@@ -813,6 +1160,7 @@ impl PegboardGateway {
 	async fn handle_websocket_hibernation_inner(
 		&self,
 		client_ws: WebSocketHandle,
+		mut msg_rx: mpsc::UnboundedReceiver<protocol::mk2::ToServerTunnelMessageKind>,
 	) -> Result<HibernationResult> {
 		let mut ready_sub = self
 			.ctx
@@ -840,6 +1188,11 @@ impl PegboardGateway {
 
 				HibernationResult::Continue
 			}
+			close = wait_for_server_close(&mut msg_rx) => {
+				tracing::debug!(?close, "actor closed websocket during hibernation");
+
+				HibernationResult::ServerClose(server_close_frame(close))
+			}
 			hibernation_res = hibernate_ws(client_ws.recv()) => {
 				let hibernation_res = hibernation_res?;
 
@@ -850,6 +1203,7 @@ impl PegboardGateway {
 					HibernationResult::Close => {
 						tracing::debug!("websocket stream closed during hibernation");
 					}
+					HibernationResult::ServerClose(_) => {}
 				}
 
 				hibernation_res
@@ -884,6 +1238,32 @@ async fn hibernate_ws(ws_rx: Arc<Mutex<WebSocketReceiver>>) -> Result<Hibernatio
 	}
 }
 
+/// Waits for the runner to close the websocket during hibernation. Never resolves if the runner does not
+/// close it.
+async fn wait_for_server_close(
+	msg_rx: &mut mpsc::UnboundedReceiver<protocol::mk2::ToServerTunnelMessageKind>,
+) -> protocol::mk2::ToServerWebSocketClose {
+	while let Some(msg) = msg_rx.recv().await {
+		match msg {
+			protocol::mk2::ToServerTunnelMessageKind::ToServerWebSocketClose(close)
+				if !close.hibernate =>
+			{
+				return close;
+			}
+			msg => tracing::debug!(?msg, "ignoring tunnel message during hibernation"),
+		}
+	}
+
+	std::future::pending().await
+}
+
+fn server_close_frame(close: protocol::mk2::ToServerWebSocketClose) -> Option<CloseFrame> {
+	close.code.map(|code| CloseFrame {
+		code: code.into(),
+		reason: close.reason.unwrap_or_default().into(),
+	})
+}
+
 async fn get_runner_protocol_version(ctx: &StandaloneCtx, runner_id: Id) -> Result<u16> {
 	ctx.udb()?
 		.txn("gateway_get_runner_protocol_version", |tx| async move {
@@ -7,4 +7,77 @@ lazy_static::lazy_static! {
 		BUCKETS.to_vec(),
 		*REGISTRY
 	).unwrap();
+	pub static ref WEBSOCKET_ACTOR_LIMIT_REJECTED_TOTAL: IntCounter = register_int_counter_with_registry!(
+		"gateway_websocket_actor_limit_rejected_total",
+		"Count of WebSockets closed because the actor reached its concurrent WebSocket limit.",
+		*REGISTRY
+	).unwrap();
+	pub static ref RUNNER_IN_FLIGHT_REQUESTS: IntGauge = register_int_gauge_with_registry!(
+		"gateway_runner_in_flight_requests",
+		"Count of in-flight HTTP requests and open WebSockets to runners through this gateway.",
+		*REGISTRY
+	).unwrap();
+	pub static ref RUNNER_PENDING_WEBSOCKET_MESSAGES: IntGauge = register_int_gauge_with_registry!(
+		"gateway_runner_pending_websocket_messages",
+		"Count of unacknowledged hibernatable WebSocket messages buffered for runners, as of the last GC tick.",
+		*REGISTRY
+	).unwrap();
+	pub static ref RUNNER_LIMIT_REJECTED_TOTAL: IntCounterVec = register_int_counter_vec_with_registry!(
+		"gateway_runner_limit_rejected_total",
+		"Count of requests rejected because the runner reached a gateway limit.",
+		&["limit"],
+		*REGISTRY
+	).unwrap();
+	pub static ref RESPONSE_STREAM_DURATION: Histogram = register_histogram_with_registry!(
+		"gateway_response_stream_duration",
+		"Duration of streamed HTTP response bodies from the response start until the stream ends.",
+		BUCKETS.to_vec(),
+		*REGISTRY
+	).unwrap();
+	pub static ref RESPONSE_STREAM_BYTES_TOTAL: IntCounter = register_int_counter_with_registry!(
+		"gateway_response_stream_bytes_total",
+		"Total bytes of streamed HTTP response bodies forwarded to clients.",
+		*REGISTRY
+	).unwrap();
+
+	pub static ref REQUEST_FIRST_BYTE_DURATION: HistogramVec = register_histogram_vec_with_registry!(
+		"gateway_request_first_byte_duration",
+		"Time from sending an HTTP request into the tunnel until the actor's response start arrives.",
+		&["namespace_id", "actor_name"],
+		BUCKETS.to_vec(),
+		*REGISTRY
+	).unwrap();
+	pub static ref REQUEST_DURATION: HistogramVec = register_histogram_vec_with_registry!(
+		"gateway_request_duration",
+		"Duration of HTTP requests through the tunnel, until the last byte of the response body.",
+		&["namespace_id", "actor_name"],
+		BUCKETS.to_vec(),
+		*REGISTRY
+	).unwrap();
+	pub static ref REQUEST_BODY_SIZE: HistogramVec = register_histogram_vec_with_registry!(
+		"gateway_request_body_size",
+		"Size in bytes of HTTP request bodies sent to actors.",
+		&["namespace_id", "actor_name"],
+		BODY_SIZE_BUCKETS.to_vec(),
+		*REGISTRY
+	).unwrap();
+	pub static ref RESPONSE_BODY_SIZE: HistogramVec = register_histogram_vec_with_registry!(
+		"gateway_response_body_size",
+		"Size in bytes of HTTP response bodies sent by actors.",
+		&["namespace_id", "actor_name"],
+		BODY_SIZE_BUCKETS.to_vec(),
+		*REGISTRY
+	).unwrap();
+	pub static ref WEBSOCKET_MESSAGES_TOTAL: IntCounterVec = register_int_counter_vec_with_registry!(
+		"gateway_websocket_messages_total",
+		"Count of WebSocket messages forwarded between clients and actors.",
+		&["namespace_id", "actor_name", "direction"],
+		*REGISTRY
+	).unwrap();
 }
+
+/// 0 B to 64 MiB in powers of 4.
+const BODY_SIZE_BUCKETS: &[f64] = &[
+	0.0, 64.0, 256.0, 1024.0, 4096.0, 16384.0, 65536.0, 262144.0, 1048576.0, 4194304.0, 16777216.0,
+	67108864.0,
+];
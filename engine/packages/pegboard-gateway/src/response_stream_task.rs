@@ -0,0 +1,107 @@
+use std::{
+	sync::{
+		Arc,
+		atomic::{AtomicU64, Ordering},
+	},
+	time::Duration,
+};
+
+use anyhow::Result;
+use bytes::Bytes;
+use gas::prelude::*;
+use rivet_guard_core::{
+	BodyError,
+	errors::{
+		ActorStoppedWhileWaiting, GatewayResponseBodyIdleTimeout, TunnelMessageTimeout,
+		TunnelRequestAborted, TunnelResponseClosed,
+	},
+};
+use rivet_runner_protocol as protocol;
+use tokio::sync::{mpsc, watch};
+
+use crate::shared_state::MsgGcReason;
+
+const PHASE_STREAMING_RESPONSE_BODY: &str = "streaming_response_body";
+
+/// Forwards response body chunks from the runner to the client until the runner sends the final
+/// chunk.
+pub async fn task(
+	actor_id: Id,
+	mut stopped_sub: message::SubscriptionHandle<pegboard::workflows::actor::Stopped>,
+	mut msg_rx: mpsc::UnboundedReceiver<protocol::mk2::ToServerTunnelMessageKind>,
+	mut drop_rx: watch::Receiver<Option<MsgGcReason>>,
+	body_tx: &mpsc::Sender<Result<Bytes, BodyError>>,
+	egress_bytes: Arc<AtomicU64>,
+	idle_timeout: Duration,
+) -> Result<()> {
+	loop {
+		tokio::select! {
+			res = tokio::time::timeout(idle_timeout, msg_rx.recv()) => {
+				let Ok(res) = res else {
+					tracing::warn!("timed out waiting for response body chunk");
+					return Err(GatewayResponseBodyIdleTimeout {
+						timeout_ms: idle_timeout.as_millis() as u64,
+					}
+					.build());
+				};
+
+				match res {
+					Some(protocol::mk2::ToServerTunnelMessageKind::ToServerResponseChunk(chunk)) => {
+						tracing::trace!(
+							body_len=chunk.body.len(),
+							finish=chunk.finish,
+							"forwarding response body chunk to client"
+						);
+
+						if !chunk.body.is_empty() {
+							egress_bytes.fetch_add(chunk.body.len() as u64, Ordering::AcqRel);
+							if body_tx.send(Ok(chunk.body.into())).await.is_err() {
+								bail!("client disconnected during response body stream");
+							}
+						}
+
+						if chunk.finish {
+							return Ok(());
+						}
+					}
+					Some(protocol::mk2::ToServerTunnelMessageKind::ToServerResponseAbort) => {
+						tracing::warn!("response body stream aborted");
+						return Err(TunnelRequestAborted {
+							phase: PHASE_STREAMING_RESPONSE_BODY.to_owned(),
+						}
+						.build());
+					}
+					Some(_) => {
+						tracing::warn!("received non-response message during response body stream");
+					}
+					None => {
+						tracing::warn!("tunnel closed during response body stream");
+						return Err(TunnelResponseClosed {
+							phase: PHASE_STREAMING_RESPONSE_BODY.to_owned(),
+						}
+						.build());
+					}
+				}
+			}
+			_ = stopped_sub.next() => {
+				tracing::debug!("actor stopped during response body stream");
+				return Err(ActorStoppedWhileWaiting {
+					actor_id: actor_id.to_string(),
+					phase: PHASE_STREAMING_RESPONSE_BODY.to_owned(),
+				}
+				.build());
+			}
+			_ = drop_rx.changed() => {
+				tracing::warn!(reason=?drop_rx.borrow().as_ref(), "tunnel message timeout");
+				return Err(TunnelMessageTimeout {
+					phase: PHASE_STREAMING_RESPONSE_BODY.to_owned(),
+					reason: format!("{:?}", drop_rx.borrow().as_ref()),
+				}
+				.build());
+			}
+			_ = body_tx.closed() => {
+				bail!("client disconnected during response body stream");
+			}
+		}
+	}
+}
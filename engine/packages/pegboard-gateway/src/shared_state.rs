@@ -1,12 +1,16 @@
 use anyhow::Result;
 use gas::prelude::*;
-use pegboard::pubsub_subjects::GatewayReceiverSubject;
-use rivet_guard_core::errors::WebSocketTunnelPingTimeout;
+use pegboard::pubsub_subjects::{GatewayReceiverSubject, RunnerReceiverSubject};
+use rivet_guard_core::errors::{
+	RunnerInFlightLimitReached, RunnerPendingMessagesLimitReached, WebSocketActorLimitReached,
+	WebSocketTunnelPingTimeout,
+};
 use rivet_runner_protocol::{
 	self as protocol, PROTOCOL_MK1_VERSION, PROTOCOL_MK2_VERSION, versioned,
 };
 use scc::{HashMap, hash_map::Entry};
 use std::{
+	collections::HashSet,
 	ops::Deref,
 	sync::Arc,
 	time::{Duration, Instant},
@@ -30,6 +34,7 @@ pub struct InFlightRequestHandle {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum InFlightRequestState {
 	AwaitingHttpResponseStart,
+	StreamingHttpResponse,
 	AwaitingWebSocketOpen,
 	ActiveWebSocket,
 	Closed,
@@ -42,8 +47,31 @@ impl InFlightRequestState {
 		match (self, message_kind) {
 			(
 				state @ InFlightRequestState::AwaitingHttpResponseStart,
-				ToServerTunnelMessageKind::ToServerResponseStart(_)
-				| ToServerTunnelMessageKind::ToServerResponseAbort,
+				ToServerTunnelMessageKind::ToServerResponseStart(start),
+			) => {
+				*state = if start.stream {
+					InFlightRequestState::StreamingHttpResponse
+				} else {
+					InFlightRequestState::Closed
+				};
+				true
+			}
+			(
+				state @ InFlightRequestState::StreamingHttpResponse,
+				ToServerTunnelMessageKind::ToServerResponseChunk(chunk),
+			) => {
+				if chunk.finish {
+					*state = InFlightRequestState::Closed;
+				}
+				true
+			}
+			(
+				state @ InFlightRequestState::AwaitingHttpResponseStart,
+				ToServerTunnelMessageKind::ToServerResponseAbort,
+			)
+			| (
+				state @ InFlightRequestState::StreamingHttpResponse,
+				ToServerTunnelMessageKind::ToServerResponseAbort,
 			) => {
 				*state = InFlightRequestState::Closed;
 				true
@@ -101,6 +129,36 @@ struct HibernationState {
 	last_ping: Instant,
 }
 
+impl HibernationState {
+	fn new() -> Self {
+		HibernationState {
+			total_pending_ws_msgs_size: 0,
+			pending_ws_msgs: Vec::new(),
+			last_ping: Instant::now(),
+		}
+	}
+
+	/// Client messages sent to the actor that it has not acked yet. The hibernating WebSocket is
+	/// rewoken immediately while there are any.
+	fn has_pending_ws_msgs(&self) -> bool {
+		!self.pending_ws_msgs.is_empty()
+	}
+
+	fn ack_pending_ws_msgs(&mut self, ack_index: protocol::mk2::MessageIndex) {
+		// Retain messages with index > ack_index (messages that haven't been acknowledged yet)
+		let len_before = self.pending_ws_msgs.len();
+		self.pending_ws_msgs
+			.retain(|msg| wrapping_gt(msg.message_index, ack_index));
+
+		let len_after = self.pending_ws_msgs.len();
+		tracing::debug!(
+			removed_count = len_before - len_after,
+			remaining_count = len_after,
+			"acked pending websocket messages"
+		);
+	}
+}
+
 pub struct PendingWebsocketMessage {
 	payload: Vec<u8>,
 	send_instant: Instant,
@@ -128,12 +186,22 @@ pub struct SharedStateInner {
 	gateway_id: protocol::mk2::GatewayId,
 	receiver_subject: GatewayReceiverSubject,
 	in_flight_requests: HashMap<protocol::mk2::RequestId, InFlightRequest>,
+	/// Open WebSockets to each actor, including hibernating ones.
+	actor_websockets: HashMap<Id, HashSet<protocol::mk2::RequestId>>,
+	/// In-flight HTTP requests and open WebSockets to each runner.
+	runner_requests: HashMap<Id, HashSet<protocol::mk2::RequestId>>,
+	/// Unacknowledged hibernatable WebSocket messages per runner receiver subject. Recounted every GC
+	/// tick.
+	runner_pending_ws_msgs: HashMap<String, usize>,
 	hibernation_timeout: i64,
 	// Config values
 	gc_interval: Duration,
 	tunnel_ping_timeout: i64,
 	hws_message_ack_timeout: Duration,
 	hws_max_pending_size: u64,
+	actor_max_websockets: Option<usize>,
+	runner_max_in_flight_requests: Option<usize>,
+	runner_max_pending_ws_msgs: Option<usize>,
 }
 
 #[derive(Clone)]
@@ -151,13 +219,21 @@ impl SharedState {
 			gateway_id,
 			receiver_subject,
 			in_flight_requests: HashMap::new(),
-			hibernation_timeout: pegboard_config.hibernating_request_eligible_threshold(),
+			actor_websockets: HashMap::new(),
+			runner_requests: HashMap::new(),
+			runner_pending_ws_msgs: HashMap::new(),
+			// Keepalives of actors with a longer threshold override are less frequent
+			hibernation_timeout: pegboard_config.max_hibernating_request_eligible_threshold(),
 			gc_interval: Duration::from_millis(pegboard_config.gateway_gc_interval_ms()),
 			tunnel_ping_timeout: pegboard_config.gateway_tunnel_ping_timeout_ms(),
 			hws_message_ack_timeout: Duration::from_millis(
 				pegboard_config.gateway_hws_message_ack_timeout_ms(),
 			),
 			hws_max_pending_size: pegboard_config.gateway_hws_max_pending_size(),
+			actor_max_websockets: pegboard_config.gateway_actor_max_websockets(),
+			runner_max_in_flight_requests: pegboard_config.gateway_runner_max_in_flight_requests(),
+			runner_max_pending_ws_msgs: pegboard_config
+				.gateway_runner_max_pending_websocket_messages(),
 		}))
 	}
 
@@ -229,6 +305,149 @@ impl SharedState {
 		}
 	}
 
+	/// Registers an open WebSocket to an actor, failing if the actor has reached its WebSocket limit. A
+	/// WebSocket that is already registered (i.e. after hibernation or a retry) is always accepted.
+	#[tracing::instrument(skip_all, fields(?actor_id, request_id=%protocol::util::id_to_string(&request_id)))]
+	pub async fn acquire_actor_websocket(
+		&self,
+		actor_id: Id,
+		request_id: protocol::mk2::RequestId,
+	) -> Result<()> {
+		let limit_reached = match self.actor_websockets.entry_async(actor_id).await {
+			Entry::Occupied(mut entry) => {
+				if entry.contains(&request_id) {
+					false
+				} else if self
+					.actor_max_websockets
+					.is_some_and(|max| entry.len() >= max)
+				{
+					true
+				} else {
+					entry.get_mut().insert(request_id);
+					false
+				}
+			}
+			Entry::Vacant(entry) => {
+				if self.actor_max_websockets == Some(0) {
+					true
+				} else {
+					entry.insert_entry(HashSet::from([request_id]));
+					false
+				}
+			}
+		};
+
+		if limit_reached {
+			return Err(WebSocketActorLimitReached {
+				max_websockets: self.actor_max_websockets.unwrap_or_default(),
+			}
+			.build());
+		}
+
+		Ok(())
+	}
+
+	#[tracing::instrument(skip_all, fields(?actor_id, request_id=%protocol::util::id_to_string(&request_id)))]
+	pub async fn release_actor_websocket(
+		&self,
+		actor_id: Id,
+		request_id: protocol::mk2::RequestId,
+	) {
+		if let Entry::Occupied(mut entry) = self.actor_websockets.entry_async(actor_id).await {
+			entry.get_mut().remove(&request_id);
+
+			if entry.is_empty() {
+				let _ = entry.remove();
+			}
+		}
+	}
+
+	/// Registers an in-flight request to a runner, failing if the runner has reached its in-flight request
+	/// or pending WebSocket message limit. A request that is already registered (i.e. a retry) is always
+	/// accepted.
+	#[tracing::instrument(skip_all, fields(?runner_id, request_id=%protocol::util::id_to_string(&request_id)))]
+	pub async fn acquire_runner_request(
+		&self,
+		runner_id: Id,
+		request_id: protocol::mk2::RequestId,
+	) -> Result<()> {
+		if let Some(max) = self.runner_max_pending_ws_msgs {
+			let receiver_subject = RunnerReceiverSubject::new(runner_id).to_string();
+			let pending = self
+				.runner_pending_ws_msgs
+				.read_async(&receiver_subject, |_, pending| *pending)
+				.await
+				.unwrap_or_default();
+
+			if pending >= max {
+				metrics::RUNNER_LIMIT_REJECTED_TOTAL
+					.with_label_values(&["pending_websocket_messages"])
+					.inc();
+
+				return Err(RunnerPendingMessagesLimitReached {
+					max_pending_websocket_messages: max,
+				}
+				.build());
+			}
+		}
+
+		let inserted = match self.runner_requests.entry_async(runner_id).await {
+			Entry::Occupied(mut entry) => {
+				if entry.contains(&request_id) {
+					return Ok(());
+				} else if self
+					.runner_max_in_flight_requests
+					.is_some_and(|max| entry.len() >= max)
+				{
+					false
+				} else {
+					entry.get_mut().insert(request_id);
+					true
+				}
+			}
+			Entry::Vacant(entry) => {
+				if self.runner_max_in_flight_requests == Some(0) {
+					false
+				} else {
+					entry.insert_entry(HashSet::from([request_id]));
+					true
+				}
+			}
+		};
+
+		if !inserted {
+			metrics::RUNNER_LIMIT_REJECTED_TOTAL
+				.with_label_values(&["in_flight_requests"])
+				.inc();
+
+			return Err(RunnerInFlightLimitReached {
+				max_in_flight_requests: self.runner_max_in_flight_requests.unwrap_or_default(),
+			}
+			.build());
+		}
+
+		metrics::RUNNER_IN_FLIGHT_REQUESTS.inc();
+
+		Ok(())
+	}
+
+	#[tracing::instrument(skip_all, fields(?runner_id, request_id=%protocol::util::id_to_string(&request_id)))]
+	pub async fn release_runner_request(
+		&self,
+		runner_id: Id,
+		request_id: protocol::mk2::RequestId,
+	) {
+		if let Entry::Occupied(mut entry) = self.runner_requests.entry_async(runner_id).await {
+			if entry.get_mut().remove(&request_id) {
+				metrics::RUNNER_IN_FLIGHT_REQUESTS.dec();
+			}
+
+			if entry.is_empty() {
+				let _ = entry.remove();
+			}
+		}
+	}
+
 	#[tracing::instrument(skip_all, fields(request_id=%protocol::util::id_to_string(&request_id)))]
 	pub async fn send_message(
 		&self,
@@ -521,13 +740,7 @@ impl SharedState {
 		match (req.hibernation_state.is_some(), enable) {
 			(true, true) => {}
 			(true, false) => req.hibernation_state = None,
-			(false, true) => {
-				req.hibernation_state = Some(HibernationState {
-					total_pending_ws_msgs_size: 0,
-					pending_ws_msgs: Vec::new(),
-					last_ping: Instant::now(),
-				});
-			}
+			(false, true) => req.hibernation_state = Some(HibernationState::new()),
 			(false, false) => {}
 		}
 
@@ -569,11 +782,27 @@ impl SharedState {
 			bail!("request not in flight");
 		};
 
-		if let Some(hs) = &req.hibernation_state {
-			Ok(!hs.pending_ws_msgs.is_empty())
-		} else {
-			Ok(false)
-		}
+		Ok(req
+			.hibernation_state
+			.as_ref()
+			.is_some_and(HibernationState::has_pending_ws_msgs))
+	}
+
+	/// Replaces the message channel of a hibernating request so messages the runner sends during
+	/// hibernation (i.e. a close) reach the hibernation handler instead of being dropped.
+	#[tracing::instrument(skip_all, fields(request_id=%protocol::util::id_to_string(&request_id)))]
+	pub async fn subscribe_hibernating_request(
+		&self,
+		request_id: protocol::mk2::RequestId,
+	) -> Result<mpsc::UnboundedReceiver<protocol::mk2::ToServerTunnelMessageKind>> {
+		let Some(mut req) = self.in_flight_requests.get_async(&request_id).await else {
+			bail!("request not in flight");
+		};
+
+		let (msg_tx, msg_rx) = mpsc::unbounded_channel();
+		req.msg_tx = msg_tx;
+
+		Ok(msg_rx)
 	}
 
 	#[tracing::instrument(skip_all, fields(request_id=%protocol::util::id_to_string(&request_id), %ack_index))]
@@ -591,17 +820,7 @@ impl SharedState {
 			return Ok(());
 		};
 
-		// Retain messages with index > ack_index (messages that haven't been acknowledged yet)
-		let len_before = hs.pending_ws_msgs.len();
-		hs.pending_ws_msgs
-			.retain(|msg| wrapping_gt(msg.message_index, ack_index));
-
-		let len_after = hs.pending_ws_msgs.len();
-		tracing::debug!(
-			removed_count = len_before - len_after,
-			remaining_count = len_after,
-			"acked pending websocket messages"
-		);
+		hs.ack_pending_ws_msgs(ack_index);
 
 		Ok(())
 	}
@@ -615,6 +834,37 @@ impl SharedState {
 			interval.tick().await;
 
 			self.gc_in_flight_requests().await;
+			self.count_runner_pending_ws_msgs().await;
+		}
+	}
+
+	/// Recounts unacknowledged hibernatable WebSocket messages per runner for
+	/// `acquire_runner_request`.
+	#[tracing::instrument(skip_all)]
+	async fn count_runner_pending_ws_msgs(&self) {
+		let mut counts = std::collections::HashMap::<String, usize>::new();
+		self.in_flight_requests
+			.iter_async(|_, req| {
+				if let Some(hs) = &req.hibernation_state {
+					if !hs.pending_ws_msgs.is_empty() {
+						*counts.entry(req.receiver_subject.clone()).or_default() +=
+							hs.pending_ws_msgs.len();
+					}
+				}
+
+				true
+			})
+			.await;
+
+		metrics::RUNNER_PENDING_WEBSOCKET_MESSAGES.set(counts.values().sum::<usize>() as i64);
+
+		self.runner_pending_ws_msgs
+			.retain_async(|receiver_subject, _| counts.contains_key(receiver_subject))
+			.await;
+		for (receiver_subject, pending) in counts {
+			self.runner_pending_ws_msgs
+				.upsert_async(receiver_subject, pending)
+				.await;
 		}
 	}
 
@@ -735,8 +985,17 @@ fn wrapping_gt(a: u16, b: u16) -> bool {
 
 #[cfg(test)]
 mod tests {
-	use super::InFlightRequestState;
+	use super::{HibernationState, InFlightRequestState, PendingWebsocketMessage};
 	use rivet_runner_protocol as protocol;
+	use std::time::Instant;
+
+	fn push_client_message(hs: &mut HibernationState, message_index: u16) {
+		hs.pending_ws_msgs.push(PendingWebsocketMessage {
+			payload: Vec::new(),
+			send_instant: Instant::now(),
+			message_index,
+		});
+	}
 
 	#[test]
 	fn http_requests_only_accept_http_terminal_messages() {
@@ -790,6 +1049,44 @@ mod tests {
 		);
 		assert_eq!(state, InFlightRequestState::ActiveWebSocket);
 	}
+
+	#[test]
+	fn unacked_client_messages_rewake_until_acked() {
+		let mut hs = HibernationState::new();
+		assert!(!hs.has_pending_ws_msgs());
+
+		// Sent as hibernation starts, before the actor acked it
+		push_client_message(&mut hs, 0);
+		push_client_message(&mut hs, 1);
+		assert!(hs.has_pending_ws_msgs());
+
+		hs.ack_pending_ws_msgs(0);
+		assert!(
+			hs.has_pending_ws_msgs(),
+			"unacked message should be kept for resend"
+		);
+		assert_eq!(hs.pending_ws_msgs[0].message_index, 1);
+
+		hs.ack_pending_ws_msgs(1);
+		assert!(!hs.has_pending_ws_msgs());
+	}
+
+	#[test]
+	fn ack_handles_message_index_wraparound() {
+		let mut hs = HibernationState::new();
+		push_client_message(&mut hs, u16::MAX - 1);
+		push_client_message(&mut hs, u16::MAX);
+		push_client_message(&mut hs, 0);
+
+		hs.ack_pending_ws_msgs(u16::MAX);
+		assert_eq!(
+			hs.pending_ws_msgs
+				.iter()
+				.map(|msg| msg.message_index)
+				.collect::<Vec<_>>(),
+			[0]
+		);
+	}
 }
 
 // fn wrapping_lt(a: u16, b: u16) -> bool {
@@ -9,6 +9,7 @@ use rivet_guard_core::{
 	WebSocketHandle,
 	errors::{WebSocketGarbageCollected, WebSocketServiceHibernate},
 };
+use rivet_metrics::prometheus::IntCounter;
 use rivet_runner_protocol as protocol;
 use tokio::sync::{mpsc, watch};
 use tokio_tungstenite::tungstenite::Message;
@@ -24,7 +25,9 @@ pub async fn task(
 	mut msg_rx: mpsc::UnboundedReceiver<protocol::mk2::ToServerTunnelMessageKind>,
 	mut drop_rx: watch::Receiver<Option<MsgGcReason>>,
 	can_hibernate: bool,
+	max_message_size: Option<usize>,
 	egress_bytes: Arc<AtomicU64>,
+	egress_messages: IntCounter,
 	mut tunnel_to_ws_abort_rx: watch::Receiver<()>,
 ) -> Result<LifecycleResult> {
 	loop {
@@ -39,6 +42,17 @@ pub async fn task(
 								binary=ws_msg.binary,
 								"forwarding websocket message to client"
 							);
+
+							if let Some(close) = message_too_big(ws_msg.data.len(), max_message_size) {
+								tracing::warn!(
+									request_id=%protocol::util::id_to_string(&request_id),
+									data_len=ws_msg.data.len(),
+									?max_message_size,
+									"websocket message from actor exceeds max message size"
+								);
+								return Ok(LifecycleResult::ServerClose(close));
+							}
+
 							let msg = if ws_msg.binary {
 								Message::Binary(ws_msg.data.into())
 							} else {
@@ -48,6 +62,7 @@ pub async fn task(
 							};
 
 							egress_bytes.fetch_add(msg.len() as u64, Ordering::AcqRel);
+							egress_messages.inc();
 							client_ws.send(msg).await?;
 						}
 						protocol::mk2::ToServerTunnelMessageKind::ToServerWebSocketMessageAck(ack) => {
@@ -105,3 +120,40 @@ pub async fn task(
 		}
 	}
 }
+
+/// Close frame sent to the client when a message from the actor is over the configured limit.
+fn message_too_big(
+	data_len: usize,
+	max_message_size: Option<usize>,
+) -> Option<protocol::mk2::ToServerWebSocketClose> {
+	if max_message_size.is_none_or(|max| data_len <= max) {
+		return None;
+	}
+
+	Some(protocol::mk2::ToServerWebSocketClose {
+		// Message too big
+		code: Some(1009),
+		reason: Some("ws.message_too_big".to_owned()),
+		hibernate: false,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn message_over_limit_closes_with_1009() {
+		let close = message_too_big(1025, Some(1024)).expect("message should be too big");
+
+		assert_eq!(close.code, Some(1009));
+		assert_eq!(close.reason.as_deref(), Some("ws.message_too_big"));
+		assert!(!close.hibernate);
+	}
+
+	#[test]
+	fn message_within_limit_is_forwarded() {
+		assert!(message_too_big(1024, Some(1024)).is_none());
+		assert!(message_too_big(usize::MAX, None).is_none());
+	}
+}
@@ -1,6 +1,7 @@
 use anyhow::Result;
 use futures_util::TryStreamExt;
 use rivet_guard_core::websocket_handle::WebSocketReceiver;
+use rivet_metrics::prometheus::IntCounter;
 use rivet_runner_protocol as protocol;
 use std::sync::{
 	Arc,
@@ -17,6 +18,7 @@ pub async fn task(
 	request_id: protocol::mk2::RequestId,
 	ws_rx: Arc<Mutex<WebSocketReceiver>>,
 	ingress_bytes: Arc<AtomicU64>,
+	ingress_messages: IntCounter,
 	mut ws_to_tunnel_abort_rx: watch::Receiver<()>,
 ) -> Result<LifecycleResult> {
 	let mut ws_rx = ws_rx.lock().await;
@@ -29,6 +31,7 @@ pub async fn task(
 
 					match msg {
 						Message::Binary(data) => {
+							ingress_messages.inc();
 							let ws_message =
 								protocol::mk2::ToClientTunnelMessageKind::ToClientWebSocketMessage(
 									protocol::mk2::ToClientWebSocketMessage {
@@ -41,6 +44,7 @@ pub async fn task(
 								.await?;
 						}
 						Message::Text(text) => {
+							ingress_messages.inc();
 							let ws_message =
 								protocol::mk2::ToClientTunnelMessageKind::ToClientWebSocketMessage(
 									protocol::mk2::ToClientWebSocketMessage {
@@ -61,14 +61,21 @@ pub async fn task(
 								request_id=%protocol::util::id_to_string(&in_flight_req.request_id),
 								data_len=ws_msg.data.len(),
 								binary=ws_msg.binary,
+								compressed=ws_msg.compressed,
 								"forwarding websocket message to client"
 							);
+							let data = if ws_msg.compressed {
+								protocol::compression::decompress(
+									&ws_msg.data,
+									protocol::compression::MAX_DECOMPRESSED_SIZE,
+								)?
+							} else {
+								ws_msg.data
+							};
 							let msg = if ws_msg.binary {
-								Message::Binary(ws_msg.data.into())
+								Message::Binary(data.into())
 							} else {
-								Message::Text(
-									String::from_utf8_lossy(&ws_msg.data).into_owned().into(),
-								)
+								Message::Text(String::from_utf8_lossy(&data).into_owned().into())
 							};
 
 							egress_bytes.fetch_add(msg.len() as u64, Ordering::AcqRel);
@@ -84,6 +91,17 @@ pub async fn task(
 								.ack_pending_websocket_messages(ack.index)
 								.await?;
 						}
+						// The actor was woken by another request and closed the websocket before the client
+						// sent anything
+						protocol::ToRivetTunnelMessageKind::ToRivetWebSocketClose(close) if !close.hibernate => {
+							tracing::debug!(
+								request_id=%protocol::util::id_to_string(&in_flight_req.request_id),
+								?close,
+								"actor closed websocket during hibernation"
+							);
+
+							return Ok(HibernationLifecycleResult::ServerClose(close));
+						}
 						_ => {}
 					}
 				} else {
@@ -112,7 +130,7 @@ pub async fn task(
 					HibernationLifecycleResult::Close => {
 						tracing::debug!("websocket stream closed during hibernation");
 					}
-					HibernationLifecycleResult::Aborted => {}
+					HibernationLifecycleResult::ServerClose(_) | HibernationLifecycleResult::Aborted => {}
 				}
 
 				return Ok(hibernation_res);
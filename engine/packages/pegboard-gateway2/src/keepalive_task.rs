@@ -17,6 +17,7 @@ pub async fn task(
 	in_flight_req: InFlightRequestHandle,
 	ctx: StandaloneCtx,
 	actor_id: Id,
+	actor_name: String,
 	gateway_id: protocol::GatewayId,
 	request_id: protocol::RequestId,
 	mut keepalive_abort_rx: watch::Receiver<()>,
@@ -24,7 +25,7 @@ pub async fn task(
 	let mut ping_interval = tokio::time::interval(Duration::from_millis(
 		(ctx.config()
 			.pegboard()
-			.hibernating_request_eligible_threshold()
+			.hibernating_request_eligible_threshold_for_actor(&actor_name)
 			/ 2)
 		.try_into()?,
 	));
@@ -67,6 +67,7 @@ enum LifecycleResult {
 enum HibernationLifecycleResult {
 	Continue,
 	Close,
+	ServerClose(protocol::ToRivetWebSocketClose),
 	Aborted,
 }
 
@@ -77,6 +78,7 @@ pub struct PegboardGateway2 {
 	pool_name: String,
 	envoy_key: String,
 	actor_id: Id,
+	actor_name: String,
 	actor_key: Option<String>,
 	actor_generation: Option<u32>,
 	path: String,
@@ -91,6 +93,7 @@ impl PegboardGateway2 {
 		pool_name: String,
 		envoy_key: String,
 		actor_id: Id,
+		actor_name: String,
 		actor_key: Option<String>,
 		actor_generation: Option<u32>,
 		path: String,
@@ -102,6 +105,7 @@ impl PegboardGateway2 {
 			pool_name,
 			envoy_key,
 			actor_id,
+			actor_name,
 			actor_key,
 			actor_generation,
 			path,
@@ -410,16 +414,20 @@ impl PegboardGateway2 {
 			.await?;
 
 		let res = async {
-			// If we are reconnecting after hibernation, don't send an open message
-			let can_hibernate = if after_hibernation {
-				true
+			// If we are reconnecting after hibernation, don't send an open message. Compression is not
+			// renegotiated without an open message, so it is reset on rewake.
+			let (can_hibernate, compression) = if after_hibernation {
+				(true, false)
 			} else {
 				// Send WebSocket open message
+				let offer_compression =
+					self.ctx.config().pegboard().gateway_websocket_compression();
 				let open_message = protocol::ToEnvoyTunnelMessageKind::ToEnvoyWebSocketOpen(
 					protocol::ToEnvoyWebSocketOpen {
 						actor_id: self.actor_id.to_string(),
 						path: self.path.clone(),
 						headers: request_headers,
+						compression: offer_compression,
 					},
 				);
 
@@ -480,6 +488,7 @@ impl PegboardGateway2 {
 												gateway_id = %display_id(&gateway_id),
 												request_id = %display_id(&request_id),
 												can_hibernate = msg.can_hibernate,
+												compression = msg.compression,
 												"received websocket open from envoy"
 											);
 											return anyhow::Ok(msg);
@@ -642,7 +651,10 @@ impl PegboardGateway2 {
 					.toggle_hibernatable(open_msg.can_hibernate)
 					.await?;
 
-				open_msg.can_hibernate
+				(
+					open_msg.can_hibernate,
+					offer_compression && open_msg.compression,
+				)
 			};
 
 			let ingress_bytes = Arc::new(AtomicU64::new(0));
@@ -667,6 +679,7 @@ impl PegboardGateway2 {
 					msg_rx,
 					drop_rx,
 					can_hibernate,
+					self.ctx.config().guard().websocket_max_actor_message_size(),
 					egress_bytes.clone(),
 					tunnel_to_ws_abort_rx,
 				)
@@ -676,6 +689,7 @@ impl PegboardGateway2 {
 				ws_to_tunnel_task::task(
 					in_flight_req.clone(),
 					ws_rx,
+					compression,
 					ingress_bytes.clone(),
 					ws_to_tunnel_abort_rx,
 				)
@@ -708,6 +722,7 @@ impl PegboardGateway2 {
 						in_flight_req.clone(),
 						ctx.clone(),
 						self.actor_id,
+						self.actor_name.clone(),
 						self.shared_state.gateway_id(),
 						request_id,
 						keepalive_abort_rx,
@@ -883,16 +898,7 @@ impl PegboardGateway2 {
 
 			// Send WebSocket close message to client
 			match lifecycle_res {
-				Ok(LifecycleResult::ServerClose(close)) => {
-					if let Some(code) = close.code {
-						Ok(Some(CloseFrame {
-							code: code.into(),
-							reason: close.reason.unwrap_or_default().into(),
-						}))
-					} else {
-						Ok(None)
-					}
-				}
+				Ok(LifecycleResult::ServerClose(close)) => Ok(server_close_frame(close)),
 				Ok(_) => Ok(None),
 				Err(err) => Err(err),
 			}
@@ -979,6 +985,22 @@ impl CustomServeTrait for PegboardGateway2 {
 		after_hibernation: bool,
 	) -> Result<Option<CloseFrame>> {
 		let ctx = self.ctx.with_ray(req_ctx.ray_id(), req_ctx.req_id())?;
+		let request_id = req_ctx.in_flight_request_id()?;
+
+		if let Err(err) = self
+			.shared_state
+			.acquire_actor_websocket(self.actor_id, request_id)
+			.await
+		{
+			metrics::WEBSOCKET_ACTOR_LIMIT_REJECTED_TOTAL
+				.with_label_values(&[
+					self.namespace_id.to_string().as_str(),
+					self.pool_name.as_str(),
+				])
+				.inc();
+			return Err(err);
+		}
+
 		let (res, metrics_res) = tokio::join!(
 			self.handle_websocket_inner(&ctx, req_ctx, client_ws, after_hibernation),
 			record_req_metrics(
@@ -989,6 +1011,13 @@ impl CustomServeTrait for PegboardGateway2 {
 			),
 		);
 
+		// Hibernating WebSockets are still open to the client
+		if !matches!(&res, Err(err) if is_ws_hibernate(err)) {
+			self.shared_state
+				.release_actor_websocket(self.actor_id, request_id)
+				.await;
+		}
+
 		if let Err(err) = metrics_res {
 			tracing::error!(?err, "ws open metrics failed");
 		} else {
@@ -1016,6 +1045,13 @@ impl CustomServeTrait for PegboardGateway2 {
 		res
 	}
 
+	fn request_coalescing(&self) -> bool {
+		self.ctx
+			.config()
+			.guard()
+			.request_coalescing_for_actor(&self.actor_name)
+	}
+
 	#[tracing::instrument(skip_all, fields(actor_id=?self.actor_id, actor_key=?self.actor_key, actor_generation=?self.actor_generation, namespace_id=?self.namespace_id, pool_name=%self.pool_name, envoy_key=%self.envoy_key))]
 	async fn handle_websocket_hibernation(
 		&self,
@@ -1083,6 +1119,7 @@ impl CustomServeTrait for PegboardGateway2 {
 				in_flight_req.clone(),
 				ctx.clone(),
 				self.actor_id,
+				self.actor_name.clone(),
 				self.shared_state.gateway_id(),
 				request_id,
 				keepalive_abort_rx,
@@ -1155,6 +1192,9 @@ impl CustomServeTrait for PegboardGateway2 {
 			(Ok(res), _, _) => match res {
 				HibernationLifecycleResult::Continue => Ok(HibernationResult::Continue),
 				HibernationLifecycleResult::Close => Ok(HibernationResult::Close),
+				HibernationLifecycleResult::ServerClose(close) => {
+					Ok(HibernationResult::ServerClose(server_close_frame(close)))
+				}
 				// Should be unreachable
 				HibernationLifecycleResult::Aborted => Err(anyhow!("hibernation aborted")),
 			},
@@ -1164,10 +1204,12 @@ impl CustomServeTrait for PegboardGateway2 {
 			async {
 				match &res {
 					Ok(HibernationResult::Continue) => {}
-					Ok(HibernationResult::Close) | Err(_) => {
+					Ok(HibernationResult::Close | HibernationResult::ServerClose(_)) | Err(_) => {
 						let stop_result = match &res {
 							Ok(HibernationResult::Close) => RequestStopResult::ClientDisconnect,
-							Ok(HibernationResult::Continue) => RequestStopResult::Success,
+							Ok(HibernationResult::Continue | HibernationResult::ServerClose(_)) => {
+								RequestStopResult::Success
+							}
 							Err(_) => RequestStopResult::ActorReadyTimeout,
 						};
 						in_flight_req.stop(stop_result).await;
@@ -1207,12 +1249,27 @@ impl CustomServeTrait for PegboardGateway2 {
 			},
 		);
 
+		// Guard drops the client WebSocket if hibernation fails or the actor closed it
+		if matches!(res, Err(_) | Ok(HibernationResult::ServerClose(_))) || delete_res.is_err() {
+			self.shared_state
+				.release_actor_websocket(self.actor_id, request_id)
+				.await;
+		}
+
 		delete_res?;
 
 		res
 	}
 }
 
+/// Builds the close frame sent to the client from the actor's close message.
+fn server_close_frame(close: protocol::ToRivetWebSocketClose) -> Option<CloseFrame> {
+	close.code.map(|code| CloseFrame {
+		code: code.into(),
+		reason: close.reason.unwrap_or_default().into(),
+	})
+}
+
 #[derive(Debug)]
 enum Metric {
 	HttpIngress(usize),
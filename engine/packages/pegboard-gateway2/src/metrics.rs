@@ -59,6 +59,12 @@ lazy_static::lazy_static! {
 			"In-flight gateway requests abandoned on pod shutdown without sending close.",
 			*REGISTRY
 		).unwrap();
+	pub static ref WEBSOCKET_ACTOR_LIMIT_REJECTED_TOTAL: IntCounterVec = register_int_counter_vec_with_registry!(
+		"gateway2_websocket_actor_limit_rejected_total",
+		"Count of WebSockets closed because the actor reached its concurrent WebSocket limit.",
+		&["namespace_id", "pool_name"],
+		*REGISTRY
+	).unwrap();
 	pub static ref MSG_SENT_TOTAL: IntCounterVec = register_int_counter_vec_with_registry!(
 		"gateway2_msg_sent_total",
 		"Count of total of tunnel messages sent.",
@@ -101,6 +107,9 @@ pub fn prepopulate() {
 				.inc_by(0);
 		}
 	}
+	WEBSOCKET_ACTOR_LIMIT_REJECTED_TOTAL
+		.with_label_values(&["", ""])
+		.inc_by(0);
 	for result in ["ok", "error", "timeout"] {
 		WEBSOCKET_OPEN_WAIT_SECONDS.with_label_values(&["", "", result]);
 	}
@@ -2,9 +2,12 @@ use anyhow::Result;
 use gas::prelude::*;
 use pegboard::pubsub_subjects::GatewayReceiverSubject;
 use rivet_envoy_protocol::{self as protocol, PROTOCOL_VERSION, versioned};
-use rivet_guard_core::errors::{TunnelMessageTimeout, WebSocketTunnelPingTimeout};
+use rivet_guard_core::errors::{
+	TunnelMessageTimeout, WebSocketActorLimitReached, WebSocketTunnelPingTimeout,
+};
 use scc::{HashMap, hash_map::Entry};
 use std::{
+	collections::HashSet,
 	fmt,
 	ops::Deref,
 	sync::{
@@ -147,12 +150,15 @@ pub struct SharedStateInner {
 	gateway_id: protocol::GatewayId,
 	receiver_subject: GatewayReceiverSubject,
 	in_flight_requests: HashMap<protocol::RequestId, InFlightRequest>,
+	/// Open WebSockets to each actor, including hibernating ones.
+	actor_websockets: HashMap<Id, HashSet<protocol::RequestId>>,
 	hibernation_timeout: i64,
 	// Config values
 	gc_interval: Duration,
 	tunnel_ping_timeout: i64,
 	hws_message_ack_timeout: Duration,
 	hws_max_pending_size: u64,
+	actor_max_websockets: Option<usize>,
 }
 
 #[derive(Clone)]
@@ -173,13 +179,16 @@ impl SharedState {
 			gateway_id,
 			receiver_subject,
 			in_flight_requests: HashMap::new(),
-			hibernation_timeout: pegboard_config.hibernating_request_eligible_threshold(),
+			actor_websockets: HashMap::new(),
+			// Keepalives of actors with a longer threshold override are less frequent
+			hibernation_timeout: pegboard_config.max_hibernating_request_eligible_threshold(),
 			gc_interval: Duration::from_millis(pegboard_config.gateway_gc_interval_ms()),
 			tunnel_ping_timeout: pegboard_config.gateway_tunnel_ping_timeout_ms(),
 			hws_message_ack_timeout: Duration::from_millis(
 				pegboard_config.gateway_hws_message_ack_timeout_ms(),
 			),
 			hws_max_pending_size: pegboard_config.gateway_hws_max_pending_size(),
+			actor_max_websockets: pegboard_config.gateway_actor_max_websockets(),
 		}))
 	}
 
@@ -187,6 +196,59 @@ impl SharedState {
 		self.gateway_id
 	}
 
+	/// Registers an open WebSocket to an actor, failing if the actor has reached its WebSocket limit. A
+	/// WebSocket that is already registered (i.e. after hibernation or a retry) is always accepted.
+	#[tracing::instrument(skip_all, fields(?actor_id, request_id=%display_id(&request_id)))]
+	pub async fn acquire_actor_websocket(
+		&self,
+		actor_id: Id,
+		request_id: protocol::RequestId,
+	) -> Result<()> {
+		let limit_reached = match self.actor_websockets.entry_async(actor_id).await {
+			Entry::Occupied(mut entry) => {
+				if entry.contains(&request_id) {
+					false
+				} else if self
+					.actor_max_websockets
+					.is_some_and(|max| entry.len() >= max)
+				{
+					true
+				} else {
+					entry.get_mut().insert(request_id);
+					false
+				}
+			}
+			Entry::Vacant(entry) => {
+				if self.actor_max_websockets == Some(0) {
+					true
+				} else {
+					entry.insert_entry(HashSet::from([request_id]));
+					false
+				}
+			}
+		};
+
+		if limit_reached {
+			return Err(WebSocketActorLimitReached {
+				max_websockets: self.actor_max_websockets.unwrap_or_default(),
+			}
+			.build());
+		}
+
+		Ok(())
+	}
+
+	#[tracing::instrument(skip_all, fields(?actor_id, request_id=%display_id(&request_id)))]
+	pub async fn release_actor_websocket(&self, actor_id: Id, request_id: protocol::RequestId) {
+		if let Entry::Occupied(mut entry) = self.actor_websockets.entry_async(actor_id).await {
+			entry.get_mut().remove(&request_id);
+
+			if entry.is_empty() {
+				let _ = entry.remove();
+			}
+		}
+	}
+
 	#[tracing::instrument(skip_all)]
 	pub async fn start(&self) -> Result<()> {
 		let self_clone = self.clone();
@@ -974,17 +1036,7 @@ impl InFlightRequestHandle {
 			bail!("request not in flight");
 		};
 
-		match &req.state {
-			InFlightRequestState::Active {
-				hibernation_state: Some(hibernation_state),
-				..
-			}
-			| InFlightRequestState::PendingHibernation { hibernation_state }
-			| InFlightRequestState::Hibernating {
-				hibernation_state, ..
-			} => Ok(!hibernation_state.pending_ws_msgs.is_empty()),
-			_ => Ok(false),
-		}
+		Ok(req.has_pending_websocket_messages())
 	}
 
 	#[tracing::instrument(skip_all, fields(request_id=%display_id(&self.request_id), %ack_index))]
@@ -1003,17 +1055,7 @@ impl InFlightRequestHandle {
 			return Ok(());
 		};
 
-		// Retain messages with index > ack_index (messages that haven't been acknowledged yet)
-		let len_before = hs.pending_ws_msgs.len();
-		hs.pending_ws_msgs
-			.retain(|msg| wrapping_gt(msg.message_index, ack_index));
-
-		let len_after = hs.pending_ws_msgs.len();
-		tracing::debug!(
-			removed_count = len_before - len_after,
-			remaining_count = len_after,
-			"acked pending websocket messages"
-		);
+		hs.ack_pending_ws_msgs(ack_index);
 
 		Ok(())
 	}
@@ -1029,24 +1071,7 @@ impl InFlightRequestHandle {
 			bail!("request not in flight");
 		};
 
-		match &mut req.state {
-			InFlightRequestState::Active {
-				hibernation_state, ..
-			} => {
-				req.state = InFlightRequestState::PendingHibernation {
-					hibernation_state: std::mem::take(hibernation_state)
-						.context("should be hibernatable")?,
-				};
-			}
-			InFlightRequestState::PendingHibernation { .. } => {
-				tracing::warn!("request already hibernating");
-			}
-			InFlightRequestState::Hibernating { .. } => {
-				tracing::warn!("request already hibernating");
-			}
-		}
-
-		Ok(())
+		req.start_hibernation()
 	}
 
 	#[tracing::instrument(skip_all)]
@@ -1130,6 +1155,42 @@ impl InFlightRequest {
 		}
 	}
 
+	fn has_pending_websocket_messages(&self) -> bool {
+		match &self.state {
+			InFlightRequestState::Active {
+				hibernation_state: Some(hibernation_state),
+				..
+			}
+			| InFlightRequestState::PendingHibernation { hibernation_state }
+			| InFlightRequestState::Hibernating {
+				hibernation_state, ..
+			} => !hibernation_state.pending_ws_msgs.is_empty(),
+			_ => false,
+		}
+	}
+
+	/// Transition from active to pending hibernation
+	fn start_hibernation(&mut self) -> Result<()> {
+		match &mut self.state {
+			InFlightRequestState::Active {
+				hibernation_state, ..
+			} => {
+				self.state = InFlightRequestState::PendingHibernation {
+					hibernation_state: std::mem::take(hibernation_state)
+						.context("should be hibernatable")?,
+				};
+			}
+			InFlightRequestState::PendingHibernation { .. } => {
+				tracing::warn!("request already hibernating");
+			}
+			InFlightRequestState::Hibernating { .. } => {
+				tracing::warn!("request already hibernating");
+			}
+		}
+
+		Ok(())
+	}
+
 	#[tracing::instrument(skip_all)]
 	fn recv_message(&mut self, msg: protocol::ToGateway) {
 		match msg {
@@ -1409,6 +1470,22 @@ struct HibernationState {
 	last_ping: Instant,
 }
 
+impl HibernationState {
+	fn ack_pending_ws_msgs(&mut self, ack_index: u16) {
+		// Retain messages with index > ack_index (messages that haven't been acknowledged yet)
+		let len_before = self.pending_ws_msgs.len();
+		self.pending_ws_msgs
+			.retain(|msg| wrapping_gt(msg.message_index, ack_index));
+
+		let len_after = self.pending_ws_msgs.len();
+		tracing::debug!(
+			removed_count = len_before - len_after,
+			remaining_count = len_after,
+			"acked pending websocket messages"
+		);
+	}
+}
+
 pub struct PendingWebsocketMessage {
 	payload: Vec<u8>,
 	send_instant: Instant,
@@ -1471,3 +1548,217 @@ fn wrapping_gt(a: u16, b: u16) -> bool {
 // fn wrapping_lt(a: u16, b: u16) -> bool {
 //     b.wrapping_sub(a) < u16::MAX / 2
 // }
+
+#[cfg(test)]
+mod tests {
+	use gas::prelude::*;
+	use rivet_envoy_protocol as protocol;
+	use std::time::Instant;
+	use tokio::sync::{mpsc, watch};
+
+	use super::{
+		HibernationState, InFlightRequest, InFlightRequestState, PendingWebsocketMessage,
+		RequestProtocol,
+	};
+
+	type MsgRx = mpsc::UnboundedReceiver<protocol::ToRivetTunnelMessageKind>;
+
+	fn channel() -> (
+		mpsc::UnboundedSender<protocol::ToRivetTunnelMessageKind>,
+		MsgRx,
+	) {
+		mpsc::unbounded_channel()
+	}
+
+	fn hibernatable_request() -> (InFlightRequest, MsgRx) {
+		let (msg_tx, msg_rx) = channel();
+		let (drop_tx, _) = watch::channel(None);
+
+		let req = InFlightRequest {
+			namespace_id: Id::nil(),
+			pool_name: "default".to_string(),
+			actor_key: None,
+			actor_generation: None,
+			protocol: RequestProtocol::WebSocket,
+			receiver_subject: "test".to_string(),
+			message_index: 0,
+			created_at: Instant::now(),
+			state: InFlightRequestState::Active {
+				msg_tx,
+				drop_tx,
+				last_pong: util::timestamp::now(),
+				hibernation_state: Some(HibernationState {
+					total_pending_ws_msgs_size: 0,
+					pending_ws_msgs: Vec::new(),
+					pending_tunnel_msgs: Vec::new(),
+					last_ping: Instant::now(),
+				}),
+			},
+		};
+
+		(req, msg_rx)
+	}
+
+	fn hibernate(req: &mut InFlightRequest) -> MsgRx {
+		let (msg_tx, msg_rx) = channel();
+		let (drop_tx, _) = watch::channel(None);
+		req.hibernate(msg_tx, drop_tx);
+
+		msg_rx
+	}
+
+	fn wake(req: &mut InFlightRequest) -> MsgRx {
+		let (msg_tx, msg_rx) = channel();
+		let (drop_tx, _) = watch::channel(None);
+		req.wake("test".to_string(), msg_tx, drop_tx);
+
+		msg_rx
+	}
+
+	/// Message from the actor.
+	fn recv_tunnel_message(req: &mut InFlightRequest, message_index: u16, data: &str) {
+		req.recv_message(protocol::ToGateway::ToRivetTunnelMessage(
+			protocol::ToRivetTunnelMessage {
+				message_id: protocol::MessageId {
+					gateway_id: [0; 4],
+					request_id: [0; 4],
+					message_index,
+				},
+				message_kind: protocol::ToRivetTunnelMessageKind::ToRivetWebSocketMessage(
+					protocol::ToRivetWebSocketMessage {
+						data: data.as_bytes().to_vec(),
+						binary: false,
+						compressed: false,
+					},
+				),
+			},
+		));
+	}
+
+	/// Message from the client that was sent to the actor but not acked yet.
+	fn push_client_message(req: &mut InFlightRequest) {
+		let message_index = req.message_index;
+		req.message_index = req.message_index.wrapping_add(1);
+
+		req.hibernation_state_mut()
+			.expect("should be hibernatable")
+			.pending_ws_msgs
+			.push(PendingWebsocketMessage {
+				payload: Vec::new(),
+				send_instant: Instant::now(),
+				message_index,
+			});
+	}
+
+	fn drain(msg_rx: &mut MsgRx) -> Vec<String> {
+		let mut msgs = Vec::new();
+		while let Ok(msg) = msg_rx.try_recv() {
+			match msg {
+				protocol::ToRivetTunnelMessageKind::ToRivetWebSocketMessage(msg) => {
+					msgs.push(String::from_utf8(msg.data).unwrap());
+				}
+				other => panic!("unexpected message: {other:?}"),
+			}
+		}
+
+		msgs
+	}
+
+	#[test]
+	fn actor_message_during_hibernation_start_is_delivered_once() {
+		let (mut req, mut active_rx) = hibernatable_request();
+
+		req.start_hibernation().unwrap();
+		recv_tunnel_message(&mut req, 0, "a");
+		assert!(
+			drain(&mut active_rx).is_empty(),
+			"active handler should not receive messages after hibernation started"
+		);
+
+		let mut hibernation_rx = hibernate(&mut req);
+		assert_eq!(drain(&mut hibernation_rx), ["a"]);
+
+		let mut woken_rx = wake(&mut req);
+		assert!(
+			drain(&mut woken_rx).is_empty(),
+			"message should not be redelivered on wake"
+		);
+	}
+
+	#[test]
+	fn client_message_during_hibernation_start_rewakes() {
+		let (mut req, _active_rx) = hibernatable_request();
+		assert!(!req.has_pending_websocket_messages());
+
+		req.start_hibernation().unwrap();
+		push_client_message(&mut req);
+		assert!(
+			req.has_pending_websocket_messages(),
+			"unacked client message should rewake immediately"
+		);
+
+		let _hibernation_rx = hibernate(&mut req);
+		let _woken_rx = wake(&mut req);
+		assert!(
+			req.has_pending_websocket_messages(),
+			"client message should be kept for resend until acked"
+		);
+
+		req.hibernation_state_mut().unwrap().ack_pending_ws_msgs(0);
+		assert!(!req.has_pending_websocket_messages());
+	}
+
+	#[test]
+	fn actor_messages_during_keepalive_loop_are_delivered_in_order() {
+		let (mut req, _active_rx) = hibernatable_request();
+		req.start_hibernation().unwrap();
+		let mut hibernation_rx = hibernate(&mut req);
+
+		recv_tunnel_message(&mut req, 0, "a");
+		assert_eq!(drain(&mut hibernation_rx), ["a"]);
+
+		// Hibernation task exited to rewake, later messages are kept until the request is active again
+		drop(hibernation_rx);
+		recv_tunnel_message(&mut req, 1, "b");
+		recv_tunnel_message(&mut req, 2, "c");
+
+		let mut woken_rx = wake(&mut req);
+		assert_eq!(drain(&mut woken_rx), ["b", "c"]);
+
+		recv_tunnel_message(&mut req, 3, "d");
+		assert_eq!(drain(&mut woken_rx), ["d"]);
+	}
+
+	#[test]
+	fn actor_ready_concurrently_with_client_message() {
+		let (mut req, _active_rx) = hibernatable_request();
+		push_client_message(&mut req);
+		req.hibernation_state_mut().unwrap().ack_pending_ws_msgs(0);
+
+		req.start_hibernation().unwrap();
+		let hibernation_rx = hibernate(&mut req);
+
+		// Client sends a message at the same time the actor becomes ready and sends its own message
+		push_client_message(&mut req);
+		drop(hibernation_rx);
+		recv_tunnel_message(&mut req, 0, "ready");
+		assert!(req.has_pending_websocket_messages());
+
+		let mut woken_rx = wake(&mut req);
+		assert_eq!(drain(&mut woken_rx), ["ready"]);
+
+		let pending = &req.hibernation_state_mut().unwrap().pending_ws_msgs;
+		assert_eq!(
+			pending
+				.iter()
+				.map(|msg| msg.message_index)
+				.collect::<Vec<_>>(),
+			[1],
+			"only the unacked client message should be resent"
+		);
+
+		req.hibernation_state_mut().unwrap().ack_pending_ws_msgs(1);
+		assert!(!req.has_pending_websocket_messages());
+		assert!(drain(&mut woken_rx).is_empty());
+	}
+}
@@ -26,6 +26,7 @@ pub async fn task(
 	mut msg_rx: mpsc::UnboundedReceiver<protocol::ToRivetTunnelMessageKind>,
 	mut drop_rx: watch::Receiver<Option<MsgGcReason>>,
 	can_hibernate: bool,
+	max_message_size: Option<usize>,
 	egress_bytes: Arc<AtomicU64>,
 	mut tunnel_to_ws_abort_rx: watch::Receiver<()>,
 ) -> Result<LifecycleResult> {
@@ -35,7 +36,11 @@ pub async fn task(
 				if let Some(msg) = res {
 					match msg {
 						protocol::ToRivetTunnelMessageKind::ToRivetWebSocketMessage(ws_msg) => {
-							let data_len = ws_msg.data.len();
+							let data_len = if ws_msg.compressed {
+								protocol::compression::decompressed_size(&ws_msg.data)?
+							} else {
+								ws_msg.data.len()
+							};
 							let binary = ws_msg.binary;
 							tracing::trace!(
 								request_id=%display_id(&in_flight_req.request_id),
@@ -43,12 +48,30 @@ pub async fn task(
 								binary,
 								"forwarding websocket message to client"
 							);
-							let msg = if ws_msg.binary {
-								Message::Binary(ws_msg.data.into())
+
+							if let Some(close) = message_too_big(data_len, max_message_size) {
+								tracing::warn!(
+									request_id=%display_id(&in_flight_req.request_id),
+									data_len,
+									?max_message_size,
+									"websocket message from actor exceeds max message size"
+								);
+								return Ok(LifecycleResult::ServerClose(close));
+							}
+
+							let data = if ws_msg.compressed {
+								protocol::compression::decompress(
+									&ws_msg.data,
+									max_message_size
+										.unwrap_or(protocol::compression::MAX_DECOMPRESSED_SIZE),
+								)?
+							} else {
+								ws_msg.data
+							};
+							let msg = if binary {
+								Message::Binary(data.into())
 							} else {
-								Message::Text(
-									String::from_utf8_lossy(&ws_msg.data).into_owned().into(),
-								)
+								Message::Text(String::from_utf8_lossy(&data).into_owned().into())
 							};
 
 							egress_bytes.fetch_add(msg.len() as u64, Ordering::AcqRel);
@@ -118,3 +141,40 @@ pub async fn task(
 		}
 	}
 }
+
+/// Close frame sent to the client when a message from the actor is over the configured limit.
+fn message_too_big(
+	data_len: usize,
+	max_message_size: Option<usize>,
+) -> Option<protocol::ToRivetWebSocketClose> {
+	if max_message_size.is_none_or(|max| data_len <= max) {
+		return None;
+	}
+
+	Some(protocol::ToRivetWebSocketClose {
+		// Message too big
+		code: Some(1009),
+		reason: Some("ws.message_too_big".to_owned()),
+		hibernate: false,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn message_over_limit_closes_with_1009() {
+		let close = message_too_big(1025, Some(1024)).expect("message should be too big");
+
+		assert_eq!(close.code, Some(1009));
+		assert_eq!(close.reason.as_deref(), Some("ws.message_too_big"));
+		assert!(!close.hibernate);
+	}
+
+	#[test]
+	fn message_within_limit_is_forwarded() {
+		assert!(message_too_big(1024, Some(1024)).is_none());
+		assert!(message_too_big(usize::MAX, None).is_none());
+	}
+}
@@ -16,6 +16,7 @@ use crate::shared_state::{InFlightRequestHandle, display_id};
 pub async fn task(
 	in_flight_req: InFlightRequestHandle,
 	ws_rx: Arc<Mutex<WebSocketReceiver>>,
+	compression: bool,
 	ingress_bytes: Arc<AtomicU64>,
 	mut ws_to_tunnel_abort_rx: watch::Receiver<()>,
 ) -> Result<LifecycleResult> {
@@ -36,11 +37,13 @@ pub async fn task(
 								binary = true,
 								"received websocket message from client"
 							);
+							let (data, compressed) = compress(data.into(), compression);
 							let ws_message =
 								protocol::ToEnvoyTunnelMessageKind::ToEnvoyWebSocketMessage(
 									protocol::ToEnvoyWebSocketMessage {
-										data: data.into(),
+										data,
 										binary: true,
+										compressed,
 									},
 								);
 							in_flight_req.send_message(ws_message, false).await?;
@@ -59,11 +62,13 @@ pub async fn task(
 								binary = false,
 								"received websocket message from client"
 							);
+							let (data, compressed) = compress(text.as_bytes().to_vec(), compression);
 							let ws_message =
 								protocol::ToEnvoyTunnelMessageKind::ToEnvoyWebSocketMessage(
 									protocol::ToEnvoyWebSocketMessage {
-										data: text.as_bytes().to_vec(),
+										data,
 										binary: false,
+										compressed,
 									},
 								);
 							in_flight_req.send_message(ws_message, false).await?;
@@ -91,3 +96,11 @@ pub async fn task(
 		};
 	}
 }
+
+fn compress(data: Vec<u8>, compression: bool) -> (Vec<u8>, bool) {
+	if compression {
+		protocol::compression::maybe_compress(data)
+	} else {
+		(data, false)
+	}
+}
@@ -2,12 +2,12 @@ use anyhow::Result;
 use futures_util::{StreamExt, stream::FuturesUnordered};
 use gas::prelude::*;
 use pegboard::pubsub_subjects::ServerlessOutboundSubject;
-use reqwest::header::{HeaderName, HeaderValue};
+use reqwest::header::{AUTHORIZATION, HeaderMap, HeaderName, HeaderValue};
 use reqwest_eventsource as sse;
 use rivet_envoy_protocol::{self as protocol, PROTOCOL_VERSION, versioned};
 use rivet_runtime::TermSignal;
 use rivet_types::actor::RunnerPoolError;
-use rivet_types::runner_configs::RunnerConfigKind;
+use rivet_types::runner_configs::{RunnerConfigKind, ServerlessAuth};
 use std::collections::HashMap;
 use std::time::{Duration, Instant};
 use tokio::task::JoinHandle;
@@ -114,6 +114,13 @@ async fn inner(ctx: &StandaloneCtx, conns: &mut Vec<OutboundHandler>) -> Result<
 					NextOutput::Message(msg) => {
 						match protocol::versioned::ToOutbound::deserialize_with_embedded_version(&msg.payload) {
 							Ok(packet) => {
+								// Ack the start. If the request already expired the actor workflow retries it, so it is
+								// not handled here to avoid starting the actor twice.
+								if let Err(err) = msg.respond(&[]).await {
+									tracing::warn!(?err, "failed to ack outbound message, dropping");
+									continue;
+								}
+
 								// TODO: Use interval for gc instead of for every packet
 								// Clean up finished conns
 								conns.retain(|c| !c.handle.is_finished());
@@ -224,6 +231,8 @@ async fn handle(ctx: &StandaloneCtx, packet: protocol::ToOutbound) -> Result<()>
 		headers,
 		request_lifespan,
 		drain_grace_period,
+		body,
+		auth,
 		..
 	} = pool.config.kind
 	else {
@@ -236,6 +245,13 @@ async fn handle(ctx: &StandaloneCtx, packet: protocol::ToOutbound) -> Result<()>
 
 	tracing::Span::current().record("url", &url);
 
+	if body.is_some() {
+		tracing::warn!(
+			?actor_id,
+			"serverless `body` is not supported by envoy pools, sending the start command instead"
+		);
+	}
+
 	let protocol_version = pool.protocol_version.unwrap_or(PROTOCOL_VERSION);
 	let res = async {
 		let payload_body =
@@ -290,6 +306,7 @@ async fn handle(ctx: &StandaloneCtx, packet: protocol::ToOutbound) -> Result<()>
 			request_lifespan,
 			drain_grace_period,
 			token,
+			auth.as_ref(),
 		)
 		.await;
 
@@ -329,6 +346,7 @@ fn error_label(error: &RunnerPoolError) -> &'static str {
 	match error {
 		RunnerPoolError::ServerlessHttpError { .. } => "http_error",
 		RunnerPoolError::ServerlessConnectionError { .. } => "connection_error",
+		RunnerPoolError::ServerlessInitTimeout { .. } => "init_timeout",
 		RunnerPoolError::ServerlessStreamEndedEarly => "stream_ended_early",
 		RunnerPoolError::ServerlessInvalidSsePayload { .. } => "invalid_payload",
 		RunnerPoolError::Downgrade => "downgrade",
@@ -347,6 +365,7 @@ fn status_label(error: &RunnerPoolError) -> &'static str {
 			_ => "other",
 		},
 		RunnerPoolError::ServerlessConnectionError { .. }
+		| RunnerPoolError::ServerlessInitTimeout { .. }
 		| RunnerPoolError::ServerlessStreamEndedEarly
 		| RunnerPoolError::ServerlessInvalidSsePayload { .. }
 		| RunnerPoolError::Downgrade
@@ -364,6 +383,7 @@ fn error_result_label(error: &RunnerPoolError) -> &'static str {
 			_ => "error_http_other",
 		},
 		RunnerPoolError::ServerlessConnectionError { .. } => "error_connection",
+		RunnerPoolError::ServerlessInitTimeout { .. } => "error_init_timeout",
 		RunnerPoolError::ServerlessStreamEndedEarly => "error_stream_ended",
 		RunnerPoolError::ServerlessInvalidSsePayload { .. } => "error_invalid_payload",
 		RunnerPoolError::Downgrade => "error_downgrade",
@@ -408,6 +428,7 @@ async fn serverless_outbound_req(
 	request_lifespan: u32,
 	drain_grace_period: u32,
 	token: Option<&str>,
+	auth: Option<&ServerlessAuth>,
 ) -> Result<()> {
 	let current_dc = ctx.config().topology().current_dc()?;
 	let mut term_signal = TermSignal::get();
@@ -418,7 +439,15 @@ async fn serverless_outbound_req(
 		None
 	};
 
-	let headers = headers
+	let authorization = if let Some(auth) = auth {
+		Some(HeaderValue::try_from(auth.authorization(
+			ctx.config().pegboard().serverless_auth_env_allowlist(),
+		)?)?)
+	} else {
+		None
+	};
+
+	let mut headers: HeaderMap = headers
 		.into_iter()
 		.flat_map(|(k, v)| {
 			// NOTE: This will filter out invalid headers without warning
@@ -440,6 +469,10 @@ async fn serverless_outbound_req(
 		])
 		.chain(token_header)
 		.collect();
+	// Insert so the configured auth replaces any `authorization` header instead of adding a second one
+	if let Some(authorization) = authorization {
+		headers.insert(AUTHORIZATION, authorization);
+	}
 
 	let endpoint_url = format!("{}/start", url.trim_end_matches('/'));
 
@@ -0,0 +1,49 @@
+use std::time::Duration;
+
+use gas::prelude::*;
+use pegboard::ops::actor::log::{append, demuxer};
+use rivet_runner_protocol as protocol;
+
+pub type ActorLogDemuxer = demuxer::ActorLogDemuxer<protocol::mk2::ActorLogLine>;
+
+/// Creates a demuxer that only stores logs for actors that are running on this runner.
+pub fn new(ctx: StandaloneCtx, runner_id: Id) -> ActorLogDemuxer {
+	let pegboard_config = ctx.config().pegboard();
+	let gc_interval = Duration::from_millis(pegboard_config.runner_event_demuxer_gc_interval_ms());
+	let max_last_seen =
+		Duration::from_millis(pegboard_config.runner_event_demuxer_max_last_seen_ms());
+
+	ActorLogDemuxer::new(
+		ctx,
+		gc_interval,
+		max_last_seen,
+		convert_line,
+		move |ctx: StandaloneCtx, actor_id| async move {
+			let actor = ctx
+				.op(pegboard::ops::actor::get_for_runner::Input { actor_id })
+				.await?;
+
+			Ok(actor.is_some_and(|actor| actor.runner_id == runner_id))
+		},
+	)
+}
+
+pub fn convert_log(
+	log: protocol::mk2::ToServerActorLog,
+) -> demuxer::ActorLog<protocol::mk2::ActorLogLine> {
+	demuxer::ActorLog {
+		generation: log.generation,
+		lines: log.lines,
+	}
+}
+
+fn convert_line(line: protocol::mk2::ActorLogLine) -> append::Line {
+	append::Line {
+		ts: line.ts,
+		stream: match line.stream {
+			protocol::mk2::ActorLogStream::Stdout => rivet_types::actors::ActorLogStream::Stdout,
+			protocol::mk2::ActorLogStream::Stderr => rivet_types::actors::ActorLogStream::Stderr,
+		},
+		message: line.message,
+	}
+}
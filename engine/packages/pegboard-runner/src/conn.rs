@@ -17,10 +17,11 @@ use rivet_guard_core::WebSocketHandle;
 use rivet_runner_protocol::{self as protocol, versioned};
 use rivet_types::runner_configs::RunnerConfigKind;
 use scc::HashMap;
+use subtle::ConstantTimeEq;
 use universaldb::prelude::*;
 use vbare::OwnedVersionedData;
 
-use crate::{errors::WsError, metrics, utils::UrlData};
+use crate::{errors::WsError, eviction::EvictionToken, metrics, utils::UrlData};
 
 pub struct Conn {
 	pub namespace_id: Id,
@@ -34,6 +35,45 @@ pub struct Conn {
 	pub last_rtt: AtomicU32,
 	/// Timestamp (epoch ms) of the last pong received from the runner.
 	pub last_ping_ts: AtomicI64,
+	/// Decides which connection survives when connections with the same runner id or key evict each other.
+	pub eviction_token: EvictionToken,
+}
+
+/// Validates the runner token against the admin token and the namespace's runner tokens.
+///
+/// This only runs when a runner connects, so tokens can be rotated without disconnecting runners that
+/// are already connected.
+fn authenticate(ctx: &StandaloneCtx, namespace: &str, token: Option<&str>) -> Result<()> {
+	let config = ctx.config();
+
+	// Guard validates the token itself unless runner tokens are configured, in which case it only accepts
+	// the admin token and defers any other token to here
+	if config.pegboard().runner_tokens.is_none() {
+		return Ok(());
+	}
+
+	let runner_tokens = config.pegboard().runner_tokens_for_namespace(namespace);
+	if config.auth.is_none() && runner_tokens.is_none() {
+		return Ok(());
+	}
+
+	let Some(token) = token else {
+		return Err(WsError::Unauthorized.build());
+	};
+
+	let valid = config
+		.auth
+		.iter()
+		.map(|auth| &auth.admin_token)
+		.chain(runner_tokens.into_iter().flat_map(|tokens| tokens.values()))
+		.any(|secret| bool::from(token.as_bytes().ct_eq(secret.read().as_bytes())));
+	if !valid {
+		return Err(WsError::Unauthorized.build());
+	}
+
+	tracing::debug!(%namespace, "authenticated runner connection");
+
+	Ok(())
 }
 
 #[tracing::instrument(skip_all)]
@@ -44,9 +84,14 @@ pub async fn init_conn(
 		protocol_version,
 		namespace,
 		runner_key,
+		capabilities,
 	}: UrlData,
+	token: Option<String>,
 ) -> Result<Arc<Conn>> {
 	let start = Instant::now();
+
+	authenticate(ctx, &namespace, token.as_deref())?;
+
 	let namespace_name = namespace.clone();
 	let namespace = ctx
 		.op(namespace::ops::resolve_for_name_global::Input { name: namespace })
@@ -150,6 +195,7 @@ pub async fn init_conn(
 			version: init.version(),
 			total_slots: init.total_slots(),
 			protocol_version,
+			capabilities,
 		})
 		.tag("runner_id", runner_id)
 		.unique()
@@ -193,6 +239,7 @@ pub async fn init_conn(
 		authorized_tunnel_routes: HashMap::new(),
 		last_rtt: AtomicU32::new(0),
 		last_ping_ts: AtomicI64::new(util::timestamp::now()),
+		eviction_token: EvictionToken::new(),
 	});
 
 	match init {
@@ -392,7 +439,9 @@ pub async fn handle_init(
 		protocol::mk2::ToClientInit {
 			runner_id: conn.runner_id.to_string(),
 			metadata: protocol::mk2::ProtocolMetadata {
-				runner_lost_threshold: pb.runner_lost_threshold(),
+				// Runners keep their actors alive for as long as the engine keeps their allocations
+				runner_lost_threshold: pb.runner_lost_threshold()
+					+ pb.runner_reconnect_grace_period(),
 				actor_stop_threshold: pb.actor_stop_threshold(),
 				serverless_drain_grace_period: is_serverless
 					.then(|| pb.serverless_drain_grace_period() as i64),
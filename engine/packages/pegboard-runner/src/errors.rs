@@ -35,4 +35,6 @@ pub enum WsError {
 	InvalidPacket(String),
 	#[error("invalid_url", "The connection URL is invalid.", "Invalid url: {0}")]
 	InvalidUrl(String),
+	#[error("unauthorized", "The runner token is missing or invalid.")]
+	Unauthorized,
 }
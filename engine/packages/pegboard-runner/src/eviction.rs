@@ -0,0 +1,92 @@
+use gas::prelude::*;
+
+/// Identifies a runner connection in eviction messages.
+///
+/// Every connection publishes its token when it starts and is only evicted by tokens greater than its own.
+/// When a connection receives an older token it publishes its own token again, in case the older connection
+/// subscribed after our first message was sent. This way connections with the same runner id or key that
+/// race at startup always agree on a single survivor: the newest connection, with ties broken by the random
+/// nonce.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct EvictionToken {
+	connected_ts: i64,
+	nonce: u64,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum EvictionCheck {
+	/// Published by this connection.
+	Own,
+	/// Published by an older connection, which should be evicted by this connection's token.
+	Older,
+	/// This connection is evicted.
+	Evicted,
+}
+
+impl EvictionToken {
+	pub fn new() -> Self {
+		EvictionToken {
+			connected_ts: util::timestamp::now(),
+			nonce: rand::random(),
+		}
+	}
+
+	pub fn serialize(&self) -> Vec<u8> {
+		let mut buf = Vec::with_capacity(16);
+		buf.extend_from_slice(&self.connected_ts.to_be_bytes());
+		buf.extend_from_slice(&self.nonce.to_be_bytes());
+		buf
+	}
+
+	fn deserialize(buf: &[u8]) -> Option<Self> {
+		let (connected_ts, nonce) = buf.split_first_chunk::<8>()?;
+
+		Some(EvictionToken {
+			connected_ts: i64::from_be_bytes(*connected_ts),
+			nonce: u64::from_be_bytes(nonce.try_into().ok()?),
+		})
+	}
+
+	/// Determines what an eviction message with the given payload means for this connection.
+	pub fn check(&self, payload: &[u8]) -> EvictionCheck {
+		match Self::deserialize(payload) {
+			Some(other) if other == *self => EvictionCheck::Own,
+			Some(other) if other < *self => EvictionCheck::Older,
+			Some(_) => EvictionCheck::Evicted,
+			// Published without a token (i.e. by an older version), always evict
+			None => EvictionCheck::Evicted,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{EvictionCheck, EvictionToken};
+
+	#[test]
+	fn newest_connection_survives() {
+		let a = EvictionToken {
+			connected_ts: 1,
+			nonce: 5,
+		};
+		let b = EvictionToken {
+			connected_ts: 2,
+			nonce: 0,
+		};
+		let c = EvictionToken {
+			connected_ts: 2,
+			nonce: 1,
+		};
+
+		assert_eq!(a.check(&a.serialize()), EvictionCheck::Own);
+
+		// Exactly one of each pair is evicted, regardless of the order messages arrive in
+		for (older, newer) in [(a, b), (a, c), (b, c)] {
+			assert_eq!(older.check(&newer.serialize()), EvictionCheck::Evicted);
+			assert_eq!(newer.check(&older.serialize()), EvictionCheck::Older);
+		}
+
+		// Legacy eviction messages have no payload
+		assert_eq!(c.check(&[]), EvictionCheck::Evicted);
+	}
+}
@@ -13,14 +13,18 @@ use tokio_tungstenite::tungstenite::protocol::frame::CloseFrame;
 use universalpubsub::PublishOpts;
 
 mod actor_event_demuxer;
+mod actor_log_demuxer;
 mod conn;
 mod errors;
+mod eviction;
 mod metrics;
 mod ping_task;
 mod tunnel_to_ws_task;
 mod utils;
 mod ws_to_tunnel_task;
 
+pub use utils::parse_token;
+
 #[derive(Debug)]
 enum LifecycleResult {
 	Closed,
@@ -78,10 +82,12 @@ impl CustomServeTrait for PegboardRunnerWsCustomServe {
 		let url_data = utils::UrlData::parse_url(url)
 			.map_err(|err| errors::WsError::InvalidUrl(err.to_string()).build())?;
 
+		let token = utils::parse_token(req_ctx);
+
 		tracing::debug!(path=%req_ctx.path(), "tunnel ws connection established");
 
 		// Create connection
-		let conn = conn::init_conn(&ctx, ws_handle.clone(), url_data)
+		let conn = conn::init_conn(&ctx, ws_handle.clone(), url_data, token)
 			.await
 			.context("failed to initialize runner connection")?;
 
@@ -100,13 +106,13 @@ impl CustomServeTrait for PegboardRunnerWsCustomServe {
 			.subscribe(&topic)
 			.await
 			.with_context(|| format!("failed to subscribe to runner receiver topic: {}", topic))?;
-		let mut eviction_sub = ups.subscribe(&eviction_topic).await.with_context(|| {
+		let eviction_sub = ups.subscribe(&eviction_topic).await.with_context(|| {
 			format!(
 				"failed to subscribe to runner eviction topic: {}",
 				eviction_topic
 			)
 		})?;
-		let mut eviction_sub2 = ups.subscribe(&eviction_topic2).await.with_context(|| {
+		let eviction_sub2 = ups.subscribe(&eviction_topic2).await.with_context(|| {
 			format!(
 				"failed to subscribe to runner eviction topic: {}",
 				eviction_topic2
@@ -114,19 +120,16 @@ impl CustomServeTrait for PegboardRunnerWsCustomServe {
 		})?;
 
 		// Publish eviction message to evict any currently connected runners with the same id or ns id +
-		// runner name + runner key. This happens after subscribing to prevent race conditions.
+		// runner name + runner key. This happens after subscribing to prevent race conditions. Our own
+		// message and messages from older connections are ignored by the eviction token.
+		let eviction_payload = conn.eviction_token.serialize();
 		tokio::try_join!(
-			async {
-				ups.publish(&eviction_topic, &[], PublishOpts::broadcast())
-					.await?;
-				// Because we will receive our own message, skip the first message in the sub
-				eviction_sub.next().await
-			},
-			async {
-				ups.publish(&eviction_topic2, &[], PublishOpts::broadcast())
-					.await?;
-				eviction_sub2.next().await
-			},
+			ups.publish(&eviction_topic, &eviction_payload, PublishOpts::broadcast()),
+			ups.publish(
+				&eviction_topic2,
+				&eviction_payload,
+				PublishOpts::broadcast()
+			),
 		)?;
 
 		metrics::CONNECTION_ACTIVE
@@ -1,7 +1,7 @@
 use anyhow::Result;
 use gas::prelude::*;
 use hyper_tungstenite::tungstenite::Message;
-use pegboard::pubsub_subjects::GatewayReceiverSubject;
+use pegboard::pubsub_subjects::{GatewayReceiverSubject, RunnerEvictionByIdSubject};
 use rivet_runner_protocol::{self as protocol, PROTOCOL_MK2_VERSION, versioned};
 use std::sync::Arc;
 use tokio::sync::watch;
@@ -9,7 +9,7 @@ use universalpubsub as ups;
 use universalpubsub::{NextOutput, PublishOpts, Subscriber};
 use vbare::OwnedVersionedData;
 
-use crate::{LifecycleResult, conn::Conn, errors, metrics};
+use crate::{LifecycleResult, conn::Conn, errors, eviction::EvictionCheck, metrics};
 
 #[tracing::instrument(name="tunnel_to_ws_task", skip_all, fields(ray_id=?ctx.ray_id(), req_id=?ctx.req_id(), runner_id=?conn.runner_id, workflow_id=?conn.workflow_id, protocol_version=%conn.protocol_version))]
 pub async fn task(
@@ -21,6 +21,7 @@ pub async fn task(
 ) -> Result<LifecycleResult> {
 	loop {
 		match recv_msg(
+			&ctx,
 			&conn,
 			&mut tunnel_sub,
 			&mut eviction_sub,
@@ -41,36 +42,57 @@ pub async fn task(
 }
 
 async fn recv_msg(
+	ctx: &StandaloneCtx,
 	conn: &Conn,
 	tunnel_sub: &mut Subscriber,
 	eviction_sub: &mut Subscriber,
 	tunnel_to_ws_abort_rx: &mut watch::Receiver<()>,
 ) -> Result<std::result::Result<ups::Message, LifecycleResult>> {
-	let tunnel_msg = tokio::select! {
-		res = tunnel_sub.next() => {
-			if let NextOutput::Message(tunnel_msg) = res? {
-				tunnel_msg
-			} else {
-				tracing::debug!("tunnel sub closed");
-				bail!("tunnel sub closed");
+	let tunnel_msg = loop {
+		tokio::select! {
+			res = tunnel_sub.next() => {
+				if let NextOutput::Message(tunnel_msg) = res? {
+					break tunnel_msg;
+				} else {
+					tracing::debug!("tunnel sub closed");
+					bail!("tunnel sub closed");
+				}
 			}
-		}
-		_ = eviction_sub.next() => {
-			tracing::debug!("runner evicted");
+			res = eviction_sub.next() => {
+				if let Ok(NextOutput::Message(msg)) = &res {
+					match conn.eviction_token.check(&msg.payload) {
+						EvictionCheck::Own => continue,
+						EvictionCheck::Older => {
+							tracing::debug!("received eviction from older connection, evicting it");
+							ctx.ups()?
+								.publish(
+									&RunnerEvictionByIdSubject::new(conn.runner_id),
+									&conn.eviction_token.serialize(),
+									PublishOpts::broadcast(),
+								)
+								.await?;
+							continue;
+						}
+						EvictionCheck::Evicted => {}
+					}
+				}
 
-			metrics::EVICTION_TOTAL
-				.with_label_values(&[
-					conn.namespace_id.to_string().as_str(),
-					&conn.runner_name,
-					conn.protocol_version.to_string().as_str(),
-				])
-				.inc();
+				tracing::debug!("runner evicted");
 
-			return Ok(Err(LifecycleResult::Evicted));
-		}
-		_ = tunnel_to_ws_abort_rx.changed() => {
-			tracing::debug!("task aborted");
-			return Ok(Err(LifecycleResult::Aborted));
+				metrics::EVICTION_TOTAL
+					.with_label_values(&[
+						conn.namespace_id.to_string().as_str(),
+						&conn.runner_name,
+						conn.protocol_version.to_string().as_str(),
+					])
+					.inc();
+
+				return Ok(Err(LifecycleResult::Evicted));
+			}
+			_ = tunnel_to_ws_abort_rx.changed() => {
+				tracing::debug!("task aborted");
+				return Ok(Err(LifecycleResult::Aborted));
+			}
 		}
 	};
 
@@ -1,10 +1,17 @@
 use gas::prelude::*;
+use hyper::header::{HeaderName, SEC_WEBSOCKET_PROTOCOL};
+use rivet_guard_core::request_context::RequestContext;
+
+const X_RIVET_TOKEN: HeaderName = HeaderName::from_static("x-rivet-token");
+const WS_PROTOCOL_TOKEN: &str = "rivet_token.";
 
 #[derive(Clone)]
 pub struct UrlData {
 	pub protocol_version: u16,
 	pub namespace: String,
 	pub runner_key: String,
+	/// Capabilities advertised by the runner, matched against actor requirements during allocation.
+	pub capabilities: Vec<String>,
 }
 
 impl UrlData {
@@ -31,10 +38,43 @@ impl UrlData {
 			.context("missing `runner_key` query parameter")?
 			.to_string();
 
+		// Read capabilities from query parameters (optional, comma separated)
+		let mut capabilities = url
+			.query_pairs()
+			.find_map(|(n, v)| (n == "capabilities").then_some(v))
+			.map(|v| {
+				v.split(',')
+					.map(str::trim)
+					.filter(|c| !c.is_empty())
+					.map(ToString::to_string)
+					.collect::<Vec<_>>()
+			})
+			.unwrap_or_default();
+		capabilities.sort();
+		capabilities.dedup();
+
 		Ok(UrlData {
 			protocol_version,
 			namespace,
 			runner_key,
+			capabilities,
 		})
 	}
 }
+
+/// Reads the runner token from the `rivet_token.*` WebSocket protocol or the `x-rivet-token` header.
+pub fn parse_token(req_ctx: &RequestContext) -> Option<String> {
+	let headers = req_ctx.headers();
+
+	headers
+		.get(SEC_WEBSOCKET_PROTOCOL)
+		.and_then(|protocols| protocols.to_str().ok())
+		.and_then(|protocols| {
+			protocols
+				.split(',')
+				.map(|p| p.trim())
+				.find_map(|p| p.strip_prefix(WS_PROTOCOL_TOKEN))
+		})
+		.or_else(|| headers.get(X_RIVET_TOKEN).and_then(|x| x.to_str().ok()))
+		.map(ToString::to_string)
+}
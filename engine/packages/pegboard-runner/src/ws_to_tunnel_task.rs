@@ -5,7 +5,7 @@ use gas::prelude::Id;
 use gas::prelude::*;
 use hyper_tungstenite::tungstenite::Message;
 use pegboard::actor_kv;
-use pegboard::pubsub_subjects::GatewayReceiverSubject;
+use pegboard::pubsub_subjects::{GatewayReceiverSubject, RunnerEvictionByNameSubject};
 use rivet_envoy_protocol as ep;
 use rivet_guard_core::websocket_handle::WebSocketReceiver;
 use rivet_runner_protocol::{self as protocol, PROTOCOL_MK2_VERSION, versioned};
@@ -13,11 +13,19 @@ use scc::HashMap;
 use std::sync::{Arc, atomic::Ordering};
 use tokio::sync::{Mutex, MutexGuard, watch};
 use universaldb::utils::end_of_key_range;
-use universalpubsub::Subscriber;
+use universalpubsub::{NextOutput, Subscriber};
 use universalpubsub::{PubSub, PublishOpts};
 use vbare::OwnedVersionedData;
 
-use crate::{LifecycleResult, actor_event_demuxer::ActorEventDemuxer, conn::Conn, errors, metrics};
+use crate::{
+	LifecycleResult,
+	actor_event_demuxer::ActorEventDemuxer,
+	actor_log_demuxer::{self, ActorLogDemuxer},
+	conn::Conn,
+	errors,
+	eviction::EvictionCheck,
+	metrics,
+};
 
 #[tracing::instrument(name="ws_to_tunnel_task", skip_all, fields(ray_id=?ctx.ray_id(), req_id=?ctx.req_id(), runner_id=?conn.runner_id, workflow_id=?conn.workflow_id, protocol_version=%conn.protocol_version))]
 pub async fn task(
@@ -28,6 +36,7 @@ pub async fn task(
 	ws_to_tunnel_abort_rx: watch::Receiver<()>,
 ) -> Result<LifecycleResult> {
 	let mut event_demuxer = ActorEventDemuxer::new(ctx.clone(), conn.runner_id);
+	let mut log_demuxer = actor_log_demuxer::new(ctx.clone(), conn.runner_id);
 
 	let res = task_inner(
 		ctx,
@@ -36,11 +45,13 @@ pub async fn task(
 		eviction_sub2,
 		ws_to_tunnel_abort_rx,
 		&mut event_demuxer,
+		&mut log_demuxer,
 	)
 	.await;
 
-	// Must shutdown demuxer to allow for all in-flight events to finish
+	// Must shutdown demuxers to allow for all in-flight events and logs to finish
 	event_demuxer.shutdown().await;
+	log_demuxer.shutdown().await;
 
 	res
 }
@@ -53,12 +64,14 @@ pub async fn task_inner(
 	mut eviction_sub2: Subscriber,
 	mut ws_to_tunnel_abort_rx: watch::Receiver<()>,
 	event_demuxer: &mut ActorEventDemuxer,
+	log_demuxer: &mut ActorLogDemuxer,
 ) -> Result<LifecycleResult> {
 	let mut ws_rx = ws_rx.lock().await;
 	let mut term_signal = rivet_runtime::TermSignal::get();
 
 	loop {
 		match recv_msg(
+			&ctx,
 			&conn,
 			&mut ws_rx,
 			&mut eviction_sub2,
@@ -69,7 +82,7 @@ pub async fn task_inner(
 		{
 			Ok(Some(msg)) => {
 				if protocol::is_mk2(conn.protocol_version) {
-					handle_message_mk2(&ctx, &conn, event_demuxer, msg).await?;
+					handle_message_mk2(&ctx, &conn, event_demuxer, log_demuxer, msg).await?;
 				} else {
 					handle_message_mk1(&ctx, &conn, msg).await?;
 				}
@@ -81,6 +94,7 @@ pub async fn task_inner(
 }
 
 async fn recv_msg(
+	ctx: &StandaloneCtx,
 	conn: &Conn,
 	ws_rx: &mut MutexGuard<'_, WebSocketReceiver>,
 	eviction_sub2: &mut Subscriber,
@@ -96,7 +110,29 @@ async fn recv_msg(
 				return Ok(Err(LifecycleResult::Closed));
 			}
 		}
-		_ = eviction_sub2.next() => {
+		res = eviction_sub2.next() => {
+			if let Ok(NextOutput::Message(msg)) = &res {
+				match conn.eviction_token.check(&msg.payload) {
+					EvictionCheck::Own => return Ok(Ok(None)),
+					EvictionCheck::Older => {
+						tracing::debug!("received eviction from older connection, evicting it");
+						ctx.ups()?
+							.publish(
+								&RunnerEvictionByNameSubject::new(
+									conn.namespace_id,
+									&conn.runner_name,
+									&conn.runner_key,
+								),
+								&conn.eviction_token.serialize(),
+								PublishOpts::broadcast(),
+							)
+							.await?;
+						return Ok(Ok(None));
+					}
+					EvictionCheck::Evicted => {}
+				}
+			}
+
 			tracing::debug!("runner evicted");
 
 			metrics::EVICTION_TOTAL
@@ -143,6 +179,7 @@ async fn handle_message_mk2(
 	ctx: &StandaloneCtx,
 	conn: &Conn,
 	event_demuxer: &mut ActorEventDemuxer,
+	log_demuxer: &mut ActorLogDemuxer,
 	msg: Bytes,
 ) -> Result<()> {
 	// Parse message
@@ -339,7 +376,14 @@ async fn handle_message_mk2(
 						.context("failed to send KV list response to client")?;
 				}
 				protocol::mk2::KvRequestData::KvPutRequest(body) => {
-					let res = actor_kv::put(&*ctx.udb()?, &recipient, body.keys, body.values).await;
+					let res =
+						match pegboard::utils::validate_batch_size(ctx.config(), body.keys.len()) {
+							Ok(()) => {
+								actor_kv::put(&*ctx.udb()?, &recipient, body.keys, body.values)
+									.await
+							}
+							Err(err) => Err(err),
+						};
 
 					let res_msg = versioned::ToClientMk2::wrap_latest(
 						protocol::mk2::ToClient::ToClientKvResponse(
@@ -455,6 +499,11 @@ async fn handle_message_mk2(
 				}
 			}
 		}
+		// Stored off of the message loop
+		protocol::mk2::ToServer::ToServerActorLog(log) => match Id::parse(&log.actor_id) {
+			Ok(actor_id) => log_demuxer.ingest(actor_id, actor_log_demuxer::convert_log(log)),
+			Err(err) => tracing::warn!(?err, "invalid actor id in actor log"),
+		},
 		protocol::mk2::ToServer::ToServerTunnelMessage(tunnel_msg) => {
 			handle_tunnel_message_mk2(
 				&ctx.ups()
@@ -702,7 +751,14 @@ async fn handle_message_mk1(ctx: &StandaloneCtx, conn: &Conn, msg: Bytes) -> Res
 						.context("failed to send KV list response to client")?;
 				}
 				protocol::KvRequestData::KvPutRequest(body) => {
-					let res = actor_kv::put(&*ctx.udb()?, &recipient, body.keys, body.values).await;
+					let res =
+						match pegboard::utils::validate_batch_size(ctx.config(), body.keys.len()) {
+							Ok(()) => {
+								actor_kv::put(&*ctx.udb()?, &recipient, body.keys, body.values)
+									.await
+							}
+							Err(err) => Err(err),
+						};
 
 					let res_msg = versioned::ToClient::wrap_latest(
 						protocol::ToClient::ToClientKvResponse(protocol::ToClientKvResponse {
@@ -16,4 +16,11 @@ lazy_static::lazy_static! {
 		vec![1.0, 2.0, 4.0, 8.0, 16.0, 32.0, 64.0, 128.0],
 		*REGISTRY
 	).unwrap();
+
+	pub static ref ACTOR_KV_SIZE_ESTIMATE_TOTAL: IntCounterVec = register_int_counter_vec_with_registry!(
+		"pegboard_actor_kv_size_estimate_total",
+		"Count of actor KV storage size lookups during puts, by whether the cached estimate was used.",
+		&["result"],
+		*REGISTRY
+	).unwrap();
 }
@@ -10,8 +10,9 @@ use utils::{validate_entries, validate_keys, validate_range};
 use crate::keys;
 
 mod entry;
-mod metrics;
+pub mod metrics;
 pub mod preload;
+mod size_cache;
 mod utils;
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -25,6 +26,8 @@ pub const MAX_KEYS: usize = 128;
 pub const MAX_PUT_PAYLOAD_SIZE: usize = 976 * 1024;
 const MAX_STORAGE_SIZE: usize = 10 * 1024 * 1024 * 1024; // 10 GiB
 const VALUE_CHUNK_SIZE: usize = 10_000; // 10 KB, not KiB, see https://apple.github.io/foundationdb/blob.html
+/// Max keys cleared per transaction by `delete_all`.
+const DELETE_ALL_BATCH_SIZE: usize = 1_000;
 
 // Namespace and name are used for metrics
 pub struct Recipient {
@@ -312,7 +315,14 @@ pub async fn put(
 	let result = db
 		.txn("pegboard_kv_put", |tx| {
 			async move {
-				let total_size = estimate_kv_size(&tx, recipient.actor_id).await? as usize;
+				let total_size = match size_cache::get(recipient.actor_id).await {
+					Some(size) => size,
+					None => {
+						let size = estimate_kv_size(&tx, recipient.actor_id).await? as usize;
+						size_cache::insert(recipient.actor_id, size).await;
+						size
+					}
+				};
 
 				validate_entries(&keys, &values, total_size)?;
 
@@ -380,6 +390,11 @@ pub async fn put(
 		.custom_instrument(tracing::info_span!("kv_put_tx"))
 		.await
 		.map_err(Into::into);
+	if result.is_ok() {
+		let written = keys.iter().fold(0, |s, key| s + key.len())
+			+ values.iter().fold(0, |s, value| s + value.len());
+		size_cache::record_write(recipient.actor_id, written).await;
+	}
 	metrics::ACTOR_KV_OPERATION_DURATION
 		.with_label_values(&["put"])
 		.observe(start.elapsed().as_secs_f64());
@@ -429,6 +444,7 @@ pub async fn delete(
 		.custom_instrument(tracing::info_span!("kv_delete_tx"))
 		.await
 		.map_err(Into::into);
+	size_cache::invalidate(recipient.actor_id).await;
 	metrics::ACTOR_KV_OPERATION_DURATION
 		.with_label_values(&["delete"])
 		.observe(start.elapsed().as_secs_f64());
@@ -486,18 +502,27 @@ pub async fn delete_range(
 		.custom_instrument(tracing::info_span!("kv_delete_range_tx"))
 		.await
 		.map_err(Into::into);
+	size_cache::invalidate(recipient.actor_id).await;
 	metrics::ACTOR_KV_OPERATION_DURATION
 		.with_label_values(&["delete_range"])
 		.observe(timer.elapsed().as_secs_f64());
 	result
 }
 
-/// Deletes all keys from the KV store. Cannot be undone.
+/// Deletes all keys from the KV store. Cannot be undone. Keys are cleared in batches so this is not atomic.
 #[tracing::instrument(skip_all)]
 pub async fn delete_all(db: &universaldb::Database, recipient: &Recipient) -> Result<()> {
-	db.txn("pegboard_kv_delete_all", |tx| async move {
-		tx.clear_subspace_range(&keys::actor_kv::subspace(recipient.actor_id));
+	let cleared = universaldb::utils::clear_range_chunked(
+		db,
+		&keys::actor_kv::subspace(recipient.actor_id),
+		DELETE_ALL_BATCH_SIZE,
+	)
+	.await;
+	// Invalidate even if the clear failed partway, earlier batches may have already committed
+	size_cache::invalidate(recipient.actor_id).await;
+	cleared?;
 
+	db.txn("pegboard_kv_delete_all", |tx| async move {
 		// Total written bytes (rounded up to nearest chunk)
 		namespace::keys::metric::inc(
 			&tx.with_subspace(namespace::keys::subspace()),
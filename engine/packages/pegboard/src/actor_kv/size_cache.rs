@@ -0,0 +1,89 @@
+//! Short-lived per-actor cache of the estimated KV storage size so frequent small puts don't each read the
+//! estimated range size. Bytes written on this node are added on top of the cached estimate, so the quota
+//! check is off by at most `MAX_DELTA` bytes plus whatever other nodes wrote within `TTL`.
+
+use std::{
+	sync::LazyLock,
+	time::{Duration, Instant},
+};
+
+use gas::prelude::*;
+use moka::{future::Cache, ops::compute::Op};
+
+use super::metrics;
+
+/// How long a cached estimate is used before it is read from the database again.
+const TTL: Duration = Duration::from_secs(5);
+/// Bytes that can be written on top of a cached estimate before it is invalidated.
+const MAX_DELTA: usize = 1024 * 1024; // 1 MiB
+const MAX_CAPACITY: u64 = 100_000;
+
+#[derive(Clone, Copy)]
+struct Entry {
+	estimate: usize,
+	written: usize,
+	cached_at: Instant,
+}
+
+static CACHE: LazyLock<Cache<Id, Entry>> = LazyLock::new(|| {
+	Cache::builder()
+		.max_capacity(MAX_CAPACITY)
+		.time_to_live(TTL)
+		.build()
+});
+
+/// Returns the cached size estimate plus any bytes written since it was cached.
+pub async fn get(actor_id: Id) -> Option<usize> {
+	let size = CACHE
+		.get(&actor_id)
+		.await
+		// Writes update the entry which resets moka's TTL, check the original insert time instead
+		.filter(|entry| entry.cached_at.elapsed() < TTL)
+		.map(|entry| entry.estimate + entry.written);
+
+	metrics::ACTOR_KV_SIZE_ESTIMATE_TOTAL
+		.with_label_values(&[if size.is_some() { "hit" } else { "miss" }])
+		.inc();
+
+	size
+}
+
+pub async fn insert(actor_id: Id, estimate: usize) {
+	CACHE
+		.insert(
+			actor_id,
+			Entry {
+				estimate,
+				written: 0,
+				cached_at: Instant::now(),
+			},
+		)
+		.await;
+}
+
+/// Adds written bytes to the cached estimate. Invalidates the estimate once more than `MAX_DELTA` bytes have
+/// been written since it was cached.
+pub async fn record_write(actor_id: Id, bytes: usize) {
+	CACHE
+		.entry(actor_id)
+		.and_compute_with(|entry| async move {
+			let Some(entry) = entry else {
+				return Op::Nop;
+			};
+
+			let mut entry = entry.into_value();
+			entry.written += bytes;
+
+			if entry.written > MAX_DELTA {
+				Op::Remove
+			} else {
+				Op::Put(entry)
+			}
+		})
+		.await;
+}
+
+/// Drops the cached estimate, used after deletes so freed space is reflected immediately.
+pub async fn invalidate(actor_id: Id) {
+	CACHE.invalidate(&actor_id).await;
+}
@@ -33,6 +33,40 @@ pub enum Actor {
 		key_preview: String,
 	},
 
+	#[error(
+		"affinity_too_large",
+		"Too many actors in affinity rules.",
+		"Too many actors in affinity rules (max {max_actors})."
+	)]
+	AffinityTooLarge { max_actors: usize },
+
+	#[error(
+		"too_many_required_capabilities",
+		"Too many required capabilities.",
+		"Too many required capabilities (max {max_capabilities})."
+	)]
+	TooManyRequiredCapabilities { max_capabilities: usize },
+
+	#[error(
+		"batch_too_large",
+		"Too many items in batch.",
+		"Too many items in batch ({size} items, max {max_size})."
+	)]
+	BatchTooLarge { max_size: usize, size: usize },
+
+	#[error("too_many_tags", "Too many tags.", "Too many tags (max {max_tags}).")]
+	TooManyTags { max_tags: usize },
+
+	#[error(
+		"tag_too_large",
+		"Tag too large.",
+		"Tag too large (max {max_size} bytes): {tag_preview}"
+	)]
+	TagTooLarge {
+		max_size: usize,
+		tag_preview: String,
+	},
+
 	#[error(
 		"duplicate_key",
 		"Actor key already in use.",
@@ -40,6 +74,13 @@ pub enum Actor {
 	)]
 	DuplicateKey { key: String, existing_actor_id: Id },
 
+	#[error(
+		"key_not_held",
+		"Actor does not hold the key.",
+		"Actor '{actor_id}' does not hold the key '{key}'."
+	)]
+	KeyNotHeld { key: String, actor_id: Id },
+
 	#[error("destroyed_during_creation", "Actor was destroyed during creation.")]
 	DestroyedDuringCreation,
 
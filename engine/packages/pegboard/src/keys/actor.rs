@@ -1,7 +1,12 @@
 use anyhow::Result;
 use gas::prelude::*;
+use rivet_data::generated::{
+	pegboard_actor_required_capabilities_v1, pegboard_actor_response_cache_v1,
+};
 use rivet_runner_protocol as protocol;
+use rivet_types::actors::ActorState;
 use universaldb::prelude::*;
+use vbare::OwnedVersionedData;
 
 #[derive(Debug)]
 pub struct DataSubspaceKey;
@@ -768,3 +773,187 @@ impl<'de> TupleUnpack<'de> for GenerationKey {
 		Ok((input, v))
 	}
 }
+
+/// Capabilities a runner must advertise for the actor to be allocated to it. Only set if non-empty.
+#[derive(Debug)]
+pub struct RequiredCapabilitiesKey {
+	actor_id: Id,
+}
+
+impl RequiredCapabilitiesKey {
+	pub fn new(actor_id: Id) -> Self {
+		RequiredCapabilitiesKey { actor_id }
+	}
+}
+
+impl FormalKey for RequiredCapabilitiesKey {
+	type Value = Vec<String>;
+
+	fn deserialize(&self, raw: &[u8]) -> Result<Self::Value> {
+		let data =
+			rivet_data::versioned::ActorRequiredCapabilitiesKeyData::deserialize_with_embedded_version(raw)?;
+
+		Ok(data.capabilities)
+	}
+
+	fn serialize(&self, value: Self::Value) -> Result<Vec<u8>> {
+		rivet_data::versioned::ActorRequiredCapabilitiesKeyData::wrap_latest(
+			pegboard_actor_required_capabilities_v1::Data {
+				capabilities: value,
+			},
+		)
+		.serialize_with_embedded_version(rivet_data::PEGBOARD_ACTOR_REQUIRED_CAPABILITIES_VERSION)
+	}
+}
+
+impl TuplePack for RequiredCapabilitiesKey {
+	fn pack<W: std::io::Write>(
+		&self,
+		w: &mut W,
+		tuple_depth: TupleDepth,
+	) -> std::io::Result<VersionstampOffset> {
+		let t = (ACTOR, DATA, self.actor_id, CAPABILITIES);
+		t.pack(w, tuple_depth)
+	}
+}
+
+impl<'de> TupleUnpack<'de> for RequiredCapabilitiesKey {
+	fn unpack(input: &[u8], tuple_depth: TupleDepth) -> PackResult<(&[u8], Self)> {
+		let (input, (_, _, actor_id, _)) = <(usize, usize, Id, usize)>::unpack(input, tuple_depth)?;
+
+		let v = RequiredCapabilitiesKey { actor_id };
+
+		Ok((input, v))
+	}
+}
+
+/// State the actor is currently listed under in `ns::ActorByStateKey`.
+#[derive(Debug)]
+pub struct StateKey {
+	actor_id: Id,
+}
+
+impl StateKey {
+	pub fn new(actor_id: Id) -> Self {
+		StateKey { actor_id }
+	}
+}
+
+impl FormalKey for StateKey {
+	type Value = ActorState;
+
+	fn deserialize(&self, raw: &[u8]) -> Result<Self::Value> {
+		let [state] = raw else {
+			bail!("invalid actor state length: {}", raw.len());
+		};
+
+		ActorState::from_repr(*state as usize)
+			.ok_or_else(|| anyhow!("invalid actor state: {state}"))
+	}
+
+	fn serialize(&self, value: Self::Value) -> Result<Vec<u8>> {
+		Ok(vec![value as u8])
+	}
+}
+
+impl TuplePack for StateKey {
+	fn pack<W: std::io::Write>(
+		&self,
+		w: &mut W,
+		tuple_depth: TupleDepth,
+	) -> std::io::Result<VersionstampOffset> {
+		let t = (ACTOR, DATA, self.actor_id, STATE);
+		t.pack(w, tuple_depth)
+	}
+}
+
+impl<'de> TupleUnpack<'de> for StateKey {
+	fn unpack(input: &[u8], tuple_depth: TupleDepth) -> PackResult<(&[u8], Self)> {
+		let (input, (_, _, actor_id, _)) = <(usize, usize, Id, usize)>::unpack(input, tuple_depth)?;
+
+		let v = StateKey { actor_id };
+
+		Ok((input, v))
+	}
+}
+
+/// HTTP response cached by guard for a request to this actor. Expired entries are cleared when read
+/// and the whole subspace is cleared when the actor is destroyed.
+#[derive(Debug)]
+pub struct ResponseCacheKey {
+	actor_id: Id,
+	pub request_key: u64,
+}
+
+impl ResponseCacheKey {
+	pub fn new(actor_id: Id, request_key: u64) -> Self {
+		ResponseCacheKey {
+			actor_id,
+			request_key,
+		}
+	}
+
+	pub fn subspace(actor_id: Id) -> ResponseCacheSubspaceKey {
+		ResponseCacheSubspaceKey::new(actor_id)
+	}
+}
+
+impl FormalKey for ResponseCacheKey {
+	type Value = pegboard_actor_response_cache_v1::Data;
+
+	fn deserialize(&self, raw: &[u8]) -> Result<Self::Value> {
+		rivet_data::versioned::ActorResponseCacheKeyData::deserialize_with_embedded_version(raw)
+	}
+
+	fn serialize(&self, value: Self::Value) -> Result<Vec<u8>> {
+		rivet_data::versioned::ActorResponseCacheKeyData::wrap_latest(value)
+			.serialize_with_embedded_version(rivet_data::PEGBOARD_ACTOR_RESPONSE_CACHE_VERSION)
+	}
+}
+
+impl TuplePack for ResponseCacheKey {
+	fn pack<W: std::io::Write>(
+		&self,
+		w: &mut W,
+		tuple_depth: TupleDepth,
+	) -> std::io::Result<VersionstampOffset> {
+		let t = (ACTOR, RESPONSE_CACHE, self.actor_id, self.request_key);
+		t.pack(w, tuple_depth)
+	}
+}
+
+impl<'de> TupleUnpack<'de> for ResponseCacheKey {
+	fn unpack(input: &[u8], tuple_depth: TupleDepth) -> PackResult<(&[u8], Self)> {
+		let (input, (_, _, actor_id, request_key)) =
+			<(usize, usize, Id, u64)>::unpack(input, tuple_depth)?;
+
+		let v = ResponseCacheKey {
+			actor_id,
+			request_key,
+		};
+
+		Ok((input, v))
+	}
+}
+
+#[derive(Debug)]
+pub struct ResponseCacheSubspaceKey {
+	actor_id: Id,
+}
+
+impl ResponseCacheSubspaceKey {
+	pub fn new(actor_id: Id) -> Self {
+		ResponseCacheSubspaceKey { actor_id }
+	}
+}
+
+impl TuplePack for ResponseCacheSubspaceKey {
+	fn pack<W: std::io::Write>(
+		&self,
+		w: &mut W,
+		tuple_depth: TupleDepth,
+	) -> std::io::Result<VersionstampOffset> {
+		let t = (ACTOR, RESPONSE_CACHE, self.actor_id);
+		t.pack(w, tuple_depth)
+	}
+}
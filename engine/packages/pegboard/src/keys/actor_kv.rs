@@ -98,49 +98,19 @@ impl<'de> TupleUnpack<'de> for EntryBaseKey {
 	}
 }
 
-pub struct EntryValueChunkKey {
-	key: KeyWrapper,
-	pub chunk: usize,
-}
-
-impl EntryValueChunkKey {
-	pub fn new(key: KeyWrapper, chunk: usize) -> Self {
-		EntryValueChunkKey { key, chunk }
+universaldb::typed_key! {
+	pub struct EntryValueChunkKey {
+		key: KeyWrapper,
+		const DATA,
+		pub chunk: usize,
 	}
 }
 
-impl TuplePack for EntryValueChunkKey {
-	fn pack<W: std::io::Write>(
-		&self,
-		w: &mut W,
-		tuple_depth: TupleDepth,
-	) -> std::io::Result<VersionstampOffset> {
-		let t = (&self.key, DATA, self.chunk);
-		t.pack(w, tuple_depth)
-	}
-}
-
-impl<'de> TupleUnpack<'de> for EntryValueChunkKey {
-	fn unpack(input: &[u8], tuple_depth: TupleDepth) -> PackResult<(&[u8], Self)> {
-		let (input, (key, data, chunk)) = <(KeyWrapper, usize, usize)>::unpack(input, tuple_depth)?;
-		if data != DATA {
-			return Err(PackError::Message("expected DATA data".into()));
-		}
-
-		let v = EntryValueChunkKey { key, chunk };
-
-		Ok((input, v))
-	}
-}
-
-#[derive(Debug)]
-pub struct EntryMetadataKey {
-	pub key: KeyWrapper,
-}
-
-impl EntryMetadataKey {
-	pub fn new(key: KeyWrapper) -> Self {
-		EntryMetadataKey { key }
+universaldb::typed_key! {
+	#[derive(Debug)]
+	pub struct EntryMetadataKey {
+		pub key: KeyWrapper,
+		const METADATA,
 	}
 }
 
@@ -157,27 +127,3 @@ impl FormalKey for EntryMetadataKey {
 		serde_bare::to_vec(&value).map_err(Into::into)
 	}
 }
-
-impl TuplePack for EntryMetadataKey {
-	fn pack<W: std::io::Write>(
-		&self,
-		w: &mut W,
-		tuple_depth: TupleDepth,
-	) -> std::io::Result<VersionstampOffset> {
-		let t = (&self.key, METADATA);
-		t.pack(w, tuple_depth)
-	}
-}
-
-impl<'de> TupleUnpack<'de> for EntryMetadataKey {
-	fn unpack(input: &[u8], tuple_depth: TupleDepth) -> PackResult<(&[u8], Self)> {
-		let (input, (key, data)) = <(KeyWrapper, usize)>::unpack(input, tuple_depth)?;
-		if data != METADATA {
-			return Err(PackError::Message("expected METADATA data".into()));
-		}
-
-		let v = EntryMetadataKey { key };
-
-		Ok((input, v))
-	}
-}
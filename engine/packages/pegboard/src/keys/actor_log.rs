@@ -0,0 +1,61 @@
+use anyhow::Result;
+use gas::prelude::*;
+use rivet_data::generated::pegboard_actor_log_v1;
+use rivet_types::actors::ActorLogStream;
+use universaldb::prelude::*;
+use vbare::OwnedVersionedData;
+
+/// Stored log line, see `rivet_types::actors::ActorLog`.
+#[derive(Debug, Clone)]
+pub struct LogEntry {
+	pub ts: i64,
+	pub generation: u32,
+	pub stream: ActorLogStream,
+	pub message: String,
+}
+
+universaldb::typed_key! {
+	/// Line in the bounded ring of recent logs of an actor.
+	#[derive(Debug)]
+	pub struct LogKey {
+		const ACTOR,
+		const LOG,
+		pub actor_id: Id,
+		pub idx: u64,
+	}
+}
+
+impl FormalKey for LogKey {
+	type Value = LogEntry;
+
+	fn deserialize(&self, raw: &[u8]) -> Result<Self::Value> {
+		let data = rivet_data::versioned::ActorLogKeyData::deserialize_with_embedded_version(raw)?;
+
+		Ok(LogEntry {
+			ts: data.ts,
+			generation: data.generation,
+			stream: match data.stream {
+				pegboard_actor_log_v1::LogStream::Stdout => ActorLogStream::Stdout,
+				pegboard_actor_log_v1::LogStream::Stderr => ActorLogStream::Stderr,
+			},
+			message: data.message,
+		})
+	}
+
+	fn serialize(&self, value: Self::Value) -> Result<Vec<u8>> {
+		rivet_data::versioned::ActorLogKeyData::wrap_latest(pegboard_actor_log_v1::Data {
+			ts: value.ts,
+			generation: value.generation,
+			stream: match value.stream {
+				ActorLogStream::Stdout => pegboard_actor_log_v1::LogStream::Stdout,
+				ActorLogStream::Stderr => pegboard_actor_log_v1::LogStream::Stderr,
+			},
+			message: value.message,
+		})
+		.serialize_with_embedded_version(rivet_data::PEGBOARD_ACTOR_LOG_VERSION)
+	}
+}
+
+pub fn subspace(actor_id: Id) -> universaldb::utils::Subspace {
+	super::subspace().subspace(&(ACTOR, LOG, actor_id))
+}
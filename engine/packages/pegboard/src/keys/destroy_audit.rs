@@ -0,0 +1,131 @@
+use anyhow::Result;
+use gas::prelude::*;
+use rivet_data::generated::pegboard_actor_destroy_audit_v1;
+use universaldb::prelude::*;
+use vbare::OwnedVersionedData;
+
+use crate::workflows::actor::DestroyReason;
+
+/// Audit record of an actor destroy, written when the destroy starts.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DestroyAudit {
+	pub reason: DestroyReason,
+	pub destroy_ts: i64,
+	pub generation: u32,
+
+	// Final state of the actor when it was destroyed
+	pub runner_id: Option<Id>,
+	pub start_ts: Option<i64>,
+	pub connectable_ts: Option<i64>,
+	pub sleep_ts: Option<i64>,
+}
+
+universaldb::typed_key! {
+	#[derive(Debug)]
+	pub struct DestroyAuditKey {
+		const DESTROY_AUDIT,
+		const DATA,
+		pub actor_id: Id,
+	}
+}
+
+impl FormalKey for DestroyAuditKey {
+	type Value = DestroyAudit;
+
+	fn deserialize(&self, raw: &[u8]) -> Result<Self::Value> {
+		let data =
+			rivet_data::versioned::ActorDestroyAuditKeyData::deserialize_with_embedded_version(
+				raw,
+			)?;
+
+		Ok(DestroyAudit {
+			reason: match data.reason {
+				pegboard_actor_destroy_audit_v1::DestroyReason::Unknown => DestroyReason::Unknown,
+				pegboard_actor_destroy_audit_v1::DestroyReason::Requested => {
+					DestroyReason::Requested
+				}
+				pegboard_actor_destroy_audit_v1::DestroyReason::ValidationFailed => {
+					DestroyReason::ValidationFailed
+				}
+				pegboard_actor_destroy_audit_v1::DestroyReason::Exited => DestroyReason::Exited,
+				pegboard_actor_destroy_audit_v1::DestroyReason::Crashed => DestroyReason::Crashed,
+				pegboard_actor_destroy_audit_v1::DestroyReason::Lost => DestroyReason::Lost,
+				pegboard_actor_destroy_audit_v1::DestroyReason::GoingAway => {
+					DestroyReason::GoingAway
+				}
+			},
+			destroy_ts: data.destroy_ts,
+			generation: data.generation,
+			runner_id: data
+				.runner_id
+				.map(|runner_id| Id::from_slice(&runner_id))
+				.transpose()?,
+			start_ts: data.start_ts,
+			connectable_ts: data.connectable_ts,
+			sleep_ts: data.sleep_ts,
+		})
+	}
+
+	fn serialize(&self, value: Self::Value) -> Result<Vec<u8>> {
+		rivet_data::versioned::ActorDestroyAuditKeyData::wrap_latest(
+			pegboard_actor_destroy_audit_v1::Data {
+				reason: match value.reason {
+					DestroyReason::Unknown => {
+						pegboard_actor_destroy_audit_v1::DestroyReason::Unknown
+					}
+					DestroyReason::Requested => {
+						pegboard_actor_destroy_audit_v1::DestroyReason::Requested
+					}
+					DestroyReason::ValidationFailed => {
+						pegboard_actor_destroy_audit_v1::DestroyReason::ValidationFailed
+					}
+					DestroyReason::Exited => pegboard_actor_destroy_audit_v1::DestroyReason::Exited,
+					DestroyReason::Crashed => {
+						pegboard_actor_destroy_audit_v1::DestroyReason::Crashed
+					}
+					DestroyReason::Lost => pegboard_actor_destroy_audit_v1::DestroyReason::Lost,
+					DestroyReason::GoingAway => {
+						pegboard_actor_destroy_audit_v1::DestroyReason::GoingAway
+					}
+				},
+				destroy_ts: value.destroy_ts,
+				generation: value.generation,
+				runner_id: value.runner_id.map(|runner_id| runner_id.as_bytes()),
+				start_ts: value.start_ts,
+				connectable_ts: value.connectable_ts,
+				sleep_ts: value.sleep_ts,
+			},
+		)
+		.serialize_with_embedded_version(rivet_data::PEGBOARD_ACTOR_DESTROY_AUDIT_VERSION)
+	}
+}
+
+universaldb::typed_key! {
+	/// Index of audit records by destroy timestamp, used to clear records past their TTL.
+	#[derive(Debug)]
+	pub struct DestroyAuditByTsKey {
+		const DESTROY_AUDIT,
+		const BY_TS,
+		pub destroy_ts: i64,
+		pub actor_id: Id,
+	}
+}
+
+impl FormalKey for DestroyAuditByTsKey {
+	type Value = ();
+
+	fn deserialize(&self, _raw: &[u8]) -> Result<Self::Value> {
+		Ok(())
+	}
+
+	fn serialize(&self, _value: Self::Value) -> Result<Vec<u8>> {
+		Ok(Vec::new())
+	}
+}
+
+/// Key range of index entries for records destroyed before `destroy_ts`.
+pub fn by_ts_range_before(destroy_ts: i64) -> (Vec<u8>, Vec<u8>) {
+	let subspace = super::subspace().subspace(&(DESTROY_AUDIT, BY_TS));
+
+	(subspace.range().0, subspace.pack(&(destroy_ts,)))
+}
@@ -1,11 +1,14 @@
 pub mod actor;
 pub mod actor_kv;
+pub mod actor_log;
+pub mod destroy_audit;
 pub mod envoy;
 pub mod epoxy;
 pub mod hibernating_request;
 pub mod ns;
 pub mod runner;
 pub mod runner_config;
+pub mod webhook;
 
 pub fn subspace() -> universaldb::utils::Subspace {
 	rivet_types::keys::pegboard::subspace()
@@ -1,5 +1,6 @@
 use anyhow::Result;
 use gas::prelude::*;
+use rivet_types::actors::ActorState;
 use universaldb::prelude::*;
 use vbare::OwnedVersionedData;
 
@@ -712,6 +713,277 @@ impl TuplePack for ActorByKeySubspaceKey {
 	}
 }
 
+#[derive(Debug)]
+pub struct ActorByStateKey {
+	namespace_id: Id,
+	pub name: String,
+	pub state: ActorState,
+	pub create_ts: i64,
+	pub actor_id: Id,
+}
+
+impl ActorByStateKey {
+	pub fn new(
+		namespace_id: Id,
+		name: String,
+		state: ActorState,
+		create_ts: i64,
+		actor_id: Id,
+	) -> Self {
+		ActorByStateKey {
+			namespace_id,
+			name,
+			state,
+			create_ts,
+			actor_id,
+		}
+	}
+
+	pub fn subspace(namespace_id: Id, name: String, state: ActorState) -> ActorByStateSubspaceKey {
+		ActorByStateSubspaceKey::new(namespace_id, name, state)
+	}
+
+	pub fn subspace_with_create_ts(
+		namespace_id: Id,
+		name: String,
+		state: ActorState,
+		create_ts: i64,
+	) -> ActorByStateSubspaceKey {
+		ActorByStateSubspaceKey::new_with_create_ts(namespace_id, name, state, create_ts)
+	}
+}
+
+impl FormalKey for ActorByStateKey {
+	/// Workflow id.
+	type Value = Id;
+
+	fn deserialize(&self, raw: &[u8]) -> Result<Self::Value> {
+		Ok(Id::from_slice(raw)?)
+	}
+
+	fn serialize(&self, value: Self::Value) -> Result<Vec<u8>> {
+		Ok(value.as_bytes().to_vec())
+	}
+}
+
+impl TuplePack for ActorByStateKey {
+	fn pack<W: std::io::Write>(
+		&self,
+		w: &mut W,
+		tuple_depth: TupleDepth,
+	) -> std::io::Result<VersionstampOffset> {
+		let t = (
+			NAMESPACE,
+			self.namespace_id,
+			ACTOR,
+			BY_STATE,
+			&self.name,
+			self.state as usize,
+			self.create_ts,
+			self.actor_id,
+		);
+		t.pack(w, tuple_depth)
+	}
+}
+
+impl<'de> TupleUnpack<'de> for ActorByStateKey {
+	fn unpack(input: &[u8], tuple_depth: TupleDepth) -> PackResult<(&[u8], Self)> {
+		let (input, (_, namespace_id, _, data, name, state, create_ts, actor_id)) =
+			<(usize, Id, usize, usize, String, usize, i64, Id)>::unpack(input, tuple_depth)?;
+		if data != BY_STATE {
+			return Err(PackError::Message("expected BY_STATE data".into()));
+		}
+		let state = ActorState::from_repr(state).ok_or_else(|| {
+			PackError::Message(format!("invalid actor state `{state}` in key").into())
+		})?;
+
+		let v = ActorByStateKey {
+			namespace_id,
+			name,
+			state,
+			create_ts,
+			actor_id,
+		};
+
+		Ok((input, v))
+	}
+}
+
+pub struct ActorByStateSubspaceKey {
+	namespace_id: Id,
+	name: String,
+	state: ActorState,
+	create_ts: Option<i64>,
+}
+
+impl ActorByStateSubspaceKey {
+	pub fn new(namespace_id: Id, name: String, state: ActorState) -> Self {
+		ActorByStateSubspaceKey {
+			namespace_id,
+			name,
+			state,
+			create_ts: None,
+		}
+	}
+
+	pub fn new_with_create_ts(
+		namespace_id: Id,
+		name: String,
+		state: ActorState,
+		create_ts: i64,
+	) -> Self {
+		ActorByStateSubspaceKey {
+			namespace_id,
+			name,
+			state,
+			create_ts: Some(create_ts),
+		}
+	}
+}
+
+impl TuplePack for ActorByStateSubspaceKey {
+	fn pack<W: std::io::Write>(
+		&self,
+		w: &mut W,
+		tuple_depth: TupleDepth,
+	) -> std::io::Result<VersionstampOffset> {
+		let mut offset = VersionstampOffset::None { size: 0 };
+
+		let t = (
+			NAMESPACE,
+			self.namespace_id,
+			ACTOR,
+			BY_STATE,
+			&self.name,
+			self.state as usize,
+		);
+		offset += t.pack(w, tuple_depth)?;
+
+		if let Some(create_ts) = &self.create_ts {
+			offset += create_ts.pack(w, tuple_depth)?;
+		}
+
+		Ok(offset)
+	}
+}
+
+#[derive(Debug)]
+pub struct ActorByTagKey {
+	namespace_id: Id,
+	pub tag_key: String,
+	pub tag_value: String,
+	pub create_ts: i64,
+	pub actor_id: Id,
+}
+
+impl ActorByTagKey {
+	pub fn new(
+		namespace_id: Id,
+		tag_key: String,
+		tag_value: String,
+		create_ts: i64,
+		actor_id: Id,
+	) -> Self {
+		ActorByTagKey {
+			namespace_id,
+			tag_key,
+			tag_value,
+			create_ts,
+			actor_id,
+		}
+	}
+
+	pub fn subspace(namespace_id: Id, tag_key: String, tag_value: String) -> ActorByTagSubspaceKey {
+		ActorByTagSubspaceKey::new(namespace_id, tag_key, tag_value)
+	}
+}
+
+impl FormalKey for ActorByTagKey {
+	/// Workflow id.
+	type Value = Id;
+
+	fn deserialize(&self, raw: &[u8]) -> Result<Self::Value> {
+		Ok(Id::from_slice(raw)?)
+	}
+
+	fn serialize(&self, value: Self::Value) -> Result<Vec<u8>> {
+		Ok(value.as_bytes().to_vec())
+	}
+}
+
+impl TuplePack for ActorByTagKey {
+	fn pack<W: std::io::Write>(
+		&self,
+		w: &mut W,
+		tuple_depth: TupleDepth,
+	) -> std::io::Result<VersionstampOffset> {
+		let t = (
+			NAMESPACE,
+			self.namespace_id,
+			ACTOR,
+			TAG,
+			&self.tag_key,
+			&self.tag_value,
+			self.create_ts,
+			self.actor_id,
+		);
+		t.pack(w, tuple_depth)
+	}
+}
+
+impl<'de> TupleUnpack<'de> for ActorByTagKey {
+	fn unpack(input: &[u8], tuple_depth: TupleDepth) -> PackResult<(&[u8], Self)> {
+		let (input, (_, namespace_id, _, data, tag_key, tag_value, create_ts, actor_id)) =
+			<(usize, Id, usize, usize, String, String, i64, Id)>::unpack(input, tuple_depth)?;
+		if data != TAG {
+			return Err(PackError::Message("expected TAG data".into()));
+		}
+		let v = ActorByTagKey {
+			namespace_id,
+			tag_key,
+			tag_value,
+			create_ts,
+			actor_id,
+		};
+
+		Ok((input, v))
+	}
+}
+
+pub struct ActorByTagSubspaceKey {
+	namespace_id: Id,
+	tag_key: String,
+	tag_value: String,
+}
+
+impl ActorByTagSubspaceKey {
+	pub fn new(namespace_id: Id, tag_key: String, tag_value: String) -> Self {
+		ActorByTagSubspaceKey {
+			namespace_id,
+			tag_key,
+			tag_value,
+		}
+	}
+}
+
+impl TuplePack for ActorByTagSubspaceKey {
+	fn pack<W: std::io::Write>(
+		&self,
+		w: &mut W,
+		tuple_depth: TupleDepth,
+	) -> std::io::Result<VersionstampOffset> {
+		let t = (
+			NAMESPACE,
+			self.namespace_id,
+			ACTOR,
+			TAG,
+			&self.tag_key,
+			&self.tag_value,
+		);
+		t.pack(w, tuple_depth)
+	}
+}
+
 #[derive(Debug)]
 pub struct ActiveRunnerKey {
 	namespace_id: Id,
@@ -1493,6 +1765,71 @@ impl TuplePack for ActorSlotsSubspaceKey {
 	}
 }
 
+/// Next free reschedule slot of one shard of a runner pool, used to pace reschedules. Reschedules
+/// are spread across shards so the pool's slot is not a single contended key.
+#[derive(Debug)]
+pub struct RescheduleSlotKey {
+	pub namespace_id: Id,
+	pub runner_name_selector: String,
+	pub shard: u16,
+}
+
+impl RescheduleSlotKey {
+	pub fn new(namespace_id: Id, runner_name_selector: String, shard: u16) -> Self {
+		RescheduleSlotKey {
+			namespace_id,
+			runner_name_selector,
+			shard,
+		}
+	}
+}
+
+impl FormalKey for RescheduleSlotKey {
+	/// Timestamp.
+	type Value = i64;
+
+	fn deserialize(&self, raw: &[u8]) -> Result<Self::Value> {
+		Ok(i64::from_be_bytes(raw.try_into()?))
+	}
+
+	fn serialize(&self, value: Self::Value) -> Result<Vec<u8>> {
+		Ok(value.to_be_bytes().to_vec())
+	}
+}
+
+impl TuplePack for RescheduleSlotKey {
+	fn pack<W: std::io::Write>(
+		&self,
+		w: &mut W,
+		tuple_depth: TupleDepth,
+	) -> std::io::Result<VersionstampOffset> {
+		let t = (
+			NAMESPACE,
+			self.namespace_id,
+			ACTOR,
+			RESCHEDULE_SLOT,
+			&self.runner_name_selector,
+			self.shard,
+		);
+		t.pack(w, tuple_depth)
+	}
+}
+
+impl<'de> TupleUnpack<'de> for RescheduleSlotKey {
+	fn unpack(input: &[u8], tuple_depth: TupleDepth) -> PackResult<(&[u8], Self)> {
+		let (input, (_, namespace_id, _, _, runner_name_selector, shard)) =
+			<(usize, Id, usize, usize, String, u16)>::unpack(input, tuple_depth)?;
+
+		let v = RescheduleSlotKey {
+			namespace_id,
+			runner_name_selector,
+			shard,
+		};
+
+		Ok((input, v))
+	}
+}
+
 #[derive(Debug)]
 pub struct EnvoyLoadBalancerIdxKey {
 	pub namespace_id: Id,
@@ -2038,3 +2375,109 @@ impl TuplePack for ActiveEnvoyByNameSubspaceKey {
 		Ok(offset)
 	}
 }
+
+/// Set when the serverless autoscaler is paused for a namespace.
+#[derive(Debug)]
+pub struct ServerlessAutoscalerPausedKey {
+	namespace_id: Id,
+}
+
+impl ServerlessAutoscalerPausedKey {
+	pub fn new(namespace_id: Id) -> Self {
+		ServerlessAutoscalerPausedKey { namespace_id }
+	}
+}
+
+impl FormalKey for ServerlessAutoscalerPausedKey {
+	type Value = ();
+
+	fn deserialize(&self, _raw: &[u8]) -> Result<Self::Value> {
+		Ok(())
+	}
+
+	fn serialize(&self, _value: Self::Value) -> Result<Vec<u8>> {
+		Ok(Vec::new())
+	}
+}
+
+impl TuplePack for ServerlessAutoscalerPausedKey {
+	fn pack<W: std::io::Write>(
+		&self,
+		w: &mut W,
+		tuple_depth: TupleDepth,
+	) -> std::io::Result<VersionstampOffset> {
+		let t = (NAMESPACE, self.namespace_id, SERVERLESS, PAUSED);
+		t.pack(w, tuple_depth)
+	}
+}
+
+impl<'de> TupleUnpack<'de> for ServerlessAutoscalerPausedKey {
+	fn unpack(input: &[u8], tuple_depth: TupleDepth) -> PackResult<(&[u8], Self)> {
+		let (input, (_, namespace_id, _, _)) =
+			<(usize, Id, usize, usize)>::unpack(input, tuple_depth)?;
+
+		let v = ServerlessAutoscalerPausedKey { namespace_id };
+
+		Ok((input, v))
+	}
+}
+
+/// Set while a serverless runner pool has more signals pending than it can drain in one tick. The
+/// pool ticks again on its own while this is set, so publishers can skip sending bumps.
+#[derive(Debug)]
+pub struct ServerlessPoolSignalBacklogKey {
+	namespace_id: Id,
+	runner_name: String,
+}
+
+impl ServerlessPoolSignalBacklogKey {
+	pub fn new(namespace_id: Id, runner_name: String) -> Self {
+		ServerlessPoolSignalBacklogKey {
+			namespace_id,
+			runner_name,
+		}
+	}
+}
+
+impl FormalKey for ServerlessPoolSignalBacklogKey {
+	type Value = ();
+
+	fn deserialize(&self, _raw: &[u8]) -> Result<Self::Value> {
+		Ok(())
+	}
+
+	fn serialize(&self, _value: Self::Value) -> Result<Vec<u8>> {
+		Ok(Vec::new())
+	}
+}
+
+impl TuplePack for ServerlessPoolSignalBacklogKey {
+	fn pack<W: std::io::Write>(
+		&self,
+		w: &mut W,
+		tuple_depth: TupleDepth,
+	) -> std::io::Result<VersionstampOffset> {
+		let t = (
+			NAMESPACE,
+			self.namespace_id,
+			SERVERLESS,
+			SIGNAL_BACKLOG,
+			&self.runner_name,
+		);
+		t.pack(w, tuple_depth)
+	}
+}
+
+impl<'de> TupleUnpack<'de> for ServerlessPoolSignalBacklogKey {
+	fn unpack(input: &[u8], tuple_depth: TupleDepth) -> PackResult<(&[u8], Self)> {
+		let (input, (_, namespace_id, _, _, runner_name)) =
+			<(usize, Id, usize, usize, String)>::unpack(input, tuple_depth)?;
+
+		let v = ServerlessPoolSignalBacklogKey {
+			namespace_id,
+			runner_name,
+		};
+
+		Ok((input, v))
+	}
+}
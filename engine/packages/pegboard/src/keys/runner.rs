@@ -2,6 +2,7 @@ use std::result::Result::Ok;
 
 use anyhow::*;
 use gas::prelude::*;
+use rivet_data::generated::pegboard_runner_capabilities_v1;
 use universaldb::prelude::*;
 use vbare::OwnedVersionedData;
 
@@ -551,6 +552,62 @@ impl<'de> TupleUnpack<'de> for VersionKey {
 	}
 }
 
+/// Capabilities advertised by the runner when it connected.
+#[derive(Debug)]
+pub struct CapabilitiesKey {
+	runner_id: Id,
+}
+
+impl CapabilitiesKey {
+	pub fn new(runner_id: Id) -> Self {
+		CapabilitiesKey { runner_id }
+	}
+}
+
+impl FormalKey for CapabilitiesKey {
+	type Value = Vec<String>;
+
+	fn deserialize(&self, raw: &[u8]) -> Result<Self::Value> {
+		let data =
+			rivet_data::versioned::RunnerCapabilitiesKeyData::deserialize_with_embedded_version(
+				raw,
+			)?;
+
+		Ok(data.capabilities)
+	}
+
+	fn serialize(&self, value: Self::Value) -> Result<Vec<u8>> {
+		rivet_data::versioned::RunnerCapabilitiesKeyData::wrap_latest(
+			pegboard_runner_capabilities_v1::Data {
+				capabilities: value,
+			},
+		)
+		.serialize_with_embedded_version(rivet_data::PEGBOARD_RUNNER_CAPABILITIES_VERSION)
+	}
+}
+
+impl TuplePack for CapabilitiesKey {
+	fn pack<W: std::io::Write>(
+		&self,
+		w: &mut W,
+		tuple_depth: TupleDepth,
+	) -> std::io::Result<VersionstampOffset> {
+		let t = (RUNNER, DATA, self.runner_id, CAPABILITIES);
+		t.pack(w, tuple_depth)
+	}
+}
+
+impl<'de> TupleUnpack<'de> for CapabilitiesKey {
+	fn unpack(input: &[u8], tuple_depth: TupleDepth) -> PackResult<(&[u8], Self)> {
+		let (input, (_, _, runner_id, _)) =
+			<(usize, usize, Id, usize)>::unpack(input, tuple_depth)?;
+
+		let v = CapabilitiesKey { runner_id };
+
+		Ok((input, v))
+	}
+}
+
 #[derive(Debug)]
 pub struct StopTsKey {
 	runner_id: Id,
@@ -0,0 +1,117 @@
+use anyhow::Result;
+use gas::prelude::*;
+use rivet_data::generated::{pegboard_webhook_config_v1, pegboard_webhook_dead_letter_v1};
+use universaldb::prelude::*;
+use vbare::OwnedVersionedData;
+
+/// Webhook notified of actor lifecycle transitions in a namespace.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct WebhookConfig {
+	pub url: String,
+	/// Used to sign payloads with HMAC-SHA256.
+	pub secret: String,
+}
+
+universaldb::typed_key! {
+	#[derive(Debug)]
+	pub struct WebhookConfigKey {
+		const WEBHOOK,
+		const DATA,
+		pub namespace_id: Id,
+	}
+}
+
+impl FormalKey for WebhookConfigKey {
+	type Value = WebhookConfig;
+
+	fn deserialize(&self, raw: &[u8]) -> Result<Self::Value> {
+		let data =
+			rivet_data::versioned::WebhookConfigKeyData::deserialize_with_embedded_version(raw)?;
+
+		Ok(WebhookConfig {
+			url: data.url,
+			secret: data.secret,
+		})
+	}
+
+	fn serialize(&self, value: Self::Value) -> Result<Vec<u8>> {
+		rivet_data::versioned::WebhookConfigKeyData::wrap_latest(pegboard_webhook_config_v1::Data {
+			url: value.url,
+			secret: value.secret,
+		})
+		.serialize_with_embedded_version(rivet_data::PEGBOARD_WEBHOOK_CONFIG_VERSION)
+	}
+}
+
+/// Notification that could not be delivered after all attempts.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DeadLetter {
+	pub notification_id: Id,
+	pub url: String,
+	/// JSON payload that was sent.
+	pub body: String,
+	pub attempts: usize,
+	/// Error of the last attempt.
+	pub error: String,
+	pub ts: i64,
+}
+
+universaldb::typed_key! {
+	#[derive(Debug)]
+	pub struct WebhookDeadLetterKey {
+		const WEBHOOK,
+		const DEAD_LETTER,
+		pub namespace_id: Id,
+		pub ts: i64,
+		pub notification_id: Id,
+	}
+}
+
+impl FormalKey for WebhookDeadLetterKey {
+	type Value = DeadLetter;
+
+	fn deserialize(&self, raw: &[u8]) -> Result<Self::Value> {
+		let data =
+			rivet_data::versioned::WebhookDeadLetterKeyData::deserialize_with_embedded_version(
+				raw,
+			)?;
+
+		Ok(DeadLetter {
+			notification_id: Id::from_slice(&data.notification_id)?,
+			url: data.url,
+			body: data.body,
+			attempts: data.attempts.try_into()?,
+			error: data.error,
+			ts: data.ts,
+		})
+	}
+
+	fn serialize(&self, value: Self::Value) -> Result<Vec<u8>> {
+		rivet_data::versioned::WebhookDeadLetterKeyData::wrap_latest(
+			pegboard_webhook_dead_letter_v1::Data {
+				notification_id: value.notification_id.as_bytes(),
+				url: value.url,
+				body: value.body,
+				attempts: value.attempts.try_into()?,
+				error: value.error,
+				ts: value.ts,
+			},
+		)
+		.serialize_with_embedded_version(rivet_data::PEGBOARD_WEBHOOK_DEAD_LETTER_VERSION)
+	}
+}
+
+pub fn dead_letter_subspace(namespace_id: Id) -> universaldb::utils::Subspace {
+	super::subspace().subspace(&(WEBHOOK, DEAD_LETTER, namespace_id))
+}
+
+/// Range of the dead letters of a namespace inserted before `ts`.
+pub fn dead_letter_range_before(namespace_id: Id, ts: i64) -> (Vec<u8>, Vec<u8>) {
+	let begin = dead_letter_subspace(namespace_id).range().0;
+	let end = super::subspace()
+		.subspace(&(WEBHOOK, DEAD_LETTER, namespace_id, ts))
+		.range()
+		.0;
+
+	(begin, end)
+}
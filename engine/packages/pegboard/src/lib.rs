@@ -32,6 +32,8 @@ pub fn registry() -> WorkflowResult<Registry> {
 	registry.register_workflow::<serverless::backfill::Workflow>()?;
 	registry.register_workflow::<metrics_aggregator::Workflow>()?;
 	registry.register_workflow::<actor_runner_name_selector_backfill::Workflow>()?;
+	registry.register_workflow::<actor_state_idx_backfill::Workflow>()?;
+	registry.register_workflow::<webhook::Workflow>()?;
 
 	Ok(registry)
 }
@@ -15,6 +15,13 @@ lazy_static::lazy_static! {
 		*REGISTRY
 	).unwrap();
 
+	pub static ref ACTOR_STATE: IntGaugeVec = register_int_gauge_vec_with_registry!(
+		"pegboard_actor_state",
+		"Total actors per state (running, sleeping, stopping).",
+		&["namespace_id", "state"],
+		*REGISTRY
+	).unwrap();
+
 	pub static ref SERVERLESS_DESIRED_SLOTS: IntGaugeVec = register_int_gauge_vec_with_registry!(
 		"pegboard_serverless_desired_slots",
 		"Total amount of desired slots for serverless runners.",
@@ -22,6 +29,41 @@ lazy_static::lazy_static! {
 		*REGISTRY
 	).unwrap();
 
+	pub static ref SERVERLESS_DESIRED_RUNNERS: IntGaugeVec = register_int_gauge_vec_with_registry!(
+		"pegboard_serverless_desired_runners",
+		"Desired amount of serverless runners per runner pool.",
+		&["namespace_id", "runner_name"],
+		*REGISTRY
+	).unwrap();
+
+	pub static ref SERVERLESS_POOL_SIGNAL_BACKLOG: IntGaugeVec = register_int_gauge_vec_with_registry!(
+		"pegboard_serverless_pool_signal_backlog",
+		"Amount of signals a serverless runner pool drained in its last tick.",
+		&["namespace_id", "runner_name"],
+		*REGISTRY
+	).unwrap();
+
+	pub static ref SERVERLESS_POOL_BUMPS_COALESCED_TOTAL: IntCounterVec = register_int_counter_vec_with_registry!(
+		"pegboard_serverless_pool_bumps_coalesced_total",
+		"Count of serverless runner pool bumps that were folded into another bump's tick.",
+		&["namespace_id", "runner_name"],
+		*REGISTRY
+	).unwrap();
+
+	pub static ref SERVERLESS_POOL_BACKLOG_SATURATED_TOTAL: IntCounterVec = register_int_counter_vec_with_registry!(
+		"pegboard_serverless_pool_backlog_saturated_total",
+		"Count of serverless runner pool ticks that left signals pending because the drain limit was reached.",
+		&["namespace_id", "runner_name"],
+		*REGISTRY
+	).unwrap();
+
+	pub static ref ACTOR_UNROUTED_SELECTOR_TOTAL: IntCounterVec = register_int_counter_vec_with_registry!(
+		"pegboard_actor_unrouted_selector_total",
+		"Count of actor allocation attempts whose runner name selector does not match any runner config.",
+		&["namespace_id", "runner_name"],
+		*REGISTRY
+	).unwrap();
+
 	pub static ref ACTOR_ALLOCATE_DURATION: HistogramVec = register_histogram_vec_with_registry!(
 		"pegboard_actor_allocate_duration",
 		"Total duration to allocate an actor.",
@@ -66,6 +108,20 @@ lazy_static::lazy_static! {
 		*REGISTRY
 	).unwrap();
 
+	pub static ref SERVERLESS_CIRCUIT_OPEN: IntGaugeVec = register_int_gauge_vec_with_registry!(
+		"pegboard_serverless_circuit_open",
+		"Whether the circuit breaker of a serverless runner pool is open.",
+		&["namespace_id", "runner_name"],
+		*REGISTRY
+	).unwrap();
+
+	pub static ref SERVERLESS_CIRCUIT_OPENED_TOTAL: IntCounterVec = register_int_counter_vec_with_registry!(
+		"pegboard_serverless_circuit_opened_total",
+		"Count of times the circuit breaker of a serverless runner pool opened.",
+		&["namespace_id", "runner_name"],
+		*REGISTRY
+	).unwrap();
+
 	pub static ref SQLITE_MIGRATION_ATTEMPTS_TOTAL: IntCounter = register_int_counter_with_registry!(
 		"pegboard_sqlite_migration_attempts_total",
 		"Total number of sqlite v1 to v2 migration attempts.",
@@ -205,4 +261,17 @@ lazy_static::lazy_static! {
 		&["namespace_id", "pool_name", "strategy"],
 		*REGISTRY
 	).unwrap();
+
+	pub static ref ACTOR_LOG_DEMUXER_COUNT: IntGauge = register_int_gauge_with_registry!(
+		"pegboard_actor_log_demuxer_count",
+		"Number of active actor log demultiplexers.",
+		*REGISTRY
+	).unwrap();
+
+	pub static ref ACTOR_LOG_LINES_DROPPED_TOTAL: IntCounterVec = register_int_counter_vec_with_registry!(
+		"pegboard_actor_log_lines_dropped_total",
+		"Count of actor log lines dropped before being stored.",
+		&["reason"],
+		*REGISTRY
+	).unwrap();
 }
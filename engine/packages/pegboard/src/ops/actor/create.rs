@@ -1,7 +1,9 @@
+use std::collections::HashMap;
+
 use anyhow::{Context, Result};
 use gas::prelude::*;
 use rivet_api_util::{Method, request_remote_datacenter};
-use rivet_types::actors::{Actor, CrashPolicy};
+use rivet_types::actors::{Actor, ActorAffinity, CrashPolicy};
 
 #[derive(Debug)]
 pub struct Input {
@@ -10,7 +12,12 @@ pub struct Input {
 	pub name: String,
 	pub key: Option<String>,
 	pub runner_name_selector: String,
-	pub crash_policy: CrashPolicy,
+	/// Falls back to the namespace's default crash policy if not set.
+	pub crash_policy: Option<CrashPolicy>,
+	pub affinity: Option<ActorAffinity>,
+	pub required_capabilities: Vec<String>,
+	/// Free-form tags used to group and filter actors. See `ops::actor::list_for_tags`.
+	pub tags: HashMap<String, String>,
 	pub input: Option<String>,
 	/// If true, will handle ForwardToDatacenter errors by forwarding the request to the correct datacenter.
 	/// Used by api-public. api-peer should set this to false.
@@ -65,6 +72,7 @@ pub async fn pegboard_actor_create(ctx: &OperationCtx, input: &Input) -> Result<
 			key: input.key.clone(),
 			namespace_id: input.namespace_id,
 			input: input.input.clone(),
+			tags: input.tags.clone(),
 			from_v1: false,
 		})
 		.tag("actor_id", input.actor_id)
@@ -93,7 +101,10 @@ pub async fn pegboard_actor_create(ctx: &OperationCtx, input: &Input) -> Result<
 							input.key.clone(),
 							input.runner_name_selector.clone(),
 							input.input.clone(),
-						input.crash_policy
+						input.crash_policy,
+						input.affinity.clone(),
+						input.required_capabilities.clone(),
+						input.tags.clone(),
 						).await;
 					}
 				}
@@ -115,6 +126,9 @@ pub async fn pegboard_actor_create(ctx: &OperationCtx, input: &Input) -> Result<
 			key: input.key.clone(),
 			namespace_id: input.namespace_id,
 			crash_policy: input.crash_policy,
+			affinity: input.affinity.clone(),
+			required_capabilities: input.required_capabilities.clone(),
+			tags: input.tags.clone(),
 			input: input.input.clone(),
 		})
 		.tag("actor_id", input.actor_id)
@@ -143,7 +157,10 @@ pub async fn pegboard_actor_create(ctx: &OperationCtx, input: &Input) -> Result<
 							input.key.clone(),
 							input.runner_name_selector.clone(),
 							input.input.clone(),
-						input.crash_policy
+						input.crash_policy,
+						input.affinity.clone(),
+						input.required_capabilities.clone(),
+						input.tags.clone(),
 						).await;
 					}
 				}
@@ -184,7 +201,10 @@ async fn forward_to_datacenter(
 	key: Option<String>,
 	runner_name_selector: String,
 	input: Option<String>,
-	crash_policy: CrashPolicy,
+	crash_policy: Option<CrashPolicy>,
+	affinity: Option<ActorAffinity>,
+	required_capabilities: Vec<String>,
+	tags: HashMap<String, String>,
 ) -> Result<Output> {
 	// Get the datacenter configuration
 	let _target_dc = ctx
@@ -218,6 +238,9 @@ async fn forward_to_datacenter(
 			input,
 			runner_name_selector,
 			crash_policy,
+			affinity,
+			required_capabilities,
+			tags,
 		}),
 	)
 	.await?;
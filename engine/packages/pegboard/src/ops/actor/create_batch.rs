@@ -0,0 +1,82 @@
+use std::collections::{HashMap, HashSet};
+
+use futures_util::StreamExt;
+use gas::prelude::*;
+use rivet_types::actors::{Actor, ActorAffinity, CrashPolicy};
+
+#[derive(Debug)]
+pub struct Input {
+	pub namespace_id: Id,
+	pub actors: Vec<BatchActor>,
+}
+
+#[derive(Debug)]
+pub struct BatchActor {
+	pub actor_id: Id,
+	pub name: String,
+	pub key: Option<String>,
+	pub runner_name_selector: String,
+	/// Falls back to the namespace's default crash policy if not set.
+	pub crash_policy: Option<CrashPolicy>,
+	pub affinity: Option<ActorAffinity>,
+	pub required_capabilities: Vec<String>,
+	pub tags: HashMap<String, String>,
+	pub input: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct Output {
+	/// Same order as `actors`. A failed actor does not affect the rest of the batch.
+	pub results: Vec<Result<Actor>>,
+}
+
+/// Creates many actors in one call.
+///
+/// Every actor still runs its own workflow, which reserves the key through Epoxy before writing the
+/// namespace indexes, so those writes cannot share a transaction. This reads the runner configs of
+/// the whole batch in one transaction up front and bounds how many workflows are in flight at once.
+#[operation]
+pub async fn pegboard_actor_create_batch(ctx: &OperationCtx, input: &Input) -> Result<Output> {
+	crate::utils::validate_create_batch_size(ctx.config(), input.actors.len())?;
+
+	// Populates the runner config cache read by each actor's create
+	let runners = input
+		.actors
+		.iter()
+		.map(|actor| (input.namespace_id, actor.runner_name_selector.clone()))
+		.collect::<HashSet<_>>()
+		.into_iter()
+		.collect();
+	ctx.op(crate::ops::runner_config::get::Input {
+		runners,
+		bypass_cache: false,
+	})
+	.await?;
+
+	let results = futures_util::stream::iter(&input.actors)
+		.map(|actor| async move {
+			let res = ctx
+				.op(super::create::Input {
+					actor_id: actor.actor_id,
+					namespace_id: input.namespace_id,
+					name: actor.name.clone(),
+					key: actor.key.clone(),
+					runner_name_selector: actor.runner_name_selector.clone(),
+					crash_policy: actor.crash_policy,
+					affinity: actor.affinity.clone(),
+					required_capabilities: actor.required_capabilities.clone(),
+					tags: actor.tags.clone(),
+					input: actor.input.clone(),
+					forward_request: true,
+					datacenter_name: None,
+				})
+				.await?;
+
+			Ok::<_, anyhow::Error>(res.actor)
+		})
+		.buffered(ctx.config().pegboard().create_batch_concurrency())
+		.collect()
+		.await;
+
+	Ok(Output { results })
+}
@@ -0,0 +1,242 @@
+use futures_util::{StreamExt, TryStreamExt};
+use gas::prelude::*;
+use universaldb::options::StreamingMode;
+use universaldb::utils::IsolationLevel::*;
+
+use crate::keys;
+
+#[derive(Debug)]
+pub struct Input {
+	pub namespace_id: Id,
+	pub selector: Selector,
+}
+
+#[derive(Debug)]
+pub enum Selector {
+	ActorIds(Vec<Id>),
+	/// All active actors in this datacenter with the given name. If `key_prefix` is set, only actors
+	/// with a key starting with it.
+	Filter {
+		name: String,
+		key_prefix: Option<String>,
+	},
+}
+
+#[derive(Debug)]
+pub struct Output {
+	/// For `Selector::ActorIds`, same order as the input. A failed actor does not affect the rest of
+	/// the batch.
+	pub results: Vec<(Id, Result<()>)>,
+	/// More actors match the filter than were destroyed in this batch. Always false for
+	/// `Selector::ActorIds`.
+	pub has_more: bool,
+}
+
+/// Destroys many actors in this datacenter in one call.
+///
+/// Each actor is sent a destroy signal and waited on until it is destroyed, the same as a single
+/// actor delete. At most `pegboard.destroy_batch_concurrency` actors are destroyed at once.
+///
+/// A filter destroys at most `pegboard.max_destroy_batch_size` actors per call. Call it again while
+/// `has_more` is set to continue.
+#[operation]
+pub async fn pegboard_actor_destroy_batch(ctx: &OperationCtx, input: &Input) -> Result<Output> {
+	let max_size = ctx.config().pegboard().max_destroy_batch_size();
+
+	// Each target is `Ok(true)` if the actor still has to be destroyed
+	let (targets, has_more) = match &input.selector {
+		Selector::ActorIds(actor_ids) => {
+			crate::utils::validate_destroy_batch_size(ctx.config(), actor_ids.len())?;
+
+			let actors = ctx
+				.op(super::get::Input {
+					actor_ids: actor_ids.clone(),
+					fetch_error: false,
+				})
+				.await?
+				.actors;
+
+			let targets = actor_ids
+				.iter()
+				.map(|actor_id| {
+					let actor = actors
+						.iter()
+						.find(|actor| actor.actor_id == *actor_id)
+						.filter(|actor| actor.namespace_id == input.namespace_id);
+
+					match actor {
+						// Already destroyed: succeed idempotently
+						Some(actor) if actor.destroy_ts.is_some() => (*actor_id, Ok(false)),
+						Some(_) => (*actor_id, Ok(true)),
+						None => (*actor_id, Err(crate::errors::Actor::NotFound.build())),
+					}
+				})
+				.collect::<Vec<_>>();
+
+			(targets, false)
+		}
+		Selector::Filter { name, key_prefix } => {
+			let mut actor_ids = find_actors(
+				ctx,
+				input.namespace_id,
+				name,
+				key_prefix.as_deref(),
+				max_size + 1,
+			)
+			.await?;
+			let has_more = actor_ids.len() > max_size;
+			actor_ids.truncate(max_size);
+
+			tracing::debug!(
+				namespace_id=?input.namespace_id,
+				%name,
+				?key_prefix,
+				count=actor_ids.len(),
+				has_more,
+				"destroying batch of actors"
+			);
+
+			let targets = actor_ids
+				.into_iter()
+				.map(|actor_id| (actor_id, Ok(true)))
+				.collect();
+
+			(targets, has_more)
+		}
+	};
+
+	let results = futures_util::stream::iter(targets)
+		.map(|(actor_id, target)| async move {
+			let res = match target {
+				Ok(true) => destroy_actor(ctx, actor_id).await,
+				Ok(false) => Ok(()),
+				Err(err) => Err(err),
+			};
+
+			(actor_id, res)
+		})
+		.buffered(ctx.config().pegboard().destroy_batch_concurrency())
+		.collect::<Vec<_>>()
+		.await;
+
+	Ok(Output { results, has_more })
+}
+
+/// Reads up to `limit` active actors matching the filter, oldest first.
+async fn find_actors(
+	ctx: &OperationCtx,
+	namespace_id: Id,
+	name: &str,
+	key_prefix: Option<&str>,
+	limit: usize,
+) -> Result<Vec<Id>> {
+	ctx.udb()?
+		.txn(
+			"pegboard_actor_destroy_batch_find_actors",
+			|tx| async move {
+				let tx = tx.with_subspace(keys::subspace());
+				let mut actor_ids = Vec::new();
+
+				if let Some(key_prefix) = key_prefix {
+					// Strip the string terminator from the packed key so the range covers every key
+					// starting with the prefix
+					let mut start = keys::subspace().pack(&keys::ns::ActorByKeyKey::subspace(
+						namespace_id,
+						name.to_string(),
+						key_prefix.to_string(),
+					));
+					start.pop();
+					let mut end = start.clone();
+					end.push(0xFF);
+
+					let mut stream = tx.get_ranges_keyvalues(
+						universaldb::RangeOption {
+							mode: StreamingMode::Iterator,
+							..(start, end).into()
+						},
+						// NOTE: Does not have to be serializable, stale actors are already destroyed
+						Snapshot,
+					);
+
+					while let Some(entry) = stream.try_next().await? {
+						let (idx_key, data) = tx.read_entry::<keys::ns::ActorByKeyKey>(&entry)?;
+
+						if !data.is_destroyed {
+							actor_ids.push(idx_key.actor_id);
+
+							if actor_ids.len() >= limit {
+								break;
+							}
+						}
+					}
+				} else {
+					let actor_subspace = keys::subspace().subspace(
+						&keys::ns::ActiveActorKey::subspace(namespace_id, name.to_string()),
+					);
+
+					let mut stream = tx.get_ranges_keyvalues(
+						universaldb::RangeOption {
+							mode: StreamingMode::Iterator,
+							..(&actor_subspace).into()
+						},
+						// NOTE: Does not have to be serializable, stale actors are already destroyed
+						Snapshot,
+					);
+
+					while let Some(entry) = stream.try_next().await? {
+						let (idx_key, _) = tx.read_entry::<keys::ns::ActiveActorKey>(&entry)?;
+
+						actor_ids.push(idx_key.actor_id);
+
+						if actor_ids.len() >= limit {
+							break;
+						}
+					}
+				}
+
+				Ok(actor_ids)
+			},
+		)
+		.custom_instrument(tracing::info_span!("actor_destroy_batch_find_actors_tx"))
+		.await
+}
+
+/// Sends the destroy signal to the actor's workflow and waits until it is destroyed.
+async fn destroy_actor(ctx: &OperationCtx, actor_id: Id) -> Result<()> {
+	// Subscribe before sending the signal
+	let (mut destroy_sub, mut destroy_sub2) = tokio::try_join!(
+		ctx.subscribe::<crate::workflows::actor::DestroyComplete>(("actor_id", actor_id)),
+		ctx.subscribe::<crate::workflows::actor2::DestroyComplete>(("actor_id", actor_id)),
+	)?;
+
+	// Try actor2 first, then fallback to actor
+	let res = ctx
+		.signal(crate::workflows::actor2::Destroy {})
+		.to_workflow::<crate::workflows::actor2::Workflow>()
+		.tag("actor_id", actor_id)
+		.graceful_not_found()
+		.send()
+		.await?;
+	if res.is_none() {
+		let res = ctx
+			.signal(crate::workflows::actor::Destroy {})
+			.to_workflow::<crate::workflows::actor::Workflow>()
+			.tag("actor_id", actor_id)
+			.graceful_not_found()
+			.send()
+			.await?;
+
+		if res.is_none() {
+			tracing::warn!(
+				?actor_id,
+				"actor workflow not found, likely already stopped"
+			);
+		} else {
+			destroy_sub.next().await?;
+		}
+	} else {
+		destroy_sub2.next().await?;
+	}
+
+	Ok(())
+}
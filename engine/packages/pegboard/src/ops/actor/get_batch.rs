@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+
+use gas::prelude::*;
+use rivet_types::actors::Actor;
+
+/// Max actors read per transaction.
+const CHUNK_SIZE: usize = 1024;
+
+#[derive(Debug)]
+pub struct Input {
+	pub actor_ids: Vec<Id>,
+	pub fetch_error: bool,
+}
+
+#[derive(Debug)]
+pub struct Output {
+	/// Same order as `actor_ids`. None if the actor does not exist.
+	pub actors: Vec<Option<Actor>>,
+}
+
+/// Same as `ops::actor::get` but keeps the order of the input and reports missing actors.
+#[operation]
+pub async fn pegboard_actor_get_batch(ctx: &OperationCtx, input: &Input) -> Result<Output> {
+	crate::utils::validate_batch_size(ctx.config(), input.actor_ids.len())?;
+
+	let mut actors_by_id = HashMap::with_capacity(input.actor_ids.len());
+
+	for chunk in input.actor_ids.chunks(CHUNK_SIZE) {
+		let res = ctx
+			.op(super::get::Input {
+				actor_ids: chunk.to_vec(),
+				fetch_error: input.fetch_error,
+			})
+			.await?;
+
+		actors_by_id.extend(res.actors.into_iter().map(|actor| (actor.actor_id, actor)));
+	}
+
+	Ok(Output {
+		actors: input
+			.actor_ids
+			.iter()
+			.map(|actor_id| actors_by_id.get(actor_id).cloned())
+			.collect(),
+	})
+}
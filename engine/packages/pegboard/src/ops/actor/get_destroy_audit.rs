@@ -0,0 +1,36 @@
+use gas::prelude::*;
+use universaldb::utils::IsolationLevel::*;
+
+use crate::keys;
+
+#[derive(Debug)]
+pub struct Input {
+	pub actor_id: Id,
+}
+
+/// Returns the audit record of an actor's destroy. None if the actor was not destroyed or the record
+/// expired.
+#[operation]
+pub async fn pegboard_actor_get_destroy_audit(
+	ctx: &OperationCtx,
+	input: &Input,
+) -> Result<Option<keys::destroy_audit::DestroyAudit>> {
+	let expire_ts = util::timestamp::now() - ctx.config().pegboard().actor_destroy_audit_ttl();
+
+	let audit = ctx
+		.udb()?
+		.txn("pegboard_actor_get_destroy_audit", |tx| async move {
+			let tx = tx.with_subspace(keys::subspace());
+
+			tx.read_opt(
+				&keys::destroy_audit::DestroyAuditKey::new(input.actor_id),
+				Serializable,
+			)
+			.await
+		})
+		.custom_instrument(tracing::info_span!("actor_get_destroy_audit_tx"))
+		.await?;
+
+	// Expired records are cleared lazily by later destroys
+	Ok(audit.filter(|audit| audit.destroy_ts >= expire_ts))
+}
@@ -44,7 +44,10 @@ pub async fn pegboard_actor_get_for_key(ctx: &OperationCtx, input: &Input) -> Re
 				name: input.name.clone(),
 				key: Some(input.key.clone()),
 				include_destroyed: false,
+				state: None,
 				created_before: None,
+				created_after: None,
+				cursor: None,
 				limit: 1,
 				fetch_error: input.fetch_error,
 			})
@@ -0,0 +1,40 @@
+use gas::prelude::*;
+
+#[derive(Debug)]
+pub struct Input {
+	pub namespace_id: Id,
+	pub name: String,
+	pub key: String,
+	/// If provided, limits fanout to only enabled dcs.
+	pub pool_name: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct Output {
+	/// Label of the datacenter holding the reservation, if the key is reserved.
+	pub datacenter_label: Option<u16>,
+}
+
+/// Read-only lookup of which datacenter holds the reservation for an actor key. Lets callers route
+/// creation to the owning datacenter instead of waiting for a `KeyReservedInDifferentDatacenter`
+/// error.
+#[operation]
+pub async fn pegboard_actor_get_reservation_dc_for_key(
+	ctx: &OperationCtx,
+	input: &Input,
+) -> Result<Output> {
+	let reservation_res = ctx
+		.op(crate::ops::actor::get_reservation_for_key::Input {
+			namespace_id: input.namespace_id,
+			name: input.name.clone(),
+			key: input.key.clone(),
+			pool_name: input.pool_name.clone(),
+		})
+		.await?;
+
+	Ok(Output {
+		datacenter_label: reservation_res
+			.reservation_id
+			.map(|reservation_id| reservation_id.label()),
+	})
+}
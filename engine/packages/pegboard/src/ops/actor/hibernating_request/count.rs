@@ -0,0 +1,52 @@
+use futures_util::TryStreamExt;
+use gas::prelude::*;
+use universaldb::options::StreamingMode;
+use universaldb::utils::IsolationLevel::*;
+
+use crate::keys;
+
+#[derive(Debug, Default)]
+pub struct Input {
+	pub actor_id: Id,
+}
+
+/// Counts the hibernating requests of an actor that are still eligible to be restored, same as `list`.
+#[operation]
+pub async fn pegboard_actor_hibernating_request_count(
+	ctx: &OperationCtx,
+	input: &Input,
+) -> Result<usize> {
+	let pegboard_config = ctx.config().pegboard();
+
+	ctx.udb()?
+		.txn("pegboard_hibernating_request_count", |tx| async move {
+			let tx = tx.with_subspace(keys::subspace());
+
+			let hibernating_request_eligible_threshold =
+				super::eligible_threshold(pegboard_config, &tx, input.actor_id).await?;
+
+			let ping_threshold_ts = util::timestamp::now() - hibernating_request_eligible_threshold;
+			let hr_subspace_start = tx.pack(&keys::actor::HibernatingRequestKey::subspace_with_ts(
+				input.actor_id,
+				ping_threshold_ts,
+			));
+			let hr_subspace_end = keys::subspace()
+				.subspace(&keys::actor::HibernatingRequestKey::subspace(
+					input.actor_id,
+				))
+				.range()
+				.1;
+
+			tx.get_ranges_keyvalues(
+				universaldb::RangeOption {
+					mode: StreamingMode::WantAll,
+					..(hr_subspace_start, hr_subspace_end).into()
+				},
+				Serializable,
+			)
+			.try_fold(0, |count, _| async move { Ok(count + 1) })
+			.await
+		})
+		.custom_instrument(tracing::info_span!("hibernating_request_count_tx"))
+		.await
+}
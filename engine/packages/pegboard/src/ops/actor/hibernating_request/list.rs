@@ -22,15 +22,15 @@ pub async fn pegboard_actor_hibernating_request_list(
 	ctx: &OperationCtx,
 	input: &Input,
 ) -> Result<Vec<HibernatingRequestItem>> {
-	let hibernating_request_eligible_threshold = ctx
-		.config()
-		.pegboard()
-		.hibernating_request_eligible_threshold();
+	let pegboard_config = ctx.config().pegboard();
 
 	ctx.udb()?
 		.txn("pegboard_hibernating_request_list", |tx| async move {
 			let tx = tx.with_subspace(keys::subspace());
 
+			let hibernating_request_eligible_threshold =
+				super::eligible_threshold(pegboard_config, &tx, input.actor_id).await?;
+
 			let ping_threshold_ts = util::timestamp::now() - hibernating_request_eligible_threshold;
 			let hr_subspace_start = tx.pack(&keys::actor::HibernatingRequestKey::subspace_with_ts(
 				input.actor_id,
@@ -1,3 +1,25 @@
+use gas::prelude::*;
+use universaldb::utils::IsolationLevel::*;
+
+use crate::keys;
+
+pub mod count;
 pub mod delete;
 pub mod list;
 pub mod upsert;
+
+/// Reads the actor's name to resolve its threshold override. Expects `tx` to be in the pegboard subspace.
+async fn eligible_threshold(
+	config: &rivet_config::config::pegboard::Pegboard,
+	tx: &universaldb::Transaction,
+	actor_id: Id,
+) -> Result<i64> {
+	let name = tx
+		.read_opt(&keys::actor::NameKey::new(actor_id), Serializable)
+		.await?;
+
+	Ok(match name {
+		Some(name) => config.hibernating_request_eligible_threshold_for_actor(&name),
+		None => config.hibernating_request_eligible_threshold(),
+	})
+}
@@ -1,6 +1,6 @@
 use futures_util::TryStreamExt;
 use gas::prelude::*;
-use rivet_types::actors::Actor;
+use rivet_types::actors::{Actor, ActorState};
 use universaldb::options::StreamingMode;
 use universaldb::utils::IsolationLevel::*;
 
@@ -12,7 +12,15 @@ pub struct Input {
 	pub name: String,
 	pub key: Option<String>,
 	pub include_destroyed: bool,
+	/// Only list actors in this state. Takes precedence over `include_destroyed`.
+	pub state: Option<ActorState>,
+	/// Only list actors created strictly before this timestamp.
 	pub created_before: Option<i64>,
+	/// Only list actors created strictly after this timestamp.
+	pub created_after: Option<i64>,
+	/// `(create_ts, actor_id)` of the last actor of the previous page. Only actors listed after it
+	/// are returned.
+	pub cursor: Option<(i64, Id)>,
 	pub limit: usize,
 	pub fetch_error: bool,
 }
@@ -36,20 +44,12 @@ pub async fn pegboard_actor_list_for_ns(ctx: &OperationCtx, input: &Input) -> Re
 					input.name.clone(),
 					key.clone(),
 				));
-				let (start, end) = actor_subspace.range();
-
-				let end = if let Some(created_before) = input.created_before {
-					universaldb::utils::end_of_key_range(&tx.pack(
-						&keys::ns::ActorByKeyKey::subspace_with_create_ts(
-							input.namespace_id,
-							input.name.clone(),
-							key.clone(),
-							created_before,
-						),
-					))
-				} else {
-					end
-				};
+				let (start, end) = super::util::create_ts_range(
+					&actor_subspace,
+					input.created_after,
+					input.created_before,
+					input.cursor,
+				);
 
 				let mut stream = tx.get_ranges_keyvalues(
 					universaldb::RangeOption {
@@ -64,7 +64,11 @@ pub async fn pegboard_actor_list_for_ns(ctx: &OperationCtx, input: &Input) -> Re
 				while let Some(entry) = stream.try_next().await? {
 					let (idx_key, data) = tx.read_entry::<keys::ns::ActorByKeyKey>(&entry)?;
 
-					if !data.is_destroyed || input.include_destroyed {
+					// The key index has no state, all actors with the key are filtered by state once
+					// they are built
+					if input.state.is_some() {
+						results.push((idx_key.actor_id, data.workflow_id));
+					} else if !data.is_destroyed || input.include_destroyed {
 						results.push((idx_key.actor_id, data.workflow_id));
 
 						if results.len() >= input.limit {
@@ -72,24 +76,51 @@ pub async fn pegboard_actor_list_for_ns(ctx: &OperationCtx, input: &Input) -> Re
 						}
 					}
 				}
+			} else if let Some(state) = input.state {
+				let actor_subspace =
+					keys::subspace().subspace(&keys::ns::ActorByStateKey::subspace(
+						input.namespace_id,
+						input.name.clone(),
+						state,
+					));
+				let (start, end) = super::util::create_ts_range(
+					&actor_subspace,
+					input.created_after,
+					input.created_before,
+					input.cursor,
+				);
+
+				let mut stream = tx.get_ranges_keyvalues(
+					universaldb::RangeOption {
+						mode: StreamingMode::Iterator,
+						reverse: true,
+						..(start, end).into()
+					},
+					// NOTE: Does not have to be serializable because we are listing, stale data does not matter
+					Snapshot,
+				);
+
+				while let Some(entry) = stream.try_next().await? {
+					let (idx_key, workflow_id) =
+						tx.read_entry::<keys::ns::ActorByStateKey>(&entry)?;
+
+					results.push((idx_key.actor_id, workflow_id));
+
+					if results.len() >= input.limit {
+						break;
+					}
+				}
 			} else if input.include_destroyed {
 				let actor_subspace = keys::subspace().subspace(&keys::ns::AllActorKey::subspace(
 					input.namespace_id,
 					input.name.clone(),
 				));
-				let (start, end) = actor_subspace.range();
-
-				let end = if let Some(created_before) = input.created_before {
-					universaldb::utils::end_of_key_range(&tx.pack(
-						&keys::ns::AllActorKey::subspace_with_create_ts(
-							input.namespace_id,
-							input.name.clone(),
-							created_before,
-						),
-					))
-				} else {
-					end
-				};
+				let (start, end) = super::util::create_ts_range(
+					&actor_subspace,
+					input.created_after,
+					input.created_before,
+					input.cursor,
+				);
 
 				let mut stream = tx.get_ranges_keyvalues(
 					universaldb::RangeOption {
@@ -114,19 +145,12 @@ pub async fn pegboard_actor_list_for_ns(ctx: &OperationCtx, input: &Input) -> Re
 				let actor_subspace = keys::subspace().subspace(
 					&keys::ns::ActiveActorKey::subspace(input.namespace_id, input.name.clone()),
 				);
-				let (start, end) = actor_subspace.range();
-
-				let end = if let Some(created_before) = input.created_before {
-					universaldb::utils::end_of_key_range(&tx.pack(
-						&keys::ns::ActiveActorKey::subspace_with_create_ts(
-							input.namespace_id,
-							input.name.clone(),
-							created_before,
-						),
-					))
-				} else {
-					end
-				};
+				let (start, end) = super::util::create_ts_range(
+					&actor_subspace,
+					input.created_after,
+					input.created_before,
+					input.cursor,
+				);
 
 				let mut stream = tx.get_ranges_keyvalues(
 					universaldb::RangeOption {
@@ -166,7 +190,7 @@ pub async fn pegboard_actor_list_for_ns(ctx: &OperationCtx, input: &Input) -> Re
 
 	let dc_name = ctx.config().dc_name()?.to_string();
 
-	let actors = super::util::build_actors_from_workflows(
+	let mut actors = super::util::build_actors_from_workflows(
 		ctx,
 		actors_with_wf_ids,
 		wfs,
@@ -175,5 +199,10 @@ pub async fn pegboard_actor_list_for_ns(ctx: &OperationCtx, input: &Input) -> Re
 	)
 	.await?;
 
+	if let (Some(state), Some(_)) = (input.state, &input.key) {
+		actors.retain(|actor| actor.state() == state);
+		actors.truncate(input.limit);
+	}
+
 	Ok(Output { actors })
 }
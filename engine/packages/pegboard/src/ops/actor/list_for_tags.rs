@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+use anyhow::bail;
+use futures_util::TryStreamExt;
+use gas::prelude::*;
+use rivet_types::actors::Actor;
+use universaldb::options::StreamingMode;
+use universaldb::utils::IsolationLevel::*;
+
+use crate::keys;
+
+#[derive(Debug, Default)]
+pub struct Input {
+	pub namespace_id: Id,
+	/// Actors must have all of the given tags to match.
+	pub tags: HashMap<String, String>,
+	pub created_before: Option<i64>,
+	pub created_after: Option<i64>,
+	/// `(create_ts, actor_id)` of the last actor of the previous page.
+	pub cursor: Option<(i64, Id)>,
+	pub limit: usize,
+	pub fetch_error: bool,
+}
+
+#[derive(Debug)]
+pub struct Output {
+	pub actors: Vec<Actor>,
+}
+
+/// Lists active actors matching all of the given tags, newest first.
+#[operation]
+pub async fn pegboard_actor_list_for_tags(ctx: &OperationCtx, input: &Input) -> Result<Output> {
+	// Scan the index of one tag and check the remaining tags for each actor found
+	let mut tags = input.tags.iter().collect::<Vec<_>>();
+	tags.sort();
+	let Some(((first_key, first_value), rest)) = tags.split_first() else {
+		bail!("must provide at least one tag");
+	};
+
+	let actors_with_wf_ids = ctx
+		.udb()?
+		.txn("pegboard_actor_list_for_tags", |tx| async move {
+			let tx = tx.with_subspace(keys::subspace());
+			let mut results = Vec::new();
+
+			let tag_subspace = keys::subspace().subspace(&keys::ns::ActorByTagKey::subspace(
+				input.namespace_id,
+				first_key.to_string(),
+				first_value.to_string(),
+			));
+			let (start, end) = super::util::create_ts_range(
+				&tag_subspace,
+				input.created_after,
+				input.created_before,
+				input.cursor,
+			);
+
+			let mut stream = tx.get_ranges_keyvalues(
+				universaldb::RangeOption {
+					mode: StreamingMode::Iterator,
+					reverse: true,
+					..(start, end).into()
+				},
+				// NOTE: Does not have to be serializable because we are listing, stale data does not matter
+				Snapshot,
+			);
+
+			'outer: while let Some(entry) = stream.try_next().await? {
+				let (idx_key, workflow_id) = tx.read_entry::<keys::ns::ActorByTagKey>(&entry)?;
+
+				for (k, v) in rest {
+					let other_key = keys::ns::ActorByTagKey::new(
+						input.namespace_id,
+						k.to_string(),
+						v.to_string(),
+						idx_key.create_ts,
+						idx_key.actor_id,
+					);
+					if !tx.exists(&other_key, Snapshot).await? {
+						continue 'outer;
+					}
+				}
+
+				results.push((idx_key.actor_id, workflow_id));
+
+				if results.len() >= input.limit {
+					break;
+				}
+			}
+
+			Ok(results)
+		})
+		.custom_instrument(tracing::info_span!("actor_list_for_tags_tx"))
+		.await?;
+
+	let wfs = ctx
+		.get_workflows(
+			actors_with_wf_ids
+				.iter()
+				.map(|(_, workflow_id)| *workflow_id)
+				.collect(),
+		)
+		.await?;
+
+	let dc_name = ctx.config().dc_name()?.to_string();
+
+	let actors = super::util::build_actors_from_workflows(
+		ctx,
+		actors_with_wf_ids,
+		wfs,
+		&dc_name,
+		input.fetch_error,
+	)
+	.await?;
+
+	Ok(Output { actors })
+}
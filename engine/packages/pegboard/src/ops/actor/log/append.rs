@@ -0,0 +1,106 @@
+use futures_util::TryStreamExt;
+use gas::prelude::*;
+use rivet_types::actors::ActorLogStream;
+use universaldb::options::StreamingMode;
+use universaldb::utils::IsolationLevel::*;
+
+use crate::keys;
+
+#[derive(Debug)]
+pub struct Input {
+	pub actor_id: Id,
+	pub generation: u32,
+	pub lines: Vec<Line>,
+}
+
+#[derive(Debug)]
+pub struct Line {
+	pub ts: i64,
+	pub stream: ActorLogStream,
+	pub message: String,
+}
+
+/// Appends lines to the actor's log, dropping the oldest lines past `actor_log_max_lines`.
+#[operation]
+pub async fn pegboard_actor_log_append(ctx: &OperationCtx, input: &Input) -> Result<()> {
+	let pegboard_config = ctx.config().pegboard();
+	let max_lines = pegboard_config.actor_log_max_lines();
+	let max_line_size = pegboard_config.actor_log_max_line_size();
+
+	if max_lines == 0 || input.lines.is_empty() {
+		return Ok(());
+	}
+
+	// Lines that would be trimmed by this same append are never written
+	let skip = input
+		.lines
+		.len()
+		.saturating_sub(usize::try_from(max_lines).unwrap_or(usize::MAX));
+	let lines = &input.lines[skip..];
+
+	ctx.udb()?
+		.txn("pegboard_actor_log_append", |tx| async move {
+			let tx = tx.with_subspace(keys::subspace());
+			let log_subspace = keys::actor_log::subspace(input.actor_id);
+
+			// Continue after the newest stored line
+			let last_entry = tx
+				.get_ranges_keyvalues(
+					universaldb::RangeOption {
+						mode: StreamingMode::Exact,
+						limit: Some(1),
+						reverse: true,
+						..(&log_subspace).into()
+					},
+					Serializable,
+				)
+				.try_next()
+				.await?;
+			let start_idx = if let Some(entry) = last_entry {
+				tx.unpack::<keys::actor_log::LogKey>(entry.key())?.idx + 1
+			} else {
+				0
+			};
+
+			for (i, line) in lines.iter().enumerate() {
+				tx.write(
+					&keys::actor_log::LogKey::new(input.actor_id, start_idx + i as u64),
+					keys::actor_log::LogEntry {
+						ts: line.ts,
+						generation: input.generation,
+						stream: line.stream,
+						message: truncate(&line.message, max_line_size),
+					},
+				)?;
+			}
+
+			// Trim the ring
+			let end_idx = start_idx + lines.len() as u64;
+			if end_idx > max_lines {
+				tx.clear_range(
+					&tx.pack(&keys::actor_log::LogKey::new(input.actor_id, 0)),
+					&tx.pack(&keys::actor_log::LogKey::new(
+						input.actor_id,
+						end_idx - max_lines,
+					)),
+				);
+			}
+
+			Ok(())
+		})
+		.custom_instrument(tracing::info_span!("actor_log_append_tx"))
+		.await
+}
+
+fn truncate(message: &str, max_size: usize) -> String {
+	if message.len() <= max_size {
+		return message.to_string();
+	}
+
+	let mut end = max_size;
+	while !message.is_char_boundary(end) {
+		end -= 1;
+	}
+
+	message[..end].to_string()
+}
@@ -0,0 +1,248 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use futures_util::{FutureExt, future::BoxFuture};
+use gas::prelude::*;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+use super::append;
+use crate::metrics;
+
+/// Max log messages queued per actor before new messages are dropped.
+const CHANNEL_CAPACITY: usize = 64;
+
+/// Returns true if the actor still belongs to the connection that sent its logs.
+type OwnershipCheck =
+	Arc<dyn Fn(StandaloneCtx, Id) -> BoxFuture<'static, Result<bool>> + Send + Sync>;
+
+/// Log lines sent by an actor in a single message.
+pub struct ActorLog<L> {
+	pub generation: u32,
+	pub lines: Vec<L>,
+}
+
+struct Channel<L> {
+	tx: mpsc::Sender<ActorLog<L>>,
+	handle: JoinHandle<()>,
+	last_seen: Instant,
+}
+
+/// Stores actor logs off of a connection's message loop. Each actor gets its own bounded queue and task
+/// so a noisy actor can't delay messages for other actors on the same connection.
+///
+/// Generic over the protocol's log line type so envoy and runner connections share the same queueing,
+/// rate limiting, and ownership checks.
+pub struct ActorLogDemuxer<L> {
+	ctx: StandaloneCtx,
+	convert_line: fn(L) -> append::Line,
+	belongs_to_conn: OwnershipCheck,
+	channels: HashMap<Id, Channel<L>>,
+	last_gc: Instant,
+	gc_interval: Duration,
+	max_last_seen: Duration,
+}
+
+impl<L: Send + 'static> ActorLogDemuxer<L> {
+	/// `belongs_to_conn` is called with the actor's ID before storing logs for a new generation, logs are
+	/// dropped if it returns false.
+	pub fn new<F, Fut>(
+		ctx: StandaloneCtx,
+		gc_interval: Duration,
+		max_last_seen: Duration,
+		convert_line: fn(L) -> append::Line,
+		belongs_to_conn: F,
+	) -> Self
+	where
+		F: Fn(StandaloneCtx, Id) -> Fut + Send + Sync + 'static,
+		Fut: Future<Output = Result<bool>> + Send + 'static,
+	{
+		let belongs_to_conn: OwnershipCheck =
+			Arc::new(move |ctx, actor_id| belongs_to_conn(ctx, actor_id).boxed());
+
+		Self {
+			ctx,
+			convert_line,
+			belongs_to_conn,
+			channels: HashMap::new(),
+			last_gc: Instant::now(),
+			gc_interval,
+			max_last_seen,
+		}
+	}
+
+	/// Queues logs for the actor. Never waits, logs are dropped if the actor's queue is full.
+	pub fn ingest(&mut self, actor_id: Id, log: ActorLog<L>) {
+		let channel = self.channels.entry(actor_id).or_insert_with(|| {
+			let (tx, rx) = mpsc::channel(CHANNEL_CAPACITY);
+
+			let handle = tokio::spawn(channel_handler(
+				self.ctx.clone(),
+				actor_id,
+				self.convert_line,
+				self.belongs_to_conn.clone(),
+				rx,
+			));
+			metrics::ACTOR_LOG_DEMUXER_COUNT.inc();
+
+			Channel {
+				tx,
+				handle,
+				last_seen: Instant::now(),
+			}
+		});
+		channel.last_seen = Instant::now();
+
+		if let Err(mpsc::error::TrySendError::Full(log)) = channel.tx.try_send(log) {
+			tracing::debug!(?actor_id, "actor log queue full, dropping logs");
+			metrics::ACTOR_LOG_LINES_DROPPED_TOTAL
+				.with_label_values(&["queue_full"])
+				.inc_by(log.lines.len() as u64);
+		}
+
+		// Run gc periodically
+		if self.last_gc.elapsed() > self.gc_interval {
+			self.last_gc = Instant::now();
+
+			self.channels.retain(|_, channel| {
+				let keep = channel.last_seen.elapsed() < self.max_last_seen;
+
+				if !keep {
+					channel.handle.abort();
+					metrics::ACTOR_LOG_DEMUXER_COUNT.dec();
+				}
+
+				keep
+			});
+		}
+	}
+
+	/// Shutdown all tasks and wait for queued logs to be stored
+	#[tracing::instrument(skip_all)]
+	pub async fn shutdown(self) {
+		tracing::debug!(channels=?self.channels.len(), "shutting down actor log demuxer");
+
+		// Drop all senders
+		let handles = self
+			.channels
+			.into_iter()
+			.map(|(_, channel)| channel.handle)
+			.collect::<Vec<_>>();
+
+		for handle in handles {
+			let _ = handle.await;
+			metrics::ACTOR_LOG_DEMUXER_COUNT.dec();
+		}
+
+		tracing::debug!("actor log demuxer shut down");
+	}
+}
+
+#[tracing::instrument(name="actor_log_demuxer_channel", skip_all, fields(ray_id=?ctx.ray_id(), req_id=?ctx.req_id(), ?actor_id))]
+async fn channel_handler<L>(
+	ctx: StandaloneCtx,
+	actor_id: Id,
+	convert_line: fn(L) -> append::Line,
+	belongs_to_conn: OwnershipCheck,
+	mut rx: mpsc::Receiver<ActorLog<L>>,
+) {
+	let mut rate_limiter =
+		RateLimiter::new(ctx.config().pegboard().actor_log_max_lines_per_second());
+	// Generation last verified to belong to this connection
+	let mut verified_generation = None;
+
+	loop {
+		let mut buffer = Vec::new();
+
+		if rx.recv_many(&mut buffer, CHANNEL_CAPACITY).await == 0 {
+			break;
+		}
+
+		for log in buffer {
+			if verified_generation != Some(log.generation) {
+				match belongs_to_conn(ctx.clone(), actor_id).await {
+					Ok(true) => verified_generation = Some(log.generation),
+					Ok(false) => {
+						tracing::debug!("actor does not belong to connection, dropping logs");
+						continue;
+					}
+					Err(err) => {
+						tracing::warn!(?err, "failed to verify actor for logs");
+						continue;
+					}
+				}
+			}
+
+			if let Err(err) = store_logs(&ctx, actor_id, log, convert_line, &mut rate_limiter).await
+			{
+				tracing::warn!(?err, "failed to store actor logs");
+			}
+		}
+	}
+}
+
+#[tracing::instrument(skip_all, fields(generation=%log.generation))]
+async fn store_logs<L>(
+	ctx: &StandaloneCtx,
+	actor_id: Id,
+	mut log: ActorLog<L>,
+	convert_line: fn(L) -> append::Line,
+	rate_limiter: &mut RateLimiter,
+) -> Result<()> {
+	let allowed = rate_limiter.take(log.lines.len());
+	if allowed < log.lines.len() {
+		let dropped = log.lines.len() - allowed;
+		tracing::debug!(?dropped, "actor log rate limit reached, dropping lines");
+		metrics::ACTOR_LOG_LINES_DROPPED_TOTAL
+			.with_label_values(&["rate_limited"])
+			.inc_by(dropped as u64);
+
+		log.lines.truncate(allowed);
+	}
+
+	if log.lines.is_empty() {
+		return Ok(());
+	}
+
+	ctx.op(append::Input {
+		actor_id,
+		generation: log.generation,
+		lines: log.lines.into_iter().map(convert_line).collect(),
+	})
+	.await
+	.context("failed to append actor logs")
+}
+
+/// Token bucket allowing up to one second of lines in a burst.
+struct RateLimiter {
+	lines_per_second: f64,
+	tokens: f64,
+	last_refill: Instant,
+}
+
+impl RateLimiter {
+	fn new(lines_per_second: u32) -> Self {
+		RateLimiter {
+			lines_per_second: lines_per_second as f64,
+			tokens: lines_per_second as f64,
+			last_refill: Instant::now(),
+		}
+	}
+
+	/// Takes up to `count` tokens, returns how many were taken.
+	fn take(&mut self, count: usize) -> usize {
+		let now = Instant::now();
+		self.tokens = (self.tokens
+			+ now.duration_since(self.last_refill).as_secs_f64() * self.lines_per_second)
+			.min(self.lines_per_second);
+		self.last_refill = now;
+
+		let taken = (self.tokens.floor() as usize).min(count);
+		self.tokens -= taken as f64;
+
+		taken
+	}
+}
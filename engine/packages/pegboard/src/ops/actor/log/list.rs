@@ -0,0 +1,70 @@
+use futures_util::TryStreamExt;
+use gas::prelude::*;
+use rivet_types::actors::ActorLog;
+use universaldb::options::StreamingMode;
+use universaldb::utils::IsolationLevel::*;
+
+use crate::keys;
+
+#[derive(Debug)]
+pub struct Input {
+	pub actor_id: Id,
+	/// Only return lines with an index greater than this.
+	pub after: Option<u64>,
+	pub limit: usize,
+}
+
+#[derive(Debug)]
+pub struct Output {
+	pub logs: Vec<ActorLog>,
+}
+
+/// Lists stored log lines of an actor in ascending order.
+#[operation]
+pub async fn pegboard_actor_log_list(ctx: &OperationCtx, input: &Input) -> Result<Output> {
+	let logs = ctx
+		.udb()?
+		.txn("pegboard_actor_log_list", |tx| async move {
+			let tx = tx.with_subspace(keys::subspace());
+			let log_subspace = keys::actor_log::subspace(input.actor_id);
+
+			let start = if let Some(after) = input.after {
+				tx.pack(&keys::actor_log::LogKey::new(
+					input.actor_id,
+					after.saturating_add(1),
+				))
+			} else {
+				log_subspace.range().0
+			};
+			let end = log_subspace.range().1;
+
+			let mut stream = tx.get_ranges_keyvalues(
+				universaldb::RangeOption {
+					mode: StreamingMode::WantAll,
+					limit: Some(input.limit),
+					..(start, end).into()
+				},
+				// NOTE: Does not have to be serializable because we are listing, stale data does not matter
+				Snapshot,
+			);
+
+			let mut logs = Vec::new();
+			while let Some(entry) = stream.try_next().await? {
+				let (key, entry) = tx.read_entry::<keys::actor_log::LogKey>(&entry)?;
+
+				logs.push(ActorLog {
+					idx: key.idx,
+					ts: entry.ts,
+					generation: entry.generation,
+					stream: entry.stream,
+					message: entry.message,
+				});
+			}
+
+			Ok(logs)
+		})
+		.custom_instrument(tracing::info_span!("actor_log_list_tx"))
+		.await?;
+
+	Ok(Output { logs })
+}
@@ -0,0 +1,3 @@
+pub mod append;
+pub mod demuxer;
+pub mod list;
@@ -1,11 +1,20 @@
 pub mod create;
+pub mod create_batch;
+pub mod destroy_batch;
 pub mod get;
+pub mod get_batch;
+pub mod get_destroy_audit;
 pub mod get_for_gateway;
 pub mod get_for_key;
 pub mod get_for_kv;
 pub mod get_for_runner;
+pub mod get_reservation_dc_for_key;
 pub mod get_reservation_for_key;
 pub mod hibernating_request;
 pub mod list_for_ns;
+pub mod list_for_tags;
 pub mod list_names;
+pub mod log;
+pub mod simulate_allocation;
+pub mod transfer_key;
 mod util;
@@ -0,0 +1,181 @@
+use std::collections::BinaryHeap;
+
+use futures_util::{StreamExt, TryStreamExt};
+use gas::prelude::*;
+use universaldb::options::StreamingMode;
+use universaldb::prelude::*;
+
+use crate::keys;
+
+#[derive(Debug)]
+pub struct Input {
+	pub namespace_id: Id,
+	pub runner_name_selector: String,
+	/// Number of hypothetical actors to place.
+	pub count: usize,
+	pub required_capabilities: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct Output {
+	/// Number of actors that would be allocated immediately.
+	pub allocated: usize,
+	/// Number of actors that would go pending.
+	pub pending: usize,
+	/// Runners that would receive actors, most receiving first.
+	pub runners: Vec<RunnerPlacement>,
+}
+
+#[derive(Debug)]
+pub struct RunnerPlacement {
+	pub runner_id: Id,
+	/// Number of simulated actors placed on this runner.
+	pub actors: usize,
+	/// Slots left on this runner after placement.
+	pub remaining_slots: u32,
+}
+
+/// Simulates allocating a batch of actors against the current runner allocation index without writing
+/// anything. Follows the same candidate rules as the allocator (highest version, valid ping, required
+/// capabilities). The allocator picks candidates at random weighted by remaining slots, so placement is
+/// approximated by always picking the runner with the most remaining capacity.
+#[operation]
+pub async fn pegboard_actor_simulate_allocation(
+	ctx: &OperationCtx,
+	input: &Input,
+) -> Result<Output> {
+	let runner_eligible_threshold = ctx.config().pegboard().runner_eligible_threshold();
+
+	let candidates = ctx
+		.udb()?
+		.txn("pegboard_actor_simulate_allocation", |tx| async move {
+			let ping_threshold_ts = util::timestamp::now() - runner_eligible_threshold;
+
+			let tx = tx.with_subspace(keys::subspace());
+
+			// New actors queue behind existing pending actors
+			let pending_actor_subspace = keys::subspace().subspace(
+				&keys::ns::PendingActorByRunnerNameSelectorKey::subspace(
+					input.namespace_id,
+					input.runner_name_selector.clone(),
+				),
+			);
+
+			let mut queue_stream = tx.get_ranges_keyvalues(
+				universaldb::RangeOption {
+					mode: StreamingMode::Exact,
+					limit: Some(1),
+					..(&pending_actor_subspace).into()
+				},
+				Snapshot,
+			);
+			if queue_stream.next().await.is_some() {
+				return Ok(Vec::new());
+			}
+
+			let runner_alloc_subspace =
+				keys::subspace().subspace(&keys::ns::RunnerAllocIdxKey::subspace(
+					input.namespace_id,
+					input.runner_name_selector.clone(),
+				));
+
+			let mut stream = tx.get_ranges_keyvalues(
+				universaldb::RangeOption {
+					mode: StreamingMode::Iterator,
+					..(&runner_alloc_subspace).into()
+				},
+				Snapshot,
+			);
+
+			let mut highest_version = None;
+			let mut candidates = Vec::new();
+
+			loop {
+				let Some(entry) = stream.try_next().await? else {
+					break;
+				};
+
+				let (runner_alloc_key, runner_alloc_key_data) =
+					tx.read_entry::<keys::ns::RunnerAllocIdxKey>(&entry)?;
+
+				if let Some(highest_version) = highest_version {
+					if runner_alloc_key.version < highest_version {
+						break;
+					}
+				} else {
+					highest_version = Some(runner_alloc_key.version);
+				}
+
+				// An empty runner means we have reached the end of the runners with the highest version
+				if runner_alloc_key.remaining_millislots == 0 {
+					break;
+				}
+
+				if runner_alloc_key.last_ping_ts < ping_threshold_ts {
+					continue;
+				}
+
+				if !crate::utils::runner_has_capabilities(
+					&tx,
+					runner_alloc_key.runner_id,
+					&input.required_capabilities,
+				)
+				.await?
+				{
+					continue;
+				}
+
+				candidates.push((
+					runner_alloc_key.runner_id,
+					runner_alloc_key_data.remaining_slots,
+					runner_alloc_key_data.total_slots,
+				));
+			}
+
+			Ok(candidates)
+		})
+		.custom_instrument(tracing::info_span!("actor_simulate_allocation_tx"))
+		.await?;
+
+	let mut runners = candidates
+		.iter()
+		.map(|(runner_id, remaining_slots, _)| RunnerPlacement {
+			runner_id: *runner_id,
+			actors: 0,
+			remaining_slots: *remaining_slots,
+		})
+		.collect::<Vec<_>>();
+
+	// Ordered by remaining millislots, same as the allocation index
+	let mut heap = candidates
+		.iter()
+		.enumerate()
+		.filter(|(_, (_, remaining_slots, _))| *remaining_slots > 0)
+		.map(|(i, (_, remaining_slots, total_slots))| (remaining_slots * 1000 / total_slots, i))
+		.collect::<BinaryHeap<_>>();
+
+	let mut allocated = 0;
+	while allocated < input.count {
+		let Some((_, i)) = heap.pop() else {
+			break;
+		};
+
+		let runner = &mut runners[i];
+		runner.actors += 1;
+		runner.remaining_slots -= 1;
+		allocated += 1;
+
+		if runner.remaining_slots > 0 {
+			heap.push((runner.remaining_slots * 1000 / candidates[i].2, i));
+		}
+	}
+
+	runners.retain(|runner| runner.actors > 0);
+	runners.sort_by(|a, b| b.actors.cmp(&a.actors));
+
+	Ok(Output {
+		allocated,
+		pending: input.count - allocated,
+		runners,
+	})
+}
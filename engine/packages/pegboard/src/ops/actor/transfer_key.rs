@@ -0,0 +1,156 @@
+use anyhow::ensure;
+use futures_util::TryStreamExt;
+use gas::prelude::*;
+use rivet_data::converted::ActorByKeyKeyData;
+use universaldb::options::StreamingMode;
+use universaldb::prelude::*;
+
+use crate::{errors, keys};
+
+#[derive(Debug)]
+pub struct Input {
+	pub namespace_id: Id,
+	pub name: String,
+	pub key: String,
+	/// Actor currently holding the key.
+	pub from_actor_id: Id,
+	/// Actor to take over the key. Must have been created without a key.
+	pub to_actor_id: Id,
+}
+
+/// Atomically moves an actor key from one actor to another in the same datacenter. The epoxy reservation
+/// for the key is owned by the datacenter and is left as is, only the local key index is updated. Since
+/// both index entries are changed in a single transaction, there is no point where the key is unheld.
+#[operation]
+pub async fn pegboard_actor_transfer_key(ctx: &OperationCtx, input: &Input) -> Result<()> {
+	ensure!(
+		input.from_actor_id != input.to_actor_id,
+		"cannot transfer key to the same actor"
+	);
+
+	let reservation_res = ctx
+		.op(crate::ops::actor::get_reservation_for_key::Input {
+			namespace_id: input.namespace_id,
+			name: input.name.clone(),
+			key: input.key.clone(),
+			pool_name: None,
+		})
+		.await?;
+
+	let Some(reservation_id) = reservation_res.reservation_id else {
+		return Err(errors::Actor::KeyNotHeld {
+			key: input.key.clone(),
+			actor_id: input.from_actor_id,
+		}
+		.build());
+	};
+
+	if reservation_id.label() != ctx.config().dc_label() {
+		return Err(errors::Actor::KeyReservedInDifferentDatacenter {
+			datacenter_label: reservation_id.label(),
+		}
+		.build());
+	}
+
+	ctx.udb()?
+		.txn("pegboard_actor_transfer_key", |tx| async move {
+			let tx = tx.with_subspace(keys::subspace());
+
+			// Validate the new actor
+			let (to_workflow_id, to_namespace_id, to_name, to_create_ts, to_destroy_ts, to_key) = tokio::try_join!(
+				tx.read_opt(&keys::actor::WorkflowIdKey::new(input.to_actor_id), Serializable),
+				tx.read_opt(&keys::actor::NamespaceIdKey::new(input.to_actor_id), Serializable),
+				tx.read_opt(&keys::actor::NameKey::new(input.to_actor_id), Serializable),
+				tx.read_opt(&keys::actor::CreateTsKey::new(input.to_actor_id), Serializable),
+				tx.read_opt(&keys::actor::DestroyTsKey::new(input.to_actor_id), Serializable),
+				tx.read_opt(&keys::actor::KeyKey::new(input.to_actor_id), Serializable),
+			)?;
+
+			let (Some(to_workflow_id), Some(to_namespace_id), Some(to_name), Some(to_create_ts)) =
+				(to_workflow_id, to_namespace_id, to_name, to_create_ts)
+			else {
+				return Err(errors::Actor::NotFound.build());
+			};
+
+			if to_destroy_ts.is_some()
+				|| to_namespace_id != input.namespace_id
+				|| to_name != input.name
+			{
+				return Err(errors::Actor::NotFound.build());
+			}
+
+			ensure!(
+				to_key.is_none(),
+				"actor {} already has a key",
+				input.to_actor_id
+			);
+
+			// Find the current holder of the key
+			let actor_key_subspace = keys::subspace().subspace(&keys::ns::ActorByKeyKey::subspace(
+				input.namespace_id,
+				input.name.clone(),
+				input.key.clone(),
+			));
+
+			let mut stream = tx.get_ranges_keyvalues(
+				universaldb::RangeOption {
+					mode: StreamingMode::Iterator,
+					..(&actor_key_subspace).into()
+				},
+				Serializable,
+			);
+
+			let mut holder = None;
+			while let Some(entry) = stream.try_next().await? {
+				let (idx_key, data) = tx.read_entry::<keys::ns::ActorByKeyKey>(&entry)?;
+				if !data.is_destroyed {
+					holder = Some((idx_key, data));
+					break;
+				}
+			}
+
+			let Some((from_idx_key, from_data)) =
+				holder.filter(|(idx_key, _)| idx_key.actor_id == input.from_actor_id)
+			else {
+				return Err(errors::Actor::KeyNotHeld {
+					key: input.key.clone(),
+					actor_id: input.from_actor_id,
+				}
+				.build());
+			};
+
+			// Release the key from the old actor. Its workflow state still has the key, so destroying it later
+			// rewrites its own index entry as destroyed, which leaves the new holder's entry untouched.
+			tx.write(
+				&from_idx_key,
+				ActorByKeyKeyData {
+					workflow_id: from_data.workflow_id,
+					is_destroyed: true,
+				},
+			)?;
+			tx.delete(&keys::actor::KeyKey::new(input.from_actor_id));
+
+			// Hand the key to the new actor
+			tx.write(
+				&keys::ns::ActorByKeyKey::new(
+					input.namespace_id,
+					input.name.clone(),
+					input.key.clone(),
+					to_create_ts,
+					input.to_actor_id,
+				),
+				ActorByKeyKeyData {
+					workflow_id: to_workflow_id,
+					is_destroyed: false,
+				},
+			)?;
+			tx.write(
+				&keys::actor::KeyKey::new(input.to_actor_id),
+				input.key.clone(),
+			)?;
+
+			Ok(())
+		})
+		.custom_instrument(tracing::info_span!("actor_transfer_key_tx"))
+		.await
+}
@@ -1,8 +1,11 @@
+use futures_util::{StreamExt, TryStreamExt};
 use gas::db::WorkflowData;
 use gas::prelude::*;
 use rivet_types::actors::{Actor, CrashPolicy};
 use std::collections::{HashMap, HashSet};
+use universaldb::prelude::*;
 
+use crate::keys;
 use crate::workflows::actor::FailureReason as WorkflowFailureReason;
 use crate::workflows::actor2::ActorError as WorkflowActorError;
 
@@ -174,6 +177,15 @@ pub async fn build_actors_from_workflows(
 		HashMap::new()
 	};
 
+	let mut actor_keys = fetch_actor_keys(
+		ctx,
+		actor_data
+			.iter()
+			.map(|(actor_id, _, _)| *actor_id)
+			.collect(),
+	)
+	.await?;
+
 	// Build actors with resolved errors
 	let mut actors = Vec::with_capacity(actor_data.len());
 	for (actor_id, wf, actor_state) in actor_data {
@@ -185,11 +197,13 @@ pub async fn build_actors_from_workflows(
 			None
 		};
 
+		let key = actor_keys.remove(&actor_id).flatten();
+
 		let actor = match actor_state {
 			ActorState::V1(s) => Actor {
 				actor_id,
 				name: s.name.clone(),
-				key: s.key.clone(),
+				key,
 				namespace_id: s.namespace_id,
 				datacenter: dc_name.to_string(),
 				runner_name_selector: s.runner_name_selector,
@@ -198,6 +212,7 @@ pub async fn build_actors_from_workflows(
 				create_ts: s.create_ts,
 				start_ts: s.start_ts,
 				pending_allocation_ts: s.pending_allocation_ts,
+				pending_reason: s.pending_reason,
 				connectable_ts: s.connectable_ts,
 				sleep_ts: s.sleep_ts,
 				reschedule_ts: s.reschedule_ts,
@@ -208,7 +223,7 @@ pub async fn build_actors_from_workflows(
 			ActorState::V2(s) => Actor {
 				actor_id,
 				name: s.name.clone(),
-				key: s.key.clone(),
+				key,
 				namespace_id: s.namespace_id,
 				datacenter: dc_name.to_string(),
 				runner_name_selector: s.pool_name,
@@ -217,6 +232,7 @@ pub async fn build_actors_from_workflows(
 				create_ts: s.create_ts,
 				start_ts: s.start_ts,
 				pending_allocation_ts: None,
+				pending_reason: s.pending_reason,
 				connectable_ts: s.connectable_ts,
 				sleep_ts: s.sleep_ts,
 				reschedule_ts: s.reschedule_ts,
@@ -232,6 +248,42 @@ pub async fn build_actors_from_workflows(
 	Ok(actors)
 }
 
+/// Reads the current key of each actor. The key index is read instead of the workflow state since actors can
+/// hand their key to another actor through `ops::actor::transfer_key` without their state changing.
+async fn fetch_actor_keys(
+	ctx: &OperationCtx,
+	actor_ids: Vec<Id>,
+) -> Result<HashMap<Id, Option<String>>> {
+	if actor_ids.is_empty() {
+		return Ok(HashMap::new());
+	}
+
+	ctx.udb()?
+		.txn("pegboard_actor_fetch_keys", |tx| {
+			let actor_ids = actor_ids.clone();
+			async move {
+				let tx = tx.with_subspace(keys::subspace());
+
+				futures_util::stream::iter(actor_ids)
+					.map(|actor_id| {
+						let tx = tx.clone();
+						async move {
+							let key = tx
+								.read_opt(&keys::actor::KeyKey::new(actor_id), Serializable)
+								.await?;
+
+							Ok((actor_id, key))
+						}
+					})
+					.buffer_unordered(1024)
+					.try_collect::<HashMap<_, _>>()
+					.await
+			}
+		})
+		.custom_instrument(tracing::info_span!("actor_fetch_keys_tx"))
+		.await
+}
+
 /// Fetches runner pool errors for actors with NoCapacity failures.
 async fn fetch_runner_pool_errors(
 	ctx: &OperationCtx,
@@ -261,3 +313,34 @@ async fn fetch_runner_pool_errors(
 		.map(|e| ((e.namespace_id, e.runner_name), e.error))
 		.collect())
 }
+
+/// Range of an index subspace whose keys end in `(create_ts, actor_id)`, bounded by the time filters
+/// and cursor of a list.
+pub(crate) fn create_ts_range(
+	subspace: &universaldb::Subspace,
+	created_after: Option<i64>,
+	created_before: Option<i64>,
+	cursor: Option<(i64, Id)>,
+) -> (Vec<u8>, Vec<u8>) {
+	let (mut start, mut end) = subspace.range();
+
+	if let Some(created_after) = created_after {
+		start = start.max(subspace.subspace(&created_after).range().1);
+	}
+
+	if let Some(created_before) = created_before {
+		end = end.min(subspace.pack(&created_before));
+	}
+
+	// Listing is newest first, so the next page ends right before the cursor's key
+	if let Some(cursor) = cursor {
+		end = end.min(subspace.pack(&cursor));
+	}
+
+	// Filters that exclude every actor produce an empty range instead of an inverted one
+	if end < start {
+		end = start.clone();
+	}
+
+	(start, end)
+}
@@ -3,3 +3,4 @@ pub mod envoy;
 pub mod runner;
 pub mod runner_config;
 pub mod serverless_metadata;
+pub mod webhook;
@@ -0,0 +1,101 @@
+use anyhow::Result;
+use gas::prelude::*;
+use universaldb::utils::IsolationLevel::*;
+
+use crate::keys;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Input {
+	pub runner_id: Id,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Output {
+	pub connection: Option<ConnectionInfo>,
+}
+
+/// Connection details of a runner, meant for debugging live runners.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConnectionInfo {
+	pub runner_id: Id,
+	pub workflow_id: Id,
+	/// Not set for runners using the mk1 protocol.
+	pub protocol_version: Option<u16>,
+	/// Runner key provided in the connection URL.
+	pub key: String,
+	/// Capabilities provided in the connection URL.
+	pub capabilities: Vec<String>,
+	pub create_ts: i64,
+	/// Last time the runner (re)connected.
+	pub connected_ts: Option<i64>,
+	pub last_ping_ts: Option<i64>,
+	/// Round trip time of the last ping in milliseconds.
+	pub last_rtt: Option<u32>,
+	pub drain_ts: Option<i64>,
+	pub stop_ts: Option<i64>,
+	pub expired_ts: Option<i64>,
+}
+
+#[operation]
+pub async fn pegboard_runner_get_connection_info(
+	ctx: &OperationCtx,
+	input: &Input,
+) -> Result<Output> {
+	let connection = ctx
+		.udb()?
+		.txn("pegboard_runner_get_connection_info", |tx| async move {
+			let tx = tx.with_subspace(keys::subspace());
+			let runner_id = input.runner_id;
+
+			// NOTE: These are not Serializable because this op is only meant for debugging
+			let (
+				workflow_id,
+				protocol_version,
+				key,
+				capabilities,
+				create_ts,
+				connected_ts,
+				last_ping_ts,
+				last_rtt,
+				drain_ts,
+				stop_ts,
+				expired_ts,
+			) = tokio::try_join!(
+				tx.read_opt(&keys::runner::WorkflowIdKey::new(runner_id), Snapshot),
+				tx.read_opt(&keys::runner::ProtocolVersionKey::new(runner_id), Snapshot),
+				tx.read_opt(&keys::runner::KeyKey::new(runner_id), Snapshot),
+				tx.read_opt(&keys::runner::CapabilitiesKey::new(runner_id), Snapshot),
+				tx.read_opt(&keys::runner::CreateTsKey::new(runner_id), Snapshot),
+				tx.read_opt(&keys::runner::ConnectedTsKey::new(runner_id), Snapshot),
+				tx.read_opt(&keys::runner::LastPingTsKey::new(runner_id), Snapshot),
+				tx.read_opt(&keys::runner::LastRttKey::new(runner_id), Snapshot),
+				tx.read_opt(&keys::runner::DrainTsKey::new(runner_id), Snapshot),
+				tx.read_opt(&keys::runner::StopTsKey::new(runner_id), Snapshot),
+				tx.read_opt(&keys::runner::ExpiredTsKey::new(runner_id), Snapshot),
+			)?;
+
+			let (Some(workflow_id), Some(key), Some(create_ts)) = (workflow_id, key, create_ts)
+			else {
+				return Ok(None);
+			};
+
+			Ok(Some(ConnectionInfo {
+				runner_id,
+				workflow_id,
+				protocol_version,
+				key,
+				capabilities: capabilities.unwrap_or_default(),
+				create_ts,
+				connected_ts,
+				last_ping_ts,
+				last_rtt,
+				drain_ts,
+				stop_ts,
+				expired_ts,
+			}))
+		})
+		.custom_instrument(tracing::info_span!("runner_get_connection_info_tx"))
+		.await?;
+
+	Ok(Output { connection })
+}
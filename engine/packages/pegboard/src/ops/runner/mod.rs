@@ -1,6 +1,7 @@
 pub mod drain;
 pub mod get;
 pub mod get_by_key;
+pub mod get_connection_info;
 pub mod list_for_ns;
 pub mod list_names;
 pub mod list_runner_config_enabled_dcs;
@@ -4,4 +4,5 @@ pub mod get;
 pub mod get_error;
 pub mod list;
 pub mod refresh_metadata;
+pub mod set_autoscaler_paused;
 pub mod upsert;
@@ -0,0 +1,92 @@
+use gas::prelude::*;
+use rivet_types::keys::namespace::runner_config::RunnerConfigVariant;
+use universaldb::prelude::*;
+
+use crate::keys;
+
+const BUMP_PAGE_SIZE: usize = 256;
+
+#[derive(Debug)]
+pub struct Input {
+	pub namespace_id: Id,
+	pub paused: bool,
+}
+
+/// Pauses or resumes the serverless autoscaler for all runner pools in a namespace in the current
+/// datacenter. While paused, pools keep their current connection count regardless of desired slots.
+#[operation]
+pub async fn pegboard_runner_config_set_autoscaler_paused(
+	ctx: &OperationCtx,
+	input: &Input,
+) -> Result<()> {
+	let changed = ctx
+		.udb()?
+		.txn(
+			"pegboard_runner_config_set_autoscaler_paused",
+			|tx| async move {
+				let tx = tx.with_subspace(keys::subspace());
+
+				let paused_key = keys::ns::ServerlessAutoscalerPausedKey::new(input.namespace_id);
+				let was_paused = tx.exists(&paused_key, Serializable).await?;
+
+				if input.paused {
+					tx.write(&paused_key, ())?;
+				} else {
+					tx.delete(&paused_key);
+				}
+
+				Ok(was_paused != input.paused)
+			},
+		)
+		.custom_instrument(tracing::info_span!(
+			"runner_config_set_autoscaler_paused_tx"
+		))
+		.await?;
+
+	if !changed {
+		return Ok(());
+	}
+
+	tracing::info!(
+		namespace_id = %input.namespace_id,
+		paused = input.paused,
+		"serverless autoscaler pause state changed"
+	);
+
+	// Bump all serverless pools so they pick up the new state immediately
+	let mut after_name = None;
+	loop {
+		let runner_configs = ctx
+			.op(crate::ops::runner_config::list::Input {
+				namespace_id: input.namespace_id,
+				variant: Some(RunnerConfigVariant::Serverless),
+				after_name: after_name.clone(),
+				limit: BUMP_PAGE_SIZE,
+			})
+			.await?;
+		let page_len = runner_configs.len();
+
+		for runner_config in runner_configs {
+			let res = ctx
+				.signal(crate::workflows::runner_pool::Bump::default())
+				.to_workflow::<crate::workflows::runner_pool::Workflow>()
+				.tag("namespace_id", input.namespace_id)
+				.tag("runner_name", runner_config.name.clone())
+				.graceful_not_found()
+				.send()
+				.await?;
+
+			if res.is_none() {
+				tracing::debug!(namespace_id=?input.namespace_id, name=%runner_config.name, "no runner pool workflow to bump");
+			}
+
+			after_name = Some(runner_config.name);
+		}
+
+		if page_len < BUMP_PAGE_SIZE {
+			break;
+		}
+	}
+
+	Ok(())
+}
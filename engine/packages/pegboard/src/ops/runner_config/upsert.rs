@@ -1,6 +1,8 @@
 use epoxy::ops::propose::{Command, CommandKind, Proposal, SetCommand};
 use gas::prelude::*;
-use rivet_types::runner_configs::{RunnerConfig, RunnerConfigKind};
+use rivet_types::runner_configs::{
+	RunnerConfig, RunnerConfigKind, ServerlessAuth, ServerlessRequestMethod,
+};
 use universaldb::prelude::*;
 
 use crate::{errors, keys, utils::runner_config_variant};
@@ -32,6 +34,9 @@ pub async fn pegboard_runner_config_upsert(ctx: &OperationCtx, input: &Input) ->
 			request_lifespan,
 			drain_grace_period,
 			slots_per_runner,
+			method,
+			body,
+			auth,
 			..
 		} => {
 			if let Err(err) = url::Url::parse(url) {
@@ -75,6 +80,61 @@ pub async fn pegboard_runner_config_upsert(ctx: &OperationCtx, input: &Input) ->
 				}
 			}
 
+			if let Some(body) = body {
+				if *method == ServerlessRequestMethod::Get {
+					return Err(errors::RunnerConfig::Invalid {
+						reason: "`body` requires `method` to be `post`".to_string(),
+					}
+					.build());
+				}
+				if body.len() > 16 * 1024 {
+					return Err(errors::RunnerConfig::Invalid {
+						reason: "invalid body: too long (max 16KiB)".to_string(),
+					}
+					.build());
+				}
+			}
+
+			match auth {
+				Some(ServerlessAuth::Bearer { token }) => {
+					if let Err(err) =
+						format!("Bearer {token}").parse::<reqwest::header::HeaderValue>()
+					{
+						return Err(errors::RunnerConfig::Invalid {
+							reason: format!("invalid bearer token: {err}"),
+						}
+						.build());
+					}
+				}
+				Some(ServerlessAuth::BearerEnv { env }) => {
+					if !ctx
+						.config()
+						.pegboard()
+						.serverless_auth_env_allowlist()
+						.contains(env)
+					{
+						return Err(errors::RunnerConfig::Invalid {
+							reason: format!(
+								"bearer token env var {env:?} is not in `pegboard.serverless_auth_env_allowlist`"
+							),
+						}
+						.build());
+					}
+				}
+				None => {}
+			}
+
+			if auth.is_some()
+				&& headers
+					.keys()
+					.any(|n| n.eq_ignore_ascii_case(reqwest::header::AUTHORIZATION.as_str()))
+			{
+				return Err(errors::RunnerConfig::Invalid {
+					reason: "`auth` cannot be combined with an `authorization` header".to_string(),
+				}
+				.build());
+			}
+
 			if *slots_per_runner == 0 {
 				return Err(errors::RunnerConfig::Invalid {
 					reason: "`slots_per_runner` cannot be 0".to_string(),
@@ -105,7 +165,7 @@ pub async fn pegboard_runner_config_upsert(ctx: &OperationCtx, input: &Input) ->
 	}
 
 	// TODO: Race
-	let existing_config = ctx
+	let (existing_config, existing_protocol_version) = ctx
 		.op(crate::ops::runner_config::get::Input {
 			runners: vec![(input.namespace_id, input.name.clone())],
 			bypass_cache: true,
@@ -113,9 +173,20 @@ pub async fn pegboard_runner_config_upsert(ctx: &OperationCtx, input: &Input) ->
 		.await?
 		.into_iter()
 		.next()
-		.map(|c| c.config);
+		.map(|c| (Some(c.config), c.protocol_version))
+		.unwrap_or_default();
+
+	// Envoy pools always POST the start command as the body of the `/start` request
+	if let RunnerConfigKind::Serverless { body: Some(_), .. } = &config.kind {
+		if existing_protocol_version.is_some() {
+			return Err(errors::RunnerConfig::Invalid {
+				reason: "`body` is not supported by envoy pools".to_string(),
+			}
+			.build());
+		}
+	}
 
-	// Check if config changed (for serverless, compare URL and headers)
+	// Check if config changed (for serverless, compare the `/start` request)
 	let (endpoint_config_changed, pool_created) = if let Some(existing_config) = &existing_config {
 		// Check if serverless endpoint config changed
 		match (&existing_config.kind, &config.kind) {
@@ -123,14 +194,27 @@ pub async fn pegboard_runner_config_upsert(ctx: &OperationCtx, input: &Input) ->
 				RunnerConfigKind::Serverless {
 					url: old_url,
 					headers: old_headers,
+					method: old_method,
+					body: old_body,
+					auth: old_auth,
 					..
 				},
 				RunnerConfigKind::Serverless {
 					url: new_url,
 					headers: new_headers,
+					method: new_method,
+					body: new_body,
+					auth: new_auth,
 					..
 				},
-			) => (old_url != new_url || old_headers != new_headers, false),
+			) => (
+				old_url != new_url
+					|| old_headers != new_headers
+					|| old_method != new_method
+					|| old_body != new_body
+					|| old_auth != new_auth,
+				false,
+			),
 			(RunnerConfigKind::Normal { .. }, RunnerConfigKind::Serverless { .. }) => {
 				// Config type changed to serverless
 				(true, true)
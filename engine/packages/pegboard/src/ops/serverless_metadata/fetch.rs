@@ -5,6 +5,7 @@ use anyhow::Result;
 use gas::prelude::*;
 use reqwest::header::{HeaderMap as ReqwestHeaderMap, HeaderName, HeaderValue};
 use rivet_envoy_protocol::PROTOCOL_VERSION;
+use rivet_types::actor::RunnerPoolErrorCategory;
 use rivetkit_shared_types::serverless_metadata::ServerlessMetadataPayload;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
@@ -21,7 +22,7 @@ pub struct Input {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ServerlessMetadataError {
 	InvalidRequest {},
-	RequestFailed {},
+	RequestFailed { reason: Option<String> },
 	RequestTimedOut {},
 	NonSuccessStatus { status_code: u16, body: String },
 	InvalidResponseJson { body: String, parse_error: String },
@@ -29,12 +30,30 @@ pub enum ServerlessMetadataError {
 	InvalidEnvoyProtocolVersion { version: u16, max_supported: u16 },
 }
 
+impl ServerlessMetadataError {
+	pub fn category(&self) -> RunnerPoolErrorCategory {
+		match self {
+			ServerlessMetadataError::InvalidRequest {} => RunnerPoolErrorCategory::ClientError,
+			ServerlessMetadataError::RequestFailed { .. } => RunnerPoolErrorCategory::Connection,
+			ServerlessMetadataError::RequestTimedOut {} => RunnerPoolErrorCategory::Timeout,
+			ServerlessMetadataError::NonSuccessStatus { status_code, .. } => {
+				RunnerPoolErrorCategory::from_status_code(*status_code)
+			}
+			ServerlessMetadataError::InvalidResponseJson { .. }
+			| ServerlessMetadataError::InvalidResponseSchema { .. }
+			| ServerlessMetadataError::InvalidEnvoyProtocolVersion { .. } => {
+				RunnerPoolErrorCategory::InvalidPayload
+			}
+		}
+	}
+}
+
 /// Wire-format envelope for serverless metadata errors.
 ///
 /// Surfaced to API clients with a stable `{message, details, metadata}` shape
 /// regardless of which internal `ServerlessMetadataError` variant produced it.
 /// `metadata.kind` discriminates the variant; per-variant fields live alongside
-/// `kind`.
+/// `kind`. `metadata.category` groups the failure by cause (e.g. `auth`, `timeout`).
 #[derive(Deserialize, Serialize, ToSchema, Clone, Debug, PartialEq, Eq)]
 #[schema(as = RunnerConfigsServerlessMetadataError)]
 pub struct ServerlessMetadataErrorEnvelope {
@@ -53,16 +72,20 @@ impl std::fmt::Display for ServerlessMetadataErrorEnvelope {
 
 impl From<ServerlessMetadataError> for ServerlessMetadataErrorEnvelope {
 	fn from(err: ServerlessMetadataError) -> Self {
-		match err {
+		let category = err.category();
+		let mut envelope = match err {
 			ServerlessMetadataError::InvalidRequest {} => Self {
 				message: "invalid serverless metadata request".to_string(),
 				details: None,
 				metadata: serde_json::json!({ "kind": "invalid_request" }),
 			},
-			ServerlessMetadataError::RequestFailed {} => Self {
+			ServerlessMetadataError::RequestFailed { reason } => Self {
 				message: "failed to reach serverless endpoint".to_string(),
-				details: None,
-				metadata: serde_json::json!({ "kind": "request_failed" }),
+				details: reason.clone(),
+				metadata: serde_json::json!({
+					"kind": "request_failed",
+					"reason": reason,
+				}),
 			},
 			ServerlessMetadataError::RequestTimedOut {} => Self {
 				message: "serverless metadata request timed out".to_string(),
@@ -110,7 +133,16 @@ impl From<ServerlessMetadataError> for ServerlessMetadataErrorEnvelope {
 					"max_supported_envoy_protocol_version": max_supported,
 				}),
 			},
+		};
+
+		if let Some(metadata) = envelope.metadata.as_object_mut() {
+			metadata.insert(
+				"category".to_string(),
+				serde_json::Value::String(category.as_str().to_string()),
+			);
 		}
+
+		envelope
 	}
 }
 
@@ -179,7 +211,9 @@ pub async fn pegboard_serverless_metadata_fetch(
 
 	let client = match rivet_pools::reqwest::client().await {
 		Ok(c) => c,
-		Err(_) => return Ok(Err(ServerlessMetadataError::RequestFailed {})),
+		Err(_) => {
+			return Ok(Err(ServerlessMetadataError::RequestFailed { reason: None }));
+		}
 	};
 
 	tracing::debug!("sending metadata request");
@@ -196,7 +230,16 @@ pub async fn pegboard_serverless_metadata_fetch(
 			return Ok(Err(if err.is_timeout() {
 				ServerlessMetadataError::RequestTimedOut {}
 			} else {
-				ServerlessMetadataError::RequestFailed {}
+				ServerlessMetadataError::RequestFailed {
+					// Include the entire error chain so the cause (DNS, connection refused, TLS) is visible
+					reason: Some(
+						anyhow::Error::from(err)
+							.chain()
+							.map(|err| err.to_string())
+							.collect::<Vec<_>>()
+							.join(": "),
+					),
+				}
 			}));
 		}
 	};
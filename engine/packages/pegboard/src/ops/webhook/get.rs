@@ -0,0 +1,28 @@
+use gas::prelude::*;
+use universaldb::utils::IsolationLevel::*;
+
+use crate::keys;
+
+#[derive(Debug)]
+pub struct Input {
+	pub namespace_id: Id,
+}
+
+#[operation]
+pub async fn pegboard_webhook_get(
+	ctx: &OperationCtx,
+	input: &Input,
+) -> Result<Option<keys::webhook::WebhookConfig>> {
+	ctx.udb()?
+		.txn("pegboard_webhook_get", |tx| async move {
+			let tx = tx.with_subspace(keys::subspace());
+
+			tx.read_opt(
+				&keys::webhook::WebhookConfigKey::new(input.namespace_id),
+				Serializable,
+			)
+			.await
+		})
+		.custom_instrument(tracing::info_span!("webhook_get_tx"))
+		.await
+}
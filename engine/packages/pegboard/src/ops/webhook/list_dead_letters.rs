@@ -0,0 +1,59 @@
+use futures_util::TryStreamExt;
+use gas::prelude::*;
+use universaldb::options::StreamingMode;
+use universaldb::utils::IsolationLevel::*;
+
+use crate::keys;
+
+#[derive(Debug)]
+pub struct Input {
+	pub namespace_id: Id,
+	pub limit: usize,
+}
+
+/// Lists notifications that could not be delivered, newest first.
+#[operation]
+pub async fn pegboard_webhook_list_dead_letters(
+	ctx: &OperationCtx,
+	input: &Input,
+) -> Result<Vec<keys::webhook::DeadLetter>> {
+	let expire_ts = util::timestamp::now() - ctx.config().pegboard().webhook_dead_letter_ttl();
+
+	ctx.udb()?
+		.txn("pegboard_webhook_list_dead_letters", |tx| async move {
+			let tx = tx.with_subspace(keys::subspace());
+			let dead_letter_subspace = keys::webhook::dead_letter_subspace(input.namespace_id);
+
+			let mut stream = tx.get_ranges_keyvalues(
+				universaldb::RangeOption {
+					mode: StreamingMode::Iterator,
+					reverse: true,
+					..(&dead_letter_subspace).into()
+				},
+				// NOTE: Does not have to be serializable because we are listing, stale data does not matter
+				Snapshot,
+			);
+
+			let mut dead_letters = Vec::new();
+			while let Some(entry) = stream.try_next().await? {
+				let (_, dead_letter) =
+					tx.read_entry::<keys::webhook::WebhookDeadLetterKey>(&entry)?;
+
+				// Expired dead letters are cleared lazily by later inserts. Since the list is newest first
+				// all remaining entries are expired too.
+				if dead_letter.ts < expire_ts {
+					break;
+				}
+
+				dead_letters.push(dead_letter);
+
+				if dead_letters.len() >= input.limit {
+					break;
+				}
+			}
+
+			Ok(dead_letters)
+		})
+		.custom_instrument(tracing::info_span!("webhook_list_dead_letters_tx"))
+		.await
+}
@@ -0,0 +1,5 @@
+pub mod get;
+pub mod list_dead_letters;
+pub mod notify;
+pub mod replay_dead_letters;
+pub mod set;
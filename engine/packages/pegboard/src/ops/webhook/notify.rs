@@ -0,0 +1,54 @@
+use gas::prelude::*;
+
+use crate::workflows::{
+	actor::FailureReason,
+	webhook::{WebhookEvent, WebhookPayload},
+};
+
+#[derive(Debug)]
+pub struct Input {
+	pub namespace_id: Id,
+	pub actor_id: Id,
+	pub actor_name: String,
+	pub event: WebhookEvent,
+	pub failure_reason: Option<FailureReason>,
+	/// Prevents sending the same notification twice when the caller is retried.
+	pub idempotency_key: String,
+}
+
+/// Dispatches a webhook notification if the namespace has a webhook configured. Delivery happens in the
+/// background.
+#[operation]
+pub async fn pegboard_webhook_notify(ctx: &OperationCtx, input: &Input) -> Result<()> {
+	let Some(config) = ctx
+		.op(super::get::Input {
+			namespace_id: input.namespace_id,
+		})
+		.await?
+	else {
+		return Ok(());
+	};
+
+	let payload = WebhookPayload {
+		notification_id: Id::new_v1(ctx.config().dc_label()),
+		event: input.event,
+		namespace_id: input.namespace_id,
+		actor_id: input.actor_id,
+		actor_name: input.actor_name.clone(),
+		ts: util::timestamp::now(),
+		failure_reason: input.failure_reason.clone(),
+	};
+
+	ctx.workflow(crate::workflows::webhook::Input {
+		namespace_id: input.namespace_id,
+		notification_id: payload.notification_id,
+		url: config.url,
+		body: serde_json::to_string(&payload)?,
+	})
+	.tag("namespace_id", input.namespace_id)
+	.idempotency_key(&input.idempotency_key)
+	.dispatch()
+	.await?;
+
+	Ok(())
+}
@@ -0,0 +1,89 @@
+use anyhow::bail;
+use gas::prelude::*;
+
+use crate::keys;
+
+#[derive(Debug)]
+pub struct Input {
+	pub namespace_id: Id,
+	/// Dead letters to replay. Replays every scanned dead letter if empty.
+	pub notification_ids: Vec<Id>,
+	/// How many of the newest dead letters to scan.
+	pub limit: usize,
+}
+
+/// Redelivers dead-lettered notifications to the url they were originally sent to, signed with the
+/// namespace's current webhook secret. Replayed dead letters are removed, notifications that fail again
+/// are dead-lettered anew. Returns the ids of the replayed notifications.
+#[operation]
+pub async fn pegboard_webhook_replay_dead_letters(
+	ctx: &OperationCtx,
+	input: &Input,
+) -> Result<Vec<Id>> {
+	if ctx
+		.op(super::get::Input {
+			namespace_id: input.namespace_id,
+		})
+		.await?
+		.is_none()
+	{
+		bail!("namespace has no webhook configured, dead letters cannot be signed");
+	};
+
+	let dead_letters = ctx
+		.op(super::list_dead_letters::Input {
+			namespace_id: input.namespace_id,
+			limit: input.limit,
+		})
+		.await?;
+
+	let mut replayed = Vec::new();
+	for dead_letter in dead_letters {
+		if !input.notification_ids.is_empty()
+			&& !input
+				.notification_ids
+				.contains(&dead_letter.notification_id)
+		{
+			continue;
+		}
+
+		ctx.workflow(crate::workflows::webhook::Input {
+			namespace_id: input.namespace_id,
+			notification_id: dead_letter.notification_id,
+			url: dead_letter.url.clone(),
+			body: dead_letter.body.clone(),
+		})
+		.tag("namespace_id", input.namespace_id)
+		// Keyed by the dead letter so replaying it twice does not send it twice
+		.idempotency_key(&format!(
+			"replay:{}:{}",
+			dead_letter.notification_id, dead_letter.ts
+		))
+		.dispatch()
+		.await?;
+
+		// Cleared after dispatching so a failure in between leaves the dead letter for the next replay
+		let dead_letter_key = keys::webhook::WebhookDeadLetterKey::new(
+			input.namespace_id,
+			dead_letter.ts,
+			dead_letter.notification_id,
+		);
+		ctx.udb()?
+			.txn("pegboard_webhook_clear_dead_letter", |tx| {
+				let dead_letter_key = &dead_letter_key;
+				async move {
+					let tx = tx.with_subspace(keys::subspace());
+					tx.delete(dead_letter_key);
+
+					Ok(())
+				}
+			})
+			.custom_instrument(tracing::info_span!("webhook_clear_dead_letter_tx"))
+			.await?;
+
+		tracing::debug!(notification_id=%dead_letter.notification_id, "replayed webhook dead letter");
+		replayed.push(dead_letter.notification_id);
+	}
+
+	Ok(replayed)
+}
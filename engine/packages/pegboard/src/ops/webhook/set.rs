@@ -0,0 +1,40 @@
+use anyhow::ensure;
+use gas::prelude::*;
+
+use crate::keys;
+
+#[derive(Debug)]
+pub struct Input {
+	pub namespace_id: Id,
+	/// Removes the webhook if not set.
+	pub config: Option<keys::webhook::WebhookConfig>,
+}
+
+/// Sets the webhook of a namespace in the current datacenter.
+#[operation]
+pub async fn pegboard_webhook_set(ctx: &OperationCtx, input: &Input) -> Result<()> {
+	if let Some(config) = &input.config {
+		let url = url::Url::parse(&config.url)?;
+		ensure!(
+			matches!(url.scheme(), "http" | "https"),
+			"webhook url must be http or https"
+		);
+		ensure!(!config.secret.is_empty(), "webhook secret cannot be empty");
+	}
+
+	ctx.udb()?
+		.txn("pegboard_webhook_set", |tx| async move {
+			let tx = tx.with_subspace(keys::subspace());
+
+			let config_key = keys::webhook::WebhookConfigKey::new(input.namespace_id);
+			if let Some(config) = &input.config {
+				tx.write(&config_key, config.clone())?;
+			} else {
+				tx.delete(&config_key);
+			}
+
+			Ok(())
+		})
+		.custom_instrument(tracing::info_span!("webhook_set_tx"))
+		.await
+}
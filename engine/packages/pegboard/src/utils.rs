@@ -2,10 +2,65 @@ use gas::prelude::*;
 use rivet_cache::Cache;
 use rivet_runner_protocol as protocol;
 use rivet_types::{
+	actors::ActorState,
 	keys::namespace::runner_config::RunnerConfigVariant,
 	runner_configs::{RunnerConfig, RunnerConfigKind},
 };
 
+const MAX_TAGS: usize = 32;
+const MAX_TAG_SIZE: usize = 256;
+
+/// Checks the limits on an actor's tags.
+pub fn validate_tags<'a>(
+	tags: impl ExactSizeIterator<Item = (&'a String, &'a String)>,
+) -> std::result::Result<(), crate::errors::Actor> {
+	if tags.len() > MAX_TAGS {
+		return Err(crate::errors::Actor::TooManyTags { max_tags: MAX_TAGS });
+	}
+
+	for (k, v) in tags {
+		if k.len() + v.len() > MAX_TAG_SIZE {
+			return Err(crate::errors::Actor::TagTooLarge {
+				max_size: MAX_TAG_SIZE,
+				tag_preview: util::safe_slice(k, 0, MAX_TAG_SIZE).to_string(),
+			});
+		}
+	}
+
+	Ok(())
+}
+
+/// Rejects batches larger than `pegboard.max_batch_size` before any work is done.
+pub fn validate_batch_size(config: &rivet_config::Config, size: usize) -> Result<()> {
+	let max_size = config.pegboard().max_batch_size();
+	if size > max_size {
+		return Err(crate::errors::Actor::BatchTooLarge { max_size, size }.build());
+	}
+
+	Ok(())
+}
+
+/// Rejects actor create batches larger than `pegboard.max_create_batch_size` before any work is done.
+pub fn validate_create_batch_size(config: &rivet_config::Config, size: usize) -> Result<()> {
+	let max_size = config.pegboard().max_create_batch_size();
+	if size > max_size {
+		return Err(crate::errors::Actor::BatchTooLarge { max_size, size }.build());
+	}
+
+	Ok(())
+}
+
+/// Rejects actor destroy batches larger than `pegboard.max_destroy_batch_size` before any work is
+/// done.
+pub fn validate_destroy_batch_size(config: &rivet_config::Config, size: usize) -> Result<()> {
+	let max_size = config.pegboard().max_destroy_batch_size();
+	if size > max_size {
+		return Err(crate::errors::Actor::BatchTooLarge { max_size, size }.build());
+	}
+
+	Ok(())
+}
+
 pub fn event_actor_id_mk1(event: &protocol::Event) -> &str {
 	match event {
 		protocol::Event::EventActorIntent(protocol::EventActorIntent { actor_id, .. }) => actor_id,
@@ -61,3 +116,89 @@ pub async fn purge_runner_config_caches(
 
 	Ok(())
 }
+
+/// Flags an allocation attempt whose runner name selector does not resolve to any runner config.
+///
+/// Every runner pool (serverless or normal) has a runner config, so a missing config means the
+/// actor can never be routed to a provider and will stay pending until one is configured.
+pub fn flag_unrouted_selector(namespace_id: Id, runner_name: &str) {
+	tracing::warn!(
+		?namespace_id,
+		%runner_name,
+		"runner name selector does not match any runner config, actor cannot be routed to a pool"
+	);
+
+	crate::metrics::ACTOR_UNROUTED_SELECTOR_TOTAL
+		.with_label_values(&[&namespace_id.to_string(), runner_name])
+		.inc();
+}
+
+/// Returns true if the runner advertises every one of the required capabilities.
+///
+/// Capabilities are written once when the runner connects, so this is a snapshot read to avoid
+/// conflicting with the rest of the runner's data.
+pub async fn runner_has_capabilities(
+	tx: &universaldb::Transaction,
+	runner_id: Id,
+	required_capabilities: &[String],
+) -> Result<bool> {
+	if required_capabilities.is_empty() {
+		return Ok(true);
+	}
+
+	let capabilities = tx
+		.read_opt(
+			&crate::keys::runner::CapabilitiesKey::new(runner_id),
+			universaldb::utils::IsolationLevel::Snapshot,
+		)
+		.await?
+		.unwrap_or_default();
+
+	Ok(required_capabilities
+		.iter()
+		.all(|required| capabilities.contains(required)))
+}
+
+/// Moves the actor to `state` in the namespace state index, removing the entry for the state it
+/// was listed under before.
+///
+/// The entry is always rewritten so it points at `workflow_id` after an actor migrates workflows.
+pub async fn update_actor_state_idx(
+	tx: &universaldb::Transaction,
+	namespace_id: Id,
+	name: &str,
+	create_ts: i64,
+	actor_id: Id,
+	workflow_id: Id,
+	state: ActorState,
+) -> Result<()> {
+	let tx = tx.with_subspace(crate::keys::subspace());
+
+	let state_key = crate::keys::actor::StateKey::new(actor_id);
+	let old_state = tx
+		.read_opt(&state_key, universaldb::utils::IsolationLevel::Serializable)
+		.await?;
+	if let Some(old_state) = old_state.filter(|old_state| *old_state != state) {
+		tx.delete(&crate::keys::ns::ActorByStateKey::new(
+			namespace_id,
+			name.to_string(),
+			old_state,
+			create_ts,
+			actor_id,
+		));
+	}
+
+	tx.write(
+		&crate::keys::ns::ActorByStateKey::new(
+			namespace_id,
+			name.to_string(),
+			state,
+			create_ts,
+			actor_id,
+		),
+		workflow_id,
+	)?;
+	tx.write(&state_key, state)?;
+
+	Ok(())
+}
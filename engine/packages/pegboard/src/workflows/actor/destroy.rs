@@ -1,13 +1,39 @@
+use futures_util::TryStreamExt;
 use gas::prelude::*;
 use rivet_data::converted::ActorByKeyKeyData;
 use rivet_runner_protocol::PROTOCOL_MK1_VERSION;
-use universaldb::options::MutationType;
+use rivet_types::actors::ActorState;
+use universaldb::options::{MutationType, StreamingMode};
 use universaldb::utils::IsolationLevel::*;
 
 use super::{DestroyComplete, DestroyStarted, State};
 
 use crate::keys;
 
+/// Max expired audit records cleared per destroy.
+const EXPIRED_AUDIT_CLEAR_LIMIT: usize = 100;
+
+/// What triggered an actor destroy.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum DestroyReason {
+	/// Destroyed before destroy reasons were recorded.
+	#[default]
+	Unknown,
+	/// Received a destroy signal, for example from the destroy actor API.
+	Requested,
+	/// Failed validation during creation, for example because its key is already reserved.
+	ValidationFailed,
+	/// Exited gracefully.
+	Exited,
+	/// Crashed with the destroy crash policy.
+	Crashed,
+	/// Lost its runner with the destroy crash policy.
+	Lost,
+	/// Stopped while its runner was going away with the destroy crash policy.
+	GoingAway,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub(crate) struct Input {
 	pub namespace_id: Id,
@@ -15,6 +41,8 @@ pub(crate) struct Input {
 	pub name: String,
 	pub key: Option<String>,
 	pub generation: u32,
+	#[serde(default)]
+	pub reason: DestroyReason,
 }
 
 #[workflow]
@@ -27,6 +55,8 @@ pub(crate) async fn pegboard_actor_destroy(ctx: &mut WorkflowCtx, input: &Input)
 	let res = ctx
 		.activity(UpdateStateAndDbInput {
 			actor_id: input.actor_id,
+			generation: input.generation,
+			reason: input.reason,
 		})
 		.await?;
 
@@ -77,6 +107,10 @@ pub(crate) async fn pegboard_actor_destroy(ctx: &mut WorkflowCtx, input: &Input)
 #[derive(Debug, Serialize, Deserialize, Hash)]
 struct UpdateStateAndDbInput {
 	actor_id: Id,
+	#[serde(default)]
+	generation: u32,
+	#[serde(default)]
+	reason: DestroyReason,
 }
 
 #[derive(Debug, Serialize, Deserialize, Hash)]
@@ -100,6 +134,17 @@ async fn update_state_and_db(
 	let name = &state.name;
 	let create_ts = state.create_ts;
 	let key = &state.key;
+	let tags = &state.tags;
+	let audit = &keys::destroy_audit::DestroyAudit {
+		reason: input.reason,
+		destroy_ts,
+		generation: input.generation,
+		runner_id: state.runner_id,
+		start_ts: state.start_ts,
+		connectable_ts: state.connectable_ts,
+		sleep_ts: state.sleep_ts,
+	};
+	let audit_ttl = ctx.config().pegboard().actor_destroy_audit_ttl();
 	ctx.udb()?
 		.txn("pegboard_actor_destroy", |tx| {
 			async move {
@@ -124,6 +169,34 @@ async fn update_state_and_db(
 					create_ts,
 					input.actor_id,
 				));
+				crate::utils::update_actor_state_idx(
+					&tx,
+					namespace_id,
+					name,
+					create_ts,
+					input.actor_id,
+					ctx.workflow_id(),
+					ActorState::Destroyed,
+				)
+				.await?;
+				for (k, v) in tags {
+					tx.delete(&keys::ns::ActorByTagKey::new(
+						namespace_id,
+						k.clone(),
+						v.clone(),
+						create_ts,
+						input.actor_id,
+					));
+				}
+
+				// Actors that took over a key through `ops::actor::transfer_key` have no key in their state
+				let key = match key {
+					Some(key) => Some(key.clone()),
+					None => {
+						tx.read_opt(&keys::actor::KeyKey::new(input.actor_id), Serializable)
+							.await?
+					}
+				};
 
 				if let Some(key) = &key {
 					tx.write(
@@ -149,6 +222,17 @@ async fn update_state_and_db(
 					-1,
 				);
 
+				tx.write(
+					&keys::destroy_audit::DestroyAuditKey::new(input.actor_id),
+					audit.clone(),
+				)?;
+				tx.write(
+					&keys::destroy_audit::DestroyAuditByTsKey::new(destroy_ts, input.actor_id),
+					(),
+				)?;
+
+				clear_expired_audits(destroy_ts - audit_ttl, &tx).await?;
+
 				Ok(())
 			}
 		})
@@ -192,6 +276,15 @@ async fn clear_kv(ctx: &ActivityCtx, input: &ClearKvInput) -> Result<ClearKvOutp
 			tx.clear_subspace_range(&subspace);
 			crate::actor_sqlite::clear_v2_storage_for_destroy(&tx, input.actor_id);
 
+			// Responses cached by guard
+			tx.clear_subspace_range(
+				&keys::subspace()
+					.subspace(&keys::actor::ResponseCacheKey::subspace(input.actor_id)),
+			);
+
+			// Logs forwarded by the runner
+			tx.clear_subspace_range(&keys::actor_log::subspace(input.actor_id));
+
 			Ok(final_size)
 		})
 		.custom_instrument(tracing::info_span!("actor_clear_kv_tx"))
@@ -200,6 +293,35 @@ async fn clear_kv(ctx: &ActivityCtx, input: &ClearKvInput) -> Result<ClearKvOutp
 	Ok(ClearKvOutput { final_size })
 }
 
+/// Clears a batch of destroy audit records older than `expire_ts`.
+async fn clear_expired_audits(expire_ts: i64, tx: &universaldb::Transaction) -> Result<()> {
+	let (start, end) = keys::destroy_audit::by_ts_range_before(expire_ts);
+
+	// Snapshot read so concurrent destroys clearing the same records do not conflict
+	let entries = tx
+		.get_ranges_keyvalues(
+			universaldb::RangeOption {
+				mode: StreamingMode::Exact,
+				limit: Some(EXPIRED_AUDIT_CLEAR_LIMIT),
+				..(start, end).into()
+			},
+			Snapshot,
+		)
+		.try_collect::<Vec<_>>()
+		.await?;
+
+	for entry in entries {
+		let by_ts_key = tx.unpack::<keys::destroy_audit::DestroyAuditByTsKey>(entry.key())?;
+
+		tx.delete(&keys::destroy_audit::DestroyAuditKey::new(
+			by_ts_key.actor_id,
+		));
+		tx.delete(&by_ts_key);
+	}
+
+	Ok(())
+}
+
 pub(crate) async fn clear_slot(
 	actor_id: Id,
 	namespace_id: Id,
@@ -1,7 +1,9 @@
+use std::collections::HashMap;
+
 use futures_util::FutureExt;
 use gas::prelude::*;
 use rivet_runner_protocol as protocol;
-use rivet_types::actors::CrashPolicy;
+use rivet_types::actors::{ActorAffinity, ActorState, CrashPolicy, PendingReason};
 
 use crate::{errors, workflows::runner2::AllocatePendingActorsInput};
 
@@ -11,6 +13,7 @@ pub mod metrics;
 mod runtime;
 mod setup;
 
+pub use destroy::DestroyReason;
 pub use runtime::AllocationOverride;
 
 /// Batch size of how many events to ack.
@@ -26,7 +29,17 @@ pub struct Input {
 
 	pub namespace_id: Id,
 	pub runner_name_selector: String,
-	pub crash_policy: CrashPolicy,
+	/// Falls back to the namespace's default crash policy if not set. Always set after validation.
+	pub crash_policy: Option<CrashPolicy>,
+	/// Soft placement rules honored when allocating the actor to a runner.
+	#[serde(default)]
+	pub affinity: Option<ActorAffinity>,
+	/// Capabilities a runner must advertise for the actor to be allocated to it.
+	#[serde(default)]
+	pub required_capabilities: Vec<String>,
+	/// Free-form tags used to group and filter actors. See `ops::actor::list_for_tags`.
+	#[serde(default)]
+	pub tags: HashMap<String, String>,
 
 	/// Arbitrary user-provided binary encoded in base64. We assume this is valid base64.
 	pub input: Option<String>,
@@ -54,17 +67,27 @@ pub async fn pegboard_actor(ctx: &mut WorkflowCtx, input: &Input) -> Result<()>
 			key: input.key.clone(),
 			namespace_id: input.namespace_id,
 			input: input.input.clone(),
+			crash_policy: input.crash_policy,
+			tags: input.tags.clone().into_iter().collect(),
 		})
 		.await?;
 
-	if let Err(error) = validation_res {
-		ctx.msg(Failed { error })
-			.topic(("actor_id", input.actor_id))
-			.send()
-			.await?;
+	let crash_policy = match validation_res {
+		// Workflows validated before the crash policy was resolved always have it set in their input
+		Ok(crash_policy) => crash_policy.or(input.crash_policy).unwrap_or_default(),
+		Err(error) => {
+			ctx.msg(Failed { error })
+				.topic(("actor_id", input.actor_id))
+				.send()
+				.await?;
 
-		return Ok(());
-	}
+			return Ok(());
+		}
+	};
+	let input = &Input {
+		crash_policy: Some(crash_policy),
+		..input.clone()
+	};
 
 	ctx.activity(setup::InitStateAndUdbInput {
 		actor_id: input.actor_id,
@@ -72,8 +95,9 @@ pub async fn pegboard_actor(ctx: &mut WorkflowCtx, input: &Input) -> Result<()>
 		key: input.key.clone(),
 		namespace_id: input.namespace_id,
 		runner_name_selector: input.runner_name_selector.clone(),
-		crash_policy: input.crash_policy,
+		crash_policy,
 		create_ts: ctx.create_ts(),
+		required_capabilities: input.required_capabilities.clone(),
 	})
 	.await?;
 
@@ -120,6 +144,7 @@ pub async fn pegboard_actor(ctx: &mut WorkflowCtx, input: &Input) -> Result<()>
 					name: input.name.clone(),
 					key: input.key.clone(),
 					generation: 0,
+					reason: DestroyReason::ValidationFailed,
 				})
 				.output()
 				.await?;
@@ -144,6 +169,7 @@ pub async fn pegboard_actor(ctx: &mut WorkflowCtx, input: &Input) -> Result<()>
 					name: input.name.clone(),
 					key: input.key.clone(),
 					generation: 0,
+					reason: DestroyReason::ValidationFailed,
 				})
 				.output()
 				.await?;
@@ -167,6 +193,7 @@ pub async fn pegboard_actor(ctx: &mut WorkflowCtx, input: &Input) -> Result<()>
 
 	ctx.activity(setup::AddIndexesAndSetCreateCompleteInput {
 		actor_id: input.actor_id,
+		tags: input.tags.clone().into_iter().collect(),
 	})
 	.await?;
 
@@ -211,6 +238,7 @@ pub async fn pegboard_actor(ctx: &mut WorkflowCtx, input: &Input) -> Result<()>
 					name: input.name.clone(),
 					key: input.key.clone(),
 					generation: 0,
+					reason: DestroyReason::Requested,
 				})
 				.output()
 				.await?;
@@ -232,6 +260,7 @@ pub async fn pegboard_actor(ctx: &mut WorkflowCtx, input: &Input) -> Result<()>
 					key: input.key.clone(),
 					namespace_id: input.namespace_id,
 					input: input.input.clone(),
+					tags: input.tags.clone(),
 					from_v1: true,
 				})
 				.tag("actor_id", input.actor_id)
@@ -414,16 +443,18 @@ pub async fn pegboard_actor(ctx: &mut WorkflowCtx, input: &Input) -> Result<()>
 										.await?
 										{
 											StoppedResult::Continue => {}
-											StoppedResult::Destroy => {
+											StoppedResult::Destroy(reason) => {
 												return Ok(Loop::Break(runtime::LifecycleResult {
 													generation: state.generation,
 													migrate_to_v2: false,
+													destroy_reason: Some(reason),
 												}));
 											}
 											StoppedResult::MigrateToV2 => {
 												return Ok(Loop::Break(runtime::LifecycleResult {
 													generation: state.generation,
 													migrate_to_v2: true,
+													destroy_reason: None,
 												}));
 											}
 										}
@@ -564,16 +595,18 @@ pub async fn pegboard_actor(ctx: &mut WorkflowCtx, input: &Input) -> Result<()>
 											.await?
 											{
 												StoppedResult::Continue => {}
-												StoppedResult::Destroy => {
+												StoppedResult::Destroy(reason) => {
 													return Ok(Loop::Break(runtime::LifecycleResult {
 														generation: state.generation,
 														migrate_to_v2: false,
+														destroy_reason: Some(reason),
 													}));
 												}
 												StoppedResult::MigrateToV2 => {
 													return Ok(Loop::Break(runtime::LifecycleResult {
 														generation: state.generation,
 														migrate_to_v2: true,
+														destroy_reason: None,
 													}));
 												}
 											}
@@ -675,12 +708,14 @@ pub async fn pegboard_actor(ctx: &mut WorkflowCtx, input: &Input) -> Result<()>
 											return Ok(Loop::Break(runtime::LifecycleResult {
 												generation: state.generation,
 												migrate_to_v2: false,
+												destroy_reason: Some(DestroyReason::Requested),
 											}));
 										}
 										runtime::SpawnActorOutput::MigrateToV2 => {
 											return Ok(Loop::Break(runtime::LifecycleResult {
 												generation: state.generation,
 												migrate_to_v2: true,
+												destroy_reason: None,
 											}));
 										}
 									}
@@ -743,16 +778,18 @@ pub async fn pegboard_actor(ctx: &mut WorkflowCtx, input: &Input) -> Result<()>
 							.await?
 							{
 								StoppedResult::Continue => {}
-								StoppedResult::Destroy => {
+								StoppedResult::Destroy(reason) => {
 									return Ok(Loop::Break(runtime::LifecycleResult {
 										generation: state.generation,
 										migrate_to_v2: false,
+										destroy_reason: Some(reason),
 									}));
 								}
 								StoppedResult::MigrateToV2 => {
 									return Ok(Loop::Break(runtime::LifecycleResult {
 										generation: state.generation,
 										migrate_to_v2: true,
+										destroy_reason: None,
 									}));
 								}
 							}
@@ -839,6 +876,7 @@ pub async fn pegboard_actor(ctx: &mut WorkflowCtx, input: &Input) -> Result<()>
 							return Ok(Loop::Break(runtime::LifecycleResult {
 								generation: state.generation,
 								migrate_to_v2: false,
+								destroy_reason: Some(DestroyReason::Requested),
 							}));
 						}
 					}
@@ -869,6 +907,7 @@ pub async fn pegboard_actor(ctx: &mut WorkflowCtx, input: &Input) -> Result<()>
 			key: input.key.clone(),
 			namespace_id: input.namespace_id,
 			input: input.input.clone(),
+			tags: input.tags.clone(),
 			from_v1: true,
 		})
 		.tag("actor_id", input.actor_id)
@@ -886,6 +925,7 @@ pub async fn pegboard_actor(ctx: &mut WorkflowCtx, input: &Input) -> Result<()>
 			name: input.name.clone(),
 			key: input.key.clone(),
 			generation: lifecycle_res.generation,
+			reason: lifecycle_res.destroy_reason.unwrap_or_default(),
 		})
 		.output()
 		.await?;
@@ -920,6 +960,10 @@ pub struct State {
 	pub complete_ts: Option<i64>,
 	pub connectable_ts: Option<i64>,
 	pub pending_allocation_ts: Option<i64>,
+	/// Why the last allocation attempt left the actor pending. Set and cleared alongside
+	/// `pending_allocation_ts`.
+	#[serde(default)]
+	pub pending_reason: Option<PendingReason>,
 	#[serde(default)]
 	pub reschedule_ts: Option<i64>,
 	pub destroy_ts: Option<i64>,
@@ -938,6 +982,10 @@ pub struct State {
 	/// - When actor becomes connectable
 	#[serde(default)]
 	pub failure_reason: Option<FailureReason>,
+
+	/// Set once the actor is added to the tag indexes.
+	#[serde(default)]
+	pub tags: HashMap<String, String>,
 }
 
 impl State {
@@ -965,6 +1013,7 @@ impl State {
 
 			start_ts: None,
 			pending_allocation_ts: None,
+			pending_reason: None,
 			sleep_ts: None,
 			connectable_ts: None,
 			complete_ts: None,
@@ -976,8 +1025,20 @@ impl State {
 			runner_state: None,
 
 			failure_reason: None,
+
+			tags: HashMap::new(),
 		}
 	}
+
+	/// State the actor is listed under in the namespace state index.
+	pub fn actor_state(&self) -> ActorState {
+		ActorState::from_timestamps(
+			self.destroy_ts,
+			self.sleep_ts,
+			self.connectable_ts,
+			self.pending_allocation_ts,
+		)
+	}
 }
 
 /// Reason why an actor failed to allocate or run.
@@ -1040,7 +1101,7 @@ enum StoppedVariant {
 
 enum StoppedResult {
 	Continue,
-	Destroy,
+	Destroy(DestroyReason),
 	MigrateToV2,
 }
 
@@ -1069,6 +1130,7 @@ async fn handle_stopped(
 		} => {
 			ctx.v(3)
 				.activity(runtime::SetFailureReasonInput {
+					actor_id: input.actor_id,
 					failure_reason: FailureReason::Crashed {
 						message: message.clone(),
 					},
@@ -1086,6 +1148,7 @@ async fn handle_stopped(
 			if let Some(failure_reason) = &failure_reason {
 				ctx.v(3)
 					.activity(runtime::SetFailureReasonInput {
+						actor_id: input.actor_id,
 						failure_reason: failure_reason.clone(),
 					})
 					.await?;
@@ -1224,7 +1287,9 @@ async fn handle_stopped(
 				.await?;
 			}
 			// Destroyed early
-			runtime::SpawnActorOutput::Destroy => return Ok(StoppedResult::Destroy),
+			runtime::SpawnActorOutput::Destroy => {
+				return Ok(StoppedResult::Destroy(DestroyReason::Requested));
+			}
 			runtime::SpawnActorOutput::MigrateToV2 => return Ok(StoppedResult::MigrateToV2),
 		}
 	}
@@ -1239,7 +1304,7 @@ async fn handle_stopped(
 				}
 			);
 
-		match (input.crash_policy, graceful_exit) {
+		match (input.crash_policy.unwrap_or_default(), graceful_exit) {
 			(CrashPolicy::Restart, false) => {
 				match runtime::reschedule_actor(
 					ctx,
@@ -1266,7 +1331,7 @@ async fn handle_stopped(
 					}
 					runtime::SpawnActorOutput::Destroy => {
 						// Destroyed early
-						return Ok(StoppedResult::Destroy);
+						return Ok(StoppedResult::Destroy(DestroyReason::Requested));
 					}
 					runtime::SpawnActorOutput::MigrateToV2 => {
 						return Ok(StoppedResult::MigrateToV2);
@@ -1288,7 +1353,17 @@ async fn handle_stopped(
 			_ => {
 				ctx.activity(runtime::SetCompleteInput {}).await?;
 
-				return Ok(StoppedResult::Destroy);
+				let reason = if graceful_exit {
+					DestroyReason::Exited
+				} else if state.going_away {
+					DestroyReason::GoingAway
+				} else if matches!(variant, StoppedVariant::Lost { .. }) {
+					DestroyReason::Lost
+				} else {
+					DestroyReason::Crashed
+				};
+
+				return Ok(StoppedResult::Destroy(reason));
 			}
 		}
 	}
@@ -1310,7 +1385,9 @@ async fn handle_stopped(
 				state.sleeping = true;
 			}
 			// Destroyed early
-			runtime::SpawnActorOutput::Destroy => return Ok(StoppedResult::Destroy),
+			runtime::SpawnActorOutput::Destroy => {
+				return Ok(StoppedResult::Destroy(DestroyReason::Requested));
+			}
 			runtime::SpawnActorOutput::MigrateToV2 => return Ok(StoppedResult::MigrateToV2),
 		}
 	}
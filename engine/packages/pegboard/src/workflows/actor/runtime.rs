@@ -4,12 +4,13 @@ use base64::prelude::BASE64_STANDARD;
 use futures_util::StreamExt;
 use futures_util::TryStreamExt;
 use gas::prelude::*;
-use rand::prelude::SliceRandom;
+use rand::{Rng, prelude::SliceRandom};
 use rivet_runner_protocol::{
 	self as protocol, PROTOCOL_MK1_VERSION, PROTOCOL_MK2_VERSION, versioned,
 };
-use rivet_types::actors::CrashPolicy;
+use rivet_types::actors::{ActorAffinity, CrashPolicy, PendingReason};
 use rivet_types::runner_configs::RunnerConfigKind;
+use std::collections::HashSet;
 use std::time::Instant;
 use universaldb::prelude::*;
 use universalpubsub::PublishOpts;
@@ -17,7 +18,7 @@ use vbare::OwnedVersionedData;
 
 use super::FailureReason;
 
-use crate::{keys, metrics};
+use crate::{keys, metrics, workflows::webhook::WebhookEvent};
 
 use super::{Allocate, Destroy, Input, PendingAllocation, State, destroy};
 
@@ -112,6 +113,9 @@ pub struct LifecycleResult {
 	pub generation: u32,
 	#[serde(default)]
 	pub migrate_to_v2: bool,
+	/// Set if the actor should be destroyed.
+	#[serde(default)]
+	pub destroy_reason: Option<super::DestroyReason>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Default)]
@@ -134,12 +138,29 @@ async fn update_runner(ctx: &ActivityCtx, input: &UpdateRunnerInput) -> Result<(
 
 	state.sleep_ts = None;
 	state.pending_allocation_ts = None;
+	state.pending_reason = None;
 	state.failure_reason = None;
 	state.runner_id = Some(input.runner_id);
 	state.runner_workflow_id = Some(input.runner_workflow_id);
 
+	let namespace_id = state.namespace_id;
+	let name = &state.name;
+	let create_ts = state.create_ts;
+	let actor_state = state.actor_state();
+
 	ctx.udb()?
 		.txn("pegboard_actor_runtime_clear_sleep", |tx| async move {
+			crate::utils::update_actor_state_idx(
+				&tx,
+				namespace_id,
+				name,
+				create_ts,
+				input.actor_id,
+				ctx.workflow_id(),
+				actor_state,
+			)
+			.await?;
+
 			let tx = tx.with_subspace(keys::subspace());
 
 			// Set actor as not sleeping
@@ -185,6 +206,10 @@ struct AllocateActorInputV2 {
 	actor_id: Id,
 	generation: u32,
 	force_allocate: bool,
+	#[serde(default)]
+	affinity: Option<ActorAffinity>,
+	#[serde(default)]
+	required_capabilities: Vec<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -210,6 +235,7 @@ impl From<AllocateActorOutputV1> for AllocateActorOutputV2 {
 					pending_allocation_ts,
 				} => AllocateActorStatus::Pending {
 					pending_allocation_ts,
+					pending_reason: None,
 				},
 				AllocateActorOutputV1::Sleep => AllocateActorStatus::Sleep,
 			},
@@ -228,6 +254,8 @@ enum AllocateActorStatus {
 	},
 	Pending {
 		pending_allocation_ts: i64,
+		#[serde(default)]
+		pending_reason: Option<PendingReason>,
 	},
 	Sleep,
 	MigrateToV2,
@@ -259,6 +287,9 @@ async fn allocate_actor_v2(
 		})
 		.await?;
 	let pool = pool_res.into_iter().next();
+	if pool.is_none() {
+		crate::utils::flag_unrouted_selector(namespace_id, runner_name_selector);
+	}
 	let for_serverless = pool
 		.as_ref()
 		.map(|pool| matches!(pool.config.kind, RunnerConfigKind::Serverless { .. }))
@@ -361,6 +392,17 @@ async fn allocate_actor_v2(
 						continue;
 					}
 
+					// Ignore runners missing a required capability
+					if !crate::utils::runner_has_capabilities(
+						&tx,
+						old_runner_alloc_key.runner_id,
+						&input.required_capabilities,
+					)
+					.await?
+					{
+						continue;
+					}
+
 					candidates.push((old_runner_alloc_key, old_runner_alloc_key_data));
 
 					// Max candidate size reached
@@ -369,6 +411,10 @@ async fn allocate_actor_v2(
 					}
 				}
 
+				if let Some(affinity) = &input.affinity {
+					apply_affinity(&tx, affinity, &mut candidates).await?;
+				}
+
 				if !candidates.is_empty() {
 					// Select a candidate at random, weighted by remaining slots
 					let (old_runner_alloc_key, old_runner_alloc_key_data) = candidates
@@ -452,6 +498,12 @@ async fn allocate_actor_v2(
 				// Write the actor to the alloc queue to wait
 				_ => {
 					let pending_allocation_ts = util::timestamp::now();
+					let pending_reason = if queue_exists {
+						PendingReason::NoCapacity
+					} else {
+						read_pending_reason(&tx, namespace_id, runner_name_selector, for_serverless)
+							.await?
+					};
 
 					// NOTE: This will conflict with serializable reads to the alloc queue, which is the behavior we
 					// want. If a runner reads from the queue while this is being inserted, one of the two txns will
@@ -470,6 +522,7 @@ async fn allocate_actor_v2(
 						serverless: for_serverless,
 						status: AllocateActorStatus::Pending {
 							pending_allocation_ts,
+							pending_reason: Some(pending_reason),
 						},
 					})
 				}
@@ -507,20 +560,23 @@ async fn allocate_actor_v2(
 		} => {
 			state.sleep_ts = None;
 			state.pending_allocation_ts = None;
+			state.pending_reason = None;
 			state.failure_reason = None;
 			state.runner_id = Some(*runner_id);
 			state.runner_workflow_id = Some(*runner_workflow_id);
 		}
 		AllocateActorStatus::Pending {
 			pending_allocation_ts,
-			..
+			pending_reason,
 		} => {
 			tracing::debug!(
 				actor_id=?input.actor_id,
+				?pending_reason,
 				"failed to allocate (no availability), waiting for allocation",
 			);
 
 			state.pending_allocation_ts = Some(*pending_allocation_ts);
+			state.pending_reason = *pending_reason;
 			if state.failure_reason.is_none() {
 				state.failure_reason = Some(super::FailureReason::NoCapacity);
 			}
@@ -533,6 +589,8 @@ async fn allocate_actor_v2(
 		AllocateActorStatus::MigrateToV2 => bail!("should not be migrate_to_v2"),
 	}
 
+	update_state_idx(ctx, input.actor_id, &state).await?;
+
 	Ok(res)
 }
 
@@ -562,6 +620,8 @@ pub async fn set_not_connectable(ctx: &ActivityCtx, input: &SetNotConnectableInp
 
 	state.connectable_ts = None;
 
+	update_state_idx(ctx, input.actor_id, &state).await?;
+
 	Ok(())
 }
 
@@ -611,6 +671,10 @@ pub async fn deallocate(ctx: &ActivityCtx, input: &DeallocateInput) -> Result<De
 	// Slot was cleared by the above txn
 	state.allocated_serverless_slot = false;
 
+	update_state_idx(ctx, input.actor_id, &state).await?;
+
+	notify_webhook(ctx, &state, input.actor_id, WebhookEvent::Stopped, None).await;
+
 	Ok(DeallocateOutput {
 		for_serverless: state.for_serverless,
 	})
@@ -669,6 +733,8 @@ pub async fn spawn_actor(
 						&allocation_override,
 						AllocationOverride::DontSleep { .. }
 					),
+					affinity: input.affinity.clone(),
+					required_capabilities: input.required_capabilities.clone(),
 				})
 				.await?
 		}
@@ -771,6 +837,7 @@ pub async fn spawn_actor(
 		}
 		AllocateActorStatus::Pending {
 			pending_allocation_ts,
+			..
 		} => {
 			ctx.removed::<Message<super::BumpServerlessAutoscalerStub>>()
 				.await?;
@@ -1103,6 +1170,26 @@ pub async fn reschedule_actor(
 		}
 	}
 
+	// Pace reschedules per runner pool so that losing a runner with many actors does not reallocate all of
+	// them at once. Actors in crash backoff only claim a slot once their backoff ends, so actors that are
+	// healthy but lost their runner are reallocated first.
+	let slot_ts = ctx
+		.v(3)
+		.activity(ClaimRescheduleSlotInput {
+			namespace_id: input.namespace_id,
+			runner_name_selector: input.runner_name_selector.clone(),
+		})
+		.await?;
+
+	if let Some(slot_ts) = slot_ts {
+		// Wait for slot or destroy early
+		if let Some(_sig) = ctx.v(3).listen_until::<Destroy>(slot_ts).await? {
+			tracing::debug!("destroying before actor start");
+
+			return Ok(SpawnActorOutput::Destroy);
+		}
+	}
+
 	let next_generation = state.generation + 1;
 	let spawn_res = spawn_actor(ctx, &input, next_generation, allocation_override).await?;
 
@@ -1134,6 +1221,105 @@ pub async fn reschedule_actor(
 	Ok(spawn_res)
 }
 
+/// Narrows allocation candidates according to the actor's affinity rules. Rules are soft constraints:
+/// a rule that no candidate satisfies is ignored rather than leaving the actor pending.
+async fn apply_affinity(
+	tx: &universaldb::Transaction,
+	affinity: &ActorAffinity,
+	candidates: &mut Vec<(
+		keys::ns::RunnerAllocIdxKey,
+		rivet_data::converted::RunnerAllocIdxKeyData,
+	)>,
+) -> Result<()> {
+	let (colocate_runner_ids, avoid_runner_ids) = tokio::try_join!(
+		read_actor_runner_ids(tx, &affinity.colocate_with),
+		read_actor_runner_ids(tx, &affinity.avoid),
+	)?;
+
+	if candidates
+		.iter()
+		.any(|(key, _)| !avoid_runner_ids.contains(&key.runner_id))
+	{
+		candidates.retain(|(key, _)| !avoid_runner_ids.contains(&key.runner_id));
+	}
+
+	if candidates
+		.iter()
+		.any(|(key, _)| colocate_runner_ids.contains(&key.runner_id))
+	{
+		candidates.retain(|(key, _)| colocate_runner_ids.contains(&key.runner_id));
+	}
+
+	Ok(())
+}
+
+/// Reads the runners that the given actors are currently allocated to.
+async fn read_actor_runner_ids(
+	tx: &universaldb::Transaction,
+	actor_ids: &[Id],
+) -> Result<HashSet<Id>> {
+	let runner_ids =
+		futures_util::future::try_join_all(actor_ids.iter().map(|actor_id| async move {
+			// NOTE: Snapshot because affinity is a soft constraint, we don't want to conflict
+			// with other actors being rescheduled
+			tx.read_opt(&keys::actor::RunnerIdKey::new(*actor_id), Snapshot)
+				.await
+		}))
+		.await?;
+
+	Ok(runner_ids.into_iter().flatten().collect())
+}
+
+/// Number of active runners to inspect when determining why an actor could not be allocated.
+const PENDING_REASON_RUNNER_SAMPLE_SIZE: usize = 64;
+
+/// Determines why an actor could not be allocated after the alloc idx yielded no candidates.
+async fn read_pending_reason(
+	tx: &universaldb::Transaction,
+	namespace_id: Id,
+	runner_name_selector: &str,
+	for_serverless: bool,
+) -> Result<PendingReason> {
+	let active_runner_subspace = keys::subspace().subspace(
+		&keys::ns::ActiveRunnerByNameKey::subspace(namespace_id, runner_name_selector.to_string()),
+	);
+
+	let mut stream = tx.get_ranges_keyvalues(
+		universaldb::RangeOption {
+			mode: StreamingMode::Iterator,
+			limit: Some(PENDING_REASON_RUNNER_SAMPLE_SIZE),
+			..(&active_runner_subspace).into()
+		},
+		// NOTE: This is purely informational, we don't want to conflict with runners connecting
+		Snapshot,
+	);
+
+	let mut found_runner = false;
+	while let Some(entry) = stream.try_next().await? {
+		let runner_key = tx.unpack::<keys::ns::ActiveRunnerByNameKey>(entry.key())?;
+		found_runner = true;
+
+		let draining = tx
+			.exists(
+				&keys::runner::DrainTsKey::new(runner_key.runner_id),
+				Snapshot,
+			)
+			.await?;
+		if !draining {
+			return Ok(PendingReason::NoCapacity);
+		}
+	}
+
+	if found_runner {
+		Ok(PendingReason::AllRunnersDraining)
+	} else if for_serverless {
+		// Serverless pools start runners on demand, the actor is waiting for the pool to scale up
+		Ok(PendingReason::NoCapacity)
+	} else {
+		Ok(PendingReason::NoRunnerMatchesSelector)
+	}
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ClearPendingAllocationInput {
 	actor_id: Id,
@@ -1229,6 +1415,66 @@ async fn compare_retry(ctx: &ActivityCtx, input: &CompareRetryInput) -> Result<(
 	Ok((now, reset))
 }
 
+/// Amount of reschedule slot keys per runner pool.
+const RESCHEDULE_SLOT_SHARDS: u16 = 16;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ClaimRescheduleSlotInput {
+	namespace_id: Id,
+	runner_name_selector: String,
+}
+
+/// Claims the next free reschedule slot of a random shard of the runner pool. Returns the slot's
+/// timestamp if the reschedule has to wait for it.
+///
+/// Each shard is paced at `RESCHEDULE_SLOT_SHARDS` times the interval so the pool as a whole
+/// reschedules at the configured rate, while concurrent reschedules rarely conflict on the same key.
+#[activity(ClaimRescheduleSlot)]
+async fn claim_reschedule_slot(
+	ctx: &ActivityCtx,
+	input: &ClaimRescheduleSlotInput,
+) -> Result<Option<i64>> {
+	let interval = (1000 * i64::from(RESCHEDULE_SLOT_SHARDS)
+		/ i64::from(ctx.config().pegboard().actor_reschedule_rate()))
+	.max(1);
+	let shard = rand::thread_rng().gen_range(0..RESCHEDULE_SLOT_SHARDS);
+	let now = util::timestamp::now();
+
+	let slot_ts = ctx
+		.udb()?
+		.txn(
+			"pegboard_actor_runtime_claim_reschedule_slot",
+			|tx| async move {
+				let tx = tx.with_subspace(keys::subspace());
+
+				let slot_key = keys::ns::RescheduleSlotKey::new(
+					input.namespace_id,
+					input.runner_name_selector.clone(),
+					shard,
+				);
+				let slot_ts = tx
+					.read_opt(&slot_key, Serializable)
+					.await?
+					.map_or(now, |next_slot_ts| next_slot_ts.max(now));
+
+				tx.write(&slot_key, slot_ts + interval)?;
+
+				Ok(slot_ts)
+			},
+		)
+		.custom_instrument(tracing::info_span!("actor_claim_reschedule_slot_tx"))
+		.await?;
+
+	if slot_ts > now {
+		let mut state = ctx.state::<State>()?;
+		state.reschedule_ts = Some(slot_ts);
+
+		Ok(Some(slot_ts))
+	} else {
+		Ok(None)
+	}
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SetStartedInput {
 	pub actor_id: Id,
@@ -1244,8 +1490,24 @@ pub async fn set_started(ctx: &ActivityCtx, input: &SetStartedInput) -> Result<(
 	}
 	state.connectable_ts = Some(now);
 
+	let namespace_id = state.namespace_id;
+	let name = &state.name;
+	let create_ts = state.create_ts;
+	let actor_state = state.actor_state();
+
 	ctx.udb()?
 		.txn("pegboard_actor_runtime_set_started", |tx| async move {
+			crate::utils::update_actor_state_idx(
+				&tx,
+				namespace_id,
+				name,
+				create_ts,
+				input.actor_id,
+				ctx.workflow_id(),
+				actor_state,
+			)
+			.await?;
+
 			let tx = tx.with_subspace(keys::subspace());
 
 			let connectable_key = keys::actor::ConnectableKey::new(input.actor_id);
@@ -1259,6 +1521,8 @@ pub async fn set_started(ctx: &ActivityCtx, input: &SetStartedInput) -> Result<(
 		.custom_instrument(tracing::info_span!("actor_set_started_tx"))
 		.await?;
 
+	notify_webhook(ctx, &state, input.actor_id, WebhookEvent::Ready, None).await;
+
 	Ok(())
 }
 
@@ -1275,8 +1539,24 @@ pub async fn set_sleeping(ctx: &ActivityCtx, input: &SetSleepingInput) -> Result
 	state.sleep_ts = Some(now);
 	state.connectable_ts = None;
 
+	let namespace_id = state.namespace_id;
+	let name = &state.name;
+	let create_ts = state.create_ts;
+	let actor_state = state.actor_state();
+
 	ctx.udb()?
 		.txn("pegboard_actor_runtime_set_sleeping", |tx| async move {
+			crate::utils::update_actor_state_idx(
+				&tx,
+				namespace_id,
+				name,
+				create_ts,
+				input.actor_id,
+				ctx.workflow_id(),
+				actor_state,
+			)
+			.await?;
+
 			let tx = tx.with_subspace(keys::subspace());
 
 			// Make not connectable
@@ -1433,6 +1713,7 @@ pub async fn check_runners(ctx: &ActivityCtx, input: &CheckRunnersStubInput) ->
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SetFailureReasonInput {
+	pub actor_id: Id,
 	pub failure_reason: FailureReason,
 }
 
@@ -1455,9 +1736,74 @@ pub async fn set_failure_reason(ctx: &ActivityCtx, input: &SetFailureReasonInput
 	}
 
 	state.failure_reason = Some(input.failure_reason.clone());
+
+	notify_webhook(
+		ctx,
+		&state,
+		input.actor_id,
+		WebhookEvent::Failed,
+		Some(input.failure_reason.clone()),
+	)
+	.await;
+
 	Ok(())
 }
 
+/// Moves the actor to its current state in the namespace state index.
+async fn update_state_idx(ctx: &ActivityCtx, actor_id: Id, state: &State) -> Result<()> {
+	let namespace_id = state.namespace_id;
+	let name = &state.name;
+	let create_ts = state.create_ts;
+	let actor_state = state.actor_state();
+
+	ctx.udb()?
+		.txn("pegboard_actor_runtime_update_state_idx", |tx| async move {
+			crate::utils::update_actor_state_idx(
+				&tx,
+				namespace_id,
+				name,
+				create_ts,
+				actor_id,
+				ctx.workflow_id(),
+				actor_state,
+			)
+			.await
+		})
+		.custom_instrument(tracing::info_span!("actor_update_state_idx_tx"))
+		.await
+}
+
+/// Notifies the namespace's webhook (if any) of a lifecycle transition. Best effort, a failure to dispatch
+/// the notification does not fail the activity.
+async fn notify_webhook(
+	ctx: &ActivityCtx,
+	state: &State,
+	actor_id: Id,
+	event: WebhookEvent,
+	failure_reason: Option<FailureReason>,
+) {
+	let res = ctx
+		.op(crate::ops::webhook::notify::Input {
+			namespace_id: state.namespace_id,
+			actor_id,
+			actor_name: state.name.clone(),
+			event,
+			failure_reason,
+			// The activity's create ts does not change when it is retried
+			idempotency_key: format!("{}:{}:{event:?}", ctx.workflow_id(), ctx.create_ts()),
+		})
+		.await;
+
+	if let Err(err) = res {
+		tracing::warn!(
+			?err,
+			?actor_id,
+			?event,
+			"failed to dispatch webhook notification"
+		);
+	}
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct RecordEventMetricsInput {
 	pub namespace_id: Id,
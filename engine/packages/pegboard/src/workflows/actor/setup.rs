@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use gas::prelude::*;
 use rivet_data::converted::ActorNameKeyData;
 use rivet_types::actors::CrashPolicy;
@@ -15,20 +17,26 @@ pub struct ValidateInput {
 	pub name: String,
 	pub key: Option<String>,
 	pub input: Option<String>,
+	#[serde(default)]
+	pub crash_policy: Option<CrashPolicy>,
+	#[serde(default)]
+	pub tags: BTreeMap<String, String>,
 }
 
+/// Returns the actor's crash policy, falling back to the namespace's default if the input leaves it
+/// unspecified.
 #[activity(Validate)]
 pub async fn validate(
 	ctx: &ActivityCtx,
 	input: &ValidateInput,
-) -> Result<std::result::Result<(), errors::Actor>> {
+) -> Result<std::result::Result<Option<CrashPolicy>, errors::Actor>> {
 	let ns_res = ctx
 		.op(namespace::ops::get_global::Input {
 			namespace_ids: vec![input.namespace_id],
 		})
 		.await?;
 
-	if ns_res.is_empty() {
+	let Some(ns) = ns_res.into_iter().next() else {
 		return Ok(Err(errors::Actor::NamespaceNotFound));
 	};
 
@@ -55,7 +63,16 @@ pub async fn validate(
 		}
 	}
 
-	Ok(Ok(()))
+	if let Err(err) = crate::utils::validate_tags(input.tags.iter()) {
+		return Ok(Err(err));
+	}
+
+	Ok(Ok(Some(
+		input
+			.crash_policy
+			.or(ns.default_crash_policy)
+			.unwrap_or_default(),
+	)))
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Hash)]
@@ -67,6 +84,8 @@ pub struct InitStateAndUdbInput {
 	pub runner_name_selector: String,
 	pub crash_policy: CrashPolicy,
 	pub create_ts: i64,
+	#[serde(default)]
+	pub required_capabilities: Vec<String>,
 }
 
 #[activity(InitStateAndDb)]
@@ -112,6 +131,13 @@ pub async fn insert_state_and_db(ctx: &ActivityCtx, input: &InitStateAndUdbInput
 				tx.write(&keys::actor::KeyKey::new(input.actor_id), key.clone())?;
 			}
 
+			if !input.required_capabilities.is_empty() {
+				tx.write(
+					&keys::actor::RequiredCapabilitiesKey::new(input.actor_id),
+					input.required_capabilities.clone(),
+				)?;
+			}
+
 			// Update metrics
 			namespace::keys::metric::inc(
 				&tx.with_subspace(namespace::keys::subspace()),
@@ -131,6 +157,8 @@ pub async fn insert_state_and_db(ctx: &ActivityCtx, input: &InitStateAndUdbInput
 #[derive(Debug, Serialize, Deserialize, Hash)]
 pub struct AddIndexesAndSetCreateCompleteInput {
 	pub actor_id: Id,
+	#[serde(default)]
+	pub tags: BTreeMap<String, String>,
 }
 
 #[activity(AddIndexesAndSetCreateComplete)]
@@ -142,6 +170,7 @@ pub async fn add_indexes_and_set_create_complete(
 
 	// Set create complete
 	state.create_complete_ts = Some(util::timestamp::now());
+	state.tags = input.tags.clone().into_iter().collect();
 
 	// Populate indexes
 	ctx.udb()?
@@ -149,7 +178,19 @@ pub async fn add_indexes_and_set_create_complete(
 			let namespace_id = state.namespace_id;
 			let name = state.name.clone();
 			let create_ts = state.create_ts;
+			let actor_state = state.actor_state();
 			async move {
+				crate::utils::update_actor_state_idx(
+					&tx,
+					namespace_id,
+					&name,
+					create_ts,
+					input.actor_id,
+					ctx.workflow_id(),
+					actor_state,
+				)
+				.await?;
+
 				let tx = tx.with_subspace(keys::subspace());
 
 				// Populate indexes
@@ -173,6 +214,19 @@ pub async fn add_indexes_and_set_create_complete(
 					ctx.workflow_id(),
 				)?;
 
+				for (k, v) in &input.tags {
+					tx.write(
+						&keys::ns::ActorByTagKey::new(
+							namespace_id,
+							k.clone(),
+							v.clone(),
+							create_ts,
+							input.actor_id,
+						),
+						ctx.workflow_id(),
+					)?;
+				}
+
 				// Write name into namespace actor names list with empty metadata (if it doesn't already exist)
 				let name_key = keys::ns::ActorNameKey::new(namespace_id, name.clone());
 				if !tx.exists(&name_key, Serializable).await? {
@@ -1,7 +1,10 @@
+use std::collections::{BTreeMap, HashMap};
+
 use futures_util::FutureExt;
 use gas::prelude::*;
 use rivet_data::converted::ActorByKeyKeyData;
 use rivet_envoy_protocol as protocol;
+use rivet_types::actors::{ActorState, PendingReason};
 use universaldb::prelude::*;
 
 use crate::errors;
@@ -29,6 +32,9 @@ pub struct Input {
 
 	/// Arbitrary user-provided binary data encoded in base64.
 	pub input: Option<String>,
+	/// Free-form tags used to group and filter actors. See `ops::actor::list_for_tags`.
+	#[serde(default)]
+	pub tags: HashMap<String, String>,
 	pub from_v1: bool,
 }
 
@@ -64,6 +70,13 @@ pub struct State {
 	/// - When actor is allocated
 	/// - When actor becomes connectable
 	pub error: Option<ActorError>,
+	/// Why the last allocation attempt did not find an envoy. Cleared once the actor is allocated.
+	#[serde(default)]
+	pub pending_reason: Option<PendingReason>,
+
+	/// Set once the actor is added to the tag indexes.
+	#[serde(default)]
+	pub tags: HashMap<String, String>,
 }
 
 impl State {
@@ -96,8 +109,16 @@ impl State {
 			destroy_ts: None,
 
 			error: None,
+			pending_reason: None,
+
+			tags: HashMap::new(),
 		}
 	}
+
+	/// State the actor is listed under in the namespace state index.
+	pub fn actor_state(&self) -> ActorState {
+		ActorState::from_timestamps(self.destroy_ts, self.sleep_ts, self.connectable_ts, None)
+	}
 }
 
 #[doc(hidden)]
@@ -177,7 +198,11 @@ pub async fn pegboard_actor2(ctx: &mut WorkflowCtx, input: &Input) -> Result<()>
 			})
 			.await?;
 		ctx.v(2).activity(keys::UpdateKeyIndexInput {}).await?;
-		ctx.v(2).activity(PopulateIndexesInput {}).await?;
+		ctx.v(2)
+			.activity(PopulateIndexesInput {
+				tags: input.tags.clone().into_iter().collect(),
+			})
+			.await?;
 	} else {
 		if let Some(key) = &input.key {
 			match keys::reserve_key(
@@ -225,7 +250,10 @@ pub async fn pegboard_actor2(ctx: &mut WorkflowCtx, input: &Input) -> Result<()>
 			}
 		}
 
-		ctx.activity(PopulateIndexesInput {}).await?;
+		ctx.activity(PopulateIndexesInput {
+			tags: input.tags.clone().into_iter().collect(),
+		})
+		.await?;
 
 		ctx.msg(CreateComplete {})
 			.topic(("actor_id", input.actor_id))
@@ -373,7 +401,10 @@ pub async fn insert_state_and_db(ctx: &ActivityCtx, input: &InitStateAndUdbInput
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-pub struct PopulateIndexesInput {}
+pub struct PopulateIndexesInput {
+	#[serde(default)]
+	pub tags: BTreeMap<String, String>,
+}
 
 #[activity(PopulateIndexes)]
 pub async fn populate_indexes(ctx: &ActivityCtx, input: &PopulateIndexesInput) -> Result<()> {
@@ -381,16 +412,29 @@ pub async fn populate_indexes(ctx: &ActivityCtx, input: &PopulateIndexesInput) -
 
 	// Set create complete
 	state.create_complete_ts = Some(util::timestamp::now());
+	state.tags = input.tags.clone().into_iter().collect();
 
 	let namespace_id = state.namespace_id;
 	let actor_id = state.actor_id;
 	let name = &state.name;
 	let create_ts = state.create_ts;
+	let actor_state = state.actor_state();
 
 	// Populate indexes
 	ctx.udb()?
 		.txn("pegboard_actor2_populate_indexes", |tx| {
 			async move {
+				crate::utils::update_actor_state_idx(
+					&tx,
+					namespace_id,
+					name,
+					create_ts,
+					actor_id,
+					ctx.workflow_id(),
+					actor_state,
+				)
+				.await?;
+
 				let tx = tx.with_subspace(crate::keys::subspace());
 
 				// Populate indexes
@@ -414,6 +458,20 @@ pub async fn populate_indexes(ctx: &ActivityCtx, input: &PopulateIndexesInput) -
 					ctx.workflow_id(),
 				)?;
 
+				// Actors migrated from v1 already have tag indexes, overwrite them with this workflow's id
+				for (k, v) in &input.tags {
+					tx.write(
+						&crate::keys::ns::ActorByTagKey::new(
+							namespace_id,
+							k.clone(),
+							v.clone(),
+							create_ts,
+							actor_id,
+						),
+						ctx.workflow_id(),
+					)?;
+				}
+
 				// NOTE: keys::ns::ActorByKeyKey is written in actor_keys.rs when reserved by epoxy
 
 				Ok(())
@@ -1172,12 +1230,14 @@ async fn update_state_and_db(ctx: &ActivityCtx, input: &UpdateStateAndDbInput) -
 
 	let destroy_ts = util::timestamp::now();
 	state.destroy_ts = Some(destroy_ts);
+	state.pending_reason = None;
 
 	let namespace_id = state.namespace_id;
 	let actor_id = state.actor_id;
 	let name = &state.name;
 	let create_ts = state.create_ts;
 	let key = &state.key;
+	let tags = &state.tags;
 	ctx.udb()?
 		.txn("pegboard_actor2_destroy", |tx| {
 			async move {
@@ -1192,6 +1252,34 @@ async fn update_state_and_db(ctx: &ActivityCtx, input: &UpdateStateAndDbInput) -
 					create_ts,
 					actor_id,
 				));
+				crate::utils::update_actor_state_idx(
+					&tx,
+					namespace_id,
+					name,
+					create_ts,
+					actor_id,
+					ctx.workflow_id(),
+					ActorState::Destroyed,
+				)
+				.await?;
+				for (k, v) in tags {
+					tx.delete(&crate::keys::ns::ActorByTagKey::new(
+						namespace_id,
+						k.clone(),
+						v.clone(),
+						create_ts,
+						actor_id,
+					));
+				}
+
+				// Actors that took over a key through `ops::actor::transfer_key` have no key in their state
+				let key = match key {
+					Some(key) => Some(key.clone()),
+					None => {
+						tx.read_opt(&crate::keys::actor::KeyKey::new(actor_id), Serializable)
+							.await?
+					}
+				};
 
 				if let Some(key) = &key {
 					tx.write(
@@ -1252,6 +1340,12 @@ async fn clear_kv(ctx: &ActivityCtx, input: &ClearKvInput) -> Result<ClearKvOutp
 			tx.clear_subspace_range(&subspace);
 			crate::actor_sqlite::clear_v2_storage_for_destroy(&tx, actor_id);
 
+			// Responses cached by guard
+			tx.clear_subspace_range(
+				&crate::keys::subspace()
+					.subspace(&crate::keys::actor::ResponseCacheKey::subspace(actor_id)),
+			);
+
 			Ok(final_size)
 		})
 		.custom_instrument(tracing::info_span!("actor_clear_kv_tx"))
@@ -3,8 +3,11 @@ use base64::prelude::BASE64_STANDARD;
 use futures_util::TryStreamExt;
 use gas::{prelude::*, workflow::StateGuard};
 use rivet_envoy_protocol::{self as protocol, PROTOCOL_VERSION, versioned};
-use rivet_types::runner_configs::RunnerConfigKind;
-use std::{fmt, time::Instant};
+use rivet_types::{actors::PendingReason, runner_configs::RunnerConfigKind};
+use std::{
+	fmt,
+	time::{Duration, Instant},
+};
 use universaldb::prelude::*;
 use universalpubsub::PublishOpts;
 use vbare::OwnedVersionedData;
@@ -191,6 +194,9 @@ pub async fn allocate(ctx: &ActivityCtx, input: &AllocateInput) -> Result<Alloca
 		})
 		.await?;
 	let pool = pool_res.into_iter().next();
+	if pool.is_none() {
+		crate::utils::flag_unrouted_selector(state.namespace_id, &state.pool_name);
+	}
 	let is_serverless = pool
 		.as_ref()
 		.map(|pool| matches!(pool.config.kind, RunnerConfigKind::Serverless { .. }))
@@ -211,7 +217,7 @@ pub async fn allocate(ctx: &ActivityCtx, input: &AllocateInput) -> Result<Alloca
 	let pools = ctx.pools().clone();
 
 	// Check if limit has been reached and choose an envoy if serverful
-	let (acquired_slot, allocation, error) = ctx
+	let (acquired_slot, allocation, error, pending_reason) = ctx
 		.udb()?
 		.txn("pegboard_actor2_acquire_slot", |tx| {
 			let pools = pools.clone();
@@ -237,9 +243,9 @@ pub async fn allocate(ctx: &ActivityCtx, input: &AllocateInput) -> Result<Alloca
 					true
 				};
 
-				let (allocation, error) = if acquired_slot {
+				let (allocation, error, pending_reason) = if acquired_slot {
 					if is_serverless {
-						(Some(Allocation::Serverless), None)
+						(Some(Allocation::Serverless), None, None)
 					} else {
 						let allocation = super::alloc_serverful::allocate_serverful(
 							namespace_id,
@@ -274,15 +280,27 @@ pub async fn allocate(ctx: &ActivityCtx, input: &AllocateInput) -> Result<Alloca
 							);
 						}
 
-						let error = allocation.is_none().then_some(super::ActorError::NoEnvoys);
+						let (error, pending_reason) = if allocation.is_none() {
+							(
+								Some(super::ActorError::NoEnvoys),
+								Some(read_pending_reason(&tx, namespace_id, pool_name).await?),
+							)
+						} else {
+							(None, None)
+						};
 
 						(
 							allocation.map(|envoy_key| Allocation::Serverful { envoy_key }),
 							error,
+							pending_reason,
 						)
 					}
 				} else {
-					(None, Some(super::ActorError::ConcurrentActorLimitReached))
+					(
+						None,
+						Some(super::ActorError::ConcurrentActorLimitReached),
+						Some(PendingReason::NoCapacity),
+					)
 				};
 
 				if allocation.is_some() {
@@ -291,7 +309,7 @@ pub async fn allocate(ctx: &ActivityCtx, input: &AllocateInput) -> Result<Alloca
 					tx.write(&keys::actor::GenerationKey::new(actor_id), input.generation)?;
 				}
 
-				Ok((acquired_slot, allocation, error))
+				Ok((acquired_slot, allocation, error, pending_reason))
 			}
 		})
 		.custom_instrument(tracing::info_span!("actor_allocate_tx"))
@@ -299,6 +317,7 @@ pub async fn allocate(ctx: &ActivityCtx, input: &AllocateInput) -> Result<Alloca
 
 	state.acquired_slot = acquired_slot;
 	state.error = error;
+	state.pending_reason = pending_reason;
 	state.envoy_last_command_idx = 0;
 
 	let now = util::timestamp::now();
@@ -324,9 +343,57 @@ pub async fn allocate(ctx: &ActivityCtx, input: &AllocateInput) -> Result<Alloca
 		])
 		.observe(dt);
 
+	update_state_idx(ctx, &state).await?;
+
 	Ok(AllocateOutput { allocation, now })
 }
 
+/// Number of active envoys to inspect when determining why an actor could not be allocated.
+const PENDING_REASON_ENVOY_SAMPLE_SIZE: usize = 64;
+
+/// Determines why no envoy could be chosen for the actor.
+async fn read_pending_reason(
+	tx: &universaldb::Transaction,
+	namespace_id: Id,
+	pool_name: &str,
+) -> Result<PendingReason> {
+	let active_envoy_subspace = keys::subspace().subspace(
+		&keys::ns::ActiveEnvoyByNameKey::subspace(namespace_id, pool_name.to_string()),
+	);
+
+	let mut stream = tx.get_ranges_keyvalues(
+		universaldb::RangeOption {
+			mode: StreamingMode::Iterator,
+			limit: Some(PENDING_REASON_ENVOY_SAMPLE_SIZE),
+			..(&active_envoy_subspace).into()
+		},
+		// NOTE: This is purely informational, we don't want to conflict with envoys connecting
+		Snapshot,
+	);
+
+	let mut found_envoy = false;
+	while let Some(entry) = stream.try_next().await? {
+		let envoy_key = tx.unpack::<keys::ns::ActiveEnvoyByNameKey>(entry.key())?;
+		found_envoy = true;
+
+		let stopping = tx
+			.exists(
+				&keys::envoy::StopTsKey::new(namespace_id, envoy_key.envoy_key),
+				Snapshot,
+			)
+			.await?;
+		if !stopping {
+			return Ok(PendingReason::NoCapacity);
+		}
+	}
+
+	if found_envoy {
+		Ok(PendingReason::AllRunnersDraining)
+	} else {
+		Ok(PendingReason::NoRunnerMatchesSelector)
+	}
+}
+
 fn allocator_debug_enabled() -> bool {
 	static CACHED: std::sync::OnceLock<bool> = std::sync::OnceLock::new();
 	*CACHED.get_or_init(|| {
@@ -395,6 +462,9 @@ async fn dump_envoy_lb_idx_eligible(
 	Ok(out)
 }
 
+/// How long to wait for pegboard-outbound to ack a serverless actor start before the activity is retried.
+const OUTBOUND_ACK_TIMEOUT: Duration = Duration::from_secs(10);
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SendOutboundInput {
 	pub generation: u32,
@@ -432,11 +502,13 @@ pub async fn send_outbound(ctx: &ActivityCtx, input: &SendOutboundInput) -> Resu
 			)
 			.serialize_with_embedded_version(PROTOCOL_VERSION)?;
 
+			// Fails if no pegboard-outbound service picked up the start, in which case the activity is retried
+			// instead of the start being dropped
 			ctx.ups()?
-				.publish(
+				.request_response(
 					crate::pubsub_subjects::ServerlessOutboundSubject,
 					&message_serialized,
-					PublishOpts::one(),
+					OUTBOUND_ACK_TIMEOUT,
 				)
 				.await?;
 		}
@@ -926,6 +998,8 @@ pub async fn set_connectable(ctx: &ActivityCtx, input: &SetConnectableInput) ->
 		.custom_instrument(tracing::info_span!("actor_set_connectable_tx"))
 		.await?;
 
+	update_state_idx(ctx, &state).await?;
+
 	Ok(())
 }
 
@@ -952,6 +1026,8 @@ pub async fn set_not_connectable(ctx: &ActivityCtx, input: &SetNotConnectableInp
 
 	state.connectable_ts = None;
 
+	update_state_idx(ctx, &state).await?;
+
 	Ok(())
 }
 
@@ -981,6 +1057,8 @@ pub async fn set_sleeping(ctx: &ActivityCtx, input: &SetSleepingInput) -> Result
 	state.sleep_ts = Some(now);
 	state.connectable_ts = None;
 
+	update_state_idx(ctx, &state).await?;
+
 	Ok(())
 }
 
@@ -1035,9 +1113,36 @@ pub async fn deallocate(ctx: &ActivityCtx, input: &DeallocateInput) -> Result<()
 	state.envoy_key = None;
 	state.acquired_slot = false;
 
+	update_state_idx(ctx, &state).await?;
+
 	Ok(())
 }
 
+/// Moves the actor to its current state in the namespace state index.
+async fn update_state_idx(ctx: &ActivityCtx, state: &State) -> Result<()> {
+	let namespace_id = state.namespace_id;
+	let name = &state.name;
+	let create_ts = state.create_ts;
+	let actor_id = state.actor_id;
+	let actor_state = state.actor_state();
+
+	ctx.udb()?
+		.txn("pegboard_actor2_update_state_idx", |tx| async move {
+			crate::utils::update_actor_state_idx(
+				&tx,
+				namespace_id,
+				name,
+				create_ts,
+				actor_id,
+				ctx.workflow_id(),
+				actor_state,
+			)
+			.await
+		})
+		.custom_instrument(tracing::info_span!("actor_update_state_idx_tx"))
+		.await
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct InsertAndSendCommandsInput {
 	pub generation: u32,
@@ -0,0 +1,301 @@
+//! Backfills `ns::ActorByStateKey` for actors created before their workflows started maintaining the
+//! state index.
+
+use std::time::{Duration, Instant};
+
+use futures_util::TryStreamExt;
+use gas::prelude::*;
+use rivet_types::actors::ActorState;
+use universaldb::KeySelector;
+use universaldb::options::StreamingMode;
+use universaldb::prelude::*;
+
+use super::actor_runner_name_selector_backfill::MarkCompleteInput;
+use crate::keys;
+
+pub const BACKFILL_NAME: &str = "actor_state_idx";
+
+/// Timeout to stop processing early to avoid transaction timeout.
+const EARLY_TXN_TIMEOUT: Duration = Duration::from_millis(2500);
+
+const BATCH_SIZE: usize = 256;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Input {}
+
+#[workflow]
+pub async fn pegboard_actor_state_idx_backfill(
+	ctx: &mut WorkflowCtx,
+	_input: &Input,
+) -> Result<()> {
+	#[derive(Serialize, Deserialize)]
+	struct State {
+		/// The last actor_id processed, used for pagination.
+		after_actor_id: Option<Id>,
+		/// Total actors backfilled so far.
+		total_backfilled: u64,
+		/// Total actors that failed to deserialize (corrupt data).
+		total_errored: u64,
+	}
+
+	ctx.loope(
+		State {
+			after_actor_id: None,
+			total_backfilled: 0,
+			total_errored: 0,
+		},
+		|ctx, state| {
+			Box::pin(async move {
+				let output = ctx
+					.activity(BackfillBatchInput {
+						after_actor_id: state.after_actor_id,
+						batch_size: BATCH_SIZE,
+					})
+					.await?;
+
+				state.total_backfilled += output.backfilled_count as u64;
+				state.total_errored += output.errored_count as u64;
+
+				if let Some(last_actor_id) = output.last_actor_id {
+					state.after_actor_id = Some(last_actor_id);
+					Ok(Loop::Continue)
+				} else {
+					tracing::info!(
+						total_backfilled = state.total_backfilled,
+						total_errored = state.total_errored,
+						"completed actor state index backfill"
+					);
+					Ok(Loop::Break(()))
+				}
+			})
+		},
+	)
+	.await?;
+
+	ctx.activity(MarkCompleteInput {
+		name: BACKFILL_NAME.to_string(),
+	})
+	.await?;
+
+	Ok(())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Hash)]
+pub struct BackfillBatchInput {
+	pub after_actor_id: Option<Id>,
+	pub batch_size: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackfillBatchOutput {
+	/// The last actor_id processed in this batch, used for pagination.
+	/// If None, there are no more actors to process.
+	pub last_actor_id: Option<Id>,
+	/// Number of actors backfilled in this batch.
+	pub backfilled_count: usize,
+	/// Number of actors that failed to deserialize (corrupt data).
+	pub errored_count: usize,
+}
+
+#[derive(Clone)]
+struct StateIdxEntry {
+	actor_id: Id,
+	workflow_id: Id,
+	namespace_id: Id,
+	name: String,
+	create_ts: i64,
+	state: ActorState,
+}
+
+#[activity(BackfillBatch)]
+pub async fn backfill_batch(
+	ctx: &ActivityCtx,
+	input: &BackfillBatchInput,
+) -> Result<BackfillBatchOutput> {
+	// Find actors that are not in the state index yet
+	let (actors_to_backfill, last_actor_id, mut errored_count) = ctx
+		.udb()?
+		.txn("pegboard_actor_state_idx_backfill_find_actors", |tx| {
+			let after_actor_id = input.after_actor_id;
+			let batch_size = input.batch_size;
+			async move {
+				let start = Instant::now();
+				let tx = tx.with_subspace(keys::subspace());
+
+				let actor_data_subspace = keys::subspace().subspace(&keys::actor::DataSubspaceKey);
+				let (subspace_start, subspace_end) = actor_data_subspace.range();
+
+				// Build range start key based on pagination cursor
+				let begin_key = if let Some(after_actor_id) = after_actor_id {
+					// Start after the last processed actor's WorkflowIdKey
+					let after_key = keys::actor::WorkflowIdKey::new(after_actor_id);
+					let packed = keys::subspace().pack(&after_key);
+					KeySelector::first_greater_than(packed)
+				} else {
+					KeySelector::first_greater_or_equal(subspace_start)
+				};
+
+				let range_option = universaldb::RangeOption {
+					mode: StreamingMode::Iterator,
+					begin: begin_key,
+					end: KeySelector::first_greater_or_equal(subspace_end),
+					..Default::default()
+				};
+
+				let mut actors_missing_state = Vec::new();
+				let mut last_actor_id = None;
+				let mut scanned_count = 0usize;
+				let mut errored_count = 0usize;
+				let mut stream = tx.get_ranges_keyvalues(range_option, Snapshot);
+
+				while let Some(entry) = stream.try_next().await? {
+					if start.elapsed() > EARLY_TXN_TIMEOUT {
+						tracing::warn!("timed out finding actors to backfill");
+						break;
+					}
+
+					// Skip non-WorkflowIdKey entries (validation happens in WorkflowIdKey::unpack)
+					let (key, workflow_id) =
+						match tx.read_entry::<keys::actor::WorkflowIdKey>(&entry) {
+							Ok(result) => result,
+							Err(err) => {
+								if !err.to_string().contains("expected WORKFLOW_ID") {
+									tracing::error!(
+										?err,
+										"failed to deserialize actor entry, skipping"
+									);
+									errored_count += 1;
+								}
+								continue;
+							}
+						};
+
+					last_actor_id = Some(key.actor_id);
+					scanned_count += 1;
+
+					let state_key = keys::actor::StateKey::new(key.actor_id);
+					if !tx.exists(&state_key, Snapshot).await? {
+						actors_missing_state.push((key.actor_id, workflow_id));
+					}
+
+					if scanned_count >= batch_size {
+						break;
+					}
+				}
+
+				Ok((actors_missing_state, last_actor_id, errored_count))
+			}
+		})
+		.custom_instrument(tracing::info_span!("find_actors_tx"))
+		.await?;
+
+	if actors_to_backfill.is_empty() {
+		return Ok(BackfillBatchOutput {
+			last_actor_id,
+			backfilled_count: 0,
+			errored_count,
+		});
+	}
+
+	tracing::debug!(
+		count = actors_to_backfill.len(),
+		?last_actor_id,
+		"backfilling batch of actors"
+	);
+
+	let workflow_ids = actors_to_backfill
+		.iter()
+		.map(|(_, workflow_id)| *workflow_id)
+		.collect::<Vec<_>>();
+	let workflows = ctx.get_workflows(workflow_ids).await?;
+
+	let mut entries = Vec::new();
+	for (actor_id, workflow_id) in &actors_to_backfill {
+		let Some(wf) = workflows.iter().find(|wf| wf.workflow_id == *workflow_id) else {
+			tracing::warn!(?actor_id, ?workflow_id, "workflow not found for actor");
+			continue;
+		};
+
+		let entry = match wf.name.as_str() {
+			"pegboard_actor" => wf
+				.parse_state::<Option<crate::workflows::actor::State>>()
+				.map(|state| {
+					state.map(|state| StateIdxEntry {
+						actor_id: *actor_id,
+						workflow_id: *workflow_id,
+						namespace_id: state.namespace_id,
+						state: state.actor_state(),
+						name: state.name,
+						create_ts: state.create_ts,
+					})
+				}),
+			"pegboard_actor2" => wf
+				.parse_state::<Option<crate::workflows::actor2::State>>()
+				.map(|state| {
+					state.map(|state| StateIdxEntry {
+						actor_id: *actor_id,
+						workflow_id: *workflow_id,
+						namespace_id: state.namespace_id,
+						state: state.actor_state(),
+						name: state.name,
+						create_ts: state.create_ts,
+					})
+				}),
+			_ => {
+				tracing::error!(?actor_id, ?workflow_id, wf_name=?wf.name, "unknown actor wf name");
+				errored_count += 1;
+				continue;
+			}
+		};
+
+		match entry {
+			Ok(Some(entry)) => entries.push(entry),
+			// Actor did not initialize state yet, it will be indexed by its own workflow
+			Ok(None) => {}
+			Err(err) => {
+				tracing::error!(?actor_id, ?workflow_id, ?err, "failed to parse wf state");
+				errored_count += 1;
+			}
+		}
+	}
+
+	if !entries.is_empty() {
+		ctx.udb()?
+			.txn("pegboard_actor_state_idx_backfill_write", |tx| {
+				let entries = entries.clone();
+				async move {
+					for entry in entries {
+						// The actor's workflow indexed it since the scan, its entry is more recent
+						if tx
+							.with_subspace(keys::subspace())
+							.exists(&keys::actor::StateKey::new(entry.actor_id), Serializable)
+							.await?
+						{
+							continue;
+						}
+
+						crate::utils::update_actor_state_idx(
+							&tx,
+							entry.namespace_id,
+							&entry.name,
+							entry.create_ts,
+							entry.actor_id,
+							entry.workflow_id,
+							entry.state,
+						)
+						.await?;
+					}
+
+					Ok(())
+				}
+			})
+			.custom_instrument(tracing::info_span!("backfill_actor_state_idx_tx"))
+			.await?;
+	}
+
+	Ok(BackfillBatchOutput {
+		last_actor_id,
+		backfilled_count: entries.len(),
+		errored_count,
+	})
+}
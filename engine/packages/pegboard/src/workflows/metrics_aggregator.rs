@@ -1,13 +1,15 @@
-use std::time::{Duration, Instant};
+use std::{
+	collections::HashMap,
+	time::{Duration, Instant},
+};
 
 use anyhow::Result;
 use futures_util::{FutureExt, TryStreamExt};
 use gas::prelude::*;
-use universaldb::{options::StreamingMode, utils::IsolationLevel::*};
+use universaldb::prelude::*;
 
 use crate::{keys, metrics};
 
-const TICK_RATE: Duration = Duration::from_secs(15);
 const EARLY_TXN_TIMEOUT: Duration = Duration::from_millis(2500);
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -22,10 +24,12 @@ pub async fn pegboard_metrics_aggregator(ctx: &mut WorkflowCtx, input: &Input) -
 				activity(AggregatePendingActorsInput {}),
 				// activity(AggregateActiveActorsInput { }),
 				activity(AggregateServerlessDesiredSlotsInput {}),
+				v(2).activity(AggregateActorStatesInput {}),
 			))
 			.await?;
 
-			ctx.sleep(TICK_RATE).await?;
+			ctx.sleep(ctx.config().pegboard().metrics_aggregator_interval())
+				.await?;
 
 			Ok(Loop::<()>::Continue)
 		}
@@ -36,6 +40,14 @@ pub async fn pegboard_metrics_aggregator(ctx: &mut WorkflowCtx, input: &Input) -
 	Ok(())
 }
 
+/// Aggregations run independently so that one failing does not keep the others from updating their
+/// metrics. Failures are logged instead of failing the activity, the next tick tries again.
+fn log_failure(aggregation: &str, res: Result<()>) {
+	if let Err(err) = res {
+		tracing::error!(?err, aggregation, "failed to aggregate metrics");
+	}
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Hash)]
 struct AggregatePendingActorsInput {}
 
@@ -45,6 +57,12 @@ async fn aggregate_pending_actors(
 	ctx: &ActivityCtx,
 	_input: &AggregatePendingActorsInput,
 ) -> Result<()> {
+	log_failure("pending_actors", aggregate_pending_actors_inner(ctx).await);
+
+	Ok(())
+}
+
+async fn aggregate_pending_actors_inner(ctx: &ActivityCtx) -> Result<()> {
 	metrics::ACTOR_PENDING_ALLOCATION.reset();
 
 	let mut last_key = Vec::new();
@@ -115,6 +133,174 @@ async fn aggregate_pending_actors(
 	Ok(())
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, Hash)]
+struct AggregateActorStatesInput {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum ActorMetricState {
+	Running,
+	Sleeping,
+	/// Going to sleep but not yet deallocated from its runner.
+	Stopping,
+}
+
+impl ActorMetricState {
+	fn as_str(&self) -> &'static str {
+		match self {
+			ActorMetricState::Running => "running",
+			ActorMetricState::Sleeping => "sleeping",
+			ActorMetricState::Stopping => "stopping",
+		}
+	}
+}
+
+/// Data keys seen for a single actor while scanning.
+#[derive(Default)]
+struct ActorDataEntry {
+	namespace_id: Option<Id>,
+	connectable: bool,
+	sleeping: bool,
+	allocated: bool,
+	destroyed: bool,
+}
+
+impl ActorDataEntry {
+	fn state(&self) -> Option<(Id, ActorMetricState)> {
+		let namespace_id = self.namespace_id?;
+
+		if self.destroyed {
+			return None;
+		}
+
+		let state = if self.connectable {
+			ActorMetricState::Running
+		} else if self.sleeping && self.allocated {
+			ActorMetricState::Stopping
+		} else if self.sleeping {
+			ActorMetricState::Sleeping
+		} else {
+			// Pending and starting actors are not counted here
+			return None;
+		};
+
+		Some((namespace_id, state))
+	}
+}
+
+/// Scans actor data subspace and aggregates the amount of actors per state. Pending actors are
+/// covered by `aggregate_pending_actors`.
+#[activity(AggregateActorStates)]
+async fn aggregate_actor_states(
+	ctx: &ActivityCtx,
+	_input: &AggregateActorStatesInput,
+) -> Result<()> {
+	log_failure("actor_states", aggregate_actor_states_inner(ctx).await);
+
+	Ok(())
+}
+
+async fn aggregate_actor_states_inner(ctx: &ActivityCtx) -> Result<()> {
+	let mut counts = HashMap::<(Id, ActorMetricState), i64>::new();
+
+	let mut last_key = Vec::new();
+	loop {
+		let (new_last_key, states) = ctx
+			.udb()?
+			.txn("pegboard_metrics_aggregate_actor_states", |tx| {
+				let last_key = &last_key;
+				async move {
+					let start = Instant::now();
+					let tx = tx.with_subspace(keys::subspace());
+					let mut states = Vec::new();
+
+					let actor_data_subspace =
+						keys::subspace().subspace(&keys::actor::DataSubspaceKey);
+					let range = actor_data_subspace.range();
+
+					let range_start = if last_key.is_empty() {
+						&range.0
+					} else {
+						&last_key
+					};
+					let range_end = &actor_data_subspace.range().1;
+
+					let mut stream = tx.get_ranges_keyvalues(
+						universaldb::RangeOption {
+							mode: StreamingMode::WantAll,
+							..(range_start.as_slice(), range_end.as_slice()).into()
+						},
+						Snapshot,
+					);
+
+					// Keys of an actor are contiguous. If the txn times out mid actor, the next txn
+					// starts again from that actor's first key.
+					let mut current: Option<(Id, ActorDataEntry)> = None;
+					let mut current_start_key = range_start.clone();
+					let new_last_key = loop {
+						if start.elapsed() > EARLY_TXN_TIMEOUT {
+							tracing::warn!("timed out processing actor state metrics");
+							break current_start_key;
+						}
+
+						let Some(entry) = stream.try_next().await? else {
+							states.extend(current.take().and_then(|(_, actor)| actor.state()));
+							break Vec::new();
+						};
+
+						let Ok((_, _, actor_id, variant)) =
+							tx.unpack::<(usize, usize, Id, usize)>(entry.key())
+						else {
+							continue;
+						};
+
+						if current.as_ref().map(|(id, _)| *id) != Some(actor_id) {
+							states.extend(current.take().and_then(|(_, actor)| actor.state()));
+							current = Some((actor_id, ActorDataEntry::default()));
+							current_start_key = entry.key().to_vec();
+						}
+						let Some((_, actor)) = current.as_mut() else {
+							continue;
+						};
+
+						match variant {
+							NAMESPACE_ID => {
+								let (_, namespace_id) =
+									tx.read_entry::<keys::actor::NamespaceIdKey>(&entry)?;
+								actor.namespace_id = Some(namespace_id);
+							}
+							CONNECTABLE => actor.connectable = true,
+							SLEEP_TS => actor.sleeping = true,
+							RUNNER_ID | ENVOY_KEY => actor.allocated = true,
+							DESTROY_TS => actor.destroyed = true,
+							_ => {}
+						}
+					};
+
+					Ok((new_last_key, states))
+				}
+			})
+			.await?;
+
+		for state in states {
+			*counts.entry(state).or_default() += 1;
+		}
+
+		last_key = new_last_key;
+		if last_key.is_empty() {
+			break;
+		}
+	}
+
+	metrics::ACTOR_STATE.reset();
+	for ((namespace_id, state), count) in counts {
+		metrics::ACTOR_STATE
+			.with_label_values(&[&namespace_id.to_string(), state.as_str()])
+			.set(count);
+	}
+
+	Ok(())
+}
+
 // #[derive(Debug, Clone, Serialize, Deserialize, Hash)]
 // struct AggregateActiveActorsInput {}
 
@@ -209,6 +395,15 @@ async fn aggregate_serverless_desired_slots(
 	ctx: &ActivityCtx,
 	_input: &AggregateServerlessDesiredSlotsInput,
 ) -> Result<()> {
+	log_failure(
+		"serverless_desired_slots",
+		aggregate_serverless_desired_slots_inner(ctx).await,
+	);
+
+	Ok(())
+}
+
+async fn aggregate_serverless_desired_slots_inner(ctx: &ActivityCtx) -> Result<()> {
 	metrics::SERVERLESS_DESIRED_SLOTS.reset();
 
 	let mut last_key = Vec::new();
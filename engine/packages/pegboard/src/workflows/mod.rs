@@ -2,6 +2,7 @@ pub mod actor;
 pub mod actor2;
 pub mod actor_migration_fix_backfill;
 pub mod actor_runner_name_selector_backfill;
+pub mod actor_state_idx_backfill;
 pub mod metrics_aggregator;
 pub mod runner;
 pub mod runner2;
@@ -11,3 +12,4 @@ pub mod runner_pool_backfill;
 pub mod runner_pool_error_tracker;
 pub mod runner_pool_metadata_poller;
 pub mod serverless;
+pub mod webhook;
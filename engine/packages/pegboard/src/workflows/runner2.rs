@@ -25,6 +25,10 @@ pub struct Input {
 	pub version: u32,
 	pub total_slots: u32,
 	pub protocol_version: u16,
+	/// Capabilities advertised by the runner. Actors with required capabilities are only allocated to
+	/// runners advertising all of them.
+	#[serde(default)]
+	pub capabilities: Vec<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -62,6 +66,7 @@ pub async fn pegboard_runner2(ctx: &mut WorkflowCtx, input: &Input) -> Result<()
 		total_slots: input.total_slots,
 		protocol_version: input.protocol_version,
 		create_ts: ctx.create_ts(),
+		capabilities: input.capabilities.clone(),
 	})
 	.await?;
 	ctx.v(2)
@@ -95,13 +100,16 @@ pub async fn pegboard_runner2(ctx: &mut WorkflowCtx, input: &Input) -> Result<()
 			let input = input.clone();
 
 			async move {
-				let runner_lost_threshold = ctx.config().pegboard().runner_lost_threshold();
+				// While disconnected, wait out the reconnect grace period instead
+				let timeout = state
+					.reconnect_grace_period
+					.unwrap_or_else(|| ctx.config().pegboard().runner_lost_threshold());
 
-				match ctx
-					.listen_with_timeout::<Main>(runner_lost_threshold)
-					.await?
-				{
+				match ctx.listen_with_timeout::<Main>(timeout).await? {
 					Some(Main::Init(_)) => {
+						// Runner reconnected
+						state.reconnect_grace_period = None;
+
 						if !state.draining {
 							ctx.activity(MarkEligibleInput {
 								runner_id: input.runner_id,
@@ -118,6 +126,27 @@ pub async fn pegboard_runner2(ctx: &mut WorkflowCtx, input: &Input) -> Result<()
 						handle_stopping(ctx, &input, state, sig.reset_actor_rescheduling).await?;
 					}
 					None => {
+						if !state.draining && state.reconnect_grace_period.is_none() {
+							match ctx.check_version(2).await? {
+								1 => {}
+								_latest => {
+									let grace_period = ctx
+										.v(2)
+										.activity(CheckDisconnectedInput {
+											runner_id: input.runner_id,
+										})
+										.await?;
+
+									// Keep the runner's allocations so it can resume them if it
+									// reconnects within the grace period
+									if let Some(grace_period) = grace_period {
+										state.reconnect_grace_period = Some(grace_period);
+										return Ok(Loop::Continue);
+									}
+								}
+							}
+						}
+
 						let expired = ctx
 							.activity(CheckExpiredInput {
 								runner_id: input.runner_id,
@@ -126,7 +155,11 @@ pub async fn pegboard_runner2(ctx: &mut WorkflowCtx, input: &Input) -> Result<()
 
 						if expired {
 							return Ok(Loop::Break(RunnerStopReason::ConnectionLost));
-						} else if state.draining {
+						}
+
+						state.reconnect_grace_period = None;
+
+						if state.draining {
 							return Ok(Loop::Break(RunnerStopReason::Draining));
 						} else {
 							return Ok(Loop::Continue);
@@ -271,11 +304,17 @@ async fn handle_stopping(
 #[derive(Debug, Serialize, Deserialize)]
 struct LifecycleState {
 	draining: bool,
+	/// Set while the runner is disconnected and within its reconnect grace period.
+	#[serde(default)]
+	reconnect_grace_period: Option<i64>,
 }
 
 impl LifecycleState {
 	fn new() -> Self {
-		LifecycleState { draining: false }
+		LifecycleState {
+			draining: false,
+			reconnect_grace_period: None,
+		}
 	}
 }
 
@@ -289,6 +328,8 @@ struct InitInput {
 	version: u32,
 	total_slots: u32,
 	create_ts: i64,
+	#[serde(default)]
+	capabilities: Vec<String>,
 }
 
 #[activity(InitActivity)]
@@ -368,6 +409,13 @@ async fn init(ctx: &ActivityCtx, input: &InitInput) -> Result<()> {
 					input.protocol_version,
 				)?;
 
+				if !input.capabilities.is_empty() {
+					tx.write(
+						&keys::runner::CapabilitiesKey::new(input.runner_id),
+						input.capabilities.clone(),
+					)?;
+				}
+
 				// Populate ns indexes
 				tx.write(
 					&keys::ns::ActiveRunnerKey::new(
@@ -654,6 +702,58 @@ async fn check_expired(ctx: &ActivityCtx, input: &CheckExpiredInput) -> Result<b
 		.map_err(Into::into)
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+struct CheckDisconnectedInput {
+	runner_id: Id,
+}
+
+/// Returns the reconnect grace period if the runner lost its connection and one is configured. The runner
+/// is removed from the alloc idx so no new actors are allocated to it until it reconnects (which re-adds it
+/// on ping), but its existing allocations are kept.
+#[activity(CheckDisconnected)]
+async fn check_disconnected(
+	ctx: &ActivityCtx,
+	input: &CheckDisconnectedInput,
+) -> Result<Option<i64>> {
+	let runner_lost_threshold = ctx.config().pegboard().runner_lost_threshold();
+	let grace_period = ctx.config().pegboard().runner_reconnect_grace_period();
+
+	if grace_period <= 0 {
+		return Ok(None);
+	}
+
+	let disconnected = ctx
+		.udb()?
+		.txn("pegboard_runner2_check_disconnected", |tx| async move {
+			let tx = tx.with_subspace(keys::subspace());
+
+			let last_ping_ts = tx
+				.read(
+					&keys::runner::LastPingTsKey::new(input.runner_id),
+					Serializable,
+				)
+				.await?;
+
+			Ok(last_ping_ts < util::timestamp::now() - runner_lost_threshold)
+		})
+		.custom_instrument(tracing::info_span!("runner_check_disconnected_tx"))
+		.await?;
+
+	if !disconnected {
+		return Ok(None);
+	}
+
+	ctx.op(crate::ops::runner::update_alloc_idx::Input {
+		runners: vec![crate::ops::runner::update_alloc_idx::Runner {
+			runner_id: input.runner_id,
+			action: crate::ops::runner::update_alloc_idx::Action::ClearIdx,
+		}],
+	})
+	.await?;
+
+	Ok(Some(grace_period))
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub(crate) struct AllocatePendingActorsInput {
 	pub namespace_id: Id,
@@ -751,6 +851,14 @@ pub(crate) async fn allocate_pending_actors(
 						return Ok(None);
 					}
 
+					let required_capabilities = tx
+						.read_opt(
+							&keys::actor::RequiredCapabilitiesKey::new(queue_key.actor_id),
+							Snapshot,
+						)
+						.await?
+						.unwrap_or_default();
+
 					let runner_alloc_subspace =
 						keys::subspace().subspace(&keys::ns::RunnerAllocIdxKey::subspace(
 							input.namespace_id,
@@ -804,6 +912,17 @@ pub(crate) async fn allocate_pending_actors(
 							continue;
 						}
 
+						// Ignore runners missing a required capability
+						if !crate::utils::runner_has_capabilities(
+							&tx,
+							old_runner_alloc_key.runner_id,
+							&required_capabilities,
+						)
+						.await?
+						{
+							continue;
+						}
+
 						candidates.push((old_runner_alloc_key, old_runner_alloc_key_data));
 
 						// Max candidate size reached
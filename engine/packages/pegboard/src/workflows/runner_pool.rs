@@ -1,4 +1,6 @@
+use std::collections::HashMap;
 use std::hash::{DefaultHasher, Hash, Hasher};
+use std::time::Duration;
 
 use futures_util::FutureExt;
 use gas::prelude::*;
@@ -7,6 +9,11 @@ use universaldb::prelude::*;
 
 use super::{runner_pool_error_tracker, runner_pool_metadata_poller, serverless};
 
+/// Max signals received per listen.
+const SIGNAL_BATCH_SIZE: usize = 256;
+/// Max listens per tick. Bounds how long a backlog of signals can delay the next tick.
+const MAX_SIGNAL_BATCHES: usize = 8;
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Input {
 	pub namespace_id: Id,
@@ -16,12 +23,21 @@ pub struct Input {
 #[derive(Debug, Serialize, Deserialize, Default)]
 struct LifecycleState {
 	runners: Vec<RunnerState>,
+	/// Connection count to hold while the autoscaler is paused for this namespace.
+	#[serde(default)]
+	paused_count: Option<usize>,
+	/// Whether `ServerlessPoolSignalBacklogKey` is currently set.
+	#[serde(default)]
+	backlogged: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 struct RunnerState {
 	receiver_wf_id: Id,
 	details_hash: u64,
+	/// Runner currently connected through this connection. Set once the runner sends its init.
+	#[serde(default)]
+	runner_id: Option<Id>,
 }
 
 #[workflow]
@@ -69,24 +85,50 @@ pub async fn pegboard_runner_pool2(ctx: &mut WorkflowCtx, input: &Input) -> Resu
 			let input = input.clone();
 			async move {
 				// Get desired count -> drain and start counts
-				let ReadDesiredOutput::Desired {
-					desired_count,
-					details_hash,
-				} = ctx.activity(ReadDesiredInput {
-					namespace_id: input.namespace_id,
-					runner_name: input.runner_name.clone(),
-				})
-				.await?
-				else {
-					// Drain all
-					for runner in &state.runners {
-						ctx.signal(serverless::receiver::Drain {})
-							.to_workflow_id(runner.receiver_wf_id)
-							.send()
-							.await?;
+				let (desired_count, details_hash) = match ctx
+					.activity(ReadDesiredInput {
+						namespace_id: input.namespace_id,
+						runner_name: input.runner_name.clone(),
+					})
+					.await?
+				{
+					ReadDesiredOutput::Desired {
+						desired_count,
+						details_hash,
+					} => {
+						state.paused_count = None;
+
+						(desired_count, details_hash)
 					}
+					// Hold the connection count from when the pause started. Connections that end
+					// (i.e. reach their lifespan) are still replaced.
+					ReadDesiredOutput::Paused { details_hash } => {
+						let paused_count = *state.paused_count.get_or_insert(state.runners.len());
 
-					return Ok(Loop::Break(()));
+						(paused_count, details_hash)
+					}
+					ReadDesiredOutput::Stop => {
+						// Drain all
+						for runner in &state.runners {
+							ctx.signal(serverless::receiver::Drain {})
+								.to_workflow_id(runner.receiver_wf_id)
+								.send()
+								.await?;
+						}
+
+						// Don't leave a stale backlog for the next pool with this name
+						if state.backlogged {
+							ctx.v(2)
+								.activity(SetSignalBacklogInput {
+									namespace_id: input.namespace_id,
+									runner_name: input.runner_name.clone(),
+									backlogged: false,
+								})
+								.await?;
+						}
+
+						return Ok(Loop::Break(()));
+					}
 				};
 
 				// Remove runners that have an outdated hash. This is done outside of the below draining mechanism
@@ -110,7 +152,22 @@ pub async fn pegboard_runner_pool2(ctx: &mut WorkflowCtx, input: &Input) -> Resu
 
 				// Drain unnecessary runners
 				if drain_count != 0 {
-					// TODO: Implement smart logic of draining runners with the lowest allocated actors
+					// Drain the runners with the fewest allocated actors first so that scaling down
+					// reschedules as few actors as possible
+					let allocated_slots = ctx
+						.v(2)
+						.activity(ReadAllocatedSlotsInput {
+							runner_ids: state.runners.iter().filter_map(|r| r.runner_id).collect(),
+						})
+						.await?;
+					// Connections without a runner have no actors. Stable sort keeps the oldest
+					// connections first among equally loaded runners.
+					state.runners.sort_by_key(|r| {
+						r.runner_id
+							.and_then(|runner_id| allocated_slots.get(&runner_id).copied())
+							.unwrap_or_default()
+					});
+
 					let remaining_runners = state.runners.split_off(drain_count);
 					let draining_runners = std::mem::replace(&mut state.runners, remaining_runners);
 
@@ -140,12 +197,68 @@ pub async fn pegboard_runner_pool2(ctx: &mut WorkflowCtx, input: &Input) -> Resu
 						state.runners.push(RunnerState {
 							receiver_wf_id,
 							details_hash,
+							runner_id: None,
 						});
 					}
 				}
 
 				// Wait for Bump or serverless signals until we tick again
-				for sig in ctx.listen_n::<Main>(256).await? {
+				let version = ctx.check_version(2).await?;
+				let signals = if version == 1 {
+					ctx.listen_n::<Main>(SIGNAL_BATCH_SIZE).await?
+				} else {
+					// Any backlog of pending signals is drained (up to a limit) so that it collapses
+					// into a single tick
+					let mut signals = ctx.v(2).listen_n::<Main>(SIGNAL_BATCH_SIZE).await?;
+					let mut batches = 1;
+					let mut last_batch_len = signals.len();
+					while last_batch_len == SIGNAL_BATCH_SIZE && batches < MAX_SIGNAL_BATCHES {
+						let batch = ctx
+							.v(2)
+							.listen_n_with_timeout::<Main>(Duration::ZERO, SIGNAL_BATCH_SIZE)
+							.await?;
+						last_batch_len = batch.len();
+						signals.extend(batch);
+						batches += 1;
+					}
+
+					let namespace_id_label = input.namespace_id.to_string();
+					crate::metrics::SERVERLESS_POOL_SIGNAL_BACKLOG
+						.with_label_values(&[&namespace_id_label, &input.runner_name])
+						.set(signals.len() as i64);
+
+					let backlogged = last_batch_len == SIGNAL_BATCH_SIZE;
+					if backlogged {
+						tracing::warn!(
+							namespace_id=%input.namespace_id,
+							runner_name=%input.runner_name,
+							drained=signals.len(),
+							"runner pool is behind, signals still pending after drain limit"
+						);
+
+						crate::metrics::SERVERLESS_POOL_BACKLOG_SATURATED_TOTAL
+							.with_label_values(&[&namespace_id_label, &input.runner_name])
+							.inc();
+					}
+
+					// Let publishers know whether to hold off on sending bumps
+					if backlogged != state.backlogged {
+						ctx.v(2)
+							.activity(SetSignalBacklogInput {
+								namespace_id: input.namespace_id,
+								runner_name: input.runner_name.clone(),
+								backlogged,
+							})
+							.await?;
+						state.backlogged = backlogged;
+					}
+
+					signals
+				};
+
+				let mut bumps = 0;
+				let mut endpoint_config_changed = false;
+				for sig in signals {
 					match sig {
 						Main::OutboundConnDrainStarted(sig) => {
 							let (new, drain_started) = std::mem::take(&mut state.runners)
@@ -161,20 +274,53 @@ pub async fn pegboard_runner_pool2(ctx: &mut WorkflowCtx, input: &Input) -> Resu
 									.await?;
 							}
 						}
+						Main::OutboundConnRunnerConnected(sig) => {
+							if let Some(runner) = state
+								.runners
+								.iter_mut()
+								.find(|r| r.receiver_wf_id == sig.receiver_wf_id)
+							{
+								runner.runner_id = Some(sig.runner_id);
+							}
+						}
 						Main::Bump(bump) => {
-							if bump.endpoint_config_changed {
-								// Forward to metadata poller to trigger immediate metadata fetch
-								ctx.signal(runner_pool_metadata_poller::EndpointConfigChanged {})
+							if version == 1 {
+								if bump.endpoint_config_changed {
+									// Forward to metadata poller to trigger immediate metadata fetch
+									ctx.signal(
+										runner_pool_metadata_poller::EndpointConfigChanged {},
+									)
 									.to_workflow::<runner_pool_metadata_poller::Workflow>()
 									.tag("namespace_id", input.namespace_id)
 									.tag("runner_name", &input.runner_name)
 									.send()
 									.await?;
+								}
+							} else {
+								bumps += 1;
+								endpoint_config_changed |= bump.endpoint_config_changed;
 							}
 						}
 					}
 				}
 
+				if bumps > 1 {
+					crate::metrics::SERVERLESS_POOL_BUMPS_COALESCED_TOTAL
+						.with_label_values(&[&input.namespace_id.to_string(), &input.runner_name])
+						.inc_by(bumps - 1);
+				}
+
+				if endpoint_config_changed {
+					// Forward to metadata poller to trigger immediate metadata fetch
+					ctx.v(2)
+						.signal(runner_pool_metadata_poller::EndpointConfigChanged {})
+						.to_workflow::<runner_pool_metadata_poller::Workflow>()
+						.tag("namespace_id", input.namespace_id)
+						.tag("runner_name", &input.runner_name)
+						.send()
+						.await?;
+				}
+
 				Ok(Loop::Continue)
 			}
 			.boxed()
@@ -204,12 +350,16 @@ enum ReadDesiredOutput {
 		details_hash: u64,
 	},
 	Stop,
+	/// The serverless autoscaler is paused for this namespace.
+	Paused {
+		details_hash: u64,
+	},
 }
 
 #[activity(ReadDesired)]
 async fn read_desired(ctx: &ActivityCtx, input: &ReadDesiredInput) -> Result<ReadDesiredOutput> {
 	let udb_pool = ctx.udb()?;
-	let (runner_config_res, desired_slots) = tokio::try_join!(
+	let (runner_config_res, (desired_slots, paused)) = tokio::try_join!(
 		ctx.op(crate::ops::runner_config::get::Input {
 			runners: vec![(input.namespace_id, input.runner_name.clone())],
 			bypass_cache: false,
@@ -217,17 +367,21 @@ async fn read_desired(ctx: &ActivityCtx, input: &ReadDesiredInput) -> Result<Rea
 		udb_pool.txn("pegboard_runner_pool_read_desired_slots", |tx| async move {
 			let tx = tx.with_subspace(keys::pegboard::subspace());
 
-			let desired_slots = tx
-				.read_opt(
+			let (desired_slots, paused) = tokio::try_join!(
+				tx.read_opt(
 					&keys::pegboard::ns::ServerlessDesiredSlotsKey {
 						namespace_id: input.namespace_id,
 						runner_name: input.runner_name.clone(),
 					},
 					Serializable,
-				)
-				.await?;
+				),
+				tx.exists(
+					&crate::keys::ns::ServerlessAutoscalerPausedKey::new(input.namespace_id),
+					Serializable,
+				),
+			)?;
 
-			Ok(desired_slots.unwrap_or_default())
+			Ok((desired_slots.unwrap_or_default(), paused))
 		}),
 	)?;
 	let Some(runner_config) = runner_config_res.into_iter().next() else {
@@ -256,6 +410,24 @@ async fn read_desired(ctx: &ActivityCtx, input: &ReadDesiredInput) -> Result<Rea
 		});
 	}
 
+	// Compute consistent hash of serverless details
+	let mut hasher = DefaultHasher::new();
+	url.hash(&mut hasher);
+	let mut sorted_headers = headers.iter().collect::<Vec<_>>();
+	sorted_headers.sort();
+	sorted_headers.hash(&mut hasher);
+	let details_hash = hasher.finish();
+
+	if paused {
+		tracing::debug!(
+			namespace_id=%input.namespace_id,
+			runner_name=%input.runner_name,
+			"serverless autoscaler paused, holding connection count"
+		);
+
+		return Ok(ReadDesiredOutput::Paused { details_hash });
+	}
+
 	let adjusted_desired_slots = if desired_slots < 0 {
 		tracing::error!(
 			namespace_id=%input.namespace_id,
@@ -269,7 +441,7 @@ async fn read_desired(ctx: &ActivityCtx, input: &ReadDesiredInput) -> Result<Rea
 	};
 
 	// Won't overflow as these values are all in u32 range
-	let desired_count = (runners_margin
+	let desired_count: usize = (runners_margin
 		+ (adjusted_desired_slots as u32).div_ceil(slots_per_runner.max(1)))
 	.max(min_runners)
 	.min(max_runners)
@@ -281,13 +453,11 @@ async fn read_desired(ctx: &ActivityCtx, input: &ReadDesiredInput) -> Result<Rea
 	)
 	.try_into()?;
 
-	// Compute consistent hash of serverless details
-	let mut hasher = DefaultHasher::new();
-	url.hash(&mut hasher);
-	let mut sorted_headers = headers.iter().collect::<Vec<_>>();
-	sorted_headers.sort();
-	sorted_headers.hash(&mut hasher);
-	let details_hash = hasher.finish();
+	// Each runner name scales its own pool independently, even when several runner names in the
+	// same namespace point at different providers
+	crate::metrics::SERVERLESS_DESIRED_RUNNERS
+		.with_label_values(&[&input.namespace_id.to_string(), &input.runner_name])
+		.set(desired_count as i64);
 
 	Ok(ReadDesiredOutput::Desired {
 		desired_count,
@@ -295,6 +465,80 @@ async fn read_desired(ctx: &ActivityCtx, input: &ReadDesiredInput) -> Result<Rea
 	})
 }
 
+#[derive(Debug, Serialize, Deserialize, Hash)]
+struct SetSignalBacklogInput {
+	namespace_id: Id,
+	runner_name: String,
+	backlogged: bool,
+}
+
+#[activity(SetSignalBacklog)]
+async fn set_signal_backlog(ctx: &ActivityCtx, input: &SetSignalBacklogInput) -> Result<()> {
+	ctx.udb()?
+		.txn("pegboard_runner_pool_set_signal_backlog", |tx| async move {
+			let tx = tx.with_subspace(crate::keys::subspace());
+
+			let backlog_key = crate::keys::ns::ServerlessPoolSignalBacklogKey::new(
+				input.namespace_id,
+				input.runner_name.clone(),
+			);
+			if input.backlogged {
+				tx.write(&backlog_key, ())?;
+			} else {
+				tx.delete(&backlog_key);
+			}
+
+			Ok(())
+		})
+		.custom_instrument(tracing::info_span!("runner_pool_set_signal_backlog_tx"))
+		.await
+}
+
+#[derive(Debug, Serialize, Deserialize, Hash)]
+struct ReadAllocatedSlotsInput {
+	runner_ids: Vec<Id>,
+}
+
+/// Reads the amount of allocated slots of each runner. Runners that no longer exist are omitted.
+#[activity(ReadAllocatedSlots)]
+async fn read_allocated_slots(
+	ctx: &ActivityCtx,
+	input: &ReadAllocatedSlotsInput,
+) -> Result<HashMap<Id, u32>> {
+	ctx.udb()?
+		.txn(
+			"pegboard_runner_pool_read_allocated_slots",
+			|tx| async move {
+				let tx = tx.with_subspace(crate::keys::subspace());
+				let mut allocated_slots = HashMap::new();
+
+				for runner_id in &input.runner_ids {
+					let (total_slots, remaining_slots) = tokio::try_join!(
+						tx.read_opt(
+							&crate::keys::runner::TotalSlotsKey::new(*runner_id),
+							Serializable
+						),
+						tx.read_opt(
+							&crate::keys::runner::RemainingSlotsKey::new(*runner_id),
+							Serializable
+						),
+					)?;
+
+					if let (Some(total_slots), Some(remaining_slots)) =
+						(total_slots, remaining_slots)
+					{
+						allocated_slots
+							.insert(*runner_id, total_slots.saturating_sub(remaining_slots));
+					}
+				}
+
+				Ok(allocated_slots)
+			},
+		)
+		.custom_instrument(tracing::info_span!("runner_pool_read_allocated_slots_tx"))
+		.await
+}
+
 #[signal("pegboard_runner_pool_bump")]
 #[derive(Debug, Default)]
 pub struct Bump {
@@ -307,7 +551,14 @@ pub struct OutboundConnDrainStarted {
 	pub receiver_wf_id: Id,
 }
 
+#[signal("pegboard_outbound_conn_runner_connected")]
+pub struct OutboundConnRunnerConnected {
+	pub receiver_wf_id: Id,
+	pub runner_id: Id,
+}
+
 join_signal!(Main {
 	Bump,
 	OutboundConnDrainStarted,
+	OutboundConnRunnerConnected,
 });
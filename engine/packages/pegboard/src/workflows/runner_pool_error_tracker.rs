@@ -21,6 +21,24 @@ pub struct State {
 	/// Count of consecutive successes since last error.
 	/// Error is only cleared after reaching the configured threshold.
 	pub consecutive_successes: u32,
+
+	/// Count of consecutive errors since last success. The circuit breaker opens once this reaches the
+	/// configured threshold.
+	#[serde(default)]
+	pub consecutive_errors: u32,
+
+	/// Timestamp until which the circuit breaker is open. Serverless connections do not send requests to
+	/// the endpoint while it is open.
+	#[serde(default)]
+	pub circuit_open_until: Option<i64>,
+}
+
+impl State {
+	/// Returns the timestamp until which the circuit breaker is open, if it is open at `now`.
+	pub fn circuit_open_until(&self, now: i64) -> Option<i64> {
+		self.circuit_open_until
+			.filter(|open_until| *open_until > now)
+	}
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -48,6 +66,7 @@ pub async fn pegboard_runner_pool_error_tracker(
 		// Txn sizes can quickly get large in this workflow, need to commit loop more often
 		.commit_interval(1)
 		.run(|ctx, _| {
+			let input = input.clone();
 			Box::pin(async move {
 				// Sleep until we receive a signal
 				let signals_a = ctx.v(2).listen_n::<Main>(SIGNAL_BATCH_SIZE).await?;
@@ -74,6 +93,8 @@ pub async fn pegboard_runner_pool_error_tracker(
 				// Process signals
 				let shutdown = ctx
 					.activity(ProcessSignalsInput {
+						namespace_id: input.namespace_id,
+						runner_name: input.runner_name.clone(),
 						signals: signals_inner,
 					})
 					.await?;
@@ -102,6 +123,8 @@ pub async fn init_state(ctx: &ActivityCtx, _input: &InitStateInput) -> Result<()
 
 #[derive(Debug, Serialize, Deserialize, Hash)]
 pub struct ProcessSignalsInput {
+	pub namespace_id: Id,
+	pub runner_name: String,
 	pub signals: Vec<MainInner>,
 }
 
@@ -118,6 +141,8 @@ pub async fn process_signals(ctx: &ActivityCtx, input: &ProcessSignalsInput) ->
 				tracing::debug!(
 					workflow_id = %ctx.workflow_id(),
 					error = ?report.error,
+					category = report.error.category().as_str(),
+					message = %report.error,
 					was_clean,
 					"runner pool error tracker received error"
 				);
@@ -126,9 +151,49 @@ pub async fn process_signals(ctx: &ActivityCtx, input: &ProcessSignalsInput) ->
 					error: report.error.clone(),
 				});
 				state.consecutive_successes = 0;
+				state.consecutive_errors = state.consecutive_errors.saturating_add(1);
+
+				// Open the circuit breaker. Once the cooldown passes, the next error (i.e. the first
+				// request after the cooldown failing) opens it again.
+				let pegboard_config = ctx.config().pegboard();
+				if state.consecutive_errors
+					>= pegboard_config.serverless_circuit_breaker_threshold()
+					&& state.circuit_open_until(now).is_none()
+				{
+					tracing::warn!(
+						namespace_id = %input.namespace_id,
+						runner_name = %input.runner_name,
+						consecutive_errors = state.consecutive_errors,
+						"opening serverless circuit breaker"
+					);
+
+					state.circuit_open_until =
+						Some(now + pegboard_config.serverless_circuit_breaker_cooldown());
+
+					let namespace_id = input.namespace_id.to_string();
+					crate::metrics::SERVERLESS_CIRCUIT_OPEN
+						.with_label_values(&[&namespace_id, &input.runner_name])
+						.set(1);
+					crate::metrics::SERVERLESS_CIRCUIT_OPENED_TOTAL
+						.with_label_values(&[&namespace_id, &input.runner_name])
+						.inc();
+				}
 			}
 			MainInner::ReportSuccess(_) => {
 				state.consecutive_successes += 1;
+				state.consecutive_errors = 0;
+
+				if state.circuit_open_until.take().is_some() {
+					tracing::debug!(
+						namespace_id = %input.namespace_id,
+						runner_name = %input.runner_name,
+						"closing serverless circuit breaker"
+					);
+
+					crate::metrics::SERVERLESS_CIRCUIT_OPEN
+						.with_label_values(&[&input.namespace_id.to_string(), &input.runner_name])
+						.set(0);
+				}
 
 				// Only clear error after threshold reached
 				let threshold = ctx
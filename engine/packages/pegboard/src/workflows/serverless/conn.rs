@@ -4,17 +4,20 @@ use anyhow::{Context, bail};
 use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
 use futures_util::{FutureExt, StreamExt};
 use gas::prelude::*;
-use reqwest::header::{HeaderName, HeaderValue};
+use rand::Rng;
+use reqwest::header::{AUTHORIZATION, CONTENT_TYPE, HeaderMap, HeaderName, HeaderValue};
 use reqwest_eventsource as sse;
 use rivet_runner_protocol as protocol;
 use rivet_runtime::TermSignal;
 use rivet_types::actor::RunnerPoolError;
-use rivet_types::runner_configs::RunnerConfigKind;
+use rivet_types::runner_configs::{RunnerConfigKind, ServerlessRequestMethod};
 use rivet_util::safe_slice;
 use tokio::time::Duration;
+use universaldb::utils::IsolationLevel::*;
 use universalpubsub::PublishOpts;
 use vbare::OwnedVersionedData;
 
+use crate::keys;
 use crate::metrics;
 use crate::pubsub_subjects::RunnerReceiverSubject;
 use crate::workflows::{runner_pool, runner_pool_error_tracker, serverless::receiver};
@@ -24,6 +27,8 @@ const X_RIVET_TOKEN: HeaderName = HeaderName::from_static("x-rivet-token");
 const X_RIVET_TOTAL_SLOTS: HeaderName = HeaderName::from_static("x-rivet-total-slots");
 const X_RIVET_RUNNER_NAME: HeaderName = HeaderName::from_static("x-rivet-runner-name");
 const X_RIVET_NAMESPACE_NAME: HeaderName = HeaderName::from_static("x-rivet-namespace-name");
+/// Max random delay added to retries after the circuit breaker closes, in milliseconds.
+const CIRCUIT_RETRY_JITTER_MS: i64 = 1_000;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Input {
@@ -56,8 +61,23 @@ pub async fn pegboard_serverless_conn2(ctx: &mut WorkflowCtx, input: &Input) ->
 					})
 					.await?;
 
-				if let OutboundReqOutput::Draining { drain_sent } = res {
-					return Ok(Loop::Break(drain_sent));
+				match res {
+					OutboundReqOutput::Draining { drain_sent } => {
+						return Ok(Loop::Break(drain_sent));
+					}
+					// Wait for the circuit breaker to close without increasing the backoff
+					OutboundReqOutput::CircuitOpen { retry_ts } => {
+						if ctx.listen_until::<Drain>(retry_ts).await?.is_some() {
+							tracing::debug!(
+								"drain received while serverless circuit breaker is open"
+							);
+
+							return Ok(Loop::Break(false));
+						}
+
+						return Ok(Loop::Continue);
+					}
+					OutboundReqOutput::Continue | OutboundReqOutput::Retry => {}
 				}
 
 				let mut backoff = reconnect_backoff(
@@ -150,6 +170,11 @@ enum OutboundReqOutput {
 		drain_sent: bool,
 	},
 	Retry,
+	/// The runner pool's circuit breaker is open. No request was sent.
+	CircuitOpen {
+		/// When to retry the request.
+		retry_ts: i64,
+	},
 }
 
 #[activity(OutboundReq)]
@@ -171,7 +196,10 @@ async fn outbound_req(ctx: &ActivityCtx, input: &OutboundReqInput) -> Result<Out
 		match res {
 			// If the outbound req exited successfully, continue with no backoff
 			Ok(OutboundReqOutput::Continue) => {
-				if let Err(err) = ctx
+				// The pool ticks again on its own while it has a signal backlog, don't add to it
+				if is_pool_backlogged(ctx, input).await {
+					tracing::debug!("runner pool is behind, skipping bump");
+				} else if let Err(err) = ctx
 					.signal(runner_pool::Bump::default())
 					// This is ok because bumps are not stateful
 					.bypass_signal_from_workflow_I_KNOW_WHAT_IM_DOING()
@@ -188,6 +216,7 @@ async fn outbound_req(ctx: &ActivityCtx, input: &OutboundReqInput) -> Result<Out
 				return Ok(OutboundReqOutput::Draining { drain_sent });
 			}
 			Ok(OutboundReqOutput::Retry) => return Ok(OutboundReqOutput::Retry),
+			Ok(res @ OutboundReqOutput::CircuitOpen { .. }) => return Ok(res),
 			Err(error) => {
 				tracing::warn!(?error, "outbound_req_inner failed, retrying after backoff");
 				return Ok(OutboundReqOutput::Retry);
@@ -196,6 +225,38 @@ async fn outbound_req(ctx: &ActivityCtx, input: &OutboundReqInput) -> Result<Out
 	}
 }
 
+/// Best effort, errors are treated as not backlogged so the bump is still sent.
+async fn is_pool_backlogged(ctx: &ActivityCtx, input: &OutboundReqInput) -> bool {
+	let res = async {
+		ctx.udb()?
+			.txn(
+				"pegboard_serverless_conn_read_pool_backlog",
+				|tx| async move {
+					let tx = tx.with_subspace(keys::subspace());
+
+					tx.exists(
+						&keys::ns::ServerlessPoolSignalBacklogKey::new(
+							input.namespace_id,
+							input.runner_name.clone(),
+						),
+						Serializable,
+					)
+					.await
+				},
+			)
+			.await
+	}
+	.await;
+
+	match res {
+		Ok(backlogged) => backlogged,
+		Err(err) => {
+			tracing::debug!(?err, "failed to read runner pool signal backlog");
+			false
+		}
+	}
+}
+
 async fn outbound_req_inner(
 	ctx: &ActivityCtx,
 	input: &OutboundReqInput,
@@ -205,6 +266,24 @@ async fn outbound_req_inner(
 		return Ok(OutboundReqOutput::Draining { drain_sent: false });
 	}
 
+	if let Some(open_until) =
+		circuit_open_until(ctx, input.namespace_id, &input.runner_name).await?
+	{
+		// Jitter so that connections don't all retry at the same time once the circuit closes
+		let jitter = rand::thread_rng().gen_range(0..CIRCUIT_RETRY_JITTER_MS);
+
+		tracing::debug!(
+			namespace_id = %input.namespace_id,
+			runner_name = %input.runner_name,
+			open_until,
+			"serverless circuit breaker open, skipping outbound req"
+		);
+
+		return Ok(OutboundReqOutput::CircuitOpen {
+			retry_ts: open_until + jitter,
+		});
+	}
+
 	let (runner_config_res, namespace_res) = tokio::try_join!(
 		ctx.op(crate::ops::runner_config::get::Input {
 			runners: vec![(input.namespace_id, input.runner_name.clone())],
@@ -228,6 +307,9 @@ async fn outbound_req_inner(
 		headers,
 		slots_per_runner,
 		request_lifespan,
+		method,
+		body,
+		auth,
 		..
 	} = runner_config.config.kind
 	else {
@@ -262,7 +344,38 @@ async fn outbound_req_inner(
 		None
 	};
 
-	let headers = headers
+	let authorization = if let Some(auth) = &auth {
+		match auth.authorization(ctx.config().pegboard().serverless_auth_env_allowlist()) {
+			Ok(authorization) => Some(HeaderValue::try_from(authorization)?),
+			Err(err) => {
+				tracing::error!(?err, "failed to resolve serverless auth");
+				report_error(
+					ctx,
+					input.namespace_id,
+					&input.runner_name,
+					RunnerPoolError::InternalError,
+				)
+				.await;
+				return Ok(OutboundReqOutput::Retry);
+			}
+		}
+	} else {
+		None
+	};
+
+	let body = body.map(|body| {
+		render_body(
+			&body,
+			&[
+				("endpoint", current_dc.public_url.as_str()),
+				("namespace_name", &namespace.name),
+				("runner_name", &input.runner_name),
+				("total_slots", &slots_per_runner.to_string()),
+			],
+		)
+	});
+
+	let mut headers: HeaderMap = headers
 		.into_iter()
 		.flat_map(|(k, v)| {
 			// NOTE: This will filter out invalid headers without warning
@@ -296,18 +409,31 @@ async fn outbound_req_inner(
 		])
 		.chain(token)
 		.collect();
+	// Insert so the configured auth replaces any `authorization` header instead of adding a second one
+	if let Some(authorization) = authorization {
+		headers.insert(AUTHORIZATION, authorization);
+	}
+	if body.is_some() && !headers.contains_key(CONTENT_TYPE) {
+		headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+	}
 
 	let endpoint_url = format!("{}/start", url.trim_end_matches('/'));
 
-	tracing::debug!(%endpoint_url, "sending outbound req");
+	tracing::debug!(%endpoint_url, ?method, "sending outbound req");
 
 	let client = rivet_pools::reqwest::client_no_timeout().await?;
-	let req = client.get(endpoint_url).headers(headers);
+	let req = match method {
+		ServerlessRequestMethod::Get => client.get(endpoint_url),
+		ServerlessRequestMethod::Post => client.post(endpoint_url).body(body.unwrap_or_default()),
+	}
+	.headers(headers);
 
 	let conn_started = Instant::now();
 	let mut source = sse::EventSource::new(req).context("failed creating event source")?;
 	let mut runner_id = None;
 	let mut runner_protocol_version = None;
+	let (init_tx, init_rx) = tokio::sync::oneshot::channel::<()>();
+	let mut init_tx = Some(init_tx);
 
 	let runner_protocol_version2 = &mut runner_protocol_version;
 	let stream_handler = async {
@@ -339,12 +465,33 @@ async fn outbound_req_inner(
 					match payload {
 						protocol::mk2::ToServerlessServer::ToServerlessServerInit(init) => {
 							if runner_id.is_none() {
-								runner_id =
-									Some(Id::parse(&init.runner_id).context("invalid runner id")?);
+								let runner_id_local =
+									Id::parse(&init.runner_id).context("invalid runner id")?;
+								runner_id = Some(runner_id_local);
 								*runner_protocol_version2 = Some(init.runner_protocol_version);
 
+								if let Some(init_tx) = init_tx.take() {
+									let _ = init_tx.send(());
+								}
+
 								// Report success to error tracker - runner initialized successfully
 								report_success(ctx, input.namespace_id, &input.runner_name).await;
+
+								// Let the pool know which runner this connection serves so that it can
+								// drain the least loaded runners when scaling down
+								if let Err(err) = ctx
+									.signal(runner_pool::OutboundConnRunnerConnected {
+										receiver_wf_id: input.receiver_wf_id,
+										runner_id: runner_id_local,
+									})
+									// This is ok because the pool only keeps the latest runner id
+									.bypass_signal_from_workflow_I_KNOW_WHAT_IM_DOING()
+									.to_workflow_id(input.pool_wf_id)
+									.send()
+									.await
+								{
+									tracing::debug!(?err, "failed to send runner connected signal");
+								}
 							}
 						}
 					}
@@ -443,6 +590,19 @@ async fn outbound_req_inner(
 	let sleep_until_drain = Duration::from_secs(request_lifespan as u64).saturating_sub(
 		Duration::from_millis(ctx.config().pegboard().serverless_drain_grace_period()),
 	);
+
+	// Resolves only if the runner did not send its init message in time. Once init is received (or the
+	// stream handler exits) this never resolves.
+	let init_timeout = ctx.config().pegboard().serverless_init_timeout();
+	let init_watchdog = async {
+		if tokio::time::timeout(Duration::from_millis(init_timeout), init_rx)
+			.await
+			.is_ok()
+		{
+			std::future::pending::<()>().await;
+		}
+	};
+
 	tokio::select! {
 		res = stream_handler => {
 			match res {
@@ -451,6 +611,27 @@ async fn outbound_req_inner(
 				Err(e) => return Err(e.into()),
 			}
 		},
+		_ = init_watchdog => {
+			tracing::warn!(
+				namespace_id = %input.namespace_id,
+				runner_name = %input.runner_name,
+				init_timeout_ms = init_timeout,
+				"serverless runner did not send init, closing connection"
+			);
+
+			report_error(
+				ctx,
+				input.namespace_id,
+				&input.runner_name,
+				RunnerPoolError::ServerlessInitTimeout {
+					timeout_ms: init_timeout,
+				},
+			)
+			.await;
+
+			// Dropping the event source closes the connection
+			return Ok(OutboundReqOutput::Retry);
+		}
 		_ = tokio::time::sleep(sleep_until_drain) => {}
 		_ = term_signal.recv() => {}
 	};
@@ -499,6 +680,37 @@ async fn is_runner_draining(ctx: &ActivityCtx, receiver_wf_id: Id) -> Result<boo
 	Ok(state.is_draining)
 }
 
+/// Reads the circuit breaker state of the runner pool from its error tracker workflow.
+#[tracing::instrument(skip_all)]
+async fn circuit_open_until(
+	ctx: &ActivityCtx,
+	namespace_id: Id,
+	runner_name: &str,
+) -> Result<Option<i64>> {
+	let Some(error_tracker_wf_id) = ctx
+		.find_workflow::<runner_pool_error_tracker::Workflow>(serde_json::json!({
+			"namespace_id": namespace_id,
+			"runner_name": runner_name,
+		}))
+		.await?
+	else {
+		return Ok(None);
+	};
+
+	let Some(error_tracker_wf) = ctx
+		.get_workflows(vec![error_tracker_wf_id])
+		.await?
+		.into_iter()
+		.next()
+	else {
+		return Ok(None);
+	};
+
+	let state = error_tracker_wf.parse_state::<Option<runner_pool_error_tracker::State>>()?;
+
+	Ok(state.and_then(|state| state.circuit_open_until(util::timestamp::now())))
+}
+
 #[tracing::instrument(skip_all)]
 async fn finish_non_critical_draining(
 	ctx: &ActivityCtx,
@@ -639,6 +851,14 @@ fn reconnect_backoff(
 	util::backoff::Backoff::new_at(max_exponent, None, base_retry_timeout, 500, retry_count)
 }
 
+/// Replaces `{{name}}` placeholders in the configured request body.
+fn render_body(template: &str, vars: &[(&str, &str)]) -> String {
+	vars.iter()
+		.fold(template.to_string(), |body, (name, value)| {
+			body.replace(&format!("{{{{{name}}}}}"), value)
+		})
+}
+
 /// Report an error to the error tracker workflow.
 async fn report_error(
 	ctx: &ActivityCtx,
@@ -0,0 +1,226 @@
+use std::time::Duration;
+
+use anyhow::anyhow;
+use futures_util::FutureExt;
+use gas::prelude::*;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::keys;
+
+/// How many times delivery is attempted before the notification is moved to the dead letters.
+const MAX_ATTEMPTS: usize = 5;
+const RETRY_BASE_MS: usize = 500;
+const RETRY_MAX_EXPONENT: usize = 5;
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub const NOTIFICATION_ID_HEADER: &str = "x-rivet-notification-id";
+pub const TIMESTAMP_HEADER: &str = "x-rivet-timestamp";
+pub const SIGNATURE_HEADER: &str = "x-rivet-signature";
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookEvent {
+	/// The actor started and is connectable.
+	Ready,
+	/// The actor stopped running. Sent for every stop, including crashes.
+	Stopped,
+	/// The actor crashed or its runner failed. Followed by a `stopped` event.
+	Failed,
+}
+
+/// Body posted to the webhook.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookPayload {
+	pub notification_id: Id,
+	pub event: WebhookEvent,
+	pub namespace_id: Id,
+	pub actor_id: Id,
+	pub actor_name: String,
+	pub ts: i64,
+	/// Set for `failed` events.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub failure_reason: Option<crate::workflows::actor::FailureReason>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Input {
+	pub namespace_id: Id,
+	pub notification_id: Id,
+	pub url: String,
+	/// Serialized `WebhookPayload`.
+	pub body: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct DeliveryState {
+	attempts: usize,
+}
+
+#[workflow]
+pub async fn pegboard_webhook(ctx: &mut WorkflowCtx, input: &Input) -> Result<()> {
+	let res = ctx
+		.loope(DeliveryState::default(), |ctx, state| {
+			let input = input.clone();
+			async move {
+				let res = ctx
+					.activity(DeliverInput {
+						namespace_id: input.namespace_id,
+						notification_id: input.notification_id,
+						url: input.url.clone(),
+						body: input.body.clone(),
+					})
+					.await?;
+
+				state.attempts += 1;
+
+				match res {
+					Ok(()) => Ok(Loop::Break(Ok(()))),
+					Err(error) if state.attempts >= MAX_ATTEMPTS => {
+						Ok(Loop::Break(Err((state.attempts, error))))
+					}
+					Err(error) => {
+						tracing::debug!(attempts=%state.attempts, %error, "webhook delivery failed, retrying");
+
+						let backoff = util::backoff::Backoff::new_at(
+							RETRY_MAX_EXPONENT,
+							None,
+							RETRY_BASE_MS,
+							0,
+							state.attempts - 1,
+						);
+						ctx.sleep(backoff.current_duration() as u64).await?;
+
+						Ok(Loop::Continue)
+					}
+				}
+			}
+			.boxed()
+		})
+		.await?;
+
+	if let Err((attempts, error)) = res {
+		tracing::warn!(%attempts, %error, "webhook delivery failed, moving to dead letters");
+
+		ctx.activity(InsertDeadLetterInput {
+			namespace_id: input.namespace_id,
+			notification_id: input.notification_id,
+			url: input.url.clone(),
+			body: input.body.clone(),
+			attempts,
+			error,
+		})
+		.await?;
+	}
+
+	Ok(())
+}
+
+/// Signature sent in the `x-rivet-signature` header. Hex encoded HMAC-SHA256 of `{timestamp}.{body}`
+/// where the timestamp is the value of the `x-rivet-timestamp` header.
+pub fn sign(secret: &str, ts: i64, body: &str) -> Result<String> {
+	let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+		.map_err(|_| anyhow!("invalid webhook secret"))?;
+	mac.update(format!("{ts}.").as_bytes());
+	mac.update(body.as_bytes());
+
+	Ok(hex::encode(mac.finalize().into_bytes()))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Hash)]
+struct DeliverInput {
+	namespace_id: Id,
+	notification_id: Id,
+	url: String,
+	body: String,
+}
+
+/// Makes a single delivery attempt. Returns the error of a failed attempt instead of failing so the
+/// workflow controls retries.
+///
+/// The secret is read on every attempt so it is never stored in workflow history.
+#[activity(Deliver)]
+async fn deliver(
+	ctx: &ActivityCtx,
+	input: &DeliverInput,
+) -> Result<std::result::Result<(), String>> {
+	let Some(config) = ctx
+		.op(crate::ops::webhook::get::Input {
+			namespace_id: input.namespace_id,
+		})
+		.await?
+	else {
+		tracing::debug!("webhook removed, dropping notification");
+		return Ok(Ok(()));
+	};
+
+	let ts = util::timestamp::now();
+	let signature = sign(&config.secret, ts, &input.body)?;
+
+	let client = rivet_pools::reqwest::client().await?;
+	let res = client
+		.post(&input.url)
+		.timeout(REQUEST_TIMEOUT)
+		.header(reqwest::header::CONTENT_TYPE, "application/json")
+		.header(NOTIFICATION_ID_HEADER, input.notification_id.to_string())
+		.header(TIMESTAMP_HEADER, ts.to_string())
+		.header(SIGNATURE_HEADER, signature)
+		.body(input.body.clone())
+		.send()
+		.await;
+
+	match res {
+		Ok(res) if res.status().is_success() => Ok(Ok(())),
+		Ok(res) => Ok(Err(format!(
+			"webhook responded with status {}",
+			res.status()
+		))),
+		Err(err) => Ok(Err(format!("webhook request failed: {err}"))),
+	}
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Hash)]
+struct InsertDeadLetterInput {
+	namespace_id: Id,
+	notification_id: Id,
+	url: String,
+	body: String,
+	attempts: usize,
+	error: String,
+}
+
+#[activity(InsertDeadLetter)]
+async fn insert_dead_letter(ctx: &ActivityCtx, input: &InsertDeadLetterInput) -> Result<()> {
+	let ts = util::timestamp::now();
+	let expire_ts = ts - ctx.config().pegboard().webhook_dead_letter_ttl();
+
+	ctx.udb()?
+		.txn("pegboard_webhook_insert_dead_letter", |tx| async move {
+			// Clear expired dead letters
+			let (begin, end) =
+				keys::webhook::dead_letter_range_before(input.namespace_id, expire_ts);
+			tx.clear_range(&begin, &end);
+
+			let tx = tx.with_subspace(keys::subspace());
+
+			tx.write(
+				&keys::webhook::WebhookDeadLetterKey::new(
+					input.namespace_id,
+					ts,
+					input.notification_id,
+				),
+				keys::webhook::DeadLetter {
+					notification_id: input.notification_id,
+					url: input.url.clone(),
+					body: input.body.clone(),
+					attempts: input.attempts,
+					error: input.error.clone(),
+					ts,
+				},
+			)?;
+
+			Ok(())
+		})
+		.custom_instrument(tracing::info_span!("webhook_insert_dead_letter_tx"))
+		.await
+}
@@ -26,7 +26,10 @@ async fn actor_v1_pre_migration() {
 			name: "test".to_string(),
 			key: None,
 			include_destroyed: true,
+			state: None,
 			created_before: None,
+			created_after: None,
+			cursor: None,
 			limit: 1,
 			fetch_error: false,
 		})
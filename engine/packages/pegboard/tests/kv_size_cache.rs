@@ -0,0 +1,86 @@
+use anyhow::Result;
+use gas::prelude::*;
+use pegboard::actor_kv as kv;
+
+#[tokio::test]
+async fn test_kv_size_cache() -> Result<()> {
+	// Setup test environment
+	tracing_subscriber::fmt()
+		.with_max_level(tracing::Level::INFO)
+		.with_target(false)
+		.init();
+
+	let test_id = Uuid::new_v4();
+	let dc_label = 1;
+	let datacenters = [(
+		"test-dc".to_string(),
+		rivet_config::config::topology::Datacenter {
+			name: "test-dc".to_string(),
+			datacenter_label: dc_label,
+			is_leader: true,
+			peer_url: url::Url::parse("http://127.0.0.1:8080")?,
+			public_url: url::Url::parse("http://127.0.0.1:8081")?,
+			proxy_url: None,
+			valid_hosts: None,
+		},
+	)]
+	.into_iter()
+	.collect();
+
+	let api_peer_port = portpicker::pick_unused_port().expect("failed to pick api peer port");
+	let guard_port = portpicker::pick_unused_port().expect("failed to pick guard port");
+
+	let test_deps = rivet_test_deps::setup_single_datacenter(
+		test_id,
+		dc_label,
+		datacenters,
+		api_peer_port,
+		guard_port,
+	)
+	.await?;
+
+	let db = &test_deps.pools.udb()?;
+	let actor_id = Id::new_v1(dc_label);
+	let recipient = kv::Recipient {
+		actor_id,
+		namespace_id: Id::new_v1(dc_label),
+		name: "default".to_string(),
+	};
+
+	let misses = kv::metrics::ACTOR_KV_SIZE_ESTIMATE_TOTAL.with_label_values(&["miss"]);
+	let hits = kv::metrics::ACTOR_KV_SIZE_ESTIMATE_TOTAL.with_label_values(&["hit"]);
+	let misses_before = misses.get();
+	let hits_before = hits.get();
+
+	// Many rapid small puts should only read the estimated size once
+	let puts = 20;
+	for i in 0..puts {
+		kv::put(
+			db,
+			&recipient,
+			vec![format!("key{i}").into_bytes()],
+			vec![b"value".to_vec()],
+		)
+		.await?;
+	}
+
+	let put_misses = misses.get() - misses_before;
+	assert_eq!(
+		put_misses, 1,
+		"only the first put should read the estimated size"
+	);
+	assert_eq!(hits.get() - hits_before, puts - 1);
+
+	// Deletes invalidate the cached estimate
+	kv::delete_all(db, &recipient).await?;
+	kv::put(
+		db,
+		&recipient,
+		vec![b"after_delete".to_vec()],
+		vec![b"value".to_vec()],
+	)
+	.await?;
+	assert_eq!(misses.get() - misses_before, put_misses + 1);
+
+	Ok(())
+}
@@ -0,0 +1,74 @@
+mod common;
+
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use gas::prelude::*;
+use pegboard::{keys, metrics};
+use rivet_types::keys::pegboard::ns::ServerlessDesiredSlotsKey;
+use universaldb::prelude::*;
+
+#[tokio::test]
+async fn metrics_aggregator_updates_pending_actors_when_serverless_aggregation_fails() -> Result<()>
+{
+	let test_deps = common::setup_deps().await?;
+	let namespace_id = Id::new_v1(test_deps.config().dc_label());
+	let actor_id = Id::new_v1(test_deps.config().dc_label());
+
+	test_deps
+		.pools
+		.udb()?
+		.txn("test_metrics_aggregator_partial_failure", |tx| async move {
+			let tx = tx.with_subspace(keys::subspace());
+
+			tx.write(
+				&keys::ns::PendingActorByRunnerNameSelectorKey::new(
+					namespace_id,
+					common::POOL_NAME.to_string(),
+					util::timestamp::now(),
+					actor_id,
+				),
+				0,
+			)?;
+
+			// Desired slots are an 8 byte counter, this fails to deserialize
+			tx.set(
+				&keys::subspace().pack(&ServerlessDesiredSlotsKey::new(
+					namespace_id,
+					common::POOL_NAME.to_string(),
+				)),
+				&[0xff],
+			);
+
+			Ok(())
+		})
+		.await?;
+
+	let test_ctx = TestCtx::new_with_deps(pegboard::registry()?, test_deps).await?;
+	test_ctx
+		.workflow(pegboard::workflows::metrics_aggregator::Input {})
+		.dispatch()
+		.await?;
+
+	let namespace_label = namespace_id.to_string();
+	let start = Instant::now();
+	while metrics::ACTOR_PENDING_ALLOCATION
+		.with_label_values(&[&namespace_label, common::POOL_NAME])
+		.get() != 1
+	{
+		assert!(
+			start.elapsed() < Duration::from_secs(10),
+			"pending actors should be aggregated even though serverless aggregation fails"
+		);
+		tokio::time::sleep(Duration::from_millis(50)).await;
+	}
+
+	assert_eq!(
+		metrics::SERVERLESS_DESIRED_SLOTS
+			.with_label_values(&[&namespace_label, common::POOL_NAME])
+			.get(),
+		0
+	);
+
+	Ok(())
+}
@@ -6,7 +6,7 @@ use std::time::Duration;
 
 use anyhow::Result;
 use gas::prelude::*;
-use rivet_types::runner_configs::{RunnerConfig, RunnerConfigKind};
+use rivet_types::runner_configs::{RunnerConfig, RunnerConfigKind, ServerlessRequestMethod};
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 
 struct MockMetadataState {
@@ -88,6 +88,9 @@ async fn refresh_metadata_purges_runner_config_protocol_cache() -> Result<()> {
 			actor_eviction_delay: 0,
 			actor_eviction_period: 0,
 			actor_eviction_rate: 1.0,
+			method: ServerlessRequestMethod::Get,
+			body: None,
+			auth: None,
 		},
 		metadata: None,
 	};
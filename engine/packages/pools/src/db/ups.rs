@@ -111,6 +111,7 @@ pub async fn setup(config: &Config, client_name: &str) -> Result<UpsPool> {
 			Arc::new(
 				ups::driver::postgres::PostgresDriver::connect(
 					pg.url.read().clone(),
+					pg.listen_connections,
 					ssl_root_cert_path,
 					ssl_client_cert_path,
 					ssl_client_key_path,
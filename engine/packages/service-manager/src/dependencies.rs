@@ -0,0 +1,163 @@
+use std::{collections::HashSet, sync::Arc};
+
+use anyhow::{Result, bail, ensure};
+use tokio::sync::watch;
+
+use crate::Service;
+
+/// Orders services so that every service comes after its dependencies. Services without
+/// dependencies between them keep their original order.
+///
+/// Dependencies on services that are not run by this process (e.g. when running a subset of
+/// services with `--services`) are assumed to be running elsewhere and are ignored.
+///
+/// Fails if dependencies form a cycle.
+pub(crate) fn order_services(mut services: Vec<Service>) -> Result<Vec<Service>> {
+	let names = services.iter().map(|x| x.name).collect::<HashSet<_>>();
+	ensure!(
+		names.len() == services.len(),
+		"service names must be unique"
+	);
+
+	for service in &mut services {
+		service.depends_on.retain(|dep| {
+			if names.contains(dep) {
+				true
+			} else {
+				tracing::debug!(service=%service.name, %dep, "ignoring dependency on service not run by this process");
+				false
+			}
+		});
+	}
+
+	let mut remaining = services;
+	let mut ordered = Vec::with_capacity(remaining.len());
+	let mut placed = HashSet::new();
+
+	while !remaining.is_empty() {
+		let (unblocked, blocked) = remaining
+			.into_iter()
+			.partition::<Vec<_>, _>(|x| x.depends_on.iter().all(|dep| placed.contains(dep)));
+
+		if unblocked.is_empty() {
+			let names = blocked.iter().map(|x| x.name).collect::<Vec<_>>();
+			bail!(
+				"service dependencies contain a cycle between: {}",
+				names.join(", ")
+			);
+		}
+
+		placed.extend(unblocked.iter().map(|x| x.name));
+		ordered.extend(unblocked);
+		remaining = blocked;
+	}
+
+	Ok(ordered)
+}
+
+#[derive(Default)]
+struct ReadinessState {
+	ready: HashSet<&'static str>,
+	cancelled: bool,
+}
+
+/// Tracks which services have reported ready so dependents can wait for them before starting.
+///
+/// A service stays ready once it has reported ready, even if it later crashes and restarts.
+#[derive(Clone)]
+pub(crate) struct Readiness {
+	tx: Arc<watch::Sender<ReadinessState>>,
+}
+
+impl Default for Readiness {
+	fn default() -> Self {
+		let (tx, _) = watch::channel(ReadinessState::default());
+
+		Readiness { tx: Arc::new(tx) }
+	}
+}
+
+impl Readiness {
+	pub(crate) fn set_ready(&self, name: &'static str) {
+		self.tx.send_modify(|state| {
+			state.ready.insert(name);
+		});
+	}
+
+	/// Stops waiting for dependencies. Used on shutdown so services that have not started yet exit.
+	pub(crate) fn cancel(&self) {
+		self.tx.send_modify(|state| {
+			state.cancelled = true;
+		});
+	}
+
+	pub(crate) fn is_cancelled(&self) -> bool {
+		self.tx.borrow().cancelled
+	}
+
+	pub(crate) fn is_ready(&self, deps: &[&'static str]) -> bool {
+		let state = self.tx.borrow();
+
+		deps.iter().all(|dep| state.ready.contains(dep))
+	}
+
+	/// Waits for every dependency to be ready. Returns false if waiting was cancelled.
+	pub(crate) async fn wait(&self, deps: &[&'static str]) -> bool {
+		let mut rx = self.tx.subscribe();
+
+		match rx
+			.wait_for(|state| state.cancelled || deps.iter().all(|dep| state.ready.contains(dep)))
+			.await
+		{
+			Ok(state) => !state.cancelled,
+			Err(_) => false,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::ServiceKind;
+
+	fn service(name: &'static str, deps: &[&'static str]) -> Service {
+		Service::new(
+			name,
+			ServiceKind::Standalone,
+			|_, _| async { anyhow::Ok(()) },
+			false,
+		)
+		.with_depends_on(deps.iter().copied())
+	}
+
+	#[test]
+	fn orders_dependencies_first() {
+		let ordered = order_services(vec![
+			service("worker", &["bootstrap", "api"]),
+			service("api", &[]),
+			service("bootstrap", &["api"]),
+			service("metrics", &[]),
+		])
+		.unwrap();
+
+		let names = ordered.iter().map(|x| x.name).collect::<Vec<_>>();
+		assert_eq!(names, vec!["api", "metrics", "bootstrap", "worker"]);
+	}
+
+	#[test]
+	fn rejects_cycles() {
+		let err = order_services(vec![
+			service("a", &["b"]),
+			service("b", &["a"]),
+			service("c", &[]),
+		])
+		.unwrap_err();
+		assert!(err.to_string().contains("cycle between: a, b"));
+	}
+
+	#[test]
+	fn ignores_services_not_in_process() {
+		let ordered = order_services(vec![service("a", &["missing"])]).unwrap();
+		assert!(ordered[0].depends_on.is_empty());
+	}
+}
@@ -0,0 +1,170 @@
+use std::{
+	collections::BTreeMap,
+	sync::{
+		Arc, Mutex,
+		atomic::{AtomicBool, Ordering},
+	},
+};
+
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ServiceHealthKind {
+	/// Must be running for the process to be ready.
+	Service,
+	/// Must have finished for the process to be ready.
+	Oneshot,
+	/// Does not affect readiness.
+	Cron,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ServiceState {
+	/// Spawned but not yet running.
+	Starting,
+	/// Running. For crons, waiting for the next scheduled run.
+	Running,
+	/// Crashed or exited and waiting to be restarted.
+	Restarting,
+	/// Oneshot completed successfully.
+	Finished,
+	/// Crashed and its restart policy does not allow restarting it. For crons, applies to the last
+	/// run.
+	Failed,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ServiceStatus {
+	pub kind: ServiceHealthKind,
+	pub state: ServiceState,
+	pub crash_count: u32,
+	pub last_error: Option<String>,
+	/// Milliseconds since epoch.
+	pub last_restart_ts: Option<i64>,
+}
+
+/// Tracks the status of every service run by the service manager.
+#[derive(Clone, Default)]
+pub struct ServiceHealth {
+	services: Arc<Mutex<BTreeMap<String, ServiceStatus>>>,
+	shutting_down: Arc<AtomicBool>,
+}
+
+impl ServiceHealth {
+	pub(crate) fn register(&self, name: &str, kind: ServiceHealthKind) {
+		self.services.lock().expect("poisoned").insert(
+			name.to_string(),
+			ServiceStatus {
+				kind,
+				state: ServiceState::Starting,
+				crash_count: 0,
+				last_error: None,
+				last_restart_ts: None,
+			},
+		);
+	}
+
+	pub(crate) fn set_state(&self, name: &str, state: ServiceState) {
+		self.update(name, |status| {
+			if status.state == ServiceState::Restarting && state == ServiceState::Running {
+				status.last_restart_ts = Some(chrono::Utc::now().timestamp_millis());
+			}
+
+			status.state = state;
+		});
+	}
+
+	/// Records a crash or unexpected exit. The service is marked as restarting.
+	pub(crate) fn record_crash(&self, name: &str, error: String) {
+		self.update(name, |status| {
+			status.state = ServiceState::Restarting;
+			status.crash_count = status.crash_count.saturating_add(1);
+			status.last_error = Some(error);
+		});
+	}
+
+	pub(crate) fn set_shutting_down(&self) {
+		self.shutting_down.store(true, Ordering::SeqCst);
+	}
+
+	fn update(&self, name: &str, f: impl FnOnce(&mut ServiceStatus)) {
+		if let Some(status) = self.services.lock().expect("poisoned").get_mut(name) {
+			f(status);
+		}
+	}
+
+	/// Returns a snapshot of every service's status.
+	pub fn statuses(&self) -> BTreeMap<String, ServiceStatus> {
+		self.services.lock().expect("poisoned").clone()
+	}
+
+	/// The process is ready when it is not shutting down, every service is running and every oneshot
+	/// has finished.
+	pub fn ready(&self) -> bool {
+		if self.shutting_down.load(Ordering::SeqCst) {
+			return false;
+		}
+
+		self.services
+			.lock()
+			.expect("poisoned")
+			.values()
+			.all(|status| match status.kind {
+				ServiceHealthKind::Service => status.state == ServiceState::Running,
+				ServiceHealthKind::Oneshot => status.state == ServiceState::Finished,
+				ServiceHealthKind::Cron => true,
+			})
+	}
+}
+
+impl rivet_metrics_server::HealthReporter for ServiceHealth {
+	fn ready(&self) -> bool {
+		ServiceHealth::ready(self)
+	}
+
+	fn report(&self) -> serde_json::Value {
+		serde_json::json!({
+			"ready": ServiceHealth::ready(self),
+			"shutting_down": self.shutting_down.load(Ordering::SeqCst),
+			"services": self.statuses(),
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn ready_once_services_are_up() {
+		let health = ServiceHealth::default();
+		health.register("api", ServiceHealthKind::Service);
+		health.register("bootstrap", ServiceHealthKind::Oneshot);
+		health.register("cron", ServiceHealthKind::Cron);
+		assert!(!health.ready());
+
+		health.set_state("api", ServiceState::Running);
+		health.set_state("bootstrap", ServiceState::Running);
+		assert!(!health.ready());
+
+		health.set_state("bootstrap", ServiceState::Finished);
+		health.record_crash("cron", "cron crashed".to_string());
+		assert!(health.ready());
+
+		health.record_crash("api", "api crashed".to_string());
+		assert!(!health.ready());
+
+		health.set_state("api", ServiceState::Running);
+		assert!(health.ready());
+
+		let api = &health.statuses()["api"];
+		assert_eq!(api.crash_count, 1);
+		assert_eq!(api.last_error.as_deref(), Some("api crashed"));
+		assert!(api.last_restart_ts.is_some());
+
+		health.set_shutting_down();
+		assert!(!health.ready());
+	}
+}
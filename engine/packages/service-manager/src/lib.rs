@@ -5,26 +5,51 @@ use std::{
 		Arc,
 		atomic::{AtomicBool, Ordering},
 	},
-	time::Duration,
+	time::{Duration, Instant},
 };
 
 use anyhow::{Context, Result, ensure};
 use futures_util::{StreamExt, stream::FuturesUnordered};
 use tokio::task::JoinHandle;
 
+mod dependencies;
+mod health;
+mod metrics;
+mod restart;
+
+use dependencies::Readiness;
+pub use health::{ServiceHealth, ServiceHealthKind, ServiceState, ServiceStatus};
+use restart::RestartTracker;
+pub use restart::{RestartMode, RestartPolicy};
+
+/// How often a service's ready check is polled until it succeeds.
+const READY_CHECK_INTERVAL: Duration = Duration::from_millis(250);
+
+pub type ServiceFn = Arc<
+	dyn Fn(
+			rivet_config::Config,
+			rivet_pools::Pools,
+		) -> Pin<Box<dyn Future<Output = Result<()>> + Send>>
+		+ Send
+		+ Sync,
+>;
+
 #[derive(Clone)]
 pub struct Service {
 	pub name: &'static str,
 	pub kind: ServiceKind,
-	pub run: Arc<
-		dyn Fn(
-				rivet_config::Config,
-				rivet_pools::Pools,
-			) -> Pin<Box<dyn Future<Output = Result<()>> + Send>>
-			+ Send
-			+ Sync,
-	>,
+	pub run: ServiceFn,
 	pub requires_graceful_shutdown: bool,
+	/// Services that must be ready before this service starts.
+	pub depends_on: Vec<&'static str>,
+	/// Polled once the service has started until it succeeds, at which point the service is ready.
+	///
+	/// Without a ready check, a service is ready as soon as it starts. Oneshots are ready once they
+	/// finish.
+	pub ready_check: Option<ServiceFn>,
+	/// Defaults to always restarting services, restarting oneshots until they succeed and retrying
+	/// crashed cron runs up to 7 times. Only crashed runs are retried for crons.
+	pub restart_policy: Option<RestartPolicy>,
 }
 
 impl Service {
@@ -43,7 +68,128 @@ impl Service {
 			kind,
 			run: Arc::new(move |config, pools| Box::pin(run(config, pools))),
 			requires_graceful_shutdown,
+			depends_on: Vec::new(),
+			ready_check: None,
+			restart_policy: None,
+		}
+	}
+
+	pub fn with_restart_policy(mut self, restart_policy: RestartPolicy) -> Self {
+		self.restart_policy = Some(restart_policy);
+		self
+	}
+
+	fn restart_tracker(&self) -> RestartTracker {
+		let restart_policy =
+			self.restart_policy
+				.clone()
+				.unwrap_or_else(|| match self.kind.behavior() {
+					ServiceBehavior::Service => RestartPolicy::always(),
+					ServiceBehavior::Oneshot => RestartPolicy::on_failure(),
+					ServiceBehavior::Cron(_) => RestartPolicy::on_failure().with_max_retries(7),
+				});
+
+		RestartTracker::new(restart_policy)
+	}
+
+	/// Waits for the restart backoff after this service exits. Returns false if the service's
+	/// restart policy does not allow restarting it.
+	async fn restart_backoff(
+		&self,
+		restart: &mut RestartTracker,
+		health: &ServiceHealth,
+		failed: bool,
+		ran_for: Duration,
+	) -> bool {
+		let Some(backoff) = restart.next_backoff(failed, ran_for) else {
+			if failed {
+				tracing::error!(service=%self.name, retries=%restart.retries(), "service failed, not restarting");
+				metrics::SERVICE_GAVE_UP_TOTAL
+					.with_label_values(&[self.name])
+					.inc();
+				health.set_state(self.name, ServiceState::Failed);
+			} else {
+				tracing::info!(service=%self.name, "service exited, not restarting");
+				health.set_state(self.name, ServiceState::Finished);
+			}
+
+			return false;
+		};
+
+		metrics::SERVICE_RESTART_TOTAL
+			.with_label_values(&[self.name])
+			.inc();
+		tokio::time::sleep(backoff).await;
+
+		true
+	}
+
+	pub fn with_depends_on(mut self, depends_on: impl IntoIterator<Item = &'static str>) -> Self {
+		self.depends_on.extend(depends_on);
+		self
+	}
+
+	pub fn with_ready_check<F, Fut>(mut self, ready_check: F) -> Self
+	where
+		F: Fn(rivet_config::Config, rivet_pools::Pools) -> Fut + Send + Sync + 'static,
+		Fut: Future<Output = Result<()>> + Send + 'static,
+	{
+		self.ready_check = Some(Arc::new(move |config, pools| {
+			Box::pin(ready_check(config, pools))
+		}));
+		self
+	}
+
+	/// Waits for this service's dependencies to be ready. Returns false if shutting down before
+	/// they are.
+	async fn wait_for_dependencies(&self, readiness: &Readiness) -> bool {
+		if self.depends_on.is_empty() {
+			return true;
 		}
+
+		tracing::debug!(service=%self.name, depends_on=?self.depends_on, "waiting for dependencies");
+
+		if readiness.wait(&self.depends_on).await {
+			tracing::debug!(service=%self.name, "dependencies ready");
+			true
+		} else {
+			false
+		}
+	}
+
+	/// Marks this service as ready once its ready check passes.
+	fn report_ready(
+		&self,
+		config: rivet_config::Config,
+		pools: rivet_pools::Pools,
+		readiness: Readiness,
+	) {
+		let name = self.name;
+		let Some(ready_check) = self.ready_check.clone() else {
+			readiness.set_ready(name);
+			return;
+		};
+
+		tokio::spawn(async move {
+			loop {
+				match ready_check(config.clone(), pools.clone()).await {
+					Result::Ok(()) => {
+						tracing::debug!(service=%name, "service ready");
+						readiness.set_ready(name);
+						break;
+					}
+					Err(err) => {
+						tracing::debug!(service=%name, ?err, "service not ready");
+					}
+				}
+
+				if readiness.is_cancelled() {
+					break;
+				}
+
+				tokio::time::sleep(READY_CHECK_INTERVAL).await;
+			}
+		});
 	}
 }
 
@@ -144,14 +290,22 @@ pub async fn start(
 	pools: rivet_pools::Pools,
 	mut services: Vec<Service>,
 ) -> Result<()> {
+	let health = ServiceHealth::default();
+
+	let metrics_health = health.clone();
 	services.push(Service::new(
 		"metrics",
 		ServiceKind::Core,
-		|config, _pools| rivet_metrics_server::run_standalone(config),
+		move |config, _pools| {
+			rivet_metrics_server::run_standalone(config, Arc::new(metrics_health.clone()))
+		},
 		false,
 	));
 
-	// Spawn services
+	let services = dependencies::order_services(services)?;
+	let readiness = Readiness::default();
+
+	// Spawn services in dependency order
 	tracing::info!(services=?services.len(), "starting services");
 	let mut running_services = Vec::new();
 	let cron_schedule = tokio_cron_scheduler::JobScheduler::new().await?;
@@ -167,33 +321,60 @@ pub async fn start(
 				let config = config.clone();
 				let pools = pools.clone();
 				let shutting_down = shutting_down.clone();
+				let health = health.clone();
+				let readiness = readiness.clone();
 				let task_name = format!("rivet::service::{}", service.name);
+				health.register(service.name, ServiceHealthKind::Service);
 
 				let join_handle = tokio::task::Builder::new()
 					.name(&task_name)
 					.spawn(async move {
+						if !service.wait_for_dependencies(&readiness).await {
+							return;
+						}
+
 						tracing::debug!(service=%service.name, "starting service");
+						service.report_ready(config.clone(), pools.clone(), readiness);
+
+						let mut restart = service.restart_tracker();
 
 						loop {
-							match (service.run)(config.clone(), pools.clone()).await {
+							health.set_state(service.name, ServiceState::Running);
+							let start = Instant::now();
+
+							let failed = match (service.run)(config.clone(), pools.clone()).await {
 								Result::Ok(_) => {
 									if shutting_down.load(Ordering::SeqCst) {
 										tracing::info!(service=%service.name, "service exited");
 										break;
 									} else {
 										tracing::error!(service=%service.name, "service exited unexpectedly");
+										health.record_crash(
+											service.name,
+											"service exited unexpectedly".to_string(),
+										);
 									}
+
+									false
 								}
 								Err(err) => {
 									tracing::error!(service=%service.name, ?err, "service crashed");
+									health.record_crash(service.name, format!("{err:#}"));
 
 									if shutting_down.load(Ordering::SeqCst) {
 										break;
 									}
+
+									true
 								}
-							}
+							};
 
-							tokio::time::sleep(Duration::from_secs(1)).await;
+							if !service
+								.restart_backoff(&mut restart, &health, failed, start.elapsed())
+								.await
+							{
+								break;
+							}
 
 							tracing::info!(service=%service.name, "restarting service");
 						}
@@ -210,27 +391,49 @@ pub async fn start(
 				let config = config.clone();
 				let pools = pools.clone();
 				let shutting_down = shutting_down.clone();
+				let health = health.clone();
+				let readiness = readiness.clone();
 				let task_name = format!("rivet::oneoff::{}", service.name);
+				health.register(service.name, ServiceHealthKind::Oneshot);
 
 				let join_handle = tokio::task::Builder::new()
 					.name(&task_name)
 					.spawn(async move {
+						if !service.wait_for_dependencies(&readiness).await {
+							return;
+						}
+
 						tracing::debug!(oneoff=%service.name, "starting oneoff");
 
+						let mut restart = service.restart_tracker();
+
 						loop {
+							health.set_state(service.name, ServiceState::Running);
+							let start = Instant::now();
+
 							match (service.run)(config.clone(), pools.clone()).await {
 								Result::Ok(_) => {
 									tracing::debug!(oneoff=%service.name, "oneoff finished");
+									health.set_state(service.name, ServiceState::Finished);
+									readiness.set_ready(service.name);
 									break;
 								}
 								Err(err) => {
 									tracing::error!(oneoff=%service.name, ?err, "oneoff crashed");
-
-									if shutting_down.load(Ordering::SeqCst) {
+									health.record_crash(service.name, format!("{err:#}"));
+
+									if shutting_down.load(Ordering::SeqCst)
+										|| !service
+											.restart_backoff(
+												&mut restart,
+												&health,
+												true,
+												start.elapsed(),
+											)
+											.await
+									{
 										break;
 									} else {
-										tokio::time::sleep(Duration::from_secs(1)).await;
-
 										tracing::info!(oneoff=%service.name, "restarting oneoff");
 									}
 								}
@@ -246,40 +449,60 @@ pub async fn start(
 				});
 			}
 			ServiceBehavior::Cron(cron_config) => {
+				health.register(service.name, ServiceHealthKind::Cron);
+				readiness.set_ready(service.name);
+
 				// Spawn immediate task
 				if cron_config.run_immediately {
 					let service = service.clone();
 					let config = config.clone();
 					let pools = pools.clone();
 					let shutting_down = shutting_down.clone();
+					let health = health.clone();
+					let readiness = readiness.clone();
 					let task_name = format!("rivet::cron_immediate::{}", service.name);
 
 					let join_handle = tokio::task::Builder::new()
 						.name(&task_name)
 						.spawn(async move {
+							if !service.wait_for_dependencies(&readiness).await {
+								return;
+							}
+
 							tracing::debug!(cron=%service.name, "starting immediate cron");
 
-							for attempt in 1..=8 {
+							let mut restart = service.restart_tracker();
+
+							for attempt in 1.. {
+								health.set_state(service.name, ServiceState::Running);
+								let start = Instant::now();
+
 								match (service.run)(config.clone(), pools.clone()).await {
 									Result::Ok(_) => {
 										tracing::debug!(cron=%service.name, ?attempt, "cron finished");
-										break;
+										return;
 									}
 									Err(err) => {
 										tracing::error!(cron=%service.name, ?attempt, ?err, "cron crashed");
-
-										if shutting_down.load(Ordering::SeqCst) {
+										health.record_crash(service.name, format!("{err:#}"));
+
+										if shutting_down.load(Ordering::SeqCst)
+											|| !service
+												.restart_backoff(
+													&mut restart,
+													&health,
+													true,
+													start.elapsed(),
+												)
+												.await
+										{
 											return;
 										} else {
-											tokio::time::sleep(Duration::from_secs(1)).await;
-
 											tracing::info!(cron=%service.name, ?attempt, "restarting cron");
 										}
 									}
 								}
 							}
-
-							tracing::error!(cron=%service.name, "cron failed all restart attempts");
 						})
 						.context("failed to spawn cron")?;
 
@@ -295,6 +518,8 @@ pub async fn start(
 				let pools = pools.clone();
 				let service2 = service.clone();
 				let shutting_down = shutting_down.clone();
+				let cron_health = health.clone();
+				let cron_readiness = readiness.clone();
 				let task_name = format!("rivet::cron_dummy::{}", service.name);
 
 				cron_schedule
@@ -306,10 +531,22 @@ pub async fn start(
 							let pools = pools.clone();
 							let service = service2.clone();
 							let shutting_down = shutting_down.clone();
+							let health = cron_health.clone();
+							let readiness = cron_readiness.clone();
 							Box::pin(async move {
+								if !readiness.is_ready(&service.depends_on) {
+									tracing::debug!(cron=%service.name, depends_on=?service.depends_on, "dependencies not ready, skipping cron");
+									return;
+								}
+
 								tracing::debug!(cron=%service.name, ?notification, "running cron");
 
-								for attempt in 1..=8 {
+								let mut restart = service.restart_tracker();
+
+								for attempt in 1.. {
+									health.set_state(service.name, ServiceState::Running);
+									let start = Instant::now();
+
 									match (service.run)(config.clone(), pools.clone()).await {
 										Result::Ok(_) => {
 											tracing::debug!(cron=%service.name, ?attempt, "cron finished");
@@ -317,19 +554,25 @@ pub async fn start(
 										}
 										Err(err) => {
 											tracing::error!(cron=%service.name, ?attempt, ?err, "cron crashed");
-
-											if shutting_down.load(Ordering::SeqCst) {
+											health.record_crash(service.name, format!("{err:#}"));
+
+											if shutting_down.load(Ordering::SeqCst)
+												|| !service
+													.restart_backoff(
+														&mut restart,
+														&health,
+														true,
+														start.elapsed(),
+													)
+													.await
+											{
 												return;
 											} else {
-												tokio::time::sleep(Duration::from_secs(1)).await;
-
 												tracing::info!(cron=%service.name, ?attempt, "restarting cron");
 											}
 										}
 									}
 								}
-
-								tracing::error!(cron=%service.name, "cron failed all restart attempts");
 							})
 						},
 					)?)
@@ -352,6 +595,9 @@ pub async fn start(
 
 	cron_schedule.start().await?;
 
+	// Set once shutting down. Services that have not stopped by then are aborted.
+	let mut shutdown_deadline = None;
+
 	loop {
 		// Waits for all service tasks to complete
 		let join_fut = async {
@@ -363,11 +609,29 @@ pub async fn start(
 			while let Some(_) = handle_futs.next().await {}
 		};
 
+		let deadline_fut = async {
+			if let Some(deadline) = shutdown_deadline {
+				tokio::time::sleep_until(deadline).await;
+			} else {
+				std::future::pending().await
+			}
+		};
+
 		tokio::select! {
 			_ = join_fut => {
 				tracing::info!("all services finished");
 				break;
 			}
+			_ = deadline_fut => {
+				shutdown_deadline = None;
+
+				for task in &running_services {
+					if !task.handle.is_finished() {
+						tracing::warn!(name=%task.name, "service did not shut down before the deadline, aborting");
+						task.handle.abort();
+					}
+				}
+			}
 			abort = term_signal.recv() => {
 				if !shutting_down.load(Ordering::SeqCst) {
 					// Spawn force exit task in case of a lingering task
@@ -378,9 +642,15 @@ pub async fn start(
 						tracing::warn!("force shutdown timeout reached, exiting process, this indicates a bug");
 						std::process::exit(1);
 					});
+
+					let service_shutdown_duration = config.runtime.service_shutdown_duration();
+					tracing::info!(?service_shutdown_duration, "service shutdown deadline started");
+					shutdown_deadline = Some(tokio::time::Instant::now() + service_shutdown_duration);
 				}
 
 				shutting_down.store(true, Ordering::SeqCst);
+				health.set_shutting_down();
+				readiness.cancel();
 
 				// Abort services that don't require graceful shutdown
 				running_services.retain(|task| {
@@ -0,0 +1,16 @@
+use rivet_metrics::{REGISTRY, prometheus::*};
+
+lazy_static::lazy_static! {
+	pub static ref SERVICE_RESTART_TOTAL: IntCounterVec = register_int_counter_vec_with_registry!(
+		"service_restart_total",
+		"Total number of service restarts after crashing or exiting.",
+		&["service"],
+		*REGISTRY
+	).unwrap();
+	pub static ref SERVICE_GAVE_UP_TOTAL: IntCounterVec = register_int_counter_vec_with_registry!(
+		"service_gave_up_total",
+		"Total number of times a service was not restarted because of its restart policy.",
+		&["service"],
+		*REGISTRY
+	).unwrap();
+}
@@ -0,0 +1,166 @@
+use std::time::Duration;
+
+/// When a service is restarted after it exits.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RestartMode {
+	/// Restart after crashing or exiting.
+	Always,
+	/// Restart only after crashing.
+	OnFailure,
+	/// Never restart.
+	Never,
+}
+
+/// Configures how a service is restarted after it exits.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RestartPolicy {
+	pub mode: RestartMode,
+	/// Consecutive restarts before giving up. Retries forever if not set.
+	pub max_retries: Option<u32>,
+	/// Backoff before the first restart. Doubles with every consecutive restart.
+	pub min_backoff: Duration,
+	pub max_backoff: Duration,
+	/// If the service runs for at least this long before exiting, it is no longer considered to be
+	/// crash looping and its retry count and backoff are reset.
+	pub reset_window: Duration,
+}
+
+impl RestartPolicy {
+	pub fn always() -> Self {
+		RestartPolicy {
+			mode: RestartMode::Always,
+			..Default::default()
+		}
+	}
+
+	pub fn on_failure() -> Self {
+		RestartPolicy {
+			mode: RestartMode::OnFailure,
+			..Default::default()
+		}
+	}
+
+	pub fn never() -> Self {
+		RestartPolicy {
+			mode: RestartMode::Never,
+			..Default::default()
+		}
+	}
+
+	pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+		self.max_retries = Some(max_retries);
+		self
+	}
+
+	pub fn with_backoff(mut self, min_backoff: Duration, max_backoff: Duration) -> Self {
+		self.min_backoff = min_backoff;
+		self.max_backoff = max_backoff;
+		self
+	}
+
+	pub fn with_reset_window(mut self, reset_window: Duration) -> Self {
+		self.reset_window = reset_window;
+		self
+	}
+}
+
+impl Default for RestartPolicy {
+	fn default() -> Self {
+		RestartPolicy {
+			mode: RestartMode::Always,
+			max_retries: None,
+			min_backoff: Duration::from_secs(1),
+			max_backoff: Duration::from_secs(30),
+			reset_window: Duration::from_secs(60),
+		}
+	}
+}
+
+/// Tracks consecutive restarts of a service for its restart policy.
+pub(crate) struct RestartTracker {
+	policy: RestartPolicy,
+	retries: u32,
+}
+
+impl RestartTracker {
+	pub(crate) fn new(policy: RestartPolicy) -> Self {
+		RestartTracker { policy, retries: 0 }
+	}
+
+	/// Returns the backoff to wait before restarting the service, or `None` if it should not be
+	/// restarted.
+	pub(crate) fn next_backoff(&mut self, failed: bool, ran_for: Duration) -> Option<Duration> {
+		if ran_for >= self.policy.reset_window {
+			self.retries = 0;
+		}
+
+		match self.policy.mode {
+			RestartMode::Always => {}
+			RestartMode::OnFailure if failed => {}
+			RestartMode::OnFailure | RestartMode::Never => return None,
+		}
+
+		if let Some(max_retries) = self.policy.max_retries {
+			if self.retries >= max_retries {
+				return None;
+			}
+		}
+
+		let backoff = self
+			.policy
+			.min_backoff
+			.saturating_mul(2u32.saturating_pow(self.retries))
+			.min(self.policy.max_backoff);
+		self.retries += 1;
+
+		Some(backoff)
+	}
+
+	pub(crate) fn retries(&self) -> u32 {
+		self.retries
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn backs_off_exponentially_and_resets() {
+		let mut tracker = RestartTracker::new(
+			RestartPolicy::always()
+				.with_max_retries(3)
+				.with_backoff(Duration::from_secs(1), Duration::from_secs(3)),
+		);
+
+		assert_eq!(
+			tracker.next_backoff(true, Duration::ZERO),
+			Some(Duration::from_secs(1))
+		);
+		assert_eq!(
+			tracker.next_backoff(false, Duration::ZERO),
+			Some(Duration::from_secs(2))
+		);
+		assert_eq!(
+			tracker.next_backoff(true, Duration::ZERO),
+			Some(Duration::from_secs(3))
+		);
+		assert_eq!(tracker.next_backoff(true, Duration::ZERO), None);
+
+		// Running past the reset window clears the retry count
+		assert_eq!(
+			tracker.next_backoff(true, Duration::from_secs(60)),
+			Some(Duration::from_secs(1))
+		);
+	}
+
+	#[test]
+	fn respects_mode() {
+		let mut on_failure = RestartTracker::new(RestartPolicy::on_failure());
+		assert_eq!(on_failure.next_backoff(false, Duration::ZERO), None);
+		assert!(on_failure.next_backoff(true, Duration::ZERO).is_some());
+
+		let mut never = RestartTracker::new(RestartPolicy::never());
+		assert_eq!(never.next_backoff(true, Duration::ZERO), None);
+	}
+}
@@ -100,6 +100,11 @@ pub async fn setup_single_datacenter(
 		actor_start_threshold: Some(3_000), // 3 seconds instead of 30
 		serverless_base_retry_timeout: Some(500), // 500ms instead of 2s
 		serverless_backoff_max_exponent: Some(2), // Max 2^2 = 4x base = 2s
+		serverless_init_timeout: Some(5_000), // 5s instead of 30s
+		hibernating_request_eligible_threshold_overrides: Some(HashMap::from([(
+			"short-hibernation-actor".to_string(),
+			1_000,
+		)])),
 		..Default::default()
 	});
 
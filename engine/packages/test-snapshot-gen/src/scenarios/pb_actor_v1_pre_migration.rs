@@ -1,4 +1,4 @@
-use std::time::Duration;
+use std::{collections::HashMap, time::Duration};
 
 use anyhow::{Context, Result};
 use async_trait::async_trait;
@@ -42,7 +42,10 @@ impl Scenario for PbActorV1PreMigration {
 			key: None,
 			runner_name_selector: "default".to_string(),
 			input: None,
-			crash_policy: CrashPolicy::Sleep,
+			crash_policy: Some(CrashPolicy::Sleep),
+			affinity: None,
+			required_capabilities: Vec::new(),
+			tags: HashMap::new(),
 			forward_request: false,
 			datacenter_name: None,
 		})
@@ -15,6 +15,9 @@ pub enum RunnerPoolError {
 	/// Serverless: SSE connection or network error
 	ServerlessConnectionError { message: String },
 
+	/// Serverless: SSE stream opened but the runner did not send its init message in time
+	ServerlessInitTimeout { timeout_ms: u64 },
+
 	/// Serverless: Runner sent invalid payload
 	ServerlessInvalidSsePayload {
 		message: String,
@@ -28,6 +31,105 @@ pub enum RunnerPoolError {
 	InternalError,
 }
 
+impl RunnerPoolError {
+	/// Broad reason the pool is failing, used to group errors for operators.
+	pub fn category(&self) -> RunnerPoolErrorCategory {
+		match self {
+			RunnerPoolError::ServerlessHttpError { status_code, .. } => {
+				RunnerPoolErrorCategory::from_status_code(*status_code)
+			}
+			RunnerPoolError::ServerlessStreamEndedEarly => RunnerPoolErrorCategory::Init,
+			RunnerPoolError::ServerlessConnectionError { .. } => {
+				RunnerPoolErrorCategory::Connection
+			}
+			RunnerPoolError::ServerlessInitTimeout { .. } => RunnerPoolErrorCategory::Init,
+			RunnerPoolError::ServerlessInvalidSsePayload { .. } => {
+				RunnerPoolErrorCategory::InvalidPayload
+			}
+			RunnerPoolError::Downgrade => RunnerPoolErrorCategory::Init,
+			RunnerPoolError::InternalError => RunnerPoolErrorCategory::Internal,
+		}
+	}
+}
+
+impl std::fmt::Display for RunnerPoolError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			RunnerPoolError::ServerlessHttpError { status_code, body } => {
+				write!(f, "serverless endpoint returned status {status_code}")?;
+				if !body.is_empty() {
+					write!(f, ": {body}")?;
+				}
+				Ok(())
+			}
+			RunnerPoolError::ServerlessStreamEndedEarly => {
+				write!(f, "serverless stream ended before the runner initialized")
+			}
+			RunnerPoolError::ServerlessConnectionError { message } => {
+				write!(f, "failed to connect to serverless endpoint: {message}")
+			}
+			RunnerPoolError::ServerlessInitTimeout { timeout_ms } => write!(
+				f,
+				"serverless runner did not send init message within {timeout_ms}ms"
+			),
+			RunnerPoolError::ServerlessInvalidSsePayload { message, .. } => {
+				write!(f, "serverless runner sent an invalid payload: {message}")
+			}
+			RunnerPoolError::Downgrade => write!(
+				f,
+				"RivetKit was downgraded to a version that does not support envoys"
+			),
+			RunnerPoolError::InternalError => write!(f, "internal error"),
+		}
+	}
+}
+
+/// Category of a `RunnerPoolError` or serverless health check failure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RunnerPoolErrorCategory {
+	/// Endpoint rejected the request's credentials (401, 403)
+	Auth,
+	/// Request or endpoint timed out (408, 504)
+	Timeout,
+	/// Endpoint returned a 5xx status code
+	ServerError,
+	/// Endpoint returned any other non-success status code, or the request itself was invalid
+	ClientError,
+	/// Endpoint could not be reached
+	Connection,
+	/// Runner did not finish initializing
+	Init,
+	/// Endpoint responded with a payload that could not be parsed
+	InvalidPayload,
+	/// Internal error
+	Internal,
+}
+
+impl RunnerPoolErrorCategory {
+	pub fn from_status_code(status_code: u16) -> Self {
+		match status_code {
+			401 | 403 => RunnerPoolErrorCategory::Auth,
+			408 | 504 => RunnerPoolErrorCategory::Timeout,
+			500..=599 => RunnerPoolErrorCategory::ServerError,
+			_ => RunnerPoolErrorCategory::ClientError,
+		}
+	}
+
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			RunnerPoolErrorCategory::Auth => "auth",
+			RunnerPoolErrorCategory::Timeout => "timeout",
+			RunnerPoolErrorCategory::ServerError => "server_error",
+			RunnerPoolErrorCategory::ClientError => "client_error",
+			RunnerPoolErrorCategory::Connection => "connection",
+			RunnerPoolErrorCategory::Init => "init",
+			RunnerPoolErrorCategory::InvalidPayload => "invalid_payload",
+			RunnerPoolErrorCategory::Internal => "internal",
+		}
+	}
+}
+
 /// Keep in sync with `website/src/content/docs/actors/troubleshooting.mdx` and
 /// `frontend/src/components/actors/actor-status-label.tsx`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
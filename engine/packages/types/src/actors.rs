@@ -21,6 +21,9 @@ pub struct Actor {
 	pub start_ts: Option<i64>,
 	/// Denotes when the actor started waiting for an allocation.
 	pub pending_allocation_ts: Option<i64>,
+	/// Explains why the actor is waiting for an allocation. Null if the actor is not pending.
+	#[serde(default)]
+	pub pending_reason: Option<PendingReason>,
 	/// Denotes when the actor was last connectable. Null if actor is not running.
 	pub connectable_ts: Option<i64>,
 	/// Denotes when the actor entered a sleeping state.
@@ -37,6 +40,53 @@ pub struct Actor {
 	pub error: Option<crate::actor::ActorError>,
 }
 
+impl Actor {
+	pub fn state(&self) -> ActorState {
+		ActorState::from_timestamps(
+			self.destroy_ts,
+			self.sleep_ts,
+			self.connectable_ts,
+			self.pending_allocation_ts,
+		)
+	}
+}
+
+/// Lifecycle state of an actor, derived from its timestamps.
+#[derive(
+	Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, ToSchema, strum::FromRepr,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum ActorState {
+	/// Waiting for a runner with capacity to be allocated to.
+	PendingAllocation = 0,
+	/// Not connectable yet, including while waiting to be rescheduled after a crash.
+	Starting = 1,
+	Running = 2,
+	Sleeping = 3,
+	Destroyed = 4,
+}
+
+impl ActorState {
+	pub fn from_timestamps(
+		destroy_ts: Option<i64>,
+		sleep_ts: Option<i64>,
+		connectable_ts: Option<i64>,
+		pending_ts: Option<i64>,
+	) -> Self {
+		if destroy_ts.is_some() {
+			ActorState::Destroyed
+		} else if sleep_ts.is_some() {
+			ActorState::Sleeping
+		} else if connectable_ts.is_some() {
+			ActorState::Running
+		} else if pending_ts.is_some() {
+			ActorState::PendingAllocation
+		} else {
+			ActorState::Starting
+		}
+	}
+}
+
 #[derive(Debug, Copy, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum CrashPolicy {
@@ -46,6 +96,66 @@ pub enum CrashPolicy {
 	Destroy,
 }
 
+/// Reason an actor could not be allocated to a runner and is waiting in the allocation queue.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PendingReason {
+	/// No runner config or connected runner matches the actor's runner name selector.
+	NoRunnerMatchesSelector,
+	/// Every connected runner matching the selector is draining.
+	AllRunnersDraining,
+	/// Matching runners exist but none have free slots, or other actors are queued ahead.
+	NoCapacity,
+}
+
+impl std::fmt::Display for PendingReason {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			PendingReason::NoRunnerMatchesSelector => write!(f, "no runner matches selector"),
+			PendingReason::AllRunnersDraining => write!(f, "all matching runners are draining"),
+			PendingReason::NoCapacity => write!(f, "no runner capacity available"),
+		}
+	}
+}
+
+/// Soft placement rules for an actor relative to other actors. The allocator honors them when a
+/// matching runner has capacity and ignores them otherwise.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub struct ActorAffinity {
+	/// Prefer runners that are already running any of these actors.
+	#[serde(default)]
+	pub colocate_with: Vec<Id>,
+	/// Avoid runners that are already running any of these actors.
+	#[serde(default)]
+	pub avoid: Vec<Id>,
+}
+
+impl ActorAffinity {
+	pub fn is_empty(&self) -> bool {
+		self.colocate_with.is_empty() && self.avoid.is_empty()
+	}
+}
+
+/// Line of output forwarded by the runner hosting an actor.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ActorLog {
+	/// Position of the line in the actor's log. Lines are returned in ascending order.
+	pub idx: u64,
+	/// Denotes when the line was written by the actor.
+	pub ts: i64,
+	/// Generation of the actor that wrote the line.
+	pub generation: u32,
+	pub stream: ActorLogStream,
+	pub message: String,
+}
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ActorLogStream {
+	Stdout,
+	Stderr,
+}
+
 #[derive(Debug, Deserialize, Serialize, Hash, ToSchema)]
 pub struct ActorName {
 	pub metadata: serde_json::Map<String, serde_json::Value>,
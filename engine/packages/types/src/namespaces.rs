@@ -7,4 +7,44 @@ pub struct Namespace {
 	pub name: String,
 	pub display_name: String,
 	pub create_ts: i64,
+	/// Crash policy used for actors created in this namespace without an explicit crash policy.
+	#[serde(default)]
+	pub default_crash_policy: Option<crate::actors::CrashPolicy>,
+	/// CORS policy the gateway applies to requests for actors in this namespace. All origins,
+	/// methods and headers are allowed if not set.
+	#[serde(default)]
+	pub cors_policy: Option<CorsPolicy>,
+	/// Rate limit the gateway applies to requests for each actor in this namespace, tracked per
+	/// client IP. Requests are not rate limited per actor if not set.
+	#[serde(default)]
+	pub actor_rate_limit: Option<ActorRateLimit>,
+}
+
+/// CORS policy for requests to actors through the gateway. Unset fields keep the permissive
+/// default.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash, ToSchema)]
+pub struct CorsPolicy {
+	/// Origins allowed to make requests, e.g. `https://example.com`. Any origin is allowed if not
+	/// set.
+	#[serde(default)]
+	pub allowed_origins: Option<Vec<String>>,
+	/// Methods allowed by preflight requests. Defaults to `GET, POST, PUT, DELETE, OPTIONS, PATCH`.
+	#[serde(default)]
+	pub allowed_methods: Option<Vec<String>>,
+	/// Headers allowed by preflight requests. Any requested header is allowed if not set.
+	#[serde(default)]
+	pub allowed_headers: Option<Vec<String>>,
+	/// How long browsers may cache a preflight response in seconds. Defaults to 86400.
+	#[serde(default)]
+	pub max_age: Option<u32>,
+}
+
+/// Token bucket rate limit for requests to a single actor from a single client IP.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, ToSchema)]
+pub struct ActorRateLimit {
+	/// Sustained number of requests allowed per second.
+	pub requests_per_second: u32,
+	/// Maximum number of requests allowed in a burst. Defaults to `requests_per_second`.
+	#[serde(default)]
+	pub burst: Option<u32>,
 }
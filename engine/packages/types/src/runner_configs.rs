@@ -111,9 +111,60 @@ pub enum RunnerConfigKind {
 		/// Actors per second.
 		#[serde(default = "default_actor_eviction_rate")]
 		actor_eviction_rate: f32,
+		/// HTTP method used for the `/start` request. Envoy pools always POST the start command.
+		#[serde(default)]
+		method: ServerlessRequestMethod,
+		/// Body sent with the `/start` request. `{{endpoint}}`, `{{namespace_name}}`,
+		/// `{{runner_name}}` and `{{total_slots}}` are replaced before sending. Not supported by
+		/// envoy pools, which send the start command as the body.
+		#[serde(default, skip_serializing_if = "Option::is_none")]
+		body: Option<String>,
+		#[serde(default, skip_serializing_if = "Option::is_none")]
+		auth: Option<ServerlessAuth>,
 	},
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ServerlessRequestMethod {
+	#[default]
+	Get,
+	Post,
+}
+
+/// How the engine authenticates requests to a serverless endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ServerlessAuth {
+	/// Sends the given token as `Authorization: Bearer <token>`.
+	Bearer { token: String },
+	/// Sends the value of the given environment variable on the engine as
+	/// `Authorization: Bearer <token>`. The variable must be listed in the engine's
+	/// `pegboard.serverless_auth_env_allowlist`.
+	BearerEnv { env: String },
+}
+
+impl ServerlessAuth {
+	/// Resolves the `Authorization` header value for this auth strategy. `env_allowlist` is the
+	/// set of environment variables `BearerEnv` may read.
+	pub fn authorization(&self, env_allowlist: &[String]) -> Result<String> {
+		let token = match self {
+			ServerlessAuth::Bearer { token } => token.clone(),
+			ServerlessAuth::BearerEnv { env } => {
+				ensure!(
+					env_allowlist.contains(env),
+					"serverless auth env var `{env}` is not in the allowlist"
+				);
+
+				std::env::var(env)
+					.with_context(|| format!("serverless auth env var `{env}` is not set"))?
+			}
+		};
+
+		Ok(format!("Bearer {token}"))
+	}
+}
+
 fn default_drain_on_version_upgrade() -> bool {
 	false
 }
@@ -131,15 +182,15 @@ fn default_actor_eviction_rate() -> f32 {
 }
 
 impl From<RunnerConfig>
-	for rivet_data::generated::pegboard_namespace_runner_config_v6::RunnerConfig
+	for rivet_data::generated::pegboard_namespace_runner_config_v7::RunnerConfig
 {
 	fn from(value: RunnerConfig) -> Self {
 		let RunnerConfig { kind, metadata } = value;
-		rivet_data::generated::pegboard_namespace_runner_config_v6::RunnerConfig {
+		rivet_data::generated::pegboard_namespace_runner_config_v7::RunnerConfig {
 			metadata: metadata.and_then(|value| serde_json::to_string(&value).ok()),
 			kind: match kind {
 				RunnerConfigKind::Normal { drain_on_version_upgrade, actor_eviction_delay, actor_eviction_period, actor_eviction_rate } => {
-					rivet_data::generated::pegboard_namespace_runner_config_v6::RunnerConfigKind::Normal(rivet_data::generated::pegboard_namespace_runner_config_v6::Normal {
+					rivet_data::generated::pegboard_namespace_runner_config_v7::RunnerConfigKind::Normal(rivet_data::generated::pegboard_namespace_runner_config_v7::Normal {
 						drain_on_version_upgrade,
 						actor_eviction_delay,
 						actor_eviction_period,
@@ -161,9 +212,12 @@ impl From<RunnerConfig>
 					actor_eviction_delay,
 					actor_eviction_period,
 					actor_eviction_rate,
+					method,
+					body,
+					auth,
 				} => {
-					rivet_data::generated::pegboard_namespace_runner_config_v6::RunnerConfigKind::Serverless(
-						rivet_data::generated::pegboard_namespace_runner_config_v6::Serverless {
+					rivet_data::generated::pegboard_namespace_runner_config_v7::RunnerConfigKind::Serverless(
+						rivet_data::generated::pegboard_namespace_runner_config_v7::Serverless {
 							url,
 							headers: headers.into(),
 							request_lifespan,
@@ -178,6 +232,9 @@ impl From<RunnerConfig>
 							actor_eviction_delay,
 							actor_eviction_period,
 							actor_eviction_rate,
+							method: method.into(),
+							body,
+							auth: auth.map(Into::into),
 						},
 					)
 				}
@@ -186,18 +243,18 @@ impl From<RunnerConfig>
 	}
 }
 
-impl From<rivet_data::generated::pegboard_namespace_runner_config_v6::RunnerConfig>
+impl From<rivet_data::generated::pegboard_namespace_runner_config_v7::RunnerConfig>
 	for RunnerConfig
 {
 	fn from(
-		value: rivet_data::generated::pegboard_namespace_runner_config_v6::RunnerConfig,
+		value: rivet_data::generated::pegboard_namespace_runner_config_v7::RunnerConfig,
 	) -> Self {
-		let rivet_data::generated::pegboard_namespace_runner_config_v6::RunnerConfig {
+		let rivet_data::generated::pegboard_namespace_runner_config_v7::RunnerConfig {
 			metadata,
 			kind,
 		} = value;
 		let kind = match kind {
-				rivet_data::generated::pegboard_namespace_runner_config_v6::RunnerConfigKind::Normal(o) => {
+				rivet_data::generated::pegboard_namespace_runner_config_v7::RunnerConfigKind::Normal(o) => {
 					RunnerConfigKind::Normal {
 						drain_on_version_upgrade: o.drain_on_version_upgrade,
 						actor_eviction_delay: o.actor_eviction_delay,
@@ -205,7 +262,7 @@ impl From<rivet_data::generated::pegboard_namespace_runner_config_v6::RunnerConf
 						actor_eviction_rate: o.actor_eviction_rate,
 					}
 				}
-				rivet_data::generated::pegboard_namespace_runner_config_v6::RunnerConfigKind::Serverless(
+				rivet_data::generated::pegboard_namespace_runner_config_v7::RunnerConfigKind::Serverless(
 					o,
 				) => RunnerConfigKind::Serverless {
 					url: o.url,
@@ -222,6 +279,9 @@ impl From<rivet_data::generated::pegboard_namespace_runner_config_v6::RunnerConf
 					actor_eviction_delay: o.actor_eviction_delay,
 					actor_eviction_period: o.actor_eviction_period,
 					actor_eviction_rate: o.actor_eviction_rate,
+					method: o.method.into(),
+					body: o.body,
+					auth: o.auth.map(Into::into),
 				},
 			};
 		RunnerConfig {
@@ -231,6 +291,70 @@ impl From<rivet_data::generated::pegboard_namespace_runner_config_v6::RunnerConf
 	}
 }
 
+impl From<ServerlessRequestMethod>
+	for rivet_data::generated::pegboard_namespace_runner_config_v7::ServerlessRequestMethod
+{
+	fn from(value: ServerlessRequestMethod) -> Self {
+		match value {
+			ServerlessRequestMethod::Get => Self::Get,
+			ServerlessRequestMethod::Post => Self::Post,
+		}
+	}
+}
+
+impl From<rivet_data::generated::pegboard_namespace_runner_config_v7::ServerlessRequestMethod>
+	for ServerlessRequestMethod
+{
+	fn from(
+		value: rivet_data::generated::pegboard_namespace_runner_config_v7::ServerlessRequestMethod,
+	) -> Self {
+		match value {
+			rivet_data::generated::pegboard_namespace_runner_config_v7::ServerlessRequestMethod::Get => {
+				ServerlessRequestMethod::Get
+			}
+			rivet_data::generated::pegboard_namespace_runner_config_v7::ServerlessRequestMethod::Post => {
+				ServerlessRequestMethod::Post
+			}
+		}
+	}
+}
+
+impl From<ServerlessAuth>
+	for rivet_data::generated::pegboard_namespace_runner_config_v7::ServerlessAuth
+{
+	fn from(value: ServerlessAuth) -> Self {
+		match value {
+			ServerlessAuth::Bearer { token } => Self::ServerlessAuthBearer(
+				rivet_data::generated::pegboard_namespace_runner_config_v7::ServerlessAuthBearer {
+					token,
+				},
+			),
+			ServerlessAuth::BearerEnv { env } => Self::ServerlessAuthBearerEnv(
+				rivet_data::generated::pegboard_namespace_runner_config_v7::ServerlessAuthBearerEnv {
+					env,
+				},
+			),
+		}
+	}
+}
+
+impl From<rivet_data::generated::pegboard_namespace_runner_config_v7::ServerlessAuth>
+	for ServerlessAuth
+{
+	fn from(
+		value: rivet_data::generated::pegboard_namespace_runner_config_v7::ServerlessAuth,
+	) -> Self {
+		match value {
+			rivet_data::generated::pegboard_namespace_runner_config_v7::ServerlessAuth::ServerlessAuthBearer(o) => {
+				ServerlessAuth::Bearer { token: o.token }
+			}
+			rivet_data::generated::pegboard_namespace_runner_config_v7::ServerlessAuth::ServerlessAuthBearerEnv(o) => {
+				ServerlessAuth::BearerEnv { env: o.env }
+			}
+		}
+	}
+}
+
 impl RunnerConfig {
 	/// If updates to this run config affects the pool.
 	pub fn affects_pool(&self) -> bool {
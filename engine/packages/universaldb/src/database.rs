@@ -65,6 +65,8 @@ impl Database {
 		let res = self
 			.driver
 			.run(Box::new(|tx| {
+				attempts.fetch_add(1, Ordering::AcqRel);
+
 				let tx = tx.with_name(name);
 				async move { closure(tx).await.map(|value| Box::new(value) as Erased) }
 					.custom_instrument(tracing::info_span!("txn_attempt"))
@@ -83,6 +85,9 @@ impl Database {
 		metrics::TRANSACTION_ATTEMPTS
 			.with_label_values(&[name])
 			.observe(final_attempts as f64);
+		metrics::TRANSACTION_RETRIES_TOTAL
+			.with_label_values(&[name])
+			.inc_by(final_attempts.saturating_sub(1) as u64);
 		metrics::TRANSACTION_PENDING
 			.with_label_values(&[name])
 			.dec();
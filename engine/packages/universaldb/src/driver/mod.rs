@@ -1,8 +1,15 @@
-use std::{any::Any, future::Future, path::Path, pin::Pin, sync::Arc};
+use std::{
+	any::Any,
+	future::Future,
+	path::Path,
+	pin::Pin,
+	sync::{Arc, OnceLock},
+};
 
 use anyhow::{Result, bail};
 
 use crate::{
+	error::TransactionConflict,
 	key_selector::KeySelector,
 	options::{ConflictRangeType, MutationType, Priority},
 	range_option::RangeOption,
@@ -22,6 +29,40 @@ pub type Erased = Box<dyn Any + Send>;
 
 pub type DatabaseDriverHandle = Arc<dyn DatabaseDriver>;
 
+/// Returns true if UDB_LOG_CONFLICTS is set, read once at startup. Only meant for debugging contention
+/// since every conflict is logged.
+fn log_conflicts_enabled() -> bool {
+	static ENABLED: OnceLock<bool> = OnceLock::new();
+	*ENABLED.get_or_init(|| {
+		std::env::var("UDB_LOG_CONFLICTS")
+			.map(|x| x == "1" || x == "true")
+			.unwrap_or_default()
+	})
+}
+
+/// Logs the details of a conflicted transaction before it is retried, if enabled. Drivers that know which
+/// ranges conflicted attach a `TransactionConflict`, otherwise the backend's error is logged as is.
+pub(crate) fn log_transaction_conflict(attempt: i32, error: &anyhow::Error) {
+	if !log_conflicts_enabled() {
+		return;
+	}
+
+	if let Some(conflict) = error.downcast_ref::<TransactionConflict>() {
+		tracing::debug!(
+			attempt,
+			begin=%hex::encode(&conflict.begin),
+			end=%hex::encode(&conflict.end),
+			conflict_type=?conflict.conflict_type,
+			other_begin=%hex::encode(&conflict.other_begin),
+			other_end=%hex::encode(&conflict.other_end),
+			other_conflict_type=?conflict.other_conflict_type,
+			"transaction conflict"
+		);
+	} else {
+		tracing::debug!(attempt, ?error, "transaction conflict");
+	}
+}
+
 pub trait DatabaseDriver: Send + Sync {
 	fn create_txn(&self) -> Result<Transaction>;
 	fn run<'a>(
@@ -16,7 +16,7 @@ use url::Url;
 
 use crate::{
 	RetryableTransaction, Transaction,
-	driver::{BoxFut, DatabaseDriver, Erased},
+	driver::{BoxFut, DatabaseDriver, Erased, log_transaction_conflict},
 	error::DatabaseError,
 	transaction::TXN_TIMEOUT,
 	utils::{MaybeCommitted, calculate_tx_retry_backoff},
@@ -270,6 +270,10 @@ impl DatabaseDriver for PostgresDatabaseDriver {
 				if let Some(db_error) = chain {
 					// Handle retry or return error
 					if db_error.is_retryable() {
+						if let DatabaseError::NotCommitted = db_error {
+							log_transaction_conflict(attempt, &error);
+						}
+
 						if db_error.is_maybe_committed() {
 							maybe_committed = MaybeCommitted(true);
 						}
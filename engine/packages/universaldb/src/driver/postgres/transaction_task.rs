@@ -522,8 +522,8 @@ fn map_postgres_error(err: tokio_postgres::Error) -> anyhow::Error {
 	if error_str.contains("exclusion_violation")
 		|| error_str.contains("violates exclusion constraint")
 	{
-		// Retryable - another transaction has a conflicting range
-		DatabaseError::NotCommitted.into()
+		// Retryable - another transaction has a conflicting range. Postgres' error describes the range
+		anyhow::Error::from(DatabaseError::NotCommitted).context(error_str)
 	} else if error_str.contains("serialization failure")
 		|| error_str.contains("could not serialize")
 		|| error_str.contains("deadlock detected")
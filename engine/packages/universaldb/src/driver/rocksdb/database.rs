@@ -11,7 +11,7 @@ use rocksdb::{OptimisticTransactionDB, Options, checkpoint::Checkpoint};
 
 use crate::{
 	RetryableTransaction, Transaction,
-	driver::{BoxFut, DatabaseDriver, Erased},
+	driver::{BoxFut, DatabaseDriver, Erased, log_transaction_conflict},
 	error::DatabaseError,
 	transaction::TXN_TIMEOUT,
 	utils::{MaybeCommitted, calculate_tx_retry_backoff},
@@ -93,6 +93,10 @@ impl DatabaseDriver for RocksDbDatabaseDriver {
 				if let Some(db_error) = chain {
 					// Handle retry or return error
 					if db_error.is_retryable() {
+						if let DatabaseError::NotCommitted = db_error {
+							log_transaction_conflict(attempt, &error);
+						}
+
 						if db_error.is_maybe_committed() {
 							maybe_committed = MaybeCommitted(true);
 						}
@@ -8,7 +8,7 @@ use std::{
 
 use tokio::sync::Mutex;
 
-use crate::options::ConflictRangeType;
+use crate::{error::TransactionConflict, options::ConflictRangeType};
 
 // Transactions cannot live longer than 5 seconds so we don't need to store transaction conflicts longer than
 // that
@@ -49,7 +49,7 @@ impl TransactionConflictTracker {
 		&self,
 		txn1_start_version: u64,
 		txn1_conflict_ranges: Vec<(Vec<u8>, Vec<u8>, ConflictRangeType)>,
-	) -> bool {
+	) -> Option<TransactionConflict> {
 		let mut txns = self.txns.lock().await;
 		let txn1_commit_version = self.next_global_version();
 
@@ -64,20 +64,14 @@ impl TransactionConflictTracker {
 					for (cr2_start, cr2_end, cr2_type) in &txn2.conflict_ranges {
 						// Check conflict ranges overlap
 						if cr1_start < cr2_end && cr2_start < cr1_end && cr1_type != cr2_type {
-							tracing::debug!(
-								cr1_start=%hex::encode(cr1_start),
-								cr1_end=%hex::encode(cr1_end),
-								?cr1_type,
-								cr2_start=%hex::encode(cr2_start),
-								cr2_end=%hex::encode(cr2_end),
-								?cr2_type,
-								txn1_start_version,
-								txn1_commit_version,
-								txn2_start_version = txn2.start_version,
-								txn2_commit_version = txn2.commit_version,
-								"transaction conflict detected"
-							);
-							return true;
+							return Some(TransactionConflict {
+								begin: cr1_start.clone(),
+								end: cr1_end.clone(),
+								conflict_type: *cr1_type,
+								other_begin: cr2_start.clone(),
+								other_end: cr2_end.clone(),
+								other_conflict_type: *cr2_type,
+							});
 						}
 					}
 				}
@@ -92,7 +86,7 @@ impl TransactionConflictTracker {
 			conflict_ranges: txn1_conflict_ranges,
 		});
 
-		false
+		None
 	}
 
 	pub async fn remove(&self, txn_start_version: u64) {
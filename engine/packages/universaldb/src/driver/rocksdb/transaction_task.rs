@@ -412,12 +412,12 @@ impl TransactionTask {
 			}
 		}
 
-		if self
+		if let Some(conflict) = self
 			.txn_conflict_tracker
 			.check_and_insert(start_version, conflict_ranges)
 			.await
 		{
-			return Err(DatabaseError::NotCommitted.into());
+			return Err(anyhow::Error::from(DatabaseError::NotCommitted).context(conflict));
 		}
 
 		// Commit the transaction (this consumes txn)
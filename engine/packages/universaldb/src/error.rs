@@ -1,3 +1,5 @@
+use crate::options::ConflictRangeType;
+
 #[derive(thiserror::Error, Debug)]
 pub enum DatabaseError {
 	#[error("transaction not committed due to conflict with another transaction")]
@@ -28,3 +30,23 @@ impl DatabaseError {
 		false
 	}
 }
+
+/// Identifies the conflict ranges that caused a transaction to not be committed. Attached as context to
+/// `DatabaseError::NotCommitted` by drivers that track conflict ranges themselves.
+#[derive(thiserror::Error, Debug)]
+#[error(
+	"{conflict_type:?} range {}..{} conflicted with {other_conflict_type:?} range {}..{}",
+	hex::encode(.begin),
+	hex::encode(.end),
+	hex::encode(.other_begin),
+	hex::encode(.other_end)
+)]
+pub struct TransactionConflict {
+	pub begin: Vec<u8>,
+	pub end: Vec<u8>,
+	pub conflict_type: ConflictRangeType,
+	/// Range of the previously committed transaction.
+	pub other_begin: Vec<u8>,
+	pub other_end: Vec<u8>,
+	pub other_conflict_type: ConflictRangeType,
+}
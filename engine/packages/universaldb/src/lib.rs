@@ -3,7 +3,8 @@ mod database;
 pub mod driver;
 pub mod error;
 pub mod key_selector;
-mod metrics;
+pub mod metrics;
+pub mod migration;
 pub mod options;
 pub mod prelude;
 pub mod range_option;
@@ -40,6 +40,12 @@ lazy_static::lazy_static! {
 		vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 10.0, 12.0, 14.0, 16.0],
 		*REGISTRY
 	).unwrap();
+	pub static ref TRANSACTION_RETRIES_TOTAL: IntCounterVec = register_int_counter_vec_with_registry!(
+		"udb_transaction_retries_total",
+		"How many times transactions have been retried (i.e. because of a conflict).",
+		&["name"],
+		*REGISTRY
+	).unwrap();
 
 	pub static ref OPERATION_TOTAL: IntCounterVec = register_int_counter_vec_with_registry!(
 		"udb_operation_total",
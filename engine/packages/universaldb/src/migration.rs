@@ -0,0 +1,201 @@
+//! Migrations for key layout changes. Value schema changes are handled by versioned types, this is for
+//! rewriting every entry in a subspace into a new key layout across a live dataset.
+//!
+//! Entries are migrated in bounded batches, each in its own transaction along with the migration's
+//! progress. An interrupted migration resumes from the last committed batch.
+
+use anyhow::{Context, Result, ensure};
+use futures_util::TryStreamExt;
+
+use crate::{
+	Database, RangeOption,
+	options::StreamingMode,
+	utils::{
+		IsolationLevel::*,
+		Subspace, end_of_key_range,
+		keys::{COMPLETE, COUNT, CURSOR, MIGRATION, RIVET},
+	},
+	value::Slice,
+};
+
+const DEFAULT_BATCH_SIZE: usize = 500;
+
+/// Rewrites entries from an old key layout into a new one.
+pub trait KeyMigration: Send + Sync {
+	/// Unique name of this migration, used to track progress. Must not change once deployed.
+	fn name(&self) -> &str;
+
+	/// Subspace containing the entries in the old layout.
+	fn old_subspace(&self) -> Subspace;
+
+	/// Converts an entry in the old layout to the new layout. Returning `None` drops the entry. The old
+	/// entry is always cleared, so the new key must be outside of the old subspace.
+	fn migrate(&self, key: &[u8], value: &[u8]) -> Result<Option<(Vec<u8>, Vec<u8>)>>;
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MigrationProgress {
+	/// Total entries migrated so far.
+	pub migrated: u64,
+	pub complete: bool,
+}
+
+/// Runs key migrations, tracking progress under `(RIVET, MIGRATION, name)`.
+pub struct MigrationRunner {
+	db: Database,
+	subspace: Subspace,
+	batch_size: usize,
+}
+
+impl MigrationRunner {
+	pub fn new(db: Database) -> Self {
+		MigrationRunner {
+			db,
+			subspace: Subspace::new(&(RIVET, MIGRATION)),
+			batch_size: DEFAULT_BATCH_SIZE,
+		}
+	}
+
+	/// Max entries migrated per transaction.
+	pub fn batch_size(mut self, batch_size: usize) -> Self {
+		self.batch_size = batch_size.max(1);
+		self
+	}
+
+	/// Runs the migration until all entries have been rewritten. Does nothing if it already completed.
+	pub async fn run(&self, migration: &dyn KeyMigration) -> Result<MigrationProgress> {
+		loop {
+			let progress = self.run_batch(migration).await?;
+
+			if progress.complete {
+				tracing::info!(name=%migration.name(), migrated=progress.migrated, "key migration complete");
+				return Ok(progress);
+			}
+
+			tracing::debug!(name=%migration.name(), migrated=progress.migrated, "migrated batch");
+		}
+	}
+
+	/// Migrates a single batch of entries after the last committed batch.
+	pub async fn run_batch(&self, migration: &dyn KeyMigration) -> Result<MigrationProgress> {
+		let name = migration.name();
+		let progress_subspace = self.subspace.subspace(&(name,));
+		let old_subspace = migration.old_subspace();
+		let batch_size = self.batch_size;
+
+		self.db
+			.txn("udb_migration_batch", |tx| {
+				let progress_subspace = &progress_subspace;
+				let old_subspace = &old_subspace;
+
+				async move {
+					let cursor_key = progress_subspace.pack(&(CURSOR,));
+					let count_key = progress_subspace.pack(&(COUNT,));
+					let complete_key = progress_subspace.pack(&(COMPLETE,));
+
+					let (cursor, count, complete) = tokio::try_join!(
+						tx.get(&cursor_key, Serializable),
+						tx.get(&count_key, Serializable),
+						tx.get(&complete_key, Serializable),
+					)?;
+
+					let mut migrated = decode_count(count)?;
+
+					if complete.is_some() {
+						return Ok(MigrationProgress {
+							migrated,
+							complete: true,
+						});
+					}
+
+					let (old_begin, old_end) = old_subspace.range();
+					let begin = if let Some(cursor) = &cursor {
+						end_of_key_range(cursor)
+					} else {
+						old_begin.clone()
+					};
+
+					let entries = tx
+						.get_ranges_keyvalues(
+							RangeOption {
+								mode: StreamingMode::Exact,
+								limit: Some(batch_size),
+								..(begin.as_slice(), old_end.as_slice()).into()
+							},
+							Serializable,
+						)
+						.try_collect::<Vec<_>>()
+						.await?;
+
+					for entry in &entries {
+						if let Some((new_key, new_value)) =
+							migration.migrate(entry.key(), entry.value())?
+						{
+							ensure!(
+								new_key < old_begin || new_key >= old_end,
+								"key migration {name} wrote a key inside of its old subspace"
+							);
+
+							tx.set(&new_key, &new_value);
+						}
+
+						tx.clear(entry.key());
+					}
+
+					migrated += entries.len() as u64;
+					tx.set(&count_key, &migrated.to_le_bytes());
+
+					if let Some(last) = entries.last() {
+						tx.set(&cursor_key, last.key());
+					}
+
+					// A partial batch means there is nothing left to migrate
+					let complete = entries.len() < batch_size;
+					if complete {
+						tx.set(&complete_key, &[]);
+					}
+
+					Ok(MigrationProgress { migrated, complete })
+				}
+			})
+			.await
+	}
+
+	/// Returns the committed progress of the given migration.
+	pub async fn progress(&self, name: &str) -> Result<MigrationProgress> {
+		let progress_subspace = self.subspace.subspace(&(name,));
+
+		self.db
+			.txn("udb_migration_progress", |tx| {
+				let progress_subspace = &progress_subspace;
+
+				async move {
+					let (count, complete) = tokio::try_join!(
+						tx.get(&progress_subspace.pack(&(COUNT,)), Snapshot),
+						tx.get(&progress_subspace.pack(&(COMPLETE,)), Snapshot),
+					)?;
+
+					let migrated = decode_count(count)?;
+
+					Ok(MigrationProgress {
+						migrated,
+						complete: complete.is_some(),
+					})
+				}
+			})
+			.await
+	}
+}
+
+fn decode_count(count: Option<Slice>) -> Result<u64> {
+	let Some(count) = count else {
+		return Ok(0);
+	};
+
+	Ok(u64::from_le_bytes(
+		count
+			.as_slice()
+			.try_into()
+			.context("invalid migration count")?,
+	))
+}
@@ -261,6 +261,21 @@ impl Transaction {
 		self.get_ranges_keyvalues(opt, isolation_level)
 	}
 
+	/// Reads a range under snapshot isolation while registering read conflicts on only the given keys. Use
+	/// this for bulk scans where changes to the scanned range are fine but a few critical keys must still
+	/// cause a retry if they are modified concurrently.
+	pub fn read_range_guarded<'a, T: TuplePack>(
+		&'a self,
+		opt: RangeOption<'a>,
+		conflict_keys: &[T],
+	) -> Result<crate::value::Stream<'a, Value>> {
+		for key in conflict_keys {
+			self.add_conflict_key(key, ConflictRangeType::Read)?;
+		}
+
+		Ok(self.read_range(opt, IsolationLevel::Snapshot))
+	}
+
 	// TODO: Fix types
 	// pub fn read_entries<'a, T: FormalKey + for<'de> TupleUnpack<'de>>(
 	// 	&'a self,
@@ -0,0 +1,72 @@
+use anyhow::{Result, ensure};
+use futures_util::TryStreamExt;
+
+use crate::{
+	Database,
+	options::StreamingMode,
+	range_option::RangeOption,
+	utils::{IsolationLevel::*, Subspace, end_of_key_range},
+};
+
+/// Clears all keys in the given subspace over multiple transactions, each of which clears at most
+/// `batch_size` keys. Unlike `clear_subspace_range`, this is safe to use on subspaces of any size since no
+/// single transaction grows with the size of the subspace.
+///
+/// This is not atomic. Keys written to the subspace while it is being cleared may or may not be removed.
+///
+/// Returns the total number of keys cleared.
+pub async fn clear_range_chunked(
+	db: &Database,
+	subspace: &Subspace,
+	batch_size: usize,
+) -> Result<usize> {
+	ensure!(batch_size > 0, "batch size must be greater than 0");
+
+	let (mut begin, end) = subspace.range();
+	let mut total = 0;
+
+	loop {
+		let (cleared, next_begin) = db
+			.txn("udb_clear_range_chunked", |tx| {
+				let begin = &begin;
+				let end = &end;
+
+				async move {
+					let entries = tx
+						.get_ranges_keyvalues(
+							RangeOption {
+								mode: StreamingMode::Exact,
+								limit: Some(batch_size),
+								..(begin.as_slice(), end.as_slice()).into()
+							},
+							Snapshot,
+						)
+						.try_collect::<Vec<_>>()
+						.await?;
+
+					let Some(last) = entries.last() else {
+						return Ok((0, None));
+					};
+
+					// Only clear up to and including the last read key so this txn stays bounded
+					let next_begin = end_of_key_range(last.key());
+					tx.clear_range(begin, &next_begin);
+
+					Ok((
+						entries.len(),
+						(entries.len() == batch_size).then_some(next_begin),
+					))
+				}
+			})
+			.await?;
+
+		total += cleared;
+
+		let Some(next_begin) = next_begin else {
+			break;
+		};
+		begin = next_begin;
+	}
+
+	Ok(total)
+}
@@ -158,4 +158,24 @@ define_keys! {
 	(130, GENERATION, "generation"),
 	(131, ENVOY_HASH_IDX, "envoy_hash_idx"),
 	(132, VIRTUAL_NODES, "virtual_nodes"),
+	(133, PAUSED, "paused"),
+	(134, STEP, "step"),
+	(135, PARENT_WORKFLOW_ID, "parent_workflow_id"),
+	(136, CHILD_WORKFLOW, "child_workflow"),
+	(137, IDEMPOTENCY_KEY, "idempotency_key"),
+	(138, MIGRATION, "migration"),
+	(139, CURSOR, "cursor"),
+	(140, COUNT, "count"),
+	(141, CAPABILITIES, "capabilities"),
+	(142, DEFAULT_CRASH_POLICY, "default_crash_policy"),
+	(143, DESTROY_AUDIT, "destroy_audit"),
+	(144, BY_TS, "by_ts"),
+	(145, WEBHOOK, "webhook"),
+	(146, DEAD_LETTER, "dead_letter"),
+	(147, RESCHEDULE_SLOT, "reschedule_slot"),
+	(148, CORS_POLICY, "cors_policy"),
+	(149, ACTOR_RATE_LIMIT, "actor_rate_limit"),
+	(150, RESPONSE_CACHE, "response_cache"),
+	(151, BY_STATE, "by_state"),
+	(152, SIGNAL_BACKLOG, "signal_backlog"),
 }
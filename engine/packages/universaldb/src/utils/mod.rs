@@ -1,16 +1,20 @@
 use crate::tuple::{PackError, PackResult};
 
 mod cherry_pick;
+mod clear_range;
 pub mod codes;
 mod ext;
 mod formal_key;
 pub mod keys;
 mod subspace;
+mod typed_key;
 
 pub use cherry_pick::*;
+pub use clear_range::*;
 pub use ext::*;
 pub use formal_key::*;
 pub use subspace::Subspace;
+pub use typed_key::*;
 
 pub const CHUNK_SIZE: usize = 10_000; // 10 KB, not KiB, see https://apple.github.io/foundationdb/blob.html
 
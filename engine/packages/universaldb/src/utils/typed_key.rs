@@ -0,0 +1,224 @@
+/// Defines a key struct along with its `TuplePack` and `TupleUnpack` impls from a single layout.
+///
+/// Each entry in the body is either a field (`name: Type`) or a constant tag segment (`const PATH`). Fields
+/// and tags are packed in the order they are written and unpacked in the same order, so the two can't
+/// drift. When unpacking, tag segments must equal their constant or the unpack fails.
+///
+/// A `new` constructor taking all fields in order is also generated.
+///
+/// ```
+/// use universaldb::{prelude::*, typed_key};
+///
+/// typed_key! {
+///     #[derive(Debug, PartialEq)]
+///     pub struct ExampleChunkKey {
+///         pub name: String,
+///         const DATA,
+///         pub chunk: usize,
+///     }
+/// }
+///
+/// let key = ExampleChunkKey::new("foo".to_string(), 2);
+/// let packed = universaldb::tuple::pack(&key);
+/// assert_eq!(universaldb::tuple::unpack::<ExampleChunkKey>(&packed).unwrap(), key);
+///
+/// // Same layout as a manually packed tuple
+/// assert_eq!(packed, universaldb::tuple::pack(&("foo", DATA, 2usize)));
+/// ```
+///
+/// Since the pack and unpack impls are generated together, a manual impl for a typed key does not compile:
+///
+/// ```compile_fail
+/// use universaldb::{prelude::*, typed_key};
+///
+/// typed_key! {
+///     pub struct ExampleKey {
+///         pub name: String,
+///         const DATA,
+///     }
+/// }
+///
+/// impl<'de> TupleUnpack<'de> for ExampleKey {
+///     fn unpack(input: &[u8], tuple_depth: TupleDepth) -> PackResult<(&[u8], Self)> {
+///         let (input, (name, _)) = <(String, usize)>::unpack(input, tuple_depth)?;
+///         Ok((input, ExampleKey { name }))
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! typed_key {
+	(
+		$(#[$meta:meta])*
+		$vis:vis struct $name:ident {
+			$($body:tt)*
+		}
+	) => {
+		$crate::typed_key! {
+			@parse
+			meta: [$(#[$meta])*],
+			vis: [$vis],
+			name: $name,
+			fields: [],
+			segments: [],
+			rest: [$($body)*],
+		}
+	};
+
+	// Constant tag segment
+	(
+		@parse
+		meta: [$($meta:tt)*],
+		vis: [$vis:vis],
+		name: $name:ident,
+		fields: [$($fields:tt)*],
+		segments: [$($segments:tt)*],
+		rest: [const $tag:path $(, $($rest:tt)*)?],
+	) => {
+		$crate::typed_key! {
+			@parse
+			meta: [$($meta)*],
+			vis: [$vis],
+			name: $name,
+			fields: [$($fields)*],
+			segments: [$($segments)* (tag $tag)],
+			rest: [$($($rest)*)?],
+		}
+	};
+
+	// Field segment
+	(
+		@parse
+		meta: [$($meta:tt)*],
+		vis: [$vis:vis],
+		name: $name:ident,
+		fields: [$($fields:tt)*],
+		segments: [$($segments:tt)*],
+		rest: [$(#[$field_meta:meta])* $field_vis:vis $field:ident : $ty:ty $(, $($rest:tt)*)?],
+	) => {
+		$crate::typed_key! {
+			@parse
+			meta: [$($meta)*],
+			vis: [$vis],
+			name: $name,
+			fields: [$($fields)* ([$(#[$field_meta])*] [$field_vis] $field : $ty)],
+			segments: [$($segments)* (field $field : $ty)],
+			rest: [$($($rest)*)?],
+		}
+	};
+
+	(
+		@parse
+		meta: [$($meta:tt)*],
+		vis: [$vis:vis],
+		name: $name:ident,
+		fields: [$(([$($field_meta:tt)*] [$field_vis:vis] $field:ident : $ty:ty))*],
+		segments: [$($segment:tt)*],
+		rest: [],
+	) => {
+		$($meta)*
+		$vis struct $name {
+			$(
+				$($field_meta)*
+				$field_vis $field: $ty,
+			)*
+		}
+
+		impl $name {
+			#[allow(clippy::new_without_default, clippy::too_many_arguments)]
+			pub fn new($($field: $ty),*) -> Self {
+				$name { $($field),* }
+			}
+		}
+
+		impl $crate::tuple::TuplePack for $name {
+			fn pack<W: std::io::Write>(
+				&self,
+				w: &mut W,
+				tuple_depth: $crate::tuple::TupleDepth,
+			) -> std::io::Result<$crate::tuple::VersionstampOffset> {
+				let $name { $($field),* } = self;
+				let mut offset = $crate::tuple::VersionstampOffset::None { size: 0 };
+
+				// Same framing as packing a tuple
+				if tuple_depth.depth() > 0 {
+					w.write_all(&[$crate::utils::codes::NESTED])?;
+					offset += 1;
+				}
+
+				$(
+					$crate::typed_key!(@pack_segment w, tuple_depth, offset, $segment);
+				)*
+
+				if tuple_depth.depth() > 0 {
+					w.write_all(&[$crate::utils::codes::NIL])?;
+					offset += 1;
+				}
+
+				Ok(offset)
+			}
+		}
+
+		impl<'de> $crate::tuple::TupleUnpack<'de> for $name {
+			fn unpack(
+				input: &'de [u8],
+				tuple_depth: $crate::tuple::TupleDepth,
+			) -> $crate::tuple::PackResult<(&'de [u8], Self)> {
+				let input = if tuple_depth.depth() > 0 {
+					$crate::utils::parse_code(input, $crate::utils::codes::NESTED)?
+				} else {
+					input
+				};
+
+				$(
+					$crate::typed_key!(@unpack_segment input, tuple_depth, $segment);
+				)*
+
+				let input = if tuple_depth.depth() > 0 {
+					$crate::utils::parse_code(input, $crate::utils::codes::NIL)?
+				} else {
+					input
+				};
+
+				Ok((input, $name { $($field),* }))
+			}
+		}
+	};
+
+	(@pack_segment $w:ident, $tuple_depth:ident, $offset:ident, (tag $tag:path)) => {
+		$offset += $crate::tuple::TuplePack::pack(&$tag, $w, $tuple_depth.increment())?;
+	};
+
+	(@pack_segment $w:ident, $tuple_depth:ident, $offset:ident, (field $field:ident : $ty:ty)) => {
+		$offset += $crate::tuple::TuplePack::pack($field, $w, $tuple_depth.increment())?;
+	};
+
+	(@unpack_segment $input:ident, $tuple_depth:ident, (tag $tag:path)) => {
+		let $input = $crate::utils::unpack_tag(&$tag, $input, $tuple_depth.increment(), stringify!($tag))?;
+	};
+
+	(@unpack_segment $input:ident, $tuple_depth:ident, (field $field:ident : $ty:ty)) => {
+		let ($input, $field) =
+			<$ty as $crate::tuple::TupleUnpack>::unpack($input, $tuple_depth.increment())?;
+	};
+}
+
+/// Unpacks a value of the same type as `tag` and errors if it does not equal `tag`. Used by `typed_key!`.
+#[doc(hidden)]
+pub fn unpack_tag<'de, T>(
+	tag: &T,
+	input: &'de [u8],
+	tuple_depth: crate::tuple::TupleDepth,
+	tag_name: &str,
+) -> crate::tuple::PackResult<&'de [u8]>
+where
+	T: crate::tuple::TupleUnpack<'de> + PartialEq,
+{
+	let (input, value) = T::unpack(input, tuple_depth)?;
+	if &value != tag {
+		return Err(crate::tuple::PackError::Message(
+			format!("expected {tag_name} data").into(),
+		));
+	}
+
+	Ok(input)
+}
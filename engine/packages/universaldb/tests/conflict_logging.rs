@@ -0,0 +1,97 @@
+use std::{
+	io::Write,
+	sync::{
+		Arc, Mutex,
+		atomic::{AtomicUsize, Ordering},
+	},
+};
+
+use rivet_test_deps_docker::TestDatabase;
+use universaldb::{Database, prelude::*};
+use uuid::Uuid;
+
+/// Collects everything written by the tracing subscriber.
+#[derive(Clone, Default)]
+struct CapturedLogs(Arc<Mutex<Vec<u8>>>);
+
+impl Write for CapturedLogs {
+	fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+		self.0.lock().unwrap().extend_from_slice(buf);
+		Ok(buf.len())
+	}
+
+	fn flush(&mut self) -> std::io::Result<()> {
+		Ok(())
+	}
+}
+
+#[tokio::test]
+async fn rocksdb_logs_conflicting_range() {
+	// SAFETY: Set before any transaction runs, this is the only test in this binary.
+	unsafe {
+		std::env::set_var("UDB_LOG_CONFLICTS", "1");
+	}
+
+	let logs = CapturedLogs::default();
+	let subscriber = tracing_subscriber::fmt()
+		.with_env_filter("universaldb=debug")
+		.with_ansi(false)
+		.with_writer({
+			let logs = logs.clone();
+			move || logs.clone()
+		})
+		.finish();
+	// Test runtime is single threaded so the subscriber applies to the driver's retry loop
+	let _guard = tracing::subscriber::set_default(subscriber);
+
+	let test_id = Uuid::new_v4();
+	let (db_config, _docker_config) = TestDatabase::FileSystem.config(test_id, 1).await.unwrap();
+
+	let rivet_config::config::Database::FileSystem(fs_config) = db_config else {
+		unreachable!()
+	};
+
+	let driver = universaldb::driver::RocksDbDatabaseDriver::new(fs_config.path)
+		.await
+		.unwrap();
+	let db = Database::new(Arc::new(driver));
+
+	let tries = AtomicUsize::new(0);
+	db.txn("conflict_logging", |tx| {
+		let db = db.clone();
+		let tries = &tries;
+
+		async move {
+			tx.get(&[7, 8, 9], Serializable).await?;
+
+			// Both txns write the contended key but only this one read it first, so it conflicts
+			if tries.fetch_add(1, Ordering::SeqCst) == 0 {
+				db.txn("conflict_logging_other", |tx| async move {
+					tx.set(&[7, 8, 9], b"other");
+					Ok(())
+				})
+				.await?;
+			}
+
+			tx.set(&[7, 8, 9], b"value");
+
+			Ok(())
+		}
+	})
+	.await
+	.unwrap();
+
+	assert_eq!(tries.load(Ordering::SeqCst), 2);
+
+	let logs = String::from_utf8(logs.0.lock().unwrap().clone()).unwrap();
+	let conflict_line = logs
+		.lines()
+		.find(|line| line.contains("transaction conflict"))
+		.expect("conflict should be logged");
+	assert!(
+		conflict_line.contains("begin=070809"),
+		"conflict detail should identify the contended key: {conflict_line}"
+	);
+	assert!(conflict_line.contains("conflict_type=Read"));
+	assert!(conflict_line.contains("other_conflict_type=Write"));
+}
@@ -15,7 +15,7 @@ use rocksdb::{OptimisticTransactionDB, Options, WriteOptions};
 use universaldb::{
 	Database,
 	prelude::*,
-	utils::{calculate_tx_retry_backoff, end_of_key_range},
+	utils::{Subspace, calculate_tx_retry_backoff, end_of_key_range},
 };
 use uuid::Uuid;
 
@@ -499,3 +499,310 @@ async fn rocksdb_empty_values() {
 		.unwrap();
 	assert_eq!(remaining, 0, "clear_range must delete every key in the range");
 }
+
+/// A transaction whose read is invalidated by a concurrently committed write must be retried, and the
+/// retry must be counted in the retry metric.
+#[tokio::test]
+async fn rocksdb_retry_metrics() {
+	let _ = tracing_subscriber::fmt()
+		.with_env_filter("debug")
+		.with_test_writer()
+		.try_init();
+
+	let test_id = Uuid::new_v4();
+	let (db_config, _docker_config) = TestDatabase::FileSystem.config(test_id, 1).await.unwrap();
+
+	let rivet_config::config::Database::FileSystem(fs_config) = db_config else {
+		unreachable!()
+	};
+
+	let driver = universaldb::driver::RocksDbDatabaseDriver::new(fs_config.path)
+		.await
+		.unwrap();
+	let db = Database::new(Arc::new(driver));
+
+	let retries = universaldb::metrics::TRANSACTION_RETRIES_TOTAL
+		.with_label_values(&["rocksdb_retry_metrics"]);
+	let retries_before = retries.get();
+
+	let tries = AtomicUsize::new(0);
+	db.txn("rocksdb_retry_metrics", |tx| {
+		let db = db.clone();
+		let tries = &tries;
+
+		async move {
+			tx.get(&[4, 5, 6], Serializable).await?;
+
+			// Commit a conflicting write between this txn's read and its commit on the first try only
+			if tries.fetch_add(1, Ordering::SeqCst) == 0 {
+				db.txn("rocksdb_retry_metrics_conflict", |tx| async move {
+					tx.set(&[4, 5, 6], b"conflict");
+					Ok(())
+				})
+				.await?;
+			}
+
+			tx.set(&[4, 5, 6, 0], b"value");
+
+			Ok(())
+		}
+	})
+	.await
+	.unwrap();
+
+	assert_eq!(tries.load(Ordering::SeqCst), 2);
+	assert_eq!(retries.get() - retries_before, 1);
+}
+
+#[tokio::test]
+async fn rocksdb_clear_range_chunked() {
+	let _ = tracing_subscriber::fmt()
+		.with_env_filter("debug")
+		.with_test_writer()
+		.try_init();
+
+	let test_id = Uuid::new_v4();
+	let (db_config, _docker_config) = TestDatabase::FileSystem.config(test_id, 1).await.unwrap();
+
+	let rivet_config::config::Database::FileSystem(fs_config) = db_config else {
+		unreachable!()
+	};
+
+	let driver = universaldb::driver::RocksDbDatabaseDriver::new(fs_config.path)
+		.await
+		.unwrap();
+	let db = Database::new(Arc::new(driver));
+
+	let subspace = Subspace::all().subspace(&("clear_range_chunked",));
+	let neighbor = Subspace::all().subspace(&("clear_range_neighbor",));
+
+	// Populate the subspace over several txns
+	for chunk in 0..10 {
+		let subspace = &subspace;
+		db.txn("populate", |tx| async move {
+			for i in 0..100 {
+				tx.set(&subspace.pack(&(chunk * 100 + i,)), b"value");
+			}
+			Ok(())
+		})
+		.await
+		.unwrap();
+	}
+
+	let neighbor_ref = &neighbor;
+	db.txn("populate_neighbor", |tx| async move {
+		tx.set(&neighbor_ref.pack(&(0,)), b"value");
+		Ok(())
+	})
+	.await
+	.unwrap();
+
+	let cleared = universaldb::utils::clear_range_chunked(&db, &subspace, 64)
+		.await
+		.unwrap();
+	assert_eq!(cleared, 1000);
+
+	let subspace_ref = &subspace;
+	let (remaining, neighbor_remaining) = db
+		.txn("read", |tx| async move {
+			let remaining = tx
+				.get_ranges_keyvalues(subspace_ref.into(), Serializable)
+				.try_collect::<Vec<_>>()
+				.await?;
+			let neighbor_remaining = tx
+				.get_ranges_keyvalues(neighbor_ref.into(), Serializable)
+				.try_collect::<Vec<_>>()
+				.await?;
+
+			Ok((remaining.len(), neighbor_remaining.len()))
+		})
+		.await
+		.unwrap();
+	assert_eq!(remaining, 0, "subspace should be empty");
+	assert_eq!(neighbor_remaining, 1, "neighbor should be untouched");
+
+	// Clearing an empty subspace is a no-op
+	let cleared = universaldb::utils::clear_range_chunked(&db, &subspace, 64)
+		.await
+		.unwrap();
+	assert_eq!(cleared, 0);
+}
+
+/// Moves `("migration_old", id)` entries to `("migration_new", id, "default")`.
+struct AddFieldMigration;
+
+impl universaldb::migration::KeyMigration for AddFieldMigration {
+	fn name(&self) -> &str {
+		"test_add_field"
+	}
+
+	fn old_subspace(&self) -> Subspace {
+		Subspace::new(&("migration_old",))
+	}
+
+	fn migrate(&self, key: &[u8], value: &[u8]) -> anyhow::Result<Option<(Vec<u8>, Vec<u8>)>> {
+		let (id,) = self.old_subspace().unpack::<(i64,)>(key)?;
+		let new_key = Subspace::new(&("migration_new",)).pack(&(id, "default"));
+
+		Ok(Some((new_key, value.to_vec())))
+	}
+}
+
+#[tokio::test]
+async fn rocksdb_key_migration() {
+	let _ = tracing_subscriber::fmt()
+		.with_env_filter("debug")
+		.with_test_writer()
+		.try_init();
+
+	let test_id = Uuid::new_v4();
+	let (db_config, _docker_config) = TestDatabase::FileSystem.config(test_id, 1).await.unwrap();
+
+	let rivet_config::config::Database::FileSystem(fs_config) = db_config else {
+		unreachable!()
+	};
+
+	let driver = universaldb::driver::RocksDbDatabaseDriver::new(fs_config.path)
+		.await
+		.unwrap();
+	let db = Database::new(Arc::new(driver));
+
+	let old_subspace = Subspace::new(&("migration_old",));
+	let new_subspace = Subspace::new(&("migration_new",));
+
+	let old_subspace_ref = &old_subspace;
+	db.txn("populate", |tx| async move {
+		for id in 0..250i64 {
+			tx.set(&old_subspace_ref.pack(&(id,)), &id.to_le_bytes());
+		}
+		Ok(())
+	})
+	.await
+	.unwrap();
+
+	// Simulate an interrupted run
+	let runner = universaldb::migration::MigrationRunner::new(db.clone()).batch_size(100);
+	let progress = runner.run_batch(&AddFieldMigration).await.unwrap();
+	assert_eq!(progress.migrated, 100);
+	assert!(!progress.complete);
+	drop(runner);
+
+	// A new runner resumes from the committed progress
+	let runner = universaldb::migration::MigrationRunner::new(db.clone()).batch_size(100);
+	assert_eq!(
+		runner.progress("test_add_field").await.unwrap().migrated,
+		100
+	);
+	let progress = runner.run(&AddFieldMigration).await.unwrap();
+	assert_eq!(progress.migrated, 250);
+	assert!(progress.complete);
+
+	let new_subspace_ref = &new_subspace;
+	let (old_entries, new_entries) = db
+		.txn("read", |tx| async move {
+			let old_entries = tx
+				.get_ranges_keyvalues(old_subspace_ref.into(), Serializable)
+				.try_collect::<Vec<_>>()
+				.await?;
+			let new_entries = tx
+				.get_ranges_keyvalues(new_subspace_ref.into(), Serializable)
+				.try_collect::<Vec<_>>()
+				.await?;
+
+			Ok((old_entries, new_entries))
+		})
+		.await
+		.unwrap();
+	assert!(old_entries.is_empty(), "old layout should be cleared");
+	assert_eq!(new_entries.len(), 250);
+	for (expected_id, entry) in (0..250i64).zip(&new_entries) {
+		let (id, field) = new_subspace.unpack::<(i64, String)>(entry.key()).unwrap();
+		assert_eq!(id, expected_id);
+		assert_eq!(field, "default");
+		assert_eq!(entry.value(), expected_id.to_le_bytes());
+	}
+
+	// Completed migrations are not run again
+	let progress = runner.run(&AddFieldMigration).await.unwrap();
+	assert_eq!(progress.migrated, 250);
+}
+
+#[tokio::test]
+async fn rocksdb_read_range_guarded() {
+	let _ = tracing_subscriber::fmt()
+		.with_env_filter("debug")
+		.with_test_writer()
+		.try_init();
+
+	let test_id = Uuid::new_v4();
+	let (db_config, _docker_config) = TestDatabase::FileSystem.config(test_id, 1).await.unwrap();
+
+	let rivet_config::config::Database::FileSystem(fs_config) = db_config else {
+		unreachable!()
+	};
+
+	let driver = universaldb::driver::RocksDbDatabaseDriver::new(fs_config.path)
+		.await
+		.unwrap();
+	let db = Database::new(Arc::new(driver));
+
+	let subspace = Subspace::new(&("guarded",));
+	let subspace_ref = &subspace;
+	db.txn("populate", |tx| async move {
+		for i in 0..100i64 {
+			tx.set(&subspace_ref.pack(&(i,)), b"value");
+		}
+		Ok(())
+	})
+	.await
+	.unwrap();
+
+	// Scans the subspace while guarding key 0, then writes `conflicting_key` from another txn on the
+	// first try only. Returns the number of tries.
+	let run = |conflicting_key: i64| {
+		let db = db.clone();
+
+		async move {
+			let tries = AtomicUsize::new(0);
+			db.txn("guarded_scan", |tx| {
+				let db = db.clone();
+				let tries = &tries;
+
+				async move {
+					let tx = tx.with_subspace(subspace_ref.clone());
+					let entries = tx
+						.read_range_guarded((&Subspace::all()).into(), &[(0i64,)])?
+						.try_collect::<Vec<_>>()
+						.await?;
+
+					if tries.fetch_add(1, Ordering::SeqCst) == 0 {
+						db.txn("guarded_scan_conflict", |tx| async move {
+							tx.set(&subspace_ref.pack(&(conflicting_key,)), b"changed");
+							Ok(())
+						})
+						.await?;
+					}
+
+					tx.set(b"guarded_output", &entries.len().to_le_bytes());
+
+					Ok(())
+				}
+			})
+			.await
+			.unwrap();
+
+			tries.load(Ordering::SeqCst)
+		}
+	};
+
+	assert_eq!(
+		run(50).await,
+		1,
+		"scan-only changes should not cause a retry"
+	);
+	assert_eq!(
+		run(0).await,
+		2,
+		"changes to the guarded key should cause a retry"
+	);
+}
@@ -1,21 +1,27 @@
+use std::collections::BTreeMap;
 use std::ops::Deref;
-use std::sync::Arc;
-use std::time::Duration;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use anyhow::Result;
 use async_trait::async_trait;
 use rand::seq::SliceRandom;
 use scc::HashMap;
-use tokio::sync::mpsc;
+use tokio::sync::{Notify, mpsc};
 use uuid::Uuid;
 
-use crate::driver::{PubSubDriver, SubscriberDriver, SubscriberDriverHandle};
+use crate::driver::{
+	DURABLE_ACK_WAIT, DURABLE_MAX_AGE, DurableAck, DurableSubscriberDriver,
+	DurableSubscriberDriverHandle, PubSubDriver, SubscriberDriver, SubscriberDriverHandle,
+};
 use crate::metrics;
-use crate::pubsub::DriverOutput;
+use crate::pubsub::{DriverOutput, DurableDriverOutput};
 
 /// This is arbitrary.
 const MEMORY_MAX_MESSAGE_SIZE: usize = 10 * 1024 * 1024; // 10MiB
 const GC_INTERVAL: Duration = Duration::from_secs(60);
+/// How often durable subscribers check for messages whose ack wait expired.
+const DURABLE_POLL_INTERVAL: Duration = Duration::from_secs(1);
 
 enum MemoryMessage {
 	Payload(Vec<u8>),
@@ -28,6 +34,63 @@ pub struct MemoryDriverInner {
 	subscribers: HashMap<String, Vec<mpsc::UnboundedSender<MemoryMessage>>>,
 	// Map<topic, Map<queue, Vec<sub>>>
 	queue_subscribers: HashMap<String, HashMap<String, Vec<mpsc::UnboundedSender<MemoryMessage>>>>,
+	// Map<topic, Map<group, queue>>
+	durable_groups: HashMap<String, HashMap<String, Arc<DurableQueue>>>,
+}
+
+struct DurableMessageEntry {
+	payload: Vec<u8>,
+	created_at: Instant,
+	visible_at: Instant,
+}
+
+#[derive(Default)]
+struct DurableQueueState {
+	next_id: u64,
+	messages: BTreeMap<u64, DurableMessageEntry>,
+}
+
+/// Messages stored for a durable group until a member acks them.
+#[derive(Default)]
+struct DurableQueue {
+	state: Mutex<DurableQueueState>,
+	notify: Notify,
+}
+
+impl DurableQueue {
+	fn push(&self, payload: Vec<u8>) {
+		{
+			let mut state = self.state.lock().expect("poisoned");
+			let id = state.next_id;
+			state.next_id += 1;
+
+			let now = Instant::now();
+			state.messages.insert(
+				id,
+				DurableMessageEntry {
+					payload,
+					created_at: now,
+					visible_at: now,
+				},
+			);
+		}
+
+		self.notify.notify_waiters();
+	}
+
+	/// Claims the oldest visible message and hides it from other members until its ack wait expires.
+	fn claim(&self) -> Option<(u64, Vec<u8>)> {
+		let mut state = self.state.lock().expect("poisoned");
+		let now = Instant::now();
+
+		let (id, entry) = state
+			.messages
+			.iter_mut()
+			.find(|(_, entry)| entry.visible_at <= now)?;
+		entry.visible_at = now + DURABLE_ACK_WAIT;
+
+		Some((*id, entry.payload.clone()))
+	}
 }
 
 #[derive(Clone)]
@@ -47,6 +110,7 @@ impl MemoryDriver {
 			channel,
 			subscribers: HashMap::new(),
 			queue_subscribers: HashMap::new(),
+			durable_groups: HashMap::new(),
 		});
 
 		// TODO: Why not use drop impl?
@@ -87,6 +151,22 @@ impl MemoryDriver {
 						})
 						.await;
 
+					// Expire durable messages that were never acked
+					inner
+						.durable_groups
+						.iter_async(|_, groups| {
+							groups.iter_sync(|_, queue| {
+								queue.state.lock().expect("poisoned").messages.retain(
+									|_, entry| entry.created_at.elapsed() < DURABLE_MAX_AGE,
+								);
+
+								true
+							});
+
+							true
+						})
+						.await;
+
 					metrics::MEMORY_SUBSCRIBER_COUNT
 						.set((inner.subscribers.len() + inner.queue_subscribers.len()) as i64);
 				} else {
@@ -211,6 +291,44 @@ impl PubSubDriver for MemoryDriver {
 		Ok(())
 	}
 
+	async fn publish_durable(&self, subject: &str, payload: &[u8]) -> Result<()> {
+		let subject_with_channel = self.subject_with_channel(subject);
+
+		// Store a copy of the message for every group of this subject
+		if let Some(groups) = self.durable_groups.get_async(&subject_with_channel).await {
+			groups
+				.iter_async(|_, queue| {
+					queue.push(payload.to_vec());
+
+					true
+				})
+				.await;
+		}
+
+		Ok(())
+	}
+
+	async fn subscribe_durable(
+		&self,
+		subject: &str,
+		group: &str,
+	) -> Result<DurableSubscriberDriverHandle> {
+		let subject_with_channel = self.subject_with_channel(subject);
+
+		let queue = self
+			.durable_groups
+			.entry_async(subject_with_channel)
+			.await
+			.or_default()
+			.entry_async(group.to_string())
+			.await
+			.or_default()
+			.get()
+			.clone();
+
+		Ok(Box::new(MemoryDurableSubscriber { queue }))
+	}
+
 	async fn flush(&self) -> Result<()> {
 		Ok(())
 	}
@@ -238,3 +356,52 @@ impl SubscriberDriver for MemorySubscriber {
 		}
 	}
 }
+
+pub struct MemoryDurableSubscriber {
+	queue: Arc<DurableQueue>,
+}
+
+#[async_trait]
+impl DurableSubscriberDriver for MemoryDurableSubscriber {
+	async fn next(&mut self) -> Result<DurableDriverOutput> {
+		loop {
+			// Register for notifications before claiming so a message published in between is not
+			// missed
+			let notified = self.queue.notify.notified();
+			tokio::pin!(notified);
+			notified.as_mut().enable();
+
+			if let Some((id, payload)) = self.queue.claim() {
+				return Ok(DurableDriverOutput::Message {
+					payload,
+					ack: Box::new(MemoryDurableAck {
+						queue: self.queue.clone(),
+						id,
+					}),
+				});
+			}
+
+			// Wait for a new message or poll again for expired ack waits
+			let _ = tokio::time::timeout(DURABLE_POLL_INTERVAL, notified).await;
+		}
+	}
+}
+
+struct MemoryDurableAck {
+	queue: Arc<DurableQueue>,
+	id: u64,
+}
+
+#[async_trait]
+impl DurableAck for MemoryDurableAck {
+	async fn ack(&self) -> Result<()> {
+		self.queue
+			.state
+			.lock()
+			.expect("poisoned")
+			.messages
+			.remove(&self.id);
+
+		Ok(())
+	}
+}
@@ -1,4 +1,5 @@
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::Result;
 use async_trait::async_trait;
@@ -12,6 +13,11 @@ pub mod postgres;
 
 pub type PubSubDriverHandle = Arc<dyn PubSubDriver>;
 
+/// How long a durable message can remain unacknowledged before it is redelivered.
+pub const DURABLE_ACK_WAIT: Duration = Duration::from_secs(30);
+/// How long durable messages are retained if they are never acknowledged by every group.
+pub const DURABLE_MAX_AGE: Duration = Duration::from_secs(60 * 60);
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum PublishBehavior {
 	/// Publishes a message to a single subscriber.
@@ -62,6 +68,28 @@ pub trait PubSubDriver: Send + Sync {
 		message: &[u8],
 		reply_subject: Option<&str>,
 	) -> Result<()>;
+	/// Publishes multiple messages in order. Drivers override this to pipeline the messages into
+	/// fewer round trips.
+	async fn publish_many(&self, messages: &[(&str, &[u8])]) -> Result<()> {
+		for (subject, message) in messages {
+			self.publish(subject, message, None).await?;
+		}
+
+		Ok(())
+	}
+	/// Publishes a message that is stored until it is acknowledged by every durable group
+	/// subscribed to the subject, or for at most `DURABLE_MAX_AGE`.
+	async fn publish_durable(&self, subject: &str, message: &[u8]) -> Result<()>;
+	/// Subscribes to durable messages as a member of `group`.
+	///
+	/// A group receives every durable message published after it was first subscribed to, even
+	/// while it has no connected members. Messages are load balanced between members of a group and
+	/// redelivered if not acknowledged within `DURABLE_ACK_WAIT`.
+	async fn subscribe_durable(
+		&self,
+		subject: &str,
+		group: &str,
+	) -> Result<DurableSubscriberDriverHandle>;
 	async fn flush(&self) -> Result<()>;
 	fn max_message_size(&self) -> usize;
 	fn new_inbox(&self) -> InboxSubject {
@@ -75,3 +103,18 @@ pub type SubscriberDriverHandle = Box<dyn SubscriberDriver>;
 pub trait SubscriberDriver: Send + Sync {
 	async fn next(&mut self) -> Result<crate::pubsub::DriverOutput>;
 }
+
+pub type DurableSubscriberDriverHandle = Box<dyn DurableSubscriberDriver>;
+
+#[async_trait]
+pub trait DurableSubscriberDriver: Send + Sync {
+	async fn next(&mut self) -> Result<crate::pubsub::DurableDriverOutput>;
+}
+
+pub type DurableAckHandle = Box<dyn DurableAck>;
+
+#[async_trait]
+pub trait DurableAck: Send + Sync {
+	/// Acknowledges the message so it is not redelivered.
+	async fn ack(&self) -> Result<()>;
+}
@@ -1,15 +1,19 @@
 use anyhow::{Result, bail};
-use async_nats::Client;
+use async_nats::{Client, jetstream};
 use async_trait::async_trait;
 use futures_util::StreamExt;
 use scc::HashMap;
+use sha2::{Digest, Sha256};
 use std::sync::{Arc, OnceLock};
-use tokio::sync::oneshot;
+use tokio::sync::{OnceCell, oneshot};
 use uuid::Uuid;
 
 use crate::InboxSubject;
-use crate::driver::{PubSubDriver, SubscriberDriver, SubscriberDriverHandle};
-use crate::pubsub::DriverOutput;
+use crate::driver::{
+	DURABLE_ACK_WAIT, DURABLE_MAX_AGE, DurableAck, DurableSubscriberDriver,
+	DurableSubscriberDriverHandle, PubSubDriver, SubscriberDriver, SubscriberDriverHandle,
+};
+use crate::pubsub::{DriverOutput, DurableDriverOutput};
 
 /// > The size is set to 1 MB by default, but can be increased up to 64 MB if needed (though we recommend keeping the max message size to something more reasonable like 8 MB).
 ///
@@ -21,11 +25,18 @@ static NODE_ID: OnceLock<Uuid> = OnceLock::new();
 /// Global map of all request reply subscribers in this process.
 static REPLY_SUBSCRIBERS: OnceLock<Arc<HashMap<Uuid, oneshot::Sender<async_nats::Message>>>> =
 	OnceLock::new();
+/// JetStream stream that stores durable messages for all subjects.
+const DURABLE_STREAM_NAME: &str = "UPS_DURABLE";
+/// Durable messages are published under this prefix so they are captured by the durable stream
+/// without being delivered to regular subscribers of the same subject.
+const DURABLE_SUBJECT_PREFIX: &str = "ups-durable";
 
 #[derive(Clone)]
 pub struct NatsDriver {
 	client: Client,
 	reply_subscribers: Arc<HashMap<Uuid, oneshot::Sender<async_nats::Message>>>,
+	jetstream: jetstream::Context,
+	durable_stream: Arc<OnceCell<jetstream::stream::Stream>>,
 }
 
 impl NatsDriver {
@@ -59,14 +70,57 @@ impl NatsDriver {
 			.clone();
 
 		Ok(Self {
+			jetstream: jetstream::new(client.clone()),
 			client,
 			reply_subscribers,
+			durable_stream: Arc::new(OnceCell::new()),
 		})
 	}
 
 	pub fn statistics(&self) -> Arc<async_nats::Statistics> {
 		self.client.statistics()
 	}
+
+	/// Creates the durable stream if it does not exist yet.
+	async fn durable_stream(&self) -> Result<&jetstream::stream::Stream> {
+		self.durable_stream
+			.get_or_try_init(|| async {
+				let stream = self
+					.jetstream
+					.get_or_create_stream(jetstream::stream::Config {
+						name: DURABLE_STREAM_NAME.to_string(),
+						subjects: vec![format!("{DURABLE_SUBJECT_PREFIX}.>")],
+						// Messages are removed once every consumer acknowledged them
+						retention: jetstream::stream::RetentionPolicy::Interest,
+						max_age: DURABLE_MAX_AGE,
+						..Default::default()
+					})
+					.await?;
+
+				anyhow::Ok(stream)
+			})
+			.await
+	}
+
+	fn durable_subject(&self, subject: &str) -> String {
+		format!("{DURABLE_SUBJECT_PREFIX}.{subject}")
+	}
+
+	/// Consumer names cannot contain `.`, `*` or `>`, so the subject and group are hashed. Consumers
+	/// are stored by the server, so the hash must be stable between releases.
+	fn durable_consumer_name(&self, subject: &str, group: &str) -> String {
+		let mut hasher = Sha256::new();
+		hasher.update(subject.as_bytes());
+		hasher.update([0]);
+		hasher.update(group.as_bytes());
+		let hash = hasher.finalize();
+
+		let name = hash[..16]
+			.iter()
+			.map(|byte| format!("{byte:02x}"))
+			.collect::<String>();
+		format!("ups_{name}")
+	}
 }
 
 #[async_trait]
@@ -129,6 +183,59 @@ impl PubSubDriver for NatsDriver {
 		Ok(())
 	}
 
+	async fn publish_many(&self, messages: &[(&str, &[u8])]) -> Result<()> {
+		// Publishes are buffered by the client, so flush once for the whole batch
+		for (subject, payload) in messages {
+			self.client
+				.publish(subject.to_string(), payload.to_vec().into())
+				.await?;
+		}
+		self.client.flush().await?;
+
+		Ok(())
+	}
+
+	async fn publish_durable(&self, subject: &str, payload: &[u8]) -> Result<()> {
+		self.durable_stream().await?;
+
+		// Wait for the stream to acknowledge that the message was stored
+		self.jetstream
+			.publish(self.durable_subject(subject), payload.to_vec().into())
+			.await?
+			.await?;
+
+		Ok(())
+	}
+
+	async fn subscribe_durable(
+		&self,
+		subject: &str,
+		group: &str,
+	) -> Result<DurableSubscriberDriverHandle> {
+		let stream = self.durable_stream().await?;
+		let consumer_name = self.durable_consumer_name(subject, group);
+
+		// Each group is a durable consumer shared by all of its members. The consumer is removed
+		// by the server once it has had no members for `DURABLE_MAX_AGE`.
+		let consumer: jetstream::consumer::PullConsumer = stream
+			.get_or_create_consumer(
+				&consumer_name,
+				jetstream::consumer::pull::Config {
+					durable_name: Some(consumer_name.clone()),
+					filter_subject: self.durable_subject(subject),
+					deliver_policy: jetstream::consumer::DeliverPolicy::New,
+					ack_policy: jetstream::consumer::AckPolicy::Explicit,
+					ack_wait: DURABLE_ACK_WAIT,
+					inactive_threshold: DURABLE_MAX_AGE,
+					..Default::default()
+				},
+			)
+			.await?;
+		let messages = consumer.messages().await?;
+
+		Ok(Box::new(NatsDurableSubscriber { messages }))
+	}
+
 	async fn flush(&self) -> Result<()> {
 		self.client.flush().await?;
 		Ok(())
@@ -176,6 +283,38 @@ impl Drop for NatsSubscriber {
 	}
 }
 
+pub struct NatsDurableSubscriber {
+	messages: jetstream::consumer::pull::Stream,
+}
+
+#[async_trait]
+impl DurableSubscriberDriver for NatsDurableSubscriber {
+	async fn next(&mut self) -> Result<DurableDriverOutput> {
+		match self.messages.next().await {
+			Some(msg) => {
+				let msg = msg?;
+
+				Ok(DurableDriverOutput::Message {
+					payload: msg.payload.to_vec(),
+					ack: Box::new(NatsDurableAck { msg }),
+				})
+			}
+			None => Ok(DurableDriverOutput::Unsubscribed),
+		}
+	}
+}
+
+struct NatsDurableAck {
+	msg: jetstream::Message,
+}
+
+#[async_trait]
+impl DurableAck for NatsDurableAck {
+	async fn ack(&self) -> Result<()> {
+		self.msg.ack().await.map_err(|err| anyhow::anyhow!(err))
+	}
+}
+
 /// Local channel subscriber used for multiplexing request-reply.
 pub struct NatsReplySubscriber {
 	reply_id: Uuid,
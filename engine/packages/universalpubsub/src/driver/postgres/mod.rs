@@ -17,9 +17,12 @@ use tokio_postgres_rustls::MakeRustlsConnect;
 use tracing::Instrument;
 use uuid::Uuid;
 
-use crate::driver::{PubSubDriver, SubscriberDriver, SubscriberDriverHandle};
+use crate::driver::{
+	DURABLE_ACK_WAIT, DURABLE_MAX_AGE, DurableAck, DurableSubscriberDriver,
+	DurableSubscriberDriverHandle, PubSubDriver, SubscriberDriver, SubscriberDriverHandle,
+};
 use crate::metrics;
-use crate::pubsub::DriverOutput;
+use crate::pubsub::{DriverOutput, DurableDriverOutput};
 
 #[derive(Clone)]
 struct Subscription {
@@ -56,21 +59,33 @@ const QUEUE_SUB_TTL_SECS: i64 = 30;
 const QUEUE_MESSAGE_GC_INTERVAL: Duration = Duration::from_secs(300);
 /// Max age before an unconsumed queue message is garbage collected.
 const QUEUE_MESSAGE_MAX_AGE_SECS: i64 = 3600;
+/// How often durable subscribers check for messages whose ack wait expired, since redeliveries are
+/// not notified.
+const DURABLE_POLL_INTERVAL: Duration = Duration::from_secs(5);
+const DURABLE_GROUP_HEARTBEAT_INTERVAL: Duration = Duration::from_secs(60);
+
+/// A dedicated connection that LISTENs to a subset of channels.
+struct ListenShard {
+	client: Mutex<Option<tokio_postgres::Client>>,
+	client_ready: tokio::sync::watch::Receiver<bool>,
+}
 
 #[derive(Clone)]
 pub struct PostgresDriver {
 	pool: Arc<Pool>,
-	client: Arc<Mutex<Option<tokio_postgres::Client>>>,
+	/// Channels are spread across listen connections with a consistent hash so each channel is
+	/// only ever LISTENed on one connection.
+	shards: Arc<Vec<Arc<ListenShard>>>,
 	subscriptions: Arc<HashMap<String, Subscription>>,
 	/// Wakeup channels for queue subscriptions, keyed by queue channel name.
 	queue_subscriptions: Arc<HashMap<String, Subscription>>,
-	client_ready: tokio::sync::watch::Receiver<bool>,
 }
 
 impl PostgresDriver {
 	#[tracing::instrument(skip(conn_str))]
 	pub async fn connect(
 		conn_str: String,
+		listen_connections: usize,
 		ssl_root_cert_path: Option<PathBuf>,
 		ssl_client_cert_path: Option<PathBuf>,
 		ssl_client_key_path: Option<PathBuf>,
@@ -105,33 +120,45 @@ impl PostgresDriver {
 
 		let subscriptions: Arc<HashMap<String, Subscription>> = Arc::new(HashMap::new());
 		let queue_subscriptions: Arc<HashMap<String, Subscription>> = Arc::new(HashMap::new());
-		let client: Arc<Mutex<Option<tokio_postgres::Client>>> = Arc::new(Mutex::new(None));
-
-		// Create channel for client ready notifications
-		let (ready_tx, client_ready) = tokio::sync::watch::channel(false);
-
-		// Spawn connection lifecycle task
-		tokio::spawn(Self::spawn_connection_lifecycle(
-			conn_str.clone(),
-			subscriptions.clone(),
-			queue_subscriptions.clone(),
-			client.clone(),
-			ready_tx,
-			ssl_root_cert_path.clone(),
-			ssl_client_cert_path.clone(),
-			ssl_client_key_path.clone(),
-		));
+
+		let shard_count = listen_connections.max(1);
+		let mut shards = Vec::with_capacity(shard_count);
+		for shard_idx in 0..shard_count {
+			// Create channel for client ready notifications
+			let (ready_tx, client_ready) = tokio::sync::watch::channel(false);
+			let shard = Arc::new(ListenShard {
+				client: Mutex::new(None),
+				client_ready,
+			});
+
+			// Spawn connection lifecycle task
+			tokio::spawn(Self::spawn_connection_lifecycle(
+				shard_idx,
+				shard_count,
+				conn_str.clone(),
+				subscriptions.clone(),
+				queue_subscriptions.clone(),
+				shard.clone(),
+				ready_tx,
+				ssl_root_cert_path.clone(),
+				ssl_client_cert_path.clone(),
+				ssl_client_key_path.clone(),
+			));
+
+			shards.push(shard);
+		}
 
 		let driver = Self {
 			pool: Arc::new(pool),
-			client,
+			shards: Arc::new(shards),
 			subscriptions,
 			queue_subscriptions,
-			client_ready,
 		};
 
-		// Wait for initial connection to be established
-		driver.wait_for_client().await?;
+		// Wait for initial connections to be established
+		for shard in driver.shards.iter() {
+			Self::wait_for_client(shard).await?;
+		}
 
 		// Create queue tables eagerly so they exist before any publish or subscribe
 		{
@@ -157,7 +184,23 @@ impl PostgresDriver {
 				     created_at TIMESTAMPTZ NOT NULL DEFAULT NOW() \
 				 ); \
 				 CREATE INDEX IF NOT EXISTS ups_queue_messages_idx \
-				     ON ups_queue_messages (subject_hash, queue_hash, id);",
+				     ON ups_queue_messages (subject_hash, queue_hash, id); \
+				 CREATE TABLE IF NOT EXISTS ups_durable_groups ( \
+				     subject_hash TEXT NOT NULL, \
+				     group_hash TEXT NOT NULL, \
+				     last_seen_at TIMESTAMPTZ NOT NULL DEFAULT NOW(), \
+				     PRIMARY KEY (subject_hash, group_hash) \
+				 ); \
+				 CREATE TABLE IF NOT EXISTS ups_durable_messages ( \
+				     id BIGSERIAL PRIMARY KEY, \
+				     subject_hash TEXT NOT NULL, \
+				     group_hash TEXT NOT NULL, \
+				     payload BYTEA NOT NULL, \
+				     created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(), \
+				     visible_at TIMESTAMPTZ NOT NULL DEFAULT NOW() \
+				 ); \
+				 CREATE INDEX IF NOT EXISTS ups_durable_messages_idx \
+				     ON ups_durable_messages (subject_hash, group_hash, id);",
 			)
 			.await
 			.context("failed to create queue tables")?;
@@ -183,6 +226,20 @@ impl PostgresDriver {
 					if let Err(e) = result {
 						tracing::warn!(?e, "failed to gc queue messages");
 					}
+
+					// Durable messages and groups without members expire after the same max age
+					let durable_max_age_secs = DURABLE_MAX_AGE.as_secs() as i64;
+					let result = conn
+						.batch_execute(&format!(
+							"DELETE FROM ups_durable_messages \
+							 WHERE created_at < NOW() - ({durable_max_age_secs}::bigint * INTERVAL '1 second'); \
+							 DELETE FROM ups_durable_groups \
+							 WHERE last_seen_at < NOW() - ({durable_max_age_secs}::bigint * INTERVAL '1 second');"
+						))
+						.await;
+					if let Err(e) = result {
+						tracing::warn!(?e, "failed to gc durable messages");
+					}
 				}
 			}
 		});
@@ -190,12 +247,14 @@ impl PostgresDriver {
 		Ok(driver)
 	}
 
-	/// Manages the connection lifecycle with automatic reconnection
+	/// Manages the connection lifecycle of a listen shard with automatic reconnection
 	async fn spawn_connection_lifecycle(
+		shard_idx: usize,
+		shard_count: usize,
 		conn_str: String,
 		subscriptions: Arc<HashMap<String, Subscription>>,
 		queue_subscriptions: Arc<HashMap<String, Subscription>>,
-		client: Arc<Mutex<Option<tokio_postgres::Client>>>,
+		shard: Arc<ListenShard>,
 		ready_tx: tokio::sync::watch::Sender<bool>,
 		ssl_root_cert_path: Option<PathBuf>,
 		ssl_client_cert_path: Option<PathBuf>,
@@ -221,7 +280,7 @@ impl PostgresDriver {
 		loop {
 			match tokio_postgres::connect(&conn_str, tls.clone()).await {
 				Result::Ok((new_client, conn)) => {
-					tracing::debug!("postgres listen connection established");
+					tracing::debug!(shard=%shard_idx, "postgres listen connection established");
 					// Reset backoff on successful connection
 					backoff = Backoff::default();
 
@@ -230,24 +289,33 @@ impl PostgresDriver {
 					let subscriptions_clone = subscriptions.clone();
 					let queue_subscriptions_clone = queue_subscriptions.clone();
 					let poll_handle = tokio::spawn(async move {
-						Self::poll_connection(conn, subscriptions_clone, queue_subscriptions_clone)
-							.await;
+						Self::poll_connection(
+							shard_idx,
+							conn,
+							subscriptions_clone,
+							queue_subscriptions_clone,
+						)
+						.await;
 					});
 
-					// Get regular channels to re-subscribe to
+					// Get regular channels owned by this shard to re-subscribe to
 					let mut channels = Vec::new();
 					subscriptions
 						.iter_async(|k, _| {
-							channels.push(k.clone());
+							if shard_index(k, shard_count) == shard_idx {
+								channels.push(k.clone());
+							}
 							true
 						})
 						.await;
 
-					// Get queue wakeup channels to re-subscribe to
+					// Get queue wakeup channels owned by this shard to re-subscribe to
 					let mut queue_channels = Vec::new();
 					queue_subscriptions
 						.iter_async(|k, _| {
-							queue_channels.push(k.clone());
+							if shard_index(k, shard_count) == shard_idx {
+								queue_channels.push(k.clone());
+							}
 							true
 						})
 						.await;
@@ -255,6 +323,7 @@ impl PostgresDriver {
 					let needs_resubscribe = !channels.is_empty() || !queue_channels.is_empty();
 					if needs_resubscribe {
 						tracing::debug!(
+							shard=%shard_idx,
 							regular_channels = channels.len(),
 							queue_channels = queue_channels.len(),
 							"re-subscribing to channels after reconnection"
@@ -275,20 +344,20 @@ impl PostgresDriver {
 
 					// Update the client reference and signal ready
 					// Do this AFTER re-subscribing to ensure LISTEN is complete
-					*client.lock().await = Some(new_client);
+					*shard.client.lock().await = Some(new_client);
 					let _ = ready_tx.send(true);
 
 					// Wait for the polling task to complete (when the connection closes)
 					let _ = poll_handle.await;
 
 					// Clear the client reference on disconnect
-					*client.lock().await = None;
+					*shard.client.lock().await = None;
 
 					// Notify that client is disconnected
 					let _ = ready_tx.send(false);
 				}
 				Result::Err(e) => {
-					tracing::error!(?e, "failed to connect to postgres, retrying");
+					tracing::error!(?e, shard=%shard_idx, "failed to connect to postgres, retrying");
 					backoff.tick().await;
 				}
 			}
@@ -297,6 +366,7 @@ impl PostgresDriver {
 
 	/// Polls the connection for notifications until it closes or errors
 	async fn poll_connection<T>(
+		shard_idx: usize,
 		mut conn: tokio_postgres::Connection<tokio_postgres::Socket, T>,
 		subscriptions: Arc<HashMap<String, Subscription>>,
 		queue_subscriptions: Arc<HashMap<String, Subscription>>,
@@ -306,7 +376,10 @@ impl PostgresDriver {
 		loop {
 			match poll_fn(|cx| conn.poll_message(cx)).await {
 				Some(std::result::Result::Ok(AsyncMessage::Notification(note))) => {
-					tracing::trace!(channel = %note.channel(), "received notification");
+					tracing::trace!(channel = %note.channel(), shard=%shard_idx, "received notification");
+					metrics::POSTGRES_NOTIFICATION_COUNT
+						.with_label_values(&[&shard_idx.to_string()])
+						.inc();
 					if let Some(sub) = subscriptions.get_async(note.channel()).await {
 						let bytes = match BASE64.decode(note.payload()) {
 							std::result::Result::Ok(b) => b,
@@ -328,24 +401,24 @@ impl PostgresDriver {
 					// Ignore other async messages
 				}
 				Some(std::result::Result::Err(err)) => {
-					tracing::error!(?err, "postgres connection error");
+					tracing::error!(?err, shard=%shard_idx, "postgres connection error");
 					break;
 				}
 				None => {
-					tracing::warn!("postgres connection closed");
+					tracing::warn!(shard=%shard_idx, "postgres connection closed");
 					break;
 				}
 			}
 		}
 	}
 
-	/// Wait for the client to be connected
-	async fn wait_for_client(&self) -> Result<()> {
-		let mut ready_rx = self.client_ready.clone();
+	/// Wait for the shard's client to be connected
+	async fn wait_for_client(shard: &ListenShard) -> Result<()> {
+		let mut ready_rx = shard.client_ready.clone();
 		tokio::time::timeout(tokio::time::Duration::from_secs(5), async {
 			loop {
 				// Check if client is already available
-				if self.client.lock().await.is_some() {
+				if shard.client.lock().await.is_some() {
 					return Ok(());
 				}
 
@@ -360,6 +433,11 @@ impl PostgresDriver {
 		.context("timeout waiting for postgres client connection")?
 	}
 
+	/// Returns the listen shard that owns a channel.
+	fn shard(&self, channel: &str) -> &ListenShard {
+		&self.shards[shard_index(channel, self.shards.len())]
+	}
+
 	fn hash_subject(&self, subject: &str) -> String {
 		// Postgres channel names have a 64 character limit
 		// Hash the subject to ensure it fits
@@ -380,6 +458,11 @@ impl PostgresDriver {
 		format!("ups_q_{}_{}", subject_hash, queue_hash)
 	}
 
+	/// Returns the NOTIFY channel name for a durable (subject, group) pair.
+	fn durable_channel(&self, subject_hash: &str, group_hash: &str) -> String {
+		format!("ups_d_{}_{}", subject_hash, group_hash)
+	}
+
 	/// Inserts messages into the queue table and notifies active queue subscribers.
 	async fn publish_to_queues(&self, subject: &str, payload: &[u8]) -> Result<()> {
 		let subject_hash = self.hash_subject(subject);
@@ -421,6 +504,50 @@ impl PostgresDriver {
 		Ok(())
 	}
 
+	/// Sets up a shared LISTEN/broadcast channel for wakeup signals on a queue or durable channel.
+	async fn listen_queue_channel(
+		&self,
+		channel: String,
+	) -> (broadcast::Receiver<Vec<u8>>, tokio_util::sync::DropGuard) {
+		match self.queue_subscriptions.entry_async(channel.clone()).await {
+			scc::hash_map::Entry::Occupied(existing_sub) => {
+				let rx = existing_sub.tx.subscribe();
+				let drop_guard = self.spawn_queue_subscription_cleanup_task(
+					channel.clone(),
+					existing_sub.tx.clone(),
+				);
+				(rx, drop_guard)
+			}
+			scc::hash_map::Entry::Vacant(e) => {
+				let (tx, rx) = tokio::sync::broadcast::channel(1024);
+				let subscription = Subscription::new(tx.clone());
+
+				e.insert_entry(subscription.clone());
+
+				if let Some(client) = &*self.shard(&channel).client.lock().await {
+					match client
+						.execute(&format!("LISTEN \"{}\"", channel), &[])
+						.instrument(tracing::trace_span!("pg_listen_queue"))
+						.await
+					{
+						Result::Ok(_) => {
+							tracing::debug!(%channel, "successfully subscribed to queue channel");
+						}
+						Result::Err(e) => {
+							tracing::warn!(?e, %channel, "failed to LISTEN queue channel, will retry on reconnection");
+						}
+					}
+				} else {
+					tracing::debug!(%channel, "client not connected, will LISTEN queue channel on reconnection");
+				}
+
+				let drop_guard =
+					self.spawn_queue_subscription_cleanup_task(channel.clone(), tx.clone());
+				(rx, drop_guard)
+			}
+		}
+	}
+
 	fn spawn_subscription_cleanup_task(
 		&self,
 		subject_hash: String,
@@ -433,7 +560,7 @@ impl PostgresDriver {
 		tokio::spawn(async move {
 			token.cancelled().await;
 			if tx.receiver_count() == 0 {
-				if let Some(client) = &*driver.client.lock().await {
+				if let Some(client) = &*driver.shard(&subject_hash).client.lock().await {
 					let sql = format!("UNLISTEN \"{}\"", subject_hash);
 					if let Err(err) = client.execute(sql.as_str(), &[]).await {
 						tracing::warn!(?err, %subject_hash, "failed to UNLISTEN channel");
@@ -461,7 +588,7 @@ impl PostgresDriver {
 		tokio::spawn(async move {
 			token.cancelled().await;
 			if tx.receiver_count() == 0 {
-				if let Some(client) = &*driver.client.lock().await {
+				if let Some(client) = &*driver.shard(&channel).client.lock().await {
 					let sql = format!("UNLISTEN \"{}\"", channel);
 
 					if let Err(err) = client.execute(sql.as_str(), &[]).await {
@@ -478,6 +605,26 @@ impl PostgresDriver {
 	}
 }
 
+/// Maps a channel to one of `shard_count` listen connections using jump consistent hashing, so
+/// changing the number of connections only moves the minimum number of channels.
+///
+/// See https://arxiv.org/abs/1406.2294
+fn shard_index(channel: &str, shard_count: usize) -> usize {
+	let mut hasher = DefaultHasher::new();
+	channel.hash(&mut hasher);
+	let mut key = hasher.finish();
+
+	let mut b: i64 = -1;
+	let mut j: i64 = 0;
+	while j < shard_count as i64 {
+		b = j;
+		key = key.wrapping_mul(2862933555777941757).wrapping_add(1);
+		j = ((b + 1) as f64 * ((1u64 << 31) as f64 / ((key >> 33) + 1) as f64)) as i64;
+	}
+
+	b.max(0) as usize
+}
+
 #[async_trait]
 impl PubSubDriver for PostgresDriver {
 	async fn subscribe(
@@ -520,7 +667,7 @@ impl PubSubDriver for PostgresDriver {
 				// This only needs to be done once per channel
 				// Try to LISTEN if client is available, but don't fail if disconnected
 				// The reconnection logic will handle re-subscribing
-				if let Some(client) = &*self.client.lock().await {
+				if let Some(client) = &*self.shard(&hashed).client.lock().await {
 					match client
 						.execute(&format!("LISTEN \"{hashed}\""), &[])
 						.instrument(tracing::trace_span!("pg_listen"))
@@ -571,43 +718,7 @@ impl PubSubDriver for PostgresDriver {
 		}
 
 		// Set up a shared LISTEN/broadcast channel for the wakeup signal
-		let (rx, drop_guard) = match self.queue_subscriptions.entry_async(channel.clone()).await {
-			scc::hash_map::Entry::Occupied(existing_sub) => {
-				let rx = existing_sub.tx.subscribe();
-				let drop_guard = self.spawn_queue_subscription_cleanup_task(
-					channel.clone(),
-					existing_sub.tx.clone(),
-				);
-				(rx, drop_guard)
-			}
-			scc::hash_map::Entry::Vacant(e) => {
-				let (tx, rx) = tokio::sync::broadcast::channel(1024);
-				let subscription = Subscription::new(tx.clone());
-
-				e.insert_entry(subscription.clone());
-
-				if let Some(client) = &*self.client.lock().await {
-					match client
-						.execute(&format!("LISTEN \"{}\"", channel), &[])
-						.instrument(tracing::trace_span!("pg_listen_queue"))
-						.await
-					{
-						Result::Ok(_) => {
-							tracing::debug!(%channel, "successfully subscribed to queue channel");
-						}
-						Result::Err(e) => {
-							tracing::warn!(?e, %channel, "failed to LISTEN queue channel, will retry on reconnection");
-						}
-					}
-				} else {
-					tracing::debug!(%channel, "client not connected, will LISTEN queue channel on reconnection");
-				}
-
-				let drop_guard =
-					self.spawn_queue_subscription_cleanup_task(channel.clone(), tx.clone());
-				(rx, drop_guard)
-			}
-		};
+		let (rx, drop_guard) = self.listen_queue_channel(channel).await;
 
 		// Spawn heartbeat task to keep the registration alive
 		let pool = self.pool.clone();
@@ -667,7 +778,7 @@ impl PubSubDriver for PostgresDriver {
 		// Wait for listen connection to be ready first if this channel has subscribers
 		// This ensures that if we're reconnecting, the LISTEN is re-registered before NOTIFY
 		if self.subscriptions.contains_async(&hashed).await {
-			self.wait_for_client().await?;
+			Self::wait_for_client(self.shard(&hashed)).await?;
 		}
 
 		// Retry getting a connection from the pool with backoff in case the connection is
@@ -730,6 +841,149 @@ impl PubSubDriver for PostgresDriver {
 		}
 	}
 
+	async fn publish_many(&self, messages: &[(&str, &[u8])]) -> Result<()> {
+		let mut channels = Vec::with_capacity(messages.len());
+		let mut encoded = Vec::with_capacity(messages.len());
+		for (subject, payload) in messages {
+			let hashed = self.hash_subject(subject);
+
+			// See `publish`
+			if self.subscriptions.contains_async(&hashed).await {
+				Self::wait_for_client(self.shard(&hashed)).await?;
+			}
+
+			channels.push(hashed);
+			encoded.push(BASE64.encode(payload));
+		}
+
+		let conn = self
+			.pool
+			.get()
+			.await
+			.context("failed to get connection for batch publish")?;
+
+		// Send every notification in a single statement. Notifications are delivered in order when
+		// the statement's transaction commits.
+		conn.execute(
+			"SELECT pg_notify(channel, payload) \
+			 FROM unnest($1::text[], $2::text[]) WITH ORDINALITY AS t(channel, payload, idx) \
+			 ORDER BY idx",
+			&[&channels, &encoded],
+		)
+		.instrument(tracing::trace_span!("pg_notify_many"))
+		.await
+		.context("failed to notify batch")?;
+
+		for (subject, payload) in messages {
+			if let Err(e) = self.publish_to_queues(subject, payload).await {
+				tracing::warn!(?e, %subject, "failed to publish to queue subscribers");
+			}
+		}
+
+		Ok(())
+	}
+
+	async fn publish_durable(&self, subject: &str, payload: &[u8]) -> Result<()> {
+		let subject_hash = self.hash_subject(subject);
+
+		let conn = self
+			.pool
+			.get()
+			.await
+			.context("failed to get connection for durable publish")?;
+
+		// Store a copy of the message for every group of this subject
+		let rows = conn
+			.query(
+				"INSERT INTO ups_durable_messages (subject_hash, group_hash, payload) \
+				 SELECT subject_hash, group_hash, $2 FROM ups_durable_groups \
+				 WHERE subject_hash = $1 \
+				 RETURNING group_hash",
+				&[&subject_hash, &payload],
+			)
+			.await
+			.context("failed to insert durable messages")?;
+
+		for row in rows {
+			let group_hash: String = row.get(0);
+			let channel = self.durable_channel(&subject_hash, &group_hash);
+
+			conn.execute(&format!("NOTIFY \"{}\"", channel), &[])
+				.await
+				.context("failed to notify durable channel")?;
+		}
+
+		Ok(())
+	}
+
+	async fn subscribe_durable(
+		&self,
+		subject: &str,
+		group: &str,
+	) -> Result<DurableSubscriberDriverHandle> {
+		let subject_hash = self.hash_subject(subject);
+		let group_hash = self.hash_queue(group);
+		let channel = self.durable_channel(&subject_hash, &group_hash);
+
+		// Register the group so publishers store messages for it while no members are connected
+		{
+			let conn = self
+				.pool
+				.get()
+				.await
+				.context("failed to get connection for durable subscribe")?;
+			conn.execute(
+				"INSERT INTO ups_durable_groups (subject_hash, group_hash) VALUES ($1, $2) \
+				 ON CONFLICT (subject_hash, group_hash) DO UPDATE SET last_seen_at = NOW()",
+				&[&subject_hash, &group_hash],
+			)
+			.await
+			.context("failed to register durable group")?;
+		}
+
+		let (rx, drop_guard) = self.listen_queue_channel(channel).await;
+
+		// Spawn heartbeat task to keep the group from being garbage collected
+		let pool = self.pool.clone();
+		let heartbeat_token = tokio_util::sync::CancellationToken::new();
+		let heartbeat_drop_guard = heartbeat_token.clone().drop_guard();
+		let subject_hash2 = subject_hash.clone();
+		let group_hash2 = group_hash.clone();
+		tokio::spawn(async move {
+			let mut interval = tokio::time::interval(DURABLE_GROUP_HEARTBEAT_INTERVAL);
+			interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+			loop {
+				tokio::select! {
+					_ = heartbeat_token.cancelled() => break,
+					_ = interval.tick() => {
+						if let Ok(conn) = pool.get().await {
+							if let Err(e) = conn
+								.execute(
+									"UPDATE ups_durable_groups SET last_seen_at = NOW() \
+									 WHERE subject_hash = $1 AND group_hash = $2",
+									&[&subject_hash2, &group_hash2],
+								)
+								.await
+							{
+								tracing::warn!(?e, "failed to heartbeat durable group");
+							}
+						}
+					}
+				}
+			}
+		});
+
+		Ok(Box::new(PostgresDurableSubscriber {
+			subject_hash,
+			group_hash,
+			pool: self.pool.clone(),
+			rx: Some(rx),
+			_drop_guard: drop_guard,
+			_heartbeat_drop_guard: heartbeat_drop_guard,
+		}))
+	}
+
 	async fn flush(&self) -> Result<()> {
 		Ok(())
 	}
@@ -857,3 +1111,108 @@ impl Drop for PostgresQueueSubscriber {
 		});
 	}
 }
+
+pub struct PostgresDurableSubscriber {
+	subject_hash: String,
+	group_hash: String,
+	pool: Arc<Pool>,
+	rx: Option<tokio::sync::broadcast::Receiver<Vec<u8>>>,
+	_drop_guard: tokio_util::sync::DropGuard,
+	_heartbeat_drop_guard: tokio_util::sync::DropGuard,
+}
+
+impl PostgresDurableSubscriber {
+	/// Claims one visible message for this group and hides it from other members until its ack
+	/// wait expires.
+	async fn claim_message(&self) -> Result<Option<(i64, Vec<u8>)>> {
+		let conn = self
+			.pool
+			.get()
+			.await
+			.context("failed to get connection for durable claim")?;
+
+		let rows = conn
+			.query(
+				"WITH claimed AS ( \
+				     SELECT id FROM ups_durable_messages \
+				     WHERE subject_hash = $1 AND group_hash = $2 AND visible_at <= NOW() \
+				     ORDER BY id \
+				     LIMIT 1 \
+				     FOR UPDATE SKIP LOCKED \
+				 ) \
+				 UPDATE ups_durable_messages \
+				 SET visible_at = NOW() + ($3::bigint * INTERVAL '1 second') \
+				 WHERE id IN (SELECT id FROM claimed) \
+				 RETURNING id, payload",
+				&[
+					&self.subject_hash,
+					&self.group_hash,
+					&(DURABLE_ACK_WAIT.as_secs() as i64),
+				],
+			)
+			.await
+			.context("failed to claim durable message")?;
+
+		Ok(rows
+			.into_iter()
+			.next()
+			.map(|row| (row.get::<_, i64>(0), row.get::<_, Vec<u8>>(1))))
+	}
+}
+
+#[async_trait]
+impl DurableSubscriberDriver for PostgresDurableSubscriber {
+	async fn next(&mut self) -> Result<DurableDriverOutput> {
+		loop {
+			if let Some((id, payload)) = self.claim_message().await? {
+				return Ok(DurableDriverOutput::Message {
+					payload,
+					ack: Box::new(PostgresDurableAck {
+						pool: self.pool.clone(),
+						id,
+					}),
+				});
+			}
+
+			let rx = match self.rx.as_mut() {
+				Some(rx) => rx,
+				None => return Ok(DurableDriverOutput::Unsubscribed),
+			};
+
+			// Wait for a wakeup notification or poll again for expired ack waits
+			match tokio::time::timeout(DURABLE_POLL_INTERVAL, rx.recv()).await {
+				Err(_) | std::result::Result::Ok(std::result::Result::Ok(_)) => {}
+				std::result::Result::Ok(Err(tokio::sync::broadcast::error::RecvError::Closed)) => {
+					return Ok(DurableDriverOutput::Unsubscribed);
+				}
+				std::result::Result::Ok(Err(tokio::sync::broadcast::error::RecvError::Lagged(
+					_,
+				))) => {}
+			}
+		}
+	}
+}
+
+struct PostgresDurableAck {
+	pool: Arc<Pool>,
+	id: i64,
+}
+
+#[async_trait]
+impl DurableAck for PostgresDurableAck {
+	async fn ack(&self) -> Result<()> {
+		let conn = self
+			.pool
+			.get()
+			.await
+			.context("failed to get connection for durable ack")?;
+		conn.execute(
+			"DELETE FROM ups_durable_messages WHERE id = $1",
+			&[&self.id],
+		)
+		.await
+		.context("failed to ack durable message")?;
+
+		Ok(())
+	}
+}
@@ -6,6 +6,23 @@ use serde::{Deserialize, Serialize};
 pub enum Ups {
 	#[error("request_timeout", "Request timeout.")]
 	RequestTimeout,
+	#[error("no_responders", "No subscribers for request.")]
+	NoResponders,
+	#[error(
+		"unsubscribed",
+		"Reply subscription closed before a reply was received."
+	)]
+	Unsubscribed,
+	#[error(
+		"not_a_request",
+		"Message was not sent as a request and cannot be responded to."
+	)]
+	NotARequest,
 	#[error("publish_failed", "Failed to publish message after retries")]
 	PublishFailed,
+	#[error(
+		"durable_message_too_large",
+		"Durable message exceeds the max message size of the driver."
+	)]
+	DurableMessageTooLarge,
 }
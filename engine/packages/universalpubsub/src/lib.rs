@@ -6,5 +6,7 @@ pub mod pubsub;
 pub mod subject;
 
 pub use driver::*;
-pub use pubsub::{Message, NextOutput, PubSub, Subscriber};
+pub use pubsub::{
+	DurableMessage, DurableSubscriber, Message, NextOutput, PubSub, Response, Subscriber,
+};
 pub use subject::{InboxSubject, Subject};
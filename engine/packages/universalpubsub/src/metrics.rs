@@ -31,6 +31,12 @@ lazy_static::lazy_static! {
 		"Number of subscription entries in the postgres driver.",
 		*REGISTRY
 	).unwrap();
+	pub static ref POSTGRES_NOTIFICATION_COUNT: IntCounterVec = register_int_counter_vec_with_registry!(
+		"ups_postgres_notification_count",
+		"Total number of notifications received by each postgres listen connection.",
+		&["shard"],
+		*REGISTRY
+	).unwrap();
 
 	// Message metrics
 	pub static ref MESSAGE_RECV_COUNT: IntCounterVec = register_int_counter_vec_with_registry!(
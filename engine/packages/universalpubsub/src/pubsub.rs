@@ -11,7 +11,10 @@ use uuid::Uuid;
 use rivet_util::backoff::Backoff;
 
 use crate::chunking::{ChunkTracker, FastPath, encode_chunk, split_payload_into_chunks};
-use crate::driver::{PubSubDriverHandle, PublishOpts, SubscriberDriverHandle};
+use crate::driver::{
+	DurableAckHandle, DurableSubscriberDriverHandle, PubSubDriverHandle, PublishOpts,
+	SubscriberDriverHandle,
+};
 use crate::errors;
 use crate::metrics;
 use crate::subject::{InboxSubject, Subject};
@@ -275,6 +278,193 @@ impl PubSub {
 		Ok(message_id)
 	}
 
+	/// Publishes multiple payloads to the same subject. See `publish_many`.
+	#[tracing::instrument(skip_all, fields(%subject, count = payloads.len()))]
+	pub async fn publish_batch<T: Subject + Clone>(
+		&self,
+		subject: T,
+		payloads: &[&[u8]],
+		opts: PublishOpts,
+	) -> Result<Vec<Uuid>> {
+		self.publish_many(
+			payloads.iter().map(|payload| (subject.clone(), *payload)),
+			opts,
+		)
+		.await
+	}
+
+	/// Publishes multiple messages in order, using as few driver round trips as possible.
+	///
+	/// If publishing fails partway through, the whole batch is retried so some messages may be
+	/// delivered more than once.
+	#[tracing::instrument(skip_all, fields(?opts))]
+	pub async fn publish_many<'a, T: Subject>(
+		&self,
+		messages: impl IntoIterator<Item = (T, &'a [u8])>,
+		opts: PublishOpts,
+	) -> Result<Vec<Uuid>> {
+		let mut message_ids = Vec::new();
+		let mut driver_messages = Vec::new();
+
+		for (subject, payload) in messages {
+			let message_id = Uuid::new_v4();
+			message_ids.push(message_id);
+
+			let chunks = split_payload_into_chunks(
+				payload,
+				self.driver.max_message_size(),
+				message_id,
+				None,
+				None,
+			)?;
+			let chunk_count = chunks.len() as u32;
+
+			let use_local = self
+				.should_use_local_subscriber(&subject, opts.behavior)
+				.await;
+
+			let subject_cow = subject.as_cow();
+			let subject_root = subject.subject_root();
+			let subject_root = subject_root.as_deref().unwrap_or("unknown");
+
+			for (chunk_idx, chunk_payload) in chunks.into_iter().enumerate() {
+				let encoded = encode_chunk(
+					chunk_payload,
+					chunk_idx as u32,
+					chunk_count,
+					message_id,
+					None,
+					None,
+				)?;
+
+				if use_local {
+					if let Some(sender) = self.local_subscribers.get_async(&*subject_cow).await {
+						let _ = sender.send(encoded);
+					} else {
+						tracing::warn!(%subject, "local subscriber disappeared");
+						break;
+					}
+				} else {
+					driver_messages.push((subject_cow.to_string(), encoded));
+				}
+			}
+
+			metrics::MESSAGE_SEND_COUNT
+				.with_label_values(&[if use_local { "local" } else { "driver" }, subject_root])
+				.inc();
+		}
+
+		if driver_messages.is_empty() {
+			return Ok(message_ids);
+		}
+
+		let driver_messages = driver_messages
+			.iter()
+			.map(|(subject, payload)| (subject.as_str(), payload.as_slice()))
+			.collect::<Vec<_>>();
+
+		let mut backoff = Backoff::default();
+		loop {
+			match self.driver.publish_many(&driver_messages).await {
+				Result::Ok(_) => break,
+				Err(err) if !backoff.tick().await => {
+					metrics::PUBLISH_RETRY_TOTAL
+						.with_label_values(&["batch"])
+						.inc();
+					tracing::warn!(?err, "error publishing batch, cannot retry again");
+					return Err(errors::Ups::PublishFailed.build().into());
+				}
+				Err(err) => {
+					metrics::PUBLISH_RETRY_TOTAL
+						.with_label_values(&["batch"])
+						.inc();
+					tracing::debug!(?err, "error publishing batch, retrying");
+				}
+			}
+		}
+
+		Ok(message_ids)
+	}
+
+	/// Publishes a message that is delivered at least once to every durable group subscribed to
+	/// the subject, including groups with no members connected at the time of publishing. See
+	/// `subscribe_durable`.
+	///
+	/// Durable messages are never delivered to regular subscribers and must fit in a single chunk.
+	#[tracing::instrument(skip_all, fields(%subject, message_id = tracing::field::Empty))]
+	pub async fn publish_durable(&self, subject: impl Subject, payload: &[u8]) -> Result<Uuid> {
+		let message_id = Uuid::new_v4();
+		tracing::Span::current().record("message_id", message_id.to_string());
+
+		// Durable messages are acknowledged as a whole, so they cannot be split into chunks
+		let mut chunks = split_payload_into_chunks(
+			payload,
+			self.driver.max_message_size(),
+			message_id,
+			None,
+			None,
+		)?;
+		if chunks.len() != 1 {
+			return Err(errors::Ups::DurableMessageTooLarge.build().into());
+		}
+		let encoded = encode_chunk(chunks.remove(0), 0, 1, message_id, None, None)?;
+
+		let subject_cow = subject.as_cow();
+		let subject_root = subject.subject_root();
+		let subject_root = subject_root.as_deref().unwrap_or("unknown");
+
+		let mut backoff = Backoff::default();
+		loop {
+			match self.driver.publish_durable(&subject_cow, &encoded).await {
+				Result::Ok(_) => break,
+				Err(err) if !backoff.tick().await => {
+					metrics::PUBLISH_RETRY_TOTAL
+						.with_label_values(&[subject_root])
+						.inc();
+					tracing::warn!(?err, "error publishing durable message, cannot retry again");
+					return Err(errors::Ups::PublishFailed.build().into());
+				}
+				Err(err) => {
+					metrics::PUBLISH_RETRY_TOTAL
+						.with_label_values(&[subject_root])
+						.inc();
+					tracing::debug!(?err, "error publishing durable message, retrying");
+				}
+			}
+		}
+
+		metrics::MESSAGE_SEND_COUNT
+			.with_label_values(&["durable", subject_root])
+			.inc();
+
+		Ok(message_id)
+	}
+
+	/// Subscribes to durable messages on the subject as a member of `group`.
+	///
+	/// Every group receives each durable message published after the group was first subscribed
+	/// to, even if it had no members connected at the time. Messages are load balanced between the
+	/// members of a group. Messages that are not acknowledged with `DurableMessage::ack` are
+	/// redelivered, so handlers must be idempotent.
+	#[tracing::instrument(skip_all, fields(%subject, %group))]
+	pub async fn subscribe_durable<T: Subject>(
+		&self,
+		subject: T,
+		group: &str,
+	) -> Result<DurableSubscriber> {
+		let driver = self
+			.driver
+			.subscribe_durable(&subject.as_cow(), group)
+			.await?;
+
+		Ok(DurableSubscriber {
+			driver,
+			pubsub: self.clone(),
+			subject: subject.to_string(),
+			root_subject: subject.subject_root().map(|x| x.to_string()),
+		})
+	}
+
 	#[tracing::instrument(skip_all)]
 	pub async fn flush(&self) -> Result<()> {
 		self.driver.flush().await
@@ -297,6 +487,31 @@ impl PubSub {
 			.await
 	}
 
+	/// Sends a request and waits for a single reply sent with `Message::respond`.
+	///
+	/// Unlike `request_with_timeout`, fails with `ups.no_responders` if nothing is subscribed to the
+	/// subject and `ups.unsubscribed` if the reply subscription closes, instead of returning them as
+	/// `NextOutput` variants.
+	#[tracing::instrument(skip_all, fields(%subject))]
+	pub async fn request_response(
+		&self,
+		subject: impl Subject,
+		payload: &[u8],
+		timeout: Duration,
+	) -> Result<Response> {
+		match self
+			.request_with_timeout_inner(subject, payload, timeout)
+			.await?
+		{
+			NextOutput::Message(msg) => Ok(Response {
+				message_id: msg.message_id,
+				payload: msg.payload,
+			}),
+			NextOutput::NoResponders => Err(errors::Ups::NoResponders.build().into()),
+			NextOutput::Unsubscribed => Err(errors::Ups::Unsubscribed.build().into()),
+		}
+	}
+
 	#[tracing::instrument(skip_all, fields(%subject))]
 	pub async fn request_with_timeout_inner<T: Subject>(
 		&self,
@@ -513,6 +728,82 @@ impl Drop for Subscriber {
 	}
 }
 
+pub struct DurableSubscriber {
+	driver: DurableSubscriberDriverHandle,
+	pubsub: PubSub,
+	subject: String,
+	root_subject: Option<String>,
+}
+
+impl DurableSubscriber {
+	/// Returns the next durable message, or `None` if unsubscribed.
+	#[tracing::instrument(skip_all, fields(subject=%self.subject, message_id = tracing::field::Empty))]
+	pub async fn next(&mut self) -> Result<Option<DurableMessage>> {
+		loop {
+			let (payload, ack) = match self.driver.next().await? {
+				DurableDriverOutput::Message { payload, ack } => (payload, ack),
+				DurableDriverOutput::Unsubscribed => return Ok(None),
+			};
+
+			let decoded = match self.pubsub.chunk_tracker.try_process_chunk_fast(&payload) {
+				std::result::Result::Ok(FastPath::Decoded(decoded)) => decoded,
+				std::result::Result::Ok(FastPath::Multi(_)) => {
+					tracing::warn!("durable message has multiple chunks, dropping");
+					ack.ack().await?;
+					continue;
+				}
+				std::result::Result::Err(e) => {
+					tracing::warn!(?e, "failed to decode durable message, dropping");
+					ack.ack().await?;
+					continue;
+				}
+			};
+
+			let root_subject = self.root_subject.as_deref().unwrap_or("unknown");
+			let secs =
+				rivet_util::timestamp::now().saturating_sub(decoded.timestamp) as f64 / 1000.0;
+			metrics::MESSAGE_RECV_LAG
+				.with_label_values(&[root_subject])
+				.observe(secs);
+			metrics::MESSAGE_RECV_COUNT
+				.with_label_values(&[root_subject])
+				.inc();
+
+			tracing::Span::current().record("message_id", decoded.message_id.to_string());
+
+			return Ok(Some(DurableMessage {
+				message_id: decoded.message_id,
+				payload: decoded.payload,
+				ack,
+			}));
+		}
+	}
+}
+
+/// A message received from a durable subscription. It is redelivered unless acknowledged.
+pub struct DurableMessage {
+	pub message_id: Uuid,
+	pub payload: Vec<u8>,
+	ack: DurableAckHandle,
+}
+
+impl DurableMessage {
+	/// Acknowledges the message so it is not redelivered to this group.
+	#[tracing::instrument(skip_all, fields(message_id=?self.message_id))]
+	pub async fn ack(self) -> Result<()> {
+		self.ack.ack().await
+	}
+}
+
+// Output from durable drivers
+pub enum DurableDriverOutput {
+	Message {
+		payload: Vec<u8>,
+		ack: DurableAckHandle,
+	},
+	Unsubscribed,
+}
+
 // Output from drivers (raw binary messages)
 pub enum DriverOutput {
 	Message { subject: String, payload: Vec<u8> },
@@ -536,6 +827,13 @@ impl From<NextOutput> for Option<Message> {
 	}
 }
 
+/// Reply to a request sent with `PubSub::request_response`.
+#[derive(Debug)]
+pub struct Response {
+	pub message_id: Uuid,
+	pub payload: Vec<u8>,
+}
+
 pub struct Message {
 	pub message_id: Uuid,
 	pub pubsub: PubSub,
@@ -566,6 +864,16 @@ impl Message {
 		Ok(())
 	}
 
+	/// Responds to a request sent with `PubSub::request_response`. Unlike `reply`, fails with
+	/// `ups.not_a_request` if the message has no reply subject.
+	pub async fn respond(&self, payload: &[u8]) -> Result<()> {
+		if self.reply.is_none() {
+			return Err(errors::Ups::NotARequest.build().into());
+		}
+
+		self.reply(payload).await
+	}
+
 	pub fn is_request_expired(&self) -> bool {
 		self.request_deadline_at
 			.is_some_and(|deadline_at| rivet_util::timestamp::now() >= deadline_at)
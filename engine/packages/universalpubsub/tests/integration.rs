@@ -134,9 +134,10 @@ async fn test_postgres_no_responders() {
 	};
 	let url = pg.url.read().clone();
 
-	let driver = universalpubsub::driver::postgres::PostgresDriver::connect(url, None, None, None)
-		.await
-		.unwrap();
+	let driver =
+		universalpubsub::driver::postgres::PostgresDriver::connect(url, 1, None, None, None)
+			.await
+			.unwrap();
 	let pubsub = PubSub::new_with_memory_optimization(Arc::new(driver), false);
 
 	test_no_responders(&pubsub).await.unwrap();
@@ -173,9 +174,10 @@ async fn test_postgres_driver_with_memory() {
 	};
 	let url = pg.url.read().clone();
 
-	let driver = universalpubsub::driver::postgres::PostgresDriver::connect(url, None, None, None)
-		.await
-		.unwrap();
+	let driver =
+		universalpubsub::driver::postgres::PostgresDriver::connect(url, 1, None, None, None)
+			.await
+			.unwrap();
 	let pubsub = PubSub::new_with_memory_optimization(Arc::new(driver), true);
 
 	test_inner(&pubsub).await;
@@ -196,9 +198,34 @@ async fn test_postgres_driver_without_memory() {
 	};
 	let url = pg.url.read().clone();
 
-	let driver = universalpubsub::driver::postgres::PostgresDriver::connect(url, None, None, None)
-		.await
-		.unwrap();
+	let driver =
+		universalpubsub::driver::postgres::PostgresDriver::connect(url, 1, None, None, None)
+			.await
+			.unwrap();
+	let pubsub = PubSub::new_with_memory_optimization(Arc::new(driver), false);
+
+	test_inner(&pubsub).await;
+}
+
+#[tokio::test]
+async fn test_postgres_driver_sharded_listen() {
+	setup_logging();
+
+	let test_id = Uuid::new_v4();
+	let (db_config, docker_config) = TestDatabase::Postgres.config(test_id, 1).await.unwrap();
+	let mut docker = docker_config.unwrap();
+	docker.start().await.unwrap();
+	tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+
+	let rivet_config::config::Database::Postgres(pg) = db_config else {
+		unreachable!();
+	};
+	let url = pg.url.read().clone();
+
+	let driver =
+		universalpubsub::driver::postgres::PostgresDriver::connect(url, 4, None, None, None)
+			.await
+			.unwrap();
 	let pubsub = PubSub::new_with_memory_optimization(Arc::new(driver), false);
 
 	test_inner(&pubsub).await;
@@ -233,6 +260,10 @@ async fn test_inner(pubsub: &PubSub) {
 	test_queue_subscribe_multi_group(pubsub).await.unwrap();
 	tracing::info!(duration_ms = ?start.elapsed().as_millis(), "test_queue_subscribe_multi_group completed");
 
+	let start = Instant::now();
+	test_durable_pub_sub(pubsub).await.unwrap();
+	tracing::info!(duration_ms = ?start.elapsed().as_millis(), "test_durable_pub_sub completed");
+
 	let start = Instant::now();
 	test_basic_pub_sub(&pubsub).await.unwrap();
 	tracing::info!(duration_ms = ?start.elapsed().as_millis(), "test_basic_pub_sub completed");
@@ -264,6 +295,10 @@ async fn test_inner(pubsub: &PubSub) {
 	let start = Instant::now();
 	test_large_payloads(&pubsub).await.unwrap();
 	tracing::info!(duration_ms = ?start.elapsed().as_millis(), "test_large_payloads completed");
+
+	let start = Instant::now();
+	test_publish_many(&pubsub).await.unwrap();
+	tracing::info!(duration_ms = ?start.elapsed().as_millis(), "test_publish_many completed");
 }
 
 async fn test_basic_pub_sub(pubsub: &PubSub) -> Result<()> {
@@ -283,6 +318,14 @@ async fn test_basic_pub_sub(pubsub: &PubSub) -> Result<()> {
 	match subscriber.next().await? {
 		NextOutput::Message(msg) => {
 			assert_eq!(msg.payload, message);
+
+			// Plain publishes have no reply subject to respond to
+			let err = msg.respond(b"response").await.err().unwrap();
+			let err = err
+				.downcast_ref::<RivetError>()
+				.expect("expected errors::Ups");
+			assert_eq!(err.group(), "ups");
+			assert_eq!(err.code(), "not_a_request");
 		}
 		NextOutput::Unsubscribed => {
 			panic!("unexpected unsubscribe");
@@ -405,7 +448,7 @@ async fn test_request_response(pubsub: &PubSub) -> Result<()> {
 			ready_tx.send(()).unwrap();
 			while let NextOutput::Message(msg) = sub.next().await.unwrap() {
 				// Reply with the same payload back
-				let _ = msg.reply(&msg.payload).await;
+				let _ = msg.respond(&msg.payload).await;
 			}
 		});
 		ready_rx.await.unwrap();
@@ -427,6 +470,11 @@ async fn test_request_response(pubsub: &PubSub) -> Result<()> {
 		}
 	}
 
+	let response = pubsub
+		.request_response("test.request_response", payload, Duration::from_secs(5))
+		.await?;
+	assert_eq!(response.payload, payload);
+
 	Ok(())
 }
 
@@ -492,6 +540,17 @@ async fn test_no_responders(pubsub: &PubSub) -> Result<()> {
 		NextOutput::Unsubscribed => panic!("unexpected unsubscribe"),
 	}
 
+	let err = pubsub
+		.request_response(&subject, payload, Duration::from_secs(5))
+		.await
+		.err()
+		.unwrap();
+	let err = err
+		.downcast_ref::<RivetError>()
+		.expect("expected errors::Ups");
+	assert_eq!(err.group(), "ups");
+	assert_eq!(err.code(), "no_responders");
+
 	Ok(())
 }
 
@@ -715,6 +774,98 @@ async fn test_queue_subscribe_multi_group(pubsub: &PubSub) -> Result<()> {
 	Ok(())
 }
 
+async fn test_durable_pub_sub(pubsub: &PubSub) -> Result<()> {
+	tracing::info!("testing durable pub/sub");
+
+	let subject = format!("test.durable.{}", Uuid::new_v4());
+
+	// Create both groups, then disconnect group-a's only member
+	let sub_a = pubsub.subscribe_durable(&subject, "group-a").await?;
+	let mut sub_b = pubsub.subscribe_durable(&subject, "group-b").await?;
+	drop(sub_a);
+
+	// Messages published while a group has no members are kept for it
+	let message = b"durable message";
+	pubsub.publish_durable(&subject, message).await?;
+
+	let mut sub_a = pubsub.subscribe_durable(&subject, "group-a").await?;
+
+	for (group, sub) in [("group-a", &mut sub_a), ("group-b", &mut sub_b)] {
+		match tokio::time::timeout(Duration::from_secs(5), sub.next()).await {
+			Ok(Ok(Some(msg))) => {
+				assert_eq!(msg.payload, message, "{group} received wrong payload");
+				msg.ack().await?;
+			}
+			Ok(Ok(None)) => panic!("{group} unexpected unsubscribe"),
+			Ok(Err(e)) => panic!("{group} error: {e}"),
+			Err(_) => panic!("{group} timed out"),
+		}
+	}
+
+	// Acked messages are not redelivered
+	assert!(
+		tokio::time::timeout(Duration::from_millis(500), sub_a.next())
+			.await
+			.is_err(),
+		"acked message was redelivered"
+	);
+
+	Ok(())
+}
+
+async fn test_publish_many(pubsub: &PubSub) -> Result<()> {
+	tracing::info!("testing batch publish");
+
+	let subject_a = format!("test.publish_many.a.{}", Uuid::new_v4());
+	let subject_b = format!("test.publish_many.b.{}", Uuid::new_v4());
+	let mut sub_a = pubsub.subscribe(&subject_a).await?;
+	let mut sub_b = pubsub.subscribe(&subject_b).await?;
+
+	// Includes a payload large enough to be chunked
+	let large = vec![7u8; 12_000];
+	pubsub
+		.publish_batch(
+			&subject_a,
+			&[b"first", large.as_slice(), b"third"],
+			PublishOpts::broadcast(),
+		)
+		.await?;
+	pubsub
+		.publish_many(
+			[
+				(&subject_a, b"fourth".as_slice()),
+				(&subject_b, b"other".as_slice()),
+			],
+			PublishOpts::broadcast(),
+		)
+		.await?;
+	pubsub.flush().await?;
+
+	let expected_a = [
+		b"first".to_vec(),
+		large,
+		b"third".to_vec(),
+		b"fourth".to_vec(),
+	];
+	for expected in expected_a {
+		match tokio::time::timeout(Duration::from_secs(5), sub_a.next()).await {
+			Ok(Ok(NextOutput::Message(msg))) => assert_eq!(msg.payload, expected),
+			Ok(Ok(_)) => panic!("unexpected output"),
+			Ok(Err(e)) => panic!("error: {e}"),
+			Err(_) => panic!("timed out waiting for batch message"),
+		}
+	}
+
+	match tokio::time::timeout(Duration::from_secs(5), sub_b.next()).await {
+		Ok(Ok(NextOutput::Message(msg))) => assert_eq!(msg.payload, b"other"),
+		Ok(Ok(_)) => panic!("unexpected output"),
+		Ok(Err(e)) => panic!("error: {e}"),
+		Err(_) => panic!("timed out waiting for batch message"),
+	}
+
+	Ok(())
+}
+
 async fn test_payload_size(pubsub: &PubSub, size: usize, label: &str) -> Result<()> {
 	tracing::info!(size, label, "testing payload size");
 
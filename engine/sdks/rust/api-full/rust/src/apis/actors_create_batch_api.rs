@@ -0,0 +1,68 @@
+/*
+ * rivet-api-public
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.3.0-rc.12
+ * Contact: developer@rivet.dev
+ * Generated by: https://openapi-generator.tech
+ */
+
+
+use reqwest;
+use serde::{Deserialize, Serialize, de::Error as _};
+use crate::{apis::ResponseContent, models};
+use super::{Error, configuration, ContentType};
+
+
+/// struct for typed errors of method [`actors_create_batch`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ActorsCreateBatchError {
+    UnknownValue(serde_json::Value),
+}
+
+
+/// Actors are grouped by target datacenter and each group is created with a single request to that datacenter. A failure to create one actor does not fail the rest of the batch; each result has either the created actor or the error for that actor.  ## Datacenter Round Trips  2 round trips: - namespace::ops::resolve_for_name_global - [pegboard::workflows::actor] Create actor workflows (includes Epoxy key allocation)  Each target datacenter other than the current one adds a POST /actors/batch round trip. All datacenters are requested in parallel.
+pub async fn actors_create_batch(configuration: &configuration::Configuration, namespace: &str, actors_create_batch_request: models::ActorsCreateBatchRequest) -> Result<models::ActorsCreateBatchResponse, Error<ActorsCreateBatchError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_namespace = namespace;
+    let p_actors_create_batch_request = actors_create_batch_request;
+
+    let uri_str = format!("{}/actors/batch", configuration.base_path);
+    let mut req_builder = configuration.client.request(reqwest::Method::POST, &uri_str);
+
+    req_builder = req_builder.query(&[("namespace", &p_namespace.to_string())]);
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_actors_create_batch_request);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ActorsCreateBatchResponse`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ActorsCreateBatchResponse`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<ActorsCreateBatchError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
@@ -0,0 +1,68 @@
+/*
+ * rivet-api-public
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.3.0-rc.12
+ * Contact: developer@rivet.dev
+ * Generated by: https://openapi-generator.tech
+ */
+
+
+use reqwest;
+use serde::{Deserialize, Serialize, de::Error as _};
+use crate::{apis::ResponseContent, models};
+use super::{Error, configuration, ContentType};
+
+
+/// struct for typed errors of method [`actors_destroy_batch`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ActorsDestroyBatchError {
+    UnknownValue(serde_json::Value),
+}
+
+
+/// Each actor is destroyed the same as `DELETE /actors/{actor_id}`. A failure to destroy one actor does not fail the rest of the batch. A filter destroys a limited number of actors per datacenter, send the request again while `has_more` is true to destroy the rest.  ## Datacenter Round Trips  **If destroying by actor IDs**  2 round trips: - POST /actors/destroy-batch (one per datacenter the actors live in) - [api-peer] namespace::ops::resolve_for_name_global  **If destroying by filter**  2 round trips: - POST /actors/destroy-batch (fanout) - [api-peer] namespace::ops::resolve_for_name_global
+pub async fn actors_destroy_batch(configuration: &configuration::Configuration, namespace: &str, actors_destroy_batch_request: models::ActorsDestroyBatchRequest) -> Result<models::ActorsDestroyBatchResponse, Error<ActorsDestroyBatchError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_namespace = namespace;
+    let p_actors_destroy_batch_request = actors_destroy_batch_request;
+
+    let uri_str = format!("{}/actors/destroy-batch", configuration.base_path);
+    let mut req_builder = configuration.client.request(reqwest::Method::POST, &uri_str);
+
+    req_builder = req_builder.query(&[("namespace", &p_namespace.to_string())]);
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_actors_destroy_batch_request);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ActorsDestroyBatchResponse`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ActorsDestroyBatchResponse`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<ActorsDestroyBatchError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
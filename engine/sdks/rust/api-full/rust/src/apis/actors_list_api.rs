@@ -24,14 +24,18 @@ pub enum ActorsListError {
 
 
 ///  **If key is some & `include_destroyed` is false**   2 round trips:  - namespace::ops::resolve_for_name_global  - GET /actors (multiple DCs based on actor IDs)   This path is optimized because we can read the actor IDs fro the key directly from Epoxy with  stale consistency to determine which datacenter the actor lives in. Under most circumstances,  this means we don't need to fan out to all datacenters (like normal list does).   The reason `include_destroyed` has to be false is Epoxy only stores currently active actors. If  `include_destroyed` is true, we show all previous iterations of actors with the same key.   **Otherwise**   2 round trips:  - namespace::ops::resolve_for_name_global  - GET /actors (fanout)   ## Optimized Alternative Routes
-pub async fn actors_list(configuration: &configuration::Configuration, namespace: &str, name: Option<&str>, key: Option<&str>, actor_ids: Option<&str>, actor_id: Option<Vec<String>>, include_destroyed: Option<bool>, limit: Option<i32>, cursor: Option<&str>) -> Result<models::ActorsListResponse, Error<ActorsListError>> {
+pub async fn actors_list(configuration: &configuration::Configuration, namespace: &str, name: Option<&str>, key: Option<&str>, actor_ids: Option<&str>, actor_id: Option<Vec<String>>, tag: Option<Vec<String>>, include_destroyed: Option<bool>, state: Option<models::ActorState>, created_before: Option<i64>, created_after: Option<i64>, limit: Option<i32>, cursor: Option<&str>) -> Result<models::ActorsListResponse, Error<ActorsListError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_namespace = namespace;
     let p_name = name;
     let p_key = key;
     let p_actor_ids = actor_ids;
     let p_actor_id = actor_id;
+    let p_tag = tag;
     let p_include_destroyed = include_destroyed;
+    let p_state = state;
+    let p_created_before = created_before;
+    let p_created_after = created_after;
     let p_limit = limit;
     let p_cursor = cursor;
 
@@ -54,9 +58,24 @@ pub async fn actors_list(configuration: &configuration::Configuration, namespace
             _ => req_builder.query(&[("actor_id", &param_value.into_iter().map(|p| p.to_string()).collect::<Vec<String>>().join(",").to_string())]),
         };
     }
+    if let Some(ref param_value) = p_tag {
+        req_builder = match "multi" {
+            "multi" => req_builder.query(&param_value.into_iter().map(|p| ("tag".to_owned(), p.to_string())).collect::<Vec<(std::string::String, std::string::String)>>()),
+            _ => req_builder.query(&[("tag", &param_value.into_iter().map(|p| p.to_string()).collect::<Vec<String>>().join(",").to_string())]),
+        };
+    }
     if let Some(ref param_value) = p_include_destroyed {
         req_builder = req_builder.query(&[("include_destroyed", &param_value.to_string())]);
     }
+    if let Some(ref param_value) = p_state {
+        req_builder = req_builder.query(&[("state", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_created_before {
+        req_builder = req_builder.query(&[("created_before", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_created_after {
+        req_builder = req_builder.query(&[("created_after", &param_value.to_string())]);
+    }
     if let Some(ref param_value) = p_limit {
         req_builder = req_builder.query(&[("limit", &param_value.to_string())]);
     }
@@ -0,0 +1,79 @@
+/*
+ * rivet-api-public
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.3.0-rc.12
+ * Contact: developer@rivet.dev
+ * Generated by: https://openapi-generator.tech
+ */
+
+
+use reqwest;
+use serde::{Deserialize, Serialize, de::Error as _};
+use crate::{apis::ResponseContent, models};
+use super::{Error, configuration, ContentType};
+
+
+/// struct for typed errors of method [`actors_logs`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ActorsLogsError {
+    UnknownValue(serde_json::Value),
+}
+
+
+/// With `follow`, responds with a `text/event-stream` instead where each `log` event holds one line as JSON. The event id is the line index, pass it as `after` to resume.
+pub async fn actors_logs(configuration: &configuration::Configuration, actor_id: &str, namespace: &str, after: Option<i64>, limit: Option<i32>, follow: Option<bool>) -> Result<models::ActorsLogsResponse, Error<ActorsLogsError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_actor_id = actor_id;
+    let p_namespace = namespace;
+    let p_after = after;
+    let p_limit = limit;
+    let p_follow = follow;
+
+    let uri_str = format!("{}/actors/{actor_id}/logs", configuration.base_path, actor_id=crate::apis::urlencode(p_actor_id));
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    req_builder = req_builder.query(&[("namespace", &p_namespace.to_string())]);
+    if let Some(ref param_value) = p_after {
+        req_builder = req_builder.query(&[("after", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_limit {
+        req_builder = req_builder.query(&[("limit", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_follow {
+        req_builder = req_builder.query(&[("follow", &param_value.to_string())]);
+    }
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ActorsLogsResponse`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ActorsLogsResponse`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<ActorsLogsError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
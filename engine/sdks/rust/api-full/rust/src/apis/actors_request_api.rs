@@ -0,0 +1,73 @@
+/*
+ * rivet-api-public
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.3.0-rc.12
+ * Contact: developer@rivet.dev
+ * Generated by: https://openapi-generator.tech
+ */
+
+
+use reqwest;
+use serde::{Deserialize, Serialize, de::Error as _};
+use crate::{apis::ResponseContent, models};
+use super::{Error, configuration, ContentType};
+
+
+/// struct for typed errors of method [`actors_request`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ActorsRequestError {
+    UnknownValue(serde_json::Value),
+}
+
+
+/// The request body and headers are forwarded to the actor through the gateway and the actor's response is returned as is. The ID of the actor that handled the request is returned in the `x-rivet-actor` header.
+pub async fn actors_request(configuration: &configuration::Configuration, name: &str, key: &str, namespace: &str, runner_name_selector: &str, body: String, datacenter: Option<&str>, crash_policy: Option<models::CrashPolicy>, path: Option<&str>) -> Result<(), Error<ActorsRequestError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_name = name;
+    let p_key = key;
+    let p_namespace = namespace;
+    let p_runner_name_selector = runner_name_selector;
+    let p_body = body;
+    let p_datacenter = datacenter;
+    let p_crash_policy = crash_policy;
+    let p_path = path;
+
+    let uri_str = format!("{}/actors/by-key/{name}/{key}/request", configuration.base_path, name=crate::apis::urlencode(p_name), key=crate::apis::urlencode(p_key));
+    let mut req_builder = configuration.client.request(reqwest::Method::POST, &uri_str);
+
+    req_builder = req_builder.query(&[("namespace", &p_namespace.to_string())]);
+    req_builder = req_builder.query(&[("runner_name_selector", &p_runner_name_selector.to_string())]);
+    if let Some(ref param_value) = p_datacenter {
+        req_builder = req_builder.query(&[("datacenter", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_crash_policy {
+        req_builder = req_builder.query(&[("crash_policy", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_path {
+        req_builder = req_builder.query(&[("path", &param_value.to_string())]);
+    }
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_body);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+
+    if !status.is_client_error() && !status.is_server_error() {
+        Ok(())
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<ActorsRequestError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
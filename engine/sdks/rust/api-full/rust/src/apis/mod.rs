@@ -112,11 +112,15 @@ impl From<&str> for ContentType {
 }
 
 pub mod actors_create_api;
+pub mod actors_create_batch_api;
 pub mod actors_delete_api;
+pub mod actors_destroy_batch_api;
 pub mod actors_get_or_create_api;
 pub mod actors_kv_get_api;
 pub mod actors_list_api;
 pub mod actors_list_names_api;
+pub mod actors_logs_api;
+pub mod actors_request_api;
 pub mod actors_reschedule_api;
 pub mod actors_sleep_api;
 pub mod datacenters_api;
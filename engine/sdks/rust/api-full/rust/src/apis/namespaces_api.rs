@@ -29,6 +29,13 @@ pub enum NamespacesListError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`namespaces_update`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum NamespacesUpdateError {
+    UnknownValue(serde_json::Value),
+}
+
 
 pub async fn namespaces_create(configuration: &configuration::Configuration, namespaces_create_request: models::NamespacesCreateRequest) -> Result<models::NamespacesCreateResponse, Error<NamespacesCreateError>> {
     // add a prefix to parameters to efficiently prevent name collisions
@@ -131,3 +138,44 @@ pub async fn namespaces_list(configuration: &configuration::Configuration, limit
     }
 }
 
+pub async fn namespaces_update(configuration: &configuration::Configuration, namespace: &str, namespaces_update_request: models::NamespacesUpdateRequest) -> Result<models::NamespacesUpdateResponse, Error<NamespacesUpdateError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_namespace = namespace;
+    let p_namespaces_update_request = namespaces_update_request;
+
+    let uri_str = format!("{}/namespaces/{namespace}", configuration.base_path, namespace=crate::apis::urlencode(p_namespace));
+    let mut req_builder = configuration.client.request(reqwest::Method::PUT, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_namespaces_update_request);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::NamespacesUpdateResponse`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::NamespacesUpdateResponse`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<NamespacesUpdateError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
@@ -40,6 +40,9 @@ pub struct Actor {
     /// Denotes when the actor started waiting for an allocation.
     #[serde(rename = "pending_allocation_ts", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub pending_allocation_ts: Option<Option<i64>>,
+    /// Explains why the actor is waiting for an allocation. Null if the actor is not pending.
+    #[serde(rename = "pending_reason", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub pending_reason: Option<Option<models::PendingReason>>,
     /// Denotes when the actor will try to allocate again. If this is set, the actor will not attempt to allocate until the given timestamp.
     #[serde(rename = "reschedule_ts", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub reschedule_ts: Option<Option<i64>>,
@@ -67,6 +70,7 @@ impl Actor {
             name,
             namespace_id,
             pending_allocation_ts: None,
+            pending_reason: None,
             reschedule_ts: None,
             runner_name_selector,
             sleep_ts: None,
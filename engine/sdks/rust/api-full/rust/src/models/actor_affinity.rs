@@ -0,0 +1,34 @@
+/*
+ * rivet-api-public
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.3.0-rc.12
+ * Contact: developer@rivet.dev
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// ActorAffinity : Soft placement rules for an actor relative to other actors. The allocator honors them when a matching runner has capacity and ignores them otherwise.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ActorAffinity {
+    /// Avoid runners that are already running any of these actors.
+    #[serde(rename = "avoid", skip_serializing_if = "Option::is_none")]
+    pub avoid: Option<Vec<String>>,
+    /// Prefer runners that are already running any of these actors.
+    #[serde(rename = "colocate_with", skip_serializing_if = "Option::is_none")]
+    pub colocate_with: Option<Vec<String>>,
+}
+
+impl ActorAffinity {
+    /// Soft placement rules for an actor relative to other actors. The allocator honors them when a matching runner has capacity and ignores them otherwise.
+    pub fn new() -> ActorAffinity {
+        ActorAffinity {
+            avoid: None,
+            colocate_with: None,
+        }
+    }
+}
+
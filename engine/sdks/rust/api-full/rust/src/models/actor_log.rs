@@ -0,0 +1,44 @@
+/*
+ * rivet-api-public
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.3.0-rc.12
+ * Contact: developer@rivet.dev
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// ActorLog : Line of output forwarded by the runner hosting an actor.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ActorLog {
+    /// Generation of the actor that wrote the line.
+    #[serde(rename = "generation")]
+    pub generation: i32,
+    /// Position of the line in the actor's log. Lines are returned in ascending order.
+    #[serde(rename = "idx")]
+    pub idx: i64,
+    #[serde(rename = "message")]
+    pub message: String,
+    #[serde(rename = "stream")]
+    pub stream: models::ActorLogStream,
+    /// Denotes when the line was written by the actor.
+    #[serde(rename = "ts")]
+    pub ts: i64,
+}
+
+impl ActorLog {
+    /// Line of output forwarded by the runner hosting an actor.
+    pub fn new(generation: i32, idx: i64, message: String, stream: models::ActorLogStream, ts: i64) -> ActorLog {
+        ActorLog {
+            generation,
+            idx,
+            message,
+            stream,
+            ts,
+        }
+    }
+}
+
@@ -0,0 +1,33 @@
+/*
+ * rivet-api-public
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.3.0-rc.12
+ * Contact: developer@rivet.dev
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+/// ActorRateLimit : Token bucket rate limit for requests to a single actor from a single client IP.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ActorRateLimit {
+    /// Maximum number of requests allowed in a burst. Defaults to `requests_per_second`.
+    #[serde(rename = "burst", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub burst: Option<Option<i32>>,
+    /// Sustained number of requests allowed per second.
+    #[serde(rename = "requests_per_second")]
+    pub requests_per_second: i32,
+}
+
+impl ActorRateLimit {
+    /// Token bucket rate limit for requests to a single actor from a single client IP.
+    pub fn new(requests_per_second: i32) -> ActorRateLimit {
+        ActorRateLimit {
+            burst: None,
+            requests_per_second,
+        }
+    }
+}
+
@@ -0,0 +1,48 @@
+/*
+ * rivet-api-public
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.3.0-rc.12
+ * Contact: developer@rivet.dev
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// ActorState : Lifecycle state of an actor, derived from its timestamps.
+/// Lifecycle state of an actor, derived from its timestamps.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum ActorState {
+    #[serde(rename = "pending_allocation")]
+    PendingAllocation,
+    #[serde(rename = "starting")]
+    Starting,
+    #[serde(rename = "running")]
+    Running,
+    #[serde(rename = "sleeping")]
+    Sleeping,
+    #[serde(rename = "destroyed")]
+    Destroyed,
+
+}
+
+impl std::fmt::Display for ActorState {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::PendingAllocation => write!(f, "pending_allocation"),
+            Self::Starting => write!(f, "starting"),
+            Self::Running => write!(f, "running"),
+            Self::Sleeping => write!(f, "sleeping"),
+            Self::Destroyed => write!(f, "destroyed"),
+        }
+    }
+}
+
+impl Default for ActorState {
+    fn default() -> ActorState {
+        Self::PendingAllocation
+    }
+}
+
@@ -0,0 +1,33 @@
+/*
+ * rivet-api-public
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.3.0-rc.12
+ * Contact: developer@rivet.dev
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ActorsCreateBatchError {
+    #[serde(rename = "code")]
+    pub code: String,
+    #[serde(rename = "group")]
+    pub group: String,
+    #[serde(rename = "message")]
+    pub message: String,
+}
+
+impl ActorsCreateBatchError {
+    pub fn new(code: String, group: String, message: String) -> ActorsCreateBatchError {
+        ActorsCreateBatchError {
+            code,
+            group,
+            message,
+        }
+    }
+}
+
@@ -0,0 +1,28 @@
+/*
+ * rivet-api-public
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.3.0-rc.12
+ * Contact: developer@rivet.dev
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ActorsCreateBatchResponse {
+    /// One result per requested actor, in the same order as the request.
+    #[serde(rename = "results")]
+    pub results: Vec<models::ActorsCreateBatchResult>,
+}
+
+impl ActorsCreateBatchResponse {
+    pub fn new(results: Vec<models::ActorsCreateBatchResult>) -> ActorsCreateBatchResponse {
+        ActorsCreateBatchResponse {
+            results,
+        }
+    }
+}
+
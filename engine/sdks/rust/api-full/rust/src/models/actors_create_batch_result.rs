@@ -0,0 +1,32 @@
+/*
+ * rivet-api-public
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.3.0-rc.12
+ * Contact: developer@rivet.dev
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// ActorsCreateBatchResult : Exactly one of `actor` or `error` is set.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ActorsCreateBatchResult {
+    #[serde(rename = "actor", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub actor: Option<Option<Box<models::Actor>>>,
+    #[serde(rename = "error", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub error: Option<Option<Box<models::ActorsCreateBatchError>>>,
+}
+
+impl ActorsCreateBatchResult {
+    /// Exactly one of `actor` or `error` is set.
+    pub fn new() -> ActorsCreateBatchResult {
+        ActorsCreateBatchResult {
+            actor: None,
+            error: None,
+        }
+    }
+}
+
@@ -13,8 +13,12 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ActorsCreateRequest {
-    #[serde(rename = "crash_policy")]
-    pub crash_policy: models::CrashPolicy,
+    /// Soft placement rules relative to other actors in this datacenter.
+    #[serde(rename = "affinity", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub affinity: Option<Option<Box<models::ActorAffinity>>>,
+    /// Defaults to the namespace's default crash policy, or `destroy` if the namespace has none.
+    #[serde(rename = "crash_policy", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub crash_policy: Option<Option<models::CrashPolicy>>,
     #[serde(rename = "datacenter", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub datacenter: Option<Option<String>>,
     /// Arbitrary base64 encoded binary data.
@@ -24,19 +28,28 @@ pub struct ActorsCreateRequest {
     pub key: Option<Option<String>>,
     #[serde(rename = "name")]
     pub name: String,
+    /// Capabilities a runner must advertise for this actor to be allocated to it.
+    #[serde(rename = "required_capabilities", skip_serializing_if = "Option::is_none")]
+    pub required_capabilities: Option<Vec<String>>,
     #[serde(rename = "runner_name_selector")]
     pub runner_name_selector: String,
+    /// Free-form tags used to group and filter actors. See the `tag` query of the list endpoint.
+    #[serde(rename = "tags", skip_serializing_if = "Option::is_none")]
+    pub tags: Option<std::collections::HashMap<String, String>>,
 }
 
 impl ActorsCreateRequest {
-    pub fn new(crash_policy: models::CrashPolicy, name: String, runner_name_selector: String) -> ActorsCreateRequest {
+    pub fn new(name: String, runner_name_selector: String) -> ActorsCreateRequest {
         ActorsCreateRequest {
-            crash_policy,
+            affinity: None,
+            crash_policy: None,
             datacenter: None,
             input: None,
             key: None,
             name,
+            required_capabilities: None,
             runner_name_selector,
+            tags: None,
         }
     }
 }
@@ -0,0 +1,37 @@
+/*
+ * rivet-api-public
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.3.0-rc.12
+ * Contact: developer@rivet.dev
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// ActorsDestroyBatchRequest : Either `actor_ids` or `name` must be set.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ActorsDestroyBatchRequest {
+    #[serde(rename = "actor_ids", skip_serializing_if = "Option::is_none")]
+    pub actor_ids: Option<Vec<String>>,
+    /// Only destroy actors with `name` whose key starts with this prefix. Actors without a key are skipped.
+    #[serde(rename = "key_prefix", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub key_prefix: Option<Option<String>>,
+    /// Destroy every active actor with this name.
+    #[serde(rename = "name", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub name: Option<Option<String>>,
+}
+
+impl ActorsDestroyBatchRequest {
+    /// Either `actor_ids` or `name` must be set.
+    pub fn new() -> ActorsDestroyBatchRequest {
+        ActorsDestroyBatchRequest {
+            actor_ids: None,
+            key_prefix: None,
+            name: None,
+        }
+    }
+}
+
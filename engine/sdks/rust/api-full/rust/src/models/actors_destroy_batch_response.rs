@@ -0,0 +1,32 @@
+/*
+ * rivet-api-public
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.3.0-rc.12
+ * Contact: developer@rivet.dev
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ActorsDestroyBatchResponse {
+    /// More actors match `name` and `key_prefix` than this request destroyed. Send the same request again until this is false.
+    #[serde(rename = "has_more")]
+    pub has_more: bool,
+    /// One result per actor this request tried to destroy. When destroying by `actor_ids`, in the same order as the request.
+    #[serde(rename = "results")]
+    pub results: Vec<models::ActorsDestroyBatchResult>,
+}
+
+impl ActorsDestroyBatchResponse {
+    pub fn new(has_more: bool, results: Vec<models::ActorsDestroyBatchResult>) -> ActorsDestroyBatchResponse {
+        ActorsDestroyBatchResponse {
+            has_more,
+            results,
+        }
+    }
+}
+
@@ -13,8 +13,9 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ActorsGetOrCreateRequest {
-    #[serde(rename = "crash_policy")]
-    pub crash_policy: models::CrashPolicy,
+    /// Defaults to the namespace's default crash policy, or `destroy` if the namespace has none.
+    #[serde(rename = "crash_policy", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub crash_policy: Option<Option<models::CrashPolicy>>,
     #[serde(rename = "datacenter", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub datacenter: Option<Option<String>>,
     #[serde(rename = "input", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
@@ -28,9 +29,9 @@ pub struct ActorsGetOrCreateRequest {
 }
 
 impl ActorsGetOrCreateRequest {
-    pub fn new(crash_policy: models::CrashPolicy, key: String, name: String, runner_name_selector: String) -> ActorsGetOrCreateRequest {
+    pub fn new(key: String, name: String, runner_name_selector: String) -> ActorsGetOrCreateRequest {
         ActorsGetOrCreateRequest {
-            crash_policy,
+            crash_policy: None,
             datacenter: None,
             input: None,
             key,
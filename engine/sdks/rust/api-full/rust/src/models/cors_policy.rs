@@ -0,0 +1,41 @@
+/*
+ * rivet-api-public
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.3.0-rc.12
+ * Contact: developer@rivet.dev
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+/// CorsPolicy : CORS policy for requests to actors through the gateway. Unset fields keep the permissive default.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CorsPolicy {
+    /// Headers allowed by preflight requests. Any requested header is allowed if not set.
+    #[serde(rename = "allowed_headers", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub allowed_headers: Option<Option<Vec<String>>>,
+    /// Methods allowed by preflight requests. Defaults to `GET, POST, PUT, DELETE, OPTIONS, PATCH`.
+    #[serde(rename = "allowed_methods", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub allowed_methods: Option<Option<Vec<String>>>,
+    /// Origins allowed to make requests, e.g. `https://example.com`. Any origin is allowed if not set.
+    #[serde(rename = "allowed_origins", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub allowed_origins: Option<Option<Vec<String>>>,
+    /// How long browsers may cache a preflight response in seconds. Defaults to 86400.
+    #[serde(rename = "max_age", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub max_age: Option<Option<i32>>,
+}
+
+impl CorsPolicy {
+    /// CORS policy for requests to actors through the gateway. Unset fields keep the permissive default.
+    pub fn new() -> CorsPolicy {
+        CorsPolicy {
+            allowed_headers: None,
+            allowed_methods: None,
+            allowed_origins: None,
+            max_age: None,
+        }
+    }
+}
+
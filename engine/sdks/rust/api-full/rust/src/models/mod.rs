@@ -1,11 +1,37 @@
 pub mod actor;
 pub use self::actor::Actor;
+pub mod actor_affinity;
+pub use self::actor_affinity::ActorAffinity;
+pub mod actor_log;
+pub use self::actor_log::ActorLog;
+pub mod actor_log_stream;
+pub use self::actor_log_stream::ActorLogStream;
 pub mod actor_name;
 pub use self::actor_name::ActorName;
+pub mod actor_rate_limit;
+pub use self::actor_rate_limit::ActorRateLimit;
+pub mod actor_state;
+pub use self::actor_state::ActorState;
+pub mod actors_create_batch_error;
+pub use self::actors_create_batch_error::ActorsCreateBatchError;
+pub mod actors_create_batch_request;
+pub use self::actors_create_batch_request::ActorsCreateBatchRequest;
+pub mod actors_create_batch_response;
+pub use self::actors_create_batch_response::ActorsCreateBatchResponse;
+pub mod actors_create_batch_result;
+pub use self::actors_create_batch_result::ActorsCreateBatchResult;
 pub mod actors_create_request;
 pub use self::actors_create_request::ActorsCreateRequest;
 pub mod actors_create_response;
 pub use self::actors_create_response::ActorsCreateResponse;
+pub mod actors_destroy_batch_error;
+pub use self::actors_destroy_batch_error::ActorsDestroyBatchError;
+pub mod actors_destroy_batch_request;
+pub use self::actors_destroy_batch_request::ActorsDestroyBatchRequest;
+pub mod actors_destroy_batch_response;
+pub use self::actors_destroy_batch_response::ActorsDestroyBatchResponse;
+pub mod actors_destroy_batch_result;
+pub use self::actors_destroy_batch_result::ActorsDestroyBatchResult;
 pub mod actors_get_or_create_request;
 pub use self::actors_get_or_create_request::ActorsGetOrCreateRequest;
 pub mod actors_get_or_create_response;
@@ -16,6 +42,10 @@ pub mod actors_list_names_response;
 pub use self::actors_list_names_response::ActorsListNamesResponse;
 pub mod actors_list_response;
 pub use self::actors_list_response::ActorsListResponse;
+pub mod actors_logs_response;
+pub use self::actors_logs_response::ActorsLogsResponse;
+pub mod cors_policy;
+pub use self::cors_policy::CorsPolicy;
 pub mod crash_policy;
 pub use self::crash_policy::CrashPolicy;
 pub mod datacenter;
@@ -44,8 +74,14 @@ pub mod namespaces_create_request;
 pub use self::namespaces_create_request::NamespacesCreateRequest;
 pub mod namespaces_create_response;
 pub use self::namespaces_create_response::NamespacesCreateResponse;
+pub mod namespaces_update_request;
+pub use self::namespaces_update_request::NamespacesUpdateRequest;
+pub mod namespaces_update_response;
+pub use self::namespaces_update_response::NamespacesUpdateResponse;
 pub mod pagination;
 pub use self::pagination::Pagination;
+pub mod pending_reason;
+pub use self::pending_reason::PendingReason;
 pub mod runner;
 pub use self::runner::Runner;
 pub mod runner_config;
@@ -90,3 +126,15 @@ pub mod runners_list_names_response;
 pub use self::runners_list_names_response::RunnersListNamesResponse;
 pub mod runners_list_response;
 pub use self::runners_list_response::RunnersListResponse;
+pub mod serverless_auth;
+pub use self::serverless_auth::ServerlessAuth;
+pub mod serverless_auth_one_of;
+pub use self::serverless_auth_one_of::ServerlessAuthOneOf;
+pub mod serverless_auth_one_of_1;
+pub use self::serverless_auth_one_of_1::ServerlessAuthOneOf1;
+pub mod serverless_auth_one_of_1_bearer_env;
+pub use self::serverless_auth_one_of_1_bearer_env::ServerlessAuthOneOf1BearerEnv;
+pub mod serverless_auth_one_of_bearer;
+pub use self::serverless_auth_one_of_bearer::ServerlessAuthOneOfBearer;
+pub mod serverless_request_method;
+pub use self::serverless_request_method::ServerlessRequestMethod;
@@ -13,6 +13,15 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct NamespacesCreateRequest {
+    /// Rate limit the gateway applies to requests for each actor in this namespace, tracked per client IP. Requests are not rate limited per actor if not set.
+    #[serde(rename = "actor_rate_limit", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub actor_rate_limit: Option<Option<Box<models::ActorRateLimit>>>,
+    /// CORS policy the gateway applies to requests for actors in this namespace. All origins, methods and headers are allowed if not set.
+    #[serde(rename = "cors_policy", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub cors_policy: Option<Option<Box<models::CorsPolicy>>>,
+    /// Crash policy used for actors created in this namespace without an explicit crash policy.
+    #[serde(rename = "default_crash_policy", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub default_crash_policy: Option<Option<models::CrashPolicy>>,
     #[serde(rename = "display_name")]
     pub display_name: String,
     #[serde(rename = "name")]
@@ -22,6 +31,9 @@ pub struct NamespacesCreateRequest {
 impl NamespacesCreateRequest {
     pub fn new(display_name: String, name: String) -> NamespacesCreateRequest {
         NamespacesCreateRequest {
+            actor_rate_limit: None,
+            cors_policy: None,
+            default_crash_policy: None,
             display_name,
             name,
         }
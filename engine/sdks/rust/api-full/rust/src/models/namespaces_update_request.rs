@@ -0,0 +1,36 @@
+/*
+ * rivet-api-public
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.3.0-rc.12
+ * Contact: developer@rivet.dev
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NamespacesUpdateRequest {
+    /// Rate limit the gateway applies to requests for each actor in this namespace, tracked per client IP. Requests are not rate limited per actor if not set.
+    #[serde(rename = "actor_rate_limit", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub actor_rate_limit: Option<Option<Box<models::ActorRateLimit>>>,
+    /// CORS policy the gateway applies to requests for actors in this namespace. All origins, methods and headers are allowed if not set.
+    #[serde(rename = "cors_policy", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub cors_policy: Option<Option<Box<models::CorsPolicy>>>,
+    /// Crash policy used for actors created in this namespace without an explicit crash policy. Cleared if not set.
+    #[serde(rename = "default_crash_policy", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub default_crash_policy: Option<Option<models::CrashPolicy>>,
+}
+
+impl NamespacesUpdateRequest {
+    pub fn new() -> NamespacesUpdateRequest {
+        NamespacesUpdateRequest {
+            actor_rate_limit: None,
+            cors_policy: None,
+            default_crash_policy: None,
+        }
+    }
+}
+
@@ -0,0 +1,42 @@
+/*
+ * rivet-api-public
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.3.0-rc.12
+ * Contact: developer@rivet.dev
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// PendingReason : Reason an actor could not be allocated to a runner and is waiting in the allocation queue.
+/// Reason an actor could not be allocated to a runner and is waiting in the allocation queue.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum PendingReason {
+    #[serde(rename = "no_runner_matches_selector")]
+    NoRunnerMatchesSelector,
+    #[serde(rename = "all_runners_draining")]
+    AllRunnersDraining,
+    #[serde(rename = "no_capacity")]
+    NoCapacity,
+
+}
+
+impl std::fmt::Display for PendingReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::NoRunnerMatchesSelector => write!(f, "no_runner_matches_selector"),
+            Self::AllRunnersDraining => write!(f, "all_runners_draining"),
+            Self::NoCapacity => write!(f, "no_capacity"),
+        }
+    }
+}
+
+impl Default for PendingReason {
+    fn default() -> PendingReason {
+        Self::NoRunnerMatchesSelector
+    }
+}
+
@@ -22,6 +22,11 @@ pub struct RunnerConfigKindOneOf1Serverless {
     /// Actors per second.
     #[serde(rename = "actor_eviction_rate", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub actor_eviction_rate: Option<Option<f32>>,
+    #[serde(rename = "auth", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub auth: Option<Option<Box<models::ServerlessAuth>>>,
+    /// Body sent with the `/start` request. `{{endpoint}}`, `{{namespace_name}}`, `{{runner_name}}` and `{{total_slots}}` are replaced before sending. Not supported by envoy pools, which send the start command as the body.
+    #[serde(rename = "body", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub body: Option<Option<String>>,
     /// Seconds.
     #[serde(rename = "drain_grace_period", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub drain_grace_period: Option<Option<i32>>,
@@ -37,6 +42,9 @@ pub struct RunnerConfigKindOneOf1Serverless {
     /// Milliseconds between metadata polling. If not set, uses the global default.
     #[serde(rename = "metadata_poll_interval", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub metadata_poll_interval: Option<Option<i64>>,
+    /// HTTP method used for the `/start` request. Defaults to `get`. Envoy pools always POST the start command.
+    #[serde(rename = "method", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub method: Option<Option<models::ServerlessRequestMethod>>,
     /// Deprecated.
     #[serde(rename = "min_runners", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub min_runners: Option<Option<i32>>,
@@ -59,12 +67,15 @@ impl RunnerConfigKindOneOf1Serverless {
             actor_eviction_delay: None,
             actor_eviction_period: None,
             actor_eviction_rate: None,
+            auth: None,
+            body: None,
             drain_grace_period: None,
             drain_on_version_upgrade: None,
             headers: None,
             max_concurrent_actors: None,
             max_runners: None,
             metadata_poll_interval: None,
+            method: None,
             min_runners: None,
             request_lifespan,
             runners_margin: None,
@@ -26,6 +26,9 @@ pub struct RunnerConfigResponse {
     pub protocol_version: Option<Option<i32>>,
     #[serde(rename = "runner_pool_error", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
     pub runner_pool_error: Option<Option<serde_json::Value>>,
+    /// Groups `runner_pool_error` by cause (e.g. `auth`, `timeout`).
+    #[serde(rename = "runner_pool_error_category", default, with = "::serde_with::rust::double_option", skip_serializing_if = "Option::is_none")]
+    pub runner_pool_error_category: Option<Option<String>>,
 }
 
 impl RunnerConfigResponse {
@@ -37,6 +40,7 @@ impl RunnerConfigResponse {
             metadata: None,
             protocol_version: None,
             runner_pool_error: None,
+            runner_pool_error_category: None,
         }
     }
 }
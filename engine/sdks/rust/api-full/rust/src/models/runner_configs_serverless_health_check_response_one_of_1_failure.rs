@@ -15,12 +15,16 @@ use serde::{Deserialize, Serialize};
 pub struct RunnerConfigsServerlessHealthCheckResponseOneOf1Failure {
     #[serde(rename = "error")]
     pub error: Box<models::RunnerConfigsServerlessMetadataError>,
+    /// Time until the probe request failed in milliseconds.
+    #[serde(rename = "latency_ms")]
+    pub latency_ms: i64,
 }
 
 impl RunnerConfigsServerlessHealthCheckResponseOneOf1Failure {
-    pub fn new(error: models::RunnerConfigsServerlessMetadataError) -> RunnerConfigsServerlessHealthCheckResponseOneOf1Failure {
+    pub fn new(error: models::RunnerConfigsServerlessMetadataError, latency_ms: i64) -> RunnerConfigsServerlessHealthCheckResponseOneOf1Failure {
         RunnerConfigsServerlessHealthCheckResponseOneOf1Failure {
             error: Box::new(error),
+            latency_ms,
         }
     }
 }
@@ -13,13 +13,17 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct RunnerConfigsServerlessHealthCheckResponseOneOfSuccess {
+    /// Round trip time of the probe request in milliseconds.
+    #[serde(rename = "latency_ms")]
+    pub latency_ms: i64,
     #[serde(rename = "version")]
     pub version: String,
 }
 
 impl RunnerConfigsServerlessHealthCheckResponseOneOfSuccess {
-    pub fn new(version: String) -> RunnerConfigsServerlessHealthCheckResponseOneOfSuccess {
+    pub fn new(latency_ms: i64, version: String) -> RunnerConfigsServerlessHealthCheckResponseOneOfSuccess {
         RunnerConfigsServerlessHealthCheckResponseOneOfSuccess {
+            latency_ms,
             version,
         }
     }
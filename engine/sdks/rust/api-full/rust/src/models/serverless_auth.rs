@@ -0,0 +1,28 @@
+/*
+ * rivet-api-public
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.3.0-rc.12
+ * Contact: developer@rivet.dev
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// ServerlessAuth : How the engine authenticates requests to a serverless endpoint.
+/// How the engine authenticates requests to a serverless endpoint.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ServerlessAuth {
+    ServerlessAuthOneOf(Box<models::ServerlessAuthOneOf>),
+    ServerlessAuthOneOf1(Box<models::ServerlessAuthOneOf1>),
+}
+
+impl Default for ServerlessAuth {
+    fn default() -> Self {
+        Self::ServerlessAuthOneOf(Default::default())
+    }
+}
+
@@ -0,0 +1,29 @@
+/*
+ * rivet-api-public
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.3.0-rc.12
+ * Contact: developer@rivet.dev
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// ServerlessAuthOneOf : Sends the given token as `Authorization: Bearer <token>`.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ServerlessAuthOneOf {
+    #[serde(rename = "bearer")]
+    pub bearer: Box<models::ServerlessAuthOneOfBearer>,
+}
+
+impl ServerlessAuthOneOf {
+    /// Sends the given token as `Authorization: Bearer <token>`.
+    pub fn new(bearer: models::ServerlessAuthOneOfBearer) -> ServerlessAuthOneOf {
+        ServerlessAuthOneOf {
+            bearer: Box::new(bearer),
+        }
+    }
+}
+
@@ -0,0 +1,29 @@
+/*
+ * rivet-api-public
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.3.0-rc.12
+ * Contact: developer@rivet.dev
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// ServerlessAuthOneOf1 : Sends the value of the given environment variable on the engine as `Authorization: Bearer <token>`. The variable must be listed in the engine's `pegboard.serverless_auth_env_allowlist`.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ServerlessAuthOneOf1 {
+    #[serde(rename = "bearer_env")]
+    pub bearer_env: Box<models::ServerlessAuthOneOf1BearerEnv>,
+}
+
+impl ServerlessAuthOneOf1 {
+    /// Sends the value of the given environment variable on the engine as `Authorization: Bearer <token>`. The variable must be listed in the engine's `pegboard.serverless_auth_env_allowlist`.
+    pub fn new(bearer_env: models::ServerlessAuthOneOf1BearerEnv) -> ServerlessAuthOneOf1 {
+        ServerlessAuthOneOf1 {
+            bearer_env: Box::new(bearer_env),
+        }
+    }
+}
+
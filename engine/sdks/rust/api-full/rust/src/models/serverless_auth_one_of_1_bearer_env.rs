@@ -0,0 +1,27 @@
+/*
+ * rivet-api-public
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.3.0-rc.12
+ * Contact: developer@rivet.dev
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ServerlessAuthOneOf1BearerEnv {
+    #[serde(rename = "env")]
+    pub env: String,
+}
+
+impl ServerlessAuthOneOf1BearerEnv {
+    pub fn new(env: String) -> ServerlessAuthOneOf1BearerEnv {
+        ServerlessAuthOneOf1BearerEnv {
+            env,
+        }
+    }
+}
+
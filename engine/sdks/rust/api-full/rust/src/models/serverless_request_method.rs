@@ -0,0 +1,38 @@
+/*
+ * rivet-api-public
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.3.0-rc.12
+ * Contact: developer@rivet.dev
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// 
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum ServerlessRequestMethod {
+    #[serde(rename = "get")]
+    Get,
+    #[serde(rename = "post")]
+    Post,
+
+}
+
+impl std::fmt::Display for ServerlessRequestMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Get => write!(f, "get"),
+            Self::Post => write!(f, "post"),
+        }
+    }
+}
+
+impl Default for ServerlessRequestMethod {
+    fn default() -> ServerlessRequestMethod {
+        Self::Get
+    }
+}
+
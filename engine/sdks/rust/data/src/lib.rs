@@ -3,7 +3,12 @@ pub mod generated;
 pub mod versioned;
 
 pub use generated::{
-	PEGBOARD_NAMESPACE_ACTOR_BY_KEY_VERSION, PEGBOARD_NAMESPACE_ACTOR_NAME_VERSION,
-	PEGBOARD_NAMESPACE_RUNNER_ALLOC_IDX_VERSION, PEGBOARD_NAMESPACE_RUNNER_BY_KEY_VERSION,
-	PEGBOARD_NAMESPACE_RUNNER_CONFIG_VERSION, PEGBOARD_RUNNER_METADATA_VERSION,
+	NAMESPACE_ACTOR_RATE_LIMIT_VERSION, NAMESPACE_CORS_POLICY_VERSION,
+	NAMESPACE_DEFAULT_CRASH_POLICY_VERSION, PEGBOARD_ACTOR_DESTROY_AUDIT_VERSION,
+	PEGBOARD_ACTOR_LOG_VERSION, PEGBOARD_ACTOR_REQUIRED_CAPABILITIES_VERSION,
+	PEGBOARD_ACTOR_RESPONSE_CACHE_VERSION, PEGBOARD_NAMESPACE_ACTOR_BY_KEY_VERSION,
+	PEGBOARD_NAMESPACE_ACTOR_NAME_VERSION, PEGBOARD_NAMESPACE_RUNNER_ALLOC_IDX_VERSION,
+	PEGBOARD_NAMESPACE_RUNNER_BY_KEY_VERSION, PEGBOARD_NAMESPACE_RUNNER_CONFIG_VERSION,
+	PEGBOARD_RUNNER_CAPABILITIES_VERSION, PEGBOARD_RUNNER_METADATA_VERSION,
+	PEGBOARD_WEBHOOK_CONFIG_VERSION, PEGBOARD_WEBHOOK_DEAD_LETTER_VERSION,
 };
@@ -273,6 +273,368 @@ impl OwnedVersionedData for ActorNameKeyData {
 	}
 }
 
+pub enum NamespaceDefaultCrashPolicyKeyData {
+	V1(namespace_default_crash_policy_v1::Data),
+}
+
+impl OwnedVersionedData for NamespaceDefaultCrashPolicyKeyData {
+	type Latest = namespace_default_crash_policy_v1::Data;
+
+	fn wrap_latest(latest: namespace_default_crash_policy_v1::Data) -> Self {
+		NamespaceDefaultCrashPolicyKeyData::V1(latest)
+	}
+
+	fn unwrap_latest(self) -> Result<Self::Latest> {
+		#[allow(irrefutable_let_patterns)]
+		if let NamespaceDefaultCrashPolicyKeyData::V1(data) = self {
+			Ok(data)
+		} else {
+			bail!("version not latest");
+		}
+	}
+
+	fn deserialize_version(payload: &[u8], version: u16) -> Result<Self> {
+		match version {
+			1 => Ok(NamespaceDefaultCrashPolicyKeyData::V1(
+				serde_bare::from_slice(payload)?,
+			)),
+			_ => bail!("invalid version: {version}"),
+		}
+	}
+
+	fn serialize_version(self, _version: u16) -> Result<Vec<u8>> {
+		match self {
+			NamespaceDefaultCrashPolicyKeyData::V1(data) => {
+				serde_bare::to_vec(&data).map_err(Into::into)
+			}
+		}
+	}
+}
+
+pub enum NamespaceCorsPolicyKeyData {
+	V1(namespace_cors_policy_v1::Data),
+}
+
+impl OwnedVersionedData for NamespaceCorsPolicyKeyData {
+	type Latest = namespace_cors_policy_v1::Data;
+
+	fn wrap_latest(latest: namespace_cors_policy_v1::Data) -> Self {
+		NamespaceCorsPolicyKeyData::V1(latest)
+	}
+
+	fn unwrap_latest(self) -> Result<Self::Latest> {
+		#[allow(irrefutable_let_patterns)]
+		if let NamespaceCorsPolicyKeyData::V1(data) = self {
+			Ok(data)
+		} else {
+			bail!("version not latest");
+		}
+	}
+
+	fn deserialize_version(payload: &[u8], version: u16) -> Result<Self> {
+		match version {
+			1 => Ok(NamespaceCorsPolicyKeyData::V1(serde_bare::from_slice(
+				payload,
+			)?)),
+			_ => bail!("invalid version: {version}"),
+		}
+	}
+
+	fn serialize_version(self, _version: u16) -> Result<Vec<u8>> {
+		match self {
+			NamespaceCorsPolicyKeyData::V1(data) => serde_bare::to_vec(&data).map_err(Into::into),
+		}
+	}
+}
+
+pub enum NamespaceActorRateLimitKeyData {
+	V1(namespace_actor_rate_limit_v1::Data),
+}
+
+impl OwnedVersionedData for NamespaceActorRateLimitKeyData {
+	type Latest = namespace_actor_rate_limit_v1::Data;
+
+	fn wrap_latest(latest: namespace_actor_rate_limit_v1::Data) -> Self {
+		NamespaceActorRateLimitKeyData::V1(latest)
+	}
+
+	fn unwrap_latest(self) -> Result<Self::Latest> {
+		#[allow(irrefutable_let_patterns)]
+		if let NamespaceActorRateLimitKeyData::V1(data) = self {
+			Ok(data)
+		} else {
+			bail!("version not latest");
+		}
+	}
+
+	fn deserialize_version(payload: &[u8], version: u16) -> Result<Self> {
+		match version {
+			1 => Ok(NamespaceActorRateLimitKeyData::V1(serde_bare::from_slice(
+				payload,
+			)?)),
+			_ => bail!("invalid version: {version}"),
+		}
+	}
+
+	fn serialize_version(self, _version: u16) -> Result<Vec<u8>> {
+		match self {
+			NamespaceActorRateLimitKeyData::V1(data) => {
+				serde_bare::to_vec(&data).map_err(Into::into)
+			}
+		}
+	}
+}
+
+pub enum ActorResponseCacheKeyData {
+	V1(pegboard_actor_response_cache_v1::Data),
+}
+
+impl OwnedVersionedData for ActorResponseCacheKeyData {
+	type Latest = pegboard_actor_response_cache_v1::Data;
+
+	fn wrap_latest(latest: pegboard_actor_response_cache_v1::Data) -> Self {
+		ActorResponseCacheKeyData::V1(latest)
+	}
+
+	fn unwrap_latest(self) -> Result<Self::Latest> {
+		#[allow(irrefutable_let_patterns)]
+		if let ActorResponseCacheKeyData::V1(data) = self {
+			Ok(data)
+		} else {
+			bail!("version not latest");
+		}
+	}
+
+	fn deserialize_version(payload: &[u8], version: u16) -> Result<Self> {
+		match version {
+			1 => Ok(ActorResponseCacheKeyData::V1(serde_bare::from_slice(
+				payload,
+			)?)),
+			_ => bail!("invalid version: {version}"),
+		}
+	}
+
+	fn serialize_version(self, _version: u16) -> Result<Vec<u8>> {
+		match self {
+			ActorResponseCacheKeyData::V1(data) => serde_bare::to_vec(&data).map_err(Into::into),
+		}
+	}
+}
+
+pub enum ActorDestroyAuditKeyData {
+	V1(pegboard_actor_destroy_audit_v1::Data),
+}
+
+impl OwnedVersionedData for ActorDestroyAuditKeyData {
+	type Latest = pegboard_actor_destroy_audit_v1::Data;
+
+	fn wrap_latest(latest: pegboard_actor_destroy_audit_v1::Data) -> Self {
+		ActorDestroyAuditKeyData::V1(latest)
+	}
+
+	fn unwrap_latest(self) -> Result<Self::Latest> {
+		#[allow(irrefutable_let_patterns)]
+		if let ActorDestroyAuditKeyData::V1(data) = self {
+			Ok(data)
+		} else {
+			bail!("version not latest");
+		}
+	}
+
+	fn deserialize_version(payload: &[u8], version: u16) -> Result<Self> {
+		match version {
+			1 => Ok(ActorDestroyAuditKeyData::V1(serde_bare::from_slice(
+				payload,
+			)?)),
+			_ => bail!("invalid version: {version}"),
+		}
+	}
+
+	fn serialize_version(self, _version: u16) -> Result<Vec<u8>> {
+		match self {
+			ActorDestroyAuditKeyData::V1(data) => serde_bare::to_vec(&data).map_err(Into::into),
+		}
+	}
+}
+
+pub enum ActorRequiredCapabilitiesKeyData {
+	V1(pegboard_actor_required_capabilities_v1::Data),
+}
+
+impl OwnedVersionedData for ActorRequiredCapabilitiesKeyData {
+	type Latest = pegboard_actor_required_capabilities_v1::Data;
+
+	fn wrap_latest(latest: pegboard_actor_required_capabilities_v1::Data) -> Self {
+		ActorRequiredCapabilitiesKeyData::V1(latest)
+	}
+
+	fn unwrap_latest(self) -> Result<Self::Latest> {
+		#[allow(irrefutable_let_patterns)]
+		if let ActorRequiredCapabilitiesKeyData::V1(data) = self {
+			Ok(data)
+		} else {
+			bail!("version not latest");
+		}
+	}
+
+	fn deserialize_version(payload: &[u8], version: u16) -> Result<Self> {
+		match version {
+			1 => Ok(ActorRequiredCapabilitiesKeyData::V1(
+				serde_bare::from_slice(payload)?,
+			)),
+			_ => bail!("invalid version: {version}"),
+		}
+	}
+
+	fn serialize_version(self, _version: u16) -> Result<Vec<u8>> {
+		match self {
+			ActorRequiredCapabilitiesKeyData::V1(data) => {
+				serde_bare::to_vec(&data).map_err(Into::into)
+			}
+		}
+	}
+}
+
+pub enum RunnerCapabilitiesKeyData {
+	V1(pegboard_runner_capabilities_v1::Data),
+}
+
+impl OwnedVersionedData for RunnerCapabilitiesKeyData {
+	type Latest = pegboard_runner_capabilities_v1::Data;
+
+	fn wrap_latest(latest: pegboard_runner_capabilities_v1::Data) -> Self {
+		RunnerCapabilitiesKeyData::V1(latest)
+	}
+
+	fn unwrap_latest(self) -> Result<Self::Latest> {
+		#[allow(irrefutable_let_patterns)]
+		if let RunnerCapabilitiesKeyData::V1(data) = self {
+			Ok(data)
+		} else {
+			bail!("version not latest");
+		}
+	}
+
+	fn deserialize_version(payload: &[u8], version: u16) -> Result<Self> {
+		match version {
+			1 => Ok(RunnerCapabilitiesKeyData::V1(serde_bare::from_slice(
+				payload,
+			)?)),
+			_ => bail!("invalid version: {version}"),
+		}
+	}
+
+	fn serialize_version(self, _version: u16) -> Result<Vec<u8>> {
+		match self {
+			RunnerCapabilitiesKeyData::V1(data) => serde_bare::to_vec(&data).map_err(Into::into),
+		}
+	}
+}
+
+pub enum ActorLogKeyData {
+	V1(pegboard_actor_log_v1::Data),
+}
+
+impl OwnedVersionedData for ActorLogKeyData {
+	type Latest = pegboard_actor_log_v1::Data;
+
+	fn wrap_latest(latest: pegboard_actor_log_v1::Data) -> Self {
+		ActorLogKeyData::V1(latest)
+	}
+
+	fn unwrap_latest(self) -> Result<Self::Latest> {
+		#[allow(irrefutable_let_patterns)]
+		if let ActorLogKeyData::V1(data) = self {
+			Ok(data)
+		} else {
+			bail!("version not latest");
+		}
+	}
+
+	fn deserialize_version(payload: &[u8], version: u16) -> Result<Self> {
+		match version {
+			1 => Ok(ActorLogKeyData::V1(serde_bare::from_slice(payload)?)),
+			_ => bail!("invalid version: {version}"),
+		}
+	}
+
+	fn serialize_version(self, _version: u16) -> Result<Vec<u8>> {
+		match self {
+			ActorLogKeyData::V1(data) => serde_bare::to_vec(&data).map_err(Into::into),
+		}
+	}
+}
+
+pub enum WebhookConfigKeyData {
+	V1(pegboard_webhook_config_v1::Data),
+}
+
+impl OwnedVersionedData for WebhookConfigKeyData {
+	type Latest = pegboard_webhook_config_v1::Data;
+
+	fn wrap_latest(latest: pegboard_webhook_config_v1::Data) -> Self {
+		WebhookConfigKeyData::V1(latest)
+	}
+
+	fn unwrap_latest(self) -> Result<Self::Latest> {
+		#[allow(irrefutable_let_patterns)]
+		if let WebhookConfigKeyData::V1(data) = self {
+			Ok(data)
+		} else {
+			bail!("version not latest");
+		}
+	}
+
+	fn deserialize_version(payload: &[u8], version: u16) -> Result<Self> {
+		match version {
+			1 => Ok(WebhookConfigKeyData::V1(serde_bare::from_slice(payload)?)),
+			_ => bail!("invalid version: {version}"),
+		}
+	}
+
+	fn serialize_version(self, _version: u16) -> Result<Vec<u8>> {
+		match self {
+			WebhookConfigKeyData::V1(data) => serde_bare::to_vec(&data).map_err(Into::into),
+		}
+	}
+}
+
+pub enum WebhookDeadLetterKeyData {
+	V1(pegboard_webhook_dead_letter_v1::Data),
+}
+
+impl OwnedVersionedData for WebhookDeadLetterKeyData {
+	type Latest = pegboard_webhook_dead_letter_v1::Data;
+
+	fn wrap_latest(latest: pegboard_webhook_dead_letter_v1::Data) -> Self {
+		WebhookDeadLetterKeyData::V1(latest)
+	}
+
+	fn unwrap_latest(self) -> Result<Self::Latest> {
+		#[allow(irrefutable_let_patterns)]
+		if let WebhookDeadLetterKeyData::V1(data) = self {
+			Ok(data)
+		} else {
+			bail!("version not latest");
+		}
+	}
+
+	fn deserialize_version(payload: &[u8], version: u16) -> Result<Self> {
+		match version {
+			1 => Ok(WebhookDeadLetterKeyData::V1(serde_bare::from_slice(
+				payload,
+			)?)),
+			_ => bail!("invalid version: {version}"),
+		}
+	}
+
+	fn serialize_version(self, _version: u16) -> Result<Vec<u8>> {
+		match self {
+			WebhookDeadLetterKeyData::V1(data) => serde_bare::to_vec(&data).map_err(Into::into),
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -10,18 +10,19 @@ pub enum NamespaceRunnerConfig {
 	V4(pegboard_namespace_runner_config_v4::RunnerConfig),
 	V5(pegboard_namespace_runner_config_v5::RunnerConfig),
 	V6(pegboard_namespace_runner_config_v6::RunnerConfig),
+	V7(pegboard_namespace_runner_config_v7::RunnerConfig),
 }
 
 impl OwnedVersionedData for NamespaceRunnerConfig {
-	type Latest = pegboard_namespace_runner_config_v6::RunnerConfig;
+	type Latest = pegboard_namespace_runner_config_v7::RunnerConfig;
 
-	fn wrap_latest(latest: pegboard_namespace_runner_config_v6::RunnerConfig) -> Self {
-		NamespaceRunnerConfig::V6(latest)
+	fn wrap_latest(latest: pegboard_namespace_runner_config_v7::RunnerConfig) -> Self {
+		NamespaceRunnerConfig::V7(latest)
 	}
 
 	fn unwrap_latest(self) -> Result<Self::Latest> {
 		#[allow(irrefutable_let_patterns)]
-		if let NamespaceRunnerConfig::V6(data) = self {
+		if let NamespaceRunnerConfig::V7(data) = self {
 			Ok(data)
 		} else {
 			bail!("version not latest");
@@ -36,6 +37,7 @@ impl OwnedVersionedData for NamespaceRunnerConfig {
 			4 => Ok(NamespaceRunnerConfig::V4(serde_bare::from_slice(payload)?)),
 			5 => Ok(NamespaceRunnerConfig::V5(serde_bare::from_slice(payload)?)),
 			6 => Ok(NamespaceRunnerConfig::V6(serde_bare::from_slice(payload)?)),
+			7 => Ok(NamespaceRunnerConfig::V7(serde_bare::from_slice(payload)?)),
 			_ => bail!("invalid version: {version}"),
 		}
 	}
@@ -48,6 +50,7 @@ impl OwnedVersionedData for NamespaceRunnerConfig {
 			NamespaceRunnerConfig::V4(data) => serde_bare::to_vec(&data).map_err(Into::into),
 			NamespaceRunnerConfig::V5(data) => serde_bare::to_vec(&data).map_err(Into::into),
 			NamespaceRunnerConfig::V6(data) => serde_bare::to_vec(&data).map_err(Into::into),
+			NamespaceRunnerConfig::V7(data) => serde_bare::to_vec(&data).map_err(Into::into),
 		}
 	}
 
@@ -58,11 +61,13 @@ impl OwnedVersionedData for NamespaceRunnerConfig {
 			Self::v3_to_v4,
 			Self::v4_to_v5,
 			Self::v5_to_v6,
+			Self::v6_to_v7,
 		]
 	}
 
 	fn serialize_converters() -> Vec<impl Fn(Self) -> Result<Self>> {
 		vec![
+			Self::v7_to_v6,
 			Self::v6_to_v5,
 			Self::v5_to_v4,
 			Self::v4_to_v3,
@@ -435,6 +440,102 @@ impl NamespaceRunnerConfig {
 		}
 	}
 
+	fn v6_to_v7(self) -> Result<Self> {
+		if let NamespaceRunnerConfig::V6(config) = self {
+			let pegboard_namespace_runner_config_v6::RunnerConfig { kind, metadata } = config;
+
+			let kind = match kind {
+				pegboard_namespace_runner_config_v6::RunnerConfigKind::Serverless(serverless) => {
+					pegboard_namespace_runner_config_v7::RunnerConfigKind::Serverless(
+						pegboard_namespace_runner_config_v7::Serverless {
+							url: serverless.url,
+							headers: serverless.headers,
+							request_lifespan: serverless.request_lifespan,
+							max_concurrent_actors: serverless.max_concurrent_actors,
+							drain_grace_period: serverless.drain_grace_period,
+							slots_per_runner: serverless.slots_per_runner,
+							min_runners: serverless.min_runners,
+							max_runners: serverless.max_runners,
+							runners_margin: serverless.runners_margin,
+							metadata_poll_interval: serverless.metadata_poll_interval,
+							drain_on_version_upgrade: serverless.drain_on_version_upgrade,
+							actor_eviction_delay: serverless.actor_eviction_delay,
+							actor_eviction_period: serverless.actor_eviction_period,
+							actor_eviction_rate: serverless.actor_eviction_rate,
+							// Default to a GET without a body or auth for v6 -> v7 migration
+							method:
+								pegboard_namespace_runner_config_v7::ServerlessRequestMethod::Get,
+							body: None,
+							auth: None,
+						},
+					)
+				}
+				pegboard_namespace_runner_config_v6::RunnerConfigKind::Normal(normal) => {
+					pegboard_namespace_runner_config_v7::RunnerConfigKind::Normal(
+						pegboard_namespace_runner_config_v7::Normal {
+							drain_on_version_upgrade: normal.drain_on_version_upgrade,
+							actor_eviction_delay: normal.actor_eviction_delay,
+							actor_eviction_period: normal.actor_eviction_period,
+							actor_eviction_rate: normal.actor_eviction_rate,
+						},
+					)
+				}
+			};
+
+			Ok(NamespaceRunnerConfig::V7(
+				pegboard_namespace_runner_config_v7::RunnerConfig { kind, metadata },
+			))
+		} else {
+			bail!("unexpected version");
+		}
+	}
+
+	fn v7_to_v6(self) -> Result<Self> {
+		if let NamespaceRunnerConfig::V7(config) = self {
+			let pegboard_namespace_runner_config_v7::RunnerConfig { kind, metadata } = config;
+
+			let kind = match kind {
+				pegboard_namespace_runner_config_v7::RunnerConfigKind::Serverless(serverless) => {
+					pegboard_namespace_runner_config_v6::RunnerConfigKind::Serverless(
+						pegboard_namespace_runner_config_v6::Serverless {
+							url: serverless.url,
+							headers: serverless.headers,
+							request_lifespan: serverless.request_lifespan,
+							max_concurrent_actors: serverless.max_concurrent_actors,
+							drain_grace_period: serverless.drain_grace_period,
+							slots_per_runner: serverless.slots_per_runner,
+							min_runners: serverless.min_runners,
+							max_runners: serverless.max_runners,
+							runners_margin: serverless.runners_margin,
+							metadata_poll_interval: serverless.metadata_poll_interval,
+							drain_on_version_upgrade: serverless.drain_on_version_upgrade,
+							actor_eviction_delay: serverless.actor_eviction_delay,
+							actor_eviction_period: serverless.actor_eviction_period,
+							actor_eviction_rate: serverless.actor_eviction_rate,
+							// method, body and auth are dropped in downgrade
+						},
+					)
+				}
+				pegboard_namespace_runner_config_v7::RunnerConfigKind::Normal(normal) => {
+					pegboard_namespace_runner_config_v6::RunnerConfigKind::Normal(
+						pegboard_namespace_runner_config_v6::Normal {
+							drain_on_version_upgrade: normal.drain_on_version_upgrade,
+							actor_eviction_delay: normal.actor_eviction_delay,
+							actor_eviction_period: normal.actor_eviction_period,
+							actor_eviction_rate: normal.actor_eviction_rate,
+						},
+					)
+				}
+			};
+
+			Ok(NamespaceRunnerConfig::V6(
+				pegboard_namespace_runner_config_v6::RunnerConfig { kind, metadata },
+			))
+		} else {
+			bail!("unexpected version");
+		}
+	}
+
 	fn v5_to_v4(self) -> Result<Self> {
 		if let NamespaceRunnerConfig::V5(config) = self {
 			let pegboard_namespace_runner_config_v5::RunnerConfig {
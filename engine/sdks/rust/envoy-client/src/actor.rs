@@ -48,6 +48,7 @@ pub enum ToActor {
 		message_id: protocol::MessageId,
 		path: String,
 		headers: BTreeMap<String, String>,
+		compression: bool,
 	},
 	WsMsg {
 		message_id: protocol::MessageId,
@@ -338,8 +339,10 @@ async fn actor_inner(
 						message_id,
 						path,
 						headers,
+						compression,
 					} => {
-						handle_ws_open(&mut ctx, &handle, message_id, path, headers).await;
+						handle_ws_open(&mut ctx, &handle, message_id, path, headers, compression)
+							.await;
 					}
 					ToActor::WsMsg { message_id, msg } => {
 						handle_ws_message(&mut ctx, message_id, msg).await;
@@ -652,6 +655,7 @@ fn spawn_ws_outgoing_task(
 	shared: Arc<SharedContext>,
 	gateway_id: protocol::GatewayId,
 	request_id: protocol::RequestId,
+	compression: bool,
 	mut outgoing_rx: mpsc::UnboundedReceiver<crate::config::WsOutgoing>,
 ) {
 	let ws_task = async move {
@@ -660,6 +664,11 @@ fn spawn_ws_outgoing_task(
 			idx += 1;
 			match msg {
 				crate::config::WsOutgoing::Message { data, binary } => {
+					let (data, compressed) = if compression {
+						protocol::compression::maybe_compress(data)
+					} else {
+						(data, false)
+					};
 					ws_send(
 						&shared,
 						protocol::ToRivet::ToRivetTunnelMessage(protocol::ToRivetTunnelMessage {
@@ -670,7 +679,11 @@ fn spawn_ws_outgoing_task(
 							},
 							message_kind:
 								protocol::ToRivetTunnelMessageKind::ToRivetWebSocketMessage(
-									protocol::ToRivetWebSocketMessage { data, binary },
+									protocol::ToRivetWebSocketMessage {
+										data,
+										binary,
+										compressed,
+									},
 								),
 						}),
 					)
@@ -712,6 +725,7 @@ async fn handle_ws_open(
 	message_id: protocol::MessageId,
 	path: String,
 	headers: BTreeMap<String, String>,
+	compression: bool,
 ) {
 	let restored_ws = ctx
 		.ws_entries
@@ -841,6 +855,7 @@ async fn handle_ws_open(
 				ctx.shared.clone(),
 				message_id.gateway_id,
 				message_id.request_id,
+				compression,
 				outgoing_rx,
 			);
 
@@ -854,6 +869,7 @@ async fn handle_ws_open(
 				protocol::ToRivetTunnelMessageKind::ToRivetWebSocketOpen(
 					protocol::ToRivetWebSocketOpen {
 						can_hibernate: is_hibernatable,
+						compression,
 					},
 				),
 			)
@@ -955,12 +971,31 @@ async fn handle_ws_message(
 			ws.rivet_message_index = received_index;
 		}
 
+		let data = if msg.compressed {
+			match protocol::compression::decompress(
+				&msg.data,
+				protocol::compression::MAX_DECOMPRESSED_SIZE,
+			) {
+				Ok(data) => data,
+				Err(error) => {
+					tracing::warn!(
+						request_id = id_to_str(&message_id.request_id),
+						?error,
+						"failed to decompress websocket message"
+					);
+					return;
+				}
+			}
+		} else {
+			msg.data
+		};
+
 		if let Some(handler) = &ws.ws_handler {
 			let sender = crate::config::WebSocketSender {
 				tx: ws.outgoing_tx.clone(),
 			};
 			let ws_msg = WebSocketMessage {
-				data: msg.data,
+				data,
 				binary: msg.binary,
 				gateway_id: message_id.gateway_id,
 				request_id: message_id.request_id,
@@ -1059,10 +1094,13 @@ async fn handle_hws_restore(
 
 			match ws_result {
 				Ok(ws_handler) => {
+					// The gateway does not renegotiate compression when a hibernating websocket is
+					// rewoken, so restored websockets always start uncompressed
 					spawn_ws_outgoing_task(
 						ctx.shared.clone(),
 						hib_req.gateway_id,
 						hib_req.request_id,
+						false,
 						hws_outgoing_rx,
 					);
 					ctx.ws_entries.insert(
@@ -1083,6 +1121,7 @@ async fn handle_hws_restore(
 						protocol::ToRivetTunnelMessageKind::ToRivetWebSocketOpen(
 							protocol::ToRivetWebSocketOpen {
 								can_hibernate: true,
+								compression: false,
 							},
 						),
 					)
@@ -24,6 +24,7 @@ use crate::kv::{
 	KV_CLEANUP_INTERVAL_MS, KvRequestEntry, cleanup_old_kv_requests, handle_kv_request,
 	handle_kv_response, process_unsent_kv_requests,
 };
+use crate::logs::handle_send_actor_logs;
 use crate::metrics::METRICS;
 use crate::sqlite::{
 	RemoteSqliteRequest, RemoteSqliteRequestEntry, RemoteSqliteResponse, SqliteRequest,
@@ -123,6 +124,11 @@ pub enum ToEnvoyMessage {
 		alarm_ts: Option<i64>,
 		ack_tx: Option<oneshot::Sender<()>>,
 	},
+	ActorLogs {
+		actor_id: String,
+		generation: Option<u32>,
+		lines: Vec<protocol::ActorLogLine>,
+	},
 	HwsAck {
 		gateway_id: protocol::GatewayId,
 		request_id: protocol::RequestId,
@@ -408,6 +414,9 @@ async fn envoy_loop(
 							let _ = ack_tx.send(());
 						}
 					}
+					ToEnvoyMessage::ActorLogs { actor_id, generation, lines } => {
+						handle_send_actor_logs(&ctx, actor_id, generation, lines).await;
+					}
 					ToEnvoyMessage::HwsAck { gateway_id, request_id, envoy_message_index } => {
 						send_hibernatable_ws_message_ack(&mut ctx, gateway_id, request_id, envoy_message_index);
 					}
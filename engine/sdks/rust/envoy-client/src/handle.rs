@@ -308,6 +308,23 @@ impl EnvoyHandle {
 		);
 	}
 
+	/// Sends log lines for an actor to rivet. Defaults to the latest generation if not set.
+	pub fn send_actor_logs(
+		&self,
+		actor_id: String,
+		generation: Option<u32>,
+		lines: Vec<protocol::ActorLogLine>,
+	) {
+		let _ = crate::envoy::send_to_envoy_tx(
+			&self.shared,
+			ToEnvoyMessage::ActorLogs {
+				actor_id,
+				generation,
+				lines,
+			},
+		);
+	}
+
 	pub async fn kv_get(
 		&self,
 		actor_id: String,
@@ -0,0 +1,44 @@
+use rivet_envoy_protocol as protocol;
+
+use crate::connection::ws_send;
+use crate::envoy::EnvoyContext;
+
+/// Forwards log lines written by an actor to rivet. Logs are best effort, they are dropped if the actor is
+/// not running on this envoy or the connection is down.
+pub async fn handle_send_actor_logs(
+	ctx: &EnvoyContext,
+	actor_id: String,
+	generation: Option<u32>,
+	lines: Vec<protocol::ActorLogLine>,
+) {
+	if lines.is_empty() {
+		return;
+	}
+
+	let generation = match generation {
+		Some(generation) => ctx
+			.get_actor(&actor_id, Some(generation))
+			.map(|_| generation),
+		// Highest generation non-closed entry, same as `get_actor`
+		None => ctx.actors.get(&actor_id).and_then(|gens| {
+			gens.iter()
+				.filter(|(_, entry)| !entry.handle.is_closed())
+				.map(|(&generation, _)| generation)
+				.max()
+		}),
+	};
+	let Some(generation) = generation else {
+		tracing::debug!(%actor_id, "actor not found, dropping logs");
+		return;
+	};
+
+	ws_send(
+		&ctx.shared,
+		protocol::ToRivet::ToRivetActorLog(protocol::ToRivetActorLog {
+			actor_id,
+			generation,
+			lines,
+		}),
+	)
+	.await;
+}
@@ -51,15 +51,16 @@ pub fn stringify_to_rivet_tunnel_message_kind(kind: &protocol::ToRivetTunnelMess
 		}
 		protocol::ToRivetTunnelMessageKind::ToRivetWebSocketOpen(val) => {
 			format!(
-				"ToRivetWebSocketOpen{{canHibernate: {}}}",
-				val.can_hibernate
+				"ToRivetWebSocketOpen{{canHibernate: {}, compression: {}}}",
+				val.can_hibernate, val.compression
 			)
 		}
 		protocol::ToRivetTunnelMessageKind::ToRivetWebSocketMessage(val) => {
 			format!(
-				"ToRivetWebSocketMessage{{data: {}, binary: {}}}",
+				"ToRivetWebSocketMessage{{data: {}, binary: {}, compressed: {}}}",
 				stringify_bytes(&val.data),
-				val.binary
+				val.binary,
+				val.compressed
 			)
 		}
 		protocol::ToRivetTunnelMessageKind::ToRivetWebSocketMessageAck(val) => {
@@ -111,17 +112,19 @@ pub fn stringify_to_envoy_tunnel_message_kind(kind: &protocol::ToEnvoyTunnelMess
 		}
 		protocol::ToEnvoyTunnelMessageKind::ToEnvoyWebSocketOpen(val) => {
 			format!(
-				"ToEnvoyWebSocketOpen{{actorId: \"{}\", path: \"{}\", headers: {}}}",
+				"ToEnvoyWebSocketOpen{{actorId: \"{}\", path: \"{}\", headers: {}, compression: {}}}",
 				val.actor_id,
 				val.path,
-				stringify_map(&val.headers)
+				stringify_map(&val.headers),
+				val.compression
 			)
 		}
 		protocol::ToEnvoyTunnelMessageKind::ToEnvoyWebSocketMessage(val) => {
 			format!(
-				"ToEnvoyWebSocketMessage{{data: {}, binary: {}}}",
+				"ToEnvoyWebSocketMessage{{data: {}, binary: {}, compressed: {}}}",
 				stringify_bytes(&val.data),
-				val.binary
+				val.binary,
+				val.compressed
 			)
 		}
 		protocol::ToEnvoyTunnelMessageKind::ToEnvoyWebSocketClose(val) => {
@@ -287,6 +290,14 @@ pub fn stringify_to_rivet(message: &protocol::ToRivet) -> String {
 				val.request_id, val.data.actor_id, val.data.generation
 			)
 		}
+		protocol::ToRivet::ToRivetActorLog(val) => {
+			format!(
+				"ToRivetActorLog{{actorId: \"{}\", generation: {}, lines: {}}}",
+				val.actor_id,
+				val.generation,
+				val.lines.len()
+			)
+		}
 		protocol::ToRivet::ToRivetTunnelMessage(val) => {
 			format!(
 				"ToRivetTunnelMessage{{messageId: {}, messageKind: {}}}",
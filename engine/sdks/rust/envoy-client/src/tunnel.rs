@@ -166,6 +166,7 @@ async fn handle_ws_open(
 		message_id,
 		path: open.path,
 		headers,
+		compression: open.compression,
 	});
 }
 
@@ -0,0 +1,88 @@
+//! Compression of tunneled websocket message payloads.
+//!
+//! Compression is negotiated per websocket with `ToEnvoyWebSocketOpen.compression` and
+//! `ToRivetWebSocketOpen.compression`. Every message is compressed on its own (no shared context), so
+//! buffered messages can be replayed after hibernation and a rewoken websocket starts uncompressed without
+//! any state to reset on either side.
+
+use anyhow::{Result, bail, ensure};
+
+/// Payloads smaller than this are sent uncompressed.
+pub const MIN_COMPRESS_SIZE: usize = 1024;
+
+/// Upper bound for decompressed payloads when the receiver has no tighter message size limit.
+pub const MAX_DECOMPRESSED_SIZE: usize = 32 * 1024 * 1024;
+
+const SIZE_PREFIX_LEN: usize = 4;
+
+/// Compresses `data` into an lz4 block prefixed with its little endian uncompressed size.
+pub fn compress(data: &[u8]) -> Vec<u8> {
+	lz4_flex::compress_prepend_size(data)
+}
+
+/// Compresses `data` if it is large enough and compression makes it smaller. Returns the payload and whether
+/// it was compressed.
+pub fn maybe_compress(data: Vec<u8>) -> (Vec<u8>, bool) {
+	if data.len() < MIN_COMPRESS_SIZE {
+		return (data, false);
+	}
+
+	let compressed = compress(&data);
+	if compressed.len() < data.len() {
+		(compressed, true)
+	} else {
+		(data, false)
+	}
+}
+
+/// Returns the uncompressed size of a payload created by `compress` without decompressing it.
+pub fn decompressed_size(data: &[u8]) -> Result<usize> {
+	let Some((size, _)) = data.split_first_chunk::<SIZE_PREFIX_LEN>() else {
+		bail!("compressed websocket message is missing its size prefix");
+	};
+
+	Ok(u32::from_le_bytes(*size) as usize)
+}
+
+/// Decompresses a payload created by `compress`. The uncompressed size is checked against `max_size` before
+/// allocating.
+pub fn decompress(data: &[u8], max_size: usize) -> Result<Vec<u8>> {
+	let size = decompressed_size(data)?;
+	ensure!(
+		size <= max_size,
+		"compressed websocket message expands to {size} bytes, max is {max_size}"
+	);
+
+	Ok(lz4_flex::block::decompress(&data[SIZE_PREFIX_LEN..], size)?)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn round_trips_compressible_payload() -> Result<()> {
+		let data = "hello world ".repeat(1024).into_bytes();
+
+		let (payload, compressed) = maybe_compress(data.clone());
+		assert!(compressed);
+		assert!(payload.len() < data.len());
+		assert_eq!(decompressed_size(&payload)?, data.len());
+		assert_eq!(decompress(&payload, data.len())?, data);
+
+		Ok(())
+	}
+
+	#[test]
+	fn skips_small_payload() {
+		let (payload, compressed) = maybe_compress(b"hello".to_vec());
+		assert!(!compressed);
+		assert_eq!(payload, b"hello");
+	}
+
+	#[test]
+	fn rejects_payload_over_max_size() {
+		let payload = compress(&[0; 4096]);
+		assert!(decompress(&payload, 1024).is_err());
+	}
+}
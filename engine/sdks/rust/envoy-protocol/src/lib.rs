@@ -1,8 +1,9 @@
+pub mod compression;
 pub mod generated;
 pub mod util;
 pub mod versioned;
 
 // Re-export latest
-pub use generated::v5::*;
+pub use generated::v6::*;
 
 pub use generated::PROTOCOL_VERSION;
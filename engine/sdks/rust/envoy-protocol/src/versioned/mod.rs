@@ -3,7 +3,7 @@ use std::{error::Error, fmt};
 use anyhow::{Result, bail};
 use vbare::OwnedVersionedData;
 
-use crate::generated::{v1, v2, v3, v4, v5};
+use crate::generated::{v1, v2, v3, v4, v5, v6};
 
 mod v1_to_v2;
 mod v2_to_v1;
@@ -13,6 +13,8 @@ mod v3_to_v4;
 mod v4_to_v3;
 mod v4_to_v5;
 mod v5_to_v4;
+mod v5_to_v6;
+mod v6_to_v5;
 
 // MARK: Protocol compatibility errors
 
@@ -22,6 +24,8 @@ pub enum ProtocolCompatibilityFeature {
 	SqlitePageIo,
 	SqlitePageRange,
 	RemoteSqliteExecution,
+	ActorLogs,
+	WebSocketCompression,
 }
 
 impl ProtocolCompatibilityFeature {
@@ -40,6 +44,8 @@ impl ProtocolCompatibilityFeature {
 				ProtocolCompatibilityDirection::ToEnvoy => "remote sqlite responses",
 				ProtocolCompatibilityDirection::ToRivet => "remote sqlite requests",
 			},
+			ProtocolCompatibilityFeature::ActorLogs => "actor logs",
+			ProtocolCompatibilityFeature::WebSocketCompression => "compressed websocket messages",
 		}
 	}
 }
@@ -64,7 +70,9 @@ impl fmt::Display for ProtocolCompatibilityError {
 			ProtocolCompatibilityFeature::SqliteStartupData => "requires",
 			ProtocolCompatibilityFeature::SqlitePageIo
 			| ProtocolCompatibilityFeature::SqlitePageRange
-			| ProtocolCompatibilityFeature::RemoteSqliteExecution => "require",
+			| ProtocolCompatibilityFeature::RemoteSqliteExecution
+			| ProtocolCompatibilityFeature::ActorLogs
+			| ProtocolCompatibilityFeature::WebSocketCompression => "require",
 		};
 		write!(
 			f,
@@ -102,18 +110,19 @@ pub enum ToEnvoy {
 	V3(v3::ToEnvoy),
 	V4(v4::ToEnvoy),
 	V5(v5::ToEnvoy),
+	V6(v6::ToEnvoy),
 }
 
 impl OwnedVersionedData for ToEnvoy {
-	type Latest = v5::ToEnvoy;
+	type Latest = v6::ToEnvoy;
 
 	fn wrap_latest(latest: Self::Latest) -> Self {
-		Self::V5(latest)
+		Self::V6(latest)
 	}
 
 	fn unwrap_latest(self) -> Result<Self::Latest> {
 		match self {
-			Self::V5(x) => Ok(x),
+			Self::V6(x) => Ok(x),
 			_ => bail!("version not latest"),
 		}
 	}
@@ -125,6 +134,7 @@ impl OwnedVersionedData for ToEnvoy {
 			3 => Ok(Self::V3(serde_bare::from_slice(payload)?)),
 			4 => Ok(Self::V4(serde_bare::from_slice(payload)?)),
 			5 => Ok(Self::V5(serde_bare::from_slice(payload)?)),
+			6 => Ok(Self::V6(serde_bare::from_slice(payload)?)),
 			_ => bail!("invalid version: {version}"),
 		}
 	}
@@ -136,6 +146,7 @@ impl OwnedVersionedData for ToEnvoy {
 			Self::V3(x) => serde_bare::to_vec(&x).map_err(Into::into),
 			Self::V4(x) => serde_bare::to_vec(&x).map_err(Into::into),
 			Self::V5(x) => serde_bare::to_vec(&x).map_err(Into::into),
+			Self::V6(x) => serde_bare::to_vec(&x).map_err(Into::into),
 		}
 	}
 
@@ -145,11 +156,13 @@ impl OwnedVersionedData for ToEnvoy {
 			Self::v2_to_v3,
 			Self::v3_to_v4,
 			Self::v4_to_v5,
+			Self::v5_to_v6,
 		]
 	}
 
 	fn serialize_converters() -> Vec<impl Fn(Self) -> Result<Self>> {
 		vec![
+			Self::v6_to_v5,
 			Self::v5_to_v4,
 			Self::v4_to_v3,
 			Self::v3_to_v2,
@@ -207,6 +220,18 @@ impl ToEnvoy {
 			_ => bail!("unexpected version"),
 		}
 	}
+	fn v5_to_v6(self) -> Result<Self> {
+		match self {
+			Self::V5(x) => Ok(Self::V6(v5_to_v6::convert_to_envoy_v5_to_v6(x)?)),
+			_ => bail!("unexpected version"),
+		}
+	}
+	fn v6_to_v5(self) -> Result<Self> {
+		match self {
+			Self::V6(x) => Ok(Self::V5(v6_to_v5::convert_to_envoy_v6_to_v5(x)?)),
+			_ => bail!("unexpected version"),
+		}
+	}
 }
 
 // MARK: ToRivet
@@ -217,18 +242,19 @@ pub enum ToRivet {
 	V3(v3::ToRivet),
 	V4(v4::ToRivet),
 	V5(v5::ToRivet),
+	V6(v6::ToRivet),
 }
 
 impl OwnedVersionedData for ToRivet {
-	type Latest = v5::ToRivet;
+	type Latest = v6::ToRivet;
 
 	fn wrap_latest(latest: Self::Latest) -> Self {
-		Self::V5(latest)
+		Self::V6(latest)
 	}
 
 	fn unwrap_latest(self) -> Result<Self::Latest> {
 		match self {
-			Self::V5(x) => Ok(x),
+			Self::V6(x) => Ok(x),
 			_ => bail!("version not latest"),
 		}
 	}
@@ -240,6 +266,7 @@ impl OwnedVersionedData for ToRivet {
 			3 => Ok(Self::V3(serde_bare::from_slice(payload)?)),
 			4 => Ok(Self::V4(serde_bare::from_slice(payload)?)),
 			5 => Ok(Self::V5(serde_bare::from_slice(payload)?)),
+			6 => Ok(Self::V6(serde_bare::from_slice(payload)?)),
 			_ => bail!("invalid version: {version}"),
 		}
 	}
@@ -251,6 +278,7 @@ impl OwnedVersionedData for ToRivet {
 			Self::V3(x) => serde_bare::to_vec(&x).map_err(Into::into),
 			Self::V4(x) => serde_bare::to_vec(&x).map_err(Into::into),
 			Self::V5(x) => serde_bare::to_vec(&x).map_err(Into::into),
+			Self::V6(x) => serde_bare::to_vec(&x).map_err(Into::into),
 		}
 	}
 
@@ -260,11 +288,13 @@ impl OwnedVersionedData for ToRivet {
 			Self::v2_to_v3,
 			Self::v3_to_v4,
 			Self::v4_to_v5,
+			Self::v5_to_v6,
 		]
 	}
 
 	fn serialize_converters() -> Vec<impl Fn(Self) -> Result<Self>> {
 		vec![
+			Self::v6_to_v5,
 			Self::v5_to_v4,
 			Self::v4_to_v3,
 			Self::v3_to_v2,
@@ -322,6 +352,18 @@ impl ToRivet {
 			_ => bail!("unexpected version"),
 		}
 	}
+	fn v5_to_v6(self) -> Result<Self> {
+		match self {
+			Self::V5(x) => Ok(Self::V6(v5_to_v6::convert_to_rivet_v5_to_v6(x)?)),
+			_ => bail!("unexpected version"),
+		}
+	}
+	fn v6_to_v5(self) -> Result<Self> {
+		match self {
+			Self::V6(x) => Ok(Self::V5(v6_to_v5::convert_to_rivet_v6_to_v5(x)?)),
+			_ => bail!("unexpected version"),
+		}
+	}
 }
 
 // MARK: ToEnvoyConn
@@ -332,18 +374,19 @@ pub enum ToEnvoyConn {
 	V3(v3::ToEnvoyConn),
 	V4(v4::ToEnvoyConn),
 	V5(v5::ToEnvoyConn),
+	V6(v6::ToEnvoyConn),
 }
 
 impl OwnedVersionedData for ToEnvoyConn {
-	type Latest = v5::ToEnvoyConn;
+	type Latest = v6::ToEnvoyConn;
 
 	fn wrap_latest(latest: Self::Latest) -> Self {
-		Self::V5(latest)
+		Self::V6(latest)
 	}
 
 	fn unwrap_latest(self) -> Result<Self::Latest> {
 		match self {
-			Self::V5(x) => Ok(x),
+			Self::V6(x) => Ok(x),
 			_ => bail!("version not latest"),
 		}
 	}
@@ -355,6 +398,7 @@ impl OwnedVersionedData for ToEnvoyConn {
 			3 => Ok(Self::V3(serde_bare::from_slice(payload)?)),
 			4 => Ok(Self::V4(serde_bare::from_slice(payload)?)),
 			5 => Ok(Self::V5(serde_bare::from_slice(payload)?)),
+			6 => Ok(Self::V6(serde_bare::from_slice(payload)?)),
 			_ => bail!("invalid version: {version}"),
 		}
 	}
@@ -366,6 +410,7 @@ impl OwnedVersionedData for ToEnvoyConn {
 			Self::V3(x) => serde_bare::to_vec(&x).map_err(Into::into),
 			Self::V4(x) => serde_bare::to_vec(&x).map_err(Into::into),
 			Self::V5(x) => serde_bare::to_vec(&x).map_err(Into::into),
+			Self::V6(x) => serde_bare::to_vec(&x).map_err(Into::into),
 		}
 	}
 
@@ -375,11 +420,13 @@ impl OwnedVersionedData for ToEnvoyConn {
 			Self::v2_to_v3,
 			Self::v3_to_v4,
 			Self::v4_to_v5,
+			Self::v5_to_v6,
 		]
 	}
 
 	fn serialize_converters() -> Vec<impl Fn(Self) -> Result<Self>> {
 		vec![
+			Self::v6_to_v5,
 			Self::v5_to_v4,
 			Self::v4_to_v3,
 			Self::v3_to_v2,
@@ -437,6 +484,18 @@ impl ToEnvoyConn {
 			_ => bail!("unexpected version"),
 		}
 	}
+	fn v5_to_v6(self) -> Result<Self> {
+		match self {
+			Self::V5(x) => Ok(Self::V6(v5_to_v6::convert_to_envoy_conn_v5_to_v6(x)?)),
+			_ => bail!("unexpected version"),
+		}
+	}
+	fn v6_to_v5(self) -> Result<Self> {
+		match self {
+			Self::V6(x) => Ok(Self::V5(v6_to_v5::convert_to_envoy_conn_v6_to_v5(x)?)),
+			_ => bail!("unexpected version"),
+		}
+	}
 }
 
 // MARK: ToGateway
@@ -447,18 +506,19 @@ pub enum ToGateway {
 	V3(v3::ToGateway),
 	V4(v4::ToGateway),
 	V5(v5::ToGateway),
+	V6(v6::ToGateway),
 }
 
 impl OwnedVersionedData for ToGateway {
-	type Latest = v5::ToGateway;
+	type Latest = v6::ToGateway;
 
 	fn wrap_latest(latest: Self::Latest) -> Self {
-		Self::V5(latest)
+		Self::V6(latest)
 	}
 
 	fn unwrap_latest(self) -> Result<Self::Latest> {
 		match self {
-			Self::V5(x) => Ok(x),
+			Self::V6(x) => Ok(x),
 			_ => bail!("version not latest"),
 		}
 	}
@@ -470,6 +530,7 @@ impl OwnedVersionedData for ToGateway {
 			3 => Ok(Self::V3(serde_bare::from_slice(payload)?)),
 			4 => Ok(Self::V4(serde_bare::from_slice(payload)?)),
 			5 => Ok(Self::V5(serde_bare::from_slice(payload)?)),
+			6 => Ok(Self::V6(serde_bare::from_slice(payload)?)),
 			_ => bail!("invalid version: {version}"),
 		}
 	}
@@ -481,6 +542,7 @@ impl OwnedVersionedData for ToGateway {
 			Self::V3(x) => serde_bare::to_vec(&x).map_err(Into::into),
 			Self::V4(x) => serde_bare::to_vec(&x).map_err(Into::into),
 			Self::V5(x) => serde_bare::to_vec(&x).map_err(Into::into),
+			Self::V6(x) => serde_bare::to_vec(&x).map_err(Into::into),
 		}
 	}
 
@@ -490,11 +552,13 @@ impl OwnedVersionedData for ToGateway {
 			Self::v2_to_v3,
 			Self::v3_to_v4,
 			Self::v4_to_v5,
+			Self::v5_to_v6,
 		]
 	}
 
 	fn serialize_converters() -> Vec<impl Fn(Self) -> Result<Self>> {
 		vec![
+			Self::v6_to_v5,
 			Self::v5_to_v4,
 			Self::v4_to_v3,
 			Self::v3_to_v2,
@@ -552,6 +616,18 @@ impl ToGateway {
 			_ => bail!("unexpected version"),
 		}
 	}
+	fn v5_to_v6(self) -> Result<Self> {
+		match self {
+			Self::V5(x) => Ok(Self::V6(v5_to_v6::convert_to_gateway_v5_to_v6(x)?)),
+			_ => bail!("unexpected version"),
+		}
+	}
+	fn v6_to_v5(self) -> Result<Self> {
+		match self {
+			Self::V6(x) => Ok(Self::V5(v6_to_v5::convert_to_gateway_v6_to_v5(x)?)),
+			_ => bail!("unexpected version"),
+		}
+	}
 }
 
 // MARK: ToOutbound
@@ -562,18 +638,19 @@ pub enum ToOutbound {
 	V3(v3::ToOutbound),
 	V4(v4::ToOutbound),
 	V5(v5::ToOutbound),
+	V6(v6::ToOutbound),
 }
 
 impl OwnedVersionedData for ToOutbound {
-	type Latest = v5::ToOutbound;
+	type Latest = v6::ToOutbound;
 
 	fn wrap_latest(latest: Self::Latest) -> Self {
-		Self::V5(latest)
+		Self::V6(latest)
 	}
 
 	fn unwrap_latest(self) -> Result<Self::Latest> {
 		match self {
-			Self::V5(x) => Ok(x),
+			Self::V6(x) => Ok(x),
 			_ => bail!("version not latest"),
 		}
 	}
@@ -585,6 +662,7 @@ impl OwnedVersionedData for ToOutbound {
 			3 => Ok(Self::V3(serde_bare::from_slice(payload)?)),
 			4 => Ok(Self::V4(serde_bare::from_slice(payload)?)),
 			5 => Ok(Self::V5(serde_bare::from_slice(payload)?)),
+			6 => Ok(Self::V6(serde_bare::from_slice(payload)?)),
 			_ => bail!("invalid version: {version}"),
 		}
 	}
@@ -596,6 +674,7 @@ impl OwnedVersionedData for ToOutbound {
 			Self::V3(x) => serde_bare::to_vec(&x).map_err(Into::into),
 			Self::V4(x) => serde_bare::to_vec(&x).map_err(Into::into),
 			Self::V5(x) => serde_bare::to_vec(&x).map_err(Into::into),
+			Self::V6(x) => serde_bare::to_vec(&x).map_err(Into::into),
 		}
 	}
 
@@ -605,11 +684,13 @@ impl OwnedVersionedData for ToOutbound {
 			Self::v2_to_v3,
 			Self::v3_to_v4,
 			Self::v4_to_v5,
+			Self::v5_to_v6,
 		]
 	}
 
 	fn serialize_converters() -> Vec<impl Fn(Self) -> Result<Self>> {
 		vec![
+			Self::v6_to_v5,
 			Self::v5_to_v4,
 			Self::v4_to_v3,
 			Self::v3_to_v2,
@@ -667,6 +748,18 @@ impl ToOutbound {
 			_ => bail!("unexpected version"),
 		}
 	}
+	fn v5_to_v6(self) -> Result<Self> {
+		match self {
+			Self::V5(x) => Ok(Self::V6(v5_to_v6::convert_to_outbound_v5_to_v6(x)?)),
+			_ => bail!("unexpected version"),
+		}
+	}
+	fn v6_to_v5(self) -> Result<Self> {
+		match self {
+			Self::V6(x) => Ok(Self::V5(v6_to_v5::convert_to_outbound_v6_to_v5(x)?)),
+			_ => bail!("unexpected version"),
+		}
+	}
 }
 
 // MARK: ActorCommandKeyData
@@ -677,18 +770,19 @@ pub enum ActorCommandKeyData {
 	V3(v3::ActorCommandKeyData),
 	V4(v4::ActorCommandKeyData),
 	V5(v5::ActorCommandKeyData),
+	V6(v6::ActorCommandKeyData),
 }
 
 impl OwnedVersionedData for ActorCommandKeyData {
-	type Latest = v5::ActorCommandKeyData;
+	type Latest = v6::ActorCommandKeyData;
 
 	fn wrap_latest(latest: Self::Latest) -> Self {
-		Self::V5(latest)
+		Self::V6(latest)
 	}
 
 	fn unwrap_latest(self) -> Result<Self::Latest> {
 		match self {
-			Self::V5(x) => Ok(x),
+			Self::V6(x) => Ok(x),
 			_ => bail!("version not latest"),
 		}
 	}
@@ -700,6 +794,7 @@ impl OwnedVersionedData for ActorCommandKeyData {
 			3 => Ok(Self::V3(serde_bare::from_slice(payload)?)),
 			4 => Ok(Self::V4(serde_bare::from_slice(payload)?)),
 			5 => Ok(Self::V5(serde_bare::from_slice(payload)?)),
+			6 => Ok(Self::V6(serde_bare::from_slice(payload)?)),
 			_ => bail!("invalid version: {version}"),
 		}
 	}
@@ -711,6 +806,7 @@ impl OwnedVersionedData for ActorCommandKeyData {
 			Self::V3(x) => serde_bare::to_vec(&x).map_err(Into::into),
 			Self::V4(x) => serde_bare::to_vec(&x).map_err(Into::into),
 			Self::V5(x) => serde_bare::to_vec(&x).map_err(Into::into),
+			Self::V6(x) => serde_bare::to_vec(&x).map_err(Into::into),
 		}
 	}
 
@@ -720,11 +816,13 @@ impl OwnedVersionedData for ActorCommandKeyData {
 			Self::v2_to_v3,
 			Self::v3_to_v4,
 			Self::v4_to_v5,
+			Self::v5_to_v6,
 		]
 	}
 
 	fn serialize_converters() -> Vec<impl Fn(Self) -> Result<Self>> {
 		vec![
+			Self::v6_to_v5,
 			Self::v5_to_v4,
 			Self::v4_to_v3,
 			Self::v3_to_v2,
@@ -798,6 +896,22 @@ impl ActorCommandKeyData {
 			_ => bail!("unexpected version"),
 		}
 	}
+	fn v5_to_v6(self) -> Result<Self> {
+		match self {
+			Self::V5(x) => Ok(Self::V6(v5_to_v6::convert_actor_command_key_data_v5_to_v6(
+				x,
+			)?)),
+			_ => bail!("unexpected version"),
+		}
+	}
+	fn v6_to_v5(self) -> Result<Self> {
+		match self {
+			Self::V6(x) => Ok(Self::V5(v6_to_v5::convert_actor_command_key_data_v6_to_v5(
+				x,
+			)?)),
+			_ => bail!("unexpected version"),
+		}
+	}
 }
 
 // MARK: Tests
@@ -807,15 +921,15 @@ mod tests {
 	use anyhow::Result;
 	use vbare::OwnedVersionedData;
 
-	use super::{ActorCommandKeyData, ToEnvoy};
+	use super::{ActorCommandKeyData, ToEnvoy, ToRivet};
 	use crate::{
 		PROTOCOL_VERSION,
-		generated::{v1, v2, v5},
+		generated::{v1, v2, v6},
 	};
 
 	#[test]
 	fn protocol_version_constant_matches_schema_version() {
-		assert_eq!(PROTOCOL_VERSION, 5);
+		assert_eq!(PROTOCOL_VERSION, 6);
 	}
 
 	#[test]
@@ -840,10 +954,10 @@ mod tests {
 			}]))?;
 
 		let decoded = ToEnvoy::deserialize(&payload, 1)?;
-		let v5::ToEnvoy::ToEnvoyCommands(commands) = decoded else {
+		let v6::ToEnvoy::ToEnvoyCommands(commands) = decoded else {
 			panic!("expected commands");
 		};
-		let v5::Command::CommandStartActor(start) = &commands[0].inner else {
+		let v6::Command::CommandStartActor(start) = &commands[0].inner else {
 			panic!("expected start actor");
 		};
 
@@ -870,9 +984,9 @@ mod tests {
 
 	#[test]
 	fn actor_command_key_data_round_trips_to_v1() -> Result<()> {
-		let encoded = ActorCommandKeyData::wrap_latest(v5::ActorCommandKeyData::CommandStartActor(
-			v5::CommandStartActor {
-				config: v5::ActorConfig {
+		let encoded = ActorCommandKeyData::wrap_latest(v6::ActorCommandKeyData::CommandStartActor(
+			v6::CommandStartActor {
+				config: v6::ActorConfig {
 					name: "demo".into(),
 					key: None,
 					create_ts: 7,
@@ -885,11 +999,88 @@ mod tests {
 		.serialize(1)?;
 
 		let decoded = ActorCommandKeyData::deserialize(&encoded, 1)?;
-		let v5::ActorCommandKeyData::CommandStartActor(start) = decoded else {
+		let v6::ActorCommandKeyData::CommandStartActor(start) = decoded else {
 			panic!("expected start actor");
 		};
 		assert_eq!(start.config.name, "demo");
 
 		Ok(())
 	}
+
+	#[test]
+	fn actor_log_does_not_serialize_to_v5() {
+		let msg = ToRivet::wrap_latest(v6::ToRivet::ToRivetActorLog(v6::ToRivetActorLog {
+			actor_id: "actor".into(),
+			generation: 1,
+			lines: vec![v6::ActorLogLine {
+				ts: 42,
+				stream: v6::ActorLogStream::Stdout,
+				message: "hello".into(),
+			}],
+		}));
+
+		assert!(msg.serialize(5).is_err());
+	}
+
+	#[test]
+	fn compressed_websocket_message_round_trips() -> Result<()> {
+		let data = r#"{"key":"value"}"#.repeat(1024).into_bytes();
+		let (payload, compressed) = crate::compression::maybe_compress(data.clone());
+		assert!(compressed);
+
+		let encoded = ToRivet::wrap_latest(v6::ToRivet::ToRivetTunnelMessage(
+			v6::ToRivetTunnelMessage {
+				message_id: v6::MessageId {
+					gateway_id: [0; 4],
+					request_id: [0; 4],
+					message_index: 0,
+				},
+				message_kind: v6::ToRivetTunnelMessageKind::ToRivetWebSocketMessage(
+					v6::ToRivetWebSocketMessage {
+						data: payload,
+						binary: false,
+						compressed,
+					},
+				),
+			},
+		))
+		.serialize(6)?;
+		assert!(encoded.len() < data.len());
+
+		let v6::ToRivet::ToRivetTunnelMessage(msg) = ToRivet::deserialize(&encoded, 6)? else {
+			panic!("expected tunnel message");
+		};
+		let v6::ToRivetTunnelMessageKind::ToRivetWebSocketMessage(ws_msg) = msg.message_kind else {
+			panic!("expected websocket message");
+		};
+		assert!(ws_msg.compressed);
+		assert_eq!(
+			crate::compression::decompress(&ws_msg.data, data.len())?,
+			data
+		);
+
+		Ok(())
+	}
+
+	#[test]
+	fn compressed_websocket_message_does_not_serialize_to_v5() {
+		let msg = ToEnvoy::wrap_latest(v6::ToEnvoy::ToEnvoyTunnelMessage(
+			v6::ToEnvoyTunnelMessage {
+				message_id: v6::MessageId {
+					gateway_id: [0; 4],
+					request_id: [0; 4],
+					message_index: 0,
+				},
+				message_kind: v6::ToEnvoyTunnelMessageKind::ToEnvoyWebSocketMessage(
+					v6::ToEnvoyWebSocketMessage {
+						data: crate::compression::compress(&[0; 4096]),
+						binary: true,
+						compressed: true,
+					},
+				),
+			},
+		));
+
+		assert!(msg.serialize(5).is_err());
+	}
 }
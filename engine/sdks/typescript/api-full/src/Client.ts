@@ -105,7 +105,20 @@ export class RivetClient {
         request: Rivet.ActorsListRequest,
         requestOptions?: RivetClient.RequestOptions,
     ): Promise<Rivet.ActorsListResponse> {
-        const { namespace, name, key, actorIds, actorId, includeDestroyed, limit, cursor } = request;
+        const {
+            namespace,
+            name,
+            key,
+            actorIds,
+            actorId,
+            tag,
+            includeDestroyed,
+            state,
+            createdBefore,
+            createdAfter,
+            limit,
+            cursor,
+        } = request;
         const _queryParams: Record<string, string | string[] | object | object[] | null> = {};
         _queryParams["namespace"] = namespace;
         if (name != null) {
@@ -130,10 +143,26 @@ export class RivetClient {
             }
         }
 
+        if (tag != null) {
+            _queryParams["tag"] = tag;
+        }
+
         if (includeDestroyed != null) {
             _queryParams["include_destroyed"] = includeDestroyed.toString();
         }
 
+        if (state != null) {
+            _queryParams["state"] = serializers.ActorState.jsonOrThrow(state, { unrecognizedObjectKeys: "strip" });
+        }
+
+        if (createdBefore != null) {
+            _queryParams["created_before"] = createdBefore.toString();
+        }
+
+        if (createdAfter != null) {
+            _queryParams["created_after"] = createdAfter.toString();
+        }
+
         if (limit != null) {
             _queryParams["limit"] = limit.toString();
         }
@@ -385,6 +414,184 @@ export class RivetClient {
         }
     }
 
+    /**
+     * Actors are grouped by target datacenter and each group is created with a single request to that
+     * datacenter. A failure to create one actor does not fail the rest of the batch; each result has
+     * either the created actor or the error for that actor.
+     *
+     * ## Datacenter Round Trips
+     *
+     * 2 round trips:
+     *
+     * - namespace::ops::resolve_for_name_global
+     * - [pegboard::workflows::actor] Create actor workflows (includes Epoxy key allocation)
+     *
+     * Each target datacenter other than the current one adds a POST /actors/batch round trip. All
+     * datacenters are requested in parallel.
+     *
+     * @param {Rivet.ActorsCreateBatchRequest} request
+     * @param {RivetClient.RequestOptions} requestOptions - Request-specific configuration.
+     *
+     * @example
+     *     await client.actorsCreateBatch({
+     *         namespace: "namespace",
+     *         actors: [{
+     *                 name: "name",
+     *                 runnerNameSelector: "runner_name_selector"
+     *             }]
+     *     })
+     */
+    public async actorsCreateBatch(
+        request: Rivet.ActorsCreateBatchRequest,
+        requestOptions?: RivetClient.RequestOptions,
+    ): Promise<Rivet.ActorsCreateBatchResponse> {
+        const { namespace, ..._body } = request;
+        const _queryParams: Record<string, string | string[] | object | object[] | null> = {};
+        _queryParams["namespace"] = namespace;
+        const _response = await (this._options.fetcher ?? core.fetcher)({
+            url: urlJoin(
+                (await core.Supplier.get(this._options.baseUrl)) ??
+                    (await core.Supplier.get(this._options.environment)),
+                "actors/batch",
+            ),
+            method: "POST",
+            headers: {
+                Authorization: await this._getAuthorizationHeader(),
+                "X-Fern-Language": "JavaScript",
+                "X-Fern-Runtime": core.RUNTIME.type,
+                "X-Fern-Runtime-Version": core.RUNTIME.version,
+                ...requestOptions?.headers,
+            },
+            contentType: "application/json",
+            queryParameters: _queryParams,
+            requestType: "json",
+            body: serializers.ActorsCreateBatchRequest.jsonOrThrow(_body, { unrecognizedObjectKeys: "strip" }),
+            timeoutMs: requestOptions?.timeoutInSeconds != null ? requestOptions.timeoutInSeconds * 1000 : 180000,
+            maxRetries: requestOptions?.maxRetries,
+            abortSignal: requestOptions?.abortSignal,
+        });
+        if (_response.ok) {
+            return serializers.ActorsCreateBatchResponse.parseOrThrow(_response.body, {
+                unrecognizedObjectKeys: "passthrough",
+                allowUnrecognizedUnionMembers: true,
+                allowUnrecognizedEnumValues: true,
+                skipValidation: true,
+                breadcrumbsPrefix: ["response"],
+            });
+        }
+
+        if (_response.error.reason === "status-code") {
+            throw new errors.RivetError({
+                statusCode: _response.error.statusCode,
+                body: _response.error.body,
+            });
+        }
+
+        switch (_response.error.reason) {
+            case "non-json":
+                throw new errors.RivetError({
+                    statusCode: _response.error.statusCode,
+                    body: _response.error.rawBody,
+                });
+            case "timeout":
+                throw new errors.RivetTimeoutError("Timeout exceeded when calling POST /actors/batch.");
+            case "unknown":
+                throw new errors.RivetError({
+                    message: _response.error.errorMessage,
+                });
+        }
+    }
+
+    /**
+     * Each actor is destroyed the same as `DELETE /actors/{actor_id}`. A failure to destroy one actor
+     * does not fail the rest of the batch. A filter destroys a limited number of actors per
+     * datacenter, send the request again while `has_more` is true to destroy the rest.
+     *
+     * ## Datacenter Round Trips
+     *
+     * **If destroying by actor IDs**
+     *
+     * 2 round trips:
+     *
+     * - POST /actors/destroy-batch (one per datacenter the actors live in)
+     * - [api-peer] namespace::ops::resolve_for_name_global
+     *
+     * **If destroying by filter**
+     *
+     * 2 round trips:
+     *
+     * - POST /actors/destroy-batch (fanout)
+     * - [api-peer] namespace::ops::resolve_for_name_global
+     *
+     * @param {Rivet.ActorsDestroyBatchRequest} request
+     * @param {RivetClient.RequestOptions} requestOptions - Request-specific configuration.
+     *
+     * @example
+     *     await client.actorsDestroyBatch({
+     *         namespace: "namespace"
+     *     })
+     */
+    public async actorsDestroyBatch(
+        request: Rivet.ActorsDestroyBatchRequest,
+        requestOptions?: RivetClient.RequestOptions,
+    ): Promise<Rivet.ActorsDestroyBatchResponse> {
+        const { namespace, ..._body } = request;
+        const _queryParams: Record<string, string | string[] | object | object[] | null> = {};
+        _queryParams["namespace"] = namespace;
+        const _response = await (this._options.fetcher ?? core.fetcher)({
+            url: urlJoin(
+                (await core.Supplier.get(this._options.baseUrl)) ??
+                    (await core.Supplier.get(this._options.environment)),
+                "actors/destroy-batch",
+            ),
+            method: "POST",
+            headers: {
+                Authorization: await this._getAuthorizationHeader(),
+                "X-Fern-Language": "JavaScript",
+                "X-Fern-Runtime": core.RUNTIME.type,
+                "X-Fern-Runtime-Version": core.RUNTIME.version,
+                ...requestOptions?.headers,
+            },
+            contentType: "application/json",
+            queryParameters: _queryParams,
+            requestType: "json",
+            body: serializers.ActorsDestroyBatchRequest.jsonOrThrow(_body, { unrecognizedObjectKeys: "strip" }),
+            timeoutMs: requestOptions?.timeoutInSeconds != null ? requestOptions.timeoutInSeconds * 1000 : 180000,
+            maxRetries: requestOptions?.maxRetries,
+            abortSignal: requestOptions?.abortSignal,
+        });
+        if (_response.ok) {
+            return serializers.ActorsDestroyBatchResponse.parseOrThrow(_response.body, {
+                unrecognizedObjectKeys: "passthrough",
+                allowUnrecognizedUnionMembers: true,
+                allowUnrecognizedEnumValues: true,
+                skipValidation: true,
+                breadcrumbsPrefix: ["response"],
+            });
+        }
+
+        if (_response.error.reason === "status-code") {
+            throw new errors.RivetError({
+                statusCode: _response.error.statusCode,
+                body: _response.error.body,
+            });
+        }
+
+        switch (_response.error.reason) {
+            case "non-json":
+                throw new errors.RivetError({
+                    statusCode: _response.error.statusCode,
+                    body: _response.error.rawBody,
+                });
+            case "timeout":
+                throw new errors.RivetTimeoutError("Timeout exceeded when calling POST /actors/destroy-batch.");
+            case "unknown":
+                throw new errors.RivetError({
+                    message: _response.error.errorMessage,
+                });
+        }
+    }
+
     /**
      * 2 round trips:
      *
@@ -618,6 +825,183 @@ export class RivetClient {
         }
     }
 
+    /**
+     * With `follow`, responds with a `text/event-stream` instead where each `log` event holds one line
+     * as JSON. The event id is the line index, pass it as `after` to resume.
+     *
+     * @param {Rivet.RivetId} actorId
+     * @param {Rivet.ActorsLogsRequest} request
+     * @param {RivetClient.RequestOptions} requestOptions - Request-specific configuration.
+     *
+     * @example
+     *     await client.actorsLogs("actor_id", {
+     *         namespace: "namespace"
+     *     })
+     */
+    public async actorsLogs(
+        actorId: Rivet.RivetId,
+        request: Rivet.ActorsLogsRequest,
+        requestOptions?: RivetClient.RequestOptions,
+    ): Promise<Rivet.ActorsLogsResponse> {
+        const { namespace, after, limit, follow } = request;
+        const _queryParams: Record<string, string | string[] | object | object[] | null> = {};
+        _queryParams["namespace"] = namespace;
+        if (after != null) {
+            _queryParams["after"] = after.toString();
+        }
+
+        if (limit != null) {
+            _queryParams["limit"] = limit.toString();
+        }
+
+        if (follow != null) {
+            _queryParams["follow"] = follow.toString();
+        }
+
+        const _response = await (this._options.fetcher ?? core.fetcher)({
+            url: urlJoin(
+                (await core.Supplier.get(this._options.baseUrl)) ??
+                    (await core.Supplier.get(this._options.environment)),
+                `actors/${encodeURIComponent(serializers.RivetId.jsonOrThrow(actorId))}/logs`,
+            ),
+            method: "GET",
+            headers: {
+                Authorization: await this._getAuthorizationHeader(),
+                "X-Fern-Language": "JavaScript",
+                "X-Fern-Runtime": core.RUNTIME.type,
+                "X-Fern-Runtime-Version": core.RUNTIME.version,
+                ...requestOptions?.headers,
+            },
+            contentType: "application/json",
+            queryParameters: _queryParams,
+            requestType: "json",
+            timeoutMs: requestOptions?.timeoutInSeconds != null ? requestOptions.timeoutInSeconds * 1000 : 180000,
+            maxRetries: requestOptions?.maxRetries,
+            abortSignal: requestOptions?.abortSignal,
+        });
+        if (_response.ok) {
+            return serializers.ActorsLogsResponse.parseOrThrow(_response.body, {
+                unrecognizedObjectKeys: "passthrough",
+                allowUnrecognizedUnionMembers: true,
+                allowUnrecognizedEnumValues: true,
+                skipValidation: true,
+                breadcrumbsPrefix: ["response"],
+            });
+        }
+
+        if (_response.error.reason === "status-code") {
+            throw new errors.RivetError({
+                statusCode: _response.error.statusCode,
+                body: _response.error.body,
+            });
+        }
+
+        switch (_response.error.reason) {
+            case "non-json":
+                throw new errors.RivetError({
+                    statusCode: _response.error.statusCode,
+                    body: _response.error.rawBody,
+                });
+            case "timeout":
+                throw new errors.RivetTimeoutError("Timeout exceeded when calling GET /actors/{actor_id}/logs.");
+            case "unknown":
+                throw new errors.RivetError({
+                    message: _response.error.errorMessage,
+                });
+        }
+    }
+
+    /**
+     * The request body and headers are forwarded to the actor through the gateway and the actor's
+     * response is returned as is. The ID of the actor that handled the request is returned in the
+     * `x-rivet-actor` header.
+     *
+     * @param {string} name
+     * @param {string} key
+     * @param {Rivet.ActorsRequestRequest} request
+     * @param {RivetClient.RequestOptions} requestOptions - Request-specific configuration.
+     *
+     * @example
+     *     await client.actorsRequest("name", "key", {
+     *         namespace: "namespace",
+     *         runnerNameSelector: "runner_name_selector",
+     *         body: "string"
+     *     })
+     */
+    public async actorsRequest(
+        name: string,
+        key: string,
+        request: Rivet.ActorsRequestRequest,
+        requestOptions?: RivetClient.RequestOptions,
+    ): Promise<void> {
+        const { namespace, runnerNameSelector, datacenter, crashPolicy, path, body: _body } = request;
+        const _queryParams: Record<string, string | string[] | object | object[] | null> = {};
+        _queryParams["namespace"] = namespace;
+        _queryParams["runner_name_selector"] = runnerNameSelector;
+        if (datacenter != null) {
+            _queryParams["datacenter"] = datacenter;
+        }
+
+        if (crashPolicy != null) {
+            _queryParams["crash_policy"] = serializers.CrashPolicy.jsonOrThrow(crashPolicy, {
+                unrecognizedObjectKeys: "strip",
+            });
+        }
+
+        if (path != null) {
+            _queryParams["path"] = path;
+        }
+
+        const _response = await (this._options.fetcher ?? core.fetcher)({
+            url: urlJoin(
+                (await core.Supplier.get(this._options.baseUrl)) ??
+                    (await core.Supplier.get(this._options.environment)),
+                `actors/by-key/${encodeURIComponent(name)}/${encodeURIComponent(key)}/request`,
+            ),
+            method: "POST",
+            headers: {
+                Authorization: await this._getAuthorizationHeader(),
+                "X-Fern-Language": "JavaScript",
+                "X-Fern-Runtime": core.RUNTIME.type,
+                "X-Fern-Runtime-Version": core.RUNTIME.version,
+                ...requestOptions?.headers,
+            },
+            contentType: "application/octet-stream",
+            queryParameters: _queryParams,
+            requestType: "bytes",
+            body: _body,
+            timeoutMs: requestOptions?.timeoutInSeconds != null ? requestOptions.timeoutInSeconds * 1000 : 180000,
+            maxRetries: requestOptions?.maxRetries,
+            abortSignal: requestOptions?.abortSignal,
+        });
+        if (_response.ok) {
+            return;
+        }
+
+        if (_response.error.reason === "status-code") {
+            throw new errors.RivetError({
+                statusCode: _response.error.statusCode,
+                body: _response.error.body,
+            });
+        }
+
+        switch (_response.error.reason) {
+            case "non-json":
+                throw new errors.RivetError({
+                    statusCode: _response.error.statusCode,
+                    body: _response.error.rawBody,
+                });
+            case "timeout":
+                throw new errors.RivetTimeoutError(
+                    "Timeout exceeded when calling POST /actors/by-key/{name}/{key}/request.",
+                );
+            case "unknown":
+                throw new errors.RivetError({
+                    message: _response.error.errorMessage,
+                });
+        }
+    }
+
     /**
      * @param {Rivet.RivetId} actorId
      * @param {Rivet.ActorsRescheduleRequest} request
@@ -0,0 +1,20 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+import * as Rivet from "../../index";
+
+/**
+ * @example
+ *     {
+ *         namespace: "namespace",
+ *         actors: [{
+ *                 name: "name",
+ *                 runnerNameSelector: "runner_name_selector"
+ *             }]
+ *     }
+ */
+export interface ActorsCreateBatchRequest {
+    namespace: string;
+    actors: Omit<Rivet.ActorsCreateRequest, "namespace">[];
+}
@@ -8,18 +8,24 @@ import * as Rivet from "../../index";
  * @example
  *     {
  *         namespace: "namespace",
- *         crashPolicy: "restart",
  *         name: "name",
  *         runnerNameSelector: "runner_name_selector"
  *     }
  */
 export interface ActorsCreateRequest {
     namespace: string;
-    crashPolicy: Rivet.CrashPolicy;
+    /** Soft placement rules relative to other actors in this datacenter. */
+    affinity?: Rivet.ActorAffinity;
+    /** Defaults to the namespace's default crash policy, or `destroy` if the namespace has none. */
+    crashPolicy?: Rivet.CrashPolicy;
     datacenter?: string;
     /** Arbitrary base64 encoded binary data. */
     input?: string;
     key?: string;
     name: string;
+    /** Capabilities a runner must advertise for this actor to be allocated to it. */
+    requiredCapabilities?: string[];
     runnerNameSelector: string;
+    /** Free-form tags used to group and filter actors. See the `tag` query of the list endpoint. */
+    tags?: Record<string, string>;
 }
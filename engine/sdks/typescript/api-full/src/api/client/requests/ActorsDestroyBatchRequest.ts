@@ -0,0 +1,25 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+import * as Rivet from "../../index";
+
+/**
+ * @example
+ *     {
+ *         namespace: "namespace"
+ *     }
+ */
+export interface ActorsDestroyBatchRequest {
+    namespace: string;
+    actorIds?: Rivet.RivetId[];
+    /**
+     * Only destroy actors with `name` whose key starts with this prefix. Actors without a key are
+     * skipped.
+     */
+    keyPrefix?: string;
+    /**
+     * Destroy every active actor with this name.
+     */
+    name?: string;
+}
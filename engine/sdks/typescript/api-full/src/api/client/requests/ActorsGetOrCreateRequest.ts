@@ -8,7 +8,6 @@ import * as Rivet from "../../index";
  * @example
  *     {
  *         namespace: "namespace",
- *         crashPolicy: "restart",
  *         key: "key",
  *         name: "name",
  *         runnerNameSelector: "runner_name_selector"
@@ -16,7 +15,8 @@ import * as Rivet from "../../index";
  */
 export interface ActorsGetOrCreateRequest {
     namespace: string;
-    crashPolicy: Rivet.CrashPolicy;
+    /** Defaults to the namespace's default crash policy, or `destroy` if the namespace has none. */
+    crashPolicy?: Rivet.CrashPolicy;
     datacenter?: string;
     input?: string;
     key: string;
@@ -19,7 +19,24 @@ export interface ActorsListRequest {
      */
     actorIds?: string;
     actorId?: Rivet.RivetId | Rivet.RivetId[];
+    /**
+     * Only list active actors with all of these tags, formatted as `key=value`. Cannot be combined
+     * with `name`, `key`, or `actor_id`.
+     */
+    tag?: string | string[];
     includeDestroyed?: boolean;
+    /**
+     * Only list actors in this state. Takes precedence over `include_destroyed`.
+     */
+    state?: Rivet.ActorState;
+    /**
+     * Only list actors created strictly before this timestamp.
+     */
+    createdBefore?: number;
+    /**
+     * Only list actors created strictly after this timestamp.
+     */
+    createdAfter?: number;
     limit?: number;
     cursor?: string;
 }
@@ -0,0 +1,22 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+/**
+ * @example
+ *     {
+ *         namespace: "namespace"
+ *     }
+ */
+export interface ActorsLogsRequest {
+    namespace: string;
+    /**
+     * Only return lines with an index greater than this.
+     */
+    after?: number;
+    limit?: number;
+    /**
+     * Stream new lines as server-sent events instead of returning once.
+     */
+    follow?: boolean;
+}
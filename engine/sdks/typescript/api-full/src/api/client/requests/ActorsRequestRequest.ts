@@ -0,0 +1,34 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+import * as Rivet from "../../index";
+
+/**
+ * @example
+ *     {
+ *         namespace: "namespace",
+ *         runnerNameSelector: "runner_name_selector",
+ *         body: "string"
+ *     }
+ */
+export interface ActorsRequestRequest {
+    namespace: string;
+    /**
+     * Runner the actor is created on if it does not exist yet.
+     */
+    runnerNameSelector: string;
+    /**
+     * Datacenter the actor is created in if it does not exist yet.
+     */
+    datacenter?: string;
+    /**
+     * Defaults to the namespace's default crash policy, or `destroy` if the namespace has none.
+     */
+    crashPolicy?: Rivet.CrashPolicy;
+    /**
+     * Path and query string of the request sent to the actor. Defaults to `/`.
+     */
+    path?: string;
+    body: string;
+}
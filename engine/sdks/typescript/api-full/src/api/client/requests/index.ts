@@ -1,9 +1,13 @@
 export { type ActorsListRequest } from "./ActorsListRequest";
 export { type ActorsCreateRequest } from "./ActorsCreateRequest";
 export { type ActorsGetOrCreateRequest } from "./ActorsGetOrCreateRequest";
+export { type ActorsCreateBatchRequest } from "./ActorsCreateBatchRequest";
+export { type ActorsDestroyBatchRequest } from "./ActorsDestroyBatchRequest";
 export { type ActorsListNamesRequest } from "./ActorsListNamesRequest";
 export { type ActorsDeleteRequest } from "./ActorsDeleteRequest";
 export { type ActorsKvGetRequest } from "./ActorsKvGetRequest";
+export { type ActorsLogsRequest } from "./ActorsLogsRequest";
+export { type ActorsRequestRequest } from "./ActorsRequestRequest";
 export { type ActorsRescheduleRequest } from "./ActorsRescheduleRequest";
 export { type ActorsSleepRequest } from "./ActorsSleepRequest";
 export { type RunnerConfigsListRequest } from "./RunnerConfigsListRequest";
@@ -2,6 +2,8 @@
  * This file was auto-generated by Fern from our API Definition.
  */
 
+import * as Rivet from "../../../../index";
+
 /**
  * @example
  *     {
@@ -10,6 +12,18 @@
  *     }
  */
 export interface NamespacesCreateRequest {
+    /**
+     * Rate limit the gateway applies to requests for each actor in this namespace, tracked per
+     * client IP. Requests are not rate limited per actor if not set.
+     */
+    actorRateLimit?: Rivet.ActorRateLimit;
+    /**
+     * CORS policy the gateway applies to requests for actors in this namespace. All origins,
+     * methods and headers are allowed if not set.
+     */
+    corsPolicy?: Rivet.CorsPolicy;
+    /** Crash policy used for actors created in this namespace without an explicit crash policy. */
+    defaultCrashPolicy?: Rivet.CrashPolicy;
     displayName: string;
     name: string;
 }
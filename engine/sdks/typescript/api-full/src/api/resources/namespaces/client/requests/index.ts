@@ -1,2 +1,3 @@
 export { type NamespacesListRequest } from "./NamespacesListRequest";
 export { type NamespacesCreateRequest } from "./NamespacesCreateRequest";
+export { type NamespacesUpdateRequest } from "./NamespacesUpdateRequest";
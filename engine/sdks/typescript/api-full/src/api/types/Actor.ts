@@ -21,6 +21,8 @@ export interface Actor {
     namespaceId: Rivet.RivetId;
     /** Denotes when the actor started waiting for an allocation. */
     pendingAllocationTs?: number;
+    /** Explains why the actor is waiting for an allocation. Null if the actor is not pending. */
+    pendingReason?: Rivet.PendingReason;
     /**
      * Denotes when the actor will try to allocate again. If this is set, the actor will not attempt to
      * allocate until the given timestamp.
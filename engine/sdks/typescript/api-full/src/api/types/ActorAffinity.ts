@@ -0,0 +1,16 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+import * as Rivet from "../index";
+
+/**
+ * Soft placement rules for an actor relative to other actors. The allocator honors them when a
+ * matching runner has capacity and ignores them otherwise.
+ */
+export interface ActorAffinity {
+    /** Avoid runners that are already running any of these actors. */
+    avoid?: Rivet.RivetId[];
+    /** Prefer runners that are already running any of these actors. */
+    colocateWith?: Rivet.RivetId[];
+}
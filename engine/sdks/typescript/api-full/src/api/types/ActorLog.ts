@@ -0,0 +1,19 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+import * as Rivet from "../index";
+
+/**
+ * Line of output forwarded by the runner hosting an actor.
+ */
+export interface ActorLog {
+    /** Generation of the actor that wrote the line. */
+    generation: number;
+    /** Position of the line in the actor's log. Lines are returned in ascending order. */
+    idx: number;
+    message: string;
+    stream: Rivet.ActorLogStream;
+    /** Denotes when the line was written by the actor. */
+    ts: number;
+}
@@ -0,0 +1,9 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+export type ActorLogStream = "stdout" | "stderr";
+export const ActorLogStream = {
+    Stdout: "stdout",
+    Stderr: "stderr",
+} as const;
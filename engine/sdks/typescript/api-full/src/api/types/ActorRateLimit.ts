@@ -0,0 +1,13 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+/**
+ * Token bucket rate limit for requests to a single actor from a single client IP.
+ */
+export interface ActorRateLimit {
+    /** Maximum number of requests allowed in a burst. Defaults to `requests_per_second`. */
+    burst?: number;
+    /** Sustained number of requests allowed per second. */
+    requestsPerSecond: number;
+}
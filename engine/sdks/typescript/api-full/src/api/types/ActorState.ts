@@ -0,0 +1,15 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+/**
+ * Lifecycle state of an actor, derived from its timestamps.
+ */
+export type ActorState = "pending_allocation" | "starting" | "running" | "sleeping" | "destroyed";
+export const ActorState = {
+    PendingAllocation: "pending_allocation",
+    Starting: "starting",
+    Running: "running",
+    Sleeping: "sleeping",
+    Destroyed: "destroyed",
+} as const;
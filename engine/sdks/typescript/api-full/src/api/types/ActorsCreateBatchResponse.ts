@@ -0,0 +1,10 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+import * as Rivet from "../index";
+
+export interface ActorsCreateBatchResponse {
+    /** One result per requested actor, in the same order as the request. */
+    results: Rivet.ActorsCreateBatchResult[];
+}
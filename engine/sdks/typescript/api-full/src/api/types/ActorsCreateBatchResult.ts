@@ -0,0 +1,13 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+import * as Rivet from "../index";
+
+/**
+ * Exactly one of `actor` or `error` is set.
+ */
+export interface ActorsCreateBatchResult {
+    actor?: Rivet.Actor;
+    error?: Rivet.ActorsCreateBatchError;
+}
@@ -0,0 +1,9 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+export interface ActorsDestroyBatchError {
+    code: string;
+    group: string;
+    message: string;
+}
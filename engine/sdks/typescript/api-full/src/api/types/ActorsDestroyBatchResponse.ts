@@ -0,0 +1,18 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+import * as Rivet from "../index";
+
+export interface ActorsDestroyBatchResponse {
+    /**
+     * More actors match `name` and `key_prefix` than this request destroyed. Send the same request
+     * again until this is false.
+     */
+    hasMore: boolean;
+    /**
+     * One result per actor this request tried to destroy. When destroying by `actor_ids`, in the
+     * same order as the request.
+     */
+    results: Rivet.ActorsDestroyBatchResult[];
+}
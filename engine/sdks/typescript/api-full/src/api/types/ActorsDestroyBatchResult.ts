@@ -0,0 +1,13 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+import * as Rivet from "../index";
+
+/**
+ * The actor was destroyed unless `error` is set.
+ */
+export interface ActorsDestroyBatchResult {
+    actorId: Rivet.RivetId;
+    error?: Rivet.ActorsDestroyBatchError;
+}
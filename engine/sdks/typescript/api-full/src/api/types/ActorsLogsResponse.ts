@@ -0,0 +1,9 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+import * as Rivet from "../index";
+
+export interface ActorsLogsResponse {
+    logs: Rivet.ActorLog[];
+}
@@ -0,0 +1,21 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+/**
+ * CORS policy for requests to actors through the gateway. Unset fields keep the permissive
+ * default.
+ */
+export interface CorsPolicy {
+    /** Headers allowed by preflight requests. Any requested header is allowed if not set. */
+    allowedHeaders?: string[];
+    /** Methods allowed by preflight requests. Defaults to `GET, POST, PUT, DELETE, OPTIONS, PATCH`. */
+    allowedMethods?: string[];
+    /**
+     * Origins allowed to make requests, e.g. `https://example.com`. Any origin is allowed if not
+     * set.
+     */
+    allowedOrigins?: string[];
+    /** How long browsers may cache a preflight response in seconds. Defaults to 86400. */
+    maxAge?: number;
+}
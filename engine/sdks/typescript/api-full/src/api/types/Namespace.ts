@@ -5,7 +5,19 @@
 import * as Rivet from "../index";
 
 export interface Namespace {
+    /**
+     * Rate limit the gateway applies to requests for each actor in this namespace, tracked per
+     * client IP. Requests are not rate limited per actor if not set.
+     */
+    actorRateLimit?: Rivet.ActorRateLimit;
     createTs: number;
+    /**
+     * CORS policy the gateway applies to requests for actors in this namespace. All origins,
+     * methods and headers are allowed if not set.
+     */
+    corsPolicy?: Rivet.CorsPolicy;
+    /** Crash policy used for actors created in this namespace without an explicit crash policy. */
+    defaultCrashPolicy?: Rivet.CrashPolicy;
     displayName: string;
     name: string;
     namespaceId: Rivet.RivetId;
@@ -0,0 +1,13 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+/**
+ * Reason an actor could not be allocated to a runner and is waiting in the allocation queue.
+ */
+export type PendingReason = "no_runner_matches_selector" | "all_runners_draining" | "no_capacity";
+export const PendingReason = {
+    NoRunnerMatchesSelector: "no_runner_matches_selector",
+    AllRunnersDraining: "all_runners_draining",
+    NoCapacity: "no_capacity",
+} as const;
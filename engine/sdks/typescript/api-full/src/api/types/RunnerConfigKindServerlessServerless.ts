@@ -2,6 +2,8 @@
  * This file was auto-generated by Fern from our API Definition.
  */
 
+import * as Rivet from "../index";
+
 export interface RunnerConfigKindServerlessServerless {
     /** Seconds. */
     actorEvictionDelay?: number;
@@ -9,6 +11,13 @@ export interface RunnerConfigKindServerlessServerless {
     actorEvictionPeriod?: number;
     /** Actors per second. */
     actorEvictionRate?: number;
+    auth?: Rivet.ServerlessAuth;
+    /**
+     * Body sent with the `/start` request. `{{endpoint}}`, `{{namespace_name}}`,
+     * `{{runner_name}}` and `{{total_slots}}` are replaced before sending. Not supported by
+     * envoy pools, which send the start command as the body.
+     */
+    body?: string;
     /** Seconds. */
     drainGracePeriod?: number;
     drainOnVersionUpgrade?: boolean;
@@ -16,6 +25,11 @@ export interface RunnerConfigKindServerlessServerless {
     maxConcurrentActors?: number;
     /** Deprecated. */
     maxRunners?: number;
+    /**
+     * HTTP method used for the `/start` request. Defaults to `get`. Envoy pools always POST
+     * the start command.
+     */
+    method?: Rivet.ServerlessRequestMethod;
     /** Milliseconds between metadata polling. If not set, uses the global default. */
     metadataPollInterval?: number;
     /** Deprecated. */
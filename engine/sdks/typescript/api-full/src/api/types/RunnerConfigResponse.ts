@@ -7,4 +7,6 @@ import * as Rivet from "../index";
 export interface RunnerConfigResponse extends Rivet.RunnerConfig {
     protocolVersion?: number;
     runnerPoolError?: Record<string, unknown>;
+    /** Groups `runner_pool_error` by cause (e.g. `auth`, `timeout`). */
+    runnerPoolErrorCategory?: string;
 }
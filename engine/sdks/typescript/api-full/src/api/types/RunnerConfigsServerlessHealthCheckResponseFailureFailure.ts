@@ -6,4 +6,6 @@ import * as Rivet from "../index";
 
 export interface RunnerConfigsServerlessHealthCheckResponseFailureFailure {
     error: Rivet.RunnerConfigsServerlessMetadataError;
+    /** Time until the probe request failed in milliseconds. */
+    latencyMs: number;
 }
@@ -3,5 +3,7 @@
  */
 
 export interface RunnerConfigsServerlessHealthCheckResponseSuccessSuccess {
+    /** Round trip time of the probe request in milliseconds. */
+    latencyMs: number;
     version: string;
 }
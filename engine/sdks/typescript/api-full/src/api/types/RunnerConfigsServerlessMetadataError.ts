@@ -8,7 +8,7 @@
  * Surfaced to API clients with a stable `{message, details, metadata}` shape
  * regardless of which internal `ServerlessMetadataError` variant produced it.
  * `metadata.kind` discriminates the variant; per-variant fields live alongside
- * `kind`.
+ * `kind`. `metadata.category` groups the failure by cause (e.g. `auth`, `timeout`).
  */
 export interface RunnerConfigsServerlessMetadataError {
     details?: string;
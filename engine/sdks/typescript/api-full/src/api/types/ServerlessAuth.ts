@@ -0,0 +1,10 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+import * as Rivet from "../index";
+
+/**
+ * How the engine authenticates requests to a serverless endpoint.
+ */
+export type ServerlessAuth = Rivet.ServerlessAuthBearer | Rivet.ServerlessAuthBearerEnv;
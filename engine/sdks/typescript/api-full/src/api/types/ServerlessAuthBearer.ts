@@ -0,0 +1,12 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+import * as Rivet from "../index";
+
+/**
+ * Sends the given token as `Authorization: Bearer <token>`.
+ */
+export interface ServerlessAuthBearer {
+    bearer: Rivet.ServerlessAuthBearerBearer;
+}
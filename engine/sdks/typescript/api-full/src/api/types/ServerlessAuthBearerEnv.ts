@@ -0,0 +1,14 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+import * as Rivet from "../index";
+
+/**
+ * Sends the value of the given environment variable on the engine as
+ * `Authorization: Bearer <token>`. The variable must be listed in the engine's
+ * `pegboard.serverless_auth_env_allowlist`.
+ */
+export interface ServerlessAuthBearerEnv {
+    bearerEnv: Rivet.ServerlessAuthBearerEnvBearerEnv;
+}
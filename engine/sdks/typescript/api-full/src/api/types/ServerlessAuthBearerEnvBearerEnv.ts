@@ -0,0 +1,7 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+export interface ServerlessAuthBearerEnvBearerEnv {
+    env: string;
+}
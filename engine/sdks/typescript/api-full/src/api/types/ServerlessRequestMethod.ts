@@ -0,0 +1,9 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+export type ServerlessRequestMethod = "get" | "post";
+export const ServerlessRequestMethod = {
+    Get: "get",
+    Post: "post",
+} as const;
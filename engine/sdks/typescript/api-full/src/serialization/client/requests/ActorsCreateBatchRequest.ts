@@ -0,0 +1,21 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+import * as serializers from "../../index";
+import * as Rivet from "../../../api/index";
+import * as core from "../../../core";
+import { ActorsCreateRequest } from "./ActorsCreateRequest";
+
+export const ActorsCreateBatchRequest: core.serialization.Schema<
+    serializers.ActorsCreateBatchRequest.Raw,
+    Omit<Rivet.ActorsCreateBatchRequest, "namespace">
+> = core.serialization.object({
+    actors: core.serialization.list(ActorsCreateRequest),
+});
+
+export declare namespace ActorsCreateBatchRequest {
+    export interface Raw {
+        actors: ActorsCreateRequest.Raw[];
+    }
+}
@@ -5,27 +5,37 @@
 import * as serializers from "../../index";
 import * as Rivet from "../../../api/index";
 import * as core from "../../../core";
+import { ActorAffinity } from "../../types/ActorAffinity";
 import { CrashPolicy } from "../../types/CrashPolicy";
 
 export const ActorsCreateRequest: core.serialization.Schema<
     serializers.ActorsCreateRequest.Raw,
     Omit<Rivet.ActorsCreateRequest, "namespace">
 > = core.serialization.object({
-    crashPolicy: core.serialization.property("crash_policy", CrashPolicy),
+    affinity: ActorAffinity.optional(),
+    crashPolicy: core.serialization.property("crash_policy", CrashPolicy.optional()),
     datacenter: core.serialization.string().optional(),
     input: core.serialization.string().optional(),
     key: core.serialization.string().optional(),
     name: core.serialization.string(),
+    requiredCapabilities: core.serialization.property(
+        "required_capabilities",
+        core.serialization.list(core.serialization.string()).optional(),
+    ),
     runnerNameSelector: core.serialization.property("runner_name_selector", core.serialization.string()),
+    tags: core.serialization.record(core.serialization.string(), core.serialization.string()).optional(),
 });
 
 export declare namespace ActorsCreateRequest {
     export interface Raw {
-        crash_policy: CrashPolicy.Raw;
+        affinity?: ActorAffinity.Raw | null;
+        crash_policy?: CrashPolicy.Raw | null;
         datacenter?: string | null;
         input?: string | null;
         key?: string | null;
         name: string;
+        required_capabilities?: string[] | null;
         runner_name_selector: string;
+        tags?: Record<string, string> | null;
     }
 }
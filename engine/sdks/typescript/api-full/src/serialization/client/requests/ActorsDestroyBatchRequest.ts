@@ -0,0 +1,25 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+import * as serializers from "../../index";
+import * as Rivet from "../../../api/index";
+import * as core from "../../../core";
+import { RivetId } from "../../types/RivetId";
+
+export const ActorsDestroyBatchRequest: core.serialization.Schema<
+    serializers.ActorsDestroyBatchRequest.Raw,
+    Omit<Rivet.ActorsDestroyBatchRequest, "namespace">
+> = core.serialization.object({
+    actorIds: core.serialization.property("actor_ids", core.serialization.list(RivetId).optional()),
+    keyPrefix: core.serialization.property("key_prefix", core.serialization.string().optional()),
+    name: core.serialization.string().optional(),
+});
+
+export declare namespace ActorsDestroyBatchRequest {
+    export interface Raw {
+        actor_ids?: RivetId.Raw[] | null;
+        key_prefix?: string | null;
+        name?: string | null;
+    }
+}
@@ -11,7 +11,7 @@ export const ActorsGetOrCreateRequest: core.serialization.Schema<
     serializers.ActorsGetOrCreateRequest.Raw,
     Omit<Rivet.ActorsGetOrCreateRequest, "namespace">
 > = core.serialization.object({
-    crashPolicy: core.serialization.property("crash_policy", CrashPolicy),
+    crashPolicy: core.serialization.property("crash_policy", CrashPolicy.optional()),
     datacenter: core.serialization.string().optional(),
     input: core.serialization.string().optional(),
     key: core.serialization.string(),
@@ -21,7 +21,7 @@ export const ActorsGetOrCreateRequest: core.serialization.Schema<
 
 export declare namespace ActorsGetOrCreateRequest {
     export interface Raw {
-        crash_policy: CrashPolicy.Raw;
+        crash_policy?: CrashPolicy.Raw | null;
         datacenter?: string | null;
         input?: string | null;
         key: string;
@@ -1,4 +1,6 @@
 export { ActorsCreateRequest } from "./ActorsCreateRequest";
 export { ActorsGetOrCreateRequest } from "./ActorsGetOrCreateRequest";
+export { ActorsCreateBatchRequest } from "./ActorsCreateBatchRequest";
+export { ActorsDestroyBatchRequest } from "./ActorsDestroyBatchRequest";
 export { RunnerConfigsServerlessHealthCheckRequest } from "./RunnerConfigsServerlessHealthCheckRequest";
 export { RunnerConfigsUpsertRequestBody } from "./RunnerConfigsUpsertRequestBody";
@@ -5,17 +5,26 @@
 import * as serializers from "../../../../index";
 import * as Rivet from "../../../../../api/index";
 import * as core from "../../../../../core";
+import { ActorRateLimit } from "../../../../types/ActorRateLimit";
+import { CorsPolicy } from "../../../../types/CorsPolicy";
+import { CrashPolicy } from "../../../../types/CrashPolicy";
 
 export const NamespacesCreateRequest: core.serialization.Schema<
     serializers.NamespacesCreateRequest.Raw,
     Rivet.NamespacesCreateRequest
 > = core.serialization.object({
+    actorRateLimit: core.serialization.property("actor_rate_limit", ActorRateLimit.optional()),
+    corsPolicy: core.serialization.property("cors_policy", CorsPolicy.optional()),
+    defaultCrashPolicy: core.serialization.property("default_crash_policy", CrashPolicy.optional()),
     displayName: core.serialization.property("display_name", core.serialization.string()),
     name: core.serialization.string(),
 });
 
 export declare namespace NamespacesCreateRequest {
     export interface Raw {
+        actor_rate_limit?: ActorRateLimit.Raw | null;
+        cors_policy?: CorsPolicy.Raw | null;
+        default_crash_policy?: CrashPolicy.Raw | null;
         display_name: string;
         name: string;
     }
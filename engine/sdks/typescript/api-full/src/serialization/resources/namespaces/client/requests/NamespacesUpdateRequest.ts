@@ -0,0 +1,27 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+import * as serializers from "../../../../index";
+import * as Rivet from "../../../../../api/index";
+import * as core from "../../../../../core";
+import { ActorRateLimit } from "../../../../types/ActorRateLimit";
+import { CorsPolicy } from "../../../../types/CorsPolicy";
+import { CrashPolicy } from "../../../../types/CrashPolicy";
+
+export const NamespacesUpdateRequest: core.serialization.Schema<
+    serializers.NamespacesUpdateRequest.Raw,
+    Rivet.NamespacesUpdateRequest
+> = core.serialization.object({
+    actorRateLimit: core.serialization.property("actor_rate_limit", ActorRateLimit.optional()),
+    corsPolicy: core.serialization.property("cors_policy", CorsPolicy.optional()),
+    defaultCrashPolicy: core.serialization.property("default_crash_policy", CrashPolicy.optional()),
+});
+
+export declare namespace NamespacesUpdateRequest {
+    export interface Raw {
+        actor_rate_limit?: ActorRateLimit.Raw | null;
+        cors_policy?: CorsPolicy.Raw | null;
+        default_crash_policy?: CrashPolicy.Raw | null;
+    }
+}
@@ -1 +1,2 @@
 export { NamespacesCreateRequest } from "./NamespacesCreateRequest";
+export { NamespacesUpdateRequest } from "./NamespacesUpdateRequest";
@@ -7,6 +7,7 @@ import * as Rivet from "../../api/index";
 import * as core from "../../core";
 import { RivetId } from "./RivetId";
 import { CrashPolicy } from "./CrashPolicy";
+import { PendingReason } from "./PendingReason";
 
 export const Actor: core.serialization.ObjectSchema<serializers.Actor.Raw, Rivet.Actor> = core.serialization.object({
     actorId: core.serialization.property("actor_id", RivetId),
@@ -20,6 +21,7 @@ export const Actor: core.serialization.ObjectSchema<serializers.Actor.Raw, Rivet
     name: core.serialization.string(),
     namespaceId: core.serialization.property("namespace_id", RivetId),
     pendingAllocationTs: core.serialization.property("pending_allocation_ts", core.serialization.number().optional()),
+    pendingReason: core.serialization.property("pending_reason", PendingReason.optional()),
     rescheduleTs: core.serialization.property("reschedule_ts", core.serialization.number().optional()),
     runnerNameSelector: core.serialization.property("runner_name_selector", core.serialization.string()),
     sleepTs: core.serialization.property("sleep_ts", core.serialization.number().optional()),
@@ -39,6 +41,7 @@ export declare namespace Actor {
         name: string;
         namespace_id: RivetId.Raw;
         pending_allocation_ts?: number | null;
+        pending_reason?: PendingReason.Raw | null;
         reschedule_ts?: number | null;
         runner_name_selector: string;
         sleep_ts?: number | null;
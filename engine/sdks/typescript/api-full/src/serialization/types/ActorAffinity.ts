@@ -0,0 +1,21 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+import * as serializers from "../index";
+import * as Rivet from "../../api/index";
+import * as core from "../../core";
+import { RivetId } from "./RivetId";
+
+export const ActorAffinity: core.serialization.ObjectSchema<serializers.ActorAffinity.Raw, Rivet.ActorAffinity> =
+    core.serialization.object({
+        avoid: core.serialization.list(RivetId).optional(),
+        colocateWith: core.serialization.property("colocate_with", core.serialization.list(RivetId).optional()),
+    });
+
+export declare namespace ActorAffinity {
+    export interface Raw {
+        avoid?: RivetId.Raw[] | null;
+        colocate_with?: RivetId.Raw[] | null;
+    }
+}
@@ -0,0 +1,27 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+import * as serializers from "../index";
+import * as Rivet from "../../api/index";
+import * as core from "../../core";
+import { ActorLogStream } from "./ActorLogStream";
+
+export const ActorLog: core.serialization.ObjectSchema<serializers.ActorLog.Raw, Rivet.ActorLog> =
+    core.serialization.object({
+        generation: core.serialization.number(),
+        idx: core.serialization.number(),
+        message: core.serialization.string(),
+        stream: ActorLogStream,
+        ts: core.serialization.number(),
+    });
+
+export declare namespace ActorLog {
+    export interface Raw {
+        generation: number;
+        idx: number;
+        message: string;
+        stream: ActorLogStream.Raw;
+        ts: number;
+    }
+}
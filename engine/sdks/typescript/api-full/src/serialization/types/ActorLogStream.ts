@@ -0,0 +1,14 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+import * as serializers from "../index";
+import * as Rivet from "../../api/index";
+import * as core from "../../core";
+
+export const ActorLogStream: core.serialization.Schema<serializers.ActorLogStream.Raw, Rivet.ActorLogStream> =
+    core.serialization.enum_(["stdout", "stderr"]);
+
+export declare namespace ActorLogStream {
+    export type Raw = "stdout" | "stderr";
+}
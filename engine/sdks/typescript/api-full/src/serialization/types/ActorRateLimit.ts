@@ -0,0 +1,20 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+import * as serializers from "../index";
+import * as Rivet from "../../api/index";
+import * as core from "../../core";
+
+export const ActorRateLimit: core.serialization.ObjectSchema<serializers.ActorRateLimit.Raw, Rivet.ActorRateLimit> =
+    core.serialization.object({
+        burst: core.serialization.number().optional(),
+        requestsPerSecond: core.serialization.property("requests_per_second", core.serialization.number()),
+    });
+
+export declare namespace ActorRateLimit {
+    export interface Raw {
+        burst?: number | null;
+        requests_per_second: number;
+    }
+}
@@ -0,0 +1,14 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+import * as serializers from "../index";
+import * as Rivet from "../../api/index";
+import * as core from "../../core";
+
+export const ActorState: core.serialization.Schema<serializers.ActorState.Raw, Rivet.ActorState> =
+    core.serialization.enum_(["pending_allocation", "starting", "running", "sleeping", "destroyed"]);
+
+export declare namespace ActorState {
+    export type Raw = "pending_allocation" | "starting" | "running" | "sleeping" | "destroyed";
+}
@@ -0,0 +1,24 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+import * as serializers from "../index";
+import * as Rivet from "../../api/index";
+import * as core from "../../core";
+
+export const ActorsCreateBatchError: core.serialization.ObjectSchema<
+    serializers.ActorsCreateBatchError.Raw,
+    Rivet.ActorsCreateBatchError
+> = core.serialization.object({
+    code: core.serialization.string(),
+    group: core.serialization.string(),
+    message: core.serialization.string(),
+});
+
+export declare namespace ActorsCreateBatchError {
+    export interface Raw {
+        code: string;
+        group: string;
+        message: string;
+    }
+}
@@ -0,0 +1,21 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+import * as serializers from "../index";
+import * as Rivet from "../../api/index";
+import * as core from "../../core";
+import { ActorsCreateBatchResult } from "./ActorsCreateBatchResult";
+
+export const ActorsCreateBatchResponse: core.serialization.ObjectSchema<
+    serializers.ActorsCreateBatchResponse.Raw,
+    Rivet.ActorsCreateBatchResponse
+> = core.serialization.object({
+    results: core.serialization.list(ActorsCreateBatchResult),
+});
+
+export declare namespace ActorsCreateBatchResponse {
+    export interface Raw {
+        results: ActorsCreateBatchResult.Raw[];
+    }
+}
@@ -0,0 +1,24 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+import * as serializers from "../index";
+import * as Rivet from "../../api/index";
+import * as core from "../../core";
+import { Actor } from "./Actor";
+import { ActorsCreateBatchError } from "./ActorsCreateBatchError";
+
+export const ActorsCreateBatchResult: core.serialization.ObjectSchema<
+    serializers.ActorsCreateBatchResult.Raw,
+    Rivet.ActorsCreateBatchResult
+> = core.serialization.object({
+    actor: Actor.optional(),
+    error: ActorsCreateBatchError.optional(),
+});
+
+export declare namespace ActorsCreateBatchResult {
+    export interface Raw {
+        actor?: Actor.Raw | null;
+        error?: ActorsCreateBatchError.Raw | null;
+    }
+}
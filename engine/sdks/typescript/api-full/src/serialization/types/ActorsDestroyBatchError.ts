@@ -0,0 +1,24 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+import * as serializers from "../index";
+import * as Rivet from "../../api/index";
+import * as core from "../../core";
+
+export const ActorsDestroyBatchError: core.serialization.ObjectSchema<
+    serializers.ActorsDestroyBatchError.Raw,
+    Rivet.ActorsDestroyBatchError
+> = core.serialization.object({
+    code: core.serialization.string(),
+    group: core.serialization.string(),
+    message: core.serialization.string(),
+});
+
+export declare namespace ActorsDestroyBatchError {
+    export interface Raw {
+        code: string;
+        group: string;
+        message: string;
+    }
+}
@@ -0,0 +1,23 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+import * as serializers from "../index";
+import * as Rivet from "../../api/index";
+import * as core from "../../core";
+import { ActorsDestroyBatchResult } from "./ActorsDestroyBatchResult";
+
+export const ActorsDestroyBatchResponse: core.serialization.ObjectSchema<
+    serializers.ActorsDestroyBatchResponse.Raw,
+    Rivet.ActorsDestroyBatchResponse
+> = core.serialization.object({
+    hasMore: core.serialization.property("has_more", core.serialization.boolean()),
+    results: core.serialization.list(ActorsDestroyBatchResult),
+});
+
+export declare namespace ActorsDestroyBatchResponse {
+    export interface Raw {
+        has_more: boolean;
+        results: ActorsDestroyBatchResult.Raw[];
+    }
+}
@@ -0,0 +1,24 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+import * as serializers from "../index";
+import * as Rivet from "../../api/index";
+import * as core from "../../core";
+import { RivetId } from "./RivetId";
+import { ActorsDestroyBatchError } from "./ActorsDestroyBatchError";
+
+export const ActorsDestroyBatchResult: core.serialization.ObjectSchema<
+    serializers.ActorsDestroyBatchResult.Raw,
+    Rivet.ActorsDestroyBatchResult
+> = core.serialization.object({
+    actorId: core.serialization.property("actor_id", RivetId),
+    error: ActorsDestroyBatchError.optional(),
+});
+
+export declare namespace ActorsDestroyBatchResult {
+    export interface Raw {
+        actor_id: RivetId.Raw;
+        error?: ActorsDestroyBatchError.Raw | null;
+    }
+}
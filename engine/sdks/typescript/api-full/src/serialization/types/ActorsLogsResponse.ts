@@ -0,0 +1,21 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+import * as serializers from "../index";
+import * as Rivet from "../../api/index";
+import * as core from "../../core";
+import { ActorLog } from "./ActorLog";
+
+export const ActorsLogsResponse: core.serialization.ObjectSchema<
+    serializers.ActorsLogsResponse.Raw,
+    Rivet.ActorsLogsResponse
+> = core.serialization.object({
+    logs: core.serialization.list(ActorLog),
+});
+
+export declare namespace ActorsLogsResponse {
+    export interface Raw {
+        logs: ActorLog.Raw[];
+    }
+}
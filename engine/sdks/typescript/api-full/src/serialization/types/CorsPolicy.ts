@@ -0,0 +1,33 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+import * as serializers from "../index";
+import * as Rivet from "../../api/index";
+import * as core from "../../core";
+
+export const CorsPolicy: core.serialization.ObjectSchema<serializers.CorsPolicy.Raw, Rivet.CorsPolicy> =
+    core.serialization.object({
+        allowedHeaders: core.serialization.property(
+            "allowed_headers",
+            core.serialization.list(core.serialization.string()).optional(),
+        ),
+        allowedMethods: core.serialization.property(
+            "allowed_methods",
+            core.serialization.list(core.serialization.string()).optional(),
+        ),
+        allowedOrigins: core.serialization.property(
+            "allowed_origins",
+            core.serialization.list(core.serialization.string()).optional(),
+        ),
+        maxAge: core.serialization.property("max_age", core.serialization.number().optional()),
+    });
+
+export declare namespace CorsPolicy {
+    export interface Raw {
+        allowed_headers?: string[] | null;
+        allowed_methods?: string[] | null;
+        allowed_origins?: string[] | null;
+        max_age?: number | null;
+    }
+}
@@ -5,11 +5,17 @@
 import * as serializers from "../index";
 import * as Rivet from "../../api/index";
 import * as core from "../../core";
+import { ActorRateLimit } from "./ActorRateLimit";
+import { CorsPolicy } from "./CorsPolicy";
+import { CrashPolicy } from "./CrashPolicy";
 import { RivetId } from "./RivetId";
 
 export const Namespace: core.serialization.ObjectSchema<serializers.Namespace.Raw, Rivet.Namespace> =
     core.serialization.object({
+        actorRateLimit: core.serialization.property("actor_rate_limit", ActorRateLimit.optional()),
         createTs: core.serialization.property("create_ts", core.serialization.number()),
+        corsPolicy: core.serialization.property("cors_policy", CorsPolicy.optional()),
+        defaultCrashPolicy: core.serialization.property("default_crash_policy", CrashPolicy.optional()),
         displayName: core.serialization.property("display_name", core.serialization.string()),
         name: core.serialization.string(),
         namespaceId: core.serialization.property("namespace_id", RivetId),
@@ -17,7 +23,10 @@ export const Namespace: core.serialization.ObjectSchema<serializers.Namespace.Ra
 
 export declare namespace Namespace {
     export interface Raw {
+        actor_rate_limit?: ActorRateLimit.Raw | null;
         create_ts: number;
+        cors_policy?: CorsPolicy.Raw | null;
+        default_crash_policy?: CrashPolicy.Raw | null;
         display_name: string;
         name: string;
         namespace_id: RivetId.Raw;
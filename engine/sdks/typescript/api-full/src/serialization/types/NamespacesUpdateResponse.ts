@@ -0,0 +1,21 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+import * as serializers from "../index";
+import * as Rivet from "../../api/index";
+import * as core from "../../core";
+import { Namespace } from "./Namespace";
+
+export const NamespacesUpdateResponse: core.serialization.ObjectSchema<
+    serializers.NamespacesUpdateResponse.Raw,
+    Rivet.NamespacesUpdateResponse
+> = core.serialization.object({
+    namespace: Namespace,
+});
+
+export declare namespace NamespacesUpdateResponse {
+    export interface Raw {
+        namespace: Namespace.Raw;
+    }
+}
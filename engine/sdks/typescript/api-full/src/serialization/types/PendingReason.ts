@@ -0,0 +1,14 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+import * as serializers from "../index";
+import * as Rivet from "../../api/index";
+import * as core from "../../core";
+
+export const PendingReason: core.serialization.Schema<serializers.PendingReason.Raw, Rivet.PendingReason> =
+    core.serialization.enum_(["no_runner_matches_selector", "all_runners_draining", "no_capacity"]);
+
+export declare namespace PendingReason {
+    export type Raw = "no_runner_matches_selector" | "all_runners_draining" | "no_capacity";
+}
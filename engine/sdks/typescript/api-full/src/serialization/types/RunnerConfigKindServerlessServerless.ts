@@ -5,6 +5,8 @@
 import * as serializers from "../index";
 import * as Rivet from "../../api/index";
 import * as core from "../../core";
+import { ServerlessAuth } from "./ServerlessAuth";
+import { ServerlessRequestMethod } from "./ServerlessRequestMethod";
 
 export const RunnerConfigKindServerlessServerless: core.serialization.ObjectSchema<
     serializers.RunnerConfigKindServerlessServerless.Raw,
@@ -13,6 +15,8 @@ export const RunnerConfigKindServerlessServerless: core.serialization.ObjectSche
     actorEvictionDelay: core.serialization.property("actor_eviction_delay", core.serialization.number().optional()),
     actorEvictionPeriod: core.serialization.property("actor_eviction_period", core.serialization.number().optional()),
     actorEvictionRate: core.serialization.property("actor_eviction_rate", core.serialization.number().optional()),
+    auth: ServerlessAuth.optional(),
+    body: core.serialization.string().optional(),
     drainGracePeriod: core.serialization.property("drain_grace_period", core.serialization.number().optional()),
     drainOnVersionUpgrade: core.serialization.property(
         "drain_on_version_upgrade",
@@ -21,6 +25,7 @@ export const RunnerConfigKindServerlessServerless: core.serialization.ObjectSche
     headers: core.serialization.record(core.serialization.string(), core.serialization.string()).optional(),
     maxConcurrentActors: core.serialization.property("max_concurrent_actors", core.serialization.number().optional()),
     maxRunners: core.serialization.property("max_runners", core.serialization.number().optional()),
+    method: ServerlessRequestMethod.optional(),
     metadataPollInterval: core.serialization.property("metadata_poll_interval", core.serialization.number().optional()),
     minRunners: core.serialization.property("min_runners", core.serialization.number().optional()),
     requestLifespan: core.serialization.property("request_lifespan", core.serialization.number()),
@@ -34,11 +39,14 @@ export declare namespace RunnerConfigKindServerlessServerless {
         actor_eviction_delay?: number | null;
         actor_eviction_period?: number | null;
         actor_eviction_rate?: number | null;
+        auth?: ServerlessAuth.Raw | null;
+        body?: string | null;
         drain_grace_period?: number | null;
         drain_on_version_upgrade?: boolean | null;
         headers?: Record<string, string> | null;
         max_concurrent_actors?: number | null;
         max_runners?: number | null;
+        method?: ServerlessRequestMethod.Raw | null;
         metadata_poll_interval?: number | null;
         min_runners?: number | null;
         request_lifespan: number;
@@ -17,6 +17,10 @@ export const RunnerConfigResponse: core.serialization.ObjectSchema<
             "runner_pool_error",
             core.serialization.record(core.serialization.string(), core.serialization.unknown()).optional(),
         ),
+        runnerPoolErrorCategory: core.serialization.property(
+            "runner_pool_error_category",
+            core.serialization.string().optional(),
+        ),
     })
     .extend(RunnerConfig);
 
@@ -24,5 +28,6 @@ export declare namespace RunnerConfigResponse {
     export interface Raw extends RunnerConfig.Raw {
         protocol_version?: number | null;
         runner_pool_error?: Record<string, unknown> | null;
+        runner_pool_error_category?: string | null;
     }
 }
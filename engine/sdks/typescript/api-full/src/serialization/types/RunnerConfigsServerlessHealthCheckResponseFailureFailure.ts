@@ -12,10 +12,12 @@ export const RunnerConfigsServerlessHealthCheckResponseFailureFailure: core.seri
     Rivet.RunnerConfigsServerlessHealthCheckResponseFailureFailure
 > = core.serialization.object({
     error: RunnerConfigsServerlessMetadataError,
+    latencyMs: core.serialization.property("latency_ms", core.serialization.number()),
 });
 
 export declare namespace RunnerConfigsServerlessHealthCheckResponseFailureFailure {
     export interface Raw {
         error: RunnerConfigsServerlessMetadataError.Raw;
+        latency_ms: number;
     }
 }
@@ -10,11 +10,13 @@ export const RunnerConfigsServerlessHealthCheckResponseSuccessSuccess: core.seri
     serializers.RunnerConfigsServerlessHealthCheckResponseSuccessSuccess.Raw,
     Rivet.RunnerConfigsServerlessHealthCheckResponseSuccessSuccess
 > = core.serialization.object({
+    latencyMs: core.serialization.property("latency_ms", core.serialization.number()),
     version: core.serialization.string(),
 });
 
 export declare namespace RunnerConfigsServerlessHealthCheckResponseSuccessSuccess {
     export interface Raw {
+        latency_ms: number;
         version: string;
     }
 }
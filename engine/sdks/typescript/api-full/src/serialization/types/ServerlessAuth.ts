@@ -0,0 +1,16 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+import * as serializers from "../index";
+import * as Rivet from "../../api/index";
+import * as core from "../../core";
+import { ServerlessAuthBearer } from "./ServerlessAuthBearer";
+import { ServerlessAuthBearerEnv } from "./ServerlessAuthBearerEnv";
+
+export const ServerlessAuth: core.serialization.Schema<serializers.ServerlessAuth.Raw, Rivet.ServerlessAuth> =
+    core.serialization.undiscriminatedUnion([ServerlessAuthBearer, ServerlessAuthBearerEnv]);
+
+export declare namespace ServerlessAuth {
+    export type Raw = ServerlessAuthBearer.Raw | ServerlessAuthBearerEnv.Raw;
+}
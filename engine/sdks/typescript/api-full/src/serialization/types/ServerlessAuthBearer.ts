@@ -0,0 +1,21 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+import * as serializers from "../index";
+import * as Rivet from "../../api/index";
+import * as core from "../../core";
+import { ServerlessAuthBearerBearer } from "./ServerlessAuthBearerBearer";
+
+export const ServerlessAuthBearer: core.serialization.ObjectSchema<
+    serializers.ServerlessAuthBearer.Raw,
+    Rivet.ServerlessAuthBearer
+> = core.serialization.object({
+    bearer: ServerlessAuthBearerBearer,
+});
+
+export declare namespace ServerlessAuthBearer {
+    export interface Raw {
+        bearer: ServerlessAuthBearerBearer.Raw;
+    }
+}
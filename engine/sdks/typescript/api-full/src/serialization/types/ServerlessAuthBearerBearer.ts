@@ -0,0 +1,20 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+import * as serializers from "../index";
+import * as Rivet from "../../api/index";
+import * as core from "../../core";
+
+export const ServerlessAuthBearerBearer: core.serialization.ObjectSchema<
+    serializers.ServerlessAuthBearerBearer.Raw,
+    Rivet.ServerlessAuthBearerBearer
+> = core.serialization.object({
+    token: core.serialization.string(),
+});
+
+export declare namespace ServerlessAuthBearerBearer {
+    export interface Raw {
+        token: string;
+    }
+}
@@ -0,0 +1,21 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+import * as serializers from "../index";
+import * as Rivet from "../../api/index";
+import * as core from "../../core";
+import { ServerlessAuthBearerEnvBearerEnv } from "./ServerlessAuthBearerEnvBearerEnv";
+
+export const ServerlessAuthBearerEnv: core.serialization.ObjectSchema<
+    serializers.ServerlessAuthBearerEnv.Raw,
+    Rivet.ServerlessAuthBearerEnv
+> = core.serialization.object({
+    bearerEnv: core.serialization.property("bearer_env", ServerlessAuthBearerEnvBearerEnv),
+});
+
+export declare namespace ServerlessAuthBearerEnv {
+    export interface Raw {
+        bearer_env: ServerlessAuthBearerEnvBearerEnv.Raw;
+    }
+}
@@ -0,0 +1,20 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+import * as serializers from "../index";
+import * as Rivet from "../../api/index";
+import * as core from "../../core";
+
+export const ServerlessAuthBearerEnvBearerEnv: core.serialization.ObjectSchema<
+    serializers.ServerlessAuthBearerEnvBearerEnv.Raw,
+    Rivet.ServerlessAuthBearerEnvBearerEnv
+> = core.serialization.object({
+    env: core.serialization.string(),
+});
+
+export declare namespace ServerlessAuthBearerEnvBearerEnv {
+    export interface Raw {
+        env: string;
+    }
+}
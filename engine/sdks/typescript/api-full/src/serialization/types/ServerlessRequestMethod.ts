@@ -0,0 +1,16 @@
+/**
+ * This file was auto-generated by Fern from our API Definition.
+ */
+
+import * as serializers from "../index";
+import * as Rivet from "../../api/index";
+import * as core from "../../core";
+
+export const ServerlessRequestMethod: core.serialization.Schema<
+    serializers.ServerlessRequestMethod.Raw,
+    Rivet.ServerlessRequestMethod
+> = core.serialization.enum_(["get", "post"]);
+
+export declare namespace ServerlessRequestMethod {
+    export type Raw = "get" | "post";
+}
@@ -1,15 +1,28 @@
 export * from "./Actor";
+export * from "./ActorAffinity";
+export * from "./ActorLog";
+export * from "./ActorLogStream";
 export * from "./ActorName";
+export * from "./ActorRateLimit";
+export * from "./ActorState";
+export * from "./ActorsCreateBatchError";
+export * from "./ActorsCreateBatchResponse";
+export * from "./ActorsCreateBatchResult";
 export * from "./ActorsCreateResponse";
 export * from "./ActorsDeleteResponse";
+export * from "./ActorsDestroyBatchError";
+export * from "./ActorsDestroyBatchResponse";
+export * from "./ActorsDestroyBatchResult";
 export * from "./ActorsGetOrCreateResponse";
 export * from "./ActorsKvGetResponse";
 export * from "./ActorsListNamesResponse";
 export * from "./ActorsListResponse";
+export * from "./ActorsLogsResponse";
 export * from "./ActorsRescheduleRequestBody";
 export * from "./ActorsRescheduleResponse";
 export * from "./ActorsSleepRequestBody";
 export * from "./ActorsSleepResponse";
+export * from "./CorsPolicy";
 export * from "./CrashPolicy";
 export * from "./Datacenter";
 export * from "./DatacenterHealth";
@@ -23,7 +36,9 @@ export * from "./MetadataGetResponse";
 export * from "./Namespace";
 export * from "./NamespaceListResponse";
 export * from "./NamespacesCreateResponse";
+export * from "./NamespacesUpdateResponse";
 export * from "./Pagination";
+export * from "./PendingReason";
 export * from "./RivetId";
 export * from "./Runner";
 export * from "./RunnerConfigNormal";
@@ -50,3 +65,9 @@ export * from "./RunnerConfigsServerlessMetadataError";
 export * from "./RunnerConfigsUpsertResponse";
 export * from "./RunnersListNamesResponse";
 export * from "./RunnersListResponse";
+export * from "./ServerlessAuthBearerBearer";
+export * from "./ServerlessAuthBearer";
+export * from "./ServerlessAuthBearerEnvBearerEnv";
+export * from "./ServerlessAuthBearerEnv";
+export * from "./ServerlessAuth";
+export * from "./ServerlessRequestMethod";
@@ -2068,6 +2068,11 @@ export type ToEnvoyWebSocketOpen = {
     readonly actorId: Id
     readonly path: string
     readonly headers: ReadonlyMap<string, string>
+    /**
+     * Offers lz4 compression of message payloads for this websocket. Not sent
+     * again after hibernation, so a rewoken websocket starts uncompressed.
+     */
+    readonly compression: boolean
 }
 
 export function readToEnvoyWebSocketOpen(bc: bare.ByteCursor): ToEnvoyWebSocketOpen {
@@ -2075,6 +2080,7 @@ export function readToEnvoyWebSocketOpen(bc: bare.ByteCursor): ToEnvoyWebSocketO
         actorId: readId(bc),
         path: bare.readString(bc),
         headers: read20(bc),
+        compression: bare.readBool(bc),
     }
 }
 
@@ -2082,23 +2088,30 @@ export function writeToEnvoyWebSocketOpen(bc: bare.ByteCursor, x: ToEnvoyWebSock
     writeId(bc, x.actorId)
     bare.writeString(bc, x.path)
     write20(bc, x.headers)
+    bare.writeBool(bc, x.compression)
 }
 
 export type ToEnvoyWebSocketMessage = {
     readonly data: ArrayBuffer
     readonly binary: boolean
+    /**
+     * `data` is an lz4 block prefixed with its uncompressed size
+     */
+    readonly compressed: boolean
 }
 
 export function readToEnvoyWebSocketMessage(bc: bare.ByteCursor): ToEnvoyWebSocketMessage {
     return {
         data: bare.readData(bc),
         binary: bare.readBool(bc),
+        compressed: bare.readBool(bc),
     }
 }
 
 export function writeToEnvoyWebSocketMessage(bc: bare.ByteCursor, x: ToEnvoyWebSocketMessage): void {
     bare.writeData(bc, x.data)
     bare.writeBool(bc, x.binary)
+    bare.writeBool(bc, x.compressed)
 }
 
 function read21(bc: bare.ByteCursor): u16 | null {
@@ -2131,33 +2144,45 @@ export function writeToEnvoyWebSocketClose(bc: bare.ByteCursor, x: ToEnvoyWebSoc
 
 export type ToRivetWebSocketOpen = {
     readonly canHibernate: boolean
+    /**
+     * Accepts the compression offered in `ToEnvoyWebSocketOpen`
+     */
+    readonly compression: boolean
 }
 
 export function readToRivetWebSocketOpen(bc: bare.ByteCursor): ToRivetWebSocketOpen {
     return {
         canHibernate: bare.readBool(bc),
+        compression: bare.readBool(bc),
     }
 }
 
 export function writeToRivetWebSocketOpen(bc: bare.ByteCursor, x: ToRivetWebSocketOpen): void {
     bare.writeBool(bc, x.canHibernate)
+    bare.writeBool(bc, x.compression)
 }
 
 export type ToRivetWebSocketMessage = {
     readonly data: ArrayBuffer
     readonly binary: boolean
+    /**
+     * `data` is an lz4 block prefixed with its uncompressed size
+     */
+    readonly compressed: boolean
 }
 
 export function readToRivetWebSocketMessage(bc: bare.ByteCursor): ToRivetWebSocketMessage {
     return {
         data: bare.readData(bc),
         binary: bare.readBool(bc),
+        compressed: bare.readBool(bc),
     }
 }
 
 export function writeToRivetWebSocketMessage(bc: bare.ByteCursor, x: ToRivetWebSocketMessage): void {
     bare.writeData(bc, x.data)
     bare.writeBool(bc, x.binary)
+    bare.writeBool(bc, x.compressed)
 }
 
 export type ToRivetWebSocketMessageAck = {
@@ -2621,6 +2646,101 @@ export function writeToRivetSqliteExecuteRequest(bc: bare.ByteCursor, x: ToRivet
     writeSqliteExecuteRequest(bc, x.data)
 }
 
+export enum ActorLogStream {
+    Stdout = "Stdout",
+    Stderr = "Stderr",
+}
+
+export function readActorLogStream(bc: bare.ByteCursor): ActorLogStream {
+    const offset = bc.offset
+    const tag = bare.readU8(bc)
+    switch (tag) {
+        case 0:
+            return ActorLogStream.Stdout
+        case 1:
+            return ActorLogStream.Stderr
+        default: {
+            bc.offset = offset
+            throw new bare.BareError(offset, "invalid tag")
+        }
+    }
+}
+
+export function writeActorLogStream(bc: bare.ByteCursor, x: ActorLogStream): void {
+    switch (x) {
+        case ActorLogStream.Stdout: {
+            bare.writeU8(bc, 0)
+            break
+        }
+        case ActorLogStream.Stderr: {
+            bare.writeU8(bc, 1)
+            break
+        }
+    }
+}
+
+export type ActorLogLine = {
+    /**
+     * Epoch ms
+     */
+    readonly ts: i64
+    readonly stream: ActorLogStream
+    readonly message: string
+}
+
+export function readActorLogLine(bc: bare.ByteCursor): ActorLogLine {
+    return {
+        ts: bare.readI64(bc),
+        stream: readActorLogStream(bc),
+        message: bare.readString(bc),
+    }
+}
+
+export function writeActorLogLine(bc: bare.ByteCursor, x: ActorLogLine): void {
+    bare.writeI64(bc, x.ts)
+    writeActorLogStream(bc, x.stream)
+    bare.writeString(bc, x.message)
+}
+
+function read26(bc: bare.ByteCursor): readonly ActorLogLine[] {
+    const len = bare.readUintSafe(bc)
+    if (len === 0) {
+        return []
+    }
+    const result = [readActorLogLine(bc)]
+    for (let i = 1; i < len; i++) {
+        result[i] = readActorLogLine(bc)
+    }
+    return result
+}
+
+function write26(bc: bare.ByteCursor, x: readonly ActorLogLine[]): void {
+    bare.writeUintSafe(bc, x.length)
+    for (let i = 0; i < x.length; i++) {
+        writeActorLogLine(bc, x[i])
+    }
+}
+
+export type ToRivetActorLog = {
+    readonly actorId: Id
+    readonly generation: u32
+    readonly lines: readonly ActorLogLine[]
+}
+
+export function readToRivetActorLog(bc: bare.ByteCursor): ToRivetActorLog {
+    return {
+        actorId: readId(bc),
+        generation: bare.readU32(bc),
+        lines: read26(bc),
+    }
+}
+
+export function writeToRivetActorLog(bc: bare.ByteCursor, x: ToRivetActorLog): void {
+    writeId(bc, x.actorId)
+    bare.writeU32(bc, x.generation)
+    write26(bc, x.lines)
+}
+
 export type ToRivet =
     | { readonly tag: "ToRivetMetadata"; readonly val: ToRivetMetadata }
     | { readonly tag: "ToRivetEvents"; readonly val: ToRivetEvents }
@@ -2633,6 +2753,7 @@ export type ToRivet =
     | { readonly tag: "ToRivetSqliteCommitRequest"; readonly val: ToRivetSqliteCommitRequest }
     | { readonly tag: "ToRivetSqliteExecRequest"; readonly val: ToRivetSqliteExecRequest }
     | { readonly tag: "ToRivetSqliteExecuteRequest"; readonly val: ToRivetSqliteExecuteRequest }
+    | { readonly tag: "ToRivetActorLog"; readonly val: ToRivetActorLog }
 
 export function readToRivet(bc: bare.ByteCursor): ToRivet {
     const offset = bc.offset
@@ -2660,6 +2781,8 @@ export function readToRivet(bc: bare.ByteCursor): ToRivet {
             return { tag: "ToRivetSqliteExecRequest", val: readToRivetSqliteExecRequest(bc) }
         case 10:
             return { tag: "ToRivetSqliteExecuteRequest", val: readToRivetSqliteExecuteRequest(bc) }
+        case 11:
+            return { tag: "ToRivetActorLog", val: readToRivetActorLog(bc) }
         default: {
             bc.offset = offset
             throw new bare.BareError(offset, "invalid tag")
@@ -2723,6 +2846,11 @@ export function writeToRivet(bc: bare.ByteCursor, x: ToRivet): void {
             writeToRivetSqliteExecuteRequest(bc, x.val)
             break
         }
+        case "ToRivetActorLog": {
+            bare.writeU8(bc, 11)
+            writeToRivetActorLog(bc, x.val)
+            break
+        }
     }
 }
 
@@ -3269,4 +3397,4 @@ function assert(condition: boolean, message?: string): asserts condition {
     if (!condition) throw new Error(message ?? "Assertion failed")
 }
 
-export const VERSION = 5;
\ No newline at end of file
+export const VERSION = 6;
\ No newline at end of file
@@ -238,6 +238,10 @@ export function stringifyToServer(message: protocol.ToServer): string {
 			const { messageId, messageKind } = message.val;
 			return `ToServerTunnelMessage{messageId: ${stringifyMessageId(messageId)}, messageKind: ${stringifyToServerTunnelMessageKind(messageKind)}}`;
 		}
+		case "ToServerActorLog": {
+			const { actorId, generation, lines } = message.val;
+			return `ToServerActorLog{actorId: "${actorId}", generation: ${generation}, lines: ${lines.length}}`;
+		}
 	}
 }
 
@@ -43,6 +43,7 @@ interface ClassifiedError {
 	kind:
 		| "serverless_http"
 		| "serverless_connection"
+		| "serverless_init_timeout"
 		| "serverless_invalid_sse"
 		| "serverless_stream_ended_early"
 		| "downgrade"
@@ -116,6 +117,17 @@ function classifyRunnerError(error: RivetActorError): ClassifiedError {
 				fingerprint: `conn:${e.serverless_connection_error.message.slice(0, 64)}`,
 			}),
 		)
+		.with(
+			P.shape({
+				serverless_init_timeout: P.shape({ timeout_ms: P.number }),
+			}),
+			(e) => ({
+				severity: "warning",
+				kind: "serverless_init_timeout",
+				title: "Runner did not initialize",
+				fingerprint: `init_timeout:${e.serverless_init_timeout.timeout_ms}`,
+			}),
+		)
 		.with(
 			P.shape({
 				serverless_invalid_sse_payload: P.shape({ message: P.string }),
@@ -502,6 +514,8 @@ function describeKind(kind: ClassifiedError["kind"]): string {
 			return "Runner pool was downgraded to an unsupported version. Revert to a higher version.";
 		case "serverless_stream_ended_early":
 			return "Connection terminated before the runner stopped. Check the request lifespan limits on your serverless provider.";
+		case "serverless_init_timeout":
+			return "The serverless endpoint opened the connection but the runner never initialized. Check that your endpoint starts the runner.";
 		case "internal":
 			return "An internal error occurred in the runner pool.";
 		default:
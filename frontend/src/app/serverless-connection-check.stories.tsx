@@ -17,13 +17,17 @@ const INVALID_REQUEST: Failure = {
 		message: "invalid serverless metadata request",
 		metadata: { kind: "invalid_request" },
 	},
+	latencyMs: 42,
 };
 
 const REQUEST_FAILED: Failure = {
 	error: {
 		message: "failed to reach serverless endpoint",
+		details:
+			"error sending request for url (http://localhost:3000/metadata): client error (Connect): tcp connect error: Connection refused (os error 111)",
 		metadata: { kind: "request_failed" },
 	},
+	latencyMs: 42,
 };
 
 const REQUEST_TIMED_OUT: Failure = {
@@ -31,6 +35,7 @@ const REQUEST_TIMED_OUT: Failure = {
 		message: "serverless metadata request timed out",
 		metadata: { kind: "request_timed_out" },
 	},
+	latencyMs: 10000,
 };
 
 const NON_SUCCESS_STATUS: Failure = {
@@ -42,6 +47,7 @@ const NON_SUCCESS_STATUS: Failure = {
 			body: "Bad Gateway: upstream connection refused",
 		},
 	},
+	latencyMs: 42,
 };
 
 const INVALID_RESPONSE_JSON: Failure = {
@@ -53,6 +59,7 @@ const INVALID_RESPONSE_JSON: Failure = {
 			parse_error: "expected value at line 1 column 1",
 		},
 	},
+	latencyMs: 42,
 };
 
 const INVALID_RESPONSE_SCHEMA: Failure = {
@@ -64,6 +71,7 @@ const INVALID_RESPONSE_SCHEMA: Failure = {
 			version: "0.1.0",
 		},
 	},
+	latencyMs: 42,
 };
 
 const INVALID_ENVOY_PROTOCOL_VERSION: Failure = {
@@ -75,6 +83,7 @@ const INVALID_ENVOY_PROTOCOL_VERSION: Failure = {
 			max_supported_envoy_protocol_version: 4,
 		},
 	},
+	latencyMs: 42,
 };
 
 // Unrecognized envelope: the server message still surfaces even when the
@@ -84,6 +93,7 @@ const UNKNOWN_KIND: Failure = {
 		message: "something new went wrong",
 		metadata: { kind: "some_future_variant", extra: "context" },
 	},
+	latencyMs: 42,
 };
 
 function Frame({ children }: { children: React.ReactNode }) {
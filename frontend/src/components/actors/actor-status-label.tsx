@@ -102,6 +102,7 @@ export function ActorError({ error }: { error: object | string }) {
 					.or(P.shape({ serverless_http_error: P.any }))
 					.or(P.string)
 					.or(P.shape({ serverless_connection_error: P.any }))
+					.or(P.shape({ serverless_init_timeout: P.any }))
 					.or(P.shape({ serverless_invalid_sse_payload: P.any })),
 			}),
 			(err) => <RunnerPoolError error={err.runner_pool_error} />,
@@ -220,6 +221,18 @@ export function RunnerPoolError({ error }: { error: RivetActorError }) {
 				);
 			},
 		)
+		.with(
+			P.shape({
+				serverless_init_timeout: P.shape({ timeout_ms: P.number }),
+			}),
+			(errObj) => (
+				<p>
+					Serverless runner did not initialize within{" "}
+					{errObj.serverless_init_timeout.timeout_ms}ms. Check that
+					your endpoint starts the runner.
+				</p>
+			),
+		)
 		.with(
 			P.shape({
 				serverless_invalid_sse_payload: P.shape({ message: P.string }),
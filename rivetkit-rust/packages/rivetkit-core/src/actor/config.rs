@@ -61,6 +61,10 @@ pub struct ActorConfig {
 	/// on the TS side). Gates the inspector database tab.
 	pub has_database: bool,
 	pub remote_sqlite: bool,
+	/// Enforce foreign key constraints in the actor's SQLite database.
+	pub sqlite_foreign_keys: bool,
+	/// Open the actor's SQLite database read-only. Writes, including migrations, are rejected.
+	pub sqlite_readonly: bool,
 	/// Whether the user declared actor state (`state: ...` or `createState`).
 	/// Gates the inspector state tab and state-subscription messages.
 	pub has_state: bool,
@@ -98,6 +102,8 @@ pub struct ActorConfigInput {
 	pub icon: Option<String>,
 	pub has_database: Option<bool>,
 	pub remote_sqlite: Option<bool>,
+	pub sqlite_foreign_keys: Option<bool>,
+	pub sqlite_readonly: Option<bool>,
 	pub has_state: Option<bool>,
 	pub can_hibernate_websocket: Option<bool>,
 	pub state_save_interval_ms: Option<u32>,
@@ -132,6 +138,12 @@ impl ActorConfig {
 			has_state: config.has_state.unwrap_or(false),
 			..Self::default()
 		};
+		if let Some(value) = config.sqlite_foreign_keys {
+			actor_config.sqlite_foreign_keys = value;
+		}
+		if let Some(value) = config.sqlite_readonly {
+			actor_config.sqlite_readonly = value;
+		}
 		if let Some(can_hibernate_websocket) = config.can_hibernate_websocket {
 			actor_config.can_hibernate_websocket =
 				CanHibernateWebSocket::Bool(can_hibernate_websocket);
@@ -226,6 +238,8 @@ impl Default for ActorConfig {
 			icon: None,
 			has_database: false,
 			remote_sqlite: false,
+			sqlite_foreign_keys: true,
+			sqlite_readonly: false,
 			has_state: false,
 			can_hibernate_websocket: CanHibernateWebSocket::default(),
 			state_save_interval: DEFAULT_STATE_SAVE_INTERVAL,
@@ -235,6 +235,10 @@ impl ActorContext {
 		let mut sql = sql;
 		#[cfg(feature = "sqlite-local")]
 		sql.set_vfs_metrics(Arc::new(metrics.clone()));
+		#[cfg(feature = "sqlite-local")]
+		sql.set_foreign_keys(config.sqlite_foreign_keys);
+		#[cfg(feature = "sqlite-local")]
+		sql.set_readonly(config.sqlite_readonly);
 		let diagnostics = ActorDiagnostics::new(actor_id.clone());
 		let state_save_interval = config.state_save_interval;
 		let abort_signal = CancellationToken::new();
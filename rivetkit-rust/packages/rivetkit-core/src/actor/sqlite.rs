@@ -6,8 +6,8 @@ use std::sync::{
 };
 
 use anyhow::{Context, Result};
-use depot_client_types::is_head_fence_mismatch;
 pub use depot_client_types::{BindParam, ColumnValue, ExecResult, ExecuteResult, QueryResult};
+use depot_client_types::{insert_sql, is_head_fence_mismatch};
 #[cfg(feature = "sqlite-local")]
 use parking_lot::Mutex;
 use rivet_envoy_client::protocol;
@@ -31,7 +31,8 @@ use crate::runtime::RuntimeSpawner;
 
 #[cfg(feature = "sqlite-local")]
 use depot_client::{
-	database::{NativeDatabaseHandle, open_database_from_transport},
+	database::{NativeDatabaseHandle, NativeDatabaseOptions, open_database_from_transport},
+	query::{SqliteErrorCategory, SqliteQueryError},
 	vfs::{SqliteVfsMetrics, SqliteVfsMetricsSnapshot},
 	worker::{
 		SQLITE_WORKER_QUEUE_CAPACITY, SqliteWorkerCloseTimeoutError, SqliteWorkerClosingError,
@@ -94,6 +95,8 @@ pub struct SqliteDb {
 	worker_fatal_reported: Arc<AtomicBool>,
 	#[cfg(feature = "sqlite-local")]
 	vfs_metrics: Option<Arc<dyn SqliteVfsMetrics>>,
+	#[cfg(feature = "sqlite-local")]
+	options: NativeDatabaseOptions,
 }
 
 impl SqliteDb {
@@ -125,6 +128,8 @@ impl SqliteDb {
 			worker_fatal_reported: Default::default(),
 			#[cfg(feature = "sqlite-local")]
 			vfs_metrics: None,
+			#[cfg(feature = "sqlite-local")]
+			options: NativeDatabaseOptions::default(),
 		}
 	}
 
@@ -133,6 +138,18 @@ impl SqliteDb {
 		self.vfs_metrics = Some(metrics);
 	}
 
+	/// Only applies to the local backend, remote databases are opened by the engine.
+	#[cfg(feature = "sqlite-local")]
+	pub(crate) fn set_foreign_keys(&mut self, foreign_keys: bool) {
+		self.options.foreign_keys = foreign_keys;
+	}
+
+	/// Only applies to the local backend, remote databases are opened by the engine.
+	#[cfg(feature = "sqlite-local")]
+	pub(crate) fn set_readonly(&mut self, readonly: bool) {
+		self.options.readonly = readonly;
+	}
+
 	pub fn is_enabled(&self) -> bool {
 		self.enabled
 	}
@@ -179,6 +196,7 @@ impl SqliteDb {
 								.generation
 								.ok_or_else(|| sqlite_not_configured("generation"))?,
 							rt_handle,
+							self.options.clone(),
 							vfs_metrics,
 						)
 						.await,
@@ -264,6 +282,75 @@ impl SqliteDb {
 		Err(SqliteRuntimeError::Unavailable.build())
 	}
 
+	#[cfg(feature = "sqlite-local")]
+	async fn local_insert_many(
+		&self,
+		table: String,
+		columns: Vec<String>,
+		rows: Vec<Vec<BindParam>>,
+	) -> Result<ExecResult> {
+		self.open().await?;
+		self.map_local_worker_result(
+			self.native_db_handle()?
+				.insert_many(table, columns, rows)
+				.await,
+		)
+	}
+
+	#[cfg(not(feature = "sqlite-local"))]
+	async fn local_insert_many(
+		&self,
+		_table: String,
+		_columns: Vec<String>,
+		_rows: Vec<Vec<BindParam>>,
+	) -> Result<ExecResult> {
+		Err(SqliteRuntimeError::Unavailable.build())
+	}
+
+	/// Remote SQLite has no batch insert request, so rows are sent one statement at a time inside a
+	/// savepoint. Either every row is inserted or none are.
+	async fn remote_insert_many(
+		&self,
+		table: String,
+		columns: Vec<String>,
+		rows: Vec<Vec<BindParam>>,
+	) -> Result<ExecResult> {
+		let sql = insert_sql(&table, &columns);
+
+		// A savepoint nests inside a caller's transaction and behaves like BEGIN outside of one
+		self.remote_exec("SAVEPOINT insert_many;".to_owned())
+			.await?;
+
+		let result = async {
+			let mut changes = 0;
+			for row in rows {
+				changes += self.remote_execute(sql.clone(), Some(row)).await?.changes;
+			}
+
+			Ok(ExecResult { changes })
+		}
+		.await;
+
+		match result {
+			Ok(result) => {
+				self.remote_exec("RELEASE insert_many;".to_owned()).await?;
+				Ok(result)
+			}
+			Err(err) => {
+				if let Err(rollback_err) = self
+					.remote_exec("ROLLBACK TO insert_many; RELEASE insert_many;".to_owned())
+					.await
+				{
+					tracing::warn!(
+						?rollback_err,
+						"failed to roll back remote sqlite insert_many"
+					);
+				}
+				Err(err)
+			}
+		}
+	}
+
 	pub async fn exec(&self, sql: impl Into<String>) -> Result<QueryResult> {
 		let sql = sql.into();
 		let sql_for_log = sql.clone();
@@ -357,6 +444,44 @@ impl SqliteDb {
 		}
 	}
 
+	/// Inserts `rows` into `table` using one prepared statement. Each row must have a value for every
+	/// column.
+	pub async fn insert_many(
+		&self,
+		table: impl Into<String>,
+		columns: Vec<String>,
+		rows: Vec<Vec<BindParam>>,
+	) -> Result<ExecResult> {
+		let table = table.into();
+		let sql_for_log = insert_sql(&table, &columns);
+		let binding_count = rows.iter().map(Vec::len).sum();
+		let result = if let Some(row) = rows.iter().find(|row| row.len() != columns.len()) {
+			Err(SqliteRuntimeError::InvalidBindParameter {
+				name: "rows".to_owned(),
+				reason: format!(
+					"row has {} values but {} columns were given",
+					row.len(),
+					columns.len()
+				),
+			}
+			.build())
+		} else {
+			match self.backend {
+				SqliteBackend::LocalNative => self.local_insert_many(table, columns, rows).await,
+				SqliteBackend::RemoteEnvoy => self.remote_insert_many(table, columns, rows).await,
+				SqliteBackend::Unavailable => Err(SqliteRuntimeError::Unavailable.build()),
+			}
+		};
+		match result {
+			Ok(result) => Ok(result),
+			Err(error) => {
+				let error = self.attach_actor(error);
+				self.log_operation_error("insert_many", &sql_for_log, binding_count, &error);
+				Err(error)
+			}
+		}
+	}
+
 	pub async fn close(&self) -> Result<()> {
 		match self.backend {
 			SqliteBackend::LocalNative => {
@@ -728,6 +853,43 @@ fn map_local_worker_error(error: anyhow::Error) -> anyhow::Error {
 		return SqliteRuntimeError::Closed.build();
 	}
 
+	// Surface the SQLite error class so callers can tell retryable errors from permanent ones
+	if let Some(query_error) = error.downcast_ref::<SqliteQueryError>() {
+		let message = query_error.message().to_string();
+		let extended_code = query_error.extended_code();
+		match query_error.category() {
+			SqliteErrorCategory::Constraint => {
+				return SqliteRuntimeError::Constraint {
+					message,
+					extended_code,
+				}
+				.build();
+			}
+			SqliteErrorCategory::Busy => {
+				return SqliteRuntimeError::Busy {
+					message,
+					extended_code,
+				}
+				.build();
+			}
+			SqliteErrorCategory::Locked => {
+				return SqliteRuntimeError::Locked {
+					message,
+					extended_code,
+				}
+				.build();
+			}
+			SqliteErrorCategory::Corrupt => {
+				return SqliteRuntimeError::Corrupt {
+					message,
+					extended_code,
+				}
+				.build();
+			}
+			SqliteErrorCategory::Other => {}
+		}
+	}
+
 	error
 }
 
@@ -226,4 +226,32 @@ pub(crate) enum SqliteRuntimeError {
 		"Remote SQLite generation is stale: {reason}"
 	)]
 	RemoteFenceMismatch { reason: String },
+
+	#[error(
+		"constraint",
+		"SQLite constraint violated.",
+		"SQLite constraint violated: {message}"
+	)]
+	Constraint { message: String, extended_code: i32 },
+
+	#[error(
+		"busy",
+		"SQLite database is busy.",
+		"SQLite database is busy: {message}"
+	)]
+	Busy { message: String, extended_code: i32 },
+
+	#[error(
+		"locked",
+		"SQLite table is locked.",
+		"SQLite table is locked: {message}"
+	)]
+	Locked { message: String, extended_code: i32 },
+
+	#[error(
+		"corrupt",
+		"SQLite database is corrupt.",
+		"SQLite database is corrupt: {message}"
+	)]
+	Corrupt { message: String, extended_code: i32 },
 }
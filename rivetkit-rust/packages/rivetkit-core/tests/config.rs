@@ -13,6 +13,8 @@ mod moved_tests {
 			sleep_grace_period_ms: Some(12_000),
 			max_queue_size: Some(42),
 			preload_max_workflow_bytes: Some(1024.0),
+			sqlite_foreign_keys: Some(false),
+			sqlite_readonly: Some(true),
 			..ActorConfigInput::default()
 		});
 
@@ -22,6 +24,8 @@ mod moved_tests {
 		assert!(config.sleep_grace_period_overridden);
 		assert_eq!(config.max_queue_size, 42);
 		assert_eq!(config.preload_max_workflow_bytes, Some(1024));
+		assert!(!config.sqlite_foreign_keys);
+		assert!(config.sqlite_readonly);
 	}
 
 	#[test]
@@ -31,6 +35,8 @@ mod moved_tests {
 
 		assert_eq!(config.name, default.name);
 		assert_eq!(config.icon, default.icon);
+		assert!(config.sqlite_foreign_keys);
+		assert!(!config.sqlite_readonly);
 		assert_eq!(config.state_save_interval, default.state_save_interval);
 		assert_eq!(config.create_vars_timeout, default.create_vars_timeout);
 		assert_eq!(
@@ -298,6 +298,42 @@ fn remote_lost_response_errors_become_indeterminate_result() {
 	assert_eq!(structured.code(), "remote_indeterminate_result");
 }
 
+#[cfg(feature = "sqlite-local")]
+#[test]
+fn local_query_errors_keep_sqlite_error_class() {
+	let cases = [
+		(2067, "constraint"), // SQLITE_CONSTRAINT_UNIQUE
+		(5, "busy"),          // SQLITE_BUSY
+		(262, "locked"),      // SQLITE_LOCKED_SHAREDCACHE
+		(11, "corrupt"),      // SQLITE_CORRUPT
+	];
+	for (extended_code, code) in cases {
+		let err = SqliteQueryError::new(
+			"failed to execute sqlite statement",
+			"sqlite failure",
+			extended_code,
+		);
+
+		let mapped = map_local_worker_error(err.into());
+		let structured = rivet_error::RivetError::extract(&mapped);
+		assert_eq!(structured.group(), "sqlite");
+		assert_eq!(structured.code(), code);
+		assert_eq!(
+			structured.metadata(),
+			Some(serde_json::json!({
+				"message": "sqlite failure",
+				"extended_code": extended_code,
+			}))
+		);
+	}
+
+	// Other failures such as syntax errors are left as is
+	let mapped = map_local_worker_error(
+		SqliteQueryError::new("failed to prepare sqlite statement", "syntax error", 1).into(),
+	);
+	assert!(mapped.downcast_ref::<SqliteQueryError>().is_some());
+}
+
 #[tokio::test]
 async fn remote_execute_logs_operation_context_at_source() {
 	let (handle, envoy_rx) = test_envoy_handle();
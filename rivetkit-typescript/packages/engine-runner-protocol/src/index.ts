@@ -1671,6 +1671,101 @@ export function writeToServerKvRequest(bc: bare.ByteCursor, x: ToServerKvRequest
     writeKvRequestData(bc, x.data)
 }
 
+export enum ActorLogStream {
+    Stdout = "Stdout",
+    Stderr = "Stderr",
+}
+
+export function readActorLogStream(bc: bare.ByteCursor): ActorLogStream {
+    const offset = bc.offset
+    const tag = bare.readU8(bc)
+    switch (tag) {
+        case 0:
+            return ActorLogStream.Stdout
+        case 1:
+            return ActorLogStream.Stderr
+        default: {
+            bc.offset = offset
+            throw new bare.BareError(offset, "invalid tag")
+        }
+    }
+}
+
+export function writeActorLogStream(bc: bare.ByteCursor, x: ActorLogStream): void {
+    switch (x) {
+        case ActorLogStream.Stdout: {
+            bare.writeU8(bc, 0)
+            break
+        }
+        case ActorLogStream.Stderr: {
+            bare.writeU8(bc, 1)
+            break
+        }
+    }
+}
+
+export type ActorLogLine = {
+    /**
+     * Epoch ms
+     */
+    readonly ts: i64
+    readonly stream: ActorLogStream
+    readonly message: string
+}
+
+export function readActorLogLine(bc: bare.ByteCursor): ActorLogLine {
+    return {
+        ts: bare.readI64(bc),
+        stream: readActorLogStream(bc),
+        message: bare.readString(bc),
+    }
+}
+
+export function writeActorLogLine(bc: bare.ByteCursor, x: ActorLogLine): void {
+    bare.writeI64(bc, x.ts)
+    writeActorLogStream(bc, x.stream)
+    bare.writeString(bc, x.message)
+}
+
+function read15(bc: bare.ByteCursor): readonly ActorLogLine[] {
+    const len = bare.readUintSafe(bc)
+    if (len === 0) {
+        return []
+    }
+    const result = [readActorLogLine(bc)]
+    for (let i = 1; i < len; i++) {
+        result[i] = readActorLogLine(bc)
+    }
+    return result
+}
+
+function write15(bc: bare.ByteCursor, x: readonly ActorLogLine[]): void {
+    bare.writeUintSafe(bc, x.length)
+    for (let i = 0; i < x.length; i++) {
+        writeActorLogLine(bc, x[i])
+    }
+}
+
+export type ToServerActorLog = {
+    readonly actorId: Id
+    readonly generation: u32
+    readonly lines: readonly ActorLogLine[]
+}
+
+export function readToServerActorLog(bc: bare.ByteCursor): ToServerActorLog {
+    return {
+        actorId: readId(bc),
+        generation: bare.readU32(bc),
+        lines: read15(bc),
+    }
+}
+
+export function writeToServerActorLog(bc: bare.ByteCursor, x: ToServerActorLog): void {
+    writeId(bc, x.actorId)
+    bare.writeU32(bc, x.generation)
+    write15(bc, x.lines)
+}
+
 export type ToServer =
     | { readonly tag: "ToServerInit"; readonly val: ToServerInit }
     | { readonly tag: "ToServerEvents"; readonly val: ToServerEvents }
@@ -1679,6 +1774,7 @@ export type ToServer =
     | { readonly tag: "ToServerPong"; readonly val: ToServerPong }
     | { readonly tag: "ToServerKvRequest"; readonly val: ToServerKvRequest }
     | { readonly tag: "ToServerTunnelMessage"; readonly val: ToServerTunnelMessage }
+    | { readonly tag: "ToServerActorLog"; readonly val: ToServerActorLog }
 
 export function readToServer(bc: bare.ByteCursor): ToServer {
     const offset = bc.offset
@@ -1698,6 +1794,8 @@ export function readToServer(bc: bare.ByteCursor): ToServer {
             return { tag: "ToServerKvRequest", val: readToServerKvRequest(bc) }
         case 6:
             return { tag: "ToServerTunnelMessage", val: readToServerTunnelMessage(bc) }
+        case 7:
+            return { tag: "ToServerActorLog", val: readToServerActorLog(bc) }
         default: {
             bc.offset = offset
             throw new bare.BareError(offset, "invalid tag")
@@ -1741,6 +1839,11 @@ export function writeToServer(bc: bare.ByteCursor, x: ToServer): void {
             writeToServerTunnelMessage(bc, x.val)
             break
         }
+        case "ToServerActorLog": {
+            bare.writeU8(bc, 7)
+            writeToServerActorLog(bc, x.val)
+            break
+        }
     }
 }
 
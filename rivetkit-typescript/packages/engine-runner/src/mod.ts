@@ -19,7 +19,7 @@ export { RunnerActor, type ActorConfig };
 export { idToStr } from "./utils";
 
 const KV_EXPIRE: number = 30_000;
-const PROTOCOL_VERSION: number = 7;
+const PROTOCOL_VERSION: number = 8;
 
 /** Warn once the backlog significantly exceeds the server's ack batch size. */
 const EVENT_BACKLOG_WARN_THRESHOLD = 10_000;
@@ -1638,6 +1638,30 @@ export class Runner {
 		await this.#sendKvRequest(actorId, requestData);
 	}
 
+	// MARK: Log Operations
+	/**
+	 * Forwards log lines written by the actor to the engine so they can be
+	 * read through the actor logs API. Dropped if the actor is not running on
+	 * this runner or the WebSocket is not open.
+	 */
+	sendActorLogs(
+		actorId: string,
+		lines: protocol.ActorLogLine[],
+		generation?: number,
+	) {
+		const actor = this.getActor(actorId, generation);
+		if (!actor || lines.length === 0) return;
+
+		this.__sendToServer({
+			tag: "ToServerActorLog",
+			val: {
+				actorId,
+				generation: actor.generation,
+				lines,
+			},
+		});
+	}
+
 	// MARK: Alarm Operations
 	setAlarm(actorId: string, alarmTs: number | null, generation?: number) {
 		const actor = this.getActor(actorId, generation);
@@ -72,6 +72,8 @@ export interface JsActorConfig {
   icon?: string
   hasDatabase?: boolean
   remoteSqlite?: boolean
+  sqliteForeignKeys?: boolean
+  sqliteReadonly?: boolean
   hasState?: boolean
   canHibernateWebsocket?: boolean
   stateSaveIntervalMs?: number
@@ -305,6 +307,7 @@ export declare class JsNativeDatabase {
   query(sql: string, params?: Array<JsBindParam> | undefined | null): Promise<QueryResult>
   execute(sql: string, params?: Array<JsBindParam> | undefined | null): Promise<NativeExecuteResult>
   exec(sql: string): Promise<QueryResult>
+  insertMany(table: string, columns: Array<string>, rows: Array<Array<JsBindParam>>): Promise<ExecuteResult>
   close(): Promise<void>
 }
 export declare class Kv {
@@ -85,6 +85,8 @@ pub struct JsActorConfig {
 	pub icon: Option<String>,
 	pub has_database: Option<bool>,
 	pub remote_sqlite: Option<bool>,
+	pub sqlite_foreign_keys: Option<bool>,
+	pub sqlite_readonly: Option<bool>,
 	pub has_state: Option<bool>,
 	pub can_hibernate_websocket: Option<bool>,
 	pub state_save_interval_ms: Option<u32>,
@@ -1021,6 +1023,8 @@ impl From<JsActorConfig> for ActorConfigInput {
 			icon: value.icon,
 			has_database: value.has_database,
 			remote_sqlite: value.remote_sqlite,
+			sqlite_foreign_keys: value.sqlite_foreign_keys,
+			sqlite_readonly: value.sqlite_readonly,
 			has_state: value.has_state,
 			can_hibernate_websocket: value.can_hibernate_websocket,
 			state_save_interval_ms: value.state_save_interval_ms,
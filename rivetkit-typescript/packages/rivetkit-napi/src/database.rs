@@ -154,6 +154,27 @@ impl JsNativeDatabase {
 		Ok(core_query_result_to_js(result))
 	}
 
+	#[napi]
+	pub async fn insert_many(
+		&self,
+		table: String,
+		columns: Vec<String>,
+		rows: Vec<Vec<JsBindParam>>,
+	) -> napi::Result<ExecuteResult> {
+		let rows = rows
+			.into_iter()
+			.map(js_bind_params_to_core)
+			.collect::<napi::Result<Vec<_>>>()?;
+		let result = self
+			.db
+			.insert_many(table, columns, rows)
+			.await
+			.map_err(crate::napi_anyhow_error)?;
+		Ok(ExecuteResult {
+			changes: result.changes,
+		})
+	}
+
 	#[napi]
 	pub async fn close(&self) -> napi::Result<()> {
 		self.db.close().await.map_err(crate::napi_anyhow_error)
@@ -178,6 +178,8 @@ pub struct WasmActorConfig {
 	pub icon: Option<String>,
 	pub has_database: Option<bool>,
 	pub remote_sqlite: Option<bool>,
+	pub sqlite_foreign_keys: Option<bool>,
+	pub sqlite_readonly: Option<bool>,
 	pub has_state: Option<bool>,
 	pub can_hibernate_websocket: Option<bool>,
 	pub state_save_interval_ms: Option<u32>,
@@ -213,6 +215,8 @@ impl From<WasmActorConfig> for ActorConfigInput {
 			icon: config.icon,
 			has_database: config.has_database,
 			remote_sqlite: config.remote_sqlite,
+			sqlite_foreign_keys: config.sqlite_foreign_keys,
+			sqlite_readonly: config.sqlite_readonly,
 			has_state: config.has_state,
 			can_hibernate_websocket: config.can_hibernate_websocket,
 			state_save_interval_ms: config.state_save_interval_ms,
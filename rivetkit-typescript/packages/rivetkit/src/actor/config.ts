@@ -1007,6 +1007,10 @@ const InstanceActorOptionsBaseSchema = z
 		preloadMaxWorkflowBytes: z.number().nonnegative().optional(),
 		/** Override RivetKit's connections preload budget for this actor. Set to 0 to disable connections preloading. */
 		preloadMaxConnectionsBytes: z.number().nonnegative().optional(),
+		/** Enforce foreign key constraints in the actor's database. Disable for schemas that rely on SQLite ignoring them. */
+		sqliteForeignKeys: z.boolean().default(true),
+		/** Open the actor's database read-only, e.g. to serve an exported snapshot. Writes, including migrations, are rejected. */
+		sqliteReadonly: z.boolean().default(false),
 	})
 	.strict();
 
@@ -1973,6 +1977,18 @@ export const DocActorOptionsSchema = z
 			.describe(
 				"Override RivetKit's connections preload budget for this actor. Set to 0 to disable connections preloading.",
 			),
+		sqliteForeignKeys: z
+			.boolean()
+			.optional()
+			.describe(
+				"Enforce foreign key constraints in the actor's database. Disable for schemas that rely on SQLite ignoring them. Default: true",
+			),
+		sqliteReadonly: z
+			.boolean()
+			.optional()
+			.describe(
+				"Open the actor's database read-only, e.g. to serve an exported snapshot. Writes, including migrations, are rejected. Default: false",
+			),
 	})
 	.describe("Actor options for timeouts and behavior configuration.");
 
@@ -3300,6 +3300,8 @@ function buildActorConfig(
 		remoteSqlite:
 			config.db !== undefined &&
 			sqliteBackendForConfig(registryConfig) === "remote",
+		sqliteForeignKeys: options.sqliteForeignKeys as boolean | undefined,
+		sqliteReadonly: options.sqliteReadonly as boolean | undefined,
 		hasState:
 			config.state !== undefined ||
 			typeof config.createState === "function",
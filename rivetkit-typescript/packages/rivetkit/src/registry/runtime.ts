@@ -201,6 +201,8 @@ export interface RuntimeActorConfig {
 	icon?: string;
 	hasDatabase?: boolean;
 	remoteSqlite?: boolean;
+	sqliteForeignKeys?: boolean;
+	sqliteReadonly?: boolean;
 	hasState?: boolean;
 	canHibernateWebsocket?: boolean;
 	stateSaveIntervalMs?: number;